@@ -1,9 +1,28 @@
+use crate::StreamEncoder;
 use core::fmt;
 
+/// Number of bytes encoded at a time when formatting.
+const CHUNK_SIZE: usize = 32;
+
 /// `core::fmt` presenter for binary data encoded as hexadecimal (Base16).
+///
+/// Formatting is performed lazily, encoding the data in fixed-size chunks
+/// into a stack buffer without allocating.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub struct HexDisplay<'a>(pub &'a [u8]);
 
+impl HexDisplay<'_> {
+    fn fmt_with(&self, encoder: StreamEncoder, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut hex = [0u8; CHUNK_SIZE * 2];
+
+        for chunk in self.0.chunks(CHUNK_SIZE) {
+            f.write_str(encoder.encode(chunk, &mut hex)?)?;
+        }
+
+        Ok(())
+    }
+}
+
 impl fmt::Display for HexDisplay<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{:X}", self)
@@ -12,24 +31,12 @@ impl fmt::Display for HexDisplay<'_> {
 
 impl fmt::UpperHex for HexDisplay<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut hex = [0u8; 2];
-
-        for &byte in self.0 {
-            f.write_str(crate::upper::encode_str(&[byte], &mut hex)?)?;
-        }
-
-        Ok(())
+        self.fmt_with(StreamEncoder::upper(), f)
     }
 }
 
 impl fmt::LowerHex for HexDisplay<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut hex = [0u8; 2];
-
-        for &byte in self.0 {
-            f.write_str(crate::lower::encode_str(&[byte], &mut hex)?)?;
-        }
-
-        Ok(())
+        self.fmt_with(StreamEncoder::lower(), f)
     }
 }
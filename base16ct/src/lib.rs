@@ -76,10 +76,13 @@ pub mod upper;
 mod display;
 /// Error types.
 mod error;
+/// Incremental encoding and decoding.
+mod stream;
 
 pub use crate::{
     display::HexDisplay,
     error::{Error, Result},
+    stream::{StreamDecoder, StreamEncoder},
 };
 
 #[cfg(feature = "alloc")]
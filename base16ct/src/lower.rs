@@ -51,7 +51,7 @@ pub fn encode_string(input: &[u8]) -> String {
 
 /// Decode a single nibble of lower hex
 #[inline(always)]
-fn decode_nibble(src: u8) -> u16 {
+pub(crate) fn decode_nibble(src: u8) -> u16 {
     // 0-9  0x30-0x39
     // A-F  0x41-0x46 or a-f  0x61-0x66
     let byte = src as i16;
@@ -69,7 +69,7 @@ fn decode_nibble(src: u8) -> u16 {
 
 /// Encode a single nibble of hex
 #[inline(always)]
-fn encode_nibble(src: u8) -> u8 {
+pub(crate) fn encode_nibble(src: u8) -> u8 {
     let mut ret = src as i16 + 0x30;
     // 0-9  0x30-0x39
     // a-f  0x61-0x66
@@ -17,7 +17,7 @@ pub fn decode_vec(input: impl AsRef<[u8]>) -> Result<Vec<u8>, Error> {
 
 /// Decode a single nibble of lower hex
 #[inline(always)]
-fn decode_nibble(src: u8) -> u16 {
+pub(crate) fn decode_nibble(src: u8) -> u16 {
     // 0-9  0x30-0x39
     // A-F  0x41-0x46 or a-f  0x61-0x66
     let byte = src as i16;
@@ -0,0 +1,175 @@
+use crate::{Error, Result};
+use core::str;
+
+/// Incremental Base16 (hex) encoder.
+///
+/// Encodes input supplied in arbitrarily sized chunks into a caller-provided
+/// (and potentially reused) output buffer, avoiding the need to allocate a
+/// buffer for the hex encoding of the whole input.
+///
+/// ```
+/// let mut buf = [0u8; 8];
+/// let mut hex = String::new();
+/// let encoder = base16ct::StreamEncoder::lower();
+///
+/// for chunk in b"\xab\xcd\x12\x34\x56".chunks(4) {
+///     hex.push_str(encoder.encode(chunk, &mut buf)?);
+/// }
+///
+/// assert_eq!(hex, "abcd123456");
+/// # Ok::<(), base16ct::Error>(())
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct StreamEncoder {
+    /// Function for encoding a single nibble.
+    encode_nibble: fn(u8) -> u8,
+}
+
+impl StreamEncoder {
+    /// Create an encoder which produces lower case Base16 (hex).
+    pub fn lower() -> Self {
+        Self {
+            encode_nibble: crate::lower::encode_nibble,
+        }
+    }
+
+    /// Create an encoder which produces upper case Base16 (hex).
+    pub fn upper() -> Self {
+        Self {
+            encode_nibble: crate::upper::encode_nibble,
+        }
+    }
+
+    /// Encode a chunk of input into the provided destination buffer, which
+    /// must be at least twice as long as `src`.
+    pub fn encode<'a>(&self, src: &[u8], dst: &'a mut [u8]) -> Result<&'a str> {
+        let dst = dst
+            .get_mut(..crate::encoded_len(src))
+            .ok_or(Error::InvalidLength)?;
+
+        for (src, dst) in src.iter().zip(dst.chunks_exact_mut(2)) {
+            dst[0] = (self.encode_nibble)(src >> 4);
+            dst[1] = (self.encode_nibble)(src & 0x0f);
+        }
+
+        // SAFETY: `dst` only contains ASCII characters written by `encode_nibble`
+        Ok(unsafe { str::from_utf8_unchecked(dst) })
+    }
+}
+
+/// Incremental Base16 (hex) decoder.
+///
+/// Decodes input supplied in arbitrarily sized chunks, which need not be
+/// split on byte boundaries (i.e. chunks may have an odd length). A trailing
+/// unpaired character is carried over to the next chunk.
+///
+/// Once all input has been supplied, [`StreamDecoder::finish`] must be called
+/// to ensure the input didn't end with an unpaired character.
+///
+/// After an error has been returned the state of the decoder is unspecified,
+/// and it should be discarded.
+///
+/// ```
+/// let mut buf = [0u8; 4];
+/// let mut decoded = Vec::new();
+/// let mut decoder = base16ct::StreamDecoder::mixed();
+///
+/// for chunk in b"abCD12345".chunks(3) {
+///     decoded.extend_from_slice(decoder.decode(chunk, &mut buf)?);
+/// }
+///
+/// decoded.extend_from_slice(decoder.decode(b"6", &mut buf)?);
+/// decoder.finish()?;
+/// assert_eq!(decoded, b"\xab\xcd\x12\x34\x56");
+/// # Ok::<(), base16ct::Error>(())
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct StreamDecoder {
+    /// Function for decoding a single nibble.
+    decode_nibble: fn(u8) -> u16,
+
+    /// Decoded high nibble carried over from the previous chunk.
+    pending: Option<u16>,
+}
+
+impl StreamDecoder {
+    /// Create a decoder which accepts lower case Base16 (hex).
+    pub fn lower() -> Self {
+        Self::new(crate::lower::decode_nibble)
+    }
+
+    /// Create a decoder which accepts upper case Base16 (hex).
+    pub fn upper() -> Self {
+        Self::new(crate::upper::decode_nibble)
+    }
+
+    /// Create a decoder which accepts both lower and upper case Base16 (hex).
+    pub fn mixed() -> Self {
+        Self::new(crate::mixed::decode_nibble)
+    }
+
+    fn new(decode_nibble: fn(u8) -> u16) -> Self {
+        Self {
+            decode_nibble,
+            pending: None,
+        }
+    }
+
+    /// Get the number of bytes which will be produced by decoding the given
+    /// chunk of input.
+    pub fn decoded_len(&self, src: &[u8]) -> usize {
+        (src.len() + usize::from(self.pending.is_some())) / 2
+    }
+
+    /// Decode a chunk of input into the provided destination buffer, which
+    /// must be at least [`StreamDecoder::decoded_len`] bytes long.
+    pub fn decode<'a>(&mut self, mut src: &[u8], dst: &'a mut [u8]) -> Result<&'a [u8]> {
+        let dst = dst
+            .get_mut(..self.decoded_len(src))
+            .ok_or(Error::InvalidLength)?;
+
+        let mut err: u16 = 0;
+        let mut out = dst.iter_mut();
+
+        if let Some(hi) = self.pending.take() {
+            match (src.split_first(), out.next()) {
+                (Some((&lo, rest)), Some(byte)) => {
+                    let decoded = (hi << 4) | (self.decode_nibble)(lo);
+                    err |= decoded >> 8;
+                    *byte = decoded as u8;
+                    src = rest;
+                }
+                _ => {
+                    self.pending = Some(hi);
+                    return Ok(dst);
+                }
+            }
+        }
+
+        let mut pairs = src.chunks_exact(2);
+
+        for (pair, byte) in (&mut pairs).zip(out) {
+            let decoded = ((self.decode_nibble)(pair[0]) << 4) | (self.decode_nibble)(pair[1]);
+            err |= decoded >> 8;
+            *byte = decoded as u8;
+        }
+
+        if let Some(&c) = pairs.remainder().first() {
+            self.pending = Some((self.decode_nibble)(c));
+        }
+
+        match err {
+            0 => Ok(dst),
+            _ => Err(Error::InvalidEncoding),
+        }
+    }
+
+    /// Finish decoding, returning an error if the input ended with an
+    /// unpaired character.
+    pub fn finish(self) -> Result<()> {
+        match self.pending {
+            None => Ok(()),
+            Some(_) => Err(Error::InvalidLength),
+        }
+    }
+}
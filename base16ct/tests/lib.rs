@@ -161,3 +161,87 @@ fn hex_display_lower() {
         assert_eq!(hex.as_bytes(), vector.lower_hex);
     }
 }
+
+#[test]
+fn hex_display_long() {
+    let data = [0xa5u8; 100];
+    let hex = format!("{:x}", base16ct::HexDisplay(&data));
+    assert_eq!(hex, "a5".repeat(100));
+}
+
+#[test]
+fn stream_encode() {
+    for vector in HEX_TEST_VECTORS {
+        for chunk_size in 1..4 {
+            let mut lower = Vec::new();
+            let mut upper = Vec::new();
+            let mut buf = [0u8; 8];
+
+            for chunk in vector.raw.chunks(chunk_size) {
+                let hex = base16ct::StreamEncoder::lower().encode(chunk, &mut buf);
+                lower.extend_from_slice(hex.unwrap().as_bytes());
+                let hex = base16ct::StreamEncoder::upper().encode(chunk, &mut buf);
+                upper.extend_from_slice(hex.unwrap().as_bytes());
+            }
+
+            assert_eq!(lower, vector.lower_hex);
+            assert_eq!(upper, vector.upper_hex);
+        }
+    }
+
+    let mut buf = [0u8; 3];
+    assert_eq!(
+        base16ct::StreamEncoder::lower().encode(b"ab", &mut buf),
+        Err(base16ct::Error::InvalidLength)
+    );
+}
+
+#[test]
+fn stream_decode() {
+    for vector in HEX_TEST_VECTORS {
+        for chunk_size in 1..6 {
+            for (hex, mut decoder) in [
+                (vector.lower_hex, base16ct::StreamDecoder::lower()),
+                (vector.upper_hex, base16ct::StreamDecoder::upper()),
+                (vector.lower_hex, base16ct::StreamDecoder::mixed()),
+                (vector.upper_hex, base16ct::StreamDecoder::mixed()),
+            ] {
+                let mut decoded = Vec::new();
+                let mut buf = [0u8; 3];
+
+                for chunk in hex.chunks(chunk_size) {
+                    decoded.extend_from_slice(decoder.decode(chunk, &mut buf).unwrap());
+                }
+
+                decoder.finish().unwrap();
+                assert_eq!(decoded, vector.raw);
+            }
+        }
+    }
+}
+
+#[test]
+fn stream_decode_errors() {
+    let mut buf = [0u8; 4];
+
+    let mut decoder = base16ct::StreamDecoder::lower();
+    assert_eq!(decoder.decode(b"abc", &mut buf), Ok(&b"\xab"[..]));
+    assert_eq!(decoder.finish(), Err(base16ct::Error::InvalidLength));
+
+    let mut decoder = base16ct::StreamDecoder::lower();
+    assert_eq!(decoder.decode(b"a", &mut buf), Ok(&b""[..]));
+    assert_eq!(
+        decoder.decode(b"B", &mut buf),
+        Err(base16ct::Error::InvalidEncoding)
+    );
+
+    let mut decoder = base16ct::StreamDecoder::upper();
+    assert_eq!(
+        decoder.decode(b"AB12", &mut [0u8; 1]),
+        Err(base16ct::Error::InvalidLength)
+    );
+    assert_eq!(
+        decoder.decode(b"AG", &mut buf),
+        Err(base16ct::Error::InvalidEncoding)
+    );
+}
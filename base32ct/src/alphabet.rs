@@ -1,5 +1,6 @@
 //! Base32 alphabets.
 
+pub(crate) mod crockford;
 pub(crate) mod rfc4648;
 
 use core::{fmt::Debug, ops::RangeInclusive};
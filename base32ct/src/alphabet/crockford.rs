@@ -0,0 +1,233 @@
+//! Crockford's Base32 alphabet.
+
+use super::{Alphabet, DecodeStep, EncodeStep};
+use crate::{Encoding, Error, Result};
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+/// Crockford's Base32 encoding *without* padding.
+///
+/// Encodes using upper case characters, excluding `I`, `L`, `O`, and `U`:
+///
+/// ```text
+/// [0-9]      [A-H]      [J-K]      [M-N]      [P-T]      [V-Z]
+/// 0x30-0x39, 0x41-0x48, 0x4a-0x4b, 0x4d-0x4e, 0x50-0x54, 0x56-0x5a
+/// ```
+///
+/// Decoding is case-insensitive, and folds the ambiguous characters `I` and
+/// `L` to `1`, and `O` to `0`. Hyphens are not accepted and must be removed
+/// prior to decoding.
+///
+/// The optional check symbol described in the specification is supported by
+/// [`Base32Crockford::encode_with_check`] and
+/// [`Base32Crockford::decode_with_check`].
+///
+/// See: <https://www.crockford.com/base32.html>
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Base32Crockford;
+
+impl Alphabet for Base32Crockford {
+    const BASE: u8 = b'0';
+    const DECODER: &'static [DecodeStep] = &[
+        DecodeStep(b'0'..=b'9', -47),
+        DecodeStep(b'A'..=b'H', -54),
+        DecodeStep(b'I'..=b'I', -71),
+        DecodeStep(b'J'..=b'K', -55),
+        DecodeStep(b'L'..=b'L', -74),
+        DecodeStep(b'M'..=b'N', -56),
+        DecodeStep(b'O'..=b'O', -78),
+        DecodeStep(b'P'..=b'T', -57),
+        DecodeStep(b'V'..=b'Z', -58),
+        DecodeStep(b'a'..=b'h', -86),
+        DecodeStep(b'i'..=b'i', -103),
+        DecodeStep(b'j'..=b'k', -87),
+        DecodeStep(b'l'..=b'l', -106),
+        DecodeStep(b'm'..=b'n', -88),
+        DecodeStep(b'o'..=b'o', -110),
+        DecodeStep(b'p'..=b't', -89),
+        DecodeStep(b'v'..=b'z', -90),
+    ];
+    const ENCODER: &'static [EncodeStep] = &[
+        EncodeStep(9, -7),
+        EncodeStep(17, -1),
+        EncodeStep(19, -1),
+        EncodeStep(21, -1),
+        EncodeStep(26, -1),
+    ];
+    const PADDED: bool = false;
+}
+
+/// Additional check symbols for the values 32-36.
+const CHECK_SYMBOLS: [u8; 5] = *b"*~$=U";
+
+/// Decoder for the additional check symbols, including lower case `u`.
+const CHECK_DECODER: &[DecodeStep] = &[
+    DecodeStep(b'*'..=b'*', -9),
+    DecodeStep(b'~'..=b'~', -92),
+    DecodeStep(b'$'..=b'$', -1),
+    DecodeStep(b'='..=b'=', -25),
+    DecodeStep(b'U'..=b'U', -48),
+    DecodeStep(b'u'..=b'u', -80),
+];
+
+impl Base32Crockford {
+    /// Encode the input byte slice as Base32, followed by a check symbol.
+    ///
+    /// The destination buffer must be one byte larger than
+    /// [`Encoding::encoded_len`].
+    pub fn encode_with_check<'a>(src: &[u8], dst: &'a mut [u8]) -> Result<&'a str> {
+        let elen = Self::encoded_len(src);
+        let dst = dst.get_mut(..=elen).ok_or(Error::InvalidLength)?;
+        let (encoded, check) = dst.split_at_mut(elen);
+        Self::encode(src, encoded)?;
+        check[0] = encode_check(checksum(encoded));
+
+        // Only ASCII characters have been written to `dst`
+        core::str::from_utf8(dst).map_err(|_| Error::InvalidEncoding)
+    }
+
+    /// Encode the input byte slice into a [`String`] containing Base32,
+    /// followed by a check symbol.
+    #[cfg(feature = "alloc")]
+    pub fn encode_string_with_check(input: &[u8]) -> String {
+        let mut output = Self::encode_string(input);
+        let check = encode_check(checksum(output.as_bytes()));
+        output.push(char::from(check));
+        output
+    }
+
+    /// Decode a Base32 string which ends with a check symbol into the
+    /// provided output buffer, returning a slice containing the decoded data.
+    ///
+    /// Returns [`Error::InvalidEncoding`] if the check symbol does not match
+    /// the decoded data.
+    pub fn decode_with_check(src: impl AsRef<[u8]>, dst: &mut [u8]) -> Result<&[u8]> {
+        let (&check, src) = src.as_ref().split_last().ok_or(Error::InvalidEncoding)?;
+
+        let expected = decode_check(check);
+        let actual = checksum(src);
+        let decoded = Self::decode(src, dst)?;
+
+        if (expected ^ actual) == 0 {
+            Ok(decoded)
+        } else {
+            Err(Error::InvalidEncoding)
+        }
+    }
+
+    /// Decode a Base32 string which ends with a check symbol into a byte
+    /// vector.
+    #[cfg(feature = "alloc")]
+    pub fn decode_vec_with_check(input: &str) -> Result<Vec<u8>> {
+        let mut output = vec![0u8; (input.len() * 5) / 8];
+        let len = Self::decode_with_check(input, &mut output)?.len();
+        output.truncate(len);
+        Ok(output)
+    }
+}
+
+/// Compute the value of the check symbol for the given Base32 characters,
+/// i.e. the encoded number modulo 37.
+///
+/// Invalid characters are not detected; they are rejected by the decoder.
+fn checksum(encoded: &[u8]) -> i16 {
+    encoded.iter().fold(0, |acc, &byte| {
+        let value = Base32Crockford::decode_5bits(byte) & 31;
+        mod37(acc * 32 + value)
+    })
+}
+
+/// Compute `n mod 37` for `0 <= n < 1216` without a division.
+fn mod37(n: i16) -> i16 {
+    // floor(n * 1772 / 2^16) == floor(n / 37) for all n < 1216
+    let q = ((n as u32 * 1772) >> 16) as i16;
+    n - q * 37
+}
+
+/// Encode a check symbol value in the range 0-36.
+fn encode_check(value: i16) -> u8 {
+    // 0xff if `value` < 32
+    let is_digit = ((value - 32) >> 8) as u8;
+    let mut ret = Base32Crockford::encode_5bits((value & 31) as u8) & is_digit;
+
+    for (i, &symbol) in CHECK_SYMBOLS.iter().enumerate() {
+        // 0xff if `value` == 32 + i
+        let is_symbol = ((((value ^ (32 + i as i16)) - 1) >> 8) & 0xff) as u8;
+        ret |= symbol & is_symbol;
+    }
+
+    ret
+}
+
+/// Decode a check symbol, returning -1 if it's invalid.
+fn decode_check(byte: u8) -> i16 {
+    let src = byte as i16;
+    let mut ret = Base32Crockford::decode_5bits(byte);
+
+    for DecodeStep(range, offset) in CHECK_DECODER {
+        let start = *range.start() as i16 - 1;
+        let end = *range.end() as i16 + 1;
+        ret += (((start - src) & (src - end)) >> 8) & (src + *offset);
+    }
+
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_check, encode_check, mod37, Base32Crockford, CHECK_SYMBOLS};
+    use crate::alphabet::Alphabet;
+
+    const ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+    #[test]
+    fn alphabet() {
+        for (value, &c) in ALPHABET.iter().enumerate() {
+            assert_eq!(Base32Crockford::encode_5bits(value as u8), c);
+            assert_eq!(Base32Crockford::decode_5bits(c), value as i16);
+            assert_eq!(
+                Base32Crockford::decode_5bits(c.to_ascii_lowercase()),
+                value as i16
+            );
+        }
+    }
+
+    #[test]
+    fn ambiguous_characters() {
+        for c in *b"OoIiLlUu-=*" {
+            let expected = match c {
+                b'O' | b'o' => 0,
+                b'I' | b'i' | b'L' | b'l' => 1,
+                _ => -1,
+            };
+
+            assert_eq!(Base32Crockford::decode_5bits(c), expected);
+        }
+    }
+
+    #[test]
+    fn check_symbols() {
+        for value in 0..37 {
+            let c = encode_check(value);
+
+            if value < 32 {
+                assert_eq!(c, ALPHABET[value as usize]);
+            } else {
+                assert_eq!(c, CHECK_SYMBOLS[value as usize - 32]);
+            }
+
+            assert_eq!(decode_check(c), value);
+            assert_eq!(decode_check(c.to_ascii_lowercase()), value);
+        }
+
+        assert_eq!(decode_check(b'#'), -1);
+    }
+
+    #[test]
+    fn mod37_matches_remainder() {
+        for n in 0..1216 {
+            assert_eq!(mod37(n), n % 37);
+        }
+    }
+}
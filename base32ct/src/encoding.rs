@@ -42,6 +42,11 @@ impl<T: Alphabet> Encoding for T {
             return Ok(&[]);
         }
 
+        // A trailing chunk of 1, 3, or 6 characters can't be produced by the encoder
+        if matches!(src.len() % 8, 1 | 3 | 6) {
+            return Err(Error::InvalidEncoding);
+        }
+
         let dlen = decoded_len(src.len());
         let dst = dst.get_mut(..dlen).ok_or(Error::InvalidLength)?;
 
@@ -226,7 +231,7 @@ impl<T: Alphabet> Encoding for T {
         } else if Self::PADDED {
             ((bytes.len() - 1) / 5 + 1) * 8
         } else {
-            (bytes.len() * 8) / 5 + 1
+            (bytes.len() * 8 + 4) / 5
         }
     }
 }
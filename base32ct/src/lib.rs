@@ -58,7 +58,10 @@ mod encoding;
 mod error;
 
 pub use crate::{
-    alphabet::rfc4648::{Base32, Base32Unpadded, Base32Upper},
+    alphabet::{
+        crockford::Base32Crockford,
+        rfc4648::{Base32, Base32Unpadded, Base32Upper},
+    },
     encoding::Encoding,
     error::{Error, Result},
 };
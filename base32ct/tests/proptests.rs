@@ -3,11 +3,15 @@
 #![cfg(feature = "alloc")]
 
 use base32::Alphabet;
-use base32ct::{Base32 as Base32Ct, Base32Unpadded as Base32UnpaddedCt, Encoding};
+use base32ct::{
+    Base32 as Base32Ct, Base32Crockford as Base32CrockfordCt, Base32Unpadded as Base32UnpaddedCt,
+    Encoding,
+};
 use proptest::{prelude::*, string::*};
 
 const RFC4648_PADDED: Alphabet = Alphabet::RFC4648 { padding: true };
 const RFC4648_UNPADDED: Alphabet = Alphabet::RFC4648 { padding: false };
+const CROCKFORD: Alphabet = Alphabet::Crockford;
 
 proptest! {
     /// Ensure `base32ct` decodes padded data encoded by `base32` ref crate.
@@ -34,4 +38,16 @@ proptest! {
         let expected = base32::encode(RFC4648_PADDED, &bytes).to_lowercase();
         prop_assert_eq!(actual, expected);
     }
+
+    /// Ensure `base32ct` and the `base32` ref crate encode and decode
+    /// randomly generated inputs equivalently (Crockford).
+    #[test]
+    fn crockford_equiv(bytes in bytes_regex(".{0,256}").unwrap()) {
+        let encoded = base32::encode(CROCKFORD, &bytes);
+        prop_assert_eq!(&Base32CrockfordCt::encode_string(&bytes), &encoded);
+        prop_assert_eq!(Ok(bytes.clone()), Base32CrockfordCt::decode_vec(&encoded));
+
+        let checked = Base32CrockfordCt::encode_string_with_check(&bytes);
+        prop_assert_eq!(Ok(bytes), Base32CrockfordCt::decode_vec_with_check(&checked));
+    }
 }
@@ -2,7 +2,7 @@
 
 #![cfg(feature = "alloc")]
 
-use base32ct::{Base32, Base32Unpadded, Base32Upper, Encoding, Error};
+use base32ct::{Base32, Base32Crockford, Base32Unpadded, Base32Upper, Encoding, Error};
 
 #[derive(Debug)]
 struct TestVector {
@@ -55,6 +55,61 @@ const UPPER_PADDED_VECTORS: &[TestVector] = &[
     },
 ];
 
+const CROCKFORD_VECTORS: &[TestVector] = &[
+    TestVector {
+        decoded: &[0],
+        encoded: "00",
+    },
+    TestVector {
+        decoded: &[1, 2, 3, 5, 9, 17, 33, 65, 129],
+        encoded: "0410618924GM308",
+    },
+    TestVector {
+        decoded: &[32, 7],
+        encoded: "403G",
+    },
+    TestVector {
+        decoded: b"hello",
+        encoded: "D1JPRV3F",
+    },
+];
+
+/// Crockford test vectors with a trailing check symbol.
+const CROCKFORD_CHECK_VECTORS: &[TestVector] = &[
+    TestVector {
+        decoded: &[],
+        encoded: "0",
+    },
+    TestVector {
+        decoded: &[1, 2, 3, 5, 9, 17, 33, 65, 129],
+        encoded: "0410618924GM308D",
+    },
+    TestVector {
+        decoded: b"hello",
+        encoded: "D1JPRV3FJ",
+    },
+    TestVector {
+        decoded: &[8],
+        encoded: "10*",
+    },
+    TestVector {
+        decoded: &[36],
+        encoded: "4G~",
+    },
+    TestVector {
+        decoded: &[27],
+        encoded: "3C$",
+    },
+    TestVector {
+        decoded: &[18],
+        encoded: "28=",
+    },
+    TestVector {
+        decoded: &[9],
+        encoded: "14U",
+    },
+];
+
 #[test]
 fn decode_valid_base32() {
     for vector in LOWER_PADDED_VECTORS {
@@ -74,6 +129,73 @@ fn decode_valid_base32() {
             vector.decoded
         );
     }
+
+    for vector in CROCKFORD_VECTORS {
+        assert_eq!(
+            &Base32Crockford::decode_vec(vector.encoded).unwrap(),
+            vector.decoded
+        );
+        assert_eq!(
+            &Base32Crockford::decode_vec(&vector.encoded.to_lowercase()).unwrap(),
+            vector.decoded
+        );
+    }
+}
+
+#[test]
+fn decode_crockford_ambiguous() {
+    assert_eq!(
+        Base32Crockford::decode_vec("o4IO6l8924gm3o8").unwrap(),
+        Base32Crockford::decode_vec("0410618924GM308").unwrap()
+    );
+    assert_eq!(
+        Base32Crockford::decode_vec("403U"),
+        Err(Error::InvalidEncoding)
+    );
+    assert_eq!(
+        Base32Crockford::decode_vec("40-3G"),
+        Err(Error::InvalidEncoding)
+    );
+}
+
+#[test]
+fn decode_crockford_with_check() {
+    for vector in CROCKFORD_CHECK_VECTORS {
+        assert_eq!(
+            &Base32Crockford::decode_vec_with_check(vector.encoded).unwrap(),
+            vector.decoded
+        );
+        assert_eq!(
+            &Base32Crockford::decode_vec_with_check(&vector.encoded.to_lowercase()).unwrap(),
+            vector.decoded
+        );
+    }
+}
+
+#[test]
+fn decode_crockford_check_error() {
+    // Wrong check symbol
+    assert_eq!(
+        Base32Crockford::decode_vec_with_check("D1JPRV3FK"),
+        Err(Error::InvalidEncoding)
+    );
+
+    // Transposed characters
+    assert_eq!(
+        Base32Crockford::decode_vec_with_check("D1JPRV3JF"),
+        Err(Error::InvalidEncoding)
+    );
+
+    // Check symbol which is only valid in the check position
+    assert_eq!(
+        Base32Crockford::decode_vec_with_check("1*0*"),
+        Err(Error::InvalidEncoding)
+    );
+
+    assert_eq!(
+        Base32Crockford::decode_vec_with_check(""),
+        Err(Error::InvalidEncoding)
+    );
 }
 
 #[test]
@@ -107,4 +229,43 @@ fn encode_base32() {
     for vector in UPPER_PADDED_VECTORS {
         assert_eq!(&Base32Upper::encode_string(vector.decoded), vector.encoded);
     }
+
+    for vector in CROCKFORD_VECTORS {
+        assert_eq!(
+            &Base32Crockford::encode_string(vector.decoded),
+            vector.encoded
+        );
+    }
+}
+
+#[test]
+fn encode_crockford_with_check() {
+    for vector in CROCKFORD_CHECK_VECTORS {
+        assert_eq!(
+            &Base32Crockford::encode_string_with_check(vector.decoded),
+            vector.encoded
+        );
+
+        let mut buf = [0u8; 16];
+        assert_eq!(
+            Base32Crockford::encode_with_check(vector.decoded, &mut buf),
+            Ok(vector.encoded)
+        );
+    }
+
+    let mut buf = [0u8; 8];
+    assert_eq!(
+        Base32Crockford::encode_with_check(b"hello", &mut buf),
+        Err(Error::InvalidLength)
+    );
+}
+
+#[test]
+fn decode_truncated_error() {
+    for encoded in ["a", "aaa", "aaaaaa", "aaaaaaaaa"] {
+        assert_eq!(
+            Base32Unpadded::decode_vec(encoded),
+            Err(Error::InvalidEncoding)
+        );
+    }
 }
@@ -0,0 +1,254 @@
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_auto_cfg))]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/media/6ee8e381/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/media/6ee8e381/logo.svg"
+)]
+#![warn(
+    clippy::mod_module_files,
+    clippy::unwrap_used,
+    missing_docs,
+    rust_2018_idioms,
+    unused_lifetimes,
+    unused_qualifications
+)]
+
+//! Pure Rust implementation of Base45 ([RFC 9285]).
+//!
+//! Base45 is designed for compactly encoding binary data in alphanumeric
+//! mode QR codes, e.g. the payloads of EU Digital COVID Certificates.
+//!
+//! Implemented without data-dependent branches or lookup tables, thereby
+//! providing portable "best effort" constant-time operation. Not
+//! constant-time with respect to message length (only data).
+//!
+//! Supports `no_std` environments and avoids heap allocations in the core API
+//! (but also provides optional `alloc` support for convenience).
+//!
+//! # Examples
+//! ```
+//! let mut buf = [0u8; 16];
+//!
+//! let res = base45ct::encode(b"ietf!", &mut buf).unwrap();
+//! assert_eq!(res, "QED8WEX0");
+//!
+//! let res = base45ct::decode("QED8WEX0", &mut buf).unwrap();
+//! assert_eq!(res, b"ietf!");
+//! ```
+//!
+//! [RFC 9285]: https://datatracker.ietf.org/doc/html/rfc9285
+
+#[cfg(feature = "alloc")]
+#[macro_use]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+/// Error types.
+mod error;
+
+pub use crate::error::{Error, Result};
+
+use core::str;
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+/// Compute decoded length of the given Base45-encoded input.
+#[inline(always)]
+pub fn decoded_len(bytes: &[u8]) -> Result<usize> {
+    match bytes.len() % 3 {
+        0 => Ok(bytes.len() / 3 * 2),
+        2 => Ok(bytes.len() / 3 * 2 + 1),
+        _ => Err(Error::InvalidLength),
+    }
+}
+
+/// Get the length of Base45 produced by encoding the given bytes.
+#[inline(always)]
+pub fn encoded_len(bytes: &[u8]) -> usize {
+    bytes.len() / 2 * 3 + (bytes.len() % 2) * 2
+}
+
+/// Decode a Base45 string into the provided destination buffer.
+pub fn decode(src: impl AsRef<[u8]>, dst: &mut [u8]) -> Result<&[u8]> {
+    let src = src.as_ref();
+    let dst = dst
+        .get_mut(..decoded_len(src)?)
+        .ok_or(Error::InvalidLength)?;
+
+    let mut src_chunks = src.chunks_exact(3);
+    let mut dst_chunks = dst.chunks_exact_mut(2);
+    let mut err: u32 = 0;
+
+    for (s, d) in (&mut src_chunks).zip(&mut dst_chunks) {
+        let c = decode_char(s[0]);
+        let d1 = decode_char(s[1]);
+        let e = decode_char(s[2]);
+        err |= (c | d1 | e) >> 8;
+
+        let n = (c & 0x3f) + (d1 & 0x3f) * 45 + (e & 0x3f) * 45 * 45;
+        err |= n >> 16;
+        d[0] = (n >> 8) as u8;
+        d[1] = n as u8;
+    }
+
+    let src_rem = src_chunks.remainder();
+
+    if let [c, d1] = *src_rem {
+        let c = decode_char(c);
+        let d1 = decode_char(d1);
+        err |= (c | d1) >> 8;
+
+        let n = (c & 0x3f) + (d1 & 0x3f) * 45;
+        err |= n >> 8;
+        dst_chunks.into_remainder()[0] = n as u8;
+    }
+
+    match err {
+        0 => Ok(dst),
+        _ => Err(Error::InvalidEncoding),
+    }
+}
+
+/// Decode a Base45 string into a byte vector.
+#[cfg(feature = "alloc")]
+pub fn decode_vec(input: impl AsRef<[u8]>) -> Result<Vec<u8>> {
+    let mut output = vec![0u8; decoded_len(input.as_ref())?];
+    decode(input, &mut output)?;
+    Ok(output)
+}
+
+/// Encode the input byte slice as Base45.
+///
+/// Writes the result into the provided destination slice, returning an
+/// ASCII-encoded Base45 string value.
+pub fn encode<'a>(src: &[u8], dst: &'a mut [u8]) -> Result<&'a str> {
+    let dst = dst
+        .get_mut(..encoded_len(src))
+        .ok_or(Error::InvalidLength)?;
+
+    let mut src_chunks = src.chunks_exact(2);
+    let mut dst_chunks = dst.chunks_exact_mut(3);
+
+    for (s, d) in (&mut src_chunks).zip(&mut dst_chunks) {
+        let n = (u32::from(s[0]) << 8) | u32::from(s[1]);
+        let q = div45(n);
+        d[0] = encode_char(n - q * 45);
+        d[1] = encode_char(q - div45(q) * 45);
+        d[2] = encode_char(div45(q));
+    }
+
+    if let [byte] = *src_chunks.remainder() {
+        let n = u32::from(byte);
+        let d = dst_chunks.into_remainder();
+        d[0] = encode_char(n - div45(n) * 45);
+        d[1] = encode_char(div45(n));
+    }
+
+    debug_assert!(dst.is_ascii());
+    str::from_utf8(dst).map_err(|_| Error::InvalidEncoding)
+}
+
+/// Encode input byte slice into a [`String`] containing Base45.
+///
+/// # Panics
+/// If `input` length is greater than `usize::MAX/3*2`.
+#[cfg(feature = "alloc")]
+pub fn encode_string(input: &[u8]) -> String {
+    let elen = encoded_len(input);
+    let mut dst = vec![0u8; elen];
+    let res = encode(input, &mut dst).expect("dst length is correct");
+
+    debug_assert_eq!(elen, res.len());
+    String::from_utf8(dst).expect("encoded data is ASCII")
+}
+
+/// Compute `n / 45` for `n < 2^16` without a division.
+#[inline(always)]
+fn div45(n: u32) -> u32 {
+    // ceil(2^21 / 45)
+    (n * 46604) >> 21
+}
+
+/// Decode a single Base45 character, returning a value greater than `0xff`
+/// if it is not in the alphabet.
+#[inline(always)]
+fn decode_char(src: u8) -> u32 {
+    // 0-9  0x30-0x39
+    // A-Z  0x41-0x5a
+    // ' '  0x20
+    // $%   0x24-0x25
+    // *+   0x2a-0x2b
+    // -./  0x2d-0x2f
+    // :    0x3a
+    let byte = src as i16;
+    let mut ret: i16 = -1;
+
+    // if (byte > 0x2f && byte < 0x3a) ret += byte - 0x30 + 1; // -47
+    ret += (((0x2fi16 - byte) & (byte - 0x3a)) >> 8) & (byte - 47);
+    // if (byte > 0x40 && byte < 0x5b) ret += byte - 0x41 + 10 + 1; // -54
+    ret += (((0x40i16 - byte) & (byte - 0x5b)) >> 8) & (byte - 54);
+    // if (byte == 0x20) ret += 36 + 1; // +5
+    ret += (((0x1fi16 - byte) & (byte - 0x21)) >> 8) & (byte + 5);
+    // if (byte > 0x23 && byte < 0x26) ret += byte - 0x24 + 37 + 1; // +2
+    ret += (((0x23i16 - byte) & (byte - 0x26)) >> 8) & (byte + 2);
+    // if (byte > 0x29 && byte < 0x2c) ret += byte - 0x2a + 39 + 1; // -2
+    ret += (((0x29i16 - byte) & (byte - 0x2c)) >> 8) & (byte - 2);
+    // if (byte > 0x2c && byte < 0x30) ret += byte - 0x2d + 41 + 1; // -3
+    ret += (((0x2ci16 - byte) & (byte - 0x30)) >> 8) & (byte - 3);
+    // if (byte == 0x3a) ret += 44 + 1; // -13
+    ret += (((0x39i16 - byte) & (byte - 0x3b)) >> 8) & (byte - 13);
+
+    ret as u16 as u32
+}
+
+/// Encode a single Base45 character.
+#[inline(always)]
+fn encode_char(src: u32) -> u8 {
+    let src = src as i16;
+    let mut ret = src + 0x30;
+
+    // 0-9 -> 0x30-0x39, A-Z -> 0x41-0x5a
+    ret += ((9i16 - src) >> 8) & 7;
+    // ' ' -> 0x20
+    ret += ((35i16 - src) >> 8) & -59;
+    // $% -> 0x24-0x25
+    ret += ((36i16 - src) >> 8) & 3;
+    // *+ -> 0x2a-0x2b
+    ret += ((38i16 - src) >> 8) & 4;
+    // -./ -> 0x2d-0x2f
+    ret += ((40i16 - src) >> 8) & 1;
+    // : -> 0x3a
+    ret += ((43i16 - src) >> 8) & 10;
+
+    ret as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_char, div45, encode_char};
+
+    const ALPHABET: &[u8; 45] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ $%*+-./:";
+
+    #[test]
+    fn alphabet() {
+        for (value, &c) in ALPHABET.iter().enumerate() {
+            assert_eq!(encode_char(value as u32), c);
+        }
+
+        for byte in 0..=u8::MAX {
+            match ALPHABET.iter().position(|&c| c == byte) {
+                Some(value) => assert_eq!(decode_char(byte), value as u32),
+                None => assert!(decode_char(byte) > 0xff, "{:#04x}", byte),
+            }
+        }
+    }
+
+    #[test]
+    fn div45_matches_division() {
+        for n in 0..=u32::from(u16::MAX) {
+            assert_eq!(div45(n), n / 45);
+        }
+    }
+}
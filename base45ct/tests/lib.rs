@@ -0,0 +1,114 @@
+//! Integration tests.
+
+/// Base45 test vectors
+struct TestVector {
+    /// Raw bytes
+    raw: &'static [u8],
+    /// Base45 encoded
+    base45: &'static str,
+}
+
+/// Test vectors, including those from RFC 9285 § 4.
+const TEST_VECTORS: &[TestVector] = &[
+    TestVector {
+        raw: b"",
+        base45: "",
+    },
+    TestVector {
+        raw: b"\0",
+        base45: "00",
+    },
+    TestVector {
+        raw: b"\xFF",
+        base45: "U5",
+    },
+    TestVector {
+        raw: b"\xFF\xFF",
+        base45: "FGW",
+    },
+    TestVector {
+        raw: b"AB",
+        base45: "BB8",
+    },
+    TestVector {
+        raw: b"Hello!!",
+        base45: "%69 VD92EX0",
+    },
+    TestVector {
+        raw: b"base-45",
+        base45: "UJCLQE7W581",
+    },
+    TestVector {
+        raw: b"ietf!",
+        base45: "QED8WEX0",
+    },
+];
+
+#[test]
+fn encode() {
+    for vector in TEST_VECTORS {
+        // 11 is the size of the largest encoded test vector
+        let mut buf = [0u8; 11];
+        let out = base45ct::encode(vector.raw, &mut buf).unwrap();
+        assert_eq!(vector.base45, out);
+    }
+}
+
+#[test]
+fn decode() {
+    for vector in TEST_VECTORS {
+        // 7 is the size of the largest decoded test vector
+        let mut buf = [0u8; 7];
+        let out = base45ct::decode(vector.base45, &mut buf).unwrap();
+        assert_eq!(vector.raw, out);
+    }
+}
+
+#[test]
+fn reject_invalid_length() {
+    let mut buf = [0u8; 8];
+
+    for input in ["0", "0000"] {
+        assert_eq!(
+            base45ct::decode(input, &mut buf),
+            Err(base45ct::Error::InvalidLength)
+        );
+    }
+
+    assert_eq!(
+        base45ct::decode("QED8WEX0", &mut buf[..4]),
+        Err(base45ct::Error::InvalidLength)
+    );
+    assert_eq!(
+        base45ct::encode(b"ietf!", &mut buf[..7]),
+        Err(base45ct::Error::InvalidLength)
+    );
+}
+
+#[test]
+fn reject_invalid_encoding() {
+    let mut buf = [0u8; 4];
+
+    // Lower case, out of alphabet characters, and values which overflow
+    for input in ["qed", "QE~", "GGW", "V5", ":::", "QED8W="] {
+        assert_eq!(
+            base45ct::decode(input, &mut buf),
+            Err(base45ct::Error::InvalidEncoding),
+            "{}",
+            input
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn encode_and_decode_various_lengths() {
+    let data = [0xA5u8; 64];
+
+    for i in 0..data.len() {
+        let encoded = base45ct::encode_string(&data[..i]);
+        assert_eq!(encoded.len(), base45ct::encoded_len(&data[..i]));
+        let decoded = base45ct::decode_vec(encoded).unwrap();
+        assert_eq!(decoded.as_slice(), &data[..i]);
+    }
+}
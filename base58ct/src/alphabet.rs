@@ -0,0 +1,85 @@
+//! Bitcoin Base58 alphabet.
+//!
+//! ```text
+//! [1-9]      [A-H]      [J-N]      [P-Z]      [a-k]      [m-z]
+//! 0x31-0x39, 0x41-0x48, 0x4a-0x4e, 0x50-0x5a, 0x61-0x6b, 0x6d-0x7a
+//! ```
+
+use core::ops::RangeInclusive;
+
+/// Decoder passes: character range and the offset which maps it to the
+/// digit value plus one.
+const DECODER: &[(RangeInclusive<u8>, i16)] = &[
+    (b'1'..=b'9', -48),
+    (b'A'..=b'H', -55),
+    (b'J'..=b'N', -56),
+    (b'P'..=b'Z', -57),
+    (b'a'..=b'k', -63),
+    (b'm'..=b'z', -64),
+];
+
+/// Encoder passes: digit threshold and the offset which is added to digits
+/// above it.
+const ENCODER: &[(u8, i16)] = &[(8, 7), (16, 1), (21, 1), (32, 6), (43, 1)];
+
+/// First character in the alphabet.
+const BASE: u8 = b'1';
+
+/// Decode a single character into a digit in the range 0-57 using bitwise
+/// operators instead of table lookups, returning -1 if it is not in the
+/// alphabet.
+#[inline(always)]
+pub(crate) fn decode_digit(byte: u8) -> i16 {
+    let src = byte as i16;
+    let mut ret: i16 = -1;
+
+    for (range, offset) in DECODER {
+        // Compute exclusive range from inclusive one
+        let start = *range.start() as i16 - 1;
+        let end = *range.end() as i16 + 1;
+        ret += (((start - src) & (src - end)) >> 8) & (src + *offset);
+    }
+
+    ret
+}
+
+/// Encode a digit in the range 0-57 as a character using bitwise operators
+/// instead of table lookups.
+#[inline(always)]
+pub(crate) fn encode_digit(digit: u8) -> u8 {
+    let src = digit as i16;
+    let mut ret = src + BASE as i16;
+
+    for &(threshold, offset) in ENCODER {
+        ret += ((threshold as i16 - src) >> 8) & offset;
+    }
+
+    ret as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_digit, encode_digit};
+
+    const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+    #[test]
+    fn encode() {
+        for (digit, &c) in ALPHABET.iter().enumerate() {
+            assert_eq!(encode_digit(digit as u8), c);
+        }
+    }
+
+    #[test]
+    fn decode() {
+        for byte in 0..=u8::MAX {
+            let expected = ALPHABET
+                .iter()
+                .position(|&c| c == byte)
+                .map(|digit| digit as i16)
+                .unwrap_or(-1);
+
+            assert_eq!(decode_digit(byte), expected, "{:#04x}", byte);
+        }
+    }
+}
@@ -0,0 +1,33 @@
+//! Base58 without a checksum.
+
+use crate::{
+    encoding::{self, decode_inner, encode_inner},
+    Encoding, Error,
+};
+
+/// Base58 encoding using the Bitcoin alphabet, without a checksum.
+///
+/// ```text
+/// [1-9]      [A-H]      [J-N]      [P-Z]      [a-k]      [m-z]
+/// 0x31-0x39, 0x41-0x48, 0x4a-0x4e, 0x50-0x5a, 0x61-0x6b, 0x6d-0x7a
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Base58;
+
+impl Encoding for Base58 {
+    fn decode(src: impl AsRef<[u8]>, dst: &mut [u8]) -> Result<&[u8], Error> {
+        decode_inner(src.as_ref(), dst)
+    }
+
+    fn encode<'a>(src: &[u8], dst: &'a mut [u8]) -> Result<&'a str, Error> {
+        encode_inner(src.iter().copied(), src.len(), dst)
+    }
+
+    fn max_decoded_len(input_len: usize) -> usize {
+        input_len
+    }
+
+    fn max_encoded_len(input_len: usize) -> usize {
+        encoding::max_encoded_len(input_len)
+    }
+}
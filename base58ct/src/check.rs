@@ -0,0 +1,74 @@
+//! Base58Check: Base58 with a double SHA-256 checksum.
+
+use crate::{
+    encoding::{self, decode_inner, encode_inner},
+    Encoding, Error,
+};
+use sha2::{Digest, Sha256};
+
+/// Length of the Base58Check checksum.
+const CHECKSUM_LEN: usize = 4;
+
+/// Base58Check encoding using the Bitcoin alphabet.
+///
+/// Appends the first 4 bytes of the double SHA-256 digest of the data to the
+/// data prior to encoding, and verifies them upon decoding.
+///
+/// Any version prefix is considered part of the data.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Base58Check;
+
+impl Encoding for Base58Check {
+    /// Decode a Base58Check string into the provided destination buffer,
+    /// returning the data without the checksum.
+    ///
+    /// The buffer is also used to hold the checksum, and therefore must be
+    /// 4 bytes larger than the decoded data.
+    fn decode(src: impl AsRef<[u8]>, dst: &mut [u8]) -> Result<&[u8], Error> {
+        let decoded = decode_inner(src.as_ref(), dst)?;
+        let data_len = decoded
+            .len()
+            .checked_sub(CHECKSUM_LEN)
+            .ok_or(Error::InvalidEncoding)?;
+
+        let (data, expected) = decoded.split_at(data_len);
+        let actual = checksum(data);
+
+        let mut diff = 0u8;
+        for (a, b) in actual.iter().zip(expected) {
+            diff |= a ^ b;
+        }
+
+        if diff == 0 {
+            Ok(data)
+        } else {
+            Err(Error::InvalidChecksum)
+        }
+    }
+
+    fn encode<'a>(src: &[u8], dst: &'a mut [u8]) -> Result<&'a str, Error> {
+        let len = src
+            .len()
+            .checked_add(CHECKSUM_LEN)
+            .ok_or(Error::InvalidLength)?;
+
+        let bytes = src.iter().copied().chain(checksum(src));
+        encode_inner(bytes, len, dst)
+    }
+
+    fn max_decoded_len(input_len: usize) -> usize {
+        input_len
+    }
+
+    fn max_encoded_len(input_len: usize) -> usize {
+        encoding::max_encoded_len(input_len.saturating_add(CHECKSUM_LEN))
+    }
+}
+
+/// Compute the Base58Check checksum of the given data.
+fn checksum(data: &[u8]) -> [u8; CHECKSUM_LEN] {
+    let digest = Sha256::digest(Sha256::digest(data));
+    let mut checksum = [0u8; CHECKSUM_LEN];
+    checksum.copy_from_slice(&digest[..CHECKSUM_LEN]);
+    checksum
+}
@@ -0,0 +1,261 @@
+//! Base58 encoding trait and the underlying base conversion.
+
+use crate::{
+    alphabet::{decode_digit, encode_digit},
+    Error,
+};
+use core::{cmp, fmt::Debug, str};
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+/// Core encoder/decoder functions for a particular Base58 variant.
+pub trait Encoding: 'static + Copy + Debug + Eq + Send + Sized + Sync {
+    /// Decode a Base58 string into the provided destination buffer.
+    ///
+    /// The buffer must be large enough to hold the decoded data. A buffer
+    /// of [`Encoding::max_decoded_len`] bytes is always sufficient.
+    fn decode(src: impl AsRef<[u8]>, dst: &mut [u8]) -> Result<&[u8], Error>;
+
+    /// Decode a Base58 string into a byte vector.
+    #[cfg(feature = "alloc")]
+    fn decode_vec(input: &str) -> Result<Vec<u8>, Error> {
+        let mut output = vec![0u8; Self::max_decoded_len(input.len())];
+        let len = Self::decode(input, &mut output)?.len();
+        output.truncate(len);
+        Ok(output)
+    }
+
+    /// Encode the input byte slice as Base58.
+    ///
+    /// Writes the result into the provided destination slice, returning an
+    /// ASCII-encoded Base58 string value.
+    ///
+    /// The destination slice must be at least [`Encoding::max_encoded_len`]
+    /// bytes long, as it is used as scratch space for the conversion.
+    fn encode<'a>(src: &[u8], dst: &'a mut [u8]) -> Result<&'a str, Error>;
+
+    /// Encode input byte slice into a [`String`] containing Base58.
+    #[cfg(feature = "alloc")]
+    fn encode_string(input: &[u8]) -> String {
+        let mut output = vec![0u8; Self::max_encoded_len(input.len())];
+        let len = Self::encode(input, &mut output)
+            .expect("buffer is large enough")
+            .len();
+        output.truncate(len);
+        String::from_utf8(output).expect("encoded data is ASCII")
+    }
+
+    /// Get the maximum length of the data decoded from a Base58 string of
+    /// the given length.
+    fn max_decoded_len(input_len: usize) -> usize;
+
+    /// Get the maximum length of the Base58 string produced by encoding the
+    /// given number of bytes.
+    ///
+    /// The actual length depends on the data being encoded.
+    fn max_encoded_len(input_len: usize) -> usize;
+}
+
+/// Get the maximum number of Base58 digits needed to encode `input_len`
+/// bytes, i.e. `ceil(input_len * log(256) / log(58))` rounded up.
+pub(crate) fn max_encoded_len(input_len: usize) -> usize {
+    // log(256) / log(58) ~= 1.3657 < 1.38
+    (input_len / 100)
+        .saturating_mul(138)
+        .saturating_add((input_len % 100) * 138 / 100 + 1)
+}
+
+/// Encode the big-endian number formed by `src` (of length `src_len`) as
+/// Base58, using `dst` as scratch space for the digits.
+///
+/// Every input byte is multiplied into every potential output digit, so the
+/// work performed depends only on `src_len`.
+pub(crate) fn encode_inner(
+    src: impl Iterator<Item = u8>,
+    src_len: usize,
+    dst: &mut [u8],
+) -> Result<&str, Error> {
+    let digits = dst
+        .get_mut(..max_encoded_len(src_len))
+        .ok_or(Error::InvalidLength)?;
+
+    digits.fill(0);
+
+    let mut leading_zeros = 0usize;
+    let mut is_leading = 1usize;
+
+    for byte in src {
+        is_leading &= is_zero(byte);
+        leading_zeros += is_leading;
+
+        let mut carry = u32::from(byte);
+
+        for digit in digits.iter_mut().rev() {
+            carry += u32::from(*digit) << 8;
+            let quotient = div58(carry);
+            *digit = (carry - quotient * 58) as u8;
+            carry = quotient;
+        }
+
+        debug_assert_eq!(carry, 0);
+    }
+
+    // Each leading zero byte is encoded as a leading zero digit, and all
+    // other leading zero digits are dropped
+    let shift = count_leading_zeros(digits)
+        .checked_sub(leading_zeros)
+        .ok_or(Error::InvalidLength)?;
+
+    shift_left(digits, shift);
+    let len = digits.len() - shift;
+    let encoded = &mut digits[..len];
+
+    for digit in encoded.iter_mut() {
+        *digit = encode_digit(*digit);
+    }
+
+    Ok(str::from_utf8(encoded)?)
+}
+
+/// Decode a Base58 string into a big-endian number in `dst`.
+///
+/// Every input character is multiplied into every potential output byte, so
+/// the work performed depends only on the input length and length of `dst`.
+pub(crate) fn decode_inner<'a>(src: &[u8], dst: &'a mut [u8]) -> Result<&'a [u8], Error> {
+    let len = cmp::min(src.len(), dst.len());
+    let bytes = &mut dst[..len];
+    bytes.fill(0);
+
+    let mut err = 0u32;
+    let mut overflow = 0u32;
+    let mut leading_zeros = 0usize;
+    let mut is_leading = 1usize;
+
+    for &c in src {
+        let digit = decode_digit(c);
+        err |= ((digit >> 8) & 1) as u32;
+        is_leading &= is_zero(digit as u8);
+        leading_zeros += is_leading;
+
+        let mut carry = (digit & 0x3f) as u32;
+
+        for byte in bytes.iter_mut().rev() {
+            carry += u32::from(*byte) * 58;
+            *byte = (carry & 0xff) as u8;
+            carry >>= 8;
+        }
+
+        overflow |= carry;
+    }
+
+    if err != 0 {
+        return Err(Error::InvalidEncoding);
+    }
+
+    if overflow != 0 {
+        return Err(Error::InvalidLength);
+    }
+
+    // Each leading `1` is decoded as a leading zero byte, and all other
+    // leading zero bytes are dropped
+    let shift = count_leading_zeros(bytes)
+        .checked_sub(leading_zeros)
+        .ok_or(Error::InvalidLength)?;
+
+    shift_left(bytes, shift);
+    Ok(&bytes[..len - shift])
+}
+
+/// Compute `n / 58` for `n < 2^16` without a division.
+#[inline(always)]
+fn div58(n: u32) -> u32 {
+    // ceil(2^20 / 58)
+    (n * 18079) >> 20
+}
+
+/// Returns 1 if `byte` is zero, 0 otherwise.
+#[inline(always)]
+fn is_zero(byte: u8) -> usize {
+    (u32::from(byte).wrapping_sub(1) >> 31) as usize
+}
+
+/// Count the number of leading zero bytes in constant time.
+fn count_leading_zeros(bytes: &[u8]) -> usize {
+    let mut count = 0;
+    let mut is_leading = 1;
+
+    for &byte in bytes {
+        is_leading &= is_zero(byte);
+        count += is_leading;
+    }
+
+    count
+}
+
+/// Shift the contents of `buf` to the left by `shift` bytes in constant time,
+/// with one conditional shift pass per bit of `buf.len()`.
+fn shift_left(buf: &mut [u8], shift: usize) {
+    let mut bit = 0;
+
+    while bit < usize::BITS && (1 << bit) <= buf.len() {
+        let amount = 1usize << bit;
+        let mask = 0u8.wrapping_sub(((shift >> bit) & 1) as u8);
+
+        for i in 0..buf.len() {
+            let shifted = buf.get(i + amount).copied().unwrap_or(0);
+            buf[i] = (shifted & mask) | (buf[i] & !mask);
+        }
+
+        bit += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_inner, div58, encode_inner, max_encoded_len, shift_left};
+    use crate::Error;
+
+    #[test]
+    fn div58_matches_division() {
+        // Upper bound on the carry during encoding: 57 * 256 + 255
+        for n in 0..(58 * 256) {
+            assert_eq!(div58(n), n / 58);
+        }
+    }
+
+    #[test]
+    fn shift() {
+        for shift in 0..=9 {
+            let mut buf = *b"123456789";
+            shift_left(&mut buf, shift);
+            assert_eq!(&buf[..9 - shift], &b"123456789"[shift..]);
+        }
+    }
+
+    #[test]
+    fn max_encoded_len_is_sufficient() {
+        let mut buf = [0u8; 128];
+
+        for zeros in 0..16 {
+            for len in zeros..80 {
+                let mut input = [0xffu8; 80];
+                input[..zeros].fill(0);
+                let input = &input[..len];
+
+                let encoded = encode_inner(input.iter().copied(), len, &mut buf)
+                    .unwrap()
+                    .len();
+                assert!(encoded <= max_encoded_len(len));
+            }
+        }
+    }
+
+    #[test]
+    fn decode_buffer_too_small() {
+        let mut buf = [0u8; 1];
+        assert_eq!(decode_inner(b"LUv", &mut buf), Err(Error::InvalidLength));
+        assert_eq!(decode_inner(b"11", &mut buf), Err(Error::InvalidLength));
+        assert_eq!(decode_inner(b"5Q", &mut buf), Ok(&[0xff][..]));
+    }
+}
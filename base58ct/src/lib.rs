@@ -0,0 +1,85 @@
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_auto_cfg))]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/media/6ee8e381/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/media/6ee8e381/logo.svg"
+)]
+#![doc = include_str!("../README.md")]
+#![warn(
+    clippy::mod_module_files,
+    clippy::panic,
+    clippy::panic_in_result_fn,
+    clippy::unwrap_used,
+    missing_docs,
+    rust_2018_idioms,
+    unsafe_code,
+    unused_lifetimes,
+    unused_qualifications
+)]
+
+//! # Usage
+//!
+//! ## Allocating (enable `alloc` crate feature)
+//!
+//! ```
+//! # #[cfg(feature = "alloc")]
+//! # {
+//! use base58ct::{Base58, Encoding};
+//!
+//! let bytes = b"example bytestring!";
+//! let encoded = Base58::encode_string(bytes);
+//! assert_eq!(encoded, "KaRXeUPHPJdfiW35ib46HfPTyz");
+//!
+//! let decoded = Base58::decode_vec(&encoded).unwrap();
+//! assert_eq!(decoded, bytes);
+//! # }
+//! ```
+//!
+//! ## Heapless `no_std` usage
+//!
+//! ```
+//! use base58ct::{Base58, Encoding};
+//!
+//! const BUF_SIZE: usize = 128;
+//!
+//! let bytes = b"example bytestring!";
+//! assert!(Base58::max_encoded_len(bytes.len()) <= BUF_SIZE);
+//!
+//! let mut enc_buf = [0u8; BUF_SIZE];
+//! let encoded = Base58::encode(bytes, &mut enc_buf).unwrap();
+//! assert_eq!(encoded, "KaRXeUPHPJdfiW35ib46HfPTyz");
+//!
+//! let mut dec_buf = [0u8; BUF_SIZE];
+//! let decoded = Base58::decode(encoded, &mut dec_buf).unwrap();
+//! assert_eq!(decoded, bytes);
+//! ```
+//!
+//! # Implementation
+//!
+//! Implemented using integer arithmetic alone without any lookup tables or
+//! data-dependent branches, thereby providing portable "best effort"
+//! constant-time operation.
+//!
+//! Not constant-time with respect to message length (only data). Note that
+//! the length of a Base58 string depends on the number of leading zero bytes
+//! of the encoded data as well as its magnitude, which is revealed by the
+//! length of the output.
+
+#[cfg(feature = "alloc")]
+#[macro_use]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+mod alphabet;
+mod base58;
+mod encoding;
+mod errors;
+
+#[cfg(feature = "check")]
+mod check;
+
+pub use crate::{base58::Base58, encoding::Encoding, errors::Error};
+
+#[cfg(feature = "check")]
+pub use crate::check::Base58Check;
@@ -0,0 +1,153 @@
+//! Base58 tests using vectors from Bitcoin Core.
+
+use base58ct::{Base58, Encoding, Error};
+use hex_literal::hex;
+
+/// Base58 test vector.
+struct TestVector {
+    raw: &'static [u8],
+    b58: &'static str,
+}
+
+/// From Bitcoin Core's `base58_encode_decode.json`.
+const TEST_VECTORS: &[TestVector] = &[
+    TestVector { raw: b"", b58: "" },
+    TestVector {
+        raw: &hex!("61"),
+        b58: "2g",
+    },
+    TestVector {
+        raw: &hex!("626262"),
+        b58: "a3gV",
+    },
+    TestVector {
+        raw: &hex!("636363"),
+        b58: "aPEr",
+    },
+    TestVector {
+        raw: &hex!("73696d706c792061206c6f6e6720737472696e67"),
+        b58: "2cFupjhnEsSn59qHXstmK2ffpLv2",
+    },
+    TestVector {
+        raw: &hex!("00eb15231dfceb60925886b67d065299925915aeb172c06647"),
+        b58: "1NS17iag9jJgTHD1VXjvLCEnZuQ3rJDE9L",
+    },
+    TestVector {
+        raw: &hex!("516b6fcd0f"),
+        b58: "ABnLTmg",
+    },
+    TestVector {
+        raw: &hex!("bf4f89001e670274dd"),
+        b58: "3SEo3LWLoPntC",
+    },
+    TestVector {
+        raw: &hex!("572e4794"),
+        b58: "3EFU7m",
+    },
+    TestVector {
+        raw: &hex!("ecac89cad93923c02321"),
+        b58: "EJDM8drfXA6uyA",
+    },
+    TestVector {
+        raw: &hex!("10c8511e"),
+        b58: "Rt5zm",
+    },
+    TestVector {
+        raw: &hex!("00000000000000000000"),
+        b58: "1111111111",
+    },
+    TestVector {
+        raw: &hex!(
+            "000111d38e5fc9071ffcd20b4a763cc9ae4f252bb4e48fd66a835e252ada93ff"
+            "480d6dd43dc62a641155a5"
+        ),
+        b58: "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz",
+    },
+];
+
+#[test]
+fn encode_test_vectors() {
+    let mut buf = [0u8; 128];
+
+    for vector in TEST_VECTORS {
+        let out = Base58::encode(vector.raw, &mut buf).unwrap();
+        assert_eq!(out, vector.b58);
+        assert!(out.len() <= Base58::max_encoded_len(vector.raw.len()));
+    }
+}
+
+#[test]
+fn decode_test_vectors() {
+    let mut buf = [0u8; 128];
+
+    for vector in TEST_VECTORS {
+        let out = Base58::decode(vector.b58, &mut buf).unwrap();
+        assert_eq!(out, vector.raw);
+        assert!(out.len() <= Base58::max_decoded_len(vector.b58.len()));
+
+        // Exactly sized output buffer
+        let mut exact = [0u8; 128];
+        let out = Base58::decode(vector.b58, &mut exact[..vector.raw.len()]).unwrap();
+        assert_eq!(out, vector.raw);
+    }
+}
+
+#[test]
+fn encode_buffer_too_small() {
+    let vector = &TEST_VECTORS[4];
+    let mut buf = [0u8; 128];
+    let len = Base58::max_encoded_len(vector.raw.len());
+
+    assert_eq!(
+        Base58::encode(vector.raw, &mut buf[..(len - 1)]),
+        Err(Error::InvalidLength)
+    );
+}
+
+#[test]
+fn decode_buffer_too_small() {
+    let mut buf = [0u8; 128];
+
+    for vector in TEST_VECTORS.iter().filter(|v| !v.raw.is_empty()) {
+        assert_eq!(
+            Base58::decode(vector.b58, &mut buf[..(vector.raw.len() - 1)]),
+            Err(Error::InvalidLength)
+        );
+    }
+}
+
+#[test]
+fn decode_invalid_characters() {
+    let mut buf = [0u8; 128];
+
+    for invalid in [
+        "0",
+        "O",
+        "I",
+        "l",
+        "3SEo3LWL0PntC",
+        "Rt5z=",
+        " Rt5zm",
+        "Rt5zm\n",
+    ] {
+        assert_eq!(
+            Base58::decode(invalid, &mut buf),
+            Err(Error::InvalidEncoding)
+        );
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn round_trip_vec() {
+    for len in 0..64 {
+        for zeros in 0..=len {
+            let mut data = [0xa5u8; 64];
+            data[..zeros].fill(0);
+            let data = &data[..len];
+
+            let encoded = Base58::encode_string(data);
+            assert_eq!(Base58::decode_vec(&encoded).unwrap(), data);
+        }
+    }
+}
@@ -0,0 +1,91 @@
+//! Base58Check tests.
+
+#![cfg(feature = "check")]
+
+use base58ct::{Base58Check, Encoding, Error};
+use hex_literal::hex;
+
+/// Base58Check test vector.
+struct TestVector {
+    raw: &'static [u8],
+    b58: &'static str,
+}
+
+const TEST_VECTORS: &[TestVector] = &[
+    TestVector {
+        raw: b"",
+        b58: "3QJmnh",
+    },
+    TestVector {
+        raw: &hex!("00"),
+        b58: "1Wh4bh",
+    },
+    // P2PKH address
+    TestVector {
+        raw: &hex!("00f54a5851e9372b87810a8e60cdd2e7cfd80b6e31"),
+        b58: "1PMycacnJaSqwwJqjawXBErnLsZ7RkXUAs",
+    },
+    // WIF private key
+    TestVector {
+        raw: &hex!("800c28fca386c7a227600b2fe50b7cae11ec86d3bf1fbe471be89827e19d72aa1d"),
+        b58: "5HueCGU8rMjxEXxiPuD5BDku4MkFqeZyd4dZ1jvhTVqvbTLvyTJ",
+    },
+];
+
+#[test]
+fn encode_test_vectors() {
+    let mut buf = [0u8; 128];
+
+    for vector in TEST_VECTORS {
+        let out = Base58Check::encode(vector.raw, &mut buf).unwrap();
+        assert_eq!(out, vector.b58);
+        assert!(out.len() <= Base58Check::max_encoded_len(vector.raw.len()));
+    }
+}
+
+#[test]
+fn decode_test_vectors() {
+    let mut buf = [0u8; 128];
+
+    for vector in TEST_VECTORS {
+        let out = Base58Check::decode(vector.b58, &mut buf).unwrap();
+        assert_eq!(out, vector.raw);
+    }
+}
+
+#[test]
+fn decode_invalid_checksum() {
+    let mut buf = [0u8; 128];
+
+    assert_eq!(
+        Base58Check::decode("1PMycacnJaSqwwJqjawXBErnLsZ7RkXUAt", &mut buf),
+        Err(Error::InvalidChecksum)
+    );
+    assert_eq!(
+        Base58Check::decode("1PMycacnJaSqwwJqjawXBErnLsZ7RkXUA", &mut buf),
+        Err(Error::InvalidChecksum)
+    );
+}
+
+#[test]
+fn decode_too_short() {
+    let mut buf = [0u8; 128];
+    assert_eq!(
+        Base58Check::decode("", &mut buf),
+        Err(Error::InvalidEncoding)
+    );
+    assert_eq!(
+        Base58Check::decode("2g", &mut buf),
+        Err(Error::InvalidEncoding)
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn round_trip_vec() {
+    for len in 0..40 {
+        let data = [0x5au8; 40];
+        let encoded = Base58Check::encode_string(&data[..len]);
+        assert_eq!(Base58Check::decode_vec(&encoded).unwrap(), &data[..len]);
+    }
+}
@@ -0,0 +1,250 @@
+//! [`std::io`] adapters which transparently encode and decode Base64.
+
+use crate::{Base64, Encoding, StreamDecoder, StreamEncoder};
+use core::{cmp, mem};
+use std::io::{self, Read, Write};
+
+/// Size of the Base64-encoded buffers used by the adapters.
+const ENCODED_BUFFER_SIZE: usize = 1024;
+
+/// Size of the decoded buffers used by the adapters.
+const DECODED_BUFFER_SIZE: usize = ENCODED_BUFFER_SIZE / 4 * 3;
+
+/// [`Read`] adapter which decodes Base64 read from an inner reader.
+///
+/// The inner reader must yield Base64 without whitespace or line breaks.
+///
+/// ```
+/// use base64ct::Base64Reader;
+/// use std::io::Read;
+///
+/// let mut reader = Base64Reader::<_>::new(&b"SGVsbG8sIHdvcmxkIQ=="[..]);
+/// let mut decoded = Vec::new();
+/// reader.read_to_end(&mut decoded)?;
+/// assert_eq!(decoded, b"Hello, world!");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct Base64Reader<R: Read, E: Encoding = Base64> {
+    /// Inner reader.
+    inner: R,
+
+    /// Streaming decoder.
+    decoder: StreamDecoder<E>,
+
+    /// Buffer of Base64 data read from the inner reader.
+    encoded: [u8; ENCODED_BUFFER_SIZE],
+
+    /// Buffer of decoded data which hasn't been read yet.
+    decoded: [u8; DECODED_BUFFER_SIZE],
+
+    /// Position of the unread data in `decoded`.
+    pos: usize,
+
+    /// Length of the data in `decoded`.
+    len: usize,
+
+    /// Has the inner reader reached EOF?
+    eof: bool,
+}
+
+impl<R: Read, E: Encoding> Base64Reader<R, E> {
+    /// Create a new reader which decodes Base64 read from `inner`.
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            decoder: StreamDecoder::new(),
+            encoded: [0u8; ENCODED_BUFFER_SIZE],
+            decoded: [0u8; DECODED_BUFFER_SIZE],
+            pos: 0,
+            len: 0,
+            eof: false,
+        }
+    }
+
+    /// Borrow the inner reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Unwrap the inner reader.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Refill the decoded buffer, returning `false` once all input has been
+    /// decoded.
+    fn fill_buf(&mut self) -> io::Result<bool> {
+        while self.pos == self.len {
+            if self.eof {
+                return Ok(false);
+            }
+
+            let n = self.inner.read(&mut self.encoded)?;
+
+            let decoded = if n == 0 {
+                self.eof = true;
+                mem::take(&mut self.decoder).finish(&mut self.decoded)?
+            } else {
+                self.decoder.decode(&self.encoded[..n], &mut self.decoded)?
+            };
+
+            self.pos = 0;
+            self.len = decoded.len();
+        }
+
+        Ok(true)
+    }
+}
+
+impl<R: Read, E: Encoding> Read for Base64Reader<R, E> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() || !self.fill_buf()? {
+            return Ok(0);
+        }
+
+        let available = &self.decoded[self.pos..self.len];
+        let len = cmp::min(available.len(), buf.len());
+        buf[..len].copy_from_slice(&available[..len]);
+        self.pos = self.pos.saturating_add(len);
+        Ok(len)
+    }
+}
+
+/// [`Write`] adapter which Base64-encodes data written to it into an inner
+/// writer.
+///
+/// The output is not line wrapped. [`Base64Writer::finish`] must be called
+/// to write the final (potentially padded) block.
+///
+/// ```
+/// use base64ct::Base64Writer;
+/// use std::io::Write;
+///
+/// let mut writer = Base64Writer::<_>::new(Vec::new());
+/// writer.write_all(b"Hello, world!")?;
+/// assert_eq!(writer.finish()?, b"SGVsbG8sIHdvcmxkIQ==");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct Base64Writer<W: Write, E: Encoding = Base64> {
+    /// Inner writer.
+    inner: W,
+
+    /// Streaming encoder.
+    encoder: StreamEncoder<E>,
+
+    /// Buffer of encoded output.
+    encoded: [u8; ENCODED_BUFFER_SIZE],
+}
+
+impl<W: Write, E: Encoding> Base64Writer<W, E> {
+    /// Create a new writer which writes Base64 to `inner`.
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner,
+            encoder: StreamEncoder::new(),
+            encoded: [0u8; ENCODED_BUFFER_SIZE],
+        }
+    }
+
+    /// Borrow the inner writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Finish encoding, writing any buffered input (including padding, if
+    /// the encoding is padded) to the inner writer.
+    ///
+    /// On success, returns the inner writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        let encoded = self.encoder.finish(&mut self.encoded)?;
+        self.inner.write_all(encoded.as_bytes())?;
+        self.inner.flush()?;
+        Ok(self.inner)
+    }
+}
+
+impl<W: Write, E: Encoding> Write for Base64Writer<W, E> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let len = cmp::min(buf.len(), DECODED_BUFFER_SIZE);
+        let encoded = self.encoder.encode(&buf[..len], &mut self.encoded)?;
+        self.inner.write_all(encoded.as_bytes())?;
+        Ok(len)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Base64Reader, Base64Writer};
+    use crate::{
+        test_vectors::{MULTILINE_PADDED_BIN, UNPADDED_BASE64, UNPADDED_BIN},
+        Base64, Base64Unpadded, Encoding,
+    };
+    use std::{
+        io::{Read, Write},
+        vec::Vec,
+    };
+
+    /// Reader which yields at most `chunk_size` bytes per read.
+    struct ChunkedReader<'a> {
+        data: &'a [u8],
+        chunk_size: usize,
+    }
+
+    impl Read for ChunkedReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let len = self.data.len().min(buf.len()).min(self.chunk_size);
+            buf[..len].copy_from_slice(&self.data[..len]);
+            self.data = &self.data[len..];
+            Ok(len)
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let data = MULTILINE_PADDED_BIN.repeat(16);
+        let encoded = Base64::encode_string(&data);
+
+        for chunk_size in [1, 3, 7, 1000, 4096] {
+            let mut writer = Base64Writer::<_>::new(Vec::new());
+
+            for chunk in data.chunks(chunk_size) {
+                writer.write_all(chunk).unwrap();
+            }
+
+            assert_eq!(writer.finish().unwrap(), encoded.as_bytes());
+
+            let mut reader = Base64Reader::<_>::new(ChunkedReader {
+                data: encoded.as_bytes(),
+                chunk_size,
+            });
+
+            let mut decoded = Vec::new();
+            reader.read_to_end(&mut decoded).unwrap();
+            assert_eq!(decoded, data);
+        }
+    }
+
+    #[test]
+    fn unpadded() {
+        let mut reader = Base64Reader::<_, Base64Unpadded>::new(UNPADDED_BASE64.as_bytes());
+        let mut decoded = Vec::new();
+        reader.read_to_end(&mut decoded).unwrap();
+        assert_eq!(decoded, UNPADDED_BIN);
+
+        let mut writer = Base64Writer::<_, Base64Unpadded>::new(Vec::new());
+        writer.write_all(UNPADDED_BIN).unwrap();
+        assert_eq!(writer.finish().unwrap(), UNPADDED_BASE64.as_bytes());
+    }
+
+    #[test]
+    fn invalid_encoding() {
+        let mut reader = Base64Reader::<_>::new(&b"Zm9v*mFy"[..]);
+        let mut decoded = Vec::new();
+        let err = reader.read_to_end(&mut decoded).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    }
+}
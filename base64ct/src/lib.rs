@@ -82,6 +82,14 @@ mod encoder;
 mod encoding;
 mod errors;
 mod line_ending;
+mod mime;
+mod stream;
+
+#[cfg(feature = "std")]
+mod io;
+
+#[cfg(feature = "simd")]
+pub mod simd;
 
 #[cfg(test)]
 mod test_vectors;
@@ -99,7 +107,12 @@ pub use crate::{
     encoding::Encoding,
     errors::{Error, InvalidEncodingError, InvalidLengthError},
     line_ending::LineEnding,
+    mime::Base64Mime,
+    stream::{StreamDecoder, StreamEncoder},
 };
 
+#[cfg(feature = "std")]
+pub use crate::io::{Base64Reader, Base64Writer};
+
 /// Minimum supported line width.
 const MIN_LINE_WIDTH: usize = 4;
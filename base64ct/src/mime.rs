@@ -0,0 +1,231 @@
+//! MIME Base64 encoding with line wrapping.
+
+use crate::{
+    line_ending::{CHAR_CR, CHAR_LF},
+    Base64, Encoding, Error, InvalidLengthError, LineEnding,
+};
+use core::str;
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+/// Maximum length of an encoded line as defined in [RFC 2045 § 6.8].
+///
+/// [RFC 2045 § 6.8]: https://datatracker.ietf.org/doc/html/rfc2045#section-6.8
+const LINE_WIDTH: usize = 76;
+
+/// Line ending used when encoding.
+const LINE_ENDING: LineEnding = LineEnding::CRLF;
+
+/// MIME Base64 encoding as described in [RFC 2045 § 6.8].
+///
+/// Uses the standard Base64 alphabet with `=` padding. Encoding inserts a
+/// CRLF line ending after every 76 characters (without a trailing line
+/// ending), and decoding ignores any whitespace (`' '`, `'\t'`, `'\r'` and
+/// `'\n'`) in the input.
+///
+/// Decoding only branches on whether each character is whitespace, and is
+/// otherwise performed by the constant-time [`Base64`] implementation.
+///
+/// Unlike the other encodings, this type doesn't implement [`Encoding`]:
+/// the trait is implemented generically for the (internal) Base64 alphabet
+/// types, so an encoding can't add line wrapping on top of an alphabet.
+/// The same operations are provided as inherent methods instead.
+///
+/// ```
+/// use base64ct::Base64Mime;
+///
+/// let mut buf = [0u8; 128];
+/// let encoded = Base64Mime::encode(&[0u8; 60], &mut buf)?;
+/// assert_eq!(encoded.lines().map(str::len).collect::<Vec<_>>(), [76, 4]);
+///
+/// let mut decoded = [0u8; 60];
+/// assert_eq!(Base64Mime::decode(encoded, &mut decoded)?, [0u8; 60]);
+/// # Ok::<(), base64ct::Error>(())
+/// ```
+///
+/// [RFC 2045 § 6.8]: https://datatracker.ietf.org/doc/html/rfc2045#section-6.8
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Base64Mime;
+
+impl Base64Mime {
+    /// Decode a MIME Base64 string into the provided destination buffer,
+    /// ignoring any whitespace.
+    pub fn decode(src: impl AsRef<[u8]>, dst: &mut [u8]) -> Result<&[u8], Error> {
+        let mut block = [0u8; 4];
+        let mut block_len = 0usize;
+        let mut pos = 0usize;
+        let mut padded = false;
+
+        for &byte in src.as_ref() {
+            if matches!(byte, b' ' | b'\t' | CHAR_CR | CHAR_LF) {
+                continue;
+            }
+
+            // No input is allowed after padding
+            if padded {
+                return Err(Error::InvalidEncoding);
+            }
+
+            block[block_len] = byte;
+            block_len = block_len.saturating_add(1);
+
+            if block_len == block.len() {
+                let mut decoded = [0u8; 3];
+                let decoded = Base64::decode(block, &mut decoded)?;
+                let end = pos.checked_add(decoded.len()).ok_or(Error::InvalidLength)?;
+
+                dst.get_mut(pos..end)
+                    .ok_or(Error::InvalidLength)?
+                    .copy_from_slice(decoded);
+
+                padded = decoded.len() < 3;
+                block_len = 0;
+                pos = end;
+            }
+        }
+
+        if block_len != 0 {
+            return Err(Error::InvalidEncoding);
+        }
+
+        Ok(&dst[..pos])
+    }
+
+    /// Decode a MIME Base64 string into a byte vector.
+    #[cfg(feature = "alloc")]
+    pub fn decode_vec(input: &str) -> Result<Vec<u8>, Error> {
+        let mut output = vec![0u8; crate::encoding::decoded_len(input.len())];
+        let len = Self::decode(input, &mut output)?.len();
+        output.truncate(len);
+        Ok(output)
+    }
+
+    /// Encode the input byte slice as MIME Base64.
+    ///
+    /// Writes the result into the provided destination slice, returning an
+    /// ASCII-encoded Base64 string value.
+    pub fn encode<'a>(src: &[u8], dst: &'a mut [u8]) -> Result<&'a str, InvalidLengthError> {
+        let dst = dst
+            .get_mut(..Self::encoded_len(src))
+            .ok_or(InvalidLengthError)?;
+
+        let mut pos = 0usize;
+
+        for (i, line) in src.chunks(LINE_WIDTH / 4 * 3).enumerate() {
+            if i > 0 {
+                let end = pos
+                    .checked_add(LINE_ENDING.len())
+                    .ok_or(InvalidLengthError)?;
+                dst.get_mut(pos..end)
+                    .ok_or(InvalidLengthError)?
+                    .copy_from_slice(LINE_ENDING.as_bytes());
+                pos = end;
+            }
+
+            let len = Base64::encode(line, dst.get_mut(pos..).ok_or(InvalidLengthError)?)?.len();
+            pos = pos.checked_add(len).ok_or(InvalidLengthError)?;
+        }
+
+        debug_assert_eq!(pos, dst.len());
+        str::from_utf8(dst).map_err(|_| InvalidLengthError)
+    }
+
+    /// Encode input byte slice into a [`String`] containing MIME Base64.
+    ///
+    /// # Panics
+    /// If `input` length is greater than `usize::MAX/4`.
+    #[cfg(feature = "alloc")]
+    pub fn encode_string(input: &[u8]) -> String {
+        let mut output = vec![0u8; Self::encoded_len(input)];
+        Self::encode(input, &mut output).expect("encoding error");
+        String::from_utf8(output).expect("invalid UTF-8")
+    }
+
+    /// Get the length of MIME Base64 produced by encoding the given bytes,
+    /// including line endings.
+    ///
+    /// WARNING: this function will return `0` for lengths greater than `usize::MAX/4`!
+    pub fn encoded_len(bytes: &[u8]) -> usize {
+        let len = Base64::encoded_len(bytes);
+        let line_endings = len.saturating_sub(1) / LINE_WIDTH;
+
+        line_endings
+            .checked_mul(LINE_ENDING.len())
+            .and_then(|line_endings_len| len.checked_add(line_endings_len))
+            .unwrap_or(0)
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::Base64Mime;
+    use crate::{test_vectors::MULTILINE_PADDED_BIN, Base64, Encoding, Error};
+
+    #[test]
+    fn round_trip() {
+        for len in 0..MULTILINE_PADDED_BIN.len() {
+            let data = &MULTILINE_PADDED_BIN[..len];
+            let encoded = Base64Mime::encode_string(data);
+
+            assert_eq!(encoded.len(), Base64Mime::encoded_len(data));
+            assert!(encoded.split("\r\n").all(|line| line.len() <= 76));
+            assert_eq!(encoded.replace("\r\n", ""), Base64::encode_string(data));
+            assert_eq!(Base64Mime::decode_vec(&encoded).unwrap(), data);
+
+            // Exactly sized output buffer
+            let mut buf = vec![0u8; len];
+            assert_eq!(Base64Mime::decode(&encoded, &mut buf), Ok(data));
+        }
+    }
+
+    #[test]
+    fn line_endings() {
+        assert_eq!(Base64Mime::encode_string(&[0u8; 57]), "A".repeat(76));
+        assert_eq!(
+            Base64Mime::encode_string(&[0u8; 58]),
+            format!("{}\r\nAA==", "A".repeat(76))
+        );
+    }
+
+    #[test]
+    fn decode_ignores_whitespace() {
+        assert_eq!(
+            Base64Mime::decode_vec(" SGVs\tbG8s\r\nIHdv\ncmxk IQ==\r\n").unwrap(),
+            b"Hello, world!"
+        );
+        assert_eq!(Base64Mime::decode_vec("S G V s b G 8 =").unwrap(), b"Hello");
+    }
+
+    #[test]
+    fn decode_errors() {
+        assert_eq!(
+            Base64Mime::decode_vec("SGVsbG8"),
+            Err(Error::InvalidEncoding)
+        );
+        assert_eq!(
+            Base64Mime::decode_vec("SGVsbG8=\r\nIQ=="),
+            Err(Error::InvalidEncoding)
+        );
+        assert_eq!(
+            Base64Mime::decode_vec("SGVsbG8=SGVs"),
+            Err(Error::InvalidEncoding)
+        );
+        assert_eq!(
+            Base64Mime::decode_vec("SGVs\0bG8="),
+            Err(Error::InvalidEncoding)
+        );
+
+        let mut buf = [0u8; 4];
+        assert_eq!(
+            Base64Mime::decode("SGVsbG8=", &mut buf),
+            Err(Error::InvalidLength)
+        );
+    }
+
+    #[test]
+    fn encode_buffer_too_small() {
+        let mut buf = [0u8; 80];
+        assert!(Base64Mime::encode(&[0u8; 58], &mut buf).is_err());
+    }
+}
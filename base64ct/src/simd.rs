@@ -0,0 +1,437 @@
+//! Variable-time SIMD-accelerated decoding for non-secret data.
+//!
+//! The decoders in this module are **not** constant-time: they exit early on
+//! invalid input and may select a different backend depending on the CPU.
+//! They are intended for decoding large amounts of public data, e.g. bundles
+//! of PEM-encoded certificates, and MUST NOT be used to decode secret
+//! material such as private keys. Use [`Encoding::decode`] for that.
+//!
+//! Backends are selected at runtime (or at compile time when the `std`
+//! feature is disabled) in the following order of preference:
+//!
+//! - AVX2 on `x86`/`x86_64`
+//! - NEON on `aarch64`
+//! - the constant-time scalar implementation on all other targets
+//!
+//! Regardless of the backend, the final block of the input (which may
+//! contain padding) is always decoded by the scalar implementation, so
+//! the accepted inputs are exactly the same as those accepted by
+//! [`Encoding::decode`].
+
+#![allow(unsafe_code)]
+
+use crate::{Base64, Base64Unpadded, Encoding, Error};
+
+#[cfg(feature = "alloc")]
+use {crate::encoding::decoded_len, alloc::vec::Vec};
+
+/// Variable-time decoding of the standard Base64 alphabet using the fastest
+/// backend available on the current CPU.
+///
+/// See the [module-level documentation](self) for more information.
+pub trait DecodeVartime: Encoding + sealed::Sealed {
+    /// Decode the provided Base64 string into the provided destination
+    /// buffer in variable time.
+    fn decode_vartime(src: impl AsRef<[u8]>, dst: &mut [u8]) -> Result<&[u8], Error> {
+        let src = src.as_ref();
+        let (consumed, written) = decode_bulk(src, dst)?;
+        let tail_len = Self::decode(&src[consumed..], &mut dst[written..])?.len();
+        let len = written.checked_add(tail_len).ok_or(Error::InvalidLength)?;
+        Ok(&dst[..len])
+    }
+
+    /// Decode the provided Base64 string into a [`Vec`] in variable time.
+    #[cfg(feature = "alloc")]
+    fn decode_vec_vartime(input: &str) -> Result<Vec<u8>, Error> {
+        let mut output = vec![0u8; decoded_len(input.len())];
+        let len = Self::decode_vartime(input, &mut output)?.len();
+        output.truncate(len);
+        Ok(output)
+    }
+}
+
+impl DecodeVartime for Base64 {}
+impl DecodeVartime for Base64Unpadded {}
+
+mod sealed {
+    /// Sealed trait which prevents implementing [`super::DecodeVartime`]
+    /// for alphabets which don't have a SIMD implementation.
+    pub trait Sealed {}
+
+    impl Sealed for crate::Base64 {}
+    impl Sealed for crate::Base64Unpadded {}
+}
+
+/// Decode as many complete blocks as possible using a SIMD backend, leaving
+/// at least the final block of the input for the scalar implementation.
+///
+/// Returns the number of input characters consumed and the number of bytes
+/// written to `dst`.
+#[cfg_attr(
+    not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")),
+    allow(unused_variables)
+)]
+fn decode_bulk(src: &[u8], dst: &mut [u8]) -> Result<(usize, usize), Error> {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    if avx2::is_available() {
+        // SAFETY: AVX2 support was detected above
+        return unsafe { avx2::decode(src, dst) };
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    if neon::is_available() {
+        // SAFETY: NEON support was detected above
+        return unsafe { neon::decode(src, dst) };
+    }
+
+    Ok((0, 0))
+}
+
+/// AVX2 backend.
+///
+/// Based on the algorithm described in "Faster Base64 Encoding and Decoding
+/// Using AVX2 Instructions" by Wojciech Muła and Daniel Lemire.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod avx2 {
+    use crate::Error;
+
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+
+    /// Number of Base64 characters decoded per iteration.
+    const BLOCK_SIZE: usize = 32;
+
+    /// Number of bytes produced per iteration.
+    const DECODED_BLOCK_SIZE: usize = 24;
+
+    /// Is AVX2 available on the current CPU?
+    pub(super) fn is_available() -> bool {
+        #[cfg(feature = "std")]
+        {
+            std::is_x86_feature_detected!("avx2")
+        }
+
+        #[cfg(not(feature = "std"))]
+        {
+            cfg!(target_feature = "avx2")
+        }
+    }
+
+    /// Decode 32-character blocks of the standard Base64 alphabet, always
+    /// leaving at least one character of input for the scalar decoder.
+    ///
+    /// # Safety
+    ///
+    /// The CPU must support AVX2.
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn decode(src: &[u8], dst: &mut [u8]) -> Result<(usize, usize), Error> {
+        let lut_lo = _mm256_setr_epi8(
+            0x15, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x13, 0x1A, 0x1B, 0x1B,
+            0x1B, 0x1A, 0x15, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x13, 0x1A,
+            0x1B, 0x1B, 0x1B, 0x1A,
+        );
+        let lut_hi = _mm256_setr_epi8(
+            0x10, 0x10, 0x01, 0x02, 0x04, 0x08, 0x04, 0x08, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10,
+            0x10, 0x10, 0x10, 0x10, 0x01, 0x02, 0x04, 0x08, 0x04, 0x08, 0x10, 0x10, 0x10, 0x10,
+            0x10, 0x10, 0x10, 0x10,
+        );
+        let lut_roll = _mm256_setr_epi8(
+            0, 16, 19, 4, -65, -65, -71, -71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 19, 4, -65, -65, -71,
+            -71, 0, 0, 0, 0, 0, 0, 0, 0,
+        );
+        let mask_2f = _mm256_set1_epi8(0x2F);
+        let pack_bytes = _mm256_setr_epi8(
+            2, 1, 0, 6, 5, 4, 10, 9, 8, 14, 13, 12, -1, -1, -1, -1, 2, 1, 0, 6, 5, 4, 10, 9, 8, 14,
+            13, 12, -1, -1, -1, -1,
+        );
+        let pack_lanes = _mm256_setr_epi32(0, 1, 2, 4, 5, 6, -1, -1);
+
+        let mut consumed = 0;
+        let mut written = 0;
+
+        while src.len().saturating_sub(consumed) > BLOCK_SIZE {
+            let block = &src[consumed..][..BLOCK_SIZE];
+            let out = dst
+                .get_mut(written..)
+                .and_then(|out| out.get_mut(..DECODED_BLOCK_SIZE))
+                .ok_or(Error::InvalidLength)?;
+
+            // SAFETY: `block` is exactly 32 bytes long, and unaligned loads
+            // are permitted
+            let input = _mm256_loadu_si256(block.as_ptr().cast());
+
+            // Classify each character by its high and low nibble, rejecting
+            // any characters outside of the alphabet
+            let hi_nibbles = _mm256_and_si256(_mm256_srli_epi32(input, 4), mask_2f);
+            let lo_nibbles = _mm256_and_si256(input, mask_2f);
+            let hi = _mm256_shuffle_epi8(lut_hi, hi_nibbles);
+            let lo = _mm256_shuffle_epi8(lut_lo, lo_nibbles);
+
+            if _mm256_testz_si256(lo, hi) == 0 {
+                return Err(Error::InvalidEncoding);
+            }
+
+            // Translate characters into their 6-bit values
+            let eq_2f = _mm256_cmpeq_epi8(input, mask_2f);
+            let roll = _mm256_shuffle_epi8(lut_roll, _mm256_add_epi8(eq_2f, hi_nibbles));
+            let sextets = _mm256_add_epi8(input, roll);
+
+            // Pack 4 x 6-bit values into 3 bytes
+            let merged = _mm256_maddubs_epi16(sextets, _mm256_set1_epi32(0x0140_0140));
+            let merged = _mm256_madd_epi16(merged, _mm256_set1_epi32(0x0001_1000));
+            let packed = _mm256_shuffle_epi8(merged, pack_bytes);
+            let packed = _mm256_permutevar8x32_epi32(packed, pack_lanes);
+
+            let mut decoded = [0u8; BLOCK_SIZE];
+
+            // SAFETY: `decoded` is exactly 32 bytes long, and unaligned
+            // stores are permitted
+            _mm256_storeu_si256(decoded.as_mut_ptr().cast(), packed);
+            out.copy_from_slice(&decoded[..DECODED_BLOCK_SIZE]);
+
+            consumed = consumed
+                .checked_add(BLOCK_SIZE)
+                .ok_or(Error::InvalidLength)?;
+            written = written
+                .checked_add(DECODED_BLOCK_SIZE)
+                .ok_or(Error::InvalidLength)?;
+        }
+
+        Ok((consumed, written))
+    }
+}
+
+/// NEON backend.
+///
+/// Translates characters using two 64-entry table lookups, the first for
+/// characters below 64 and the second for the remaining ASCII characters,
+/// as in the NEON decoder of Alfred Klomp's `base64` library.
+#[cfg(target_arch = "aarch64")]
+mod neon {
+    use crate::Error;
+    use core::arch::aarch64::*;
+
+    /// Number of Base64 characters decoded per iteration.
+    const BLOCK_SIZE: usize = 64;
+
+    /// Number of bytes produced per iteration.
+    const DECODED_BLOCK_SIZE: usize = 48;
+
+    /// Marker for characters outside of the alphabet.
+    const INVALID: u8 = 0xFF;
+
+    /// 6-bit values of the characters `0x00..=0x3F`.
+    #[rustfmt::skip]
+    const LUT_LO: [u8; 64] = [
+        INVALID, INVALID, INVALID, INVALID, INVALID, INVALID, INVALID, INVALID,
+        INVALID, INVALID, INVALID, INVALID, INVALID, INVALID, INVALID, INVALID,
+        INVALID, INVALID, INVALID, INVALID, INVALID, INVALID, INVALID, INVALID,
+        INVALID, INVALID, INVALID, INVALID, INVALID, INVALID, INVALID, INVALID,
+        INVALID, INVALID, INVALID, INVALID, INVALID, INVALID, INVALID, INVALID,
+        INVALID, INVALID, INVALID, 62, INVALID, INVALID, INVALID, 63,
+        52, 53, 54, 55, 56, 57, 58, 59,
+        60, 61, INVALID, INVALID, INVALID, INVALID, INVALID, INVALID,
+    ];
+
+    /// 6-bit values of the characters `0x40..=0x7E`, indexed by the
+    /// character minus 63. Index 0 is used for all characters below 64 and
+    /// is therefore zero.
+    #[rustfmt::skip]
+    const LUT_HI: [u8; 64] = [
+        0, INVALID, 0, 1, 2, 3, 4, 5,
+        6, 7, 8, 9, 10, 11, 12, 13,
+        14, 15, 16, 17, 18, 19, 20, 21,
+        22, 23, 24, 25, INVALID, INVALID, INVALID, INVALID,
+        INVALID, INVALID, 26, 27, 28, 29, 30, 31,
+        32, 33, 34, 35, 36, 37, 38, 39,
+        40, 41, 42, 43, 44, 45, 46, 47,
+        48, 49, 50, 51, INVALID, INVALID, INVALID, INVALID,
+    ];
+
+    /// Is NEON available on the current CPU?
+    pub(super) fn is_available() -> bool {
+        #[cfg(feature = "std")]
+        {
+            std::arch::is_aarch64_feature_detected!("neon")
+        }
+
+        #[cfg(not(feature = "std"))]
+        {
+            cfg!(target_feature = "neon")
+        }
+    }
+
+    /// Decode 64-character blocks of the standard Base64 alphabet, always
+    /// leaving at least one character of input for the scalar decoder.
+    ///
+    /// # Safety
+    ///
+    /// The CPU must support NEON.
+    #[target_feature(enable = "neon")]
+    pub(super) unsafe fn decode(src: &[u8], dst: &mut [u8]) -> Result<(usize, usize), Error> {
+        let lut_lo = load_lut(&LUT_LO);
+        let lut_hi = load_lut(&LUT_HI);
+
+        let mut consumed = 0;
+        let mut written = 0;
+
+        while src.len().saturating_sub(consumed) > BLOCK_SIZE {
+            let block = &src[consumed..][..BLOCK_SIZE];
+            let out = dst
+                .get_mut(written..)
+                .and_then(|out| out.get_mut(..DECODED_BLOCK_SIZE))
+                .ok_or(Error::InvalidLength)?;
+
+            // SAFETY: `block` is exactly 64 bytes long. The load
+            // deinterleaves it, so that lane `i` of the `j`-th register holds
+            // character `j` of the `i`-th group of four characters.
+            let input = vld4q_u8(block.as_ptr());
+
+            let a = translate(input.0, lut_lo, lut_hi);
+            let b = translate(input.1, lut_lo, lut_hi);
+            let c = translate(input.2, lut_lo, lut_hi);
+            let d = translate(input.3, lut_lo, lut_hi);
+
+            // Invalid characters translate to values above 63
+            if vmaxvq_u8(vorrq_u8(vorrq_u8(a, b), vorrq_u8(c, d))) > 63 {
+                return Err(Error::InvalidEncoding);
+            }
+
+            // Pack 4 x 6-bit values into 3 bytes
+            let packed = uint8x16x3_t(
+                vorrq_u8(vshlq_n_u8::<2>(a), vshrq_n_u8::<4>(b)),
+                vorrq_u8(vshlq_n_u8::<4>(b), vshrq_n_u8::<2>(c)),
+                vorrq_u8(vshlq_n_u8::<6>(c), d),
+            );
+
+            // SAFETY: `out` is exactly 48 bytes long
+            vst3q_u8(out.as_mut_ptr(), packed);
+
+            consumed = consumed
+                .checked_add(BLOCK_SIZE)
+                .ok_or(Error::InvalidLength)?;
+            written = written
+                .checked_add(DECODED_BLOCK_SIZE)
+                .ok_or(Error::InvalidLength)?;
+        }
+
+        Ok((consumed, written))
+    }
+
+    /// Translate characters into their 6-bit values, or values above 63 for
+    /// characters outside of the alphabet.
+    #[inline]
+    #[target_feature(enable = "neon")]
+    unsafe fn translate(
+        chars: uint8x16_t,
+        lut_lo: uint8x16x4_t,
+        lut_hi: uint8x16x4_t,
+    ) -> uint8x16_t {
+        // Characters above 63 are out of range for `LUT_LO` and become zero
+        let lo = vqtbl4q_u8(lut_lo, chars);
+
+        // Characters above 126 are out of range for `LUT_HI` and keep their
+        // index, which is above 63
+        let hi_idx = vqsubq_u8(chars, vdupq_n_u8(63));
+        let hi = vqtbx4q_u8(hi_idx, lut_hi, hi_idx);
+
+        vorrq_u8(lo, hi)
+    }
+
+    /// Load a 64-entry lookup table into registers.
+    #[inline]
+    #[target_feature(enable = "neon")]
+    unsafe fn load_lut(lut: &[u8; 64]) -> uint8x16x4_t {
+        // SAFETY: `lut` is exactly 64 bytes long
+        uint8x16x4_t(
+            vld1q_u8(lut.as_ptr()),
+            vld1q_u8(lut[16..].as_ptr()),
+            vld1q_u8(lut[32..].as_ptr()),
+            vld1q_u8(lut[48..].as_ptr()),
+        )
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::DecodeVartime;
+    use crate::{
+        test_vectors::{MULTILINE_PADDED_BIN, UNPADDED_BIN},
+        Base64, Base64Unpadded, Encoding, Error,
+    };
+    use alloc::vec::Vec;
+
+    /// Generate test data of the given length.
+    fn test_data(len: usize) -> impl Iterator<Item = u8> {
+        MULTILINE_PADDED_BIN
+            .iter()
+            .chain(UNPADDED_BIN)
+            .copied()
+            .cycle()
+            .take(len)
+    }
+
+    fn matches_constant_time<E: DecodeVartime>() {
+        for len in 0..300 {
+            let data = test_data(len).collect::<Vec<u8>>();
+            let encoded = E::encode_string(&data);
+
+            let mut buf = [0u8; 300];
+            assert_eq!(E::decode_vartime(&encoded, &mut buf), Ok(&data[..]));
+
+            // Exactly sized output buffer
+            let mut buf = vec![0u8; len];
+            assert_eq!(E::decode_vartime(&encoded, &mut buf), Ok(&data[..]));
+
+            // Insufficient output buffer
+            if len > 0 {
+                let mut buf = vec![0u8; len - 1];
+                assert_eq!(
+                    E::decode_vartime(&encoded, &mut buf),
+                    Err(Error::InvalidLength)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn padded_matches_constant_time() {
+        matches_constant_time::<Base64>();
+    }
+
+    #[test]
+    fn unpadded_matches_constant_time() {
+        matches_constant_time::<Base64Unpadded>();
+    }
+
+    #[test]
+    fn reject_invalid_characters() {
+        let data = test_data(192).collect::<Vec<u8>>();
+        let encoded = Base64::encode_string(&data).into_bytes();
+
+        for pos in 0..encoded.len() {
+            for invalid in [b'=', b'-', b'_', b'*', b' ', b'\n', 0, 0x80, 0xFF] {
+                let mut input = encoded.clone();
+                input[pos] = invalid;
+
+                let mut buf = [0u8; 192];
+                assert_eq!(
+                    Base64::decode_vartime(&input, &mut buf).is_ok(),
+                    Base64::decode(&input, &mut buf).is_ok(),
+                    "mismatch for {:#04x} at position {}",
+                    invalid,
+                    pos
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn decode_vec() {
+        let data = test_data(100).collect::<Vec<u8>>();
+        let encoded = Base64::encode_string(&data);
+        assert_eq!(Base64::decode_vec_vartime(&encoded).unwrap(), data);
+    }
+}
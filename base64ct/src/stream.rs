@@ -0,0 +1,365 @@
+//! Chunked streaming encoder and decoder.
+//!
+//! Unlike [`Encoder`][`crate::Encoder`] and [`Decoder`][`crate::Decoder`],
+//! which operate on a single contiguous output or input buffer respectively,
+//! the types in this module accept both input and output in arbitrarily sized
+//! chunks, which makes it possible to process payloads of any size in
+//! constant memory.
+
+use crate::{Encoding, Error};
+use core::marker::PhantomData;
+
+/// Chunked streaming Base64 encoder.
+///
+/// Input is provided in chunks of any size via [`StreamEncoder::encode`],
+/// and bytes which don't form a complete 3-byte block are carried over to the
+/// next call. Each call writes the Base64 encoding of every complete block
+/// into the provided output buffer, which must be at least
+/// [`StreamEncoder::encoded_len`] bytes long.
+///
+/// All encoding is performed by the constant-time [`Encoding`]
+/// implementation for `E`: only input lengths influence control flow.
+///
+/// ```
+/// use base64ct::{Base64, StreamEncoder};
+///
+/// let mut encoder = StreamEncoder::<Base64>::new();
+/// let mut buf = [0u8; 16];
+/// let mut encoded = String::new();
+///
+/// for chunk in [&b"Hel"[..], b"lo, w", b"orld!"] {
+///     encoded.push_str(encoder.encode(chunk, &mut buf)?);
+/// }
+///
+/// encoded.push_str(encoder.finish(&mut buf)?);
+/// assert_eq!(encoded, "SGVsbG8sIHdvcmxkIQ==");
+/// # Ok::<(), base64ct::Error>(())
+/// ```
+#[derive(Clone)]
+pub struct StreamEncoder<E: Encoding> {
+    /// Input bytes which don't yet form a complete block.
+    block: [u8; 3],
+
+    /// Number of bytes in `block`.
+    block_len: usize,
+
+    /// Phantom parameter for the Base64 encoding in use.
+    encoding: PhantomData<E>,
+}
+
+impl<E: Encoding> StreamEncoder<E> {
+    /// Create a new streaming encoder.
+    pub fn new() -> Self {
+        Self {
+            block: [0u8; 3],
+            block_len: 0,
+            encoding: PhantomData,
+        }
+    }
+
+    /// Get the length of the output which will be written by a call to
+    /// [`StreamEncoder::encode`] with `input_len` bytes of input.
+    pub fn encoded_len(&self, input_len: usize) -> Result<usize, Error> {
+        self.block_len
+            .checked_add(input_len)
+            .map(|len| len / 3)
+            .and_then(|blocks| blocks.checked_mul(4))
+            .ok_or(Error::InvalidLength)
+    }
+
+    /// Encode a chunk of input, writing the Base64 encoding of all complete
+    /// blocks into `out` and carrying over any remaining bytes.
+    ///
+    /// On error the encoder's state is left unchanged.
+    pub fn encode<'o>(&mut self, mut input: &[u8], out: &'o mut [u8]) -> Result<&'o str, Error> {
+        let out_len = self.encoded_len(input.len())?;
+        let out = out.get_mut(..out_len).ok_or(Error::InvalidLength)?;
+        let mut pos = 0;
+
+        // Complete the carried over block
+        if self.block_len != 0 {
+            let needed = self.block.len().saturating_sub(self.block_len);
+
+            if input.len() < needed {
+                self.block[self.block_len..][..input.len()].copy_from_slice(input);
+                self.block_len = self
+                    .block_len
+                    .checked_add(input.len())
+                    .ok_or(Error::InvalidLength)?;
+                return Ok("");
+            }
+
+            let (head, rest) = input.split_at(needed);
+            self.block[self.block_len..].copy_from_slice(head);
+            pos = E::encode(&self.block, out)?.len();
+            self.block_len = 0;
+            input = rest;
+        }
+
+        // Encode all remaining complete blocks
+        let bulk_len = input.len().saturating_sub(input.len() % 3);
+        let (bulk, rest) = input.split_at(bulk_len);
+        let end = pos
+            .checked_add(E::encode(bulk, &mut out[pos..])?.len())
+            .ok_or(Error::InvalidLength)?;
+
+        // Carry over the remainder
+        self.block[..rest.len()].copy_from_slice(rest);
+        self.block_len = rest.len();
+
+        // Output consists solely of Base64 characters
+        core::str::from_utf8(&out[..end]).map_err(|_| Error::InvalidEncoding)
+    }
+
+    /// Finish encoding, writing the encoding of any carried over bytes
+    /// (including padding, if the encoding is padded) into `out`.
+    ///
+    /// The output buffer must be at least 4 bytes long.
+    pub fn finish(self, out: &mut [u8]) -> Result<&str, Error> {
+        Ok(E::encode(&self.block[..self.block_len], out)?)
+    }
+}
+
+impl<E: Encoding> Default for StreamEncoder<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Chunked streaming Base64 decoder.
+///
+/// Base64 input is provided in chunks of any size via
+/// [`StreamDecoder::decode`], and characters which don't form a complete
+/// 4-character block are carried over to the next call. Each call decodes
+/// every complete block into the provided output buffer, which must be at
+/// least [`StreamDecoder::decoded_len`] bytes long.
+///
+/// The input must not contain whitespace or line breaks. All decoding is
+/// performed by the constant-time [`Encoding`] implementation for `E`: only
+/// input lengths (and the presence of padding) influence control flow.
+///
+/// ```
+/// use base64ct::{Base64, StreamDecoder};
+///
+/// let mut decoder = StreamDecoder::<Base64>::new();
+/// let mut buf = [0u8; 16];
+/// let mut decoded = Vec::new();
+///
+/// for chunk in ["SGVsb", "G8sIHdvcm", "xkIQ=="] {
+///     decoded.extend_from_slice(decoder.decode(chunk.as_bytes(), &mut buf)?);
+/// }
+///
+/// decoded.extend_from_slice(decoder.finish(&mut buf)?);
+/// assert_eq!(decoded, b"Hello, world!");
+/// # Ok::<(), base64ct::Error>(())
+/// ```
+#[derive(Clone)]
+pub struct StreamDecoder<E: Encoding> {
+    /// Base64 characters which don't yet form a complete block.
+    block: [u8; 4],
+
+    /// Number of characters in `block`.
+    block_len: usize,
+
+    /// Has a padded (and therefore final) block been decoded?
+    padded: bool,
+
+    /// Phantom parameter for the Base64 encoding in use.
+    encoding: PhantomData<E>,
+}
+
+impl<E: Encoding> StreamDecoder<E> {
+    /// Create a new streaming decoder.
+    pub fn new() -> Self {
+        Self {
+            block: [0u8; 4],
+            block_len: 0,
+            padded: false,
+            encoding: PhantomData,
+        }
+    }
+
+    /// Get the maximum length of the output which will be written by a call
+    /// to [`StreamDecoder::decode`] with `input_len` characters of input.
+    pub fn decoded_len(&self, input_len: usize) -> Result<usize, Error> {
+        self.block_len
+            .checked_add(input_len)
+            .map(|len| len / 4)
+            .and_then(|blocks| blocks.checked_mul(3))
+            .ok_or(Error::InvalidLength)
+    }
+
+    /// Decode a chunk of Base64 input, writing the decoding of all complete
+    /// blocks into `out` and carrying over any remaining characters.
+    pub fn decode<'o>(&mut self, mut input: &[u8], out: &'o mut [u8]) -> Result<&'o [u8], Error> {
+        if input.is_empty() {
+            return Ok(&[]);
+        }
+
+        // No input is allowed after padding
+        if self.padded {
+            return Err(Error::InvalidEncoding);
+        }
+
+        let out_len = self.decoded_len(input.len())?;
+        let out = out.get_mut(..out_len).ok_or(Error::InvalidLength)?;
+        let mut pos = 0;
+
+        // Complete the carried over block
+        if self.block_len != 0 {
+            let needed = self.block.len().saturating_sub(self.block_len);
+
+            if input.len() < needed {
+                self.block[self.block_len..][..input.len()].copy_from_slice(input);
+                self.block_len = self
+                    .block_len
+                    .checked_add(input.len())
+                    .ok_or(Error::InvalidLength)?;
+                return Ok(&[]);
+            }
+
+            let (head, rest) = input.split_at(needed);
+            self.block[self.block_len..].copy_from_slice(head);
+            pos = E::decode(self.block, out)?.len();
+            self.block_len = 0;
+            self.padded = pos < 3;
+            input = rest;
+
+            if self.padded && !input.is_empty() {
+                return Err(Error::InvalidEncoding);
+            }
+        }
+
+        // Decode all remaining complete blocks
+        let bulk_len = input.len().saturating_sub(input.len() % 4);
+        let (bulk, rest) = input.split_at(bulk_len);
+
+        if !bulk.is_empty() {
+            let decoded_len = E::decode(bulk, &mut out[pos..])?.len();
+            let full_len = (bulk_len / 4).checked_mul(3).ok_or(Error::InvalidLength)?;
+            self.padded = decoded_len < full_len;
+
+            if self.padded && !rest.is_empty() {
+                return Err(Error::InvalidEncoding);
+            }
+
+            pos = pos.checked_add(decoded_len).ok_or(Error::InvalidLength)?;
+        }
+
+        // Carry over the remainder
+        self.block[..rest.len()].copy_from_slice(rest);
+        self.block_len = rest.len();
+
+        Ok(&out[..pos])
+    }
+
+    /// Finish decoding, decoding any carried over characters into `out`.
+    ///
+    /// Carried over characters are only valid for unpadded encodings, in
+    /// which case the output buffer must be at least 2 bytes long.
+    pub fn finish(self, out: &mut [u8]) -> Result<&[u8], Error> {
+        if self.block_len == 0 {
+            return Ok(&[]);
+        }
+
+        E::decode(&self.block[..self.block_len], out)
+    }
+}
+
+impl<E: Encoding> Default for StreamDecoder<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{StreamDecoder, StreamEncoder};
+    use crate::{test_vectors::MULTILINE_PADDED_BIN, Base64, Base64Unpadded, Encoding, Error};
+
+    /// Encode the input in chunks of the given size, returning the number
+    /// of bytes written to `out`.
+    fn encode_chunked<E: Encoding>(input: &[u8], chunk_size: usize, out: &mut [u8]) -> usize {
+        let mut encoder = StreamEncoder::<E>::new();
+        let mut pos = 0;
+
+        for chunk in input.chunks(chunk_size) {
+            pos += encoder.encode(chunk, &mut out[pos..]).unwrap().len();
+        }
+
+        pos + encoder.finish(&mut out[pos..]).unwrap().len()
+    }
+
+    /// Decode the input in chunks of the given size, returning the number
+    /// of bytes written to `out`.
+    fn decode_chunked<E: Encoding>(input: &[u8], chunk_size: usize, out: &mut [u8]) -> usize {
+        let mut decoder = StreamDecoder::<E>::new();
+        let mut pos = 0;
+
+        for chunk in input.chunks(chunk_size) {
+            pos += decoder.decode(chunk, &mut out[pos..]).unwrap().len();
+        }
+
+        pos + decoder.finish(&mut out[pos..]).unwrap().len()
+    }
+
+    fn round_trip<E: Encoding>() {
+        let input = MULTILINE_PADDED_BIN;
+
+        for len in [0, 1, 2, 3, 4, 5, input.len()] {
+            let input = &input[..len];
+            let mut expected = [0u8; 512];
+            let expected = E::encode(input, &mut expected).unwrap();
+
+            for chunk_size in [1, 2, 3, 4, 5, 7, 64] {
+                let mut encoded = [0u8; 512];
+                let encoded_len = encode_chunked::<E>(input, chunk_size, &mut encoded);
+                assert_eq!(&encoded[..encoded_len], expected.as_bytes());
+
+                let mut decoded = [0u8; 512];
+                let decoded_len =
+                    decode_chunked::<E>(expected.as_bytes(), chunk_size, &mut decoded);
+                assert_eq!(&decoded[..decoded_len], input);
+            }
+        }
+    }
+
+    #[test]
+    fn round_trip_padded() {
+        round_trip::<Base64>();
+    }
+
+    #[test]
+    fn round_trip_unpadded() {
+        round_trip::<Base64Unpadded>();
+    }
+
+    #[test]
+    fn encode_insufficient_output() {
+        let mut encoder = StreamEncoder::<Base64>::new();
+        assert_eq!(
+            encoder.encode(b"foobar", &mut [0u8; 7]),
+            Err(Error::InvalidLength)
+        );
+        assert_eq!(encoder.encode(b"foobar", &mut [0u8; 8]), Ok("Zm9vYmFy"));
+    }
+
+    #[test]
+    fn decode_rejects_data_after_padding() {
+        let mut decoder = StreamDecoder::<Base64>::new();
+        let mut buf = [0u8; 16];
+        assert_eq!(decoder.decode(b"Zm9vYg==", &mut buf), Ok(&b"foob"[..]));
+        assert_eq!(
+            decoder.decode(b"Zm9v", &mut buf),
+            Err(Error::InvalidEncoding)
+        );
+    }
+
+    #[test]
+    fn decode_rejects_truncated_padded_input() {
+        let mut decoder = StreamDecoder::<Base64>::new();
+        let mut buf = [0u8; 16];
+        assert_eq!(decoder.decode(b"Zm9vY", &mut buf), Ok(&b"foo"[..]));
+        assert_eq!(decoder.finish(&mut buf), Err(Error::InvalidEncoding));
+    }
+}
@@ -1,7 +1,18 @@
 //! Attribute-related types
-use der::asn1::OctetString;
+use alloc::vec;
+use alloc::vec::Vec;
+use const_oid::ObjectIdentifier;
+use der::asn1::{OctetString, SetOfVec};
+use der::{Any, ErrorKind, Sequence, Tag, ValueOrd};
 
+use spki::AlgorithmIdentifierOwned;
+use x509_cert::attr::Attribute;
+use x509_cert::ext::pkix::certpolicy::PolicyInformation;
+use x509_cert::ext::pkix::name::{GeneralName, GeneralNames};
+use x509_cert::impl_newtype;
+use x509_cert::serial_number::SerialNumber;
 use x509_cert::time::Time;
+use x509_cert::Certificate;
 
 use crate::signed_data::SignerInfo;
 
@@ -23,6 +34,17 @@ pub type MessageDigest = OctetString;
 /// [RFC 5652 Section 11.3]: https://www.rfc-editor.org/rfc/rfc5652#section-11.3
 pub type SigningTime = Time;
 
+/// The `BinarySigningTime` attribute is defined in [RFC 6019 Section 3].
+///
+/// ```text
+///   BinarySigningTime ::= BinaryTime
+///
+///   BinaryTime ::= INTEGER (0..MAX)
+/// ```
+///
+/// [RFC 6019 Section 3]: https://www.rfc-editor.org/rfc/rfc6019#section-3
+pub type BinarySigningTime = u64;
+
 /// The `Countersignature` attribute is defined in [RFC 5652 Section 11.4].
 ///
 /// ```text
@@ -31,3 +53,251 @@ pub type SigningTime = Time;
 ///
 /// [RFC 5652 Section 11.4]: https://www.rfc-editor.org/rfc/rfc5652#section-11.4
 pub type Countersignature = SignerInfo;
+
+/// The `SMIMECapabilities` attribute is defined in [RFC 8551 Section 2.5.2].
+///
+/// ```text
+///   SMIMECapabilities ::= SEQUENCE OF SMIMECapability
+/// ```
+///
+/// The capabilities are ordered by decreasing preference.
+///
+/// [RFC 8551 Section 2.5.2]: https://www.rfc-editor.org/rfc/rfc8551#section-2.5.2
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SmimeCapabilities(pub Vec<SmimeCapability>);
+impl_newtype!(SmimeCapabilities, Vec<SmimeCapability>);
+
+impl SmimeCapabilities {
+    /// The AES-CBC content encryption algorithms, from AES-256 to AES-128.
+    pub fn aes() -> Self {
+        Self(vec![
+            SmimeCapability::new(const_oid::db::rfc5911::ID_AES_256_CBC),
+            SmimeCapability::new(const_oid::db::rfc5911::ID_AES_192_CBC),
+            SmimeCapability::new(const_oid::db::rfc5911::ID_AES_128_CBC),
+        ])
+    }
+
+    /// The Triple-DES content encryption algorithm, which is deprecated but may still be
+    /// required by older clients.
+    pub fn triple_des() -> Self {
+        Self(vec![SmimeCapability::new(
+            const_oid::db::rfc5911::DES_EDE_3_CBC,
+        )])
+    }
+
+    /// The SHA-2 digest algorithms, from SHA-512 to SHA-256.
+    pub fn sha2() -> Self {
+        Self(vec![
+            SmimeCapability::new(const_oid::db::rfc5912::ID_SHA_512),
+            SmimeCapability::new(const_oid::db::rfc5912::ID_SHA_384),
+            SmimeCapability::new(const_oid::db::rfc5912::ID_SHA_256),
+        ])
+    }
+
+    /// Check whether the capability with the given OID is included, regardless of its
+    /// parameters.
+    pub fn contains(&self, capability_id: ObjectIdentifier) -> bool {
+        self.0
+            .iter()
+            .any(|capability| capability.capability_id == capability_id)
+    }
+
+    /// Create an `smimeCapabilities` attribute, e.g. to be added to the signed attributes of
+    /// a `SignerInfo`.
+    pub fn to_attribute(&self) -> der::Result<Attribute> {
+        let mut values = SetOfVec::new();
+        values.insert(Any::encode_from(self)?)?;
+        Ok(Attribute {
+            oid: const_oid::db::rfc5911::SMIME_CAPABILITIES,
+            values,
+        })
+    }
+
+    /// Parse an `smimeCapabilities` attribute, which must have exactly one value.
+    pub fn from_attribute(attribute: &Attribute) -> der::Result<Self> {
+        if attribute.oid != const_oid::db::rfc5911::SMIME_CAPABILITIES {
+            return Err(ErrorKind::OidUnknown { oid: attribute.oid }.into());
+        }
+        match attribute.values.as_slice() {
+            [value] => value.decode_as(),
+            _ => Err(Tag::Set.value_error()),
+        }
+    }
+}
+
+impl Extend<SmimeCapability> for SmimeCapabilities {
+    fn extend<I: IntoIterator<Item = SmimeCapability>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+impl IntoIterator for SmimeCapabilities {
+    type Item = SmimeCapability;
+    type IntoIter = vec::IntoIter<SmimeCapability>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+/// The `SMIMECapability` type is defined in [RFC 8551 Section 2.5.2].
+///
+/// ```text
+///   SMIMECapability ::= SEQUENCE {
+///       capabilityID OBJECT IDENTIFIER,
+///       parameters ANY DEFINED BY capabilityID OPTIONAL }
+/// ```
+///
+/// [RFC 8551 Section 2.5.2]: https://www.rfc-editor.org/rfc/rfc8551#section-2.5.2
+#[derive(Clone, Debug, Eq, PartialEq, Sequence, ValueOrd)]
+#[allow(missing_docs)]
+pub struct SmimeCapability {
+    pub capability_id: ObjectIdentifier,
+    #[asn1(optional = "true")]
+    pub parameters: Option<Any>,
+}
+
+impl SmimeCapability {
+    /// Create a capability without parameters.
+    pub fn new(capability_id: ObjectIdentifier) -> Self {
+        Self {
+            capability_id,
+            parameters: None,
+        }
+    }
+}
+
+/// The `SigningCertificateV2` attribute is defined in [RFC 5035 Section 3].
+///
+/// ```text
+///   SigningCertificateV2 ::=  SEQUENCE {
+///       certs        SEQUENCE OF ESSCertIDv2,
+///       policies     SEQUENCE OF PolicyInformation OPTIONAL
+///   }
+/// ```
+///
+/// The first certificate identifies the signer certificate, the others may be used to limit
+/// the set of certificates used in path validation.
+///
+/// [RFC 5035 Section 3]: https://www.rfc-editor.org/rfc/rfc5035#section-3
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct SigningCertificateV2 {
+    pub certs: Vec<EssCertIdV2>,
+    #[asn1(optional = "true")]
+    pub policies: Option<Vec<PolicyInformation>>,
+}
+
+impl SigningCertificateV2 {
+    /// Create a `signingCertificateV2` attribute.
+    pub fn to_attribute(&self) -> der::Result<Attribute> {
+        let mut values = SetOfVec::new();
+        values.insert(Any::encode_from(self)?)?;
+        Ok(Attribute {
+            oid: const_oid::db::rfc5911::ID_AA_SIGNING_CERTIFICATE_V_2,
+            values,
+        })
+    }
+
+    /// Parse a `signingCertificateV2` attribute, which must have exactly one value.
+    pub fn from_attribute(attribute: &Attribute) -> der::Result<Self> {
+        if attribute.oid != const_oid::db::rfc5911::ID_AA_SIGNING_CERTIFICATE_V_2 {
+            return Err(ErrorKind::OidUnknown { oid: attribute.oid }.into());
+        }
+        match attribute.values.as_slice() {
+            [value] => value.decode_as(),
+            _ => Err(Tag::Set.value_error()),
+        }
+    }
+}
+
+/// The `ESSCertIDv2` type is defined in [RFC 5035 Section 4].
+///
+/// ```text
+///   ESSCertIDv2 ::=  SEQUENCE {
+///       hashAlgorithm           AlgorithmIdentifier
+///              DEFAULT {algorithm id-sha256},
+///       certHash                 Hash,
+///       issuerSerial             IssuerSerial OPTIONAL
+///   }
+///
+///   Hash ::= OCTET STRING
+/// ```
+///
+/// As DER requires the default value to be omitted, a `hash_algorithm` of `None` denotes
+/// SHA-256.
+///
+/// [RFC 5035 Section 4]: https://www.rfc-editor.org/rfc/rfc5035#section-4
+#[derive(Clone, Debug, Eq, PartialEq, Sequence, ValueOrd)]
+#[allow(missing_docs)]
+pub struct EssCertIdV2 {
+    #[asn1(optional = "true")]
+    pub hash_algorithm: Option<AlgorithmIdentifierOwned>,
+    pub cert_hash: OctetString,
+    #[asn1(optional = "true")]
+    pub issuer_serial: Option<IssuerSerial>,
+}
+
+impl EssCertIdV2 {
+    /// Get the hash algorithm, taking the SHA-256 default into account.
+    pub fn effective_hash_algorithm(&self) -> AlgorithmIdentifierOwned {
+        self.hash_algorithm
+            .clone()
+            .unwrap_or(AlgorithmIdentifierOwned {
+                oid: const_oid::db::rfc5912::ID_SHA_256,
+                parameters: None,
+            })
+    }
+}
+
+/// The `IssuerSerial` type is defined in [RFC 5035 Section 4].
+///
+/// ```text
+///   IssuerSerial ::= SEQUENCE {
+///       issuer                   GeneralNames,
+///       serialNumber             CertificateSerialNumber
+///   }
+/// ```
+///
+/// [RFC 5035 Section 4]: https://www.rfc-editor.org/rfc/rfc5035#section-4
+#[derive(Clone, Debug, Eq, PartialEq, Sequence, ValueOrd)]
+#[allow(missing_docs)]
+pub struct IssuerSerial {
+    pub issuer: GeneralNames,
+    pub serial_number: SerialNumber,
+}
+
+impl IssuerSerial {
+    /// Identify `certificate` by the directory name of its issuer and its serial number.
+    pub fn from_certificate(certificate: &Certificate) -> Self {
+        Self {
+            issuer: vec![GeneralName::DirectoryName(
+                certificate.tbs_certificate.issuer.clone(),
+            )],
+            serial_number: certificate.tbs_certificate.serial_number.clone(),
+        }
+    }
+
+    /// Check whether `certificate` is identified by this issuer and serial number.
+    pub fn matches(&self, certificate: &Certificate) -> bool {
+        self.serial_number == certificate.tbs_certificate.serial_number
+            && self.issuer.iter().any(|name| match name {
+                GeneralName::DirectoryName(name) => *name == certificate.tbs_certificate.issuer,
+                _ => false,
+            })
+    }
+}
+
+/// Find the value of the attribute with the given OID, which must be present exactly once and
+/// have exactly one value.
+#[cfg(any(feature = "decrypt", feature = "verify"))]
+pub(crate) fn find_single_attribute_value<'a>(
+    mut attributes: impl Iterator<Item = &'a Attribute>,
+    oid: ObjectIdentifier,
+) -> Option<&'a Any> {
+    let attribute = attributes.find(|attribute| attribute.oid == oid)?;
+    if attributes.any(|attribute| attribute.oid == oid) || attribute.values.len() != 1 {
+        return None;
+    }
+    attribute.values.get(0)
+}
@@ -1,5 +1,6 @@
 //! AuthenticatedData-related types
 
+use const_oid::{AssociatedOid, ObjectIdentifier};
 use der::asn1::OctetString;
 use der::Sequence;
 
@@ -63,6 +64,10 @@ pub struct AuthenticatedData {
     )]
     pub unauth_attrs: Option<Attributes>,
 }
+
+impl AssociatedOid for AuthenticatedData {
+    const OID: ObjectIdentifier = const_oid::db::rfc5911::ID_CT_AUTH_DATA;
+}
 /// The `MessageAuthenticationCode` type is defined in [RFC 5652 Section 9.1].
 ///
 /// ```text
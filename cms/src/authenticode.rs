@@ -0,0 +1,232 @@
+//! Authenticode-related types
+//!
+//! Authenticode signatures of Windows PE files are PKCS #7 `SignedData` messages with an
+//! encapsulated `SpcIndirectDataContent`, as specified in the
+//! [Windows Authenticode Portable Executable Signature Format][authenticode].
+//!
+//! Unlike CMS, Authenticode encapsulates the `SpcIndirectDataContent` directly rather than in an
+//! OCTET STRING, see [`SpcIndirectDataContent::to_encapsulated_content_info`].
+//!
+//! [authenticode]: https://learn.microsoft.com/en-us/windows-hardware/drivers/install/authenticode
+
+use crate::signed_data::EncapsulatedContentInfo;
+use alloc::vec::Vec;
+use const_oid::ObjectIdentifier;
+use der::asn1::{BitString, BmpString, Ia5String, OctetString};
+use der::{Any, Choice, Decode, ErrorKind, Sequence, Tag, Tagged};
+use spki::AlgorithmIdentifierOwned;
+
+/// `SPC_INDIRECT_DATA_OBJID`, the content type of [`SpcIndirectDataContent`]
+pub const SPC_INDIRECT_DATA: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.3.6.1.4.1.311.2.1.4");
+
+/// `SPC_STATEMENT_TYPE_OBJID`, the signed attribute holding a [`SpcStatementType`]
+pub const SPC_STATEMENT_TYPE: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.3.6.1.4.1.311.2.1.11");
+
+/// `SPC_SP_OPUS_INFO_OBJID`, the signed attribute holding a [`SpcSpOpusInfo`]
+pub const SPC_SP_OPUS_INFO: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.3.6.1.4.1.311.2.1.12");
+
+/// `SPC_PE_IMAGE_DATAOBJ`, the type of [`SpcPeImageData`] in a [`SpcAttributeTypeAndOptionalValue`]
+pub const SPC_PE_IMAGE_DATA: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.3.6.1.4.1.311.2.1.15");
+
+/// `SPC_INDIVIDUAL_SP_KEY_PURPOSE_OBJID`, the individual code signing [`SpcStatementType`]
+pub const SPC_INDIVIDUAL_SP_KEY_PURPOSE: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.3.6.1.4.1.311.2.1.21");
+
+/// `SPC_COMMERCIAL_SP_KEY_PURPOSE_OBJID`, the commercial code signing [`SpcStatementType`]
+pub const SPC_COMMERCIAL_SP_KEY_PURPOSE: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.3.6.1.4.1.311.2.1.22");
+
+/// The `SpcIndirectDataContent` type is defined in the Authenticode specification.
+///
+/// ```text
+///   SpcIndirectDataContent ::= SEQUENCE {
+///       data                    SpcAttributeTypeAndOptionalValue,
+///       messageDigest           DigestInfo
+///   } --#public--
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct SpcIndirectDataContent {
+    pub data: SpcAttributeTypeAndOptionalValue,
+    pub message_digest: DigestInfo,
+}
+
+impl SpcIndirectDataContent {
+    /// Encapsulate this content in a `SignedData` as Authenticode does, i.e. as the `eContent`
+    /// itself instead of the DER encoding wrapped in an OCTET STRING.
+    ///
+    /// As the message digest covers the value of the `eContent` without the tag and length
+    /// octets, Authenticode signatures only digest the value of the `SpcIndirectDataContent`
+    /// SEQUENCE.
+    pub fn to_encapsulated_content_info(&self) -> der::Result<EncapsulatedContentInfo> {
+        Ok(EncapsulatedContentInfo {
+            econtent_type: SPC_INDIRECT_DATA,
+            econtent: Some(Any::encode_from(self)?),
+        })
+    }
+
+    /// Extract the `SpcIndirectDataContent` encapsulated in a `SignedData`. Both the
+    /// Authenticode encoding and the CMS encoding wrapped in an OCTET STRING are accepted.
+    pub fn from_encapsulated_content_info(
+        encapsulated_content_info: &EncapsulatedContentInfo,
+    ) -> der::Result<Self> {
+        if encapsulated_content_info.econtent_type != SPC_INDIRECT_DATA {
+            return Err(ErrorKind::OidUnknown {
+                oid: encapsulated_content_info.econtent_type,
+            }
+            .into());
+        }
+        let econtent = encapsulated_content_info
+            .econtent
+            .as_ref()
+            .ok_or_else(|| Tag::Sequence.value_error())?;
+        if econtent.tag() == Tag::OctetString {
+            Self::from_der(econtent.value())
+        } else {
+            econtent.decode_as()
+        }
+    }
+}
+
+/// The `SpcAttributeTypeAndOptionalValue` type is defined in the Authenticode specification.
+///
+/// ```text
+///   SpcAttributeTypeAndOptionalValue ::= SEQUENCE {
+///       type                    ObjectID,
+///       value                   [0] EXPLICIT ANY OPTIONAL
+///   }
+/// ```
+///
+/// Despite the definition, the value is encoded without the context-specific tag in practice,
+/// e.g. [`SpcPeImageData`] for type [`SPC_PE_IMAGE_DATA`].
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct SpcAttributeTypeAndOptionalValue {
+    pub oid: ObjectIdentifier,
+    #[asn1(optional = "true")]
+    pub value: Option<Any>,
+}
+
+/// The `DigestInfo` type is defined in the Authenticode specification.
+///
+/// ```text
+///   DigestInfo ::= SEQUENCE {
+///       digestAlgorithm     AlgorithmIdentifier,
+///       digest              OCTETSTRING
+///   }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct DigestInfo {
+    pub digest_algorithm: AlgorithmIdentifierOwned,
+    pub digest: OctetString,
+}
+
+/// The `SpcPeImageData` type is defined in the Authenticode specification.
+///
+/// ```text
+///   SpcPeImageData ::= SEQUENCE {
+///      flags                   SpcPeImageFlags DEFAULT { includeResources },
+///      file                    SpcLink
+///   } --#public--
+///
+///   SpcPeImageFlags ::= BIT STRING {
+///       includeResources            (0),
+///       includeDebugInfo            (1),
+///       includeImportAddressTable   (2)
+///   }
+/// ```
+///
+/// In practice, the flags are always encoded and `file` is wrapped in an explicit `[0]` tag.
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct SpcPeImageData {
+    pub flags: BitString,
+    #[asn1(context_specific = "0", tag_mode = "EXPLICIT", optional = "true")]
+    pub file: Option<SpcLink>,
+}
+
+/// The `SpcLink` type is defined in the Authenticode specification.
+///
+/// ```text
+///   SpcLink ::= CHOICE {
+///       url                     [0] IMPLICIT IA5STRING,
+///       moniker                 [1] IMPLICIT SpcSerializedObject,
+///       file                    [2] EXPLICIT SpcString
+///   } --#public--
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Choice)]
+#[allow(missing_docs)]
+pub enum SpcLink {
+    #[asn1(context_specific = "0", tag_mode = "IMPLICIT")]
+    Url(Ia5String),
+
+    #[asn1(context_specific = "1", tag_mode = "IMPLICIT", constructed = "true")]
+    Moniker(SpcSerializedObject),
+
+    #[asn1(context_specific = "2", tag_mode = "EXPLICIT", constructed = "true")]
+    File(SpcString),
+}
+
+/// The `SpcString` type is defined in the Authenticode specification.
+///
+/// ```text
+///   SpcString ::= CHOICE {
+///       unicode                 [0] IMPLICIT BMPSTRING,
+///       ascii                   [1] IMPLICIT IA5STRING
+///   }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Choice)]
+#[allow(missing_docs)]
+pub enum SpcString {
+    #[asn1(context_specific = "0", tag_mode = "IMPLICIT")]
+    Unicode(BmpString),
+
+    #[asn1(context_specific = "1", tag_mode = "IMPLICIT")]
+    Ascii(Ia5String),
+}
+
+/// The `SpcSerializedObject` type is defined in the Authenticode specification.
+///
+/// ```text
+///   SpcSerializedObject ::= SEQUENCE {
+///       classId             SpcUuid,
+///       serializedData      OCTETSTRING
+///   }
+///
+///   SpcUuid ::= OCTETSTRING
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct SpcSerializedObject {
+    pub class_id: OctetString,
+    pub serialized_data: OctetString,
+}
+
+/// The `SpcSpOpusInfo` type is defined in the Authenticode specification.
+///
+/// ```text
+///   SpcSpOpusInfo ::= SEQUENCE {
+///       programName             [0] EXPLICIT SpcString OPTIONAL,
+///       moreInfo                [1] EXPLICIT SpcLink OPTIONAL,
+///   } --#public--
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct SpcSpOpusInfo {
+    #[asn1(context_specific = "0", tag_mode = "EXPLICIT", optional = "true")]
+    pub program_name: Option<SpcString>,
+    #[asn1(context_specific = "1", tag_mode = "EXPLICIT", optional = "true")]
+    pub more_info: Option<SpcLink>,
+}
+
+/// The `SpcStatementType` type is defined in the Authenticode specification.
+///
+/// ```text
+///   SpcStatementType ::= SEQUENCE of OBJECT IDENTIFIER
+/// ```
+pub type SpcStatementType = Vec<ObjectIdentifier>;
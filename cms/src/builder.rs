@@ -2,35 +2,63 @@
 
 //! CMS Builder
 
-use crate::cert::CertificateChoices;
+use crate::attr::{
+    BinarySigningTime, Countersignature, EssCertIdV2, IssuerSerial, SigningCertificateV2,
+};
+use crate::auth_enveloped_data::{AuthEnvelopedData, GcmParameters};
+use crate::authenticated_data::AuthenticatedData;
+use crate::authenticode::SPC_INDIRECT_DATA;
+use crate::cert::{CertificateChoices, IssuerAndSerialNumber};
 use crate::content_info::{CmsVersion, ContentInfo};
+use crate::digested_data::DigestedData;
+use crate::encrypted_data::EncryptedData;
+use crate::enveloped_data::{
+    EncryptedContentInfo, EncryptedKey, EnvelopedData, KemRecipientInfo,
+    KeyAgreeRecipientIdentifier, KeyAgreeRecipientInfo, KeyTransRecipientInfo,
+    OriginatorIdentifierOrKey, OriginatorInfo, OriginatorPublicKey, PasswordRecipientInfo,
+    RecipientEncryptedKey, RecipientIdentifier, RecipientInfo, RecipientInfos, UserKeyingMaterial,
+};
+use crate::gcm;
+use crate::kem::KemKeyDerivationAlgorithm;
+use crate::key_agreement::{KeyAgreementAlgorithm, KeyWrapAlgorithm};
+use crate::mac;
+use crate::password::{self, PasswordKeyEncryptionAlgorithm};
 use crate::revocation::{RevocationInfoChoice, RevocationInfoChoices};
 use crate::signed_data::{
     CertificateSet, DigestAlgorithmIdentifiers, EncapsulatedContentInfo, SignatureValue,
-    SignedAttributes, SignedData, SignerIdentifier, SignerInfo, SignerInfos, UnsignedAttributes,
+    SignedAttributes, SignedAttributesExt, SignedData, SignerIdentifier, SignerInfo, SignerInfos,
+    UnsignedAttributes,
 };
+use crate::verify::get_hasher;
 use alloc::borrow::ToOwned;
 use alloc::boxed::Box;
+use alloc::format;
 use alloc::string::String;
 use alloc::vec::Vec;
+use cipher::block_padding::Pkcs7;
+use cipher::{BlockCipher, BlockEncryptMut, KeyInit, KeyIvInit};
 use const_oid::ObjectIdentifier;
 use core::cmp::Ordering;
 use core::fmt;
-use der::asn1::{BitString, OctetStringRef, SetOfVec};
-use der::oid::db::DB;
+use der::asn1::{BitString, OctetString, OctetStringRef, SetOfVec};
+use der::referenced::OwnedToRef;
 use der::{Any, AnyRef, DateTime, Decode, Encode, ErrorKind, Tag};
-use digest::Digest;
-use sha2::digest;
+use pkcs5::pbes2::Pbkdf2Params;
+use rand_core::CryptoRngCore;
+use rsa::pkcs1::RsaOaepParams;
+use rsa::{Oaep, Pkcs1v15Encrypt, RsaPublicKey};
 use signature::digest::DynDigest;
 use signature::{Keypair, Signer};
 use spki::{
     AlgorithmIdentifierOwned, DynSignatureAlgorithmIdentifier, EncodePublicKey,
-    SignatureBitStringEncoding,
+    SignatureBitStringEncoding, SubjectPublicKeyInfoOwned,
 };
 use std::time::SystemTime;
 use std::vec;
-use x509_cert::attr::{Attribute, AttributeValue};
+use x509_cert::attr::{Attribute, AttributeValue, Attributes};
 use x509_cert::builder::Builder;
+use x509_cert::Certificate;
+use zeroize::Zeroizing;
 
 /// Error type
 #[derive(Debug)]
@@ -47,6 +75,9 @@ pub enum Error {
 
     /// Builder no table to build, because the struct is not properly configured
     Builder(String),
+
+    /// I/O error while streaming content.
+    Io(std::io::Error),
 }
 
 impl fmt::Display for Error {
@@ -56,6 +87,7 @@ impl fmt::Display for Error {
             Error::PublicKey(err) => write!(f, "public key error: {}", err),
             Error::Signature(err) => write!(f, "signature error: {}", err),
             Error::Builder(message) => write!(f, "builder error: {message}"),
+            Error::Io(err) => write!(f, "I/O error: {}", err),
         }
     }
 }
@@ -78,6 +110,12 @@ impl From<signature::Error> for Error {
     }
 }
 
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Error {
+        Error::Io(err)
+    }
+}
+
 type Result<T> = core::result::Result<T, Error>;
 
 /// Collect info needed for creating a `SignerInfo`.
@@ -190,16 +228,12 @@ where
                     return Err(der::Error::from(ErrorKind::Failed));
                 }
                 Some(content) => {
-                    let mut hasher = get_hasher(&self.digest_algorithm).ok_or_else(|| {
-                        // Unsupported hash algorithm: {}, &self.digest_algorithm.oid.to_string()
-                        der::Error::from(ErrorKind::Failed)
-                    })?;
                     // Only the octets comprising the value of the eContent
                     // OCTET STRING are input to the message digest algorithm, not the tag
-                    // or the length octets.
-                    let content_value = content.value();
-                    hasher.update(content_value);
-                    hasher.finalize_reset().to_vec()
+                    // or the length octets. The same applies to eContent which is not wrapped
+                    // in an OCTET STRING, e.g. Authenticode's `SpcIndirectDataContent`.
+                    create_message_digest(&self.digest_algorithm, content.value())
+                        .map_err(|_| der::Error::from(ErrorKind::Failed))?
                 }
             },
         };
@@ -246,10 +280,7 @@ where
         // Now use `signer` to sign the DER encoded signed attributes
         let signed_attributes = SignedAttributes::try_from(signed_attributes.to_owned())
             .map_err(|_| der::Error::from(ErrorKind::Failed))?;
-        let mut signed_attributes_der = Vec::new();
-        signed_attributes.encode_to_vec(&mut signed_attributes_der)?;
-
-        Ok(signed_attributes_der)
+        signed_attributes.to_der_for_signing()
     }
 
     fn assemble(
@@ -283,6 +314,148 @@ where
     }
 }
 
+/// Collect info needed for creating a countersignature, i.e. a `SignerInfo` signing the
+/// signature of another `SignerInfo` according to
+/// [RFC 5652 § 11.4](https://datatracker.ietf.org/doc/html/rfc5652#section-11.4)
+/// Calling `build()` on this struct will
+/// - calculate the message digest of the countersigned signature
+/// - calculate the signature
+/// - create a `SignerInfo` object, which can be added to the countersigned `SignerInfo` using
+///   [`add_countersignature`]
+pub struct CountersignatureBuilder<'s, S> {
+    signer: &'s S,
+    sid: SignerIdentifier,
+    digest_algorithm: AlgorithmIdentifierOwned,
+    signed_attributes: Option<Vec<Attribute>>,
+    unsigned_attributes: Option<Vec<Attribute>>,
+    signer_info: &'s SignerInfo,
+}
+
+impl<'s, S> CountersignatureBuilder<'s, S>
+where
+    S: Keypair + DynSignatureAlgorithmIdentifier,
+    S::VerifyingKey: EncodePublicKey,
+{
+    /// Create a new `CountersignatureBuilder` countersigning the signature of `signer_info`.
+    pub fn new(
+        signer: &'s S,
+        sid: SignerIdentifier,
+        digest_algorithm: AlgorithmIdentifierOwned,
+        signer_info: &'s SignerInfo,
+    ) -> Result<Self> {
+        Ok(CountersignatureBuilder {
+            signer,
+            sid,
+            digest_algorithm,
+            signed_attributes: None,
+            unsigned_attributes: None,
+            signer_info,
+        })
+    }
+
+    /// Add a "signed" attribute. The attribute will be signed together with the other "signed"
+    /// attributes, when `build()` is called.
+    /// RFC 5652 § 11.4: The countersignature MUST NOT contain a content-type attribute.
+    pub fn add_signed_attribute(&mut self, signed_attribute: Attribute) -> Result<&mut Self> {
+        if signed_attribute.oid == const_oid::db::rfc5911::ID_CONTENT_TYPE {
+            return Err(Error::Builder(String::from(
+                "countersignatures must not contain a content type attribute",
+            )));
+        }
+        if let Some(signed_attributes) = &mut self.signed_attributes {
+            signed_attributes.push(signed_attribute);
+        } else {
+            self.signed_attributes = Some(vec![signed_attribute]);
+        }
+        Ok(self)
+    }
+
+    /// Add an unsigned attribute.
+    pub fn add_unsigned_attribute(&mut self, unsigned_attribute: Attribute) -> Result<&mut Self> {
+        if let Some(unsigned_attributes) = &mut self.unsigned_attributes {
+            unsigned_attributes.push(unsigned_attribute);
+        } else {
+            self.unsigned_attributes = Some(vec![unsigned_attribute]);
+        }
+        Ok(self)
+    }
+
+    /// Calculate the CMSVersion of the countersignature, see [`SignerInfoBuilder::version`].
+    pub fn version(&self) -> CmsVersion {
+        match self.sid {
+            SignerIdentifier::IssuerAndSerialNumber(_) => CmsVersion::V1,
+            SignerIdentifier::SubjectKeyIdentifier(_) => CmsVersion::V3,
+        }
+    }
+}
+
+impl<'s, S> Builder for CountersignatureBuilder<'s, S>
+where
+    S: Keypair + DynSignatureAlgorithmIdentifier,
+    S::VerifyingKey: EncodePublicKey,
+{
+    type Signer = S;
+    type Output = SignerInfo;
+
+    fn signer(&self) -> &Self::Signer {
+        self.signer
+    }
+
+    /// Calculate the data to be signed
+    /// RFC 5652 § 11.4: The input to the message-digesting process is the contents
+    /// octets of the DER encoding of the signatureValue field of the SignerInfo value
+    /// with which the attribute is associated.
+    fn finalize(&mut self) -> der::Result<Vec<u8>> {
+        let message_digest = create_message_digest(
+            &self.digest_algorithm,
+            self.signer_info.signature.as_bytes(),
+        )
+        .map_err(|_| der::Error::from(ErrorKind::Failed))?;
+
+        // This implementation uses signed attributes.
+        let signed_attributes = self.signed_attributes.get_or_insert_with(Vec::new);
+        signed_attributes.push(
+            create_message_digest_attribute(&message_digest)
+                .map_err(|_| der::Error::from(ErrorKind::Failed))?,
+        );
+
+        // Now use `signer` to sign the DER encoded signed attributes
+        let signed_attributes = SignedAttributes::try_from(signed_attributes.to_owned())
+            .map_err(|_| der::Error::from(ErrorKind::Failed))?;
+        signed_attributes.to_der_for_signing()
+    }
+
+    fn assemble(
+        self,
+        signature: BitString,
+    ) -> core::result::Result<Self::Output, x509_cert::builder::Error> {
+        let version = self.version();
+        let signed_attrs = self
+            .signed_attributes
+            .map(SignedAttributes::try_from)
+            .transpose()?;
+        let unsigned_attrs = self
+            .unsigned_attributes
+            .map(UnsignedAttributes::try_from)
+            .transpose()?;
+
+        let signature_value =
+            SignatureValue::new(signature.raw_bytes()).map_err(x509_cert::builder::Error::from)?;
+
+        let signature_algorithm = self.signer.signature_algorithm_identifier()?;
+
+        Ok(SignerInfo {
+            version,
+            sid: self.sid,
+            digest_alg: self.digest_algorithm,
+            signed_attrs,
+            signature_algorithm,
+            signature: signature_value,
+            unsigned_attrs,
+        })
+    }
+}
+
 /// Builder for signedData (CMS and PKCS #7)
 pub struct SignedDataBuilder<'s> {
     digest_algorithms: Vec<AlgorithmIdentifierOwned>,
@@ -290,6 +463,7 @@ pub struct SignedDataBuilder<'s> {
     certificates: Option<Vec<CertificateChoices>>,
     crls: Option<Vec<RevocationInfoChoice>>,
     signer_infos: Vec<SignerInfo>,
+    detached: bool,
 }
 
 impl<'s> SignedDataBuilder<'s> {
@@ -301,10 +475,28 @@ impl<'s> SignedDataBuilder<'s> {
             certificates: None,
             crls: None,
             signer_infos: Vec::new(),
+            detached: false,
         }
     }
 
+    /// Create a detached signature, i.e. omit `eContent` from the `SignedData` while signing
+    /// the content of `encapsulated_content_info`.
+    /// RFC 5652 § 5.2: The optional omission of the eContent within the
+    /// EncapsulatedContentInfo field makes it possible to construct
+    /// "external signatures."  In the case of external signatures, the
+    /// content being signed is absent from the EncapsulatedContentInfo value
+    /// included in the signed-data content type.
+    ///
+    /// The content is still required to calculate the message digests of the signer infos.
+    /// If it isn't available, create the signer infos with an external message digest instead,
+    /// see [`SignerInfoBuilder::new`].
+    pub fn set_detached(&mut self, detached: bool) -> Result<&mut Self> {
+        self.detached = detached;
+        Ok(self)
+    }
+
     /// Add a digest algorithm to the collection of message digest algorithms.
+    /// The digest algorithms of added signer infos are added automatically.
     /// RFC 5652 § 5.1: digestAlgorithms is a collection of message digest algorithm
     /// identifiers.  There MAY be any number of elements in the
     /// collection, including zero.  Each element identifies the message
@@ -363,8 +555,9 @@ impl<'s> SignedDataBuilder<'s> {
         Ok(self)
     }
 
-    /// Add a signer info. The signature will be calculated. Note that the encapsulated content
-    /// must not be changed after the first signer info was added.
+    /// Add a signer info. The signature will be calculated and the signer's digest algorithm
+    /// added to the collection of message digest algorithms, if not present yet. Note that the
+    /// encapsulated content must not be changed after the first signer info was added.
     pub fn add_signer_info<S, Signature>(
         &mut self,
         signer_info_builder: SignerInfoBuilder<'_, S>,
@@ -378,6 +571,9 @@ impl<'s> SignedDataBuilder<'s> {
         let signer_info = signer_info_builder
             .build::<Signature>()
             .map_err(|_| der::Error::from(ErrorKind::Failed))?;
+        if !self.digest_algorithms.contains(&signer_info.digest_alg) {
+            self.digest_algorithms.push(signer_info.digest_alg.clone());
+        }
         self.signer_infos.push(signer_info);
 
         Ok(self)
@@ -385,41 +581,129 @@ impl<'s> SignedDataBuilder<'s> {
 
     /// This method returns a `ContentInfo` of type `signedData`.
     pub fn build(&mut self) -> Result<ContentInfo> {
+        let signed_data = self.build_signed_data()?;
+        let signed_data_der = signed_data.to_der()?;
+        let content = AnyRef::try_from(signed_data_der.as_slice())?;
+
+        let signed_data = ContentInfo {
+            content_type: const_oid::db::rfc5911::ID_SIGNED_DATA,
+            content: Any::from(content),
+        };
+
+        Ok(signed_data)
+    }
+
+    /// Write the DER encoding of a `ContentInfo` of type `signedData` to `writer`, streaming
+    /// `content_len` bytes read from `content` into the `eContent` OCTET STRING. This allows
+    /// creating attached signatures of content which is too large to be kept in memory.
+    ///
+    /// The encapsulated content info passed to [`SignedDataBuilder::new`] must not contain
+    /// `eContent`, and the signer infos must be created using the message digest of the
+    /// streamed content, see [`MessageDigestCalculator`] and [`SignerInfoBuilder::new`].
+    /// The content is not digested again while writing, it is up to the caller to pass the
+    /// same content to both.
+    ///
+    /// Unlike the rest of this crate, lengths beyond the 256 MiB limit of [`der::Length`] are
+    /// supported for the content.
+    pub fn build_to_writer(
+        &mut self,
+        content: impl std::io::Read,
+        content_len: u64,
+        mut writer: impl std::io::Write,
+    ) -> Result<()> {
+        if self.encapsulated_content_info.econtent.is_some() {
+            return Err(Error::Builder(String::from(
+                "eContent must be absent when streaming content",
+            )));
+        }
+
+        let signed_data = self.build_signed_data()?;
+        let content_type_der = const_oid::db::rfc5911::ID_SIGNED_DATA.to_der()?;
+        let econtent_type_der = signed_data.encap_content_info.econtent_type.to_der()?;
+        let mut prefix_der = signed_data.version.to_der()?;
+        prefix_der.extend(signed_data.digest_algorithms.to_der()?);
+
+        // Everything after `encapContentInfo`: certificates, crls and signerInfos
+        let signed_data_der = signed_data.to_der()?;
+        let signed_data_value = AnyRef::from_der(&signed_data_der)?.value();
+        let encap_content_info_len =
+            usize::try_from(signed_data.encap_content_info.encoded_len()?)?;
+        let suffix_der = &signed_data_value[prefix_der.len() + encap_content_info_len..];
+
+        let octet_string_len = tlv_len(content_len);
+        let econtent_len = tlv_len(octet_string_len);
+        let encap_content_info_len = econtent_type_der.len() as u64 + econtent_len;
+        let signed_data_len =
+            prefix_der.len() as u64 + tlv_len(encap_content_info_len) + suffix_der.len() as u64;
+        let content_info_len = content_type_der.len() as u64 + tlv_len(tlv_len(signed_data_len));
+
+        write_header(&mut writer, Tag::Sequence, content_info_len)?;
+        writer.write_all(&content_type_der)?;
+        write_header(
+            &mut writer,
+            Tag::ContextSpecific {
+                constructed: true,
+                number: der::TagNumber::N0,
+            },
+            tlv_len(signed_data_len),
+        )?;
+        write_header(&mut writer, Tag::Sequence, signed_data_len)?;
+        writer.write_all(&prefix_der)?;
+        write_header(&mut writer, Tag::Sequence, encap_content_info_len)?;
+        writer.write_all(&econtent_type_der)?;
+        write_header(
+            &mut writer,
+            Tag::ContextSpecific {
+                constructed: true,
+                number: der::TagNumber::N0,
+            },
+            octet_string_len,
+        )?;
+        write_header(&mut writer, Tag::OctetString, content_len)?;
+        let copied = std::io::copy(&mut content.take(content_len), &mut writer)?;
+        if copied != content_len {
+            return Err(Error::Builder(format!(
+                "content is shorter than the given length: {copied} < {content_len}"
+            )));
+        }
+        writer.write_all(suffix_der)?;
+        writer.flush()?;
+
+        Ok(())
+    }
+
+    /// Assemble the `SignedData` from the collected parts.
+    fn build_signed_data(&mut self) -> Result<SignedData> {
         let digest_algorithms =
-            DigestAlgorithmIdentifiers::try_from(self.digest_algorithms.to_owned()).unwrap();
+            DigestAlgorithmIdentifiers::try_from(self.digest_algorithms.to_owned())?;
 
-        let encap_content_info = self.encapsulated_content_info.clone();
+        let mut encap_content_info = self.encapsulated_content_info.clone();
+        if self.detached {
+            encap_content_info.econtent = None;
+        }
 
         let certificates = self
             .certificates
             .as_mut()
-            .map(|certificates| CertificateSet::try_from(certificates.to_owned()).unwrap());
+            .map(|certificates| CertificateSet::try_from(certificates.to_owned()))
+            .transpose()?;
 
         let crls = self
             .crls
             .as_mut()
-            .map(|crls| RevocationInfoChoices::try_from(crls.to_owned()).unwrap());
+            .map(|crls| RevocationInfoChoices::try_from(crls.to_owned()))
+            .transpose()?;
 
-        let signer_infos = SignerInfos::try_from(self.signer_infos.clone()).unwrap();
+        let signer_infos = SignerInfos::try_from(self.signer_infos.clone())?;
 
-        let signed_data = SignedData {
+        Ok(SignedData {
             version: self.calculate_version(),
             digest_algorithms,
             encap_content_info,
             certificates,
             crls,
             signer_infos,
-        };
-
-        let signed_data_der = signed_data.to_der()?;
-        let content = AnyRef::try_from(signed_data_der.as_slice())?;
-
-        let signed_data = ContentInfo {
-            content_type: const_oid::db::rfc5911::ID_SIGNED_DATA,
-            content: Any::from(content),
-        };
-
-        Ok(signed_data)
+        })
     }
 
     fn calculate_version(&self) -> CmsVersion {
@@ -477,8 +761,11 @@ impl<'s> SignedDataBuilder<'s> {
             .signer_infos
             .iter()
             .any(|signer_info| signer_info.version == CmsVersion::V3);
+        // Authenticode is based on PKCS #7 v1.5 and requires version 1 for
+        // `SpcIndirectDataContent`.
+        let econtent_type = self.encapsulated_content_info.econtent_type;
         let content_not_data =
-            self.encapsulated_content_info.econtent_type != const_oid::db::rfc5911::ID_DATA;
+            econtent_type != const_oid::db::rfc5911::ID_DATA && econtent_type != SPC_INDIRECT_DATA;
 
         if other_certificates_are_present || other_crls_are_present {
             CmsVersion::V5
@@ -492,79 +779,1489 @@ impl<'s> SignedDataBuilder<'s> {
     }
 }
 
-/// Get a hasher for a given digest algorithm
-fn get_hasher(
-    digest_algorithm_identifier: &AlgorithmIdentifierOwned,
-) -> Option<Box<dyn DynDigest>> {
-    let digest_name = DB.by_oid(&digest_algorithm_identifier.oid)?;
-    match digest_name {
-        "id-sha1" => Some(Box::new(sha1::Sha1::new())),
-        "id-sha256" => Some(Box::new(sha2::Sha256::new())),
-        "id-sha384" => Some(Box::new(sha2::Sha384::new())),
-        "id-sha512" => Some(Box::new(sha2::Sha512::new())),
-        "id-sha224" => Some(Box::new(sha2::Sha224::new())),
-        "id-sha-3-224" => Some(Box::new(sha3::Sha3_224::new())),
-        "id-sha-3-256" => Some(Box::new(sha3::Sha3_256::new())),
-        "id-sha-3-384" => Some(Box::new(sha3::Sha3_384::new())),
-        "id-sha-3-512" => Some(Box::new(sha3::Sha3_512::new())),
-        _ => None,
+/// Content encryption algorithms supported by [`EnvelopedDataBuilder`]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ContentEncryptionAlgorithm {
+    /// AES-128 in CBC mode
+    Aes128Cbc,
+    /// AES-192 in CBC mode
+    Aes192Cbc,
+    /// AES-256 in CBC mode
+    Aes256Cbc,
+}
+
+impl ContentEncryptionAlgorithm {
+    /// Return the OID of the algorithm.
+    pub fn oid(&self) -> ObjectIdentifier {
+        match self {
+            ContentEncryptionAlgorithm::Aes128Cbc => const_oid::db::rfc5911::ID_AES_128_CBC,
+            ContentEncryptionAlgorithm::Aes192Cbc => const_oid::db::rfc5911::ID_AES_192_CBC,
+            ContentEncryptionAlgorithm::Aes256Cbc => const_oid::db::rfc5911::ID_AES_256_CBC,
+        }
+    }
+
+    /// Return the size of the content-encryption key in bytes.
+    pub fn key_size(&self) -> usize {
+        match self {
+            ContentEncryptionAlgorithm::Aes128Cbc => 16,
+            ContentEncryptionAlgorithm::Aes192Cbc => 24,
+            ContentEncryptionAlgorithm::Aes256Cbc => 32,
+        }
+    }
+
+    /// Encrypt `content` with `key` and a random IV, returning the algorithm identifier
+    /// (containing the IV as parameters) and the encrypted content.
+    fn encrypt(
+        &self,
+        key: &[u8],
+        content: &[u8],
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<(AlgorithmIdentifierOwned, Vec<u8>)> {
+        let mut iv = [0u8; 16];
+        rng.fill_bytes(&mut iv);
+
+        let encrypted_content = match self {
+            ContentEncryptionAlgorithm::Aes128Cbc => encrypt_cbc::<aes::Aes128>(key, &iv, content)?,
+            ContentEncryptionAlgorithm::Aes192Cbc => encrypt_cbc::<aes::Aes192>(key, &iv, content)?,
+            ContentEncryptionAlgorithm::Aes256Cbc => encrypt_cbc::<aes::Aes256>(key, &iv, content)?,
+        };
+
+        let algorithm = AlgorithmIdentifierOwned {
+            oid: self.oid(),
+            parameters: Some(Any::new(Tag::OctetString, iv)?),
+        };
+
+        Ok((algorithm, encrypted_content))
     }
 }
 
-/// Create a content-type attribute according to
-/// [RFC 5652 § 11.1](https://datatracker.ietf.org/doc/html/rfc5652#section-11.1)
-pub fn create_content_type_attribute(content_type: ObjectIdentifier) -> Result<Attribute> {
-    let content_type_attribute_value =
-        AttributeValue::new(Tag::ObjectIdentifier, content_type.as_bytes())?;
-    let mut values = SetOfVec::new();
-    values.insert(content_type_attribute_value)?;
-    let attribute = Attribute {
-        oid: const_oid::db::rfc5911::ID_CONTENT_TYPE,
-        values,
-    };
-    Ok(attribute)
+/// Encrypt `content` using block cipher `C` in CBC mode with PKCS #7 padding.
+fn encrypt_cbc<C>(key: &[u8], iv: &[u8], content: &[u8]) -> Result<Vec<u8>>
+where
+    C: BlockEncryptMut + BlockCipher + KeyInit,
+{
+    let encryptor = cbc::Encryptor::<C>::new_from_slices(key, iv)
+        .map_err(|_| Error::Builder(String::from("invalid content-encryption key or IV")))?;
+    Ok(encryptor.encrypt_padded_vec_mut::<Pkcs7>(content))
 }
 
-/// Create a message digest attribute according to
-/// [RFC 5652 § 11.2](https://datatracker.ietf.org/doc/html/rfc5652#section-11.2)
-pub fn create_message_digest_attribute(message_digest: &[u8]) -> Result<Attribute> {
-    let message_digest_der = OctetStringRef::new(message_digest)?;
-    let message_digest_attribute_value =
-        AttributeValue::new(Tag::OctetString, message_digest_der.as_bytes())?;
-    let mut values = SetOfVec::new();
-    values.insert(message_digest_attribute_value)?;
-    let attribute = Attribute {
-        oid: const_oid::db::rfc5911::ID_MESSAGE_DIGEST,
-        values,
-    };
-    Ok(attribute)
+/// Public key and algorithm used to encrypt the content-encryption key for a recipient.
+pub enum KeyEncryptionInfo {
+    /// RSAES-PKCS1-v1_5 ([RFC 3370 § 4.2.1](https://datatracker.ietf.org/doc/html/rfc3370#section-4.2.1))
+    Rsa(RsaPublicKey),
+    /// RSAES-OAEP with SHA-256 and MGF1 with SHA-256
+    /// ([RFC 3560](https://datatracker.ietf.org/doc/html/rfc3560))
+    RsaOaep(RsaPublicKey),
 }
 
-/// Create a signing time attribute according to
-/// [RFC 5652 § 11.3](https://datatracker.ietf.org/doc/html/rfc5652#section-11.3)
-/// Dates between 1 January 1950 and 31 December 2049 (inclusive) MUST be
-/// encoded as UTCTime.  Any dates with year values before 1950 or after
-/// 2049 MUST be encoded as GeneralizedTime.
-pub fn create_signing_time_attribute() -> Result<Attribute> {
-    let now = DateTime::from_system_time(SystemTime::now())?;
-    let tag = if now.year() < 1950 || now.year() > 2049 {
-        Tag::GeneralizedTime
-    } else {
-        Tag::UtcTime
-    };
-    // let mut signing_time_buf = Vec::new();
-    let time_der = if tag == Tag::GeneralizedTime {
-        // der::asn1::GeneralizedTime::from_date_time(now).encode_to_vec(&mut signing_time_buf)?;
-        der::asn1::GeneralizedTime::from_date_time(now).to_der()?
-    } else {
-        // der::asn1::UtcTime::from_date_time(now)?.encode_to_vec(&mut signing_time_buf)?;
-        der::asn1::UtcTime::from_date_time(now)?.to_der()?
-    };
-    let signing_time_attribute_value = AttributeValue::from_der(&time_der)?;
-    let mut values = SetOfVec::<AttributeValue>::new();
-    values.insert(signing_time_attribute_value)?;
-    let attribute = Attribute {
-        oid: const_oid::db::rfc5911::ID_SIGNING_TIME,
+/// Builds a `RecipientInfo` by encrypting the content-encryption key for a recipient.
+pub trait RecipientInfoBuilder {
+    /// Encrypt the content-encryption key and return the `RecipientInfo`.
+    fn build(&mut self, content_encryption_key: &[u8]) -> Result<RecipientInfo>;
+}
+
+/// Builds a `KeyTransRecipientInfo` according to
+/// [RFC 5652 § 6.2.1](https://datatracker.ietf.org/doc/html/rfc5652#section-6.2.1)
+pub struct KeyTransRecipientInfoBuilder<'a, R> {
+    rid: RecipientIdentifier,
+    key_encryption_info: KeyEncryptionInfo,
+    rng: &'a mut R,
+}
+
+impl<'a, R> KeyTransRecipientInfoBuilder<'a, R>
+where
+    R: CryptoRngCore,
+{
+    /// Create a new `KeyTransRecipientInfoBuilder` for the recipient identified by `rid`.
+    pub fn new(
+        rid: RecipientIdentifier,
+        key_encryption_info: KeyEncryptionInfo,
+        rng: &'a mut R,
+    ) -> Result<Self> {
+        Ok(KeyTransRecipientInfoBuilder {
+            rid,
+            key_encryption_info,
+            rng,
+        })
+    }
+
+    /// Create a new `KeyTransRecipientInfoBuilder` for the RSA public key in `certificate`.
+    /// The recipient is identified by the issuer and serial number of the certificate, and
+    /// `key_encryption` selects the algorithm, e.g. `KeyEncryptionInfo::RsaOaep`.
+    pub fn from_certificate(
+        certificate: &Certificate,
+        key_encryption: fn(RsaPublicKey) -> KeyEncryptionInfo,
+        rng: &'a mut R,
+    ) -> Result<Self> {
+        let tbs_certificate = &certificate.tbs_certificate;
+        let rid = RecipientIdentifier::IssuerAndSerialNumber(IssuerAndSerialNumber {
+            issuer: tbs_certificate.issuer.clone(),
+            serial_number: tbs_certificate.serial_number.clone(),
+        });
+        let public_key =
+            RsaPublicKey::try_from(tbs_certificate.subject_public_key_info.owned_to_ref())?;
+
+        Self::new(rid, key_encryption(public_key), rng)
+    }
+
+    /// Calculate the CMSVersion of the recipient info.
+    /// RFC 5652 § 6.2.1: version is the syntax version number.  If the
+    /// RecipientIdentifier is the CHOICE issuerAndSerialNumber, then the
+    /// version MUST be 0.  If the RecipientIdentifier is
+    /// subjectKeyIdentifier, then the version MUST be 2.
+    pub fn version(&self) -> CmsVersion {
+        match self.rid {
+            RecipientIdentifier::IssuerAndSerialNumber(_) => CmsVersion::V0,
+            RecipientIdentifier::SubjectKeyIdentifier(_) => CmsVersion::V2,
+        }
+    }
+}
+
+impl<'a, R> RecipientInfoBuilder for KeyTransRecipientInfoBuilder<'a, R>
+where
+    R: CryptoRngCore,
+{
+    fn build(&mut self, content_encryption_key: &[u8]) -> Result<RecipientInfo> {
+        let (key_enc_alg, enc_key) = match &self.key_encryption_info {
+            KeyEncryptionInfo::Rsa(public_key) => {
+                let key_enc_alg = AlgorithmIdentifierOwned {
+                    oid: const_oid::db::rfc5912::RSA_ENCRYPTION,
+                    parameters: Some(Any::from(der::asn1::Null)),
+                };
+                let enc_key = public_key
+                    .encrypt(self.rng, Pkcs1v15Encrypt, content_encryption_key)
+                    .map_err(|err| Error::Builder(format!("RSA encryption failed: {err}")))?;
+                (key_enc_alg, enc_key)
+            }
+            KeyEncryptionInfo::RsaOaep(public_key) => {
+                let key_enc_alg = AlgorithmIdentifierOwned {
+                    oid: const_oid::db::rfc5912::ID_RSAES_OAEP,
+                    parameters: Some(Any::encode_from(&RsaOaepParams::sha256(&[]))?),
+                };
+                let enc_key = public_key
+                    .encrypt(
+                        self.rng,
+                        Oaep::new::<sha2::Sha256>(),
+                        content_encryption_key,
+                    )
+                    .map_err(|err| Error::Builder(format!("RSA encryption failed: {err}")))?;
+                (key_enc_alg, enc_key)
+            }
+        };
+
+        Ok(RecipientInfo::Ktri(KeyTransRecipientInfo {
+            version: self.version(),
+            rid: self.rid.clone(),
+            key_enc_alg,
+            enc_key: EncryptedKey::new(enc_key)?,
+        }))
+    }
+}
+
+/// Originator side of an ephemeral-static (EC)DH key agreement, e.g. using X25519 or a NIST
+/// curve, as used by [`KeyAgreeRecipientInfoBuilder`].
+pub trait OriginatorKeyAgreement {
+    /// Return the originator's ephemeral public key, which is sent to the recipient.
+    fn originator_public_key(&self) -> Result<OriginatorPublicKey>;
+
+    /// Compute the shared secret with the recipient's public key. For elliptic curves, this is
+    /// the x-coordinate of the shared point.
+    fn shared_secret(
+        &mut self,
+        recipient_public_key: &SubjectPublicKeyInfoOwned,
+    ) -> Result<Vec<u8>>;
+}
+
+/// Builds a `KeyAgreeRecipientInfo` according to
+/// [RFC 5652 § 6.2.2](https://datatracker.ietf.org/doc/html/rfc5652#section-6.2.2) and
+/// [RFC 5753 § 3.1](https://datatracker.ietf.org/doc/html/rfc5753#section-3.1)
+pub struct KeyAgreeRecipientInfoBuilder<K> {
+    rid: KeyAgreeRecipientIdentifier,
+    recipient_public_key: SubjectPublicKeyInfoOwned,
+    key_agreement: K,
+    key_agreement_algorithm: KeyAgreementAlgorithm,
+    key_wrap_algorithm: KeyWrapAlgorithm,
+    ukm: Option<UserKeyingMaterial>,
+}
+
+impl<K> KeyAgreeRecipientInfoBuilder<K>
+where
+    K: OriginatorKeyAgreement,
+{
+    /// Create a new `KeyAgreeRecipientInfoBuilder` for the recipient identified by `rid`.
+    pub fn new(
+        rid: KeyAgreeRecipientIdentifier,
+        recipient_public_key: SubjectPublicKeyInfoOwned,
+        key_agreement: K,
+        key_agreement_algorithm: KeyAgreementAlgorithm,
+        key_wrap_algorithm: KeyWrapAlgorithm,
+    ) -> Result<Self> {
+        Ok(KeyAgreeRecipientInfoBuilder {
+            rid,
+            recipient_public_key,
+            key_agreement,
+            key_agreement_algorithm,
+            key_wrap_algorithm,
+            ukm: None,
+        })
+    }
+
+    /// Create a new `KeyAgreeRecipientInfoBuilder` for the public key in `certificate`. The
+    /// recipient is identified by the issuer and serial number of the certificate.
+    pub fn from_certificate(
+        certificate: &Certificate,
+        key_agreement: K,
+        key_agreement_algorithm: KeyAgreementAlgorithm,
+        key_wrap_algorithm: KeyWrapAlgorithm,
+    ) -> Result<Self> {
+        let tbs_certificate = &certificate.tbs_certificate;
+        let rid = KeyAgreeRecipientIdentifier::IssuerAndSerialNumber(IssuerAndSerialNumber {
+            issuer: tbs_certificate.issuer.clone(),
+            serial_number: tbs_certificate.serial_number.clone(),
+        });
+
+        Self::new(
+            rid,
+            tbs_certificate.subject_public_key_info.clone(),
+            key_agreement,
+            key_agreement_algorithm,
+            key_wrap_algorithm,
+        )
+    }
+
+    /// Set the user keying material.
+    /// RFC 5753 § 3.1.1: ukm MAY be present to ensure that a different
+    /// key-encryption key is generated when the ephemeral private key
+    /// might be used more than once.
+    pub fn set_user_keying_material(&mut self, ukm: UserKeyingMaterial) -> Result<&mut Self> {
+        self.ukm = Some(ukm);
+        Ok(self)
+    }
+}
+
+impl<K> RecipientInfoBuilder for KeyAgreeRecipientInfoBuilder<K>
+where
+    K: OriginatorKeyAgreement,
+{
+    fn build(&mut self, content_encryption_key: &[u8]) -> Result<RecipientInfo> {
+        let shared_secret = Zeroizing::new(
+            self.key_agreement
+                .shared_secret(&self.recipient_public_key)?,
+        );
+        let key_encryption_key = self.key_agreement_algorithm.derive_key_encryption_key(
+            &shared_secret,
+            self.key_wrap_algorithm,
+            self.ukm.as_ref(),
+        )?;
+
+        let enc_key = self
+            .key_wrap_algorithm
+            .wrap(&key_encryption_key, content_encryption_key)
+            .ok_or_else(|| Error::Builder(String::from("invalid content-encryption key size")))?;
+
+        Ok(RecipientInfo::Kari(KeyAgreeRecipientInfo {
+            version: CmsVersion::V3,
+            originator: OriginatorIdentifierOrKey::OriginatorKey(
+                self.key_agreement.originator_public_key()?,
+            ),
+            ukm: self.ukm.clone(),
+            key_enc_alg: AlgorithmIdentifierOwned {
+                oid: self.key_agreement_algorithm.oid(),
+                parameters: Some(Any::encode_from(
+                    &self.key_wrap_algorithm.algorithm_identifier(),
+                )?),
+            },
+            recipient_enc_keys: vec![RecipientEncryptedKey {
+                rid: self.rid.clone(),
+                enc_key: EncryptedKey::new(enc_key)?,
+            }],
+        }))
+    }
+}
+
+/// Originator side of a key encapsulation mechanism (KEM), e.g. ML-KEM, as used by
+/// [`KemRecipientInfoBuilder`].
+pub trait OriginatorKeyEncapsulation {
+    /// Return the algorithm identifier of the KEM.
+    fn kem_algorithm_identifier(&self) -> AlgorithmIdentifierOwned;
+
+    /// Encapsulate a fresh shared secret to the recipient's public key and return the
+    /// ciphertext and the shared secret.
+    fn encapsulate(
+        &mut self,
+        recipient_public_key: &SubjectPublicKeyInfoOwned,
+    ) -> Result<(Vec<u8>, Vec<u8>)>;
+}
+
+/// Builds a `KEMRecipientInfo` according to
+/// [RFC 9629 § 3](https://datatracker.ietf.org/doc/html/rfc9629#section-3), carried in an
+/// `OtherRecipientInfo`
+pub struct KemRecipientInfoBuilder<K> {
+    rid: RecipientIdentifier,
+    recipient_public_key: SubjectPublicKeyInfoOwned,
+    kem: K,
+    key_derivation_algorithm: KemKeyDerivationAlgorithm,
+    key_wrap_algorithm: KeyWrapAlgorithm,
+    ukm: Option<UserKeyingMaterial>,
+}
+
+impl<K> KemRecipientInfoBuilder<K>
+where
+    K: OriginatorKeyEncapsulation,
+{
+    /// Create a new `KemRecipientInfoBuilder` for the recipient identified by `rid`.
+    pub fn new(
+        rid: RecipientIdentifier,
+        recipient_public_key: SubjectPublicKeyInfoOwned,
+        kem: K,
+        key_derivation_algorithm: KemKeyDerivationAlgorithm,
+        key_wrap_algorithm: KeyWrapAlgorithm,
+    ) -> Result<Self> {
+        Ok(KemRecipientInfoBuilder {
+            rid,
+            recipient_public_key,
+            kem,
+            key_derivation_algorithm,
+            key_wrap_algorithm,
+            ukm: None,
+        })
+    }
+
+    /// Create a new `KemRecipientInfoBuilder` for the public key in `certificate`. The
+    /// recipient is identified by the issuer and serial number of the certificate.
+    pub fn from_certificate(
+        certificate: &Certificate,
+        kem: K,
+        key_derivation_algorithm: KemKeyDerivationAlgorithm,
+        key_wrap_algorithm: KeyWrapAlgorithm,
+    ) -> Result<Self> {
+        let tbs_certificate = &certificate.tbs_certificate;
+        let rid = RecipientIdentifier::IssuerAndSerialNumber(IssuerAndSerialNumber {
+            issuer: tbs_certificate.issuer.clone(),
+            serial_number: tbs_certificate.serial_number.clone(),
+        });
+
+        Self::new(
+            rid,
+            tbs_certificate.subject_public_key_info.clone(),
+            kem,
+            key_derivation_algorithm,
+            key_wrap_algorithm,
+        )
+    }
+
+    /// Set the user keying material, which is included in the key derivation.
+    /// RFC 9629 § 3: ukm is optional. When the ukm value is provided, it is
+    /// used as part of the info structure described in Section 5 to provide
+    /// a context input to the key-derivation function.
+    pub fn set_user_keying_material(&mut self, ukm: UserKeyingMaterial) -> Result<&mut Self> {
+        self.ukm = Some(ukm);
+        Ok(self)
+    }
+}
+
+impl<K> RecipientInfoBuilder for KemRecipientInfoBuilder<K>
+where
+    K: OriginatorKeyEncapsulation,
+{
+    fn build(&mut self, content_encryption_key: &[u8]) -> Result<RecipientInfo> {
+        let (kem_ct, shared_secret) = self.kem.encapsulate(&self.recipient_public_key)?;
+        let shared_secret = Zeroizing::new(shared_secret);
+        let key_encryption_key = self.key_derivation_algorithm.derive_key_encryption_key(
+            &shared_secret,
+            self.key_wrap_algorithm,
+            self.ukm.as_ref(),
+        )?;
+
+        let encrypted_key = self
+            .key_wrap_algorithm
+            .wrap(&key_encryption_key, content_encryption_key)
+            .ok_or_else(|| Error::Builder(String::from("invalid content-encryption key size")))?;
+
+        let kem_recipient_info = KemRecipientInfo {
+            version: CmsVersion::V0,
+            rid: self.rid.clone(),
+            kem: self.kem.kem_algorithm_identifier(),
+            kem_ct: OctetString::new(kem_ct)?,
+            kdf: self.key_derivation_algorithm.algorithm_identifier(),
+            kek_length: self.key_wrap_algorithm.key_size() as u16,
+            ukm: self.ukm.clone(),
+            wrap: self.key_wrap_algorithm.algorithm_identifier(),
+            encrypted_key: EncryptedKey::new(encrypted_key)?,
+        };
+        Ok(RecipientInfo::Ori(
+            kem_recipient_info.to_other_recipient_info()?,
+        ))
+    }
+}
+
+/// Builds a `PasswordRecipientInfo` according to
+/// [RFC 5652 § 6.2.4](https://datatracker.ietf.org/doc/html/rfc5652#section-6.2.4) and
+/// [RFC 3211](https://datatracker.ietf.org/doc/html/rfc3211)
+pub struct PasswordRecipientInfoBuilder<'a, R> {
+    password: &'a [u8],
+    key_derivation_params: Pbkdf2Params<'a>,
+    key_encryption_algorithm: PasswordKeyEncryptionAlgorithm,
+    rng: &'a mut R,
+}
+
+impl<'a, R> PasswordRecipientInfoBuilder<'a, R>
+where
+    R: CryptoRngCore,
+{
+    /// Create a new `PasswordRecipientInfoBuilder`. The key-encryption key is derived from
+    /// `password` using PBKDF2 with `key_derivation_params`, e.g.
+    /// `Pbkdf2Params::hmac_with_sha256(iterations, salt)` with a random salt.
+    pub fn new(
+        password: &'a [u8],
+        key_derivation_params: Pbkdf2Params<'a>,
+        key_encryption_algorithm: PasswordKeyEncryptionAlgorithm,
+        rng: &'a mut R,
+    ) -> Result<Self> {
+        Ok(PasswordRecipientInfoBuilder {
+            password,
+            key_derivation_params,
+            key_encryption_algorithm,
+            rng,
+        })
+    }
+}
+
+impl<'a, R> RecipientInfoBuilder for PasswordRecipientInfoBuilder<'a, R>
+where
+    R: CryptoRngCore,
+{
+    fn build(&mut self, content_encryption_key: &[u8]) -> Result<RecipientInfo> {
+        let key_encryption_key = password::derive_key_encryption_key(
+            self.password,
+            &self.key_derivation_params,
+            self.key_encryption_algorithm.key_size(),
+        )
+        .ok_or_else(|| Error::Builder(String::from("unsupported PBKDF2 parameters")))?;
+
+        let mut iv = [0u8; 16];
+        self.rng.fill_bytes(&mut iv);
+        let enc_key = self.key_encryption_algorithm.wrap(
+            &key_encryption_key,
+            content_encryption_key,
+            &iv,
+            self.rng,
+        );
+        let enc_key = enc_key
+            .ok_or_else(|| Error::Builder(String::from("invalid content-encryption key size")))?;
+
+        Ok(RecipientInfo::Pwri(PasswordRecipientInfo {
+            version: CmsVersion::V0,
+            key_derivation_alg: Some(AlgorithmIdentifierOwned {
+                oid: pkcs5::pbes2::PBKDF2_OID,
+                parameters: Some(Any::encode_from(&self.key_derivation_params)?),
+            }),
+            key_enc_alg: self.key_encryption_algorithm.algorithm_identifier(&iv)?,
+            enc_key: EncryptedKey::new(enc_key)?,
+        }))
+    }
+}
+
+/// Builder for envelopedData (CMS and PKCS #7)
+/// Calling `build_with_rng()` on this struct will
+/// - generate a random content-encryption key
+/// - encrypt the content with the content-encryption key
+/// - encrypt the content-encryption key for each recipient
+/// - create a `ContentInfo` of type `envelopedData`
+pub struct EnvelopedDataBuilder<'c> {
+    originator_info: Option<OriginatorInfo>,
+    recipient_infos: Vec<Box<dyn RecipientInfoBuilder + 'c>>,
+    unencrypted_content: &'c [u8],
+    content_encryption_algorithm: ContentEncryptionAlgorithm,
+    unprotected_attributes: Option<Vec<Attribute>>,
+}
+
+impl<'c> EnvelopedDataBuilder<'c> {
+    /// Create a new builder for `EnvelopedData`
+    pub fn new(
+        unencrypted_content: &'c [u8],
+        content_encryption_algorithm: ContentEncryptionAlgorithm,
+    ) -> EnvelopedDataBuilder<'c> {
+        Self {
+            originator_info: None,
+            recipient_infos: Vec::new(),
+            unencrypted_content,
+            content_encryption_algorithm,
+            unprotected_attributes: None,
+        }
+    }
+
+    /// Set the originator info.
+    /// RFC 5652 § 6.1: originatorInfo optionally provides information about the
+    /// originator.  It is present only if required by the key management
+    /// algorithm.  It may contain certificates and CRLs.
+    pub fn set_originator_info(&mut self, originator_info: OriginatorInfo) -> Result<&mut Self> {
+        self.originator_info = Some(originator_info);
+        Ok(self)
+    }
+
+    /// Add a recipient. The content-encryption key will be encrypted for the recipient, when
+    /// `build_with_rng()` is called.
+    pub fn add_recipient_info(
+        &mut self,
+        recipient_info_builder: impl RecipientInfoBuilder + 'c,
+    ) -> Result<&mut Self> {
+        self.recipient_infos.push(Box::new(recipient_info_builder));
+        Ok(self)
+    }
+
+    /// Add an unprotected attribute.
+    pub fn add_unprotected_attribute(
+        &mut self,
+        unprotected_attribute: Attribute,
+    ) -> Result<&mut Self> {
+        if let Some(unprotected_attributes) = &mut self.unprotected_attributes {
+            unprotected_attributes.push(unprotected_attribute);
+        } else {
+            self.unprotected_attributes = Some(vec![unprotected_attribute]);
+        }
+        Ok(self)
+    }
+
+    /// This method returns a `ContentInfo` of type `envelopedData`. `rng` is used to generate
+    /// the content-encryption key and IV.
+    pub fn build_with_rng(&mut self, rng: &mut impl CryptoRngCore) -> Result<ContentInfo> {
+        if self.recipient_infos.is_empty() {
+            return Err(Error::Builder(String::from("no recipients")));
+        }
+
+        let mut content_encryption_key =
+            Zeroizing::new(vec![0u8; self.content_encryption_algorithm.key_size()]);
+        rng.fill_bytes(&mut content_encryption_key);
+
+        let (content_enc_alg, encrypted_content) = self.content_encryption_algorithm.encrypt(
+            &content_encryption_key,
+            self.unencrypted_content,
+            rng,
+        )?;
+
+        let recip_infos =
+            build_recipient_infos(&mut self.recipient_infos, &content_encryption_key)?;
+
+        let unprotected_attrs = self
+            .unprotected_attributes
+            .as_ref()
+            .map(|unprotected_attributes| Attributes::try_from(unprotected_attributes.to_owned()))
+            .transpose()?;
+
+        let enveloped_data = EnvelopedData {
+            version: self.calculate_version(&recip_infos, unprotected_attrs.is_some()),
+            originator_info: self.originator_info.clone(),
+            recip_infos,
+            encrypted_content: EncryptedContentInfo {
+                content_type: const_oid::db::rfc5911::ID_DATA,
+                content_enc_alg,
+                encrypted_content: Some(OctetString::new(encrypted_content)?),
+            },
+            unprotected_attrs,
+        };
+
+        Ok(ContentInfo {
+            content_type: const_oid::db::rfc5911::ID_ENVELOPED_DATA,
+            content: Any::encode_from(&enveloped_data)?,
+        })
+    }
+
+    fn calculate_version(
+        &self,
+        recipient_infos: &RecipientInfos,
+        unprotected_attributes_present: bool,
+    ) -> CmsVersion {
+        // RFC 5652, 6.1.  EnvelopedData Type
+        // IF (originatorInfo is present) AND
+        //    ((any certificates with a type of other are present) OR
+        //    (any crls with a type of other are present))
+        // THEN version is 4
+        // ELSE
+        //    IF ((originatorInfo is present) AND
+        //       (any version 2 attribute certificates are present)) OR
+        //       (any RecipientInfo structures include pwri) OR
+        //       (any RecipientInfo structures include ori)
+        //    THEN version is 3
+        //    ELSE
+        //       IF (originatorInfo is absent) AND
+        //          (unprotectedAttrs is absent) AND
+        //          (all RecipientInfo structures are version 0)
+        //       THEN version is 0
+        //       ELSE version is 2
+        let other_certificates_or_crls_are_present =
+            self.originator_info
+                .as_ref()
+                .map_or(false, |originator_info| {
+                    let other_certificates =
+                        originator_info.certs.as_ref().map_or(false, |certs| {
+                            certs.0.iter().any(|certificate| {
+                                matches!(certificate, CertificateChoices::Other(_))
+                            })
+                        });
+                    let other_crls = originator_info.crls.as_ref().map_or(false, |crls| {
+                        crls.0
+                            .iter()
+                            .any(|crl| matches!(crl, RevocationInfoChoice::Other(_)))
+                    });
+                    other_certificates || other_crls
+                });
+        // v2 attribute certificates currently not supported
+        let pwri_or_ori_are_present = recipient_infos.0.iter().any(|recipient_info| {
+            matches!(
+                recipient_info,
+                RecipientInfo::Pwri(_) | RecipientInfo::Ori(_)
+            )
+        });
+        let all_recipient_infos_are_v0 = recipient_infos.0.iter().all(|recipient_info| {
+            matches!(recipient_info, RecipientInfo::Ktri(ktri) if ktri.version == CmsVersion::V0)
+        });
+
+        if other_certificates_or_crls_are_present {
+            CmsVersion::V4
+        } else if pwri_or_ori_are_present {
+            CmsVersion::V3
+        } else if self.originator_info.is_none()
+            && !unprotected_attributes_present
+            && all_recipient_infos_are_v0
+        {
+            CmsVersion::V0
+        } else {
+            CmsVersion::V2
+        }
+    }
+}
+
+/// Encrypt the content-encryption key for each recipient.
+fn build_recipient_infos(
+    recipient_infos: &mut [Box<dyn RecipientInfoBuilder + '_>],
+    content_encryption_key: &[u8],
+) -> Result<RecipientInfos> {
+    let recipient_infos = recipient_infos
+        .iter_mut()
+        .map(|recipient_info| recipient_info.build(content_encryption_key))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(RecipientInfos::try_from(recipient_infos)?)
+}
+
+/// Authenticated content encryption algorithms supported by [`AuthEnvelopedDataBuilder`]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AuthContentEncryptionAlgorithm {
+    /// AES-128 in GCM mode
+    Aes128Gcm,
+    /// AES-192 in GCM mode
+    Aes192Gcm,
+    /// AES-256 in GCM mode
+    Aes256Gcm,
+}
+
+impl AuthContentEncryptionAlgorithm {
+    /// Return the OID of the algorithm.
+    pub fn oid(&self) -> ObjectIdentifier {
+        match self {
+            AuthContentEncryptionAlgorithm::Aes128Gcm => const_oid::db::rfc5911::ID_AES_128_GCM,
+            AuthContentEncryptionAlgorithm::Aes192Gcm => const_oid::db::rfc5911::ID_AES_192_GCM,
+            AuthContentEncryptionAlgorithm::Aes256Gcm => const_oid::db::rfc5911::ID_AES_256_GCM,
+        }
+    }
+
+    /// Return the size of the content-encryption key in bytes.
+    pub fn key_size(&self) -> usize {
+        match self {
+            AuthContentEncryptionAlgorithm::Aes128Gcm => 16,
+            AuthContentEncryptionAlgorithm::Aes192Gcm => 24,
+            AuthContentEncryptionAlgorithm::Aes256Gcm => 32,
+        }
+    }
+
+    /// Encrypt `content` with `key` and a random nonce, authenticating `aad`. Returns the
+    /// algorithm identifier (containing the nonce as parameters), the encrypted content and the
+    /// message authentication code.
+    fn encrypt(
+        &self,
+        key: &[u8],
+        content: &[u8],
+        aad: &[u8],
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<(AlgorithmIdentifierOwned, Vec<u8>, Vec<u8>)> {
+        let mut nonce = [0u8; gcm::NONCE_SIZE];
+        rng.fill_bytes(&mut nonce);
+
+        let (encrypted_content, mac) = gcm::encrypt(key, &nonce, aad, content)
+            .ok_or_else(|| Error::Builder(String::from("invalid content-encryption key")))?;
+
+        // Use the full 16-octet ICV rather than the default of 12 octets.
+        let parameters = GcmParameters {
+            nonce: OctetString::new(nonce)?,
+            icv_len: mac.len() as u8,
+        };
+        let algorithm = AlgorithmIdentifierOwned {
+            oid: self.oid(),
+            parameters: Some(Any::encode_from(&parameters)?),
+        };
+
+        Ok((algorithm, encrypted_content, mac.to_vec()))
+    }
+}
+
+/// Builder for authEnvelopedData ([RFC 5083](https://datatracker.ietf.org/doc/html/rfc5083))
+/// Calling `build_with_rng()` on this struct will
+/// - generate a random content-encryption key
+/// - encrypt the content and authenticate it together with the authenticated attributes
+/// - encrypt the content-encryption key for each recipient
+/// - create a `ContentInfo` of type `authEnvelopedData`
+pub struct AuthEnvelopedDataBuilder<'c> {
+    originator_info: Option<OriginatorInfo>,
+    recipient_infos: Vec<Box<dyn RecipientInfoBuilder + 'c>>,
+    unencrypted_content: &'c [u8],
+    content_encryption_algorithm: AuthContentEncryptionAlgorithm,
+    authenticated_attributes: Option<Vec<Attribute>>,
+    unauthenticated_attributes: Option<Vec<Attribute>>,
+}
+
+impl<'c> AuthEnvelopedDataBuilder<'c> {
+    /// Create a new builder for `AuthEnvelopedData`
+    pub fn new(
+        unencrypted_content: &'c [u8],
+        content_encryption_algorithm: AuthContentEncryptionAlgorithm,
+    ) -> AuthEnvelopedDataBuilder<'c> {
+        Self {
+            originator_info: None,
+            recipient_infos: Vec::new(),
+            unencrypted_content,
+            content_encryption_algorithm,
+            authenticated_attributes: None,
+            unauthenticated_attributes: None,
+        }
+    }
+
+    /// Set the originator info.
+    /// RFC 5083 § 2.1: originatorInfo optionally provides information about the
+    /// originator.  It is present only if required by the key management
+    /// algorithm.  It may contain certificates and CRLs.
+    pub fn set_originator_info(&mut self, originator_info: OriginatorInfo) -> Result<&mut Self> {
+        self.originator_info = Some(originator_info);
+        Ok(self)
+    }
+
+    /// Add a recipient. The content-encryption key will be encrypted for the recipient, when
+    /// `build_with_rng()` is called.
+    pub fn add_recipient_info(
+        &mut self,
+        recipient_info_builder: impl RecipientInfoBuilder + 'c,
+    ) -> Result<&mut Self> {
+        self.recipient_infos.push(Box::new(recipient_info_builder));
+        Ok(self)
+    }
+
+    /// Add an authenticated attribute.
+    /// RFC 5083 § 2.1: authAttrs optionally contains the authenticated
+    /// attributes, which are integrity-protected but not encrypted.
+    pub fn add_authenticated_attribute(
+        &mut self,
+        authenticated_attribute: Attribute,
+    ) -> Result<&mut Self> {
+        if let Some(authenticated_attributes) = &mut self.authenticated_attributes {
+            authenticated_attributes.push(authenticated_attribute);
+        } else {
+            self.authenticated_attributes = Some(vec![authenticated_attribute]);
+        }
+        Ok(self)
+    }
+
+    /// Add an unauthenticated attribute.
+    pub fn add_unauthenticated_attribute(
+        &mut self,
+        unauthenticated_attribute: Attribute,
+    ) -> Result<&mut Self> {
+        if let Some(unauthenticated_attributes) = &mut self.unauthenticated_attributes {
+            unauthenticated_attributes.push(unauthenticated_attribute);
+        } else {
+            self.unauthenticated_attributes = Some(vec![unauthenticated_attribute]);
+        }
+        Ok(self)
+    }
+
+    /// This method returns a `ContentInfo` of type `authEnvelopedData`. `rng` is used to
+    /// generate the content-encryption key and nonce.
+    pub fn build_with_rng(&mut self, rng: &mut impl CryptoRngCore) -> Result<ContentInfo> {
+        if self.recipient_infos.is_empty() {
+            return Err(Error::Builder(String::from("no recipients")));
+        }
+
+        let auth_attrs = self
+            .authenticated_attributes
+            .as_ref()
+            .map(|authenticated_attributes| {
+                Attributes::try_from(authenticated_attributes.to_owned())
+            })
+            .transpose()?;
+        let unauth_attrs = self
+            .unauthenticated_attributes
+            .as_ref()
+            .map(|unauthenticated_attributes| {
+                Attributes::try_from(unauthenticated_attributes.to_owned())
+            })
+            .transpose()?;
+
+        // RFC 5083 § 2.2: the authenticated attributes are DER encoded with an EXPLICIT
+        // SET OF tag, rather than the IMPLICIT [1] tag, and input to the authenticated
+        // encryption algorithm as additional authenticated data.
+        let aad = auth_attrs
+            .as_ref()
+            .map(|auth_attrs| auth_attrs.to_der())
+            .transpose()?
+            .unwrap_or_default();
+
+        let mut content_encryption_key =
+            Zeroizing::new(vec![0u8; self.content_encryption_algorithm.key_size()]);
+        rng.fill_bytes(&mut content_encryption_key);
+
+        let (content_enc_alg, encrypted_content, mac) = self.content_encryption_algorithm.encrypt(
+            &content_encryption_key,
+            self.unencrypted_content,
+            &aad,
+            rng,
+        )?;
+        let recip_infos = build_recipient_infos(&mut self.recipient_infos, &content_encryption_key);
+
+        let auth_enveloped_data = AuthEnvelopedData {
+            // RFC 5083 § 2.1: version is the syntax version number.  It MUST be set to 0.
+            version: CmsVersion::V0,
+            originator_info: self.originator_info.clone(),
+            recip_infos: recip_infos?,
+            auth_encrypted_content: EncryptedContentInfo {
+                content_type: const_oid::db::rfc5911::ID_DATA,
+                content_enc_alg,
+                encrypted_content: Some(OctetString::new(encrypted_content)?),
+            },
+            auth_attrs,
+            mac: OctetString::new(mac)?,
+            unauth_attrs,
+        };
+
+        Ok(ContentInfo {
+            content_type: const_oid::db::rfc5911::ID_CT_AUTH_ENVELOPED_DATA,
+            content: Any::encode_from(&auth_enveloped_data)?,
+        })
+    }
+}
+
+/// Message authentication code algorithms supported by [`AuthenticatedDataBuilder`]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MacAlgorithm {
+    /// HMAC with SHA-256
+    HmacWithSha256,
+    /// HMAC with SHA-384
+    HmacWithSha384,
+    /// HMAC with SHA-512
+    HmacWithSha512,
+}
+
+impl MacAlgorithm {
+    /// Return the OID of the algorithm.
+    pub fn oid(&self) -> ObjectIdentifier {
+        match self {
+            MacAlgorithm::HmacWithSha256 => const_oid::db::rfc6268::ID_HMAC_WITH_SHA_256,
+            MacAlgorithm::HmacWithSha384 => const_oid::db::rfc6268::ID_HMAC_WITH_SHA_384,
+            MacAlgorithm::HmacWithSha512 => const_oid::db::rfc6268::ID_HMAC_WITH_SHA_512,
+        }
+    }
+
+    /// Return the size of the authentication key in bytes.
+    pub fn key_size(&self) -> usize {
+        match self {
+            MacAlgorithm::HmacWithSha256 => 32,
+            MacAlgorithm::HmacWithSha384 => 48,
+            MacAlgorithm::HmacWithSha512 => 64,
+        }
+    }
+}
+
+/// Builder for authenticatedData according to
+/// [RFC 5652 § 9](https://datatracker.ietf.org/doc/html/rfc5652#section-9)
+/// Calling `build_with_rng()` on this struct will
+/// - generate a random authentication key
+/// - add the message digest and content type attributes, if a digest algorithm is set
+/// - calculate the message authentication code over the authenticated attributes, or the
+///   content if there are none
+/// - encrypt the authentication key for each recipient
+/// - create a `ContentInfo` of type `authenticatedData`
+pub struct AuthenticatedDataBuilder<'c> {
+    originator_info: Option<OriginatorInfo>,
+    recipient_infos: Vec<Box<dyn RecipientInfoBuilder + 'c>>,
+    encapsulated_content_info: &'c EncapsulatedContentInfo,
+    mac_algorithm: MacAlgorithm,
+    digest_algorithm: Option<AlgorithmIdentifierOwned>,
+    authenticated_attributes: Option<Vec<Attribute>>,
+    unauthenticated_attributes: Option<Vec<Attribute>>,
+}
+
+impl<'c> AuthenticatedDataBuilder<'c> {
+    /// Create a new builder for `AuthenticatedData`. The content to be authenticated must be
+    /// encapsulated in `encapsulated_content_info`.
+    pub fn new(
+        encapsulated_content_info: &'c EncapsulatedContentInfo,
+        mac_algorithm: MacAlgorithm,
+    ) -> AuthenticatedDataBuilder<'c> {
+        Self {
+            originator_info: None,
+            recipient_infos: Vec::new(),
+            encapsulated_content_info,
+            mac_algorithm,
+            digest_algorithm: None,
+            authenticated_attributes: None,
+            unauthenticated_attributes: None,
+        }
+    }
+
+    /// Set the originator info.
+    /// RFC 5652 § 9.1: originatorInfo optionally provides information about the
+    /// originator.  It is present only if required by the key management
+    /// algorithm.  It MAY contain certificates, attribute certificates, and
+    /// CRLs.
+    pub fn set_originator_info(&mut self, originator_info: OriginatorInfo) -> Result<&mut Self> {
+        self.originator_info = Some(originator_info);
+        Ok(self)
+    }
+
+    /// Add a recipient. The authentication key will be encrypted for the recipient, when
+    /// `build_with_rng()` is called.
+    pub fn add_recipient_info(
+        &mut self,
+        recipient_info_builder: impl RecipientInfoBuilder + 'c,
+    ) -> Result<&mut Self> {
+        self.recipient_infos.push(Box::new(recipient_info_builder));
+        Ok(self)
+    }
+
+    /// Set the digest algorithm. If set, the message digest of the content is included in the
+    /// authenticated attributes, which are then authenticated instead of the content itself.
+    /// RFC 5652 § 9.1: If the digestAlgorithm field is present, then the authAttrs
+    /// field MUST also be present.
+    pub fn set_digest_algorithm(
+        &mut self,
+        digest_algorithm: AlgorithmIdentifierOwned,
+    ) -> Result<&mut Self> {
+        self.digest_algorithm = Some(digest_algorithm);
+        Ok(self)
+    }
+
+    /// Add an authenticated attribute. Authenticated attributes require a digest algorithm,
+    /// see [`Self::set_digest_algorithm`].
+    pub fn add_authenticated_attribute(
+        &mut self,
+        authenticated_attribute: Attribute,
+    ) -> Result<&mut Self> {
+        if let Some(authenticated_attributes) = &mut self.authenticated_attributes {
+            authenticated_attributes.push(authenticated_attribute);
+        } else {
+            self.authenticated_attributes = Some(vec![authenticated_attribute]);
+        }
+        Ok(self)
+    }
+
+    /// Add an unauthenticated attribute.
+    pub fn add_unauthenticated_attribute(
+        &mut self,
+        unauthenticated_attribute: Attribute,
+    ) -> Result<&mut Self> {
+        if let Some(unauthenticated_attributes) = &mut self.unauthenticated_attributes {
+            unauthenticated_attributes.push(unauthenticated_attribute);
+        } else {
+            self.unauthenticated_attributes = Some(vec![unauthenticated_attribute]);
+        }
+        Ok(self)
+    }
+
+    /// This method returns a `ContentInfo` of type `authenticatedData`. `rng` is used to
+    /// generate the authentication key.
+    pub fn build_with_rng(&mut self, rng: &mut impl CryptoRngCore) -> Result<ContentInfo> {
+        if self.recipient_infos.is_empty() {
+            return Err(Error::Builder(String::from("no recipients")));
+        }
+
+        let content = self
+            .encapsulated_content_info
+            .econtent
+            .as_ref()
+            .ok_or_else(|| Error::Builder(String::from("content missing")))?;
+
+        let auth_attrs = match &self.digest_algorithm {
+            Some(digest_algorithm) => {
+                // RFC 5652 § 9.2: the authAttrs MUST include the content-type attribute
+                // and the message-digest attribute.
+                let mut authenticated_attributes =
+                    self.authenticated_attributes.clone().unwrap_or_default();
+                if authenticated_attributes.iter().any(|attribute| {
+                    attribute.oid == const_oid::db::rfc5911::ID_CONTENT_TYPE
+                        || attribute.oid == const_oid::db::rfc5911::ID_MESSAGE_DIGEST
+                }) {
+                    return Err(Error::Builder(String::from(
+                        "content type and message digest attributes are added automatically",
+                    )));
+                }
+
+                // Only the octets comprising the value of the eContent OCTET STRING are
+                // input to the message digest algorithm.
+                let message_digest = create_message_digest(digest_algorithm, content.value())?;
+                authenticated_attributes.push(create_content_type_attribute(
+                    self.encapsulated_content_info.econtent_type,
+                )?);
+                authenticated_attributes.push(create_message_digest_attribute(&message_digest)?);
+                Some(Attributes::try_from(authenticated_attributes)?)
+            }
+            None if self.authenticated_attributes.is_some() => {
+                return Err(Error::Builder(String::from(
+                    "authenticated attributes require a digest algorithm",
+                )));
+            }
+            None => None,
+        };
+        let unauth_attrs = self
+            .unauthenticated_attributes
+            .as_ref()
+            .map(|unauthenticated_attributes| {
+                Attributes::try_from(unauthenticated_attributes.to_owned())
+            })
+            .transpose()?;
+
+        // RFC 5652 § 9.2: If authAttrs are present, the MAC is calculated over their DER
+        // encoding with an EXPLICIT SET OF tag, otherwise over the value of the eContent OCTET
+        // STRING.
+        let mac_input = match &auth_attrs {
+            Some(auth_attrs) => auth_attrs.to_der()?,
+            None => content.value().to_vec(),
+        };
+
+        let mut authentication_key = Zeroizing::new(vec![0u8; self.mac_algorithm.key_size()]);
+        rng.fill_bytes(&mut authentication_key);
+
+        let mac = mac::compute(self.mac_algorithm.oid(), &authentication_key, &mac_input);
+        let recip_infos = build_recipient_infos(&mut self.recipient_infos, &authentication_key);
+        let mac = mac.ok_or_else(|| Error::Builder(String::from("invalid authentication key")))?;
+
+        let authenticated_data = AuthenticatedData {
+            version: self.calculate_version(),
+            originator_info: self.originator_info.clone(),
+            recip_infos: recip_infos?,
+            // RFC 4231 § 3.1: the parameters field MUST be absent
+            mac_alg: AlgorithmIdentifierOwned {
+                oid: self.mac_algorithm.oid(),
+                parameters: None,
+            },
+            digest_alg: self.digest_algorithm.clone(),
+            encap_content_info: self.encapsulated_content_info.clone(),
+            auth_attrs,
+            mac: OctetString::new(mac)?,
+            unauth_attrs,
+        };
+
+        Ok(ContentInfo {
+            content_type: const_oid::db::rfc5911::ID_CT_AUTH_DATA,
+            content: Any::encode_from(&authenticated_data)?,
+        })
+    }
+
+    fn calculate_version(&self) -> CmsVersion {
+        // RFC 5652, 9.1.  AuthenticatedData Type
+        // IF ((originatorInfo is present) AND
+        //    ((any certificates with a type of other are present) OR
+        //    (any crls with a type of other are present)))
+        // THEN version is 3
+        // ELSE
+        //    IF ((originatorInfo is present) AND
+        //       (any version 2 attribute certificates are present))
+        //    THEN version is 1
+        //    ELSE version is 0
+        let other_certificates_or_crls_are_present =
+            self.originator_info
+                .as_ref()
+                .map_or(false, |originator_info| {
+                    let other_certificates =
+                        originator_info.certs.as_ref().map_or(false, |certs| {
+                            certs.0.iter().any(|certificate| {
+                                matches!(certificate, CertificateChoices::Other(_))
+                            })
+                        });
+                    let other_crls = originator_info.crls.as_ref().map_or(false, |crls| {
+                        crls.0
+                            .iter()
+                            .any(|crl| matches!(crl, RevocationInfoChoice::Other(_)))
+                    });
+                    other_certificates || other_crls
+                });
+        // v2 attribute certificates currently not supported
+
+        if other_certificates_or_crls_are_present {
+            CmsVersion::V3
+        } else {
+            CmsVersion::V0
+        }
+    }
+}
+
+/// Builder for digestedData according to
+/// [RFC 5652 § 7](https://datatracker.ietf.org/doc/html/rfc5652#section-7)
+/// Calling `build()` on this struct will
+/// - calculate the message digest of the encapsulated content
+/// - create a `ContentInfo` of type `digestedData`
+pub struct DigestedDataBuilder<'c> {
+    digest_algorithm: AlgorithmIdentifierOwned,
+    encapsulated_content_info: &'c EncapsulatedContentInfo,
+}
+
+impl<'c> DigestedDataBuilder<'c> {
+    /// Create a new builder for `DigestedData`. The content to be digested must be
+    /// encapsulated in `encapsulated_content_info`.
+    pub fn new(
+        digest_algorithm: AlgorithmIdentifierOwned,
+        encapsulated_content_info: &'c EncapsulatedContentInfo,
+    ) -> DigestedDataBuilder<'c> {
+        Self {
+            digest_algorithm,
+            encapsulated_content_info,
+        }
+    }
+
+    /// This method returns a `ContentInfo` of type `digestedData`.
+    pub fn build(&self) -> Result<ContentInfo> {
+        let content = self
+            .encapsulated_content_info
+            .econtent
+            .as_ref()
+            .ok_or_else(|| Error::Builder(String::from("content missing")))?;
+
+        // Only the octets comprising the value of the eContent OCTET STRING are input to the
+        // message digest algorithm.
+        let digest = create_message_digest(&self.digest_algorithm, content.value())?;
+
+        let digested_data = DigestedData {
+            // RFC 5652 § 7: If the encapsulated content type is id-data, then the
+            // value of version MUST be 0; however, if the encapsulated content type
+            // is other than id-data, then the value of version MUST be 2.
+            version: if self.encapsulated_content_info.econtent_type
+                == const_oid::db::rfc5911::ID_DATA
+            {
+                CmsVersion::V0
+            } else {
+                CmsVersion::V2
+            },
+            digest_alg: self.digest_algorithm.clone(),
+            encap_content_info: self.encapsulated_content_info.clone(),
+            digest: OctetString::new(digest)?,
+        };
+
+        Ok(ContentInfo {
+            content_type: const_oid::db::rfc5911::ID_DIGESTED_DATA,
+            content: Any::encode_from(&digested_data)?,
+        })
+    }
+}
+
+/// Builder for encryptedData according to
+/// [RFC 5652 § 8](https://datatracker.ietf.org/doc/html/rfc5652#section-8)
+/// The content is encrypted using PBES2 ([RFC 8018 § 6.2](https://datatracker.ietf.org/doc/html/rfc8018#section-6.2))
+/// with a key derived from a password, as used e.g. by PKCS #12.
+/// Calling `build()` on this struct will
+/// - derive the content-encryption key from the password
+/// - encrypt the content
+/// - create a `ContentInfo` of type `encryptedData`
+pub struct EncryptedDataBuilder<'c> {
+    unencrypted_content: &'c [u8],
+    encryption_scheme: pkcs5::pbes2::Parameters<'c>,
+    unprotected_attributes: Option<Vec<Attribute>>,
+}
+
+impl<'c> EncryptedDataBuilder<'c> {
+    /// Create a new builder for `EncryptedData`. The salt and IV contained in
+    /// `encryption_scheme` should be generated randomly for each message.
+    pub fn new(
+        unencrypted_content: &'c [u8],
+        encryption_scheme: pkcs5::pbes2::Parameters<'c>,
+    ) -> EncryptedDataBuilder<'c> {
+        Self {
+            unencrypted_content,
+            encryption_scheme,
+            unprotected_attributes: None,
+        }
+    }
+
+    /// Add an unprotected attribute.
+    pub fn add_unprotected_attribute(
+        &mut self,
+        unprotected_attribute: Attribute,
+    ) -> Result<&mut Self> {
+        if let Some(unprotected_attributes) = &mut self.unprotected_attributes {
+            unprotected_attributes.push(unprotected_attribute);
+        } else {
+            self.unprotected_attributes = Some(vec![unprotected_attribute]);
+        }
+        Ok(self)
+    }
+
+    /// This method returns a `ContentInfo` of type `encryptedData`, with the content encrypted
+    /// using a key derived from `password`.
+    pub fn build(&self, password: impl AsRef<[u8]>) -> Result<ContentInfo> {
+        let encrypted_content = self
+            .encryption_scheme
+            .encrypt(password, self.unencrypted_content)
+            .map_err(|err| Error::Builder(format!("PBES2 encryption failed: {}", err)))?;
+
+        let unprotected_attrs = self
+            .unprotected_attributes
+            .as_ref()
+            .map(|unprotected_attributes| Attributes::try_from(unprotected_attributes.to_owned()))
+            .transpose()?;
+
+        let encrypted_data = EncryptedData {
+            // RFC 5652 § 8: If unprotectedAttrs is present, then the version MUST be 2.
+            // If unprotectedAttrs is absent, then version MUST be 0.
+            version: if unprotected_attrs.is_some() {
+                CmsVersion::V2
+            } else {
+                CmsVersion::V0
+            },
+            enc_content_info: EncryptedContentInfo {
+                content_type: const_oid::db::rfc5911::ID_DATA,
+                content_enc_alg: AlgorithmIdentifierOwned {
+                    oid: pkcs5::pbes2::PBES2_OID,
+                    parameters: Some(Any::encode_from(&self.encryption_scheme)?),
+                },
+                encrypted_content: Some(OctetString::new(encrypted_content)?),
+            },
+            unprotected_attrs,
+        };
+
+        Ok(ContentInfo {
+            content_type: const_oid::db::rfc5911::ID_ENCRYPTED_DATA,
+            content: Any::encode_from(&encrypted_data)?,
+        })
+    }
+}
+
+/// Create an `EncapsulatedContentInfo` of type `id-data` according to
+/// [RFC 5652 § 5.2](https://datatracker.ietf.org/doc/html/rfc5652#section-5.2)
+/// If `content` is `None`, `eContent` is omitted, i.e. the content is external and the signature
+/// detached. In this case the message digest of the content must be passed to
+/// [`SignerInfoBuilder::new`] as `external_message_digest`, see [`create_message_digest`].
+pub fn create_data_content(content: Option<&[u8]>) -> Result<EncapsulatedContentInfo> {
+    let econtent = content
+        .map(|content| Any::new(Tag::OctetString, content))
+        .transpose()?;
+    Ok(EncapsulatedContentInfo {
+        econtent_type: const_oid::db::rfc5911::ID_DATA,
+        econtent,
+    })
+}
+
+/// Calculate the message digest of `content` using the given digest algorithm according to
+/// [RFC 5652 § 5.4](https://datatracker.ietf.org/doc/html/rfc5652#section-5.4)
+/// For encapsulated content, only the value of the `eContent` OCTET STRING is digested.
+pub fn create_message_digest(
+    digest_algorithm: &AlgorithmIdentifierOwned,
+    content: &[u8],
+) -> Result<Vec<u8>> {
+    let mut hasher = get_hasher(digest_algorithm).ok_or_else(|| {
+        Error::Builder(format!(
+            "unsupported digest algorithm: {}",
+            digest_algorithm.oid
+        ))
+    })?;
+    hasher.update(content);
+    Ok(hasher.finalize_reset().to_vec())
+}
+
+/// Incremental calculation of a message digest, for content which is too large to be kept in
+/// memory. Feed the content using `update()` or [`std::io::Write`], then pass the result of
+/// `finalize()` to [`SignerInfoBuilder::new`] as `external_message_digest`.
+pub struct MessageDigestCalculator {
+    hasher: Box<dyn DynDigest>,
+}
+
+impl MessageDigestCalculator {
+    /// Create a new calculator using the given digest algorithm.
+    pub fn new(digest_algorithm: &AlgorithmIdentifierOwned) -> Result<Self> {
+        let hasher = get_hasher(digest_algorithm).ok_or_else(|| {
+            Error::Builder(format!(
+                "unsupported digest algorithm: {}",
+                digest_algorithm.oid
+            ))
+        })?;
+        Ok(Self { hasher })
+    }
+
+    /// Digest the next chunk of the content.
+    pub fn update(&mut self, chunk: &[u8]) -> &mut Self {
+        self.hasher.update(chunk);
+        self
+    }
+
+    /// Return the message digest of the content.
+    pub fn finalize(mut self) -> Vec<u8> {
+        self.hasher.finalize_reset().to_vec()
+    }
+}
+
+impl std::io::Write for MessageDigestCalculator {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.hasher.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Length of a DER TLV with a single-byte tag and a value of `len` bytes.
+fn tlv_len(len: u64) -> u64 {
+    let length_len = match len {
+        0..=0x7f => 1,
+        _ => 1 + (u64::BITS - len.leading_zeros() + 7) as u64 / 8,
+    };
+    1 + length_len + len
+}
+
+/// Write a DER header, using the long form for lengths beyond [`der::Length::MAX`].
+fn write_header(writer: &mut impl std::io::Write, tag: Tag, len: u64) -> std::io::Result<()> {
+    writer.write_all(&[tag.octet()])?;
+    if len <= 0x7f {
+        return writer.write_all(&[len as u8]);
+    }
+    let len_bytes = len.to_be_bytes();
+    let skip = len.leading_zeros() as usize / 8;
+    writer.write_all(&[0x80 | (len_bytes.len() - skip) as u8])?;
+    writer.write_all(&len_bytes[skip..])
+}
+
+/// Create a content-type attribute according to
+/// [RFC 5652 § 11.1](https://datatracker.ietf.org/doc/html/rfc5652#section-11.1)
+pub fn create_content_type_attribute(content_type: ObjectIdentifier) -> Result<Attribute> {
+    let content_type_attribute_value =
+        AttributeValue::new(Tag::ObjectIdentifier, content_type.as_bytes())?;
+    let mut values = SetOfVec::new();
+    values.insert(content_type_attribute_value)?;
+    let attribute = Attribute {
+        oid: const_oid::db::rfc5911::ID_CONTENT_TYPE,
+        values,
+    };
+    Ok(attribute)
+}
+
+/// Create a message digest attribute according to
+/// [RFC 5652 § 11.2](https://datatracker.ietf.org/doc/html/rfc5652#section-11.2)
+pub fn create_message_digest_attribute(message_digest: &[u8]) -> Result<Attribute> {
+    let message_digest_der = OctetStringRef::new(message_digest)?;
+    let message_digest_attribute_value =
+        AttributeValue::new(Tag::OctetString, message_digest_der.as_bytes())?;
+    let mut values = SetOfVec::new();
+    values.insert(message_digest_attribute_value)?;
+    let attribute = Attribute {
+        oid: const_oid::db::rfc5911::ID_MESSAGE_DIGEST,
+        values,
+    };
+    Ok(attribute)
+}
+
+/// Add `countersignature` to the countersignature attribute in the unsigned attributes of
+/// `signer_info` according to
+/// [RFC 5652 § 11.4](https://datatracker.ietf.org/doc/html/rfc5652#section-11.4)
+/// The countersignature attribute is created if it doesn't exist yet, otherwise the
+/// countersignature is added as an additional attribute value.
+pub fn add_countersignature(
+    signer_info: &mut SignerInfo,
+    countersignature: &Countersignature,
+) -> Result<()> {
+    let value = AttributeValue::encode_from(countersignature)?;
+    let mut unsigned_attributes = signer_info
+        .unsigned_attrs
+        .take()
+        .map(|unsigned_attributes| unsigned_attributes.into_vec())
+        .unwrap_or_default();
+
+    match unsigned_attributes
+        .iter_mut()
+        .find(|attribute| attribute.oid == const_oid::db::rfc5911::ID_COUNTERSIGNATURE)
+    {
+        Some(attribute) => attribute.values.insert(value)?,
+        None => {
+            let mut values = SetOfVec::new();
+            values.insert(value)?;
+            unsigned_attributes.push(Attribute {
+                oid: const_oid::db::rfc5911::ID_COUNTERSIGNATURE,
+                values,
+            });
+        }
+    }
+
+    signer_info.unsigned_attrs = Some(UnsignedAttributes::try_from(unsigned_attributes)?);
+    Ok(())
+}
+
+/// Create a signing time attribute with the current time according to
+/// [RFC 5652 § 11.3](https://datatracker.ietf.org/doc/html/rfc5652#section-11.3)
+pub fn create_signing_time_attribute() -> Result<Attribute> {
+    create_signing_time_attribute_at(SystemTime::now())
+}
+
+/// Create a signing time attribute according to
+/// [RFC 5652 § 11.3](https://datatracker.ietf.org/doc/html/rfc5652#section-11.3)
+/// Dates between 1 January 1950 and 31 December 2049 (inclusive) MUST be
+/// encoded as UTCTime.  Any dates with year values before 1950 or after
+/// 2049 MUST be encoded as GeneralizedTime.
+pub fn create_signing_time_attribute_at(signing_time: SystemTime) -> Result<Attribute> {
+    let date_time = DateTime::from_system_time(signing_time)?;
+    let time_der = if date_time.year() < 1950 || date_time.year() > 2049 {
+        der::asn1::GeneralizedTime::from_date_time(date_time).to_der()?
+    } else {
+        der::asn1::UtcTime::from_date_time(date_time)?.to_der()?
+    };
+    let signing_time_attribute_value = AttributeValue::from_der(&time_der)?;
+    let mut values = SetOfVec::<AttributeValue>::new();
+    values.insert(signing_time_attribute_value)?;
+    let attribute = Attribute {
+        oid: const_oid::db::rfc5911::ID_SIGNING_TIME,
+        values,
+    };
+    Ok(attribute)
+}
+
+/// Create a signing certificate v2 attribute identifying the signer `certificate` by its hash
+/// and its issuer and serial number according to
+/// [RFC 5035 § 3](https://datatracker.ietf.org/doc/html/rfc5035#section-3), as required by
+/// CAdES. The hash algorithm is omitted from the attribute if it is the default, SHA-256.
+pub fn create_signing_certificate_v2_attribute(
+    certificate: &Certificate,
+    hash_algorithm: &AlgorithmIdentifierOwned,
+) -> Result<Attribute> {
+    let cert_hash = create_message_digest(hash_algorithm, &certificate.to_der()?)?;
+    let is_default_hash_algorithm = hash_algorithm.oid == const_oid::db::rfc5912::ID_SHA_256
+        && hash_algorithm.parameters.is_none();
+    let signing_certificate = SigningCertificateV2 {
+        certs: vec![EssCertIdV2 {
+            hash_algorithm: (!is_default_hash_algorithm).then(|| hash_algorithm.clone()),
+            cert_hash: OctetString::new(cert_hash)?,
+            issuer_serial: Some(IssuerSerial::from_certificate(certificate)),
+        }],
+        policies: None,
+    };
+    Ok(signing_certificate.to_attribute()?)
+}
+
+/// Create a binary signing time attribute according to
+/// [RFC 6019 § 3](https://datatracker.ietf.org/doc/html/rfc6019#section-3)
+/// The time is encoded as the number of seconds since 1 January 1970 00:00:00 UTC, ignoring
+/// leap seconds.
+pub fn create_binary_signing_time_attribute(signing_time: SystemTime) -> Result<Attribute> {
+    let binary_time: BinarySigningTime = signing_time
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map_err(|_| der::Error::from(ErrorKind::DateTime))?
+        .as_secs();
+    let mut values = SetOfVec::<AttributeValue>::new();
+    values.insert(AttributeValue::encode_from(&binary_time)?)?;
+    let attribute = Attribute {
+        oid: const_oid::db::rfc6268::ID_AA_BINARY_SIGNING_TIME,
         values,
     };
     Ok(attribute)
@@ -0,0 +1,241 @@
+#![cfg(feature = "compress")]
+
+//! CMS CompressedData compression and decompression according to
+//! [RFC 3274](https://datatracker.ietf.org/doc/html/rfc3274), using zlib
+//! ([RFC 1950](https://datatracker.ietf.org/doc/html/rfc1950)).
+
+use crate::compressed_data::CompressedData;
+use crate::content_info::{CmsVersion, ContentInfo};
+use crate::signed_data::EncapsulatedContentInfo;
+use alloc::vec::Vec;
+use const_oid::ObjectIdentifier;
+use core::fmt;
+use der::asn1::OctetString;
+use der::{Any, Decode, Encode, Tag};
+use miniz_oxide::inflate::TINFLStatus;
+use spki::AlgorithmIdentifierOwned;
+
+/// Default compression level passed to zlib, between 0 (no compression) and 10 (best
+/// compression).
+const DEFAULT_COMPRESSION_LEVEL: u8 = 6;
+
+/// Default maximum size of decompressed content in bytes (16 MiB), see
+/// [`CompressedDataDecompressor::set_size_limit`].
+pub const DEFAULT_SIZE_LIMIT: usize = 16 * 1024 * 1024;
+
+/// Default maximum number of nested `CompressedData` layers removed by [`decompress_nested`].
+pub const DEFAULT_MAX_NESTING_DEPTH: usize = 4;
+
+/// Error type
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// ASN.1 DER-related errors.
+    Asn1(der::Error),
+
+    /// The `CompressedData` doesn't contain the compressed content.
+    ContentMissing,
+
+    /// The compression algorithm is not supported.
+    UnsupportedCompressionAlgorithm(ObjectIdentifier),
+
+    /// The decompressed content exceeds the size limit.
+    SizeLimitExceeded,
+
+    /// The content is nested in more `CompressedData` layers than allowed.
+    NestingDepthExceeded,
+
+    /// The compressed content is malformed.
+    Decompression,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Asn1(err) => write!(f, "ASN.1 error: {}", err),
+            Error::ContentMissing => write!(f, "compressed content missing"),
+            Error::UnsupportedCompressionAlgorithm(oid) => {
+                write!(f, "unsupported compression algorithm: {}", oid)
+            }
+            Error::SizeLimitExceeded => write!(f, "decompressed content exceeds size limit"),
+            Error::NestingDepthExceeded => write!(f, "compressed content nested too deeply"),
+            Error::Decompression => write!(f, "decompression failed"),
+        }
+    }
+}
+
+impl From<der::Error> for Error {
+    fn from(err: der::Error) -> Error {
+        Error::Asn1(err)
+    }
+}
+
+type Result<T> = core::result::Result<T, Error>;
+
+/// Builder for compressedData
+/// ([RFC 3274 § 1.1](https://datatracker.ietf.org/doc/html/rfc3274#section-1.1))
+/// Calling `build()` on this struct will
+/// - compress the encapsulated content using zlib
+/// - create a `ContentInfo` of type `compressedData`
+pub struct CompressedDataBuilder<'c> {
+    encapsulated_content_info: &'c EncapsulatedContentInfo,
+    compression_level: u8,
+}
+
+impl<'c> CompressedDataBuilder<'c> {
+    /// Create a new builder for `CompressedData`. The content to be compressed must be
+    /// encapsulated in `encapsulated_content_info`, see [`create_encapsulated_content`].
+    pub fn new(
+        encapsulated_content_info: &'c EncapsulatedContentInfo,
+    ) -> CompressedDataBuilder<'c> {
+        Self {
+            encapsulated_content_info,
+            compression_level: DEFAULT_COMPRESSION_LEVEL,
+        }
+    }
+
+    /// Set the zlib compression level, between 0 (no compression) and 10 (best compression).
+    pub fn set_compression_level(&mut self, compression_level: u8) -> &mut Self {
+        self.compression_level = compression_level;
+        self
+    }
+
+    /// This method returns a `ContentInfo` of type `compressedData`.
+    pub fn build(&self) -> Result<ContentInfo> {
+        let content = self
+            .encapsulated_content_info
+            .econtent
+            .as_ref()
+            .ok_or(Error::ContentMissing)?;
+        let compressed_content =
+            miniz_oxide::deflate::compress_to_vec_zlib(content.value(), self.compression_level);
+
+        let compressed_data = CompressedData {
+            // RFC 3274 § 1.1: version is the syntax version number.  It MUST be 0.
+            version: CmsVersion::V0,
+            // RFC 3274 § 2: The AlgorithmIdentifier parameters field MUST be absent.
+            compression_alg: AlgorithmIdentifierOwned {
+                oid: const_oid::db::rfc6268::ID_ALG_ZLIB_COMPRESS,
+                parameters: None,
+            },
+            encap_content_info: EncapsulatedContentInfo {
+                econtent_type: self.encapsulated_content_info.econtent_type,
+                econtent: Some(Any::new(Tag::OctetString, compressed_content)?),
+            },
+        };
+
+        Ok(ContentInfo {
+            content_type: const_oid::db::rfc6268::ID_CT_COMPRESSED_DATA,
+            content: Any::encode_from(&compressed_data)?,
+        })
+    }
+}
+
+/// Decompressor for compressedData
+/// ([RFC 3274 § 1.1](https://datatracker.ietf.org/doc/html/rfc3274#section-1.1))
+///
+/// To protect against decompression bombs, the size of the decompressed content is limited to
+/// [`DEFAULT_SIZE_LIMIT`] unless changed using `set_size_limit()`.
+pub struct CompressedDataDecompressor<'a> {
+    compressed_data: &'a CompressedData,
+    size_limit: usize,
+}
+
+impl<'a> CompressedDataDecompressor<'a> {
+    /// Create a new decompressor for `CompressedData`.
+    pub fn new(compressed_data: &'a CompressedData) -> CompressedDataDecompressor<'a> {
+        Self {
+            compressed_data,
+            size_limit: DEFAULT_SIZE_LIMIT,
+        }
+    }
+
+    /// Set the maximum size of the decompressed content in bytes.
+    pub fn set_size_limit(&mut self, size_limit: usize) -> &mut Self {
+        self.size_limit = size_limit;
+        self
+    }
+
+    /// Decompress the encapsulated content.
+    pub fn decompress(&self) -> Result<Vec<u8>> {
+        let compression_alg = &self.compressed_data.compression_alg;
+        if compression_alg.oid != const_oid::db::rfc6268::ID_ALG_ZLIB_COMPRESS {
+            return Err(Error::UnsupportedCompressionAlgorithm(compression_alg.oid));
+        }
+
+        let compressed_content = self
+            .compressed_data
+            .encap_content_info
+            .econtent
+            .as_ref()
+            .ok_or(Error::ContentMissing)?;
+        miniz_oxide::inflate::decompress_to_vec_zlib_with_limit(
+            compressed_content.value(),
+            self.size_limit,
+        )
+        .map_err(|err| match err.status {
+            TINFLStatus::HasMoreOutput => Error::SizeLimitExceeded,
+            _ => Error::Decompression,
+        })
+    }
+
+    /// Decompress the encapsulated content and return it as a `ContentInfo` of the encapsulated
+    /// content type. This is the inverse of [`create_encapsulated_content`].
+    pub fn decompress_content_info(&self) -> Result<ContentInfo> {
+        let content_type = self.compressed_data.encap_content_info.econtent_type;
+        let content = self.decompress()?;
+        let content = if content_type == const_oid::db::rfc5911::ID_DATA {
+            Any::new(Tag::OctetString, content)?
+        } else {
+            Any::from_der(&content)?
+        };
+        Ok(ContentInfo {
+            content_type,
+            content,
+        })
+    }
+}
+
+/// Encapsulate the content of `content_info`, e.g. to compress another CMS content type with
+/// [`CompressedDataBuilder`]. The content of `id-data` is encapsulated as is, any other content
+/// type is encapsulated using its DER encoding.
+pub fn create_encapsulated_content(content_info: &ContentInfo) -> Result<EncapsulatedContentInfo> {
+    let econtent = if content_info.content_type == const_oid::db::rfc5911::ID_DATA {
+        content_info
+            .content
+            .decode_as::<OctetString>()?
+            .into_bytes()
+    } else {
+        content_info.content.to_der()?
+    };
+    Ok(EncapsulatedContentInfo {
+        econtent_type: content_info.content_type,
+        econtent: Some(Any::new(Tag::OctetString, econtent)?),
+    })
+}
+
+/// Decompress `content_info` as long as it is of type `compressedData`, and return the innermost
+/// `ContentInfo`. Content of other types is returned unchanged.
+///
+/// `size_limit` limits the size of each decompressed layer, see
+/// [`CompressedDataDecompressor::set_size_limit`]. At most `max_depth` layers are decompressed,
+/// e.g. [`DEFAULT_MAX_NESTING_DEPTH`], otherwise [`Error::NestingDepthExceeded`] is returned.
+pub fn decompress_nested(
+    mut content_info: ContentInfo,
+    size_limit: usize,
+    max_depth: usize,
+) -> Result<ContentInfo> {
+    let mut depth = 0;
+    while content_info.content_type == const_oid::db::rfc6268::ID_CT_COMPRESSED_DATA {
+        if depth == max_depth {
+            return Err(Error::NestingDepthExceeded);
+        }
+        depth += 1;
+
+        let compressed_data = content_info.content.decode_as::<CompressedData>()?;
+        content_info = CompressedDataDecompressor::new(&compressed_data)
+            .set_size_limit(size_limit)
+            .decompress_content_info()?;
+    }
+    Ok(content_info)
+}
@@ -1,4 +1,5 @@
 //! CompressedData-related types
+use const_oid::{AssociatedOid, ObjectIdentifier};
 use der::Sequence;
 use spki::AlgorithmIdentifierOwned;
 
@@ -23,3 +24,7 @@ pub struct CompressedData {
     pub compression_alg: AlgorithmIdentifierOwned,
     pub encap_content_info: EncapsulatedContentInfo,
 }
+
+impl AssociatedOid for CompressedData {
+    const OID: ObjectIdentifier = const_oid::db::rfc6268::ID_CT_COMPRESSED_DATA;
+}
@@ -1,13 +1,16 @@
 //! ContentInfo types
 
-use crate::cert::CertificateChoices;
-use crate::revocation::RevocationInfoChoices;
-use crate::signed_data::EncapsulatedContentInfo;
-use crate::signed_data::{CertificateSet, SignedData, SignerInfos};
+use crate::auth_enveloped_data::AuthEnvelopedData;
+use crate::authenticated_data::AuthenticatedData;
+use crate::compressed_data::CompressedData;
+use crate::digested_data::DigestedData;
+use crate::encrypted_data::EncryptedData;
+use crate::enveloped_data::EnvelopedData;
+use crate::signed_data::SignedData;
+use const_oid::AssociatedOid;
 use core::cmp::Ordering;
-use der::asn1::SetOfVec;
-use der::Encode;
-use der::{asn1::ObjectIdentifier, Any, AnyRef, Enumerated, Sequence, ValueOrd};
+use der::asn1::{ObjectIdentifier, OctetString};
+use der::{Any, Choice, DecodeValue, Enumerated, ErrorKind, Sequence, ValueOrd};
 use x509_cert::{Certificate, PkiPath};
 
 /// The `OtherCertificateFormat` type is defined in [RFC 5652 Section 10.2.5].
@@ -55,66 +58,132 @@ pub struct ContentInfo {
     pub content: Any,
 }
 
-/// Convert a Certificate to a certs-only SignedData message
-impl TryFrom<Certificate> for ContentInfo {
+impl ContentInfo {
+    /// Decode the content as `T`, checking that the `contentType` matches `T::OID`.
+    pub fn decode_content<'a, T>(&'a self) -> der::Result<T>
+    where
+        T: AssociatedOid + Choice<'a> + DecodeValue<'a>,
+    {
+        if self.content_type != T::OID {
+            return Err(ErrorKind::OidUnknown {
+                oid: self.content_type,
+            }
+            .into());
+        }
+        self.content.decode_as()
+    }
+
+    /// Decode the content as one of the standard CMS content types, dispatching on the
+    /// `contentType`.
+    pub fn decode_standard_content(&self) -> der::Result<StandardContent> {
+        Ok(match self.content_type {
+            const_oid::db::rfc5911::ID_DATA => StandardContent::Data(self.content.decode_as()?),
+            SignedData::OID => StandardContent::SignedData(self.content.decode_as()?),
+            EnvelopedData::OID => StandardContent::EnvelopedData(self.content.decode_as()?),
+            DigestedData::OID => StandardContent::DigestedData(self.content.decode_as()?),
+            EncryptedData::OID => StandardContent::EncryptedData(self.content.decode_as()?),
+            AuthenticatedData::OID => StandardContent::AuthenticatedData(self.content.decode_as()?),
+            AuthEnvelopedData::OID => StandardContent::AuthEnvelopedData(self.content.decode_as()?),
+            CompressedData::OID => StandardContent::CompressedData(self.content.decode_as()?),
+            oid => return Err(ErrorKind::OidUnknown { oid }.into()),
+        })
+    }
+}
+
+/// The content of a [`ContentInfo`] decoded as one of the content types defined in
+/// [RFC 5652], [RFC 5083] and [RFC 3274].
+///
+/// [RFC 5652]: https://www.rfc-editor.org/rfc/rfc5652
+/// [RFC 5083]: https://www.rfc-editor.org/rfc/rfc5083
+/// [RFC 3274]: https://www.rfc-editor.org/rfc/rfc3274
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[allow(clippy::large_enum_variant)]
+#[allow(missing_docs)]
+pub enum StandardContent {
+    Data(OctetString),
+    SignedData(SignedData),
+    EnvelopedData(EnvelopedData),
+    DigestedData(DigestedData),
+    EncryptedData(EncryptedData),
+    AuthenticatedData(AuthenticatedData),
+    AuthEnvelopedData(AuthEnvelopedData),
+    CompressedData(CompressedData),
+}
+
+impl StandardContent {
+    /// The `contentType` OID of this content.
+    pub fn content_type(&self) -> ObjectIdentifier {
+        match self {
+            StandardContent::Data(_) => const_oid::db::rfc5911::ID_DATA,
+            StandardContent::SignedData(_) => SignedData::OID,
+            StandardContent::EnvelopedData(_) => EnvelopedData::OID,
+            StandardContent::DigestedData(_) => DigestedData::OID,
+            StandardContent::EncryptedData(_) => EncryptedData::OID,
+            StandardContent::AuthenticatedData(_) => AuthenticatedData::OID,
+            StandardContent::AuthEnvelopedData(_) => AuthEnvelopedData::OID,
+            StandardContent::CompressedData(_) => CompressedData::OID,
+        }
+    }
+}
+
+/// Wrap standard content in a ContentInfo
+impl TryFrom<StandardContent> for ContentInfo {
     type Error = der::Error;
 
-    fn try_from(cert: Certificate) -> der::Result<Self> {
-        let mut certs = CertificateSet(Default::default());
-        certs.0.insert(CertificateChoices::Certificate(cert))?;
-
-        // include empty CRLs field instead of omitting it to match OpenSSL's behavior
-        let sd = SignedData {
-            version: CmsVersion::V1,
-            digest_algorithms: SetOfVec::default(),
-            encap_content_info: EncapsulatedContentInfo {
-                econtent_type: const_oid::db::rfc5911::ID_DATA,
-                econtent: None,
-            },
-            certificates: Some(certs),
-            crls: Some(RevocationInfoChoices(Default::default())),
-            signer_infos: SignerInfos(Default::default()),
+    fn try_from(content: StandardContent) -> der::Result<Self> {
+        let content_type = content.content_type();
+        let content = match &content {
+            StandardContent::Data(data) => Any::encode_from(data)?,
+            StandardContent::SignedData(signed_data) => Any::encode_from(signed_data)?,
+            StandardContent::EnvelopedData(enveloped_data) => Any::encode_from(enveloped_data)?,
+            StandardContent::DigestedData(digested_data) => Any::encode_from(digested_data)?,
+            StandardContent::EncryptedData(encrypted_data) => Any::encode_from(encrypted_data)?,
+            StandardContent::AuthenticatedData(authenticated_data) => {
+                Any::encode_from(authenticated_data)?
+            }
+            StandardContent::AuthEnvelopedData(auth_enveloped_data) => {
+                Any::encode_from(auth_enveloped_data)?
+            }
+            StandardContent::CompressedData(compressed_data) => Any::encode_from(compressed_data)?,
         };
+        Ok(ContentInfo {
+            content_type,
+            content,
+        })
+    }
+}
 
-        let signed_data = sd.to_der()?;
-        let content = AnyRef::try_from(signed_data.as_slice())?;
+#[cfg(feature = "pem")]
+impl der::pem::PemLabel for ContentInfo {
+    const PEM_LABEL: &'static str = der::pem::Label::Pkcs7.as_str();
+}
+
+/// Wrap a SignedData message in a ContentInfo
+impl TryFrom<SignedData> for ContentInfo {
+    type Error = der::Error;
 
+    fn try_from(signed_data: SignedData) -> der::Result<Self> {
         Ok(ContentInfo {
-            content_type: const_oid::db::rfc5911::ID_SIGNED_DATA,
-            content: Any::from(content),
+            content_type: SignedData::OID,
+            content: Any::encode_from(&signed_data)?,
         })
     }
 }
 
+/// Convert a Certificate to a certs-only SignedData message
+impl TryFrom<Certificate> for ContentInfo {
+    type Error = der::Error;
+
+    fn try_from(cert: Certificate) -> der::Result<Self> {
+        ContentInfo::try_from(SignedData::certs_only([cert])?)
+    }
+}
+
 /// Convert a vector of Certificates to a certs-only SignedData message
 impl TryFrom<PkiPath> for ContentInfo {
     type Error = der::Error;
 
     fn try_from(pki_path: PkiPath) -> der::Result<Self> {
-        let mut certs = CertificateSet(Default::default());
-        for cert in pki_path {
-            certs.0.insert(CertificateChoices::Certificate(cert))?;
-        }
-
-        // include empty CRLs field instead of omitting it to match OpenSSL's behavior
-        let sd = SignedData {
-            version: CmsVersion::V1,
-            digest_algorithms: SetOfVec::default(),
-            encap_content_info: EncapsulatedContentInfo {
-                econtent_type: const_oid::db::rfc5911::ID_DATA,
-                econtent: None,
-            },
-            certificates: Some(certs),
-            crls: Some(RevocationInfoChoices(Default::default())),
-            signer_infos: SignerInfos(Default::default()),
-        };
-
-        let signed_data = sd.to_der()?;
-        let content = AnyRef::try_from(signed_data.as_slice())?;
-
-        Ok(ContentInfo {
-            content_type: const_oid::db::rfc5911::ID_SIGNED_DATA,
-            content: Any::from(content),
-        })
+        ContentInfo::try_from(SignedData::certs_only(pki_path)?)
     }
 }
@@ -0,0 +1,912 @@
+#![cfg(feature = "decrypt")]
+
+//! CMS EnvelopedData, AuthenticatedData and EncryptedData decryption
+
+use crate::attr::find_single_attribute_value;
+use crate::auth_enveloped_data::{AuthEnvelopedData, GcmParameters};
+use crate::authenticated_data::AuthenticatedData;
+use crate::cert::IssuerAndSerialNumber;
+use crate::encrypted_data::EncryptedData;
+use crate::enveloped_data::{
+    EncryptedContentInfo, EnvelopedData, KemRecipientInfo, KeyAgreeRecipientIdentifier,
+    KeyAgreeRecipientInfo, KeyTransRecipientInfo, OriginatorIdentifierOrKey, OriginatorPublicKey,
+    PasswordRecipientInfo, RecipientEncryptedKey, RecipientIdentifier, RecipientInfo,
+    RecipientInfos, RecipientKeyIdentifier, ID_ORI_KEM,
+};
+use crate::gcm;
+use crate::kem::KemKeyDerivationAlgorithm;
+use crate::key_agreement::{KeyAgreementAlgorithm, KeyWrapAlgorithm};
+use crate::mac;
+use crate::password::{self, PasswordKeyEncryptionAlgorithm};
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use cipher::block_padding::Pkcs7;
+use cipher::{BlockCipher, BlockDecryptMut, KeyInit, KeyIvInit};
+use const_oid::ObjectIdentifier;
+use core::fmt;
+use der::asn1::OctetStringRef;
+use der::referenced::OwnedToRef;
+use der::Encode;
+use pkcs5::pbes2::Pbkdf2Params;
+use rand_core::CryptoRngCore;
+use rsa::pkcs1::RsaOaepParams;
+use rsa::{Oaep, Pkcs1v15Encrypt, RsaPrivateKey};
+use sha2::digest::{Digest, DynDigest};
+use spki::AlgorithmIdentifierOwned;
+use subtle::{ConditionallySelectable, ConstantTimeEq};
+use x509_cert::ext::pkix::SubjectKeyIdentifier;
+use x509_cert::Certificate;
+use zeroize::Zeroizing;
+
+/// Error type
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// ASN.1 DER-related errors.
+    Asn1(der::Error),
+
+    /// No `RecipientInfo` matching the recipient was found.
+    RecipientNotFound,
+
+    /// The key encryption algorithm (or its parameters) is not supported.
+    UnsupportedKeyEncryptionAlgorithm(ObjectIdentifier),
+
+    /// The content encryption algorithm is not supported.
+    UnsupportedContentEncryptionAlgorithm(ObjectIdentifier),
+
+    /// The key derivation algorithm (or its parameters) of a `PasswordRecipientInfo` is not
+    /// supported.
+    UnsupportedKeyDerivationAlgorithm(ObjectIdentifier),
+
+    /// The message authentication code algorithm of an `AuthenticatedData` is not supported.
+    UnsupportedMacAlgorithm(ObjectIdentifier),
+
+    /// The digest algorithm of an `AuthenticatedData` is not supported.
+    UnsupportedDigestAlgorithm(ObjectIdentifier),
+
+    /// The `EnvelopedData` or `EncryptedData` doesn't contain the encrypted content.
+    ContentMissing,
+
+    /// The originator of a `KeyAgreeRecipientInfo` isn't identified by its public key.
+    UnsupportedOriginator,
+
+    /// The key agreement failed.
+    KeyAgreement,
+
+    /// The key decapsulation of a `KEMRecipientInfo` failed.
+    KeyDecapsulation,
+
+    /// Decryption failed.
+    ///
+    /// This error is deliberately returned both if the content-encryption key can't be
+    /// decrypted and if the content can't be decrypted with it. It is also returned if the
+    /// message authentication code or the authenticated attributes of an `AuthenticatedData`
+    /// can't be verified.
+    Decryption,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Asn1(err) => write!(f, "ASN.1 error: {}", err),
+            Error::RecipientNotFound => write!(f, "recipient not found"),
+            Error::UnsupportedKeyEncryptionAlgorithm(oid) => {
+                write!(f, "unsupported key encryption algorithm: {}", oid)
+            }
+            Error::UnsupportedContentEncryptionAlgorithm(oid) => {
+                write!(f, "unsupported content encryption algorithm: {}", oid)
+            }
+            Error::UnsupportedKeyDerivationAlgorithm(oid) => {
+                write!(f, "unsupported key derivation algorithm: {}", oid)
+            }
+            Error::UnsupportedMacAlgorithm(oid) => {
+                write!(
+                    f,
+                    "unsupported message authentication code algorithm: {}",
+                    oid
+                )
+            }
+            Error::UnsupportedDigestAlgorithm(oid) => {
+                write!(f, "unsupported digest algorithm: {}", oid)
+            }
+            Error::ContentMissing => write!(f, "encrypted content missing"),
+            Error::UnsupportedOriginator => write!(f, "unsupported originator"),
+            Error::KeyAgreement => write!(f, "key agreement failed"),
+            Error::KeyDecapsulation => write!(f, "key decapsulation failed"),
+            Error::Decryption => write!(f, "decryption failed"),
+        }
+    }
+}
+
+impl From<der::Error> for Error {
+    fn from(err: der::Error) -> Error {
+        Error::Asn1(err)
+    }
+}
+
+type Result<T> = core::result::Result<T, Error>;
+
+/// Recipient of an `EnvelopedData`, holding the RSA private key used to decrypt the
+/// content-encryption key of a `KeyTransRecipientInfo`.
+///
+/// The private key can be obtained using the [`rsa::pkcs8::DecodePrivateKey`] or
+/// [`rsa::pkcs1::DecodeRsaPrivateKey`] traits.
+pub struct KeyTransRecipient {
+    identifiers: Vec<RecipientIdentifier>,
+    private_key: RsaPrivateKey,
+}
+
+impl KeyTransRecipient {
+    /// Create a new recipient identified by `rid`.
+    pub fn new(rid: RecipientIdentifier, private_key: RsaPrivateKey) -> Self {
+        Self {
+            identifiers: vec![rid],
+            private_key,
+        }
+    }
+
+    /// Create a new recipient for `certificate`. The recipient is identified by the issuer and
+    /// serial number of the certificate, as well as by its subject key identifier, if present.
+    pub fn from_certificate(certificate: &Certificate, private_key: RsaPrivateKey) -> Result<Self> {
+        Ok(Self {
+            identifiers: recipient_identifiers(certificate)?,
+            private_key,
+        })
+    }
+
+    /// Whether `rid` identifies this recipient.
+    pub fn matches(&self, rid: &RecipientIdentifier) -> bool {
+        self.identifiers.iter().any(|identifier| identifier == rid)
+    }
+}
+
+/// Recipient side of an (EC)DH key agreement, e.g. using X25519 or a NIST curve, holding the
+/// recipient's static private key.
+pub trait RecipientKeyAgreement {
+    /// Compute the shared secret with the originator's public key. For elliptic curves, this is
+    /// the x-coordinate of the shared point. Failures should be reported as
+    /// [`Error::KeyAgreement`].
+    fn shared_secret(&self, originator_public_key: &OriginatorPublicKey) -> Result<Vec<u8>>;
+}
+
+/// Recipient of an `EnvelopedData`, using key agreement to decrypt the content-encryption key
+/// of a `KeyAgreeRecipientInfo`.
+pub struct KeyAgreeRecipient<K> {
+    identifiers: Vec<KeyAgreeRecipientIdentifier>,
+    key_agreement: K,
+}
+
+impl<K> KeyAgreeRecipient<K>
+where
+    K: RecipientKeyAgreement,
+{
+    /// Create a new recipient identified by `rid`.
+    pub fn new(rid: KeyAgreeRecipientIdentifier, key_agreement: K) -> Self {
+        Self {
+            identifiers: vec![rid],
+            key_agreement,
+        }
+    }
+
+    /// Create a new recipient for `certificate`. The recipient is identified by the issuer and
+    /// serial number of the certificate, as well as by its subject key identifier, if present.
+    pub fn from_certificate(certificate: &Certificate, key_agreement: K) -> Result<Self> {
+        let identifiers = recipient_identifiers(certificate)?
+            .into_iter()
+            .map(|rid| match rid {
+                RecipientIdentifier::IssuerAndSerialNumber(issuer_and_serial_number) => {
+                    KeyAgreeRecipientIdentifier::IssuerAndSerialNumber(issuer_and_serial_number)
+                }
+                RecipientIdentifier::SubjectKeyIdentifier(subject_key_identifier) => {
+                    KeyAgreeRecipientIdentifier::RKeyId(RecipientKeyIdentifier {
+                        subject_key_identifier,
+                        date: None,
+                        other: None,
+                    })
+                }
+            })
+            .collect();
+
+        Ok(Self {
+            identifiers,
+            key_agreement,
+        })
+    }
+
+    /// Whether `rid` identifies this recipient. For `rKeyId`, only the subject key identifier is
+    /// compared.
+    pub fn matches(&self, rid: &KeyAgreeRecipientIdentifier) -> bool {
+        self.identifiers
+            .iter()
+            .any(|identifier| match (identifier, rid) {
+                (
+                    KeyAgreeRecipientIdentifier::RKeyId(identifier),
+                    KeyAgreeRecipientIdentifier::RKeyId(rid),
+                ) => identifier.subject_key_identifier == rid.subject_key_identifier,
+                (identifier, rid) => identifier == rid,
+            })
+    }
+}
+
+/// Recipient side of a key encapsulation mechanism (KEM), e.g. ML-KEM, holding the
+/// recipient's private key.
+pub trait RecipientKeyEncapsulation {
+    /// Decapsulate the shared secret from the ciphertext `kem_ct` produced by the KEM
+    /// identified by `kem`. Failures should be reported as [`Error::KeyDecapsulation`].
+    fn decapsulate(&self, kem: &AlgorithmIdentifierOwned, kem_ct: &[u8]) -> Result<Vec<u8>>;
+}
+
+/// Recipient of an `EnvelopedData`, using a KEM to decrypt the content-encryption key of a
+/// `KEMRecipientInfo`.
+pub struct KemRecipient<K> {
+    identifiers: Vec<RecipientIdentifier>,
+    kem: K,
+}
+
+impl<K> KemRecipient<K>
+where
+    K: RecipientKeyEncapsulation,
+{
+    /// Create a new recipient identified by `rid`.
+    pub fn new(rid: RecipientIdentifier, kem: K) -> Self {
+        Self {
+            identifiers: vec![rid],
+            kem,
+        }
+    }
+
+    /// Create a new recipient for `certificate`. The recipient is identified by the issuer and
+    /// serial number of the certificate, as well as by its subject key identifier, if present.
+    pub fn from_certificate(certificate: &Certificate, kem: K) -> Result<Self> {
+        Ok(Self {
+            identifiers: recipient_identifiers(certificate)?,
+            kem,
+        })
+    }
+
+    /// Whether `rid` identifies this recipient.
+    pub fn matches(&self, rid: &RecipientIdentifier) -> bool {
+        self.identifiers.iter().any(|identifier| identifier == rid)
+    }
+}
+
+/// Get the identifiers of the recipient holding `certificate`: the issuer and serial number of
+/// the certificate, as well as its subject key identifier, if present.
+fn recipient_identifiers(certificate: &Certificate) -> Result<Vec<RecipientIdentifier>> {
+    let tbs_certificate = &certificate.tbs_certificate;
+    let mut identifiers = vec![RecipientIdentifier::IssuerAndSerialNumber(
+        IssuerAndSerialNumber {
+            issuer: tbs_certificate.issuer.clone(),
+            serial_number: tbs_certificate.serial_number.clone(),
+        },
+    )];
+    if let Some((_, subject_key_identifier)) = tbs_certificate.get::<SubjectKeyIdentifier>()? {
+        identifiers.push(RecipientIdentifier::SubjectKeyIdentifier(
+            subject_key_identifier,
+        ));
+    }
+    Ok(identifiers)
+}
+
+/// Decryptor for envelopedData (CMS and PKCS #7), authEnvelopedData
+/// ([RFC 5083](https://datatracker.ietf.org/doc/html/rfc5083)) and authenticatedData
+/// Calling `decrypt_with_rng()`, `decrypt_key_agreement()`, `decrypt_kem()` or
+/// `decrypt_password()` on this struct will
+/// - locate the `KeyTransRecipientInfo`, `KeyAgreeRecipientInfo`, `KEMRecipientInfo` or
+///   `PasswordRecipientInfo` of the recipient
+/// - decrypt the content-encryption key using the recipient's private key or password
+/// - decrypt the encrypted content using the content-encryption key, verifying the message
+///   authentication code for `AuthEnvelopedData`
+///
+/// For `AuthenticatedData`, the recipient's key instead decrypts the authentication key, which
+/// is used to verify the message authentication code of the (unencrypted) content.
+pub struct EnvelopedDataDecryptor<'a> {
+    recipient_infos: &'a RecipientInfos,
+    encrypted_content: EncryptedContent<'a>,
+}
+
+/// The encrypted content of an `EnvelopedData` or `AuthEnvelopedData`, or the authenticated
+/// content of an `AuthenticatedData`.
+enum EncryptedContent<'a> {
+    Enveloped(&'a EncryptedContentInfo),
+    AuthEnveloped(&'a AuthEnvelopedData),
+    Authenticated(&'a AuthenticatedData),
+}
+
+impl<'a> EnvelopedDataDecryptor<'a> {
+    /// Create a new decryptor for `EnvelopedData`.
+    pub fn new(enveloped_data: &'a EnvelopedData) -> EnvelopedDataDecryptor<'a> {
+        Self {
+            recipient_infos: &enveloped_data.recip_infos,
+            encrypted_content: EncryptedContent::Enveloped(&enveloped_data.encrypted_content),
+        }
+    }
+
+    /// Create a new decryptor for `AuthEnvelopedData`. The recipients are handled like those
+    /// of `EnvelopedData`.
+    pub fn from_auth_enveloped_data(
+        auth_enveloped_data: &'a AuthEnvelopedData,
+    ) -> EnvelopedDataDecryptor<'a> {
+        Self {
+            recipient_infos: &auth_enveloped_data.recip_infos,
+            encrypted_content: EncryptedContent::AuthEnveloped(auth_enveloped_data),
+        }
+    }
+
+    /// Create a new decryptor for `AuthenticatedData`. The recipients are handled like those
+    /// of `EnvelopedData`, and the "decrypted" content is the encapsulated content once the
+    /// message authentication code has been verified.
+    pub fn from_authenticated_data(
+        authenticated_data: &'a AuthenticatedData,
+    ) -> EnvelopedDataDecryptor<'a> {
+        Self {
+            recipient_infos: &authenticated_data.recip_infos,
+            encrypted_content: EncryptedContent::Authenticated(authenticated_data),
+        }
+    }
+
+    /// Find the `KeyTransRecipientInfo` of `recipient`.
+    pub fn find_recipient_info(
+        &self,
+        recipient: &KeyTransRecipient,
+    ) -> Option<&'a KeyTransRecipientInfo> {
+        self.recipient_infos
+            .0
+            .iter()
+            .find_map(|recipient_info| match recipient_info {
+                RecipientInfo::Ktri(ktri) if recipient.matches(&ktri.rid) => Some(ktri),
+                _ => None,
+            })
+    }
+
+    /// Decrypt the content for `recipient`. `rng` is used for RSA blinding and to generate a
+    /// substitute content-encryption key.
+    ///
+    /// To prevent Bleichenbacher-style attacks, a failure to decrypt the content-encryption key
+    /// is not reported directly. As recommended by
+    /// [RFC 3218 § 2.3.2](https://datatracker.ietf.org/doc/html/rfc3218#section-2.3.2),
+    /// a random content-encryption key is used instead in that case, so that the failure is
+    /// indistinguishable from a failure to decrypt the content.
+    pub fn decrypt_with_rng(
+        &self,
+        recipient: &KeyTransRecipient,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<Vec<u8>> {
+        let ktri = self
+            .find_recipient_info(recipient)
+            .ok_or(Error::RecipientNotFound)?;
+
+        let key_size = self.content_encryption_key_size()?;
+        let mut content_encryption_key = Zeroizing::new(vec![0u8; key_size]);
+        rng.fill_bytes(&mut content_encryption_key);
+
+        let private_key = &recipient.private_key;
+        let enc_key = ktri.enc_key.as_bytes();
+        let decrypted_key = match ktri.key_enc_alg.oid {
+            const_oid::db::rfc5912::RSA_ENCRYPTION => {
+                private_key.decrypt_blinded(rng, Pkcs1v15Encrypt, enc_key)
+            }
+            const_oid::db::rfc5912::ID_RSAES_OAEP => {
+                private_key.decrypt_blinded(rng, oaep_padding(&ktri.key_enc_alg)?, enc_key)
+            }
+            oid => return Err(Error::UnsupportedKeyEncryptionAlgorithm(oid)),
+        };
+
+        // Replace the random key with the decrypted one only if decryption succeeded and the
+        // decrypted key has the correct size.
+        let (decrypted_key, is_valid) = match decrypted_key {
+            Ok(decrypted_key) => {
+                let is_valid = (decrypted_key.len() as u64).ct_eq(&(key_size as u64));
+                (Zeroizing::new(decrypted_key), is_valid)
+            }
+            Err(_) => (Zeroizing::new(Vec::new()), 0.into()),
+        };
+        for (i, byte) in content_encryption_key.iter_mut().enumerate() {
+            let decrypted_byte = decrypted_key.get(i).copied().unwrap_or_default();
+            byte.conditional_assign(&decrypted_byte, is_valid);
+        }
+
+        self.decrypt_content(&content_encryption_key)
+    }
+
+    /// Find the `KeyAgreeRecipientInfo` and `RecipientEncryptedKey` of `recipient`.
+    pub fn find_key_agree_recipient_info<K>(
+        &self,
+        recipient: &KeyAgreeRecipient<K>,
+    ) -> Option<(&'a KeyAgreeRecipientInfo, &'a RecipientEncryptedKey)>
+    where
+        K: RecipientKeyAgreement,
+    {
+        self.recipient_infos
+            .0
+            .iter()
+            .filter_map(|recipient_info| match recipient_info {
+                RecipientInfo::Kari(kari) => Some(kari),
+                _ => None,
+            })
+            .find_map(|kari| {
+                kari.recipient_enc_keys
+                    .iter()
+                    .find(|recipient_enc_key| recipient.matches(&recipient_enc_key.rid))
+                    .map(|recipient_enc_key| (kari, recipient_enc_key))
+            })
+    }
+
+    /// Decrypt the content for `recipient` using ephemeral-static (EC)DH key agreement
+    /// according to [RFC 5753 § 3.1.2](https://datatracker.ietf.org/doc/html/rfc5753#section-3.1.2).
+    pub fn decrypt_key_agreement<K>(&self, recipient: &KeyAgreeRecipient<K>) -> Result<Vec<u8>>
+    where
+        K: RecipientKeyAgreement,
+    {
+        let (kari, recipient_enc_key) = self
+            .find_key_agree_recipient_info(recipient)
+            .ok_or(Error::RecipientNotFound)?;
+
+        let originator_public_key = match &kari.originator {
+            OriginatorIdentifierOrKey::OriginatorKey(originator_public_key) => {
+                originator_public_key
+            }
+            _ => return Err(Error::UnsupportedOriginator),
+        };
+
+        let key_enc_alg = &kari.key_enc_alg;
+        let key_agreement_algorithm = KeyAgreementAlgorithm::from_oid(key_enc_alg.oid)
+            .ok_or(Error::UnsupportedKeyEncryptionAlgorithm(key_enc_alg.oid))?;
+        let key_wrap_algorithm_identifier = key_enc_alg
+            .parameters
+            .as_ref()
+            .ok_or(Error::Asn1(der::Tag::Sequence.value_error()))?
+            .decode_as::<AlgorithmIdentifierOwned>()?;
+        let key_wrap_algorithm = KeyWrapAlgorithm::from_oid(key_wrap_algorithm_identifier.oid)
+            .ok_or(Error::UnsupportedKeyEncryptionAlgorithm(
+                key_wrap_algorithm_identifier.oid,
+            ))?;
+        let key_size = self.content_encryption_key_size()?;
+
+        let shared_secret = Zeroizing::new(
+            recipient
+                .key_agreement
+                .shared_secret(originator_public_key)?,
+        );
+        let key_encryption_key = key_agreement_algorithm.derive_key_encryption_key(
+            &shared_secret,
+            key_wrap_algorithm,
+            kari.ukm.as_ref(),
+        )?;
+
+        let content_encryption_key = Zeroizing::new(
+            key_wrap_algorithm
+                .unwrap(&key_encryption_key, recipient_enc_key.enc_key.as_bytes())
+                .ok_or(Error::Decryption)?,
+        );
+
+        if content_encryption_key.len() != key_size {
+            return Err(Error::Decryption);
+        }
+        self.decrypt_content(&content_encryption_key)
+    }
+
+    /// Find the `KEMRecipientInfo` of `recipient` among the `OtherRecipientInfo`s of type
+    /// `id-ori-kem`.
+    pub fn find_kem_recipient_info<K>(
+        &self,
+        recipient: &KemRecipient<K>,
+    ) -> Result<Option<KemRecipientInfo>>
+    where
+        K: RecipientKeyEncapsulation,
+    {
+        for recipient_info in self.recipient_infos.0.iter() {
+            let ori = match recipient_info {
+                RecipientInfo::Ori(ori) if ori.ori_type == ID_ORI_KEM => ori,
+                _ => continue,
+            };
+            let kem_recipient_info = KemRecipientInfo::from_other_recipient_info(ori)?;
+            if recipient.matches(&kem_recipient_info.rid) {
+                return Ok(Some(kem_recipient_info));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Decrypt the content for `recipient` using a KEM according to
+    /// [RFC 9629 § 2](https://datatracker.ietf.org/doc/html/rfc9629#section-2).
+    pub fn decrypt_kem<K>(&self, recipient: &KemRecipient<K>) -> Result<Vec<u8>>
+    where
+        K: RecipientKeyEncapsulation,
+    {
+        let kemri = self
+            .find_kem_recipient_info(recipient)?
+            .ok_or(Error::RecipientNotFound)?;
+
+        let key_derivation_algorithm = KemKeyDerivationAlgorithm::from_oid(kemri.kdf.oid)
+            .ok_or(Error::UnsupportedKeyDerivationAlgorithm(kemri.kdf.oid))?;
+        let key_wrap_algorithm = KeyWrapAlgorithm::from_oid(kemri.wrap.oid)
+            .ok_or(Error::UnsupportedKeyEncryptionAlgorithm(kemri.wrap.oid))?;
+        if usize::from(kemri.kek_length) != key_wrap_algorithm.key_size() {
+            return Err(Error::Asn1(der::Tag::Integer.value_error()));
+        }
+        let key_size = self.content_encryption_key_size()?;
+
+        let shared_secret = Zeroizing::new(
+            recipient
+                .kem
+                .decapsulate(&kemri.kem, kemri.kem_ct.as_bytes())?,
+        );
+        let key_encryption_key = key_derivation_algorithm.derive_key_encryption_key(
+            &shared_secret,
+            key_wrap_algorithm,
+            kemri.ukm.as_ref(),
+        )?;
+
+        let content_encryption_key = Zeroizing::new(
+            key_wrap_algorithm
+                .unwrap(&key_encryption_key, kemri.encrypted_key.as_bytes())
+                .ok_or(Error::Decryption)?,
+        );
+
+        if content_encryption_key.len() != key_size {
+            return Err(Error::Decryption);
+        }
+        self.decrypt_content(&content_encryption_key)
+    }
+
+    /// Decrypt the content using `password` according to
+    /// [RFC 3211 § 2.2](https://datatracker.ietf.org/doc/html/rfc3211#section-2.2).
+    ///
+    /// Since a `PasswordRecipientInfo` doesn't identify the recipient, each of them is tried in
+    /// turn. `PasswordRecipientInfo`s without a key derivation algorithm use a key-encryption key
+    /// that has been distributed by other means, and are skipped.
+    pub fn decrypt_password(&self, password: &[u8]) -> Result<Vec<u8>> {
+        let mut result = Err(Error::RecipientNotFound);
+        for pwri in
+            self.recipient_infos
+                .0
+                .iter()
+                .filter_map(|recipient_info| match recipient_info {
+                    RecipientInfo::Pwri(pwri) => Some(pwri),
+                    _ => None,
+                })
+        {
+            if let Some(key_derivation_alg) = &pwri.key_derivation_alg {
+                result = self.decrypt_password_recipient_info(pwri, key_derivation_alg, password);
+                if result.is_ok() {
+                    break;
+                }
+            }
+        }
+        result
+    }
+
+    /// Decrypt the content using a single `PasswordRecipientInfo`.
+    fn decrypt_password_recipient_info(
+        &self,
+        pwri: &PasswordRecipientInfo,
+        key_derivation_alg: &AlgorithmIdentifierOwned,
+        password: &[u8],
+    ) -> Result<Vec<u8>> {
+        if key_derivation_alg.oid != pkcs5::pbes2::PBKDF2_OID {
+            return Err(Error::UnsupportedKeyDerivationAlgorithm(
+                key_derivation_alg.oid,
+            ));
+        }
+        let key_derivation_params = key_derivation_alg
+            .parameters
+            .as_ref()
+            .ok_or(Error::Asn1(der::Tag::Sequence.value_error()))?
+            .decode_as::<Pbkdf2Params<'_>>()?;
+        let (key_encryption_algorithm, iv) =
+            PasswordKeyEncryptionAlgorithm::from_algorithm_identifier(&pwri.key_enc_alg)?.ok_or(
+                Error::UnsupportedKeyEncryptionAlgorithm(pwri.key_enc_alg.oid),
+            )?;
+        let key_size = self.content_encryption_key_size()?;
+
+        let key_encryption_key = password::derive_key_encryption_key(
+            password,
+            &key_derivation_params,
+            key_encryption_algorithm.key_size(),
+        )
+        .ok_or(Error::UnsupportedKeyDerivationAlgorithm(
+            key_derivation_alg.oid,
+        ))?;
+
+        let content_encryption_key = Zeroizing::new(
+            key_encryption_algorithm
+                .unwrap(&key_encryption_key, pwri.enc_key.as_bytes(), iv)
+                .ok_or(Error::Decryption)?,
+        );
+
+        if content_encryption_key.len() != key_size {
+            return Err(Error::Decryption);
+        }
+        self.decrypt_content(&content_encryption_key)
+    }
+
+    /// Get the size of the content-encryption key (or authentication key) in bytes.
+    fn content_encryption_key_size(&self) -> Result<usize> {
+        match self.encrypted_content {
+            EncryptedContent::Enveloped(encrypted_content_info) => {
+                let content_enc_alg = &encrypted_content_info.content_enc_alg;
+                content_encryption_key_size(content_enc_alg.oid).ok_or(
+                    Error::UnsupportedContentEncryptionAlgorithm(content_enc_alg.oid),
+                )
+            }
+            EncryptedContent::AuthEnveloped(auth_enveloped_data) => {
+                let content_enc_alg = &auth_enveloped_data.auth_encrypted_content.content_enc_alg;
+                auth_content_encryption_key_size(content_enc_alg.oid).ok_or(
+                    Error::UnsupportedContentEncryptionAlgorithm(content_enc_alg.oid),
+                )
+            }
+            EncryptedContent::Authenticated(authenticated_data) => {
+                let mac_alg = &authenticated_data.mac_alg;
+                mac::key_size(mac_alg.oid).ok_or(Error::UnsupportedMacAlgorithm(mac_alg.oid))
+            }
+        }
+    }
+
+    /// Decrypt the encrypted content using the content-encryption key.
+    fn decrypt_content(&self, content_encryption_key: &[u8]) -> Result<Vec<u8>> {
+        match self.encrypted_content {
+            EncryptedContent::Enveloped(encrypted_content_info) => {
+                let encrypted_content = encrypted_content_info
+                    .encrypted_content
+                    .as_ref()
+                    .ok_or(Error::ContentMissing)?;
+
+                decrypt_content(
+                    &encrypted_content_info.content_enc_alg,
+                    content_encryption_key,
+                    encrypted_content.as_bytes(),
+                )
+            }
+            EncryptedContent::AuthEnveloped(auth_enveloped_data) => {
+                decrypt_auth_content(auth_enveloped_data, content_encryption_key)
+            }
+            EncryptedContent::Authenticated(authenticated_data) => {
+                verify_authenticated_content(authenticated_data, content_encryption_key)
+            }
+        }
+    }
+}
+
+/// Decryptor for encryptedData according to
+/// [RFC 5652 § 8](https://datatracker.ietf.org/doc/html/rfc5652#section-8), encrypted using
+/// PBES2 ([RFC 8018 § 6.2](https://datatracker.ietf.org/doc/html/rfc8018#section-6.2)) as e.g.
+/// in PKCS #12.
+/// Calling `decrypt()` on this struct will derive the content-encryption key from the password
+/// and decrypt the encrypted content.
+pub struct EncryptedDataDecryptor<'a> {
+    encrypted_data: &'a EncryptedData,
+}
+
+impl<'a> EncryptedDataDecryptor<'a> {
+    /// Create a new decryptor for `EncryptedData`
+    pub fn new(encrypted_data: &'a EncryptedData) -> Self {
+        Self { encrypted_data }
+    }
+
+    /// Decrypt the content using a key derived from `password`.
+    pub fn decrypt(&self, password: impl AsRef<[u8]>) -> Result<Vec<u8>> {
+        let enc_content_info = &self.encrypted_data.enc_content_info;
+        let content_enc_alg = &enc_content_info.content_enc_alg;
+        let encrypted_content = enc_content_info
+            .encrypted_content
+            .as_ref()
+            .ok_or(Error::ContentMissing)?;
+
+        let encryption_scheme =
+            pkcs5::EncryptionScheme::try_from(content_enc_alg.owned_to_ref())
+                .map_err(|_| Error::UnsupportedContentEncryptionAlgorithm(content_enc_alg.oid))?;
+        encryption_scheme
+            .decrypt(password, encrypted_content.as_bytes())
+            .map_err(|err| match err {
+                // CBC padding errors are reported as `EncryptFailed` by `pkcs5`
+                pkcs5::Error::DecryptFailed | pkcs5::Error::EncryptFailed => Error::Decryption,
+                pkcs5::Error::AlgorithmParametersInvalid { oid }
+                | pkcs5::Error::UnsupportedAlgorithm { oid } => {
+                    Error::UnsupportedContentEncryptionAlgorithm(oid)
+                }
+                _ => Error::UnsupportedContentEncryptionAlgorithm(content_enc_alg.oid),
+            })
+    }
+}
+
+/// Get the OAEP padding scheme described by the parameters of an `id-RSAES-OAEP` algorithm
+/// identifier.
+fn oaep_padding(key_enc_alg: &AlgorithmIdentifierOwned) -> Result<Oaep> {
+    let unsupported = || Error::UnsupportedKeyEncryptionAlgorithm(key_enc_alg.oid);
+
+    let params = match &key_enc_alg.parameters {
+        Some(parameters) => parameters.decode_as::<RsaOaepParams<'_>>()?,
+        None => RsaOaepParams::default(),
+    };
+    if params.mask_gen.oid != const_oid::db::rfc5912::ID_MGF_1 {
+        return Err(unsupported());
+    }
+    let mgf_hash = params.mask_gen.parameters.ok_or_else(unsupported)?;
+
+    let label = match params.label().ok_or_else(unsupported)? {
+        [] => None,
+        label => Some(String::from(
+            core::str::from_utf8(label).map_err(|_| unsupported())?,
+        )),
+    };
+
+    Ok(Oaep {
+        digest: get_digest(params.hash.oid).ok_or_else(unsupported)?,
+        mgf_digest: get_digest(mgf_hash.oid).ok_or_else(unsupported)?,
+        label,
+    })
+}
+
+/// Get a hasher for OAEP encoding or the message digest of `AuthenticatedData`.
+fn get_digest(oid: ObjectIdentifier) -> Option<Box<dyn DynDigest + Send + Sync>> {
+    match oid {
+        const_oid::db::rfc5912::ID_SHA_1 => Some(Box::new(sha1::Sha1::new())),
+        const_oid::db::rfc5912::ID_SHA_224 => Some(Box::new(sha2::Sha224::new())),
+        const_oid::db::rfc5912::ID_SHA_256 => Some(Box::new(sha2::Sha256::new())),
+        const_oid::db::rfc5912::ID_SHA_384 => Some(Box::new(sha2::Sha384::new())),
+        const_oid::db::rfc5912::ID_SHA_512 => Some(Box::new(sha2::Sha512::new())),
+        _ => None,
+    }
+}
+
+/// Get the size of the content-encryption key in bytes.
+fn content_encryption_key_size(oid: ObjectIdentifier) -> Option<usize> {
+    match oid {
+        const_oid::db::rfc5911::ID_AES_128_CBC => Some(16),
+        const_oid::db::rfc5911::ID_AES_192_CBC => Some(24),
+        const_oid::db::rfc5911::ID_AES_256_CBC => Some(32),
+        _ => None,
+    }
+}
+
+/// Decrypt the encrypted content using the content-encryption key.
+fn decrypt_content(
+    content_enc_alg: &AlgorithmIdentifierOwned,
+    key: &[u8],
+    encrypted_content: &[u8],
+) -> Result<Vec<u8>> {
+    let iv = content_enc_alg
+        .parameters
+        .as_ref()
+        .ok_or(Error::Asn1(der::Tag::OctetString.value_error()))?
+        .decode_as::<OctetStringRef<'_>>()?;
+
+    match content_enc_alg.oid {
+        const_oid::db::rfc5911::ID_AES_128_CBC => {
+            decrypt_cbc::<aes::Aes128>(key, iv.as_bytes(), encrypted_content)
+        }
+        const_oid::db::rfc5911::ID_AES_192_CBC => {
+            decrypt_cbc::<aes::Aes192>(key, iv.as_bytes(), encrypted_content)
+        }
+        const_oid::db::rfc5911::ID_AES_256_CBC => {
+            decrypt_cbc::<aes::Aes256>(key, iv.as_bytes(), encrypted_content)
+        }
+        oid => Err(Error::UnsupportedContentEncryptionAlgorithm(oid)),
+    }
+}
+
+/// Get the size of the content-encryption key of an authenticated encryption algorithm in
+/// bytes.
+fn auth_content_encryption_key_size(oid: ObjectIdentifier) -> Option<usize> {
+    match oid {
+        const_oid::db::rfc5911::ID_AES_128_GCM => Some(16),
+        const_oid::db::rfc5911::ID_AES_192_GCM => Some(24),
+        const_oid::db::rfc5911::ID_AES_256_GCM => Some(32),
+        _ => None,
+    }
+}
+
+/// Decrypt the encrypted content of `AuthEnvelopedData` using the content-encryption key and
+/// verify the message authentication code according to
+/// [RFC 5084 § 3](https://datatracker.ietf.org/doc/html/rfc5084#section-3).
+fn decrypt_auth_content(auth_enveloped_data: &AuthEnvelopedData, key: &[u8]) -> Result<Vec<u8>> {
+    let encrypted_content_info = &auth_enveloped_data.auth_encrypted_content;
+    let encrypted_content = encrypted_content_info
+        .encrypted_content
+        .as_ref()
+        .ok_or(Error::ContentMissing)?;
+    let content_enc_alg = &encrypted_content_info.content_enc_alg;
+    let params = content_enc_alg
+        .parameters
+        .as_ref()
+        .ok_or(Error::Asn1(der::Tag::Sequence.value_error()))?
+        .decode_as::<GcmParameters>()?;
+    let nonce = params
+        .nonce
+        .as_bytes()
+        .try_into()
+        .map_err(|_| Error::UnsupportedContentEncryptionAlgorithm(content_enc_alg.oid))?;
+    let mac = auth_enveloped_data.mac.as_bytes();
+    if mac.len() != usize::from(params.icv_len) {
+        return Err(Error::Decryption);
+    }
+
+    // RFC 5083 § 2.2: the authenticated attributes are authenticated using their DER encoding
+    // with an EXPLICIT SET OF tag.
+    let aad = auth_enveloped_data
+        .auth_attrs
+        .as_ref()
+        .map(|auth_attrs| auth_attrs.to_der())
+        .transpose()?
+        .unwrap_or_default();
+
+    gcm::decrypt(key, nonce, &aad, encrypted_content.as_bytes(), mac).ok_or(Error::Decryption)
+}
+
+/// Verify the message authentication code of `AuthenticatedData` using the authentication key
+/// according to [RFC 5652 § 9.3](https://datatracker.ietf.org/doc/html/rfc5652#section-9.3),
+/// and return the authenticated content.
+fn verify_authenticated_content(
+    authenticated_data: &AuthenticatedData,
+    key: &[u8],
+) -> Result<Vec<u8>> {
+    let encapsulated_content_info = &authenticated_data.encap_content_info;
+    let content = encapsulated_content_info
+        .econtent
+        .as_ref()
+        .ok_or(Error::ContentMissing)?
+        .value();
+
+    // RFC 5652 § 9.2: If authAttrs are present, the MAC is calculated over their DER encoding
+    // with an EXPLICIT SET OF tag, otherwise over the value of the eContent OCTET STRING.
+    let mac_input = match &authenticated_data.auth_attrs {
+        Some(auth_attrs) => auth_attrs.to_der()?,
+        None => content.to_vec(),
+    };
+    let mac_alg = &authenticated_data.mac_alg;
+    let mac_is_valid = mac::verify(
+        mac_alg.oid,
+        key,
+        &mac_input,
+        authenticated_data.mac.as_bytes(),
+    )
+    .ok_or(Error::UnsupportedMacAlgorithm(mac_alg.oid))?;
+    if !mac_is_valid {
+        return Err(Error::Decryption);
+    }
+
+    if let Some(auth_attrs) = &authenticated_data.auth_attrs {
+        let digest_alg = authenticated_data
+            .digest_alg
+            .as_ref()
+            .ok_or(Error::Asn1(der::Tag::Sequence.value_error()))?;
+        let mut hasher =
+            get_digest(digest_alg.oid).ok_or(Error::UnsupportedDigestAlgorithm(digest_alg.oid))?;
+        hasher.update(content);
+
+        let message_digest = find_single_attribute_value(
+            auth_attrs.iter(),
+            const_oid::db::rfc5911::ID_MESSAGE_DIGEST,
+        )
+        .ok_or(Error::Decryption)?
+        .decode_as::<OctetStringRef<'_>>()?;
+        if hasher.finalize_reset().as_ref() != message_digest.as_bytes() {
+            return Err(Error::Decryption);
+        }
+
+        let content_type =
+            find_single_attribute_value(auth_attrs.iter(), const_oid::db::rfc5911::ID_CONTENT_TYPE)
+                .ok_or(Error::Decryption)?
+                .decode_as::<ObjectIdentifier>()?;
+        if content_type != encapsulated_content_info.econtent_type {
+            return Err(Error::Decryption);
+        }
+    }
+
+    Ok(content.to_vec())
+}
+
+/// Decrypt `encrypted_content` using block cipher `C` in CBC mode with PKCS #7 padding.
+fn decrypt_cbc<C>(key: &[u8], iv: &[u8], encrypted_content: &[u8]) -> Result<Vec<u8>>
+where
+    C: BlockDecryptMut + BlockCipher + KeyInit,
+{
+    let decryptor = cbc::Decryptor::<C>::new_from_slices(key, iv)
+        .map_err(|_| Error::Asn1(der::Tag::OctetString.value_error()))?;
+    decryptor
+        .decrypt_padded_vec_mut::<Pkcs7>(encrypted_content)
+        .map_err(|_| Error::Decryption)
+}
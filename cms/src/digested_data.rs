@@ -1,4 +1,5 @@
 //! DigestedData-related types
+use const_oid::{AssociatedOid, ObjectIdentifier};
 use der::{asn1::OctetString, Sequence};
 
 use spki::AlgorithmIdentifierOwned;
@@ -27,6 +28,10 @@ pub struct DigestedData {
     pub digest: Digest,
 }
 
+impl AssociatedOid for DigestedData {
+    const OID: ObjectIdentifier = const_oid::db::rfc5911::ID_DIGESTED_DATA;
+}
+
 /// The `Digest` type is defined in [RFC 5652 Section 7].
 ///
 /// ```text
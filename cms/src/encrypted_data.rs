@@ -1,4 +1,5 @@
 //! EncryptedData-related types
+use const_oid::{AssociatedOid, ObjectIdentifier};
 use der::Sequence;
 
 use x509_cert::attr::Attributes;
@@ -31,3 +32,7 @@ pub struct EncryptedData {
     )]
     pub unprotected_attrs: Option<Attributes>,
 }
+
+impl AssociatedOid for EncryptedData {
+    const OID: ObjectIdentifier = const_oid::db::rfc5911::ID_ENCRYPTED_DATA;
+}
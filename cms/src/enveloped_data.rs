@@ -5,9 +5,10 @@ use crate::content_info::CmsVersion;
 use crate::revocation::RevocationInfoChoices;
 use crate::signed_data::CertificateSet;
 
+use const_oid::AssociatedOid;
 use core::cmp::Ordering;
 use der::asn1::{BitString, GeneralizedTime, ObjectIdentifier, OctetString, SetOfVec};
-use der::{Any, Choice, Sequence, ValueOrd};
+use der::{Any, Choice, ErrorKind, Sequence, ValueOrd};
 use spki::AlgorithmIdentifierOwned;
 use x509_cert::attr::{Attribute, Attributes};
 use x509_cert::ext::pkix::SubjectKeyIdentifier;
@@ -49,6 +50,10 @@ pub struct EnvelopedData {
     pub unprotected_attrs: Option<Attributes>,
 }
 
+impl AssociatedOid for EnvelopedData {
+    const OID: ObjectIdentifier = const_oid::db::rfc5911::ID_ENVELOPED_DATA;
+}
+
 /// The `OriginatorInfo` type is defined in [RFC 5652 Section 6.1].
 ///
 /// ```text
@@ -299,7 +304,7 @@ pub struct RecipientEncryptedKey {
 #[allow(missing_docs)]
 pub enum KeyAgreeRecipientIdentifier {
     IssuerAndSerialNumber(IssuerAndSerialNumber),
-    #[asn1(context_specific = "0", tag_mode = "IMPLICIT")]
+    #[asn1(context_specific = "0", tag_mode = "IMPLICIT", constructed = "true")]
     RKeyId(RecipientKeyIdentifier),
 }
 
@@ -407,6 +412,86 @@ pub struct OtherRecipientInfo {
     pub ori_value: Any,
 }
 
+/// `id-ori-kem`, the `oriType` of a [`KemRecipientInfo`]
+pub const ID_ORI_KEM: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.16.13.3");
+
+/// The `KEMRecipientInfo` type is defined in [RFC 9629 Section 3].
+///
+/// ```text
+///   KEMRecipientInfo ::= SEQUENCE {
+///       version CMSVersion,  -- always set to 0
+///       rid RecipientIdentifier,
+///       kem KEMAlgorithmIdentifier,
+///       kemct OCTET STRING,
+///       kdf KeyDerivationAlgorithmIdentifier,
+///       kekLength INTEGER (1..65535),
+///       ukm [0] EXPLICIT UserKeyingMaterial OPTIONAL,
+///       wrap KeyEncryptionAlgorithmIdentifier,
+///       encryptedKey EncryptedKey }
+/// ```
+///
+/// It is carried in an [`OtherRecipientInfo`] of type [`ID_ORI_KEM`].
+///
+/// [RFC 9629 Section 3]: https://www.rfc-editor.org/rfc/rfc9629#section-3
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct KemRecipientInfo {
+    pub version: CmsVersion,
+    pub rid: RecipientIdentifier,
+    pub kem: AlgorithmIdentifierOwned,
+    pub kem_ct: OctetString,
+    pub kdf: AlgorithmIdentifierOwned,
+    pub kek_length: u16,
+    #[asn1(context_specific = "0", tag_mode = "EXPLICIT", optional = "true")]
+    pub ukm: Option<UserKeyingMaterial>,
+    pub wrap: AlgorithmIdentifierOwned,
+    pub encrypted_key: EncryptedKey,
+}
+
+impl KemRecipientInfo {
+    /// Wrap this `KEMRecipientInfo` in an `OtherRecipientInfo` of type `id-ori-kem`.
+    pub fn to_other_recipient_info(&self) -> der::Result<OtherRecipientInfo> {
+        Ok(OtherRecipientInfo {
+            ori_type: ID_ORI_KEM,
+            ori_value: Any::encode_from(self)?,
+        })
+    }
+
+    /// Extract the `KEMRecipientInfo` from an `OtherRecipientInfo` of type `id-ori-kem`.
+    pub fn from_other_recipient_info(
+        other_recipient_info: &OtherRecipientInfo,
+    ) -> der::Result<Self> {
+        if other_recipient_info.ori_type != ID_ORI_KEM {
+            return Err(ErrorKind::OidUnknown {
+                oid: other_recipient_info.ori_type,
+            }
+            .into());
+        }
+        other_recipient_info.ori_value.decode_as()
+    }
+}
+
+/// The `CMSORIforKEMOtherInfo` type is defined in [RFC 9629 Section 5].
+///
+/// ```text
+///   CMSORIforKEMOtherInfo ::= SEQUENCE {
+///       wrap KeyEncryptionAlgorithmIdentifier,
+///       kekLength INTEGER (1..65535),
+///       ukm [0] EXPLICIT UserKeyingMaterial OPTIONAL }
+/// ```
+///
+/// Its DER encoding is the `info` input of the key derivation function.
+///
+/// [RFC 9629 Section 5]: https://www.rfc-editor.org/rfc/rfc9629#section-5
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct CmsOriForKemOtherInfo {
+    pub wrap: AlgorithmIdentifierOwned,
+    pub kek_length: u16,
+    #[asn1(context_specific = "0", tag_mode = "EXPLICIT", optional = "true")]
+    pub ukm: Option<UserKeyingMaterial>,
+}
+
 /// The `UserKeyingMaterial` type is defined in [RFC 5652 Section 10.2.6].
 ///
 /// ```text
@@ -415,3 +500,23 @@ pub struct OtherRecipientInfo {
 ///
 /// [RFC 5652 Section 10.2.5]: https://www.rfc-editor.org/rfc/rfc5652#section-10.2.5
 pub type UserKeyingMaterial = OctetString;
+
+/// The `ECC-CMS-SharedInfo` type is defined in [RFC 5753 Section 7.2].
+///
+/// ```text
+///   ECC-CMS-SharedInfo ::= SEQUENCE {
+///       keyInfo         AlgorithmIdentifier,
+///       entityUInfo [0] EXPLICIT OCTET STRING OPTIONAL,
+///       suppPubInfo [2] EXPLICIT OCTET STRING  }
+/// ```
+///
+/// [RFC 5753 Section 7.2]: https://www.rfc-editor.org/rfc/rfc5753#section-7.2
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct EccCmsSharedInfo {
+    pub key_info: AlgorithmIdentifierOwned,
+    #[asn1(context_specific = "0", tag_mode = "EXPLICIT", optional = "true")]
+    pub entity_u_info: Option<OctetString>,
+    #[asn1(context_specific = "2", tag_mode = "EXPLICIT")]
+    pub supp_pub_info: OctetString,
+}
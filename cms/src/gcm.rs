@@ -0,0 +1,119 @@
+#![cfg(any(feature = "builder", feature = "decrypt"))]
+
+//! AES-GCM with the authentication tag kept separate from the ciphertext as required by the
+//! `mac` field of `AuthEnvelopedData`.
+
+use aes_gcm::aead::consts::{U12, U16};
+use aes_gcm::aead::AeadInPlace;
+use aes_gcm::AesGcm;
+use alloc::vec::Vec;
+use cipher::{BlockCipher, BlockEncrypt, BlockSizeUser, KeyInit};
+
+#[cfg(feature = "decrypt")]
+use aes_gcm::{
+    aead::consts::{U13, U14, U15},
+    Tag, TagSize,
+};
+
+/// Nonce size. Only the recommended 12-octet nonces are supported.
+pub(crate) const NONCE_SIZE: usize = 12;
+
+/// Size of an untruncated authentication tag
+#[cfg(feature = "builder")]
+const TAG_SIZE: usize = 16;
+
+/// Encrypt `plaintext` with AES-GCM, selecting AES-128, AES-192 or AES-256 by the size of `key`.
+/// Returns the ciphertext and the (untruncated) authentication tag.
+#[cfg(feature = "builder")]
+pub(crate) fn encrypt(
+    key: &[u8],
+    nonce: &[u8; NONCE_SIZE],
+    aad: &[u8],
+    plaintext: &[u8],
+) -> Option<(Vec<u8>, [u8; TAG_SIZE])> {
+    match key.len() {
+        16 => encrypt_with::<aes::Aes128>(key, nonce, aad, plaintext),
+        24 => encrypt_with::<aes::Aes192>(key, nonce, aad, plaintext),
+        32 => encrypt_with::<aes::Aes256>(key, nonce, aad, plaintext),
+        _ => None,
+    }
+}
+
+/// Verify the authentication tag `tag` and decrypt `ciphertext` with AES-GCM, selecting
+/// AES-128, AES-192 or AES-256 by the size of `key`.
+///
+/// The tag may be truncated to between 12 and 16 octets.
+#[cfg(feature = "decrypt")]
+pub(crate) fn decrypt(
+    key: &[u8],
+    nonce: &[u8; NONCE_SIZE],
+    aad: &[u8],
+    ciphertext: &[u8],
+    tag: &[u8],
+) -> Option<Vec<u8>> {
+    match key.len() {
+        16 => decrypt_with::<aes::Aes128>(key, nonce, aad, ciphertext, tag),
+        24 => decrypt_with::<aes::Aes192>(key, nonce, aad, ciphertext, tag),
+        32 => decrypt_with::<aes::Aes256>(key, nonce, aad, ciphertext, tag),
+        _ => None,
+    }
+}
+
+#[cfg(feature = "builder")]
+fn encrypt_with<C>(
+    key: &[u8],
+    nonce: &[u8; NONCE_SIZE],
+    aad: &[u8],
+    plaintext: &[u8],
+) -> Option<(Vec<u8>, [u8; TAG_SIZE])>
+where
+    C: BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt + KeyInit,
+{
+    let mut ciphertext = plaintext.to_vec();
+    let tag = AesGcm::<C, U12, U16>::new_from_slice(key)
+        .ok()?
+        .encrypt_in_place_detached(nonce.into(), aad, &mut ciphertext)
+        .ok()?;
+    Some((ciphertext, tag.into()))
+}
+
+#[cfg(feature = "decrypt")]
+fn decrypt_with<C>(
+    key: &[u8],
+    nonce: &[u8; NONCE_SIZE],
+    aad: &[u8],
+    ciphertext: &[u8],
+    tag: &[u8],
+) -> Option<Vec<u8>>
+where
+    C: BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt + KeyInit,
+{
+    match tag.len() {
+        12 => decrypt_with_tag::<C, U12>(key, nonce, aad, ciphertext, tag),
+        13 => decrypt_with_tag::<C, U13>(key, nonce, aad, ciphertext, tag),
+        14 => decrypt_with_tag::<C, U14>(key, nonce, aad, ciphertext, tag),
+        15 => decrypt_with_tag::<C, U15>(key, nonce, aad, ciphertext, tag),
+        16 => decrypt_with_tag::<C, U16>(key, nonce, aad, ciphertext, tag),
+        _ => None,
+    }
+}
+
+#[cfg(feature = "decrypt")]
+fn decrypt_with_tag<C, T>(
+    key: &[u8],
+    nonce: &[u8; NONCE_SIZE],
+    aad: &[u8],
+    ciphertext: &[u8],
+    tag: &[u8],
+) -> Option<Vec<u8>>
+where
+    C: BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt + KeyInit,
+    T: TagSize,
+{
+    let mut plaintext = ciphertext.to_vec();
+    AesGcm::<C, U12, T>::new_from_slice(key)
+        .ok()?
+        .decrypt_in_place_detached(nonce.into(), aad, &mut plaintext, Tag::<T>::from_slice(tag))
+        .ok()?;
+    Some(plaintext)
+}
@@ -0,0 +1,125 @@
+#![cfg(any(feature = "builder", feature = "decrypt"))]
+
+//! Key encapsulation support for `KEMRecipientInfo`
+//!
+//! Implements the key derivation of [RFC 9629 § 5](https://datatracker.ietf.org/doc/html/rfc9629#section-5)
+//! using HKDF as specified in [RFC 8619](https://datatracker.ietf.org/doc/html/rfc8619), which
+//! derives the key-encryption key for the AES key wrap algorithm from the shared secret of a KEM.
+//! The key encapsulation itself, e.g. using ML-KEM, is performed by the caller, see
+//! `builder::OriginatorKeyEncapsulation` and `decrypt::RecipientKeyEncapsulation`.
+
+use crate::enveloped_data::{CmsOriForKemOtherInfo, UserKeyingMaterial};
+use crate::key_agreement::KeyWrapAlgorithm;
+use alloc::{vec, vec::Vec};
+use const_oid::ObjectIdentifier;
+use der::{Encode, Tag};
+use hkdf::{Hkdf, HmacImpl};
+use hmac::digest::OutputSizeUser;
+use spki::AlgorithmIdentifierOwned;
+use zeroize::Zeroizing;
+
+/// `id-alg-hkdf-with-sha256` from
+/// [RFC 8619 § 2](https://datatracker.ietf.org/doc/html/rfc8619#section-2)
+pub const ID_ALG_HKDF_WITH_SHA256: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.16.3.28");
+
+/// `id-alg-hkdf-with-sha384` from
+/// [RFC 8619 § 2](https://datatracker.ietf.org/doc/html/rfc8619#section-2)
+pub const ID_ALG_HKDF_WITH_SHA384: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.16.3.29");
+
+/// `id-alg-hkdf-with-sha512` from
+/// [RFC 8619 § 2](https://datatracker.ietf.org/doc/html/rfc8619#section-2)
+pub const ID_ALG_HKDF_WITH_SHA512: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.16.3.30");
+
+/// Key derivation algorithms applied to the shared secret of a KEM
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum KemKeyDerivationAlgorithm {
+    /// `id-alg-hkdf-with-sha256`
+    HkdfSha256,
+    /// `id-alg-hkdf-with-sha384`
+    HkdfSha384,
+    /// `id-alg-hkdf-with-sha512`
+    HkdfSha512,
+}
+
+impl KemKeyDerivationAlgorithm {
+    /// Return the OID of the algorithm.
+    pub fn oid(&self) -> ObjectIdentifier {
+        match self {
+            KemKeyDerivationAlgorithm::HkdfSha256 => ID_ALG_HKDF_WITH_SHA256,
+            KemKeyDerivationAlgorithm::HkdfSha384 => ID_ALG_HKDF_WITH_SHA384,
+            KemKeyDerivationAlgorithm::HkdfSha512 => ID_ALG_HKDF_WITH_SHA512,
+        }
+    }
+
+    /// Get the algorithm identified by `oid`, if it is supported.
+    pub fn from_oid(oid: ObjectIdentifier) -> Option<Self> {
+        match oid {
+            ID_ALG_HKDF_WITH_SHA256 => Some(KemKeyDerivationAlgorithm::HkdfSha256),
+            ID_ALG_HKDF_WITH_SHA384 => Some(KemKeyDerivationAlgorithm::HkdfSha384),
+            ID_ALG_HKDF_WITH_SHA512 => Some(KemKeyDerivationAlgorithm::HkdfSha512),
+            _ => None,
+        }
+    }
+
+    /// Return the algorithm identifier. As specified in
+    /// [RFC 8619 § 3](https://datatracker.ietf.org/doc/html/rfc8619#section-3),
+    /// the parameters are absent.
+    pub fn algorithm_identifier(&self) -> AlgorithmIdentifierOwned {
+        AlgorithmIdentifierOwned {
+            oid: self.oid(),
+            parameters: None,
+        }
+    }
+
+    /// Derive the key-encryption key for `key_wrap_algorithm` from the shared secret using HKDF
+    /// without salt, i.e. a salt of zeros, and with `CMSORIforKEMOtherInfo` as info.
+    pub(crate) fn derive_key_encryption_key(
+        &self,
+        shared_secret: &[u8],
+        key_wrap_algorithm: KeyWrapAlgorithm,
+        ukm: Option<&UserKeyingMaterial>,
+    ) -> der::Result<Zeroizing<Vec<u8>>> {
+        let key_size = key_wrap_algorithm.key_size();
+        let info = CmsOriForKemOtherInfo {
+            wrap: key_wrap_algorithm.algorithm_identifier(),
+            kek_length: key_size as u16,
+            ukm: ukm.cloned(),
+        }
+        .to_der()?;
+
+        Ok(Zeroizing::new(match self {
+            KemKeyDerivationAlgorithm::HkdfSha256 => hkdf_expand(
+                &Hkdf::<sha2::Sha256>::new(None, shared_secret),
+                &info,
+                key_size,
+            )?,
+            KemKeyDerivationAlgorithm::HkdfSha384 => hkdf_expand(
+                &Hkdf::<sha2::Sha384>::new(None, shared_secret),
+                &info,
+                key_size,
+            )?,
+            KemKeyDerivationAlgorithm::HkdfSha512 => hkdf_expand(
+                &Hkdf::<sha2::Sha512>::new(None, shared_secret),
+                &info,
+                key_size,
+            )?,
+        }))
+    }
+}
+
+/// HKDF-Expand according to [RFC 5869](https://datatracker.ietf.org/doc/html/rfc5869).
+///
+/// Returns an error if `length` exceeds 255 times the output size of the hash.
+fn hkdf_expand<D, I>(hkdf: &Hkdf<D, I>, info: &[u8], length: usize) -> der::Result<Vec<u8>>
+where
+    D: OutputSizeUser,
+    I: HmacImpl<D>,
+{
+    let mut okm = vec![0u8; length];
+    hkdf.expand(info, &mut okm)
+        .map_err(|_| Tag::Integer.value_error())?;
+    Ok(okm)
+}
@@ -0,0 +1,206 @@
+#![cfg(any(feature = "builder", feature = "decrypt"))]
+
+//! Key agreement support for `KeyAgreeRecipientInfo`
+//!
+//! Implements the ANSI X9.63 key derivation of
+//! [RFC 5753 § 7.2](https://datatracker.ietf.org/doc/html/rfc5753#section-7.2), which is used
+//! together with the AES key wrap algorithm of
+//! [RFC 3394](https://datatracker.ietf.org/doc/html/rfc3394) to encrypt the content-encryption
+//! key with the shared secret of an (EC)DH key agreement. The key agreement itself is performed by the caller, see
+//! `builder::OriginatorKeyAgreement` and `decrypt::RecipientKeyAgreement`.
+
+use crate::enveloped_data::{EccCmsSharedInfo, UserKeyingMaterial};
+use aes_kw::{KekAes128, KekAes192, KekAes256};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use const_oid::ObjectIdentifier;
+use der::asn1::OctetString;
+use der::Encode;
+use sha2::digest::{Digest, DynDigest};
+use spki::AlgorithmIdentifierOwned;
+use zeroize::Zeroizing;
+
+/// `dhSinglePass-stdDH-sha1kdf-scheme` from
+/// [RFC 5753 § 7.1.4](https://datatracker.ietf.org/doc/html/rfc5753#section-7.1.4)
+pub const DH_SINGLE_PASS_STD_DH_SHA1KDF_SCHEME: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.3.133.16.840.63.0.2");
+
+/// `dhSinglePass-stdDH-sha224kdf-scheme` from
+/// [RFC 5753 § 7.1.4](https://datatracker.ietf.org/doc/html/rfc5753#section-7.1.4)
+pub const DH_SINGLE_PASS_STD_DH_SHA224KDF_SCHEME: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.3.132.1.11.0");
+
+/// `dhSinglePass-stdDH-sha256kdf-scheme` from
+/// [RFC 5753 § 7.1.4](https://datatracker.ietf.org/doc/html/rfc5753#section-7.1.4)
+pub const DH_SINGLE_PASS_STD_DH_SHA256KDF_SCHEME: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.3.132.1.11.1");
+
+/// `dhSinglePass-stdDH-sha384kdf-scheme` from
+/// [RFC 5753 § 7.1.4](https://datatracker.ietf.org/doc/html/rfc5753#section-7.1.4)
+pub const DH_SINGLE_PASS_STD_DH_SHA384KDF_SCHEME: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.3.132.1.11.2");
+
+/// `dhSinglePass-stdDH-sha512kdf-scheme` from
+/// [RFC 5753 § 7.1.4](https://datatracker.ietf.org/doc/html/rfc5753#section-7.1.4)
+pub const DH_SINGLE_PASS_STD_DH_SHA512KDF_SCHEME: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.3.132.1.11.3");
+
+/// Key agreement algorithms, i.e. the key derivation function applied to the shared secret
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum KeyAgreementAlgorithm {
+    /// `dhSinglePass-stdDH-sha1kdf-scheme`
+    StdDhSha1Kdf,
+    /// `dhSinglePass-stdDH-sha224kdf-scheme`
+    StdDhSha224Kdf,
+    /// `dhSinglePass-stdDH-sha256kdf-scheme`
+    StdDhSha256Kdf,
+    /// `dhSinglePass-stdDH-sha384kdf-scheme`
+    StdDhSha384Kdf,
+    /// `dhSinglePass-stdDH-sha512kdf-scheme`
+    StdDhSha512Kdf,
+}
+
+impl KeyAgreementAlgorithm {
+    /// Return the OID of the algorithm.
+    pub fn oid(&self) -> ObjectIdentifier {
+        match self {
+            KeyAgreementAlgorithm::StdDhSha1Kdf => DH_SINGLE_PASS_STD_DH_SHA1KDF_SCHEME,
+            KeyAgreementAlgorithm::StdDhSha224Kdf => DH_SINGLE_PASS_STD_DH_SHA224KDF_SCHEME,
+            KeyAgreementAlgorithm::StdDhSha256Kdf => DH_SINGLE_PASS_STD_DH_SHA256KDF_SCHEME,
+            KeyAgreementAlgorithm::StdDhSha384Kdf => DH_SINGLE_PASS_STD_DH_SHA384KDF_SCHEME,
+            KeyAgreementAlgorithm::StdDhSha512Kdf => DH_SINGLE_PASS_STD_DH_SHA512KDF_SCHEME,
+        }
+    }
+
+    /// Get the algorithm identified by `oid`, if it is supported.
+    pub fn from_oid(oid: ObjectIdentifier) -> Option<Self> {
+        match oid {
+            DH_SINGLE_PASS_STD_DH_SHA1KDF_SCHEME => Some(KeyAgreementAlgorithm::StdDhSha1Kdf),
+            DH_SINGLE_PASS_STD_DH_SHA224KDF_SCHEME => Some(KeyAgreementAlgorithm::StdDhSha224Kdf),
+            DH_SINGLE_PASS_STD_DH_SHA256KDF_SCHEME => Some(KeyAgreementAlgorithm::StdDhSha256Kdf),
+            DH_SINGLE_PASS_STD_DH_SHA384KDF_SCHEME => Some(KeyAgreementAlgorithm::StdDhSha384Kdf),
+            DH_SINGLE_PASS_STD_DH_SHA512KDF_SCHEME => Some(KeyAgreementAlgorithm::StdDhSha512Kdf),
+            _ => None,
+        }
+    }
+
+    fn hasher(&self) -> Box<dyn DynDigest> {
+        match self {
+            KeyAgreementAlgorithm::StdDhSha1Kdf => Box::new(sha1::Sha1::new()),
+            KeyAgreementAlgorithm::StdDhSha224Kdf => Box::new(sha2::Sha224::new()),
+            KeyAgreementAlgorithm::StdDhSha256Kdf => Box::new(sha2::Sha256::new()),
+            KeyAgreementAlgorithm::StdDhSha384Kdf => Box::new(sha2::Sha384::new()),
+            KeyAgreementAlgorithm::StdDhSha512Kdf => Box::new(sha2::Sha512::new()),
+        }
+    }
+
+    /// Derive the key-encryption key for `key_wrap_algorithm` from the shared secret using the
+    /// ANSI X9.63 KDF with `ECC-CMS-SharedInfo` as shared info.
+    pub(crate) fn derive_key_encryption_key(
+        &self,
+        shared_secret: &[u8],
+        key_wrap_algorithm: KeyWrapAlgorithm,
+        ukm: Option<&UserKeyingMaterial>,
+    ) -> der::Result<Zeroizing<Vec<u8>>> {
+        let key_size = key_wrap_algorithm.key_size();
+        let shared_info = EccCmsSharedInfo {
+            key_info: key_wrap_algorithm.algorithm_identifier(),
+            entity_u_info: ukm.cloned(),
+            supp_pub_info: OctetString::new(((key_size * 8) as u32).to_be_bytes())?,
+        }
+        .to_der()?;
+
+        let mut hasher = self.hasher();
+        let mut key_encryption_key =
+            Zeroizing::new(Vec::with_capacity(key_size + hasher.output_size()));
+        let mut counter: u32 = 1;
+        while key_encryption_key.len() < key_size {
+            hasher.update(shared_secret);
+            hasher.update(&counter.to_be_bytes());
+            hasher.update(&shared_info);
+            key_encryption_key.extend_from_slice(&hasher.finalize_reset());
+            counter += 1;
+        }
+        key_encryption_key.truncate(key_size);
+
+        Ok(key_encryption_key)
+    }
+}
+
+/// Key wrap algorithms used to encrypt the content-encryption key with the key-encryption key
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum KeyWrapAlgorithm {
+    /// `id-aes128-wrap`
+    Aes128,
+    /// `id-aes192-wrap`
+    Aes192,
+    /// `id-aes256-wrap`
+    Aes256,
+}
+
+impl KeyWrapAlgorithm {
+    /// Return the OID of the algorithm.
+    pub fn oid(&self) -> ObjectIdentifier {
+        match self {
+            KeyWrapAlgorithm::Aes128 => const_oid::db::rfc5911::ID_AES_128_WRAP,
+            KeyWrapAlgorithm::Aes192 => const_oid::db::rfc5911::ID_AES_192_WRAP,
+            KeyWrapAlgorithm::Aes256 => const_oid::db::rfc5911::ID_AES_256_WRAP,
+        }
+    }
+
+    /// Get the algorithm identified by `oid`, if it is supported.
+    pub fn from_oid(oid: ObjectIdentifier) -> Option<Self> {
+        match oid {
+            const_oid::db::rfc5911::ID_AES_128_WRAP => Some(KeyWrapAlgorithm::Aes128),
+            const_oid::db::rfc5911::ID_AES_192_WRAP => Some(KeyWrapAlgorithm::Aes192),
+            const_oid::db::rfc5911::ID_AES_256_WRAP => Some(KeyWrapAlgorithm::Aes256),
+            _ => None,
+        }
+    }
+
+    /// Return the size of the key-encryption key in bytes.
+    pub fn key_size(&self) -> usize {
+        match self {
+            KeyWrapAlgorithm::Aes128 => 16,
+            KeyWrapAlgorithm::Aes192 => 24,
+            KeyWrapAlgorithm::Aes256 => 32,
+        }
+    }
+
+    /// Return the algorithm identifier. As specified in
+    /// [RFC 3565 § 2.3.2](https://datatracker.ietf.org/doc/html/rfc3565#section-2.3.2),
+    /// the parameters are absent.
+    pub fn algorithm_identifier(&self) -> AlgorithmIdentifierOwned {
+        AlgorithmIdentifierOwned {
+            oid: self.oid(),
+            parameters: None,
+        }
+    }
+
+    /// Wrap `key` with `key_encryption_key`. Returns `None` if either key has an invalid size.
+    pub fn wrap(&self, key_encryption_key: &[u8], key: &[u8]) -> Option<Vec<u8>> {
+        match self {
+            KeyWrapAlgorithm::Aes128 => KekAes128::try_from(key_encryption_key).ok()?.wrap_vec(key),
+            KeyWrapAlgorithm::Aes192 => KekAes192::try_from(key_encryption_key).ok()?.wrap_vec(key),
+            KeyWrapAlgorithm::Aes256 => KekAes256::try_from(key_encryption_key).ok()?.wrap_vec(key),
+        }
+        .ok()
+    }
+
+    /// Unwrap `wrapped_key` with `key_encryption_key`. Returns `None` if the integrity check
+    /// fails or either key has an invalid size.
+    pub fn unwrap(&self, key_encryption_key: &[u8], wrapped_key: &[u8]) -> Option<Vec<u8>> {
+        match self {
+            KeyWrapAlgorithm::Aes128 => KekAes128::try_from(key_encryption_key)
+                .ok()?
+                .unwrap_vec(wrapped_key),
+            KeyWrapAlgorithm::Aes192 => KekAes192::try_from(key_encryption_key)
+                .ok()?
+                .unwrap_vec(wrapped_key),
+            KeyWrapAlgorithm::Aes256 => KekAes256::try_from(key_encryption_key)
+                .ok()?
+                .unwrap_vec(wrapped_key),
+        }
+        .ok()
+    }
+}
@@ -28,13 +28,25 @@ extern crate alloc;
 extern crate std;
 
 pub mod attr;
+pub mod auth_enveloped_data;
 pub mod authenticated_data;
+pub mod authenticode;
 pub mod builder;
 pub mod cert;
+pub mod compress;
 pub mod compressed_data;
 pub mod content_info;
+pub mod decrypt;
 pub mod digested_data;
 pub mod encrypted_data;
 pub mod enveloped_data;
+pub mod kem;
+pub mod key_agreement;
+pub mod password;
 pub mod revocation;
+pub mod scep;
 pub mod signed_data;
+pub mod verify;
+
+mod gcm;
+mod mac;
@@ -0,0 +1,73 @@
+#![cfg(any(feature = "builder", feature = "decrypt"))]
+
+//! HMAC message authentication codes, as used by `AuthenticatedData` according to
+//! [RFC 5652 § 9](https://datatracker.ietf.org/doc/html/rfc5652#section-9) and
+//! [RFC 4231](https://datatracker.ietf.org/doc/html/rfc4231).
+
+#[cfg(feature = "builder")]
+use alloc::vec::Vec;
+use const_oid::ObjectIdentifier;
+use hmac::digest::KeyInit;
+use hmac::{Hmac, Mac};
+
+/// Get the size of the authentication key in bytes, which is the output size of the hash
+/// function as recommended by [RFC 2104 § 3](https://datatracker.ietf.org/doc/html/rfc2104#section-3).
+#[cfg(feature = "decrypt")]
+pub(crate) fn key_size(oid: ObjectIdentifier) -> Option<usize> {
+    match oid {
+        const_oid::db::rfc6268::ID_HMAC_WITH_SHA_256 => Some(32),
+        const_oid::db::rfc6268::ID_HMAC_WITH_SHA_384 => Some(48),
+        const_oid::db::rfc6268::ID_HMAC_WITH_SHA_512 => Some(64),
+        _ => None,
+    }
+}
+
+/// Calculate the message authentication code of `data` using the HMAC algorithm identified by
+/// `oid`.
+#[cfg(feature = "builder")]
+pub(crate) fn compute(oid: ObjectIdentifier, key: &[u8], data: &[u8]) -> Option<Vec<u8>> {
+    match oid {
+        const_oid::db::rfc6268::ID_HMAC_WITH_SHA_256 => {
+            compute_hmac::<Hmac<sha2::Sha256>>(key, data)
+        }
+        const_oid::db::rfc6268::ID_HMAC_WITH_SHA_384 => {
+            compute_hmac::<Hmac<sha2::Sha384>>(key, data)
+        }
+        const_oid::db::rfc6268::ID_HMAC_WITH_SHA_512 => {
+            compute_hmac::<Hmac<sha2::Sha512>>(key, data)
+        }
+        _ => None,
+    }
+}
+
+/// Verify the message authentication code `mac` of `data` in constant time. Returns `None` if
+/// the algorithm identified by `oid` is not supported.
+#[cfg(feature = "decrypt")]
+pub(crate) fn verify(oid: ObjectIdentifier, key: &[u8], data: &[u8], mac: &[u8]) -> Option<bool> {
+    match oid {
+        const_oid::db::rfc6268::ID_HMAC_WITH_SHA_256 => {
+            verify_hmac::<Hmac<sha2::Sha256>>(key, data, mac)
+        }
+        const_oid::db::rfc6268::ID_HMAC_WITH_SHA_384 => {
+            verify_hmac::<Hmac<sha2::Sha384>>(key, data, mac)
+        }
+        const_oid::db::rfc6268::ID_HMAC_WITH_SHA_512 => {
+            verify_hmac::<Hmac<sha2::Sha512>>(key, data, mac)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(feature = "builder")]
+fn compute_hmac<M: Mac + KeyInit>(key: &[u8], data: &[u8]) -> Option<Vec<u8>> {
+    let mut hmac = <M as KeyInit>::new_from_slice(key).ok()?;
+    hmac.update(data);
+    Some(hmac.finalize().into_bytes().to_vec())
+}
+
+#[cfg(feature = "decrypt")]
+fn verify_hmac<M: Mac + KeyInit>(key: &[u8], data: &[u8], mac: &[u8]) -> Option<bool> {
+    let mut hmac = <M as KeyInit>::new_from_slice(key).ok()?;
+    hmac.update(data);
+    Some(hmac.verify_slice(mac).is_ok())
+}
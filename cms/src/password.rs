@@ -0,0 +1,318 @@
+#![cfg(any(feature = "builder", feature = "decrypt"))]
+
+//! Password-based key encryption support for `PasswordRecipientInfo`
+//!
+//! The key-encryption key is derived from the password using PBKDF2 as specified in
+//! [RFC 3211 § 2.2](https://datatracker.ietf.org/doc/html/rfc3211#section-2.2). The
+//! content-encryption key is then encrypted with either the `id-alg-PWRI-KEK` key wrap
+//! algorithm of [RFC 3211 § 2.3](https://datatracker.ietf.org/doc/html/rfc3211#section-2.3)
+//! or the AES key wrap algorithm of [RFC 3394](https://datatracker.ietf.org/doc/html/rfc3394).
+
+use crate::key_agreement::KeyWrapAlgorithm;
+use alloc::vec;
+use alloc::vec::Vec;
+use cipher::block_padding::NoPadding;
+use cipher::consts::U16;
+use cipher::{
+    BlockCipher, BlockDecrypt, BlockDecryptMut, BlockEncrypt, BlockEncryptMut, KeyInit, KeyIvInit,
+};
+use const_oid::ObjectIdentifier;
+use der::asn1::OctetStringRef;
+use der::{Any, Tag};
+use pkcs5::pbes2::{Pbkdf2Params, Pbkdf2Prf};
+use rand_core::CryptoRngCore;
+use spki::{AlgorithmIdentifierOwned, AlgorithmIdentifierRef};
+use zeroize::Zeroizing;
+
+/// `id-alg-PWRI-KEK` from [RFC 3211 § 2.3](https://datatracker.ietf.org/doc/html/rfc3211#section-2.3)
+pub const ID_ALG_PWRI_KEK: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.16.3.9");
+
+/// Block size of AES in bytes.
+const AES_BLOCK_SIZE: usize = 16;
+
+/// Key encryption algorithms used to encrypt the content-encryption key with the key-encryption
+/// key derived from the password
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PasswordKeyEncryptionAlgorithm {
+    /// `id-alg-PWRI-KEK` with AES-128 in CBC mode
+    PwriKekAes128Cbc,
+    /// `id-alg-PWRI-KEK` with AES-192 in CBC mode
+    PwriKekAes192Cbc,
+    /// `id-alg-PWRI-KEK` with AES-256 in CBC mode
+    PwriKekAes256Cbc,
+    /// AES key wrap
+    KeyWrap(KeyWrapAlgorithm),
+}
+
+impl PasswordKeyEncryptionAlgorithm {
+    /// Return the OID of the algorithm.
+    pub fn oid(&self) -> ObjectIdentifier {
+        match self {
+            PasswordKeyEncryptionAlgorithm::PwriKekAes128Cbc
+            | PasswordKeyEncryptionAlgorithm::PwriKekAes192Cbc
+            | PasswordKeyEncryptionAlgorithm::PwriKekAes256Cbc => ID_ALG_PWRI_KEK,
+            PasswordKeyEncryptionAlgorithm::KeyWrap(key_wrap_algorithm) => key_wrap_algorithm.oid(),
+        }
+    }
+
+    /// Return the size of the key-encryption key in bytes.
+    pub fn key_size(&self) -> usize {
+        match self {
+            PasswordKeyEncryptionAlgorithm::PwriKekAes128Cbc => 16,
+            PasswordKeyEncryptionAlgorithm::PwriKekAes192Cbc => 24,
+            PasswordKeyEncryptionAlgorithm::PwriKekAes256Cbc => 32,
+            PasswordKeyEncryptionAlgorithm::KeyWrap(key_wrap_algorithm) => {
+                key_wrap_algorithm.key_size()
+            }
+        }
+    }
+
+    /// Return the OID of the block cipher used by `id-alg-PWRI-KEK`.
+    fn pwri_kek_cipher_oid(&self) -> Option<ObjectIdentifier> {
+        match self {
+            PasswordKeyEncryptionAlgorithm::PwriKekAes128Cbc => {
+                Some(const_oid::db::rfc5911::ID_AES_128_CBC)
+            }
+            PasswordKeyEncryptionAlgorithm::PwriKekAes192Cbc => {
+                Some(const_oid::db::rfc5911::ID_AES_192_CBC)
+            }
+            PasswordKeyEncryptionAlgorithm::PwriKekAes256Cbc => {
+                Some(const_oid::db::rfc5911::ID_AES_256_CBC)
+            }
+            PasswordKeyEncryptionAlgorithm::KeyWrap(_) => None,
+        }
+    }
+
+    /// Return the algorithm identifier. For `id-alg-PWRI-KEK`, the parameters are the algorithm
+    /// identifier of the block cipher with `iv` as parameters, see
+    /// [RFC 3211 § 2.3.1](https://datatracker.ietf.org/doc/html/rfc3211#section-2.3.1).
+    /// For AES key wrap, `iv` is ignored.
+    pub fn algorithm_identifier(&self, iv: &[u8]) -> der::Result<AlgorithmIdentifierOwned> {
+        match self.pwri_kek_cipher_oid() {
+            Some(cipher_oid) => Ok(AlgorithmIdentifierOwned {
+                oid: ID_ALG_PWRI_KEK,
+                parameters: Some(Any::encode_from(&AlgorithmIdentifierOwned {
+                    oid: cipher_oid,
+                    parameters: Some(Any::new(Tag::OctetString, iv)?),
+                })?),
+            }),
+            None => Ok(AlgorithmIdentifierOwned {
+                oid: self.oid(),
+                parameters: None,
+            }),
+        }
+    }
+
+    /// Get the algorithm identified by `algorithm_identifier`, together with the IV for
+    /// `id-alg-PWRI-KEK`, if it is supported.
+    pub fn from_algorithm_identifier(
+        algorithm_identifier: &AlgorithmIdentifierOwned,
+    ) -> der::Result<Option<(Self, &[u8])>> {
+        if let Some(key_wrap_algorithm) = KeyWrapAlgorithm::from_oid(algorithm_identifier.oid) {
+            return Ok(Some((
+                PasswordKeyEncryptionAlgorithm::KeyWrap(key_wrap_algorithm),
+                &[],
+            )));
+        }
+        if algorithm_identifier.oid != ID_ALG_PWRI_KEK {
+            return Ok(None);
+        }
+
+        let cipher = algorithm_identifier
+            .parameters
+            .as_ref()
+            .ok_or(Tag::Sequence.value_error())?
+            .decode_as::<AlgorithmIdentifierRef<'_>>()?;
+        let algorithm = match cipher.oid {
+            const_oid::db::rfc5911::ID_AES_128_CBC => {
+                PasswordKeyEncryptionAlgorithm::PwriKekAes128Cbc
+            }
+            const_oid::db::rfc5911::ID_AES_192_CBC => {
+                PasswordKeyEncryptionAlgorithm::PwriKekAes192Cbc
+            }
+            const_oid::db::rfc5911::ID_AES_256_CBC => {
+                PasswordKeyEncryptionAlgorithm::PwriKekAes256Cbc
+            }
+            _ => return Ok(None),
+        };
+        let iv = cipher
+            .parameters
+            .ok_or(Tag::OctetString.value_error())?
+            .decode_as::<OctetStringRef<'_>>()?;
+        if iv.as_bytes().len() != AES_BLOCK_SIZE {
+            return Err(Tag::OctetString.value_error());
+        }
+
+        Ok(Some((algorithm, iv.as_bytes())))
+    }
+
+    /// Wrap `key` with `key_encryption_key`. `rng` is used to generate the padding of
+    /// `id-alg-PWRI-KEK`. Returns `None` if either key has an invalid size.
+    pub fn wrap(
+        &self,
+        key_encryption_key: &[u8],
+        key: &[u8],
+        iv: &[u8],
+        rng: &mut impl CryptoRngCore,
+    ) -> Option<Vec<u8>> {
+        match self {
+            PasswordKeyEncryptionAlgorithm::PwriKekAes128Cbc => {
+                pwri_kek_wrap::<aes::Aes128>(key_encryption_key, key, iv, rng)
+            }
+            PasswordKeyEncryptionAlgorithm::PwriKekAes192Cbc => {
+                pwri_kek_wrap::<aes::Aes192>(key_encryption_key, key, iv, rng)
+            }
+            PasswordKeyEncryptionAlgorithm::PwriKekAes256Cbc => {
+                pwri_kek_wrap::<aes::Aes256>(key_encryption_key, key, iv, rng)
+            }
+            PasswordKeyEncryptionAlgorithm::KeyWrap(key_wrap_algorithm) => {
+                key_wrap_algorithm.wrap(key_encryption_key, key)
+            }
+        }
+    }
+
+    /// Unwrap `wrapped_key` with `key_encryption_key`. Returns `None` if the integrity check
+    /// fails or either key has an invalid size.
+    pub fn unwrap(
+        &self,
+        key_encryption_key: &[u8],
+        wrapped_key: &[u8],
+        iv: &[u8],
+    ) -> Option<Vec<u8>> {
+        match self {
+            PasswordKeyEncryptionAlgorithm::PwriKekAes128Cbc => {
+                pwri_kek_unwrap::<aes::Aes128>(key_encryption_key, wrapped_key, iv)
+            }
+            PasswordKeyEncryptionAlgorithm::PwriKekAes192Cbc => {
+                pwri_kek_unwrap::<aes::Aes192>(key_encryption_key, wrapped_key, iv)
+            }
+            PasswordKeyEncryptionAlgorithm::PwriKekAes256Cbc => {
+                pwri_kek_unwrap::<aes::Aes256>(key_encryption_key, wrapped_key, iv)
+            }
+            PasswordKeyEncryptionAlgorithm::KeyWrap(key_wrap_algorithm) => {
+                key_wrap_algorithm.unwrap(key_encryption_key, wrapped_key)
+            }
+        }
+    }
+}
+
+/// Derive a key-encryption key of `key_size` bytes from `password` using PBKDF2. Returns `None`
+/// if the parameters are not supported or specify a different key length.
+pub(crate) fn derive_key_encryption_key(
+    password: &[u8],
+    params: &Pbkdf2Params<'_>,
+    key_size: usize,
+) -> Option<Zeroizing<Vec<u8>>> {
+    if params.iteration_count > Pbkdf2Params::MAX_ITERATION_COUNT
+        || params
+            .key_length
+            .map_or(false, |key_length| usize::from(key_length) != key_size)
+    {
+        return None;
+    }
+
+    let mut key_encryption_key = Zeroizing::new(vec![0u8; key_size]);
+    let (salt, rounds) = (params.salt, params.iteration_count);
+    match params.prf {
+        Pbkdf2Prf::HmacWithSha1 => {
+            pbkdf2::pbkdf2_hmac::<sha1::Sha1>(password, salt, rounds, &mut key_encryption_key)
+        }
+        Pbkdf2Prf::HmacWithSha224 => {
+            pbkdf2::pbkdf2_hmac::<sha2::Sha224>(password, salt, rounds, &mut key_encryption_key)
+        }
+        Pbkdf2Prf::HmacWithSha256 => {
+            pbkdf2::pbkdf2_hmac::<sha2::Sha256>(password, salt, rounds, &mut key_encryption_key)
+        }
+        Pbkdf2Prf::HmacWithSha384 => {
+            pbkdf2::pbkdf2_hmac::<sha2::Sha384>(password, salt, rounds, &mut key_encryption_key)
+        }
+        Pbkdf2Prf::HmacWithSha512 => {
+            pbkdf2::pbkdf2_hmac::<sha2::Sha512>(password, salt, rounds, &mut key_encryption_key)
+        }
+        _ => return None,
+    }
+
+    Some(key_encryption_key)
+}
+
+/// Wrap `key` according to [RFC 3211 § 2.3.1](https://datatracker.ietf.org/doc/html/rfc3211#section-2.3.1):
+/// the key is formatted as `LEN || check value || key || padding` and encrypted twice in CBC
+/// mode, the second time using the last ciphertext block of the first pass as IV.
+fn pwri_kek_wrap<C>(
+    key_encryption_key: &[u8],
+    key: &[u8],
+    iv: &[u8],
+    rng: &mut impl CryptoRngCore,
+) -> Option<Vec<u8>>
+where
+    C: BlockCipher<BlockSize = U16> + BlockEncrypt + KeyInit,
+{
+    if key.len() < 3 || key.len() > usize::from(u8::MAX) {
+        return None;
+    }
+
+    let len = ((4 + key.len() + AES_BLOCK_SIZE - 1) / AES_BLOCK_SIZE * AES_BLOCK_SIZE)
+        .max(2 * AES_BLOCK_SIZE);
+    let mut formatted_key = Zeroizing::new(Vec::with_capacity(len));
+    formatted_key.push(key.len() as u8);
+    formatted_key.extend(key[..3].iter().map(|byte| !byte));
+    formatted_key.extend_from_slice(key);
+    let mut padding = vec![0u8; len - formatted_key.len()];
+    rng.fill_bytes(&mut padding);
+    formatted_key.extend_from_slice(&padding);
+
+    let first_pass = cbc::Encryptor::<C>::new_from_slices(key_encryption_key, iv)
+        .ok()?
+        .encrypt_padded_vec_mut::<NoPadding>(&formatted_key);
+    let second_iv = &first_pass[first_pass.len() - AES_BLOCK_SIZE..];
+    let wrapped_key = cbc::Encryptor::<C>::new_from_slices(key_encryption_key, second_iv)
+        .ok()?
+        .encrypt_padded_vec_mut::<NoPadding>(&first_pass);
+
+    Some(wrapped_key)
+}
+
+/// Unwrap `wrapped_key` according to
+/// [RFC 3211 § 2.3.2](https://datatracker.ietf.org/doc/html/rfc3211#section-2.3.2).
+fn pwri_kek_unwrap<C>(key_encryption_key: &[u8], wrapped_key: &[u8], iv: &[u8]) -> Option<Vec<u8>>
+where
+    C: BlockCipher<BlockSize = U16> + BlockDecrypt + KeyInit,
+{
+    let len = wrapped_key.len();
+    if len < 2 * AES_BLOCK_SIZE || len % AES_BLOCK_SIZE != 0 {
+        return None;
+    }
+
+    // Decrypting the last block with the second-to-last block as IV yields the last block of
+    // the first pass, which is the IV of the second pass.
+    let cipher = C::new_from_slice(key_encryption_key).ok()?;
+    let mut second_iv = cipher::Block::<C>::clone_from_slice(&wrapped_key[len - AES_BLOCK_SIZE..]);
+    cipher.decrypt_block(&mut second_iv);
+    second_iv
+        .iter_mut()
+        .zip(&wrapped_key[len - 2 * AES_BLOCK_SIZE..len - AES_BLOCK_SIZE])
+        .for_each(|(byte, previous)| *byte ^= previous);
+
+    let first_pass = cbc::Decryptor::<C>::new_from_slices(key_encryption_key, &second_iv)
+        .ok()?
+        .decrypt_padded_vec_mut::<NoPadding>(wrapped_key)
+        .ok()?;
+    let formatted_key = Zeroizing::new(
+        cbc::Decryptor::<C>::new_from_slices(key_encryption_key, iv)
+            .ok()?
+            .decrypt_padded_vec_mut::<NoPadding>(&first_pass)
+            .ok()?,
+    );
+
+    let key_len = usize::from(formatted_key[0]);
+    let check_value_is_valid = (0..3)
+        .map(|i| formatted_key[1 + i] ^ formatted_key[4 + i])
+        .fold(0, |acc, check| acc | (check ^ 0xFF))
+        == 0;
+    if key_len >= 3 && key_len <= len - 4 && check_value_is_valid {
+        Some(formatted_key[4..4 + key_len].to_vec())
+    } else {
+        None
+    }
+}
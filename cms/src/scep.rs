@@ -0,0 +1,367 @@
+//! SCEP-related types
+//!
+//! A SCEP `pkiMessage`, as defined in [RFC 8894 Section 3], is a `SignedData` whose signed
+//! attributes carry the SCEP message attributes, see [`ScepAttributes`]. Unless the message
+//! has no content, e.g. a failed `CertRep`, the encapsulated content is an `EnvelopedData`
+//! (the `pkcsPKIEnvelope`) holding the `messageData`:
+//! - a `CertReq` (PKCS #10 certification request) for `PKCSReq` and `RenewalReq`,
+//! - a degenerate certs-only `SignedData` for a successful `CertRep`, see
+//!   [`create_cert_rep_message_data`] and [`cert_rep_certificates`].
+//!
+//! The `pkcsPKIEnvelope` is created with
+//! [`EnvelopedDataBuilder`](crate::builder::EnvelopedDataBuilder), encapsulated with
+//! [`create_pki_message_content`] and signed with
+//! [`SignedDataBuilder`](crate::builder::SignedDataBuilder), adding the
+//! [`ScepAttributes::to_attributes`] as signed attributes.
+//!
+//! [RFC 8894 Section 3]: https://www.rfc-editor.org/rfc/rfc8894#section-3
+
+use crate::content_info::ContentInfo;
+use crate::enveloped_data::EnvelopedData;
+use crate::signed_data::{EncapsulatedContentInfo, SignedData, SignerInfo};
+use alloc::string::String;
+use alloc::vec::Vec;
+use const_oid::ObjectIdentifier;
+use der::asn1::{OctetString, PrintableString, PrintableStringRef, SetOfVec};
+use der::{
+    Any, Decode, DecodeValue, Encode, EncodeValue, ErrorKind, FixedTag, Header, Length, Reader,
+    Tag, Tagged, Writer,
+};
+use x509_cert::attr::Attribute;
+use x509_cert::Certificate;
+
+/// `id-transactionID`, the signed attribute holding the transaction ID
+pub const ID_TRANSACTION_ID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("2.16.840.1.113733.1.9.7");
+
+/// `id-messageType`, the signed attribute holding a [`MessageType`]
+pub const ID_MESSAGE_TYPE: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("2.16.840.1.113733.1.9.2");
+
+/// `id-pkiStatus`, the signed attribute holding a [`PkiStatus`]
+pub const ID_PKI_STATUS: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.16.840.1.113733.1.9.3");
+
+/// `id-failInfo`, the signed attribute holding a [`FailInfo`]
+pub const ID_FAIL_INFO: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.16.840.1.113733.1.9.4");
+
+/// `id-senderNonce`, the signed attribute holding the sender nonce
+pub const ID_SENDER_NONCE: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("2.16.840.1.113733.1.9.5");
+
+/// `id-recipientNonce`, the signed attribute holding the recipient nonce
+pub const ID_RECIPIENT_NONCE: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("2.16.840.1.113733.1.9.6");
+
+/// `id-scep-failInfoText`, the signed attribute holding a free-form failure description
+pub const ID_SCEP_FAIL_INFO_TEXT: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.3.6.1.5.5.7.24.1");
+
+/// Implement DER encoding of an enum as the `PrintableString` of its decimal value.
+macro_rules! impl_printable_string_enum {
+    ($ty:ty, $($variant:ident => $value:literal),+) => {
+        impl $ty {
+            /// Get the decimal value of this variant, as encoded in the attribute.
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $value,)+
+                }
+            }
+        }
+
+        impl FixedTag for $ty {
+            const TAG: Tag = Tag::PrintableString;
+        }
+
+        impl<'a> DecodeValue<'a> for $ty {
+            fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> der::Result<Self> {
+                match PrintableStringRef::decode_value(reader, header)?.as_str() {
+                    $($value => Ok(Self::$variant),)+
+                    _ => Err(Tag::PrintableString.value_error()),
+                }
+            }
+        }
+
+        impl EncodeValue for $ty {
+            fn value_len(&self) -> der::Result<Length> {
+                Length::try_from(self.as_str().len())
+            }
+
+            fn encode_value(&self, writer: &mut impl Writer) -> der::Result<()> {
+                writer.write(self.as_str().as_bytes())
+            }
+        }
+    };
+}
+
+/// The `messageType` attribute is defined in [RFC 8894 Section 3.2.1.2].
+///
+/// ```text
+///   MessageType ::= PrintableString
+/// ```
+///
+/// [RFC 8894 Section 3.2.1.2]: https://www.rfc-editor.org/rfc/rfc8894#section-3.2.1.2
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[allow(missing_docs)]
+pub enum MessageType {
+    CertRep,
+    RenewalReq,
+    PkcsReq,
+    CertPoll,
+    GetCert,
+    GetCrl,
+}
+
+impl_printable_string_enum!(
+    MessageType,
+    CertRep => "3",
+    RenewalReq => "17",
+    PkcsReq => "19",
+    CertPoll => "20",
+    GetCert => "21",
+    GetCrl => "22"
+);
+
+/// The `pkiStatus` attribute is defined in [RFC 8894 Section 3.2.1.3].
+///
+/// ```text
+///   PkiStatus ::= PrintableString
+/// ```
+///
+/// [RFC 8894 Section 3.2.1.3]: https://www.rfc-editor.org/rfc/rfc8894#section-3.2.1.3
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[allow(missing_docs)]
+pub enum PkiStatus {
+    Success,
+    Failure,
+    Pending,
+}
+
+impl_printable_string_enum!(
+    PkiStatus,
+    Success => "0",
+    Failure => "2",
+    Pending => "3"
+);
+
+/// The `failInfo` attribute is defined in [RFC 8894 Section 3.2.1.4].
+///
+/// ```text
+///   FailInfo ::= PrintableString
+/// ```
+///
+/// [RFC 8894 Section 3.2.1.4]: https://www.rfc-editor.org/rfc/rfc8894#section-3.2.1.4
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[allow(missing_docs)]
+pub enum FailInfo {
+    BadAlg,
+    BadMessageCheck,
+    BadRequest,
+    BadTime,
+    BadCertId,
+}
+
+impl_printable_string_enum!(
+    FailInfo,
+    BadAlg => "0",
+    BadMessageCheck => "1",
+    BadRequest => "2",
+    BadTime => "3",
+    BadCertId => "4"
+);
+
+/// The signed attributes of a SCEP `pkiMessage`, defined in [RFC 8894 Section 3.2.1].
+///
+/// ```text
+///   transactionID   PrintableString
+///   messageType     PrintableString
+///   senderNonce     OCTET STRING
+///   recipientNonce  OCTET STRING         -- CertRep only
+///   pkiStatus       PrintableString      -- CertRep only
+///   failInfo        PrintableString      -- CertRep with pkiStatus FAILURE only
+///   failInfoText    UTF8String           -- optional, CertRep with pkiStatus FAILURE only
+/// ```
+///
+/// [RFC 8894 Section 3.2.1]: https://www.rfc-editor.org/rfc/rfc8894#section-3.2.1
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[allow(missing_docs)]
+pub struct ScepAttributes {
+    pub transaction_id: PrintableString,
+    pub message_type: MessageType,
+    pub sender_nonce: OctetString,
+    pub recipient_nonce: Option<OctetString>,
+    pub pki_status: Option<PkiStatus>,
+    pub fail_info: Option<FailInfo>,
+    pub fail_info_text: Option<String>,
+}
+
+impl ScepAttributes {
+    /// Create the attributes of a `PKCSReq` message.
+    pub fn pkcs_req(transaction_id: PrintableString, sender_nonce: OctetString) -> Self {
+        Self {
+            transaction_id,
+            message_type: MessageType::PkcsReq,
+            sender_nonce,
+            recipient_nonce: None,
+            pki_status: None,
+            fail_info: None,
+            fail_info_text: None,
+        }
+    }
+
+    /// Create the attributes of a `CertRep` message responding to the request with the
+    /// attributes `request`. The transaction ID is copied and the sender nonce of the request
+    /// becomes the recipient nonce.
+    pub fn cert_rep(
+        request: &ScepAttributes,
+        pki_status: PkiStatus,
+        sender_nonce: OctetString,
+    ) -> Self {
+        Self {
+            transaction_id: request.transaction_id.clone(),
+            message_type: MessageType::CertRep,
+            sender_nonce,
+            recipient_nonce: Some(request.sender_nonce.clone()),
+            pki_status: Some(pki_status),
+            fail_info: None,
+            fail_info_text: None,
+        }
+    }
+
+    /// Create the signed attributes to be added to the `SignerInfo` of the `pkiMessage`.
+    pub fn to_attributes(&self) -> der::Result<Vec<Attribute>> {
+        let mut attributes = Vec::new();
+        attributes.push(single_value_attribute(
+            ID_TRANSACTION_ID,
+            &self.transaction_id,
+        )?);
+        attributes.push(single_value_attribute(ID_MESSAGE_TYPE, &self.message_type)?);
+        attributes.push(single_value_attribute(ID_SENDER_NONCE, &self.sender_nonce)?);
+        if let Some(recipient_nonce) = &self.recipient_nonce {
+            attributes.push(single_value_attribute(ID_RECIPIENT_NONCE, recipient_nonce)?);
+        }
+        if let Some(pki_status) = &self.pki_status {
+            attributes.push(single_value_attribute(ID_PKI_STATUS, pki_status)?);
+        }
+        if let Some(fail_info) = &self.fail_info {
+            attributes.push(single_value_attribute(ID_FAIL_INFO, fail_info)?);
+        }
+        if let Some(fail_info_text) = &self.fail_info_text {
+            attributes.push(single_value_attribute(
+                ID_SCEP_FAIL_INFO_TEXT,
+                fail_info_text,
+            )?);
+        }
+        Ok(attributes)
+    }
+
+    /// Parse the SCEP attributes from the signed attributes of `signer_info`. Each attribute
+    /// must be present at most once and have exactly one value.
+    pub fn from_signer_info(signer_info: &SignerInfo) -> der::Result<Self> {
+        let attributes = signer_info
+            .signed_attrs
+            .as_ref()
+            .map(|signed_attrs| signed_attrs.as_slice())
+            .unwrap_or_default();
+        let required =
+            |oid| find_attribute_value(attributes, oid)?.ok_or_else(|| Tag::Set.value_error());
+
+        Ok(Self {
+            transaction_id: required(ID_TRANSACTION_ID)?.decode_as()?,
+            message_type: required(ID_MESSAGE_TYPE)?.decode_as()?,
+            sender_nonce: required(ID_SENDER_NONCE)?.decode_as()?,
+            recipient_nonce: find_attribute_value(attributes, ID_RECIPIENT_NONCE)?
+                .map(Any::decode_as)
+                .transpose()?,
+            pki_status: find_attribute_value(attributes, ID_PKI_STATUS)?
+                .map(Any::decode_as)
+                .transpose()?,
+            fail_info: find_attribute_value(attributes, ID_FAIL_INFO)?
+                .map(Any::decode_as)
+                .transpose()?,
+            fail_info_text: find_attribute_value(attributes, ID_SCEP_FAIL_INFO_TEXT)?
+                .map(Any::decode_as)
+                .transpose()?,
+        })
+    }
+}
+
+/// Encapsulate the DER encoding of the `pkcsPKIEnvelope` (a `ContentInfo` of type
+/// `envelopedData`) as the content of a `pkiMessage`.
+pub fn create_pki_message_content(
+    pkcs_pki_envelope: &ContentInfo,
+) -> der::Result<EncapsulatedContentInfo> {
+    if pkcs_pki_envelope.content_type != const_oid::db::rfc5911::ID_ENVELOPED_DATA {
+        return Err(ErrorKind::OidUnknown {
+            oid: pkcs_pki_envelope.content_type,
+        }
+        .into());
+    }
+    Ok(EncapsulatedContentInfo {
+        econtent_type: const_oid::db::rfc5911::ID_DATA,
+        econtent: Some(Any::new(Tag::OctetString, pkcs_pki_envelope.to_der()?)?),
+    })
+}
+
+/// Extract the `pkcsPKIEnvelope` from the content of a `pkiMessage`. Returns `None` if the
+/// message has no content, as e.g. a `CertRep` with a `pkiStatus` other than `SUCCESS`.
+pub fn pkcs_pki_envelope(signed_data: &SignedData) -> der::Result<Option<EnvelopedData>> {
+    let encap_content_info = &signed_data.encap_content_info;
+    if encap_content_info.econtent_type != const_oid::db::rfc5911::ID_DATA {
+        return Err(ErrorKind::OidUnknown {
+            oid: encap_content_info.econtent_type,
+        }
+        .into());
+    }
+    match &encap_content_info.econtent {
+        Some(econtent) => {
+            let content_info =
+                ContentInfo::from_der(econtent.decode_as::<OctetString>()?.as_bytes())?;
+            content_info.decode_content().map(Some)
+        }
+        None => Ok(None),
+    }
+}
+
+/// Create the `messageData` of a successful `CertRep`, i.e. the DER encoding of a degenerate
+/// certs-only `SignedData` holding the issued certificate, to be encrypted in the
+/// `pkcsPKIEnvelope`.
+pub fn create_cert_rep_message_data(
+    certificates: impl IntoIterator<Item = Certificate>,
+) -> der::Result<Vec<u8>> {
+    ContentInfo::try_from(SignedData::certs_only(certificates)?)?.to_der()
+}
+
+/// Extract the certificates from the decrypted `messageData` of a successful `CertRep`.
+pub fn cert_rep_certificates(message_data: &[u8]) -> der::Result<Vec<Certificate>> {
+    let signed_data = ContentInfo::from_der(message_data)?.decode_content::<SignedData>()?;
+    Ok(signed_data.certificates().cloned().collect())
+}
+
+fn single_value_attribute(
+    oid: ObjectIdentifier,
+    value: &(impl EncodeValue + Tagged),
+) -> der::Result<Attribute> {
+    let mut values = SetOfVec::new();
+    values.insert(Any::encode_from(value)?)?;
+    Ok(Attribute { oid, values })
+}
+
+/// Find the value of the attribute with the given OID, failing if the attribute is present
+/// more than once or doesn't have exactly one value.
+fn find_attribute_value(
+    attributes: &[Attribute],
+    oid: ObjectIdentifier,
+) -> der::Result<Option<&Any>> {
+    let mut matching = attributes.iter().filter(|attribute| attribute.oid == oid);
+    let attribute = match matching.next() {
+        Some(attribute) => attribute,
+        None => return Ok(None),
+    };
+    if matching.next().is_some() {
+        return Err(Tag::Set.value_error());
+    }
+    match attribute.values.as_slice() {
+        [value] => Ok(Some(value)),
+        _ => Err(Tag::Set.value_error()),
+    }
+}
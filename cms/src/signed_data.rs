@@ -1,16 +1,20 @@
 //! SignedData-related types
 
-use crate::cert::{CertificateChoices, IssuerAndSerialNumber};
+use crate::cert::{CertificateChoices, IssuerAndSerialNumber, OtherCertificateFormat};
 use crate::content_info::CmsVersion;
-use crate::revocation::RevocationInfoChoices;
+use crate::revocation::{OtherRevocationInfoFormat, RevocationInfoChoice, RevocationInfoChoices};
 
+use alloc::vec::Vec;
+use const_oid::AssociatedOid;
 use core::cmp::Ordering;
 use der::asn1::{ObjectIdentifier, OctetString, SetOfVec};
-use der::{Any, Choice, DerOrd, Sequence, ValueOrd};
+use der::{Any, Choice, Encode, Sequence, ValueOrd};
 use spki::AlgorithmIdentifierOwned;
 use x509_cert::attr::Attributes;
+use x509_cert::crl::CertificateList;
 use x509_cert::ext::pkix::SubjectKeyIdentifier;
 use x509_cert::impl_newtype;
+use x509_cert::Certificate;
 
 /// The `SignedData` type is defined in [RFC 5652 Section 5.1].
 ///
@@ -39,6 +43,91 @@ pub struct SignedData {
     pub signer_infos: SignerInfos,
 }
 
+impl AssociatedOid for SignedData {
+    const OID: ObjectIdentifier = const_oid::db::rfc5911::ID_SIGNED_DATA;
+}
+
+impl SignedData {
+    /// Create a degenerate, certs-only `SignedData` without signers and content, as used for
+    /// certificate bundles (`.p7b` files), see
+    /// [RFC 5652 § 5.2](https://datatracker.ietf.org/doc/html/rfc5652#section-5.2).
+    pub fn certs_only(certs: impl IntoIterator<Item = Certificate>) -> der::Result<Self> {
+        let mut certificates = CertificateSet(Default::default());
+        for cert in certs {
+            certificates
+                .0
+                .insert(CertificateChoices::Certificate(cert))?;
+        }
+
+        // include empty CRLs field instead of omitting it to match OpenSSL's behavior
+        Ok(SignedData {
+            version: CmsVersion::V1,
+            digest_algorithms: SetOfVec::default(),
+            encap_content_info: EncapsulatedContentInfo {
+                econtent_type: const_oid::db::rfc5911::ID_DATA,
+                econtent: None,
+            },
+            certificates: Some(certificates),
+            crls: Some(RevocationInfoChoices(Default::default())),
+            signer_infos: SignerInfos(Default::default()),
+        })
+    }
+
+    /// Iterate over the X.509 certificates included in this `SignedData`. Other certificate
+    /// formats are skipped, see [`SignedData::other_certificates`].
+    pub fn certificates(&self) -> impl Iterator<Item = &Certificate> {
+        self.certificates
+            .iter()
+            .flat_map(|certificates| certificates.0.iter())
+            .filter_map(|certificate| match certificate {
+                CertificateChoices::Certificate(certificate) => Some(certificate),
+                CertificateChoices::Other(_) => None,
+            })
+    }
+
+    /// Iterate over the certificates in other formats than X.509 included in this `SignedData`.
+    pub fn other_certificates(&self) -> impl Iterator<Item = &OtherCertificateFormat> {
+        self.certificates
+            .iter()
+            .flat_map(|certificates| certificates.0.iter())
+            .filter_map(|certificate| match certificate {
+                CertificateChoices::Certificate(_) => None,
+                CertificateChoices::Other(other) => Some(other),
+            })
+    }
+
+    /// Iterate over the X.509 CRLs included in this `SignedData`. Other revocation information
+    /// formats are skipped, see [`SignedData::other_revocation_info`].
+    pub fn crls(&self) -> impl Iterator<Item = &CertificateList> {
+        self.crls
+            .iter()
+            .flat_map(|crls| crls.0.iter())
+            .filter_map(|crl| match crl {
+                RevocationInfoChoice::Crl(crl) => Some(crl),
+                RevocationInfoChoice::Other(_) => None,
+            })
+    }
+
+    /// Iterate over the revocation information in other formats than X.509 CRLs included in
+    /// this `SignedData`, e.g. OCSP responses.
+    pub fn other_revocation_info(&self) -> impl Iterator<Item = &OtherRevocationInfoFormat> {
+        self.crls
+            .iter()
+            .flat_map(|crls| crls.0.iter())
+            .filter_map(|crl| match crl {
+                RevocationInfoChoice::Crl(_) => None,
+                RevocationInfoChoice::Other(other) => Some(other),
+            })
+    }
+
+    /// Find the X.509 certificate identified by `sid` among the certificates included in this
+    /// `SignedData`.
+    pub fn find_certificate(&self, sid: &SignerIdentifier) -> Option<&Certificate> {
+        self.certificates()
+            .find(|certificate| sid.matches(certificate))
+    }
+}
+
 /// The `DigestAlgorithmIdentifiers` type is defined in [RFC 5652 Section 5.1].
 ///
 /// ```text
@@ -60,10 +149,10 @@ pub struct CertificateSet(pub SetOfVec<CertificateChoices>);
 impl_newtype!(CertificateSet, SetOfVec<CertificateChoices>);
 
 #[cfg(feature = "std")]
-impl TryFrom<std::vec::Vec<CertificateChoices>> for CertificateSet {
+impl TryFrom<Vec<CertificateChoices>> for CertificateSet {
     type Error = der::Error;
 
-    fn try_from(vec: std::vec::Vec<CertificateChoices>) -> der::Result<CertificateSet> {
+    fn try_from(vec: Vec<CertificateChoices>) -> der::Result<CertificateSet> {
         Ok(CertificateSet(SetOfVec::try_from(vec)?))
     }
 }
@@ -80,10 +169,10 @@ pub struct SignerInfos(pub SetOfVec<SignerInfo>);
 impl_newtype!(SignerInfos, SetOfVec<SignerInfo>);
 
 #[cfg(feature = "std")]
-impl TryFrom<std::vec::Vec<SignerInfo>> for SignerInfos {
+impl TryFrom<Vec<SignerInfo>> for SignerInfos {
     type Error = der::Error;
 
-    fn try_from(vec: std::vec::Vec<SignerInfo>) -> der::Result<SignerInfos> {
+    fn try_from(vec: Vec<SignerInfo>) -> der::Result<SignerInfos> {
         Ok(SignerInfos(SetOfVec::try_from(vec)?))
     }
 }
@@ -122,7 +211,7 @@ pub struct EncapsulatedContentInfo {
 /// ```
 ///
 /// [RFC 5652 Section 5.3]: https://www.rfc-editor.org/rfc/rfc5652#section-5.3
-#[derive(Clone, Debug, Eq, PartialEq, Sequence, ValueOrd)]
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
 #[allow(missing_docs)]
 pub struct SignerInfo {
     pub version: CmsVersion,
@@ -146,6 +235,15 @@ pub struct SignerInfo {
     pub unsigned_attrs: Option<UnsignedAttributes>,
 }
 
+// The derived `ValueOrd` compares the fields one by one, which doesn't match the ordering of
+// the encodings if OPTIONAL fields are absent. Compare the encodings instead, so that a DER
+// SET OF `SignerInfo` is sorted correctly.
+impl ValueOrd for SignerInfo {
+    fn value_cmp(&self, other: &Self) -> der::Result<Ordering> {
+        Ok(self.to_der()?.cmp(&other.to_der()?))
+    }
+}
+
 /// The `SignerInfo` type is defined in [RFC 5652 Section 5.3].
 ///
 /// ```text
@@ -155,6 +253,23 @@ pub struct SignerInfo {
 /// [RFC 5652 Section 5.3]: https://datatracker.ietf.org/doc/html/rfc5652#section-5.3
 pub type SignedAttributes = Attributes;
 
+/// Encoding of [`SignedAttributes`] as input to the signature algorithm.
+pub trait SignedAttributesExt {
+    /// Encode the signed attributes for signing and verification according to
+    /// [RFC 5652 § 5.4](https://datatracker.ietf.org/doc/html/rfc5652#section-5.4): the DER
+    /// encoding with an EXPLICIT SET OF tag, rather than the IMPLICIT `[0]` tag used in the
+    /// `signedAttrs` field of a [`SignerInfo`].
+    fn to_der_for_signing(&self) -> der::Result<Vec<u8>>;
+}
+
+impl SignedAttributesExt for SignedAttributes {
+    fn to_der_for_signing(&self) -> der::Result<Vec<u8>> {
+        // `SetOfVec` is encoded with the SET OF tag and its elements in DER order, the IMPLICIT
+        // tag is only applied when encoding the `SignerInfo`.
+        self.to_der()
+    }
+}
+
 /// The `SignerIdentifier` type is defined in [RFC 5652 Section 5.3].
 ///
 /// ```text
@@ -173,11 +288,32 @@ pub enum SignerIdentifier {
     SubjectKeyIdentifier(SubjectKeyIdentifier),
 }
 
+impl SignerIdentifier {
+    /// Check whether `certificate` is identified by this signer identifier, i.e. by its issuer
+    /// and serial number or by its subject key identifier extension.
+    pub fn matches(&self, certificate: &Certificate) -> bool {
+        let tbs_certificate = &certificate.tbs_certificate;
+        match self {
+            SignerIdentifier::IssuerAndSerialNumber(issuer_and_serial_number) => {
+                tbs_certificate.issuer == issuer_and_serial_number.issuer
+                    && tbs_certificate.serial_number == issuer_and_serial_number.serial_number
+            }
+            SignerIdentifier::SubjectKeyIdentifier(subject_key_identifier) => {
+                matches!(
+                    tbs_certificate.get::<SubjectKeyIdentifier>(),
+                    Ok(Some((_, ref skid))) if skid == subject_key_identifier
+                )
+            }
+        }
+    }
+}
+
 // TODO DEFER ValueOrd is not supported for CHOICE types (see new_enum in value_ord.rs)
 impl ValueOrd for SignerIdentifier {
     fn value_cmp(&self, other: &Self) -> der::Result<Ordering> {
-        use der::Encode;
-        self.to_der()?.der_cmp(&other.to_der()?)
+        // Compare the encodings as octets, not as a `Vec<u8>` which is encoded as a SEQUENCE OF
+        // INTEGER.
+        Ok(self.to_der()?.cmp(&other.to_der()?))
     }
 }
 
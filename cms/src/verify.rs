@@ -0,0 +1,641 @@
+#![cfg(feature = "verify")]
+
+//! CMS SignedData and DigestedData verification
+
+use crate::attr::{find_single_attribute_value, Countersignature, SigningCertificateV2};
+use crate::cert::CertificateChoices;
+use crate::digested_data::DigestedData;
+use crate::signed_data::{SignedAttributesExt, SignedData, SignerIdentifier, SignerInfo};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use const_oid::ObjectIdentifier;
+use core::fmt;
+use der::asn1::OctetStringRef;
+use der::oid::db::DB;
+use der::Encode;
+use digest::Digest;
+use sha2::digest;
+use signature::digest::DynDigest;
+use signature::Verifier;
+use spki::AlgorithmIdentifierOwned;
+use x509_cert::Certificate;
+
+/// Error type
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// ASN.1 DER-related errors.
+    Asn1(der::Error),
+
+    /// Public key errors propagated from the [`spki::Error`] type.
+    PublicKey(spki::Error),
+
+    /// Signature verification error propagated from the [`signature::Verifier`] type.
+    Signature(signature::Error),
+
+    /// No certificate matching the `SignerIdentifier` was found.
+    CertificateNotFound,
+
+    /// Neither encapsulated nor detached content is available.
+    ContentMissing,
+
+    /// The digest algorithm of the signer is not supported.
+    UnsupportedDigestAlgorithm(ObjectIdentifier),
+
+    /// The signed attributes don't contain exactly one message digest value.
+    MessageDigestMissing,
+
+    /// The message digest attribute, or the digest of a `DigestedData`, doesn't match the
+    /// digest of the content.
+    MessageDigestMismatch,
+
+    /// The signed attributes don't contain a content type matching `eContentType`, or the
+    /// signed attributes of a countersignature contain a content type.
+    ContentTypeMismatch,
+
+    /// The signed attributes don't contain exactly one signing certificate v2 attribute with
+    /// exactly one value.
+    SigningCertificateMissing,
+
+    /// The signing certificate v2 attribute doesn't identify the signer certificate.
+    SigningCertificateMismatch,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Asn1(err) => write!(f, "ASN.1 error: {}", err),
+            Error::PublicKey(err) => write!(f, "public key error: {}", err),
+            Error::Signature(err) => write!(f, "signature error: {}", err),
+            Error::CertificateNotFound => write!(f, "signer certificate not found"),
+            Error::ContentMissing => write!(f, "content missing"),
+            Error::UnsupportedDigestAlgorithm(oid) => {
+                write!(f, "unsupported digest algorithm: {}", oid)
+            }
+            Error::MessageDigestMissing => write!(f, "message digest attribute missing"),
+            Error::MessageDigestMismatch => write!(f, "message digest mismatch"),
+            Error::ContentTypeMismatch => write!(f, "content type attribute mismatch"),
+            Error::SigningCertificateMissing => {
+                write!(f, "signing certificate attribute missing")
+            }
+            Error::SigningCertificateMismatch => {
+                write!(f, "signing certificate attribute mismatch")
+            }
+        }
+    }
+}
+
+impl From<der::Error> for Error {
+    fn from(err: der::Error) -> Error {
+        Error::Asn1(err)
+    }
+}
+
+impl From<spki::Error> for Error {
+    fn from(err: spki::Error) -> Error {
+        Error::PublicKey(err)
+    }
+}
+
+impl From<signature::Error> for Error {
+    fn from(err: signature::Error) -> Error {
+        Error::Signature(err)
+    }
+}
+
+type Result<T> = core::result::Result<T, Error>;
+
+/// Result of verifying a single `SignerInfo`.
+#[derive(Debug)]
+pub struct SignerVerification<'a> {
+    /// The verified signer info.
+    pub signer_info: &'a SignerInfo,
+
+    /// The certificate identified by the signer info's `sid`, if found.
+    pub certificate: Option<&'a Certificate>,
+
+    /// Outcome of the verification.
+    pub result: Result<()>,
+}
+
+impl SignerVerification<'_> {
+    /// Whether the signature of this signer is valid.
+    pub fn is_valid(&self) -> bool {
+        self.result.is_ok()
+    }
+}
+
+/// Result of verifying a single countersignature.
+#[derive(Debug)]
+pub struct CountersignatureVerification<'a> {
+    /// The verified countersignature.
+    pub countersignature: Countersignature,
+
+    /// The certificate identified by the countersignature's `sid`, if found.
+    pub certificate: Option<&'a Certificate>,
+
+    /// Outcome of the verification.
+    pub result: Result<()>,
+}
+
+impl CountersignatureVerification<'_> {
+    /// Whether this countersignature is valid.
+    pub fn is_valid(&self) -> bool {
+        self.result.is_ok()
+    }
+}
+
+/// Verifier for signedData (CMS and PKCS #7)
+/// Calling `verify()` on this struct will, for each `SignerInfo`,
+/// - locate the signer certificate using the `SignerIdentifier`
+/// - calculate the message digest of the content
+/// - check the message digest and content type attributes, if signed attributes are present
+/// - verify the signature over the signed attributes, or the content if there are none
+/// - check the signing certificate v2 attribute, if present
+///
+/// Countersignatures of a `SignerInfo` can be verified using `verify_countersignatures()`.
+///
+/// For detached signatures, the external content can be passed to the verifier as a slice, a
+/// reader or as precalculated message digests, so that it doesn't have to be held in memory.
+pub struct SignedDataVerifier<'a> {
+    signed_data: &'a SignedData,
+    detached_content: Option<&'a [u8]>,
+    detached_digests: Vec<(ObjectIdentifier, Vec<u8>)>,
+    certificates: Vec<&'a Certificate>,
+    require_signing_certificate: bool,
+}
+
+impl<'a> SignedDataVerifier<'a> {
+    /// Create a new verifier for `SignedData`. The certificates embedded in `signed_data` are
+    /// used to locate the signer certificates.
+    pub fn new(signed_data: &'a SignedData) -> SignedDataVerifier<'a> {
+        let certificates = signed_data
+            .certificates
+            .iter()
+            .flat_map(|certificates| certificates.0.iter())
+            .filter_map(|certificate| match certificate {
+                CertificateChoices::Certificate(certificate) => Some(certificate),
+                CertificateChoices::Other(_) => None,
+            })
+            .collect();
+
+        Self {
+            signed_data,
+            detached_content: None,
+            detached_digests: Vec::new(),
+            certificates,
+            require_signing_certificate: false,
+        }
+    }
+
+    /// Set the external content of a detached signature.
+    /// RFC 5652 § 5.2: eContent is the content itself, carried as an octet
+    /// string.  The eContent need not be DER encoded.  The optional omission
+    /// of the eContent within the EncapsulatedContentInfo field makes it
+    /// possible to construct "external signatures."
+    /// The detached content is only used if `eContent` is absent.
+    pub fn set_detached_content(&mut self, content: &'a [u8]) -> &mut Self {
+        self.detached_content = Some(content);
+        self
+    }
+
+    /// Set the message digest of the external content of a detached signature, calculated
+    /// using the digest algorithm identified by `digest_algorithm`. Signer infos using other
+    /// digest algorithms, or without signed attributes, can't be verified this way.
+    /// The detached digest is only used if `eContent` is absent and no detached content is set.
+    pub fn set_detached_digest(
+        &mut self,
+        digest_algorithm: ObjectIdentifier,
+        digest: &[u8],
+    ) -> &mut Self {
+        self.detached_digests
+            .retain(|(algorithm, _)| *algorithm != digest_algorithm);
+        self.detached_digests
+            .push((digest_algorithm, digest.to_vec()));
+        self
+    }
+
+    /// Read the external content of a detached signature from `reader`, calculating its message
+    /// digest for each digest algorithm used by the signer infos, see
+    /// [`SignedDataVerifier::set_detached_digest`].
+    #[cfg(feature = "std")]
+    pub fn read_detached_content(
+        &mut self,
+        mut reader: impl std::io::Read,
+    ) -> std::io::Result<&mut Self> {
+        let mut hashers = Vec::new();
+        for signer_info in self.signed_data.signer_infos.0.iter() {
+            let digest_algorithm = signer_info.digest_alg.oid;
+            if hashers
+                .iter()
+                .any(|(algorithm, _)| *algorithm == digest_algorithm)
+            {
+                continue;
+            }
+            // Unsupported digest algorithms are reported when verifying.
+            if let Some(hasher) = get_hasher(&signer_info.digest_alg) {
+                hashers.push((digest_algorithm, hasher));
+            }
+        }
+
+        let mut buffer = [0u8; 8192];
+        loop {
+            let len = match reader.read(&mut buffer) {
+                Ok(0) => break,
+                Ok(len) => len,
+                Err(err) if err.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(err) => return Err(err),
+            };
+            for (_, hasher) in hashers.iter_mut() {
+                hasher.update(&buffer[..len]);
+            }
+        }
+
+        for (digest_algorithm, mut hasher) in hashers {
+            self.set_detached_digest(digest_algorithm, &hasher.finalize_reset());
+        }
+        Ok(self)
+    }
+
+    /// Add a certificate which is not embedded in the `SignedData`, but may be identified by a
+    /// `SignerIdentifier`.
+    pub fn add_certificate(&mut self, certificate: &'a Certificate) -> &mut Self {
+        self.certificates.push(certificate);
+        self
+    }
+
+    /// Require the signed attributes of each signer info to contain a signing certificate v2
+    /// attribute identifying the signer certificate, as CAdES does, see
+    /// [`verify_signing_certificate_v2`]. The attribute is checked regardless whenever it is
+    /// present.
+    pub fn set_require_signing_certificate(&mut self, require: bool) -> &mut Self {
+        self.require_signing_certificate = require;
+        self
+    }
+
+    /// Verify all signer infos, returning one `SignerVerification` per signer info.
+    /// `verifying_key` is called with the signer certificate to obtain the key which is used to
+    /// verify the signature.
+    pub fn verify<V, Signature, F>(&self, mut verifying_key: F) -> Vec<SignerVerification<'a>>
+    where
+        F: FnMut(&Certificate) -> spki::Result<V>,
+        V: Verifier<Signature>,
+        Signature: for<'s> TryFrom<&'s [u8]>,
+    {
+        self.signed_data
+            .signer_infos
+            .0
+            .iter()
+            .map(|signer_info| {
+                let certificate = self.find_certificate(&signer_info.sid);
+                let result =
+                    certificate
+                        .ok_or(Error::CertificateNotFound)
+                        .and_then(|certificate| {
+                            self.verify_signer_info(signer_info, &verifying_key(certificate)?)?;
+                            self.verify_signing_certificate(signer_info, certificate)
+                        });
+
+                SignerVerification {
+                    signer_info,
+                    certificate,
+                    result,
+                }
+            })
+            .collect()
+    }
+
+    /// Verify all signer infos, returning an error if any of them is invalid or if there are no
+    /// signer infos.
+    pub fn verify_all<V, Signature, F>(&self, verifying_key: F) -> Result<()>
+    where
+        F: FnMut(&Certificate) -> spki::Result<V>,
+        V: Verifier<Signature>,
+        Signature: for<'s> TryFrom<&'s [u8]>,
+    {
+        let verifications = self.verify(verifying_key);
+        if verifications.is_empty() {
+            return Err(Error::Signature(signature::Error::new()));
+        }
+        verifications
+            .into_iter()
+            .try_for_each(|verification| verification.result)
+    }
+
+    /// Check the signing certificate v2 attribute of `signer_info`, if present or required.
+    fn verify_signing_certificate(
+        &self,
+        signer_info: &SignerInfo,
+        certificate: &Certificate,
+    ) -> Result<()> {
+        let present = signer_info
+            .signed_attrs
+            .iter()
+            .flat_map(|signed_attributes| signed_attributes.iter())
+            .any(|attribute| {
+                attribute.oid == const_oid::db::rfc5911::ID_AA_SIGNING_CERTIFICATE_V_2
+            });
+        if present || self.require_signing_certificate {
+            verify_signing_certificate_v2(signer_info, certificate)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Find the certificate identified by `sid`.
+    fn find_certificate(&self, sid: &SignerIdentifier) -> Option<&'a Certificate> {
+        self.certificates
+            .iter()
+            .copied()
+            .find(|certificate| sid.matches(certificate))
+    }
+
+    /// Verify the countersignatures in the unsigned attributes of `signer_info` according to
+    /// [RFC 5652 § 11.4](https://datatracker.ietf.org/doc/html/rfc5652#section-11.4),
+    /// returning one `CountersignatureVerification` per countersignature.
+    /// `verifying_key` is called with the countersigner certificate to obtain the key which is
+    /// used to verify the countersignature.
+    ///
+    /// An error is only returned if the countersignature attribute can't be decoded.
+    pub fn verify_countersignatures<V, Signature, F>(
+        &self,
+        signer_info: &SignerInfo,
+        mut verifying_key: F,
+    ) -> Result<Vec<CountersignatureVerification<'a>>>
+    where
+        F: FnMut(&Certificate) -> spki::Result<V>,
+        V: Verifier<Signature>,
+        Signature: for<'s> TryFrom<&'s [u8]>,
+    {
+        Ok(countersignatures(signer_info)?
+            .into_iter()
+            .map(|countersignature| {
+                let certificate = self.find_certificate(&countersignature.sid);
+                let result =
+                    certificate
+                        .ok_or(Error::CertificateNotFound)
+                        .and_then(|certificate| {
+                            // RFC 5652 § 11.4: The countersignature is calculated over the
+                            // contents octets of the signatureValue of the countersigned
+                            // SignerInfo, and has no content type.
+                            verify_signature(
+                                &countersignature,
+                                Content::Data(signer_info.signature.as_bytes()),
+                                None,
+                                &verifying_key(certificate)?,
+                            )
+                        });
+
+                CountersignatureVerification {
+                    countersignature,
+                    certificate,
+                    result,
+                }
+            })
+            .collect())
+    }
+
+    /// Verify a single signer info using the given verifying key.
+    /// [RFC 5652 § 5.6](https://datatracker.ietf.org/doc/html/rfc5652#section-5.6)
+    fn verify_signer_info<V, Signature>(&self, signer_info: &SignerInfo, verifier: &V) -> Result<()>
+    where
+        V: Verifier<Signature>,
+        Signature: for<'s> TryFrom<&'s [u8]>,
+    {
+        let encapsulated_content_info = &self.signed_data.encap_content_info;
+
+        // Only the octets comprising the value of the eContent OCTET STRING
+        // are input to the message digest algorithm, not the tag or the length octets.
+        // The same applies to eContent which is not wrapped in an OCTET STRING, e.g.
+        // Authenticode's `SpcIndirectDataContent`.
+        let content = match (&encapsulated_content_info.econtent, self.detached_content) {
+            (Some(econtent), _) => Content::Data(econtent.value()),
+            (None, Some(detached_content)) => Content::Data(detached_content),
+            (None, None) => {
+                let digest_algorithm = &signer_info.digest_alg;
+                match self
+                    .detached_digests
+                    .iter()
+                    .find(|(algorithm, _)| *algorithm == digest_algorithm.oid)
+                {
+                    Some((_, digest)) => Content::Digest(digest),
+                    None if !self.detached_digests.is_empty()
+                        && get_hasher(digest_algorithm).is_none() =>
+                    {
+                        return Err(Error::UnsupportedDigestAlgorithm(digest_algorithm.oid))
+                    }
+                    None => return Err(Error::ContentMissing),
+                }
+            }
+        };
+
+        verify_signature(
+            signer_info,
+            content,
+            Some(encapsulated_content_info.econtent_type),
+            verifier,
+        )
+    }
+}
+
+/// Verify that the signing certificate v2 attribute in the signed attributes of `signer_info`
+/// identifies `certificate` according to
+/// [RFC 5035 § 3](https://datatracker.ietf.org/doc/html/rfc5035#section-3): the hash of the
+/// first `ESSCertIDv2` must match the certificate, as well as its issuer and serial number if
+/// present.
+pub fn verify_signing_certificate_v2(
+    signer_info: &SignerInfo,
+    certificate: &Certificate,
+) -> Result<()> {
+    let signing_certificate = find_single_attribute_value(
+        signer_info
+            .signed_attrs
+            .iter()
+            .flat_map(|signed_attributes| signed_attributes.iter()),
+        const_oid::db::rfc5911::ID_AA_SIGNING_CERTIFICATE_V_2,
+    )
+    .ok_or(Error::SigningCertificateMissing)?
+    .decode_as::<SigningCertificateV2>()?;
+
+    // The first certificate identifies the signer certificate, the others may be used to
+    // limit the set of certificates used in path validation.
+    let cert_id = signing_certificate
+        .certs
+        .first()
+        .ok_or(Error::SigningCertificateMismatch)?;
+    let hash_algorithm = cert_id.effective_hash_algorithm();
+    let mut hasher =
+        get_hasher(&hash_algorithm).ok_or(Error::UnsupportedDigestAlgorithm(hash_algorithm.oid))?;
+    hasher.update(&certificate.to_der()?);
+    if *hasher.finalize() != *cert_id.cert_hash.as_bytes() {
+        return Err(Error::SigningCertificateMismatch);
+    }
+
+    match &cert_id.issuer_serial {
+        Some(issuer_serial) if !issuer_serial.matches(certificate) => {
+            Err(Error::SigningCertificateMismatch)
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Get the countersignatures contained in the unsigned attributes of `signer_info`.
+pub fn countersignatures(signer_info: &SignerInfo) -> Result<Vec<Countersignature>> {
+    Ok(signer_info
+        .unsigned_attrs
+        .iter()
+        .flat_map(|unsigned_attributes| unsigned_attributes.iter())
+        .filter(|attribute| attribute.oid == const_oid::db::rfc5911::ID_COUNTERSIGNATURE)
+        .flat_map(|attribute| attribute.values.iter())
+        .map(|value| value.decode_as::<Countersignature>())
+        .collect::<der::Result<_>>()?)
+}
+
+/// Signed content, or its message digest if the content isn't available.
+enum Content<'c> {
+    Data(&'c [u8]),
+    Digest(&'c [u8]),
+}
+
+/// Verify the signature of `signer_info` over `content` using the given verifying key.
+/// The signed attributes, if present, must contain a content type attribute matching
+/// `content_type`, or no content type attribute if `content_type` is `None`.
+fn verify_signature<V, Signature>(
+    signer_info: &SignerInfo,
+    content: Content<'_>,
+    content_type: Option<ObjectIdentifier>,
+    verifier: &V,
+) -> Result<()>
+where
+    V: Verifier<Signature>,
+    Signature: for<'s> TryFrom<&'s [u8]>,
+{
+    let signature = Signature::try_from(signer_info.signature.as_bytes())
+        .map_err(|_| Error::Signature(signature::Error::new()))?;
+
+    let signed_attributes = match &signer_info.signed_attrs {
+        Some(signed_attributes) => signed_attributes,
+        // Without signed attributes, the signature is calculated over the content itself.
+        None => {
+            return match content {
+                Content::Data(content) => Ok(verifier.verify(content, &signature)?),
+                Content::Digest(_) => Err(Error::ContentMissing),
+            }
+        }
+    };
+
+    let message_digest = find_single_attribute_value(
+        signed_attributes.iter(),
+        const_oid::db::rfc5911::ID_MESSAGE_DIGEST,
+    )
+    .ok_or(Error::MessageDigestMissing)?
+    .decode_as::<OctetStringRef<'_>>()?;
+
+    let digest_matches = match content {
+        Content::Data(content) => {
+            let mut hasher = get_hasher(&signer_info.digest_alg).ok_or(
+                Error::UnsupportedDigestAlgorithm(signer_info.digest_alg.oid),
+            )?;
+            hasher.update(content);
+            hasher.finalize_reset().as_ref() == message_digest.as_bytes()
+        }
+        Content::Digest(digest) => digest == message_digest.as_bytes(),
+    };
+    if !digest_matches {
+        return Err(Error::MessageDigestMismatch);
+    }
+
+    match content_type {
+        Some(content_type) => {
+            let signed_content_type = find_single_attribute_value(
+                signed_attributes.iter(),
+                const_oid::db::rfc5911::ID_CONTENT_TYPE,
+            )
+            .ok_or(Error::ContentTypeMismatch)?
+            .decode_as::<ObjectIdentifier>()?;
+            if signed_content_type != content_type {
+                return Err(Error::ContentTypeMismatch);
+            }
+        }
+        None => {
+            if signed_attributes
+                .iter()
+                .any(|attribute| attribute.oid == const_oid::db::rfc5911::ID_CONTENT_TYPE)
+            {
+                return Err(Error::ContentTypeMismatch);
+            }
+        }
+    }
+
+    // The signature is calculated over the DER encoding of the SignedAttributes, using an
+    // EXPLICIT SET OF tag rather than the IMPLICIT [0] tag of the `signedAttrs` field.
+    let signed_attributes_der = signed_attributes.to_der_for_signing()?;
+    Ok(verifier.verify(&signed_attributes_der, &signature)?)
+}
+
+/// Verifier for digestedData according to
+/// [RFC 5652 § 7](https://datatracker.ietf.org/doc/html/rfc5652#section-7)
+/// Calling `verify()` on this struct will calculate the message digest of the content and
+/// compare it to the digest contained in the `DigestedData`.
+pub struct DigestedDataVerifier<'a> {
+    digested_data: &'a DigestedData,
+    detached_content: Option<&'a [u8]>,
+}
+
+impl<'a> DigestedDataVerifier<'a> {
+    /// Create a new verifier for `DigestedData`.
+    pub fn new(digested_data: &'a DigestedData) -> DigestedDataVerifier<'a> {
+        Self {
+            digested_data,
+            detached_content: None,
+        }
+    }
+
+    /// Set the external content, if `eContent` is absent.
+    pub fn set_detached_content(&mut self, content: &'a [u8]) -> &mut Self {
+        self.detached_content = Some(content);
+        self
+    }
+
+    /// Verify the digest of the content.
+    pub fn verify(&self) -> Result<()> {
+        let digested_data = self.digested_data;
+
+        // Only the octets comprising the value of the eContent OCTET STRING
+        // are input to the message digest algorithm, not the tag or the length octets.
+        // The same applies to eContent which is not wrapped in an OCTET STRING, e.g.
+        // Authenticode's `SpcIndirectDataContent`.
+        let content = match &digested_data.encap_content_info.econtent {
+            Some(econtent) => econtent.value(),
+            None => self.detached_content.ok_or(Error::ContentMissing)?,
+        };
+
+        let mut hasher = get_hasher(&digested_data.digest_alg).ok_or(
+            Error::UnsupportedDigestAlgorithm(digested_data.digest_alg.oid),
+        )?;
+        hasher.update(content);
+        if hasher.finalize_reset().as_ref() != digested_data.digest.as_bytes() {
+            return Err(Error::MessageDigestMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// Get a hasher for a given digest algorithm
+pub(crate) fn get_hasher(
+    digest_algorithm_identifier: &AlgorithmIdentifierOwned,
+) -> Option<Box<dyn DynDigest>> {
+    let digest_name = DB.by_oid(&digest_algorithm_identifier.oid)?;
+    match digest_name {
+        "id-sha1" => Some(Box::new(sha1::Sha1::new())),
+        "id-sha256" => Some(Box::new(sha2::Sha256::new())),
+        "id-sha384" => Some(Box::new(sha2::Sha384::new())),
+        "id-sha512" => Some(Box::new(sha2::Sha512::new())),
+        "id-sha224" => Some(Box::new(sha2::Sha224::new())),
+        "id-sha-3-224" => Some(Box::new(sha3::Sha3_224::new())),
+        "id-sha-3-256" => Some(Box::new(sha3::Sha3_256::new())),
+        "id-sha-3-384" => Some(Box::new(sha3::Sha3_384::new())),
+        "id-sha-3-512" => Some(Box::new(sha3::Sha3_512::new())),
+        _ => None,
+    }
+}
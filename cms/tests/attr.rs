@@ -0,0 +1,69 @@
+//! Attribute tests
+
+use cms::attr::{SmimeCapabilities, SmimeCapability};
+use cms::content_info::ContentInfo;
+use cms::signed_data::SignedData;
+use der::{Any, Decode, Encode};
+use hex_literal::hex;
+
+#[test]
+fn decode_smime_capabilities() {
+    // Signed by OpenSSL, which advertises its default capabilities
+    let content_info =
+        ContentInfo::from_der(include_bytes!("examples/signed_data_p256.der")).unwrap();
+    let signed_data = content_info.content.decode_as::<SignedData>().unwrap();
+    let signer_info = signed_data.signer_infos.0.get(0).unwrap();
+    let attribute = signer_info
+        .signed_attrs
+        .as_ref()
+        .unwrap()
+        .iter()
+        .find(|attribute| attribute.oid == const_oid::db::rfc5911::SMIME_CAPABILITIES)
+        .unwrap();
+
+    let capabilities = SmimeCapabilities::from_attribute(attribute).unwrap();
+    assert_eq!(capabilities.0.len(), 8);
+    assert_eq!(capabilities.0[..3], SmimeCapabilities::aes().0);
+    assert_eq!(capabilities.0[3..4], SmimeCapabilities::triple_des().0);
+    assert!(capabilities.contains(const_oid::db::rfc5911::ID_AES_128_CBC));
+    assert!(!capabilities.contains(const_oid::db::rfc5912::ID_SHA_256));
+
+    // RC2 with an effective key length of 128 bits
+    let rc2 = &capabilities.0[4];
+    assert_eq!(rc2.capability_id, const_oid::db::rfc5911::RC_2_CBC);
+    assert_eq!(
+        rc2.parameters.as_ref().unwrap().decode_as::<u8>().unwrap(),
+        128
+    );
+
+    assert_eq!(capabilities.to_attribute().unwrap(), *attribute);
+
+    let mut attribute = attribute.clone();
+    attribute.oid = const_oid::db::rfc5911::ID_SIGNING_TIME;
+    assert!(SmimeCapabilities::from_attribute(&attribute).is_err());
+}
+
+#[test]
+fn encode_smime_capabilities() {
+    let mut capabilities = SmimeCapabilities::aes();
+    capabilities.extend(SmimeCapabilities::sha2());
+    capabilities.extend([SmimeCapability {
+        capability_id: const_oid::db::rfc5911::RC_2_CBC,
+        parameters: Some(Any::encode_from(&40u8).unwrap()),
+    }]);
+    let der = capabilities.to_der().unwrap();
+    assert_eq!(
+        der,
+        hex!(
+            "305d"
+            "300b060960864801650304012a"
+            "300b0609608648016503040116"
+            "300b0609608648016503040102"
+            "300b0609608648016503040203"
+            "300b0609608648016503040202"
+            "300b0609608648016503040201"
+            "300d06082a864886f70d0302020128"
+        )
+    );
+    assert_eq!(SmimeCapabilities::from_der(&der).unwrap(), capabilities);
+}
@@ -0,0 +1,191 @@
+use cms::auth_enveloped_data::{AuthEnvelopedData, GcmParameters};
+use cms::content_info::{CmsVersion, ContentInfo};
+use cms::enveloped_data::RecipientInfo;
+use der::{Decode, Encode};
+
+/// Generated using:
+/// $ openssl cms -encrypt -binary -aes-256-gcm -in content.txt -outform der rsa2048-cert.pem
+const AUTH_ENVELOPED_DATA_RSA_DER: &[u8] =
+    include_bytes!("examples/auth_enveloped_data_rsa_aes256gcm.der");
+
+#[test]
+fn reencode_auth_enveloped_data_test() {
+    let ci = ContentInfo::from_der(AUTH_ENVELOPED_DATA_RSA_DER).unwrap();
+    assert_eq!(
+        ci.content_type,
+        const_oid::db::rfc5911::ID_CT_AUTH_ENVELOPED_DATA
+    );
+
+    let bytes = ci.content.to_der().unwrap();
+    let data = AuthEnvelopedData::from_der(bytes.as_slice()).unwrap();
+    assert_eq!(CmsVersion::V0, data.version);
+    assert!(data.originator_info.is_none());
+    assert!(matches!(
+        data.recip_infos.0.get(0).unwrap(),
+        RecipientInfo::Ktri(_)
+    ));
+    assert!(data.auth_attrs.is_none());
+    assert!(data.unauth_attrs.is_none());
+
+    let content_info = &data.auth_encrypted_content;
+    assert_eq!(content_info.content_type, const_oid::db::rfc5911::ID_DATA);
+    assert_eq!(
+        content_info.content_enc_alg.oid,
+        const_oid::db::rfc5911::ID_AES_256_GCM
+    );
+    let params = content_info
+        .content_enc_alg
+        .parameters
+        .as_ref()
+        .unwrap()
+        .decode_as::<GcmParameters>()
+        .unwrap();
+    assert_eq!(params.nonce.as_bytes().len(), 12);
+    assert_eq!(params.icv_len, 16);
+    assert_eq!(data.mac.as_bytes().len(), 16);
+
+    let reencoded_data = data.to_der().unwrap();
+    assert_eq!(bytes, reencoded_data);
+}
+
+#[test]
+fn gcm_parameters_default_icv_len() {
+    // SEQUENCE { OCTET STRING (12 bytes) }, aes-ICVlen omitted
+    let der = [0x30, 0x0e, 0x04, 0x0c, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11];
+    let params = GcmParameters::from_der(&der).unwrap();
+    assert_eq!(params.icv_len, 12);
+    assert_eq!(params.to_der().unwrap(), der);
+}
+
+#[cfg(feature = "decrypt")]
+mod decrypt {
+    use super::*;
+    use cms::decrypt::{EnvelopedDataDecryptor, Error, KeyTransRecipient};
+    use der::asn1::OctetString;
+    use rsa::pkcs1::DecodeRsaPrivateKey;
+    use rsa::rand_core::OsRng;
+    use rsa::RsaPrivateKey;
+    use x509_cert::Certificate;
+
+    /// Content encrypted in the example auth enveloped data.
+    const CONTENT: &[u8] = b"hello world\n";
+
+    const RSA_2048_CERT_DER_EXAMPLE: &[u8] = include_bytes!("examples/rsa2048-cert.der");
+
+    fn auth_enveloped_data(der: &[u8]) -> AuthEnvelopedData {
+        ContentInfo::from_der(der)
+            .unwrap()
+            .content
+            .decode_as()
+            .unwrap()
+    }
+
+    fn recipient() -> KeyTransRecipient {
+        let certificate = Certificate::from_der(RSA_2048_CERT_DER_EXAMPLE).unwrap();
+        let private_key =
+            RsaPrivateKey::from_pkcs1_der(include_bytes!("examples/rsa2048-priv.der")).unwrap();
+        KeyTransRecipient::from_certificate(&certificate, private_key).unwrap()
+    }
+
+    #[test]
+    fn decrypt_rsa_aes256_gcm() {
+        let auth_enveloped_data = auth_enveloped_data(AUTH_ENVELOPED_DATA_RSA_DER);
+        let content = EnvelopedDataDecryptor::from_auth_enveloped_data(&auth_enveloped_data)
+            .decrypt_with_rng(&recipient(), &mut OsRng)
+            .unwrap();
+        assert_eq!(content, CONTENT);
+    }
+
+    #[test]
+    fn decrypt_invalid_mac() {
+        let mut auth_enveloped_data = auth_enveloped_data(AUTH_ENVELOPED_DATA_RSA_DER);
+        let mut mac = auth_enveloped_data.mac.as_bytes().to_vec();
+        mac[0] ^= 0x01;
+        auth_enveloped_data.mac = OctetString::new(mac).unwrap();
+
+        let result = EnvelopedDataDecryptor::from_auth_enveloped_data(&auth_enveloped_data)
+            .decrypt_with_rng(&recipient(), &mut OsRng);
+        assert!(matches!(result, Err(Error::Decryption)));
+    }
+
+    #[cfg(feature = "builder")]
+    #[test]
+    fn decrypt_built_auth_enveloped_data() {
+        use cms::builder::{
+            AuthContentEncryptionAlgorithm, AuthEnvelopedDataBuilder, KeyEncryptionInfo,
+            KeyTransRecipientInfoBuilder, PasswordRecipientInfoBuilder,
+        };
+        use cms::password::PasswordKeyEncryptionAlgorithm;
+        use der::asn1::SetOfVec;
+        use pkcs5::pbes2::Pbkdf2Params;
+        use x509_cert::attr::Attribute;
+
+        let certificate = Certificate::from_der(RSA_2048_CERT_DER_EXAMPLE).unwrap();
+        let content_type = Attribute {
+            oid: const_oid::db::rfc5911::ID_CONTENT_TYPE,
+            values: SetOfVec::try_from(vec![der::Any::encode_from(
+                &const_oid::db::rfc5911::ID_DATA,
+            )
+            .unwrap()])
+            .unwrap(),
+        };
+        let salt = [0x5a; 16];
+
+        for content_encryption_algorithm in [
+            AuthContentEncryptionAlgorithm::Aes128Gcm,
+            AuthContentEncryptionAlgorithm::Aes192Gcm,
+            AuthContentEncryptionAlgorithm::Aes256Gcm,
+        ] {
+            let mut rng = OsRng;
+            let mut password_rng = OsRng;
+            let content_info = AuthEnvelopedDataBuilder::new(CONTENT, content_encryption_algorithm)
+                .add_recipient_info(
+                    KeyTransRecipientInfoBuilder::from_certificate(
+                        &certificate,
+                        KeyEncryptionInfo::RsaOaep,
+                        &mut rng,
+                    )
+                    .unwrap(),
+                )
+                .unwrap()
+                .add_recipient_info(
+                    PasswordRecipientInfoBuilder::new(
+                        b"cms test password",
+                        Pbkdf2Params::hmac_with_sha256(1000, &salt).unwrap(),
+                        PasswordKeyEncryptionAlgorithm::PwriKekAes256Cbc,
+                        &mut password_rng,
+                    )
+                    .unwrap(),
+                )
+                .unwrap()
+                .add_authenticated_attribute(content_type.clone())
+                .unwrap()
+                .build_with_rng(&mut OsRng)
+                .unwrap();
+            assert_eq!(
+                content_info.content_type,
+                const_oid::db::rfc5911::ID_CT_AUTH_ENVELOPED_DATA
+            );
+
+            let mut auth_enveloped_data = auth_enveloped_data(&content_info.to_der().unwrap());
+            assert_eq!(auth_enveloped_data.version, CmsVersion::V0);
+            let decryptor = EnvelopedDataDecryptor::from_auth_enveloped_data(&auth_enveloped_data);
+            assert_eq!(
+                decryptor
+                    .decrypt_with_rng(&recipient(), &mut OsRng)
+                    .unwrap(),
+                CONTENT
+            );
+            assert_eq!(
+                decryptor.decrypt_password(b"cms test password").unwrap(),
+                CONTENT
+            );
+
+            // The authenticated attributes are covered by the message authentication code.
+            auth_enveloped_data.auth_attrs = None;
+            let result = EnvelopedDataDecryptor::from_auth_enveloped_data(&auth_enveloped_data)
+                .decrypt_with_rng(&recipient(), &mut OsRng);
+            assert!(matches!(result, Err(Error::Decryption)));
+        }
+    }
+}
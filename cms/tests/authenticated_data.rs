@@ -0,0 +1,161 @@
+#![cfg(all(feature = "builder", feature = "decrypt"))]
+
+use cms::authenticated_data::AuthenticatedData;
+use cms::builder::{
+    create_data_content, AuthenticatedDataBuilder, KeyEncryptionInfo, KeyTransRecipientInfoBuilder,
+    MacAlgorithm, PasswordRecipientInfoBuilder,
+};
+use cms::content_info::{CmsVersion, ContentInfo};
+use cms::decrypt::{EnvelopedDataDecryptor, Error, KeyTransRecipient};
+use cms::password::PasswordKeyEncryptionAlgorithm;
+use der::{Decode, Encode};
+use pkcs5::pbes2::Pbkdf2Params;
+use rsa::pkcs1::DecodeRsaPrivateKey;
+use rsa::rand_core::OsRng;
+use rsa::RsaPrivateKey;
+use spki::AlgorithmIdentifierOwned;
+use x509_cert::Certificate;
+
+const CONTENT: &[u8] = b"hello world\n";
+
+const PASSWORD: &[u8] = b"cms test password";
+
+const RSA_2048_CERT_DER_EXAMPLE: &[u8] = include_bytes!("examples/rsa2048-cert.der");
+
+fn authenticated_data(content_info: &ContentInfo) -> AuthenticatedData {
+    assert_eq!(
+        content_info.content_type,
+        const_oid::db::rfc5911::ID_CT_AUTH_DATA
+    );
+    AuthenticatedData::from_der(&content_info.content.to_der().unwrap()).unwrap()
+}
+
+fn recipient() -> KeyTransRecipient {
+    let certificate = Certificate::from_der(RSA_2048_CERT_DER_EXAMPLE).unwrap();
+    let private_key =
+        RsaPrivateKey::from_pkcs1_der(include_bytes!("examples/rsa2048-priv.der")).unwrap();
+    KeyTransRecipient::from_certificate(&certificate, private_key).unwrap()
+}
+
+fn build_authenticated_data(
+    mac_algorithm: MacAlgorithm,
+    digest_algorithm: Option<AlgorithmIdentifierOwned>,
+) -> ContentInfo {
+    let certificate = Certificate::from_der(RSA_2048_CERT_DER_EXAMPLE).unwrap();
+    let content = create_data_content(Some(CONTENT)).unwrap();
+    let salt = [0x5a; 16];
+    let mut rng = OsRng;
+    let mut password_rng = OsRng;
+
+    let mut builder = AuthenticatedDataBuilder::new(&content, mac_algorithm);
+    builder
+        .add_recipient_info(
+            KeyTransRecipientInfoBuilder::from_certificate(
+                &certificate,
+                KeyEncryptionInfo::RsaOaep,
+                &mut rng,
+            )
+            .unwrap(),
+        )
+        .unwrap()
+        .add_recipient_info(
+            PasswordRecipientInfoBuilder::new(
+                PASSWORD,
+                Pbkdf2Params::hmac_with_sha256(1000, &salt).unwrap(),
+                PasswordKeyEncryptionAlgorithm::PwriKekAes256Cbc,
+                &mut password_rng,
+            )
+            .unwrap(),
+        )
+        .unwrap();
+    if let Some(digest_algorithm) = digest_algorithm {
+        builder.set_digest_algorithm(digest_algorithm).unwrap();
+    }
+    builder.build_with_rng(&mut OsRng).unwrap()
+}
+
+fn sha256() -> AlgorithmIdentifierOwned {
+    AlgorithmIdentifierOwned {
+        oid: const_oid::db::rfc5912::ID_SHA_256,
+        parameters: None,
+    }
+}
+
+#[test]
+fn verify_built_authenticated_data() {
+    for mac_algorithm in [
+        MacAlgorithm::HmacWithSha256,
+        MacAlgorithm::HmacWithSha384,
+        MacAlgorithm::HmacWithSha512,
+    ] {
+        let data = authenticated_data(&build_authenticated_data(mac_algorithm, None));
+        assert_eq!(data.version, CmsVersion::V0);
+        assert_eq!(data.mac_alg.oid, mac_algorithm.oid());
+        assert!(data.digest_alg.is_none());
+        assert!(data.auth_attrs.is_none());
+
+        let decryptor = EnvelopedDataDecryptor::from_authenticated_data(&data);
+        assert_eq!(
+            decryptor
+                .decrypt_with_rng(&recipient(), &mut OsRng)
+                .unwrap(),
+            CONTENT
+        );
+        assert_eq!(decryptor.decrypt_password(PASSWORD).unwrap(), CONTENT);
+    }
+}
+
+#[test]
+fn verify_built_authenticated_data_with_attributes() {
+    let data = authenticated_data(&build_authenticated_data(
+        MacAlgorithm::HmacWithSha256,
+        Some(sha256()),
+    ));
+    assert_eq!(data.digest_alg, Some(sha256()));
+    let auth_attrs = data.auth_attrs.as_ref().unwrap();
+    assert!(auth_attrs
+        .iter()
+        .any(|attribute| attribute.oid == const_oid::db::rfc5911::ID_CONTENT_TYPE));
+    assert!(auth_attrs
+        .iter()
+        .any(|attribute| attribute.oid == const_oid::db::rfc5911::ID_MESSAGE_DIGEST));
+
+    let decryptor = EnvelopedDataDecryptor::from_authenticated_data(&data);
+    assert_eq!(decryptor.decrypt_password(PASSWORD).unwrap(), CONTENT);
+}
+
+#[test]
+fn verify_modified_content() {
+    for digest_algorithm in [None, Some(sha256())] {
+        let mut data = authenticated_data(&build_authenticated_data(
+            MacAlgorithm::HmacWithSha256,
+            digest_algorithm,
+        ));
+        data.encap_content_info = create_data_content(Some(b"modified content")).unwrap();
+
+        let result = EnvelopedDataDecryptor::from_authenticated_data(&data)
+            .decrypt_with_rng(&recipient(), &mut OsRng);
+        assert!(matches!(result, Err(Error::Decryption)));
+    }
+}
+
+#[test]
+fn authenticated_attributes_require_digest_algorithm() {
+    let content = create_data_content(Some(CONTENT)).unwrap();
+    let mut password_rng = OsRng;
+    let result = AuthenticatedDataBuilder::new(&content, MacAlgorithm::HmacWithSha256)
+        .add_recipient_info(
+            PasswordRecipientInfoBuilder::new(
+                PASSWORD,
+                Pbkdf2Params::hmac_with_sha256(1000, &[0x5a; 16]).unwrap(),
+                PasswordKeyEncryptionAlgorithm::PwriKekAes256Cbc,
+                &mut password_rng,
+            )
+            .unwrap(),
+        )
+        .unwrap()
+        .add_authenticated_attribute(cms::builder::create_signing_time_attribute().unwrap())
+        .unwrap()
+        .build_with_rng(&mut OsRng);
+    assert!(result.is_err());
+}
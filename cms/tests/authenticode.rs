@@ -0,0 +1,215 @@
+//! Authenticode tests
+
+use cms::authenticode::{
+    SpcIndirectDataContent, SpcLink, SpcPeImageData, SpcSpOpusInfo, SpcStatementType, SpcString,
+    SPC_INDIRECT_DATA, SPC_INDIVIDUAL_SP_KEY_PURPOSE, SPC_PE_IMAGE_DATA,
+};
+use cms::signed_data::EncapsulatedContentInfo;
+use der::asn1::Ia5String;
+use der::{Any, Decode, Encode, Tag, Tagged};
+use hex_literal::hex;
+
+/// `SpcIndirectDataContent` of a PE image with a SHA-1 digest, as created by signtool.
+const SPC_INDIRECT_DATA_CONTENT: &[u8] = &hex!(
+    "303c"
+    "3017060a2b0601040182370201" "0f" "30090301 00a004a2028000"
+    "3021300906052b0e03021a05000414" "000102030405060708090a0b0c0d0e0f10111213"
+);
+
+#[test]
+fn decode_spc_indirect_data_content() {
+    let content = SpcIndirectDataContent::from_der(SPC_INDIRECT_DATA_CONTENT).unwrap();
+    assert_eq!(content.data.oid, SPC_PE_IMAGE_DATA);
+    assert_eq!(
+        content.message_digest.digest_algorithm.oid,
+        const_oid::db::rfc5912::ID_SHA_1
+    );
+    assert_eq!(
+        content.message_digest.digest.as_bytes(),
+        hex!("000102030405060708090a0b0c0d0e0f10111213")
+    );
+
+    let pe_image_data = content
+        .data
+        .value
+        .as_ref()
+        .unwrap()
+        .decode_as::<SpcPeImageData>()
+        .unwrap();
+    assert!(pe_image_data.flags.raw_bytes().is_empty());
+    match pe_image_data.file.unwrap() {
+        SpcLink::File(SpcString::Unicode(file)) => assert!(file.as_bytes().is_empty()),
+        link => panic!("unexpected link: {:?}", link),
+    }
+
+    assert_eq!(content.to_der().unwrap(), SPC_INDIRECT_DATA_CONTENT);
+}
+
+#[test]
+fn encapsulate_spc_indirect_data_content() {
+    let content = SpcIndirectDataContent::from_der(SPC_INDIRECT_DATA_CONTENT).unwrap();
+    let encapsulated_content_info = content.to_encapsulated_content_info().unwrap();
+    assert_eq!(encapsulated_content_info.econtent_type, SPC_INDIRECT_DATA);
+    let econtent = encapsulated_content_info.econtent.as_ref().unwrap();
+    assert_eq!(econtent.tag(), Tag::Sequence);
+    assert_eq!(econtent.value(), &SPC_INDIRECT_DATA_CONTENT[2..]);
+    assert_eq!(
+        SpcIndirectDataContent::from_encapsulated_content_info(&encapsulated_content_info).unwrap(),
+        content
+    );
+
+    // CMS-style encapsulation in an OCTET STRING
+    let encapsulated_content_info = EncapsulatedContentInfo {
+        econtent_type: SPC_INDIRECT_DATA,
+        econtent: Some(Any::new(Tag::OctetString, SPC_INDIRECT_DATA_CONTENT).unwrap()),
+    };
+    assert_eq!(
+        SpcIndirectDataContent::from_encapsulated_content_info(&encapsulated_content_info).unwrap(),
+        content
+    );
+
+    let encapsulated_content_info = EncapsulatedContentInfo {
+        econtent_type: const_oid::db::rfc5911::ID_DATA,
+        ..encapsulated_content_info
+    };
+    assert!(
+        SpcIndirectDataContent::from_encapsulated_content_info(&encapsulated_content_info).is_err()
+    );
+}
+
+#[test]
+fn reencode_spc_sp_opus_info() {
+    let opus_info = SpcSpOpusInfo {
+        program_name: Some(SpcString::Ascii(Ia5String::new("test").unwrap())),
+        more_info: Some(SpcLink::Url(Ia5String::new("https://example.com").unwrap())),
+    };
+    let der = opus_info.to_der().unwrap();
+    assert_eq!(der[..10], hex!("301fa0068104 74657374"));
+    assert_eq!(SpcSpOpusInfo::from_der(&der).unwrap(), opus_info);
+
+    assert_eq!(SpcSpOpusInfo::default().to_der().unwrap(), hex!("3000"));
+
+    let statement_type: SpcStatementType = vec![SPC_INDIVIDUAL_SP_KEY_PURPOSE];
+    assert_eq!(
+        statement_type.to_der().unwrap(),
+        hex!("300c060a2b060104018237020115")
+    );
+}
+
+#[cfg(feature = "builder")]
+mod builder {
+    use super::*;
+    use cms::builder::{SignedDataBuilder, SignerInfoBuilder};
+    use cms::cert::{CertificateChoices, IssuerAndSerialNumber};
+    use cms::content_info::{CmsVersion, ContentInfo};
+    use cms::signed_data::{SignedData, SignerIdentifier};
+    use cms::verify::{Error, SignedDataVerifier};
+    use der::asn1::{OctetString, SetOfVec};
+    use p256::ecdsa::{DerSignature, VerifyingKey};
+    use p256::pkcs8::{DecodePrivateKey, DecodePublicKey};
+    use sha2::{Digest, Sha256};
+    use spki::AlgorithmIdentifierOwned;
+    use x509_cert::attr::Attribute;
+    use x509_cert::Certificate;
+
+    const P256_CERT_DER: &[u8] = include_bytes!("examples/p256-cert.der");
+
+    fn verifying_key(certificate: &Certificate) -> spki::Result<VerifyingKey> {
+        VerifyingKey::from_public_key_der(
+            &certificate
+                .tbs_certificate
+                .subject_public_key_info
+                .to_der()?,
+        )
+    }
+
+    fn attribute(oid: const_oid::ObjectIdentifier, value: &impl Encode) -> Attribute {
+        Attribute {
+            oid,
+            values: SetOfVec::try_from(vec![Any::from_der(&value.to_der().unwrap()).unwrap()])
+                .unwrap(),
+        }
+    }
+
+    #[test]
+    fn verify_built_authenticode_signature() {
+        let secret_key =
+            p256::SecretKey::from_pkcs8_der(include_bytes!("examples/p256-priv.der")).unwrap();
+        let signer = ecdsa::SigningKey::<p256::NistP256>::from(secret_key);
+        let certificate = Certificate::from_der(P256_CERT_DER).unwrap();
+
+        let content = SpcIndirectDataContent::from_der(SPC_INDIRECT_DATA_CONTENT)
+            .unwrap()
+            .to_encapsulated_content_info()
+            .unwrap();
+        let sid = SignerIdentifier::IssuerAndSerialNumber(IssuerAndSerialNumber {
+            issuer: certificate.tbs_certificate.issuer.clone(),
+            serial_number: certificate.tbs_certificate.serial_number.clone(),
+        });
+        let digest_algorithm = AlgorithmIdentifierOwned {
+            oid: const_oid::db::rfc5912::ID_SHA_256,
+            parameters: None,
+        };
+        let mut signer_info_builder =
+            SignerInfoBuilder::new(&signer, sid, digest_algorithm, &content, None).unwrap();
+        signer_info_builder
+            .add_signed_attribute(attribute(
+                cms::authenticode::SPC_SP_OPUS_INFO,
+                &SpcSpOpusInfo::default(),
+            ))
+            .unwrap()
+            .add_signed_attribute(attribute(
+                cms::authenticode::SPC_STATEMENT_TYPE,
+                &vec![SPC_INDIVIDUAL_SP_KEY_PURPOSE],
+            ))
+            .unwrap();
+
+        let content_info = SignedDataBuilder::new(&content)
+            .add_certificate(CertificateChoices::Certificate(certificate))
+            .unwrap()
+            .add_signer_info::<_, DerSignature>(signer_info_builder)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let mut signed_data = ContentInfo::from_der(&content_info.to_der().unwrap())
+            .unwrap()
+            .content
+            .decode_as::<SignedData>()
+            .unwrap();
+        assert_eq!(signed_data.version, CmsVersion::V1);
+        assert_eq!(signed_data.encap_content_info, content);
+
+        // The message digest covers the value of the SEQUENCE only.
+        let signer_info = signed_data.signer_infos.0.get(0).unwrap();
+        let message_digest = signer_info
+            .signed_attrs
+            .as_ref()
+            .unwrap()
+            .iter()
+            .find(|attr| attr.oid == const_oid::db::rfc5911::ID_MESSAGE_DIGEST)
+            .unwrap()
+            .values
+            .get(0)
+            .unwrap()
+            .decode_as::<OctetString>()
+            .unwrap();
+        assert_eq!(
+            message_digest.as_bytes(),
+            Sha256::digest(&SPC_INDIRECT_DATA_CONTENT[2..]).as_slice()
+        );
+
+        SignedDataVerifier::new(&signed_data)
+            .verify_all::<_, DerSignature, _>(verifying_key)
+            .unwrap();
+
+        let mut modified_content =
+            SpcIndirectDataContent::from_encapsulated_content_info(&signed_data.encap_content_info)
+                .unwrap();
+        modified_content.message_digest.digest = OctetString::new([0u8; 20]).unwrap();
+        signed_data.encap_content_info = modified_content.to_encapsulated_content_info().unwrap();
+        let result =
+            SignedDataVerifier::new(&signed_data).verify_all::<_, DerSignature, _>(verifying_key);
+        assert!(matches!(result, Err(Error::MessageDigestMismatch)));
+    }
+}
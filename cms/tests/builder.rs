@@ -1,19 +1,34 @@
 #![cfg(feature = "builder")]
 
-use cms::builder::{create_signing_time_attribute, SignedDataBuilder, SignerInfoBuilder};
+use cms::builder::{
+    create_binary_signing_time_attribute, create_data_content, create_message_digest,
+    create_signing_time_attribute, create_signing_time_attribute_at, ContentEncryptionAlgorithm,
+    EnvelopedDataBuilder, KeyEncryptionInfo, KeyTransRecipientInfoBuilder, MessageDigestCalculator,
+    SignedDataBuilder, SignerInfoBuilder,
+};
 use cms::cert::{CertificateChoices, IssuerAndSerialNumber};
-use cms::signed_data::{EncapsulatedContentInfo, SignerIdentifier};
-use der::asn1::{OctetString, SetOfVec, Utf8StringRef};
-use der::{Any, DecodePem, Encode, Tag, Tagged};
+use cms::content_info::{CmsVersion, ContentInfo};
+use cms::enveloped_data::{EnvelopedData, RecipientIdentifier, RecipientInfo};
+use cms::signed_data::{
+    EncapsulatedContentInfo, SignedAttributesExt, SignedData, SignerIdentifier,
+};
+use der::asn1::{OctetString, OctetStringRef, SetOfVec, Utf8StringRef};
+use der::{Any, Decode, DecodePem, Encode, Tag, Tagged};
+use p256::ecdsa::{signature::Verifier, DerSignature};
 use p256::{pkcs8::DecodePrivateKey, NistP256};
 use pem_rfc7468::LineEnding;
 use rsa::pkcs1::DecodeRsaPrivateKey;
 use rsa::pkcs1v15::SigningKey;
+use rsa::rand_core::OsRng;
+use sha2::Digest;
 use sha2::Sha256;
 use spki::AlgorithmIdentifierOwned;
+use std::io::Read;
+use std::time::{Duration, SystemTime};
 use x509_cert::attr::{Attribute, AttributeTypeAndValue};
 use x509_cert::name::{RdnSequence, RelativeDistinguishedName};
 use x509_cert::serial_number::SerialNumber;
+use x509_cert::Certificate;
 
 const RSA_2048_PRIV_DER_EXAMPLE: &[u8] = include_bytes!("examples/rsa2048-priv.der");
 const PKCS8_PRIVATE_KEY_DER: &[u8] = include_bytes!("examples/p256-priv.der");
@@ -118,8 +133,259 @@ fn test_build_signed_data() {
     );
 }
 
+/// Sign `content` with the ECDSA signer and verify the resulting `signedData`.
+fn sign_and_verify_data(content: &[u8], detached: bool) -> SignedData {
+    let encapsulated_content_info = create_data_content((!detached).then_some(content)).unwrap();
+    let digest_algorithm = AlgorithmIdentifierOwned {
+        oid: const_oid::db::rfc5912::ID_SHA_256,
+        parameters: None,
+    };
+    let message_digest = create_message_digest(&digest_algorithm, content).unwrap();
+    assert_eq!(message_digest, Sha256::digest(content).as_slice());
+
+    let signer = ecdsa_signer();
+    let signer_info_builder = SignerInfoBuilder::new(
+        &signer,
+        signer_identifier(1),
+        digest_algorithm.clone(),
+        &encapsulated_content_info,
+        detached.then_some(message_digest.as_slice()),
+    )
+    .unwrap();
+
+    let content_info = SignedDataBuilder::new(&encapsulated_content_info)
+        .add_signer_info::<ecdsa::SigningKey<NistP256>, DerSignature>(signer_info_builder)
+        .unwrap()
+        .build()
+        .unwrap();
+    assert_eq!(
+        content_info.content_type,
+        const_oid::db::rfc5911::ID_SIGNED_DATA
+    );
+
+    let content_info = ContentInfo::from_der(&content_info.to_der().unwrap()).unwrap();
+    let signed_data = content_info.content.decode_as::<SignedData>().unwrap();
+
+    // The signer's digest algorithm is registered automatically
+    assert_eq!(signed_data.digest_algorithms.as_slice(), [digest_algorithm]);
+    assert_eq!(signed_data.encap_content_info, encapsulated_content_info);
+
+    // The message digest attribute matches the content
+    let signer_info = signed_data.signer_infos.0.get(0).unwrap();
+    let signed_attrs = signer_info.signed_attrs.as_ref().unwrap();
+    let message_digest_attr = signed_attrs
+        .iter()
+        .find(|attr| attr.oid == const_oid::db::rfc5911::ID_MESSAGE_DIGEST)
+        .unwrap();
+    let message_digest_value = message_digest_attr.values.get(0).unwrap();
+    assert_eq!(
+        message_digest_value
+            .decode_as::<OctetStringRef<'_>>()
+            .unwrap()
+            .as_bytes(),
+        message_digest
+    );
+
+    // The signature is calculated over the DER-encoded signed attributes
+    let signature = DerSignature::from_bytes(signer_info.signature.as_bytes()).unwrap();
+    signer
+        .verifying_key()
+        .verify(&signed_attrs.to_der().unwrap(), &signature)
+        .unwrap();
+
+    signed_data
+}
+
+#[test]
+fn test_build_signed_data_attached() {
+    let signed_data = sign_and_verify_data(b"hello world", false);
+    assert_eq!(
+        signed_data
+            .encap_content_info
+            .econtent
+            .unwrap()
+            .decode_as::<OctetStringRef<'_>>()
+            .unwrap()
+            .as_bytes(),
+        b"hello world"
+    );
+}
+
+#[test]
+fn test_build_signed_data_detached() {
+    let signed_data = sign_and_verify_data(b"hello world", true);
+    assert_eq!(signed_data.encap_content_info.econtent, None);
+}
+
+#[test]
+fn test_create_message_digest_unsupported() {
+    let digest_algorithm = AlgorithmIdentifierOwned {
+        oid: const_oid::db::rfc5912::ID_MD_5,
+        parameters: None,
+    };
+    assert!(create_message_digest(&digest_algorithm, b"hello world").is_err());
+}
+
+#[test]
+fn test_message_digest_calculator() {
+    let digest_algorithm = AlgorithmIdentifierOwned {
+        oid: const_oid::db::rfc5912::ID_SHA_256,
+        parameters: None,
+    };
+    let mut calculator = MessageDigestCalculator::new(&digest_algorithm).unwrap();
+    calculator.update(b"hello ").update(b"wor");
+    std::io::copy(&mut &b"ld"[..], &mut calculator).unwrap();
+    assert_eq!(
+        calculator.finalize(),
+        create_message_digest(&digest_algorithm, b"hello world").unwrap()
+    );
+
+    let digest_algorithm = AlgorithmIdentifierOwned {
+        oid: const_oid::db::rfc5912::ID_MD_5,
+        parameters: None,
+    };
+    assert!(MessageDigestCalculator::new(&digest_algorithm).is_err());
+}
+
+fn streaming_signed_data_builder<'a>(
+    encapsulated_content_info: &'a EncapsulatedContentInfo,
+    message_digest: Option<&[u8]>,
+) -> SignedDataBuilder<'a> {
+    let digest_algorithm = AlgorithmIdentifierOwned {
+        oid: const_oid::db::rfc5912::ID_SHA_256,
+        parameters: None,
+    };
+    let signer = ecdsa_signer();
+    let signer_info_builder = SignerInfoBuilder::new(
+        &signer,
+        signer_identifier(1),
+        digest_algorithm,
+        encapsulated_content_info,
+        message_digest,
+    )
+    .unwrap();
+
+    let mut builder = SignedDataBuilder::new(encapsulated_content_info);
+    builder
+        .add_signer_info::<ecdsa::SigningKey<NistP256>, DerSignature>(signer_info_builder)
+        .unwrap();
+    builder
+}
+
+#[test]
+fn test_build_signed_data_to_writer() {
+    let content: Vec<u8> = (0..1000u32).map(|i| i as u8).collect();
+    let digest_algorithm = AlgorithmIdentifierOwned {
+        oid: const_oid::db::rfc5912::ID_SHA_256,
+        parameters: None,
+    };
+    let mut calculator = MessageDigestCalculator::new(&digest_algorithm).unwrap();
+    for chunk in content.chunks(100) {
+        calculator.update(chunk);
+    }
+    let message_digest = calculator.finalize();
+
+    let encapsulated_content_info = create_data_content(None).unwrap();
+    let mut builder =
+        streaming_signed_data_builder(&encapsulated_content_info, Some(&message_digest));
+    let mut der = Vec::new();
+    builder
+        .build_to_writer(content.as_slice(), content.len() as u64, &mut der)
+        .unwrap();
+
+    // The streamed encoding is valid DER with the content encapsulated
+    let content_info = ContentInfo::from_der(&der).unwrap();
+    assert_eq!(content_info.to_der().unwrap(), der);
+    let mut signed_data = content_info.content.decode_as::<SignedData>().unwrap();
+    assert_eq!(
+        signed_data
+            .encap_content_info
+            .econtent
+            .as_ref()
+            .unwrap()
+            .decode_as::<OctetStringRef<'_>>()
+            .unwrap()
+            .as_bytes(),
+        content
+    );
+
+    // Apart from the content, it matches the detached signed data
+    let detached = builder.build().unwrap();
+    signed_data.encap_content_info.econtent = None;
+    assert_eq!(
+        detached.content.decode_as::<SignedData>().unwrap(),
+        signed_data
+    );
+
+    // The content must have the given length
+    let result = builder.build_to_writer(&content[..10], 11, std::io::sink());
+    assert!(result.is_err());
+
+    // The content to stream must not be encapsulated yet
+    let encapsulated_content_info = create_data_content(Some(&content)).unwrap();
+    let result = streaming_signed_data_builder(&encapsulated_content_info, None).build_to_writer(
+        content.as_slice(),
+        content.len() as u64,
+        std::io::sink(),
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_build_signed_data_to_writer_large_content() {
+    /// Writer which only keeps the first bytes
+    struct HeadWriter {
+        head: Vec<u8>,
+        len: u64,
+    }
+
+    impl std::io::Write for HeadWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            let remaining = 128usize.saturating_sub(self.head.len());
+            self.head
+                .extend_from_slice(&buf[..remaining.min(buf.len())]);
+            self.len += buf.len() as u64;
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    // Beyond the 256 MiB limit of `der::Length`
+    let content_len = 0x1000_0000u64;
+    let encapsulated_content_info = create_data_content(None).unwrap();
+    let mut writer = HeadWriter {
+        head: Vec::new(),
+        len: 0,
+    };
+    streaming_signed_data_builder(&encapsulated_content_info, Some(&[0; 32]))
+        .build_to_writer(
+            std::io::repeat(0).take(content_len),
+            content_len,
+            &mut writer,
+        )
+        .unwrap();
+
+    // SEQUENCE with a 4 byte length covering the rest of the encoding
+    assert_eq!(writer.head[..2], [0x30, 0x84]);
+    let len = u32::from_be_bytes(writer.head[2..6].try_into().unwrap());
+    assert_eq!(u64::from(len), writer.len - 6);
+
+    // The OCTET STRING header is followed by the content
+    let octet_string_header = [0x04, 0x84, 0x10, 0x00, 0x00, 0x00];
+    let offset = writer
+        .head
+        .windows(octet_string_header.len())
+        .position(|window| window == octet_string_header)
+        .unwrap();
+    assert!(writer.head[offset + octet_string_header.len()..]
+        .iter()
+        .all(|byte| *byte == 0));
+}
+
 // TODO more tests:
-// - external message
 // - PKCS #7 message:
 //   - different encapsulated content ASN.1 encoding
 //   - enveloped data content
@@ -186,3 +452,212 @@ fn test_create_signing_attribute() {
         "Invalid tag number in signing time attribute value"
     );
 }
+
+#[test]
+fn test_create_signing_time_attribute_at() {
+    // 2023-11-14T22:13:20Z is encoded as UTCTime
+    let signing_time = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    let attribute = create_signing_time_attribute_at(signing_time).unwrap();
+    assert_eq!(attribute.oid, const_oid::db::rfc5911::ID_SIGNING_TIME);
+    assert_eq!(
+        attribute.values.get(0).unwrap().to_der().unwrap(),
+        b"\x17\x0d231114221320Z"
+    );
+
+    // 2050-01-01T00:00:00Z is encoded as GeneralizedTime
+    let signing_time = SystemTime::UNIX_EPOCH + Duration::from_secs(2_524_608_000);
+    let attribute = create_signing_time_attribute_at(signing_time).unwrap();
+    assert_eq!(
+        attribute.values.get(0).unwrap().to_der().unwrap(),
+        b"\x18\x0f20500101000000Z"
+    );
+}
+
+#[test]
+fn test_create_binary_signing_time_attribute() {
+    let signing_time = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    let attribute = create_binary_signing_time_attribute(signing_time).unwrap();
+    assert_eq!(
+        attribute.oid,
+        const_oid::db::rfc6268::ID_AA_BINARY_SIGNING_TIME
+    );
+    assert_eq!(attribute.values.len(), 1);
+    let binary_time = attribute.values.get(0).unwrap();
+    assert_eq!(
+        binary_time.to_der().unwrap(),
+        [0x02, 0x04, 0x65, 0x53, 0xf1, 0x00]
+    );
+    assert_eq!(binary_time.decode_as::<u64>().unwrap(), 1_700_000_000);
+}
+
+#[test]
+fn test_signed_attributes_to_der_for_signing() {
+    let signed_data = sign_and_verify_data(b"hello world", false);
+    let signer_info = signed_data.signer_infos.0.get(0).unwrap();
+    let signed_attrs = signer_info.signed_attrs.as_ref().unwrap();
+    let signed_attrs_der = signed_attrs.to_der_for_signing().unwrap();
+
+    // The SignerInfo contains the same encoding, but with an IMPLICIT [0] tag
+    assert_eq!(signed_attrs_der[0], 0x31);
+    let signer_info_der = signer_info.to_der().unwrap();
+    assert!(signer_info_der
+        .windows(signed_attrs_der.len())
+        .any(|window| window[0] == 0xa0 && window[1..] == signed_attrs_der[1..]));
+}
+
+const RSA_2048_CERT_DER_EXAMPLE: &[u8] = include_bytes!("examples/rsa2048-cert.der");
+
+/// Build `EnvelopedData` for the example RSA certificate and decrypt it again using the
+/// example RSA private key.
+fn encrypt_and_decrypt(
+    key_encryption: fn(rsa::RsaPublicKey) -> KeyEncryptionInfo,
+    content_encryption_algorithm: ContentEncryptionAlgorithm,
+) -> EnvelopedData {
+    use cipher::{block_padding::Pkcs7, BlockDecryptMut, KeyIvInit};
+
+    let certificate = Certificate::from_der(RSA_2048_CERT_DER_EXAMPLE).unwrap();
+    let content = b"Hello, world!";
+
+    let mut rng = OsRng;
+    let recipient_info_builder =
+        KeyTransRecipientInfoBuilder::from_certificate(&certificate, key_encryption, &mut rng)
+            .unwrap();
+
+    let content_info = EnvelopedDataBuilder::new(content.as_slice(), content_encryption_algorithm)
+        .add_recipient_info(recipient_info_builder)
+        .unwrap()
+        .build_with_rng(&mut OsRng)
+        .unwrap();
+    assert_eq!(
+        content_info.content_type,
+        const_oid::db::rfc5911::ID_ENVELOPED_DATA
+    );
+
+    let der = content_info.to_der().unwrap();
+    let enveloped_data = ContentInfo::from_der(&der)
+        .unwrap()
+        .content
+        .decode_as::<EnvelopedData>()
+        .unwrap();
+    assert_eq!(enveloped_data.version, CmsVersion::V0);
+
+    let ktri = match enveloped_data.recip_infos.0.get(0).unwrap() {
+        RecipientInfo::Ktri(ktri) => ktri,
+        _ => panic!("expected KeyTransRecipientInfo"),
+    };
+    assert_eq!(ktri.version, CmsVersion::V0);
+    assert_eq!(
+        ktri.rid,
+        RecipientIdentifier::IssuerAndSerialNumber(IssuerAndSerialNumber {
+            issuer: certificate.tbs_certificate.issuer.clone(),
+            serial_number: certificate.tbs_certificate.serial_number.clone(),
+        })
+    );
+
+    let private_key = rsa::RsaPrivateKey::from_pkcs1_der(RSA_2048_PRIV_DER_EXAMPLE).unwrap();
+    let content_encryption_key = match ktri.key_enc_alg.oid {
+        const_oid::db::rfc5912::RSA_ENCRYPTION => private_key
+            .decrypt(rsa::Pkcs1v15Encrypt, ktri.enc_key.as_bytes())
+            .unwrap(),
+        const_oid::db::rfc5912::ID_RSAES_OAEP => private_key
+            .decrypt(rsa::Oaep::new::<Sha256>(), ktri.enc_key.as_bytes())
+            .unwrap(),
+        oid => panic!("unexpected key encryption algorithm: {oid}"),
+    };
+    assert_eq!(
+        content_encryption_key.len(),
+        content_encryption_algorithm.key_size()
+    );
+
+    let encrypted_content_info = &enveloped_data.encrypted_content;
+    assert_eq!(
+        encrypted_content_info.content_type,
+        const_oid::db::rfc5911::ID_DATA
+    );
+    assert_eq!(
+        encrypted_content_info.content_enc_alg.oid,
+        content_encryption_algorithm.oid()
+    );
+    let iv = encrypted_content_info
+        .content_enc_alg
+        .parameters
+        .as_ref()
+        .unwrap()
+        .decode_as::<OctetString>()
+        .unwrap();
+    let encrypted_content = encrypted_content_info
+        .encrypted_content
+        .as_ref()
+        .unwrap()
+        .as_bytes();
+
+    let decrypted_content = match content_encryption_algorithm {
+        ContentEncryptionAlgorithm::Aes128Cbc => {
+            cbc::Decryptor::<aes::Aes128>::new_from_slices(&content_encryption_key, iv.as_bytes())
+                .unwrap()
+                .decrypt_padded_vec_mut::<Pkcs7>(encrypted_content)
+        }
+        ContentEncryptionAlgorithm::Aes192Cbc => {
+            cbc::Decryptor::<aes::Aes192>::new_from_slices(&content_encryption_key, iv.as_bytes())
+                .unwrap()
+                .decrypt_padded_vec_mut::<Pkcs7>(encrypted_content)
+        }
+        ContentEncryptionAlgorithm::Aes256Cbc => {
+            cbc::Decryptor::<aes::Aes256>::new_from_slices(&content_encryption_key, iv.as_bytes())
+                .unwrap()
+                .decrypt_padded_vec_mut::<Pkcs7>(encrypted_content)
+        }
+    }
+    .unwrap();
+    assert_eq!(decrypted_content, content);
+
+    enveloped_data
+}
+
+#[test]
+fn test_build_enveloped_data_rsa_pkcs1v15() {
+    let enveloped_data = encrypt_and_decrypt(
+        KeyEncryptionInfo::Rsa,
+        ContentEncryptionAlgorithm::Aes128Cbc,
+    );
+    let ktri = match enveloped_data.recip_infos.0.get(0).unwrap() {
+        RecipientInfo::Ktri(ktri) => ktri,
+        _ => unreachable!(),
+    };
+    assert_eq!(ktri.key_enc_alg.parameters, Some(Any::null()));
+}
+
+#[test]
+fn test_build_enveloped_data_rsa_oaep() {
+    let enveloped_data = encrypt_and_decrypt(
+        KeyEncryptionInfo::RsaOaep,
+        ContentEncryptionAlgorithm::Aes256Cbc,
+    );
+    let ktri = match enveloped_data.recip_infos.0.get(0).unwrap() {
+        RecipientInfo::Ktri(ktri) => ktri,
+        _ => unreachable!(),
+    };
+    let oaep_params = ktri
+        .key_enc_alg
+        .parameters
+        .as_ref()
+        .unwrap()
+        .decode_as::<rsa::pkcs1::RsaOaepParams<'_>>()
+        .unwrap();
+    assert_eq!(oaep_params.hash.oid, const_oid::db::rfc5912::ID_SHA_256);
+}
+
+#[test]
+fn test_build_enveloped_data_aes192() {
+    encrypt_and_decrypt(
+        KeyEncryptionInfo::Rsa,
+        ContentEncryptionAlgorithm::Aes192Cbc,
+    );
+}
+
+#[test]
+fn test_build_enveloped_data_without_recipients() {
+    let result = EnvelopedDataBuilder::new(b"Hello, world!", ContentEncryptionAlgorithm::Aes128Cbc)
+        .build_with_rng(&mut OsRng);
+    assert!(result.is_err());
+}
@@ -50,3 +50,144 @@ fn reencode_compressed_data_test() {
     // should match the original
     assert_eq!(reencoded_data_inci, der_ci)
 }
+
+#[cfg(feature = "compress")]
+mod compress {
+    use super::*;
+    use cms::compress::{
+        create_encapsulated_content, decompress_nested, CompressedDataBuilder,
+        CompressedDataDecompressor, Error, DEFAULT_MAX_NESTING_DEPTH, DEFAULT_SIZE_LIMIT,
+    };
+    use cms::signed_data::EncapsulatedContentInfo;
+    use der::{Any, Tag};
+
+    fn compressed_data(content_info: &ContentInfo) -> CompressedData {
+        content_info.content.decode_as().unwrap()
+    }
+
+    #[test]
+    fn decompress_compressed_data() {
+        let ci = ContentInfo::from_der(include_bytes!("examples/compressed_data.bin")).unwrap();
+        let data = compressed_data(&ci);
+        let content = CompressedDataDecompressor::new(&data).decompress().unwrap();
+        assert_eq!(content, include_bytes!("examples/data.txt"));
+
+        let inner = CompressedDataDecompressor::new(&data)
+            .decompress_content_info()
+            .unwrap();
+        assert_eq!(inner.content_type, const_oid::db::rfc5911::ID_DATA);
+        assert_eq!(inner.content.value(), include_bytes!("examples/data.txt"));
+    }
+
+    #[test]
+    fn decompress_size_limit() {
+        let ci = ContentInfo::from_der(include_bytes!("examples/compressed_data.bin")).unwrap();
+        let data = compressed_data(&ci);
+        let result = CompressedDataDecompressor::new(&data)
+            .set_size_limit(16)
+            .decompress();
+        assert!(matches!(result, Err(Error::SizeLimitExceeded)));
+    }
+
+    #[test]
+    fn decompress_default_size_limit() {
+        let content = vec![0; DEFAULT_SIZE_LIMIT + 1];
+        let encapsulated_content = EncapsulatedContentInfo {
+            econtent_type: const_oid::db::rfc5911::ID_DATA,
+            econtent: Some(Any::new(Tag::OctetString, content).unwrap()),
+        };
+        let ci = CompressedDataBuilder::new(&encapsulated_content)
+            .build()
+            .unwrap();
+        let data = compressed_data(&ci);
+
+        let result = CompressedDataDecompressor::new(&data).decompress();
+        assert!(matches!(result, Err(Error::SizeLimitExceeded)));
+
+        let content = CompressedDataDecompressor::new(&data)
+            .set_size_limit(DEFAULT_SIZE_LIMIT + 1)
+            .decompress()
+            .unwrap();
+        assert_eq!(content.len(), DEFAULT_SIZE_LIMIT + 1);
+    }
+
+    #[test]
+    fn compress_data() {
+        let content = include_bytes!("examples/data.txt");
+        let encapsulated_content = create_encapsulated_content(&ContentInfo {
+            content_type: const_oid::db::rfc5911::ID_DATA,
+            content: Any::new(Tag::OctetString, content.as_slice()).unwrap(),
+        })
+        .unwrap();
+        for compression_level in [0, 6, 10] {
+            let ci = CompressedDataBuilder::new(&encapsulated_content)
+                .set_compression_level(compression_level)
+                .build()
+                .unwrap();
+            assert_eq!(
+                ci.content_type,
+                const_oid::db::rfc6268::ID_CT_COMPRESSED_DATA
+            );
+
+            let data = compressed_data(&ContentInfo::from_der(&ci.to_der().unwrap()).unwrap());
+            assert_eq!(data.version, CmsVersion::V0);
+            assert_eq!(
+                data.compression_alg.oid,
+                const_oid::db::rfc6268::ID_ALG_ZLIB_COMPRESS
+            );
+            assert_eq!(None, data.compression_alg.parameters);
+            assert_eq!(
+                CompressedDataDecompressor::new(&data).decompress().unwrap(),
+                content
+            );
+        }
+    }
+
+    #[test]
+    fn compress_nested_content_info() {
+        // compress a DigestedData twice
+        let der_ci = include_bytes!("examples/digested_data.bin");
+        let ci = ContentInfo::from_der(der_ci).unwrap();
+        let mut compressed = ci.clone();
+        for _ in 0..2 {
+            let encapsulated_content = create_encapsulated_content(&compressed).unwrap();
+            compressed = CompressedDataBuilder::new(&encapsulated_content)
+                .build()
+                .unwrap();
+        }
+
+        let inner = compressed_data(&compressed);
+        assert_eq!(
+            inner.encap_content_info.econtent_type,
+            const_oid::db::rfc6268::ID_CT_COMPRESSED_DATA
+        );
+
+        let decompressed =
+            decompress_nested(compressed, 1 << 20, DEFAULT_MAX_NESTING_DEPTH).unwrap();
+        assert_eq!(decompressed, ci);
+        assert_eq!(decompressed.to_der().unwrap(), der_ci);
+
+        // content of other types is returned unchanged
+        assert_eq!(decompress_nested(ci.clone(), 1 << 20, 0).unwrap(), ci);
+    }
+
+    #[test]
+    fn decompress_nesting_depth_limit() {
+        let der_ci = include_bytes!("examples/digested_data.bin");
+        let ci = ContentInfo::from_der(der_ci).unwrap();
+        let mut compressed = ci.clone();
+        for _ in 0..DEFAULT_MAX_NESTING_DEPTH + 1 {
+            let encapsulated_content = create_encapsulated_content(&compressed).unwrap();
+            compressed = CompressedDataBuilder::new(&encapsulated_content)
+                .build()
+                .unwrap();
+        }
+
+        let result = decompress_nested(compressed.clone(), 1 << 20, DEFAULT_MAX_NESTING_DEPTH);
+        assert!(matches!(result, Err(Error::NestingDepthExceeded)));
+
+        let decompressed =
+            decompress_nested(compressed, 1 << 20, DEFAULT_MAX_NESTING_DEPTH + 1).unwrap();
+        assert_eq!(decompressed, ci);
+    }
+}
@@ -0,0 +1,77 @@
+//! ContentInfo tests
+
+use cms::compressed_data::CompressedData;
+use cms::content_info::{ContentInfo, StandardContent};
+use cms::digested_data::DigestedData;
+use cms::enveloped_data::EnvelopedData;
+use cms::signed_data::SignedData;
+use der::{Decode, Encode, ErrorKind};
+
+#[test]
+fn decode_content_test() {
+    let der_ci = include_bytes!("examples/digested_data.bin");
+    let ci = ContentInfo::from_der(der_ci).unwrap();
+
+    let digested_data = ci.decode_content::<DigestedData>().unwrap();
+    assert_eq!(
+        digested_data,
+        DigestedData::from_der(&ci.content.to_der().unwrap()).unwrap()
+    );
+}
+
+#[test]
+fn decode_content_wrong_type_test() {
+    let der_ci = include_bytes!("examples/digested_data.bin");
+    let ci = ContentInfo::from_der(der_ci).unwrap();
+
+    let err = ci.decode_content::<SignedData>().unwrap_err();
+    assert_eq!(
+        err.kind(),
+        ErrorKind::OidUnknown {
+            oid: const_oid::db::rfc5911::ID_DIGESTED_DATA
+        }
+    );
+}
+
+#[test]
+fn decode_standard_content_test() {
+    let ci = ContentInfo::from_der(include_bytes!("examples/sd.cms")).unwrap();
+    assert!(matches!(
+        ci.decode_standard_content().unwrap(),
+        StandardContent::SignedData(_)
+    ));
+
+    let ci = ContentInfo::from_der(include_bytes!("examples/enveloped_data_ktri.bin")).unwrap();
+    match ci.decode_standard_content().unwrap() {
+        StandardContent::EnvelopedData(enveloped_data) => {
+            assert_eq!(
+                enveloped_data,
+                ci.decode_content::<EnvelopedData>().unwrap()
+            )
+        }
+        other => panic!("unexpected content: {other:?}"),
+    }
+
+    let ci = ContentInfo::from_der(include_bytes!("examples/compressed_data.bin")).unwrap();
+    match ci.decode_standard_content().unwrap() {
+        StandardContent::CompressedData(compressed_data) => {
+            assert_eq!(
+                compressed_data,
+                ci.decode_content::<CompressedData>().unwrap()
+            )
+        }
+        other => panic!("unexpected content: {other:?}"),
+    }
+}
+
+#[test]
+fn standard_content_roundtrip_test() {
+    let der_ci = include_bytes!("examples/encrypted_data.bin");
+    let ci = ContentInfo::from_der(der_ci).unwrap();
+
+    let content = ci.decode_standard_content().unwrap();
+    assert_eq!(content.content_type(), ci.content_type);
+    let reencoded = ContentInfo::try_from(content).unwrap();
+    assert_eq!(reencoded, ci);
+    assert_eq!(reencoded.to_der().unwrap(), der_ci);
+}
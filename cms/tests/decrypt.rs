@@ -0,0 +1,142 @@
+#![cfg(feature = "decrypt")]
+
+use cms::cert::IssuerAndSerialNumber;
+use cms::content_info::ContentInfo;
+use cms::decrypt::{EnvelopedDataDecryptor, Error, KeyTransRecipient};
+use cms::enveloped_data::{EnvelopedData, RecipientIdentifier, RecipientInfo};
+use der::asn1::OctetString;
+use der::Decode;
+use rsa::pkcs1::DecodeRsaPrivateKey;
+use rsa::rand_core::OsRng;
+use rsa::RsaPrivateKey;
+use x509_cert::Certificate;
+
+/// Content encrypted in the example enveloped data.
+const CONTENT: &[u8] = b"hello world\n";
+
+const RSA_2048_PRIV_DER_EXAMPLE: &[u8] = include_bytes!("examples/rsa2048-priv.der");
+
+/// Self-signed RSA certificate of the recipient, generated using:
+/// $ openssl req -new -x509 -key rsa2048-priv.pem -subj "/CN=cms test recipient"
+///   -set_serial 0x0a0b0c0d
+const RSA_2048_CERT_DER_EXAMPLE: &[u8] = include_bytes!("examples/rsa2048-cert.der");
+
+/// Generated using:
+/// $ openssl cms -encrypt -binary -aes-128-cbc -in content.txt -outform der rsa2048-cert.pem
+const ENVELOPED_DATA_RSA_DER: &[u8] = include_bytes!("examples/enveloped_data_rsa_aes128.der");
+
+/// Generated using:
+/// $ openssl cms -encrypt -binary -aes-256-cbc -keyid -in content.txt -outform der
+///   -recip rsa2048-cert.pem -keyopt rsa_padding_mode:oaep -keyopt rsa_oaep_md:sha256
+///   -keyopt rsa_mgf1_md:sha256
+const ENVELOPED_DATA_RSA_OAEP_SKID_DER: &[u8] =
+    include_bytes!("examples/enveloped_data_rsa_oaep_skid.der");
+
+fn enveloped_data(der: &[u8]) -> EnvelopedData {
+    ContentInfo::from_der(der)
+        .unwrap()
+        .content
+        .decode_as()
+        .unwrap()
+}
+
+fn recipient() -> KeyTransRecipient {
+    let certificate = Certificate::from_der(RSA_2048_CERT_DER_EXAMPLE).unwrap();
+    let private_key = RsaPrivateKey::from_pkcs1_der(RSA_2048_PRIV_DER_EXAMPLE).unwrap();
+    KeyTransRecipient::from_certificate(&certificate, private_key).unwrap()
+}
+
+#[test]
+fn decrypt_rsa_pkcs1v15() {
+    let enveloped_data = enveloped_data(ENVELOPED_DATA_RSA_DER);
+    let content = EnvelopedDataDecryptor::new(&enveloped_data)
+        .decrypt_with_rng(&recipient(), &mut OsRng)
+        .unwrap();
+    assert_eq!(content, CONTENT);
+}
+
+#[test]
+fn decrypt_rsa_oaep_subject_key_identifier() {
+    let enveloped_data = enveloped_data(ENVELOPED_DATA_RSA_OAEP_SKID_DER);
+    let decryptor = EnvelopedDataDecryptor::new(&enveloped_data);
+    let recipient = recipient();
+
+    let ktri = decryptor.find_recipient_info(&recipient).unwrap();
+    assert!(matches!(
+        ktri.rid,
+        RecipientIdentifier::SubjectKeyIdentifier(_)
+    ));
+
+    let content = decryptor.decrypt_with_rng(&recipient, &mut OsRng).unwrap();
+    assert_eq!(content, CONTENT);
+}
+
+#[test]
+fn decrypt_unknown_recipient() {
+    let enveloped_data = enveloped_data(ENVELOPED_DATA_RSA_DER);
+    let certificate = Certificate::from_der(include_bytes!("examples/p256-cert.der")).unwrap();
+    let rid = RecipientIdentifier::IssuerAndSerialNumber(IssuerAndSerialNumber {
+        issuer: certificate.tbs_certificate.issuer.clone(),
+        serial_number: certificate.tbs_certificate.serial_number,
+    });
+    let private_key = RsaPrivateKey::from_pkcs1_der(RSA_2048_PRIV_DER_EXAMPLE).unwrap();
+
+    let result = EnvelopedDataDecryptor::new(&enveloped_data)
+        .decrypt_with_rng(&KeyTransRecipient::new(rid, private_key), &mut OsRng);
+    assert!(matches!(result, Err(Error::RecipientNotFound)));
+}
+
+#[test]
+fn decrypt_invalid_encrypted_key() {
+    let mut enveloped_data = enveloped_data(ENVELOPED_DATA_RSA_DER);
+    let mut recipient_infos = enveloped_data.recip_infos.0.into_vec();
+    match &mut recipient_infos[0] {
+        RecipientInfo::Ktri(ktri) => {
+            let mut enc_key = ktri.enc_key.as_bytes().to_vec();
+            enc_key[0] ^= 0x01;
+            ktri.enc_key = OctetString::new(enc_key).unwrap();
+        }
+        _ => panic!("expected KeyTransRecipientInfo"),
+    }
+    enveloped_data.recip_infos.0 = recipient_infos.try_into().unwrap();
+
+    // The failure to decrypt the content-encryption key is indistinguishable from a failure to
+    // decrypt the content. With a random content-encryption key, the padding of the decrypted
+    // content may occasionally be valid, so the content must only differ in that case.
+    let result =
+        EnvelopedDataDecryptor::new(&enveloped_data).decrypt_with_rng(&recipient(), &mut OsRng);
+    match result {
+        Ok(content) => assert_ne!(content, CONTENT),
+        Err(err) => assert!(matches!(err, Error::Decryption)),
+    }
+}
+
+#[cfg(feature = "builder")]
+#[test]
+fn decrypt_built_enveloped_data() {
+    use cms::builder::{
+        ContentEncryptionAlgorithm, EnvelopedDataBuilder, KeyEncryptionInfo,
+        KeyTransRecipientInfoBuilder,
+    };
+    use der::Encode;
+
+    let certificate = Certificate::from_der(RSA_2048_CERT_DER_EXAMPLE).unwrap();
+    for key_encryption in [KeyEncryptionInfo::Rsa, KeyEncryptionInfo::RsaOaep] {
+        let mut rng = OsRng;
+        let recipient_info_builder =
+            KeyTransRecipientInfoBuilder::from_certificate(&certificate, key_encryption, &mut rng)
+                .unwrap();
+        let content_info =
+            EnvelopedDataBuilder::new(CONTENT, ContentEncryptionAlgorithm::Aes192Cbc)
+                .add_recipient_info(recipient_info_builder)
+                .unwrap()
+                .build_with_rng(&mut OsRng)
+                .unwrap();
+
+        let enveloped_data = enveloped_data(&content_info.to_der().unwrap());
+        let content = EnvelopedDataDecryptor::new(&enveloped_data)
+            .decrypt_with_rng(&recipient(), &mut OsRng)
+            .unwrap();
+        assert_eq!(content, CONTENT);
+    }
+}
@@ -54,3 +54,52 @@ fn reencode_digested_data_test() {
     // should match the original
     assert_eq!(reencoded_data_inci, der_ci)
 }
+
+#[cfg(feature = "verify")]
+#[test]
+fn verify_digested_data() {
+    use cms::verify::{DigestedDataVerifier, Error};
+
+    let ci = ContentInfo::from_der(include_bytes!("examples/digested_data.bin")).unwrap();
+    let mut data = ci.content.decode_as::<DigestedData>().unwrap();
+    DigestedDataVerifier::new(&data).verify().unwrap();
+
+    // detached content
+    let content = data.encap_content_info.econtent.take().unwrap();
+    assert!(matches!(
+        DigestedDataVerifier::new(&data).verify(),
+        Err(Error::ContentMissing)
+    ));
+    DigestedDataVerifier::new(&data)
+        .set_detached_content(content.value())
+        .verify()
+        .unwrap();
+    assert!(matches!(
+        DigestedDataVerifier::new(&data)
+            .set_detached_content(b"modified content")
+            .verify(),
+        Err(Error::MessageDigestMismatch)
+    ));
+}
+
+#[cfg(feature = "builder")]
+#[test]
+fn build_digested_data() {
+    use cms::builder::{create_data_content, DigestedDataBuilder};
+    use spki::AlgorithmIdentifierOwned;
+
+    let content = create_data_content(Some(include_bytes!("examples/data.txt"))).unwrap();
+    let digest_algorithm = AlgorithmIdentifierOwned {
+        oid: const_oid::db::rfc5912::ID_SHA_1,
+        parameters: None,
+    };
+    let ci = DigestedDataBuilder::new(digest_algorithm, &content)
+        .build()
+        .unwrap();
+
+    // matches the DigestedData created by OpenSSL
+    assert_eq!(
+        ci.to_der().unwrap(),
+        include_bytes!("examples/digested_data.bin")
+    );
+}
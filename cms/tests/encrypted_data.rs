@@ -56,3 +56,106 @@ fn reencode_encrypted_data_test() {
     // should match the original
     assert_eq!(reencoded_data_inci, der_ci)
 }
+
+#[cfg(feature = "decrypt")]
+mod decrypt {
+    use super::*;
+    use cms::decrypt::{EncryptedDataDecryptor, Error};
+
+    const PASSWORD: &[u8] = b"cms test password";
+
+    /// First `ContentInfo` of the `AuthenticatedSafe` of a PKCS #12 file generated using:
+    /// $ openssl pkcs12 -export -in rsa2048-cert.pem -inkey rsa2048-priv.pem
+    ///   -passout "pass:cms test password" -out test.p12
+    const ENCRYPTED_DATA_PBES2_DER: &[u8] =
+        include_bytes!("examples/encrypted_data_pbes2_aes256.der");
+
+    fn encrypted_data(der: &[u8]) -> EncryptedData {
+        ContentInfo::from_der(der)
+            .unwrap()
+            .content
+            .decode_as()
+            .unwrap()
+    }
+
+    #[test]
+    fn decrypt_pbes2_aes256() {
+        let encrypted_data = encrypted_data(ENCRYPTED_DATA_PBES2_DER);
+        assert_eq!(
+            encrypted_data.enc_content_info.content_enc_alg.oid,
+            pkcs5::pbes2::PBES2_OID
+        );
+
+        // The content is a PKCS #12 `SafeContents` containing the certificate.
+        let content = EncryptedDataDecryptor::new(&encrypted_data)
+            .decrypt(PASSWORD)
+            .unwrap();
+        let certificate = include_bytes!("examples/rsa2048-cert.der");
+        assert!(content
+            .windows(certificate.len())
+            .any(|window| window == certificate));
+    }
+
+    #[test]
+    fn decrypt_wrong_password() {
+        let encrypted_data = encrypted_data(ENCRYPTED_DATA_PBES2_DER);
+        let result = EncryptedDataDecryptor::new(&encrypted_data).decrypt(b"wrong password");
+        assert!(matches!(result, Err(Error::Decryption)));
+    }
+
+    #[test]
+    fn decrypt_unsupported_algorithm() {
+        let encrypted_data = encrypted_data(include_bytes!("examples/encrypted_data.bin"));
+        let result = EncryptedDataDecryptor::new(&encrypted_data).decrypt(PASSWORD);
+        assert!(matches!(
+            result,
+            Err(Error::UnsupportedContentEncryptionAlgorithm(_))
+        ));
+    }
+
+    #[cfg(feature = "builder")]
+    #[test]
+    fn decrypt_built_encrypted_data() {
+        use cms::builder::{create_content_type_attribute, EncryptedDataBuilder};
+
+        const CONTENT: &[u8] = b"hello world\n";
+        let salt = [0x5a; 16];
+        let iv = [0xa5; 16];
+        let scheme = pkcs5::pbes2::Parameters::pbkdf2_sha256_aes256cbc(1000, &salt, &iv).unwrap();
+
+        let content_info = EncryptedDataBuilder::new(CONTENT, scheme.clone())
+            .build(PASSWORD)
+            .unwrap();
+        assert_eq!(
+            content_info.content_type,
+            const_oid::db::rfc5911::ID_ENCRYPTED_DATA
+        );
+        let data = encrypted_data(&content_info.to_der().unwrap());
+        assert_eq!(data.version, CmsVersion::V0);
+        assert!(data.unprotected_attrs.is_none());
+        let decryptor = EncryptedDataDecryptor::new(&data);
+        assert_eq!(decryptor.decrypt(PASSWORD).unwrap(), CONTENT);
+        assert!(matches!(
+            decryptor.decrypt(b"wrong password"),
+            Err(Error::Decryption)
+        ));
+
+        // RFC 5652 § 8: the version is 2 if unprotected attributes are present.
+        let content_info = EncryptedDataBuilder::new(CONTENT, scheme)
+            .add_unprotected_attribute(
+                create_content_type_attribute(const_oid::db::rfc5911::ID_DATA).unwrap(),
+            )
+            .unwrap()
+            .build(PASSWORD)
+            .unwrap();
+        let data = encrypted_data(&content_info.to_der().unwrap());
+        assert_eq!(data.version, CmsVersion::V2);
+        assert!(data.unprotected_attrs.is_some());
+        assert_eq!(
+            EncryptedDataDecryptor::new(&data)
+                .decrypt(PASSWORD)
+                .unwrap(),
+            CONTENT
+        );
+    }
+}
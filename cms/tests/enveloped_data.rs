@@ -2,11 +2,12 @@ extern crate core;
 
 use cms::content_info::{CmsVersion, ContentInfo};
 use cms::enveloped_data::{
-    EnvelopedData, KeyAgreeRecipientIdentifier, OriginatorIdentifierOrKey, RecipientIdentifier,
-    RecipientInfo,
+    EnvelopedData, KemRecipientInfo, KeyAgreeRecipientIdentifier, OriginatorIdentifierOrKey,
+    OtherRecipientInfo, RecipientIdentifier, RecipientInfo, ID_ORI_KEM,
 };
 use const_oid::ObjectIdentifier;
-use der::{AnyRef, Decode, Encode, Tag, Tagged};
+use der::asn1::OctetString;
+use der::{Any, AnyRef, Decode, Encode, ErrorKind, Tag, Tagged};
 use hex_literal::hex;
 use pkcs5::pbes2::Pbkdf2Params;
 use spki::AlgorithmIdentifierOwned;
@@ -508,3 +509,57 @@ fn reencode_enveloped_data_multi_test() {
     // should match the original
     assert_eq!(reencoded_data_in_ci, der_ci)
 }
+
+#[test]
+fn kem_recipient_info_other_recipient_info_test() {
+    let kemri = KemRecipientInfo {
+        version: CmsVersion::V0,
+        rid: RecipientIdentifier::SubjectKeyIdentifier(
+            OctetString::new([0x01, 0x02, 0x03]).unwrap().into(),
+        ),
+        kem: AlgorithmIdentifierOwned {
+            oid: ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.4.2"),
+            parameters: None,
+        },
+        kem_ct: OctetString::new([0x42; 1088]).unwrap(),
+        kdf: AlgorithmIdentifierOwned {
+            oid: ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.16.3.28"),
+            parameters: None,
+        },
+        kek_length: 32,
+        ukm: Some(OctetString::new(b"ukm".to_vec()).unwrap()),
+        wrap: AlgorithmIdentifierOwned {
+            oid: const_oid::db::rfc5911::ID_AES_256_WRAP,
+            parameters: None,
+        },
+        encrypted_key: OctetString::new([0x24; 40]).unwrap(),
+    };
+
+    let ori = kemri.to_other_recipient_info().unwrap();
+    assert_eq!(ori.ori_type, ID_ORI_KEM);
+    let recipient_info = RecipientInfo::Ori(ori);
+    let reencoded = RecipientInfo::from_der(&recipient_info.to_der().unwrap()).unwrap();
+    assert_eq!(reencoded, recipient_info);
+    match reencoded {
+        RecipientInfo::Ori(ori) => {
+            assert_eq!(
+                KemRecipientInfo::from_other_recipient_info(&ori).unwrap(),
+                kemri
+            )
+        }
+        _ => panic!("expected OtherRecipientInfo"),
+    }
+
+    let other = OtherRecipientInfo {
+        ori_type: ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.16.13.1"),
+        ori_value: Any::encode_from(&kemri).unwrap(),
+    };
+    assert_eq!(
+        KemRecipientInfo::from_other_recipient_info(&other)
+            .unwrap_err()
+            .kind(),
+        ErrorKind::OidUnknown {
+            oid: other.ori_type
+        }
+    );
+}
@@ -0,0 +1,276 @@
+#![cfg(all(feature = "builder", feature = "decrypt"))]
+
+use cms::builder::{
+    ContentEncryptionAlgorithm, EnvelopedDataBuilder, KemRecipientInfoBuilder,
+    OriginatorKeyEncapsulation, RecipientInfoBuilder,
+};
+use cms::cert::IssuerAndSerialNumber;
+use cms::content_info::{CmsVersion, ContentInfo};
+use cms::decrypt::{EnvelopedDataDecryptor, Error, KemRecipient, RecipientKeyEncapsulation};
+use cms::enveloped_data::{
+    CmsOriForKemOtherInfo, EncryptedKey, EnvelopedData, KemRecipientInfo, RecipientIdentifier,
+    RecipientInfo, ID_ORI_KEM,
+};
+use cms::kem::{KemKeyDerivationAlgorithm, ID_ALG_HKDF_WITH_SHA256};
+use cms::key_agreement::KeyWrapAlgorithm;
+use der::asn1::OctetString;
+use der::{Decode, Encode};
+use hex_literal::hex;
+use hmac::{Hmac, Mac};
+use p256::elliptic_curve::point::AffineCoordinates;
+use p256::elliptic_curve::sec1::ToEncodedPoint;
+use p256::pkcs8::{DecodePrivateKey, DecodePublicKey};
+use rsa::rand_core::OsRng;
+use sha2::Sha256;
+use spki::{AlgorithmIdentifierOwned, SubjectPublicKeyInfoOwned};
+use x509_cert::Certificate;
+
+const CONTENT: &[u8] = b"hello world\n";
+
+const P256_CERT_DER: &[u8] = include_bytes!("examples/p256-cert.der");
+
+/// A toy KEM based on ephemeral-static ECDH on P-256, standing in for e.g. ML-KEM: the
+/// ciphertext is the ephemeral public key and the shared secret the x-coordinate of the shared
+/// point.
+fn kem_algorithm_identifier() -> AlgorithmIdentifierOwned {
+    AlgorithmIdentifierOwned {
+        oid: const_oid::db::rfc5912::ID_EC_PUBLIC_KEY,
+        parameters: Some(const_oid::db::rfc5912::SECP_256_R_1.into()),
+    }
+}
+
+fn p256_shared_secret(secret_key: &p256::SecretKey, public_key: &p256::PublicKey) -> Vec<u8> {
+    (public_key.to_projective() * *secret_key.to_nonzero_scalar())
+        .to_affine()
+        .x()
+        .to_vec()
+}
+
+/// Ephemeral originator key of the toy KEM.
+struct P256Encapsulation(p256::SecretKey);
+
+impl OriginatorKeyEncapsulation for P256Encapsulation {
+    fn kem_algorithm_identifier(&self) -> AlgorithmIdentifierOwned {
+        kem_algorithm_identifier()
+    }
+
+    fn encapsulate(
+        &mut self,
+        recipient_public_key: &SubjectPublicKeyInfoOwned,
+    ) -> Result<(Vec<u8>, Vec<u8>), cms::builder::Error> {
+        let public_key = p256::PublicKey::from_public_key_der(&recipient_public_key.to_der()?)
+            .map_err(|_| cms::builder::Error::Builder("invalid public key".into()))?;
+        let kem_ct = self
+            .0
+            .public_key()
+            .to_encoded_point(false)
+            .as_bytes()
+            .to_vec();
+        Ok((kem_ct, p256_shared_secret(&self.0, &public_key)))
+    }
+}
+
+/// Static recipient key of the toy KEM.
+struct P256Decapsulation(p256::SecretKey);
+
+impl RecipientKeyEncapsulation for P256Decapsulation {
+    fn decapsulate(&self, kem: &AlgorithmIdentifierOwned, kem_ct: &[u8]) -> Result<Vec<u8>, Error> {
+        if *kem != kem_algorithm_identifier() {
+            return Err(Error::KeyDecapsulation);
+        }
+        let public_key =
+            p256::PublicKey::from_sec1_bytes(kem_ct).map_err(|_| Error::KeyDecapsulation)?;
+        Ok(p256_shared_secret(&self.0, &public_key))
+    }
+}
+
+fn recipient_certificate() -> Certificate {
+    Certificate::from_der(P256_CERT_DER).unwrap()
+}
+
+fn recipient() -> KemRecipient<P256Decapsulation> {
+    let secret_key =
+        p256::SecretKey::from_pkcs8_der(include_bytes!("examples/p256-priv.der")).unwrap();
+    KemRecipient::from_certificate(&recipient_certificate(), P256Decapsulation(secret_key)).unwrap()
+}
+
+fn ephemeral_key() -> P256Encapsulation {
+    P256Encapsulation(p256::SecretKey::random(&mut OsRng))
+}
+
+fn envelope(recipient_info_builder: impl RecipientInfoBuilder) -> EnvelopedData {
+    let content_info = EnvelopedDataBuilder::new(CONTENT, ContentEncryptionAlgorithm::Aes128Cbc)
+        .add_recipient_info(recipient_info_builder)
+        .unwrap()
+        .build_with_rng(&mut OsRng)
+        .unwrap();
+    ContentInfo::from_der(&content_info.to_der().unwrap())
+        .unwrap()
+        .decode_content()
+        .unwrap()
+}
+
+/// HKDF-SHA256 without salt, implemented independently of the crate.
+fn hkdf_sha256(ikm: &[u8], info: &[u8], length: usize) -> Vec<u8> {
+    let mut extract = <Hmac<Sha256> as Mac>::new_from_slice(&[0; 32]).unwrap();
+    extract.update(ikm);
+    let prk = extract.finalize().into_bytes();
+
+    let mut okm = Vec::new();
+    let mut block = Vec::new();
+    for counter in 1u8.. {
+        if okm.len() >= length {
+            break;
+        }
+        let mut expand = <Hmac<Sha256> as Mac>::new_from_slice(&prk).unwrap();
+        expand.update(&block);
+        expand.update(info);
+        expand.update(&[counter]);
+        block = expand.finalize().into_bytes().to_vec();
+        okm.extend_from_slice(&block);
+    }
+    okm.truncate(length);
+    okm
+}
+
+/// Test case 3 from [RFC 5869 Appendix A.3](https://datatracker.ietf.org/doc/html/rfc5869#appendix-A.3)
+#[test]
+fn hkdf_sha256_test_vector() {
+    assert_eq!(
+        hkdf_sha256(&[0x0b; 22], &[], 42),
+        hex!(
+            "8da4e775a563c18f715f802a063c5a31b8a11f5c5ee1879ec3454e5f3c738d2d"
+            "9d201395faa4b61a96c8"
+        )
+    );
+}
+
+#[test]
+fn build_and_decrypt_kem() {
+    for (key_derivation_algorithm, key_wrap_algorithm) in [
+        (
+            KemKeyDerivationAlgorithm::HkdfSha256,
+            KeyWrapAlgorithm::Aes128,
+        ),
+        (
+            KemKeyDerivationAlgorithm::HkdfSha384,
+            KeyWrapAlgorithm::Aes192,
+        ),
+        (
+            KemKeyDerivationAlgorithm::HkdfSha512,
+            KeyWrapAlgorithm::Aes256,
+        ),
+    ] {
+        let mut recipient_info_builder = KemRecipientInfoBuilder::from_certificate(
+            &recipient_certificate(),
+            ephemeral_key(),
+            key_derivation_algorithm,
+            key_wrap_algorithm,
+        )
+        .unwrap();
+        recipient_info_builder
+            .set_user_keying_material(OctetString::new(b"ukm".to_vec()).unwrap())
+            .unwrap();
+        let enveloped_data = envelope(recipient_info_builder);
+
+        let ori = match enveloped_data.recip_infos.0.get(0).unwrap() {
+            RecipientInfo::Ori(ori) => ori,
+            _ => panic!("expected OtherRecipientInfo"),
+        };
+        assert_eq!(ori.ori_type, ID_ORI_KEM);
+        let kemri = KemRecipientInfo::from_other_recipient_info(ori).unwrap();
+        assert_eq!(kemri.version, CmsVersion::V0);
+        assert_eq!(kemri.kem, kem_algorithm_identifier());
+        assert_eq!(kemri.kdf, key_derivation_algorithm.algorithm_identifier());
+        assert_eq!(kemri.wrap, key_wrap_algorithm.algorithm_identifier());
+        assert_eq!(usize::from(kemri.kek_length), key_wrap_algorithm.key_size());
+
+        let content = EnvelopedDataDecryptor::new(&enveloped_data)
+            .decrypt_kem(&recipient())
+            .unwrap();
+        assert_eq!(content, CONTENT);
+    }
+}
+
+/// Builds a `KEMRecipientInfo` deriving the key-encryption key with [`hkdf_sha256`].
+struct IndependentKemRecipientInfoBuilder;
+
+impl RecipientInfoBuilder for IndependentKemRecipientInfoBuilder {
+    fn build(
+        &mut self,
+        content_encryption_key: &[u8],
+    ) -> Result<RecipientInfo, cms::builder::Error> {
+        let certificate = recipient_certificate();
+        let (kem_ct, shared_secret) =
+            ephemeral_key().encapsulate(&certificate.tbs_certificate.subject_public_key_info)?;
+        let ukm = OctetString::new(b"ukm".to_vec())?;
+        let info = CmsOriForKemOtherInfo {
+            wrap: KeyWrapAlgorithm::Aes256.algorithm_identifier(),
+            kek_length: 32,
+            ukm: Some(ukm.clone()),
+        };
+        let key_encryption_key = hkdf_sha256(&shared_secret, &info.to_der()?, 32);
+        let encrypted_key = KeyWrapAlgorithm::Aes256
+            .wrap(&key_encryption_key, content_encryption_key)
+            .unwrap();
+
+        let kemri = KemRecipientInfo {
+            version: CmsVersion::V0,
+            rid: RecipientIdentifier::IssuerAndSerialNumber(IssuerAndSerialNumber {
+                issuer: certificate.tbs_certificate.issuer.clone(),
+                serial_number: certificate.tbs_certificate.serial_number.clone(),
+            }),
+            kem: kem_algorithm_identifier(),
+            kem_ct: OctetString::new(kem_ct)?,
+            kdf: AlgorithmIdentifierOwned {
+                oid: ID_ALG_HKDF_WITH_SHA256,
+                parameters: None,
+            },
+            kek_length: 32,
+            ukm: Some(ukm),
+            wrap: KeyWrapAlgorithm::Aes256.algorithm_identifier(),
+            encrypted_key: EncryptedKey::new(encrypted_key)?,
+        };
+        Ok(RecipientInfo::Ori(kemri.to_other_recipient_info()?))
+    }
+}
+
+#[test]
+fn decrypt_kem_independent_key_derivation() {
+    let enveloped_data = envelope(IndependentKemRecipientInfoBuilder);
+    let content = EnvelopedDataDecryptor::new(&enveloped_data)
+        .decrypt_kem(&recipient())
+        .unwrap();
+    assert_eq!(content, CONTENT);
+}
+
+#[test]
+fn decrypt_kem_wrong_key() {
+    let enveloped_data = envelope(IndependentKemRecipientInfoBuilder);
+
+    let other_key = p256::SecretKey::from_slice(&[0x42; 32]).unwrap();
+    let recipient =
+        KemRecipient::from_certificate(&recipient_certificate(), P256Decapsulation(other_key))
+            .unwrap();
+    let result = EnvelopedDataDecryptor::new(&enveloped_data).decrypt_kem(&recipient);
+    assert!(matches!(result, Err(Error::Decryption)));
+}
+
+#[test]
+fn decrypt_kem_recipient_not_found() {
+    let enveloped_data = envelope(IndependentKemRecipientInfoBuilder);
+
+    let other_key = p256::SecretKey::from_slice(&[0x42; 32]).unwrap();
+    let recipient = KemRecipient::new(
+        RecipientIdentifier::SubjectKeyIdentifier(
+            OctetString::new([0x01, 0x02, 0x03]).unwrap().into(),
+        ),
+        P256Decapsulation(other_key),
+    );
+    let decryptor = EnvelopedDataDecryptor::new(&enveloped_data);
+    assert_eq!(decryptor.find_kem_recipient_info(&recipient).unwrap(), None);
+    assert!(matches!(
+        decryptor.decrypt_kem(&recipient),
+        Err(Error::RecipientNotFound)
+    ));
+}
@@ -0,0 +1,417 @@
+#![cfg(any(feature = "builder", feature = "decrypt"))]
+
+use cms::enveloped_data::OriginatorPublicKey;
+use cms::key_agreement::KeyWrapAlgorithm;
+use der::asn1::BitString;
+use der::Encode;
+use hex_literal::hex;
+use p256::elliptic_curve::point::AffineCoordinates;
+use p256::elliptic_curve::sec1::ToEncodedPoint;
+use p256::pkcs8::DecodePublicKey;
+use spki::{AlgorithmIdentifierOwned, SubjectPublicKeyInfoOwned};
+
+/// Content encrypted in the example enveloped data.
+#[cfg(feature = "decrypt")]
+const CONTENT: &[u8] = b"hello world\n";
+
+/// Self-signed P-256 certificate of the recipient, generated using:
+/// $ openssl req -new -x509 -key p256-priv.pem -subj "/CN=cms test signer" -set_serial 0x0102030405
+#[cfg(feature = "decrypt")]
+const P256_CERT_DER: &[u8] = include_bytes!("examples/p256-cert.der");
+
+/// Generated using:
+/// $ openssl cms -encrypt -binary -aes-128-cbc -in content.txt -outform der p256-cert.pem
+#[cfg(feature = "decrypt")]
+const ENVELOPED_DATA_ECDH_DER: &[u8] = include_bytes!("examples/enveloped_data_ecdh_p256.der");
+
+/// Generated using:
+/// $ openssl cms -encrypt -binary -aes-256-cbc -keyid -in content.txt -outform der
+///   -recip p256-cert.pem -keyopt ecdh_kdf_md:sha256
+#[cfg(feature = "decrypt")]
+const ENVELOPED_DATA_ECDH_SHA256KDF_SKID_DER: &[u8] =
+    include_bytes!("examples/enveloped_data_ecdh_p256_sha256kdf_skid.der");
+
+/// ECDH on P-256, returning the x-coordinate of the shared point.
+fn p256_shared_secret(secret_key: &p256::SecretKey, public_key: &p256::PublicKey) -> Vec<u8> {
+    (public_key.to_projective() * *secret_key.to_nonzero_scalar())
+        .to_affine()
+        .x()
+        .to_vec()
+}
+
+fn p256_originator_public_key(secret_key: &p256::SecretKey) -> OriginatorPublicKey {
+    OriginatorPublicKey {
+        algorithm: AlgorithmIdentifierOwned {
+            oid: const_oid::db::rfc5912::ID_EC_PUBLIC_KEY,
+            parameters: None,
+        },
+        public_key: BitString::from_bytes(
+            secret_key.public_key().to_encoded_point(false).as_bytes(),
+        )
+        .unwrap(),
+    }
+}
+
+fn p256_public_key(spki: &SubjectPublicKeyInfoOwned) -> Option<p256::PublicKey> {
+    p256::PublicKey::from_public_key_der(&spki.to_der().ok()?).ok()
+}
+
+/// Ephemeral originator key for P-256.
+#[cfg(feature = "builder")]
+struct P256Originator(p256::SecretKey);
+
+#[cfg(feature = "builder")]
+impl cms::builder::OriginatorKeyAgreement for P256Originator {
+    fn originator_public_key(&self) -> Result<OriginatorPublicKey, cms::builder::Error> {
+        Ok(p256_originator_public_key(&self.0))
+    }
+
+    fn shared_secret(
+        &mut self,
+        recipient_public_key: &SubjectPublicKeyInfoOwned,
+    ) -> Result<Vec<u8>, cms::builder::Error> {
+        let public_key = p256_public_key(recipient_public_key)
+            .ok_or_else(|| cms::builder::Error::Builder("invalid public key".into()))?;
+        Ok(p256_shared_secret(&self.0, &public_key))
+    }
+}
+
+/// Static recipient key for P-256.
+#[cfg(feature = "decrypt")]
+struct P256Recipient(p256::SecretKey);
+
+#[cfg(feature = "decrypt")]
+impl cms::decrypt::RecipientKeyAgreement for P256Recipient {
+    fn shared_secret(
+        &self,
+        originator_public_key: &OriginatorPublicKey,
+    ) -> Result<Vec<u8>, cms::decrypt::Error> {
+        let spki = SubjectPublicKeyInfoOwned {
+            algorithm: AlgorithmIdentifierOwned {
+                oid: originator_public_key.algorithm.oid,
+                parameters: Some(const_oid::db::rfc5912::SECP_256_R_1.into()),
+            },
+            subject_public_key: originator_public_key.public_key.clone(),
+        };
+        let public_key = p256_public_key(&spki).ok_or(cms::decrypt::Error::KeyAgreement)?;
+        Ok(p256_shared_secret(&self.0, &public_key))
+    }
+}
+
+#[cfg(feature = "decrypt")]
+fn p256_recipient() -> cms::decrypt::KeyAgreeRecipient<P256Recipient> {
+    use der::Decode;
+    use p256::pkcs8::DecodePrivateKey;
+
+    let certificate = x509_cert::Certificate::from_der(P256_CERT_DER).unwrap();
+    let secret_key =
+        p256::SecretKey::from_pkcs8_der(include_bytes!("examples/p256-priv.der")).unwrap();
+    cms::decrypt::KeyAgreeRecipient::from_certificate(&certificate, P256Recipient(secret_key))
+        .unwrap()
+}
+
+#[cfg(feature = "decrypt")]
+fn enveloped_data(der: &[u8]) -> cms::enveloped_data::EnvelopedData {
+    use der::Decode;
+
+    cms::content_info::ContentInfo::from_der(der)
+        .unwrap()
+        .content
+        .decode_as()
+        .unwrap()
+}
+
+/// Key wrap algorithm, key-encryption key, key and wrapped key.
+type KeyWrapTestVector = (
+    KeyWrapAlgorithm,
+    &'static [u8],
+    &'static [u8],
+    &'static [u8],
+);
+
+/// Test vectors from [RFC 3394 § 4](https://datatracker.ietf.org/doc/html/rfc3394#section-4)
+#[test]
+fn aes_key_wrap() {
+    let vectors: &[KeyWrapTestVector] = &[
+        (
+            KeyWrapAlgorithm::Aes128,
+            &hex!("000102030405060708090A0B0C0D0E0F"),
+            &hex!("00112233445566778899AABBCCDDEEFF"),
+            &hex!("1FA68B0A8112B447AEF34BD8FB5A7B829D3E862371D2CFE5"),
+        ),
+        (
+            KeyWrapAlgorithm::Aes192,
+            &hex!("000102030405060708090A0B0C0D0E0F1011121314151617"),
+            &hex!("00112233445566778899AABBCCDDEEFF0001020304050607"),
+            &hex!("031D33264E15D33268F24EC260743EDCE1C6C7DDEE725A936BA814915C6762D2"),
+        ),
+        (
+            KeyWrapAlgorithm::Aes256,
+            &hex!("000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F"),
+            &hex!("00112233445566778899AABBCCDDEEFF000102030405060708090A0B0C0D0E0F"),
+            &hex!(
+                "28C9F404C4B810F4CBCCB35CFB87F8263F5786E2D80ED326CBC7F0E71A99F43BFB988B9B7A02DD21"
+            ),
+        ),
+    ];
+
+    for (algorithm, key_encryption_key, key, wrapped_key) in vectors {
+        assert_eq!(
+            algorithm.wrap(key_encryption_key, key).unwrap(),
+            *wrapped_key
+        );
+        assert_eq!(
+            algorithm.unwrap(key_encryption_key, wrapped_key).unwrap(),
+            *key
+        );
+
+        let mut corrupted = wrapped_key.to_vec();
+        corrupted[0] ^= 1;
+        assert!(algorithm.unwrap(key_encryption_key, &corrupted).is_none());
+    }
+}
+
+#[cfg(feature = "decrypt")]
+#[test]
+fn decrypt_ecdh_p256() {
+    use cms::decrypt::EnvelopedDataDecryptor;
+
+    for der in [
+        ENVELOPED_DATA_ECDH_DER,
+        ENVELOPED_DATA_ECDH_SHA256KDF_SKID_DER,
+    ] {
+        let enveloped_data = enveloped_data(der);
+        let content = EnvelopedDataDecryptor::new(&enveloped_data)
+            .decrypt_key_agreement(&p256_recipient())
+            .unwrap();
+        assert_eq!(content, CONTENT);
+    }
+}
+
+#[cfg(feature = "decrypt")]
+#[test]
+fn decrypt_ecdh_p256_wrong_key() {
+    use cms::decrypt::{EnvelopedDataDecryptor, Error, KeyAgreeRecipient};
+    use der::Decode;
+
+    let certificate = x509_cert::Certificate::from_der(P256_CERT_DER).unwrap();
+    let other_key = p256::SecretKey::from_slice(&[0x42; 32]).unwrap();
+    let recipient =
+        KeyAgreeRecipient::from_certificate(&certificate, P256Recipient(other_key)).unwrap();
+
+    let enveloped_data = enveloped_data(ENVELOPED_DATA_ECDH_DER);
+    let result = EnvelopedDataDecryptor::new(&enveloped_data).decrypt_key_agreement(&recipient);
+    assert!(matches!(result, Err(Error::Decryption)));
+}
+
+#[cfg(all(feature = "builder", feature = "decrypt"))]
+#[test]
+fn build_and_decrypt_ecdh_p256() {
+    use cms::builder::{
+        ContentEncryptionAlgorithm, EnvelopedDataBuilder, KeyAgreeRecipientInfoBuilder,
+    };
+    use cms::content_info::CmsVersion;
+    use cms::decrypt::EnvelopedDataDecryptor;
+    use cms::enveloped_data::RecipientInfo;
+    use cms::key_agreement::KeyAgreementAlgorithm;
+    use der::asn1::OctetString;
+    use der::Decode;
+    use rsa::rand_core::OsRng;
+
+    let certificate = x509_cert::Certificate::from_der(P256_CERT_DER).unwrap();
+    let mut recipient_info_builder = KeyAgreeRecipientInfoBuilder::from_certificate(
+        &certificate,
+        P256Originator(p256::SecretKey::random(&mut OsRng)),
+        KeyAgreementAlgorithm::StdDhSha256Kdf,
+        KeyWrapAlgorithm::Aes256,
+    )
+    .unwrap();
+    recipient_info_builder
+        .set_user_keying_material(OctetString::new(*b"user keying material").unwrap())
+        .unwrap();
+
+    let content_info = EnvelopedDataBuilder::new(CONTENT, ContentEncryptionAlgorithm::Aes256Cbc)
+        .add_recipient_info(recipient_info_builder)
+        .unwrap()
+        .build_with_rng(&mut OsRng)
+        .unwrap();
+
+    let enveloped_data = enveloped_data(&content_info.to_der().unwrap());
+    assert_eq!(enveloped_data.version, CmsVersion::V2);
+    match enveloped_data.recip_infos.0.get(0).unwrap() {
+        RecipientInfo::Kari(kari) => {
+            assert_eq!(kari.version, CmsVersion::V3);
+            assert_eq!(
+                kari.key_enc_alg.oid,
+                KeyAgreementAlgorithm::StdDhSha256Kdf.oid()
+            );
+            assert_eq!(
+                kari.key_enc_alg
+                    .parameters
+                    .as_ref()
+                    .unwrap()
+                    .decode_as::<AlgorithmIdentifierOwned>()
+                    .unwrap(),
+                KeyWrapAlgorithm::Aes256.algorithm_identifier()
+            );
+            assert!(kari.ukm.is_some());
+        }
+        _ => panic!("expected KeyAgreeRecipientInfo"),
+    }
+
+    let content = EnvelopedDataDecryptor::new(&enveloped_data)
+        .decrypt_key_agreement(&p256_recipient())
+        .unwrap();
+    assert_eq!(content, CONTENT);
+}
+
+/// Originator and recipient using a fixed shared secret.
+#[cfg(all(feature = "builder", feature = "decrypt"))]
+struct FixedSharedSecret(&'static [u8]);
+
+#[cfg(all(feature = "builder", feature = "decrypt"))]
+impl cms::builder::OriginatorKeyAgreement for FixedSharedSecret {
+    fn originator_public_key(&self) -> Result<OriginatorPublicKey, cms::builder::Error> {
+        Ok(p256_originator_public_key(
+            &p256::SecretKey::from_slice(&[0x42; 32]).unwrap(),
+        ))
+    }
+
+    fn shared_secret(
+        &mut self,
+        _recipient_public_key: &SubjectPublicKeyInfoOwned,
+    ) -> Result<Vec<u8>, cms::builder::Error> {
+        Ok(self.0.to_vec())
+    }
+}
+
+#[cfg(all(feature = "builder", feature = "decrypt"))]
+impl cms::decrypt::RecipientKeyAgreement for FixedSharedSecret {
+    fn shared_secret(
+        &self,
+        _originator_public_key: &OriginatorPublicKey,
+    ) -> Result<Vec<u8>, cms::decrypt::Error> {
+        Ok(self.0.to_vec())
+    }
+}
+
+/// Shared secret `Z` used for the ANSI X9.63 KDF test vectors.
+#[cfg(all(feature = "builder", feature = "decrypt"))]
+const X963_SHARED_SECRET: &[u8] =
+    &hex!("96C05619D56C328AB95FE84B18264B08725B85E33FD34F08B4B4D2D7C1A9F3E1");
+
+/// Key agreement algorithm, key wrap algorithm, whether the user keying material
+/// `"user keying material"` is used, and the key-encryption key derived from
+/// [`X963_SHARED_SECRET`] with `ECC-CMS-SharedInfo` as shared info.
+///
+/// Generated with the `X963KDF` of the Python `cryptography` package and checked against:
+/// $ openssl kdf -keylen <len> -kdfopt digest:<md> -kdfopt hexkey:<Z> -kdfopt hexinfo:<info> X963KDF
+#[cfg(all(feature = "builder", feature = "decrypt"))]
+const X963_KDF_TEST_VECTORS: &[(
+    cms::key_agreement::KeyAgreementAlgorithm,
+    KeyWrapAlgorithm,
+    bool,
+    &[u8],
+)] = {
+    use cms::key_agreement::KeyAgreementAlgorithm::*;
+
+    &[
+        (
+            StdDhSha1Kdf,
+            KeyWrapAlgorithm::Aes128,
+            false,
+            &hex!("069939800DCF11A38DED8E7DD1CEC151"),
+        ),
+        (
+            StdDhSha1Kdf,
+            KeyWrapAlgorithm::Aes256,
+            true,
+            &hex!("2D54FBB5465824A1CA2E9AB82927FD365181BE5BF8BA4460ACAC0C00B9267C73"),
+        ),
+        (
+            StdDhSha224Kdf,
+            KeyWrapAlgorithm::Aes192,
+            true,
+            &hex!("1E249D73E37C95A66AABEAA78A5E98AD6C13579145881D34"),
+        ),
+        (
+            StdDhSha256Kdf,
+            KeyWrapAlgorithm::Aes256,
+            true,
+            &hex!("392751BA7720804D694B35B897C1F353075CA6C689E497AE8573367415830ED6"),
+        ),
+        (
+            StdDhSha384Kdf,
+            KeyWrapAlgorithm::Aes128,
+            false,
+            &hex!("48F617D5193DF1F8C7E7025EAD7C9DC1"),
+        ),
+        (
+            StdDhSha512Kdf,
+            KeyWrapAlgorithm::Aes256,
+            true,
+            &hex!("94B8D04308357E1CDDADEAB69581F5EC4500993BFF0BA1879A3597E2AA5FA62B"),
+        ),
+    ]
+};
+
+#[cfg(all(feature = "builder", feature = "decrypt"))]
+#[test]
+fn ecdh_x963_kdf_test_vectors() {
+    use cms::builder::{
+        ContentEncryptionAlgorithm, EnvelopedDataBuilder, KeyAgreeRecipientInfoBuilder,
+    };
+    use cms::decrypt::{EnvelopedDataDecryptor, KeyAgreeRecipient};
+    use cms::enveloped_data::RecipientInfo;
+    use der::asn1::OctetString;
+    use der::Decode;
+    use rsa::rand_core::OsRng;
+
+    let certificate = x509_cert::Certificate::from_der(P256_CERT_DER).unwrap();
+
+    for &(key_agreement_algorithm, key_wrap_algorithm, use_ukm, key_encryption_key) in
+        X963_KDF_TEST_VECTORS
+    {
+        let mut recipient_info_builder = KeyAgreeRecipientInfoBuilder::from_certificate(
+            &certificate,
+            FixedSharedSecret(X963_SHARED_SECRET),
+            key_agreement_algorithm,
+            key_wrap_algorithm,
+        )
+        .unwrap();
+        if use_ukm {
+            recipient_info_builder
+                .set_user_keying_material(OctetString::new(*b"user keying material").unwrap())
+                .unwrap();
+        }
+
+        let content_info =
+            EnvelopedDataBuilder::new(CONTENT, ContentEncryptionAlgorithm::Aes128Cbc)
+                .add_recipient_info(recipient_info_builder)
+                .unwrap()
+                .build_with_rng(&mut OsRng)
+                .unwrap();
+        let enveloped_data = enveloped_data(&content_info.to_der().unwrap());
+
+        // The content-encryption key is wrapped with the expected key-encryption key
+        match enveloped_data.recip_infos.0.get(0).unwrap() {
+            RecipientInfo::Kari(kari) => {
+                let encrypted_key = &kari.recipient_enc_keys.first().unwrap().enc_key;
+                assert!(key_wrap_algorithm
+                    .unwrap(key_encryption_key, encrypted_key.as_bytes())
+                    .is_some());
+            }
+            _ => panic!("expected KeyAgreeRecipientInfo"),
+        }
+
+        let recipient = KeyAgreeRecipient::from_certificate(
+            &certificate,
+            FixedSharedSecret(X963_SHARED_SECRET),
+        )
+        .unwrap();
+        let content = EnvelopedDataDecryptor::new(&enveloped_data)
+            .decrypt_key_agreement(&recipient)
+            .unwrap();
+        assert_eq!(content, CONTENT);
+    }
+}
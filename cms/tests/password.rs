@@ -0,0 +1,105 @@
+#![cfg(feature = "decrypt")]
+
+use cms::content_info::ContentInfo;
+use cms::decrypt::{EnvelopedDataDecryptor, Error};
+use cms::enveloped_data::{EnvelopedData, RecipientInfo};
+use cms::password::{PasswordKeyEncryptionAlgorithm, ID_ALG_PWRI_KEK};
+use der::Decode;
+
+/// Content encrypted in the example enveloped data.
+const CONTENT: &[u8] = b"hello world\n";
+
+const PASSWORD: &[u8] = b"cms test password";
+
+/// Generated using:
+/// $ openssl cms -encrypt -binary -aes-256-cbc -pwri_password "cms test password"
+///   -in content.txt -outform der
+const ENVELOPED_DATA_PWRI_DER: &[u8] = include_bytes!("examples/enveloped_data_pwri_aes256.der");
+
+fn enveloped_data(der: &[u8]) -> EnvelopedData {
+    ContentInfo::from_der(der)
+        .unwrap()
+        .content
+        .decode_as()
+        .unwrap()
+}
+
+#[test]
+fn decrypt_pwri_kek() {
+    let enveloped_data = enveloped_data(ENVELOPED_DATA_PWRI_DER);
+    match enveloped_data.recip_infos.0.get(0).unwrap() {
+        RecipientInfo::Pwri(pwri) => {
+            assert_eq!(pwri.key_enc_alg.oid, ID_ALG_PWRI_KEK);
+            let (algorithm, iv) =
+                PasswordKeyEncryptionAlgorithm::from_algorithm_identifier(&pwri.key_enc_alg)
+                    .unwrap()
+                    .unwrap();
+            assert_eq!(algorithm, PasswordKeyEncryptionAlgorithm::PwriKekAes256Cbc);
+            assert_eq!(iv.len(), 16);
+        }
+        _ => panic!("expected PasswordRecipientInfo"),
+    }
+
+    let content = EnvelopedDataDecryptor::new(&enveloped_data)
+        .decrypt_password(PASSWORD)
+        .unwrap();
+    assert_eq!(content, CONTENT);
+}
+
+#[test]
+fn decrypt_wrong_password() {
+    let enveloped_data = enveloped_data(ENVELOPED_DATA_PWRI_DER);
+    let result = EnvelopedDataDecryptor::new(&enveloped_data).decrypt_password(b"wrong password");
+    assert!(matches!(result, Err(Error::Decryption)));
+}
+
+#[test]
+fn decrypt_without_password_recipient() {
+    let enveloped_data = enveloped_data(include_bytes!("examples/enveloped_data_rsa_aes128.der"));
+    let result = EnvelopedDataDecryptor::new(&enveloped_data).decrypt_password(PASSWORD);
+    assert!(matches!(result, Err(Error::RecipientNotFound)));
+}
+
+#[cfg(feature = "builder")]
+#[test]
+fn decrypt_built_enveloped_data() {
+    use cms::builder::{
+        ContentEncryptionAlgorithm, EnvelopedDataBuilder, PasswordRecipientInfoBuilder,
+    };
+    use cms::content_info::CmsVersion;
+    use cms::key_agreement::KeyWrapAlgorithm;
+    use der::Encode;
+    use pkcs5::pbes2::Pbkdf2Params;
+    use rsa::rand_core::OsRng;
+
+    let salt = [0x5a; 16];
+    for key_encryption_algorithm in [
+        PasswordKeyEncryptionAlgorithm::PwriKekAes128Cbc,
+        PasswordKeyEncryptionAlgorithm::PwriKekAes256Cbc,
+        PasswordKeyEncryptionAlgorithm::KeyWrap(KeyWrapAlgorithm::Aes192),
+    ] {
+        let mut rng = OsRng;
+        let recipient_info_builder = PasswordRecipientInfoBuilder::new(
+            PASSWORD,
+            Pbkdf2Params::hmac_with_sha256(1000, &salt).unwrap(),
+            key_encryption_algorithm,
+            &mut rng,
+        )
+        .unwrap();
+        let content_info =
+            EnvelopedDataBuilder::new(CONTENT, ContentEncryptionAlgorithm::Aes256Cbc)
+                .add_recipient_info(recipient_info_builder)
+                .unwrap()
+                .build_with_rng(&mut OsRng)
+                .unwrap();
+
+        let enveloped_data = enveloped_data(&content_info.to_der().unwrap());
+        assert_eq!(enveloped_data.version, CmsVersion::V3);
+        let decryptor = EnvelopedDataDecryptor::new(&enveloped_data);
+        assert_eq!(decryptor.decrypt_password(PASSWORD).unwrap(), CONTENT);
+        assert!(matches!(
+            decryptor.decrypt_password(b"wrong password"),
+            Err(Error::Decryption)
+        ));
+    }
+}
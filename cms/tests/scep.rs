@@ -0,0 +1,264 @@
+//! SCEP tests
+
+use cms::scep::{
+    cert_rep_certificates, create_cert_rep_message_data, create_pki_message_content,
+    pkcs_pki_envelope, FailInfo, MessageType, PkiStatus, ScepAttributes, ID_MESSAGE_TYPE,
+    ID_PKI_STATUS,
+};
+use cms::signed_data::{SignatureValue, SignedData, SignerIdentifier, SignerInfo};
+use cms::{cert::IssuerAndSerialNumber, content_info::CmsVersion};
+use der::asn1::{OctetString, PrintableString, SetOfVec};
+use der::{Any, Decode, Encode, ErrorKind, Tag};
+use spki::AlgorithmIdentifierOwned;
+use x509_cert::attr::Attribute;
+use x509_cert::Certificate;
+
+const RSA_2048_CERT_DER_EXAMPLE: &[u8] = include_bytes!("examples/rsa2048-cert.der");
+
+fn request_attributes() -> ScepAttributes {
+    ScepAttributes::pkcs_req(
+        PrintableString::new("2F3C88114C283E9A6CD57BB8266CE313DB0BEE0DAD3E8A9E").unwrap(),
+        OctetString::new([0x11; 16]).unwrap(),
+    )
+}
+
+fn signer_info(signed_attrs: Vec<Attribute>) -> SignerInfo {
+    let certificate = Certificate::from_der(RSA_2048_CERT_DER_EXAMPLE).unwrap();
+    SignerInfo {
+        version: CmsVersion::V1,
+        sid: SignerIdentifier::IssuerAndSerialNumber(IssuerAndSerialNumber {
+            issuer: certificate.tbs_certificate.issuer.clone(),
+            serial_number: certificate.tbs_certificate.serial_number.clone(),
+        }),
+        digest_alg: AlgorithmIdentifierOwned {
+            oid: const_oid::db::rfc5912::ID_SHA_256,
+            parameters: None,
+        },
+        signed_attrs: Some(SetOfVec::try_from(signed_attrs).unwrap()),
+        signature_algorithm: AlgorithmIdentifierOwned {
+            oid: const_oid::db::rfc5912::SHA_256_WITH_RSA_ENCRYPTION,
+            parameters: None,
+        },
+        signature: SignatureValue::new(vec![0; 256]).unwrap(),
+        unsigned_attrs: None,
+    }
+}
+
+#[test]
+fn pkcs_req_attributes_roundtrip() {
+    let attributes = request_attributes();
+    let encoded = attributes.to_attributes().unwrap();
+    assert_eq!(encoded.len(), 3);
+
+    let message_type = encoded
+        .iter()
+        .find(|attribute| attribute.oid == ID_MESSAGE_TYPE)
+        .unwrap();
+    assert_eq!(
+        message_type.values.get(0).unwrap().to_der().unwrap(),
+        [0x13, 0x02, b'1', b'9']
+    );
+
+    let signer_info = SignerInfo::from_der(&signer_info(encoded).to_der().unwrap()).unwrap();
+    assert_eq!(
+        ScepAttributes::from_signer_info(&signer_info).unwrap(),
+        attributes
+    );
+}
+
+#[test]
+fn cert_rep_attributes_roundtrip() {
+    let request = request_attributes();
+    let mut attributes = ScepAttributes::cert_rep(
+        &request,
+        PkiStatus::Failure,
+        OctetString::new([0x22; 16]).unwrap(),
+    );
+    attributes.fail_info = Some(FailInfo::BadRequest);
+    attributes.fail_info_text = Some("unsupported key type".into());
+    assert_eq!(attributes.transaction_id, request.transaction_id);
+    assert_eq!(attributes.recipient_nonce, Some(request.sender_nonce));
+
+    let signer_info = signer_info(attributes.to_attributes().unwrap());
+    let parsed = ScepAttributes::from_signer_info(&signer_info).unwrap();
+    assert_eq!(parsed, attributes);
+    assert_eq!(parsed.message_type, MessageType::CertRep);
+}
+
+#[test]
+fn attributes_missing_or_invalid() {
+    let mut encoded = request_attributes().to_attributes().unwrap();
+    encoded.retain(|attribute| attribute.oid != ID_MESSAGE_TYPE);
+    let err = ScepAttributes::from_signer_info(&signer_info(encoded.clone())).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Value { tag: Tag::Set });
+
+    let mut values = SetOfVec::new();
+    values
+        .insert(Any::encode_from(&PrintableString::new("1").unwrap()).unwrap())
+        .unwrap();
+    encoded.push(Attribute {
+        oid: ID_MESSAGE_TYPE,
+        values,
+    });
+    let err = ScepAttributes::from_signer_info(&signer_info(encoded.clone())).unwrap_err();
+    assert_eq!(
+        err.kind(),
+        ErrorKind::Value {
+            tag: Tag::PrintableString
+        }
+    );
+
+    // duplicate attributes are rejected
+    for pki_status in [PkiStatus::Success, PkiStatus::Pending] {
+        let mut values = SetOfVec::new();
+        values
+            .insert(Any::encode_from(&pki_status).unwrap())
+            .unwrap();
+        encoded.push(Attribute {
+            oid: ID_PKI_STATUS,
+            values,
+        });
+    }
+    assert!(ScepAttributes::from_signer_info(&signer_info(encoded)).is_err());
+}
+
+#[test]
+fn cert_rep_message_data_roundtrip() {
+    let certificate = Certificate::from_der(RSA_2048_CERT_DER_EXAMPLE).unwrap();
+    let message_data = create_cert_rep_message_data([certificate.clone()]).unwrap();
+    assert_eq!(cert_rep_certificates(&message_data).unwrap(), [certificate]);
+}
+
+#[test]
+fn pkcs_pki_envelope_without_content() {
+    let signed_data = SignedData::certs_only([]).unwrap();
+    assert_eq!(pkcs_pki_envelope(&signed_data).unwrap(), None);
+}
+
+#[test]
+fn pki_message_content_requires_enveloped_data() {
+    let content_info = cms::content_info::ContentInfo::try_from(
+        Certificate::from_der(RSA_2048_CERT_DER_EXAMPLE).unwrap(),
+    )
+    .unwrap();
+    let err = create_pki_message_content(&content_info).unwrap_err();
+    assert_eq!(
+        err.kind(),
+        ErrorKind::OidUnknown {
+            oid: const_oid::db::rfc5911::ID_SIGNED_DATA
+        }
+    );
+}
+
+#[cfg(all(feature = "builder", feature = "decrypt"))]
+mod pki_message {
+    use super::*;
+    use cms::builder::{
+        ContentEncryptionAlgorithm, EnvelopedDataBuilder, KeyEncryptionInfo,
+        KeyTransRecipientInfoBuilder, SignedDataBuilder, SignerInfoBuilder,
+    };
+    use cms::content_info::ContentInfo;
+    use cms::decrypt::{EnvelopedDataDecryptor, KeyTransRecipient};
+    use rsa::pkcs1::DecodeRsaPrivateKey;
+    use rsa::pkcs1v15::SigningKey;
+    use rsa::rand_core::OsRng;
+    use rsa::RsaPrivateKey;
+    use sha2::Sha256;
+    use x509_cert::request::CertReq;
+
+    const RSA_2048_PRIV_DER_EXAMPLE: &[u8] = include_bytes!("examples/rsa2048-priv.der");
+    const RSA_2048_CSR_DER_EXAMPLE: &[u8] = include_bytes!("examples/rsa2048-csr.der");
+
+    /// Build a `pkiMessage` enveloping `message_data` for and signed by the example RSA key.
+    fn build_pki_message(message_data: &[u8], attributes: &ScepAttributes) -> ContentInfo {
+        let certificate = Certificate::from_der(RSA_2048_CERT_DER_EXAMPLE).unwrap();
+        let mut rng = OsRng;
+        let recipient_info_builder = KeyTransRecipientInfoBuilder::from_certificate(
+            &certificate,
+            KeyEncryptionInfo::Rsa,
+            &mut rng,
+        )
+        .unwrap();
+        let pkcs_pki_envelope =
+            EnvelopedDataBuilder::new(message_data, ContentEncryptionAlgorithm::Aes128Cbc)
+                .add_recipient_info(recipient_info_builder)
+                .unwrap()
+                .build_with_rng(&mut OsRng)
+                .unwrap();
+        let content = create_pki_message_content(&pkcs_pki_envelope).unwrap();
+
+        let private_key = RsaPrivateKey::from_pkcs1_der(RSA_2048_PRIV_DER_EXAMPLE).unwrap();
+        let signer = SigningKey::<Sha256>::new(private_key);
+        let mut signer_info_builder = SignerInfoBuilder::new(
+            &signer,
+            signer_info(Vec::new()).sid,
+            AlgorithmIdentifierOwned {
+                oid: const_oid::db::rfc5912::ID_SHA_256,
+                parameters: None,
+            },
+            &content,
+            None,
+        )
+        .unwrap();
+        for attribute in attributes.to_attributes().unwrap() {
+            signer_info_builder.add_signed_attribute(attribute).unwrap();
+        }
+
+        SignedDataBuilder::new(&content)
+            .add_certificate(cms::cert::CertificateChoices::Certificate(certificate))
+            .unwrap()
+            .add_signer_info::<SigningKey<Sha256>, rsa::pkcs1v15::Signature>(signer_info_builder)
+            .unwrap()
+            .build()
+            .unwrap()
+    }
+
+    /// Parse a `pkiMessage` built by `build_pki_message` and decrypt its `messageData`.
+    fn parse_pki_message(pki_message: &ContentInfo) -> (ScepAttributes, Vec<u8>) {
+        let pki_message = ContentInfo::from_der(&pki_message.to_der().unwrap()).unwrap();
+        let signed_data = pki_message.decode_content::<SignedData>().unwrap();
+        let attributes =
+            ScepAttributes::from_signer_info(signed_data.signer_infos.0.get(0).unwrap()).unwrap();
+
+        let enveloped_data = pkcs_pki_envelope(&signed_data).unwrap().unwrap();
+        let certificate = Certificate::from_der(RSA_2048_CERT_DER_EXAMPLE).unwrap();
+        let private_key = RsaPrivateKey::from_pkcs1_der(RSA_2048_PRIV_DER_EXAMPLE).unwrap();
+        let recipient = KeyTransRecipient::from_certificate(&certificate, private_key).unwrap();
+        let message_data = EnvelopedDataDecryptor::new(&enveloped_data)
+            .decrypt_with_rng(&recipient, &mut OsRng)
+            .unwrap();
+        (attributes, message_data)
+    }
+
+    #[test]
+    fn pkcs_req_and_cert_rep() {
+        let request_attributes = request_attributes();
+        let pkcs_req = build_pki_message(RSA_2048_CSR_DER_EXAMPLE, &request_attributes);
+
+        let (attributes, message_data) = parse_pki_message(&pkcs_req);
+        assert_eq!(attributes, request_attributes);
+        assert_eq!(attributes.message_type, MessageType::PkcsReq);
+        let cert_req = CertReq::from_der(&message_data).unwrap();
+        assert_eq!(cert_req.to_der().unwrap(), RSA_2048_CSR_DER_EXAMPLE);
+
+        let certificate = Certificate::from_der(RSA_2048_CERT_DER_EXAMPLE).unwrap();
+        let response_attributes = ScepAttributes::cert_rep(
+            &attributes,
+            PkiStatus::Success,
+            OctetString::new([0x22; 16]).unwrap(),
+        );
+        let cert_rep = build_pki_message(
+            &create_cert_rep_message_data([certificate.clone()]).unwrap(),
+            &response_attributes,
+        );
+
+        let (attributes, message_data) = parse_pki_message(&cert_rep);
+        assert_eq!(attributes, response_attributes);
+        assert_eq!(attributes.pki_status, Some(PkiStatus::Success));
+        assert_eq!(
+            attributes.recipient_nonce,
+            Some(request_attributes.sender_nonce)
+        );
+        assert_eq!(cert_rep_certificates(&message_data).unwrap(), [certificate]);
+    }
+}
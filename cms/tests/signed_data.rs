@@ -1,8 +1,14 @@
 //! SignedData tests
 
-use cms::content_info::ContentInfo;
-use cms::signed_data::{SignedData, SignerInfos};
+use cms::cert::{CertificateChoices, IssuerAndSerialNumber, OtherCertificateFormat};
+use cms::content_info::{CmsVersion, ContentInfo};
+use cms::revocation::{OtherRevocationInfoFormat, RevocationInfoChoice, RevocationInfoChoices};
+use cms::signed_data::{SignatureValue, SignedData, SignerIdentifier, SignerInfo, SignerInfos};
+use der::asn1::SetOfVec;
 use der::{AnyRef, Decode, DecodePem, Encode, ErrorKind, Tag};
+use spki::AlgorithmIdentifierOwned;
+use x509_cert::crl::CertificateList;
+use x509_cert::serial_number::SerialNumber;
 use x509_cert::Certificate;
 
 #[test]
@@ -104,6 +110,93 @@ fn misencoded_signer_infos_tests() {
     //assert_eq!(e.position(), Some(Length::ZERO));
 }
 
+fn signer_info(serial_number: &[u8], signature: &[u8]) -> SignerInfo {
+    let certificate = Certificate::from_der(include_bytes!("examples/p256-cert.der")).unwrap();
+    SignerInfo {
+        version: CmsVersion::V1,
+        sid: SignerIdentifier::IssuerAndSerialNumber(IssuerAndSerialNumber {
+            issuer: certificate.tbs_certificate.issuer,
+            serial_number: SerialNumber::new(serial_number).unwrap(),
+        }),
+        digest_alg: AlgorithmIdentifierOwned {
+            oid: const_oid::db::rfc5912::ID_SHA_256,
+            parameters: None,
+        },
+        signed_attrs: None,
+        signature_algorithm: AlgorithmIdentifierOwned {
+            oid: const_oid::db::rfc5912::ECDSA_WITH_SHA_256,
+            parameters: None,
+        },
+        signature: SignatureValue::new(signature).unwrap(),
+        unsigned_attrs: None,
+    }
+}
+
+/// Check that the signer infos are sorted by their encodings, as required for a DER SET OF.
+fn assert_der_ordering(signer_infos: &SignerInfos) {
+    let encodings = signer_infos
+        .0
+        .iter()
+        .map(|signer_info| signer_info.to_der().unwrap())
+        .collect::<Vec<_>>();
+    assert!(encodings.windows(2).all(|pair| pair[0] < pair[1]));
+}
+
+#[test]
+fn signer_infos_der_ordering() {
+    // Serial numbers of the same length, whose encodings are ordered by their octets
+    let signer_infos = vec![
+        signer_info(&[0x02, 0x01], &[0x01]),
+        signer_info(&[0x01, 0x7f], &[0x01]),
+        signer_info(&[0x01, 0x80], &[0x01]),
+    ];
+    let sorted = SignerInfos(SetOfVec::try_from(signer_infos.clone()).unwrap());
+    assert_eq!(
+        sorted.0.as_slice(),
+        [
+            signer_infos[1].clone(),
+            signer_infos[2].clone(),
+            signer_infos[0].clone()
+        ]
+    );
+    assert_der_ordering(&sorted);
+
+    // Signer infos only differing in their signature, without signed attributes
+    let signer_infos = vec![
+        signer_info(&[0x01], &[0x03]),
+        signer_info(&[0x01], &[0x01]),
+        signer_info(&[0x01], &[0x02]),
+    ];
+    let sorted = SignerInfos(SetOfVec::try_from(signer_infos.clone()).unwrap());
+    assert_eq!(sorted.0.get(0), Some(&signer_infos[1]));
+    assert_der_ordering(&sorted);
+
+    // Duplicates are rejected
+    let result = SetOfVec::try_from(vec![
+        signer_info(&[0x01], &[0x01]),
+        signer_info(&[0x01], &[0x01]),
+    ]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn reencode_multiple_signer_infos() {
+    let signer_infos = SignerInfos(
+        SetOfVec::try_from(vec![
+            signer_info(&[0x01, 0x80], &[0x01; 70]),
+            signer_info(&[0x01, 0x7f], &[0x02; 71]),
+            signer_info(&[0x01], &[0x03, 0x04]),
+        ])
+        .unwrap(),
+    );
+    assert_der_ordering(&signer_infos);
+
+    let der = signer_infos.to_der().unwrap();
+    let decoded = SignerInfos::from_der(&der).unwrap();
+    assert_eq!(decoded, signer_infos);
+    assert_eq!(decoded.to_der().unwrap(), der);
+}
+
 #[test]
 fn misencoded_signed_data_tests() {}
 
@@ -145,3 +238,126 @@ fn certs_to_p7b() {
     let p7b_buf2 = p7b_ee.to_der().unwrap();
     assert_eq!(p7b_buf, p7b_buf2.as_slice());
 }
+
+#[test]
+fn certs_only_signed_data() {
+    let ee_cert =
+        Certificate::from_der(include_bytes!("examples/ValidCertificatePathTest1EE.crt")).unwrap();
+    let ca_cert = Certificate::from_der(include_bytes!("examples/GoodCACert.crt")).unwrap();
+
+    let sd = SignedData::certs_only([ee_cert.clone(), ca_cert.clone()]).unwrap();
+    assert!(sd.signer_infos.0.is_empty());
+    assert!(sd.encap_content_info.econtent.is_none());
+    let p7b_buf2 = ContentInfo::try_from(sd).unwrap().to_der().unwrap();
+    assert_eq!(include_bytes!("examples/pkits.p7b"), p7b_buf2.as_slice());
+
+    let ci = ContentInfo::from_der(include_bytes!("examples/pkits.p7b")).unwrap();
+    let sd = ci.content.decode_as::<SignedData>().unwrap();
+    let certs: Vec<&Certificate> = sd.certificates().collect();
+    assert_eq!(certs.len(), 2);
+    assert!(certs.contains(&&ee_cert));
+    assert!(certs.contains(&&ca_cert));
+
+    let sd = SignedData::certs_only([]).unwrap();
+    assert_eq!(sd.certificates().count(), 0);
+}
+
+#[cfg(feature = "pem")]
+#[test]
+fn p7b_pem() {
+    use der::EncodePem;
+
+    // test with PEM p7b created as follows:
+    // openssl pkcs7 -inform DER -in pkits.p7b -outform PEM -out pkits.p7b.pem
+    let p7b_pem = include_str!("examples/pkits.p7b.pem");
+    let ci = ContentInfo::from_pem(p7b_pem).unwrap();
+    assert_eq!(
+        ci,
+        ContentInfo::from_der(include_bytes!("examples/pkits.p7b")).unwrap()
+    );
+    assert_eq!(
+        ci.to_pem(der::pem::LineEnding::LF).unwrap().as_str(),
+        p7b_pem
+    );
+}
+
+#[test]
+fn find_signer_certificates() {
+    let p256_cert = Certificate::from_der(include_bytes!("examples/p256-cert.der")).unwrap();
+
+    // Issuer and serial number
+    let ci = ContentInfo::from_der(include_bytes!("examples/signed_data_p256.der")).unwrap();
+    let sd = ci.content.decode_as::<SignedData>().unwrap();
+    let sid = &sd.signer_infos.0.get(0).unwrap().sid;
+    assert!(matches!(sid, SignerIdentifier::IssuerAndSerialNumber(_)));
+    assert!(sid.matches(&p256_cert));
+    assert_eq!(sd.find_certificate(sid), Some(&p256_cert));
+
+    // Subject key identifier
+    let ci = ContentInfo::from_der(include_bytes!(
+        "examples/signed_data_p256_detached_skid.der"
+    ))
+    .unwrap();
+    let sd = ci.content.decode_as::<SignedData>().unwrap();
+    let sid = &sd.signer_infos.0.get(0).unwrap().sid;
+    assert!(matches!(sid, SignerIdentifier::SubjectKeyIdentifier(_)));
+    assert!(sid.matches(&p256_cert));
+    assert_eq!(sd.find_certificate(sid), Some(&p256_cert));
+
+    // Neither matches a certificate of another subject
+    let ee_cert =
+        Certificate::from_der(include_bytes!("examples/ValidCertificatePathTest1EE.crt")).unwrap();
+    assert!(!sid.matches(&ee_cert));
+    let sd = SignedData::certs_only([ee_cert.clone()]).unwrap();
+    assert_eq!(sd.find_certificate(sid), None);
+    let sid = SignerIdentifier::IssuerAndSerialNumber(IssuerAndSerialNumber {
+        issuer: ee_cert.tbs_certificate.issuer.clone(),
+        serial_number: p256_cert.tbs_certificate.serial_number.clone(),
+    });
+    assert_eq!(sd.find_certificate(&sid), None);
+}
+
+#[test]
+fn certificates_and_crls() {
+    let ee_cert =
+        Certificate::from_der(include_bytes!("examples/ValidCertificatePathTest1EE.crt")).unwrap();
+    // GoodCACRL.crl from PKITS
+    let crl = CertificateList::from_der(include_bytes!("examples/GoodCACRL.crl")).unwrap();
+    let other_cert = OtherCertificateFormat {
+        other_cert_format: const_oid::db::rfc5911::ID_DATA,
+        other_cert: der::Any::new(Tag::OctetString, [1, 2, 3]).unwrap(),
+    };
+    let other_revocation_info = OtherRevocationInfoFormat {
+        other_format: AlgorithmIdentifierOwned {
+            oid: const_oid::db::rfc5911::ID_DATA,
+            parameters: None,
+        },
+        other: der::Any::new(Tag::OctetString, [4, 5, 6]).unwrap(),
+    };
+
+    let mut sd = SignedData::certs_only([ee_cert.clone()]).unwrap();
+    assert_eq!(sd.other_certificates().count(), 0);
+    assert_eq!(sd.crls().count(), 0);
+    assert_eq!(sd.other_revocation_info().count(), 0);
+
+    let mut certificates = sd.certificates.take().unwrap();
+    certificates
+        .0
+        .insert(CertificateChoices::Other(other_cert.clone()))
+        .unwrap();
+    sd.certificates = Some(certificates);
+    let mut crls = SetOfVec::new();
+    crls.insert(RevocationInfoChoice::Crl(crl.clone())).unwrap();
+    crls.insert(RevocationInfoChoice::Other(other_revocation_info.clone()))
+        .unwrap();
+    sd.crls = Some(RevocationInfoChoices(crls));
+
+    let sd = SignedData::from_der(&sd.to_der().unwrap()).unwrap();
+    assert_eq!(sd.certificates().collect::<Vec<_>>(), [&ee_cert]);
+    assert_eq!(sd.other_certificates().collect::<Vec<_>>(), [&other_cert]);
+    assert_eq!(sd.crls().collect::<Vec<_>>(), [&crl]);
+    assert_eq!(
+        sd.other_revocation_info().collect::<Vec<_>>(),
+        [&other_revocation_info]
+    );
+}
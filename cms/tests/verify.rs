@@ -0,0 +1,479 @@
+#![cfg(feature = "verify")]
+
+use cms::content_info::ContentInfo;
+use cms::signed_data::SignedData;
+use cms::verify::{Error, SignedDataVerifier};
+use der::{Decode, Encode};
+use p256::ecdsa::{DerSignature, VerifyingKey};
+use p256::pkcs8::DecodePublicKey;
+use x509_cert::Certificate;
+
+/// Content signed by the example signed data.
+const CONTENT: &[u8] = b"hello world\n";
+
+/// Self-signed P-256 certificate of the signer, generated using:
+/// $ openssl req -new -x509 -key p256-priv.pem -subj "/CN=cms test signer" -set_serial 0x0102030405
+const P256_CERT_DER: &[u8] = include_bytes!("examples/p256-cert.der");
+
+/// Generated using:
+/// $ openssl cms -sign -binary -nodetach -md sha256 -in content.txt -signer p256-cert.pem
+///   -inkey p256-priv.pem -outform der
+const SIGNED_DATA_DER: &[u8] = include_bytes!("examples/signed_data_p256.der");
+
+/// Generated using:
+/// $ openssl cms -sign -binary -md sha256 -in content.txt -signer p256-cert.pem
+///   -inkey p256-priv.pem -keyid -outform der
+const SIGNED_DATA_DETACHED_SKID_DER: &[u8] =
+    include_bytes!("examples/signed_data_p256_detached_skid.der");
+
+/// Generated using:
+/// $ openssl cms -sign -binary -nodetach -noattr -md sha256 -in content.txt
+///   -signer p256-cert.pem -inkey p256-priv.pem -outform der
+const SIGNED_DATA_NOATTR_DER: &[u8] = include_bytes!("examples/signed_data_p256_noattr.der");
+
+fn signed_data(der: &[u8]) -> SignedData {
+    ContentInfo::from_der(der)
+        .unwrap()
+        .content
+        .decode_as()
+        .unwrap()
+}
+
+fn verifying_key(certificate: &Certificate) -> spki::Result<VerifyingKey> {
+    VerifyingKey::from_public_key_der(
+        &certificate
+            .tbs_certificate
+            .subject_public_key_info
+            .to_der()?,
+    )
+}
+
+#[test]
+fn verify_attached() {
+    let signed_data = signed_data(SIGNED_DATA_DER);
+    let verifications =
+        SignedDataVerifier::new(&signed_data).verify::<_, DerSignature, _>(verifying_key);
+
+    assert_eq!(verifications.len(), 1);
+    assert!(verifications[0].is_valid());
+    assert_eq!(
+        verifications[0].certificate.unwrap().to_der().unwrap(),
+        P256_CERT_DER
+    );
+}
+
+#[test]
+fn verify_detached_subject_key_identifier() {
+    let signed_data = signed_data(SIGNED_DATA_DETACHED_SKID_DER);
+    let mut verifier = SignedDataVerifier::new(&signed_data);
+
+    let verifications = verifier.verify::<_, DerSignature, _>(verifying_key);
+    assert!(matches!(
+        verifications[0].result,
+        Err(Error::ContentMissing)
+    ));
+    assert!(verifications[0].certificate.is_some());
+
+    verifier.set_detached_content(b"goodbye world\n");
+    let verifications = verifier.verify::<_, DerSignature, _>(verifying_key);
+    assert!(matches!(
+        verifications[0].result,
+        Err(Error::MessageDigestMismatch)
+    ));
+
+    verifier.set_detached_content(CONTENT);
+    verifier
+        .verify_all::<_, DerSignature, _>(verifying_key)
+        .unwrap();
+}
+
+#[test]
+fn verify_detached_digest() {
+    use sha2::{Digest, Sha256};
+
+    let signed_data = signed_data(SIGNED_DATA_DETACHED_SKID_DER);
+    let mut verifier = SignedDataVerifier::new(&signed_data);
+
+    verifier.set_detached_digest(const_oid::db::rfc5912::ID_SHA_384, &[0; 48]);
+    let verifications = verifier.verify::<_, DerSignature, _>(verifying_key);
+    assert!(matches!(
+        verifications[0].result,
+        Err(Error::ContentMissing)
+    ));
+
+    verifier.set_detached_digest(
+        const_oid::db::rfc5912::ID_SHA_256,
+        &Sha256::digest(b"goodbye world\n"),
+    );
+    let verifications = verifier.verify::<_, DerSignature, _>(verifying_key);
+    assert!(matches!(
+        verifications[0].result,
+        Err(Error::MessageDigestMismatch)
+    ));
+
+    verifier.set_detached_digest(const_oid::db::rfc5912::ID_SHA_256, &Sha256::digest(CONTENT));
+    verifier
+        .verify_all::<_, DerSignature, _>(verifying_key)
+        .unwrap();
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn verify_detached_reader() {
+    let signed_data = signed_data(SIGNED_DATA_DETACHED_SKID_DER);
+    SignedDataVerifier::new(&signed_data)
+        .read_detached_content(CONTENT)
+        .unwrap()
+        .verify_all::<_, DerSignature, _>(verifying_key)
+        .unwrap();
+
+    let result = SignedDataVerifier::new(&signed_data)
+        .read_detached_content(&b"goodbye world\n"[..])
+        .unwrap()
+        .verify_all::<_, DerSignature, _>(verifying_key);
+    assert!(matches!(result, Err(Error::MessageDigestMismatch)));
+}
+
+#[test]
+fn verify_detached_digest_without_signed_attributes() {
+    use sha2::{Digest, Sha256};
+
+    // Without signed attributes, the signature covers the content itself.
+    let mut signed_data = signed_data(SIGNED_DATA_NOATTR_DER);
+    signed_data.encap_content_info.econtent = None;
+    let result = SignedDataVerifier::new(&signed_data)
+        .set_detached_digest(const_oid::db::rfc5912::ID_SHA_256, &Sha256::digest(CONTENT))
+        .verify_all::<_, DerSignature, _>(verifying_key);
+    assert!(matches!(result, Err(Error::ContentMissing)));
+}
+
+#[test]
+fn verify_without_signed_attributes() {
+    let signed_data = signed_data(SIGNED_DATA_NOATTR_DER);
+    assert!(signed_data
+        .signer_infos
+        .0
+        .get(0)
+        .unwrap()
+        .signed_attrs
+        .is_none());
+
+    SignedDataVerifier::new(&signed_data)
+        .verify_all::<_, DerSignature, _>(verifying_key)
+        .unwrap();
+}
+
+#[test]
+fn verify_external_certificate() {
+    let mut signed_data = signed_data(SIGNED_DATA_DER);
+    signed_data.certificates = None;
+    let mut verifier = SignedDataVerifier::new(&signed_data);
+
+    let verifications = verifier.verify::<_, DerSignature, _>(verifying_key);
+    assert!(matches!(
+        verifications[0].result,
+        Err(Error::CertificateNotFound)
+    ));
+    assert!(verifications[0].certificate.is_none());
+
+    let certificate = Certificate::from_der(P256_CERT_DER).unwrap();
+    verifier.add_certificate(&certificate);
+    verifier
+        .verify_all::<_, DerSignature, _>(verifying_key)
+        .unwrap();
+}
+
+#[test]
+fn verify_invalid_signature() {
+    let mut signed_data = signed_data(SIGNED_DATA_DER);
+    let mut signer_infos = signed_data.signer_infos.0.into_vec();
+    signer_infos[0].signed_attrs = without_signing_time(&signer_infos[0]);
+    signed_data.signer_infos.0 = signer_infos.try_into().unwrap();
+
+    let verifications =
+        SignedDataVerifier::new(&signed_data).verify::<_, DerSignature, _>(verifying_key);
+    assert!(matches!(verifications[0].result, Err(Error::Signature(_))));
+}
+
+/// Remove the signing time attribute, which invalidates the signature.
+fn without_signing_time(
+    signer_info: &cms::signed_data::SignerInfo,
+) -> Option<cms::signed_data::SignedAttributes> {
+    let attributes = signer_info
+        .signed_attrs
+        .as_ref()
+        .unwrap()
+        .iter()
+        .filter(|attr| attr.oid != const_oid::db::rfc5911::ID_SIGNING_TIME)
+        .cloned()
+        .collect::<Vec<_>>();
+    Some(attributes.try_into().unwrap())
+}
+
+#[cfg(feature = "builder")]
+#[test]
+fn verify_built_signed_data() {
+    use cms::builder::{create_data_content, SignedDataBuilder, SignerInfoBuilder};
+    use cms::cert::{CertificateChoices, IssuerAndSerialNumber};
+    use cms::signed_data::SignerIdentifier;
+    use p256::pkcs8::DecodePrivateKey;
+    use spki::AlgorithmIdentifierOwned;
+
+    let secret_key =
+        p256::SecretKey::from_pkcs8_der(include_bytes!("examples/p256-priv.der")).unwrap();
+    let signer = ecdsa::SigningKey::<p256::NistP256>::from(secret_key);
+    let certificate = Certificate::from_der(P256_CERT_DER).unwrap();
+
+    let content = create_data_content(Some(CONTENT)).unwrap();
+    let sid = SignerIdentifier::IssuerAndSerialNumber(IssuerAndSerialNumber {
+        issuer: certificate.tbs_certificate.issuer.clone(),
+        serial_number: certificate.tbs_certificate.serial_number.clone(),
+    });
+    let digest_algorithm = AlgorithmIdentifierOwned {
+        oid: const_oid::db::rfc5912::ID_SHA_256,
+        parameters: None,
+    };
+    let signer_info_builder =
+        SignerInfoBuilder::new(&signer, sid, digest_algorithm, &content, None).unwrap();
+
+    let content_info = SignedDataBuilder::new(&content)
+        .add_certificate(CertificateChoices::Certificate(certificate))
+        .unwrap()
+        .add_signer_info::<_, DerSignature>(signer_info_builder)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    let signed_data = signed_data(&content_info.to_der().unwrap());
+    SignedDataVerifier::new(&signed_data)
+        .verify_all::<_, DerSignature, _>(verifying_key)
+        .unwrap();
+}
+
+#[cfg(feature = "builder")]
+#[test]
+fn verify_built_detached_signed_data() {
+    use cms::builder::{create_data_content, SignedDataBuilder, SignerInfoBuilder};
+    use cms::cert::IssuerAndSerialNumber;
+    use cms::signed_data::SignerIdentifier;
+    use p256::pkcs8::DecodePrivateKey;
+    use spki::AlgorithmIdentifierOwned;
+
+    let secret_key =
+        p256::SecretKey::from_pkcs8_der(include_bytes!("examples/p256-priv.der")).unwrap();
+    let signer = ecdsa::SigningKey::<p256::NistP256>::from(secret_key);
+    let certificate = Certificate::from_der(P256_CERT_DER).unwrap();
+
+    let content = create_data_content(Some(CONTENT)).unwrap();
+    let sid = SignerIdentifier::IssuerAndSerialNumber(IssuerAndSerialNumber {
+        issuer: certificate.tbs_certificate.issuer.clone(),
+        serial_number: certificate.tbs_certificate.serial_number.clone(),
+    });
+    let digest_algorithm = AlgorithmIdentifierOwned {
+        oid: const_oid::db::rfc5912::ID_SHA_256,
+        parameters: None,
+    };
+    let signer_info_builder =
+        SignerInfoBuilder::new(&signer, sid, digest_algorithm, &content, None).unwrap();
+
+    let content_info = SignedDataBuilder::new(&content)
+        .set_detached(true)
+        .unwrap()
+        .add_signer_info::<_, DerSignature>(signer_info_builder)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    let signed_data = signed_data(&content_info.to_der().unwrap());
+    assert!(signed_data.encap_content_info.econtent.is_none());
+
+    let mut verifier = SignedDataVerifier::new(&signed_data);
+    verifier.add_certificate(&certificate);
+    let result = verifier.verify_all::<_, DerSignature, _>(verifying_key);
+    assert!(matches!(result, Err(Error::ContentMissing)));
+
+    verifier
+        .set_detached_content(CONTENT)
+        .verify_all::<_, DerSignature, _>(verifying_key)
+        .unwrap();
+}
+
+#[test]
+fn verify_required_signing_certificate_missing() {
+    let signed_data = signed_data(SIGNED_DATA_DER);
+    let result = SignedDataVerifier::new(&signed_data)
+        .set_require_signing_certificate(true)
+        .verify_all::<_, DerSignature, _>(verifying_key);
+    assert!(matches!(result, Err(Error::SigningCertificateMissing)));
+}
+
+#[cfg(feature = "builder")]
+#[test]
+fn verify_built_signing_certificate_v2() {
+    use cms::attr::SigningCertificateV2;
+    use cms::builder::{
+        create_data_content, create_signing_certificate_v2_attribute, SignedDataBuilder,
+        SignerInfoBuilder,
+    };
+    use cms::cert::IssuerAndSerialNumber;
+    use cms::signed_data::SignerIdentifier;
+    use p256::pkcs8::DecodePrivateKey;
+    use sha2::{Digest, Sha256};
+    use spki::AlgorithmIdentifierOwned;
+    use x509_cert::attr::Attribute;
+
+    let secret_key =
+        p256::SecretKey::from_pkcs8_der(include_bytes!("examples/p256-priv.der")).unwrap();
+    let signer = ecdsa::SigningKey::<p256::NistP256>::from(secret_key);
+    let certificate = Certificate::from_der(P256_CERT_DER).unwrap();
+    let content = create_data_content(Some(CONTENT)).unwrap();
+    let sha256 = AlgorithmIdentifierOwned {
+        oid: const_oid::db::rfc5912::ID_SHA_256,
+        parameters: None,
+    };
+    let sha384 = AlgorithmIdentifierOwned {
+        oid: const_oid::db::rfc5912::ID_SHA_384,
+        parameters: None,
+    };
+
+    let sign = |signing_certificate: Attribute| {
+        let sid = SignerIdentifier::IssuerAndSerialNumber(IssuerAndSerialNumber {
+            issuer: certificate.tbs_certificate.issuer.clone(),
+            serial_number: certificate.tbs_certificate.serial_number.clone(),
+        });
+        let mut signer_info_builder =
+            SignerInfoBuilder::new(&signer, sid, sha256.clone(), &content, None).unwrap();
+        signer_info_builder
+            .add_signed_attribute(signing_certificate)
+            .unwrap();
+        let content_info = SignedDataBuilder::new(&content)
+            .add_signer_info::<_, DerSignature>(signer_info_builder)
+            .unwrap()
+            .build()
+            .unwrap();
+        signed_data(&content_info.to_der().unwrap())
+    };
+
+    // SHA-256 is the default hash algorithm and omitted
+    let attribute = create_signing_certificate_v2_attribute(&certificate, &sha256).unwrap();
+    let signing_certificate = SigningCertificateV2::from_attribute(&attribute).unwrap();
+    assert_eq!(signing_certificate.certs.len(), 1);
+    let cert_id = &signing_certificate.certs[0];
+    assert_eq!(cert_id.hash_algorithm, None);
+    assert_eq!(cert_id.effective_hash_algorithm(), sha256);
+    assert_eq!(
+        cert_id.cert_hash.as_bytes(),
+        Sha256::digest(P256_CERT_DER).as_slice()
+    );
+    assert!(cert_id
+        .issuer_serial
+        .as_ref()
+        .unwrap()
+        .matches(&certificate));
+
+    let signed_data = sign(attribute);
+    SignedDataVerifier::new(&signed_data)
+        .add_certificate(&certificate)
+        .set_require_signing_certificate(true)
+        .verify_all::<_, DerSignature, _>(verifying_key)
+        .unwrap();
+
+    let attribute = create_signing_certificate_v2_attribute(&certificate, &sha384).unwrap();
+    let signing_certificate = SigningCertificateV2::from_attribute(&attribute).unwrap();
+    assert_eq!(signing_certificate.certs[0].hash_algorithm, Some(sha384));
+    let signed_data = sign(attribute);
+    SignedDataVerifier::new(&signed_data)
+        .add_certificate(&certificate)
+        .set_require_signing_certificate(true)
+        .verify_all::<_, DerSignature, _>(verifying_key)
+        .unwrap();
+
+    // The attribute is checked even if not required
+    let other_certificate =
+        Certificate::from_der(include_bytes!("examples/rsa2048-cert.der")).unwrap();
+    let attribute = create_signing_certificate_v2_attribute(&other_certificate, &sha256).unwrap();
+    let signed_data = sign(attribute);
+    let result = SignedDataVerifier::new(&signed_data)
+        .add_certificate(&certificate)
+        .verify_all::<_, DerSignature, _>(verifying_key);
+    assert!(matches!(result, Err(Error::SigningCertificateMismatch)));
+}
+
+#[test]
+fn verify_without_countersignatures() {
+    let signed_data = signed_data(SIGNED_DATA_DER);
+    let signer_info = signed_data.signer_infos.0.get(0).unwrap();
+    let verifications = SignedDataVerifier::new(&signed_data)
+        .verify_countersignatures::<_, DerSignature, _>(signer_info, verifying_key)
+        .unwrap();
+    assert!(verifications.is_empty());
+}
+
+#[cfg(feature = "builder")]
+#[test]
+fn verify_built_countersignature() {
+    use cms::builder::{add_countersignature, CountersignatureBuilder};
+    use cms::cert::IssuerAndSerialNumber;
+    use cms::signed_data::SignerIdentifier;
+    use cms::verify::countersignatures;
+    use p256::pkcs8::DecodePrivateKey;
+    use spki::AlgorithmIdentifierOwned;
+    use x509_cert::builder::Builder;
+
+    let secret_key =
+        p256::SecretKey::from_pkcs8_der(include_bytes!("examples/p256-priv.der")).unwrap();
+    let signer = ecdsa::SigningKey::<p256::NistP256>::from(secret_key);
+    let certificate = Certificate::from_der(P256_CERT_DER).unwrap();
+    let sid = SignerIdentifier::IssuerAndSerialNumber(IssuerAndSerialNumber {
+        issuer: certificate.tbs_certificate.issuer.clone(),
+        serial_number: certificate.tbs_certificate.serial_number.clone(),
+    });
+    // countersign the signer info twice
+    let mut signed_data = signed_data(SIGNED_DATA_DER);
+    let mut signer_infos = signed_data.signer_infos.0.into_vec();
+    for digest_algorithm in [
+        const_oid::db::rfc5912::ID_SHA_256,
+        const_oid::db::rfc5912::ID_SHA_384,
+    ] {
+        let digest_algorithm = AlgorithmIdentifierOwned {
+            oid: digest_algorithm,
+            parameters: None,
+        };
+        let countersignature =
+            CountersignatureBuilder::new(&signer, sid.clone(), digest_algorithm, &signer_infos[0])
+                .unwrap()
+                .build::<DerSignature>()
+                .unwrap();
+        add_countersignature(&mut signer_infos[0], &countersignature).unwrap();
+    }
+    signed_data.signer_infos.0 = signer_infos.try_into().unwrap();
+
+    let signed_data = SignedData::from_der(&signed_data.to_der().unwrap()).unwrap();
+    let signer_info = signed_data.signer_infos.0.get(0).unwrap();
+    let unsigned_attributes = signer_info.unsigned_attrs.as_ref().unwrap();
+    assert_eq!(unsigned_attributes.len(), 1);
+    assert_eq!(countersignatures(signer_info).unwrap().len(), 2);
+
+    let verifier = SignedDataVerifier::new(&signed_data);
+    verifier
+        .verify_all::<_, DerSignature, _>(verifying_key)
+        .unwrap();
+    let verifications = verifier
+        .verify_countersignatures::<_, DerSignature, _>(signer_info, verifying_key)
+        .unwrap();
+    assert_eq!(verifications.len(), 2);
+    assert!(verifications
+        .iter()
+        .all(|verification| verification.is_valid()));
+
+    // the countersignatures don't match another signature
+    let other_signed_data = self::signed_data(SIGNED_DATA_NOATTR_DER);
+    let mut other_signer_info = other_signed_data.signer_infos.0.get(0).unwrap().clone();
+    other_signer_info.unsigned_attrs = signer_info.unsigned_attrs.clone();
+    let verifications = verifier
+        .verify_countersignatures::<_, DerSignature, _>(&other_signer_info, verifying_key)
+        .unwrap();
+    assert!(verifications
+        .iter()
+        .all(|verification| matches!(verification.result, Err(Error::MessageDigestMismatch))));
+}
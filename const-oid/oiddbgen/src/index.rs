@@ -0,0 +1,139 @@
+use std::collections::BTreeSet;
+
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+
+/// Marker for an empty slot (must match `const_oid::db`).
+const EMPTY: u16 = u16::MAX;
+
+/// Average number of keys per bucket.
+const BUCKET_SIZE: usize = 4;
+
+/// Hash function used by the index (must match `const_oid::db::hash`).
+pub fn hash(bytes: &[u8], seed: u32, fold_case: bool) -> u32 {
+    let mut h = 0x811c_9dc5 ^ seed;
+
+    for &b in bytes {
+        h ^= if fold_case { b.to_ascii_lowercase() } else { b } as u32;
+        h = h.wrapping_mul(0x0100_0193);
+    }
+
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x85eb_ca6b);
+    h ^= h >> 13;
+    h = h.wrapping_mul(0xc2b2_ae35);
+    h ^ (h >> 16)
+}
+
+/// A hash-and-displace perfect hash index mapping keys to database positions.
+///
+/// If several entries share a key, the first one wins. When `fold_case` is
+/// set, keys are compared ignoring ASCII case.
+#[derive(Clone, Debug)]
+pub struct Index {
+    seeds: Vec<u16>,
+    slots: Vec<u16>,
+    fold_case: bool,
+}
+
+impl Index {
+    pub fn new<'a>(keys: impl IntoIterator<Item = &'a [u8]>, fold_case: bool) -> Self {
+        let mut seen = BTreeSet::new();
+        let mut keys = keys
+            .into_iter()
+            .enumerate()
+            .filter(|(_, key)| match fold_case {
+                true => seen.insert(key.to_ascii_lowercase()),
+                false => seen.insert(key.to_vec()),
+            })
+            .map(|(i, key)| (u16::try_from(i).expect("too many entries"), key))
+            .collect::<Vec<_>>();
+
+        let mut buckets = vec![Vec::new(); keys.len().div_ceil(BUCKET_SIZE)];
+        for (i, key) in keys.drain(..) {
+            let bucket = hash(key, 0, fold_case) as usize % buckets.len();
+            buckets[bucket].push((i, key));
+        }
+
+        let mut index = Self {
+            seeds: vec![0; buckets.len()],
+            slots: vec![EMPTY; seen.len()],
+            fold_case,
+        };
+
+        // Place the largest buckets first while the table is still sparse.
+        let mut order = (0..buckets.len()).collect::<Vec<_>>();
+        order.sort_by_key(|&b| std::cmp::Reverse(buckets[b].len()));
+
+        for b in order {
+            let bucket = &buckets[b];
+            if bucket.is_empty() {
+                continue;
+            }
+
+            let (seed, slots) = (1..=u16::MAX)
+                .find_map(|seed| index.place(bucket, seed).map(|slots| (seed, slots)))
+                .expect("unable to build perfect hash");
+
+            index.seeds[b] = seed;
+            for (slot, (i, _)) in slots.into_iter().zip(bucket) {
+                index.slots[slot] = *i;
+            }
+        }
+
+        index
+    }
+
+    /// Find free and distinct slots for every key in the bucket.
+    fn place(&self, bucket: &[(u16, &[u8])], seed: u16) -> Option<Vec<usize>> {
+        let mut slots = Vec::with_capacity(bucket.len());
+
+        for (_, key) in bucket {
+            let slot = hash(key, seed.into(), self.fold_case) as usize % self.slots.len();
+            if self.slots[slot] != EMPTY || slots.contains(&slot) {
+                return None;
+            }
+
+            slots.push(slot);
+        }
+
+        Some(slots)
+    }
+
+    pub fn tokens(&self) -> TokenStream {
+        let seeds = self.seeds.iter().map(|&seed| Literal::u16_unsuffixed(seed));
+        let slots = self.slots.iter().map(|&slot| Literal::u16_unsuffixed(slot));
+        let fold_case = self.fold_case;
+
+        quote! {
+            crate::db::Index {
+                seeds: &[#(#seeds),*],
+                slots: &[#(#slots),*],
+                fold_case: #fold_case,
+            }
+        }
+    }
+}
+
+/// BER-encode an OID in dotted notation.
+pub fn encode_oid(obid: &str) -> Vec<u8> {
+    let arcs = obid
+        .split('.')
+        .map(|arc| arc.parse::<u64>().expect("invalid OID arc"))
+        .collect::<Vec<_>>();
+
+    let mut bytes = Vec::new();
+    let first = arcs[0] * 40 + arcs[1];
+
+    for arc in std::iter::once(first).chain(arcs[2..].iter().copied()) {
+        let mut shift = (64 - arc.leading_zeros()).div_ceil(7) * 7;
+        while shift > 7 {
+            shift -= 7;
+            bytes.push(((arc >> shift) & 0x7f) as u8 | 0x80);
+        }
+
+        bytes.push((arc & 0x7f) as u8);
+    }
+
+    bytes
+}
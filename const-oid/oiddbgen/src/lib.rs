@@ -1,4 +1,5 @@
 mod asn1;
+mod index;
 mod ldap;
 mod node;
 mod root;
@@ -62,8 +62,12 @@ impl Node {
         &self.symb
     }
 
+    pub fn obid(&self) -> String {
+        self.obid.replace(' ', "") // Fix a typo.
+    }
+
     pub fn definition(&self) -> TokenStream {
-        let obid = self.obid.replace(' ', ""); // Fix a typo.
+        let obid = self.obid();
         let symb = &self.symb;
 
         quote! {
@@ -23,11 +23,16 @@ impl Root {
 
     pub fn module(&self) -> TokenStream {
         let mut mods = TokenStream::default();
-        let mut recs = TokenStream::default();
+        let mut tables = TokenStream::default();
 
         for (spec, s) in &self.0 {
+            let feature = format!("db-{}", spec);
+
             mods.extend(s.module(spec));
-            recs.extend(s.records(quote! { &#spec }));
+            tables.extend(quote! {
+                #[cfg(feature = #feature)]
+                #spec::DB.table(),
+            });
         }
 
         quote! {
@@ -36,7 +41,7 @@ impl Root {
             #mods
 
             pub const DB: super::Database<'static> = super::Database(&[
-                #recs
+                #tables
             ]);
         }
     }
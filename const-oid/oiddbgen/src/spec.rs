@@ -3,7 +3,10 @@ use std::collections::BTreeSet;
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 
-use crate::node::Node;
+use crate::{
+    index::{encode_oid, Index},
+    node::Node,
+};
 
 #[derive(Clone, Debug, PartialEq, Eq, Default)]
 pub struct Spec(BTreeSet<Node>);
@@ -13,18 +16,38 @@ impl Spec {
         self.0.insert(value)
     }
 
-    pub fn records(&self, path: TokenStream) -> TokenStream {
+    pub fn records(&self) -> TokenStream {
         let mut stream = TokenStream::default();
 
         for n in &self.0 {
             let name = n.name();
             let symb = n.symbol();
-            stream.extend(quote! { (#path::#symb, #name), })
+            stream.extend(quote! { (&#symb, #name), })
         }
 
         stream
     }
 
+    pub fn table(&self) -> TokenStream {
+        let records = self.records();
+        let names = self.0.iter().map(|n| n.name().as_bytes());
+        let by_name = Index::new(names, true).tokens();
+        let oids = self
+            .0
+            .iter()
+            .map(|n| encode_oid(&n.obid()))
+            .collect::<Vec<_>>();
+        let by_oid = Index::new(oids.iter().map(Vec::as_slice), false).tokens();
+
+        quote! {
+            pub const DB: crate::db::Database<'static> = crate::db::Database(&[crate::db::Table {
+                entries: &[#records],
+                by_name: #by_name,
+                by_oid: #by_oid,
+            }]);
+        }
+    }
+
     pub fn module(&self, spec: &Ident) -> TokenStream {
         let mut defs = TokenStream::default();
 
@@ -32,9 +55,15 @@ impl Spec {
             defs.extend(n.definition())
         }
 
+        let feature = format!("db-{}", spec);
+        let table = self.table();
+
         quote! {
+            #[cfg(feature = #feature)]
             pub mod #spec {
                 #defs
+
+                #table
             }
         }
     }
@@ -1,7 +1,6 @@
 //! Arcs are integer values which exist within an OID's hierarchy.
 
 use crate::{Error, Result};
-use core::mem;
 
 #[cfg(doc)]
 use crate::ObjectIdentifier;
@@ -10,13 +9,10 @@ use crate::ObjectIdentifier;
 ///
 /// X.660 does not define a maximum size of an arc.
 ///
-/// The current representation is `u32`, which has been selected as being
-/// sufficient to cover the current PKCS/PKIX use cases this library has been
-/// used in conjunction with.
-///
-/// Future versions may potentially make it larger if a sufficiently important
-/// use case is discovered.
-pub type Arc = u32;
+/// The current representation is `u128`, which is large enough to hold the
+/// UUID-derived arcs under `2.25` described in ITU X.667, in addition to the
+/// arcs used by PKCS/PKIX.
+pub type Arc = u128;
 
 /// Maximum value of the first arc in an OID.
 pub(crate) const ARC_MAX_FIRST: Arc = 2;
@@ -24,12 +20,6 @@ pub(crate) const ARC_MAX_FIRST: Arc = 2;
 /// Maximum value of the second arc in an OID.
 pub(crate) const ARC_MAX_SECOND: Arc = 39;
 
-/// Maximum number of bytes supported in an arc.
-const ARC_MAX_BYTES: usize = mem::size_of::<Arc>();
-
-/// Maximum value of the last byte in an arc.
-const ARC_MAX_LAST_OCTET: u8 = 0b11110000; // Max bytes of leading 1-bits
-
 /// [`Iterator`] over [`Arc`] values (a.k.a. nodes) in an [`ObjectIdentifier`].
 ///
 /// This iterates over all arcs in an OID, including the root.
@@ -75,14 +65,14 @@ impl<'a> Arcs<'a> {
                     let len = checked_add!(offset, arc_bytes);
 
                     match self.bytes.get(len).cloned() {
-                        // The arithmetic below includes advance checks
-                        // against `ARC_MAX_BYTES` and `ARC_MAX_LAST_OCTET`
-                        // which ensure the operations will not overflow.
+                        // The arithmetic below includes an advance check that
+                        // the upper 7 bits of `result` are unset, which
+                        // ensures the shift will not overflow.
                         #[allow(clippy::integer_arithmetic)]
                         Some(byte) => {
                             arc_bytes = checked_add!(arc_bytes, 1);
 
-                            if (arc_bytes > ARC_MAX_BYTES) && (byte & ARC_MAX_LAST_OCTET != 0) {
+                            if result >> (Arc::BITS - 7) != 0 {
                                 return Err(Error::ArcTooBig);
                             }
 
@@ -116,6 +106,53 @@ impl<'a> Iterator for Arcs<'a> {
     }
 }
 
+/// Ensure the arcs in the given BER/DER-encoded OID are well-formed
+/// (const-friendly).
+///
+/// Performs the same checks as iterating over [`Arcs`] with `try_next`.
+pub(crate) const fn validate(bytes: &[u8]) -> Result<()> {
+    if bytes.is_empty() {
+        return Err(Error::Empty);
+    }
+
+    // TODO(tarcieri): use `?` when stable in `const fn`
+    if let Err(err) = RootArcs::from_octet(bytes[0]) {
+        return Err(err);
+    }
+
+    let mut result: Arc = 0;
+    let mut arc_bytes = 0usize;
+    let mut i = 1;
+
+    while i < bytes.len() {
+        let byte = bytes[i];
+
+        if result >> (Arc::BITS - 7) != 0 {
+            return Err(Error::ArcTooBig);
+        }
+
+        // The check above ensures the shift will not overflow, and `i` and
+        // `arc_bytes` are bounded by the length of `bytes`.
+        #[allow(clippy::integer_arithmetic)]
+        {
+            result = result << 7 | (byte & 0b1111111) as Arc;
+            arc_bytes += 1;
+            i += 1;
+        }
+
+        if byte & 0b10000000 == 0 {
+            result = 0;
+            arc_bytes = 0;
+        }
+    }
+
+    if arc_bytes == 0 {
+        Ok(())
+    } else {
+        Err(Error::Base128)
+    }
+}
+
 /// Byte containing the first and second arcs of an OID.
 ///
 /// This is represented this way in order to reduce the overall size of the
@@ -155,15 +192,22 @@ impl RootArcs {
     }
 }
 
-impl TryFrom<u8> for RootArcs {
-    type Error = Error;
-
+impl RootArcs {
+    /// Parse [`RootArcs`] from the first octet of an OID (const-friendly).
     // Ensured not to overflow by constructor invariants
     #[allow(clippy::integer_arithmetic)]
-    fn try_from(octet: u8) -> Result<Self> {
+    const fn from_octet(octet: u8) -> Result<Self> {
         let first = octet as Arc / (ARC_MAX_SECOND + 1);
         let second = octet as Arc % (ARC_MAX_SECOND + 1);
-        let result = Self::new(first, second)?;
+        Self::new(first, second)
+    }
+}
+
+impl TryFrom<u8> for RootArcs {
+    type Error = Error;
+
+    fn try_from(octet: u8) -> Result<Self> {
+        let result = Self::from_octet(octet)?;
         debug_assert_eq!(octet, result.0);
         Ok(result)
     }
@@ -6,6 +6,11 @@
 //! `oiddbgen` (a subcrate in the source code) to generate the values from
 //! the relevant standard.
 //!
+//! The `db` crate feature enables the named OIDs from every standard. To
+//! reduce the size of the database (e.g. on embedded targets), the named OIDs
+//! from individual standards can instead be enabled using the corresponding
+//! `db-<standard>` features, e.g. `db-rfc5280` and `db-rfc5912`.
+//!
 //! [RFC 5280]: https://datatracker.ietf.org/doc/html/rfc5280
 //! [Object Identifier Descriptors]: https://www.iana.org/assignments/ldap-parameters/ldap-parameters.xhtml#ldap-parameters-3
 
@@ -35,9 +40,117 @@ const fn eq_case(lhs: &[u8], rhs: &[u8]) -> bool {
     true
 }
 
+/// Hash function used by [`Index`], applied to the first `len` bytes.
+///
+/// ASCII case is folded if `fold_case` is set, so that names can be looked
+/// up case-insensitively.
+const fn hash(bytes: &[u8], len: usize, seed: u32, fold_case: bool) -> u32 {
+    let mut h = 0x811c_9dc5 ^ seed;
+
+    let mut i = 0usize;
+    while i < len {
+        let b = if fold_case {
+            bytes[i].to_ascii_lowercase()
+        } else {
+            bytes[i]
+        };
+
+        h ^= b as u32;
+        h = h.wrapping_mul(0x0100_0193);
+        i += 1;
+    }
+
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x85eb_ca6b);
+    h ^= h >> 13;
+    h = h.wrapping_mul(0xc2b2_ae35);
+    h ^ (h >> 16)
+}
+
+/// A perfect hash index into the entries of a [`Database`], generated by
+/// `oiddbgen` using the "hash and displace" method.
+///
+/// Each key is first hashed into a bucket, and then hashed again using that
+/// bucket's seed to find the slot containing the position of its entry.
+#[derive(Copy, Clone)]
+struct Index<'a> {
+    seeds: &'a [u16],
+    slots: &'a [u16],
+    fold_case: bool,
+}
+
+impl<'a> Index<'a> {
+    /// Marker for slots which don't contain an entry.
+    const EMPTY: u16 = u16::MAX;
+
+    /// Get the position of the only entry which may match the key stored
+    /// in the first `len` bytes of `key`.
+    const fn get(&self, key: &[u8], len: usize) -> Option<usize> {
+        if self.seeds.is_empty() || self.slots.is_empty() {
+            return None;
+        }
+
+        let bucket = hash(key, len, 0, self.fold_case) as usize % self.seeds.len();
+        let seed = self.seeds[bucket] as u32;
+        let slot = hash(key, len, seed, self.fold_case) as usize % self.slots.len();
+
+        match self.slots[slot] {
+            Self::EMPTY => None,
+            pos => Some(pos as usize),
+        }
+    }
+}
+
+/// Finds a named oid in the default [`DB`] by its associated OID.
+pub const fn by_oid(oid: &ObjectIdentifier) -> Option<&'static str> {
+    DB.by_oid(oid)
+}
+
+/// Finds a named oid in the default [`DB`] by its associated name.
+pub const fn by_name(name: &str) -> Option<&'static ObjectIdentifier> {
+    DB.by_name(name)
+}
+
+/// Iterate over the named oids in the default [`DB`] which are in the subtree
+/// rooted at `prefix`.
+pub fn subtree(prefix: &ObjectIdentifier) -> Subtree<'static> {
+    DB.subtree(prefix)
+}
+
+/// Named oids defined by a single standard, which are indexed separately so
+/// that each standard can be enabled independently.
+#[derive(Copy, Clone)]
+struct Table<'a> {
+    entries: &'a [(&'a ObjectIdentifier, &'a str)],
+    by_name: Index<'a>,
+    by_oid: Index<'a>,
+}
+
+impl<'a> Table<'a> {
+    const fn by_oid(&self, oid: &ObjectIdentifier) -> Option<&'a str> {
+        match self.by_oid.get(&oid.buffer.bytes, oid.buffer.len()) {
+            Some(i) if self.entries[i].0.buffer.eq(&oid.buffer) => Some(self.entries[i].1),
+            _ => None,
+        }
+    }
+
+    const fn by_name(&self, name: &str) -> Option<&'a ObjectIdentifier> {
+        match self.by_name.get(name.as_bytes(), name.len()) {
+            Some(i) if eq_case(self.entries[i].1.as_bytes(), name.as_bytes()) => {
+                Some(self.entries[i].0)
+            }
+            _ => None,
+        }
+    }
+}
+
 /// A query interface for OIDs/Names.
+///
+/// The default [`DB`] contains the named oids from every standard enabled
+/// using the `db-*` crate features, while each standard's module contains a
+/// `DB` with only its own named oids.
 #[derive(Copy, Clone)]
-pub struct Database<'a>(&'a [(&'a ObjectIdentifier, &'a str)]);
+pub struct Database<'a>(&'a [Table<'a>]);
 
 impl<'a> Database<'a> {
     /// Looks up a name for an OID.
@@ -52,14 +165,15 @@ impl<'a> Database<'a> {
     }
 
     /// Finds a named oid by its associated OID.
+    ///
+    /// If several names are associated with the OID, the first one is
+    /// returned. See [`Database::find_names_for_oid`] to get all of them.
     pub const fn by_oid(&self, oid: &ObjectIdentifier) -> Option<&'a str> {
         let mut i = 0;
 
         while i < self.0.len() {
-            let lhs = self.0[i].0;
-
-            if lhs.buffer.eq(&oid.buffer) {
-                return Some(self.0[i].1);
+            if let Some(name) = self.0[i].by_oid(oid) {
+                return Some(name);
             }
 
             i += 1;
@@ -68,14 +182,13 @@ impl<'a> Database<'a> {
         None
     }
 
-    /// Finds a named oid by its associated name.
+    /// Finds a named oid by its associated name (ignoring ASCII case).
     pub const fn by_name(&self, name: &str) -> Option<&'a ObjectIdentifier> {
         let mut i = 0;
 
         while i < self.0.len() {
-            let lhs = self.0[i].1;
-            if eq_case(lhs.as_bytes(), name.as_bytes()) {
-                return Some(self.0[i].0);
+            if let Some(oid) = self.0[i].by_name(name) {
+                return Some(oid);
             }
 
             i += 1;
@@ -84,49 +197,111 @@ impl<'a> Database<'a> {
         None
     }
 
+    /// Iterate over all named oids in the database.
+    pub fn iter(&self) -> impl Iterator<Item = (&'a ObjectIdentifier, &'a str)> {
+        Entries::new(self.0)
+    }
+
+    /// Iterate over the named oids which are in the subtree rooted at
+    /// `prefix`, including `prefix` itself.
+    pub fn subtree(&self, prefix: &ObjectIdentifier) -> Subtree<'a> {
+        Subtree {
+            entries: Entries::new(self.0),
+            prefix: *prefix,
+        }
+    }
+
+    /// Get the table of a database containing a single standard.
+    // Unused if no standards are enabled
+    #[allow(dead_code)]
+    const fn table(&self) -> Table<'a> {
+        self.0[0]
+    }
+
     /// Return the list of matched name for the OID.
     pub const fn find_names_for_oid(&self, oid: ObjectIdentifier) -> Names<'a> {
         Names {
-            database: *self,
+            entries: Entries::new(self.0),
             oid,
-            position: 0,
         }
     }
 }
 
+/// Iterator over the entries of all tables in a [`Database`].
+#[derive(Clone)]
+struct Entries<'a> {
+    tables: &'a [Table<'a>],
+    table: usize,
+    entry: usize,
+}
+
+impl<'a> Entries<'a> {
+    const fn new(tables: &'a [Table<'a>]) -> Self {
+        Self {
+            tables,
+            table: 0,
+            entry: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for Entries<'a> {
+    type Item = (&'a ObjectIdentifier, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(table) = self.tables.get(self.table) {
+            if let Some(&entry) = table.entries.get(self.entry) {
+                self.entry += 1;
+                return Some(entry);
+            }
+
+            self.table += 1;
+            self.entry = 0;
+        }
+
+        None
+    }
+}
+
 /// Iterator returning the multiple names that may be associated with an OID.
 pub struct Names<'a> {
-    database: Database<'a>,
+    entries: Entries<'a>,
     oid: ObjectIdentifier,
-    position: usize,
 }
 
 impl<'a> Iterator for Names<'a> {
     type Item = &'a str;
 
     fn next(&mut self) -> Option<&'a str> {
-        let mut i = self.position;
+        let oid = &self.oid;
 
-        while i < self.database.0.len() {
-            let lhs = self.database.0[i].0;
+        self.entries
+            .find(|(lhs, _)| lhs.buffer.eq(&oid.buffer))
+            .map(|(_, name)| name)
+    }
+}
 
-            if lhs.buffer.eq(&self.oid.buffer) {
-                self.position = i + 1;
-                return Some(self.database.0[i].1);
-            }
+/// Iterator over the named oids in a subtree of the database.
+#[derive(Clone)]
+pub struct Subtree<'a> {
+    entries: Entries<'a>,
+    prefix: ObjectIdentifier,
+}
 
-            i += 1;
-        }
+impl<'a> Iterator for Subtree<'a> {
+    type Item = (&'a ObjectIdentifier, &'a str);
 
-        None
+    fn next(&mut self) -> Option<Self::Item> {
+        let prefix = self.prefix;
+        self.entries.find(|(oid, _)| oid.starts_with(prefix))
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "db"))]
 mod tests {
     use crate::ObjectIdentifier;
 
-    use super::rfc4519::CN;
+    use super::{rfc4519::CN, rfc5912::ID_EC_PUBLIC_KEY};
 
     #[test]
     fn by_oid() {
@@ -144,4 +319,65 @@ mod tests {
 
         assert_eq!(None, super::DB.by_name("purplePeopleEater"));
     }
+
+    #[test]
+    fn free_functions() {
+        assert_eq!(super::by_name("id-ecPublicKey"), Some(&ID_EC_PUBLIC_KEY));
+        assert_eq!(super::by_name("ID-ECPUBLICKEY"), Some(&ID_EC_PUBLIC_KEY));
+        assert_eq!(super::by_oid(&ID_EC_PUBLIC_KEY), Some("id-ecPublicKey"));
+    }
+
+    #[test]
+    fn index_covers_all_entries() {
+        for (i, (oid, name)) in super::DB.iter().enumerate() {
+            let first = || super::DB.iter().take(i + 1);
+
+            // Lookups return the first entry with a matching OID/name
+            let expected = first().find(|(o, _)| *o == oid).unwrap().1;
+            assert_eq!(super::DB.by_oid(oid), Some(expected));
+
+            let expected = first()
+                .find(|(_, n)| n.eq_ignore_ascii_case(name))
+                .unwrap()
+                .0;
+            assert_eq!(super::DB.by_name(name), Some(expected));
+        }
+    }
+
+    #[test]
+    fn per_standard_db() {
+        use super::rfc5912;
+
+        assert_eq!(
+            rfc5912::DB.by_name("id-ecPublicKey"),
+            Some(&ID_EC_PUBLIC_KEY)
+        );
+        assert_eq!(rfc5912::DB.by_oid(&CN), None);
+        assert!(rfc5912::DB
+            .iter()
+            .all(|(oid, name)| super::DB.find_names_for_oid(*oid).any(|n| n == name)));
+    }
+
+    #[test]
+    fn subtree() {
+        let prefix = ObjectIdentifier::new_unwrap("1.2.840.10045");
+        let mut names = super::DB.subtree(&prefix).map(|(oid, name)| {
+            assert!(oid.starts_with(prefix));
+            name
+        });
+
+        assert!(names.clone().any(|name| name == "id-ecPublicKey"));
+        assert!(names.clone().any(|name| name == "ecdsa-with-SHA256"));
+        assert!(!names.any(|name| name == "cn"));
+
+        let count = super::DB
+            .iter()
+            .filter(|(oid, _)| oid.starts_with(prefix))
+            .count();
+        assert_eq!(super::DB.subtree(&prefix).count(), count);
+
+        // `1.2.840.1` must not match `1.2.840.10045`
+        let prefix = ObjectIdentifier::new_unwrap("1.2.840.1");
+        assert!(super::subtree(&prefix).all(|(oid, _)| oid.starts_with(prefix)));
+    }
 }
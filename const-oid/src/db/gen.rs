@@ -1,4 +1,5 @@
 #![doc = "!! DO NOT EDIT !!: This file is auto-generated by oiddbgen."]
+#[cfg(feature = "db-fips202")]
 pub mod fips202 {
     pub const NIST_ALGORITHMS: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4");
@@ -16,7 +17,30 @@ pub mod fips202 {
         crate::ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.2.8");
     pub const ID_SHA_3_384: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.2.9");
+    pub const DB: crate::db::Database<'static> = crate::db::Database(&[crate::db::Table {
+        entries: &[
+            (&NIST_ALGORITHMS, "nistAlgorithms"),
+            (&HASH_ALGS, "hashAlgs"),
+            (&ID_SHA_3_512, "id-sha3-512"),
+            (&ID_SHAKE_128, "id-shake128"),
+            (&ID_SHAKE_256, "id-shake256"),
+            (&ID_SHA_3_224, "id-sha3-224"),
+            (&ID_SHA_3_256, "id-sha3-256"),
+            (&ID_SHA_3_384, "id-sha3-384"),
+        ],
+        by_name: crate::db::Index {
+            seeds: &[6, 207],
+            slots: &[5, 2, 7, 6, 0, 3, 4, 1],
+            fold_case: true,
+        },
+        by_oid: crate::db::Index {
+            seeds: &[1, 2],
+            slots: &[7, 2, 1, 3, 4, 0, 6, 5],
+            fold_case: false,
+        },
+    }]);
 }
+#[cfg(feature = "db-rfc1274")]
 pub mod rfc1274 {
     pub const TEXT_ENCODED_OR_ADDRESS: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("0.9.2342.19200300.100.1.2");
@@ -68,13 +92,76 @@ pub mod rfc1274 {
         crate::ObjectIdentifier::new_unwrap("0.9.2342.19200300.100.4.3");
     pub const PILOT_PERSON: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("0.9.2342.19200300.100.4.4");
+    pub const DB: crate::db::Database<'static> = crate::db::Database(&[crate::db::Table {
+        entries: &[
+            (&TEXT_ENCODED_OR_ADDRESS, "textEncodedORAddress"),
+            (&OTHER_MAILBOX, "otherMailbox"),
+            (&LAST_MODIFIED_TIME, "lastModifiedTime"),
+            (&LAST_MODIFIED_BY, "lastModifiedBy"),
+            (&A_RECORD, "aRecord"),
+            (&MD_RECORD, "mDRecord"),
+            (&MX_RECORD, "mXRecord"),
+            (&NS_RECORD, "nSRecord"),
+            (&SOA_RECORD, "sOARecord"),
+            (&CNAME_RECORD, "cNAMERecord"),
+            (&JANET_MAILBOX, "janetMailbox"),
+            (&MAIL_PREFERENCE_OPTION, "mailPreferenceOption"),
+            (&DSA_QUALITY, "dSAQuality"),
+            (&SUBTREE_MINIMUM_QUALITY, "subtreeMinimumQuality"),
+            (&SUBTREE_MAXIMUM_QUALITY, "subtreeMaximumQuality"),
+            (&PERSONAL_SIGNATURE, "personalSignature"),
+            (&DIT_REDIRECT, "dITRedirect"),
+            (&AUDIO, "audio"),
+            (&PHOTO, "photo"),
+            (&DNS_DOMAIN, "dNSDomain"),
+            (&PILOT_ORGANIZATION, "pilotOrganization"),
+            (&PILOT_DSA, "pilotDSA"),
+            (&QUALITY_LABELLED_DATA, "qualityLabelledData"),
+            (&PILOT_OBJECT, "pilotObject"),
+            (&PILOT_PERSON, "pilotPerson"),
+        ],
+        by_name: crate::db::Index {
+            seeds: &[12, 2, 0, 2, 237, 367, 2],
+            slots: &[
+                10, 8, 21, 16, 0, 23, 6, 2, 19, 5, 9, 3, 20, 15, 12, 24, 1, 18, 11, 13, 14, 4, 7,
+                17, 22,
+            ],
+            fold_case: true,
+        },
+        by_oid: crate::db::Index {
+            seeds: &[14, 1, 11, 32, 182, 52, 176],
+            slots: &[
+                8, 17, 4, 18, 2, 22, 15, 14, 24, 7, 21, 5, 1, 13, 16, 3, 9, 0, 23, 12, 10, 11, 19,
+                20, 6,
+            ],
+            fold_case: false,
+        },
+    }]);
 }
+#[cfg(feature = "db-rfc2079")]
 pub mod rfc2079 {
     pub const LABELED_URI: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.4.1.250.1.57");
     pub const LABELED_URI_OBJECT: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.4.1.250.3.15");
+    pub const DB: crate::db::Database<'static> = crate::db::Database(&[crate::db::Table {
+        entries: &[
+            (&LABELED_URI, "labeledURI"),
+            (&LABELED_URI_OBJECT, "labeledURIObject"),
+        ],
+        by_name: crate::db::Index {
+            seeds: &[1],
+            slots: &[1, 0],
+            fold_case: true,
+        },
+        by_oid: crate::db::Index {
+            seeds: &[1],
+            slots: &[0, 1],
+            fold_case: false,
+        },
+    }]);
 }
+#[cfg(feature = "db-rfc2164")]
 pub mod rfc2164 {
     pub const RFC_822_TO_X_400_MAPPING: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.4.1.453.7.1.1");
@@ -94,17 +181,72 @@ pub mod rfc2164 {
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.4.1.453.7.2.8");
     pub const MCGAM_TABLES: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.4.1.453.7.2.9");
+    pub const DB: crate::db::Database<'static> = crate::db::Database(&[crate::db::Table {
+        entries: &[
+            (&RFC_822_TO_X_400_MAPPING, "rFC822ToX400Mapping"),
+            (&X_400_TO_RFC_822_MAPPING, "x400ToRFC822Mapping"),
+            (&OMITTED_OR_ADDRESS_COMPONENT, "omittedORAddressComponent"),
+            (&MIXER_GATEWAY, "mixerGateway"),
+            (&ASSOCIATED_X_400_GATEWAY, "associatedX400Gateway"),
+            (&ASSOCIATED_OR_ADDRESS, "associatedORAddress"),
+            (&OR_ADDRESS_COMPONENT_TYPE, "oRAddressComponentType"),
+            (&ASSOCIATED_INTERNET_GATEWAY, "associatedInternetGateway"),
+            (&MCGAM_TABLES, "mcgamTables"),
+        ],
+        by_name: crate::db::Index {
+            seeds: &[6, 1, 2],
+            slots: &[2, 4, 6, 8, 3, 7, 1, 0, 5],
+            fold_case: true,
+        },
+        by_oid: crate::db::Index {
+            seeds: &[3, 13, 31],
+            slots: &[6, 4, 7, 8, 0, 3, 5, 1, 2],
+            fold_case: false,
+        },
+    }]);
 }
+#[cfg(feature = "db-rfc2247")]
 pub mod rfc2247 {
     pub const DOMAIN_NAME_FORM: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.4.1.1466.345");
+    pub const DB: crate::db::Database<'static> = crate::db::Database(&[crate::db::Table {
+        entries: &[(&DOMAIN_NAME_FORM, "domainNameForm")],
+        by_name: crate::db::Index {
+            seeds: &[1],
+            slots: &[0],
+            fold_case: true,
+        },
+        by_oid: crate::db::Index {
+            seeds: &[1],
+            slots: &[0],
+            fold_case: false,
+        },
+    }]);
 }
+#[cfg(feature = "db-rfc2252")]
 pub mod rfc2252 {
     pub const PRESENTATION_ADDRESS_MATCH: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("2.5.13.22");
     pub const PROTOCOL_INFORMATION_MATCH: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("2.5.13.24");
+    pub const DB: crate::db::Database<'static> = crate::db::Database(&[crate::db::Table {
+        entries: &[
+            (&PRESENTATION_ADDRESS_MATCH, "presentationAddressMatch"),
+            (&PROTOCOL_INFORMATION_MATCH, "protocolInformationMatch"),
+        ],
+        by_name: crate::db::Index {
+            seeds: &[2],
+            slots: &[1, 0],
+            fold_case: true,
+        },
+        by_oid: crate::db::Index {
+            seeds: &[1],
+            slots: &[1, 0],
+            fold_case: false,
+        },
+    }]);
 }
+#[cfg(feature = "db-rfc2256")]
 pub mod rfc2256 {
     pub const KNOWLEDGE_INFORMATION: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("2.5.4.2");
@@ -123,7 +265,35 @@ pub mod rfc2256 {
         crate::ObjectIdentifier::new_unwrap("2.5.6.12");
     pub const DSA: crate::ObjectIdentifier = crate::ObjectIdentifier::new_unwrap("2.5.6.13");
     pub const DMD: crate::ObjectIdentifier = crate::ObjectIdentifier::new_unwrap("2.5.6.20");
+    pub const DB: crate::db::Database<'static> = crate::db::Database(&[crate::db::Table {
+        entries: &[
+            (&KNOWLEDGE_INFORMATION, "knowledgeInformation"),
+            (&PRESENTATION_ADDRESS, "presentationAddress"),
+            (
+                &SUPPORTED_APPLICATION_CONTEXT,
+                "supportedApplicationContext",
+            ),
+            (&PROTOCOL_INFORMATION, "protocolInformation"),
+            (&DMD_NAME, "dmdName"),
+            (&STATE_OR_PROVINCE_NAME, "stateOrProvinceName"),
+            (&STREET_ADDRESS, "streetAddress"),
+            (&APPLICATION_ENTITY, "applicationEntity"),
+            (&DSA, "dSA"),
+            (&DMD, "dmd"),
+        ],
+        by_name: crate::db::Index {
+            seeds: &[52, 2, 14],
+            slots: &[7, 5, 4, 0, 9, 8, 3, 2, 1, 6],
+            fold_case: true,
+        },
+        by_oid: crate::db::Index {
+            seeds: &[63, 4, 57],
+            slots: &[2, 9, 0, 1, 6, 3, 8, 5, 4, 7],
+            fold_case: false,
+        },
+    }]);
 }
+#[cfg(feature = "db-rfc2293")]
 pub mod rfc2293 {
     pub const SUBTREE: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.4.1.453.7.1.1");
@@ -141,7 +311,33 @@ pub mod rfc2293 {
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.4.1.453.7.2.2");
     pub const DISTINGUISHED_NAME_TABLE_KEY: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.4.1.453.7.2.3");
+    pub const DB: crate::db::Database<'static> = crate::db::Database(&[crate::db::Table {
+        entries: &[
+            (&SUBTREE, "subtree"),
+            (&TABLE, "table"),
+            (&TABLE_ENTRY, "tableEntry"),
+            (&TEXT_TABLE_ENTRY, "textTableEntry"),
+            (
+                &DISTINGUISHED_NAME_TABLE_ENTRY,
+                "distinguishedNameTableEntry",
+            ),
+            (&TEXT_TABLE_KEY, "textTableKey"),
+            (&TEXT_TABLE_VALUE, "textTableValue"),
+            (&DISTINGUISHED_NAME_TABLE_KEY, "distinguishedNameTableKey"),
+        ],
+        by_name: crate::db::Index {
+            seeds: &[2, 99],
+            slots: &[1, 0, 6, 7, 2, 3, 4, 5],
+            fold_case: true,
+        },
+        by_oid: crate::db::Index {
+            seeds: &[25, 8],
+            slots: &[1, 3, 5, 6, 4, 2, 7, 0],
+            fold_case: false,
+        },
+    }]);
 }
+#[cfg(feature = "db-rfc2589")]
 pub mod rfc2589 {
     pub const DYNAMIC_OBJECT: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.4.1.1466.101.119.2");
@@ -149,7 +345,25 @@ pub mod rfc2589 {
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.4.1.1466.101.119.3");
     pub const DYNAMIC_SUBTREES: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.4.1.1466.101.119.4");
+    pub const DB: crate::db::Database<'static> = crate::db::Database(&[crate::db::Table {
+        entries: &[
+            (&DYNAMIC_OBJECT, "dynamicObject"),
+            (&ENTRY_TTL, "entryTtl"),
+            (&DYNAMIC_SUBTREES, "dynamicSubtrees"),
+        ],
+        by_name: crate::db::Index {
+            seeds: &[1],
+            slots: &[2, 0, 1],
+            fold_case: true,
+        },
+        by_oid: crate::db::Index {
+            seeds: &[4],
+            slots: &[1, 2, 0],
+            fold_case: false,
+        },
+    }]);
 }
+#[cfg(feature = "db-rfc2739")]
 pub mod rfc2739 {
     pub const CAL_CAL_URI: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.2.840.113556.1.4.478");
@@ -169,7 +383,31 @@ pub mod rfc2739 {
         crate::ObjectIdentifier::new_unwrap("1.2.840.113556.1.4.485");
     pub const CAL_ENTRY: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.2.840.113556.1.5.87");
+    pub const DB: crate::db::Database<'static> = crate::db::Database(&[crate::db::Table {
+        entries: &[
+            (&CAL_CAL_URI, "calCalURI"),
+            (&CAL_FBURL, "calFBURL"),
+            (&CAL_CAPURI, "calCAPURI"),
+            (&CAL_CAL_ADR_URI, "calCalAdrURI"),
+            (&CAL_OTHER_CAL_UR_IS, "calOtherCalURIs"),
+            (&CAL_OTHER_FBUR_LS, "calOtherFBURLs"),
+            (&CAL_OTHER_CAPUR_IS, "calOtherCAPURIs"),
+            (&CAL_OTHER_CAL_ADR_UR_IS, "calOtherCalAdrURIs"),
+            (&CAL_ENTRY, "calEntry"),
+        ],
+        by_name: crate::db::Index {
+            seeds: &[2, 15, 80],
+            slots: &[5, 7, 2, 3, 6, 1, 0, 4, 8],
+            fold_case: true,
+        },
+        by_oid: crate::db::Index {
+            seeds: &[31, 1, 15],
+            slots: &[1, 4, 0, 3, 8, 5, 7, 6, 2],
+            fold_case: false,
+        },
+    }]);
 }
+#[cfg(feature = "db-rfc2798")]
 pub mod rfc2798 {
     pub const JPEG_PHOTO: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("0.9.2342.19200300.100.1.60");
@@ -191,20 +429,77 @@ pub mod rfc2798 {
         crate::ObjectIdentifier::new_unwrap("2.16.840.1.113730.3.1.40");
     pub const INET_ORG_PERSON: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("2.16.840.1.113730.3.2.2");
+    pub const DB: crate::db::Database<'static> = crate::db::Database(&[crate::db::Table {
+        entries: &[
+            (&JPEG_PHOTO, "jpegPhoto"),
+            (&CAR_LICENSE, "carLicense"),
+            (&DEPARTMENT_NUMBER, "departmentNumber"),
+            (&USER_PKCS_12, "userPKCS12"),
+            (&DISPLAY_NAME, "displayName"),
+            (&EMPLOYEE_NUMBER, "employeeNumber"),
+            (&PREFERRED_LANGUAGE, "preferredLanguage"),
+            (&EMPLOYEE_TYPE, "employeeType"),
+            (&USER_SMIME_CERTIFICATE, "userSMIMECertificate"),
+            (&INET_ORG_PERSON, "inetOrgPerson"),
+        ],
+        by_name: crate::db::Index {
+            seeds: &[48, 7, 17],
+            slots: &[1, 8, 0, 6, 7, 9, 2, 5, 4, 3],
+            fold_case: true,
+        },
+        by_oid: crate::db::Index {
+            seeds: &[40, 8, 1],
+            slots: &[5, 3, 4, 6, 1, 7, 8, 0, 2, 9],
+            fold_case: false,
+        },
+    }]);
 }
+#[cfg(feature = "db-rfc3280")]
 pub mod rfc3280 {
     pub const EMAIL: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.1");
     pub const EMAIL_ADDRESS: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.1");
     pub const PSEUDONYM: crate::ObjectIdentifier = crate::ObjectIdentifier::new_unwrap("2.5.4.65");
+    pub const DB: crate::db::Database<'static> = crate::db::Database(&[crate::db::Table {
+        entries: &[
+            (&EMAIL, "email"),
+            (&EMAIL_ADDRESS, "emailAddress"),
+            (&PSEUDONYM, "pseudonym"),
+        ],
+        by_name: crate::db::Index {
+            seeds: &[3],
+            slots: &[1, 2, 0],
+            fold_case: true,
+        },
+        by_oid: crate::db::Index {
+            seeds: &[1],
+            slots: &[0, 2],
+            fold_case: false,
+        },
+    }]);
 }
+#[cfg(feature = "db-rfc3296")]
 pub mod rfc3296 {
     pub const REF: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("2.16.840.1.113730.3.1.34");
     pub const REFERRAL: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("2.16.840.1.113730.3.2.6");
+    pub const DB: crate::db::Database<'static> = crate::db::Database(&[crate::db::Table {
+        entries: &[(&REF, "ref"), (&REFERRAL, "referral")],
+        by_name: crate::db::Index {
+            seeds: &[2],
+            slots: &[1, 0],
+            fold_case: true,
+        },
+        by_oid: crate::db::Index {
+            seeds: &[2],
+            slots: &[0, 1],
+            fold_case: false,
+        },
+    }]);
 }
+#[cfg(feature = "db-rfc3671")]
 pub mod rfc3671 {
     pub const COLLECTIVE_ATTRIBUTE_SUBENTRIES: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("2.5.18.12");
@@ -233,7 +528,44 @@ pub mod rfc3671 {
     pub const C_L: crate::ObjectIdentifier = crate::ObjectIdentifier::new_unwrap("2.5.4.7.1");
     pub const C_ST: crate::ObjectIdentifier = crate::ObjectIdentifier::new_unwrap("2.5.4.8.1");
     pub const C_STREET: crate::ObjectIdentifier = crate::ObjectIdentifier::new_unwrap("2.5.4.9.1");
+    pub const DB: crate::db::Database<'static> = crate::db::Database(&[crate::db::Table {
+        entries: &[
+            (
+                &COLLECTIVE_ATTRIBUTE_SUBENTRIES,
+                "collectiveAttributeSubentries",
+            ),
+            (&COLLECTIVE_EXCLUSIONS, "collectiveExclusions"),
+            (
+                &COLLECTIVE_ATTRIBUTE_SUBENTRY,
+                "collectiveAttributeSubentry",
+            ),
+            (&C_O, "c-o"),
+            (&C_OU, "c-ou"),
+            (&C_POSTAL_ADDRESS, "c-PostalAddress"),
+            (&C_POSTAL_CODE, "c-PostalCode"),
+            (&C_POST_OFFICE_BOX, "c-PostOfficeBox"),
+            (&C_PHYSICAL_DELIVERY_OFFICE, "c-PhysicalDeliveryOffice"),
+            (&C_TELEPHONE_NUMBER, "c-TelephoneNumber"),
+            (&C_TELEX_NUMBER, "c-TelexNumber"),
+            (&C_FACSIMILE_TELEPHONE_NUMBER, "c-FacsimileTelephoneNumber"),
+            (&C_INTERNATIONAL_ISDN_NUMBER, "c-InternationalISDNNumber"),
+            (&C_L, "c-l"),
+            (&C_ST, "c-st"),
+            (&C_STREET, "c-street"),
+        ],
+        by_name: crate::db::Index {
+            seeds: &[93, 8, 198, 5],
+            slots: &[10, 1, 15, 14, 2, 6, 5, 8, 13, 3, 4, 0, 12, 9, 7, 11],
+            fold_case: true,
+        },
+        by_oid: crate::db::Index {
+            seeds: &[71, 111, 1, 33],
+            slots: &[14, 4, 13, 7, 9, 6, 3, 0, 10, 5, 1, 15, 8, 2, 11, 12],
+            fold_case: false,
+        },
+    }]);
 }
+#[cfg(feature = "db-rfc3672")]
 pub mod rfc3672 {
     pub const SUBENTRY: crate::ObjectIdentifier = crate::ObjectIdentifier::new_unwrap("2.5.17.0");
     pub const ADMINISTRATIVE_ROLE: crate::ObjectIdentifier =
@@ -252,7 +584,37 @@ pub mod rfc3672 {
         crate::ObjectIdentifier::new_unwrap("2.5.23.5");
     pub const COLLECTIVE_ATTRIBUTE_INNER_AREA: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("2.5.23.6");
+    pub const DB: crate::db::Database<'static> = crate::db::Database(&[crate::db::Table {
+        entries: &[
+            (&SUBENTRY, "subentry"),
+            (&ADMINISTRATIVE_ROLE, "administrativeRole"),
+            (&SUBTREE_SPECIFICATION, "subtreeSpecification"),
+            (&AUTONOMOUS_AREA, "autonomousArea"),
+            (&ACCESS_CONTROL_SPECIFIC_AREA, "accessControlSpecificArea"),
+            (&ACCESS_CONTROL_INNER_AREA, "accessControlInnerArea"),
+            (&SUBSCHEMA_ADMIN_SPECIFIC_AREA, "subschemaAdminSpecificArea"),
+            (
+                &COLLECTIVE_ATTRIBUTE_SPECIFIC_AREA,
+                "collectiveAttributeSpecificArea",
+            ),
+            (
+                &COLLECTIVE_ATTRIBUTE_INNER_AREA,
+                "collectiveAttributeInnerArea",
+            ),
+        ],
+        by_name: crate::db::Index {
+            seeds: &[1, 303, 0],
+            slots: &[3, 8, 7, 5, 2, 1, 0, 6, 4],
+            fold_case: true,
+        },
+        by_oid: crate::db::Index {
+            seeds: &[1, 22, 3],
+            slots: &[6, 2, 0, 8, 1, 5, 4, 7, 3],
+            fold_case: false,
+        },
+    }]);
 }
+#[cfg(feature = "db-rfc3687")]
 pub mod rfc3687 {
     pub const COMPONENT_FILTER_MATCH: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.2.36.79672281.1.13.2");
@@ -264,11 +626,45 @@ pub mod rfc3687 {
         crate::ObjectIdentifier::new_unwrap("1.2.36.79672281.1.13.6");
     pub const DIRECTORY_COMPONENTS_MATCH: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.2.36.79672281.1.13.7");
+    pub const DB: crate::db::Database<'static> = crate::db::Database(&[crate::db::Table {
+        entries: &[
+            (&COMPONENT_FILTER_MATCH, "componentFilterMatch"),
+            (&RDN_MATCH, "rdnMatch"),
+            (&PRESENT_MATCH, "presentMatch"),
+            (&ALL_COMPONENTS_MATCH, "allComponentsMatch"),
+            (&DIRECTORY_COMPONENTS_MATCH, "directoryComponentsMatch"),
+        ],
+        by_name: crate::db::Index {
+            seeds: &[5, 3],
+            slots: &[0, 3, 4, 1, 2],
+            fold_case: true,
+        },
+        by_oid: crate::db::Index {
+            seeds: &[1, 1],
+            slots: &[3, 1, 4, 2, 0],
+            fold_case: false,
+        },
+    }]);
 }
+#[cfg(feature = "db-rfc3698")]
 pub mod rfc3698 {
     pub const STORED_PREFIX_MATCH: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("2.5.13.41");
+    pub const DB: crate::db::Database<'static> = crate::db::Database(&[crate::db::Table {
+        entries: &[(&STORED_PREFIX_MATCH, "storedPrefixMatch")],
+        by_name: crate::db::Index {
+            seeds: &[1],
+            slots: &[0],
+            fold_case: true,
+        },
+        by_oid: crate::db::Index {
+            seeds: &[1],
+            slots: &[0],
+            fold_case: false,
+        },
+    }]);
 }
+#[cfg(feature = "db-rfc3703")]
 pub mod rfc3703 {
     pub const PCIM_POLICY: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.1.6.1.1");
@@ -390,7 +786,116 @@ pub mod rfc3703 {
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.1.6.2.8");
     pub const PCIM_RULE_ACTION_LIST: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.1.6.2.9");
+    pub const DB: crate::db::Database<'static> = crate::db::Database(&[crate::db::Table {
+        entries: &[
+            (&PCIM_POLICY, "pcimPolicy"),
+            (&PCIM_RULE_ACTION_ASSOCIATION, "pcimRuleActionAssociation"),
+            (&PCIM_CONDITION_AUX_CLASS, "pcimConditionAuxClass"),
+            (&PCIM_TPC_AUX_CLASS, "pcimTPCAuxClass"),
+            (
+                &PCIM_CONDITION_VENDOR_AUX_CLASS,
+                "pcimConditionVendorAuxClass",
+            ),
+            (&PCIM_ACTION_AUX_CLASS, "pcimActionAuxClass"),
+            (&PCIM_ACTION_VENDOR_AUX_CLASS, "pcimActionVendorAuxClass"),
+            (&PCIM_POLICY_INSTANCE, "pcimPolicyInstance"),
+            (&PCIM_ELEMENT_AUX_CLASS, "pcimElementAuxClass"),
+            (&PCIM_REPOSITORY, "pcimRepository"),
+            (&PCIM_REPOSITORY_AUX_CLASS, "pcimRepositoryAuxClass"),
+            (&PCIM_GROUP, "pcimGroup"),
+            (&PCIM_REPOSITORY_INSTANCE, "pcimRepositoryInstance"),
+            (&PCIM_SUBTREES_PTR_AUX_CLASS, "pcimSubtreesPtrAuxClass"),
+            (
+                &PCIM_GROUP_CONTAINMENT_AUX_CLASS,
+                "pcimGroupContainmentAuxClass",
+            ),
+            (
+                &PCIM_RULE_CONTAINMENT_AUX_CLASS,
+                "pcimRuleContainmentAuxClass",
+            ),
+            (&PCIM_GROUP_AUX_CLASS, "pcimGroupAuxClass"),
+            (&PCIM_GROUP_INSTANCE, "pcimGroupInstance"),
+            (&PCIM_RULE, "pcimRule"),
+            (&PCIM_RULE_AUX_CLASS, "pcimRuleAuxClass"),
+            (&PCIM_RULE_INSTANCE, "pcimRuleInstance"),
+            (
+                &PCIM_RULE_CONDITION_ASSOCIATION,
+                "pcimRuleConditionAssociation",
+            ),
+            (
+                &PCIM_RULE_VALIDITY_ASSOCIATION,
+                "pcimRuleValidityAssociation",
+            ),
+            (
+                &PCIM_RULE_VALIDITY_PERIOD_LIST,
+                "pcimRuleValidityPeriodList",
+            ),
+            (&PCIM_RULE_USAGE, "pcimRuleUsage"),
+            (&PCIM_RULE_PRIORITY, "pcimRulePriority"),
+            (&PCIM_RULE_MANDATORY, "pcimRuleMandatory"),
+            (&PCIM_RULE_SEQUENCED_ACTIONS, "pcimRuleSequencedActions"),
+            (&PCIM_ROLES, "pcimRoles"),
+            (&PCIM_CONDITION_GROUP_NUMBER, "pcimConditionGroupNumber"),
+            (&PCIM_CONDITION_NEGATED, "pcimConditionNegated"),
+            (&PCIM_CONDITION_NAME, "pcimConditionName"),
+            (&PCIM_CONDITION_DN, "pcimConditionDN"),
+            (&PCIM_VALIDITY_CONDITION_NAME, "pcimValidityConditionName"),
+            (&PCIM_TIME_PERIOD_CONDITION_DN, "pcimTimePeriodConditionDN"),
+            (&PCIM_ACTION_NAME, "pcimActionName"),
+            (&PCIM_ACTION_ORDER, "pcimActionOrder"),
+            (&PCIM_ACTION_DN, "pcimActionDN"),
+            (&PCIM_TPC_TIME, "pcimTPCTime"),
+            (&PCIM_TPC_MONTH_OF_YEAR_MASK, "pcimTPCMonthOfYearMask"),
+            (&PCIM_TPC_DAY_OF_MONTH_MASK, "pcimTPCDayOfMonthMask"),
+            (&PCIM_TPC_DAY_OF_WEEK_MASK, "pcimTPCDayOfWeekMask"),
+            (&PCIM_TPC_TIME_OF_DAY_MASK, "pcimTPCTimeOfDayMask"),
+            (&PCIM_KEYWORDS, "pcimKeywords"),
+            (&PCIM_TPC_LOCAL_OR_UTC_TIME, "pcimTPCLocalOrUtcTime"),
+            (&PCIM_VENDOR_CONSTRAINT_DATA, "pcimVendorConstraintData"),
+            (
+                &PCIM_VENDOR_CONSTRAINT_ENCODING,
+                "pcimVendorConstraintEncoding",
+            ),
+            (&PCIM_VENDOR_ACTION_DATA, "pcimVendorActionData"),
+            (&PCIM_VENDOR_ACTION_ENCODING, "pcimVendorActionEncoding"),
+            (&PCIM_POLICY_INSTANCE_NAME, "pcimPolicyInstanceName"),
+            (&PCIM_REPOSITORY_NAME, "pcimRepositoryName"),
+            (
+                &PCIM_SUBTREES_AUX_CONTAINED_SET,
+                "pcimSubtreesAuxContainedSet",
+            ),
+            (&PCIM_GROUPS_AUX_CONTAINED_SET, "pcimGroupsAuxContainedSet"),
+            (&PCIM_RULES_AUX_CONTAINED_SET, "pcimRulesAuxContainedSet"),
+            (&PCIM_GROUP_NAME, "pcimGroupName"),
+            (&PCIM_RULE_NAME, "pcimRuleName"),
+            (&PCIM_RULE_ENABLED, "pcimRuleEnabled"),
+            (&PCIM_RULE_CONDITION_LIST_TYPE, "pcimRuleConditionListType"),
+            (&PCIM_RULE_CONDITION_LIST, "pcimRuleConditionList"),
+            (&PCIM_RULE_ACTION_LIST, "pcimRuleActionList"),
+        ],
+        by_name: crate::db::Index {
+            seeds: &[
+                1, 5, 21, 51, 225, 183, 1980, 10, 3, 3, 56, 39, 167, 113, 117,
+            ],
+            slots: &[
+                32, 49, 34, 38, 51, 14, 10, 37, 19, 50, 7, 31, 48, 18, 17, 27, 0, 12, 29, 15, 43,
+                11, 8, 2, 36, 40, 41, 54, 21, 25, 22, 53, 46, 52, 5, 56, 1, 28, 9, 23, 35, 39, 24,
+                33, 58, 47, 4, 16, 45, 3, 55, 59, 13, 6, 30, 20, 42, 57, 26, 44,
+            ],
+            fold_case: true,
+        },
+        by_oid: crate::db::Index {
+            seeds: &[36, 3, 12, 2, 175, 37, 42, 236, 1, 21, 1227, 143, 101, 2, 2],
+            slots: &[
+                7, 16, 19, 6, 22, 27, 2, 48, 54, 50, 18, 35, 57, 33, 51, 8, 40, 32, 44, 53, 0, 30,
+                43, 31, 56, 14, 9, 28, 47, 1, 17, 38, 42, 15, 12, 10, 29, 37, 59, 58, 52, 11, 41,
+                23, 3, 20, 4, 21, 55, 5, 26, 34, 36, 49, 24, 25, 39, 45, 46, 13,
+            ],
+            fold_case: false,
+        },
+    }]);
 }
+#[cfg(feature = "db-rfc3712")]
 pub mod rfc3712 {
     pub const PRINTER_XRI_SUPPORTED: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.3.18.0.2.4.1107");
@@ -472,7 +977,116 @@ pub mod rfc3712 {
         crate::ObjectIdentifier::new_unwrap("1.3.18.0.2.6.257");
     pub const PRINTER_ABSTRACT: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.3.18.0.2.6.258");
+    pub const DB: crate::db::Database<'static> = crate::db::Database(&[crate::db::Table {
+        entries: &[
+            (&PRINTER_XRI_SUPPORTED, "printer-xri-supported"),
+            (&PRINTER_ALIASES, "printer-aliases"),
+            (&PRINTER_CHARSET_CONFIGURED, "printer-charset-configured"),
+            (
+                &PRINTER_JOB_PRIORITY_SUPPORTED,
+                "printer-job-priority-supported",
+            ),
+            (
+                &PRINTER_JOB_K_OCTETS_SUPPORTED,
+                "printer-job-k-octets-supported",
+            ),
+            (&PRINTER_CURRENT_OPERATOR, "printer-current-operator"),
+            (&PRINTER_SERVICE_PERSON, "printer-service-person"),
+            (
+                &PRINTER_DELIVERY_ORIENTATION_SUPPORTED,
+                "printer-delivery-orientation-supported",
+            ),
+            (
+                &PRINTER_STACKING_ORDER_SUPPORTED,
+                "printer-stacking-order-supported",
+            ),
+            (
+                &PRINTER_OUTPUT_FEATURES_SUPPORTED,
+                "printer-output-features-supported",
+            ),
+            (
+                &PRINTER_MEDIA_LOCAL_SUPPORTED,
+                "printer-media-local-supported",
+            ),
+            (&PRINTER_COPIES_SUPPORTED, "printer-copies-supported"),
+            (
+                &PRINTER_NATURAL_LANGUAGE_CONFIGURED,
+                "printer-natural-language-configured",
+            ),
+            (
+                &PRINTER_PRINT_QUALITY_SUPPORTED,
+                "printer-print-quality-supported",
+            ),
+            (
+                &PRINTER_RESOLUTION_SUPPORTED,
+                "printer-resolution-supported",
+            ),
+            (&PRINTER_MEDIA_SUPPORTED, "printer-media-supported"),
+            (&PRINTER_SIDES_SUPPORTED, "printer-sides-supported"),
+            (&PRINTER_NUMBER_UP_SUPPORTED, "printer-number-up-supported"),
+            (
+                &PRINTER_FINISHINGS_SUPPORTED,
+                "printer-finishings-supported",
+            ),
+            (
+                &PRINTER_PAGES_PER_MINUTE_COLOR,
+                "printer-pages-per-minute-color",
+            ),
+            (&PRINTER_PAGES_PER_MINUTE, "printer-pages-per-minute"),
+            (
+                &PRINTER_COMPRESSION_SUPPORTED,
+                "printer-compression-supported",
+            ),
+            (&PRINTER_COLOR_SUPPORTED, "printer-color-supported"),
+            (
+                &PRINTER_DOCUMENT_FORMAT_SUPPORTED,
+                "printer-document-format-supported",
+            ),
+            (&PRINTER_CHARSET_SUPPORTED, "printer-charset-supported"),
+            (
+                &PRINTER_MULTIPLE_DOCUMENT_JOBS_SUPPORTED,
+                "printer-multiple-document-jobs-supported",
+            ),
+            (
+                &PRINTER_IPP_VERSIONS_SUPPORTED,
+                "printer-ipp-versions-supported",
+            ),
+            (&PRINTER_MORE_INFO, "printer-more-info"),
+            (&PRINTER_NAME, "printer-name"),
+            (&PRINTER_LOCATION, "printer-location"),
+            (
+                &PRINTER_GENERATED_NATURAL_LANGUAGE_SUPPORTED,
+                "printer-generated-natural-language-supported",
+            ),
+            (&PRINTER_MAKE_AND_MODEL, "printer-make-and-model"),
+            (&PRINTER_INFO, "printer-info"),
+            (&PRINTER_URI, "printer-uri"),
+            (&PRINTER_LPR, "printerLPR"),
+            (&SLP_SERVICE_PRINTER, "slpServicePrinter"),
+            (&PRINTER_SERVICE, "printerService"),
+            (&PRINTER_IPP, "printerIPP"),
+            (&PRINTER_SERVICE_AUX_CLASS, "printerServiceAuxClass"),
+            (&PRINTER_ABSTRACT, "printerAbstract"),
+        ],
+        by_name: crate::db::Index {
+            seeds: &[15, 14, 1, 143, 37, 41, 2355, 7085, 1, 2],
+            slots: &[
+                0, 14, 25, 23, 2, 30, 8, 32, 17, 5, 6, 13, 9, 20, 37, 31, 36, 22, 3, 26, 10, 12,
+                27, 33, 18, 38, 24, 35, 11, 39, 4, 16, 7, 15, 29, 1, 21, 19, 28, 34,
+            ],
+            fold_case: true,
+        },
+        by_oid: crate::db::Index {
+            seeds: &[25, 311, 12, 59, 159, 4, 54, 6, 1065, 118],
+            slots: &[
+                5, 9, 32, 6, 8, 36, 4, 18, 2, 7, 27, 34, 13, 26, 24, 16, 33, 12, 35, 21, 20, 38, 0,
+                19, 29, 11, 14, 3, 23, 30, 1, 39, 25, 31, 28, 22, 15, 10, 17, 37,
+            ],
+            fold_case: false,
+        },
+    }]);
 }
+#[cfg(feature = "db-rfc4104")]
 pub mod rfc4104 {
     pub const PCELS_POLICY_SET: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.1.9.1.1");
@@ -712,7 +1326,269 @@ pub mod rfc4104 {
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.1.9.2.8");
     pub const PCELS_SEQUENCED_ACTIONS: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.1.9.2.9");
+    pub const DB: crate::db::Database<'static> = crate::db::Database(&[crate::db::Table {
+        entries: &[
+            (&PCELS_POLICY_SET, "pcelsPolicySet"),
+            (&PCELS_ACTION_ASSOCIATION, "pcelsActionAssociation"),
+            (
+                &PCELS_SIMPLE_CONDITION_AUX_CLASS,
+                "pcelsSimpleConditionAuxClass",
+            ),
+            (
+                &PCELS_COMPOUND_CONDITION_AUX_CLASS,
+                "pcelsCompoundConditionAuxClass",
+            ),
+            (
+                &PCELS_COMPOUND_FILTER_CONDITION_AUX_CLASS,
+                "pcelsCompoundFilterConditionAuxClass",
+            ),
+            (&PCELS_SIMPLE_ACTION_AUX_CLASS, "pcelsSimpleActionAuxClass"),
+            (
+                &PCELS_COMPOUND_ACTION_AUX_CLASS,
+                "pcelsCompoundActionAuxClass",
+            ),
+            (&PCELS_VARIABLE, "pcelsVariable"),
+            (
+                &PCELS_EXPLICIT_VARIABLE_AUX_CLASS,
+                "pcelsExplicitVariableAuxClass",
+            ),
+            (
+                &PCELS_IMPLICIT_VARIABLE_AUX_CLASS,
+                "pcelsImplicitVariableAuxClass",
+            ),
+            (
+                &PCELS_SOURCE_I_PV_4_VARIABLE_AUX_CLASS,
+                "pcelsSourceIPv4VariableAuxClass",
+            ),
+            (&PCELS_POLICY_SET_ASSOCIATION, "pcelsPolicySetAssociation"),
+            (
+                &PCELS_SOURCE_I_PV_6_VARIABLE_AUX_CLASS,
+                "pcelsSourceIPv6VariableAuxClass",
+            ),
+            (
+                &PCELS_DESTINATION_I_PV_4_VARIABLE_AUX_CLASS,
+                "pcelsDestinationIPv4VariableAuxClass",
+            ),
+            (
+                &PCELS_DESTINATION_I_PV_6_VARIABLE_AUX_CLASS,
+                "pcelsDestinationIPv6VariableAuxClass",
+            ),
+            (
+                &PCELS_SOURCE_PORT_VARIABLE_AUX_CLASS,
+                "pcelsSourcePortVariableAuxClass",
+            ),
+            (
+                &PCELS_DESTINATION_PORT_VARIABLE_AUX_CLASS,
+                "pcelsDestinationPortVariableAuxClass",
+            ),
+            (
+                &PCELS_IP_PROTOCOL_VARIABLE_AUX_CLASS,
+                "pcelsIPProtocolVariableAuxClass",
+            ),
+            (
+                &PCELS_IP_VERSION_VARIABLE_AUX_CLASS,
+                "pcelsIPVersionVariableAuxClass",
+            ),
+            (
+                &PCELS_IP_TO_S_VARIABLE_AUX_CLASS,
+                "pcelsIPToSVariableAuxClass",
+            ),
+            (&PCELS_DSCP_VARIABLE_AUX_CLASS, "pcelsDSCPVariableAuxClass"),
+            (
+                &PCELS_FLOW_ID_VARIABLE_AUX_CLASS,
+                "pcelsFlowIdVariableAuxClass",
+            ),
+            (&PCELS_GROUP, "pcelsGroup"),
+            (
+                &PCELS_SOURCE_MAC_VARIABLE_AUX_CLASS,
+                "pcelsSourceMACVariableAuxClass",
+            ),
+            (
+                &PCELS_DESTINATION_MAC_VARIABLE_AUX_CLASS,
+                "pcelsDestinationMACVariableAuxClass",
+            ),
+            (&PCELS_VLAN_VARIABLE_AUX_CLASS, "pcelsVLANVariableAuxClass"),
+            (&PCELS_CO_S_VARIABLE_AUX_CLASS, "pcelsCoSVariableAuxClass"),
+            (
+                &PCELS_ETHERTYPE_VARIABLE_AUX_CLASS,
+                "pcelsEthertypeVariableAuxClass",
+            ),
+            (
+                &PCELS_SOURCE_SAP_VARIABLE_AUX_CLASS,
+                "pcelsSourceSAPVariableAuxClass",
+            ),
+            (
+                &PCELS_DESTINATION_SAP_VARIABLE_AUX_CLASS,
+                "pcelsDestinationSAPVariableAuxClass",
+            ),
+            (
+                &PCELS_SNAPOUI_VARIABLE_AUX_CLASS,
+                "pcelsSNAPOUIVariableAuxClass",
+            ),
+            (
+                &PCELS_SNAP_TYPE_VARIABLE_AUX_CLASS,
+                "pcelsSNAPTypeVariableAuxClass",
+            ),
+            (
+                &PCELS_FLOW_DIRECTION_VARIABLE_AUX_CLASS,
+                "pcelsFlowDirectionVariableAuxClass",
+            ),
+            (&PCELS_GROUP_AUX_CLASS, "pcelsGroupAuxClass"),
+            (&PCELS_VALUE_AUX_CLASS, "pcelsValueAuxClass"),
+            (
+                &PCELS_I_PV_4_ADDR_VALUE_AUX_CLASS,
+                "pcelsIPv4AddrValueAuxClass",
+            ),
+            (
+                &PCELS_I_PV_6_ADDR_VALUE_AUX_CLASS,
+                "pcelsIPv6AddrValueAuxClass",
+            ),
+            (&PCELS_MAC_ADDR_VALUE_AUX_CLASS, "pcelsMACAddrValueAuxClass"),
+            (&PCELS_STRING_VALUE_AUX_CLASS, "pcelsStringValueAuxClass"),
+            (
+                &PCELS_BIT_STRING_VALUE_AUX_CLASS,
+                "pcelsBitStringValueAuxClass",
+            ),
+            (&PCELS_INTEGER_VALUE_AUX_CLASS, "pcelsIntegerValueAuxClass"),
+            (&PCELS_BOOLEAN_VALUE_AUX_CLASS, "pcelsBooleanValueAuxClass"),
+            (&PCELS_REUSABLE_CONTAINER, "pcelsReusableContainer"),
+            (
+                &PCELS_REUSABLE_CONTAINER_AUX_CLASS,
+                "pcelsReusableContainerAuxClass",
+            ),
+            (&PCELS_GROUP_INSTANCE, "pcelsGroupInstance"),
+            (
+                &PCELS_REUSABLE_CONTAINER_INSTANCE,
+                "pcelsReusableContainerInstance",
+            ),
+            (&PCELS_ROLE_COLLECTION, "pcelsRoleCollection"),
+            (&PCELS_FILTER_ENTRY_BASE, "pcelsFilterEntryBase"),
+            (&PCELS_IP_HEADERS_FILTER, "pcelsIPHeadersFilter"),
+            (&PCELS_8021_FILTER, "pcels8021Filter"),
+            (&PCELS_FILTER_LIST_AUX_CLASS, "pcelsFilterListAuxClass"),
+            (
+                &PCELS_VENDOR_VARIABLE_AUX_CLASS,
+                "pcelsVendorVariableAuxClass",
+            ),
+            (&PCELS_VENDOR_VALUE_AUX_CLASS, "pcelsVendorValueAuxClass"),
+            (&PCELS_RULE, "pcelsRule"),
+            (&PCELS_RULE_AUX_CLASS, "pcelsRuleAuxClass"),
+            (&PCELS_RULE_INSTANCE, "pcelsRuleInstance"),
+            (&PCELS_CONDITION_ASSOCIATION, "pcelsConditionAssociation"),
+            (&PCELS_POLICY_SET_NAME, "pcelsPolicySetName"),
+            (&PCELS_EXECUTION_STRATEGY, "pcelsExecutionStrategy"),
+            (&PCELS_VARIABLE_DN, "pcelsVariableDN"),
+            (&PCELS_VALUE_DN, "pcelsValueDN"),
+            (&PCELS_IS_MIRRORED, "pcelsIsMirrored"),
+            (&PCELS_VARIABLE_NAME, "pcelsVariableName"),
+            (&PCELS_EXPECTED_VALUE_LIST, "pcelsExpectedValueList"),
+            (&PCELS_VARIABLE_MODEL_CLASS, "pcelsVariableModelClass"),
+            (&PCELS_VARIABLE_MODEL_PROPERTY, "pcelsVariableModelProperty"),
+            (&PCELS_EXPECTED_VALUE_TYPES, "pcelsExpectedValueTypes"),
+            (&PCELS_VALUE_NAME, "pcelsValueName"),
+            (&PCELS_DECISION_STRATEGY, "pcelsDecisionStrategy"),
+            (&PCELS_I_PV_4_ADDR_LIST, "pcelsIPv4AddrList"),
+            (&PCELS_I_PV_6_ADDR_LIST, "pcelsIPv6AddrList"),
+            (&PCELS_MAC_ADDR_LIST, "pcelsMACAddrList"),
+            (&PCELS_STRING_LIST, "pcelsStringList"),
+            (&PCELS_BIT_STRING_LIST, "pcelsBitStringList"),
+            (&PCELS_INTEGER_LIST, "pcelsIntegerList"),
+            (&PCELS_BOOLEAN, "pcelsBoolean"),
+            (&PCELS_REUSABLE_CONTAINER_NAME, "pcelsReusableContainerName"),
+            (&PCELS_REUSABLE_CONTAINER_LIST, "pcelsReusableContainerList"),
+            (&PCELS_ROLE, "pcelsRole"),
+            (&PCELS_POLICY_SET_LIST, "pcelsPolicySetList"),
+            (&PCELS_ROLE_COLLECTION_NAME, "pcelsRoleCollectionName"),
+            (&PCELS_ELEMENT_LIST, "pcelsElementList"),
+            (&PCELS_FILTER_NAME, "pcelsFilterName"),
+            (&PCELS_FILTER_IS_NEGATED, "pcelsFilterIsNegated"),
+            (&PCELS_IP_HDR_VERSION, "pcelsIPHdrVersion"),
+            (&PCELS_IP_HDR_SOURCE_ADDRESS, "pcelsIPHdrSourceAddress"),
+            (
+                &PCELS_IP_HDR_SOURCE_ADDRESS_END_OF_RANGE,
+                "pcelsIPHdrSourceAddressEndOfRange",
+            ),
+            (&PCELS_IP_HDR_SOURCE_MASK, "pcelsIPHdrSourceMask"),
+            (&PCELS_IP_HDR_DEST_ADDRESS, "pcelsIPHdrDestAddress"),
+            (
+                &PCELS_IP_HDR_DEST_ADDRESS_END_OF_RANGE,
+                "pcelsIPHdrDestAddressEndOfRange",
+            ),
+            (&PCELS_PRIORITY, "pcelsPriority"),
+            (&PCELS_IP_HDR_DEST_MASK, "pcelsIPHdrDestMask"),
+            (&PCELS_IP_HDR_PROTOCOL_ID, "pcelsIPHdrProtocolID"),
+            (&PCELS_IP_HDR_SOURCE_PORT_START, "pcelsIPHdrSourcePortStart"),
+            (&PCELS_IP_HDR_SOURCE_PORT_END, "pcelsIPHdrSourcePortEnd"),
+            (&PCELS_IP_HDR_DEST_PORT_START, "pcelsIPHdrDestPortStart"),
+            (&PCELS_IP_HDR_DEST_PORT_END, "pcelsIPHdrDestPortEnd"),
+            (&PCELS_IP_HDR_DSCP_LIST, "pcelsIPHdrDSCPList"),
+            (&PCELS_IP_HDR_FLOW_LABEL, "pcelsIPHdrFlowLabel"),
+            (
+                &PCELS_8021_HDR_SOURCE_MAC_ADDRESS,
+                "pcels8021HdrSourceMACAddress",
+            ),
+            (&PCELS_8021_HDR_SOURCE_MAC_MASK, "pcels8021HdrSourceMACMask"),
+            (&PCELS_POLICY_SET_DN, "pcelsPolicySetDN"),
+            (
+                &PCELS_8021_HDR_DEST_MAC_ADDRESS,
+                "pcels8021HdrDestMACAddress",
+            ),
+            (&PCELS_8021_HDR_DEST_MAC_MASK, "pcels8021HdrDestMACMask"),
+            (&PCELS_8021_HDR_PROTOCOL_ID, "pcels8021HdrProtocolID"),
+            (&PCELS_8021_HDR_PRIORITY, "pcels8021HdrPriority"),
+            (&PCELS_8021_HDR_VLANID, "pcels8021HdrVLANID"),
+            (&PCELS_FILTER_LIST_NAME, "pcelsFilterListName"),
+            (&PCELS_FILTER_DIRECTION, "pcelsFilterDirection"),
+            (&PCELS_FILTER_ENTRY_LIST, "pcelsFilterEntryList"),
+            (&PCELS_VENDOR_VARIABLE_DATA, "pcelsVendorVariableData"),
+            (
+                &PCELS_VENDOR_VARIABLE_ENCODING,
+                "pcelsVendorVariableEncoding",
+            ),
+            (&PCELS_CONDITION_LIST_TYPE, "pcelsConditionListType"),
+            (&PCELS_VENDOR_VALUE_DATA, "pcelsVendorValueData"),
+            (&PCELS_VENDOR_VALUE_ENCODING, "pcelsVendorValueEncoding"),
+            (
+                &PCELS_RULE_VALIDITY_PERIOD_LIST,
+                "pcelsRuleValidityPeriodList",
+            ),
+            (&PCELS_CONDITION_LIST, "pcelsConditionList"),
+            (&PCELS_ACTION_LIST, "pcelsActionList"),
+            (&PCELS_SEQUENCED_ACTIONS, "pcelsSequencedActions"),
+        ],
+        by_name: crate::db::Index {
+            seeds: &[
+                94, 5, 59, 10, 32, 77, 5, 150, 141, 53, 67, 24, 3, 29, 25, 57, 446, 2, 324, 4, 130,
+                322, 782, 164, 2763, 3, 335, 13, 2, 0,
+            ],
+            slots: &[
+                97, 27, 25, 70, 58, 77, 103, 45, 7, 6, 42, 1, 54, 35, 8, 21, 64, 100, 31, 98, 5,
+                74, 105, 46, 3, 43, 24, 116, 52, 111, 78, 99, 92, 69, 12, 117, 94, 60, 47, 101, 56,
+                40, 17, 13, 28, 36, 108, 59, 102, 86, 44, 68, 81, 91, 76, 83, 112, 107, 106, 30,
+                10, 9, 88, 2, 33, 114, 11, 16, 55, 51, 62, 61, 104, 32, 48, 41, 84, 95, 109, 34,
+                65, 72, 113, 18, 96, 87, 110, 115, 29, 85, 26, 73, 22, 49, 67, 38, 89, 15, 4, 90,
+                57, 20, 66, 53, 118, 23, 14, 0, 75, 82, 19, 79, 39, 80, 37, 93, 50, 63, 71,
+            ],
+            fold_case: true,
+        },
+        by_oid: crate::db::Index {
+            seeds: &[
+                2, 2, 1, 1, 17, 3, 110, 34, 1, 2, 12, 124, 2, 14, 104, 150, 1, 138, 16, 41, 545,
+                747, 27, 1126, 124, 26, 216, 2, 134, 15,
+            ],
+            slots: &[
+                47, 69, 17, 71, 96, 95, 90, 116, 12, 81, 70, 105, 106, 56, 104, 34, 32, 35, 24, 74,
+                16, 40, 72, 1, 48, 64, 26, 94, 102, 44, 61, 7, 51, 80, 88, 115, 2, 30, 22, 33, 14,
+                78, 100, 50, 99, 83, 29, 43, 68, 0, 31, 49, 54, 42, 53, 55, 98, 66, 23, 28, 73, 6,
+                117, 59, 89, 67, 46, 8, 92, 93, 108, 4, 109, 110, 21, 9, 39, 114, 19, 58, 11, 20,
+                15, 76, 41, 103, 18, 75, 112, 52, 113, 86, 45, 37, 111, 77, 25, 87, 63, 107, 62,
+                91, 118, 101, 97, 27, 85, 5, 13, 60, 79, 82, 36, 57, 84, 65, 10, 38, 3,
+            ],
+            fold_case: false,
+        },
+    }]);
 }
+#[cfg(feature = "db-rfc4237")]
 pub mod rfc4237 {
     pub const VPIM_USER: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.1.11.1.1");
@@ -736,7 +1612,39 @@ pub mod rfc4237 {
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.1.11.2.8");
     pub const VPIM_MAX_MESSAGE_SIZE: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.1.11.2.9");
+    pub const DB: crate::db::Database<'static> = crate::db::Database(&[crate::db::Table {
+        entries: &[
+            (&VPIM_USER, "vPIMUser"),
+            (&VPIM_TELEPHONE_NUMBER, "vPIMTelephoneNumber"),
+            (&VPIM_SUB_MAILBOXES, "vPIMSubMailboxes"),
+            (&VPIM_RFC_822_MAILBOX, "vPIMRfc822Mailbox"),
+            (&VPIM_SPOKEN_NAME, "vPIMSpokenName"),
+            (&VPIM_SUPPORTED_UA_BEHAVIORS, "vPIMSupportedUABehaviors"),
+            (
+                &VPIM_SUPPORTED_AUDIO_MEDIA_TYPES,
+                "vPIMSupportedAudioMediaTypes",
+            ),
+            (
+                &VPIM_SUPPORTED_MESSAGE_CONTEXT,
+                "vPIMSupportedMessageContext",
+            ),
+            (&VPIM_TEXT_NAME, "vPIMTextName"),
+            (&VPIM_EXTENDED_ABSENCE_STATUS, "vPIMExtendedAbsenceStatus"),
+            (&VPIM_MAX_MESSAGE_SIZE, "vPIMMaxMessageSize"),
+        ],
+        by_name: crate::db::Index {
+            seeds: &[56, 51, 5],
+            slots: &[3, 6, 7, 2, 8, 10, 5, 9, 0, 1, 4],
+            fold_case: true,
+        },
+        by_oid: crate::db::Index {
+            seeds: &[1, 13, 39],
+            slots: &[6, 2, 1, 3, 8, 9, 5, 0, 7, 4, 10],
+            fold_case: false,
+        },
+    }]);
 }
+#[cfg(feature = "db-rfc4403")]
 pub mod rfc4403 {
     pub const UDDIV_3_SERVICE_KEY: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.1.10.4.32");
@@ -870,7 +1778,126 @@ pub mod rfc4403 {
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.1.10.6.8");
     pub const UDDIV_3_SUBSCRIPTION: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.1.10.6.9");
+    pub const DB: crate::db::Database<'static> = crate::db::Database(&[crate::db::Table {
+        entries: &[
+            (&UDDIV_3_SERVICE_KEY, "uddiv3ServiceKey"),
+            (
+                &UDDI_BUSINESS_ENTITY_NAME_FORM,
+                "uddiBusinessEntityNameForm",
+            ),
+            (
+                &UDDIV_3_ENTITY_OBITUARY_NAME_FORM,
+                "uddiv3EntityObituaryNameForm",
+            ),
+            (&UDDI_CONTACT_NAME_FORM, "uddiContactNameForm"),
+            (&UDDI_ADDRESS_NAME_FORM, "uddiAddressNameForm"),
+            (
+                &UDDI_BUSINESS_SERVICE_NAME_FORM,
+                "uddiBusinessServiceNameForm",
+            ),
+            (
+                &UDDI_BINDING_TEMPLATE_NAME_FORM,
+                "uddiBindingTemplateNameForm",
+            ),
+            (
+                &UDDI_T_MODEL_INSTANCE_INFO_NAME_FORM,
+                "uddiTModelInstanceInfoNameForm",
+            ),
+            (&UDDI_T_MODEL_NAME_FORM, "uddiTModelNameForm"),
+            (
+                &UDDI_PUBLISHER_ASSERTION_NAME_FORM,
+                "uddiPublisherAssertionNameForm",
+            ),
+            (
+                &UDDIV_3_SUBSCRIPTION_NAME_FORM,
+                "uddiv3SubscriptionNameForm",
+            ),
+            (&UDDI_BUSINESS_KEY, "uddiBusinessKey"),
+            (&UDDI_E_MAIL, "uddiEMail"),
+            (&UDDI_SORT_CODE, "uddiSortCode"),
+            (&UDDI_T_MODEL_KEY, "uddiTModelKey"),
+            (&UDDI_ADDRESS_LINE, "uddiAddressLine"),
+            (&UDDI_IDENTIFIER_BAG, "uddiIdentifierBag"),
+            (&UDDI_CATEGORY_BAG, "uddiCategoryBag"),
+            (&UDDI_KEYED_REFERENCE, "uddiKeyedReference"),
+            (&UDDI_SERVICE_KEY, "uddiServiceKey"),
+            (&UDDI_BINDING_KEY, "uddiBindingKey"),
+            (&UDDI_ACCESS_POINT, "uddiAccessPoint"),
+            (&UDDI_AUTHORIZED_NAME, "uddiAuthorizedName"),
+            (&UDDI_HOSTING_REDIRECTOR, "uddiHostingRedirector"),
+            (&UDDI_INSTANCE_DESCRIPTION, "uddiInstanceDescription"),
+            (&UDDI_INSTANCE_PARMS, "uddiInstanceParms"),
+            (&UDDI_OVERVIEW_DESCRIPTION, "uddiOverviewDescription"),
+            (&UDDI_OVERVIEW_URL, "uddiOverviewURL"),
+            (&UDDI_FROM_KEY, "uddiFromKey"),
+            (&UDDI_TO_KEY, "uddiToKey"),
+            (&UDDI_UUID, "uddiUUID"),
+            (&UDDI_IS_HIDDEN, "uddiIsHidden"),
+            (&UDDI_IS_PROJECTION, "uddiIsProjection"),
+            (&UDDI_OPERATOR, "uddiOperator"),
+            (&UDDI_LANG, "uddiLang"),
+            (&UDDIV_3_BUSINESS_KEY, "uddiv3BusinessKey"),
+            (&UDDIV_3_BINDING_KEY, "uddiv3BindingKey"),
+            (&UDDIV_3_TMODEL_KEY, "uddiv3TmodelKey"),
+            (&UDDIV_3_DIGITAL_SIGNATURE, "uddiv3DigitalSignature"),
+            (&UDDIV_3_NODE_ID, "uddiv3NodeId"),
+            (
+                &UDDIV_3_ENTITY_MODIFICATION_TIME,
+                "uddiv3EntityModificationTime",
+            ),
+            (&UDDIV_3_SUBSCRIPTION_KEY, "uddiv3SubscriptionKey"),
+            (&UDDIV_3_SUBSCRIPTION_FILTER, "uddiv3SubscriptionFilter"),
+            (&UDDI_NAME, "uddiName"),
+            (&UDDIV_3_NOTIFICATION_INTERVAL, "uddiv3NotificationInterval"),
+            (&UDDIV_3_MAX_ENTITIES, "uddiv3MaxEntities"),
+            (&UDDIV_3_EXPIRES_AFTER, "uddiv3ExpiresAfter"),
+            (&UDDIV_3_BRIEF_RESPONSE, "uddiv3BriefResponse"),
+            (&UDDIV_3_ENTITY_KEY, "uddiv3EntityKey"),
+            (&UDDIV_3_ENTITY_CREATION_TIME, "uddiv3EntityCreationTime"),
+            (&UDDIV_3_ENTITY_DELETION_TIME, "uddiv3EntityDeletionTime"),
+            (&UDDI_DESCRIPTION, "uddiDescription"),
+            (&UDDI_DISCOVERY_UR_LS, "uddiDiscoveryURLs"),
+            (&UDDI_USE_TYPE, "uddiUseType"),
+            (&UDDI_PERSON_NAME, "uddiPersonName"),
+            (&UDDI_PHONE, "uddiPhone"),
+            (&UDDI_BUSINESS_ENTITY, "uddiBusinessEntity"),
+            (&UDDIV_3_ENTITY_OBITUARY, "uddiv3EntityObituary"),
+            (&UDDI_CONTACT, "uddiContact"),
+            (&UDDI_ADDRESS, "uddiAddress"),
+            (&UDDI_BUSINESS_SERVICE, "uddiBusinessService"),
+            (&UDDI_BINDING_TEMPLATE, "uddiBindingTemplate"),
+            (&UDDI_T_MODEL_INSTANCE_INFO, "uddiTModelInstanceInfo"),
+            (&UDDI_T_MODEL, "uddiTModel"),
+            (&UDDI_PUBLISHER_ASSERTION, "uddiPublisherAssertion"),
+            (&UDDIV_3_SUBSCRIPTION, "uddiv3Subscription"),
+        ],
+        by_name: crate::db::Index {
+            seeds: &[
+                9, 27, 108, 1, 695, 13, 90, 21, 20, 3, 5, 1, 238, 78, 36, 16, 21,
+            ],
+            slots: &[
+                56, 22, 53, 42, 0, 39, 11, 27, 9, 24, 65, 59, 41, 46, 1, 51, 47, 13, 60, 4, 57, 29,
+                61, 28, 18, 15, 30, 16, 20, 5, 55, 7, 58, 25, 35, 62, 8, 3, 44, 19, 54, 14, 34, 38,
+                43, 52, 48, 23, 49, 2, 6, 17, 37, 33, 63, 32, 64, 45, 50, 40, 36, 26, 31, 10, 21,
+                12,
+            ],
+            fold_case: true,
+        },
+        by_oid: crate::db::Index {
+            seeds: &[
+                50, 1, 56, 1, 17, 17, 125, 164, 12, 25, 2, 4, 15, 6, 91, 220, 1527,
+            ],
+            slots: &[
+                14, 20, 36, 47, 64, 42, 44, 4, 65, 55, 24, 7, 29, 18, 21, 2, 33, 48, 22, 54, 25,
+                53, 12, 51, 28, 8, 13, 45, 16, 41, 59, 60, 27, 10, 57, 31, 62, 5, 61, 58, 0, 40, 6,
+                56, 23, 35, 63, 43, 15, 26, 17, 39, 9, 19, 37, 11, 34, 30, 49, 52, 32, 46, 38, 3,
+                1, 50,
+            ],
+            fold_case: false,
+        },
+    }]);
 }
+#[cfg(feature = "db-rfc4512")]
 pub mod rfc4512 {
     pub const EXTENSIBLE_OBJECT: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.4.1.1466.101.120.111");
@@ -924,7 +1951,56 @@ pub mod rfc4512 {
         crate::ObjectIdentifier::new_unwrap("2.5.4.1");
     pub const TOP: crate::ObjectIdentifier = crate::ObjectIdentifier::new_unwrap("2.5.6.0");
     pub const ALIAS: crate::ObjectIdentifier = crate::ObjectIdentifier::new_unwrap("2.5.6.1");
+    pub const DB: crate::db::Database<'static> = crate::db::Database(&[crate::db::Table {
+        entries: &[
+            (&EXTENSIBLE_OBJECT, "extensibleObject"),
+            (&SUPPORTED_CONTROL, "supportedControl"),
+            (&SUPPORTED_SASL_MECHANISMS, "supportedSASLMechanisms"),
+            (&SUPPORTED_LDAP_VERSION, "supportedLDAPVersion"),
+            (&LDAP_SYNTAXES, "ldapSyntaxes"),
+            (&NAMING_CONTEXTS, "namingContexts"),
+            (&ALT_SERVER, "altServer"),
+            (&SUPPORTED_EXTENSION, "supportedExtension"),
+            (&SUPPORTED_FEATURES, "supportedFeatures"),
+            (&CREATE_TIMESTAMP, "createTimestamp"),
+            (&SUBSCHEMA_SUBENTRY, "subschemaSubentry"),
+            (&MODIFY_TIMESTAMP, "modifyTimestamp"),
+            (&CREATORS_NAME, "creatorsName"),
+            (&MODIFIERS_NAME, "modifiersName"),
+            (&SUBSCHEMA, "subschema"),
+            (&DIT_STRUCTURE_RULES, "dITStructureRules"),
+            (&GOVERNING_STRUCTURE_RULE, "governingStructureRule"),
+            (&DIT_CONTENT_RULES, "dITContentRules"),
+            (&MATCHING_RULES, "matchingRules"),
+            (&ATTRIBUTE_TYPES, "attributeTypes"),
+            (&OBJECT_CLASSES, "objectClasses"),
+            (&NAME_FORMS, "nameForms"),
+            (&MATCHING_RULE_USE, "matchingRuleUse"),
+            (&STRUCTURAL_OBJECT_CLASS, "structuralObjectClass"),
+            (&OBJECT_CLASS, "objectClass"),
+            (&ALIASED_OBJECT_NAME, "aliasedObjectName"),
+            (&TOP, "top"),
+            (&ALIAS, "alias"),
+        ],
+        by_name: crate::db::Index {
+            seeds: &[191, 54, 2, 97, 4, 8, 29],
+            slots: &[
+                13, 6, 26, 14, 20, 27, 17, 10, 16, 5, 9, 2, 1, 22, 3, 7, 25, 4, 0, 23, 11, 12, 15,
+                21, 24, 8, 19, 18,
+            ],
+            fold_case: true,
+        },
+        by_oid: crate::db::Index {
+            seeds: &[208, 3, 155, 1091, 4, 0, 22],
+            slots: &[
+                22, 13, 5, 3, 27, 4, 0, 21, 6, 18, 16, 12, 2, 24, 23, 11, 17, 10, 19, 25, 15, 26,
+                7, 9, 20, 14, 8, 1,
+            ],
+            fold_case: false,
+        },
+    }]);
 }
+#[cfg(feature = "db-rfc4517")]
 pub mod rfc4517 {
     pub const CASE_EXACT_IA_5_MATCH: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.4.1.1466.109.114.1");
@@ -990,7 +2066,81 @@ pub mod rfc4517 {
         crate::ObjectIdentifier::new_unwrap("2.5.13.8");
     pub const NUMERIC_STRING_ORDERING_MATCH: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("2.5.13.9");
+    pub const DB: crate::db::Database<'static> = crate::db::Database(&[crate::db::Table {
+        entries: &[
+            (&CASE_EXACT_IA_5_MATCH, "caseExactIA5Match"),
+            (&CASE_IGNORE_IA_5_MATCH, "caseIgnoreIA5Match"),
+            (
+                &CASE_IGNORE_IA_5_SUBSTRINGS_MATCH,
+                "caseIgnoreIA5SubstringsMatch",
+            ),
+            (&OBJECT_IDENTIFIER_MATCH, "objectIdentifierMatch"),
+            (&DISTINGUISHED_NAME_MATCH, "distinguishedNameMatch"),
+            (
+                &NUMERIC_STRING_SUBSTRINGS_MATCH,
+                "numericStringSubstringsMatch",
+            ),
+            (&CASE_IGNORE_LIST_MATCH, "caseIgnoreListMatch"),
+            (
+                &CASE_IGNORE_LIST_SUBSTRINGS_MATCH,
+                "caseIgnoreListSubstringsMatch",
+            ),
+            (&BOOLEAN_MATCH, "booleanMatch"),
+            (&INTEGER_MATCH, "integerMatch"),
+            (&INTEGER_ORDERING_MATCH, "integerOrderingMatch"),
+            (&BIT_STRING_MATCH, "bitStringMatch"),
+            (&OCTET_STRING_MATCH, "octetStringMatch"),
+            (&OCTET_STRING_ORDERING_MATCH, "octetStringOrderingMatch"),
+            (&CASE_IGNORE_MATCH, "caseIgnoreMatch"),
+            (&TELEPHONE_NUMBER_MATCH, "telephoneNumberMatch"),
+            (
+                &TELEPHONE_NUMBER_SUBSTRINGS_MATCH,
+                "telephoneNumberSubstringsMatch",
+            ),
+            (&UNIQUE_MEMBER_MATCH, "uniqueMemberMatch"),
+            (&GENERALIZED_TIME_MATCH, "generalizedTimeMatch"),
+            (
+                &GENERALIZED_TIME_ORDERING_MATCH,
+                "generalizedTimeOrderingMatch",
+            ),
+            (&INTEGER_FIRST_COMPONENT_MATCH, "integerFirstComponentMatch"),
+            (&CASE_IGNORE_ORDERING_MATCH, "caseIgnoreOrderingMatch"),
+            (
+                &OBJECT_IDENTIFIER_FIRST_COMPONENT_MATCH,
+                "objectIdentifierFirstComponentMatch",
+            ),
+            (
+                &DIRECTORY_STRING_FIRST_COMPONENT_MATCH,
+                "directoryStringFirstComponentMatch",
+            ),
+            (&WORD_MATCH, "wordMatch"),
+            (&KEYWORD_MATCH, "keywordMatch"),
+            (&CASE_IGNORE_SUBSTRINGS_MATCH, "caseIgnoreSubstringsMatch"),
+            (&CASE_EXACT_MATCH, "caseExactMatch"),
+            (&CASE_EXACT_ORDERING_MATCH, "caseExactOrderingMatch"),
+            (&CASE_EXACT_SUBSTRINGS_MATCH, "caseExactSubstringsMatch"),
+            (&NUMERIC_STRING_MATCH, "numericStringMatch"),
+            (&NUMERIC_STRING_ORDERING_MATCH, "numericStringOrderingMatch"),
+        ],
+        by_name: crate::db::Index {
+            seeds: &[1, 5, 19, 171, 1, 6856, 13, 331],
+            slots: &[
+                5, 21, 10, 22, 7, 29, 31, 2, 19, 11, 0, 28, 24, 13, 8, 17, 16, 20, 23, 12, 25, 27,
+                3, 26, 4, 9, 1, 15, 14, 18, 30, 6,
+            ],
+            fold_case: true,
+        },
+        by_oid: crate::db::Index {
+            seeds: &[5, 4, 10, 105, 311, 527, 3, 1],
+            slots: &[
+                8, 19, 27, 29, 0, 13, 17, 28, 21, 9, 2, 1, 5, 25, 23, 11, 3, 20, 7, 15, 10, 31, 4,
+                22, 24, 18, 26, 16, 12, 6, 14, 30,
+            ],
+            fold_case: false,
+        },
+    }]);
 }
+#[cfg(feature = "db-rfc4519")]
 pub mod rfc4519 {
     pub const UID: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("0.9.2342.19200300.100.1.1");
@@ -1101,7 +2251,98 @@ pub mod rfc4519 {
         crate::ObjectIdentifier::new_unwrap("2.5.6.8");
     pub const GROUP_OF_NAMES: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("2.5.6.9");
+    pub const DB: crate::db::Database<'static> = crate::db::Database(&[crate::db::Table {
+        entries: &[
+            (&UID, "uid"),
+            (&USER_ID, "userId"),
+            (&DC, "DC"),
+            (&DOMAIN_COMPONENT, "domainComponent"),
+            (&UID_OBJECT, "uidObject"),
+            (&DC_OBJECT, "dcObject"),
+            (&O, "o"),
+            (&ORGANIZATION_NAME, "organizationName"),
+            (&OU, "ou"),
+            (&ORGANIZATIONAL_UNIT_NAME, "organizationalUnitName"),
+            (&TITLE, "title"),
+            (&DESCRIPTION, "description"),
+            (&SEARCH_GUIDE, "searchGuide"),
+            (&BUSINESS_CATEGORY, "businessCategory"),
+            (&POSTAL_ADDRESS, "postalAddress"),
+            (&POSTAL_CODE, "postalCode"),
+            (&POST_OFFICE_BOX, "postOfficeBox"),
+            (&PHYSICAL_DELIVERY_OFFICE_NAME, "physicalDeliveryOfficeName"),
+            (&TELEPHONE_NUMBER, "telephoneNumber"),
+            (&TELEX_NUMBER, "telexNumber"),
+            (&TELETEX_TERMINAL_IDENTIFIER, "teletexTerminalIdentifier"),
+            (&FACSIMILE_TELEPHONE_NUMBER, "facsimileTelephoneNumber"),
+            (&X_121_ADDRESS, "x121Address"),
+            (&INTERNATIONALI_SDN_NUMBER, "internationaliSDNNumber"),
+            (&REGISTERED_ADDRESS, "registeredAddress"),
+            (&DESTINATION_INDICATOR, "destinationIndicator"),
+            (&PREFERRED_DELIVERY_METHOD, "preferredDeliveryMethod"),
+            (&CN, "cn"),
+            (&COMMON_NAME, "commonName"),
+            (&MEMBER, "member"),
+            (&OWNER, "owner"),
+            (&ROLE_OCCUPANT, "roleOccupant"),
+            (&SEE_ALSO, "seeAlso"),
+            (&USER_PASSWORD, "userPassword"),
+            (&SN, "sn"),
+            (&SURNAME, "surname"),
+            (&NAME, "name"),
+            (&GIVEN_NAME, "givenName"),
+            (&INITIALS, "initials"),
+            (&GENERATION_QUALIFIER, "generationQualifier"),
+            (&X_500_UNIQUE_IDENTIFIER, "x500UniqueIdentifier"),
+            (&DN_QUALIFIER, "dnQualifier"),
+            (&ENHANCED_SEARCH_GUIDE, "enhancedSearchGuide"),
+            (&DISTINGUISHED_NAME, "distinguishedName"),
+            (&SERIAL_NUMBER, "serialNumber"),
+            (&UNIQUE_MEMBER, "uniqueMember"),
+            (&HOUSE_IDENTIFIER, "houseIdentifier"),
+            (&C, "c"),
+            (&COUNTRY_NAME, "countryName"),
+            (&L, "L"),
+            (&LOCALITY_NAME, "localityName"),
+            (&ST, "st"),
+            (&STREET, "street"),
+            (&RESIDENTIAL_PERSON, "residentialPerson"),
+            (&APPLICATION_PROCESS, "applicationProcess"),
+            (&DEVICE, "device"),
+            (&GROUP_OF_UNIQUE_NAMES, "groupOfUniqueNames"),
+            (&COUNTRY, "country"),
+            (&LOCALITY, "locality"),
+            (&ORGANIZATION, "organization"),
+            (&ORGANIZATIONAL_UNIT, "organizationalUnit"),
+            (&PERSON, "person"),
+            (&ORGANIZATIONAL_PERSON, "organizationalPerson"),
+            (&ORGANIZATIONAL_ROLE, "organizationalRole"),
+            (&GROUP_OF_NAMES, "groupOfNames"),
+        ],
+        by_name: crate::db::Index {
+            seeds: &[
+                1, 40, 42, 102, 52, 67, 3, 2, 14, 27, 183, 187, 6, 53, 57, 17, 108,
+            ],
+            slots: &[
+                5, 61, 54, 57, 35, 64, 13, 62, 26, 11, 46, 40, 1, 27, 58, 44, 21, 63, 59, 52, 51,
+                16, 9, 36, 22, 39, 2, 20, 34, 60, 48, 28, 0, 43, 42, 24, 55, 25, 10, 8, 31, 33, 4,
+                23, 17, 38, 45, 6, 14, 30, 47, 53, 19, 15, 50, 37, 29, 12, 56, 41, 18, 32, 7, 3,
+                49,
+            ],
+            fold_case: true,
+        },
+        by_oid: crate::db::Index {
+            seeds: &[1, 9, 17, 614, 256, 41, 133, 4, 79, 67, 171, 10, 1, 2, 24],
+            slots: &[
+                30, 16, 57, 31, 8, 47, 56, 39, 51, 61, 23, 12, 49, 19, 46, 43, 36, 60, 52, 37, 64,
+                27, 33, 10, 58, 59, 14, 21, 17, 40, 42, 41, 22, 54, 45, 0, 13, 34, 53, 11, 2, 55,
+                18, 26, 29, 62, 20, 4, 63, 24, 5, 6, 32, 44, 25, 38, 15,
+            ],
+            fold_case: false,
+        },
+    }]);
 }
+#[cfg(feature = "db-rfc4523")]
 pub mod rfc4523 {
     pub const CERTIFICATE_EXACT_MATCH: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("2.5.13.34");
@@ -1144,7 +2385,48 @@ pub mod rfc4523 {
     pub const PKI_USER: crate::ObjectIdentifier = crate::ObjectIdentifier::new_unwrap("2.5.6.21");
     pub const PKI_CA: crate::ObjectIdentifier = crate::ObjectIdentifier::new_unwrap("2.5.6.22");
     pub const DELTA_CRL: crate::ObjectIdentifier = crate::ObjectIdentifier::new_unwrap("2.5.6.23");
+    pub const DB: crate::db::Database<'static> = crate::db::Database(&[crate::db::Table {
+        entries: &[
+            (&CERTIFICATE_EXACT_MATCH, "certificateExactMatch"),
+            (&CERTIFICATE_MATCH, "certificateMatch"),
+            (&CERTIFICATE_PAIR_EXACT_MATCH, "certificatePairExactMatch"),
+            (&CERTIFICATE_PAIR_MATCH, "certificatePairMatch"),
+            (&CERTIFICATE_LIST_EXACT_MATCH, "certificateListExactMatch"),
+            (&CERTIFICATE_LIST_MATCH, "certificateListMatch"),
+            (&ALGORITHM_IDENTIFIER_MATCH, "algorithmIdentifierMatch"),
+            (&USER_CERTIFICATE, "userCertificate"),
+            (&CA_CERTIFICATE, "cACertificate"),
+            (&AUTHORITY_REVOCATION_LIST, "authorityRevocationList"),
+            (&CERTIFICATE_REVOCATION_LIST, "certificateRevocationList"),
+            (&CROSS_CERTIFICATE_PAIR, "crossCertificatePair"),
+            (&SUPPORTED_ALGORITHMS, "supportedAlgorithms"),
+            (&DELTA_REVOCATION_LIST, "deltaRevocationList"),
+            (&STRONG_AUTHENTICATION_USER, "strongAuthenticationUser"),
+            (&CERTIFICATION_AUTHORITY, "certificationAuthority"),
+            (&CERTIFICATION_AUTHORITY_V_2, "certificationAuthority-V2"),
+            (&USER_SECURITY_INFORMATION, "userSecurityInformation"),
+            (&CRL_DISTRIBUTION_POINT, "cRLDistributionPoint"),
+            (&PKI_USER, "pkiUser"),
+            (&PKI_CA, "pkiCA"),
+            (&DELTA_CRL, "deltaCRL"),
+        ],
+        by_name: crate::db::Index {
+            seeds: &[12, 9, 32, 55, 4, 9],
+            slots: &[
+                2, 11, 7, 3, 20, 14, 16, 4, 17, 10, 13, 12, 21, 8, 18, 15, 5, 9, 0, 6, 19, 1,
+            ],
+            fold_case: true,
+        },
+        by_oid: crate::db::Index {
+            seeds: &[200, 15, 42, 14, 20, 3],
+            slots: &[
+                11, 4, 5, 18, 17, 13, 9, 12, 1, 16, 6, 7, 2, 0, 14, 15, 10, 8, 3, 20, 21, 19,
+            ],
+            fold_case: false,
+        },
+    }]);
 }
+#[cfg(feature = "db-rfc4524")]
 pub mod rfc4524 {
     pub const MANAGER: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("0.9.2342.19200300.100.1.10");
@@ -1228,7 +2510,69 @@ pub mod rfc4524 {
         crate::ObjectIdentifier::new_unwrap("0.9.2342.19200300.100.4.7");
     pub const DOCUMENT_SERIES: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("0.9.2342.19200300.100.4.8");
+    pub const DB: crate::db::Database<'static> = crate::db::Database(&[crate::db::Table {
+        entries: &[
+            (&MANAGER, "manager"),
+            (&DOCUMENT_IDENTIFIER, "documentIdentifier"),
+            (&DOCUMENT_TITLE, "documentTitle"),
+            (&DOCUMENT_VERSION, "documentVersion"),
+            (&DOCUMENT_AUTHOR, "documentAuthor"),
+            (&DOCUMENT_LOCATION, "documentLocation"),
+            (&HOME_PHONE, "homePhone"),
+            (&HOME_TELEPHONE, "homeTelephone"),
+            (&SECRETARY, "secretary"),
+            (&MAIL, "mail"),
+            (&RFC_822_MAILBOX, "RFC822Mailbox"),
+            (&ASSOCIATED_DOMAIN, "associatedDomain"),
+            (&ASSOCIATED_NAME, "associatedName"),
+            (&HOME_POSTAL_ADDRESS, "homePostalAddress"),
+            (&INFO, "info"),
+            (&PERSONAL_TITLE, "personalTitle"),
+            (&MOBILE, "mobile"),
+            (&MOBILE_TELEPHONE_NUMBER, "mobileTelephoneNumber"),
+            (&PAGER, "pager"),
+            (&PAGER_TELEPHONE_NUMBER, "pagerTelephoneNumber"),
+            (&CO, "co"),
+            (&FRIENDLY_COUNTRY_NAME, "friendlyCountryName"),
+            (&UNIQUE_IDENTIFIER, "uniqueIdentifier"),
+            (&ORGANIZATIONAL_STATUS, "organizationalStatus"),
+            (&BUILDING_NAME, "buildingName"),
+            (&DRINK, "drink"),
+            (&FAVOURITE_DRINK, "favouriteDrink"),
+            (&SINGLE_LEVEL_QUALITY, "singleLevelQuality"),
+            (&DOCUMENT_PUBLISHER, "documentPublisher"),
+            (&ROOM_NUMBER, "roomNumber"),
+            (&USER_CLASS, "userClass"),
+            (&HOST, "host"),
+            (&DOMAIN, "domain"),
+            (&RFC_822_LOCAL_PART, "RFC822LocalPart"),
+            (&DOMAIN_RELATED_OBJECT, "domainRelatedObject"),
+            (&FRIENDLY_COUNTRY, "friendlyCountry"),
+            (&SIMPLE_SECURITY_OBJECT, "simpleSecurityObject"),
+            (&ACCOUNT, "account"),
+            (&DOCUMENT, "document"),
+            (&ROOM, "room"),
+            (&DOCUMENT_SERIES, "documentSeries"),
+        ],
+        by_name: crate::db::Index {
+            seeds: &[2, 3, 20, 29, 23, 7, 138, 9, 9, 210, 1451],
+            slots: &[
+                5, 29, 7, 39, 36, 25, 38, 1, 6, 16, 10, 31, 2, 26, 34, 23, 3, 20, 13, 30, 17, 35,
+                21, 40, 9, 22, 14, 8, 4, 33, 19, 28, 11, 32, 37, 15, 18, 24, 0, 12, 27,
+            ],
+            fold_case: true,
+        },
+        by_oid: crate::db::Index {
+            seeds: &[135, 26, 3, 204, 1, 7, 6, 25, 128],
+            slots: &[
+                33, 5, 40, 25, 3, 1, 11, 12, 14, 9, 16, 18, 8, 27, 35, 4, 34, 0, 24, 32, 13, 6, 38,
+                36, 2, 39, 22, 20, 37, 29, 31, 15, 28, 23, 30,
+            ],
+            fold_case: false,
+        },
+    }]);
 }
+#[cfg(feature = "db-rfc4530")]
 pub mod rfc4530 {
     pub const UUID_MATCH: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.1.16.2");
@@ -1236,7 +2580,25 @@ pub mod rfc4530 {
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.1.16.3");
     pub const ENTRY_UUID: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.1.16.4");
+    pub const DB: crate::db::Database<'static> = crate::db::Database(&[crate::db::Table {
+        entries: &[
+            (&UUID_MATCH, "uuidMatch"),
+            (&UUID_ORDERING_MATCH, "uuidOrderingMatch"),
+            (&ENTRY_UUID, "entryUUID"),
+        ],
+        by_name: crate::db::Index {
+            seeds: &[3],
+            slots: &[1, 2, 0],
+            fold_case: true,
+        },
+        by_oid: crate::db::Index {
+            seeds: &[3],
+            slots: &[0, 2, 1],
+            fold_case: false,
+        },
+    }]);
 }
+#[cfg(feature = "db-rfc4876")]
 pub mod rfc4876 {
     pub const DEFAULT_SERVER_LIST: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.4.1.11.1.3.1.1.0");
@@ -1272,11 +2634,60 @@ pub mod rfc4876 {
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.4.1.11.1.3.1.1.9");
     pub const DUA_CONFIG_PROFILE: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.4.1.11.1.3.1.2.5");
+    pub const DB: crate::db::Database<'static> = crate::db::Database(&[crate::db::Table {
+        entries: &[
+            (&DEFAULT_SERVER_LIST, "defaultServerList"),
+            (&DEFAULT_SEARCH_BASE, "defaultSearchBase"),
+            (&CREDENTIAL_LEVEL, "credentialLevel"),
+            (&OBJECTCLASS_MAP, "objectclassMap"),
+            (&DEFAULT_SEARCH_SCOPE, "defaultSearchScope"),
+            (&SERVICE_CREDENTIAL_LEVEL, "serviceCredentialLevel"),
+            (&SERVICE_SEARCH_DESCRIPTOR, "serviceSearchDescriptor"),
+            (
+                &SERVICE_AUTHENTICATION_METHOD,
+                "serviceAuthenticationMethod",
+            ),
+            (&DEREFERENCE_ALIASES, "dereferenceAliases"),
+            (&PREFERRED_SERVER_LIST, "preferredServerList"),
+            (&SEARCH_TIME_LIMIT, "searchTimeLimit"),
+            (&BIND_TIME_LIMIT, "bindTimeLimit"),
+            (&FOLLOW_REFERRALS, "followReferrals"),
+            (&AUTHENTICATION_METHOD, "authenticationMethod"),
+            (&PROFILE_TTL, "profileTTL"),
+            (&ATTRIBUTE_MAP, "attributeMap"),
+            (&DUA_CONFIG_PROFILE, "DUAConfigProfile"),
+        ],
+        by_name: crate::db::Index {
+            seeds: &[3, 12, 2, 8, 153],
+            slots: &[14, 8, 11, 13, 12, 0, 10, 16, 5, 4, 9, 3, 2, 7, 6, 15, 1],
+            fold_case: true,
+        },
+        by_oid: crate::db::Index {
+            seeds: &[86, 12, 41, 94, 1],
+            slots: &[2, 0, 14, 10, 12, 4, 7, 5, 16, 1, 8, 9, 11, 3, 13, 15, 6],
+            fold_case: false,
+        },
+    }]);
 }
+#[cfg(feature = "db-rfc5020")]
 pub mod rfc5020 {
     pub const ENTRY_DN: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.1.20");
+    pub const DB: crate::db::Database<'static> = crate::db::Database(&[crate::db::Table {
+        entries: &[(&ENTRY_DN, "entryDN")],
+        by_name: crate::db::Index {
+            seeds: &[1],
+            slots: &[0],
+            fold_case: true,
+        },
+        by_oid: crate::db::Index {
+            seeds: &[1],
+            slots: &[0],
+            fold_case: false,
+        },
+    }]);
 }
+#[cfg(feature = "db-rfc5280")]
 pub mod rfc5280 {
     pub const PKCS_9: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.2.840.113549.1.9");
@@ -1378,7 +2789,99 @@ pub mod rfc5280 {
     pub const ID_CE_SUBJECT_DIRECTORY_ATTRIBUTES: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("2.5.29.9");
     pub const ID_AT: crate::ObjectIdentifier = crate::ObjectIdentifier::new_unwrap("2.5.4");
+    pub const DB: crate::db::Database<'static> = crate::db::Database(&[crate::db::Table {
+        entries: &[
+            (&PKCS_9, "pkcs-9"),
+            (&ID_PKIX, "id-pkix"),
+            (&ID_PE, "id-pe"),
+            (&ID_PE_AUTHORITY_INFO_ACCESS, "id-pe-authorityInfoAccess"),
+            (&ID_PE_SUBJECT_INFO_ACCESS, "id-pe-subjectInfoAccess"),
+            (&ID_QT, "id-qt"),
+            (&ID_QT_CPS, "id-qt-cps"),
+            (&ID_QT_UNOTICE, "id-qt-unotice"),
+            (&ID_KP, "id-kp"),
+            (&ID_KP_SERVER_AUTH, "id-kp-serverAuth"),
+            (&ID_KP_CLIENT_AUTH, "id-kp-clientAuth"),
+            (&ID_KP_CODE_SIGNING, "id-kp-codeSigning"),
+            (&ID_KP_EMAIL_PROTECTION, "id-kp-emailProtection"),
+            (&ID_KP_TIME_STAMPING, "id-kp-timeStamping"),
+            (&ID_KP_OCSP_SIGNING, "id-kp-OCSPSigning"),
+            (&ID_AD, "id-ad"),
+            (&ID_AD_OCSP, "id-ad-ocsp"),
+            (&ID_AD_CA_ISSUERS, "id-ad-caIssuers"),
+            (&ID_AD_TIME_STAMPING, "id-ad-timeStamping"),
+            (&ID_AD_CA_REPOSITORY, "id-ad-caRepository"),
+            (&HOLD_INSTRUCTION, "holdInstruction"),
+            (&ID_HOLDINSTRUCTION_NONE, "id-holdinstruction-none"),
+            (
+                &ID_HOLDINSTRUCTION_CALLISSUER,
+                "id-holdinstruction-callissuer",
+            ),
+            (&ID_HOLDINSTRUCTION_REJECT, "id-holdinstruction-reject"),
+            (&ID_CE, "id-ce"),
+            (&ID_CE_SUBJECT_KEY_IDENTIFIER, "id-ce-subjectKeyIdentifier"),
+            (&ID_CE_KEY_USAGE, "id-ce-keyUsage"),
+            (
+                &ID_CE_PRIVATE_KEY_USAGE_PERIOD,
+                "id-ce-privateKeyUsagePeriod",
+            ),
+            (&ID_CE_SUBJECT_ALT_NAME, "id-ce-subjectAltName"),
+            (&ID_CE_ISSUER_ALT_NAME, "id-ce-issuerAltName"),
+            (&ID_CE_BASIC_CONSTRAINTS, "id-ce-basicConstraints"),
+            (&ID_CE_CRL_NUMBER, "id-ce-cRLNumber"),
+            (&ID_CE_CRL_REASONS, "id-ce-cRLReasons"),
+            (&ID_CE_HOLD_INSTRUCTION_CODE, "id-ce-holdInstructionCode"),
+            (&ID_CE_INVALIDITY_DATE, "id-ce-invalidityDate"),
+            (&ID_CE_DELTA_CRL_INDICATOR, "id-ce-deltaCRLIndicator"),
+            (
+                &ID_CE_ISSUING_DISTRIBUTION_POINT,
+                "id-ce-issuingDistributionPoint",
+            ),
+            (&ID_CE_CERTIFICATE_ISSUER, "id-ce-certificateIssuer"),
+            (&ID_CE_NAME_CONSTRAINTS, "id-ce-nameConstraints"),
+            (
+                &ID_CE_CRL_DISTRIBUTION_POINTS,
+                "id-ce-cRLDistributionPoints",
+            ),
+            (&ID_CE_CERTIFICATE_POLICIES, "id-ce-certificatePolicies"),
+            (&ANY_POLICY, "anyPolicy"),
+            (&ID_CE_POLICY_MAPPINGS, "id-ce-policyMappings"),
+            (
+                &ID_CE_AUTHORITY_KEY_IDENTIFIER,
+                "id-ce-authorityKeyIdentifier",
+            ),
+            (&ID_CE_POLICY_CONSTRAINTS, "id-ce-policyConstraints"),
+            (&ID_CE_EXT_KEY_USAGE, "id-ce-extKeyUsage"),
+            (&ANY_EXTENDED_KEY_USAGE, "anyExtendedKeyUsage"),
+            (&ID_CE_FRESHEST_CRL, "id-ce-freshestCRL"),
+            (&ID_CE_INHIBIT_ANY_POLICY, "id-ce-inhibitAnyPolicy"),
+            (
+                &ID_CE_SUBJECT_DIRECTORY_ATTRIBUTES,
+                "id-ce-subjectDirectoryAttributes",
+            ),
+            (&ID_AT, "id-at"),
+        ],
+        by_name: crate::db::Index {
+            seeds: &[25, 25, 16, 16, 2, 164, 5, 11, 18, 4, 10, 312, 16],
+            slots: &[
+                21, 28, 25, 19, 37, 34, 17, 50, 39, 13, 30, 23, 6, 8, 38, 9, 45, 3, 12, 32, 24, 47,
+                7, 4, 16, 2, 41, 14, 36, 10, 20, 18, 29, 44, 48, 27, 5, 46, 15, 49, 22, 40, 11, 43,
+                31, 35, 1, 0, 42, 33, 26,
+            ],
+            fold_case: true,
+        },
+        by_oid: crate::db::Index {
+            seeds: &[1, 8, 170, 251, 2, 25, 267, 17, 72, 10, 160, 10, 78],
+            slots: &[
+                24, 26, 21, 40, 14, 18, 9, 25, 43, 5, 42, 35, 36, 11, 29, 32, 45, 8, 46, 44, 13, 6,
+                4, 49, 37, 50, 23, 2, 15, 19, 39, 3, 33, 34, 1, 20, 16, 0, 12, 27, 31, 41, 7, 47,
+                22, 17, 10, 28, 30, 48, 38,
+            ],
+            fold_case: false,
+        },
+    }]);
 }
+#[cfg(feature = "db-rfc5911")]
 pub mod rfc5911 {
     pub const ID_PBKDF_2: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.2.840.113549.1.5.12");
@@ -1548,7 +3051,121 @@ pub mod rfc5911 {
         crate::ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.1.6");
     pub const ID_AES_128_CCM: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.1.7");
+    pub const DB: crate::db::Database<'static> = crate::db::Database(&[crate::db::Table {
+        entries: &[
+            (&ID_PBKDF_2, "id-PBKDF2"),
+            (&ID_DATA, "id-data"),
+            (&ID_SIGNED_DATA, "id-signedData"),
+            (&ID_ENVELOPED_DATA, "id-envelopedData"),
+            (&ID_DIGESTED_DATA, "id-digestedData"),
+            (&ID_ENCRYPTED_DATA, "id-encryptedData"),
+            (&SMIME_CAPABILITIES, "smimeCapabilities"),
+            (&ID_SMIME, "id-smime"),
+            (&ID_CT_RECEIPT, "id-ct-receipt"),
+            (&ID_CT_FIRMWARE_PACKAGE, "id-ct-firmwarePackage"),
+            (&ID_CT_FIRMWARE_LOAD_RECEIPT, "id-ct-firmwareLoadReceipt"),
+            (&ID_CT_FIRMWARE_LOAD_ERROR, "id-ct-firmwareLoadError"),
+            (&ID_CT_AUTH_DATA, "id-ct-authData"),
+            (&ID_CT_AUTH_ENVELOPED_DATA, "id-ct-authEnvelopedData"),
+            (&ID_CT_CONTENT_INFO, "id-ct-contentInfo"),
+            (&ID_CAP, "id-cap"),
+            (&ID_CAP_PREFER_BINARY_INSIDE, "id-cap-preferBinaryInside"),
+            (&ID_AA, "id-aa"),
+            (&ID_AA_RECEIPT_REQUEST, "id-aa-receiptRequest"),
+            (&ID_AA_CONTENT_REFERENCE, "id-aa-contentReference"),
+            (&ID_AA_ENCRYP_KEY_PREF, "id-aa-encrypKeyPref"),
+            (&ID_AA_SIGNING_CERTIFICATE, "id-aa-signingCertificate"),
+            (&ID_AA_SECURITY_LABEL, "id-aa-securityLabel"),
+            (&ID_AA_ML_EXPAND_HISTORY, "id-aa-mlExpandHistory"),
+            (&ID_AA_FIRMWARE_PACKAGE_ID, "id-aa-firmwarePackageID"),
+            (&ID_AA_TARGET_HARDWARE_I_DS, "id-aa-targetHardwareIDs"),
+            (&ID_AA_DECRYPT_KEY_ID, "id-aa-decryptKeyID"),
+            (&ID_AA_IMPL_CRYPTO_ALGS, "id-aa-implCryptoAlgs"),
+            (&ID_AA_WRAPPED_FIRMWARE_KEY, "id-aa-wrappedFirmwareKey"),
+            (&ID_AA_CONTENT_HINT, "id-aa-contentHint"),
+            (&ID_AA_COMMUNITY_IDENTIFIERS, "id-aa-communityIdentifiers"),
+            (&ID_AA_FIRMWARE_PACKAGE_INFO, "id-aa-firmwarePackageInfo"),
+            (&ID_AA_IMPL_COMPRESS_ALGS, "id-aa-implCompressAlgs"),
+            (&ID_AA_SIGNING_CERTIFICATE_V_2, "id-aa-signingCertificateV2"),
+            (&ID_AA_ER_INTERNAL, "id-aa-er-internal"),
+            (&ID_AA_MSG_SIG_DIGEST, "id-aa-msgSigDigest"),
+            (&ID_AA_ER_EXTERNAL, "id-aa-er-external"),
+            (&ID_AA_CONTENT_IDENTIFIER, "id-aa-contentIdentifier"),
+            (&ID_AA_EQUIVALENT_LABELS, "id-aa-equivalentLabels"),
+            (&ID_ALG_SSDH, "id-alg-SSDH"),
+            (&ID_ALG_ESDH, "id-alg-ESDH"),
+            (&ID_ALG_CMS_3_DE_SWRAP, "id-alg-CMS3DESwrap"),
+            (&ID_ALG_CMSRC_2_WRAP, "id-alg-CMSRC2wrap"),
+            (&ID_SKD, "id-skd"),
+            (&ID_SKD_GL_USE_KEK, "id-skd-glUseKEK"),
+            (&ID_SKD_GLA_QUERY_REQUEST, "id-skd-glaQueryRequest"),
+            (&ID_SKD_GLA_QUERY_RESPONSE, "id-skd-glaQueryResponse"),
+            (&ID_SKD_GL_PROVIDE_CERT, "id-skd-glProvideCert"),
+            (&ID_SKD_GL_MANAGE_CERT, "id-skd-glManageCert"),
+            (&ID_SKD_GL_KEY, "id-skd-glKey"),
+            (&ID_SKD_GL_DELETE, "id-skd-glDelete"),
+            (&ID_SKD_GL_ADD_MEMBER, "id-skd-glAddMember"),
+            (&ID_SKD_GL_DELETE_MEMBER, "id-skd-glDeleteMember"),
+            (&ID_SKD_GL_REKEY, "id-skd-glRekey"),
+            (&ID_SKD_GL_ADD_OWNER, "id-skd-glAddOwner"),
+            (&ID_SKD_GL_REMOVE_OWNER, "id-skd-glRemoveOwner"),
+            (&ID_SKD_GL_KEY_COMPROMISE, "id-skd-glKeyCompromise"),
+            (&ID_SKD_GLK_REFRESH, "id-skd-glkRefresh"),
+            (&ID_CONTENT_TYPE, "id-contentType"),
+            (&ID_MESSAGE_DIGEST, "id-messageDigest"),
+            (&ID_SIGNING_TIME, "id-signingTime"),
+            (&ID_COUNTERSIGNATURE, "id-countersignature"),
+            (&RC_2_CBC, "rc2-cbc"),
+            (&DES_EDE_3_CBC, "des-ede3-cbc"),
+            (&LTANS, "ltans"),
+            (&ID_CET_SKD_FAIL_INFO, "id-cet-skdFailInfo"),
+            (&ID_CMC_GLA_RR, "id-cmc-glaRR"),
+            (&ID_CMC_GLA_SKD_ALG_REQUEST, "id-cmc-gla-skdAlgRequest"),
+            (&ID_CMC_GLA_SKD_ALG_RESPONSE, "id-cmc-gla-skdAlgResponse"),
+            (&ID_ON_HARDWARE_MODULE_NAME, "id-on-hardwareModuleName"),
+            (&HMAC_SHA_1, "hMAC-SHA1"),
+            (&AES, "aes"),
+            (&ID_AES_128_CBC, "id-aes128-CBC"),
+            (&ID_AES_192_CBC, "id-aes192-CBC"),
+            (&ID_AES_192_WRAP, "id-aes192-wrap"),
+            (&ID_AES_192_GCM, "id-aes192-GCM"),
+            (&ID_AES_192_CCM, "id-aes192-CCM"),
+            (&ID_AES_256_CBC, "id-aes256-CBC"),
+            (&ID_AES_256_WRAP, "id-aes256-wrap"),
+            (&ID_AES_256_GCM, "id-aes256-GCM"),
+            (&ID_AES_256_CCM, "id-aes256-CCM"),
+            (&ID_AES_128_WRAP, "id-aes128-wrap"),
+            (&ID_AES_128_GCM, "id-aes128-GCM"),
+            (&ID_AES_128_CCM, "id-aes128-CCM"),
+        ],
+        by_name: crate::db::Index {
+            seeds: &[
+                13, 1, 1, 21, 9, 4, 75, 49, 25, 167, 5, 9, 434, 667, 206, 1, 1347, 1489, 9, 390, 6,
+            ],
+            slots: &[
+                24, 13, 57, 73, 42, 31, 17, 43, 60, 20, 33, 75, 54, 56, 19, 48, 71, 49, 26, 45, 58,
+                39, 21, 0, 78, 34, 77, 82, 68, 22, 40, 2, 12, 14, 52, 47, 76, 4, 11, 8, 28, 7, 53,
+                9, 16, 46, 18, 67, 64, 35, 23, 6, 66, 69, 50, 37, 25, 83, 81, 70, 80, 32, 15, 41,
+                36, 61, 30, 65, 5, 79, 38, 55, 27, 44, 74, 51, 62, 1, 59, 10, 29, 63, 72, 3,
+            ],
+            fold_case: true,
+        },
+        by_oid: crate::db::Index {
+            seeds: &[
+                1174, 305, 3, 5, 11, 0, 1, 22, 1, 6, 136, 26, 332, 53, 56, 406, 647, 36, 184, 184,
+                8,
+            ],
+            slots: &[
+                56, 81, 74, 68, 42, 38, 3, 57, 51, 65, 76, 11, 79, 8, 7, 44, 70, 41, 46, 30, 43,
+                58, 31, 72, 34, 78, 32, 83, 25, 22, 63, 47, 10, 9, 40, 55, 15, 50, 61, 23, 29, 45,
+                62, 17, 4, 48, 6, 67, 54, 19, 53, 24, 71, 0, 21, 14, 49, 16, 80, 39, 35, 13, 66,
+                82, 1, 20, 18, 33, 28, 77, 12, 2, 36, 27, 64, 37, 59, 73, 60, 5, 52, 69, 26, 75,
+            ],
+            fold_case: false,
+        },
+    }]);
 }
+#[cfg(feature = "db-rfc5912")]
 pub mod rfc5912 {
     pub const ID_DSA: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.2.840.10040.4.1");
@@ -1969,7 +3586,309 @@ pub mod rfc5912 {
         crate::ObjectIdentifier::new_unwrap("2.5.29.9");
     pub const ID_AT: crate::ObjectIdentifier = crate::ObjectIdentifier::new_unwrap("2.5.4");
     pub const ID_AT_ROLE: crate::ObjectIdentifier = crate::ObjectIdentifier::new_unwrap("2.5.4.72");
+    pub const DB: crate::db::Database<'static> = crate::db::Database(&[crate::db::Table {
+        entries: &[
+            (&ID_DSA, "id-dsa"),
+            (&DSA_WITH_SHA_1, "dsa-with-sha1"),
+            (&ID_EC_PUBLIC_KEY, "id-ecPublicKey"),
+            (&SECP_256_R_1, "secp256r1"),
+            (&ECDSA_WITH_SHA_224, "ecdsa-with-SHA224"),
+            (&ECDSA_WITH_SHA_256, "ecdsa-with-SHA256"),
+            (&ECDSA_WITH_SHA_384, "ecdsa-with-SHA384"),
+            (&ECDSA_WITH_SHA_512, "ecdsa-with-SHA512"),
+            (&DHPUBLICNUMBER, "dhpublicnumber"),
+            (&ID_PASSWORD_BASED_MAC, "id-PasswordBasedMac"),
+            (&ID_DH_BASED_MAC, "id-DHBasedMac"),
+            (&PKCS_1, "pkcs-1"),
+            (&RSA_ENCRYPTION, "rsaEncryption"),
+            (&ID_RSASSA_PSS, "id-RSASSA-PSS"),
+            (&SHA_256_WITH_RSA_ENCRYPTION, "sha256WithRSAEncryption"),
+            (&SHA_384_WITH_RSA_ENCRYPTION, "sha384WithRSAEncryption"),
+            (&SHA_512_WITH_RSA_ENCRYPTION, "sha512WithRSAEncryption"),
+            (&SHA_224_WITH_RSA_ENCRYPTION, "sha224WithRSAEncryption"),
+            (&MD_2_WITH_RSA_ENCRYPTION, "md2WithRSAEncryption"),
+            (&MD_5_WITH_RSA_ENCRYPTION, "md5WithRSAEncryption"),
+            (&SHA_1_WITH_RSA_ENCRYPTION, "sha1WithRSAEncryption"),
+            (&ID_RSAES_OAEP, "id-RSAES-OAEP"),
+            (&ID_MGF_1, "id-mgf1"),
+            (&ID_P_SPECIFIED, "id-pSpecified"),
+            (&PKCS_9, "pkcs-9"),
+            (&ID_EXTENSION_REQ, "id-ExtensionReq"),
+            (&ID_SMIME, "id-smime"),
+            (&ID_CT, "id-ct"),
+            (&ID_CT_SCVP_CERT_VAL_REQUEST, "id-ct-scvp-certValRequest"),
+            (&ID_CT_SCVP_CERT_VAL_RESPONSE, "id-ct-scvp-certValResponse"),
+            (&ID_CT_SCVP_VAL_POL_REQUEST, "id-ct-scvp-valPolRequest"),
+            (&ID_CT_SCVP_VAL_POL_RESPONSE, "id-ct-scvp-valPolResponse"),
+            (&ID_CT_ENC_KEY_WITH_ID, "id-ct-encKeyWithID"),
+            (&ID_AA, "id-aa"),
+            (&ID_AA_CMC_UNSIGNED_DATA, "id-aa-cmc-unsignedData"),
+            (&ID_MD_2, "id-md2"),
+            (&ID_MD_5, "id-md5"),
+            (&SECT_163_K_1, "sect163k1"),
+            (&SECT_163_R_2, "sect163r2"),
+            (&SECT_283_K_1, "sect283k1"),
+            (&SECT_283_R_1, "sect283r1"),
+            (&SECT_233_K_1, "sect233k1"),
+            (&SECT_233_R_1, "sect233r1"),
+            (&SECP_224_R_1, "secp224r1"),
+            (&SECP_384_R_1, "secp384r1"),
+            (&SECP_521_R_1, "secp521r1"),
+            (&SECT_409_K_1, "sect409k1"),
+            (&SECT_409_R_1, "sect409r1"),
+            (&SECT_571_K_1, "sect571k1"),
+            (&SECT_571_R_1, "sect571r1"),
+            (&ID_EC_DH, "id-ecDH"),
+            (&ID_EC_MQV, "id-ecMQV"),
+            (&ID_SHA_1, "id-sha1"),
+            (&ID_PKIX, "id-pkix"),
+            (&ID_PE, "id-pe"),
+            (&ID_PE_AUTHORITY_INFO_ACCESS, "id-pe-authorityInfoAccess"),
+            (&ID_PE_AC_PROXYING, "id-pe-ac-proxying"),
+            (&ID_PE_SUBJECT_INFO_ACCESS, "id-pe-subjectInfoAccess"),
+            (&ID_PE_AC_AUDIT_IDENTITY, "id-pe-ac-auditIdentity"),
+            (&ID_PE_AA_CONTROLS, "id-pe-aaControls"),
+            (&ID_ACA, "id-aca"),
+            (&ID_ACA_AUTHENTICATION_INFO, "id-aca-authenticationInfo"),
+            (&ID_ACA_ACCESS_IDENTITY, "id-aca-accessIdentity"),
+            (&ID_ACA_CHARGING_IDENTITY, "id-aca-chargingIdentity"),
+            (&ID_ACA_GROUP, "id-aca-group"),
+            (&ID_ACA_ENC_ATTRS, "id-aca-encAttrs"),
+            (&ID_CCT, "id-cct"),
+            (&ID_CCT_PKI_DATA, "id-cct-PKIData"),
+            (&ID_CCT_PKI_RESPONSE, "id-cct-PKIResponse"),
+            (&ID_STC, "id-stc"),
+            (&ID_STC_BUILD_PKC_PATH, "id-stc-build-pkc-path"),
+            (&ID_STC_BUILD_VALID_PKC_PATH, "id-stc-build-valid-pkc-path"),
+            (
+                &ID_STC_BUILD_STATUS_CHECKED_PKC_PATH,
+                "id-stc-build-status-checked-pkc-path",
+            ),
+            (&ID_STC_BUILD_AA_PATH, "id-stc-build-aa-path"),
+            (&ID_STC_BUILD_VALID_AA_PATH, "id-stc-build-valid-aa-path"),
+            (
+                &ID_STC_BUILD_STATUS_CHECKED_AA_PATH,
+                "id-stc-build-status-checked-aa-path",
+            ),
+            (
+                &ID_STC_STATUS_CHECK_AC_AND_BUILD_STATUS_CHECKED_AA_PATH,
+                "id-stc-status-check-ac-and-build-status-checked-aa-path",
+            ),
+            (&ID_SWB, "id-swb"),
+            (&ID_SWB_PKC_BEST_CERT_PATH, "id-swb-pkc-best-cert-path"),
+            (&ID_SWB_PKC_CERT, "id-swb-pkc-cert"),
+            (&ID_SWB_AC_CERT, "id-swb-ac-cert"),
+            (&ID_SWB_PKC_ALL_CERT_PATHS, "id-swb-pkc-all-cert-paths"),
+            (
+                &ID_SWB_PKC_EE_REVOCATION_INFO,
+                "id-swb-pkc-ee-revocation-info",
+            ),
+            (
+                &ID_SWB_PKC_C_AS_REVOCATION_INFO,
+                "id-swb-pkc-CAs-revocation-info",
+            ),
+            (&ID_SWB_PKC_REVOCATION_INFO, "id-swb-pkc-revocation-info"),
+            (&ID_SWB_PKC_PUBLIC_KEY_INFO, "id-swb-pkc-public-key-info"),
+            (&ID_SWB_AA_CERT_PATH, "id-swb-aa-cert-path"),
+            (&ID_SWB_AA_REVOCATION_INFO, "id-swb-aa-revocation-info"),
+            (&ID_SWB_AC_REVOCATION_INFO, "id-swb-ac-revocation-info"),
+            (&ID_SWB_RELAYED_RESPONSES, "id-swb-relayed-responses"),
+            (&ID_SVP, "id-svp"),
+            (&ID_SVP_DEFAULT_VAL_POLICY, "id-svp-defaultValPolicy"),
+            (&ID_SVP_NAME_VAL_ALG, "id-svp-nameValAlg"),
+            (&ID_SVP_BASIC_VAL_ALG, "id-svp-basicValAlg"),
+            (&NAME_COMP_ALG_SET, "NameCompAlgSet"),
+            (&ID_NVA_DN_COMP_ALG, "id-nva-dnCompAlg"),
+            (&ID_QT, "id-qt"),
+            (&ID_QT_CPS, "id-qt-cps"),
+            (&ID_QT_UNOTICE, "id-qt-unotice"),
+            (&ID_KP, "id-kp"),
+            (&ID_KP_SERVER_AUTH, "id-kp-serverAuth"),
+            (&ID_KP_SCVP_SERVER, "id-kp-scvpServer"),
+            (&ID_KP_SCVP_CLIENT, "id-kp-scvpClient"),
+            (&ID_KP_CLIENT_AUTH, "id-kp-clientAuth"),
+            (&ID_KP_CODE_SIGNING, "id-kp-codeSigning"),
+            (&ID_KP_EMAIL_PROTECTION, "id-kp-emailProtection"),
+            (&ID_KP_TIME_STAMPING, "id-kp-timeStamping"),
+            (&ID_KP_OCSP_SIGNING, "id-kp-OCSPSigning"),
+            (&ID_IT, "id-it"),
+            (&ID_IT_CA_PROT_ENC_CERT, "id-it-caProtEncCert"),
+            (&ID_IT_KEY_PAIR_PARAM_REQ, "id-it-keyPairParamReq"),
+            (&ID_IT_KEY_PAIR_PARAM_REP, "id-it-keyPairParamRep"),
+            (&ID_IT_REV_PASSPHRASE, "id-it-revPassphrase"),
+            (&ID_IT_IMPLICIT_CONFIRM, "id-it-implicitConfirm"),
+            (&ID_IT_CONFIRM_WAIT_TIME, "id-it-confirmWaitTime"),
+            (&ID_IT_ORIG_PKI_MESSAGE, "id-it-origPKIMessage"),
+            (&ID_IT_SUPP_LANG_TAGS, "id-it-suppLangTags"),
+            (&ID_IT_SIGN_KEY_PAIR_TYPES, "id-it-signKeyPairTypes"),
+            (&ID_IT_ENC_KEY_PAIR_TYPES, "id-it-encKeyPairTypes"),
+            (&ID_IT_PREFERRED_SYMM_ALG, "id-it-preferredSymmAlg"),
+            (&ID_IT_CA_KEY_UPDATE_INFO, "id-it-caKeyUpdateInfo"),
+            (&ID_IT_CURRENT_CRL, "id-it-currentCRL"),
+            (&ID_IT_UNSUPPORTED_OI_DS, "id-it-unsupportedOIDs"),
+            (&ID_AD, "id-ad"),
+            (&ID_AD_OCSP, "id-ad-ocsp"),
+            (&ID_AD_CA_ISSUERS, "id-ad-caIssuers"),
+            (&ID_AD_TIME_STAMPING, "id-ad-timeStamping"),
+            (&ID_AD_CA_REPOSITORY, "id-ad-caRepository"),
+            (&ID_PKIP, "id-pkip"),
+            (&ID_REG_CTRL, "id-regCtrl"),
+            (&ID_REG_CTRL_REG_TOKEN, "id-regCtrl-regToken"),
+            (&ID_REG_CTRL_AUTHENTICATOR, "id-regCtrl-authenticator"),
+            (
+                &ID_REG_CTRL_PKI_PUBLICATION_INFO,
+                "id-regCtrl-pkiPublicationInfo",
+            ),
+            (
+                &ID_REG_CTRL_PKI_ARCHIVE_OPTIONS,
+                "id-regCtrl-pkiArchiveOptions",
+            ),
+            (&ID_REG_CTRL_OLD_CERT_ID, "id-regCtrl-oldCertID"),
+            (&ID_REG_CTRL_PROTOCOL_ENCR_KEY, "id-regCtrl-protocolEncrKey"),
+            (&ID_REG_INFO, "id-regInfo"),
+            (&ID_REG_INFO_UTF_8_PAIRS, "id-regInfo-utf8Pairs"),
+            (&ID_REG_INFO_CERT_REQ, "id-regInfo-certReq"),
+            (&ID_ALG_NO_SIGNATURE, "id-alg-noSignature"),
+            (&ID_CMC, "id-cmc"),
+            (&ID_CMC_STATUS_INFO, "id-cmc-statusInfo"),
+            (&ID_CMC_DECRYPTED_POP, "id-cmc-decryptedPOP"),
+            (&ID_CMC_LRA_POP_WITNESS, "id-cmc-lraPOPWitness"),
+            (&ID_CMC_GET_CERT, "id-cmc-getCert"),
+            (&ID_CMC_GET_CRL, "id-cmc-getCRL"),
+            (&ID_CMC_REVOKE_REQUEST, "id-cmc-revokeRequest"),
+            (&ID_CMC_REG_INFO, "id-cmc-regInfo"),
+            (&ID_CMC_RESPONSE_INFO, "id-cmc-responseInfo"),
+            (&ID_CMC_IDENTIFICATION, "id-cmc-identification"),
+            (&ID_CMC_QUERY_PENDING, "id-cmc-queryPending"),
+            (&ID_CMC_POP_LINK_RANDOM, "id-cmc-popLinkRandom"),
+            (&ID_CMC_POP_LINK_WITNESS, "id-cmc-popLinkWitness"),
+            (
+                &ID_CMC_CONFIRM_CERT_ACCEPTANCE,
+                "id-cmc-confirmCertAcceptance",
+            ),
+            (&ID_CMC_STATUS_INFO_V_2, "id-cmc-statusInfoV2"),
+            (&ID_CMC_TRUSTED_ANCHORS, "id-cmc-trustedAnchors"),
+            (&ID_CMC_AUTH_DATA, "id-cmc-authData"),
+            (&ID_CMC_BATCH_REQUESTS, "id-cmc-batchRequests"),
+            (&ID_CMC_BATCH_RESPONSES, "id-cmc-batchResponses"),
+            (&ID_CMC_IDENTITY_PROOF, "id-cmc-identityProof"),
+            (&ID_CMC_PUBLISH_CERT, "id-cmc-publishCert"),
+            (&ID_CMC_MOD_CERT_TEMPLATE, "id-cmc-modCertTemplate"),
+            (&ID_CMC_CONTROL_PROCESSED, "id-cmc-controlProcessed"),
+            (&ID_CMC_IDENTITY_PROOF_V_2, "id-cmc-identityProofV2"),
+            (&ID_CMC_POP_LINK_WITNESS_V_2, "id-cmc-popLinkWitnessV2"),
+            (&ID_CMC_DATA_RETURN, "id-cmc-dataReturn"),
+            (&ID_CMC_TRANSACTION_ID, "id-cmc-transactionId"),
+            (&ID_CMC_SENDER_NONCE, "id-cmc-senderNonce"),
+            (&ID_CMC_RECIPIENT_NONCE, "id-cmc-recipientNonce"),
+            (&ID_CMC_ADD_EXTENSIONS, "id-cmc-addExtensions"),
+            (&ID_CMC_ENCRYPTED_POP, "id-cmc-encryptedPOP"),
+            (&ID_KEY_EXCHANGE_ALGORITHM, "id-keyExchangeAlgorithm"),
+            (&ID_SHA_256, "id-sha256"),
+            (&ID_SHA_384, "id-sha384"),
+            (&ID_SHA_512, "id-sha512"),
+            (&ID_SHA_224, "id-sha224"),
+            (&DSA_WITH_SHA_224, "dsa-with-sha224"),
+            (&DSA_WITH_SHA_256, "dsa-with-sha256"),
+            (&HOLD_INSTRUCTION, "holdInstruction"),
+            (&ID_HOLDINSTRUCTION_NONE, "id-holdinstruction-none"),
+            (
+                &ID_HOLDINSTRUCTION_CALLISSUER,
+                "id-holdinstruction-callissuer",
+            ),
+            (&ID_HOLDINSTRUCTION_REJECT, "id-holdinstruction-reject"),
+            (&ID_CE, "id-ce"),
+            (&ID_CE_SUBJECT_KEY_IDENTIFIER, "id-ce-subjectKeyIdentifier"),
+            (&ID_CE_KEY_USAGE, "id-ce-keyUsage"),
+            (
+                &ID_CE_PRIVATE_KEY_USAGE_PERIOD,
+                "id-ce-privateKeyUsagePeriod",
+            ),
+            (&ID_CE_SUBJECT_ALT_NAME, "id-ce-subjectAltName"),
+            (&ID_CE_ISSUER_ALT_NAME, "id-ce-issuerAltName"),
+            (&ID_CE_BASIC_CONSTRAINTS, "id-ce-basicConstraints"),
+            (&ID_CE_CRL_NUMBER, "id-ce-cRLNumber"),
+            (&ID_CE_CRL_REASONS, "id-ce-cRLReasons"),
+            (&ID_CE_HOLD_INSTRUCTION_CODE, "id-ce-holdInstructionCode"),
+            (&ID_CE_INVALIDITY_DATE, "id-ce-invalidityDate"),
+            (&ID_CE_DELTA_CRL_INDICATOR, "id-ce-deltaCRLIndicator"),
+            (
+                &ID_CE_ISSUING_DISTRIBUTION_POINT,
+                "id-ce-issuingDistributionPoint",
+            ),
+            (&ID_CE_CERTIFICATE_ISSUER, "id-ce-certificateIssuer"),
+            (&ID_CE_NAME_CONSTRAINTS, "id-ce-nameConstraints"),
+            (
+                &ID_CE_CRL_DISTRIBUTION_POINTS,
+                "id-ce-cRLDistributionPoints",
+            ),
+            (&ID_CE_CERTIFICATE_POLICIES, "id-ce-certificatePolicies"),
+            (&ID_CE_POLICY_MAPPINGS, "id-ce-policyMappings"),
+            (
+                &ID_CE_AUTHORITY_KEY_IDENTIFIER,
+                "id-ce-authorityKeyIdentifier",
+            ),
+            (&ID_CE_POLICY_CONSTRAINTS, "id-ce-policyConstraints"),
+            (&ID_CE_EXT_KEY_USAGE, "id-ce-extKeyUsage"),
+            (&ANY_EXTENDED_KEY_USAGE, "anyExtendedKeyUsage"),
+            (&ID_CE_FRESHEST_CRL, "id-ce-freshestCRL"),
+            (&ID_CE_INHIBIT_ANY_POLICY, "id-ce-inhibitAnyPolicy"),
+            (&ID_CE_TARGET_INFORMATION, "id-ce-targetInformation"),
+            (&ID_CE_NO_REV_AVAIL, "id-ce-noRevAvail"),
+            (
+                &ID_CE_SUBJECT_DIRECTORY_ATTRIBUTES,
+                "id-ce-subjectDirectoryAttributes",
+            ),
+            (&ID_AT, "id-at"),
+            (&ID_AT_ROLE, "id-at-role"),
+        ],
+        by_name: crate::db::Index {
+            seeds: &[
+                26, 89, 176, 131, 2, 12, 3, 6, 1, 53, 19, 1, 41, 113, 110, 1, 75, 51, 1, 4, 10,
+                1048, 44, 61, 60, 0, 5825, 3, 1, 1, 120, 81, 23, 12, 28, 7, 71, 123, 19, 5536, 103,
+                0, 0, 824, 0, 3, 24, 13, 97, 188, 1406, 1431, 1159,
+            ],
+            slots: &[
+                175, 136, 63, 156, 135, 110, 185, 182, 134, 153, 13, 52, 99, 69, 163, 143, 32, 168,
+                150, 145, 191, 144, 36, 19, 197, 53, 12, 66, 61, 186, 109, 121, 25, 207, 169, 181,
+                159, 151, 98, 198, 3, 154, 138, 71, 45, 76, 1, 83, 179, 113, 42, 190, 0, 7, 173, 2,
+                105, 29, 28, 205, 111, 48, 115, 51, 64, 133, 55, 132, 50, 114, 125, 37, 65, 73,
+                210, 59, 202, 96, 131, 157, 18, 54, 94, 10, 97, 208, 74, 62, 47, 4, 187, 67, 77,
+                120, 176, 33, 85, 21, 177, 122, 102, 30, 152, 161, 103, 49, 17, 130, 147, 8, 31,
+                194, 68, 46, 155, 91, 174, 44, 199, 195, 26, 20, 5, 90, 189, 117, 101, 60, 72, 165,
+                204, 80, 116, 100, 148, 188, 200, 87, 124, 78, 41, 118, 127, 86, 171, 6, 162, 14,
+                139, 89, 140, 93, 27, 58, 119, 56, 34, 39, 22, 24, 129, 112, 167, 108, 81, 92, 209,
+                158, 15, 137, 16, 40, 178, 172, 123, 164, 79, 170, 149, 106, 146, 142, 75, 192, 88,
+                104, 84, 166, 141, 126, 180, 203, 107, 183, 35, 9, 206, 23, 11, 82, 201, 38, 43,
+                70, 193, 95, 196, 128, 160, 184, 57,
+            ],
+            fold_case: true,
+        },
+        by_oid: crate::db::Index {
+            seeds: &[
+                10, 13, 64, 1, 27, 61, 7, 2, 44, 111, 7, 2, 3, 7, 28, 134, 3, 22, 87, 29, 8, 112,
+                22, 112, 45, 0, 30, 7, 30, 59, 343, 49, 1, 191, 7, 29, 81, 25, 1, 169, 268, 12, 23,
+                157, 2009, 21, 311, 247, 177, 2, 41, 5, 692,
+            ],
+            slots: &[
+                154, 10, 141, 38, 206, 143, 87, 83, 178, 150, 19, 76, 140, 9, 128, 134, 70, 179,
+                40, 55, 57, 29, 50, 14, 52, 23, 195, 184, 36, 6, 208, 142, 125, 113, 108, 82, 42,
+                18, 144, 67, 7, 124, 148, 73, 183, 176, 91, 20, 149, 62, 186, 181, 26, 133, 151,
+                81, 86, 153, 74, 90, 48, 31, 72, 189, 100, 190, 54, 158, 116, 79, 173, 13, 32, 163,
+                66, 68, 30, 175, 138, 162, 109, 174, 207, 5, 118, 44, 8, 110, 28, 88, 197, 157,
+                203, 164, 2, 196, 35, 193, 80, 123, 172, 0, 127, 198, 177, 147, 155, 1, 71, 3, 194,
+                112, 165, 159, 56, 167, 105, 120, 131, 139, 202, 61, 47, 200, 152, 41, 171, 22, 99,
+                117, 53, 209, 191, 21, 39, 27, 51, 58, 85, 89, 169, 34, 101, 121, 137, 135, 205,
+                65, 104, 187, 180, 122, 43, 156, 94, 77, 64, 92, 84, 75, 199, 78, 210, 93, 46, 126,
+                111, 201, 4, 16, 59, 69, 136, 192, 15, 33, 97, 188, 63, 130, 60, 204, 145, 24, 115,
+                114, 102, 146, 17, 107, 12, 160, 98, 182, 25, 129, 170, 106, 161, 96, 45, 166, 11,
+                185, 168, 37, 49, 132, 103, 119,
+            ],
+            fold_case: false,
+        },
+    }]);
 }
+#[cfg(feature = "db-rfc6109")]
 pub mod rfc6109 {
     pub const LDIF_LOCATION_URL_OBJECT: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.4.1.16572.2.1.1");
@@ -1989,7 +3908,31 @@ pub mod rfc6109 {
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.4.1.16572.2.2.6");
     pub const PROVIDER_UNIT: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.4.1.16572.2.2.7");
+    pub const DB: crate::db::Database<'static> = crate::db::Database(&[crate::db::Table {
+        entries: &[
+            (&LDIF_LOCATION_URL_OBJECT, "LDIFLocationURLObject"),
+            (&PROVIDER, "provider"),
+            (&PROVIDER_CERTIFICATE_HASH, "providerCertificateHash"),
+            (&PROVIDER_CERTIFICATE, "providerCertificate"),
+            (&PROVIDER_NAME, "providerName"),
+            (&MAIL_RECEIPT, "mailReceipt"),
+            (&MANAGED_DOMAINS, "managedDomains"),
+            (&LDIF_LOCATION_URL, "LDIFLocationURL"),
+            (&PROVIDER_UNIT, "providerUnit"),
+        ],
+        by_name: crate::db::Index {
+            seeds: &[8, 10, 1],
+            slots: &[8, 7, 0, 5, 2, 3, 4, 1, 6],
+            fold_case: true,
+        },
+        by_oid: crate::db::Index {
+            seeds: &[16, 1, 20],
+            slots: &[3, 5, 2, 0, 8, 1, 6, 4, 7],
+            fold_case: false,
+        },
+    }]);
 }
+#[cfg(feature = "db-rfc6268")]
 pub mod rfc6268 {
     pub const RSADSI: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.2.840.113549");
@@ -2039,7 +3982,52 @@ pub mod rfc6268 {
         crate::ObjectIdentifier::new_unwrap("1.2.840.113549.2.8");
     pub const ID_HMAC_WITH_SHA_256: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.2.840.113549.2.9");
+    pub const DB: crate::db::Database<'static> = crate::db::Database(&[crate::db::Table {
+        entries: &[
+            (&RSADSI, "rsadsi"),
+            (&ID_DATA, "id-data"),
+            (&ID_SIGNED_DATA, "id-signedData"),
+            (&ID_ENVELOPED_DATA, "id-envelopedData"),
+            (&ID_DIGESTED_DATA, "id-digestedData"),
+            (&ID_ENCRYPTED_DATA, "id-encryptedData"),
+            (&ID_CT_CONTENT_COLLECTION, "id-ct-contentCollection"),
+            (&ID_CT_AUTH_DATA, "id-ct-authData"),
+            (&ID_CT_CONTENT_WITH_ATTRS, "id-ct-contentWithAttrs"),
+            (&ID_CT_AUTH_ENVELOPED_DATA, "id-ct-authEnvelopedData"),
+            (&ID_CT_CONTENT_INFO, "id-ct-contentInfo"),
+            (&ID_CT_COMPRESSED_DATA, "id-ct-compressedData"),
+            (&ID_AA_BINARY_SIGNING_TIME, "id-aa-binarySigningTime"),
+            (&ID_ALG_ZLIB_COMPRESS, "id-alg-zlibCompress"),
+            (&ID_AA_MULTIPLE_SIGNATURES, "id-aa-multipleSignatures"),
+            (&ID_CONTENT_TYPE, "id-contentType"),
+            (&ID_MESSAGE_DIGEST, "id-messageDigest"),
+            (&ID_SIGNING_TIME, "id-signingTime"),
+            (&ID_COUNTERSIGNATURE, "id-countersignature"),
+            (&DIGEST_ALGORITHM, "digestAlgorithm"),
+            (&ID_HMAC_WITH_SHA_384, "id-hmacWithSHA384"),
+            (&ID_HMAC_WITH_SHA_512, "id-hmacWithSHA512"),
+            (&ID_HMAC_WITH_SHA_224, "id-hmacWithSHA224"),
+            (&ID_HMAC_WITH_SHA_256, "id-hmacWithSHA256"),
+        ],
+        by_name: crate::db::Index {
+            seeds: &[5, 58, 101, 789, 35, 34],
+            slots: &[
+                21, 6, 19, 1, 22, 9, 5, 12, 16, 23, 10, 18, 13, 3, 14, 4, 2, 15, 11, 7, 20, 8, 0,
+                17,
+            ],
+            fold_case: true,
+        },
+        by_oid: crate::db::Index {
+            seeds: &[402, 95, 1, 1, 136, 16],
+            slots: &[
+                15, 4, 12, 7, 0, 9, 14, 18, 17, 2, 20, 19, 23, 22, 11, 6, 5, 8, 3, 10, 1, 16, 21,
+                13,
+            ],
+            fold_case: false,
+        },
+    }]);
 }
+#[cfg(feature = "db-rfc6960")]
 pub mod rfc6960 {
     pub const ID_PKIX_OCSP: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.5.5.7.48.1");
@@ -2061,7 +4049,38 @@ pub mod rfc6960 {
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.5.5.7.48.1.8");
     pub const ID_PKIX_OCSP_EXTENDED_REVOKE: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.5.5.7.48.1.9");
+    pub const DB: crate::db::Database<'static> = crate::db::Database(&[crate::db::Table {
+        entries: &[
+            (&ID_PKIX_OCSP, "id-pkix-ocsp"),
+            (&ID_PKIX_OCSP_BASIC, "id-pkix-ocsp-basic"),
+            (&ID_PKIX_OCSP_NONCE, "id-pkix-ocsp-nonce"),
+            (&ID_PKIX_OCSP_CRL, "id-pkix-ocsp-crl"),
+            (&ID_PKIX_OCSP_RESPONSE, "id-pkix-ocsp-response"),
+            (&ID_PKIX_OCSP_NOCHECK, "id-pkix-ocsp-nocheck"),
+            (&ID_PKIX_OCSP_ARCHIVE_CUTOFF, "id-pkix-ocsp-archive-cutoff"),
+            (
+                &ID_PKIX_OCSP_SERVICE_LOCATOR,
+                "id-pkix-ocsp-service-locator",
+            ),
+            (&ID_PKIX_OCSP_PREF_SIG_ALGS, "id-pkix-ocsp-pref-sig-algs"),
+            (
+                &ID_PKIX_OCSP_EXTENDED_REVOKE,
+                "id-pkix-ocsp-extended-revoke",
+            ),
+        ],
+        by_name: crate::db::Index {
+            seeds: &[18, 1, 135],
+            slots: &[1, 3, 0, 8, 7, 4, 2, 6, 9, 5],
+            fold_case: true,
+        },
+        by_oid: crate::db::Index {
+            seeds: &[1, 11, 77],
+            slots: &[0, 5, 3, 1, 9, 8, 6, 7, 4, 2],
+            fold_case: false,
+        },
+    }]);
 }
+#[cfg(feature = "db-rfc6962")]
 pub mod rfc6962 {
     pub const GOOGLE: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.4.1.11129");
@@ -2071,7 +4090,26 @@ pub mod rfc6962 {
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.4.1.11129.2.4.3");
     pub const CT_PRECERT_SIGNING_CERT: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.4.1.11129.2.4.4");
+    pub const DB: crate::db::Database<'static> = crate::db::Database(&[crate::db::Table {
+        entries: &[
+            (&GOOGLE, "google"),
+            (&CT_PRECERT_SCTS, "ct-precert-scts"),
+            (&CT_PRECERT_POISON, "ct-precert-poison"),
+            (&CT_PRECERT_SIGNING_CERT, "ct-precert-signing-cert"),
+        ],
+        by_name: crate::db::Index {
+            seeds: &[18],
+            slots: &[1, 3, 2, 0],
+            fold_case: true,
+        },
+        by_oid: crate::db::Index {
+            seeds: &[1],
+            slots: &[0, 3, 2, 1],
+            fold_case: false,
+        },
+    }]);
 }
+#[cfg(feature = "db-rfc7107")]
 pub mod rfc7107 {
     pub const ID_SMIME: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.16");
@@ -2101,7 +4139,36 @@ pub mod rfc7107 {
         crate::ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.16.8");
     pub const ID_STI: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.16.9");
+    pub const DB: crate::db::Database<'static> = crate::db::Database(&[crate::db::Table {
+        entries: &[
+            (&ID_SMIME, "id-smime"),
+            (&ID_MOD, "id-mod"),
+            (&ID_CT, "id-ct"),
+            (&ID_EIT, "id-eit"),
+            (&ID_CAP, "id-cap"),
+            (&ID_PSKC, "id-pskc"),
+            (&ID_AA, "id-aa"),
+            (&ID_ALG, "id-alg"),
+            (&ID_CD, "id-cd"),
+            (&ID_SPQ, "id-spq"),
+            (&ID_CTI, "id-cti"),
+            (&ID_TSP, "id-tsp"),
+            (&ID_SKD, "id-skd"),
+            (&ID_STI, "id-sti"),
+        ],
+        by_name: crate::db::Index {
+            seeds: &[6, 1, 9, 167],
+            slots: &[2, 12, 5, 8, 3, 11, 9, 0, 13, 7, 1, 6, 4, 10],
+            fold_case: true,
+        },
+        by_oid: crate::db::Index {
+            seeds: &[63, 1, 2, 86],
+            slots: &[9, 7, 10, 13, 6, 3, 5, 8, 0, 2, 11, 12, 4, 1],
+            fold_case: false,
+        },
+    }]);
 }
+#[cfg(feature = "db-rfc7299")]
 pub mod rfc7299 {
     pub const ID_PKIX: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.5.5.7");
@@ -2169,7 +4236,61 @@ pub mod rfc7299 {
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.5.5.7.8");
     pub const ID_PDA: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.5.5.7.9");
+    pub const DB: crate::db::Database<'static> = crate::db::Database(&[crate::db::Table {
+        entries: &[
+            (&ID_PKIX, "id-pkix"),
+            (&ID_MOD, "id-mod"),
+            (&ID_PE, "id-pe"),
+            (&ID_ACA, "id-aca"),
+            (&ID_QCS, "id-qcs"),
+            (&ID_CCT, "id-cct"),
+            (&ID_TEST, "id-TEST"),
+            (&ID_CP, "id-cp"),
+            (&ID_CET, "id-cet"),
+            (&ID_RI, "id-ri"),
+            (&ID_SCT, "id-sct"),
+            (&ID_SWB, "id-swb"),
+            (&ID_SVP, "id-svp"),
+            (&ID_NVAE, "id-nvae"),
+            (&ID_BVAE, "id-bvae"),
+            (&ID_DNVAE, "id-dnvae"),
+            (&ID_QT, "id-qt"),
+            (&ID_LOGO, "id-logo"),
+            (&ID_PPL, "id-ppl"),
+            (&ID_MR, "id-mr"),
+            (&ID_SKIS, "id-skis"),
+            (&ID_KP, "id-kp"),
+            (&ID_IT, "id-it"),
+            (&ID_AD, "id-ad"),
+            (&ID_PKIX_OCSP, "id-pkix-ocsp"),
+            (&ID_PKIP, "id-pkip"),
+            (&ID_REG_CTRL, "id-regCtrl"),
+            (&ID_REG_INFO, "id-regInfo"),
+            (&ID_ALG, "id-alg"),
+            (&ID_CMC, "id-cmc"),
+            (&ID_CMC_GLA_RR, "id-cmc-glaRR"),
+            (&ID_ON, "id-on"),
+            (&ID_PDA, "id-pda"),
+        ],
+        by_name: crate::db::Index {
+            seeds: &[45, 26, 892, 1, 6, 1, 6, 8, 29],
+            slots: &[
+                16, 19, 11, 20, 2, 12, 32, 21, 10, 9, 30, 8, 5, 22, 15, 28, 18, 23, 3, 31, 25, 17,
+                29, 14, 0, 4, 26, 6, 1, 13, 27, 24, 7,
+            ],
+            fold_case: true,
+        },
+        by_oid: crate::db::Index {
+            seeds: &[1, 104, 4, 8, 4, 230, 136, 12, 35],
+            slots: &[
+                19, 22, 26, 30, 18, 8, 20, 15, 24, 23, 1, 11, 5, 13, 21, 7, 29, 0, 4, 25, 14, 12,
+                28, 17, 27, 6, 32, 10, 16, 31, 3, 2, 9,
+            ],
+            fold_case: false,
+        },
+    }]);
 }
+#[cfg(feature = "db-rfc7532")]
 pub mod rfc7532 {
     pub const FEDFS_UUID: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.4.1.31103.1.1");
@@ -2251,7 +4372,68 @@ pub mod rfc7532 {
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.4.1.31103.1.8");
     pub const FEDFS_FSL_HOST: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.4.1.31103.1.9");
+    pub const DB: crate::db::Database<'static> = crate::db::Database(&[crate::db::Table {
+        entries: &[
+            (&FEDFS_UUID, "fedfsUuid"),
+            (&FEDFS_FSL_PORT, "fedfsFslPort"),
+            (&FEDFS_NFS_PATH, "fedfsNfsPath"),
+            (&FEDFS_NSDB_CONTAINER_INFO, "fedfsNsdbContainerInfo"),
+            (&FEDFS_FSN, "fedfsFsn"),
+            (&FEDFS_FSL, "fedfsFsl"),
+            (&FEDFS_NFS_FSL, "fedfsNfsFsl"),
+            (&FEDFS_NFS_MAJOR_VER, "fedfsNfsMajorVer"),
+            (&FEDFS_NFS_MINOR_VER, "fedfsNfsMinorVer"),
+            (&FEDFS_NFS_CURRENCY, "fedfsNfsCurrency"),
+            (&FEDFS_NFS_GEN_FLAG_WRITABLE, "fedfsNfsGenFlagWritable"),
+            (&FEDFS_NFS_GEN_FLAG_GOING, "fedfsNfsGenFlagGoing"),
+            (&FEDFS_NFS_GEN_FLAG_SPLIT, "fedfsNfsGenFlagSplit"),
+            (&FEDFS_NFS_TRANS_FLAG_RDMA, "fedfsNfsTransFlagRdma"),
+            (&FEDFS_NFS_CLASS_SIMUL, "fedfsNfsClassSimul"),
+            (&FEDFS_NFS_CLASS_HANDLE, "fedfsNfsClassHandle"),
+            (&FEDFS_FSL_TTL, "fedfsFslTTL"),
+            (&FEDFS_NFS_CLASS_FILEID, "fedfsNfsClassFileid"),
+            (&FEDFS_NFS_CLASS_WRITEVER, "fedfsNfsClassWritever"),
+            (&FEDFS_NFS_CLASS_CHANGE, "fedfsNfsClassChange"),
+            (&FEDFS_NFS_CLASS_READDIR, "fedfsNfsClassReaddir"),
+            (&FEDFS_NFS_READ_RANK, "fedfsNfsReadRank"),
+            (&FEDFS_NFS_READ_ORDER, "fedfsNfsReadOrder"),
+            (&FEDFS_NFS_WRITE_RANK, "fedfsNfsWriteRank"),
+            (&FEDFS_NFS_WRITE_ORDER, "fedfsNfsWriteOrder"),
+            (&FEDFS_NFS_VAR_SUB, "fedfsNfsVarSub"),
+            (&FEDFS_NFS_VALID_FOR, "fedfsNfsValidFor"),
+            (&FEDFS_ANNOTATION, "fedfsAnnotation"),
+            (&FEDFS_NFS_URI, "fedfsNfsURI"),
+            (&FEDFS_DESCR, "fedfsDescr"),
+            (&FEDFS_NCE_DN, "fedfsNceDN"),
+            (&FEDFS_FSN_TTL, "fedfsFsnTTL"),
+            (&FEDFS_NET_ADDR, "fedfsNetAddr"),
+            (&FEDFS_NET_PORT, "fedfsNetPort"),
+            (&FEDFS_FSN_UUID, "fedfsFsnUuid"),
+            (&FEDFS_NSDB_NAME, "fedfsNsdbName"),
+            (&FEDFS_NSDB_PORT, "fedfsNsdbPort"),
+            (&FEDFS_NCE_PREFIX, "fedfsNcePrefix"),
+            (&FEDFS_FSL_UUID, "fedfsFslUuid"),
+            (&FEDFS_FSL_HOST, "fedfsFslHost"),
+        ],
+        by_name: crate::db::Index {
+            seeds: &[179, 5, 3, 422, 48, 11, 3, 1, 2, 208],
+            slots: &[
+                22, 11, 26, 2, 24, 16, 29, 13, 35, 38, 39, 17, 33, 7, 23, 9, 12, 36, 25, 34, 18, 6,
+                10, 14, 4, 5, 8, 32, 27, 0, 20, 15, 21, 1, 31, 37, 19, 28, 3, 30,
+            ],
+            fold_case: true,
+        },
+        by_oid: crate::db::Index {
+            seeds: &[10, 3, 34, 67, 3, 19, 229, 72, 104, 2651],
+            slots: &[
+                15, 30, 7, 31, 32, 17, 37, 5, 22, 29, 0, 3, 39, 4, 12, 9, 6, 2, 33, 1, 27, 16, 11,
+                18, 35, 38, 23, 19, 21, 24, 25, 10, 26, 20, 28, 34, 8, 36, 13, 14,
+            ],
+            fold_case: false,
+        },
+    }]);
 }
+#[cfg(feature = "db-rfc7612")]
 pub mod rfc7612 {
     pub const PRINTER_DEVICE_ID: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.3.18.0.2.24.46.1.101");
@@ -2267,12 +4449,54 @@ pub mod rfc7612 {
         crate::ObjectIdentifier::new_unwrap("1.3.18.0.2.24.46.1.107");
     pub const PRINTER_IPP_FEATURES_SUPPORTED: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.3.18.0.2.24.46.1.108");
+    pub const DB: crate::db::Database<'static> = crate::db::Database(&[crate::db::Table {
+        entries: &[
+            (&PRINTER_DEVICE_ID, "printer-device-id"),
+            (
+                &PRINTER_DEVICE_SERVICE_COUNT,
+                "printer-device-service-count",
+            ),
+            (&PRINTER_UUID, "printer-uuid"),
+            (&PRINTER_CHARGE_INFO, "printer-charge-info"),
+            (&PRINTER_CHARGE_INFO_URI, "printer-charge-info-uri"),
+            (&PRINTER_GEO_LOCATION, "printer-geo-location"),
+            (
+                &PRINTER_IPP_FEATURES_SUPPORTED,
+                "printer-ipp-features-supported",
+            ),
+        ],
+        by_name: crate::db::Index {
+            seeds: &[112, 2],
+            slots: &[4, 5, 6, 1, 2, 3, 0],
+            fold_case: true,
+        },
+        by_oid: crate::db::Index {
+            seeds: &[17, 1],
+            slots: &[3, 5, 6, 2, 4, 1, 0],
+            fold_case: false,
+        },
+    }]);
 }
+#[cfg(feature = "db-rfc8284")]
 pub mod rfc8284 {
     pub const JID_OBJECT: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.3.6.1.1.23.1");
     pub const JID: crate::ObjectIdentifier = crate::ObjectIdentifier::new_unwrap("1.3.6.1.1.23.2");
+    pub const DB: crate::db::Database<'static> = crate::db::Database(&[crate::db::Table {
+        entries: &[(&JID_OBJECT, "JIDObject"), (&JID, "jid")],
+        by_name: crate::db::Index {
+            seeds: &[2],
+            slots: &[1, 0],
+            fold_case: true,
+        },
+        by_oid: crate::db::Index {
+            seeds: &[1],
+            slots: &[1, 0],
+            fold_case: false,
+        },
+    }]);
 }
+#[cfg(feature = "db-rfc8410")]
 pub mod rfc8410 {
     pub const ID_EDWARDS_CURVE_ALGS: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.3.101");
@@ -2284,7 +4508,27 @@ pub mod rfc8410 {
         crate::ObjectIdentifier::new_unwrap("1.3.101.112");
     pub const ID_ED_448: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("1.3.101.113");
+    pub const DB: crate::db::Database<'static> = crate::db::Database(&[crate::db::Table {
+        entries: &[
+            (&ID_EDWARDS_CURVE_ALGS, "id-edwards-curve-algs"),
+            (&ID_X_25519, "id-X25519"),
+            (&ID_X_448, "id-X448"),
+            (&ID_ED_25519, "id-Ed25519"),
+            (&ID_ED_448, "id-Ed448"),
+        ],
+        by_name: crate::db::Index {
+            seeds: &[1, 48],
+            slots: &[2, 0, 4, 1, 3],
+            fold_case: true,
+        },
+        by_oid: crate::db::Index {
+            seeds: &[0, 59],
+            slots: &[0, 3, 4, 2, 1],
+            fold_case: false,
+        },
+    }]);
 }
+#[cfg(feature = "db-rfc8894")]
 pub mod rfc8894 {
     pub const ID_VERI_SIGN: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("2.16.840.1.113733");
@@ -2304,1971 +4548,117 @@ pub mod rfc8894 {
         crate::ObjectIdentifier::new_unwrap("2.16.840.1.113733.1.9.6");
     pub const ID_TRANSACTION_ID: crate::ObjectIdentifier =
         crate::ObjectIdentifier::new_unwrap("2.16.840.1.113733.1.9.7");
+    pub const DB: crate::db::Database<'static> = crate::db::Database(&[crate::db::Table {
+        entries: &[
+            (&ID_VERI_SIGN, "id-VeriSign"),
+            (&ID_PKI, "id-pki"),
+            (&ID_ATTRIBUTES, "id-attributes"),
+            (&ID_MESSAGE_TYPE, "id-messageType"),
+            (&ID_PKI_STATUS, "id-pkiStatus"),
+            (&ID_FAIL_INFO, "id-failInfo"),
+            (&ID_SENDER_NONCE, "id-senderNonce"),
+            (&ID_RECIPIENT_NONCE, "id-recipientNonce"),
+            (&ID_TRANSACTION_ID, "id-transactionID"),
+        ],
+        by_name: crate::db::Index {
+            seeds: &[0, 2, 31],
+            slots: &[0, 2, 6, 1, 3, 5, 7, 8, 4],
+            fold_case: true,
+        },
+        by_oid: crate::db::Index {
+            seeds: &[3, 5, 1],
+            slots: &[6, 4, 7, 3, 0, 8, 5, 1, 2],
+            fold_case: false,
+        },
+    }]);
 }
 pub const DB: super::Database<'static> = super::Database(&[
-    (&fips202::NIST_ALGORITHMS, "nistAlgorithms"),
-    (&fips202::HASH_ALGS, "hashAlgs"),
-    (&fips202::ID_SHA_3_512, "id-sha3-512"),
-    (&fips202::ID_SHAKE_128, "id-shake128"),
-    (&fips202::ID_SHAKE_256, "id-shake256"),
-    (&fips202::ID_SHA_3_224, "id-sha3-224"),
-    (&fips202::ID_SHA_3_256, "id-sha3-256"),
-    (&fips202::ID_SHA_3_384, "id-sha3-384"),
-    (&rfc1274::TEXT_ENCODED_OR_ADDRESS, "textEncodedORAddress"),
-    (&rfc1274::OTHER_MAILBOX, "otherMailbox"),
-    (&rfc1274::LAST_MODIFIED_TIME, "lastModifiedTime"),
-    (&rfc1274::LAST_MODIFIED_BY, "lastModifiedBy"),
-    (&rfc1274::A_RECORD, "aRecord"),
-    (&rfc1274::MD_RECORD, "mDRecord"),
-    (&rfc1274::MX_RECORD, "mXRecord"),
-    (&rfc1274::NS_RECORD, "nSRecord"),
-    (&rfc1274::SOA_RECORD, "sOARecord"),
-    (&rfc1274::CNAME_RECORD, "cNAMERecord"),
-    (&rfc1274::JANET_MAILBOX, "janetMailbox"),
-    (&rfc1274::MAIL_PREFERENCE_OPTION, "mailPreferenceOption"),
-    (&rfc1274::DSA_QUALITY, "dSAQuality"),
-    (&rfc1274::SUBTREE_MINIMUM_QUALITY, "subtreeMinimumQuality"),
-    (&rfc1274::SUBTREE_MAXIMUM_QUALITY, "subtreeMaximumQuality"),
-    (&rfc1274::PERSONAL_SIGNATURE, "personalSignature"),
-    (&rfc1274::DIT_REDIRECT, "dITRedirect"),
-    (&rfc1274::AUDIO, "audio"),
-    (&rfc1274::PHOTO, "photo"),
-    (&rfc1274::DNS_DOMAIN, "dNSDomain"),
-    (&rfc1274::PILOT_ORGANIZATION, "pilotOrganization"),
-    (&rfc1274::PILOT_DSA, "pilotDSA"),
-    (&rfc1274::QUALITY_LABELLED_DATA, "qualityLabelledData"),
-    (&rfc1274::PILOT_OBJECT, "pilotObject"),
-    (&rfc1274::PILOT_PERSON, "pilotPerson"),
-    (&rfc2079::LABELED_URI, "labeledURI"),
-    (&rfc2079::LABELED_URI_OBJECT, "labeledURIObject"),
-    (&rfc2164::RFC_822_TO_X_400_MAPPING, "rFC822ToX400Mapping"),
-    (&rfc2164::X_400_TO_RFC_822_MAPPING, "x400ToRFC822Mapping"),
-    (
-        &rfc2164::OMITTED_OR_ADDRESS_COMPONENT,
-        "omittedORAddressComponent",
-    ),
-    (&rfc2164::MIXER_GATEWAY, "mixerGateway"),
-    (&rfc2164::ASSOCIATED_X_400_GATEWAY, "associatedX400Gateway"),
-    (&rfc2164::ASSOCIATED_OR_ADDRESS, "associatedORAddress"),
-    (
-        &rfc2164::OR_ADDRESS_COMPONENT_TYPE,
-        "oRAddressComponentType",
-    ),
-    (
-        &rfc2164::ASSOCIATED_INTERNET_GATEWAY,
-        "associatedInternetGateway",
-    ),
-    (&rfc2164::MCGAM_TABLES, "mcgamTables"),
-    (&rfc2247::DOMAIN_NAME_FORM, "domainNameForm"),
-    (
-        &rfc2252::PRESENTATION_ADDRESS_MATCH,
-        "presentationAddressMatch",
-    ),
-    (
-        &rfc2252::PROTOCOL_INFORMATION_MATCH,
-        "protocolInformationMatch",
-    ),
-    (&rfc2256::KNOWLEDGE_INFORMATION, "knowledgeInformation"),
-    (&rfc2256::PRESENTATION_ADDRESS, "presentationAddress"),
-    (
-        &rfc2256::SUPPORTED_APPLICATION_CONTEXT,
-        "supportedApplicationContext",
-    ),
-    (&rfc2256::PROTOCOL_INFORMATION, "protocolInformation"),
-    (&rfc2256::DMD_NAME, "dmdName"),
-    (&rfc2256::STATE_OR_PROVINCE_NAME, "stateOrProvinceName"),
-    (&rfc2256::STREET_ADDRESS, "streetAddress"),
-    (&rfc2256::APPLICATION_ENTITY, "applicationEntity"),
-    (&rfc2256::DSA, "dSA"),
-    (&rfc2256::DMD, "dmd"),
-    (&rfc2293::SUBTREE, "subtree"),
-    (&rfc2293::TABLE, "table"),
-    (&rfc2293::TABLE_ENTRY, "tableEntry"),
-    (&rfc2293::TEXT_TABLE_ENTRY, "textTableEntry"),
-    (
-        &rfc2293::DISTINGUISHED_NAME_TABLE_ENTRY,
-        "distinguishedNameTableEntry",
-    ),
-    (&rfc2293::TEXT_TABLE_KEY, "textTableKey"),
-    (&rfc2293::TEXT_TABLE_VALUE, "textTableValue"),
-    (
-        &rfc2293::DISTINGUISHED_NAME_TABLE_KEY,
-        "distinguishedNameTableKey",
-    ),
-    (&rfc2589::DYNAMIC_OBJECT, "dynamicObject"),
-    (&rfc2589::ENTRY_TTL, "entryTtl"),
-    (&rfc2589::DYNAMIC_SUBTREES, "dynamicSubtrees"),
-    (&rfc2739::CAL_CAL_URI, "calCalURI"),
-    (&rfc2739::CAL_FBURL, "calFBURL"),
-    (&rfc2739::CAL_CAPURI, "calCAPURI"),
-    (&rfc2739::CAL_CAL_ADR_URI, "calCalAdrURI"),
-    (&rfc2739::CAL_OTHER_CAL_UR_IS, "calOtherCalURIs"),
-    (&rfc2739::CAL_OTHER_FBUR_LS, "calOtherFBURLs"),
-    (&rfc2739::CAL_OTHER_CAPUR_IS, "calOtherCAPURIs"),
-    (&rfc2739::CAL_OTHER_CAL_ADR_UR_IS, "calOtherCalAdrURIs"),
-    (&rfc2739::CAL_ENTRY, "calEntry"),
-    (&rfc2798::JPEG_PHOTO, "jpegPhoto"),
-    (&rfc2798::CAR_LICENSE, "carLicense"),
-    (&rfc2798::DEPARTMENT_NUMBER, "departmentNumber"),
-    (&rfc2798::USER_PKCS_12, "userPKCS12"),
-    (&rfc2798::DISPLAY_NAME, "displayName"),
-    (&rfc2798::EMPLOYEE_NUMBER, "employeeNumber"),
-    (&rfc2798::PREFERRED_LANGUAGE, "preferredLanguage"),
-    (&rfc2798::EMPLOYEE_TYPE, "employeeType"),
-    (&rfc2798::USER_SMIME_CERTIFICATE, "userSMIMECertificate"),
-    (&rfc2798::INET_ORG_PERSON, "inetOrgPerson"),
-    (&rfc3280::EMAIL, "email"),
-    (&rfc3280::EMAIL_ADDRESS, "emailAddress"),
-    (&rfc3280::PSEUDONYM, "pseudonym"),
-    (&rfc3296::REF, "ref"),
-    (&rfc3296::REFERRAL, "referral"),
-    (
-        &rfc3671::COLLECTIVE_ATTRIBUTE_SUBENTRIES,
-        "collectiveAttributeSubentries",
-    ),
-    (&rfc3671::COLLECTIVE_EXCLUSIONS, "collectiveExclusions"),
-    (
-        &rfc3671::COLLECTIVE_ATTRIBUTE_SUBENTRY,
-        "collectiveAttributeSubentry",
-    ),
-    (&rfc3671::C_O, "c-o"),
-    (&rfc3671::C_OU, "c-ou"),
-    (&rfc3671::C_POSTAL_ADDRESS, "c-PostalAddress"),
-    (&rfc3671::C_POSTAL_CODE, "c-PostalCode"),
-    (&rfc3671::C_POST_OFFICE_BOX, "c-PostOfficeBox"),
-    (
-        &rfc3671::C_PHYSICAL_DELIVERY_OFFICE,
-        "c-PhysicalDeliveryOffice",
-    ),
-    (&rfc3671::C_TELEPHONE_NUMBER, "c-TelephoneNumber"),
-    (&rfc3671::C_TELEX_NUMBER, "c-TelexNumber"),
-    (
-        &rfc3671::C_FACSIMILE_TELEPHONE_NUMBER,
-        "c-FacsimileTelephoneNumber",
-    ),
-    (
-        &rfc3671::C_INTERNATIONAL_ISDN_NUMBER,
-        "c-InternationalISDNNumber",
-    ),
-    (&rfc3671::C_L, "c-l"),
-    (&rfc3671::C_ST, "c-st"),
-    (&rfc3671::C_STREET, "c-street"),
-    (&rfc3672::SUBENTRY, "subentry"),
-    (&rfc3672::ADMINISTRATIVE_ROLE, "administrativeRole"),
-    (&rfc3672::SUBTREE_SPECIFICATION, "subtreeSpecification"),
-    (&rfc3672::AUTONOMOUS_AREA, "autonomousArea"),
-    (
-        &rfc3672::ACCESS_CONTROL_SPECIFIC_AREA,
-        "accessControlSpecificArea",
-    ),
-    (
-        &rfc3672::ACCESS_CONTROL_INNER_AREA,
-        "accessControlInnerArea",
-    ),
-    (
-        &rfc3672::SUBSCHEMA_ADMIN_SPECIFIC_AREA,
-        "subschemaAdminSpecificArea",
-    ),
-    (
-        &rfc3672::COLLECTIVE_ATTRIBUTE_SPECIFIC_AREA,
-        "collectiveAttributeSpecificArea",
-    ),
-    (
-        &rfc3672::COLLECTIVE_ATTRIBUTE_INNER_AREA,
-        "collectiveAttributeInnerArea",
-    ),
-    (&rfc3687::COMPONENT_FILTER_MATCH, "componentFilterMatch"),
-    (&rfc3687::RDN_MATCH, "rdnMatch"),
-    (&rfc3687::PRESENT_MATCH, "presentMatch"),
-    (&rfc3687::ALL_COMPONENTS_MATCH, "allComponentsMatch"),
-    (
-        &rfc3687::DIRECTORY_COMPONENTS_MATCH,
-        "directoryComponentsMatch",
-    ),
-    (&rfc3698::STORED_PREFIX_MATCH, "storedPrefixMatch"),
-    (&rfc3703::PCIM_POLICY, "pcimPolicy"),
-    (
-        &rfc3703::PCIM_RULE_ACTION_ASSOCIATION,
-        "pcimRuleActionAssociation",
-    ),
-    (&rfc3703::PCIM_CONDITION_AUX_CLASS, "pcimConditionAuxClass"),
-    (&rfc3703::PCIM_TPC_AUX_CLASS, "pcimTPCAuxClass"),
-    (
-        &rfc3703::PCIM_CONDITION_VENDOR_AUX_CLASS,
-        "pcimConditionVendorAuxClass",
-    ),
-    (&rfc3703::PCIM_ACTION_AUX_CLASS, "pcimActionAuxClass"),
-    (
-        &rfc3703::PCIM_ACTION_VENDOR_AUX_CLASS,
-        "pcimActionVendorAuxClass",
-    ),
-    (&rfc3703::PCIM_POLICY_INSTANCE, "pcimPolicyInstance"),
-    (&rfc3703::PCIM_ELEMENT_AUX_CLASS, "pcimElementAuxClass"),
-    (&rfc3703::PCIM_REPOSITORY, "pcimRepository"),
-    (
-        &rfc3703::PCIM_REPOSITORY_AUX_CLASS,
-        "pcimRepositoryAuxClass",
-    ),
-    (&rfc3703::PCIM_GROUP, "pcimGroup"),
-    (&rfc3703::PCIM_REPOSITORY_INSTANCE, "pcimRepositoryInstance"),
-    (
-        &rfc3703::PCIM_SUBTREES_PTR_AUX_CLASS,
-        "pcimSubtreesPtrAuxClass",
-    ),
-    (
-        &rfc3703::PCIM_GROUP_CONTAINMENT_AUX_CLASS,
-        "pcimGroupContainmentAuxClass",
-    ),
-    (
-        &rfc3703::PCIM_RULE_CONTAINMENT_AUX_CLASS,
-        "pcimRuleContainmentAuxClass",
-    ),
-    (&rfc3703::PCIM_GROUP_AUX_CLASS, "pcimGroupAuxClass"),
-    (&rfc3703::PCIM_GROUP_INSTANCE, "pcimGroupInstance"),
-    (&rfc3703::PCIM_RULE, "pcimRule"),
-    (&rfc3703::PCIM_RULE_AUX_CLASS, "pcimRuleAuxClass"),
-    (&rfc3703::PCIM_RULE_INSTANCE, "pcimRuleInstance"),
-    (
-        &rfc3703::PCIM_RULE_CONDITION_ASSOCIATION,
-        "pcimRuleConditionAssociation",
-    ),
-    (
-        &rfc3703::PCIM_RULE_VALIDITY_ASSOCIATION,
-        "pcimRuleValidityAssociation",
-    ),
-    (
-        &rfc3703::PCIM_RULE_VALIDITY_PERIOD_LIST,
-        "pcimRuleValidityPeriodList",
-    ),
-    (&rfc3703::PCIM_RULE_USAGE, "pcimRuleUsage"),
-    (&rfc3703::PCIM_RULE_PRIORITY, "pcimRulePriority"),
-    (&rfc3703::PCIM_RULE_MANDATORY, "pcimRuleMandatory"),
-    (
-        &rfc3703::PCIM_RULE_SEQUENCED_ACTIONS,
-        "pcimRuleSequencedActions",
-    ),
-    (&rfc3703::PCIM_ROLES, "pcimRoles"),
-    (
-        &rfc3703::PCIM_CONDITION_GROUP_NUMBER,
-        "pcimConditionGroupNumber",
-    ),
-    (&rfc3703::PCIM_CONDITION_NEGATED, "pcimConditionNegated"),
-    (&rfc3703::PCIM_CONDITION_NAME, "pcimConditionName"),
-    (&rfc3703::PCIM_CONDITION_DN, "pcimConditionDN"),
-    (
-        &rfc3703::PCIM_VALIDITY_CONDITION_NAME,
-        "pcimValidityConditionName",
-    ),
-    (
-        &rfc3703::PCIM_TIME_PERIOD_CONDITION_DN,
-        "pcimTimePeriodConditionDN",
-    ),
-    (&rfc3703::PCIM_ACTION_NAME, "pcimActionName"),
-    (&rfc3703::PCIM_ACTION_ORDER, "pcimActionOrder"),
-    (&rfc3703::PCIM_ACTION_DN, "pcimActionDN"),
-    (&rfc3703::PCIM_TPC_TIME, "pcimTPCTime"),
-    (
-        &rfc3703::PCIM_TPC_MONTH_OF_YEAR_MASK,
-        "pcimTPCMonthOfYearMask",
-    ),
-    (
-        &rfc3703::PCIM_TPC_DAY_OF_MONTH_MASK,
-        "pcimTPCDayOfMonthMask",
-    ),
-    (&rfc3703::PCIM_TPC_DAY_OF_WEEK_MASK, "pcimTPCDayOfWeekMask"),
-    (&rfc3703::PCIM_TPC_TIME_OF_DAY_MASK, "pcimTPCTimeOfDayMask"),
-    (&rfc3703::PCIM_KEYWORDS, "pcimKeywords"),
-    (
-        &rfc3703::PCIM_TPC_LOCAL_OR_UTC_TIME,
-        "pcimTPCLocalOrUtcTime",
-    ),
-    (
-        &rfc3703::PCIM_VENDOR_CONSTRAINT_DATA,
-        "pcimVendorConstraintData",
-    ),
-    (
-        &rfc3703::PCIM_VENDOR_CONSTRAINT_ENCODING,
-        "pcimVendorConstraintEncoding",
-    ),
-    (&rfc3703::PCIM_VENDOR_ACTION_DATA, "pcimVendorActionData"),
-    (
-        &rfc3703::PCIM_VENDOR_ACTION_ENCODING,
-        "pcimVendorActionEncoding",
-    ),
-    (
-        &rfc3703::PCIM_POLICY_INSTANCE_NAME,
-        "pcimPolicyInstanceName",
-    ),
-    (&rfc3703::PCIM_REPOSITORY_NAME, "pcimRepositoryName"),
-    (
-        &rfc3703::PCIM_SUBTREES_AUX_CONTAINED_SET,
-        "pcimSubtreesAuxContainedSet",
-    ),
-    (
-        &rfc3703::PCIM_GROUPS_AUX_CONTAINED_SET,
-        "pcimGroupsAuxContainedSet",
-    ),
-    (
-        &rfc3703::PCIM_RULES_AUX_CONTAINED_SET,
-        "pcimRulesAuxContainedSet",
-    ),
-    (&rfc3703::PCIM_GROUP_NAME, "pcimGroupName"),
-    (&rfc3703::PCIM_RULE_NAME, "pcimRuleName"),
-    (&rfc3703::PCIM_RULE_ENABLED, "pcimRuleEnabled"),
-    (
-        &rfc3703::PCIM_RULE_CONDITION_LIST_TYPE,
-        "pcimRuleConditionListType",
-    ),
-    (&rfc3703::PCIM_RULE_CONDITION_LIST, "pcimRuleConditionList"),
-    (&rfc3703::PCIM_RULE_ACTION_LIST, "pcimRuleActionList"),
-    (&rfc3712::PRINTER_XRI_SUPPORTED, "printer-xri-supported"),
-    (&rfc3712::PRINTER_ALIASES, "printer-aliases"),
-    (
-        &rfc3712::PRINTER_CHARSET_CONFIGURED,
-        "printer-charset-configured",
-    ),
-    (
-        &rfc3712::PRINTER_JOB_PRIORITY_SUPPORTED,
-        "printer-job-priority-supported",
-    ),
-    (
-        &rfc3712::PRINTER_JOB_K_OCTETS_SUPPORTED,
-        "printer-job-k-octets-supported",
-    ),
-    (
-        &rfc3712::PRINTER_CURRENT_OPERATOR,
-        "printer-current-operator",
-    ),
-    (&rfc3712::PRINTER_SERVICE_PERSON, "printer-service-person"),
-    (
-        &rfc3712::PRINTER_DELIVERY_ORIENTATION_SUPPORTED,
-        "printer-delivery-orientation-supported",
-    ),
-    (
-        &rfc3712::PRINTER_STACKING_ORDER_SUPPORTED,
-        "printer-stacking-order-supported",
-    ),
-    (
-        &rfc3712::PRINTER_OUTPUT_FEATURES_SUPPORTED,
-        "printer-output-features-supported",
-    ),
-    (
-        &rfc3712::PRINTER_MEDIA_LOCAL_SUPPORTED,
-        "printer-media-local-supported",
-    ),
-    (
-        &rfc3712::PRINTER_COPIES_SUPPORTED,
-        "printer-copies-supported",
-    ),
-    (
-        &rfc3712::PRINTER_NATURAL_LANGUAGE_CONFIGURED,
-        "printer-natural-language-configured",
-    ),
-    (
-        &rfc3712::PRINTER_PRINT_QUALITY_SUPPORTED,
-        "printer-print-quality-supported",
-    ),
-    (
-        &rfc3712::PRINTER_RESOLUTION_SUPPORTED,
-        "printer-resolution-supported",
-    ),
-    (&rfc3712::PRINTER_MEDIA_SUPPORTED, "printer-media-supported"),
-    (&rfc3712::PRINTER_SIDES_SUPPORTED, "printer-sides-supported"),
-    (
-        &rfc3712::PRINTER_NUMBER_UP_SUPPORTED,
-        "printer-number-up-supported",
-    ),
-    (
-        &rfc3712::PRINTER_FINISHINGS_SUPPORTED,
-        "printer-finishings-supported",
-    ),
-    (
-        &rfc3712::PRINTER_PAGES_PER_MINUTE_COLOR,
-        "printer-pages-per-minute-color",
-    ),
-    (
-        &rfc3712::PRINTER_PAGES_PER_MINUTE,
-        "printer-pages-per-minute",
-    ),
-    (
-        &rfc3712::PRINTER_COMPRESSION_SUPPORTED,
-        "printer-compression-supported",
-    ),
-    (&rfc3712::PRINTER_COLOR_SUPPORTED, "printer-color-supported"),
-    (
-        &rfc3712::PRINTER_DOCUMENT_FORMAT_SUPPORTED,
-        "printer-document-format-supported",
-    ),
-    (
-        &rfc3712::PRINTER_CHARSET_SUPPORTED,
-        "printer-charset-supported",
-    ),
-    (
-        &rfc3712::PRINTER_MULTIPLE_DOCUMENT_JOBS_SUPPORTED,
-        "printer-multiple-document-jobs-supported",
-    ),
-    (
-        &rfc3712::PRINTER_IPP_VERSIONS_SUPPORTED,
-        "printer-ipp-versions-supported",
-    ),
-    (&rfc3712::PRINTER_MORE_INFO, "printer-more-info"),
-    (&rfc3712::PRINTER_NAME, "printer-name"),
-    (&rfc3712::PRINTER_LOCATION, "printer-location"),
-    (
-        &rfc3712::PRINTER_GENERATED_NATURAL_LANGUAGE_SUPPORTED,
-        "printer-generated-natural-language-supported",
-    ),
-    (&rfc3712::PRINTER_MAKE_AND_MODEL, "printer-make-and-model"),
-    (&rfc3712::PRINTER_INFO, "printer-info"),
-    (&rfc3712::PRINTER_URI, "printer-uri"),
-    (&rfc3712::PRINTER_LPR, "printerLPR"),
-    (&rfc3712::SLP_SERVICE_PRINTER, "slpServicePrinter"),
-    (&rfc3712::PRINTER_SERVICE, "printerService"),
-    (&rfc3712::PRINTER_IPP, "printerIPP"),
-    (
-        &rfc3712::PRINTER_SERVICE_AUX_CLASS,
-        "printerServiceAuxClass",
-    ),
-    (&rfc3712::PRINTER_ABSTRACT, "printerAbstract"),
-    (&rfc4104::PCELS_POLICY_SET, "pcelsPolicySet"),
-    (&rfc4104::PCELS_ACTION_ASSOCIATION, "pcelsActionAssociation"),
-    (
-        &rfc4104::PCELS_SIMPLE_CONDITION_AUX_CLASS,
-        "pcelsSimpleConditionAuxClass",
-    ),
-    (
-        &rfc4104::PCELS_COMPOUND_CONDITION_AUX_CLASS,
-        "pcelsCompoundConditionAuxClass",
-    ),
-    (
-        &rfc4104::PCELS_COMPOUND_FILTER_CONDITION_AUX_CLASS,
-        "pcelsCompoundFilterConditionAuxClass",
-    ),
-    (
-        &rfc4104::PCELS_SIMPLE_ACTION_AUX_CLASS,
-        "pcelsSimpleActionAuxClass",
-    ),
-    (
-        &rfc4104::PCELS_COMPOUND_ACTION_AUX_CLASS,
-        "pcelsCompoundActionAuxClass",
-    ),
-    (&rfc4104::PCELS_VARIABLE, "pcelsVariable"),
-    (
-        &rfc4104::PCELS_EXPLICIT_VARIABLE_AUX_CLASS,
-        "pcelsExplicitVariableAuxClass",
-    ),
-    (
-        &rfc4104::PCELS_IMPLICIT_VARIABLE_AUX_CLASS,
-        "pcelsImplicitVariableAuxClass",
-    ),
-    (
-        &rfc4104::PCELS_SOURCE_I_PV_4_VARIABLE_AUX_CLASS,
-        "pcelsSourceIPv4VariableAuxClass",
-    ),
-    (
-        &rfc4104::PCELS_POLICY_SET_ASSOCIATION,
-        "pcelsPolicySetAssociation",
-    ),
-    (
-        &rfc4104::PCELS_SOURCE_I_PV_6_VARIABLE_AUX_CLASS,
-        "pcelsSourceIPv6VariableAuxClass",
-    ),
-    (
-        &rfc4104::PCELS_DESTINATION_I_PV_4_VARIABLE_AUX_CLASS,
-        "pcelsDestinationIPv4VariableAuxClass",
-    ),
-    (
-        &rfc4104::PCELS_DESTINATION_I_PV_6_VARIABLE_AUX_CLASS,
-        "pcelsDestinationIPv6VariableAuxClass",
-    ),
-    (
-        &rfc4104::PCELS_SOURCE_PORT_VARIABLE_AUX_CLASS,
-        "pcelsSourcePortVariableAuxClass",
-    ),
-    (
-        &rfc4104::PCELS_DESTINATION_PORT_VARIABLE_AUX_CLASS,
-        "pcelsDestinationPortVariableAuxClass",
-    ),
-    (
-        &rfc4104::PCELS_IP_PROTOCOL_VARIABLE_AUX_CLASS,
-        "pcelsIPProtocolVariableAuxClass",
-    ),
-    (
-        &rfc4104::PCELS_IP_VERSION_VARIABLE_AUX_CLASS,
-        "pcelsIPVersionVariableAuxClass",
-    ),
-    (
-        &rfc4104::PCELS_IP_TO_S_VARIABLE_AUX_CLASS,
-        "pcelsIPToSVariableAuxClass",
-    ),
-    (
-        &rfc4104::PCELS_DSCP_VARIABLE_AUX_CLASS,
-        "pcelsDSCPVariableAuxClass",
-    ),
-    (
-        &rfc4104::PCELS_FLOW_ID_VARIABLE_AUX_CLASS,
-        "pcelsFlowIdVariableAuxClass",
-    ),
-    (&rfc4104::PCELS_GROUP, "pcelsGroup"),
-    (
-        &rfc4104::PCELS_SOURCE_MAC_VARIABLE_AUX_CLASS,
-        "pcelsSourceMACVariableAuxClass",
-    ),
-    (
-        &rfc4104::PCELS_DESTINATION_MAC_VARIABLE_AUX_CLASS,
-        "pcelsDestinationMACVariableAuxClass",
-    ),
-    (
-        &rfc4104::PCELS_VLAN_VARIABLE_AUX_CLASS,
-        "pcelsVLANVariableAuxClass",
-    ),
-    (
-        &rfc4104::PCELS_CO_S_VARIABLE_AUX_CLASS,
-        "pcelsCoSVariableAuxClass",
-    ),
-    (
-        &rfc4104::PCELS_ETHERTYPE_VARIABLE_AUX_CLASS,
-        "pcelsEthertypeVariableAuxClass",
-    ),
-    (
-        &rfc4104::PCELS_SOURCE_SAP_VARIABLE_AUX_CLASS,
-        "pcelsSourceSAPVariableAuxClass",
-    ),
-    (
-        &rfc4104::PCELS_DESTINATION_SAP_VARIABLE_AUX_CLASS,
-        "pcelsDestinationSAPVariableAuxClass",
-    ),
-    (
-        &rfc4104::PCELS_SNAPOUI_VARIABLE_AUX_CLASS,
-        "pcelsSNAPOUIVariableAuxClass",
-    ),
-    (
-        &rfc4104::PCELS_SNAP_TYPE_VARIABLE_AUX_CLASS,
-        "pcelsSNAPTypeVariableAuxClass",
-    ),
-    (
-        &rfc4104::PCELS_FLOW_DIRECTION_VARIABLE_AUX_CLASS,
-        "pcelsFlowDirectionVariableAuxClass",
-    ),
-    (&rfc4104::PCELS_GROUP_AUX_CLASS, "pcelsGroupAuxClass"),
-    (&rfc4104::PCELS_VALUE_AUX_CLASS, "pcelsValueAuxClass"),
-    (
-        &rfc4104::PCELS_I_PV_4_ADDR_VALUE_AUX_CLASS,
-        "pcelsIPv4AddrValueAuxClass",
-    ),
-    (
-        &rfc4104::PCELS_I_PV_6_ADDR_VALUE_AUX_CLASS,
-        "pcelsIPv6AddrValueAuxClass",
-    ),
-    (
-        &rfc4104::PCELS_MAC_ADDR_VALUE_AUX_CLASS,
-        "pcelsMACAddrValueAuxClass",
-    ),
-    (
-        &rfc4104::PCELS_STRING_VALUE_AUX_CLASS,
-        "pcelsStringValueAuxClass",
-    ),
-    (
-        &rfc4104::PCELS_BIT_STRING_VALUE_AUX_CLASS,
-        "pcelsBitStringValueAuxClass",
-    ),
-    (
-        &rfc4104::PCELS_INTEGER_VALUE_AUX_CLASS,
-        "pcelsIntegerValueAuxClass",
-    ),
-    (
-        &rfc4104::PCELS_BOOLEAN_VALUE_AUX_CLASS,
-        "pcelsBooleanValueAuxClass",
-    ),
-    (&rfc4104::PCELS_REUSABLE_CONTAINER, "pcelsReusableContainer"),
-    (
-        &rfc4104::PCELS_REUSABLE_CONTAINER_AUX_CLASS,
-        "pcelsReusableContainerAuxClass",
-    ),
-    (&rfc4104::PCELS_GROUP_INSTANCE, "pcelsGroupInstance"),
-    (
-        &rfc4104::PCELS_REUSABLE_CONTAINER_INSTANCE,
-        "pcelsReusableContainerInstance",
-    ),
-    (&rfc4104::PCELS_ROLE_COLLECTION, "pcelsRoleCollection"),
-    (&rfc4104::PCELS_FILTER_ENTRY_BASE, "pcelsFilterEntryBase"),
-    (&rfc4104::PCELS_IP_HEADERS_FILTER, "pcelsIPHeadersFilter"),
-    (&rfc4104::PCELS_8021_FILTER, "pcels8021Filter"),
-    (
-        &rfc4104::PCELS_FILTER_LIST_AUX_CLASS,
-        "pcelsFilterListAuxClass",
-    ),
-    (
-        &rfc4104::PCELS_VENDOR_VARIABLE_AUX_CLASS,
-        "pcelsVendorVariableAuxClass",
-    ),
-    (
-        &rfc4104::PCELS_VENDOR_VALUE_AUX_CLASS,
-        "pcelsVendorValueAuxClass",
-    ),
-    (&rfc4104::PCELS_RULE, "pcelsRule"),
-    (&rfc4104::PCELS_RULE_AUX_CLASS, "pcelsRuleAuxClass"),
-    (&rfc4104::PCELS_RULE_INSTANCE, "pcelsRuleInstance"),
-    (
-        &rfc4104::PCELS_CONDITION_ASSOCIATION,
-        "pcelsConditionAssociation",
-    ),
-    (&rfc4104::PCELS_POLICY_SET_NAME, "pcelsPolicySetName"),
-    (&rfc4104::PCELS_EXECUTION_STRATEGY, "pcelsExecutionStrategy"),
-    (&rfc4104::PCELS_VARIABLE_DN, "pcelsVariableDN"),
-    (&rfc4104::PCELS_VALUE_DN, "pcelsValueDN"),
-    (&rfc4104::PCELS_IS_MIRRORED, "pcelsIsMirrored"),
-    (&rfc4104::PCELS_VARIABLE_NAME, "pcelsVariableName"),
-    (
-        &rfc4104::PCELS_EXPECTED_VALUE_LIST,
-        "pcelsExpectedValueList",
-    ),
-    (
-        &rfc4104::PCELS_VARIABLE_MODEL_CLASS,
-        "pcelsVariableModelClass",
-    ),
-    (
-        &rfc4104::PCELS_VARIABLE_MODEL_PROPERTY,
-        "pcelsVariableModelProperty",
-    ),
-    (
-        &rfc4104::PCELS_EXPECTED_VALUE_TYPES,
-        "pcelsExpectedValueTypes",
-    ),
-    (&rfc4104::PCELS_VALUE_NAME, "pcelsValueName"),
-    (&rfc4104::PCELS_DECISION_STRATEGY, "pcelsDecisionStrategy"),
-    (&rfc4104::PCELS_I_PV_4_ADDR_LIST, "pcelsIPv4AddrList"),
-    (&rfc4104::PCELS_I_PV_6_ADDR_LIST, "pcelsIPv6AddrList"),
-    (&rfc4104::PCELS_MAC_ADDR_LIST, "pcelsMACAddrList"),
-    (&rfc4104::PCELS_STRING_LIST, "pcelsStringList"),
-    (&rfc4104::PCELS_BIT_STRING_LIST, "pcelsBitStringList"),
-    (&rfc4104::PCELS_INTEGER_LIST, "pcelsIntegerList"),
-    (&rfc4104::PCELS_BOOLEAN, "pcelsBoolean"),
-    (
-        &rfc4104::PCELS_REUSABLE_CONTAINER_NAME,
-        "pcelsReusableContainerName",
-    ),
-    (
-        &rfc4104::PCELS_REUSABLE_CONTAINER_LIST,
-        "pcelsReusableContainerList",
-    ),
-    (&rfc4104::PCELS_ROLE, "pcelsRole"),
-    (&rfc4104::PCELS_POLICY_SET_LIST, "pcelsPolicySetList"),
-    (
-        &rfc4104::PCELS_ROLE_COLLECTION_NAME,
-        "pcelsRoleCollectionName",
-    ),
-    (&rfc4104::PCELS_ELEMENT_LIST, "pcelsElementList"),
-    (&rfc4104::PCELS_FILTER_NAME, "pcelsFilterName"),
-    (&rfc4104::PCELS_FILTER_IS_NEGATED, "pcelsFilterIsNegated"),
-    (&rfc4104::PCELS_IP_HDR_VERSION, "pcelsIPHdrVersion"),
-    (
-        &rfc4104::PCELS_IP_HDR_SOURCE_ADDRESS,
-        "pcelsIPHdrSourceAddress",
-    ),
-    (
-        &rfc4104::PCELS_IP_HDR_SOURCE_ADDRESS_END_OF_RANGE,
-        "pcelsIPHdrSourceAddressEndOfRange",
-    ),
-    (&rfc4104::PCELS_IP_HDR_SOURCE_MASK, "pcelsIPHdrSourceMask"),
-    (&rfc4104::PCELS_IP_HDR_DEST_ADDRESS, "pcelsIPHdrDestAddress"),
-    (
-        &rfc4104::PCELS_IP_HDR_DEST_ADDRESS_END_OF_RANGE,
-        "pcelsIPHdrDestAddressEndOfRange",
-    ),
-    (&rfc4104::PCELS_PRIORITY, "pcelsPriority"),
-    (&rfc4104::PCELS_IP_HDR_DEST_MASK, "pcelsIPHdrDestMask"),
-    (&rfc4104::PCELS_IP_HDR_PROTOCOL_ID, "pcelsIPHdrProtocolID"),
-    (
-        &rfc4104::PCELS_IP_HDR_SOURCE_PORT_START,
-        "pcelsIPHdrSourcePortStart",
-    ),
-    (
-        &rfc4104::PCELS_IP_HDR_SOURCE_PORT_END,
-        "pcelsIPHdrSourcePortEnd",
-    ),
-    (
-        &rfc4104::PCELS_IP_HDR_DEST_PORT_START,
-        "pcelsIPHdrDestPortStart",
-    ),
-    (
-        &rfc4104::PCELS_IP_HDR_DEST_PORT_END,
-        "pcelsIPHdrDestPortEnd",
-    ),
-    (&rfc4104::PCELS_IP_HDR_DSCP_LIST, "pcelsIPHdrDSCPList"),
-    (&rfc4104::PCELS_IP_HDR_FLOW_LABEL, "pcelsIPHdrFlowLabel"),
-    (
-        &rfc4104::PCELS_8021_HDR_SOURCE_MAC_ADDRESS,
-        "pcels8021HdrSourceMACAddress",
-    ),
-    (
-        &rfc4104::PCELS_8021_HDR_SOURCE_MAC_MASK,
-        "pcels8021HdrSourceMACMask",
-    ),
-    (&rfc4104::PCELS_POLICY_SET_DN, "pcelsPolicySetDN"),
-    (
-        &rfc4104::PCELS_8021_HDR_DEST_MAC_ADDRESS,
-        "pcels8021HdrDestMACAddress",
-    ),
-    (
-        &rfc4104::PCELS_8021_HDR_DEST_MAC_MASK,
-        "pcels8021HdrDestMACMask",
-    ),
-    (
-        &rfc4104::PCELS_8021_HDR_PROTOCOL_ID,
-        "pcels8021HdrProtocolID",
-    ),
-    (&rfc4104::PCELS_8021_HDR_PRIORITY, "pcels8021HdrPriority"),
-    (&rfc4104::PCELS_8021_HDR_VLANID, "pcels8021HdrVLANID"),
-    (&rfc4104::PCELS_FILTER_LIST_NAME, "pcelsFilterListName"),
-    (&rfc4104::PCELS_FILTER_DIRECTION, "pcelsFilterDirection"),
-    (&rfc4104::PCELS_FILTER_ENTRY_LIST, "pcelsFilterEntryList"),
-    (
-        &rfc4104::PCELS_VENDOR_VARIABLE_DATA,
-        "pcelsVendorVariableData",
-    ),
-    (
-        &rfc4104::PCELS_VENDOR_VARIABLE_ENCODING,
-        "pcelsVendorVariableEncoding",
-    ),
-    (
-        &rfc4104::PCELS_CONDITION_LIST_TYPE,
-        "pcelsConditionListType",
-    ),
-    (&rfc4104::PCELS_VENDOR_VALUE_DATA, "pcelsVendorValueData"),
-    (
-        &rfc4104::PCELS_VENDOR_VALUE_ENCODING,
-        "pcelsVendorValueEncoding",
-    ),
-    (
-        &rfc4104::PCELS_RULE_VALIDITY_PERIOD_LIST,
-        "pcelsRuleValidityPeriodList",
-    ),
-    (&rfc4104::PCELS_CONDITION_LIST, "pcelsConditionList"),
-    (&rfc4104::PCELS_ACTION_LIST, "pcelsActionList"),
-    (&rfc4104::PCELS_SEQUENCED_ACTIONS, "pcelsSequencedActions"),
-    (&rfc4237::VPIM_USER, "vPIMUser"),
-    (&rfc4237::VPIM_TELEPHONE_NUMBER, "vPIMTelephoneNumber"),
-    (&rfc4237::VPIM_SUB_MAILBOXES, "vPIMSubMailboxes"),
-    (&rfc4237::VPIM_RFC_822_MAILBOX, "vPIMRfc822Mailbox"),
-    (&rfc4237::VPIM_SPOKEN_NAME, "vPIMSpokenName"),
-    (
-        &rfc4237::VPIM_SUPPORTED_UA_BEHAVIORS,
-        "vPIMSupportedUABehaviors",
-    ),
-    (
-        &rfc4237::VPIM_SUPPORTED_AUDIO_MEDIA_TYPES,
-        "vPIMSupportedAudioMediaTypes",
-    ),
-    (
-        &rfc4237::VPIM_SUPPORTED_MESSAGE_CONTEXT,
-        "vPIMSupportedMessageContext",
-    ),
-    (&rfc4237::VPIM_TEXT_NAME, "vPIMTextName"),
-    (
-        &rfc4237::VPIM_EXTENDED_ABSENCE_STATUS,
-        "vPIMExtendedAbsenceStatus",
-    ),
-    (&rfc4237::VPIM_MAX_MESSAGE_SIZE, "vPIMMaxMessageSize"),
-    (&rfc4403::UDDIV_3_SERVICE_KEY, "uddiv3ServiceKey"),
-    (
-        &rfc4403::UDDI_BUSINESS_ENTITY_NAME_FORM,
-        "uddiBusinessEntityNameForm",
-    ),
-    (
-        &rfc4403::UDDIV_3_ENTITY_OBITUARY_NAME_FORM,
-        "uddiv3EntityObituaryNameForm",
-    ),
-    (&rfc4403::UDDI_CONTACT_NAME_FORM, "uddiContactNameForm"),
-    (&rfc4403::UDDI_ADDRESS_NAME_FORM, "uddiAddressNameForm"),
-    (
-        &rfc4403::UDDI_BUSINESS_SERVICE_NAME_FORM,
-        "uddiBusinessServiceNameForm",
-    ),
-    (
-        &rfc4403::UDDI_BINDING_TEMPLATE_NAME_FORM,
-        "uddiBindingTemplateNameForm",
-    ),
-    (
-        &rfc4403::UDDI_T_MODEL_INSTANCE_INFO_NAME_FORM,
-        "uddiTModelInstanceInfoNameForm",
-    ),
-    (&rfc4403::UDDI_T_MODEL_NAME_FORM, "uddiTModelNameForm"),
-    (
-        &rfc4403::UDDI_PUBLISHER_ASSERTION_NAME_FORM,
-        "uddiPublisherAssertionNameForm",
-    ),
-    (
-        &rfc4403::UDDIV_3_SUBSCRIPTION_NAME_FORM,
-        "uddiv3SubscriptionNameForm",
-    ),
-    (&rfc4403::UDDI_BUSINESS_KEY, "uddiBusinessKey"),
-    (&rfc4403::UDDI_E_MAIL, "uddiEMail"),
-    (&rfc4403::UDDI_SORT_CODE, "uddiSortCode"),
-    (&rfc4403::UDDI_T_MODEL_KEY, "uddiTModelKey"),
-    (&rfc4403::UDDI_ADDRESS_LINE, "uddiAddressLine"),
-    (&rfc4403::UDDI_IDENTIFIER_BAG, "uddiIdentifierBag"),
-    (&rfc4403::UDDI_CATEGORY_BAG, "uddiCategoryBag"),
-    (&rfc4403::UDDI_KEYED_REFERENCE, "uddiKeyedReference"),
-    (&rfc4403::UDDI_SERVICE_KEY, "uddiServiceKey"),
-    (&rfc4403::UDDI_BINDING_KEY, "uddiBindingKey"),
-    (&rfc4403::UDDI_ACCESS_POINT, "uddiAccessPoint"),
-    (&rfc4403::UDDI_AUTHORIZED_NAME, "uddiAuthorizedName"),
-    (&rfc4403::UDDI_HOSTING_REDIRECTOR, "uddiHostingRedirector"),
-    (
-        &rfc4403::UDDI_INSTANCE_DESCRIPTION,
-        "uddiInstanceDescription",
-    ),
-    (&rfc4403::UDDI_INSTANCE_PARMS, "uddiInstanceParms"),
-    (
-        &rfc4403::UDDI_OVERVIEW_DESCRIPTION,
-        "uddiOverviewDescription",
-    ),
-    (&rfc4403::UDDI_OVERVIEW_URL, "uddiOverviewURL"),
-    (&rfc4403::UDDI_FROM_KEY, "uddiFromKey"),
-    (&rfc4403::UDDI_TO_KEY, "uddiToKey"),
-    (&rfc4403::UDDI_UUID, "uddiUUID"),
-    (&rfc4403::UDDI_IS_HIDDEN, "uddiIsHidden"),
-    (&rfc4403::UDDI_IS_PROJECTION, "uddiIsProjection"),
-    (&rfc4403::UDDI_OPERATOR, "uddiOperator"),
-    (&rfc4403::UDDI_LANG, "uddiLang"),
-    (&rfc4403::UDDIV_3_BUSINESS_KEY, "uddiv3BusinessKey"),
-    (&rfc4403::UDDIV_3_BINDING_KEY, "uddiv3BindingKey"),
-    (&rfc4403::UDDIV_3_TMODEL_KEY, "uddiv3TmodelKey"),
-    (
-        &rfc4403::UDDIV_3_DIGITAL_SIGNATURE,
-        "uddiv3DigitalSignature",
-    ),
-    (&rfc4403::UDDIV_3_NODE_ID, "uddiv3NodeId"),
-    (
-        &rfc4403::UDDIV_3_ENTITY_MODIFICATION_TIME,
-        "uddiv3EntityModificationTime",
-    ),
-    (&rfc4403::UDDIV_3_SUBSCRIPTION_KEY, "uddiv3SubscriptionKey"),
-    (
-        &rfc4403::UDDIV_3_SUBSCRIPTION_FILTER,
-        "uddiv3SubscriptionFilter",
-    ),
-    (&rfc4403::UDDI_NAME, "uddiName"),
-    (
-        &rfc4403::UDDIV_3_NOTIFICATION_INTERVAL,
-        "uddiv3NotificationInterval",
-    ),
-    (&rfc4403::UDDIV_3_MAX_ENTITIES, "uddiv3MaxEntities"),
-    (&rfc4403::UDDIV_3_EXPIRES_AFTER, "uddiv3ExpiresAfter"),
-    (&rfc4403::UDDIV_3_BRIEF_RESPONSE, "uddiv3BriefResponse"),
-    (&rfc4403::UDDIV_3_ENTITY_KEY, "uddiv3EntityKey"),
-    (
-        &rfc4403::UDDIV_3_ENTITY_CREATION_TIME,
-        "uddiv3EntityCreationTime",
-    ),
-    (
-        &rfc4403::UDDIV_3_ENTITY_DELETION_TIME,
-        "uddiv3EntityDeletionTime",
-    ),
-    (&rfc4403::UDDI_DESCRIPTION, "uddiDescription"),
-    (&rfc4403::UDDI_DISCOVERY_UR_LS, "uddiDiscoveryURLs"),
-    (&rfc4403::UDDI_USE_TYPE, "uddiUseType"),
-    (&rfc4403::UDDI_PERSON_NAME, "uddiPersonName"),
-    (&rfc4403::UDDI_PHONE, "uddiPhone"),
-    (&rfc4403::UDDI_BUSINESS_ENTITY, "uddiBusinessEntity"),
-    (&rfc4403::UDDIV_3_ENTITY_OBITUARY, "uddiv3EntityObituary"),
-    (&rfc4403::UDDI_CONTACT, "uddiContact"),
-    (&rfc4403::UDDI_ADDRESS, "uddiAddress"),
-    (&rfc4403::UDDI_BUSINESS_SERVICE, "uddiBusinessService"),
-    (&rfc4403::UDDI_BINDING_TEMPLATE, "uddiBindingTemplate"),
-    (
-        &rfc4403::UDDI_T_MODEL_INSTANCE_INFO,
-        "uddiTModelInstanceInfo",
-    ),
-    (&rfc4403::UDDI_T_MODEL, "uddiTModel"),
-    (&rfc4403::UDDI_PUBLISHER_ASSERTION, "uddiPublisherAssertion"),
-    (&rfc4403::UDDIV_3_SUBSCRIPTION, "uddiv3Subscription"),
-    (&rfc4512::EXTENSIBLE_OBJECT, "extensibleObject"),
-    (&rfc4512::SUPPORTED_CONTROL, "supportedControl"),
-    (
-        &rfc4512::SUPPORTED_SASL_MECHANISMS,
-        "supportedSASLMechanisms",
-    ),
-    (&rfc4512::SUPPORTED_LDAP_VERSION, "supportedLDAPVersion"),
-    (&rfc4512::LDAP_SYNTAXES, "ldapSyntaxes"),
-    (&rfc4512::NAMING_CONTEXTS, "namingContexts"),
-    (&rfc4512::ALT_SERVER, "altServer"),
-    (&rfc4512::SUPPORTED_EXTENSION, "supportedExtension"),
-    (&rfc4512::SUPPORTED_FEATURES, "supportedFeatures"),
-    (&rfc4512::CREATE_TIMESTAMP, "createTimestamp"),
-    (&rfc4512::SUBSCHEMA_SUBENTRY, "subschemaSubentry"),
-    (&rfc4512::MODIFY_TIMESTAMP, "modifyTimestamp"),
-    (&rfc4512::CREATORS_NAME, "creatorsName"),
-    (&rfc4512::MODIFIERS_NAME, "modifiersName"),
-    (&rfc4512::SUBSCHEMA, "subschema"),
-    (&rfc4512::DIT_STRUCTURE_RULES, "dITStructureRules"),
-    (&rfc4512::GOVERNING_STRUCTURE_RULE, "governingStructureRule"),
-    (&rfc4512::DIT_CONTENT_RULES, "dITContentRules"),
-    (&rfc4512::MATCHING_RULES, "matchingRules"),
-    (&rfc4512::ATTRIBUTE_TYPES, "attributeTypes"),
-    (&rfc4512::OBJECT_CLASSES, "objectClasses"),
-    (&rfc4512::NAME_FORMS, "nameForms"),
-    (&rfc4512::MATCHING_RULE_USE, "matchingRuleUse"),
-    (&rfc4512::STRUCTURAL_OBJECT_CLASS, "structuralObjectClass"),
-    (&rfc4512::OBJECT_CLASS, "objectClass"),
-    (&rfc4512::ALIASED_OBJECT_NAME, "aliasedObjectName"),
-    (&rfc4512::TOP, "top"),
-    (&rfc4512::ALIAS, "alias"),
-    (&rfc4517::CASE_EXACT_IA_5_MATCH, "caseExactIA5Match"),
-    (&rfc4517::CASE_IGNORE_IA_5_MATCH, "caseIgnoreIA5Match"),
-    (
-        &rfc4517::CASE_IGNORE_IA_5_SUBSTRINGS_MATCH,
-        "caseIgnoreIA5SubstringsMatch",
-    ),
-    (&rfc4517::OBJECT_IDENTIFIER_MATCH, "objectIdentifierMatch"),
-    (&rfc4517::DISTINGUISHED_NAME_MATCH, "distinguishedNameMatch"),
-    (
-        &rfc4517::NUMERIC_STRING_SUBSTRINGS_MATCH,
-        "numericStringSubstringsMatch",
-    ),
-    (&rfc4517::CASE_IGNORE_LIST_MATCH, "caseIgnoreListMatch"),
-    (
-        &rfc4517::CASE_IGNORE_LIST_SUBSTRINGS_MATCH,
-        "caseIgnoreListSubstringsMatch",
-    ),
-    (&rfc4517::BOOLEAN_MATCH, "booleanMatch"),
-    (&rfc4517::INTEGER_MATCH, "integerMatch"),
-    (&rfc4517::INTEGER_ORDERING_MATCH, "integerOrderingMatch"),
-    (&rfc4517::BIT_STRING_MATCH, "bitStringMatch"),
-    (&rfc4517::OCTET_STRING_MATCH, "octetStringMatch"),
-    (
-        &rfc4517::OCTET_STRING_ORDERING_MATCH,
-        "octetStringOrderingMatch",
-    ),
-    (&rfc4517::CASE_IGNORE_MATCH, "caseIgnoreMatch"),
-    (&rfc4517::TELEPHONE_NUMBER_MATCH, "telephoneNumberMatch"),
-    (
-        &rfc4517::TELEPHONE_NUMBER_SUBSTRINGS_MATCH,
-        "telephoneNumberSubstringsMatch",
-    ),
-    (&rfc4517::UNIQUE_MEMBER_MATCH, "uniqueMemberMatch"),
-    (&rfc4517::GENERALIZED_TIME_MATCH, "generalizedTimeMatch"),
-    (
-        &rfc4517::GENERALIZED_TIME_ORDERING_MATCH,
-        "generalizedTimeOrderingMatch",
-    ),
-    (
-        &rfc4517::INTEGER_FIRST_COMPONENT_MATCH,
-        "integerFirstComponentMatch",
-    ),
-    (
-        &rfc4517::CASE_IGNORE_ORDERING_MATCH,
-        "caseIgnoreOrderingMatch",
-    ),
-    (
-        &rfc4517::OBJECT_IDENTIFIER_FIRST_COMPONENT_MATCH,
-        "objectIdentifierFirstComponentMatch",
-    ),
-    (
-        &rfc4517::DIRECTORY_STRING_FIRST_COMPONENT_MATCH,
-        "directoryStringFirstComponentMatch",
-    ),
-    (&rfc4517::WORD_MATCH, "wordMatch"),
-    (&rfc4517::KEYWORD_MATCH, "keywordMatch"),
-    (
-        &rfc4517::CASE_IGNORE_SUBSTRINGS_MATCH,
-        "caseIgnoreSubstringsMatch",
-    ),
-    (&rfc4517::CASE_EXACT_MATCH, "caseExactMatch"),
-    (
-        &rfc4517::CASE_EXACT_ORDERING_MATCH,
-        "caseExactOrderingMatch",
-    ),
-    (
-        &rfc4517::CASE_EXACT_SUBSTRINGS_MATCH,
-        "caseExactSubstringsMatch",
-    ),
-    (&rfc4517::NUMERIC_STRING_MATCH, "numericStringMatch"),
-    (
-        &rfc4517::NUMERIC_STRING_ORDERING_MATCH,
-        "numericStringOrderingMatch",
-    ),
-    (&rfc4519::UID, "uid"),
-    (&rfc4519::USER_ID, "userId"),
-    (&rfc4519::DC, "DC"),
-    (&rfc4519::DOMAIN_COMPONENT, "domainComponent"),
-    (&rfc4519::UID_OBJECT, "uidObject"),
-    (&rfc4519::DC_OBJECT, "dcObject"),
-    (&rfc4519::O, "o"),
-    (&rfc4519::ORGANIZATION_NAME, "organizationName"),
-    (&rfc4519::OU, "ou"),
-    (&rfc4519::ORGANIZATIONAL_UNIT_NAME, "organizationalUnitName"),
-    (&rfc4519::TITLE, "title"),
-    (&rfc4519::DESCRIPTION, "description"),
-    (&rfc4519::SEARCH_GUIDE, "searchGuide"),
-    (&rfc4519::BUSINESS_CATEGORY, "businessCategory"),
-    (&rfc4519::POSTAL_ADDRESS, "postalAddress"),
-    (&rfc4519::POSTAL_CODE, "postalCode"),
-    (&rfc4519::POST_OFFICE_BOX, "postOfficeBox"),
-    (
-        &rfc4519::PHYSICAL_DELIVERY_OFFICE_NAME,
-        "physicalDeliveryOfficeName",
-    ),
-    (&rfc4519::TELEPHONE_NUMBER, "telephoneNumber"),
-    (&rfc4519::TELEX_NUMBER, "telexNumber"),
-    (
-        &rfc4519::TELETEX_TERMINAL_IDENTIFIER,
-        "teletexTerminalIdentifier",
-    ),
-    (
-        &rfc4519::FACSIMILE_TELEPHONE_NUMBER,
-        "facsimileTelephoneNumber",
-    ),
-    (&rfc4519::X_121_ADDRESS, "x121Address"),
-    (
-        &rfc4519::INTERNATIONALI_SDN_NUMBER,
-        "internationaliSDNNumber",
-    ),
-    (&rfc4519::REGISTERED_ADDRESS, "registeredAddress"),
-    (&rfc4519::DESTINATION_INDICATOR, "destinationIndicator"),
-    (
-        &rfc4519::PREFERRED_DELIVERY_METHOD,
-        "preferredDeliveryMethod",
-    ),
-    (&rfc4519::CN, "cn"),
-    (&rfc4519::COMMON_NAME, "commonName"),
-    (&rfc4519::MEMBER, "member"),
-    (&rfc4519::OWNER, "owner"),
-    (&rfc4519::ROLE_OCCUPANT, "roleOccupant"),
-    (&rfc4519::SEE_ALSO, "seeAlso"),
-    (&rfc4519::USER_PASSWORD, "userPassword"),
-    (&rfc4519::SN, "sn"),
-    (&rfc4519::SURNAME, "surname"),
-    (&rfc4519::NAME, "name"),
-    (&rfc4519::GIVEN_NAME, "givenName"),
-    (&rfc4519::INITIALS, "initials"),
-    (&rfc4519::GENERATION_QUALIFIER, "generationQualifier"),
-    (&rfc4519::X_500_UNIQUE_IDENTIFIER, "x500UniqueIdentifier"),
-    (&rfc4519::DN_QUALIFIER, "dnQualifier"),
-    (&rfc4519::ENHANCED_SEARCH_GUIDE, "enhancedSearchGuide"),
-    (&rfc4519::DISTINGUISHED_NAME, "distinguishedName"),
-    (&rfc4519::SERIAL_NUMBER, "serialNumber"),
-    (&rfc4519::UNIQUE_MEMBER, "uniqueMember"),
-    (&rfc4519::HOUSE_IDENTIFIER, "houseIdentifier"),
-    (&rfc4519::C, "c"),
-    (&rfc4519::COUNTRY_NAME, "countryName"),
-    (&rfc4519::L, "L"),
-    (&rfc4519::LOCALITY_NAME, "localityName"),
-    (&rfc4519::ST, "st"),
-    (&rfc4519::STREET, "street"),
-    (&rfc4519::RESIDENTIAL_PERSON, "residentialPerson"),
-    (&rfc4519::APPLICATION_PROCESS, "applicationProcess"),
-    (&rfc4519::DEVICE, "device"),
-    (&rfc4519::GROUP_OF_UNIQUE_NAMES, "groupOfUniqueNames"),
-    (&rfc4519::COUNTRY, "country"),
-    (&rfc4519::LOCALITY, "locality"),
-    (&rfc4519::ORGANIZATION, "organization"),
-    (&rfc4519::ORGANIZATIONAL_UNIT, "organizationalUnit"),
-    (&rfc4519::PERSON, "person"),
-    (&rfc4519::ORGANIZATIONAL_PERSON, "organizationalPerson"),
-    (&rfc4519::ORGANIZATIONAL_ROLE, "organizationalRole"),
-    (&rfc4519::GROUP_OF_NAMES, "groupOfNames"),
-    (&rfc4523::CERTIFICATE_EXACT_MATCH, "certificateExactMatch"),
-    (&rfc4523::CERTIFICATE_MATCH, "certificateMatch"),
-    (
-        &rfc4523::CERTIFICATE_PAIR_EXACT_MATCH,
-        "certificatePairExactMatch",
-    ),
-    (&rfc4523::CERTIFICATE_PAIR_MATCH, "certificatePairMatch"),
-    (
-        &rfc4523::CERTIFICATE_LIST_EXACT_MATCH,
-        "certificateListExactMatch",
-    ),
-    (&rfc4523::CERTIFICATE_LIST_MATCH, "certificateListMatch"),
-    (
-        &rfc4523::ALGORITHM_IDENTIFIER_MATCH,
-        "algorithmIdentifierMatch",
-    ),
-    (&rfc4523::USER_CERTIFICATE, "userCertificate"),
-    (&rfc4523::CA_CERTIFICATE, "cACertificate"),
-    (
-        &rfc4523::AUTHORITY_REVOCATION_LIST,
-        "authorityRevocationList",
-    ),
-    (
-        &rfc4523::CERTIFICATE_REVOCATION_LIST,
-        "certificateRevocationList",
-    ),
-    (&rfc4523::CROSS_CERTIFICATE_PAIR, "crossCertificatePair"),
-    (&rfc4523::SUPPORTED_ALGORITHMS, "supportedAlgorithms"),
-    (&rfc4523::DELTA_REVOCATION_LIST, "deltaRevocationList"),
-    (
-        &rfc4523::STRONG_AUTHENTICATION_USER,
-        "strongAuthenticationUser",
-    ),
-    (&rfc4523::CERTIFICATION_AUTHORITY, "certificationAuthority"),
-    (
-        &rfc4523::CERTIFICATION_AUTHORITY_V_2,
-        "certificationAuthority-V2",
-    ),
-    (
-        &rfc4523::USER_SECURITY_INFORMATION,
-        "userSecurityInformation",
-    ),
-    (&rfc4523::CRL_DISTRIBUTION_POINT, "cRLDistributionPoint"),
-    (&rfc4523::PKI_USER, "pkiUser"),
-    (&rfc4523::PKI_CA, "pkiCA"),
-    (&rfc4523::DELTA_CRL, "deltaCRL"),
-    (&rfc4524::MANAGER, "manager"),
-    (&rfc4524::DOCUMENT_IDENTIFIER, "documentIdentifier"),
-    (&rfc4524::DOCUMENT_TITLE, "documentTitle"),
-    (&rfc4524::DOCUMENT_VERSION, "documentVersion"),
-    (&rfc4524::DOCUMENT_AUTHOR, "documentAuthor"),
-    (&rfc4524::DOCUMENT_LOCATION, "documentLocation"),
-    (&rfc4524::HOME_PHONE, "homePhone"),
-    (&rfc4524::HOME_TELEPHONE, "homeTelephone"),
-    (&rfc4524::SECRETARY, "secretary"),
-    (&rfc4524::MAIL, "mail"),
-    (&rfc4524::RFC_822_MAILBOX, "RFC822Mailbox"),
-    (&rfc4524::ASSOCIATED_DOMAIN, "associatedDomain"),
-    (&rfc4524::ASSOCIATED_NAME, "associatedName"),
-    (&rfc4524::HOME_POSTAL_ADDRESS, "homePostalAddress"),
-    (&rfc4524::INFO, "info"),
-    (&rfc4524::PERSONAL_TITLE, "personalTitle"),
-    (&rfc4524::MOBILE, "mobile"),
-    (&rfc4524::MOBILE_TELEPHONE_NUMBER, "mobileTelephoneNumber"),
-    (&rfc4524::PAGER, "pager"),
-    (&rfc4524::PAGER_TELEPHONE_NUMBER, "pagerTelephoneNumber"),
-    (&rfc4524::CO, "co"),
-    (&rfc4524::FRIENDLY_COUNTRY_NAME, "friendlyCountryName"),
-    (&rfc4524::UNIQUE_IDENTIFIER, "uniqueIdentifier"),
-    (&rfc4524::ORGANIZATIONAL_STATUS, "organizationalStatus"),
-    (&rfc4524::BUILDING_NAME, "buildingName"),
-    (&rfc4524::DRINK, "drink"),
-    (&rfc4524::FAVOURITE_DRINK, "favouriteDrink"),
-    (&rfc4524::SINGLE_LEVEL_QUALITY, "singleLevelQuality"),
-    (&rfc4524::DOCUMENT_PUBLISHER, "documentPublisher"),
-    (&rfc4524::ROOM_NUMBER, "roomNumber"),
-    (&rfc4524::USER_CLASS, "userClass"),
-    (&rfc4524::HOST, "host"),
-    (&rfc4524::DOMAIN, "domain"),
-    (&rfc4524::RFC_822_LOCAL_PART, "RFC822LocalPart"),
-    (&rfc4524::DOMAIN_RELATED_OBJECT, "domainRelatedObject"),
-    (&rfc4524::FRIENDLY_COUNTRY, "friendlyCountry"),
-    (&rfc4524::SIMPLE_SECURITY_OBJECT, "simpleSecurityObject"),
-    (&rfc4524::ACCOUNT, "account"),
-    (&rfc4524::DOCUMENT, "document"),
-    (&rfc4524::ROOM, "room"),
-    (&rfc4524::DOCUMENT_SERIES, "documentSeries"),
-    (&rfc4530::UUID_MATCH, "uuidMatch"),
-    (&rfc4530::UUID_ORDERING_MATCH, "uuidOrderingMatch"),
-    (&rfc4530::ENTRY_UUID, "entryUUID"),
-    (&rfc4876::DEFAULT_SERVER_LIST, "defaultServerList"),
-    (&rfc4876::DEFAULT_SEARCH_BASE, "defaultSearchBase"),
-    (&rfc4876::CREDENTIAL_LEVEL, "credentialLevel"),
-    (&rfc4876::OBJECTCLASS_MAP, "objectclassMap"),
-    (&rfc4876::DEFAULT_SEARCH_SCOPE, "defaultSearchScope"),
-    (&rfc4876::SERVICE_CREDENTIAL_LEVEL, "serviceCredentialLevel"),
-    (
-        &rfc4876::SERVICE_SEARCH_DESCRIPTOR,
-        "serviceSearchDescriptor",
-    ),
-    (
-        &rfc4876::SERVICE_AUTHENTICATION_METHOD,
-        "serviceAuthenticationMethod",
-    ),
-    (&rfc4876::DEREFERENCE_ALIASES, "dereferenceAliases"),
-    (&rfc4876::PREFERRED_SERVER_LIST, "preferredServerList"),
-    (&rfc4876::SEARCH_TIME_LIMIT, "searchTimeLimit"),
-    (&rfc4876::BIND_TIME_LIMIT, "bindTimeLimit"),
-    (&rfc4876::FOLLOW_REFERRALS, "followReferrals"),
-    (&rfc4876::AUTHENTICATION_METHOD, "authenticationMethod"),
-    (&rfc4876::PROFILE_TTL, "profileTTL"),
-    (&rfc4876::ATTRIBUTE_MAP, "attributeMap"),
-    (&rfc4876::DUA_CONFIG_PROFILE, "DUAConfigProfile"),
-    (&rfc5020::ENTRY_DN, "entryDN"),
-    (&rfc5280::PKCS_9, "pkcs-9"),
-    (&rfc5280::ID_PKIX, "id-pkix"),
-    (&rfc5280::ID_PE, "id-pe"),
-    (
-        &rfc5280::ID_PE_AUTHORITY_INFO_ACCESS,
-        "id-pe-authorityInfoAccess",
-    ),
-    (
-        &rfc5280::ID_PE_SUBJECT_INFO_ACCESS,
-        "id-pe-subjectInfoAccess",
-    ),
-    (&rfc5280::ID_QT, "id-qt"),
-    (&rfc5280::ID_QT_CPS, "id-qt-cps"),
-    (&rfc5280::ID_QT_UNOTICE, "id-qt-unotice"),
-    (&rfc5280::ID_KP, "id-kp"),
-    (&rfc5280::ID_KP_SERVER_AUTH, "id-kp-serverAuth"),
-    (&rfc5280::ID_KP_CLIENT_AUTH, "id-kp-clientAuth"),
-    (&rfc5280::ID_KP_CODE_SIGNING, "id-kp-codeSigning"),
-    (&rfc5280::ID_KP_EMAIL_PROTECTION, "id-kp-emailProtection"),
-    (&rfc5280::ID_KP_TIME_STAMPING, "id-kp-timeStamping"),
-    (&rfc5280::ID_KP_OCSP_SIGNING, "id-kp-OCSPSigning"),
-    (&rfc5280::ID_AD, "id-ad"),
-    (&rfc5280::ID_AD_OCSP, "id-ad-ocsp"),
-    (&rfc5280::ID_AD_CA_ISSUERS, "id-ad-caIssuers"),
-    (&rfc5280::ID_AD_TIME_STAMPING, "id-ad-timeStamping"),
-    (&rfc5280::ID_AD_CA_REPOSITORY, "id-ad-caRepository"),
-    (&rfc5280::HOLD_INSTRUCTION, "holdInstruction"),
-    (&rfc5280::ID_HOLDINSTRUCTION_NONE, "id-holdinstruction-none"),
-    (
-        &rfc5280::ID_HOLDINSTRUCTION_CALLISSUER,
-        "id-holdinstruction-callissuer",
-    ),
-    (
-        &rfc5280::ID_HOLDINSTRUCTION_REJECT,
-        "id-holdinstruction-reject",
-    ),
-    (&rfc5280::ID_CE, "id-ce"),
-    (
-        &rfc5280::ID_CE_SUBJECT_KEY_IDENTIFIER,
-        "id-ce-subjectKeyIdentifier",
-    ),
-    (&rfc5280::ID_CE_KEY_USAGE, "id-ce-keyUsage"),
-    (
-        &rfc5280::ID_CE_PRIVATE_KEY_USAGE_PERIOD,
-        "id-ce-privateKeyUsagePeriod",
-    ),
-    (&rfc5280::ID_CE_SUBJECT_ALT_NAME, "id-ce-subjectAltName"),
-    (&rfc5280::ID_CE_ISSUER_ALT_NAME, "id-ce-issuerAltName"),
-    (&rfc5280::ID_CE_BASIC_CONSTRAINTS, "id-ce-basicConstraints"),
-    (&rfc5280::ID_CE_CRL_NUMBER, "id-ce-cRLNumber"),
-    (&rfc5280::ID_CE_CRL_REASONS, "id-ce-cRLReasons"),
-    (
-        &rfc5280::ID_CE_HOLD_INSTRUCTION_CODE,
-        "id-ce-holdInstructionCode",
-    ),
-    (&rfc5280::ID_CE_INVALIDITY_DATE, "id-ce-invalidityDate"),
-    (
-        &rfc5280::ID_CE_DELTA_CRL_INDICATOR,
-        "id-ce-deltaCRLIndicator",
-    ),
-    (
-        &rfc5280::ID_CE_ISSUING_DISTRIBUTION_POINT,
-        "id-ce-issuingDistributionPoint",
-    ),
-    (
-        &rfc5280::ID_CE_CERTIFICATE_ISSUER,
-        "id-ce-certificateIssuer",
-    ),
-    (&rfc5280::ID_CE_NAME_CONSTRAINTS, "id-ce-nameConstraints"),
-    (
-        &rfc5280::ID_CE_CRL_DISTRIBUTION_POINTS,
-        "id-ce-cRLDistributionPoints",
-    ),
-    (
-        &rfc5280::ID_CE_CERTIFICATE_POLICIES,
-        "id-ce-certificatePolicies",
-    ),
-    (&rfc5280::ANY_POLICY, "anyPolicy"),
-    (&rfc5280::ID_CE_POLICY_MAPPINGS, "id-ce-policyMappings"),
-    (
-        &rfc5280::ID_CE_AUTHORITY_KEY_IDENTIFIER,
-        "id-ce-authorityKeyIdentifier",
-    ),
-    (
-        &rfc5280::ID_CE_POLICY_CONSTRAINTS,
-        "id-ce-policyConstraints",
-    ),
-    (&rfc5280::ID_CE_EXT_KEY_USAGE, "id-ce-extKeyUsage"),
-    (&rfc5280::ANY_EXTENDED_KEY_USAGE, "anyExtendedKeyUsage"),
-    (&rfc5280::ID_CE_FRESHEST_CRL, "id-ce-freshestCRL"),
-    (&rfc5280::ID_CE_INHIBIT_ANY_POLICY, "id-ce-inhibitAnyPolicy"),
-    (
-        &rfc5280::ID_CE_SUBJECT_DIRECTORY_ATTRIBUTES,
-        "id-ce-subjectDirectoryAttributes",
-    ),
-    (&rfc5280::ID_AT, "id-at"),
-    (&rfc5911::ID_PBKDF_2, "id-PBKDF2"),
-    (&rfc5911::ID_DATA, "id-data"),
-    (&rfc5911::ID_SIGNED_DATA, "id-signedData"),
-    (&rfc5911::ID_ENVELOPED_DATA, "id-envelopedData"),
-    (&rfc5911::ID_DIGESTED_DATA, "id-digestedData"),
-    (&rfc5911::ID_ENCRYPTED_DATA, "id-encryptedData"),
-    (&rfc5911::SMIME_CAPABILITIES, "smimeCapabilities"),
-    (&rfc5911::ID_SMIME, "id-smime"),
-    (&rfc5911::ID_CT_RECEIPT, "id-ct-receipt"),
-    (&rfc5911::ID_CT_FIRMWARE_PACKAGE, "id-ct-firmwarePackage"),
-    (
-        &rfc5911::ID_CT_FIRMWARE_LOAD_RECEIPT,
-        "id-ct-firmwareLoadReceipt",
-    ),
-    (
-        &rfc5911::ID_CT_FIRMWARE_LOAD_ERROR,
-        "id-ct-firmwareLoadError",
-    ),
-    (&rfc5911::ID_CT_AUTH_DATA, "id-ct-authData"),
-    (
-        &rfc5911::ID_CT_AUTH_ENVELOPED_DATA,
-        "id-ct-authEnvelopedData",
-    ),
-    (&rfc5911::ID_CT_CONTENT_INFO, "id-ct-contentInfo"),
-    (&rfc5911::ID_CAP, "id-cap"),
-    (
-        &rfc5911::ID_CAP_PREFER_BINARY_INSIDE,
-        "id-cap-preferBinaryInside",
-    ),
-    (&rfc5911::ID_AA, "id-aa"),
-    (&rfc5911::ID_AA_RECEIPT_REQUEST, "id-aa-receiptRequest"),
-    (&rfc5911::ID_AA_CONTENT_REFERENCE, "id-aa-contentReference"),
-    (&rfc5911::ID_AA_ENCRYP_KEY_PREF, "id-aa-encrypKeyPref"),
-    (
-        &rfc5911::ID_AA_SIGNING_CERTIFICATE,
-        "id-aa-signingCertificate",
-    ),
-    (&rfc5911::ID_AA_SECURITY_LABEL, "id-aa-securityLabel"),
-    (&rfc5911::ID_AA_ML_EXPAND_HISTORY, "id-aa-mlExpandHistory"),
-    (
-        &rfc5911::ID_AA_FIRMWARE_PACKAGE_ID,
-        "id-aa-firmwarePackageID",
-    ),
-    (
-        &rfc5911::ID_AA_TARGET_HARDWARE_I_DS,
-        "id-aa-targetHardwareIDs",
-    ),
-    (&rfc5911::ID_AA_DECRYPT_KEY_ID, "id-aa-decryptKeyID"),
-    (&rfc5911::ID_AA_IMPL_CRYPTO_ALGS, "id-aa-implCryptoAlgs"),
-    (
-        &rfc5911::ID_AA_WRAPPED_FIRMWARE_KEY,
-        "id-aa-wrappedFirmwareKey",
-    ),
-    (&rfc5911::ID_AA_CONTENT_HINT, "id-aa-contentHint"),
-    (
-        &rfc5911::ID_AA_COMMUNITY_IDENTIFIERS,
-        "id-aa-communityIdentifiers",
-    ),
-    (
-        &rfc5911::ID_AA_FIRMWARE_PACKAGE_INFO,
-        "id-aa-firmwarePackageInfo",
-    ),
-    (&rfc5911::ID_AA_IMPL_COMPRESS_ALGS, "id-aa-implCompressAlgs"),
-    (
-        &rfc5911::ID_AA_SIGNING_CERTIFICATE_V_2,
-        "id-aa-signingCertificateV2",
-    ),
-    (&rfc5911::ID_AA_ER_INTERNAL, "id-aa-er-internal"),
-    (&rfc5911::ID_AA_MSG_SIG_DIGEST, "id-aa-msgSigDigest"),
-    (&rfc5911::ID_AA_ER_EXTERNAL, "id-aa-er-external"),
-    (
-        &rfc5911::ID_AA_CONTENT_IDENTIFIER,
-        "id-aa-contentIdentifier",
-    ),
-    (&rfc5911::ID_AA_EQUIVALENT_LABELS, "id-aa-equivalentLabels"),
-    (&rfc5911::ID_ALG_SSDH, "id-alg-SSDH"),
-    (&rfc5911::ID_ALG_ESDH, "id-alg-ESDH"),
-    (&rfc5911::ID_ALG_CMS_3_DE_SWRAP, "id-alg-CMS3DESwrap"),
-    (&rfc5911::ID_ALG_CMSRC_2_WRAP, "id-alg-CMSRC2wrap"),
-    (&rfc5911::ID_SKD, "id-skd"),
-    (&rfc5911::ID_SKD_GL_USE_KEK, "id-skd-glUseKEK"),
-    (&rfc5911::ID_SKD_GLA_QUERY_REQUEST, "id-skd-glaQueryRequest"),
-    (
-        &rfc5911::ID_SKD_GLA_QUERY_RESPONSE,
-        "id-skd-glaQueryResponse",
-    ),
-    (&rfc5911::ID_SKD_GL_PROVIDE_CERT, "id-skd-glProvideCert"),
-    (&rfc5911::ID_SKD_GL_MANAGE_CERT, "id-skd-glManageCert"),
-    (&rfc5911::ID_SKD_GL_KEY, "id-skd-glKey"),
-    (&rfc5911::ID_SKD_GL_DELETE, "id-skd-glDelete"),
-    (&rfc5911::ID_SKD_GL_ADD_MEMBER, "id-skd-glAddMember"),
-    (&rfc5911::ID_SKD_GL_DELETE_MEMBER, "id-skd-glDeleteMember"),
-    (&rfc5911::ID_SKD_GL_REKEY, "id-skd-glRekey"),
-    (&rfc5911::ID_SKD_GL_ADD_OWNER, "id-skd-glAddOwner"),
-    (&rfc5911::ID_SKD_GL_REMOVE_OWNER, "id-skd-glRemoveOwner"),
-    (&rfc5911::ID_SKD_GL_KEY_COMPROMISE, "id-skd-glKeyCompromise"),
-    (&rfc5911::ID_SKD_GLK_REFRESH, "id-skd-glkRefresh"),
-    (&rfc5911::ID_CONTENT_TYPE, "id-contentType"),
-    (&rfc5911::ID_MESSAGE_DIGEST, "id-messageDigest"),
-    (&rfc5911::ID_SIGNING_TIME, "id-signingTime"),
-    (&rfc5911::ID_COUNTERSIGNATURE, "id-countersignature"),
-    (&rfc5911::RC_2_CBC, "rc2-cbc"),
-    (&rfc5911::DES_EDE_3_CBC, "des-ede3-cbc"),
-    (&rfc5911::LTANS, "ltans"),
-    (&rfc5911::ID_CET_SKD_FAIL_INFO, "id-cet-skdFailInfo"),
-    (&rfc5911::ID_CMC_GLA_RR, "id-cmc-glaRR"),
-    (
-        &rfc5911::ID_CMC_GLA_SKD_ALG_REQUEST,
-        "id-cmc-gla-skdAlgRequest",
-    ),
-    (
-        &rfc5911::ID_CMC_GLA_SKD_ALG_RESPONSE,
-        "id-cmc-gla-skdAlgResponse",
-    ),
-    (
-        &rfc5911::ID_ON_HARDWARE_MODULE_NAME,
-        "id-on-hardwareModuleName",
-    ),
-    (&rfc5911::HMAC_SHA_1, "hMAC-SHA1"),
-    (&rfc5911::AES, "aes"),
-    (&rfc5911::ID_AES_128_CBC, "id-aes128-CBC"),
-    (&rfc5911::ID_AES_192_CBC, "id-aes192-CBC"),
-    (&rfc5911::ID_AES_192_WRAP, "id-aes192-wrap"),
-    (&rfc5911::ID_AES_192_GCM, "id-aes192-GCM"),
-    (&rfc5911::ID_AES_192_CCM, "id-aes192-CCM"),
-    (&rfc5911::ID_AES_256_CBC, "id-aes256-CBC"),
-    (&rfc5911::ID_AES_256_WRAP, "id-aes256-wrap"),
-    (&rfc5911::ID_AES_256_GCM, "id-aes256-GCM"),
-    (&rfc5911::ID_AES_256_CCM, "id-aes256-CCM"),
-    (&rfc5911::ID_AES_128_WRAP, "id-aes128-wrap"),
-    (&rfc5911::ID_AES_128_GCM, "id-aes128-GCM"),
-    (&rfc5911::ID_AES_128_CCM, "id-aes128-CCM"),
-    (&rfc5912::ID_DSA, "id-dsa"),
-    (&rfc5912::DSA_WITH_SHA_1, "dsa-with-sha1"),
-    (&rfc5912::ID_EC_PUBLIC_KEY, "id-ecPublicKey"),
-    (&rfc5912::SECP_256_R_1, "secp256r1"),
-    (&rfc5912::ECDSA_WITH_SHA_224, "ecdsa-with-SHA224"),
-    (&rfc5912::ECDSA_WITH_SHA_256, "ecdsa-with-SHA256"),
-    (&rfc5912::ECDSA_WITH_SHA_384, "ecdsa-with-SHA384"),
-    (&rfc5912::ECDSA_WITH_SHA_512, "ecdsa-with-SHA512"),
-    (&rfc5912::DHPUBLICNUMBER, "dhpublicnumber"),
-    (&rfc5912::ID_PASSWORD_BASED_MAC, "id-PasswordBasedMac"),
-    (&rfc5912::ID_DH_BASED_MAC, "id-DHBasedMac"),
-    (&rfc5912::PKCS_1, "pkcs-1"),
-    (&rfc5912::RSA_ENCRYPTION, "rsaEncryption"),
-    (&rfc5912::ID_RSASSA_PSS, "id-RSASSA-PSS"),
-    (
-        &rfc5912::SHA_256_WITH_RSA_ENCRYPTION,
-        "sha256WithRSAEncryption",
-    ),
-    (
-        &rfc5912::SHA_384_WITH_RSA_ENCRYPTION,
-        "sha384WithRSAEncryption",
-    ),
-    (
-        &rfc5912::SHA_512_WITH_RSA_ENCRYPTION,
-        "sha512WithRSAEncryption",
-    ),
-    (
-        &rfc5912::SHA_224_WITH_RSA_ENCRYPTION,
-        "sha224WithRSAEncryption",
-    ),
-    (&rfc5912::MD_2_WITH_RSA_ENCRYPTION, "md2WithRSAEncryption"),
-    (&rfc5912::MD_5_WITH_RSA_ENCRYPTION, "md5WithRSAEncryption"),
-    (&rfc5912::SHA_1_WITH_RSA_ENCRYPTION, "sha1WithRSAEncryption"),
-    (&rfc5912::ID_RSAES_OAEP, "id-RSAES-OAEP"),
-    (&rfc5912::ID_MGF_1, "id-mgf1"),
-    (&rfc5912::ID_P_SPECIFIED, "id-pSpecified"),
-    (&rfc5912::PKCS_9, "pkcs-9"),
-    (&rfc5912::ID_EXTENSION_REQ, "id-ExtensionReq"),
-    (&rfc5912::ID_SMIME, "id-smime"),
-    (&rfc5912::ID_CT, "id-ct"),
-    (
-        &rfc5912::ID_CT_SCVP_CERT_VAL_REQUEST,
-        "id-ct-scvp-certValRequest",
-    ),
-    (
-        &rfc5912::ID_CT_SCVP_CERT_VAL_RESPONSE,
-        "id-ct-scvp-certValResponse",
-    ),
-    (
-        &rfc5912::ID_CT_SCVP_VAL_POL_REQUEST,
-        "id-ct-scvp-valPolRequest",
-    ),
-    (
-        &rfc5912::ID_CT_SCVP_VAL_POL_RESPONSE,
-        "id-ct-scvp-valPolResponse",
-    ),
-    (&rfc5912::ID_CT_ENC_KEY_WITH_ID, "id-ct-encKeyWithID"),
-    (&rfc5912::ID_AA, "id-aa"),
-    (&rfc5912::ID_AA_CMC_UNSIGNED_DATA, "id-aa-cmc-unsignedData"),
-    (&rfc5912::ID_MD_2, "id-md2"),
-    (&rfc5912::ID_MD_5, "id-md5"),
-    (&rfc5912::SECT_163_K_1, "sect163k1"),
-    (&rfc5912::SECT_163_R_2, "sect163r2"),
-    (&rfc5912::SECT_283_K_1, "sect283k1"),
-    (&rfc5912::SECT_283_R_1, "sect283r1"),
-    (&rfc5912::SECT_233_K_1, "sect233k1"),
-    (&rfc5912::SECT_233_R_1, "sect233r1"),
-    (&rfc5912::SECP_224_R_1, "secp224r1"),
-    (&rfc5912::SECP_384_R_1, "secp384r1"),
-    (&rfc5912::SECP_521_R_1, "secp521r1"),
-    (&rfc5912::SECT_409_K_1, "sect409k1"),
-    (&rfc5912::SECT_409_R_1, "sect409r1"),
-    (&rfc5912::SECT_571_K_1, "sect571k1"),
-    (&rfc5912::SECT_571_R_1, "sect571r1"),
-    (&rfc5912::ID_EC_DH, "id-ecDH"),
-    (&rfc5912::ID_EC_MQV, "id-ecMQV"),
-    (&rfc5912::ID_SHA_1, "id-sha1"),
-    (&rfc5912::ID_PKIX, "id-pkix"),
-    (&rfc5912::ID_PE, "id-pe"),
-    (
-        &rfc5912::ID_PE_AUTHORITY_INFO_ACCESS,
-        "id-pe-authorityInfoAccess",
-    ),
-    (&rfc5912::ID_PE_AC_PROXYING, "id-pe-ac-proxying"),
-    (
-        &rfc5912::ID_PE_SUBJECT_INFO_ACCESS,
-        "id-pe-subjectInfoAccess",
-    ),
-    (&rfc5912::ID_PE_AC_AUDIT_IDENTITY, "id-pe-ac-auditIdentity"),
-    (&rfc5912::ID_PE_AA_CONTROLS, "id-pe-aaControls"),
-    (&rfc5912::ID_ACA, "id-aca"),
-    (
-        &rfc5912::ID_ACA_AUTHENTICATION_INFO,
-        "id-aca-authenticationInfo",
-    ),
-    (&rfc5912::ID_ACA_ACCESS_IDENTITY, "id-aca-accessIdentity"),
-    (
-        &rfc5912::ID_ACA_CHARGING_IDENTITY,
-        "id-aca-chargingIdentity",
-    ),
-    (&rfc5912::ID_ACA_GROUP, "id-aca-group"),
-    (&rfc5912::ID_ACA_ENC_ATTRS, "id-aca-encAttrs"),
-    (&rfc5912::ID_CCT, "id-cct"),
-    (&rfc5912::ID_CCT_PKI_DATA, "id-cct-PKIData"),
-    (&rfc5912::ID_CCT_PKI_RESPONSE, "id-cct-PKIResponse"),
-    (&rfc5912::ID_STC, "id-stc"),
-    (&rfc5912::ID_STC_BUILD_PKC_PATH, "id-stc-build-pkc-path"),
-    (
-        &rfc5912::ID_STC_BUILD_VALID_PKC_PATH,
-        "id-stc-build-valid-pkc-path",
-    ),
-    (
-        &rfc5912::ID_STC_BUILD_STATUS_CHECKED_PKC_PATH,
-        "id-stc-build-status-checked-pkc-path",
-    ),
-    (&rfc5912::ID_STC_BUILD_AA_PATH, "id-stc-build-aa-path"),
-    (
-        &rfc5912::ID_STC_BUILD_VALID_AA_PATH,
-        "id-stc-build-valid-aa-path",
-    ),
-    (
-        &rfc5912::ID_STC_BUILD_STATUS_CHECKED_AA_PATH,
-        "id-stc-build-status-checked-aa-path",
-    ),
-    (
-        &rfc5912::ID_STC_STATUS_CHECK_AC_AND_BUILD_STATUS_CHECKED_AA_PATH,
-        "id-stc-status-check-ac-and-build-status-checked-aa-path",
-    ),
-    (&rfc5912::ID_SWB, "id-swb"),
-    (
-        &rfc5912::ID_SWB_PKC_BEST_CERT_PATH,
-        "id-swb-pkc-best-cert-path",
-    ),
-    (&rfc5912::ID_SWB_PKC_CERT, "id-swb-pkc-cert"),
-    (&rfc5912::ID_SWB_AC_CERT, "id-swb-ac-cert"),
-    (
-        &rfc5912::ID_SWB_PKC_ALL_CERT_PATHS,
-        "id-swb-pkc-all-cert-paths",
-    ),
-    (
-        &rfc5912::ID_SWB_PKC_EE_REVOCATION_INFO,
-        "id-swb-pkc-ee-revocation-info",
-    ),
-    (
-        &rfc5912::ID_SWB_PKC_C_AS_REVOCATION_INFO,
-        "id-swb-pkc-CAs-revocation-info",
-    ),
-    (
-        &rfc5912::ID_SWB_PKC_REVOCATION_INFO,
-        "id-swb-pkc-revocation-info",
-    ),
-    (
-        &rfc5912::ID_SWB_PKC_PUBLIC_KEY_INFO,
-        "id-swb-pkc-public-key-info",
-    ),
-    (&rfc5912::ID_SWB_AA_CERT_PATH, "id-swb-aa-cert-path"),
-    (
-        &rfc5912::ID_SWB_AA_REVOCATION_INFO,
-        "id-swb-aa-revocation-info",
-    ),
-    (
-        &rfc5912::ID_SWB_AC_REVOCATION_INFO,
-        "id-swb-ac-revocation-info",
-    ),
-    (
-        &rfc5912::ID_SWB_RELAYED_RESPONSES,
-        "id-swb-relayed-responses",
-    ),
-    (&rfc5912::ID_SVP, "id-svp"),
-    (
-        &rfc5912::ID_SVP_DEFAULT_VAL_POLICY,
-        "id-svp-defaultValPolicy",
-    ),
-    (&rfc5912::ID_SVP_NAME_VAL_ALG, "id-svp-nameValAlg"),
-    (&rfc5912::ID_SVP_BASIC_VAL_ALG, "id-svp-basicValAlg"),
-    (&rfc5912::NAME_COMP_ALG_SET, "NameCompAlgSet"),
-    (&rfc5912::ID_NVA_DN_COMP_ALG, "id-nva-dnCompAlg"),
-    (&rfc5912::ID_QT, "id-qt"),
-    (&rfc5912::ID_QT_CPS, "id-qt-cps"),
-    (&rfc5912::ID_QT_UNOTICE, "id-qt-unotice"),
-    (&rfc5912::ID_KP, "id-kp"),
-    (&rfc5912::ID_KP_SERVER_AUTH, "id-kp-serverAuth"),
-    (&rfc5912::ID_KP_SCVP_SERVER, "id-kp-scvpServer"),
-    (&rfc5912::ID_KP_SCVP_CLIENT, "id-kp-scvpClient"),
-    (&rfc5912::ID_KP_CLIENT_AUTH, "id-kp-clientAuth"),
-    (&rfc5912::ID_KP_CODE_SIGNING, "id-kp-codeSigning"),
-    (&rfc5912::ID_KP_EMAIL_PROTECTION, "id-kp-emailProtection"),
-    (&rfc5912::ID_KP_TIME_STAMPING, "id-kp-timeStamping"),
-    (&rfc5912::ID_KP_OCSP_SIGNING, "id-kp-OCSPSigning"),
-    (&rfc5912::ID_IT, "id-it"),
-    (&rfc5912::ID_IT_CA_PROT_ENC_CERT, "id-it-caProtEncCert"),
-    (&rfc5912::ID_IT_KEY_PAIR_PARAM_REQ, "id-it-keyPairParamReq"),
-    (&rfc5912::ID_IT_KEY_PAIR_PARAM_REP, "id-it-keyPairParamRep"),
-    (&rfc5912::ID_IT_REV_PASSPHRASE, "id-it-revPassphrase"),
-    (&rfc5912::ID_IT_IMPLICIT_CONFIRM, "id-it-implicitConfirm"),
-    (&rfc5912::ID_IT_CONFIRM_WAIT_TIME, "id-it-confirmWaitTime"),
-    (&rfc5912::ID_IT_ORIG_PKI_MESSAGE, "id-it-origPKIMessage"),
-    (&rfc5912::ID_IT_SUPP_LANG_TAGS, "id-it-suppLangTags"),
-    (
-        &rfc5912::ID_IT_SIGN_KEY_PAIR_TYPES,
-        "id-it-signKeyPairTypes",
-    ),
-    (&rfc5912::ID_IT_ENC_KEY_PAIR_TYPES, "id-it-encKeyPairTypes"),
-    (&rfc5912::ID_IT_PREFERRED_SYMM_ALG, "id-it-preferredSymmAlg"),
-    (&rfc5912::ID_IT_CA_KEY_UPDATE_INFO, "id-it-caKeyUpdateInfo"),
-    (&rfc5912::ID_IT_CURRENT_CRL, "id-it-currentCRL"),
-    (&rfc5912::ID_IT_UNSUPPORTED_OI_DS, "id-it-unsupportedOIDs"),
-    (&rfc5912::ID_AD, "id-ad"),
-    (&rfc5912::ID_AD_OCSP, "id-ad-ocsp"),
-    (&rfc5912::ID_AD_CA_ISSUERS, "id-ad-caIssuers"),
-    (&rfc5912::ID_AD_TIME_STAMPING, "id-ad-timeStamping"),
-    (&rfc5912::ID_AD_CA_REPOSITORY, "id-ad-caRepository"),
-    (&rfc5912::ID_PKIP, "id-pkip"),
-    (&rfc5912::ID_REG_CTRL, "id-regCtrl"),
-    (&rfc5912::ID_REG_CTRL_REG_TOKEN, "id-regCtrl-regToken"),
-    (
-        &rfc5912::ID_REG_CTRL_AUTHENTICATOR,
-        "id-regCtrl-authenticator",
-    ),
-    (
-        &rfc5912::ID_REG_CTRL_PKI_PUBLICATION_INFO,
-        "id-regCtrl-pkiPublicationInfo",
-    ),
-    (
-        &rfc5912::ID_REG_CTRL_PKI_ARCHIVE_OPTIONS,
-        "id-regCtrl-pkiArchiveOptions",
-    ),
-    (&rfc5912::ID_REG_CTRL_OLD_CERT_ID, "id-regCtrl-oldCertID"),
-    (
-        &rfc5912::ID_REG_CTRL_PROTOCOL_ENCR_KEY,
-        "id-regCtrl-protocolEncrKey",
-    ),
-    (&rfc5912::ID_REG_INFO, "id-regInfo"),
-    (&rfc5912::ID_REG_INFO_UTF_8_PAIRS, "id-regInfo-utf8Pairs"),
-    (&rfc5912::ID_REG_INFO_CERT_REQ, "id-regInfo-certReq"),
-    (&rfc5912::ID_ALG_NO_SIGNATURE, "id-alg-noSignature"),
-    (&rfc5912::ID_CMC, "id-cmc"),
-    (&rfc5912::ID_CMC_STATUS_INFO, "id-cmc-statusInfo"),
-    (&rfc5912::ID_CMC_DECRYPTED_POP, "id-cmc-decryptedPOP"),
-    (&rfc5912::ID_CMC_LRA_POP_WITNESS, "id-cmc-lraPOPWitness"),
-    (&rfc5912::ID_CMC_GET_CERT, "id-cmc-getCert"),
-    (&rfc5912::ID_CMC_GET_CRL, "id-cmc-getCRL"),
-    (&rfc5912::ID_CMC_REVOKE_REQUEST, "id-cmc-revokeRequest"),
-    (&rfc5912::ID_CMC_REG_INFO, "id-cmc-regInfo"),
-    (&rfc5912::ID_CMC_RESPONSE_INFO, "id-cmc-responseInfo"),
-    (&rfc5912::ID_CMC_IDENTIFICATION, "id-cmc-identification"),
-    (&rfc5912::ID_CMC_QUERY_PENDING, "id-cmc-queryPending"),
-    (&rfc5912::ID_CMC_POP_LINK_RANDOM, "id-cmc-popLinkRandom"),
-    (&rfc5912::ID_CMC_POP_LINK_WITNESS, "id-cmc-popLinkWitness"),
-    (
-        &rfc5912::ID_CMC_CONFIRM_CERT_ACCEPTANCE,
-        "id-cmc-confirmCertAcceptance",
-    ),
-    (&rfc5912::ID_CMC_STATUS_INFO_V_2, "id-cmc-statusInfoV2"),
-    (&rfc5912::ID_CMC_TRUSTED_ANCHORS, "id-cmc-trustedAnchors"),
-    (&rfc5912::ID_CMC_AUTH_DATA, "id-cmc-authData"),
-    (&rfc5912::ID_CMC_BATCH_REQUESTS, "id-cmc-batchRequests"),
-    (&rfc5912::ID_CMC_BATCH_RESPONSES, "id-cmc-batchResponses"),
-    (&rfc5912::ID_CMC_IDENTITY_PROOF, "id-cmc-identityProof"),
-    (&rfc5912::ID_CMC_PUBLISH_CERT, "id-cmc-publishCert"),
-    (&rfc5912::ID_CMC_MOD_CERT_TEMPLATE, "id-cmc-modCertTemplate"),
-    (
-        &rfc5912::ID_CMC_CONTROL_PROCESSED,
-        "id-cmc-controlProcessed",
-    ),
-    (
-        &rfc5912::ID_CMC_IDENTITY_PROOF_V_2,
-        "id-cmc-identityProofV2",
-    ),
-    (
-        &rfc5912::ID_CMC_POP_LINK_WITNESS_V_2,
-        "id-cmc-popLinkWitnessV2",
-    ),
-    (&rfc5912::ID_CMC_DATA_RETURN, "id-cmc-dataReturn"),
-    (&rfc5912::ID_CMC_TRANSACTION_ID, "id-cmc-transactionId"),
-    (&rfc5912::ID_CMC_SENDER_NONCE, "id-cmc-senderNonce"),
-    (&rfc5912::ID_CMC_RECIPIENT_NONCE, "id-cmc-recipientNonce"),
-    (&rfc5912::ID_CMC_ADD_EXTENSIONS, "id-cmc-addExtensions"),
-    (&rfc5912::ID_CMC_ENCRYPTED_POP, "id-cmc-encryptedPOP"),
-    (
-        &rfc5912::ID_KEY_EXCHANGE_ALGORITHM,
-        "id-keyExchangeAlgorithm",
-    ),
-    (&rfc5912::ID_SHA_256, "id-sha256"),
-    (&rfc5912::ID_SHA_384, "id-sha384"),
-    (&rfc5912::ID_SHA_512, "id-sha512"),
-    (&rfc5912::ID_SHA_224, "id-sha224"),
-    (&rfc5912::DSA_WITH_SHA_224, "dsa-with-sha224"),
-    (&rfc5912::DSA_WITH_SHA_256, "dsa-with-sha256"),
-    (&rfc5912::HOLD_INSTRUCTION, "holdInstruction"),
-    (&rfc5912::ID_HOLDINSTRUCTION_NONE, "id-holdinstruction-none"),
-    (
-        &rfc5912::ID_HOLDINSTRUCTION_CALLISSUER,
-        "id-holdinstruction-callissuer",
-    ),
-    (
-        &rfc5912::ID_HOLDINSTRUCTION_REJECT,
-        "id-holdinstruction-reject",
-    ),
-    (&rfc5912::ID_CE, "id-ce"),
-    (
-        &rfc5912::ID_CE_SUBJECT_KEY_IDENTIFIER,
-        "id-ce-subjectKeyIdentifier",
-    ),
-    (&rfc5912::ID_CE_KEY_USAGE, "id-ce-keyUsage"),
-    (
-        &rfc5912::ID_CE_PRIVATE_KEY_USAGE_PERIOD,
-        "id-ce-privateKeyUsagePeriod",
-    ),
-    (&rfc5912::ID_CE_SUBJECT_ALT_NAME, "id-ce-subjectAltName"),
-    (&rfc5912::ID_CE_ISSUER_ALT_NAME, "id-ce-issuerAltName"),
-    (&rfc5912::ID_CE_BASIC_CONSTRAINTS, "id-ce-basicConstraints"),
-    (&rfc5912::ID_CE_CRL_NUMBER, "id-ce-cRLNumber"),
-    (&rfc5912::ID_CE_CRL_REASONS, "id-ce-cRLReasons"),
-    (
-        &rfc5912::ID_CE_HOLD_INSTRUCTION_CODE,
-        "id-ce-holdInstructionCode",
-    ),
-    (&rfc5912::ID_CE_INVALIDITY_DATE, "id-ce-invalidityDate"),
-    (
-        &rfc5912::ID_CE_DELTA_CRL_INDICATOR,
-        "id-ce-deltaCRLIndicator",
-    ),
-    (
-        &rfc5912::ID_CE_ISSUING_DISTRIBUTION_POINT,
-        "id-ce-issuingDistributionPoint",
-    ),
-    (
-        &rfc5912::ID_CE_CERTIFICATE_ISSUER,
-        "id-ce-certificateIssuer",
-    ),
-    (&rfc5912::ID_CE_NAME_CONSTRAINTS, "id-ce-nameConstraints"),
-    (
-        &rfc5912::ID_CE_CRL_DISTRIBUTION_POINTS,
-        "id-ce-cRLDistributionPoints",
-    ),
-    (
-        &rfc5912::ID_CE_CERTIFICATE_POLICIES,
-        "id-ce-certificatePolicies",
-    ),
-    (&rfc5912::ID_CE_POLICY_MAPPINGS, "id-ce-policyMappings"),
-    (
-        &rfc5912::ID_CE_AUTHORITY_KEY_IDENTIFIER,
-        "id-ce-authorityKeyIdentifier",
-    ),
-    (
-        &rfc5912::ID_CE_POLICY_CONSTRAINTS,
-        "id-ce-policyConstraints",
-    ),
-    (&rfc5912::ID_CE_EXT_KEY_USAGE, "id-ce-extKeyUsage"),
-    (&rfc5912::ANY_EXTENDED_KEY_USAGE, "anyExtendedKeyUsage"),
-    (&rfc5912::ID_CE_FRESHEST_CRL, "id-ce-freshestCRL"),
-    (&rfc5912::ID_CE_INHIBIT_ANY_POLICY, "id-ce-inhibitAnyPolicy"),
-    (
-        &rfc5912::ID_CE_TARGET_INFORMATION,
-        "id-ce-targetInformation",
-    ),
-    (&rfc5912::ID_CE_NO_REV_AVAIL, "id-ce-noRevAvail"),
-    (
-        &rfc5912::ID_CE_SUBJECT_DIRECTORY_ATTRIBUTES,
-        "id-ce-subjectDirectoryAttributes",
-    ),
-    (&rfc5912::ID_AT, "id-at"),
-    (&rfc5912::ID_AT_ROLE, "id-at-role"),
-    (&rfc6109::LDIF_LOCATION_URL_OBJECT, "LDIFLocationURLObject"),
-    (&rfc6109::PROVIDER, "provider"),
-    (
-        &rfc6109::PROVIDER_CERTIFICATE_HASH,
-        "providerCertificateHash",
-    ),
-    (&rfc6109::PROVIDER_CERTIFICATE, "providerCertificate"),
-    (&rfc6109::PROVIDER_NAME, "providerName"),
-    (&rfc6109::MAIL_RECEIPT, "mailReceipt"),
-    (&rfc6109::MANAGED_DOMAINS, "managedDomains"),
-    (&rfc6109::LDIF_LOCATION_URL, "LDIFLocationURL"),
-    (&rfc6109::PROVIDER_UNIT, "providerUnit"),
-    (&rfc6268::RSADSI, "rsadsi"),
-    (&rfc6268::ID_DATA, "id-data"),
-    (&rfc6268::ID_SIGNED_DATA, "id-signedData"),
-    (&rfc6268::ID_ENVELOPED_DATA, "id-envelopedData"),
-    (&rfc6268::ID_DIGESTED_DATA, "id-digestedData"),
-    (&rfc6268::ID_ENCRYPTED_DATA, "id-encryptedData"),
-    (
-        &rfc6268::ID_CT_CONTENT_COLLECTION,
-        "id-ct-contentCollection",
-    ),
-    (&rfc6268::ID_CT_AUTH_DATA, "id-ct-authData"),
-    (&rfc6268::ID_CT_CONTENT_WITH_ATTRS, "id-ct-contentWithAttrs"),
-    (
-        &rfc6268::ID_CT_AUTH_ENVELOPED_DATA,
-        "id-ct-authEnvelopedData",
-    ),
-    (&rfc6268::ID_CT_CONTENT_INFO, "id-ct-contentInfo"),
-    (&rfc6268::ID_CT_COMPRESSED_DATA, "id-ct-compressedData"),
-    (
-        &rfc6268::ID_AA_BINARY_SIGNING_TIME,
-        "id-aa-binarySigningTime",
-    ),
-    (&rfc6268::ID_ALG_ZLIB_COMPRESS, "id-alg-zlibCompress"),
-    (
-        &rfc6268::ID_AA_MULTIPLE_SIGNATURES,
-        "id-aa-multipleSignatures",
-    ),
-    (&rfc6268::ID_CONTENT_TYPE, "id-contentType"),
-    (&rfc6268::ID_MESSAGE_DIGEST, "id-messageDigest"),
-    (&rfc6268::ID_SIGNING_TIME, "id-signingTime"),
-    (&rfc6268::ID_COUNTERSIGNATURE, "id-countersignature"),
-    (&rfc6268::DIGEST_ALGORITHM, "digestAlgorithm"),
-    (&rfc6268::ID_HMAC_WITH_SHA_384, "id-hmacWithSHA384"),
-    (&rfc6268::ID_HMAC_WITH_SHA_512, "id-hmacWithSHA512"),
-    (&rfc6268::ID_HMAC_WITH_SHA_224, "id-hmacWithSHA224"),
-    (&rfc6268::ID_HMAC_WITH_SHA_256, "id-hmacWithSHA256"),
-    (&rfc6960::ID_PKIX_OCSP, "id-pkix-ocsp"),
-    (&rfc6960::ID_PKIX_OCSP_BASIC, "id-pkix-ocsp-basic"),
-    (&rfc6960::ID_PKIX_OCSP_NONCE, "id-pkix-ocsp-nonce"),
-    (&rfc6960::ID_PKIX_OCSP_CRL, "id-pkix-ocsp-crl"),
-    (&rfc6960::ID_PKIX_OCSP_RESPONSE, "id-pkix-ocsp-response"),
-    (&rfc6960::ID_PKIX_OCSP_NOCHECK, "id-pkix-ocsp-nocheck"),
-    (
-        &rfc6960::ID_PKIX_OCSP_ARCHIVE_CUTOFF,
-        "id-pkix-ocsp-archive-cutoff",
-    ),
-    (
-        &rfc6960::ID_PKIX_OCSP_SERVICE_LOCATOR,
-        "id-pkix-ocsp-service-locator",
-    ),
-    (
-        &rfc6960::ID_PKIX_OCSP_PREF_SIG_ALGS,
-        "id-pkix-ocsp-pref-sig-algs",
-    ),
-    (
-        &rfc6960::ID_PKIX_OCSP_EXTENDED_REVOKE,
-        "id-pkix-ocsp-extended-revoke",
-    ),
-    (&rfc6962::GOOGLE, "google"),
-    (&rfc6962::CT_PRECERT_SCTS, "ct-precert-scts"),
-    (&rfc6962::CT_PRECERT_POISON, "ct-precert-poison"),
-    (&rfc6962::CT_PRECERT_SIGNING_CERT, "ct-precert-signing-cert"),
-    (&rfc7107::ID_SMIME, "id-smime"),
-    (&rfc7107::ID_MOD, "id-mod"),
-    (&rfc7107::ID_CT, "id-ct"),
-    (&rfc7107::ID_EIT, "id-eit"),
-    (&rfc7107::ID_CAP, "id-cap"),
-    (&rfc7107::ID_PSKC, "id-pskc"),
-    (&rfc7107::ID_AA, "id-aa"),
-    (&rfc7107::ID_ALG, "id-alg"),
-    (&rfc7107::ID_CD, "id-cd"),
-    (&rfc7107::ID_SPQ, "id-spq"),
-    (&rfc7107::ID_CTI, "id-cti"),
-    (&rfc7107::ID_TSP, "id-tsp"),
-    (&rfc7107::ID_SKD, "id-skd"),
-    (&rfc7107::ID_STI, "id-sti"),
-    (&rfc7299::ID_PKIX, "id-pkix"),
-    (&rfc7299::ID_MOD, "id-mod"),
-    (&rfc7299::ID_PE, "id-pe"),
-    (&rfc7299::ID_ACA, "id-aca"),
-    (&rfc7299::ID_QCS, "id-qcs"),
-    (&rfc7299::ID_CCT, "id-cct"),
-    (&rfc7299::ID_TEST, "id-TEST"),
-    (&rfc7299::ID_CP, "id-cp"),
-    (&rfc7299::ID_CET, "id-cet"),
-    (&rfc7299::ID_RI, "id-ri"),
-    (&rfc7299::ID_SCT, "id-sct"),
-    (&rfc7299::ID_SWB, "id-swb"),
-    (&rfc7299::ID_SVP, "id-svp"),
-    (&rfc7299::ID_NVAE, "id-nvae"),
-    (&rfc7299::ID_BVAE, "id-bvae"),
-    (&rfc7299::ID_DNVAE, "id-dnvae"),
-    (&rfc7299::ID_QT, "id-qt"),
-    (&rfc7299::ID_LOGO, "id-logo"),
-    (&rfc7299::ID_PPL, "id-ppl"),
-    (&rfc7299::ID_MR, "id-mr"),
-    (&rfc7299::ID_SKIS, "id-skis"),
-    (&rfc7299::ID_KP, "id-kp"),
-    (&rfc7299::ID_IT, "id-it"),
-    (&rfc7299::ID_AD, "id-ad"),
-    (&rfc7299::ID_PKIX_OCSP, "id-pkix-ocsp"),
-    (&rfc7299::ID_PKIP, "id-pkip"),
-    (&rfc7299::ID_REG_CTRL, "id-regCtrl"),
-    (&rfc7299::ID_REG_INFO, "id-regInfo"),
-    (&rfc7299::ID_ALG, "id-alg"),
-    (&rfc7299::ID_CMC, "id-cmc"),
-    (&rfc7299::ID_CMC_GLA_RR, "id-cmc-glaRR"),
-    (&rfc7299::ID_ON, "id-on"),
-    (&rfc7299::ID_PDA, "id-pda"),
-    (&rfc7532::FEDFS_UUID, "fedfsUuid"),
-    (&rfc7532::FEDFS_FSL_PORT, "fedfsFslPort"),
-    (&rfc7532::FEDFS_NFS_PATH, "fedfsNfsPath"),
-    (
-        &rfc7532::FEDFS_NSDB_CONTAINER_INFO,
-        "fedfsNsdbContainerInfo",
-    ),
-    (&rfc7532::FEDFS_FSN, "fedfsFsn"),
-    (&rfc7532::FEDFS_FSL, "fedfsFsl"),
-    (&rfc7532::FEDFS_NFS_FSL, "fedfsNfsFsl"),
-    (&rfc7532::FEDFS_NFS_MAJOR_VER, "fedfsNfsMajorVer"),
-    (&rfc7532::FEDFS_NFS_MINOR_VER, "fedfsNfsMinorVer"),
-    (&rfc7532::FEDFS_NFS_CURRENCY, "fedfsNfsCurrency"),
-    (
-        &rfc7532::FEDFS_NFS_GEN_FLAG_WRITABLE,
-        "fedfsNfsGenFlagWritable",
-    ),
-    (&rfc7532::FEDFS_NFS_GEN_FLAG_GOING, "fedfsNfsGenFlagGoing"),
-    (&rfc7532::FEDFS_NFS_GEN_FLAG_SPLIT, "fedfsNfsGenFlagSplit"),
-    (&rfc7532::FEDFS_NFS_TRANS_FLAG_RDMA, "fedfsNfsTransFlagRdma"),
-    (&rfc7532::FEDFS_NFS_CLASS_SIMUL, "fedfsNfsClassSimul"),
-    (&rfc7532::FEDFS_NFS_CLASS_HANDLE, "fedfsNfsClassHandle"),
-    (&rfc7532::FEDFS_FSL_TTL, "fedfsFslTTL"),
-    (&rfc7532::FEDFS_NFS_CLASS_FILEID, "fedfsNfsClassFileid"),
-    (&rfc7532::FEDFS_NFS_CLASS_WRITEVER, "fedfsNfsClassWritever"),
-    (&rfc7532::FEDFS_NFS_CLASS_CHANGE, "fedfsNfsClassChange"),
-    (&rfc7532::FEDFS_NFS_CLASS_READDIR, "fedfsNfsClassReaddir"),
-    (&rfc7532::FEDFS_NFS_READ_RANK, "fedfsNfsReadRank"),
-    (&rfc7532::FEDFS_NFS_READ_ORDER, "fedfsNfsReadOrder"),
-    (&rfc7532::FEDFS_NFS_WRITE_RANK, "fedfsNfsWriteRank"),
-    (&rfc7532::FEDFS_NFS_WRITE_ORDER, "fedfsNfsWriteOrder"),
-    (&rfc7532::FEDFS_NFS_VAR_SUB, "fedfsNfsVarSub"),
-    (&rfc7532::FEDFS_NFS_VALID_FOR, "fedfsNfsValidFor"),
-    (&rfc7532::FEDFS_ANNOTATION, "fedfsAnnotation"),
-    (&rfc7532::FEDFS_NFS_URI, "fedfsNfsURI"),
-    (&rfc7532::FEDFS_DESCR, "fedfsDescr"),
-    (&rfc7532::FEDFS_NCE_DN, "fedfsNceDN"),
-    (&rfc7532::FEDFS_FSN_TTL, "fedfsFsnTTL"),
-    (&rfc7532::FEDFS_NET_ADDR, "fedfsNetAddr"),
-    (&rfc7532::FEDFS_NET_PORT, "fedfsNetPort"),
-    (&rfc7532::FEDFS_FSN_UUID, "fedfsFsnUuid"),
-    (&rfc7532::FEDFS_NSDB_NAME, "fedfsNsdbName"),
-    (&rfc7532::FEDFS_NSDB_PORT, "fedfsNsdbPort"),
-    (&rfc7532::FEDFS_NCE_PREFIX, "fedfsNcePrefix"),
-    (&rfc7532::FEDFS_FSL_UUID, "fedfsFslUuid"),
-    (&rfc7532::FEDFS_FSL_HOST, "fedfsFslHost"),
-    (&rfc7612::PRINTER_DEVICE_ID, "printer-device-id"),
-    (
-        &rfc7612::PRINTER_DEVICE_SERVICE_COUNT,
-        "printer-device-service-count",
-    ),
-    (&rfc7612::PRINTER_UUID, "printer-uuid"),
-    (&rfc7612::PRINTER_CHARGE_INFO, "printer-charge-info"),
-    (&rfc7612::PRINTER_CHARGE_INFO_URI, "printer-charge-info-uri"),
-    (&rfc7612::PRINTER_GEO_LOCATION, "printer-geo-location"),
-    (
-        &rfc7612::PRINTER_IPP_FEATURES_SUPPORTED,
-        "printer-ipp-features-supported",
-    ),
-    (&rfc8284::JID_OBJECT, "JIDObject"),
-    (&rfc8284::JID, "jid"),
-    (&rfc8410::ID_EDWARDS_CURVE_ALGS, "id-edwards-curve-algs"),
-    (&rfc8410::ID_X_25519, "id-X25519"),
-    (&rfc8410::ID_X_448, "id-X448"),
-    (&rfc8410::ID_ED_25519, "id-Ed25519"),
-    (&rfc8410::ID_ED_448, "id-Ed448"),
-    (&rfc8894::ID_VERI_SIGN, "id-VeriSign"),
-    (&rfc8894::ID_PKI, "id-pki"),
-    (&rfc8894::ID_ATTRIBUTES, "id-attributes"),
-    (&rfc8894::ID_MESSAGE_TYPE, "id-messageType"),
-    (&rfc8894::ID_PKI_STATUS, "id-pkiStatus"),
-    (&rfc8894::ID_FAIL_INFO, "id-failInfo"),
-    (&rfc8894::ID_SENDER_NONCE, "id-senderNonce"),
-    (&rfc8894::ID_RECIPIENT_NONCE, "id-recipientNonce"),
-    (&rfc8894::ID_TRANSACTION_ID, "id-transactionID"),
+    #[cfg(feature = "db-fips202")]
+    fips202::DB.table(),
+    #[cfg(feature = "db-rfc1274")]
+    rfc1274::DB.table(),
+    #[cfg(feature = "db-rfc2079")]
+    rfc2079::DB.table(),
+    #[cfg(feature = "db-rfc2164")]
+    rfc2164::DB.table(),
+    #[cfg(feature = "db-rfc2247")]
+    rfc2247::DB.table(),
+    #[cfg(feature = "db-rfc2252")]
+    rfc2252::DB.table(),
+    #[cfg(feature = "db-rfc2256")]
+    rfc2256::DB.table(),
+    #[cfg(feature = "db-rfc2293")]
+    rfc2293::DB.table(),
+    #[cfg(feature = "db-rfc2589")]
+    rfc2589::DB.table(),
+    #[cfg(feature = "db-rfc2739")]
+    rfc2739::DB.table(),
+    #[cfg(feature = "db-rfc2798")]
+    rfc2798::DB.table(),
+    #[cfg(feature = "db-rfc3280")]
+    rfc3280::DB.table(),
+    #[cfg(feature = "db-rfc3296")]
+    rfc3296::DB.table(),
+    #[cfg(feature = "db-rfc3671")]
+    rfc3671::DB.table(),
+    #[cfg(feature = "db-rfc3672")]
+    rfc3672::DB.table(),
+    #[cfg(feature = "db-rfc3687")]
+    rfc3687::DB.table(),
+    #[cfg(feature = "db-rfc3698")]
+    rfc3698::DB.table(),
+    #[cfg(feature = "db-rfc3703")]
+    rfc3703::DB.table(),
+    #[cfg(feature = "db-rfc3712")]
+    rfc3712::DB.table(),
+    #[cfg(feature = "db-rfc4104")]
+    rfc4104::DB.table(),
+    #[cfg(feature = "db-rfc4237")]
+    rfc4237::DB.table(),
+    #[cfg(feature = "db-rfc4403")]
+    rfc4403::DB.table(),
+    #[cfg(feature = "db-rfc4512")]
+    rfc4512::DB.table(),
+    #[cfg(feature = "db-rfc4517")]
+    rfc4517::DB.table(),
+    #[cfg(feature = "db-rfc4519")]
+    rfc4519::DB.table(),
+    #[cfg(feature = "db-rfc4523")]
+    rfc4523::DB.table(),
+    #[cfg(feature = "db-rfc4524")]
+    rfc4524::DB.table(),
+    #[cfg(feature = "db-rfc4530")]
+    rfc4530::DB.table(),
+    #[cfg(feature = "db-rfc4876")]
+    rfc4876::DB.table(),
+    #[cfg(feature = "db-rfc5020")]
+    rfc5020::DB.table(),
+    #[cfg(feature = "db-rfc5280")]
+    rfc5280::DB.table(),
+    #[cfg(feature = "db-rfc5911")]
+    rfc5911::DB.table(),
+    #[cfg(feature = "db-rfc5912")]
+    rfc5912::DB.table(),
+    #[cfg(feature = "db-rfc6109")]
+    rfc6109::DB.table(),
+    #[cfg(feature = "db-rfc6268")]
+    rfc6268::DB.table(),
+    #[cfg(feature = "db-rfc6960")]
+    rfc6960::DB.table(),
+    #[cfg(feature = "db-rfc6962")]
+    rfc6962::DB.table(),
+    #[cfg(feature = "db-rfc7107")]
+    rfc7107::DB.table(),
+    #[cfg(feature = "db-rfc7299")]
+    rfc7299::DB.table(),
+    #[cfg(feature = "db-rfc7532")]
+    rfc7532::DB.table(),
+    #[cfg(feature = "db-rfc7612")]
+    rfc7612::DB.table(),
+    #[cfg(feature = "db-rfc8284")]
+    rfc8284::DB.table(),
+    #[cfg(feature = "db-rfc8410")]
+    rfc8410::DB.table(),
+    #[cfg(feature = "db-rfc8894")]
+    rfc8894::DB.table(),
 ]);
@@ -113,12 +113,12 @@ impl Encoder {
     }
 
     /// Encode a single byte of a Base 128 value.
-    const fn encode_base128_byte(mut self, mut n: u32, i: usize, continued: bool) -> Result<Self> {
+    const fn encode_base128_byte(mut self, mut n: Arc, i: usize, continued: bool) -> Result<Self> {
         let mask = if continued { 0b10000000 } else { 0 };
 
         // Underflow checked by branch
         #[allow(clippy::integer_arithmetic)]
-        if n > 0x80 {
+        if n >= 0x80 {
             self.bytes[checked_add!(self.cursor, i)] = (n & 0b1111111) as u8 | mask;
             n >>= 7;
 
@@ -135,14 +135,18 @@ impl Encoder {
 }
 
 /// Compute the length - 1 of an arc when encoded in base 128.
+// Each iteration consumes 7 bits of a (finite) arc, so this can't overflow
+#[allow(clippy::integer_arithmetic)]
 const fn base128_len(arc: Arc) -> usize {
-    match arc {
-        0..=0x7f => 0,
-        0x80..=0x3fff => 1,
-        0x4000..=0x1fffff => 2,
-        0x200000..=0x1fffffff => 3,
-        _ => 4,
+    let mut len = 0;
+    let mut n = arc >> 7;
+
+    while n != 0 {
+        len += 1;
+        n >>= 7;
     }
+
+    len
 }
 
 #[cfg(test)]
@@ -15,11 +15,11 @@ pub enum Error {
         arc: Arc,
     },
 
-    /// Arc is too big (exceeds 32-bit limits of this library).
+    /// Arc is too big (exceeds 128-bit limits of this library).
     ///
     /// Technically the size of an arc is not constrained by X.660, however
-    /// this library has elected to use `u32` as the arc representation as
-    /// sufficient for PKIX/PKCS usages.
+    /// this library has elected to use `u128` as the arc representation as
+    /// sufficient for UUID-derived arcs (ITU X.667) and PKIX/PKCS usages.
     ArcTooBig,
 
     /// Base 128 encoding error (used in BER/DER serialization of arcs).
@@ -66,7 +66,7 @@ impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             Error::ArcInvalid { arc } => write!(f, "OID contains out-of-range arc: {}", arc),
-            Error::ArcTooBig => f.write_str("OID contains arc which is larger than 32-bits"),
+            Error::ArcTooBig => f.write_str("OID contains arc which is larger than 128-bits"),
             Error::Base128 => f.write_str("OID contains arc with invalid base 128 encoding"),
             Error::DigitExpected { actual } => {
                 write!(f, "expected digit, got '{}'", char::from(actual))
@@ -24,6 +24,7 @@ extern crate std;
 
 #[macro_use]
 mod checked;
+mod macros;
 
 mod arcs;
 mod buffer;
@@ -31,9 +32,12 @@ mod encoder;
 mod error;
 mod parser;
 
-#[cfg(feature = "db")]
+#[cfg(feature = "db-core")]
 pub mod db;
 
+#[cfg(feature = "serde")]
+mod serde;
+
 pub use crate::{
     arcs::{Arc, Arcs},
     buffer::Buffer,
@@ -141,7 +145,7 @@ impl ObjectIdentifier {
     }
 
     /// Parse an OID from from its BER/DER encoding.
-    pub fn from_bytes(ber_bytes: &[u8]) -> Result<Self> {
+    pub const fn from_bytes(ber_bytes: &[u8]) -> Result<Self> {
         let len = ber_bytes.len();
 
         match len {
@@ -150,27 +154,67 @@ impl ObjectIdentifier {
             _ => return Err(Error::NotEnoughArcs),
         }
 
+        // Ensure arcs are well-formed
+        // TODO(tarcieri): use `?` when stable in `const fn`
+        if let Err(err) = arcs::validate(ber_bytes) {
+            return Err(err);
+        }
+
         let mut bytes = [0u8; Self::MAX_SIZE];
-        bytes[..len].copy_from_slice(ber_bytes);
+        let mut i = 0;
+
+        while i < len {
+            bytes[i] = ber_bytes[i];
+
+            // Won't overflow due to `i < len` check above
+            #[allow(clippy::integer_arithmetic)]
+            {
+                i += 1;
+            }
+        }
 
         let bytes = Buffer {
             bytes,
             length: len as u8,
         };
 
-        let oid = Self { buffer: bytes };
-
-        // Ensure arcs are well-formed
-        let mut arcs = oid.arcs();
-        while arcs.try_next()?.is_some() {}
+        Ok(Self { buffer: bytes })
+    }
 
-        Ok(oid)
+    /// Parse an OID from from its BER/DER encoding, panicking on parse errors.
+    ///
+    /// Like [`ObjectIdentifier::new_unwrap`], this allows binding OIDs
+    /// embedded as byte literals to a constant value:
+    ///
+    /// ```
+    /// use const_oid::ObjectIdentifier;
+    ///
+    /// pub const MY_OID: ObjectIdentifier =
+    ///     ObjectIdentifier::from_bytes_unwrap(&[0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x01, 0x01]);
+    /// ```
+    ///
+    /// Use [`ObjectIdentifier::from_bytes`] for fallible parsing.
+    // TODO(tarcieri): remove this when `Result::unwrap` is `const fn`
+    pub const fn from_bytes_unwrap(ber_bytes: &[u8]) -> Self {
+        match Self::from_bytes(ber_bytes) {
+            Ok(oid) => oid,
+            Err(err) => err.panic(),
+        }
     }
 
     /// Get the parent OID of this one (if applicable).
-    pub fn parent(&self) -> Option<Self> {
-        let num_arcs = self.len().checked_sub(1)?;
-        Self::from_arcs(self.arcs().take(num_arcs)).ok()
+    pub const fn parent(&self) -> Option<Self> {
+        // Find the start of the last arc by skipping over the preceding bytes
+        // which have the continuation bit set.
+        let mut i = self.buffer.len().saturating_sub(1);
+
+        // Won't underflow due to `i > 1` check
+        #[allow(clippy::integer_arithmetic)]
+        while i > 1 && self.buffer.bytes[i - 1] & 0b10000000 != 0 {
+            i -= 1;
+        }
+
+        self.truncate_bytes(i)
     }
 
     /// Push an additional arc onto this OID, returning the child OID.
@@ -182,25 +226,126 @@ impl ObjectIdentifier {
         }
     }
 
+    /// Push several additional arcs onto this OID, returning the descendant
+    /// OID.
+    ///
+    /// ```
+    /// use const_oid::ObjectIdentifier;
+    ///
+    /// const PRIVATE_ENTERPRISE: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.6.1.4.1");
+    /// const MY_OID: ObjectIdentifier = match PRIVATE_ENTERPRISE.push_arcs(&[32473, 1, 2]) {
+    ///     Ok(oid) => oid,
+    ///     Err(_) => panic!("invalid OID"),
+    /// };
+    ///
+    /// assert_eq!(MY_OID, ObjectIdentifier::new_unwrap("1.3.6.1.4.1.32473.1.2"));
+    /// ```
+    pub const fn push_arcs(self, arcs: &[Arc]) -> Result<Self> {
+        let mut encoder = Encoder::extend(self);
+        let mut i = 0;
+
+        while i < arcs.len() {
+            // TODO(tarcieri): use `?` when stable in `const fn`
+            encoder = match encoder.arc(arcs[i]) {
+                Ok(encoder) => encoder,
+                Err(err) => return Err(err),
+            };
+
+            // Won't overflow due to `i < arcs.len()` check above
+            #[allow(clippy::integer_arithmetic)]
+            {
+                i += 1;
+            }
+        }
+
+        encoder.finish()
+    }
+
     /// Does this OID start with the other OID?
-    pub fn starts_with(&self, other: ObjectIdentifier) -> bool {
-        let mut self_arcs = self.arcs();
-
-        for other_arc in other.arcs() {
-            match self_arcs.next() {
-                Some(arc) => {
-                    if arc != other_arc {
-                        return false;
-                    }
-                }
-                None => {
-                    return false;
-                }
+    pub const fn starts_with(&self, other: ObjectIdentifier) -> bool {
+        // Arcs are self-delimiting in the BER encoding, so this is equivalent
+        // to comparing each of the other OID's arcs.
+        if other.buffer.len() > self.buffer.len() {
+            return false;
+        }
+
+        let mut i = 0;
+
+        while i < other.buffer.len() {
+            if self.buffer.bytes[i] != other.buffer.bytes[i] {
+                return false;
+            }
+
+            // Won't overflow due to `i < other.buffer.len()` check above
+            #[allow(clippy::integer_arithmetic)]
+            {
+                i += 1;
             }
         }
 
         true
     }
+
+    /// Truncate this OID to its first `num_arcs` arcs, returning the
+    /// ancestor OID.
+    ///
+    /// Returns `None` if this OID has fewer arcs than requested, or if the
+    /// result would not be a valid OID (i.e. would have fewer than 3 arcs).
+    pub const fn truncate(&self, num_arcs: usize) -> Option<Self> {
+        // The first byte encodes the first two arcs
+        let mut arcs = 2;
+        let mut i = 1;
+
+        while i < self.buffer.len() && arcs < num_arcs {
+            if self.buffer.bytes[i] & 0b10000000 == 0 {
+                // Bounded by the number of bytes in the buffer
+                #[allow(clippy::integer_arithmetic)]
+                {
+                    arcs += 1;
+                }
+            }
+
+            // Won't overflow due to `i < self.buffer.len()` check above
+            #[allow(clippy::integer_arithmetic)]
+            {
+                i += 1;
+            }
+        }
+
+        if arcs == num_arcs {
+            self.truncate_bytes(i)
+        } else {
+            None
+        }
+    }
+
+    /// Truncate the BER encoding of this OID to the given number of bytes,
+    /// which must be on an arc boundary.
+    const fn truncate_bytes(&self, len: usize) -> Option<Self> {
+        if len < 2 || len > self.buffer.len() {
+            return None;
+        }
+
+        let mut bytes = [0u8; Self::MAX_SIZE];
+        let mut i = 0;
+
+        while i < len {
+            bytes[i] = self.buffer.bytes[i];
+
+            // Won't overflow due to `i < len` check above
+            #[allow(clippy::integer_arithmetic)]
+            {
+                i += 1;
+            }
+        }
+
+        let buffer = Buffer {
+            bytes,
+            length: len as u8,
+        };
+
+        Some(Self { buffer })
+    }
 }
 
 impl<'a> ObjectIdentifier<&'a [u8]> {
@@ -0,0 +1,53 @@
+//! Macros for defining OIDs at compile time.
+
+/// Parse an [`ObjectIdentifier`][crate::ObjectIdentifier] from its dotted
+/// string form at compile time.
+///
+/// The OID is always evaluated in a `const` context, so malformed input is
+/// a compile error rather than a runtime panic, even when the macro is used
+/// in non-`const` code.
+///
+/// ```
+/// use const_oid::{oid, ObjectIdentifier};
+///
+/// const SHA256_WITH_RSA: ObjectIdentifier = oid!("1.2.840.113549.1.1.11");
+/// assert_eq!(SHA256_WITH_RSA.to_string(), "1.2.840.113549.1.1.11");
+/// ```
+///
+/// ```compile_fail
+/// let oid = const_oid::oid!("1.2.840.x");
+/// ```
+#[macro_export]
+macro_rules! oid {
+    ($oid:expr) => {{
+        const OID: $crate::ObjectIdentifier = $crate::ObjectIdentifier::new_unwrap($oid);
+        OID
+    }};
+}
+
+/// Resolve an [`ObjectIdentifier`][crate::ObjectIdentifier] by its name in
+/// the [OID database][crate::db::DB] at compile time.
+///
+/// Names are matched ignoring ASCII case. Unknown names are a compile error.
+///
+/// ```
+/// use const_oid::{oid, oid_name, ObjectIdentifier};
+///
+/// const SHA256_WITH_RSA: ObjectIdentifier = oid_name!("sha256WithRSAEncryption");
+/// assert_eq!(SHA256_WITH_RSA, oid!("1.2.840.113549.1.1.11"));
+/// ```
+///
+/// ```compile_fail
+/// let oid = const_oid::oid_name!("purplePeopleEater");
+/// ```
+#[cfg(feature = "db-core")]
+#[macro_export]
+macro_rules! oid_name {
+    ($name:expr) => {{
+        const OID: $crate::ObjectIdentifier = match $crate::db::by_name($name) {
+            ::core::option::Option::Some(oid) => *oid,
+            ::core::option::Option::None => ::core::panic!("unknown OID name"),
+        };
+        OID
+    }};
+}
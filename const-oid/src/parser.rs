@@ -49,11 +49,17 @@ impl Parser {
                 }
                 Err(err) => Err(err),
             },
-            // TODO(tarcieri): checked arithmetic
-            #[allow(clippy::integer_arithmetic)]
             [byte @ b'0'..=b'9', remaining @ ..] => {
-                let digit = byte.saturating_sub(b'0');
-                self.current_arc = self.current_arc * 10 + digit as Arc;
+                let digit = byte.saturating_sub(b'0') as Arc;
+
+                self.current_arc = match self.current_arc.checked_mul(10) {
+                    Some(arc) => match arc.checked_add(digit) {
+                        Some(arc) => arc,
+                        None => return Err(Error::ArcTooBig),
+                    },
+                    None => return Err(Error::ArcTooBig),
+                };
+
                 self.parse_bytes(remaining)
             }
             [b'.', remaining @ ..] => {
@@ -105,6 +111,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn reject_arc_too_big() {
+        assert_eq!(
+            Parser::parse("2.25.340282366920938463463374607431768211456")
+                .err()
+                .unwrap(),
+            Error::ArcTooBig
+        );
+    }
+
     #[test]
     fn reject_trailing_dot() {
         assert_eq!(Parser::parse("1.23.").err().unwrap(), Error::TrailingDot);
@@ -0,0 +1,59 @@
+//! Support for serializing [`ObjectIdentifier`] with [`serde`].
+//!
+//! OIDs are serialized in their dotted string form (e.g. `"1.2.840.10045.2.1"`)
+//! with human-readable formats, or as their BER encoding (without the tag and
+//! length) with binary formats.
+
+use crate::ObjectIdentifier;
+use ::serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use core::fmt;
+
+impl Serialize for ObjectIdentifier {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            serializer.serialize_bytes(self.as_bytes())
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ObjectIdentifier {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct OidVisitor;
+
+        impl<'de> de::Visitor<'de> for OidVisitor {
+            type Value = ObjectIdentifier;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("an OID as a dotted string or BER-encoded bytes")
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                ObjectIdentifier::new(v).map_err(E::custom)
+            }
+
+            fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                ObjectIdentifier::from_bytes(v).map_err(E::custom)
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(OidVisitor)
+        } else {
+            deserializer.deserialize_bytes(OidVisitor)
+        }
+    }
+}
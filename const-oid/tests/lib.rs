@@ -28,6 +28,11 @@ const EXAMPLE_OID_LARGE_ARC_BER: &[u8] = &hex!("0992268993F22C640101");
 const EXAMPLE_OID_LARGE_ARC: ObjectIdentifier =
     ObjectIdentifier::new_unwrap("0.9.2342.19200300.100.1.1");
 
+/// Example OID value with a UUID-derived arc (ITU X.667) larger than 64 bits
+const EXAMPLE_OID_UUID_STR: &str = "2.25.329800735698586629295641978511506172918";
+const EXAMPLE_OID_UUID_BER: &[u8] = &hex!("6983F09DA7EBCFDEE0C7A1A7B2C0948CC8F9D776");
+const EXAMPLE_OID_UUID: ObjectIdentifier = ObjectIdentifier::new_unwrap(EXAMPLE_OID_UUID_STR);
+
 /// Create an OID from a string.
 pub fn oid(s: &str) -> ObjectIdentifier {
     ObjectIdentifier::new(s).unwrap()
@@ -125,6 +130,72 @@ fn from_str() {
     );
 }
 
+#[test]
+fn uuid_arc() {
+    let oid = ObjectIdentifier::from_bytes(EXAMPLE_OID_UUID_BER).unwrap();
+    assert_eq!(oid, EXAMPLE_OID_UUID);
+    assert_eq!(oid.as_bytes(), EXAMPLE_OID_UUID_BER);
+    assert_eq!(oid.to_string(), EXAMPLE_OID_UUID_STR);
+    assert_eq!(
+        oid.arcs().collect::<Vec<_>>(),
+        [2, 25, 329800735698586629295641978511506172918]
+    );
+    assert_eq!(
+        ObjectIdentifier::from_arcs(oid.arcs()).unwrap(),
+        EXAMPLE_OID_UUID
+    );
+
+    // Largest arc representable as `u128`
+    let max = ObjectIdentifier::from_arcs([2, 25, u128::MAX]).unwrap();
+    assert_eq!(
+        max.as_bytes(),
+        hex!("6983FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF7F")
+    );
+    assert_eq!(max.arc(2), Some(u128::MAX));
+}
+
+#[test]
+fn from_bytes_const() {
+    const OID_1: ObjectIdentifier = ObjectIdentifier::from_bytes_unwrap(EXAMPLE_OID_1_BER);
+    assert_eq!(OID_1, EXAMPLE_OID_1);
+
+    const UUID: ObjectIdentifier = ObjectIdentifier::from_bytes_unwrap(EXAMPLE_OID_UUID_BER);
+    assert_eq!(UUID, EXAMPLE_OID_UUID);
+
+    // Root arcs out of range
+    assert_eq!(
+        ObjectIdentifier::from_bytes(&hex!("780102")),
+        Err(Error::ArcInvalid { arc: 3 })
+    );
+}
+
+#[test]
+#[should_panic]
+fn from_bytes_unwrap_invalid() {
+    ObjectIdentifier::from_bytes_unwrap(&hex!("2A864886"));
+}
+
+#[test]
+fn arc_too_big() {
+    assert_eq!(
+        ObjectIdentifier::new("2.25.340282366920938463463374607431768211456"),
+        Err(Error::ArcTooBig)
+    );
+    assert_eq!(
+        ObjectIdentifier::from_bytes(&hex!("6984808080808080808080808080808080808000")),
+        Err(Error::ArcTooBig)
+    );
+}
+
+#[test]
+fn base128_continuation_octets() {
+    // 16384 = 0x4000, which is encoded with a `0x80` continuation octet
+    let oid = ObjectIdentifier::from_arcs([1, 2, 16384]).unwrap();
+    assert_eq!(oid.as_bytes(), hex!("2A818000"));
+    assert_eq!(oid.to_string(), "1.2.16384");
+    assert_eq!(oid, ObjectIdentifier::new_unwrap("1.2.16384"));
+}
+
 #[test]
 fn display() {
     assert_eq!(EXAMPLE_OID_0.to_string(), EXAMPLE_OID_0_STR);
@@ -202,6 +273,20 @@ fn parent() {
     let parent = child.parent().unwrap();
     assert_eq!(parent, oid("1.2.3"));
     assert_eq!(parent.parent(), None);
+
+    // Multi-byte arcs
+    let child = oid("1.2.840.113549.1.1.1");
+    assert_eq!(child.parent().unwrap(), oid("1.2.840.113549.1.1"));
+    let child = oid("1.2.840.113549");
+    assert_eq!(child.parent().unwrap(), oid("1.2.840"));
+    assert_eq!(oid("1.2.840").parent(), None);
+}
+
+#[test]
+fn parent_const() {
+    const CHILD: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.6.1.4.1.32473");
+    const PARENT: Option<ObjectIdentifier> = CHILD.parent();
+    assert_eq!(PARENT, Some(oid("1.3.6.1.4.1")));
 }
 
 #[test]
@@ -210,6 +295,20 @@ fn push_arc() {
     assert_eq!(parent.push_arc(4).unwrap(), oid("1.2.3.4"));
 }
 
+#[test]
+fn push_arcs() {
+    let parent = oid("1.3.6.1.4.1");
+    assert_eq!(
+        parent.push_arcs(&[32473, 1, 2]).unwrap(),
+        oid("1.3.6.1.4.1.32473.1.2")
+    );
+    assert_eq!(parent.push_arcs(&[]).unwrap(), parent);
+    assert_eq!(
+        parent.push_arcs(&[1; ObjectIdentifier::MAX_SIZE]),
+        Err(Error::Length)
+    );
+}
+
 #[test]
 fn starts_with() {
     let child = ObjectIdentifier::new("1.2.3.4.5").unwrap();
@@ -220,4 +319,37 @@ fn starts_with() {
     assert!(!child.starts_with(oid("1.2.4")));
     assert!(!child.starts_with(oid("2.2.3")));
     assert!(!child.starts_with(oid("1.2.3.4.5.6")));
+
+    // Arcs which are a prefix of another arc's encoding
+    let child = oid("1.2.840.113549.1");
+    assert!(child.starts_with(oid("1.2.840.113549")));
+    assert!(!child.starts_with(oid("1.2.840.1")));
+    assert!(!oid("1.2.840.1").starts_with(oid("1.2.840.113549")));
+}
+
+#[test]
+fn truncate() {
+    let child = oid("1.2.840.113549.1.1.1");
+    assert_eq!(child.truncate(7), Some(child));
+    assert_eq!(child.truncate(5), Some(oid("1.2.840.113549.1")));
+    assert_eq!(child.truncate(4), Some(oid("1.2.840.113549")));
+    assert_eq!(child.truncate(3), Some(oid("1.2.840")));
+    assert_eq!(child.truncate(2), None);
+    assert_eq!(child.truncate(0), None);
+    assert_eq!(child.truncate(8), None);
+}
+
+#[test]
+fn oid_macro() {
+    const OID: ObjectIdentifier = const_oid::oid!("1.2.840.113549.1.1.11");
+    assert_eq!(OID, oid("1.2.840.113549.1.1.11"));
+    assert_eq!(const_oid::oid!("1.2.3"), oid("1.2.3"));
+}
+
+#[cfg(feature = "db")]
+#[test]
+fn oid_name_macro() {
+    const OID: ObjectIdentifier = const_oid::oid_name!("sha256WithRSAEncryption");
+    assert_eq!(OID, oid("1.2.840.113549.1.1.11"));
+    assert_eq!(const_oid::oid_name!("CN"), oid("2.5.4.3"));
 }
@@ -0,0 +1,53 @@
+//! `serde` support tests.
+
+#![cfg(feature = "serde")]
+
+use const_oid::ObjectIdentifier;
+use hex_literal::hex;
+
+/// `id-ecPublicKey`
+const EC_PUBLIC_KEY: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.2.1");
+
+/// BER encoding of `id-ecPublicKey`
+const EC_PUBLIC_KEY_BER: &[u8] = &hex!("2A 86 48 CE 3D 02 01");
+
+#[test]
+fn json() {
+    let json = serde_json::to_string(&EC_PUBLIC_KEY).unwrap();
+    assert_eq!(json, "\"1.2.840.10045.2.1\"");
+    assert_eq!(
+        serde_json::from_str::<ObjectIdentifier>(&json).unwrap(),
+        EC_PUBLIC_KEY
+    );
+
+    assert!(serde_json::from_str::<ObjectIdentifier>("\"1.2\"").is_err());
+    assert!(serde_json::from_str::<ObjectIdentifier>("\"1.2.840.x\"").is_err());
+    assert!(serde_json::from_str::<ObjectIdentifier>("[42, 134, 72]").is_err());
+}
+
+#[test]
+fn bincode() {
+    let bytes = bincode::serialize(&EC_PUBLIC_KEY).unwrap();
+    assert!(bytes.ends_with(EC_PUBLIC_KEY_BER));
+    assert_eq!(
+        bincode::deserialize::<ObjectIdentifier>(&bytes).unwrap(),
+        EC_PUBLIC_KEY
+    );
+
+    // Malformed BER (truncated multi-byte arc)
+    let bytes = bincode::serialize(&serde_bytes(&EC_PUBLIC_KEY_BER[..4])).unwrap();
+    assert!(bincode::deserialize::<ObjectIdentifier>(&bytes).is_err());
+}
+
+/// Serialize a byte slice the same way as [`ObjectIdentifier`].
+fn serde_bytes(bytes: &[u8]) -> impl serde::Serialize + '_ {
+    struct Bytes<'a>(&'a [u8]);
+
+    impl serde::Serialize for Bytes<'_> {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_bytes(self.0)
+        }
+    }
+
+    Bytes(bytes)
+}
@@ -4,9 +4,11 @@ use crate::{Asn1Type, Tag, TagMode, TagNumber};
 use proc_macro2::TokenStream;
 use proc_macro_error::{abort, abort_call_site};
 use quote::quote;
-use std::{fmt::Debug, str::FromStr};
+use std::{collections::BTreeMap, fmt::Debug, str::FromStr};
 use syn::punctuated::Punctuated;
-use syn::{parse::Parse, parse::ParseStream, Attribute, Ident, LitStr, Path, Token};
+use syn::{
+    parse::Parse, parse::ParseStream, spanned::Spanned, Attribute, Ident, LitStr, Path, Token,
+};
 
 /// Attribute name.
 pub(crate) const ATTR_NAME: &str = "asn1";
@@ -19,12 +21,17 @@ pub(crate) struct TypeAttrs {
     ///
     /// The default value is `EXPLICIT`.
     pub tag_mode: TagMode,
+
+    /// Assign context-specific tag numbers to fields or variants based on
+    /// their position, supplied as `#[asn1(auto_tag)]`.
+    pub auto_tag: bool,
 }
 
 impl TypeAttrs {
     /// Parse attributes from a struct field or enum variant.
     pub fn parse(attrs: &[Attribute]) -> Self {
         let mut tag_mode = None;
+        let mut auto_tag = None;
 
         let mut parsed_attrs = Vec::new();
         AttrNameValue::from_attributes(attrs, &mut parsed_attrs);
@@ -37,16 +44,60 @@ impl TypeAttrs {
                 }
 
                 tag_mode = Some(mode);
+            // `auto_tag` attribute
+            } else if let Some(auto) = attr.parse_value("auto_tag") {
+                if auto_tag.is_some() {
+                    abort!(attr.name, "duplicate ASN.1 `auto_tag` attribute");
+                }
+
+                auto_tag = Some(auto);
             } else {
                 abort!(
                     attr.name,
-                    "invalid `asn1` attribute (valid options are `tag_mode`)",
+                    "invalid `asn1` attribute (valid options are `auto_tag`, `tag_mode`)",
                 );
             }
         }
 
         Self {
             tag_mode: tag_mode.unwrap_or_default(),
+            auto_tag: auto_tag.unwrap_or_default(),
+        }
+    }
+
+    /// Get the context-specific tag number which is automatically assigned
+    /// to the field or variant at the given position, if `auto_tag` is set.
+    pub fn auto_tag_number(&self, index: usize, ident: &Ident) -> Option<TagNumber> {
+        if !self.auto_tag {
+            return None;
+        }
+
+        match u8::try_from(index) {
+            Ok(n) if n <= TagNumber::MAX => Some(TagNumber(n)),
+            _ => abort!(
+                ident,
+                "`auto_tag` supports at most {} fields or variants",
+                u16::from(TagNumber::MAX) + 1
+            ),
+        }
+    }
+}
+
+/// Ensure the context-specific tag numbers of the fields of a `struct` or the
+/// variants of an `enum` are unique.
+pub(crate) fn check_tag_numbers<'a>(fields: impl IntoIterator<Item = (&'a Ident, &'a FieldAttrs)>) {
+    let mut seen = BTreeMap::new();
+
+    for (ident, attrs) in fields {
+        if let Some(tag_number) = attrs.context_specific {
+            if let Some(other) = seen.insert(tag_number, ident) {
+                abort!(
+                    ident,
+                    "duplicate context-specific tag number `{}` (also used by `{}`)",
+                    tag_number,
+                    other
+                );
+            }
         }
     }
 }
@@ -79,6 +130,10 @@ pub(crate) struct FieldAttrs {
 
     /// Is the inner type constructed?
     pub constructed: bool,
+
+    /// Is this field encoded as the contents of an `OCTET STRING`, supplied
+    /// as `#[asn1(inner)]`?
+    pub inner: bool,
 }
 
 impl FieldAttrs {
@@ -98,6 +153,7 @@ impl FieldAttrs {
         let mut optional = None;
         let mut tag_mode = None;
         let mut constructed = None;
+        let mut inner = None;
 
         let mut parsed_attrs = Vec::new();
         AttrNameValue::from_attributes(attrs, &mut parsed_attrs);
@@ -154,6 +210,13 @@ impl FieldAttrs {
                 }
 
                 constructed = Some(ty);
+            // `inner` attribute
+            } else if let Some(inn) = attr.parse_value("inner") {
+                if inner.is_some() {
+                    abort!(attr.name, "duplicate ASN.1 `inner` attribute");
+                }
+
+                inner = Some(inn);
             } else {
                 abort!(
                     attr.name,
@@ -163,6 +226,12 @@ impl FieldAttrs {
             }
         }
 
+        let inner = inner.unwrap_or_default();
+
+        if inner && asn1_type.is_some() {
+            abort_call_site!("`inner` and `type` field qualifiers are mutually exclusive");
+        }
+
         Self {
             asn1_type,
             context_specific,
@@ -171,6 +240,7 @@ impl FieldAttrs {
             optional: optional.unwrap_or_default(),
             tag_mode: tag_mode.unwrap_or(type_attrs.tag_mode),
             constructed: constructed.unwrap_or_default(),
+            inner,
         }
     }
 
@@ -191,8 +261,19 @@ impl FieldAttrs {
 
     /// Get a `der::Decoder` object which respects these field attributes.
     pub fn decoder(&self) -> TokenStream {
+        // `inner` fields are decoded as an `OCTET STRING` before being
+        // converted into the field's type
+        let (asn1_type, convert) = if self.inner {
+            (
+                Some(Asn1Type::OctetString),
+                quote!(::der::asn1::OctetStringRef::decode_inner),
+            )
+        } else {
+            (self.asn1_type, quote!(TryInto::try_into))
+        };
+
         if let Some(tag_number) = self.context_specific {
-            let type_params = self.asn1_type.map(|ty| ty.type_path()).unwrap_or_default();
+            let type_params = asn1_type.map(|ty| ty.type_path()).unwrap_or_default();
             let tag_number = tag_number.to_tokens();
 
             let context_specific = match self.tag_mode {
@@ -225,7 +306,16 @@ impl FieldAttrs {
 
             if self.is_optional() {
                 if let Some(default) = &self.default {
-                    quote!(#context_specific.map(|cs| cs.value).unwrap_or_else(#default))
+                    if asn1_type.is_some() {
+                        quote! {
+                            #context_specific
+                                .map(|cs| #convert(cs.value))
+                                .transpose()?
+                                .unwrap_or_else(#default)
+                        }
+                    } else {
+                        quote!(#context_specific.map(|cs| cs.value).unwrap_or_else(#default))
+                    }
                 } else {
                     quote!(#context_specific.map(|cs| cs.value))
                 }
@@ -241,15 +331,16 @@ impl FieldAttrs {
                     })?.value
                 }
             }
-        } else if let Some(default) = &self.default {
-            let type_params = self.asn1_type.map(|ty| ty.type_path()).unwrap_or_default();
-            self.asn1_type.map(|ty| ty.decoder()).unwrap_or_else(|| {
-                quote! {
-                    Option::<#type_params>::decode(reader)?.unwrap_or_else(#default),
-                }
-            })
+        } else if let (Some(default), Some(ty)) = (&self.default, asn1_type) {
+            let type_path = ty.type_path();
+            quote! {
+                Option::<#type_path>::decode(reader)?
+                    .map(#convert)
+                    .transpose()?
+                    .unwrap_or_else(#default)
+            }
         } else {
-            self.asn1_type
+            asn1_type
                 .map(|ty| ty.decoder())
                 .unwrap_or_else(|| quote!(reader.decode()?))
         }
@@ -307,8 +398,16 @@ impl Parse for AttrNameValue {
                 }
             }
         };
-        input.parse::<Token![=]>()?;
-        let value = input.parse()?;
+
+        // Flags without a value (e.g. `#[asn1(optional)]`) are shorthand
+        // for `= "true"`
+        let value = if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            input.parse()?
+        } else {
+            LitStr::new("true", name.span())
+        };
+
         Ok(Self { name, value })
     }
 }
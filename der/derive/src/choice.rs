@@ -5,19 +5,19 @@
 mod variant;
 
 use self::variant::ChoiceVariant;
-use crate::{default_lifetime, TypeAttrs};
+use crate::{attributes::check_tag_numbers, default_lifetime, TypeAttrs};
 use proc_macro2::TokenStream;
 use proc_macro_error::abort;
 use quote::quote;
-use syn::{DeriveInput, Ident, Lifetime};
+use syn::{DeriveInput, GenericParam, Generics, Ident, LifetimeParam};
 
 /// Derive the `Choice` trait for an enum.
 pub(crate) struct DeriveChoice {
     /// Name of the enum type.
     ident: Ident,
 
-    /// Lifetime of the type.
-    lifetime: Option<Lifetime>,
+    /// Generics of the enum.
+    generics: Generics,
 
     /// Variants of this `Choice`.
     variants: Vec<ChoiceVariant>,
@@ -34,23 +34,23 @@ impl DeriveChoice {
             ),
         };
 
-        // TODO(tarcieri): properly handle multiple lifetimes
-        let lifetime = input
-            .generics
-            .lifetimes()
-            .next()
-            .map(|lt| lt.lifetime.clone());
-
         let type_attrs = TypeAttrs::parse(&input.attrs);
-        let variants = data
+        let variants: Vec<_> = data
             .variants
             .iter()
-            .map(|variant| ChoiceVariant::new(variant, &type_attrs))
+            .enumerate()
+            .map(|(index, variant)| ChoiceVariant::new(variant, index, &type_attrs))
             .collect();
 
+        check_tag_numbers(
+            variants
+                .iter()
+                .map(|variant| (&variant.ident, &variant.attrs)),
+        );
+
         Self {
             ident: input.ident,
-            lifetime,
+            generics: input.generics.clone(),
             variants,
         }
     }
@@ -59,21 +59,25 @@ impl DeriveChoice {
     pub fn to_tokens(&self) -> TokenStream {
         let ident = &self.ident;
 
-        let lifetime = match self.lifetime {
-            Some(ref lifetime) => quote!(#lifetime),
-            None => {
-                let lifetime = default_lifetime();
-                quote!(#lifetime)
-            }
-        };
+        let mut generics = self.generics.clone();
 
-        // Lifetime parameters
-        // TODO(tarcieri): support multiple lifetimes
-        let lt_params = self
-            .lifetime
-            .as_ref()
-            .map(|_| lifetime.clone())
-            .unwrap_or_default();
+        // Use the first lifetime parameter as lifetime for Decode/Encode lifetime
+        // if none found, add one.
+        let lifetime = generics
+            .lifetimes()
+            .next()
+            .map(|lt| lt.lifetime.clone())
+            .unwrap_or_else(|| {
+                let lt = default_lifetime();
+                generics
+                    .params
+                    .insert(0, GenericParam::Lifetime(LifetimeParam::new(lt.clone())));
+                lt
+            });
+
+        // We may or may not have inserted a lifetime.
+        let (orig_impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let (impl_generics, _, _) = generics.split_for_impl();
 
         let mut can_decode_body = Vec::new();
         let mut decode_body = Vec::new();
@@ -90,13 +94,13 @@ impl DeriveChoice {
         }
 
         quote! {
-            impl<#lifetime> ::der::Choice<#lifetime> for #ident<#lt_params> {
+            impl #impl_generics ::der::Choice<#lifetime> for #ident #ty_generics #where_clause {
                 fn can_decode(tag: ::der::Tag) -> bool {
                     matches!(tag, #(#can_decode_body)|*)
                 }
             }
 
-            impl<#lifetime> ::der::Decode<#lifetime> for #ident<#lt_params> {
+            impl #impl_generics ::der::Decode<#lifetime> for #ident #ty_generics #where_clause {
                 fn decode<R: ::der::Reader<#lifetime>>(reader: &mut R) -> ::der::Result<Self> {
                     use der::Reader as _;
                     match reader.peek_tag()? {
@@ -110,7 +114,7 @@ impl DeriveChoice {
                 }
             }
 
-            impl<#lt_params> ::der::EncodeValue for #ident<#lt_params> {
+            impl #orig_impl_generics ::der::EncodeValue for #ident #ty_generics #where_clause {
                 fn encode_value(&self, encoder: &mut impl ::der::Writer) -> ::der::Result<()> {
                     match self {
                         #(#encode_body)*
@@ -124,7 +128,7 @@ impl DeriveChoice {
                 }
             }
 
-            impl<#lt_params> ::der::Tagged for #ident<#lt_params> {
+            impl #orig_impl_generics ::der::Tagged for #ident #ty_generics #where_clause {
                 fn tag(&self) -> ::der::Tag {
                     match self {
                         #(#tagged_body)*
@@ -163,7 +167,7 @@ mod tests {
 
         let ir = DeriveChoice::new(input);
         assert_eq!(ir.ident, "Time");
-        assert_eq!(ir.lifetime, None);
+        assert_eq!(ir.generics.lifetimes().next(), None);
         assert_eq!(ir.variants.len(), 2);
 
         let utc_time = &ir.variants[0];
@@ -203,7 +207,10 @@ mod tests {
 
         let ir = DeriveChoice::new(input);
         assert_eq!(ir.ident, "ImplicitChoice");
-        assert_eq!(ir.lifetime.unwrap().to_string(), "'a");
+        assert_eq!(
+            ir.generics.lifetimes().next().unwrap().lifetime.to_string(),
+            "'a"
+        );
         assert_eq!(ir.variants.len(), 3);
 
         let bit_string = &ir.variants[0];
@@ -72,15 +72,24 @@ pub(super) struct ChoiceVariant {
 }
 
 impl ChoiceVariant {
-    /// Create a new [`ChoiceVariant`] from the input [`Variant`].
-    pub(super) fn new(input: &Variant, type_attrs: &TypeAttrs) -> Self {
+    /// Create a new [`ChoiceVariant`] from the input [`Variant`] at the given
+    /// position.
+    pub(super) fn new(input: &Variant, index: usize, type_attrs: &TypeAttrs) -> Self {
         let ident = input.ident.clone();
-        let attrs = FieldAttrs::parse(&input.attrs, type_attrs);
+        let mut attrs = FieldAttrs::parse(&input.attrs, type_attrs);
+
+        if attrs.context_specific.is_none() {
+            attrs.context_specific = type_attrs.auto_tag_number(index, &ident);
+        }
 
         if attrs.extensible {
             abort!(&ident, "`extensible` is not allowed on CHOICE");
         }
 
+        if attrs.inner {
+            abort!(&ident, "`inner` is not allowed on CHOICE");
+        }
+
         // Validate that variant is a 1-element tuple struct
         match &input.fields {
             // TODO(tarcieri): handle 0 bindings for ASN.1 NULL
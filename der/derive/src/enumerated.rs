@@ -7,7 +7,7 @@ use crate::{default_lifetime, ATTR_NAME};
 use proc_macro2::TokenStream;
 use proc_macro_error::abort;
 use quote::quote;
-use syn::{DeriveInput, Expr, ExprLit, Ident, Lit, LitInt, Variant};
+use syn::{DeriveInput, Expr, ExprLit, Fields, Ident, Lit, LitInt, Variant};
 
 /// Valid options for the `#[repr]` attribute on `Enumerated` types.
 const REPR_TYPES: &[&str] = &["u8", "u16", "u32"];
@@ -25,6 +25,10 @@ pub(crate) struct DeriveEnumerated {
 
     /// Variants of this enum.
     variants: Vec<EnumeratedVariant>,
+
+    /// Variant which captures any unrecognized values, if present, i.e.
+    /// a 1-tuple variant wrapping the `repr` type with `#[asn1(other = "true")]`.
+    other: Option<Ident>,
 }
 
 impl DeriveEnumerated {
@@ -83,7 +87,39 @@ impl DeriveEnumerated {
         }
 
         // Parse enum variants
-        let variants = data.variants.iter().map(EnumeratedVariant::new).collect();
+        let mut variants = Vec::new();
+        let mut other: Option<Ident> = None;
+
+        for variant in &data.variants {
+            if !EnumeratedVariant::is_other(variant) {
+                variants.push(EnumeratedVariant::new(variant));
+                continue;
+            }
+
+            if other.is_some() {
+                abort!(
+                    variant,
+                    "multiple `#[asn1(other = \"true\")]` variants encountered on `Enumerated`",
+                );
+            }
+
+            match &variant.fields {
+                Fields::Unnamed(fields) if fields.unnamed.len() == 1 => (),
+                _ => abort!(
+                    variant,
+                    "`#[asn1(other = \"true\")]` variant must be a 1-element tuple struct"
+                ),
+            }
+
+            if let Some((_, discriminant)) = &variant.discriminant {
+                abort!(
+                    discriminant,
+                    "`#[asn1(other = \"true\")]` variant can't have a discriminant"
+                );
+            }
+
+            other = Some(variant.ident.clone());
+        }
 
         Self {
             ident: input.ident.clone(),
@@ -95,6 +131,7 @@ impl DeriveEnumerated {
                 )
             }),
             variants,
+            other,
             integer,
         }
     }
@@ -114,6 +151,30 @@ impl DeriveEnumerated {
             try_from_body.push(variant.to_try_from_tokens());
         }
 
+        // Unrecognized values are either captured by the `other` variant, or rejected
+        let (value, try_from_fallback) = match &self.other {
+            Some(other) => {
+                let mut value_body = Vec::new();
+                for variant in &self.variants {
+                    value_body.push(variant.to_value_tokens());
+                }
+
+                (
+                    quote! {
+                        match self {
+                            #(#value_body)*
+                            Self::#other(n) => *n,
+                        }
+                    },
+                    quote! { n => Ok(Self::#other(n)), },
+                )
+            }
+            None => (
+                quote! { *self as #repr },
+                quote! { _ => Err(#tag.value_error()) },
+            ),
+        };
+
         quote! {
             impl<#default_lifetime> ::der::DecodeValue<#default_lifetime> for #ident {
                 fn decode_value<R: ::der::Reader<#default_lifetime>>(
@@ -126,11 +187,13 @@ impl DeriveEnumerated {
 
             impl ::der::EncodeValue for #ident {
                 fn value_len(&self) -> ::der::Result<::der::Length> {
-                    ::der::EncodeValue::value_len(&(*self as #repr))
+                    let value: #repr = #value;
+                    ::der::EncodeValue::value_len(&value)
                 }
 
                 fn encode_value(&self, encoder: &mut impl ::der::Writer) -> ::der::Result<()> {
-                    ::der::EncodeValue::encode_value(&(*self as #repr), encoder)
+                    let value: #repr = #value;
+                    ::der::EncodeValue::encode_value(&value, encoder)
                 }
             }
 
@@ -144,7 +207,7 @@ impl DeriveEnumerated {
                 fn try_from(n: #repr) -> ::der::Result<Self> {
                     match n {
                         #(#try_from_body)*
-                        _ => Err(#tag.value_error())
+                        #try_from_fallback
                     }
                 }
             }
@@ -162,17 +225,31 @@ pub struct EnumeratedVariant {
 }
 
 impl EnumeratedVariant {
-    /// Create a new [`ChoiceVariant`] from the input [`Variant`].
-    fn new(input: &Variant) -> Self {
-        for attr in &input.attrs {
-            if attr.path().is_ident(ATTR_NAME) {
-                abort!(
-                    attr,
-                    "`asn1` attribute is not allowed on fields of `Enumerated` types"
-                );
+    /// Is the input [`Variant`] marked with `#[asn1(other = "true")]`?
+    fn is_other(input: &Variant) -> bool {
+        let mut parsed_attrs = Vec::new();
+        AttrNameValue::from_attributes(&input.attrs, &mut parsed_attrs);
+
+        let mut other = None;
+
+        for attr in parsed_attrs {
+            match attr.parse_value("other") {
+                Some(_) if other.is_some() => {
+                    abort!(attr.name, "duplicate ASN.1 `other` attribute")
+                }
+                Some(value) => other = Some(value),
+                None => abort!(
+                    attr.name,
+                    "invalid `asn1` attribute on `Enumerated` variant (valid options are `other`)",
+                ),
             }
         }
 
+        other.unwrap_or_default()
+    }
+
+    /// Create a new [`EnumeratedVariant`] from the input [`Variant`].
+    fn new(input: &Variant) -> Self {
         match &input.discriminant {
             Some((
                 _,
@@ -197,6 +274,15 @@ impl EnumeratedVariant {
             #discriminant => Ok(Self::#ident),
         }
     }
+
+    /// Write the match arm converting this variant into its integer value.
+    pub fn to_value_tokens(&self) -> TokenStream {
+        let ident = &self.ident;
+        let discriminant = &self.discriminant;
+        quote! {
+            Self::#ident => #discriminant,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -239,5 +325,28 @@ mod tests {
         let key_compromise = &ir.variants[2];
         assert_eq!(key_compromise.ident, "CaCompromise");
         assert_eq!(key_compromise.discriminant.to_string(), "2");
+
+        assert!(ir.other.is_none());
+    }
+
+    /// CMP `PKIStatus` which tolerates unknown status codes.
+    #[test]
+    fn pkistatus_other_example() {
+        let input = parse_quote! {
+            #[repr(u8)]
+            pub enum PkiStatus {
+                Accepted = 0,
+                GrantedWithMods = 1,
+                Rejection = 2,
+                #[asn1(other = "true")]
+                Unknown(u8),
+            }
+        };
+
+        let ir = DeriveEnumerated::new(input);
+        assert_eq!(ir.ident, "PkiStatus");
+        assert_eq!(ir.repr, "u8");
+        assert_eq!(ir.variants.len(), 3);
+        assert_eq!(ir.other.unwrap(), "Unknown");
     }
 }
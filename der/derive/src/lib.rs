@@ -45,6 +45,19 @@
 //! The default is `EXPLICIT`, so the attribute only needs to be added when
 //! a particular module is declared `IMPLICIT`.
 //!
+//! ### `#[asn1(auto_tag)]` attribute: automatic `CONTEXT-SPECIFIC` tags
+//!
+//! This attribute assigns `CONTEXT-SPECIFIC` tag numbers to struct fields or
+//! enum variants based on their position, i.e. the first field is tagged
+//! `[0]`, the second `[1]` and so on, similar to `AUTOMATIC TAGS` in ASN.1.
+//!
+//! Fields with an explicit `context_specific` attribute keep their tag
+//! number. Combine it with `tag_mode = "IMPLICIT"` to match the `IMPLICIT`
+//! tagging used by `AUTOMATIC TAGS`.
+//!
+//! Regardless of this attribute, tag numbers used by more than one field or
+//! variant are rejected at compile time.
+//!
 //! ## Field-level attributes
 //!
 //! The following attributes can be added to either the fields of a particular
@@ -62,6 +75,13 @@
 //! This behaves like `serde_derive`'s `default` attribute, allowing you to
 //! specify the path to a function which returns a default value.
 //!
+//! As required by DER, the field is omitted when encoding if it is equal to
+//! the default value, and the default value is substituted when decoding if
+//! the field is absent. The field type must impl [`PartialEq`].
+//!
+//! It can be combined with `context_specific` and `type`, but is mutually
+//! exclusive with `optional`.
+//!
 //! ### `#[asn1(extensible = "true")]` attribute: support for `...` extensibility operator
 //!
 //! This attribute can be applied to the fields of `struct` types, and will
@@ -98,6 +118,23 @@
 //! This attribute can be used to specify that an "inner" type is constructed. It is most
 //! commonly used when a `CHOICE` has a constructed inner type.
 //!
+//! ### `#[asn1(inner = "true")]` attribute: values encapsulated in an `OCTET STRING`
+//!
+//! This attribute can be applied to the fields of `struct` types whose value
+//! is DER-encoded inside of an `OCTET STRING`, such as X.509 extension values
+//! or CMS `eContent`. The field is decoded using
+//! [`der::asn1::OctetStringRef::decode_inner`] and encoded using
+//! [`der::asn1::OctetStringInnerRef`].
+//!
+//! It can be combined with `context_specific`, `default` and `optional`, but
+//! is mutually exclusive with `type`.
+//!
+//! ### Boolean attributes
+//!
+//! Attributes which take a boolean value (i.e. `constructed`, `extensible`,
+//! `inner` and `optional`) can also be written without one, e.g.
+//! `#[asn1(inner)]` is shorthand for `#[asn1(inner = "true")]`.
+//!
 //! Note: please open a GitHub Issue if you would like to request support
 //! for additional ASN.1 types.
 //!
@@ -108,6 +145,8 @@
 //! [`der::asn1::Ia5String`]: https://docs.rs/der/latest/der/asn1/struct.Ia5String.html
 //! [`der::asn1::GeneralizedTime`]: https://docs.rs/der/latest/der/asn1/struct.GeneralizedTime.html
 //! [`der::asn1::OctetString`]: https://docs.rs/der/latest/der/asn1/struct.OctetString.html
+//! [`der::asn1::OctetStringInnerRef`]: https://docs.rs/der/latest/der/asn1/struct.OctetStringInnerRef.html
+//! [`der::asn1::OctetStringRef::decode_inner`]: https://docs.rs/der/latest/der/asn1/struct.OctetStringRef.html#method.decode_inner
 //! [`der::asn1::PrintableString`]: https://docs.rs/der/latest/der/asn1/struct.PrintableString.html
 //! [`der::asn1::UtcTime`]: https://docs.rs/der/latest/der/asn1/struct.UtcTime.html
 //! [`der::asn1::Utf8String`]: https://docs.rs/der/latest/der/asn1/struct.Utf8String.html
@@ -221,6 +260,31 @@ pub fn derive_choice(input: TokenStream) -> TokenStream {
 ///
 /// Note that the derive macro will write a `TryFrom<...>` impl for the
 /// provided `#[repr]`, which is used by the decoder.
+///
+/// # Unrecognized values
+///
+/// By default, decoding a value which doesn't correspond to any variant is an
+/// error. To tolerate unknown values instead (e.g. status codes added by later
+/// revisions of a protocol), add a 1-tuple variant wrapping the `#[repr]` type
+/// and mark it with `#[asn1(other = "true")]`:
+///
+/// ```ignore
+/// use der::Enumerated;
+///
+/// #[derive(Enumerated, Copy, Clone, Debug, Eq, PartialEq)]
+/// #[repr(u8)]
+/// pub enum PkiStatus {
+///     Accepted = 0,
+///     GrantedWithMods = 1,
+///     Rejection = 2,
+///     #[asn1(other = "true")]
+///     Unknown(u8),
+/// }
+/// ```
+///
+/// Unrecognized values are decoded as this variant and encoded unchanged.
+/// Note that it's the caller's responsibility not to construct it with a
+/// value which belongs to another variant, as that would decode differently.
 #[proc_macro_derive(Enumerated, attributes(asn1))]
 #[proc_macro_error]
 pub fn derive_enumerated(input: TokenStream) -> TokenStream {
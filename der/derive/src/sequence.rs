@@ -3,7 +3,7 @@
 
 mod field;
 
-use crate::{default_lifetime, TypeAttrs};
+use crate::{attributes::check_tag_numbers, default_lifetime, TypeAttrs};
 use field::SequenceField;
 use proc_macro2::TokenStream;
 use proc_macro_error::abort;
@@ -35,12 +35,15 @@ impl DeriveSequence {
 
         let type_attrs = TypeAttrs::parse(&input.attrs);
 
-        let fields = data
+        let fields: Vec<_> = data
             .fields
             .iter()
-            .map(|field| SequenceField::new(field, &type_attrs))
+            .enumerate()
+            .map(|(index, field)| SequenceField::new(field, index, &type_attrs))
             .collect();
 
+        check_tag_numbers(fields.iter().map(|field| (&field.ident, &field.attrs)));
+
         Self {
             ident: input.ident,
             generics: input.generics.clone(),
@@ -87,6 +90,7 @@ impl DeriveSequence {
 
         quote! {
             impl #impl_generics ::der::DecodeValue<#lifetime> for #ident #ty_generics #where_clause {
+                #[allow(clippy::redundant_closure_call)]
                 fn decode_value<R: ::der::Reader<#lifetime>>(
                     reader: &mut R,
                     header: ::der::Header,
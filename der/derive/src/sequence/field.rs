@@ -4,7 +4,7 @@ use crate::{Asn1Type, FieldAttrs, TagMode, TagNumber, TypeAttrs};
 use proc_macro2::TokenStream;
 use proc_macro_error::abort;
 use quote::quote;
-use syn::{Field, Ident, Path, Type};
+use syn::{ext::IdentExt, Field, Ident, Path, Type};
 
 /// "IR" for a field of a derived `Sequence`.
 pub(super) struct SequenceField {
@@ -19,8 +19,9 @@ pub(super) struct SequenceField {
 }
 
 impl SequenceField {
-    /// Create a new [`SequenceField`] from the input [`Field`].
-    pub(super) fn new(field: &Field, type_attrs: &TypeAttrs) -> Self {
+    /// Create a new [`SequenceField`] from the input [`Field`] at the given
+    /// position.
+    pub(super) fn new(field: &Field, index: usize, type_attrs: &TypeAttrs) -> Self {
         let ident = field.ident.as_ref().cloned().unwrap_or_else(|| {
             abort!(
                 field,
@@ -28,13 +29,10 @@ impl SequenceField {
             )
         });
 
-        let attrs = FieldAttrs::parse(&field.attrs, type_attrs);
+        let mut attrs = FieldAttrs::parse(&field.attrs, type_attrs);
 
-        if attrs.asn1_type.is_some() && attrs.default.is_some() {
-            abort!(
-                ident,
-                "ASN.1 `type` and `default` options cannot be combined"
-            );
+        if attrs.context_specific.is_none() {
+            attrs.context_specific = type_attrs.auto_tag_number(index, &ident);
         }
 
         if attrs.default.is_some() && attrs.optional {
@@ -55,21 +53,19 @@ impl SequenceField {
     pub(super) fn to_decode_tokens(&self) -> TokenStream {
         let mut lowerer = LowerFieldDecoder::new(&self.attrs);
 
-        if self.attrs.asn1_type.is_some() {
-            lowerer.apply_asn1_type(self.attrs.optional);
-        }
-
         if let Some(default) = &self.attrs.default {
-            // TODO(tarcieri): default in conjunction with ASN.1 types?
-            debug_assert!(
-                self.attrs.asn1_type.is_none(),
-                "`type` and `default` are mutually exclusive"
-            );
-
-            // TODO(tarcieri): support for context-specific fields with defaults?
-            if self.attrs.context_specific.is_none() {
+            // Defaults for fields with an ASN.1 type, `inner` fields, or fields
+            // with a context-specific tag are handled by `FieldAttrs::decoder`
+            if self.attrs.asn1_type.is_none()
+                && !self.attrs.inner
+                && self.attrs.context_specific.is_none()
+            {
                 lowerer.apply_default(default, &self.field_type);
             }
+        } else if self.attrs.asn1_type.is_some() {
+            lowerer.apply_asn1_type(self.attrs.optional);
+        } else if self.attrs.inner {
+            lowerer.apply_inner(self.attrs.optional);
         }
 
         lowerer.into_tokens(&self.ident)
@@ -81,12 +77,9 @@ impl SequenceField {
         let attrs = &self.attrs;
 
         if let Some(ty) = &attrs.asn1_type {
-            // TODO(tarcieri): default in conjunction with ASN.1 types?
-            debug_assert!(
-                attrs.default.is_none(),
-                "`type` and `default` are mutually exclusive"
-            );
             lowerer.apply_asn1_type(ty, attrs.optional);
+        } else if attrs.inner {
+            lowerer.apply_inner(attrs.optional);
         }
 
         if let Some(tag_number) = &attrs.context_specific {
@@ -122,9 +115,15 @@ impl LowerFieldDecoder {
     ///  the field decoder to tokens.
     fn into_tokens(self, ident: &Ident) -> TokenStream {
         let decoder = self.decoder;
+        let name = ident.unraw().to_string();
 
+        // Errors are annotated with the field name for `der::ErrorPath`
         quote! {
-            let #ident = #decoder;
+            let #ident = (|| {
+                let value = #decoder;
+                ::der::Result::Ok(value)
+            })()
+            .map_err(|e| e.in_field(#name))?;
         }
     }
 
@@ -143,10 +142,25 @@ impl LowerFieldDecoder {
         }
     }
 
+    /// Decode the field from the contents of an `OCTET STRING`.
+    fn apply_inner(&mut self, optional: bool) {
+        let decoder = &self.decoder;
+
+        self.decoder = if optional {
+            quote! {
+                #decoder.map(::der::asn1::OctetStringRef::decode_inner).transpose()?
+            }
+        } else {
+            quote! {
+                #decoder.decode_inner()?
+            }
+        }
+    }
+
     /// Handle default value for a type.
     fn apply_default(&mut self, default: &Path, field_type: &Type) {
         self.decoder = quote! {
-            Option::<#field_type>::decode(reader)?.unwrap_or_else(#default);
+            Option::<#field_type>::decode(reader)?.unwrap_or_else(#default)
         };
     }
 }
@@ -189,16 +203,25 @@ impl LowerFieldEncoder {
         };
     }
 
+    /// Encode the field as the contents of an `OCTET STRING`.
+    fn apply_inner(&mut self, optional: bool) {
+        let binding = &self.encoder;
+
+        self.encoder = if optional {
+            quote!(#binding.as_ref().map(::der::asn1::OctetStringInnerRef::new))
+        } else {
+            quote!(::der::asn1::OctetStringInnerRef::new(&#binding))
+        };
+    }
+
     /// Handle default value for a type.
     fn apply_default(&mut self, ident: &Ident, default: &Path) {
         let encoder = &self.encoder;
 
+        // Evaluated eagerly (rather than with `if`/`else`) so temporaries
+        // borrowed by the encoder live until the end of the statement
         self.encoder = quote! {
-            if &self.#ident == &#default() {
-                None
-            } else {
-                Some(#encoder)
-            }
+            Some(#encoder).filter(|_| &self.#ident != &#default())
         };
     }
 
@@ -273,6 +296,7 @@ mod tests {
             optional: false,
             tag_mode: TagMode::Explicit,
             constructed: false,
+            inner: false,
         };
 
         let field_type = Ident::new("String", span);
@@ -286,7 +310,11 @@ mod tests {
         assert_eq!(
             field.to_decode_tokens().to_string(),
             quote! {
-                let example_field = reader.decode()?;
+                let example_field = (|| {
+                    let value = reader.decode()?;
+                    ::der::Result::Ok(value)
+                })()
+                .map_err(|e| e.in_field("example_field"))?;
             }
             .to_string()
         );
@@ -313,6 +341,7 @@ mod tests {
             optional: false,
             tag_mode: TagMode::Implicit,
             constructed: false,
+            inner: false,
         };
 
         let field_type = Ident::new("String", span);
@@ -326,7 +355,8 @@ mod tests {
         assert_eq!(
             field.to_decode_tokens().to_string(),
             quote! {
-                let implicit_field = ::der::asn1::ContextSpecific::<>::decode_implicit(
+                let implicit_field = (|| {
+                    let value = ::der::asn1::ContextSpecific::<>::decode_implicit(
                         reader,
                         ::der::TagNumber::N0
                     )?
@@ -338,6 +368,9 @@ mod tests {
                         .value_error()
                     })?
                     .value;
+                    ::der::Result::Ok(value)
+                })()
+                .map_err(|e| e.in_field("implicit_field"))?;
             }
             .to_string()
         );
@@ -354,4 +387,49 @@ mod tests {
             .to_string()
         );
     }
+
+    #[test]
+    fn default() {
+        let span = Span::call_site();
+        let ident = Ident::new("default_field", span);
+
+        let attrs = FieldAttrs {
+            asn1_type: None,
+            context_specific: None,
+            default: Some(Path::from(Ident::new("default_fn", span))),
+            extensible: false,
+            optional: false,
+            tag_mode: TagMode::Explicit,
+            constructed: false,
+            inner: false,
+        };
+
+        let field_type = Ident::new("bool", span);
+
+        let field = SequenceField {
+            ident,
+            attrs,
+            field_type: type_path(field_type),
+        };
+
+        assert_eq!(
+            field.to_decode_tokens().to_string(),
+            quote! {
+                let default_field = (|| {
+                    let value = Option::<bool>::decode(reader)?.unwrap_or_else(default_fn);
+                    ::der::Result::Ok(value)
+                })()
+                .map_err(|e| e.in_field("default_field"))?;
+            }
+            .to_string()
+        );
+
+        assert_eq!(
+            field.to_encode_tokens().to_string(),
+            quote! {
+                Some(self.default_field).filter(|_| &self.default_field != &default_fn())
+            }
+            .to_string()
+        );
+    }
 }
@@ -9,15 +9,15 @@ use crate::{FieldAttrs, TypeAttrs};
 use proc_macro2::TokenStream;
 use proc_macro_error::abort;
 use quote::quote;
-use syn::{DeriveInput, Field, Ident, Lifetime, Variant};
+use syn::{DeriveInput, Field, Generics, Ident, Variant};
 
 /// Derive the `Enumerated` trait for an enum.
 pub(crate) struct DeriveValueOrd {
     /// Name of the enum.
     ident: Ident,
 
-    /// Lifetime of the struct.
-    lifetime: Option<Lifetime>,
+    /// Generics of the struct.
+    generics: Generics,
 
     /// Fields of structs or enum variants.
     fields: Vec<ValueField>,
@@ -32,13 +32,6 @@ impl DeriveValueOrd {
         let ident = input.ident;
         let type_attrs = TypeAttrs::parse(&input.attrs);
 
-        // TODO(tarcieri): properly handle multiple lifetimes
-        let lifetime = input
-            .generics
-            .lifetimes()
-            .next()
-            .map(|lt| lt.lifetime.clone());
-
         let (fields, input_type) = match input.data {
             syn::Data::Enum(data) => (
                 data.variants
@@ -63,7 +56,7 @@ impl DeriveValueOrd {
 
         Self {
             ident,
-            lifetime,
+            generics: input.generics.clone(),
             fields,
             input_type,
         }
@@ -73,13 +66,7 @@ impl DeriveValueOrd {
     pub fn to_tokens(&self) -> TokenStream {
         let ident = &self.ident;
 
-        // Lifetime parameters
-        // TODO(tarcieri): support multiple lifetimes
-        let lt_params = self
-            .lifetime
-            .as_ref()
-            .map(|lt| vec![lt.clone()])
-            .unwrap_or_default();
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
 
         let mut body = Vec::new();
 
@@ -111,7 +98,7 @@ impl DeriveValueOrd {
         };
 
         quote! {
-            impl<#(#lt_params)*> ::der::ValueOrd for #ident<#(#lt_params)*> {
+            impl #impl_generics ::der::ValueOrd for #ident #ty_generics #where_clause {
                 fn value_cmp(&self, other: &Self) -> ::der::Result<::core::cmp::Ordering> {
                     #body
                 }
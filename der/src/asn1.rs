@@ -11,7 +11,10 @@ mod bmp_string;
 mod boolean;
 mod choice;
 mod context_specific;
+mod duration;
+mod general_string;
 mod generalized_time;
+mod graphic_string;
 mod ia5_string;
 mod integer;
 mod null;
@@ -22,10 +25,13 @@ mod optional;
 mod printable_string;
 #[cfg(feature = "real")]
 mod real;
+#[cfg(feature = "oid")]
+mod relative_oid;
 mod sequence;
 mod sequence_of;
 mod set_of;
 mod teletex_string;
+mod time;
 mod utc_time;
 mod utf8_string;
 mod videotex_string;
@@ -35,16 +41,20 @@ pub use self::{
     bit_string::{BitStringIter, BitStringRef},
     choice::Choice,
     context_specific::{ContextSpecific, ContextSpecificRef},
-    generalized_time::GeneralizedTime,
+    duration::Duration,
+    general_string::GeneralStringRef,
+    generalized_time::{FractionalGeneralizedTime, GeneralizedTime},
+    graphic_string::GraphicStringRef,
     ia5_string::Ia5StringRef,
     integer::{int::IntRef, uint::UintRef},
     null::Null,
-    octet_string::OctetStringRef,
+    octet_string::{OctetStringInnerRef, OctetStringRef},
     printable_string::PrintableStringRef,
     sequence::{Sequence, SequenceRef},
     sequence_of::{SequenceOf, SequenceOfIter},
     set_of::{SetOf, SetOfIter},
     teletex_string::TeletexStringRef,
+    time::Time,
     utc_time::UtcTime,
     utf8_string::Utf8StringRef,
     videotex_string::VideotexStringRef,
@@ -55,13 +65,22 @@ pub use self::{
     any::Any,
     bit_string::BitString,
     bmp_string::BmpString,
+    general_string::GeneralString,
+    graphic_string::GraphicString,
     ia5_string::Ia5String,
     integer::{int::Int, uint::Uint},
     octet_string::OctetString,
     printable_string::PrintableString,
-    set_of::SetOfVec,
+    set_of::{SetOfVec, SetOfVecBuilder},
     teletex_string::TeletexString,
+    videotex_string::VideotexString,
 };
 
 #[cfg(feature = "oid")]
-pub use const_oid::ObjectIdentifier;
+pub use {
+    self::relative_oid::{RelativeOid, RelativeOidArcs},
+    const_oid::ObjectIdentifier,
+};
+
+#[cfg(feature = "real")]
+pub use self::real::Real;
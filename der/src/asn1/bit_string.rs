@@ -12,6 +12,10 @@ use core::{cmp::Ordering, iter::FusedIterator};
 /// a sequence of bytes with a known number of "unused bits".
 ///
 /// This is a zero-copy reference type which borrows from the input data.
+///
+/// Types defined using an ASN.1 named bit list (e.g. X.509 `KeyUsage`) can
+/// be modeled as a `FlagSet` when the `flagset` feature is enabled, which
+/// encodes as a `BIT STRING` with trailing zero bits removed as DER requires.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 pub struct BitStringRef<'a> {
     /// Number of unused bits in the final octet.
@@ -108,6 +112,22 @@ impl<'a> BitStringRef<'a> {
         self.inner.as_slice()
     }
 
+    /// Get the bit at the given position, where position 0 is the most
+    /// significant bit of the first octet (i.e. the first bit of a named bit
+    /// list).
+    ///
+    /// Returns `None` if the position is outside of this `BIT STRING`.
+    #[allow(clippy::integer_arithmetic)]
+    pub fn get(&self, position: usize) -> Option<bool> {
+        if position >= self.bit_len() {
+            return None;
+        }
+
+        let byte = self.raw_bytes().get(position / 8)?;
+        let bit = 1u8 << (7 - (position % 8));
+        Some(byte & bit != 0)
+    }
+
     /// Iterator over the bits of this `BIT STRING`.
     pub fn bits(self) -> BitStringIter<'a> {
         BitStringIter {
@@ -300,6 +320,14 @@ mod allocating {
             self.inner.as_slice()
         }
 
+        /// Get the bit at the given position, where position 0 is the most
+        /// significant bit of the first octet.
+        ///
+        /// Returns `None` if the position is outside of this `BIT STRING`.
+        pub fn get(&self, position: usize) -> Option<bool> {
+            BitStringRef::from(self).get(position)
+        }
+
         /// Iterator over the bits of this `BIT STRING`.
         pub fn bits(&self) -> BitStringIter<'_> {
             BitStringRef::from(self).bits()
@@ -383,6 +411,41 @@ mod allocating {
             self.into()
         }
     }
+
+    #[cfg(feature = "flagset")]
+    impl<T> TryFrom<&BitString> for flagset::FlagSet<T>
+    where
+        T: flagset::Flags,
+        T::Type: From<bool>,
+        T::Type: core::ops::Shl<usize, Output = T::Type>,
+    {
+        type Error = Error;
+
+        fn try_from(bits: &BitString) -> Result<Self> {
+            BitStringRef::from(bits).try_into()
+        }
+    }
+
+    /// Convert a [`FlagSet`][`flagset::FlagSet`] into a [`BitString`] containing
+    /// its named bits, omitting trailing zero bits as required by DER.
+    #[cfg(feature = "flagset")]
+    #[allow(clippy::cast_possible_truncation, clippy::integer_arithmetic)]
+    impl<T: flagset::Flags> From<flagset::FlagSet<T>> for BitString
+    where
+        u128: From<T::Type>,
+    {
+        fn from(set: flagset::FlagSet<T>) -> BitString {
+            let (lead, buff) = encode_flagset(&set);
+            let mut bytes = buff.to_vec();
+            bytes.truncate(buff.len() - lead / 8);
+
+            BitString {
+                unused_bits: (lead % 8) as u8,
+                bit_length: 128 - lead,
+                inner: bytes,
+            }
+        }
+    }
 }
 
 /// Iterator over the bits of a [`BitString`].
@@ -397,25 +460,20 @@ pub struct BitStringIter<'a> {
 impl<'a> Iterator for BitStringIter<'a> {
     type Item = bool;
 
-    #[allow(clippy::integer_arithmetic)]
     fn next(&mut self) -> Option<bool> {
-        if self.position >= self.bit_string.bit_len() {
-            return None;
-        }
-
-        let byte = self.bit_string.raw_bytes().get(self.position / 8)?;
-        let bit = 1u8 << (7 - (self.position % 8));
+        let bit = self.bit_string.get(self.position)?;
         self.position = self.position.checked_add(1)?;
-        Some(byte & bit != 0)
+        Some(bit)
     }
-}
 
-impl<'a> ExactSizeIterator for BitStringIter<'a> {
-    fn len(&self) -> usize {
-        self.bit_string.bit_len()
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.bit_string.bit_len().saturating_sub(self.position);
+        (len, Some(len))
     }
 }
 
+impl<'a> ExactSizeIterator for BitStringIter<'a> {}
+
 impl<'a> FusedIterator for BitStringIter<'a> {}
 
 #[cfg(feature = "flagset")]
@@ -435,7 +493,6 @@ where
 }
 
 #[cfg(feature = "flagset")]
-#[allow(clippy::integer_arithmetic)]
 impl<'a, T> DecodeValue<'a> for flagset::FlagSet<T>
 where
     T: flagset::Flags,
@@ -445,11 +502,25 @@ where
     fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
         let position = reader.position();
         let bits = BitStringRef::decode_value(reader, header)?;
+        Self::try_from(bits).map_err(|e| Error::new(e.kind(), position))
+    }
+}
 
+#[cfg(feature = "flagset")]
+#[allow(clippy::integer_arithmetic)]
+impl<'a, T> TryFrom<BitStringRef<'a>> for flagset::FlagSet<T>
+where
+    T: flagset::Flags,
+    T::Type: From<bool>,
+    T::Type: core::ops::Shl<usize, Output = T::Type>,
+{
+    type Error = Error;
+
+    fn try_from(bits: BitStringRef<'a>) -> Result<Self> {
         let mut flags = T::none().bits();
 
         if bits.bit_len() > core::mem::size_of_val(&flags) * 8 {
-            return Err(Error::new(ErrorKind::Overlength, position));
+            return Err(ErrorKind::Overlength.into());
         }
 
         for (i, bit) in bits.bits().enumerate() {
@@ -542,6 +613,21 @@ mod tests {
         assert_eq!(bits.next(), None);
     }
 
+    #[test]
+    fn get_bits() {
+        let bs = parse_bitstring(&hex!("066e5dc0")).unwrap();
+        assert_eq!(bs.get(0), Some(false));
+        assert_eq!(bs.get(1), Some(true));
+        assert_eq!(bs.get(17), Some(true));
+
+        // Unused bits aren't accessible
+        assert_eq!(bs.get(18), None);
+
+        let mut bits = bs.bits();
+        bits.next().unwrap();
+        assert_eq!(bits.len(), 17);
+    }
+
     #[test]
     fn reject_unused_bits_in_empty_string() {
         assert_eq!(
@@ -549,4 +635,66 @@ mod tests {
             Tag::BitString.value_error().kind()
         )
     }
+
+    #[cfg(feature = "flagset")]
+    mod flagset {
+        use super::parse_bitstring;
+        use crate::{Decode, Encode, ErrorKind};
+        use flagset::{flags, FlagSet};
+        use hex_literal::hex;
+
+        flags! {
+            enum KeyUsages: u16 {
+                DigitalSignature = 1 << 0,
+                KeyCertSign = 1 << 5,
+                CrlSign = 1 << 6,
+                DecipherOnly = 1 << 8,
+            }
+        }
+
+        #[test]
+        fn encode_trims_trailing_zero_bits() {
+            let mut buf = [0u8; 8];
+
+            for (flags, der) in [
+                (
+                    FlagSet::from(KeyUsages::DigitalSignature),
+                    &hex!("03 02 07 80")[..],
+                ),
+                (
+                    KeyUsages::KeyCertSign | KeyUsages::CrlSign,
+                    &hex!("03 02 01 06"),
+                ),
+                (KeyUsages::DecipherOnly.into(), &hex!("03 03 07 00 80")),
+                (FlagSet::default(), &hex!("03 01 00")),
+            ] {
+                assert_eq!(flags.encode_to_slice(&mut buf).unwrap(), der);
+                assert_eq!(FlagSet::<KeyUsages>::from_der(der).unwrap(), flags);
+            }
+        }
+
+        #[test]
+        fn from_bit_string_ref() {
+            let bs = parse_bitstring(&hex!("01 06")).unwrap();
+            let flags = FlagSet::<KeyUsages>::try_from(bs).unwrap();
+            assert_eq!(flags, KeyUsages::KeyCertSign | KeyUsages::CrlSign);
+
+            // More bits than fit in the underlying `u16`
+            let bs = parse_bitstring(&hex!("07 00 00 80")).unwrap();
+            let err = FlagSet::<KeyUsages>::try_from(bs).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::Overlength);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn bit_string_round_trip() {
+            let flags = KeyUsages::KeyCertSign | KeyUsages::CrlSign;
+            let bs = crate::asn1::BitString::from(flags);
+            assert_eq!(bs.unused_bits(), 1);
+            assert_eq!(bs.bit_len(), 7);
+            assert_eq!(bs.get(5), Some(true));
+            assert_eq!(bs.get(4), Some(false));
+            assert_eq!(FlagSet::<KeyUsages>::try_from(&bs).unwrap(), flags);
+        }
+    }
 }
@@ -10,6 +10,7 @@ use core::cmp::Ordering;
 ///
 /// This type decodes/encodes a field which is specific to a particular context
 /// and is identified by a [`TagNumber`].
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 pub struct ContextSpecific<T> {
     /// Context-specific tag number sans the leading `0b10000000` class
@@ -0,0 +1,484 @@
+//! ASN.1 `DURATION` support.
+
+use crate::{
+    datetime, Decode, DecodeValue, Encode, EncodeValue, Error, ErrorKind, Header, Length, Reader,
+    Result, Tag, Writer,
+};
+use core::{fmt, str, str::FromStr};
+
+/// Number of seconds in each unit of a duration which has a fixed length,
+/// i.e. weeks, days, hours, minutes and seconds.
+const UNIT_SECONDS: [u64; 5] = [7 * 86400, 86400, 3600, 60, 1];
+
+/// Nanoseconds per second.
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+/// ASN.1 `DURATION` type.
+///
+/// Introduced in X.680:2008 as a subtype of `TIME`, `DURATION` values are
+/// ISO 8601 durations, e.g. `P1Y2M10DT2H30M` or `P3W`. The smallest unit
+/// which is present may have a fractional part, e.g. `PT0.5S`.
+///
+/// The string is stored as-is, and may be at most [`Duration::MAX_SIZE`]
+/// bytes. Durations which don't contain years or months have a well-defined
+/// length and can be converted to a [`core::time::Duration`].
+///
+/// # Tag number
+///
+/// The tag number of `DURATION` is 34, which can't be represented as a
+/// [`Tag`] (see [`TagNumber`][`crate::TagNumber`]). [`Decode`] and [`Encode`]
+/// are implemented using its two identifier octets (`1F 22`) nonetheless, but
+/// [`FixedTag`][`crate::FixedTag`] isn't, so [`Duration`] can't be used as an
+/// `OPTIONAL` field or in a `CHOICE`. Errors refer to [`Tag::Time`].
+///
+/// ```
+/// # fn main() -> der::Result<()> {
+/// use der::asn1::Duration;
+///
+/// let duration = Duration::new("PT1H30M")?;
+/// assert_eq!(core::time::Duration::try_from(duration)?.as_secs(), 5400);
+/// assert_eq!(Duration::from(core::time::Duration::from_millis(1500)).as_str(), "PT1.5S");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Copy, Clone, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct Duration {
+    /// ISO 8601 duration, zero-padded.
+    bytes: [u8; Self::MAX_SIZE],
+
+    /// Length of the duration in bytes.
+    length: u8,
+}
+
+impl Duration {
+    /// Identifier octets of `DURATION`, which use the high tag number form.
+    pub const IDENTIFIER: [u8; 2] = [0x1F, 0x22];
+
+    /// Maximum size of an ISO 8601 duration supported by [`Duration`] in
+    /// bytes.
+    pub const MAX_SIZE: usize = 64;
+
+    /// Create a new [`Duration`] from an ISO 8601 duration.
+    ///
+    /// Either `.` or `,` may be used as the decimal mark.
+    pub fn new<T>(input: &T) -> Result<Self>
+    where
+        T: AsRef<[u8]> + ?Sized,
+    {
+        let input = input.as_ref();
+
+        if input.is_empty() || input.len() > Self::MAX_SIZE {
+            return Err(Tag::Time.length_error());
+        }
+
+        parse(input).ok_or_else(|| Tag::Time.value_error())?;
+
+        let mut bytes = [0u8; Self::MAX_SIZE];
+        bytes[..input.len()].copy_from_slice(input);
+
+        Ok(Self {
+            bytes,
+            length: input.len().try_into()?,
+        })
+    }
+
+    /// Get this [`Duration`] as a string.
+    pub fn as_str(&self) -> &str {
+        // Only ASCII characters are accepted by `Duration::new`
+        str::from_utf8(self.as_bytes()).unwrap_or_default()
+    }
+
+    /// Get this [`Duration`] as bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..usize::from(self.length)]
+    }
+}
+
+impl AsRef<[u8]> for Duration {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl AsRef<str> for Duration {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl FromStr for Duration {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::new(s)
+    }
+}
+
+impl From<core::time::Duration> for Duration {
+    fn from(duration: core::time::Duration) -> Duration {
+        let mut buf = [0u8; Self::MAX_SIZE];
+        let mut writer = crate::SliceWriter::new(&mut buf);
+
+        (|| {
+            writer.write(b"PT")?;
+            encode_integer(&mut writer, duration.as_secs())?;
+            datetime::encode_nanoseconds(&mut writer, Tag::Time, duration.subsec_nanos())?;
+            writer.write_byte(b'S')
+        })()
+        .and_then(|_| Self::new(writer.finish()?))
+        .expect("Invalid Duration")
+    }
+}
+
+impl TryFrom<Duration> for core::time::Duration {
+    type Error = Error;
+
+    fn try_from(duration: Duration) -> Result<core::time::Duration> {
+        let components = parse(duration.as_bytes()).ok_or_else(|| Tag::Time.value_error())?;
+
+        // Years and months don't have a fixed length
+        if components.values[0] != 0 || components.values[1] != 0 {
+            return Err(Tag::Time.value_error());
+        }
+
+        let mut secs = 0u64;
+
+        for (&value, &unit) in components.values[2..].iter().zip(UNIT_SECONDS.iter()) {
+            secs = value
+                .checked_mul(unit)
+                .and_then(|value| secs.checked_add(value))
+                .ok_or(ErrorKind::Overflow)?;
+        }
+
+        let mut nanos = 0;
+
+        if let Some((index, fraction)) = components.fraction {
+            let unit = index
+                .checked_sub(2)
+                .and_then(|index| UNIT_SECONDS.get(index))
+                .ok_or_else(|| Tag::Time.value_error())?;
+            let fraction_nanos = u128::from(fraction)
+                .checked_mul(u128::from(*unit))
+                .ok_or(ErrorKind::Overflow)?;
+
+            secs = u64::try_from(fraction_nanos / NANOS_PER_SEC)
+                .ok()
+                .and_then(|fraction_secs| secs.checked_add(fraction_secs))
+                .ok_or(ErrorKind::Overflow)?;
+            nanos = u32::try_from(fraction_nanos % NANOS_PER_SEC)?;
+        }
+
+        Ok(core::time::Duration::new(secs, nanos))
+    }
+}
+
+impl fmt::Display for Duration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl fmt::Debug for Duration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Duration({:?})", self.as_str())
+    }
+}
+
+impl<'a> Decode<'a> for Duration {
+    fn decode<R: Reader<'a>>(reader: &mut R) -> Result<Self> {
+        let first = reader.read_byte()?;
+
+        if first != Self::IDENTIFIER[0] {
+            return Err(Tag::try_from(first)?.unexpected_error(None));
+        }
+
+        if reader.read_byte()? != Self::IDENTIFIER[1] {
+            return Err(reader.error(ErrorKind::TagNumberInvalid));
+        }
+
+        let length = Length::decode(reader)?;
+        let header = Header::new(Tag::Time, length)?;
+        Self::decode_value(reader, header)
+    }
+}
+
+impl<'a> DecodeValue<'a> for Duration {
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
+        let mut buf = [0u8; Self::MAX_SIZE];
+        let slice = buf
+            .get_mut(..header.length.try_into()?)
+            .ok_or_else(|| Tag::Time.length_error())?;
+
+        Self::new(reader.read_into(slice)?)
+    }
+}
+
+impl Encode for Duration {
+    fn encoded_len(&self) -> Result<Length> {
+        // `Length::for_tlv` accounts for a single identifier octet
+        self.value_len()?.for_tlv()? + Length::ONE
+    }
+
+    fn encode(&self, writer: &mut impl Writer) -> Result<()> {
+        writer.write(&Self::IDENTIFIER)?;
+        self.value_len()?.encode(writer)?;
+        self.encode_value(writer)
+    }
+}
+
+impl EncodeValue for Duration {
+    fn value_len(&self) -> Result<Length> {
+        Ok(Length::from(self.length))
+    }
+
+    fn encode_value(&self, writer: &mut impl Writer) -> Result<()> {
+        writer.write(self.as_bytes())
+    }
+}
+
+// Implement by hand because the derive would create invalid values.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Duration {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(core::time::Duration::arbitrary(u)?.into())
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        core::time::Duration::size_hint(depth)
+    }
+}
+
+/// Components of a parsed ISO 8601 duration.
+struct Components {
+    /// Years, months, weeks, days, hours, minutes and seconds.
+    values: [u64; 7],
+
+    /// Index of the component with a fractional part, and the fraction in
+    /// nanoseconds.
+    fraction: Option<(usize, u32)>,
+}
+
+/// Parse an ISO 8601 duration, i.e. `P[nY][nM][nW][nD][T[nH][nM][nS]]`.
+fn parse(bytes: &[u8]) -> Option<Components> {
+    let mut remaining = bytes.strip_prefix(b"P")?;
+    let mut components = Components {
+        values: [0; 7],
+        fraction: None,
+    };
+
+    // Index of the next component which may occur, as they must be ordered
+    let mut next = 0;
+    let mut time = None;
+
+    while let Some((&byte, rest)) = remaining.split_first() {
+        if byte == b'T' {
+            if time.is_some() {
+                return None;
+            }
+
+            time = Some(false);
+            next = next.max(4);
+            remaining = rest;
+            continue;
+        }
+
+        // Only the last component may have a fractional part
+        if components.fraction.is_some() {
+            return None;
+        }
+
+        let (value, rest) = split_digits(remaining);
+        let value = decode_integer(value)?;
+
+        let (fraction, rest) = match rest.split_first() {
+            Some((b'.' | b',', rest)) => {
+                let (digits, rest) = split_digits(rest);
+                (
+                    Some(datetime::decode_nanoseconds(Tag::Time, digits).ok()?),
+                    rest,
+                )
+            }
+            _ => (None, rest),
+        };
+
+        let (&designator, rest) = rest.split_first()?;
+        let index = match (designator, time.is_some()) {
+            (b'Y', false) => 0,
+            (b'M', false) => 1,
+            (b'W', false) => 2,
+            (b'D', false) => 3,
+            (b'H', true) => 4,
+            (b'M', true) => 5,
+            (b'S', true) => 6,
+            _ => return None,
+        };
+
+        if index < next {
+            return None;
+        }
+
+        components.values[index] = value;
+        components.fraction = fraction.map(|fraction| (index, fraction));
+        next = index.checked_add(1)?;
+
+        if time.is_some() {
+            time = Some(true);
+        }
+
+        remaining = rest;
+    }
+
+    // At least one component must be present, including after `T`
+    match (next, time) {
+        (0, _) | (_, Some(false)) => None,
+        _ => Some(components),
+    }
+}
+
+/// Split the leading ASCII digits off of `bytes`.
+fn split_digits(bytes: &[u8]) -> (&[u8], &[u8]) {
+    let len = bytes
+        .iter()
+        .position(|byte| !byte.is_ascii_digit())
+        .unwrap_or(bytes.len());
+
+    bytes.split_at(len)
+}
+
+/// Decode a non-empty string of ASCII digits.
+fn decode_integer(digits: &[u8]) -> Option<u64> {
+    if digits.is_empty() {
+        return None;
+    }
+
+    digits.iter().try_fold(0u64, |value, &digit| {
+        value
+            .checked_mul(10)?
+            .checked_add(u64::from(digit.checked_sub(b'0')?))
+    })
+}
+
+/// Encode an integer as ASCII digits.
+fn encode_integer(writer: &mut impl Writer, value: u64) -> Result<()> {
+    let mut digits = [0u8; 20];
+    let mut start = digits.len();
+    let mut value = value;
+
+    loop {
+        start = start.checked_sub(1).ok_or(ErrorKind::Overflow)?;
+        digits[start] = b'0'
+            .checked_add(u8::try_from(value % 10)?)
+            .ok_or(ErrorKind::Overflow)?;
+        value /= 10;
+
+        if value == 0 {
+            break;
+        }
+    }
+
+    writer.write(&digits[start..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Duration;
+    use crate::{Decode, Encode, ErrorKind, Tag};
+    use hex_literal::hex;
+
+    #[test]
+    fn round_trip() {
+        // DURATION "P1Y2M10DT2H30M"
+        let example_bytes = hex!("1f 22 0e 50 31 59 32 4d 31 30 44 54 32 48 33 30 4d");
+        let duration = Duration::from_der(&example_bytes).unwrap();
+        assert_eq!(duration.as_str(), "P1Y2M10DT2H30M");
+        assert_eq!(
+            duration.encoded_len().unwrap(),
+            example_bytes.len().try_into().unwrap()
+        );
+        let mut buf = [0u8; 32];
+        assert_eq!(duration.encode_to_slice(&mut buf).unwrap(), example_bytes);
+    }
+
+    #[test]
+    fn to_core_duration() {
+        for (s, secs, nanos) in [
+            ("PT0S", 0, 0),
+            ("P3W", 3 * 7 * 86400, 0),
+            ("P1DT12H", 86400 + 12 * 3600, 0),
+            ("PT1H30M", 5400, 0),
+            ("PT0.5S", 0, 500_000_000),
+            ("PT1,25M", 75, 0),
+            ("P0.5D", 43200, 0),
+            ("P0Y0M1D", 86400, 0),
+        ] {
+            let duration = Duration::new(s).unwrap();
+            assert_eq!(
+                core::time::Duration::try_from(duration).unwrap(),
+                core::time::Duration::new(secs, nanos),
+                "{}",
+                s
+            );
+        }
+
+        // Years and months don't have a fixed length
+        for s in ["P1Y", "P1M", "P1Y2M10DT2H30M"] {
+            let duration = Duration::new(s).unwrap();
+            assert!(core::time::Duration::try_from(duration).is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn from_core_duration() {
+        for (duration, s) in [
+            (core::time::Duration::ZERO, "PT0S"),
+            (core::time::Duration::from_secs(5400), "PT5400S"),
+            (
+                core::time::Duration::from_nanos(1_000_000_001),
+                "PT1.000000001S",
+            ),
+            (
+                core::time::Duration::MAX,
+                "PT18446744073709551615.999999999S",
+            ),
+        ] {
+            let encoded = Duration::from(duration);
+            assert_eq!(encoded.as_str(), s);
+            assert_eq!(core::time::Duration::try_from(encoded).unwrap(), duration);
+        }
+    }
+
+    #[test]
+    fn reject_invalid() {
+        for s in [
+            "",
+            "P",
+            "PT",
+            "P1",
+            "1D",
+            "P1DT",
+            "PT1D",
+            "P1H",
+            "P1D1Y",
+            "P1MT1M1H",
+            "P0.5DT1H",
+            "P1.D",
+            "P.5D",
+            "PT1.0000000001S",
+            "P18446744073709551616D",
+        ] {
+            assert!(Duration::new(s).is_err(), "{}", s);
+        }
+
+        let err = Duration::from_der(&hex!("02 01 00")).unwrap_err();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::TagUnexpected {
+                expected: None,
+                actual: Tag::Integer
+            }
+        );
+
+        let err = Duration::from_der(&hex!("1f 21 01 50")).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::TagNumberInvalid);
+    }
+}
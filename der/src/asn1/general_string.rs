@@ -0,0 +1,91 @@
+//! ASN.1 `GeneralString` support.
+
+use crate::{StrRef, Tag};
+
+/// ASN.1 `GeneralString` type.
+///
+/// Supports the subset of its character set which is valid UTF-8 (described below).
+///
+/// For UTF-8, use [`Utf8StringRef`][`crate::asn1::Utf8StringRef`] instead.
+///
+/// This is a zero-copy reference type which borrows from the input data.
+///
+/// # Supported characters
+///
+/// The standard allows any of the registered graphic and control character sets, selected via ISO 2022 escape sequences. These aren't
+/// implemented: in practice this type occurs in legacy data (e.g. Kerberos and older X.509
+/// certificates) as a plain 8-bit string, so any contents which are valid UTF-8 (including ASCII)
+/// are accepted. Use [`GeneralString`] to also decode contents which are only valid as ISO 8859-1.
+#[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Ord)]
+pub struct GeneralStringRef<'a> {
+    /// Inner value
+    inner: StrRef<'a>,
+}
+
+impl_legacy_string_ref!(GeneralStringRef, Tag::GeneralString, "GeneralString");
+
+#[cfg(feature = "alloc")]
+pub use self::allocation::GeneralString;
+
+#[cfg(feature = "alloc")]
+mod allocation {
+    use super::GeneralStringRef;
+    use crate::{BytesOwned, StrOwned, Tag};
+
+    /// ASN.1 `GeneralString` type.
+    ///
+    /// Owned counterpart of [`GeneralStringRef`] which additionally supports
+    /// contents which are not valid UTF-8.
+    ///
+    /// # Supported characters
+    ///
+    /// Contents which are valid UTF-8 are used as-is, and any other contents are decoded as
+    /// ISO 8859-1. The original bytes are retained so such values are re-encoded unchanged.
+    #[derive(Clone, Eq, PartialEq, PartialOrd, Ord)]
+    pub struct GeneralString {
+        /// Inner value
+        inner: StrOwned,
+
+        /// Original encoding, if the value was decoded from ISO 8859-1.
+        latin1: Option<BytesOwned>,
+    }
+
+    impl_legacy_string_owned!(
+        GeneralString,
+        GeneralStringRef,
+        Tag::GeneralString,
+        "GeneralString"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GeneralStringRef;
+    use crate::{Decode, Encode};
+
+    #[test]
+    fn parse_bytes() {
+        let example_bytes = &[0x1b, 0x05, 0x48, 0x65, 0x6c, 0x6c, 0x6f];
+
+        let s = GeneralStringRef::from_der(example_bytes).unwrap();
+        assert_eq!(s.as_str(), "Hello");
+
+        let mut buf = [0u8; 16];
+        assert_eq!(s.encode_to_slice(&mut buf).unwrap(), example_bytes);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn decode_latin1() {
+        use super::GeneralString;
+
+        // "café" encoded as ISO 8859-1
+        let example_bytes = &[0x1b, 0x04, 0x63, 0x61, 0x66, 0xe9];
+        assert!(GeneralStringRef::from_der(example_bytes).is_err());
+
+        let s = GeneralString::from_der(example_bytes).unwrap();
+        assert_eq!(s.as_str(), "café");
+        assert!(s.is_latin1());
+        assert_eq!(s.to_der().unwrap(), example_bytes);
+    }
+}
@@ -1,5 +1,4 @@
 //! ASN.1 `GeneralizedTime` support.
-#![cfg_attr(feature = "arbitrary", allow(clippy::integer_arithmetic))]
 
 use crate::{
     datetime::{self, DateTime},
@@ -9,13 +8,13 @@ use crate::{
 use core::time::Duration;
 
 #[cfg(feature = "std")]
-use {
-    crate::{asn1::AnyRef, Error},
-    std::time::SystemTime,
-};
+use {crate::asn1::AnyRef, std::time::SystemTime};
+
+#[cfg(any(feature = "chrono", feature = "std", feature = "time"))]
+use crate::Error;
 
 #[cfg(feature = "time")]
-use time::PrimitiveDateTime;
+use time::{OffsetDateTime, PrimitiveDateTime};
 
 /// ASN.1 `GeneralizedTime` type.
 ///
@@ -27,8 +26,10 @@ use time::PrimitiveDateTime;
 /// > (i.e., times are `YYYYMMDDHHMMSSZ`), even where the number of seconds
 /// > is zero.  GeneralizedTime values MUST NOT include fractional seconds.
 ///
+/// Sub-second precision of the underlying [`DateTime`] is discarded. Use
+/// [`FractionalGeneralizedTime`] where fractional seconds must be preserved.
+///
 /// [1]: https://tools.ietf.org/html/rfc5280#section-4.1.2.5.2
-#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 pub struct GeneralizedTime(DateTime);
 
@@ -37,8 +38,10 @@ impl GeneralizedTime {
     const LENGTH: usize = 15;
 
     /// Create a [`GeneralizedTime`] from a [`DateTime`].
+    ///
+    /// Fractional seconds are truncated.
     pub const fn from_date_time(datetime: DateTime) -> Self {
-        Self(datetime)
+        Self(datetime.truncate_subsec())
     }
 
     /// Convert this [`GeneralizedTime`] into a [`DateTime`].
@@ -78,34 +81,7 @@ impl_any_conversions!(GeneralizedTime);
 
 impl<'a> DecodeValue<'a> for GeneralizedTime {
     fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
-        if Self::LENGTH != usize::try_from(header.length)? {
-            return Err(Self::TAG.value_error());
-        }
-
-        let mut bytes = [0u8; Self::LENGTH];
-        reader.read_into(&mut bytes)?;
-
-        match bytes {
-            // RFC 5280 requires mandatory seconds and Z-normalized time zone
-            [y1, y2, y3, y4, mon1, mon2, day1, day2, hour1, hour2, min1, min2, sec1, sec2, b'Z'] => {
-                let year = u16::from(datetime::decode_decimal(Self::TAG, y1, y2)?)
-                    .checked_mul(100)
-                    .and_then(|y| {
-                        y.checked_add(datetime::decode_decimal(Self::TAG, y3, y4).ok()?.into())
-                    })
-                    .ok_or(ErrorKind::DateTime)?;
-                let month = datetime::decode_decimal(Self::TAG, mon1, mon2)?;
-                let day = datetime::decode_decimal(Self::TAG, day1, day2)?;
-                let hour = datetime::decode_decimal(Self::TAG, hour1, hour2)?;
-                let minute = datetime::decode_decimal(Self::TAG, min1, min2)?;
-                let second = datetime::decode_decimal(Self::TAG, sec1, sec2)?;
-
-                DateTime::new(year, month, day, hour, minute, second)
-                    .map_err(|_| Self::TAG.value_error())
-                    .and_then(|dt| Self::from_unix_duration(dt.unix_duration()))
-            }
-            _ => Err(Self::TAG.value_error()),
-        }
+        decode_date_time(reader, header, false).map(Self)
     }
 }
 
@@ -115,17 +91,7 @@ impl EncodeValue for GeneralizedTime {
     }
 
     fn encode_value(&self, writer: &mut impl Writer) -> Result<()> {
-        let year_hi = u8::try_from(self.0.year() / 100)?;
-        let year_lo = u8::try_from(self.0.year() % 100)?;
-
-        datetime::encode_decimal(writer, Self::TAG, year_hi)?;
-        datetime::encode_decimal(writer, Self::TAG, year_lo)?;
-        datetime::encode_decimal(writer, Self::TAG, self.0.month())?;
-        datetime::encode_decimal(writer, Self::TAG, self.0.day())?;
-        datetime::encode_decimal(writer, Self::TAG, self.0.hour())?;
-        datetime::encode_decimal(writer, Self::TAG, self.0.minutes())?;
-        datetime::encode_decimal(writer, Self::TAG, self.0.seconds())?;
-        writer.write_byte(b'Z')
+        encode_date_time(writer, &self.0)
     }
 }
 
@@ -165,6 +131,226 @@ impl From<&DateTime> for GeneralizedTime {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GeneralizedTime {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        arbitrary::Arbitrary::arbitrary(u).map(Self::from_date_time)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <DateTime as arbitrary::Arbitrary<'a>>::size_hint(depth)
+    }
+}
+
+/// ASN.1 `GeneralizedTime` type with optional fractional seconds.
+///
+/// Unlike [`GeneralizedTime`], which implements the RFC 5280 profile, this
+/// type accepts and produces fractional seconds as permitted by the DER rules
+/// in [X.690 Section 11.7][1], i.e. values of the form `YYYYMMDDHHMMSS[.f+]Z`
+/// where the fraction uses `.` as the decimal mark and omits trailing zeroes.
+///
+/// This is needed for e.g. the `genTime` field of RFC 3161 timestamp tokens.
+/// Fractional seconds are supported with up to nanosecond precision.
+///
+/// [1]: https://www.itu.int/rec/T-REC-X.690
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct FractionalGeneralizedTime(DateTime);
+
+impl FractionalGeneralizedTime {
+    /// Maximum length of an ASN.1 DER-encoded [`FractionalGeneralizedTime`].
+    const MAX_LENGTH: usize = GeneralizedTime::LENGTH + 1 + datetime::MAX_FRACTION_DIGITS;
+
+    /// Create a [`FractionalGeneralizedTime`] from a [`DateTime`].
+    pub const fn from_date_time(datetime: DateTime) -> Self {
+        Self(datetime)
+    }
+
+    /// Convert this [`FractionalGeneralizedTime`] into a [`DateTime`].
+    pub fn to_date_time(&self) -> DateTime {
+        self.0
+    }
+
+    /// Create a new [`FractionalGeneralizedTime`] given a [`Duration`] since
+    /// `UNIX_EPOCH` (a.k.a. "Unix time")
+    pub fn from_unix_duration(unix_duration: Duration) -> Result<Self> {
+        DateTime::from_unix_duration(unix_duration)
+            .map(Self)
+            .map_err(|_| Self::TAG.value_error())
+    }
+
+    /// Get the duration of this timestamp since `UNIX_EPOCH`.
+    pub fn to_unix_duration(&self) -> Duration {
+        self.0.unix_duration()
+    }
+
+    /// Instantiate from [`SystemTime`].
+    #[cfg(feature = "std")]
+    pub fn from_system_time(time: SystemTime) -> Result<Self> {
+        DateTime::try_from(time)
+            .map(Self)
+            .map_err(|_| Self::TAG.value_error())
+    }
+
+    /// Convert to [`SystemTime`].
+    #[cfg(feature = "std")]
+    pub fn to_system_time(&self) -> SystemTime {
+        self.0.to_system_time()
+    }
+}
+
+impl_any_conversions!(FractionalGeneralizedTime);
+
+impl<'a> DecodeValue<'a> for FractionalGeneralizedTime {
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
+        decode_date_time(reader, header, true).map(Self)
+    }
+}
+
+impl EncodeValue for FractionalGeneralizedTime {
+    fn value_len(&self) -> Result<Length> {
+        GeneralizedTime::LENGTH
+            .checked_add(datetime::nanoseconds_len(self.0.nanoseconds()))
+            .ok_or(ErrorKind::Overflow)?
+            .try_into()
+    }
+
+    fn encode_value(&self, writer: &mut impl Writer) -> Result<()> {
+        encode_date_time(writer, &self.0)
+    }
+}
+
+impl FixedTag for FractionalGeneralizedTime {
+    const TAG: Tag = Tag::GeneralizedTime;
+}
+
+impl OrdIsValueOrd for FractionalGeneralizedTime {}
+
+impl From<GeneralizedTime> for FractionalGeneralizedTime {
+    fn from(time: GeneralizedTime) -> FractionalGeneralizedTime {
+        Self(time.0)
+    }
+}
+
+impl From<FractionalGeneralizedTime> for GeneralizedTime {
+    fn from(time: FractionalGeneralizedTime) -> GeneralizedTime {
+        Self::from_date_time(time.0)
+    }
+}
+
+impl From<FractionalGeneralizedTime> for DateTime {
+    fn from(time: FractionalGeneralizedTime) -> DateTime {
+        time.0
+    }
+}
+
+impl From<DateTime> for FractionalGeneralizedTime {
+    fn from(datetime: DateTime) -> Self {
+        Self::from_date_time(datetime)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<FractionalGeneralizedTime> for SystemTime {
+    fn from(time: FractionalGeneralizedTime) -> SystemTime {
+        time.to_system_time()
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<SystemTime> for FractionalGeneralizedTime {
+    type Error = Error;
+
+    fn try_from(time: SystemTime) -> Result<FractionalGeneralizedTime> {
+        FractionalGeneralizedTime::from_system_time(time)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for FractionalGeneralizedTime {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        arbitrary::Arbitrary::arbitrary(u).map(Self)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <DateTime as arbitrary::Arbitrary<'a>>::size_hint(depth)
+    }
+}
+
+/// Decode the value of a `GeneralizedTime`, accepting fractional seconds only
+/// if `fractional` is set.
+fn decode_date_time<'a, R: Reader<'a>>(
+    reader: &mut R,
+    header: Header,
+    fractional: bool,
+) -> Result<DateTime> {
+    const TAG: Tag = Tag::GeneralizedTime;
+
+    let len = usize::try_from(header.length)?;
+    let max_len = if fractional {
+        FractionalGeneralizedTime::MAX_LENGTH
+    } else {
+        GeneralizedTime::LENGTH
+    };
+
+    if len < GeneralizedTime::LENGTH || len > max_len {
+        return Err(TAG.value_error());
+    }
+
+    let mut buf = [0u8; FractionalGeneralizedTime::MAX_LENGTH];
+    let bytes = buf.get_mut(..len).ok_or(ErrorKind::Overflow)?;
+    reader.read_into(bytes)?;
+
+    let (date_time, fraction) = bytes.split_at(GeneralizedTime::LENGTH.saturating_sub(1));
+
+    let nanoseconds = match fraction {
+        // RFC 5280 requires mandatory seconds and Z-normalized time zone
+        [b'Z'] => 0,
+        // X.690 requires `.` as the decimal mark and no trailing zeroes
+        [b'.', digits @ .., b'Z'] if digits.last() != Some(&b'0') => {
+            datetime::decode_nanoseconds(TAG, digits)?
+        }
+        _ => return Err(TAG.value_error()),
+    };
+
+    match *date_time {
+        [y1, y2, y3, y4, mon1, mon2, day1, day2, hour1, hour2, min1, min2, sec1, sec2] => {
+            let year = u16::from(datetime::decode_decimal(TAG, y1, y2)?)
+                .checked_mul(100)
+                .and_then(|y| y.checked_add(datetime::decode_decimal(TAG, y3, y4).ok()?.into()))
+                .ok_or(ErrorKind::DateTime)?;
+            let month = datetime::decode_decimal(TAG, mon1, mon2)?;
+            let day = datetime::decode_decimal(TAG, day1, day2)?;
+            let hour = datetime::decode_decimal(TAG, hour1, hour2)?;
+            let minute = datetime::decode_decimal(TAG, min1, min2)?;
+            let second = datetime::decode_decimal(TAG, sec1, sec2)?;
+
+            DateTime::new(year, month, day, hour, minute, second)
+                .and_then(|dt| dt.with_nanoseconds(nanoseconds))
+                .map_err(|_| TAG.value_error())
+        }
+        _ => Err(TAG.value_error()),
+    }
+}
+
+/// Encode a [`DateTime`] as the value of a `GeneralizedTime`, including
+/// fractional seconds if they are non-zero.
+fn encode_date_time(writer: &mut impl Writer, datetime: &DateTime) -> Result<()> {
+    const TAG: Tag = Tag::GeneralizedTime;
+
+    let year_hi = u8::try_from(datetime.year() / 100)?;
+    let year_lo = u8::try_from(datetime.year() % 100)?;
+
+    datetime::encode_decimal(writer, TAG, year_hi)?;
+    datetime::encode_decimal(writer, TAG, year_lo)?;
+    datetime::encode_decimal(writer, TAG, datetime.month())?;
+    datetime::encode_decimal(writer, TAG, datetime.day())?;
+    datetime::encode_decimal(writer, TAG, datetime.hour())?;
+    datetime::encode_decimal(writer, TAG, datetime.minutes())?;
+    datetime::encode_decimal(writer, TAG, datetime.seconds())?;
+    datetime::encode_nanoseconds(writer, TAG, datetime.nanoseconds())?;
+    writer.write_byte(b'Z')
+}
+
 impl<'a> DecodeValue<'a> for DateTime {
     fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
         Ok(GeneralizedTime::decode_value(reader, header)?.into())
@@ -307,10 +493,87 @@ impl TryFrom<GeneralizedTime> for PrimitiveDateTime {
     }
 }
 
+#[cfg(feature = "time")]
+impl TryFrom<OffsetDateTime> for GeneralizedTime {
+    type Error = Error;
+
+    fn try_from(time: OffsetDateTime) -> Result<GeneralizedTime> {
+        Ok(GeneralizedTime::from_date_time(DateTime::try_from(time)?))
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<GeneralizedTime> for OffsetDateTime {
+    type Error = Error;
+
+    fn try_from(time: GeneralizedTime) -> Result<OffsetDateTime> {
+        time.to_date_time().try_into()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> TryFrom<chrono::DateTime<Tz>> for GeneralizedTime {
+    type Error = Error;
+
+    fn try_from(time: chrono::DateTime<Tz>) -> Result<GeneralizedTime> {
+        Ok(GeneralizedTime::from_date_time(DateTime::try_from(time)?))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<GeneralizedTime> for chrono::DateTime<chrono::Utc> {
+    type Error = Error;
+
+    fn try_from(time: GeneralizedTime) -> Result<chrono::DateTime<chrono::Utc>> {
+        time.to_date_time().try_into()
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<OffsetDateTime> for FractionalGeneralizedTime {
+    type Error = Error;
+
+    fn try_from(time: OffsetDateTime) -> Result<FractionalGeneralizedTime> {
+        Ok(FractionalGeneralizedTime::from_date_time(
+            DateTime::try_from(time)?,
+        ))
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<FractionalGeneralizedTime> for OffsetDateTime {
+    type Error = Error;
+
+    fn try_from(time: FractionalGeneralizedTime) -> Result<OffsetDateTime> {
+        time.to_date_time().try_into()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> TryFrom<chrono::DateTime<Tz>> for FractionalGeneralizedTime {
+    type Error = Error;
+
+    fn try_from(time: chrono::DateTime<Tz>) -> Result<FractionalGeneralizedTime> {
+        Ok(FractionalGeneralizedTime::from_date_time(
+            DateTime::try_from(time)?,
+        ))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<FractionalGeneralizedTime> for chrono::DateTime<chrono::Utc> {
+    type Error = Error;
+
+    fn try_from(time: FractionalGeneralizedTime) -> Result<chrono::DateTime<chrono::Utc>> {
+        time.to_date_time().try_into()
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::GeneralizedTime;
-    use crate::{Decode, Encode, SliceWriter};
+    use super::{FractionalGeneralizedTime, GeneralizedTime};
+    use crate::{DateTime, Decode, Encode, SliceWriter};
+    use core::time::Duration;
     use hex_literal::hex;
 
     #[test]
@@ -324,4 +587,62 @@ mod tests {
         utc_time.encode(&mut encoder).unwrap();
         assert_eq!(example_bytes, encoder.finish().unwrap());
     }
+
+    #[test]
+    fn fractional_round_trip() {
+        // 19910506234540.125Z
+        let example_bytes = hex!("18 13 31 39 39 31 30 35 30 36 32 33 34 35 34 30 2e 31 32 35 5a");
+        let time = FractionalGeneralizedTime::from_der(&example_bytes).unwrap();
+        assert_eq!(
+            time.to_unix_duration(),
+            Duration::new(673573540, 125_000_000)
+        );
+        assert_eq!(time.to_date_time().nanoseconds(), 125_000_000);
+
+        let mut buf = [0u8; 128];
+        let mut encoder = SliceWriter::new(&mut buf);
+        time.encode(&mut encoder).unwrap();
+        assert_eq!(example_bytes, encoder.finish().unwrap());
+
+        // Strict RFC 5280 decoding rejects fractional seconds
+        assert!(GeneralizedTime::from_der(&example_bytes).is_err());
+    }
+
+    #[test]
+    fn fractional_accepts_whole_seconds() {
+        let example_bytes = hex!("18 0f 31 39 39 31 30 35 30 36 32 33 34 35 34 30 5a");
+        let time = FractionalGeneralizedTime::from_der(&example_bytes).unwrap();
+        assert_eq!(
+            time,
+            GeneralizedTime::from_der(&example_bytes).unwrap().into()
+        );
+        let mut buf = [0u8; 128];
+        assert_eq!(time.encode_to_slice(&mut buf).unwrap(), example_bytes);
+    }
+
+    #[test]
+    fn fractional_rejects_non_canonical() {
+        // Trailing zero: 19910506234540.50Z
+        let trailing_zero = hex!("18 12 31 39 39 31 30 35 30 36 32 33 34 35 34 30 2e 35 30 5a");
+        assert!(FractionalGeneralizedTime::from_der(&trailing_zero).is_err());
+
+        // Comma decimal mark: 19910506234540,5Z
+        let comma = hex!("18 11 31 39 39 31 30 35 30 36 32 33 34 35 34 30 2c 35 5a");
+        assert!(FractionalGeneralizedTime::from_der(&comma).is_err());
+
+        // Missing digits: 19910506234540.Z
+        let empty = hex!("18 10 31 39 39 31 30 35 30 36 32 33 34 35 34 30 2e 5a");
+        assert!(FractionalGeneralizedTime::from_der(&empty).is_err());
+    }
+
+    #[test]
+    fn strict_truncates_fractional_seconds() {
+        let datetime = DateTime::from_unix_duration(Duration::new(673573540, 1)).unwrap();
+        let time = GeneralizedTime::from_date_time(datetime);
+        assert_eq!(time.to_unix_duration(), Duration::from_secs(673573540));
+        assert_eq!(
+            GeneralizedTime::from(FractionalGeneralizedTime::from(datetime)),
+            time
+        );
+    }
 }
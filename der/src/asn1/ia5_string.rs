@@ -8,7 +8,7 @@ macro_rules! impl_ia5_string {
         impl_ia5_string!($type,);
     };
     ($type: ty, $($li: lifetime)?) => {
-        impl_string_type!($type, $($li),*);
+        impl_string_type!($type, $($li),*; lenient);
 
         impl<$($li),*> FixedTag for $type {
             const TAG: Tag = Tag::Ia5String;
@@ -53,6 +53,13 @@ impl<'a> Ia5StringRef<'a> {
             return Err(Self::TAG.value_error());
         }
 
+        Self::new_lenient(input)
+    }
+
+    /// Create a new ASN.1 `Ia5String` without validating its character set.
+    ///
+    /// Used when decoding with [`Reader::lenient_charsets`][`crate::Reader::lenient_charsets`].
+    pub(crate) fn new_lenient(input: &'a [u8]) -> Result<Self> {
         StrRef::from_bytes(input)
             .map(|inner| Self { inner })
             .map_err(|_| Self::TAG.value_error())
@@ -119,7 +126,11 @@ mod allocation {
         {
             let input = input.as_ref();
             Ia5StringRef::new(input)?;
+            Self::new_lenient(input)
+        }
 
+        /// Create a new ASN.1 `Ia5String` without validating its character set.
+        pub(crate) fn new_lenient(input: &[u8]) -> Result<Self> {
             StrOwned::from_bytes(input)
                 .map(|inner| Self { inner })
                 .map_err(|_| Self::TAG.value_error())
@@ -192,4 +203,21 @@ mod tests {
         let internationalized_string = Ia5StringRef::from_der(&example_bytes).unwrap();
         assert_eq!(internationalized_string.as_str(), "test1@rsa.com");
     }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn lenient_charsets() {
+        use super::Ia5String;
+        use crate::{asn1::SequenceOf, SliceReader};
+
+        // SEQUENCE { IA5String "caf\u{e9}" }
+        let example_bytes = hex!("30 07 16 05 63 61 66 c3 a9");
+        assert!(SequenceOf::<Ia5String, 1>::from_der(&example_bytes).is_err());
+
+        let mut reader = SliceReader::new(&example_bytes)
+            .unwrap()
+            .with_lenient_charsets(true);
+        let strings = SequenceOf::<Ia5String, 1>::decode(&mut reader).unwrap();
+        assert_eq!(strings.get(0).unwrap().as_str(), "caf\u{e9}");
+    }
 }
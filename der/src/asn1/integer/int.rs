@@ -160,6 +160,19 @@ impl<'a> FixedTag for IntRef<'a> {
 
 impl<'a> OrdIsValueOrd for IntRef<'a> {}
 
+// Implement by hand because the derive would create invalid values.
+// Use the constructor to create a valid value.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for IntRef<'a> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Self::new(BytesRef::arbitrary(u)?.as_slice()).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        BytesRef::size_hint(depth)
+    }
+}
+
 #[cfg(feature = "alloc")]
 mod allocating {
     use super::{strip_leading_ones, validate_canonical, IntRef};
@@ -269,6 +282,19 @@ mod allocating {
 
     impl OrdIsValueOrd for Int {}
 
+    // Implement by hand because the derive would create invalid values.
+    // Use the constructor to create a valid value.
+    #[cfg(feature = "arbitrary")]
+    impl<'a> arbitrary::Arbitrary<'a> for Int {
+        fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+            Self::new(<&'a [u8]>::arbitrary(u)?).map_err(|_| arbitrary::Error::IncorrectFormat)
+        }
+
+        fn size_hint(depth: usize) -> (usize, Option<usize>) {
+            <&'a [u8] as arbitrary::Arbitrary<'a>>::size_hint(depth)
+        }
+    }
+
     impl<'a> RefToOwned<'a> for IntRef<'a> {
         type Owned = Int;
         fn ref_to_owned(&self) -> Self::Owned {
@@ -154,6 +154,19 @@ impl<'a> FixedTag for UintRef<'a> {
 
 impl<'a> OrdIsValueOrd for UintRef<'a> {}
 
+// Implement by hand because the derive would create invalid values.
+// Use the constructor to create a valid value.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for UintRef<'a> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Self::new(BytesRef::arbitrary(u)?.as_slice()).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        BytesRef::size_hint(depth)
+    }
+}
+
 #[cfg(feature = "alloc")]
 mod allocating {
     use super::{decode_to_slice, encoded_len, strip_leading_zeroes, UintRef};
@@ -247,6 +260,19 @@ mod allocating {
 
     impl OrdIsValueOrd for Uint {}
 
+    // Implement by hand because the derive would create invalid values.
+    // Use the constructor to create a valid value.
+    #[cfg(feature = "arbitrary")]
+    impl<'a> arbitrary::Arbitrary<'a> for Uint {
+        fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+            Self::new(<&'a [u8]>::arbitrary(u)?).map_err(|_| arbitrary::Error::IncorrectFormat)
+        }
+
+        fn size_hint(depth: usize) -> (usize, Option<usize>) {
+            <&'a [u8] as arbitrary::Arbitrary<'a>>::size_hint(depth)
+        }
+    }
+
     impl<'a> RefToOwned<'a> for UintRef<'a> {
         type Owned = Uint;
         fn ref_to_owned(&self) -> Self::Owned {
@@ -6,7 +6,7 @@ macro_rules! impl_any_conversions {
         impl<'__der: $($li),*, $($li),*> TryFrom<$crate::AnyRef<'__der>> for $type {
             type Error = $crate::Error;
 
-            fn try_from(any: $crate::AnyRef<'__der>) -> Result<$type> {
+            fn try_from(any: $crate::AnyRef<'__der>) -> $crate::Result<$type> {
                 any.decode_as()
             }
         }
@@ -15,7 +15,7 @@ macro_rules! impl_any_conversions {
         impl<'__der: $($li),*, $($li),*> TryFrom<&'__der $crate::Any> for $type {
             type Error = $crate::Error;
 
-            fn try_from(any: &'__der $crate::Any) -> Result<$type> {
+            fn try_from(any: &'__der $crate::Any) -> $crate::Result<$type> {
                 any.decode_as()
             }
         }
@@ -24,15 +24,49 @@ macro_rules! impl_any_conversions {
 
 macro_rules! impl_string_type {
     ($type: ty, $($li: lifetime)?) => {
+        impl_string_type!(@common $type, $($li),*);
+
+        impl<'__der: $($li),*, $($li),*> $crate::DecodeValue<'__der> for $type {
+            fn decode_value<R: $crate::Reader<'__der>>(
+                reader: &mut R,
+                header: $crate::Header,
+            ) -> $crate::Result<Self> {
+                let bytes = <$crate::BytesRef<'__der> as $crate::DecodeValue<'__der>>::decode_value(
+                    reader, header,
+                )?;
+                Self::new(bytes.as_slice())
+            }
+        }
+    };
+    // Types with a restricted character set, which is only checked when
+    // `Reader::lenient_charsets` is disabled.
+    ($type: ty, $($li: lifetime)?; lenient) => {
+        impl_string_type!(@common $type, $($li),*);
+
+        impl<'__der: $($li),*, $($li),*> $crate::DecodeValue<'__der> for $type {
+            fn decode_value<R: $crate::Reader<'__der>>(
+                reader: &mut R,
+                header: $crate::Header,
+            ) -> $crate::Result<Self> {
+                let bytes = <$crate::BytesRef<'__der> as $crate::DecodeValue<'__der>>::decode_value(
+                    reader, header,
+                )?;
+
+                if $crate::Reader::lenient_charsets(reader) {
+                    Self::new_lenient(bytes.as_slice())
+                } else {
+                    Self::new(bytes.as_slice())
+                }
+            }
+        }
+    };
+    (@common $type: ty, $($li: lifetime)?) => {
         impl_any_conversions!($type, $($li),*);
 
         mod __impl_string {
             use super::*;
 
-            use crate::{
-                ord::OrdIsValueOrd, BytesRef, DecodeValue, EncodeValue, Header, Length, Reader,
-                Result, Writer,
-            };
+            use crate::{ord::OrdIsValueOrd, EncodeValue, Length, Result, Writer};
             use core::{fmt, str};
 
             impl<$($li),*> AsRef<str> for $type {
@@ -47,12 +81,6 @@ macro_rules! impl_string_type {
                 }
             }
 
-            impl<'__der: $($li),*, $($li),*> DecodeValue<'__der> for $type {
-                fn decode_value<R: Reader<'__der>>(reader: &mut R, header: Header) -> Result<Self> {
-                    Self::new(BytesRef::decode_value(reader, header)?.as_slice())
-                }
-            }
-
             impl<$($li),*> EncodeValue for $type {
                 fn value_len(&self) -> Result<Length> {
                     self.inner.value_len()
@@ -70,6 +98,248 @@ macro_rules! impl_string_type {
                     f.write_str(self.as_str())
                 }
             }
+
+            // Implement by hand because the derive would create invalid values.
+            // Use the constructor to create a valid value.
+            #[cfg(feature = "arbitrary")]
+            impl<'__der: $($li),*, $($li),*> arbitrary::Arbitrary<'__der> for $type {
+                fn arbitrary(u: &mut arbitrary::Unstructured<'__der>) -> arbitrary::Result<Self> {
+                    Self::new(<&'__der str>::arbitrary(u)?)
+                        .map_err(|_| arbitrary::Error::IncorrectFormat)
+                }
+
+                fn size_hint(depth: usize) -> (usize, Option<usize>) {
+                    <&'__der str as arbitrary::Arbitrary<'__der>>::size_hint(depth)
+                }
+            }
+        }
+    };
+}
+
+/// Implement a borrowed legacy 8-bit string type (e.g. `TeletexString`)
+/// whose contents are accepted as long as they're valid UTF-8.
+macro_rules! impl_legacy_string_ref {
+    ($type: ident, $tag: expr, $name: literal) => {
+        impl<'a> $type<'a> {
+            #[doc = concat!("Create a new ASN.1 `", $name, "`.")]
+            ///
+            /// Returns an error if the input is not valid UTF-8.
+            pub fn new<T>(input: &'a T) -> $crate::Result<Self>
+            where
+                T: AsRef<[u8]> + ?Sized,
+            {
+                $crate::StrRef::from_bytes(input.as_ref())
+                    .map(|inner| Self { inner })
+                    .map_err(|_| <Self as $crate::FixedTag>::TAG.value_error())
+            }
+        }
+
+        impl_string_type!($type<'a>, 'a);
+
+        impl<'a> core::ops::Deref for $type<'a> {
+            type Target = $crate::StrRef<'a>;
+
+            fn deref(&self) -> &Self::Target {
+                &self.inner
+            }
+        }
+
+        impl $crate::FixedTag for $type<'_> {
+            const TAG: $crate::Tag = $tag;
+        }
+
+        impl<'a> From<&$type<'a>> for $type<'a> {
+            fn from(value: &$type<'a>) -> $type<'a> {
+                *value
+            }
+        }
+
+        impl<'a> From<$type<'a>> for $crate::asn1::AnyRef<'a> {
+            fn from(value: $type<'a>) -> $crate::asn1::AnyRef<'a> {
+                $crate::asn1::AnyRef::from_tag_and_value($tag, value.inner.into())
+            }
+        }
+
+        impl core::fmt::Debug for $type<'_> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, concat!($name, "({:?})"), self.as_str())
+            }
+        }
+    };
+}
+
+/// Implement an owned legacy 8-bit string type (e.g. `TeletexString`) with
+/// fields `inner: StrOwned` and `latin1: Option<BytesOwned>`.
+///
+/// Inputs which are valid UTF-8 are stored as-is. Other inputs are decoded as
+/// ISO 8859-1 (Latin-1), in which case the original bytes are retained in
+/// `latin1` so they can be re-encoded exactly.
+#[cfg(feature = "alloc")]
+macro_rules! impl_legacy_string_owned {
+    ($type: ident, $ref_type: ident, $tag: expr, $name: literal) => {
+        impl $type {
+            #[doc = concat!("Create a new ASN.1 `", $name, "` from its encoded bytes.")]
+            ///
+            /// Input which is valid UTF-8 is used as-is, and any other input
+            /// is decoded as ISO 8859-1 (Latin-1).
+            pub fn new<T>(input: &T) -> $crate::Result<Self>
+            where
+                T: AsRef<[u8]> + ?Sized,
+            {
+                let input = input.as_ref();
+
+                match $crate::StrOwned::from_bytes(input) {
+                    Ok(inner) => Ok(Self {
+                        inner,
+                        latin1: None,
+                    }),
+                    Err(_) => Ok(Self {
+                        inner: $crate::StrOwned::from_latin1(input)?,
+                        latin1: Some($crate::BytesOwned::new(input)?),
+                    }),
+                }
+            }
+
+            /// Get the encoded bytes of this string, which differ from its
+            /// UTF-8 representation if it was decoded from ISO 8859-1.
+            pub fn as_encoded_bytes(&self) -> &[u8] {
+                match &self.latin1 {
+                    Some(bytes) => bytes.as_slice(),
+                    None => self.inner.as_bytes(),
+                }
+            }
+
+            /// Was this string decoded from ISO 8859-1 (Latin-1) encoded bytes
+            /// which are not valid UTF-8?
+            pub fn is_latin1(&self) -> bool {
+                self.latin1.is_some()
+            }
+        }
+
+        impl_any_conversions!($type);
+
+        impl AsRef<str> for $type {
+            fn as_ref(&self) -> &str {
+                self.inner.as_str()
+            }
+        }
+
+        impl core::ops::Deref for $type {
+            type Target = $crate::StrOwned;
+
+            fn deref(&self) -> &Self::Target {
+                &self.inner
+            }
+        }
+
+        impl<'a> $crate::DecodeValue<'a> for $type {
+            fn decode_value<R: $crate::Reader<'a>>(
+                reader: &mut R,
+                header: $crate::Header,
+            ) -> $crate::Result<Self> {
+                let bytes = <$crate::BytesRef<'a> as $crate::DecodeValue<'a>>::decode_value(
+                    reader, header,
+                )?;
+                Self::new(bytes.as_slice())
+            }
+        }
+
+        impl $crate::EncodeValue for $type {
+            fn value_len(&self) -> $crate::Result<$crate::Length> {
+                match &self.latin1 {
+                    Some(bytes) => Ok(bytes.len()),
+                    None => Ok(self.inner.len()),
+                }
+            }
+
+            fn encode_value(&self, writer: &mut impl $crate::Writer) -> $crate::Result<()> {
+                writer.write(self.as_encoded_bytes())
+            }
+        }
+
+        impl $crate::FixedTag for $type {
+            const TAG: $crate::Tag = $tag;
+        }
+
+        impl $crate::ValueOrd for $type {
+            fn value_cmp(&self, other: &Self) -> $crate::Result<core::cmp::Ordering> {
+                Ok(self.as_encoded_bytes().cmp(other.as_encoded_bytes()))
+            }
+        }
+
+        impl core::fmt::Display for $type {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str(self.inner.as_str())
+            }
+        }
+
+        impl core::fmt::Debug for $type {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, concat!($name, "({:?})"), self.inner.as_str())
+            }
+        }
+
+        impl<'a> From<$ref_type<'a>> for $type {
+            fn from(value: $ref_type<'a>) -> $type {
+                Self {
+                    inner: value.inner.into(),
+                    latin1: None,
+                }
+            }
+        }
+
+        impl<'a> From<&'a $type> for $crate::asn1::AnyRef<'a> {
+            fn from(value: &'a $type) -> $crate::asn1::AnyRef<'a> {
+                $crate::asn1::AnyRef::from_tag_and_value(
+                    $tag,
+                    $crate::BytesRef::new(value.as_encoded_bytes())
+                        .expect(concat!("Invalid ", $name)),
+                )
+            }
+        }
+
+        impl<'a> $crate::referenced::RefToOwned<'a> for $ref_type<'a> {
+            type Owned = $type;
+            fn ref_to_owned(&self) -> Self::Owned {
+                $type::from(*self)
+            }
+        }
+
+        /// Strings decoded from ISO 8859-1 are borrowed in their UTF-8 form.
+        impl $crate::referenced::OwnedToRef for $type {
+            type Borrowed<'a> = $ref_type<'a>;
+            fn owned_to_ref(&self) -> Self::Borrowed<'_> {
+                $ref_type {
+                    inner: $crate::referenced::OwnedToRef::owned_to_ref(&self.inner),
+                }
+            }
+        }
+
+        // Implement by hand because the derive would create invalid values.
+        // Arbitrary bytes are accepted, since they're decoded as ISO 8859-1
+        // unless they happen to be valid UTF-8.
+        #[cfg(feature = "arbitrary")]
+        impl<'a> arbitrary::Arbitrary<'a> for $type {
+            fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+                Self::new(<&'a [u8]>::arbitrary(u)?).map_err(|_| arbitrary::Error::IncorrectFormat)
+            }
+
+            fn size_hint(depth: usize) -> (usize, Option<usize>) {
+                <&'a [u8] as arbitrary::Arbitrary<'a>>::size_hint(depth)
+            }
+        }
+
+        impl TryFrom<alloc::string::String> for $type {
+            type Error = $crate::Error;
+
+            fn try_from(input: alloc::string::String) -> $crate::Result<Self> {
+                $crate::StrOwned::new(input)
+                    .map(|inner| Self {
+                        inner,
+                        latin1: None,
+                    })
+                    .map_err(|_| <Self as $crate::FixedTag>::TAG.value_error())
+            }
         }
     };
 }
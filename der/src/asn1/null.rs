@@ -6,6 +6,7 @@ use crate::{
 };
 
 /// ASN.1 `NULL` type.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 pub struct Null;
 
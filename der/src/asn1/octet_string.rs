@@ -1,8 +1,8 @@
 //! ASN.1 `OCTET STRING` support.
 
 use crate::{
-    asn1::AnyRef, ord::OrdIsValueOrd, BytesRef, Decode, DecodeValue, EncodeValue, ErrorKind,
-    FixedTag, Header, Length, Reader, Result, Tag, Writer,
+    asn1::AnyRef, ord::OrdIsValueOrd, BytesRef, Decode, DecodeValue, Encode, EncodeValue,
+    ErrorKind, FixedTag, Header, Length, Reader, Result, Tag, Writer,
 };
 
 /// ASN.1 `OCTET STRING` type: borrowed form.
@@ -40,8 +40,17 @@ impl<'a> OctetStringRef<'a> {
     }
 
     /// Parse `T` from this `OCTET STRING`'s contents.
+    #[deprecated(since = "0.7.8", note = "use `decode_inner` instead")]
     pub fn decode_into<T: Decode<'a>>(&self) -> Result<T> {
-        Decode::from_der(self.as_bytes())
+        self.decode_inner()
+    }
+
+    /// Decode the DER-encoded `T` contained in this `OCTET STRING`, as used
+    /// by e.g. X.509 extension values and CMS `eContent`.
+    ///
+    /// The contents must consist of exactly one encoded `T`.
+    pub fn decode_inner<T: Decode<'a>>(self) -> Result<T> {
+        T::from_der(self.as_bytes())
     }
 }
 
@@ -76,6 +85,51 @@ impl FixedTag for OctetStringRef<'_> {
 
 impl OrdIsValueOrd for OctetStringRef<'_> {}
 
+// Implement by hand because the derive would create invalid values.
+// Use the constructor to create a valid value.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for OctetStringRef<'a> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Self::new(BytesRef::arbitrary(u)?.as_slice()).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        BytesRef::size_hint(depth)
+    }
+}
+
+/// Reference to a value which is encoded as the contents of an ASN.1
+/// `OCTET STRING`.
+///
+/// This is the encoding counterpart of [`OctetStringRef::decode_inner`], and
+/// doesn't require an intermediate buffer.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct OctetStringInnerRef<'a, T> {
+    /// Inner value.
+    pub inner: &'a T,
+}
+
+impl<'a, T> OctetStringInnerRef<'a, T> {
+    /// Create a new [`OctetStringInnerRef`] wrapping the given value.
+    pub fn new(inner: &'a T) -> Self {
+        Self { inner }
+    }
+}
+
+impl<T: Encode> EncodeValue for OctetStringInnerRef<'_, T> {
+    fn value_len(&self) -> Result<Length> {
+        self.inner.encoded_len()
+    }
+
+    fn encode_value(&self, writer: &mut impl Writer) -> Result<()> {
+        self.inner.encode(writer)
+    }
+}
+
+impl<T> FixedTag for OctetStringInnerRef<'_, T> {
+    const TAG: Tag = Tag::OctetString;
+}
+
 impl<'a> From<&OctetStringRef<'a>> for OctetStringRef<'a> {
     fn from(value: &OctetStringRef<'a>) -> OctetStringRef<'a> {
         *value
@@ -141,6 +195,13 @@ mod allocating {
             self.value_len().expect("invalid OCTET STRING length")
         }
 
+        /// Decode the DER-encoded `T` contained in this `OCTET STRING`.
+        ///
+        /// See [`OctetStringRef::decode_inner`].
+        pub fn decode_inner<'a, T: Decode<'a>>(&'a self) -> Result<T> {
+            OctetStringRef::from(self).decode_inner()
+        }
+
         /// Is the inner byte slice empty?
         pub fn is_empty(&self) -> bool {
             self.inner.is_empty()
@@ -184,6 +245,13 @@ mod allocating {
 
     impl OrdIsValueOrd for OctetString {}
 
+    #[cfg(feature = "zeroize")]
+    impl zeroize::Zeroize for OctetString {
+        fn zeroize(&mut self) {
+            self.inner.zeroize();
+        }
+    }
+
     impl<'a> RefToOwned<'a> for OctetStringRef<'a> {
         type Owned = OctetString;
         fn ref_to_owned(&self) -> Self::Owned {
@@ -243,9 +311,13 @@ mod bytes {
 
 #[cfg(test)]
 mod tests {
-    use crate::asn1::{OctetStringRef, PrintableStringRef};
+    use crate::{
+        asn1::{OctetStringInnerRef, OctetStringRef, PrintableStringRef},
+        Encode, ErrorKind,
+    };
 
     #[test]
+    #[allow(deprecated)]
     fn octet_string_decode_into() {
         // PrintableString "hi"
         let der = b"\x13\x02\x68\x69";
@@ -254,4 +326,29 @@ mod tests {
         let res = oct.decode_into::<PrintableStringRef<'_>>().unwrap();
         assert_eq!(AsRef::<str>::as_ref(&res), "hi");
     }
+
+    #[test]
+    fn octet_string_decode_inner() {
+        // PrintableString "hi"
+        let der = b"\x13\x02\x68\x69";
+        let oct = OctetStringRef::new(der).unwrap();
+
+        let res = oct.decode_inner::<PrintableStringRef<'_>>().unwrap();
+        assert_eq!(AsRef::<str>::as_ref(&res), "hi");
+
+        // Trailing data after the inner value
+        let oct = OctetStringRef::new(b"\x13\x02\x68\x69\x00").unwrap();
+        let err = oct.decode_inner::<PrintableStringRef<'_>>().unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::TrailingData { .. }));
+    }
+
+    #[test]
+    fn octet_string_inner_ref_encode() {
+        let hi = PrintableStringRef::new("hi").unwrap();
+        let mut buf = [0u8; 6];
+        let der = OctetStringInnerRef::new(&hi)
+            .encode_to_slice(&mut buf)
+            .unwrap();
+        assert_eq!(der, b"\x04\x04\x13\x02\x68\x69");
+    }
 }
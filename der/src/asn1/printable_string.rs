@@ -8,7 +8,7 @@ macro_rules! impl_printable_string {
         impl_printable_string!($type,);
     };
     ($type: ty, $($li: lifetime)?) => {
-        impl_string_type!($type, $($li),*);
+        impl_string_type!($type, $($li),*; lenient);
 
         impl<$($li),*> FixedTag for $type {
             const TAG: Tag = Tag::PrintableString;
@@ -87,6 +87,13 @@ impl<'a> PrintableStringRef<'a> {
             }
         }
 
+        Self::new_lenient(input)
+    }
+
+    /// Create a new ASN.1 `PrintableString` without validating its character set.
+    ///
+    /// Used when decoding with [`Reader::lenient_charsets`][`crate::Reader::lenient_charsets`].
+    pub(crate) fn new_lenient(input: &'a [u8]) -> Result<Self> {
         StrRef::from_bytes(input)
             .map(|inner| Self { inner })
             .map_err(|_| Self::TAG.value_error())
@@ -170,7 +177,11 @@ mod allocation {
         {
             let input = input.as_ref();
             PrintableStringRef::new(input)?;
+            Self::new_lenient(input)
+        }
 
+        /// Create a new ASN.1 `PrintableString` without validating its character set.
+        pub(crate) fn new_lenient(input: &[u8]) -> Result<Self> {
             StrOwned::from_bytes(input)
                 .map(|inner| Self { inner })
                 .map_err(|_| Self::TAG.value_error())
@@ -238,7 +249,7 @@ mod allocation {
 #[cfg(test)]
 mod tests {
     use super::PrintableStringRef;
-    use crate::Decode;
+    use crate::{Decode, SliceReader};
 
     #[test]
     fn parse_bytes() {
@@ -249,4 +260,27 @@ mod tests {
         let printable_string = PrintableStringRef::from_der(example_bytes).unwrap();
         assert_eq!(printable_string.as_str(), "Test User 1");
     }
+
+    #[test]
+    fn lenient_charsets() {
+        // "user@example.com", which contains `@`
+        let example_bytes = &[
+            0x13, 0x10, 0x75, 0x73, 0x65, 0x72, 0x40, 0x65, 0x78, 0x61, 0x6d, 0x70, 0x6c, 0x65,
+            0x2e, 0x63, 0x6f, 0x6d,
+        ];
+
+        assert!(PrintableStringRef::from_der(example_bytes).is_err());
+
+        let mut reader = SliceReader::new(example_bytes)
+            .unwrap()
+            .with_lenient_charsets(true);
+        let printable_string = PrintableStringRef::decode(&mut reader).unwrap();
+        assert_eq!(printable_string.as_str(), "user@example.com");
+
+        // Invalid UTF-8 is still rejected
+        let mut reader = SliceReader::new(&[0x13, 0x01, 0xff])
+            .unwrap()
+            .with_lenient_charsets(true);
+        assert!(PrintableStringRef::decode(&mut reader).is_err());
+    }
 }
@@ -8,8 +8,8 @@
 )]
 
 use crate::{
-    BytesRef, DecodeValue, EncodeValue, FixedTag, Header, Length, Reader, Result, StrRef, Tag,
-    Writer,
+    BytesRef, DecodeValue, EncodeValue, ErrorKind, FixedTag, Header, Length, Reader, Result,
+    SliceWriter, StrRef, Tag, Writer,
 };
 
 use super::integer::uint::strip_leading_zeroes;
@@ -196,6 +196,611 @@ impl FixedTag for f64 {
     const TAG: Tag = Tag::Real;
 }
 
+/// ASN.1 `REAL` type.
+///
+/// Represents the abstract value of a `REAL` along with the form it's
+/// encoded in, as described in X.690 Section 8.5:
+///
+/// - binary encoding (Section 8.5.7): `mantissa × 2^exponent`
+/// - decimal encoding (Section 8.5.8): `mantissa × 10^exponent`, using the
+///   ISO 6093 NR1, NR2, or NR3 forms
+/// - special real values (Section 8.5.9)
+///
+/// Values are always encoded canonically as described in X.690 Section 11.3:
+/// binary values use base 2 with an odd mantissa, and decimal values use the
+/// NR3 form with no trailing zeroes in the mantissa.
+///
+/// When decoding, the binary encoding must be canonical DER, whereas decimal
+/// values are accepted in any of the NR1, NR2, and NR3 forms.
+///
+/// Conversions from [`f64`] are exact and always use the binary form.
+/// Conversions to [`f64`] are performed by [`Real::to_f64`] and round to the
+/// nearest representable value.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Real {
+    /// Zero, which is encoded with an empty value.
+    Zero,
+
+    /// `MINUS-ZERO`.
+    MinusZero,
+
+    /// `PLUS-INFINITY`.
+    PlusInfinity,
+
+    /// `MINUS-INFINITY`.
+    MinusInfinity,
+
+    /// `NOT-A-NUMBER`.
+    NotANumber,
+
+    /// Binary encoding of the value `mantissa × 2^exponent`, negated if
+    /// `negative` is set.
+    Binary {
+        /// Is the value negative?
+        negative: bool,
+
+        /// Mantissa.
+        mantissa: u64,
+
+        /// Base 2 exponent.
+        exponent: i32,
+    },
+
+    /// Decimal encoding of the value `mantissa × 10^exponent`, negated if
+    /// `negative` is set.
+    Decimal {
+        /// Is the value negative?
+        negative: bool,
+
+        /// Mantissa.
+        mantissa: u64,
+
+        /// Base 10 exponent.
+        exponent: i32,
+    },
+}
+
+impl Real {
+    /// Maximum length of the value of an encoded [`Real`]: a decimal value
+    /// with a 20-digit mantissa and an exponent of `i32::MIN`.
+    const MAX_VALUE_LEN: usize = 35;
+
+    /// Convert this value to the nearest [`f64`].
+    pub fn to_f64(self) -> f64 {
+        match self {
+            Real::Zero => 0.0,
+            Real::MinusZero => -0.0,
+            Real::PlusInfinity => f64::INFINITY,
+            Real::MinusInfinity => f64::NEG_INFINITY,
+            Real::NotANumber => f64::NAN,
+            Real::Binary {
+                negative,
+                mantissa,
+                exponent,
+            } => binary_to_f64(negative, mantissa, exponent),
+            Real::Decimal {
+                negative,
+                mantissa,
+                exponent,
+            } => decimal_to_f64(negative, mantissa, exponent),
+        }
+    }
+
+    /// Get the canonical form of this value.
+    ///
+    /// Returns an error if normalizing the mantissa overflows the exponent.
+    fn normalize(self) -> Result<Self> {
+        match self {
+            Real::Binary { mantissa: 0, .. } | Real::Decimal { mantissa: 0, .. } => Ok(Real::Zero),
+            Real::Binary {
+                negative,
+                mantissa,
+                exponent,
+            } => {
+                let zeroes = mantissa.trailing_zeros();
+
+                Ok(Real::Binary {
+                    negative,
+                    mantissa: mantissa >> zeroes,
+                    exponent: i32::try_from(zeroes)
+                        .ok()
+                        .and_then(|zeroes| exponent.checked_add(zeroes))
+                        .ok_or(ErrorKind::Overflow)?,
+                })
+            }
+            Real::Decimal {
+                negative,
+                mut mantissa,
+                mut exponent,
+            } => {
+                while mantissa % 10 == 0 {
+                    mantissa /= 10;
+                    exponent = exponent.checked_add(1).ok_or(ErrorKind::Overflow)?;
+                }
+
+                Ok(Real::Decimal {
+                    negative,
+                    mantissa,
+                    exponent,
+                })
+            }
+            special => Ok(special),
+        }
+    }
+
+    /// Encode the value of this [`Real`] into the provided buffer.
+    fn encode_value_to_slice<'a>(
+        &self,
+        buf: &'a mut [u8; Self::MAX_VALUE_LEN],
+    ) -> Result<&'a [u8]> {
+        let mut writer = SliceWriter::new(buf);
+
+        match self.normalize()? {
+            Real::Zero => (),
+            Real::PlusInfinity => writer.write_byte(0b0100_0000)?,
+            Real::MinusInfinity => writer.write_byte(0b0100_0001)?,
+            Real::NotANumber => writer.write_byte(0b0100_0010)?,
+            Real::MinusZero => writer.write_byte(0b0100_0011)?,
+            Real::Binary {
+                negative,
+                mantissa,
+                exponent,
+            } => {
+                let exponent_bytes = exponent.to_be_bytes();
+                let exponent_bytes = strip_redundant_sign_bytes(&exponent_bytes);
+                let mantissa_bytes = mantissa.to_be_bytes();
+
+                // Section 8.5.7: bit 8 selects the binary encoding and bit 7
+                // is the sign, whereas bits 6-5 (base 2) and 4-3 (scaling
+                // factor 0) are always zero in DER
+                let mut first_byte = 0b1000_0000;
+
+                if negative {
+                    first_byte |= 0b0100_0000;
+                }
+
+                // Section 8.5.7.4: bits 2-1 encode the length of the exponent
+                match exponent_bytes.len() {
+                    1 => writer.write_byte(first_byte)?,
+                    2 => writer.write_byte(first_byte | 0b01)?,
+                    3 => writer.write_byte(first_byte | 0b10)?,
+                    len => {
+                        writer.write_byte(first_byte | 0b11)?;
+                        writer.write_byte(u8::try_from(len)?)?;
+                    }
+                }
+
+                writer.write(exponent_bytes)?;
+                writer.write(strip_leading_zeroes(&mantissa_bytes))?;
+            }
+            Real::Decimal {
+                negative,
+                mantissa,
+                exponent,
+            } => {
+                // X.690 Section 11.3.2: NR3 form with no leading or trailing
+                // zeroes in the mantissa and no leading zeroes in the exponent
+                writer.write_byte(0b0000_0011)?;
+
+                if negative {
+                    writer.write_byte(b'-')?;
+                }
+
+                write_digits(&mut writer, mantissa)?;
+                writer.write(b".E")?;
+
+                if exponent == 0 {
+                    writer.write(b"+0")?;
+                } else {
+                    if exponent < 0 {
+                        writer.write_byte(b'-')?;
+                    }
+
+                    write_digits(&mut writer, u64::from(exponent.unsigned_abs()))?;
+                }
+            }
+        }
+
+        writer.finish()
+    }
+}
+
+impl<'a> DecodeValue<'a> for Real {
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
+        let bytes = BytesRef::decode_value(reader, header)?.as_slice();
+
+        let (first_byte, rest) = match bytes.split_first() {
+            Some(split) => split,
+            None => return Ok(Real::Zero),
+        };
+
+        if first_byte & 0b1000_0000 != 0 {
+            decode_binary(*first_byte, rest)
+        } else if first_byte & 0b0100_0000 != 0 {
+            if !rest.is_empty() {
+                return Err(Tag::Real.length_error());
+            }
+
+            match first_byte {
+                0b0100_0000 => Ok(Real::PlusInfinity),
+                0b0100_0001 => Ok(Real::MinusInfinity),
+                0b0100_0010 => Ok(Real::NotANumber),
+                0b0100_0011 => Ok(Real::MinusZero),
+                _ => Err(Tag::Real.value_error()),
+            }
+        } else {
+            decode_decimal(*first_byte, rest)
+        }
+    }
+}
+
+impl EncodeValue for Real {
+    fn value_len(&self) -> Result<Length> {
+        let mut buf = [0u8; Self::MAX_VALUE_LEN];
+        self.encode_value_to_slice(&mut buf)?.len().try_into()
+    }
+
+    fn encode_value(&self, writer: &mut impl Writer) -> Result<()> {
+        let mut buf = [0u8; Self::MAX_VALUE_LEN];
+        writer.write(self.encode_value_to_slice(&mut buf)?)
+    }
+}
+
+impl FixedTag for Real {
+    const TAG: Tag = Tag::Real;
+}
+
+impl From<f64> for Real {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    fn from(value: f64) -> Real {
+        if value.is_nan() {
+            return Real::NotANumber;
+        }
+
+        let negative = value.is_sign_negative();
+
+        if value.is_infinite() {
+            return if negative {
+                Real::MinusInfinity
+            } else {
+                Real::PlusInfinity
+            };
+        }
+
+        let bits = value.to_bits();
+        let biased_exponent = ((bits >> 52) & 0x7ff) as i32;
+        let fraction = bits & 0xf_ffff_ffff_ffff;
+
+        let (mantissa, exponent) = if biased_exponent == 0 {
+            // Zero or subnormal
+            (fraction, -1074)
+        } else {
+            (fraction | 1 << 52, biased_exponent - 1075)
+        };
+
+        // The only normalization required is removing trailing zeroes from
+        // the mantissa, which can't overflow the exponent
+        match (mantissa, negative) {
+            (0, false) => Real::Zero,
+            (0, true) => Real::MinusZero,
+            _ => Real::Binary {
+                negative,
+                mantissa: mantissa >> mantissa.trailing_zeros(),
+                exponent: exponent + mantissa.trailing_zeros() as i32,
+            },
+        }
+    }
+}
+
+impl From<Real> for f64 {
+    fn from(value: Real) -> f64 {
+        value.to_f64()
+    }
+}
+
+/// Decode the binary encoding of a `REAL` (X.690 Section 8.5.7), which must
+/// use the canonical form described in X.690 Section 11.3.1.
+fn decode_binary(first_byte: u8, rest: &[u8]) -> Result<Real> {
+    let negative = first_byte & 0b0100_0000 != 0;
+
+    // DER only supports base 2
+    if first_byte & 0b0011_0000 != 0 {
+        return Err(Tag::Real.value_error());
+    }
+
+    let (exponent_bytes, mantissa_bytes) = match first_byte & 0b0000_0011 {
+        0b11 => {
+            let (&len, rest) = rest.split_first().ok_or_else(|| Tag::Real.length_error())?;
+
+            if len < 4 {
+                // Shorter exponents must use the preceding forms
+                return Err(Tag::Real.non_canonical_error());
+            }
+
+            split_exponent(rest, usize::from(len))?
+        }
+        len => split_exponent(rest, usize::from(len) + 1)?,
+    };
+
+    let exponent_len = exponent_bytes.len();
+    if strip_redundant_sign_bytes(exponent_bytes).len() != exponent_len {
+        return Err(Tag::Real.non_canonical_error());
+    }
+
+    if exponent_len > 4 {
+        return Err(Tag::Real.value_error());
+    }
+
+    let mut exponent = i32::from(i8::from_be_bytes([exponent_bytes[0]]));
+    for &byte in &exponent_bytes[1..] {
+        exponent = exponent << 8 | i32::from(byte);
+    }
+
+    if mantissa_bytes.len() > 8 {
+        return Err(Tag::Real.value_error());
+    }
+
+    let mut mantissa = 0u64;
+    for &byte in mantissa_bytes {
+        mantissa = mantissa << 8 | u64::from(byte);
+    }
+
+    // X.690 Section 11.3.1: the scaling factor must be zero and the mantissa
+    // must be odd and encoded without leading zeroes
+    if first_byte & 0b0000_1100 != 0 || mantissa & 1 == 0 || mantissa_bytes.first() == Some(&0) {
+        return Err(Tag::Real.non_canonical_error());
+    }
+
+    Ok(Real::Binary {
+        negative,
+        mantissa,
+        exponent,
+    })
+}
+
+/// Decode the decimal encoding of a `REAL` (X.690 Section 8.5.8) in any of
+/// the ISO 6093 NR1, NR2, or NR3 forms.
+fn decode_decimal(first_byte: u8, mut chars: &[u8]) -> Result<Real> {
+    let (allow_decimal_mark, has_exponent) = match first_byte {
+        0b0000_0001 => (false, false),
+        0b0000_0010 => (true, false),
+        0b0000_0011 => (true, true),
+        _ => return Err(Tag::Real.value_error()),
+    };
+
+    while let Some((b' ', rest)) = chars.split_first() {
+        chars = rest;
+    }
+
+    let negative = parse_sign(&mut chars);
+    let mut mantissa = 0u64;
+    let mut exponent = 0i32;
+    let mut has_digits = false;
+    let mut has_decimal_mark = false;
+
+    while let Some((&c, rest)) = chars.split_first() {
+        match c {
+            b'0'..=b'9' => {
+                mantissa = mantissa
+                    .checked_mul(10)
+                    .and_then(|m| m.checked_add(u64::from(c - b'0')))
+                    .ok_or_else(|| Tag::Real.value_error())?;
+
+                if has_decimal_mark {
+                    exponent = exponent.checked_sub(1).ok_or(ErrorKind::Overflow)?;
+                }
+
+                has_digits = true;
+            }
+            b'.' | b',' if allow_decimal_mark && !has_decimal_mark => has_decimal_mark = true,
+            _ => break,
+        }
+
+        chars = rest;
+    }
+
+    if !has_digits {
+        return Err(Tag::Real.value_error());
+    }
+
+    if has_exponent {
+        match chars.split_first() {
+            Some((b'E' | b'e', rest)) => chars = rest,
+            _ => return Err(Tag::Real.value_error()),
+        }
+
+        let exponent_negative = parse_sign(&mut chars);
+        let mut explicit_exponent = 0i32;
+        let mut has_exponent_digits = false;
+
+        while let Some((&c @ b'0'..=b'9', rest)) = chars.split_first() {
+            let digit = i32::from(c - b'0');
+
+            explicit_exponent = explicit_exponent
+                .checked_mul(10)
+                .and_then(|e| {
+                    if exponent_negative {
+                        e.checked_sub(digit)
+                    } else {
+                        e.checked_add(digit)
+                    }
+                })
+                .ok_or(ErrorKind::Overflow)?;
+
+            has_exponent_digits = true;
+            chars = rest;
+        }
+
+        if !has_exponent_digits {
+            return Err(Tag::Real.value_error());
+        }
+
+        exponent = exponent
+            .checked_add(explicit_exponent)
+            .ok_or(ErrorKind::Overflow)?;
+    }
+
+    if !chars.is_empty() {
+        return Err(Tag::Real.value_error());
+    }
+
+    match (mantissa, negative) {
+        (0, false) => Ok(Real::Zero),
+        (0, true) => Ok(Real::MinusZero),
+        _ => Real::Decimal {
+            negative,
+            mantissa,
+            exponent,
+        }
+        .normalize(),
+    }
+}
+
+/// Parse an optional leading `+` or `-` sign, returning whether the value is
+/// negative.
+fn parse_sign(chars: &mut &[u8]) -> bool {
+    match chars.split_first() {
+        Some((b'-', rest)) => {
+            *chars = rest;
+            true
+        }
+        Some((b'+', rest)) => {
+            *chars = rest;
+            false
+        }
+        _ => false,
+    }
+}
+
+/// Split the exponent octets of the given length from the remaining octets of
+/// a binary-encoded `REAL`.
+fn split_exponent(bytes: &[u8], len: usize) -> Result<(&[u8], &[u8])> {
+    if bytes.len() > len {
+        Ok(bytes.split_at(len))
+    } else {
+        Err(Tag::Real.length_error())
+    }
+}
+
+/// Strip the leading octets of a big endian two's complement integer which
+/// are redundant, i.e. only extend the sign bit.
+fn strip_redundant_sign_bytes(mut bytes: &[u8]) -> &[u8] {
+    while let [first, second, ..] = bytes {
+        if (*first == 0x00 && second & 0x80 == 0) || (*first == 0xFF && second & 0x80 != 0) {
+            bytes = &bytes[1..];
+        } else {
+            break;
+        }
+    }
+
+    bytes
+}
+
+/// Write the decimal digits of the given integer.
+#[allow(clippy::cast_possible_truncation)]
+fn write_digits(writer: &mut impl Writer, mut n: u64) -> Result<()> {
+    let mut buf = [0u8; 20];
+    let mut pos = buf.len();
+
+    loop {
+        pos -= 1;
+        buf[pos] = b'0' + (n % 10) as u8;
+        n /= 10;
+
+        if n == 0 {
+            break;
+        }
+    }
+
+    writer.write(&buf[pos..])
+}
+
+/// Convert `mantissa × 2^exponent` to the nearest [`f64`], rounding ties to
+/// even.
+#[allow(clippy::cast_possible_truncation)]
+fn binary_to_f64(negative: bool, mantissa: u64, exponent: i32) -> f64 {
+    let sign = u64::from(negative) << 63;
+
+    if mantissa == 0 {
+        return f64::from_bits(sign);
+    }
+
+    // Number of low-order bits of the mantissa which can't be represented:
+    // either because they exceed the 53-bit precision of an `f64`, or
+    // because they're below the smallest subnormal exponent
+    let bit_len = 64 - i64::from(mantissa.leading_zeros());
+    let mut exponent = i64::from(exponent);
+    let shift = (bit_len - 53).max(-1074 - exponent);
+
+    let mut mantissa = if shift > 0 {
+        // Round to nearest, ties to even
+        let shift = shift.min(127) as u32;
+        let wide = u128::from(mantissa);
+        let quotient = wide >> shift;
+        let remainder = wide & ((1 << shift) - 1);
+        let half = 1 << (shift - 1);
+
+        if remainder > half || (remainder == half && quotient & 1 == 1) {
+            (quotient + 1) as u64
+        } else {
+            quotient as u64
+        }
+    } else {
+        mantissa << -shift
+    };
+
+    exponent += shift;
+
+    // Rounding may carry into an additional bit
+    if mantissa >> 53 != 0 {
+        mantissa >>= 1;
+        exponent += 1;
+    }
+
+    if mantissa < 1 << 52 {
+        // Zero or subnormal (in which case the exponent is -1074)
+        return f64::from_bits(sign | mantissa);
+    }
+
+    let biased_exponent = exponent + 1075;
+
+    if biased_exponent >= 0x7ff {
+        f64::from_bits(sign | 0x7ff << 52)
+    } else {
+        f64::from_bits(sign | (biased_exponent as u64) << 52 | (mantissa & 0xf_ffff_ffff_ffff))
+    }
+}
+
+/// Convert `mantissa × 10^exponent` to the nearest [`f64`].
+fn decimal_to_f64(negative: bool, mantissa: u64, exponent: i32) -> f64 {
+    // Format the value in scientific notation (at most 33 bytes) and use the
+    // `core` float parser, which is correctly rounded
+    let mut buf = [0u8; 33];
+    let mut writer = SliceWriter::new(&mut buf);
+
+    let formatted = (|| {
+        if negative {
+            writer.write_byte(b'-')?;
+        }
+
+        write_digits(&mut writer, mantissa)?;
+        writer.write_byte(b'E')?;
+
+        if exponent < 0 {
+            writer.write_byte(b'-')?;
+        }
+
+        write_digits(&mut writer, u64::from(exponent.unsigned_abs()))?;
+        writer.finish()
+    })();
+
+    formatted
+        .ok()
+        .and_then(|bytes| core::str::from_utf8(bytes).ok())
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(f64::NAN)
+}
+
 /// Is the N-th bit 1 in the first octet?
 /// NOTE: this function is zero indexed
 pub(crate) fn is_nth_bit_one<const N: usize>(bytes: &[u8]) -> bool {
@@ -987,4 +1592,234 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn real_binary_encoding() {
+        use super::Real;
+
+        for (val, mantissa, exponent, expected) in [
+            (1.0, 1, 0, &[0x09, 0x03, 0x80, 0x00, 0x01][..]),
+            (0.5, 1, -1, &[0x09, 0x03, 0x80, 0xFF, 0x01]),
+            (3.0, 3, 0, &[0x09, 0x03, 0x80, 0x00, 0x03]),
+            (-6.0, 3, 1, &[0x09, 0x03, 0xC0, 0x01, 0x03]),
+            (
+                f64::MIN_POSITIVE,
+                1,
+                -1022,
+                &[0x09, 0x04, 0x81, 0xFC, 0x02, 0x01],
+            ),
+        ] {
+            let real = Real::from(val);
+            assert_eq!(
+                real,
+                Real::Binary {
+                    negative: val < 0.0,
+                    mantissa,
+                    exponent
+                }
+            );
+
+            let encoded = real.to_der().unwrap();
+            assert_eq!(encoded, expected, "invalid encoding of {}", val);
+            assert_eq!(Real::from_der(expected).unwrap(), real);
+            assert_eq!(real.to_f64(), val);
+        }
+    }
+
+    #[test]
+    fn real_binary_long_exponent() {
+        use super::Real;
+
+        let real = Real::Binary {
+            negative: false,
+            mantissa: 1,
+            exponent: 0x0100_0000,
+        };
+
+        let expected = [0x09, 0x07, 0x83, 0x04, 0x01, 0x00, 0x00, 0x00, 0x01];
+        assert_eq!(real.to_der().unwrap(), expected);
+        assert_eq!(Real::from_der(&expected).unwrap(), real);
+        assert_eq!(real.to_f64(), f64::INFINITY);
+    }
+
+    #[test]
+    fn real_binary_normalized_on_encode() {
+        use super::Real;
+
+        let real = Real::Binary {
+            negative: false,
+            mantissa: 12,
+            exponent: 0,
+        };
+
+        assert_eq!(real.to_der().unwrap(), [0x09, 0x03, 0x80, 0x02, 0x03]);
+    }
+
+    #[test]
+    fn real_binary_rejects_non_canonical() {
+        use super::Real;
+        use crate::{ErrorKind, Tag};
+
+        for bytes in [
+            // even mantissa
+            &[0x09, 0x03, 0x80, 0x00, 0x02][..],
+            // mantissa with leading zero
+            &[0x09, 0x04, 0x80, 0x00, 0x00, 0x01],
+            // non-zero scaling factor
+            &[0x09, 0x03, 0x84, 0x00, 0x01],
+            // exponent with redundant leading octet
+            &[0x09, 0x04, 0x81, 0x00, 0x01, 0x01],
+        ] {
+            assert_eq!(
+                Real::from_der(bytes).err().map(|e| e.kind()),
+                Some(ErrorKind::Noncanonical { tag: Tag::Real })
+            );
+        }
+
+        // base 8
+        assert_eq!(
+            Real::from_der(&[0x09, 0x03, 0x90, 0x00, 0x01])
+                .err()
+                .map(|e| e.kind()),
+            Some(ErrorKind::Value { tag: Tag::Real })
+        );
+    }
+
+    #[test]
+    fn real_special_values() {
+        use super::Real;
+
+        for (real, expected) in [
+            (Real::Zero, &[0x09, 0x00][..]),
+            (Real::PlusInfinity, &[0x09, 0x01, 0x40]),
+            (Real::MinusInfinity, &[0x09, 0x01, 0x41]),
+            (Real::NotANumber, &[0x09, 0x01, 0x42]),
+            (Real::MinusZero, &[0x09, 0x01, 0x43]),
+        ] {
+            assert_eq!(real.to_der().unwrap(), expected);
+            assert_eq!(Real::from_der(expected).unwrap(), real);
+        }
+
+        assert_eq!(Real::from(0.0), Real::Zero);
+        assert_eq!(Real::from(-0.0), Real::MinusZero);
+        assert_eq!(Real::from(f64::NEG_INFINITY), Real::MinusInfinity);
+        assert!(Real::from(f64::NAN).to_f64().is_nan());
+    }
+
+    #[test]
+    fn real_decimal_forms() {
+        use super::Real;
+
+        for (bytes, mantissa, exponent, val) in [
+            // NR1: "  -12"
+            (&b"\x09\x06\x01  -12"[..], 12, 0, -12.0),
+            // NR2: "2,5"
+            (b"\x09\x04\x022,5", 25, -1, 2.5),
+            // NR2: "+1200."
+            (b"\x09\x07\x02+1200.", 12, 2, 1200.0),
+            // NR3: "1.5E3"
+            (b"\x09\x06\x031.5E3", 15, 2, 1500.0),
+            // NR3: "-951.2357864E-7"
+            (
+                b"\x09\x10\x03-951.2357864E-7",
+                9512357864,
+                -14,
+                -0.00009512357864,
+            ),
+        ] {
+            let real = Real::from_der(bytes).unwrap();
+            assert_eq!(
+                real,
+                Real::Decimal {
+                    negative: val < 0.0,
+                    mantissa,
+                    exponent
+                }
+            );
+            assert_eq!(real.to_f64(), val);
+        }
+
+        assert_eq!(Real::from_der(b"\x09\x03\x01-0").unwrap(), Real::MinusZero);
+
+        for bytes in [
+            &b"\x09\x02\x01-"[..],
+            b"\x09\x04\x011.5",
+            b"\x09\x04\x021.5E3",
+            b"\x09\x04\x031.5",
+            b"\x09\x05\x031.5E",
+            b"\x09\x06\x031.5.E3",
+        ] {
+            assert!(Real::from_der(bytes).is_err());
+        }
+    }
+
+    #[test]
+    fn real_decimal_encoding() {
+        use super::Real;
+
+        for (mantissa, exponent, expected) in [
+            (15, 2, &b"\x09\x06\x0315.E2"[..]),
+            (1500, 0, b"\x09\x06\x0315.E2"),
+            (1, 0, b"\x09\x06\x031.E+0"),
+            (101, 0, b"\x09\x08\x03101.E+0"),
+            (5, -1, b"\x09\x06\x035.E-1"),
+        ] {
+            let real = Real::Decimal {
+                negative: false,
+                mantissa,
+                exponent,
+            };
+
+            assert_eq!(real.to_der().unwrap(), expected);
+        }
+
+        let real = Real::Decimal {
+            negative: true,
+            mantissa: u64::MAX,
+            exponent: i32::MIN,
+        };
+        let encoded = real.to_der().unwrap();
+        assert_eq!(encoded.len(), 2 + 35);
+        assert_eq!(Real::from_der(&encoded).unwrap(), real);
+    }
+
+    #[test]
+    fn real_to_f64_rounding() {
+        use super::Real;
+
+        // 2^53 + 1 rounds to even
+        let real = Real::Binary {
+            negative: false,
+            mantissa: (1 << 53) + 1,
+            exponent: 0,
+        };
+        assert_eq!(real.to_f64(), 9007199254740992.0);
+
+        // Smallest subnormal
+        let real = Real::Binary {
+            negative: true,
+            mantissa: 1,
+            exponent: -1074,
+        };
+        assert_eq!(real.to_f64(), -f64::from_bits(1));
+        assert_eq!(Real::from(-f64::from_bits(1)), real);
+
+        // Rounds to zero
+        let real = Real::Binary {
+            negative: false,
+            mantissa: 1,
+            exponent: -1076,
+        };
+        assert_eq!(real.to_f64(), 0.0);
+
+        for val in [f64::MAX, f64::MIN, core::f64::consts::PI, 0.1, -951.2357864] {
+            assert_eq!(Real::from(val).to_f64(), val);
+            assert_eq!(
+                Real::from_der(&Real::from(val).to_der().unwrap())
+                    .unwrap()
+                    .to_f64(),
+                val
+            );
+        }
+    }
 }
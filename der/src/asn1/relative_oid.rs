@@ -0,0 +1,443 @@
+//! ASN.1 `RELATIVE-OID` support.
+
+use crate::{
+    ord::OrdIsValueOrd, DecodeValue, EncodeValue, Error, ErrorKind, FixedTag, Header, Length,
+    Reader, Result, Tag, Writer,
+};
+use const_oid::{Arc, ObjectIdentifier};
+use core::{fmt, str::FromStr};
+
+/// Maximum number of bytes in the BER encoding of a single [`Arc`].
+const ARC_MAX_BYTES: usize = 5;
+
+/// Continuation bit set on all but the final byte of an encoded [`Arc`].
+const CONTINUATION_FLAG: u8 = 0b1000_0000;
+
+/// ASN.1 `RELATIVE-OID` type.
+///
+/// Identifies an object relative to a known base [`ObjectIdentifier`], and
+/// is encoded as described in X.690 Section 8.20: each arc is serialized
+/// using the same base 128 encoding as the subsequent arcs of an
+/// `OBJECT IDENTIFIER`.
+///
+/// A [`RelativeOid`] contains at least one arc, and its encoding may be at
+/// most [`RelativeOid::MAX_SIZE`] bytes.
+///
+/// ```
+/// # fn main() -> der::Result<()> {
+/// use der::asn1::{ObjectIdentifier, RelativeOid};
+///
+/// let base = ObjectIdentifier::new_unwrap("1.3.6.1.4.1");
+/// let oid = ObjectIdentifier::new_unwrap("1.3.6.1.4.1.311.21.20");
+///
+/// let relative = RelativeOid::from_oid(oid, base)?;
+/// assert_eq!(relative.to_string(), "311.21.20");
+/// assert_eq!(relative.to_oid(base)?, oid);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Copy, Clone, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct RelativeOid {
+    /// BER/DER-serialized arcs (sans ASN.1 tag/length), zero-padded.
+    bytes: [u8; Self::MAX_SIZE],
+
+    /// Length of the serialized arcs in bytes.
+    length: u8,
+}
+
+impl RelativeOid {
+    /// Maximum size of a BER/DER-encoded [`RelativeOid`] in bytes.
+    pub const MAX_SIZE: usize = ObjectIdentifier::MAX_SIZE;
+
+    /// Parse a [`RelativeOid`] from the dot-delimited string form, e.g.
+    /// `311.21.20`.
+    pub fn new(s: &str) -> Result<Self> {
+        let arcs = s.split('.').map(|arc| {
+            if !arc.is_empty() && arc.bytes().all(|byte| byte.is_ascii_digit()) {
+                arc.parse::<Arc>().map_err(|_| Self::TAG.value_error())
+            } else {
+                Err(Self::TAG.value_error())
+            }
+        });
+
+        let mut oid = Self::empty();
+
+        for arc in arcs {
+            oid.push_arc(arc?)?;
+        }
+
+        Ok(oid)
+    }
+
+    /// Create a [`RelativeOid`] from the given [`Arc`] values.
+    pub fn from_arcs(arcs: impl IntoIterator<Item = Arc>) -> Result<Self> {
+        let mut oid = Self::empty();
+
+        for arc in arcs {
+            oid.push_arc(arc)?;
+        }
+
+        oid.check_not_empty()
+    }
+
+    /// Parse a [`RelativeOid`] from its BER/DER encoding.
+    pub fn from_bytes(ber_bytes: &[u8]) -> Result<Self> {
+        if ber_bytes.len() > Self::MAX_SIZE {
+            return Err(Self::TAG.length_error());
+        }
+
+        let mut remaining = ber_bytes;
+
+        while !remaining.is_empty() {
+            remaining = decode_arc(remaining)
+                .ok_or_else(|| Self::TAG.value_error())?
+                .1;
+        }
+
+        let mut bytes = [0u8; Self::MAX_SIZE];
+        bytes[..ber_bytes.len()].copy_from_slice(ber_bytes);
+
+        Self {
+            bytes,
+            length: ber_bytes.len().try_into()?,
+        }
+        .check_not_empty()
+    }
+
+    /// Compute the [`RelativeOid`] of `oid` relative to `base`, i.e. the arcs
+    /// of `oid` which follow those of `base`.
+    ///
+    /// Returns an error if `oid` does not start with `base`, or if it has no
+    /// arcs beyond those of `base`.
+    pub fn from_oid(oid: ObjectIdentifier, base: ObjectIdentifier) -> Result<Self> {
+        // Arcs are self-delimiting in the BER encoding, and the combined first
+        // two arcs of `base` are always present in full, so a prefix of the
+        // encoding corresponds to a prefix of the arcs.
+        oid.as_bytes()
+            .strip_prefix(base.as_bytes())
+            .ok_or_else(|| Self::TAG.value_error())
+            .and_then(Self::from_bytes)
+    }
+
+    /// Resolve this [`RelativeOid`] to an absolute [`ObjectIdentifier`] by
+    /// appending its arcs to the given `base`.
+    pub fn to_oid(&self, base: ObjectIdentifier) -> Result<ObjectIdentifier> {
+        self.arcs()
+            .try_fold(base, |oid, arc| oid.push_arc(arc))
+            .map_err(Error::from)
+    }
+
+    /// Get the BER/DER serialization of this [`RelativeOid`] as bytes.
+    ///
+    /// Note that this encoding omits the tag/length, and only contains the
+    /// value portion of the encoded `RELATIVE-OID`.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..usize::from(self.length)]
+    }
+
+    /// Return the arc with the given index, if it exists.
+    pub fn arc(&self, index: usize) -> Option<Arc> {
+        self.arcs().nth(index)
+    }
+
+    /// Iterate over the arcs of this [`RelativeOid`].
+    pub fn arcs(&self) -> RelativeOidArcs<'_> {
+        RelativeOidArcs {
+            remaining: self.as_bytes(),
+        }
+    }
+
+    /// Get the length of this [`RelativeOid`] in arcs.
+    ///
+    /// A [`RelativeOid`] always contains at least one arc.
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.arcs().count()
+    }
+
+    /// Create a [`RelativeOid`] with no arcs, which is only valid as an
+    /// intermediate value during construction.
+    fn empty() -> Self {
+        Self {
+            bytes: [0u8; Self::MAX_SIZE],
+            length: 0,
+        }
+    }
+
+    /// Ensure this [`RelativeOid`] contains at least one arc.
+    fn check_not_empty(self) -> Result<Self> {
+        if self.length == 0 {
+            Err(Self::TAG.length_error())
+        } else {
+            Ok(self)
+        }
+    }
+
+    /// Append the BER encoding of the given arc.
+    fn push_arc(&mut self, arc: Arc) -> Result<()> {
+        let mut buf = [0u8; ARC_MAX_BYTES];
+        let mut start = ARC_MAX_BYTES;
+        let mut value = arc;
+        let mut flag = 0;
+
+        // Encode base 128 digits from least to most significant
+        loop {
+            start = start.checked_sub(1).ok_or(ErrorKind::Overflow)?;
+            buf[start] = u8::try_from(value & 0x7F)? | flag;
+            flag = CONTINUATION_FLAG;
+            value >>= 7;
+
+            if value == 0 {
+                break;
+            }
+        }
+
+        let encoded = &buf[start..];
+        let offset = usize::from(self.length);
+        let end = offset
+            .checked_add(encoded.len())
+            .filter(|&end| end <= Self::MAX_SIZE)
+            .ok_or_else(|| Self::TAG.length_error())?;
+
+        self.bytes[offset..end].copy_from_slice(encoded);
+        self.length = end.try_into()?;
+        Ok(())
+    }
+}
+
+impl AsRef<[u8]> for RelativeOid {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl FromStr for RelativeOid {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::new(s)
+    }
+}
+
+impl TryFrom<&[u8]> for RelativeOid {
+    type Error = Error;
+
+    fn try_from(ber_bytes: &[u8]) -> Result<Self> {
+        Self::from_bytes(ber_bytes)
+    }
+}
+
+impl fmt::Display for RelativeOid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, arc) in self.arcs().enumerate() {
+            if i > 0 {
+                f.write_str(".")?;
+            }
+
+            write!(f, "{}", arc)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Debug for RelativeOid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "RelativeOid({})", self)
+    }
+}
+
+impl_any_conversions!(RelativeOid);
+
+impl<'a> DecodeValue<'a> for RelativeOid {
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
+        let mut buf = [0u8; Self::MAX_SIZE];
+        let slice = buf
+            .get_mut(..header.length.try_into()?)
+            .ok_or_else(|| Self::TAG.length_error())?;
+
+        Self::from_bytes(reader.read_into(slice)?)
+    }
+}
+
+impl EncodeValue for RelativeOid {
+    fn value_len(&self) -> Result<Length> {
+        Ok(Length::from(self.length))
+    }
+
+    fn encode_value(&self, writer: &mut impl Writer) -> Result<()> {
+        writer.write(self.as_bytes())
+    }
+}
+
+impl FixedTag for RelativeOid {
+    const TAG: Tag = Tag::RelativeOid;
+}
+
+impl OrdIsValueOrd for RelativeOid {}
+
+// Implement by hand because the derive would create invalid values.
+// Use the constructor to create a valid relative OID with at least 1 arc.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for RelativeOid {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut oid =
+            Self::from_arcs([u.arbitrary()?]).map_err(|_| arbitrary::Error::IncorrectFormat)?;
+
+        for arc in u.arbitrary_iter()? {
+            oid.push_arc(arc?)
+                .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+        }
+
+        Ok(oid)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        (Arc::size_hint(depth).0, None)
+    }
+}
+
+/// Iterator over the arcs of a [`RelativeOid`].
+#[derive(Clone, Debug)]
+pub struct RelativeOidArcs<'a> {
+    /// Remaining BER-encoded arcs.
+    remaining: &'a [u8],
+}
+
+impl Iterator for RelativeOidArcs<'_> {
+    type Item = Arc;
+
+    fn next(&mut self) -> Option<Arc> {
+        // Arcs are validated when a `RelativeOid` is constructed
+        let (arc, remaining) = decode_arc(self.remaining)?;
+        self.remaining = remaining;
+        Some(arc)
+    }
+}
+
+/// Decode the first BER-encoded arc in `bytes`, returning it along with the
+/// remaining bytes.
+///
+/// Returns `None` if `bytes` is empty or doesn't begin with a well-formed arc.
+fn decode_arc(bytes: &[u8]) -> Option<(Arc, &[u8])> {
+    // Arcs must be minimally encoded, i.e. not begin with a `0x80` byte
+    if bytes.first() == Some(&CONTINUATION_FLAG) {
+        return None;
+    }
+
+    let mut arc: Arc = 0;
+    let mut remaining = bytes;
+
+    for _ in 0..ARC_MAX_BYTES {
+        let (&byte, rest) = remaining.split_first()?;
+        arc = arc.checked_mul(0x80)? | Arc::from(byte & !CONTINUATION_FLAG);
+        remaining = rest;
+
+        if byte & CONTINUATION_FLAG == 0 {
+            return Some((arc, remaining));
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RelativeOid;
+    use crate::{asn1::ObjectIdentifier, Decode, Encode, ErrorKind, Tag};
+    use hex_literal::hex;
+
+    const BASE: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.6.1.4.1");
+    const OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.6.1.4.1.311.21.20");
+
+    /// DER encoding of `RELATIVE-OID` `311.21.20`.
+    const EXAMPLE_BYTES: &[u8] = &hex!("0D 04 82 37 15 14");
+
+    #[test]
+    fn decode() {
+        let rel = RelativeOid::from_der(EXAMPLE_BYTES).unwrap();
+        assert!(rel.arcs().eq([311, 21, 20]));
+        assert_eq!(rel.len(), 3);
+        assert_eq!(rel.arc(0), Some(311));
+        assert_eq!(rel.arc(3), None);
+    }
+
+    #[test]
+    fn encode() {
+        let rel = RelativeOid::new("311.21.20").unwrap();
+        let mut buf = [0u8; 6];
+        assert_eq!(rel.encode_to_slice(&mut buf).unwrap(), EXAMPLE_BYTES);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn parse_and_display() {
+        use alloc::string::ToString;
+
+        for s in ["0", "311.21.20", "4294967295.0.128"] {
+            assert_eq!(RelativeOid::new(s).unwrap().to_string(), s);
+        }
+
+        for s in ["", ".1", "1.", "1..2", "+1", "-1", "4294967296", "a.b"] {
+            assert!(RelativeOid::new(s).is_err(), "parsed {:?}", s);
+        }
+    }
+
+    #[test]
+    fn from_arcs() {
+        let rel = RelativeOid::from_arcs([311, 21, 20]).unwrap();
+        assert_eq!(rel.as_bytes(), &EXAMPLE_BYTES[2..]);
+
+        let rel = RelativeOid::from_arcs([u32::MAX]).unwrap();
+        assert_eq!(rel.as_bytes(), &hex!("8F FF FF FF 7F"));
+        assert_eq!(RelativeOid::from_bytes(rel.as_bytes()).unwrap(), rel);
+
+        let err = RelativeOid::from_arcs([]).err().unwrap();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::Length {
+                tag: Tag::RelativeOid
+            }
+        );
+
+        assert!(RelativeOid::from_arcs([u32::MAX; 8]).is_err());
+    }
+
+    #[test]
+    fn reject_malformed_bytes() {
+        for bytes in [
+            &[][..],
+            &hex!("80 01"),
+            &hex!("81"),
+            &hex!("01 81"),
+            &hex!("90 80 80 80 00"),
+            &hex!("FF FF FF FF FF 01"),
+        ] {
+            assert!(RelativeOid::from_bytes(bytes).is_err(), "{:02X?}", bytes);
+        }
+    }
+
+    #[test]
+    fn base_conversions() {
+        let rel = RelativeOid::from_oid(OID, BASE).unwrap();
+        assert_eq!(rel, RelativeOid::new("311.21.20").unwrap());
+        assert_eq!(rel.to_oid(BASE).unwrap(), OID);
+
+        // `oid` must extend `base`
+        assert!(RelativeOid::from_oid(BASE, BASE).is_err());
+        assert!(RelativeOid::from_oid(OID, ObjectIdentifier::new_unwrap("1.3.6.1.4.2")).is_err());
+
+        // Arcs are compared in full
+        let base = ObjectIdentifier::new_unwrap("1.3.6.1.4.1.3");
+        assert!(RelativeOid::from_oid(OID, base).is_err());
+    }
+
+    #[test]
+    fn to_oid_overflow() {
+        let rel = RelativeOid::from_arcs([u32::MAX; 7]).unwrap();
+        assert_eq!(
+            rel.to_oid(BASE).err().unwrap().kind(),
+            ErrorKind::OidMalformed
+        );
+    }
+}
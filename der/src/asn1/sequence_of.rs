@@ -71,7 +71,8 @@ where
             let mut sequence_of = Self::new();
 
             while !reader.is_finished() {
-                sequence_of.add(T::decode(reader)?)?;
+                let elem = T::decode(reader).map_err(|e| e.in_element(sequence_of.len()))?;
+                sequence_of.add(elem)?;
             }
 
             Ok(sequence_of)
@@ -101,6 +102,19 @@ impl<T, const N: usize> FixedTag for SequenceOf<T, N> {
     const TAG: Tag = Tag::Sequence;
 }
 
+impl<T, const N: usize> From<[T; N]> for SequenceOf<T, N> {
+    fn from(arr: [T; N]) -> SequenceOf<T, N> {
+        let mut result = SequenceOf::new();
+
+        for elem in arr {
+            // `arr` can't exceed the capacity of the backing array
+            let _ = result.add(elem);
+        }
+
+        result
+    }
+}
+
 impl<T, const N: usize> ValueOrd for SequenceOf<T, N>
 where
     T: DerOrd,
@@ -110,6 +124,30 @@ where
     }
 }
 
+// Implement by hand because the derive would create invalid values.
+// Use `add` to stay within the capacity of the backing array.
+#[cfg(feature = "arbitrary")]
+impl<'a, T, const N: usize> arbitrary::Arbitrary<'a> for SequenceOf<T, N>
+where
+    T: arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut sequence_of = Self::new();
+
+        for element in u.arbitrary_iter()? {
+            sequence_of
+                .add(element?)
+                .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+        }
+
+        Ok(sequence_of)
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (0, None)
+    }
+}
+
 /// Iterator over the elements of an [`SequenceOf`].
 #[derive(Clone, Debug)]
 pub struct SequenceOfIter<'a, T> {
@@ -187,7 +225,8 @@ where
             let mut sequence_of = Self::new();
 
             while !reader.is_finished() {
-                sequence_of.push(T::decode(reader)?);
+                let elem = T::decode(reader).map_err(|e| e.in_element(sequence_of.len()))?;
+                sequence_of.push(elem);
             }
 
             Ok(sequence_of)
@@ -9,6 +9,9 @@
 //!
 //! However, all types in this module sort elements of a set at decode-time,
 //! ensuring they'll be in the proper order if reserialized.
+//!
+//! Elements of a [`SetOfVec`] can also be collected in any order with a
+//! [`SetOfVecBuilder`], which sorts them once when the set is built.
 
 use crate::{
     arrayvec, ord::iter_cmp, ArrayVec, Decode, DecodeValue, DerOrd, Encode, EncodeValue, Error,
@@ -113,7 +116,8 @@ where
             let mut result = Self::new();
 
             while !reader.is_finished() {
-                result.inner.push(T::decode(reader)?)?;
+                let elem = T::decode(reader).map_err(|e| e.in_element(result.len()))?;
+                result.inner.push(elem)?;
             }
 
             der_sort(result.inner.as_mut())?;
@@ -123,9 +127,9 @@ where
     }
 }
 
-impl<'a, T, const N: usize> EncodeValue for SetOf<T, N>
+impl<T, const N: usize> EncodeValue for SetOf<T, N>
 where
-    T: 'a + Decode<'a> + Encode + DerOrd,
+    T: Encode + DerOrd,
 {
     fn value_len(&self) -> Result<Length> {
         self.iter()
@@ -141,9 +145,9 @@ where
     }
 }
 
-impl<'a, T, const N: usize> FixedTag for SetOf<T, N>
+impl<T, const N: usize> FixedTag for SetOf<T, N>
 where
-    T: Decode<'a> + DerOrd,
+    T: DerOrd,
 {
     const TAG: Tag = Tag::Set;
 }
@@ -176,6 +180,30 @@ where
     }
 }
 
+// Implement by hand because the derive would create invalid values.
+// Use `insert` to keep the elements sorted and free of duplicates.
+#[cfg(feature = "arbitrary")]
+impl<'a, T, const N: usize> arbitrary::Arbitrary<'a> for SetOf<T, N>
+where
+    T: DerOrd + arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut set_of = Self::new();
+
+        for item in u.arbitrary_iter()? {
+            set_of
+                .insert(item?)
+                .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+        }
+
+        Ok(set_of)
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (0, None)
+    }
+}
+
 /// Iterator over the elements of an [`SetOf`].
 #[derive(Clone, Debug)]
 pub struct SetOfIter<'a, T> {
@@ -280,6 +308,67 @@ where
         Ok(())
     }
 
+    /// Search this [`SetOfVec`] for the given value using its [`DerOrd`]
+    /// impl.
+    ///
+    /// Like [`slice::binary_search`], returns `Ok(Ok(index))` with the index
+    /// of the value if it is present, or `Ok(Err(index))` with the index at
+    /// which it could be inserted while preserving DER order otherwise.
+    pub fn binary_search_by_value(&self, value: &T) -> Result<core::result::Result<usize, usize>> {
+        binary_search(&self.inner, value)
+    }
+
+    /// Get the first element of this [`SetOfVec`] for which the given
+    /// predicate returns `true`.
+    ///
+    /// This is useful for finding elements by a key which doesn't determine
+    /// their DER order, e.g. attributes by their OID.
+    pub fn get_by<F>(&self, f: F) -> Option<&T>
+    where
+        F: FnMut(&&T) -> bool,
+    {
+        self.inner.iter().find(f)
+    }
+
+    /// Remove the first element of this [`SetOfVec`] for which the given
+    /// predicate returns `true`, returning it.
+    pub fn remove_by<F>(&mut self, f: F) -> Option<T>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let index = self.inner.iter().position(f)?;
+        Some(self.inner.remove(index))
+    }
+
+    /// Insert an item into this [`SetOfVec`] at the position prescribed by
+    /// DER order, replacing the first existing element for which the given
+    /// predicate returns `true`.
+    ///
+    /// Returns the replaced element, if any. If the new item is a duplicate
+    /// of another element, [`ErrorKind::SetDuplicate`] is returned and the set
+    /// is left unchanged.
+    pub fn insert_or_replace<F>(&mut self, item: T, f: F) -> Result<Option<T>>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let index = self.inner.iter().position(f);
+        let replaced = index.map(|index| self.inner.remove(index));
+
+        match self.binary_search_by_value(&item) {
+            Ok(Err(pos)) => {
+                self.inner.insert(pos, item);
+                Ok(replaced)
+            }
+            result => {
+                if let (Some(index), Some(replaced)) = (index, replaced) {
+                    self.inner.insert(index, replaced);
+                }
+
+                result.and(Err(ErrorKind::SetDuplicate.into()))
+            }
+        }
+    }
+
     /// Borrow the elements of this [`SetOfVec`] as a slice.
     pub fn as_slice(&self) -> &[T] {
         self.inner.as_slice()
@@ -331,7 +420,8 @@ where
             let mut inner = Vec::new();
 
             while !reader.is_finished() {
-                inner.push(T::decode(reader)?);
+                let elem = T::decode(reader).map_err(|e| e.in_element(inner.len()))?;
+                inner.push(elem);
             }
 
             der_sort(inner.as_mut())?;
@@ -342,9 +432,9 @@ where
 }
 
 #[cfg(feature = "alloc")]
-impl<'a, T> EncodeValue for SetOfVec<T>
+impl<T> EncodeValue for SetOfVec<T>
 where
-    T: 'a + Decode<'a> + Encode + DerOrd,
+    T: Encode + DerOrd,
 {
     fn value_len(&self) -> Result<Length> {
         self.iter()
@@ -433,6 +523,110 @@ where
     }
 }
 
+/// Builder for a [`SetOfVec`] which accepts elements in any order.
+///
+/// Elements are only sorted in DER order once, when the set is built, which
+/// avoids re-sorting the set every time an item is inserted.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SetOfVecBuilder<T>
+where
+    T: DerOrd,
+{
+    inner: Vec<T>,
+}
+
+#[cfg(feature = "alloc")]
+impl<T: DerOrd> Default for SetOfVecBuilder<T> {
+    fn default() -> Self {
+        Self {
+            inner: Default::default(),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> SetOfVecBuilder<T>
+where
+    T: DerOrd,
+{
+    /// Create a new [`SetOfVecBuilder`].
+    pub fn new() -> Self {
+        Self {
+            inner: Vec::default(),
+        }
+    }
+
+    /// Add an item to this [`SetOfVecBuilder`], in any order.
+    pub fn insert(&mut self, item: T) {
+        self.inner.push(item);
+    }
+
+    /// Is this [`SetOfVecBuilder`] empty?
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Number of items added to this [`SetOfVecBuilder`].
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Sort the items in DER order according to the [`DerOrd`] impl on `T`
+    /// and build the [`SetOfVec`].
+    ///
+    /// Returns [`ErrorKind::SetDuplicate`] if any item was added twice.
+    pub fn build(self) -> Result<SetOfVec<T>> {
+        self.inner.try_into()
+    }
+
+    /// Sort the items in DER order and build the [`SetOfVec`], removing any
+    /// duplicates instead of returning an error.
+    pub fn build_dedup(self) -> Result<SetOfVec<T>> {
+        let mut inner = Vec::with_capacity(self.inner.len());
+
+        for item in self.inner {
+            if let Err(pos) = binary_search(&inner, &item)? {
+                inner.insert(pos, item);
+            }
+        }
+
+        Ok(SetOfVec { inner })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Extend<T> for SetOfVecBuilder<T>
+where
+    T: DerOrd,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.inner.extend(iter);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> FromIterator<T> for SetOfVecBuilder<T>
+where
+    T: DerOrd,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self {
+            inner: Vec::from_iter(iter),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> From<SetOfVec<T>> for SetOfVecBuilder<T>
+where
+    T: DerOrd,
+{
+    fn from(set: SetOfVec<T>) -> Self {
+        Self { inner: set.inner }
+    }
+}
+
 /// Ensure set elements are lexicographically ordered using [`DerOrd`].
 fn check_der_ordering<T: DerOrd>(a: &T, b: &T) -> Result<()> {
     match a.der_cmp(b)? {
@@ -471,6 +665,24 @@ fn der_sort<T: DerOrd>(slice: &mut [T]) -> Result<()> {
     Ok(())
 }
 
+/// Binary search a DER-sorted slice for the given value, returning any errors
+/// which might occur during the comparison.
+#[cfg(feature = "alloc")]
+fn binary_search<T: DerOrd>(slice: &[T], value: &T) -> Result<core::result::Result<usize, usize>> {
+    let mut error = None;
+    let result = slice.binary_search_by(|elem| {
+        elem.der_cmp(value).unwrap_or_else(|e| {
+            error.get_or_insert(e);
+            Ordering::Equal
+        })
+    });
+
+    match error {
+        Some(e) => Err(e),
+        None => Ok(result),
+    }
+}
+
 /// Validate the elements of a `SET OF`, ensuring that they are all in order
 /// and that there are no duplicates.
 fn validate<T: DerOrd>(slice: &[T]) -> Result<()> {
@@ -496,8 +708,13 @@ fn validate<T: DerOrd>(slice: &[T]) -> Result<()> {
 mod tests {
     use super::SetOf;
     #[cfg(feature = "alloc")]
-    use super::SetOfVec;
+    use super::{SetOfVec, SetOfVecBuilder};
     use crate::ErrorKind;
+    #[cfg(feature = "alloc")]
+    use {
+        crate::{DerOrd, Result},
+        core::cmp::Ordering,
+    };
 
     #[test]
     fn setof_tryfrom_array() {
@@ -536,4 +753,104 @@ mod tests {
         let err = SetOfVec::try_from(vec).err().unwrap();
         assert_eq!(err.kind(), ErrorKind::SetDuplicate);
     }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn setofvec_builder() {
+        use crate::Encode;
+        use hex_literal::hex;
+
+        let mut builder = SetOfVecBuilder::new();
+        builder.insert(3u16);
+        builder.insert(1);
+        builder.insert(2);
+        assert_eq!(builder.len(), 3);
+
+        let set = builder.build().unwrap();
+        assert_eq!(set.as_ref(), &[1, 2, 3]);
+        assert_eq!(set.to_der().unwrap(), hex!("31 09 020101 020102 020103"));
+        assert_eq!(set, SetOfVec::try_from([1, 2, 3]).unwrap());
+        assert_eq!(set.binary_search_by_value(&2).unwrap(), Ok(1));
+
+        // Continue adding to an existing set
+        let mut builder = SetOfVecBuilder::from(set);
+        builder.extend([5, 4]);
+        assert_eq!(builder.build().unwrap().as_ref(), &[1, 2, 3, 4, 5]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn setofvec_builder_reject_duplicates() {
+        let builder = SetOfVecBuilder::from_iter([2u16, 1, 2]);
+        let err = builder.build().err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::SetDuplicate);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn setofvec_builder_dedup() {
+        let builder = SetOfVecBuilder::from_iter([2u16, 1, 2, 3, 1]);
+        let set = builder.build_dedup().unwrap();
+        assert_eq!(set.as_ref(), &[1, 2, 3]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn setofvec_binary_search_by_value() {
+        let set = SetOfVec::try_from([1u16, 3, 5]).unwrap();
+        assert_eq!(set.binary_search_by_value(&3).unwrap(), Ok(1));
+        assert_eq!(set.binary_search_by_value(&0).unwrap(), Err(0));
+        assert_eq!(set.binary_search_by_value(&4).unwrap(), Err(2));
+        assert_eq!(set.binary_search_by_value(&6).unwrap(), Err(3));
+    }
+
+    /// Key-value pair for testing lookups by key.
+    #[cfg(feature = "alloc")]
+    #[derive(Debug, Eq, PartialEq)]
+    struct Entry(u8, u16);
+
+    #[cfg(feature = "alloc")]
+    impl DerOrd for Entry {
+        fn der_cmp(&self, other: &Self) -> Result<Ordering> {
+            Ok((self.0, self.1).cmp(&(other.0, other.1)))
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn setofvec_get_and_remove_by() {
+        let mut set = SetOfVec::try_from([Entry(1, 10), Entry(2, 20), Entry(3, 30)]).unwrap();
+        assert_eq!(set.get_by(|Entry(key, _)| *key == 2), Some(&Entry(2, 20)));
+        assert_eq!(set.get_by(|Entry(key, _)| *key == 4), None);
+
+        assert_eq!(set.remove_by(|Entry(key, _)| *key == 2), Some(Entry(2, 20)));
+        assert_eq!(set.remove_by(|Entry(key, _)| *key == 2), None);
+        assert_eq!(set.as_ref(), &[Entry(1, 10), Entry(3, 30)]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn setofvec_insert_or_replace() {
+        let mut set = SetOfVec::try_from([Entry(1, 10), Entry(3, 30)]).unwrap();
+
+        // Insert in DER order if nothing matches
+        assert_eq!(
+            set.insert_or_replace(Entry(2, 20), |Entry(key, _)| *key == 2)
+                .unwrap(),
+            None
+        );
+        assert_eq!(set.as_ref(), &[Entry(1, 10), Entry(2, 20), Entry(3, 30)]);
+
+        // Replace the matching element, which may change its position
+        let replaced = set.insert_or_replace(Entry(4, 10), |Entry(_, value)| *value == 10);
+        assert_eq!(replaced.unwrap(), Some(Entry(1, 10)));
+        assert_eq!(set.as_ref(), &[Entry(2, 20), Entry(3, 30), Entry(4, 10)]);
+
+        // Duplicates leave the set unchanged
+        let err = set
+            .insert_or_replace(Entry(3, 30), |Entry(key, _)| *key == 2)
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::SetDuplicate);
+        assert_eq!(set.as_ref(), &[Entry(2, 20), Entry(3, 30), Entry(4, 10)]);
+    }
 }
@@ -1,30 +1,11 @@
 //! ASN.1 `TeletexString` support.
 //!
-use crate::{asn1::AnyRef, FixedTag, Result, StrRef, Tag};
-use core::{fmt, ops::Deref};
-
-macro_rules! impl_teletex_string {
-    ($type: ty) => {
-        impl_teletex_string!($type,);
-    };
-    ($type: ty, $($li: lifetime)?) => {
-        impl_string_type!($type, $($li),*);
-
-        impl<$($li),*> FixedTag for $type {
-            const TAG: Tag = Tag::TeletexString;
-        }
-
-        impl<$($li),*> fmt::Debug for $type {
-            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                write!(f, "TeletexString({:?})", self.as_str())
-            }
-        }
-    };
-}
+use crate::{StrRef, Tag};
 
 /// ASN.1 `TeletexString` type.
 ///
-/// Supports a subset the ASCII character set (described below).
+/// Supports the subset of the T.61 character set which is valid UTF-8
+/// (described below).
 ///
 /// For UTF-8, use [`Utf8StringRef`][`crate::asn1::Utf8StringRef`] instead.
 /// For the full ASCII character set, use
@@ -38,52 +19,16 @@ macro_rules! impl_teletex_string {
 /// mailing list, "a sizable volume of software in the world treats TeletexString (T61String) as a
 /// simple 8-bit string with mostly Windows Latin 1 (superset of iso-8859-1) encoding".
 ///
+/// This type accepts any contents which are valid UTF-8, which includes ASCII as well as strings
+/// produced by encoders which (incorrectly) use UTF-8. Use [`TeletexString`] to also decode
+/// contents which are only valid as ISO 8859-1.
 #[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Ord)]
 pub struct TeletexStringRef<'a> {
     /// Inner value
     inner: StrRef<'a>,
 }
 
-impl<'a> TeletexStringRef<'a> {
-    /// Create a new ASN.1 `TeletexString`.
-    pub fn new<T>(input: &'a T) -> Result<Self>
-    where
-        T: AsRef<[u8]> + ?Sized,
-    {
-        let input = input.as_ref();
-
-        // FIXME: support higher part of the charset
-        if input.iter().any(|&c| c > 0x7F) {
-            return Err(Self::TAG.value_error());
-        }
-
-        StrRef::from_bytes(input)
-            .map(|inner| Self { inner })
-            .map_err(|_| Self::TAG.value_error())
-    }
-}
-
-impl_teletex_string!(TeletexStringRef<'a>, 'a);
-
-impl<'a> Deref for TeletexStringRef<'a> {
-    type Target = StrRef<'a>;
-
-    fn deref(&self) -> &Self::Target {
-        &self.inner
-    }
-}
-
-impl<'a> From<&TeletexStringRef<'a>> for TeletexStringRef<'a> {
-    fn from(value: &TeletexStringRef<'a>) -> TeletexStringRef<'a> {
-        *value
-    }
-}
-
-impl<'a> From<TeletexStringRef<'a>> for AnyRef<'a> {
-    fn from(teletex_string: TeletexStringRef<'a>) -> AnyRef<'a> {
-        AnyRef::from_tag_and_value(Tag::TeletexString, teletex_string.inner.into())
-    }
-}
+impl_legacy_string_ref!(TeletexStringRef, Tag::TeletexString, "TeletexString");
 
 #[cfg(feature = "alloc")]
 pub use self::allocation::TeletexString;
@@ -91,107 +36,40 @@ pub use self::allocation::TeletexString;
 #[cfg(feature = "alloc")]
 mod allocation {
     use super::TeletexStringRef;
-
-    use crate::{
-        asn1::AnyRef,
-        referenced::{OwnedToRef, RefToOwned},
-        BytesRef, Error, FixedTag, Result, StrOwned, Tag,
-    };
-    use alloc::string::String;
-    use core::{fmt, ops::Deref};
+    use crate::{BytesOwned, StrOwned, Tag};
 
     /// ASN.1 `TeletexString` type.
     ///
-    /// Supports a subset the ASCII character set (described below).
+    /// Owned counterpart of [`TeletexStringRef`] which additionally supports
+    /// contents which are not valid UTF-8 (described below).
     ///
-    /// For UTF-8, use [`Utf8StringRef`][`crate::asn1::Utf8StringRef`] instead.
-    /// For the full ASCII character set, use
-    /// [`Ia5StringRef`][`crate::asn1::Ia5StringRef`].
+    /// For UTF-8, use [`String`][`alloc::string::String`] instead.
     ///
     /// # Supported characters
     ///
-    /// The standard defines a complex character set allowed in this type. However, quoting the ASN.1
-    /// mailing list, "a sizable volume of software in the world treats TeletexString (T61String) as a
-    /// simple 8-bit string with mostly Windows Latin 1 (superset of iso-8859-1) encoding".
+    /// The T.61 character set isn't implemented: in practice, TeletexString (T61String) values are
+    /// treated as simple 8-bit strings, mostly in Windows Latin 1 (superset of iso-8859-1).
     ///
+    /// Contents which are valid UTF-8 are used as-is. Any other contents are decoded as
+    /// ISO 8859-1, which maps every byte to a character and can therefore never fail. This is
+    /// lossy with respect to T.61 (and Windows-1252) specific characters, however the original
+    /// bytes are retained and re-encoded exactly, so e.g. signatures over names containing such
+    /// values remain valid.
     #[derive(Clone, Eq, PartialEq, PartialOrd, Ord)]
     pub struct TeletexString {
         /// Inner value
         inner: StrOwned,
-    }
 
-    impl TeletexString {
-        /// Create a new ASN.1 `TeletexString`.
-        pub fn new<T>(input: &T) -> Result<Self>
-        where
-            T: AsRef<[u8]> + ?Sized,
-        {
-            let input = input.as_ref();
-
-            TeletexStringRef::new(input)?;
-
-            StrOwned::from_bytes(input)
-                .map(|inner| Self { inner })
-                .map_err(|_| Self::TAG.value_error())
-        }
+        /// Original encoding, if the value was decoded from ISO 8859-1.
+        latin1: Option<BytesOwned>,
     }
 
-    impl_teletex_string!(TeletexString);
-
-    impl Deref for TeletexString {
-        type Target = StrOwned;
-
-        fn deref(&self) -> &Self::Target {
-            &self.inner
-        }
-    }
-
-    impl<'a> From<TeletexStringRef<'a>> for TeletexString {
-        fn from(value: TeletexStringRef<'a>) -> TeletexString {
-            let inner =
-                StrOwned::from_bytes(value.inner.as_bytes()).expect("Invalid TeletexString");
-            Self { inner }
-        }
-    }
-
-    impl<'a> From<&'a TeletexString> for AnyRef<'a> {
-        fn from(teletex_string: &'a TeletexString) -> AnyRef<'a> {
-            AnyRef::from_tag_and_value(
-                Tag::TeletexString,
-                BytesRef::new(teletex_string.inner.as_bytes()).expect("Invalid TeletexString"),
-            )
-        }
-    }
-
-    impl<'a> RefToOwned<'a> for TeletexStringRef<'a> {
-        type Owned = TeletexString;
-        fn ref_to_owned(&self) -> Self::Owned {
-            TeletexString {
-                inner: self.inner.ref_to_owned(),
-            }
-        }
-    }
-
-    impl OwnedToRef for TeletexString {
-        type Borrowed<'a> = TeletexStringRef<'a>;
-        fn owned_to_ref(&self) -> Self::Borrowed<'_> {
-            TeletexStringRef {
-                inner: self.inner.owned_to_ref(),
-            }
-        }
-    }
-
-    impl TryFrom<String> for TeletexString {
-        type Error = Error;
-
-        fn try_from(input: String) -> Result<Self> {
-            TeletexStringRef::new(&input)?;
-
-            StrOwned::new(input)
-                .map(|inner| Self { inner })
-                .map_err(|_| Self::TAG.value_error())
-        }
-    }
+    impl_legacy_string_owned!(
+        TeletexString,
+        TeletexStringRef,
+        Tag::TeletexString,
+        "TeletexString"
+    );
 }
 
 #[cfg(test)]
@@ -199,6 +77,7 @@ mod tests {
     use super::TeletexStringRef;
     use crate::Decode;
     use crate::SliceWriter;
+    use hex_literal::hex;
 
     #[test]
     fn parse_bytes() {
@@ -214,4 +93,39 @@ mod tests {
         let encoded = writer.finish().unwrap();
         assert_eq!(encoded, example_bytes);
     }
+
+    #[test]
+    fn parse_utf8() {
+        let example_bytes = hex!("14 06 4D C3 BC 6C 6C 65");
+        let teletex_string = TeletexStringRef::from_der(&example_bytes).unwrap();
+        assert_eq!(teletex_string.as_str(), "Mülle");
+    }
+
+    #[test]
+    fn reject_latin1() {
+        let example_bytes = hex!("14 05 4D FC 6C 6C 65");
+        assert!(TeletexStringRef::from_der(&example_bytes).is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn decode_latin1() {
+        use super::TeletexString;
+        use crate::Encode;
+
+        // "Mülle" encoded as ISO 8859-1
+        let example_bytes = hex!("14 05 4D FC 6C 6C 65");
+        let teletex_string = TeletexString::from_der(&example_bytes).unwrap();
+        assert_eq!(teletex_string.as_str(), "Mülle");
+        assert!(teletex_string.is_latin1());
+        assert_eq!(teletex_string.as_encoded_bytes(), &example_bytes[2..]);
+        assert_eq!(teletex_string.to_der().unwrap(), example_bytes);
+
+        // UTF-8 contents are used as-is
+        let example_bytes = hex!("14 06 4D C3 BC 6C 6C 65");
+        let teletex_string = TeletexString::from_der(&example_bytes).unwrap();
+        assert_eq!(teletex_string.as_str(), "Mülle");
+        assert!(!teletex_string.is_latin1());
+        assert_eq!(teletex_string.to_der().unwrap(), example_bytes);
+    }
 }
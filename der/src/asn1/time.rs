@@ -0,0 +1,395 @@
+//! ASN.1 `TIME` support.
+
+use crate::{
+    asn1::Duration,
+    datetime::{self, DateTime},
+    ord::OrdIsValueOrd,
+    DecodeValue, EncodeValue, Error, FixedTag, Header, Length, Reader, Result, Tag, Writer,
+};
+use core::{fmt, str, str::FromStr};
+
+/// ASN.1 `TIME` type.
+///
+/// Introduced in X.680:2008, `TIME` values are ISO 8601 strings which may
+/// denote a date, a time of day, a date-time, a duration, an interval or a
+/// recurrence. They are encoded as the characters of the string, as described
+/// in X.690 Section 8.26.
+///
+/// The string is stored as-is, and may be at most [`Time::MAX_SIZE`] bytes.
+/// Values which are date-times with a time zone can be converted to a
+/// [`DateTime`] using [`Time::to_date_time`], and values which are durations
+/// to a [`Duration`] using [`Time::to_duration`].
+///
+/// ```
+/// # fn main() -> der::Result<()> {
+/// use der::{asn1::Time, DateTime};
+///
+/// let time = Time::new("2023-05-08T12:30:00.5+02:00")?;
+/// let expected = DateTime::new(2023, 5, 8, 10, 30, 0)?.with_nanoseconds(500_000_000)?;
+/// assert_eq!(time.to_date_time()?, expected);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Copy, Clone, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct Time {
+    /// ISO 8601 string, zero-padded.
+    bytes: [u8; Self::MAX_SIZE],
+
+    /// Length of the string in bytes.
+    length: u8,
+}
+
+impl Time {
+    /// Maximum size of an ISO 8601 string supported by [`Time`] in bytes.
+    pub const MAX_SIZE: usize = 64;
+
+    /// Create a new [`Time`] from an ISO 8601 string.
+    ///
+    /// Only the characters used by ISO 8601 are checked, i.e. ASCII digits
+    /// and uppercase letters, as well as `+`, `-`, `:`, `.`, `,` and `/`.
+    pub fn new<T>(input: &T) -> Result<Self>
+    where
+        T: AsRef<[u8]> + ?Sized,
+    {
+        let input = input.as_ref();
+
+        if input.is_empty() || input.len() > Self::MAX_SIZE {
+            return Err(Self::TAG.length_error());
+        }
+
+        for &c in input {
+            match c {
+                b'0'..=b'9' | b'A'..=b'Z' | b'+' | b'-' | b':' | b'.' | b',' | b'/' => (),
+                _ => return Err(Self::TAG.value_error()),
+            }
+        }
+
+        let mut bytes = [0u8; Self::MAX_SIZE];
+        bytes[..input.len()].copy_from_slice(input);
+
+        Ok(Self {
+            bytes,
+            length: input.len().try_into()?,
+        })
+    }
+
+    /// Create a [`Time`] from a [`DateTime`], using the ISO 8601 extended
+    /// format, e.g. `2023-05-08T10:30:00.5Z`.
+    pub fn from_date_time(datetime: DateTime) -> Result<Self> {
+        let mut buf = [0u8; Self::MAX_SIZE];
+        let mut writer = crate::SliceWriter::new(&mut buf);
+        encode_date_time(&mut writer, &datetime)?;
+        Self::new(writer.finish()?)
+    }
+
+    /// Get this [`Time`] as a string.
+    pub fn as_str(&self) -> &str {
+        // Only ASCII characters are accepted by `Time::new`
+        str::from_utf8(self.as_bytes()).unwrap_or_default()
+    }
+
+    /// Get this [`Time`] as bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..usize::from(self.length)]
+    }
+
+    /// Convert this [`Time`] into a [`DateTime`].
+    ///
+    /// The value must be a calendar date-time with seconds and a time zone,
+    /// in either the basic (`YYYYMMDDThhmmss`) or extended
+    /// (`YYYY-MM-DDThh:mm:ss`) format, optionally followed by fractional
+    /// seconds. Time zone offsets other than `Z` are normalized to UTC.
+    pub fn to_date_time(&self) -> Result<DateTime> {
+        decode_date_time(self.as_bytes()).ok_or_else(|| Self::TAG.value_error())
+    }
+
+    /// Convert this [`Time`] into a [`Duration`], if it is one.
+    pub fn to_duration(&self) -> Result<Duration> {
+        Duration::new(self.as_bytes()).map_err(|_| Self::TAG.value_error())
+    }
+}
+
+impl AsRef<[u8]> for Time {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl AsRef<str> for Time {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl FromStr for Time {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::new(s)
+    }
+}
+
+impl TryFrom<DateTime> for Time {
+    type Error = Error;
+
+    fn try_from(datetime: DateTime) -> Result<Self> {
+        Self::from_date_time(datetime)
+    }
+}
+
+impl TryFrom<Time> for DateTime {
+    type Error = Error;
+
+    fn try_from(time: Time) -> Result<DateTime> {
+        time.to_date_time()
+    }
+}
+
+impl From<Duration> for Time {
+    fn from(duration: Duration) -> Time {
+        Self::new(duration.as_bytes()).expect("Invalid TIME")
+    }
+}
+
+impl fmt::Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl fmt::Debug for Time {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Time({:?})", self.as_str())
+    }
+}
+
+impl_any_conversions!(Time);
+
+impl<'a> DecodeValue<'a> for Time {
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
+        let mut buf = [0u8; Self::MAX_SIZE];
+        let slice = buf
+            .get_mut(..header.length.try_into()?)
+            .ok_or_else(|| Self::TAG.length_error())?;
+
+        Self::new(reader.read_into(slice)?)
+    }
+}
+
+impl EncodeValue for Time {
+    fn value_len(&self) -> Result<Length> {
+        Ok(Length::from(self.length))
+    }
+
+    fn encode_value(&self, writer: &mut impl Writer) -> Result<()> {
+        writer.write(self.as_bytes())
+    }
+}
+
+impl FixedTag for Time {
+    const TAG: Tag = Tag::Time;
+}
+
+impl OrdIsValueOrd for Time {}
+
+// Implement by hand because the derive would create invalid values.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Time {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Self::from_date_time(u.arbitrary()?).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        DateTime::size_hint(depth)
+    }
+}
+
+/// Encode a [`DateTime`] in the ISO 8601 extended format.
+fn encode_date_time(writer: &mut impl Writer, datetime: &DateTime) -> Result<()> {
+    const TAG: Tag = Tag::Time;
+
+    let year_hi = u8::try_from(datetime.year() / 100)?;
+    let year_lo = u8::try_from(datetime.year() % 100)?;
+
+    datetime::encode_decimal(writer, TAG, year_hi)?;
+    datetime::encode_decimal(writer, TAG, year_lo)?;
+    writer.write_byte(b'-')?;
+    datetime::encode_decimal(writer, TAG, datetime.month())?;
+    writer.write_byte(b'-')?;
+    datetime::encode_decimal(writer, TAG, datetime.day())?;
+    writer.write_byte(b'T')?;
+    datetime::encode_decimal(writer, TAG, datetime.hour())?;
+    writer.write_byte(b':')?;
+    datetime::encode_decimal(writer, TAG, datetime.minutes())?;
+    writer.write_byte(b':')?;
+    datetime::encode_decimal(writer, TAG, datetime.seconds())?;
+    datetime::encode_nanoseconds(writer, TAG, datetime.nanoseconds())?;
+    writer.write_byte(b'Z')
+}
+
+/// Decode an ISO 8601 date-time in the basic or extended format.
+fn decode_date_time(bytes: &[u8]) -> Option<DateTime> {
+    const TAG: Tag = Tag::Time;
+
+    // The basic and extended formats can't be mixed
+    let (date, rest, extended) = match *bytes {
+        [y1, y2, y3, y4, b'-', m1, m2, b'-', d1, d2, b'T', ref rest @ ..] => {
+            ([y1, y2, y3, y4, m1, m2, d1, d2], rest, true)
+        }
+        [y1, y2, y3, y4, m1, m2, d1, d2, b'T', ref rest @ ..] => {
+            ([y1, y2, y3, y4, m1, m2, d1, d2], rest, false)
+        }
+        _ => return None,
+    };
+
+    let (time, rest) = match (rest, extended) {
+        ([h1, h2, b':', n1, n2, b':', s1, s2, rest @ ..], true)
+        | ([h1, h2, n1, n2, s1, s2, rest @ ..], false) => ([*h1, *h2, *n1, *n2, *s1, *s2], rest),
+        _ => return None,
+    };
+
+    // Fractional seconds may use either `.` or `,` as the decimal mark
+    let (nanoseconds, zone) = match rest {
+        [b'.' | b',', fraction @ ..] => {
+            let len = fraction
+                .iter()
+                .position(|c| !c.is_ascii_digit())
+                .unwrap_or(fraction.len());
+            let (digits, zone) = fraction.split_at(len);
+            (datetime::decode_nanoseconds(TAG, digits).ok()?, zone)
+        }
+        zone => (0, zone),
+    };
+
+    let offset_minutes = match *zone {
+        [b'Z'] => 0,
+        [sign @ (b'+' | b'-'), h1, h2] => decode_offset(sign, [h1, h2], [b'0', b'0'])?,
+        [sign @ (b'+' | b'-'), h1, h2, b':', m1, m2] | [sign @ (b'+' | b'-'), h1, h2, m1, m2] => {
+            decode_offset(sign, [h1, h2], [m1, m2])?
+        }
+        _ => return None,
+    };
+
+    let [y1, y2, y3, y4, m1, m2, d1, d2] = date;
+    let [h1, h2, n1, n2, s1, s2] = time;
+    let year = u16::from(datetime::decode_decimal(TAG, y1, y2).ok()?)
+        .checked_mul(100)?
+        .checked_add(datetime::decode_decimal(TAG, y3, y4).ok()?.into())?;
+
+    let local = DateTime::new(
+        year,
+        datetime::decode_decimal(TAG, m1, m2).ok()?,
+        datetime::decode_decimal(TAG, d1, d2).ok()?,
+        datetime::decode_decimal(TAG, h1, h2).ok()?,
+        datetime::decode_decimal(TAG, n1, n2).ok()?,
+        datetime::decode_decimal(TAG, s1, s2).ok()?,
+    )
+    .and_then(|dt| dt.with_nanoseconds(nanoseconds))
+    .ok()?;
+
+    if offset_minutes == 0 {
+        return Some(local);
+    }
+
+    let offset = core::time::Duration::from_secs(offset_minutes.unsigned_abs().checked_mul(60)?);
+    let unix_duration = if offset_minutes > 0 {
+        local.unix_duration().checked_sub(offset)?
+    } else {
+        local.unix_duration().checked_add(offset)?
+    };
+
+    DateTime::from_unix_duration(unix_duration).ok()
+}
+
+/// Decode a time zone offset into a signed number of minutes.
+fn decode_offset(sign: u8, hours: [u8; 2], minutes: [u8; 2]) -> Option<i64> {
+    let hours = datetime::decode_decimal(Tag::Time, hours[0], hours[1]).ok()?;
+    let minutes = datetime::decode_decimal(Tag::Time, minutes[0], minutes[1]).ok()?;
+
+    if hours > 23 || minutes > 59 {
+        return None;
+    }
+
+    let offset = i64::from(hours)
+        .checked_mul(60)?
+        .checked_add(i64::from(minutes))?;
+
+    match sign {
+        b'-' => offset.checked_neg(),
+        _ => Some(offset),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Time;
+    use crate::{asn1::Duration, DateTime, Decode, Encode, ErrorKind, Tag};
+    use hex_literal::hex;
+
+    #[test]
+    fn round_trip() {
+        // TIME "2023-05-08T10:30:00Z"
+        let example_bytes =
+            hex!("0e 14 32 30 32 33 2d 30 35 2d 30 38 54 31 30 3a 33 30 3a 30 30 5a");
+        let time = Time::from_der(&example_bytes).unwrap();
+        assert_eq!(time.as_str(), "2023-05-08T10:30:00Z");
+        let mut buf = [0u8; 32];
+        assert_eq!(time.encode_to_slice(&mut buf).unwrap(), example_bytes);
+
+        let datetime = DateTime::new(2023, 5, 8, 10, 30, 0).unwrap();
+        assert_eq!(time.to_date_time().unwrap(), datetime);
+        assert_eq!(Time::from_date_time(datetime).unwrap(), time);
+    }
+
+    #[test]
+    fn to_date_time() {
+        let expected = DateTime::new(2023, 5, 8, 10, 30, 0)
+            .unwrap()
+            .with_nanoseconds(250_000_000)
+            .unwrap();
+
+        for s in [
+            "2023-05-08T10:30:00.25Z",
+            "20230508T103000,25Z",
+            "2023-05-08T12:30:00.25+02:00",
+            "20230508T083000.25-0200",
+            "2023-05-08T11:30:00.25+01",
+        ] {
+            let time = Time::new(s).unwrap();
+            assert_eq!(time.to_date_time().unwrap(), expected, "{}", s);
+        }
+
+        // Dates, local times and intervals can't be converted
+        for s in ["2023-05-08", "2023-05-08T10:30:00", "2023-05-08/P1D"] {
+            let time = Time::new(s).unwrap();
+            assert!(time.to_date_time().is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn to_duration() {
+        let time = Time::new("PT1H30M").unwrap();
+        assert_eq!(
+            time.to_duration().unwrap(),
+            Duration::new("PT1H30M").unwrap()
+        );
+        assert_eq!(Time::from(time.to_duration().unwrap()), time);
+
+        assert!(Time::new("2023-05-08").unwrap().to_duration().is_err());
+    }
+
+    #[test]
+    fn reject_invalid() {
+        let err = Time::from_der(&hex!("0e 00")).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Length { tag: Tag::Time });
+
+        let err = Time::new(&[b'0'; Time::MAX_SIZE + 1]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Length { tag: Tag::Time });
+
+        let err = Time::from_der(&hex!("0e 05 32 30 32 33 20")).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Value { tag: Tag::Time });
+
+        let err = Time::from_der(&hex!("0e 05 32 30 32 33 7a")).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Value { tag: Tag::Time });
+    }
+}
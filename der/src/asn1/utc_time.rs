@@ -11,6 +11,9 @@ use core::time::Duration;
 #[cfg(feature = "std")]
 use std::time::SystemTime;
 
+#[cfg(feature = "time")]
+use time::OffsetDateTime;
+
 /// ASN.1 `UTCTime` type.
 ///
 /// This type implements the validity requirements specified in
@@ -40,9 +43,11 @@ impl UtcTime {
     pub const MAX_YEAR: u16 = 2049;
 
     /// Create a [`UtcTime`] from a [`DateTime`].
+    ///
+    /// Fractional seconds are truncated.
     pub fn from_date_time(datetime: DateTime) -> Result<Self> {
         if datetime.year() <= UtcTime::MAX_YEAR {
-            Ok(Self(datetime))
+            Ok(Self(datetime.truncate_subsec()))
         } else {
             Err(Self::TAG.value_error())
         }
@@ -188,6 +193,42 @@ impl From<UtcTime> for SystemTime {
     }
 }
 
+#[cfg(feature = "time")]
+impl TryFrom<OffsetDateTime> for UtcTime {
+    type Error = Error;
+
+    fn try_from(time: OffsetDateTime) -> Result<UtcTime> {
+        DateTime::try_from(time)?.try_into()
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<UtcTime> for OffsetDateTime {
+    type Error = Error;
+
+    fn try_from(time: UtcTime) -> Result<OffsetDateTime> {
+        time.to_date_time().try_into()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> TryFrom<chrono::DateTime<Tz>> for UtcTime {
+    type Error = Error;
+
+    fn try_from(time: chrono::DateTime<Tz>) -> Result<UtcTime> {
+        DateTime::try_from(time)?.try_into()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<UtcTime> for chrono::DateTime<chrono::Utc> {
+    type Error = Error;
+
+    fn try_from(time: UtcTime) -> Result<chrono::DateTime<chrono::Utc>> {
+        time.to_date_time().try_into()
+    }
+}
+
 // Implement by hand because the derive would create invalid values.
 // Use the conversion from DateTime to create a valid value.
 // The DateTime type has a way bigger range of valid years than UtcTime,
@@ -239,4 +280,17 @@ mod tests {
         utc_time.encode(&mut encoder).unwrap();
         assert_eq!(example_bytes, encoder.finish().unwrap());
     }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_conversions() {
+        use chrono::{TimeZone, Utc};
+
+        let time = Utc.with_ymd_and_hms(2049, 12, 31, 23, 59, 59).unwrap();
+        let utc_time = UtcTime::try_from(time).unwrap();
+        assert_eq!(chrono::DateTime::<Utc>::try_from(utc_time).unwrap(), time);
+
+        let time = Utc.with_ymd_and_hms(2050, 1, 1, 0, 0, 0).unwrap();
+        assert!(UtcTime::try_from(time).is_err());
+    }
 }
@@ -1,11 +1,11 @@
 //! ASN.1 `VideotexString` support.
 
-use crate::{asn1::AnyRef, FixedTag, Result, StrRef, Tag};
-use core::{fmt, ops::Deref};
+use crate::{StrRef, Tag};
 
 /// ASN.1 `VideotexString` type.
 ///
-/// Supports a subset the ASCII character set (described below).
+/// Supports the subset of the T.100/T.101 character set which is valid UTF-8
+/// (described below).
 ///
 /// For UTF-8, use [`Utf8StringRef`][`crate::asn1::Utf8StringRef`] instead.
 /// For the full ASCII character set, use
@@ -15,59 +15,16 @@ use core::{fmt, ops::Deref};
 ///
 /// # Supported characters
 ///
-/// For the practical purposes VideotexString is treated as IA5string, disallowing non-ASCII chars.
-///
+/// For the practical purposes VideotexString is treated as an 8-bit string, and any contents
+/// which are valid UTF-8 (including ASCII) are accepted. Use [`VideotexString`] to also decode
+/// contents which are only valid as ISO 8859-1.
 #[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Ord)]
 pub struct VideotexStringRef<'a> {
     /// Inner value
     inner: StrRef<'a>,
 }
 
-impl<'a> VideotexStringRef<'a> {
-    /// Create a new ASN.1 `VideotexString`.
-    pub fn new<T>(input: &'a T) -> Result<Self>
-    where
-        T: AsRef<[u8]> + ?Sized,
-    {
-        let input = input.as_ref();
-
-        // Validate all characters are within VideotexString's allowed set
-        // FIXME: treat as if it were IA5String
-        if input.iter().any(|&c| c > 0x7F) {
-            return Err(Self::TAG.value_error());
-        }
-
-        StrRef::from_bytes(input)
-            .map(|inner| Self { inner })
-            .map_err(|_| Self::TAG.value_error())
-    }
-}
-
-impl_string_type!(VideotexStringRef<'a>, 'a);
-
-impl<'a> Deref for VideotexStringRef<'a> {
-    type Target = StrRef<'a>;
-
-    fn deref(&self) -> &Self::Target {
-        &self.inner
-    }
-}
-
-impl FixedTag for VideotexStringRef<'_> {
-    const TAG: Tag = Tag::VideotexString;
-}
-
-impl<'a> From<&VideotexStringRef<'a>> for VideotexStringRef<'a> {
-    fn from(value: &VideotexStringRef<'a>) -> VideotexStringRef<'a> {
-        *value
-    }
-}
-
-impl<'a> From<VideotexStringRef<'a>> for AnyRef<'a> {
-    fn from(printable_string: VideotexStringRef<'a>) -> AnyRef<'a> {
-        AnyRef::from_tag_and_value(Tag::VideotexString, printable_string.inner.into())
-    }
-}
+impl_legacy_string_ref!(VideotexStringRef, Tag::VideotexString, "VideotexString");
 
 impl<'a> From<VideotexStringRef<'a>> for &'a [u8] {
     fn from(printable_string: VideotexStringRef<'a>) -> &'a [u8] {
@@ -75,10 +32,38 @@ impl<'a> From<VideotexStringRef<'a>> for &'a [u8] {
     }
 }
 
-impl<'a> fmt::Debug for VideotexStringRef<'a> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "VideotexString({:?})", self.as_str())
+#[cfg(feature = "alloc")]
+pub use self::allocation::VideotexString;
+
+#[cfg(feature = "alloc")]
+mod allocation {
+    use super::VideotexStringRef;
+    use crate::{BytesOwned, StrOwned, Tag};
+
+    /// ASN.1 `VideotexString` type.
+    ///
+    /// Owned counterpart of [`VideotexStringRef`] which additionally supports
+    /// contents which are not valid UTF-8.
+    ///
+    /// # Supported characters
+    ///
+    /// Contents which are valid UTF-8 are used as-is, and any other contents are decoded as
+    /// ISO 8859-1. The original bytes are retained so such values are re-encoded unchanged.
+    #[derive(Clone, Eq, PartialEq, PartialOrd, Ord)]
+    pub struct VideotexString {
+        /// Inner value
+        inner: StrOwned,
+
+        /// Original encoding, if the value was decoded from ISO 8859-1.
+        latin1: Option<BytesOwned>,
     }
+
+    impl_legacy_string_owned!(
+        VideotexString,
+        VideotexStringRef,
+        Tag::VideotexString,
+        "VideotexString"
+    );
 }
 
 #[cfg(test)]
@@ -95,4 +80,19 @@ mod tests {
         let printable_string = VideotexStringRef::from_der(example_bytes).unwrap();
         assert_eq!(printable_string.as_str(), "Test User 1");
     }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn decode_latin1() {
+        use super::VideotexString;
+        use crate::Encode;
+
+        // "café" encoded as ISO 8859-1
+        let example_bytes = &[0x15, 0x04, 0x63, 0x61, 0x66, 0xe9];
+        assert!(VideotexStringRef::from_der(example_bytes).is_err());
+
+        let videotex_string = VideotexString::from_der(example_bytes).unwrap();
+        assert_eq!(videotex_string.as_str(), "café");
+        assert_eq!(videotex_string.to_der().unwrap(), example_bytes);
+    }
 }
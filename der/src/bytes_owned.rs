@@ -145,18 +145,14 @@ impl TryFrom<Vec<u8>> for BytesOwned {
 }
 
 // Implement by hand because the derive would create invalid values.
-// Make sure the length and the inner.len matches.
+// Take the length from the remaining input so it can always be satisfied.
 #[cfg(feature = "arbitrary")]
 impl<'a> arbitrary::Arbitrary<'a> for BytesOwned {
     fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
-        let length = u.arbitrary()?;
-        Ok(Self {
-            length,
-            inner: Box::from(u.bytes(u32::from(length) as usize)?),
-        })
+        Self::new(<&'a [u8]>::arbitrary(u)?).map_err(|_| arbitrary::Error::IncorrectFormat)
     }
 
     fn size_hint(depth: usize) -> (usize, Option<usize>) {
-        arbitrary::size_hint::and(Length::size_hint(depth), (0, None))
+        <&'a [u8] as arbitrary::Arbitrary<'a>>::size_hint(depth)
     }
 }
@@ -0,0 +1,282 @@
+//! Validation of canonical DER encodings.
+
+use crate::{
+    asn1::{FractionalGeneralizedTime, Null, UtcTime},
+    reader::DEFAULT_MAX_DEPTH,
+    tag::constructed_string_tag,
+    Decode, DecodeValue, ErrorKind, Header, Reader, Result, SliceReader, Tag,
+};
+
+/// Check that the given input consists of a single TLV production which is
+/// encoded exactly as prescribed by DER, without decoding it as any
+/// particular type.
+///
+/// Since DER encodings are unique, inputs which pass this check can be
+/// compared byte-for-byte, e.g. to compare BER-or-DER encoded values received
+/// from third parties. The following is checked, recursing into constructed
+/// values:
+///
+/// - lengths are definite and encoded with a minimum number of octets
+/// - strings are encoded using the primitive form
+/// - the elements of a `SET` or `SET OF` are sorted by their encodings
+/// - `BOOLEAN`, `INTEGER`, `ENUMERATED`, `BIT STRING`, `NULL`,
+///   `OBJECT IDENTIFIER`, `RELATIVE-OID`, `UTCTime` and `GeneralizedTime`
+///   values are encoded canonically
+///
+/// The contents of primitive values with any other tag (including
+/// `IMPLICIT`ly tagged values of the types above) aren't checked, as their
+/// actual type isn't known.
+///
+/// ```
+/// use hex_literal::hex;
+///
+/// // `INTEGER 1` with a non-minimal length encoding
+/// assert!(der::validate_canonical(&hex!("02 81 01 01")).is_err());
+/// assert!(der::validate_canonical(&hex!("02 01 01")).is_ok());
+/// ```
+pub fn validate_canonical(der: &[u8]) -> Result<()> {
+    let mut reader = SliceReader::new(der)?;
+    validate_tlv(&mut reader, 0)?;
+    reader.finish(())
+}
+
+/// Validate the next TLV production in `reader`, returning its encoding.
+fn validate_tlv<'a>(reader: &mut SliceReader<'a>, depth: usize) -> Result<&'a [u8]> {
+    if depth > DEFAULT_MAX_DEPTH {
+        return Err(reader.error(ErrorKind::NestingLimitExceeded));
+    }
+
+    if let Some(tag) = reader.peek_byte().and_then(constructed_string_tag) {
+        return Err(reader.error(ErrorKind::Noncanonical { tag }));
+    }
+
+    let offset = reader.position();
+    let header = reader.peek_header().map_err(|e| match e.position() {
+        Some(_) => e,
+        None => e.kind().at(offset),
+    })?;
+    let tlv = reader.read_slice(header.tlv_len()?)?;
+
+    validate_value(tlv, depth)
+        .map_err(|e| e.nested(offset))
+        .map(|_| tlv)
+}
+
+/// Validate the value of a single TLV production.
+fn validate_value(tlv: &[u8], depth: usize) -> Result<()> {
+    let mut reader = SliceReader::new(tlv)?;
+    let header = Header::decode(&mut reader)?;
+
+    match header.tag {
+        tag if tag.is_constructed() => {
+            let mut prev: Option<&[u8]> = None;
+
+            while !reader.is_finished() {
+                let elem = validate_tlv(&mut reader, depth.saturating_add(1))?;
+
+                if tag == Tag::Set && prev.map_or(false, |prev| prev > elem) {
+                    return Err(reader.error(ErrorKind::SetOrdering));
+                }
+
+                prev = Some(elem);
+            }
+        }
+        Tag::Boolean => {
+            bool::decode_value(&mut reader, header)?;
+        }
+        Tag::Integer | Tag::Enumerated => {
+            let bytes = reader.read_slice(header.length)?;
+            validate_integer(header.tag, bytes)?;
+        }
+        Tag::BitString => {
+            let bytes = reader.read_slice(header.length)?;
+            validate_bit_string(bytes)?;
+        }
+        Tag::Null => {
+            Null::decode_value(&mut reader, header)?;
+        }
+        Tag::ObjectIdentifier | Tag::RelativeOid => {
+            let bytes = reader.read_slice(header.length)?;
+            validate_arcs(header.tag, bytes)?;
+        }
+        Tag::UtcTime => {
+            UtcTime::decode_value(&mut reader, header)?;
+        }
+        Tag::GeneralizedTime => {
+            FractionalGeneralizedTime::decode_value(&mut reader, header)?;
+        }
+        _ => {
+            reader.read_slice(header.length)?;
+        }
+    }
+
+    reader.finish(())
+}
+
+/// Validate the contents of an `INTEGER` or `ENUMERATED` value are minimal.
+fn validate_integer(tag: Tag, bytes: &[u8]) -> Result<()> {
+    match bytes {
+        [] => Err(tag.length_error()),
+        [0x00, byte, ..] if *byte < 0x80 => Err(tag.non_canonical_error()),
+        [0xFF, byte, ..] if *byte >= 0x80 => Err(tag.non_canonical_error()),
+        _ => Ok(()),
+    }
+}
+
+/// Validate the contents of a `BIT STRING`, which must have all of its
+/// unused bits set to zero per X.690 Section 11.2.1.
+fn validate_bit_string(bytes: &[u8]) -> Result<()> {
+    let tag = Tag::BitString;
+
+    match bytes.split_first() {
+        None => Err(tag.length_error()),
+        Some((&unused_bits, [])) if unused_bits != 0 => Err(tag.value_error()),
+        Some((&unused_bits, _)) if unused_bits > 7 => Err(tag.value_error()),
+        Some((&unused_bits, [.., last])) if last.trailing_zeros() < u32::from(unused_bits) => {
+            Err(tag.non_canonical_error())
+        }
+        Some(_) => Ok(()),
+    }
+}
+
+/// Validate that the arcs of an `OBJECT IDENTIFIER` or `RELATIVE-OID` are
+/// encoded with a minimum number of octets.
+fn validate_arcs(tag: Tag, bytes: &[u8]) -> Result<()> {
+    match bytes.last() {
+        None => return Err(tag.length_error()),
+        Some(last) if last & 0x80 != 0 => return Err(tag.value_error()),
+        Some(_) => (),
+    }
+
+    let mut arc_start = true;
+
+    for &byte in bytes {
+        // A leading `0x80` octet is padding (X.690 Section 8.19.2)
+        if arc_start && byte == 0x80 {
+            return Err(tag.non_canonical_error());
+        }
+
+        arc_start = byte & 0x80 == 0;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::validate_canonical;
+    use crate::{ErrorKind, Length, Tag};
+    use hex_literal::hex;
+
+    #[test]
+    fn accepts_der() {
+        // AlgorithmIdentifier for `id-ecPublicKey` with `secp256r1` parameters
+        validate_canonical(&hex!(
+            "30 13 06 07 2a 86 48 ce 3d 02 01 06 08 2a 86 48 ce 3d 03 01 07"
+        ))
+        .unwrap();
+
+        // SET containing sorted elements, including a duplicate
+        validate_canonical(&hex!("31 09 02 01 01 02 01 01 02 01 02")).unwrap();
+
+        // Context-specific constructed and primitive values
+        validate_canonical(&hex!("a0 05 80 03 00 ff 01")).unwrap();
+
+        // BOOLEAN, NULL, BIT STRING, UTCTime and GeneralizedTime
+        validate_canonical(&hex!(
+            "30 2b 01 01 ff 05 00 03 02 04 f0"
+            "17 0d 39 31 30 35 30 36 32 33 34 35 34 30 5a"
+            "18 11 32 30 32 31 30 31 30 31 30 30 30 30 30 30 2e 35 5a"
+        ))
+        .unwrap();
+    }
+
+    #[test]
+    fn rejects_non_minimal_length() {
+        let err = validate_canonical(&hex!("30 04 02 81 01 01")).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Length { tag: Tag::Integer });
+        assert_eq!(err.position(), Some(Length::new(2)));
+    }
+
+    #[test]
+    fn rejects_indefinite_length() {
+        let err = validate_canonical(&hex!("30 80 02 01 01 00 00")).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::IndefiniteLength);
+    }
+
+    #[test]
+    fn rejects_unsorted_set() {
+        let err = validate_canonical(&hex!("31 06 02 01 02 02 01 01")).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::SetOrdering);
+    }
+
+    #[test]
+    fn rejects_constructed_string() {
+        let err = validate_canonical(&hex!("30 07 24 05 04 03 01 02 03")).unwrap_err();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::Noncanonical {
+                tag: Tag::OctetString
+            }
+        );
+        assert_eq!(err.position(), Some(Length::new(2)));
+    }
+
+    #[test]
+    fn rejects_non_canonical_values() {
+        // BOOLEAN TRUE must be encoded as 0xFF
+        assert!(validate_canonical(&hex!("01 01 01")).is_err());
+
+        // INTEGER with a redundant leading zero
+        let err = validate_canonical(&hex!("02 02 00 7f")).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Noncanonical { tag: Tag::Integer });
+
+        // ENUMERATED with a redundant leading 0xFF
+        let err = validate_canonical(&hex!("0a 02 ff 80")).unwrap_err();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::Noncanonical {
+                tag: Tag::Enumerated
+            }
+        );
+
+        // BIT STRING with non-zero unused bits
+        let err = validate_canonical(&hex!("03 02 04 f8")).unwrap_err();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::Noncanonical {
+                tag: Tag::BitString
+            }
+        );
+
+        // OBJECT IDENTIFIER with a padded arc
+        let err = validate_canonical(&hex!("06 03 2a 80 01")).unwrap_err();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::Noncanonical {
+                tag: Tag::ObjectIdentifier
+            }
+        );
+
+        // GeneralizedTime with a trailing zero in its fractional seconds
+        assert!(validate_canonical(&hex!(
+            "18 12 32 30 32 31 30 31 30 31 30 30 30 30 30 30 2e 35 30 5a"
+        ))
+        .is_err());
+
+        // NULL with contents
+        assert!(validate_canonical(&hex!("05 01 00")).is_err());
+    }
+
+    #[test]
+    fn rejects_trailing_data() {
+        let err = validate_canonical(&hex!("02 01 01 00")).unwrap_err();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::TrailingData {
+                decoded: Length::new(3),
+                remaining: Length::ONE
+            }
+        );
+    }
+}
@@ -0,0 +1,484 @@
+//! `const fn` decoders for DER-encoded constants.
+//!
+//! These functions allow DER productions embedded as byte literals to be
+//! validated and decoded at compile time, e.g. to define constants:
+//!
+//! ```
+//! use der::const_decode;
+//!
+//! const VERSION: u8 = match const_decode::decode_u8(&[0x02, 0x01, 0x03]) {
+//!     Ok(version) => version,
+//!     Err(_) => panic!("invalid version"),
+//! };
+//!
+//! assert_eq!(VERSION, 3);
+//! ```
+//!
+//! Each decoder accepts exactly one complete TLV production and rejects
+//! trailing data. Lengths are limited to 65535 bytes.
+//!
+//! Errors returned by these functions don't carry a position.
+
+use crate::{Error, ErrorKind, Length, Result, Tag};
+
+#[cfg(feature = "oid")]
+use crate::asn1::ObjectIdentifier;
+
+/// Decode a TLV production with the given `tag` at the start of `bytes`,
+/// returning its value along with any remaining bytes.
+#[allow(clippy::cast_lossless, clippy::integer_arithmetic)]
+pub const fn split_tlv(bytes: &[u8], tag: Tag) -> Result<(&[u8], &[u8])> {
+    let (octet, rest) = match bytes {
+        [octet, rest @ ..] => (*octet, rest),
+        [] => return Err(incomplete(1, 0)),
+    };
+
+    if octet != tag.octet() {
+        return Err(match Tag::from_octet(octet) {
+            Ok(actual) => Error::from_kind(ErrorKind::TagUnexpected {
+                expected: Some(tag),
+                actual,
+            }),
+            Err(err) => err,
+        });
+    }
+
+    let (len, rest) = match rest {
+        [n @ 0..=0x7F, rest @ ..] => (*n as usize, rest),
+        [0x81, n @ 0x80..=0xFF, rest @ ..] => (*n as usize, rest),
+        [0x82, hi @ 0x01..=0xFF, lo, rest @ ..] => (u16::from_be_bytes([*hi, *lo]) as usize, rest),
+        [0x80, ..] => return Err(Error::from_kind(ErrorKind::IndefiniteLength)),
+        [0x81] | [0x82] | [0x82, _] | [] => return Err(incomplete(len_bytes(bytes), bytes.len())),
+        // Non-minimal lengths or lengths which don't fit in a `u16`
+        [_, ..] => return Err(Error::from_kind(ErrorKind::Overlength)),
+    };
+
+    if rest.len() < len {
+        return Err(incomplete(len_bytes(bytes) + len, bytes.len()));
+    }
+
+    Ok(split_at(rest, len))
+}
+
+/// Decode a complete TLV production with the given `tag`, returning its
+/// value and rejecting trailing data.
+// `remaining` is a suffix of `bytes`
+#[allow(clippy::integer_arithmetic)]
+pub const fn decode_tlv(bytes: &[u8], tag: Tag) -> Result<&[u8]> {
+    match split_tlv(bytes, tag) {
+        Ok((value, [])) => Ok(value),
+        Ok((_, remaining)) => Err(Error::from_kind(ErrorKind::TrailingData {
+            decoded: length(bytes.len() - remaining.len()),
+            remaining: length(remaining.len()),
+        })),
+        Err(err) => Err(err),
+    }
+}
+
+/// Decode a `BOOLEAN`.
+pub const fn decode_bool(bytes: &[u8]) -> Result<bool> {
+    match decode_tlv(bytes, Tag::Boolean) {
+        Ok([0x00]) => Ok(false),
+        Ok([0xFF]) => Ok(true),
+        Ok([_]) => Err(Error::from_kind(ErrorKind::Noncanonical {
+            tag: Tag::Boolean,
+        })),
+        Ok(_) => Err(Error::from_kind(ErrorKind::Length { tag: Tag::Boolean })),
+        Err(err) => Err(err),
+    }
+}
+
+/// Decode a `NULL`.
+pub const fn decode_null(bytes: &[u8]) -> Result<()> {
+    match decode_tlv(bytes, Tag::Null) {
+        Ok([]) => Ok(()),
+        Ok(_) => Err(Error::from_kind(ErrorKind::Length { tag: Tag::Null })),
+        Err(err) => Err(err),
+    }
+}
+
+/// Decode an `OBJECT IDENTIFIER`.
+///
+/// Arcs must use the minimal base 128 encoding.
+#[cfg(feature = "oid")]
+#[allow(clippy::integer_arithmetic)]
+pub const fn decode_oid(bytes: &[u8]) -> Result<ObjectIdentifier> {
+    /// Maximum length of the dotted string form: each BER byte expands to
+    /// at most 4 characters, i.e. 3 digits and a `.`.
+    const MAX_STR_LEN: usize = 4 * ObjectIdentifier::MAX_SIZE;
+
+    let value = match decode_tlv(bytes, Tag::ObjectIdentifier) {
+        Ok(value) if value.len() <= ObjectIdentifier::MAX_SIZE => value,
+        Ok(_) => return Err(oid_malformed()),
+        Err(err) => return Err(err),
+    };
+
+    let root = match value {
+        [root @ 0..=119, ..] => *root as u128,
+        _ => return Err(oid_malformed()),
+    };
+
+    // `const-oid` can only parse OIDs in `const fn` from their string form,
+    // so render the arcs as a dotted string first.
+    let mut buf = [0u8; MAX_STR_LEN];
+    let mut pos = write_arc(&mut buf, 0, root / 40);
+    buf[pos] = b'.';
+    pos = write_arc(&mut buf, pos + 1, root % 40);
+
+    let mut arc = 0u128;
+    let mut arc_bytes = 0;
+    let mut i = 1;
+
+    // All of the indices below are bounded by `MAX_STR_LEN` and the length of
+    // `value`, and the overflow check ensures the shift won't overflow.
+    while i < value.len() {
+        let byte = value[i];
+
+        if (arc_bytes == 0 && byte == 0x80) || arc >> (u128::BITS - 7) != 0 {
+            return Err(oid_malformed());
+        }
+
+        arc = arc << 7 | (byte & 0x7F) as u128;
+        arc_bytes += 1;
+        i += 1;
+
+        if byte & 0x80 == 0 {
+            buf[pos] = b'.';
+            pos = write_arc(&mut buf, pos + 1, arc);
+            arc = 0;
+            arc_bytes = 0;
+        }
+    }
+
+    if arc_bytes != 0 {
+        return Err(oid_malformed());
+    }
+
+    match core::str::from_utf8(split_at(&buf, pos).0) {
+        Ok(s) => match ObjectIdentifier::new(s) {
+            Ok(oid) => Ok(oid),
+            Err(_) => Err(oid_malformed()),
+        },
+        Err(_) => Err(oid_malformed()),
+    }
+}
+
+macro_rules! impl_decode_uint {
+    ($($name:ident => $uint:ty),+) => {
+        $(
+            #[doc = concat!("Decode an unsigned `INTEGER` as a [`", stringify!($uint), "`].")]
+            pub const fn $name(bytes: &[u8]) -> Result<$uint> {
+                match decode_uint(bytes, <$uint>::BITS) {
+                    // Range checked by `decode_uint`
+                    #[allow(clippy::cast_possible_truncation)]
+                    Ok(n) => Ok(n as $uint),
+                    Err(err) => Err(err),
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_decode_int {
+    ($($name:ident => $int:ty),+) => {
+        $(
+            #[doc = concat!("Decode a signed `INTEGER` as an [`", stringify!($int), "`].")]
+            pub const fn $name(bytes: &[u8]) -> Result<$int> {
+                match decode_int(bytes, <$int>::BITS) {
+                    // Range checked by `decode_int`
+                    #[allow(clippy::cast_possible_truncation)]
+                    Ok(n) => Ok(n as $int),
+                    Err(err) => Err(err),
+                }
+            }
+        )+
+    };
+}
+
+impl_decode_uint!(decode_u8 => u8, decode_u16 => u16, decode_u32 => u32, decode_u64 => u64);
+impl_decode_int!(decode_i8 => i8, decode_i16 => i16, decode_i32 => i32, decode_i64 => i64);
+
+/// Decode an unsigned `INTEGER` which fits in the given number of bits.
+const fn decode_uint(bytes: &[u8], bits: u32) -> Result<u64> {
+    let value = match decode_tlv(bytes, Tag::Integer) {
+        Ok([]) => return Err(Error::from_kind(ErrorKind::Length { tag: Tag::Integer })),
+        Ok([0x00, next, ..]) if *next < 0x80 => return Err(non_canonical_integer()),
+        Ok([0x00, rest @ ..]) if !rest.is_empty() => rest,
+        Ok([first, ..]) if *first >= 0x80 => {
+            return Err(Error::from_kind(ErrorKind::Value { tag: Tag::Integer }))
+        }
+        Ok(value) => value,
+        Err(err) => return Err(err),
+    };
+
+    // Bounded by the check above, and `bits` is at most 64
+    #[allow(clippy::integer_arithmetic)]
+    if value.len() > (bits / 8) as usize {
+        return Err(Error::from_kind(ErrorKind::Value { tag: Tag::Integer }));
+    }
+
+    let mut result = 0u64;
+    let mut i = 0;
+
+    // `i` is bounded by the length of `value`, which is at most 8 bytes
+    #[allow(clippy::integer_arithmetic)]
+    while i < value.len() {
+        result = result << 8 | value[i] as u64;
+        i += 1;
+    }
+
+    Ok(result)
+}
+
+/// Decode a signed `INTEGER` which fits in the given number of bits.
+const fn decode_int(bytes: &[u8], bits: u32) -> Result<i64> {
+    let value = match decode_tlv(bytes, Tag::Integer) {
+        Ok([]) => return Err(Error::from_kind(ErrorKind::Length { tag: Tag::Integer })),
+        Ok([0x00, next, ..]) if *next < 0x80 => return Err(non_canonical_integer()),
+        Ok([0xFF, next, ..]) if *next >= 0x80 => return Err(non_canonical_integer()),
+        Ok(value) => value,
+        Err(err) => return Err(err),
+    };
+
+    // `bits` is at most 64
+    #[allow(clippy::integer_arithmetic)]
+    if value.len() > (bits / 8) as usize {
+        return Err(Error::from_kind(ErrorKind::Value { tag: Tag::Integer }));
+    }
+
+    // Sign extend
+    let mut result = if value[0] >= 0x80 { -1i64 } else { 0 };
+    let mut i = 0;
+
+    // `i` is bounded by the length of `value`, which is at most 8 bytes
+    #[allow(
+        clippy::cast_possible_wrap,
+        clippy::cast_sign_loss,
+        clippy::integer_arithmetic
+    )]
+    while i < value.len() {
+        result = ((result as u64) << 8 | value[i] as u64) as i64;
+        i += 1;
+    }
+
+    Ok(result)
+}
+
+/// Split `bytes` at `mid` (const-friendly equivalent of `slice::split_at`).
+///
+/// Panics if `mid` is out of bounds.
+const fn split_at(bytes: &[u8], mid: usize) -> (&[u8], &[u8]) {
+    let mut head = bytes;
+    let mut tail = bytes;
+
+    // Peel off one byte at a time as slice ranges can't be indexed by
+    // `const fn` on our MSRV.
+    while head.len() > mid {
+        if let [rest @ .., _] = head {
+            head = rest;
+        }
+    }
+
+    #[allow(clippy::integer_arithmetic)]
+    while tail.len() > bytes.len() - mid {
+        if let [_, rest @ ..] = tail {
+            tail = rest;
+        }
+    }
+
+    (head, tail)
+}
+
+/// Write the decimal representation of `arc` into `buf` at `pos`, returning
+/// the position after the last digit.
+#[cfg(feature = "oid")]
+#[allow(clippy::cast_possible_truncation, clippy::integer_arithmetic)]
+const fn write_arc(buf: &mut [u8], pos: usize, mut arc: u128) -> usize {
+    let mut digits = [0u8; 39];
+    let mut len = 0;
+
+    loop {
+        digits[len] = b'0' + (arc % 10) as u8;
+        arc /= 10;
+        len += 1;
+
+        if arc == 0 {
+            break;
+        }
+    }
+
+    let mut i = 0;
+
+    while i < len {
+        buf[pos + i] = digits[len - i - 1];
+        i += 1;
+    }
+
+    pos + len
+}
+
+/// Number of header bytes for a TLV production, based on its first length
+/// octet.
+const fn len_bytes(bytes: &[u8]) -> usize {
+    match bytes {
+        [_, 0x81, ..] => 3,
+        [_, 0x82, ..] => 4,
+        _ => 2,
+    }
+}
+
+/// Convert a `usize` to a [`Length`], saturating on overflow.
+const fn length(n: usize) -> Length {
+    if n > u16::MAX as usize {
+        Length::new(u16::MAX)
+    } else {
+        // Range checked above
+        #[allow(clippy::cast_possible_truncation)]
+        Length::new(n as u16)
+    }
+}
+
+/// Create an [`ErrorKind::Incomplete`] error.
+const fn incomplete(expected_len: usize, actual_len: usize) -> Error {
+    Error::from_kind(ErrorKind::Incomplete {
+        expected_len: length(expected_len),
+        actual_len: length(actual_len),
+    })
+}
+
+/// Create an [`ErrorKind::OidMalformed`] error.
+#[cfg(feature = "oid")]
+const fn oid_malformed() -> Error {
+    Error::from_kind(ErrorKind::OidMalformed)
+}
+
+/// Create an [`ErrorKind::Noncanonical`] error for an `INTEGER`.
+const fn non_canonical_integer() -> Error {
+    Error::from_kind(ErrorKind::Noncanonical { tag: Tag::Integer })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex_literal::hex;
+
+    #[test]
+    fn decode_primitives() {
+        const TRUE: bool = match decode_bool(&hex!("0101FF")) {
+            Ok(b) => b,
+            Err(_) => panic!("invalid BOOLEAN"),
+        };
+
+        assert!(TRUE);
+        assert_eq!(decode_bool(&hex!("010100")), Ok(false));
+        assert!(decode_bool(&hex!("010101")).is_err());
+        assert_eq!(decode_null(&hex!("0500")), Ok(()));
+        assert!(decode_null(&hex!("050100")).is_err());
+    }
+
+    #[test]
+    fn decode_integers() {
+        assert_eq!(decode_u8(&hex!("020100")), Ok(0));
+        assert_eq!(decode_u8(&hex!("020200FF")), Ok(255));
+        assert_eq!(
+            decode_u16(&hex!("02030100FF")),
+            Err(Tag::Integer.value_error())
+        );
+        assert_eq!(decode_u32(&hex!("020400FFFFFF")), Ok(0xFF_FFFF));
+        assert_eq!(decode_u64(&hex!("0209008000000000000000")), Ok(1 << 63));
+        assert_eq!(decode_u8(&hex!("0201FF")), Err(Tag::Integer.value_error()));
+        assert_eq!(
+            decode_u8(&hex!("0202007F")),
+            Err(Tag::Integer.non_canonical_error())
+        );
+
+        assert_eq!(decode_i8(&hex!("0201FF")), Ok(-1));
+        assert_eq!(decode_i8(&hex!("020180")), Ok(i8::MIN));
+        assert_eq!(decode_i16(&hex!("0202FF7F")), Ok(-129));
+        assert_eq!(decode_i32(&hex!("02017F")), Ok(127));
+        assert_eq!(decode_i64(&hex!("02088000000000000000")), Ok(i64::MIN));
+        assert_eq!(
+            decode_i16(&hex!("0202FF80")),
+            Err(Tag::Integer.non_canonical_error())
+        );
+        assert!(decode_i8(&hex!("02020080")).is_err());
+    }
+
+    #[test]
+    fn decode_errors() {
+        assert_eq!(
+            decode_u8(&hex!("0401FF")),
+            Err(Tag::OctetString.unexpected_error(Some(Tag::Integer)))
+        );
+        assert_eq!(
+            decode_u8(&hex!("0202")).unwrap_err().kind(),
+            ErrorKind::Incomplete {
+                expected_len: Length::new(4),
+                actual_len: Length::new(2),
+            }
+        );
+        assert_eq!(
+            decode_u8(&hex!("02010100")).unwrap_err().kind(),
+            ErrorKind::TrailingData {
+                decoded: Length::new(3),
+                remaining: Length::new(1),
+            }
+        );
+        assert_eq!(
+            decode_null(&hex!("058100")),
+            Err(ErrorKind::Overlength.into())
+        );
+        assert_eq!(
+            decode_null(&hex!("0580")),
+            Err(ErrorKind::IndefiniteLength.into())
+        );
+    }
+
+    #[test]
+    fn decode_long_form_length() {
+        let mut bytes = [0u8; 260];
+        bytes[..3].copy_from_slice(&hex!("048180"));
+        let (value, rest) = split_tlv(&bytes, Tag::OctetString).unwrap();
+        assert_eq!(value.len(), 128);
+        assert_eq!(rest.len(), 129);
+
+        bytes[..4].copy_from_slice(&hex!("04820100"));
+        let value = decode_tlv(&bytes, Tag::OctetString).unwrap();
+        assert_eq!(value.len(), 256);
+
+        // Non-minimal lengths
+        bytes[..4].copy_from_slice(&hex!("04820080"));
+        assert_eq!(
+            split_tlv(&bytes, Tag::OctetString),
+            Err(ErrorKind::Overlength.into())
+        );
+    }
+
+    #[cfg(feature = "oid")]
+    #[test]
+    fn decode_oids() {
+        const RSA_ENCRYPTION: ObjectIdentifier = match decode_oid(&hex!("06092A864886F70D010101")) {
+            Ok(oid) => oid,
+            Err(_) => panic!("invalid OID"),
+        };
+
+        assert_eq!(
+            RSA_ENCRYPTION,
+            ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.1")
+        );
+        assert_eq!(
+            decode_oid(&hex!("060A2A864886F70D01010100")),
+            Ok(ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.1.0"))
+        );
+        assert_eq!(
+            decode_oid(&hex!("06032A8686")),
+            Err(ErrorKind::OidMalformed.into())
+        );
+
+        // Non-minimal arc encoding
+        assert_eq!(
+            decode_oid(&hex!("06042A808101")),
+            Err(ErrorKind::OidMalformed.into())
+        );
+    }
+}
@@ -12,7 +12,7 @@ use core::{fmt, str::FromStr, time::Duration};
 use std::time::{SystemTime, UNIX_EPOCH};
 
 #[cfg(feature = "time")]
-use time::PrimitiveDateTime;
+use time::{OffsetDateTime, PrimitiveDateTime};
 
 /// Minimum year allowed in [`DateTime`] values.
 const MIN_YEAR: u16 = 1970;
@@ -23,6 +23,12 @@ const MIN_YEAR: u16 = 1970;
 /// This corresponds to: 9999-12-31T23:59:59Z
 const MAX_UNIX_DURATION: Duration = Duration::from_secs(253_402_300_799);
 
+/// Number of nanoseconds in a second.
+const NANOS_PER_SEC: u32 = 1_000_000_000;
+
+/// Maximum number of fractional second digits (i.e. nanosecond precision).
+pub(crate) const MAX_FRACTION_DIGITS: usize = 9;
+
 /// Date-and-time type shared by multiple ASN.1 types
 /// (e.g. `GeneralizedTime`, `UTCTime`).
 ///
@@ -51,6 +57,9 @@ pub struct DateTime {
     /// Seconds (0-59)
     seconds: u8,
 
+    /// Fractional seconds in nanoseconds (0-999,999,999)
+    nanoseconds: u32,
+
     /// [`Duration`] since the Unix epoch.
     unix_duration: Duration,
 }
@@ -65,6 +74,7 @@ impl DateTime {
         hour: 23,
         minutes: 59,
         seconds: 59,
+        nanoseconds: 0,
         unix_duration: MAX_UNIX_DURATION,
     };
 
@@ -130,12 +140,46 @@ impl DateTime {
             hour,
             minutes,
             seconds,
+            nanoseconds: 0,
+            unix_duration,
+        })
+    }
+
+    /// Set the fractional seconds of this [`DateTime`] in nanoseconds.
+    ///
+    /// Returns an error if `nanoseconds` is not less than one second, or if
+    /// the resulting value exceeds [`DateTime::INFINITY`].
+    pub fn with_nanoseconds(self, nanoseconds: u32) -> Result<Self> {
+        if nanoseconds >= NANOS_PER_SEC {
+            return Err(ErrorKind::DateTime.into());
+        }
+
+        let unix_duration = Duration::new(self.unix_duration.as_secs(), nanoseconds);
+
+        if unix_duration > MAX_UNIX_DURATION {
+            return Err(ErrorKind::DateTime.into());
+        }
+
+        Ok(Self {
+            nanoseconds,
             unix_duration,
+            ..self
         })
     }
 
+    /// Discard the fractional seconds of this [`DateTime`].
+    pub(crate) const fn truncate_subsec(self) -> Self {
+        Self {
+            nanoseconds: 0,
+            unix_duration: Duration::from_secs(self.unix_duration.as_secs()),
+            ..self
+        }
+    }
+
     /// Compute a [`DateTime`] from the given [`Duration`] since the `UNIX_EPOCH`.
     ///
+    /// Sub-second precision of the [`Duration`] is preserved.
+    ///
     /// Returns `None` if the value is outside the supported date range.
     // TODO(tarcieri): checked arithmetic
     #[allow(clippy::integer_arithmetic)]
@@ -212,7 +256,8 @@ impl DateTime {
             hour.try_into()?,
             minute.try_into()?,
             second.try_into()?,
-        )
+        )?
+        .with_nanoseconds(unix_duration.subsec_nanos())
     }
 
     /// Get the year.
@@ -245,6 +290,11 @@ impl DateTime {
         self.seconds
     }
 
+    /// Get the fractional seconds in nanoseconds.
+    pub fn nanoseconds(&self) -> u32 {
+        self.nanoseconds
+    }
+
     /// Compute [`Duration`] since `UNIX_EPOCH` from the given calendar date.
     pub fn unix_duration(&self) -> Duration {
         self.unix_duration
@@ -269,8 +319,20 @@ impl FromStr for DateTime {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self> {
+        let s = s.strip_suffix('Z').ok_or(ErrorKind::DateTime)?;
+
+        // Optional fractional seconds, e.g. `2001-01-02T12:13:14.5Z`
+        let (s, nanoseconds) = match s.split_once('.') {
+            Some((s, fraction)) => {
+                let nanoseconds = decode_nanoseconds(Tag::GeneralizedTime, fraction.as_bytes())
+                    .map_err(|_| ErrorKind::DateTime)?;
+                (s, nanoseconds)
+            }
+            None => (s, 0),
+        };
+
         match *s.as_bytes() {
-            [year1, year2, year3, year4, b'-', month1, month2, b'-', day1, day2, b'T', hour1, hour2, b':', min1, min2, b':', sec1, sec2, b'Z'] =>
+            [year1, year2, year3, year4, b'-', month1, month2, b'-', day1, day2, b'T', hour1, hour2, b':', min1, min2, b':', sec1, sec2] =>
             {
                 let tag = Tag::GeneralizedTime;
                 let year = decode_year(&[year1, year2, year3, year4])?;
@@ -279,7 +341,7 @@ impl FromStr for DateTime {
                 let hour = decode_decimal(tag, hour1, hour2).map_err(|_| ErrorKind::DateTime)?;
                 let minutes = decode_decimal(tag, min1, min2).map_err(|_| ErrorKind::DateTime)?;
                 let seconds = decode_decimal(tag, sec1, sec2).map_err(|_| ErrorKind::DateTime)?;
-                Self::new(year, month, day, hour, minutes, seconds)
+                Self::new(year, month, day, hour, minutes, seconds)?.with_nanoseconds(nanoseconds)
             }
             _ => Err(ErrorKind::DateTime.into()),
         }
@@ -290,9 +352,16 @@ impl fmt::Display for DateTime {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "{:02}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+            "{:02}-{:02}-{:02}T{:02}:{:02}:{:02}",
             self.year, self.month, self.day, self.hour, self.minutes, self.seconds
-        )
+        )?;
+
+        if self.nanoseconds != 0 {
+            let (fraction, width) = trim_nanoseconds(self.nanoseconds);
+            write!(f, ".{:0width$}", fraction, width = width)?;
+        }
+
+        f.write_str("Z")
     }
 }
 
@@ -335,7 +404,12 @@ impl TryFrom<DateTime> for PrimitiveDateTime {
     fn try_from(time: DateTime) -> Result<PrimitiveDateTime> {
         let month = time.month().try_into()?;
         let date = time::Date::from_calendar_date(i32::from(time.year()), month, time.day())?;
-        let time = time::Time::from_hms(time.hour(), time.minutes(), time.seconds())?;
+        let time = time::Time::from_hms_nano(
+            time.hour(),
+            time.minutes(),
+            time.seconds(),
+            time.nanoseconds(),
+        )?;
 
         Ok(PrimitiveDateTime::new(date, time))
     }
@@ -353,7 +427,78 @@ impl TryFrom<PrimitiveDateTime> for DateTime {
             time.hour(),
             time.minute(),
             time.second(),
+        )?
+        .with_nanoseconds(time.nanosecond())
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<DateTime> for OffsetDateTime {
+    type Error = Error;
+
+    fn try_from(time: DateTime) -> Result<OffsetDateTime> {
+        PrimitiveDateTime::try_from(time).map(PrimitiveDateTime::assume_utc)
+    }
+}
+
+/// Converts the given [`OffsetDateTime`] to UTC.
+#[cfg(feature = "time")]
+impl TryFrom<OffsetDateTime> for DateTime {
+    type Error = Error;
+
+    fn try_from(time: OffsetDateTime) -> Result<DateTime> {
+        let secs = u64::try_from(time.unix_timestamp()).map_err(|_| ErrorKind::DateTime)?;
+        DateTime::from_unix_duration(Duration::new(secs, time.nanosecond()))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<DateTime> for chrono::DateTime<chrono::Utc> {
+    type Error = Error;
+
+    fn try_from(time: DateTime) -> Result<chrono::DateTime<chrono::Utc>> {
+        use chrono::TimeZone;
+
+        let naive = chrono::NaiveDate::from_ymd_opt(
+            i32::from(time.year()),
+            u32::from(time.month()),
+            u32::from(time.day()),
         )
+        .and_then(|date| {
+            date.and_hms_nano_opt(
+                u32::from(time.hour()),
+                u32::from(time.minutes()),
+                u32::from(time.seconds()),
+                time.nanoseconds(),
+            )
+        })
+        .ok_or(ErrorKind::DateTime)?;
+
+        Ok(chrono::Utc.from_utc_datetime(&naive))
+    }
+}
+
+/// Converts the given [`chrono::DateTime`] to UTC.
+///
+/// Leap seconds are rejected as they can't be represented by [`DateTime`].
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> TryFrom<chrono::DateTime<Tz>> for DateTime {
+    type Error = Error;
+
+    fn try_from(time: chrono::DateTime<Tz>) -> Result<DateTime> {
+        use chrono::{Datelike, Timelike};
+
+        let time = time.naive_utc();
+
+        DateTime::new(
+            time.year().try_into().map_err(|_| ErrorKind::DateTime)?,
+            time.month().try_into()?,
+            time.day().try_into()?,
+            time.hour().try_into()?,
+            time.minute().try_into()?,
+            time.second().try_into()?,
+        )?
+        .with_nanoseconds(time.nanosecond())
     }
 }
 
@@ -400,6 +545,87 @@ where
     writer.write_byte(b'0'.checked_add(value % 10).ok_or(ErrorKind::Overflow)?)
 }
 
+/// Decode fractional seconds with up to nanosecond precision (i.e. the digits
+/// following the decimal point) into nanoseconds.
+// TODO(tarcieri): checked arithmetic
+#[allow(clippy::integer_arithmetic)]
+pub(crate) fn decode_nanoseconds(tag: Tag, digits: &[u8]) -> Result<u32> {
+    if digits.is_empty() || digits.len() > MAX_FRACTION_DIGITS {
+        return Err(tag.value_error());
+    }
+
+    let mut nanoseconds = 0u32;
+
+    for i in 0..MAX_FRACTION_DIGITS {
+        let digit = match digits.get(i) {
+            Some(&byte) if byte.is_ascii_digit() => byte - b'0',
+            Some(_) => return Err(tag.value_error()),
+            None => 0,
+        };
+
+        nanoseconds = nanoseconds * 10 + u32::from(digit);
+    }
+
+    Ok(nanoseconds)
+}
+
+/// Encode fractional seconds as a decimal point followed by the significant
+/// digits of `nanoseconds`, omitting trailing zeroes.
+///
+/// Writes nothing if `nanoseconds` is zero.
+// TODO(tarcieri): checked arithmetic
+#[allow(clippy::integer_arithmetic)]
+pub(crate) fn encode_nanoseconds<W>(writer: &mut W, tag: Tag, nanoseconds: u32) -> Result<()>
+where
+    W: Writer + ?Sized,
+{
+    if nanoseconds == 0 {
+        return Ok(());
+    }
+
+    if nanoseconds >= NANOS_PER_SEC {
+        return Err(tag.value_error());
+    }
+
+    let (fraction, width) = trim_nanoseconds(nanoseconds);
+    let mut digits = [0u8; MAX_FRACTION_DIGITS];
+    let mut value = fraction;
+
+    for digit in digits[..width].iter_mut().rev() {
+        *digit = b'0' + u8::try_from(value % 10)?;
+        value /= 10;
+    }
+
+    writer.write_byte(b'.')?;
+    writer.write(&digits[..width])
+}
+
+/// Length of fractional seconds as encoded by [`encode_nanoseconds`],
+/// including the decimal point.
+pub(crate) fn nanoseconds_len(nanoseconds: u32) -> usize {
+    if nanoseconds == 0 {
+        0
+    } else {
+        trim_nanoseconds(nanoseconds).1.saturating_add(1)
+    }
+}
+
+/// Strip trailing zeroes from `nanoseconds`, returning the remaining value and
+/// its number of digits.
+// TODO(tarcieri): checked arithmetic
+#[allow(clippy::integer_arithmetic)]
+fn trim_nanoseconds(nanoseconds: u32) -> (u32, usize) {
+    let mut fraction = nanoseconds;
+    let mut width = MAX_FRACTION_DIGITS;
+
+    while width > 1 && fraction % 10 == 0 {
+        fraction /= 10;
+        width -= 1;
+    }
+
+    (fraction, width)
+}
+
 /// Decode 4-digit year.
 // TODO(tarcieri): checked arithmetic
 #[allow(clippy::integer_arithmetic)]
@@ -413,6 +639,7 @@ fn decode_year(year: &[u8; 4]) -> Result<u16> {
 #[cfg(test)]
 mod tests {
     use super::DateTime;
+    use core::time::Duration;
 
     /// Ensure a day is OK
     fn is_date_valid(year: u16, month: u8, day: u8, hour: u8, minute: u8, second: u8) -> bool {
@@ -435,6 +662,44 @@ mod tests {
         assert_eq!(datetime.hour(), 12);
         assert_eq!(datetime.minutes(), 13);
         assert_eq!(datetime.seconds(), 14);
+        assert_eq!(datetime.nanoseconds(), 0);
+    }
+
+    #[test]
+    fn from_str_fractional() {
+        let datetime = "2001-01-02T12:13:14.0625Z".parse::<DateTime>().unwrap();
+        assert_eq!(datetime.seconds(), 14);
+        assert_eq!(datetime.nanoseconds(), 62_500_000);
+        assert_eq!(datetime.unix_duration().subsec_nanos(), 62_500_000);
+
+        assert!("2001-01-02T12:13:14.Z".parse::<DateTime>().is_err());
+        assert!("2001-01-02T12:13:14.0123456789Z"
+            .parse::<DateTime>()
+            .is_err());
+    }
+
+    #[test]
+    fn with_nanoseconds() {
+        let datetime = DateTime::new(2001, 1, 2, 12, 13, 14).unwrap();
+        let precise = datetime.with_nanoseconds(999_999_999).unwrap();
+        assert_eq!(precise.nanoseconds(), 999_999_999);
+        assert_eq!(
+            precise.unix_duration(),
+            Duration::new(datetime.unix_duration().as_secs(), 999_999_999)
+        );
+        assert!(datetime.with_nanoseconds(1_000_000_000).is_err());
+        assert!(DateTime::INFINITY.with_nanoseconds(1).is_err());
+        assert_eq!(precise.truncate_subsec(), datetime);
+        assert!(datetime < precise);
+    }
+
+    #[test]
+    fn from_unix_duration_preserves_nanoseconds() {
+        let duration = Duration::new(673_573_540, 123_000_000);
+        let datetime = DateTime::from_unix_duration(duration).unwrap();
+        assert_eq!(datetime.seconds(), 40);
+        assert_eq!(datetime.nanoseconds(), 123_000_000);
+        assert_eq!(datetime.unix_duration(), duration);
     }
 
     #[cfg(feature = "alloc")]
@@ -443,5 +708,66 @@ mod tests {
         use alloc::string::ToString;
         let datetime = DateTime::new(2001, 01, 02, 12, 13, 14).unwrap();
         assert_eq!(&datetime.to_string(), "2001-01-02T12:13:14Z");
+
+        let datetime = datetime.with_nanoseconds(50_000_000).unwrap();
+        assert_eq!(&datetime.to_string(), "2001-01-02T12:13:14.05Z");
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn offset_date_time_conversions() {
+        use time::{OffsetDateTime, UtcOffset};
+
+        let datetime = DateTime::new(2001, 1, 2, 12, 13, 14)
+            .unwrap()
+            .with_nanoseconds(123_456_789)
+            .unwrap();
+
+        let time = OffsetDateTime::try_from(datetime).unwrap();
+        assert_eq!(time.unix_timestamp(), 978_437_594);
+        assert_eq!(time.nanosecond(), 123_456_789);
+        assert_eq!(DateTime::try_from(time).unwrap(), datetime);
+
+        // Offsets are normalized to UTC
+        let offset = UtcOffset::from_hms(-5, 0, 0).unwrap();
+        assert_eq!(
+            DateTime::try_from(time.to_offset(offset)).unwrap(),
+            datetime
+        );
+
+        assert!(DateTime::try_from(OffsetDateTime::UNIX_EPOCH - time::Duration::SECOND).is_err());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_conversions() {
+        use chrono::{FixedOffset, NaiveDate, TimeZone, Utc};
+
+        let datetime = DateTime::new(2001, 1, 2, 12, 13, 14)
+            .unwrap()
+            .with_nanoseconds(123_456_789)
+            .unwrap();
+
+        let time = chrono::DateTime::<Utc>::try_from(datetime).unwrap();
+        assert_eq!(time.timestamp(), 978_437_594);
+        assert_eq!(time.timestamp_subsec_nanos(), 123_456_789);
+        assert_eq!(DateTime::try_from(time).unwrap(), datetime);
+
+        // Offsets are normalized to UTC
+        let offset = FixedOffset::west_opt(5 * 3600).unwrap();
+        assert_eq!(
+            DateTime::try_from(time.with_timezone(&offset)).unwrap(),
+            datetime
+        );
+
+        assert!(
+            DateTime::try_from(Utc.with_ymd_and_hms(1969, 12, 31, 23, 59, 59).unwrap()).is_err()
+        );
+
+        // Leap seconds can't be represented
+        let leap_second = NaiveDate::from_ymd_opt(2016, 12, 31)
+            .and_then(|date| date.and_hms_nano_opt(23, 59, 59, 1_500_000_000))
+            .unwrap();
+        assert!(DateTime::try_from(Utc.from_utc_datetime(&leap_second)).is_err());
     }
 }
@@ -26,6 +26,14 @@ pub trait Decode<'a>: Sized {
         let result = Self::decode(&mut reader)?;
         reader.finish(result)
     }
+
+    /// Parse `Self` from the provided DER-encoded byte slice, permitting at
+    /// most `max_depth` levels of nested constructed values.
+    fn from_der_with_max_depth(bytes: &'a [u8], max_depth: usize) -> Result<Self> {
+        let mut reader = SliceReader::new(bytes)?.with_max_depth(max_depth);
+        let result = Self::decode(&mut reader)?;
+        reader.finish(result)
+    }
 }
 
 impl<'a, T> Decode<'a> for T
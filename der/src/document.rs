@@ -14,7 +14,10 @@ use std::{fs, path::Path};
 use alloc::borrow::ToOwned;
 
 #[cfg(feature = "zeroize")]
-use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
+use {
+    core::mem,
+    zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing},
+};
 
 /// ASN.1 DER-encoded document.
 ///
@@ -75,6 +78,37 @@ impl Document {
         msg.to_der()?.try_into()
     }
 
+    /// Decode a series of concatenated ASN.1 DER documents, such as a bundle
+    /// of certificates or CRLs stored back-to-back.
+    ///
+    /// Each document must be a DER-encoded `SEQUENCE`. Empty input results in
+    /// an empty [`Vec`].
+    pub fn from_der_multi(bytes: &[u8]) -> Result<Vec<Self>> {
+        let mut reader = SliceReader::new(bytes)?;
+        let mut docs = Vec::new();
+
+        while !reader.is_finished() {
+            let der_bytes = decode_sequence(&mut reader)?;
+
+            docs.push(Self {
+                der_bytes: der_bytes.into(),
+                length: der_bytes.len().try_into()?,
+            });
+        }
+
+        Ok(docs)
+    }
+
+    /// Concatenate the ASN.1 DER encodings of the given documents.
+    ///
+    /// This is the inverse of [`Document::from_der_multi`].
+    pub fn to_der_multi(docs: &[Self]) -> Vec<u8> {
+        docs.iter()
+            .flat_map(|doc| doc.as_bytes())
+            .copied()
+            .collect()
+    }
+
     /// Decode ASN.1 DER document from PEM.
     ///
     /// Returns the PEM label and decoded [`Document`] on success.
@@ -91,6 +125,40 @@ impl Document {
         Ok(pem::encode_string(label, line_ending, self.as_bytes())?)
     }
 
+    /// Decode a series of concatenated PEM-encoded ASN.1 DER documents, such
+    /// as a trust store containing several `CERTIFICATE` blocks.
+    ///
+    /// Returns the PEM label and decoded [`Document`] for each block on
+    /// success. Lines are expected to be terminated by either LF or CRLF.
+    #[cfg(feature = "pem")]
+    pub fn from_pem_multi(pem: &str) -> Result<Vec<(&str, Self)>> {
+        let mut remaining = pem;
+        let mut docs = Vec::new();
+
+        while !remaining.trim().is_empty() {
+            let (block, rest) = split_pem_block(remaining);
+            docs.push(Self::from_pem(block)?);
+            remaining = rest;
+        }
+
+        Ok(docs)
+    }
+
+    /// Encode a series of labeled ASN.1 DER documents as concatenated PEM
+    /// blocks.
+    ///
+    /// This is the inverse of [`Document::from_pem_multi`].
+    #[cfg(feature = "pem")]
+    pub fn to_pem_multi(docs: &[(&str, Self)], line_ending: pem::LineEnding) -> Result<String> {
+        let mut out = String::new();
+
+        for (label, doc) in docs {
+            out.push_str(&pem::encode_string(label, line_ending, doc.as_bytes())?);
+        }
+
+        Ok(out)
+    }
+
     /// Read ASN.1 DER document from a file.
     #[cfg(feature = "std")]
     pub fn read_der_file(path: impl AsRef<Path>) -> Result<Self> {
@@ -143,7 +211,7 @@ impl Debug for Document {
 impl<'a> Decode<'a> for Document {
     fn decode<R: Reader<'a>>(reader: &mut R) -> Result<Document> {
         let header = reader.peek_header()?;
-        let length = (header.encoded_len()? + header.length)?;
+        let length = header.tlv_len()?;
         let bytes = reader.read_slice(length)?;
 
         Ok(Self {
@@ -179,11 +247,7 @@ impl TryFrom<Vec<u8>> for Document {
     type Error = Error;
 
     fn try_from(der_bytes: Vec<u8>) -> Result<Self> {
-        let mut decoder = SliceReader::new(&der_bytes)?;
-        decode_sequence(&mut decoder)?;
-        decoder.finish(())?;
-
-        let length = der_bytes.len().try_into()?;
+        let length = document_len(&der_bytes)?;
         Ok(Self { der_bytes, length })
     }
 }
@@ -194,6 +258,7 @@ impl TryFrom<Vec<u8>> for Document {
 /// cryptographic keys.
 ///
 /// This type provides additional hardening such as ensuring that the contents
+/// (along with any intermediate buffers used when reading or decoding them)
 /// are zeroized-on-drop, and also using more restrictive file permissions when
 /// writing files to disk.
 #[cfg(feature = "zeroize")]
@@ -230,7 +295,14 @@ impl SecretDocument {
     /// Decode ASN.1 DER document from PEM.
     #[cfg(feature = "pem")]
     pub fn from_pem(pem: &str) -> Result<(&str, Self)> {
-        Document::from_pem(pem).map(|(label, doc)| (label, Self(doc)))
+        let mut decoder = pem::Decoder::new(pem.as_bytes())?;
+        let label = decoder.type_label();
+
+        // Zeroize partially decoded contents if an error occurs
+        let mut der_bytes = Zeroizing::new(Vec::new());
+        decoder.decode_to_end(&mut der_bytes)?;
+
+        Ok((label, mem::take(&mut *der_bytes).try_into()?))
     }
 
     /// Encode ASN.1 DER document as a PEM string.
@@ -246,7 +318,7 @@ impl SecretDocument {
     /// Read ASN.1 DER document from a file.
     #[cfg(feature = "std")]
     pub fn read_der_file(path: impl AsRef<Path>) -> Result<Self> {
-        Document::read_der_file(path).map(Self)
+        fs::read(path)?.try_into()
     }
 
     /// Write ASN.1 DER document to a file.
@@ -258,7 +330,8 @@ impl SecretDocument {
     /// Read PEM-encoded ASN.1 DER document from a file.
     #[cfg(all(feature = "pem", feature = "std"))]
     pub fn read_pem_file(path: impl AsRef<Path>) -> Result<(String, Self)> {
-        Document::read_pem_file(path).map(|(label, doc)| (label, Self(doc)))
+        let pem = Zeroizing::new(fs::read_to_string(path)?);
+        Self::from_pem(&pem).map(|(label, doc)| (label.to_owned(), doc))
     }
 
     /// Write PEM-encoded ASN.1 DER document to a file.
@@ -306,22 +379,56 @@ impl TryFrom<&[u8]> for SecretDocument {
 impl TryFrom<Vec<u8>> for SecretDocument {
     type Error = Error;
 
-    fn try_from(der_bytes: Vec<u8>) -> Result<Self> {
-        Document::try_from(der_bytes).map(Self)
+    fn try_from(mut der_bytes: Vec<u8>) -> Result<Self> {
+        match document_len(&der_bytes) {
+            Ok(length) => Ok(Self(Document { der_bytes, length })),
+            Err(err) => {
+                der_bytes.zeroize();
+                Err(err)
+            }
+        }
     }
 }
 
 #[cfg(feature = "zeroize")]
 impl ZeroizeOnDrop for SecretDocument {}
 
+/// Check that the given bytes contain a single ASN.1 `SEQUENCE`, returning
+/// their length.
+fn document_len(der_bytes: &[u8]) -> Result<Length> {
+    let mut decoder = SliceReader::new(der_bytes)?;
+    decode_sequence(&mut decoder)?;
+    decoder.finish(())?;
+    der_bytes.len().try_into()
+}
+
 /// Attempt to decode a ASN.1 `SEQUENCE` from the given decoder, returning the
 /// entire sequence including the header.
 fn decode_sequence<'a>(decoder: &mut SliceReader<'a>) -> Result<&'a [u8]> {
     let header = decoder.peek_header()?;
     header.tag.assert_eq(Tag::Sequence)?;
 
-    let len = (header.encoded_len()? + header.length)?;
-    decoder.read_slice(len)
+    decoder.read_slice(header.tlv_len()?)
+}
+
+/// Split the first PEM block off of the given input, returning the block
+/// (including any preceding text) and the remaining input.
+///
+/// The block ends at the end of the line containing the first
+/// post-encapsulation boundary. If no such boundary is present, the entire
+/// input is returned as the block so the PEM decoder can report an error.
+#[cfg(feature = "pem")]
+#[allow(clippy::integer_arithmetic)]
+fn split_pem_block(pem: &str) -> (&str, &str) {
+    /// The post-encapsulation boundary which appears after PEM encapsulated text.
+    const POST_ENCAPSULATION_BOUNDARY: &str = "-----END ";
+
+    let block_len = pem
+        .find(POST_ENCAPSULATION_BOUNDARY)
+        .and_then(|pos| pem[pos..].find('\n').map(|eol| pos + eol + 1))
+        .unwrap_or(pem.len());
+
+    pem.split_at(block_len)
 }
 
 /// Write a file containing secret data to the filesystem, restricting the
@@ -352,3 +459,103 @@ fn write_secret_file(path: impl AsRef<Path>, data: &[u8]) -> Result<()> {
     fs::write(path, data)?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Document;
+
+    /// Example SPKI document encoded as DER.
+    const SPKI_DER: &[u8] = include_bytes!("../tests/examples/spki.der");
+
+    /// Example SPKI document encoded as PEM.
+    #[cfg(feature = "pem")]
+    const SPKI_PEM: &str = include_str!("../tests/examples/spki.pem");
+
+    #[test]
+    fn der_multi_round_trip() {
+        let mut bundle = SPKI_DER.to_vec();
+        bundle.extend_from_slice(&hex_literal::hex!("30 03 02 01 01"));
+
+        let docs = Document::from_der_multi(&bundle).unwrap();
+        assert_eq!(docs.len(), 2);
+        assert_eq!(docs[0].as_bytes(), SPKI_DER);
+        assert_eq!(docs[1].as_bytes(), &[0x30, 0x03, 0x02, 0x01, 0x01]);
+        assert_eq!(Document::to_der_multi(&docs), bundle);
+    }
+
+    #[test]
+    fn der_multi_empty() {
+        assert_eq!(Document::from_der_multi(&[]).unwrap(), []);
+    }
+
+    #[test]
+    fn der_multi_rejects_trailing_garbage() {
+        let mut bundle = SPKI_DER.to_vec();
+        bundle.extend_from_slice(&[0x04, 0x01, 0xFF]);
+        assert!(Document::from_der_multi(&bundle).is_err());
+
+        let truncated = &SPKI_DER[..SPKI_DER.len() - 1];
+        assert!(Document::from_der_multi(truncated).is_err());
+    }
+
+    #[cfg(feature = "pem")]
+    #[test]
+    fn pem_multi_round_trip() {
+        use crate::pem::LineEnding;
+
+        let bundle = [SPKI_PEM, SPKI_PEM].concat();
+        let docs = Document::from_pem_multi(&bundle).unwrap();
+        assert_eq!(docs.len(), 2);
+
+        for (label, doc) in &docs {
+            assert_eq!(*label, "PUBLIC KEY");
+            assert_eq!(doc.as_bytes(), SPKI_DER);
+        }
+
+        assert_eq!(
+            Document::to_pem_multi(&docs, LineEnding::LF).unwrap(),
+            bundle
+        );
+    }
+
+    #[cfg(feature = "pem")]
+    #[test]
+    fn pem_multi_with_surrounding_text() {
+        let bundle = format!(
+            "# first\n{}\n# second\r\n{}\n\n",
+            SPKI_PEM,
+            SPKI_PEM.replace('\n', "\r\n")
+        );
+        let docs = Document::from_pem_multi(&bundle).unwrap();
+        assert_eq!(docs.len(), 2);
+        assert!(docs.iter().all(|(_, doc)| doc.as_bytes() == SPKI_DER));
+    }
+
+    #[cfg(feature = "pem")]
+    #[test]
+    fn pem_multi_rejects_trailing_garbage() {
+        let bundle = format!("{}garbage", SPKI_PEM);
+        assert!(Document::from_pem_multi(&bundle).is_err());
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn secret_document_try_from_vec() {
+        use super::SecretDocument;
+
+        let doc = SecretDocument::try_from(SPKI_DER.to_vec()).unwrap();
+        assert_eq!(doc.as_bytes(), SPKI_DER);
+        assert!(SecretDocument::try_from(SPKI_DER[1..].to_vec()).is_err());
+    }
+
+    #[cfg(all(feature = "pem", feature = "zeroize"))]
+    #[test]
+    fn secret_document_from_pem() {
+        use super::SecretDocument;
+
+        let (label, doc) = SecretDocument::from_pem(SPKI_PEM).unwrap();
+        assert_eq!(label, "PUBLIC KEY");
+        assert_eq!(doc.as_bytes(), SPKI_DER);
+        assert!(SecretDocument::from_pem(&SPKI_PEM[1..]).is_err());
+    }
+}
@@ -0,0 +1,442 @@
+//! Human-readable dumps of the structure of DER-encoded data.
+//!
+//! This is intended as a debugging aid similar to `openssl asn1parse`, e.g.
+//! for inspecting interoperability failures or reporting them in tests.
+//!
+//! BER-encoded input (i.e. indefinite lengths and constructed strings) is
+//! also accepted so that inputs which can't be decoded as DER can still be
+//! inspected.
+
+use crate::{
+    reader::{ber::decode_length, DEFAULT_MAX_DEPTH},
+    tag::constructed_string_tag,
+    ErrorKind, Length, Reader, Result, SliceReader, Tag,
+};
+use alloc::string::String;
+use core::fmt::{self, Write};
+
+/// Number of spaces to indent each level of nesting by.
+const INDENT: usize = 2;
+
+/// Render the structure of the given DER (or BER) encoded input as an
+/// indented textual tree.
+///
+/// Each TLV production is printed on its own line, starting with its offset
+/// in the input, followed by its tag and length and, for primitive types, its
+/// decoded value:
+///
+/// ```
+/// # #[cfg(feature = "dump")]
+/// # {
+/// use hex_literal::hex;
+///
+/// // AlgorithmIdentifier for `id-ecPublicKey` with `secp256r1` parameters
+/// let der = hex!("30 13 06 07 2a 86 48 ce 3d 02 01 06 08 2a 86 48 ce 3d 03 01 07");
+/// let dump = der::dump::dump(&der).unwrap();
+///
+/// assert!(dump.starts_with("    0: SEQUENCE (19 bytes)\n"));
+/// # }
+/// ```
+///
+/// When the `oid` feature is enabled, `OBJECT IDENTIFIER` values are
+/// annotated with their names from the `const-oid` database.
+///
+/// `OCTET STRING` and `BIT STRING` values which contain a DER-encoded
+/// `SEQUENCE` or `SET` (e.g. X.509 extension values and public keys) are
+/// dumped recursively.
+pub fn dump(input: &[u8]) -> Result<String> {
+    let mut dumper = Dumper::default();
+    dumper.dump_all(&mut SliceReader::new(input)?, Length::ZERO, 0)?;
+    Ok(dumper.out)
+}
+
+/// Dump output-in-progress.
+#[derive(Default)]
+struct Dumper {
+    /// Rendered output.
+    out: String,
+}
+
+impl Dumper {
+    /// Dump all of the TLV productions remaining in `reader`.
+    ///
+    /// Offsets are rendered relative to `base`, i.e. the offset of `reader`
+    /// within the original input.
+    fn dump_all(&mut self, reader: &mut SliceReader<'_>, base: Length, depth: usize) -> Result<()> {
+        while !reader.is_finished() {
+            self.dump_tlv(reader, base, depth)?;
+        }
+
+        Ok(())
+    }
+
+    /// Dump a single TLV production, recursing into constructed values.
+    fn dump_tlv(&mut self, reader: &mut SliceReader<'_>, base: Length, depth: usize) -> Result<()> {
+        if depth > DEFAULT_MAX_DEPTH {
+            return Err(reader.error(ErrorKind::NestingLimitExceeded));
+        }
+
+        let offset = (base + reader.position())?;
+        let octet = reader.read_byte()?;
+
+        let (tag, constructed) = match constructed_string_tag(octet) {
+            Some(tag) => (tag, true),
+            None => {
+                let tag = Tag::try_from(octet).map_err(|e| reader.error(e.kind()))?;
+                (tag, tag.is_constructed())
+            }
+        };
+
+        let length = decode_length(reader)?;
+        self.write_fmt(format_args!(
+            "{:>5}: {:indent$}{}",
+            offset,
+            "",
+            tag,
+            indent = depth.saturating_mul(INDENT)
+        ))?;
+
+        if constructed && !tag.is_constructed() {
+            self.write_str(" (constructed)")?;
+        }
+
+        match length {
+            Some(length) if length == Length::ONE => self.write_str(" (1 byte)")?,
+            Some(length) => self.write_fmt(format_args!(" ({} bytes)", length))?,
+            None => self.write_str(" (indefinite length)")?,
+        }
+
+        let depth = depth.saturating_add(1);
+
+        if constructed {
+            self.write_str("\n")?;
+
+            return match length {
+                Some(length) => {
+                    let nested_offset = reader.position();
+                    let mut nested = SliceReader::new(reader.read_slice(length)?)?;
+                    self.dump_all(&mut nested, (base + nested_offset)?, depth)
+                        .map_err(|e| e.nested(nested_offset))
+                }
+                None => loop {
+                    if reader.peek_byte() == Some(0) {
+                        let mut end_of_contents = [0u8; 2];
+                        reader.read_into(&mut end_of_contents)?;
+
+                        return if end_of_contents == [0, 0] {
+                            Ok(())
+                        } else {
+                            Err(reader.error(ErrorKind::TagUnknown { byte: 0 }))
+                        };
+                    }
+
+                    self.dump_tlv(reader, base, depth)?;
+                },
+            };
+        }
+
+        let length = length.ok_or_else(|| reader.error(ErrorKind::IndefiniteLength))?;
+        let value_offset = reader.position();
+        let value = reader.read_slice(length)?;
+
+        if let Some(encapsulated) = encapsulated(tag, value) {
+            let nested_offset = (value_offset + length)?;
+            let nested_offset = (nested_offset - Length::try_from(encapsulated.len())?)?;
+            let mut nested = Dumper::default();
+
+            if nested
+                .dump_all(
+                    &mut SliceReader::new(encapsulated)?,
+                    (base + nested_offset)?,
+                    depth,
+                )
+                .is_ok()
+            {
+                if tag == Tag::BitString {
+                    self.write_str(": 0 unused bits")?;
+                }
+
+                self.write_str(", encapsulates:\n")?;
+                self.write_str(&nested.out)?;
+                return Ok(());
+            }
+        }
+
+        if !value.is_empty() {
+            self.write_str(": ")?;
+            self.write_value(tag, value)?;
+        }
+
+        self.write_str("\n")
+    }
+
+    /// Write a human-readable rendering of the value of a primitive type.
+    fn write_value(&mut self, tag: Tag, value: &[u8]) -> Result<()> {
+        match tag {
+            Tag::Boolean => match value {
+                [0x00] => self.write_str("FALSE"),
+                [0xFF] => self.write_str("TRUE"),
+                _ => self.write_hex(value),
+            },
+            Tag::Integer | Tag::Enumerated => match decode_i128(value) {
+                Some(n) => self.write_fmt(format_args!("{}", n)),
+                None => self.write_hex(value),
+            },
+            #[cfg(feature = "oid")]
+            Tag::ObjectIdentifier => match crate::asn1::ObjectIdentifier::from_bytes(value) {
+                Ok(oid) => {
+                    self.write_fmt(format_args!("{}", oid))?;
+
+                    match const_oid::db::DB.by_oid(&oid) {
+                        Some(name) => self.write_fmt(format_args!(" ({})", name)),
+                        None => Ok(()),
+                    }
+                }
+                Err(_) => self.write_hex(value),
+            },
+            #[cfg(feature = "oid")]
+            Tag::RelativeOid => match crate::asn1::RelativeOid::from_bytes(value) {
+                Ok(oid) => self.write_fmt(format_args!("{}", oid)),
+                Err(_) => self.write_hex(value),
+            },
+            Tag::BitString => match value.split_first() {
+                Some((unused_bits, bits)) => {
+                    self.write_fmt(format_args!("{} unused bits", unused_bits))?;
+
+                    if bits.is_empty() {
+                        Ok(())
+                    } else {
+                        self.write_str(", ")?;
+                        self.write_hex(bits)
+                    }
+                }
+                None => Ok(()),
+            },
+            Tag::Utf8String
+            | Tag::NumericString
+            | Tag::PrintableString
+            | Tag::TeletexString
+            | Tag::VideotexString
+            | Tag::Ia5String
+            | Tag::GraphicString
+            | Tag::VisibleString
+            | Tag::GeneralString
+            | Tag::UtcTime
+            | Tag::GeneralizedTime
+            | Tag::Time => match core::str::from_utf8(value) {
+                Ok(s) => self.write_fmt(format_args!("{:?}", s)),
+                Err(_) => self.write_hex(value),
+            },
+            Tag::BmpString => match decode_bmp_string(value) {
+                Some(s) => self.write_fmt(format_args!("{:?}", s)),
+                None => self.write_hex(value),
+            },
+            _ => self.write_hex(value),
+        }
+    }
+
+    /// Write the given bytes as space-separated uppercase hex.
+    fn write_hex(&mut self, bytes: &[u8]) -> Result<()> {
+        for (i, byte) in bytes.iter().enumerate() {
+            if i > 0 {
+                self.write_str(" ")?;
+            }
+
+            self.write_fmt(format_args!("{:02X}", byte))?;
+        }
+
+        Ok(())
+    }
+
+    /// Append a string to the output.
+    fn write_str(&mut self, s: &str) -> Result<()> {
+        self.out.push_str(s);
+        Ok(())
+    }
+
+    /// Append formatted arguments to the output.
+    fn write_fmt(&mut self, args: fmt::Arguments<'_>) -> Result<()> {
+        self.out
+            .write_fmt(args)
+            .map_err(|_| ErrorKind::Failed.into())
+    }
+}
+
+/// Get the DER-encoded `SEQUENCE` or `SET` contained within the value of an
+/// `OCTET STRING` or `BIT STRING` (with no unused bits), if it looks like
+/// there is one.
+fn encapsulated(tag: Tag, value: &[u8]) -> Option<&[u8]> {
+    let contents = match tag {
+        Tag::OctetString => value,
+        Tag::BitString => match value.split_first() {
+            Some((0, bits)) => bits,
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    match contents.first() {
+        Some(&octet) if octet == Tag::Sequence.octet() || octet == Tag::Set.octet() => {
+            Some(contents)
+        }
+        _ => None,
+    }
+}
+
+/// Decode a big endian two's complement integer which fits in an `i128`.
+fn decode_i128(bytes: &[u8]) -> Option<i128> {
+    let (&first, _) = bytes.split_first()?;
+
+    if bytes.len() > 16 {
+        return None;
+    }
+
+    let mut buf = [if first & 0x80 == 0 { 0 } else { 0xFF }; 16];
+    buf.get_mut(16usize.checked_sub(bytes.len())?..)?
+        .copy_from_slice(bytes);
+    Some(i128::from_be_bytes(buf))
+}
+
+/// Decode the UCS-2 (i.e. big endian UTF-16) contents of a `BMPString`.
+fn decode_bmp_string(bytes: &[u8]) -> Option<String> {
+    if bytes.len() % 2 != 0 {
+        return None;
+    }
+
+    let units = bytes
+        .chunks_exact(2)
+        .map(|pair| u16::from_be_bytes([pair[0], pair[1]]));
+
+    char::decode_utf16(units)
+        .collect::<core::result::Result<_, _>>()
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::dump;
+    use crate::{ErrorKind, Length};
+    use alloc::vec::Vec;
+    use hex_literal::hex;
+
+    #[test]
+    fn nested_sequence() {
+        let der = hex!("30 0B 02 01 05 01 01 FF 30 03 02 01 80");
+        assert_eq!(
+            dump(&der).unwrap(),
+            "    0: SEQUENCE (11 bytes)\n\
+             \x20   2:   INTEGER (1 byte): 5\n\
+             \x20   5:   BOOLEAN (1 byte): TRUE\n\
+             \x20   8:   SEQUENCE (3 bytes)\n\
+             \x20  10:     INTEGER (1 byte): -128\n"
+        );
+    }
+
+    #[test]
+    fn strings_and_times() {
+        let der = hex!(
+            "30 1E"
+            "0C 05 68 65 6C 6C 6F"
+            "17 0D 39 31 30 35 30 36 32 33 34 35 34 30 5A"
+            "1E 04 00 68 00 69"
+            "05 00"
+        );
+        assert_eq!(
+            dump(&der).unwrap(),
+            "    0: SEQUENCE (30 bytes)\n\
+             \x20   2:   UTF8String (5 bytes): \"hello\"\n\
+             \x20   9:   UTCTime (13 bytes): \"910506234540Z\"\n\
+             \x20  24:   BMPString (4 bytes): \"hi\"\n\
+             \x20  30:   NULL (0 bytes)\n"
+        );
+    }
+
+    #[test]
+    fn context_specific_and_hex() {
+        let der = hex!("A0 06 04 04 DE AD BE EF");
+        assert_eq!(
+            dump(&der).unwrap(),
+            "    0: CONTEXT-SPECIFIC [0] (constructed) (6 bytes)\n\
+             \x20   2:   OCTET STRING (4 bytes): DE AD BE EF\n"
+        );
+    }
+
+    #[test]
+    fn encapsulated_values() {
+        let der = hex!("30 0B 04 04 30 02 05 00 03 03 00 30 00");
+        assert_eq!(
+            dump(&der).unwrap(),
+            "    0: SEQUENCE (11 bytes)\n\
+             \x20   2:   OCTET STRING (4 bytes), encapsulates:\n\
+             \x20   4:     SEQUENCE (2 bytes)\n\
+             \x20   6:       NULL (0 bytes)\n\
+             \x20   8:   BIT STRING (3 bytes): 0 unused bits, encapsulates:\n\
+             \x20  11:     SEQUENCE (0 bytes)\n"
+        );
+    }
+
+    #[test]
+    fn malformed_encapsulated_value_is_hex() {
+        let der = hex!("04 03 30 05 00");
+        assert_eq!(
+            dump(&der).unwrap(),
+            "    0: OCTET STRING (3 bytes): 30 05 00\n"
+        );
+    }
+
+    #[test]
+    fn indefinite_length() {
+        let ber = hex!("30 80 24 80 04 01 AA 00 00 02 01 01 00 00");
+        assert_eq!(
+            dump(&ber).unwrap(),
+            "    0: SEQUENCE (indefinite length)\n\
+             \x20   2:   OCTET STRING (constructed) (indefinite length)\n\
+             \x20   4:     OCTET STRING (1 byte): AA\n\
+             \x20   9:   INTEGER (1 byte): 1\n"
+        );
+    }
+
+    #[cfg(feature = "oid")]
+    #[test]
+    fn oid_names() {
+        let der = hex!("30 13 06 07 2a 86 48 ce 3d 02 01 06 08 2a 86 48 ce 3d 03 01 07");
+        assert_eq!(
+            dump(&der).unwrap(),
+            "    0: SEQUENCE (19 bytes)\n\
+             \x20   2:   OBJECT IDENTIFIER (7 bytes): 1.2.840.10045.2.1 (id-ecPublicKey)\n\
+             \x20  11:   OBJECT IDENTIFIER (8 bytes): 1.2.840.10045.3.1.7 (secp256r1)\n"
+        );
+    }
+
+    #[cfg(feature = "oid")]
+    #[test]
+    fn relative_oid() {
+        let der = hex!("0D 04 82 37 15 14");
+        assert_eq!(
+            dump(&der).unwrap(),
+            "    0: RELATIVE-OID (4 bytes): 311.21.20\n"
+        );
+    }
+
+    #[test]
+    fn nesting_limit_exceeded() {
+        let mut ber = Vec::new();
+        for _ in 0..100 {
+            ber.extend_from_slice(&[0x30, 0x80]);
+        }
+        for _ in 0..100 {
+            ber.extend_from_slice(&[0x00, 0x00]);
+        }
+
+        let err = dump(&ber).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NestingLimitExceeded);
+    }
+
+    #[test]
+    fn truncated_input() {
+        let der = hex!("30 05 02 03 01 00");
+        let err = dump(&der).unwrap_err();
+        assert_eq!(err.position(), Some(Length::new(2)));
+        assert!(matches!(err.kind(), ErrorKind::Incomplete { .. }));
+    }
+}
@@ -22,14 +22,18 @@ pub struct Error {
 
     /// Position inside of message where error occurred.
     position: Option<Length>,
+
+    /// Path to the field or element being decoded when the error occurred.
+    #[cfg(feature = "error-path")]
+    path: ErrorPath,
 }
 
 impl Error {
     /// Create a new [`Error`].
     pub fn new(kind: ErrorKind, position: Length) -> Error {
         Error {
-            kind,
             position: Some(position),
+            ..kind.into()
         }
     }
 
@@ -47,6 +51,16 @@ impl Error {
         }
     }
 
+    /// Create a new [`Error`] without a position (const-friendly).
+    pub(crate) const fn from_kind(kind: ErrorKind) -> Self {
+        Error {
+            kind,
+            position: None,
+            #[cfg(feature = "error-path")]
+            path: ErrorPath::EMPTY,
+        }
+    }
+
     /// Get the [`ErrorKind`] which occurred.
     pub fn kind(self) -> ErrorKind {
         self.kind
@@ -57,16 +71,41 @@ impl Error {
         self.position
     }
 
+    /// Get the path to the field or element which was being decoded when the
+    /// error occurred.
+    #[cfg(feature = "error-path")]
+    pub fn path(&self) -> &ErrorPath {
+        &self.path
+    }
+
+    /// Annotate this error with the name of the field which was being
+    /// decoded when it occurred, as done by the custom derive macros.
+    ///
+    /// This is a no-op unless the `error-path` feature is enabled.
+    #[allow(unused_mut, unused_variables)]
+    pub fn in_field(mut self, name: &'static str) -> Self {
+        #[cfg(feature = "error-path")]
+        self.path.push(PathSegment::Field(name));
+        self
+    }
+
+    /// Annotate this error with the index of the `SEQUENCE OF` or `SET OF`
+    /// element which was being decoded when it occurred.
+    ///
+    /// This is a no-op unless the `error-path` feature is enabled.
+    #[allow(unused_mut, unused_variables)]
+    pub fn in_element(mut self, index: usize) -> Self {
+        #[cfg(feature = "error-path")]
+        self.path.push(PathSegment::Index(index));
+        self
+    }
+
     /// For errors occurring inside of a nested message, extend the position
     /// count by the location where the nested message occurs.
     pub(crate) fn nested(self, nested_position: Length) -> Self {
         // TODO(tarcieri): better handle length overflows occurring in this calculation?
         let position = (nested_position + self.position.unwrap_or_default()).ok();
-
-        Self {
-            kind: self.kind,
-            position,
-        }
+        Self { position, ..self }
     }
 }
 
@@ -75,6 +114,17 @@ impl std::error::Error for Error {}
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #[cfg(feature = "error-path")]
+        if !self.path.is_empty() {
+            write!(f, "{}", self.path)?;
+
+            if let Some(pos) = self.position {
+                write!(f, " @ offset {:#x}", u32::from(pos))?;
+            }
+
+            return write!(f, ": {}", self.kind);
+        }
+
         write!(f, "{}", self.kind)?;
 
         if let Some(pos) = self.position {
@@ -87,10 +137,7 @@ impl fmt::Display for Error {
 
 impl From<ErrorKind> for Error {
     fn from(kind: ErrorKind) -> Error {
-        Error {
-            kind,
-            position: None,
-        }
+        Error::from_kind(kind)
     }
 }
 
@@ -102,19 +149,13 @@ impl From<Infallible> for Error {
 
 impl From<TryFromIntError> for Error {
     fn from(_: TryFromIntError) -> Error {
-        Error {
-            kind: ErrorKind::Overflow,
-            position: None,
-        }
+        ErrorKind::Overflow.into()
     }
 }
 
 impl From<Utf8Error> for Error {
     fn from(err: Utf8Error) -> Error {
-        Error {
-            kind: ErrorKind::Utf8(err),
-            position: None,
-        }
+        ErrorKind::Utf8(err).into()
     }
 }
 
@@ -204,6 +245,9 @@ pub enum ErrorKind {
         tag: Tag,
     },
 
+    /// Maximum nesting depth of constructed values exceeded.
+    NestingLimitExceeded,
+
     /// Message is not canonically encoded.
     Noncanonical {
         /// Tag of the value which is not canonically encoded.
@@ -324,6 +368,7 @@ impl fmt::Display for ErrorKind {
             ErrorKind::Io(err) => write!(f, "I/O error: {:?}", err),
             ErrorKind::IndefiniteLength => write!(f, "indefinite length disallowed"),
             ErrorKind::Length { tag } => write!(f, "incorrect length for {}", tag),
+            ErrorKind::NestingLimitExceeded => write!(f, "maximum nesting depth exceeded"),
             ErrorKind::Noncanonical { tag } => {
                 write!(f, "ASN.1 {} not canonically encoded as DER", tag)
             }
@@ -367,3 +412,140 @@ impl fmt::Display for ErrorKind {
         }
     }
 }
+
+/// Path to the field or element which was being decoded when an [`Error`]
+/// occurred, e.g. `tbs_certificate.extensions[3].extn_value`.
+///
+/// Only the innermost [`ErrorPath::MAX_FIELDS`] fields (each along with the
+/// index of the `SEQUENCE OF`/`SET OF` element within it) are retained, which
+/// keeps [`Error`] small enough to be cheaply returned by value.
+#[cfg(feature = "error-path")]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct ErrorPath {
+    /// Names of the fields in the path, starting with the innermost one.
+    ///
+    /// Empty for elements which aren't contained in a named field.
+    names: [&'static str; ErrorPath::MAX_FIELDS],
+
+    /// Index of the element within each field, or [`ErrorPath::NO_INDEX`].
+    indices: [u32; ErrorPath::MAX_FIELDS],
+
+    /// Number of populated fields.
+    length: u8,
+
+    /// Were any of the outer fields discarded?
+    truncated: bool,
+}
+
+#[cfg(feature = "error-path")]
+impl ErrorPath {
+    /// Maximum number of fields retained in an [`ErrorPath`].
+    pub const MAX_FIELDS: usize = 3;
+
+    /// Placeholder for fields which aren't a collection of elements.
+    const NO_INDEX: u32 = u32::MAX;
+
+    /// Path which doesn't contain any fields.
+    const EMPTY: Self = Self {
+        names: [""; ErrorPath::MAX_FIELDS],
+        indices: [Self::NO_INDEX; ErrorPath::MAX_FIELDS],
+        length: 0,
+        truncated: false,
+    };
+
+    /// Is this path empty?
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Were some of the outermost segments of this path discarded?
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Iterate over the segments of this path, starting with the outermost.
+    pub fn segments(&self) -> impl Iterator<Item = PathSegment> + '_ {
+        let length = usize::from(self.length);
+
+        self.names[..length]
+            .iter()
+            .zip(&self.indices[..length])
+            .rev()
+            .flat_map(|(&name, &index)| {
+                let field = Some(name)
+                    .filter(|name| !name.is_empty())
+                    .map(PathSegment::Field);
+                let index = Some(index)
+                    .filter(|&index| index != Self::NO_INDEX)
+                    .map(|index| PathSegment::Index(index as usize));
+
+                field.into_iter().chain(index)
+            })
+    }
+
+    /// Add a new outermost segment to this path.
+    fn push(&mut self, segment: PathSegment) {
+        match segment {
+            PathSegment::Field(name) => match usize::from(self.length).checked_sub(1) {
+                // Name the field containing the previously annotated element
+                Some(i) if self.names[i].is_empty() => self.names[i] = name,
+                _ => self.push_field(name, Self::NO_INDEX),
+            },
+            PathSegment::Index(index) => {
+                // Saturate as such large indices can't occur in practice
+                let index = u32::try_from(index).unwrap_or(Self::NO_INDEX - 1);
+                self.push_field("", index);
+            }
+        }
+    }
+
+    /// Add a new outermost field to this path.
+    fn push_field(&mut self, name: &'static str, index: u32) {
+        let i = usize::from(self.length);
+
+        if i < Self::MAX_FIELDS {
+            self.names[i] = name;
+            self.indices[i] = index;
+            self.length = self.length.saturating_add(1);
+        } else {
+            self.truncated = true;
+        }
+    }
+}
+
+#[cfg(feature = "error-path")]
+impl Default for ErrorPath {
+    fn default() -> Self {
+        Self::EMPTY
+    }
+}
+
+#[cfg(feature = "error-path")]
+impl fmt::Display for ErrorPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.truncated {
+            f.write_str("…")?;
+        }
+
+        for (i, segment) in self.segments().enumerate() {
+            match segment {
+                PathSegment::Field(name) if i == 0 => f.write_str(name)?,
+                PathSegment::Field(name) => write!(f, ".{}", name)?,
+                PathSegment::Index(index) => write!(f, "[{}]", index)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Segment of an [`ErrorPath`].
+#[cfg(feature = "error-path")]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum PathSegment {
+    /// Field of a `SEQUENCE`.
+    Field(&'static str),
+
+    /// Element of a `SEQUENCE OF` or `SET OF`.
+    Index(usize),
+}
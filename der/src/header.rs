@@ -14,6 +14,10 @@ pub struct Header {
 }
 
 impl Header {
+    /// Maximum number of DER octets a header can be in this crate.
+    #[cfg(feature = "alloc")]
+    pub(crate) const MAX_SIZE: usize = 1 + Length::MAX_SIZE;
+
     /// Create a new [`Header`] from a [`Tag`] and a specified length.
     ///
     /// Returns an error if the length exceeds the limits of [`Length`].
@@ -21,6 +25,12 @@ impl Header {
         let length = length.try_into().map_err(|_| ErrorKind::Overflow)?;
         Ok(Self { tag, length })
     }
+
+    /// Get the total length of the TLV production this [`Header`] belongs
+    /// to, i.e. the length of the encoded header plus the length of the value.
+    pub fn tlv_len(&self) -> Result<Length> {
+        self.encoded_len()? + self.length
+    }
 }
 
 impl<'a> Decode<'a> for Header {
@@ -37,6 +37,11 @@ impl Length {
     /// Maximum length currently supported: 256 MiB
     pub const MAX: Self = Self(MAX_U32);
 
+    /// Maximum number of octets in a DER encoding of a [`Length`] using the
+    /// rules implemented by this crate.
+    #[cfg(feature = "alloc")]
+    pub(crate) const MAX_SIZE: usize = MAX_DER_OCTETS;
+
     /// Create a new [`Length`] for any value which fits inside of a [`u16`].
     ///
     /// This function is const-safe and therefore useful for [`Length`] constants.
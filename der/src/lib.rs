@@ -47,20 +47,29 @@
 //! The following ASN.1 types provided by this crate also impl these traits:
 //! - [`Any`], [`AnyRef`]: ASN.1 `ANY`.
 //! - [`BitString`], [`BitStringRef`]: ASN.1 `BIT STRING`
-//! - [`GeneralizedTime`]: ASN.1 `GeneralizedTime`.
+//! - [`GeneralString`], [`GeneralStringRef`]: ASN.1 `GeneralString`.
+//! - [`GeneralizedTime`], [`FractionalGeneralizedTime`]: ASN.1 `GeneralizedTime`.
+//! - [`GraphicString`], [`GraphicStringRef`]: ASN.1 `GraphicString`.
 //! - [`Ia5StringRef`]: ASN.1 `IA5String`.
 //! - [`Null`]: ASN.1 `NULL`.
 //! - [`ObjectIdentifier`]: ASN.1 `OBJECT IDENTIFIER`.
 //! - [`OctetString`], [`OctetStringRef`]: ASN.1 `OCTET STRING`.
 //! - [`PrintableStringRef`]: ASN.1 `PrintableString` (ASCII subset).
-//! - [`TeletexStringRef`]: ASN.1 `TeletexString`.
-//! - [`VideotexStringRef`]: ASN.1 `VideotexString`.
+//! - [`Real`]: ASN.1 `REAL` (gated on `real` crate feature)
+//! - [`RelativeOid`]: ASN.1 `RELATIVE-OID`.
+//! - [`TeletexString`], [`TeletexStringRef`]: ASN.1 `TeletexString`.
+//! - [`VideotexString`], [`VideotexStringRef`]: ASN.1 `VideotexString`.
 //! - [`SequenceOf`]: ASN.1 `SEQUENCE OF`.
 //! - [`SetOf`], [`SetOfVec`]: ASN.1 `SET OF`.
 //! - [`UintRef`]: ASN.1 unsigned `INTEGER` with raw access to encoded bytes.
 //! - [`UtcTime`]: ASN.1 `UTCTime`.
 //! - [`Utf8StringRef`]: ASN.1 `UTF8String`.
 //!
+//! The [`DateTime`], [`GeneralizedTime`], [`FractionalGeneralizedTime`], and
+//! [`UtcTime`] types support conversions to and from `chrono::DateTime` and
+//! `time::OffsetDateTime` when the `chrono` and `time` crate features are
+//! enabled, respectively.
+//!
 //! Context specific fields can be modeled using these generic types:
 //! - [`ContextSpecific`]: decoder/encoder for owned context-specific fields
 //! - [`ContextSpecificRef`]: encode-only type for references to context-specific fields
@@ -211,6 +220,17 @@
 //! - [`Enumerated`]: derive for `ENUMERATED` enum (see [`der_derive::Enumerated`])
 //! - [`Sequence`]: derive for `SEQUENCE` struct (see [`der_derive::Sequence`])
 //!
+//! When the `arbitrary` feature is also enabled, the ASN.1 types in this crate
+//! implement `arbitrary::Arbitrary`, so `#[derive(Arbitrary)]` can be used
+//! alongside these macros (e.g. for fuzzing). Generated values are always
+//! valid, i.e. they can be encoded and decoded again.
+//!
+//! ## `serde` support
+//! When the `serde` feature of this crate is enabled, [`Any`], [`AnyRef`],
+//! [`Document`] and `SecretDocument` implement `Serialize` and `Deserialize`,
+//! preserving their exact DER encoding. Human-readable formats such as JSON
+//! represent it as a Base64 string, while binary formats use raw bytes.
+//!
 //! ### Derive [`Sequence`] for struct
 //! The following is a code example of how to use the [`Sequence`] custom derive:
 //!
@@ -304,14 +324,22 @@
 //! [`ContextSpecificRef`]: asn1::ContextSpecificRef
 //! [`BitString`]: asn1::BitString
 //! [`BitStringRef`]: asn1::BitStringRef
+//! [`FractionalGeneralizedTime`]: asn1::FractionalGeneralizedTime
+//! [`GeneralString`]: asn1::GeneralString
+//! [`GeneralStringRef`]: asn1::GeneralStringRef
 //! [`GeneralizedTime`]: asn1::GeneralizedTime
+//! [`GraphicString`]: asn1::GraphicString
+//! [`GraphicStringRef`]: asn1::GraphicStringRef
 //! [`Ia5StringRef`]: asn1::Ia5StringRef
 //! [`Null`]: asn1::Null
 //! [`ObjectIdentifier`]: asn1::ObjectIdentifier
 //! [`OctetString`]: asn1::OctetString
 //! [`OctetStringRef`]: asn1::OctetStringRef
 //! [`PrintableStringRef`]: asn1::PrintableStringRef
+//! [`Real`]: asn1::Real
+//! [`TeletexString`]: asn1::TeletexString
 //! [`TeletexStringRef`]: asn1::TeletexStringRef
+//! [`VideotexString`]: asn1::VideotexString
 //! [`VideotexStringRef`]: asn1::VideotexStringRef
 //! [`SequenceOf`]: asn1::SequenceOf
 //! [`SetOf`]: asn1::SetOf
@@ -328,10 +356,17 @@ extern crate alloc;
 extern crate std;
 
 pub mod asn1;
+pub mod const_decode;
 pub mod referenced;
 
+#[cfg(feature = "dump")]
+pub mod dump;
+#[cfg(feature = "alloc")]
+pub mod value;
+
 pub(crate) mod arrayvec;
 mod bytes_ref;
+mod canonical;
 mod datetime;
 mod decode;
 mod encode;
@@ -351,9 +386,12 @@ mod bytes_owned;
 mod document;
 #[cfg(feature = "alloc")]
 mod str_owned;
+#[cfg(feature = "serde")]
+mod serde;
 
 pub use crate::{
     asn1::{AnyRef, Choice, Sequence},
+    canonical::validate_canonical,
     datetime::DateTime,
     decode::{Decode, DecodeOwned, DecodeValue},
     encode::{Encode, EncodeValue},
@@ -368,11 +406,20 @@ pub use crate::{
 };
 
 #[cfg(feature = "alloc")]
-pub use crate::{asn1::Any, document::Document};
+pub use crate::{asn1::Any, document::Document, reader::ber::BerReader};
+
+#[cfg(feature = "std")]
+pub use crate::{reader::io::IoReader, writer::io::IoWriter};
+
+#[cfg(feature = "error-path")]
+pub use crate::error::{ErrorPath, PathSegment};
 
 #[cfg(feature = "bigint")]
 pub use crypto_bigint as bigint;
 
+#[cfg(feature = "chrono")]
+pub use chrono;
+
 #[cfg(feature = "derive")]
 pub use der_derive::{Choice, Enumerated, Sequence, ValueOrd};
 
@@ -1,5 +1,9 @@
 //! Reader trait.
 
+#[cfg(feature = "alloc")]
+pub(crate) mod ber;
+#[cfg(feature = "std")]
+pub(crate) mod io;
 pub(crate) mod nested;
 #[cfg(feature = "pem")]
 pub(crate) mod pem;
@@ -8,13 +12,16 @@ pub(crate) mod slice;
 pub(crate) use nested::NestedReader;
 
 use crate::{
-    asn1::ContextSpecific, Decode, DecodeValue, Encode, Error, ErrorKind, FixedTag, Header, Length,
-    Result, Tag, TagMode, TagNumber,
+    asn1::ContextSpecific, Decode, DecodeValue, Error, ErrorKind, FixedTag, Header, Length, Result,
+    Tag, TagMode, TagNumber,
 };
 
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 
+/// Default maximum nesting depth of constructed values.
+pub(crate) const DEFAULT_MAX_DEPTH: usize = 64;
+
 /// Reader trait which reads DER-encoded input.
 pub trait Reader<'r>: Sized {
     /// Get the length of the input.
@@ -26,7 +33,24 @@ pub trait Reader<'r>: Sized {
     /// Peek forward in the input data, attempting to decode a [`Header`] from
     /// the data at the current position in the decoder.
     ///
-    /// Does not modify the decoder's state.
+    /// Does not modify the decoder's state, which makes it possible to
+    /// dispatch on the [`Tag`] of the next value before committing to a
+    /// particular type. The length of the entire TLV production is available
+    /// via [`Header::tlv_len`].
+    ///
+    /// ```
+    /// use der::{Length, Reader, SliceReader, Tag};
+    ///
+    /// // SEQUENCE { INTEGER 42 }
+    /// let reader = SliceReader::new(&[0x30, 0x03, 0x02, 0x01, 0x2A])?;
+    /// let header = reader.peek_header()?;
+    ///
+    /// assert_eq!(header.tag, Tag::Sequence);
+    /// assert_eq!(header.length, Length::new(3));
+    /// assert_eq!(header.tlv_len()?, Length::new(5));
+    /// assert_eq!(reader.position(), Length::ZERO);
+    /// # Ok::<(), der::Error>(())
+    /// ```
     fn peek_header(&self) -> Result<Header>;
 
     /// Get the position within the buffer.
@@ -123,7 +147,36 @@ pub trait Reader<'r>: Sized {
         Ok(buf)
     }
 
+    /// Get the nesting depth of this reader, i.e. the number of constructed
+    /// values the current position is nested within.
+    fn depth(&self) -> usize {
+        0
+    }
+
+    /// Get the maximum nesting depth of constructed values permitted when
+    /// reading nested data.
+    ///
+    /// Defaults to 64.
+    fn max_depth(&self) -> usize {
+        DEFAULT_MAX_DEPTH
+    }
+
+    /// Should the restricted character sets of types like `PrintableString`
+    /// and `IA5String` be ignored when decoding them?
+    ///
+    /// Some encoders emit strings which contain characters outside of these
+    /// character sets (e.g. `@` or `_` in a `PrintableString`). When enabled,
+    /// such strings are accepted as long as they are valid UTF-8.
+    ///
+    /// Defaults to `false`.
+    fn lenient_charsets(&self) -> bool {
+        false
+    }
+
     /// Read nested data of the given length.
+    ///
+    /// Returns [`ErrorKind::NestingLimitExceeded`] if this would exceed the
+    /// [`Reader::max_depth`].
     fn read_nested<'n, T, F>(&'n mut self, len: Length, f: F) -> Result<T>
     where
         F: FnOnce(&mut NestedReader<'n, Self>) -> Result<T>,
@@ -137,7 +190,14 @@ pub trait Reader<'r>: Sized {
     #[cfg(feature = "alloc")]
     fn read_vec(&mut self, len: Length) -> Result<Vec<u8>> {
         let mut bytes = vec![0u8; usize::try_from(len)?];
-        self.read_into(&mut bytes)?;
+
+        if let Err(err) = self.read_into(&mut bytes) {
+            // Readers may have partially filled the buffer with secret data
+            #[cfg(feature = "zeroize")]
+            zeroize::Zeroize::zeroize(&mut bytes);
+            return Err(err);
+        }
+
         Ok(bytes)
     }
 
@@ -161,7 +221,6 @@ pub trait Reader<'r>: Sized {
     /// Obtain a slice of bytes contain a complete TLV production suitable for parsing later.
     fn tlv_bytes(&mut self) -> Result<&'r [u8]> {
         let header = self.peek_header()?;
-        let header_len = header.encoded_len()?;
-        self.read_slice((header_len + header.length)?)
+        self.read_slice(header.tlv_len()?)
     }
 }
@@ -0,0 +1,458 @@
+//! Reader for ASN.1 BER-encoded input.
+
+use super::{Reader, DEFAULT_MAX_DEPTH};
+use crate::{
+    tag::{constructed_string_tag, CONSTRUCTED_FLAG},
+    Decode, Encode, Error, ErrorKind, Header, Length, Result, SliceReader, Tag,
+};
+use alloc::vec::Vec;
+
+/// [`Reader`] which accepts input encoded using the ASN.1 Basic Encoding
+/// Rules (BER) as described in ITU X.690.
+///
+/// BER is a superset of DER, and the input is converted to DER up front so
+/// it can be decoded using the existing types in this crate:
+///
+/// - indefinite lengths are replaced by definite lengths and their
+///   end-of-contents octets are removed
+/// - constructed-form `BIT STRING`, `OCTET STRING`, and character string
+///   values with a `UNIVERSAL` tag are converted to the primitive form by
+///   concatenating their segments
+/// - lengths which aren't encoded using the minimum number of octets are
+///   re-encoded canonically
+///
+/// Other BER encoding variants are not normalized and are validated by the
+/// respective types as usual. In particular, strings which use an `IMPLICIT`
+/// tag can't be identified without a schema and are left in the constructed
+/// form.
+///
+/// Since data is decoded from the converted DER, [`Reader::read_slice`]
+/// always returns [`ErrorKind::Reader`], similar to [`PemReader`][`crate::PemReader`].
+/// Positions in decoding errors refer to the converted DER, which can be
+/// obtained using [`BerReader::as_der_bytes`].
+///
+/// ```
+/// # fn main() -> der::Result<()> {
+/// use der::{asn1::OctetString, BerReader, Decode, Reader};
+///
+/// // Constructed `OCTET STRING` with an indefinite length
+/// let ber = [0x24, 0x80, 0x04, 0x02, 0xAA, 0xBB, 0x04, 0x01, 0xCC, 0x00, 0x00];
+///
+/// let mut reader = BerReader::new(&ber)?;
+/// let octet_string = OctetString::decode(&mut reader)?;
+/// let octet_string = reader.finish(octet_string)?;
+///
+/// assert_eq!(octet_string.as_bytes(), &[0xAA, 0xBB, 0xCC]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct BerReader {
+    /// DER converted from the BER input.
+    der_bytes: Vec<u8>,
+
+    /// Input length: length of the converted DER.
+    input_len: Length,
+
+    /// Position within the converted DER.
+    position: Length,
+}
+
+impl BerReader {
+    /// Create a new BER reader, converting the given BER-encoded input to DER.
+    ///
+    /// Returns an error if the input is not well-formed BER, with positions
+    /// referring to the original input, or if constructed values are nested
+    /// more deeply than the default [`Reader::max_depth`].
+    pub fn new(ber: &[u8]) -> Result<Self> {
+        let mut reader = SliceReader::new(ber)?;
+        let mut der_bytes = Vec::with_capacity(ber.len());
+
+        while !reader.is_finished() {
+            normalize_tlv(&mut reader, &mut der_bytes, 0)?;
+        }
+
+        let input_len = Length::try_from(der_bytes.len())?;
+
+        Ok(Self {
+            der_bytes,
+            input_len,
+            position: Length::ZERO,
+        })
+    }
+
+    /// Get the DER which was converted from the BER input.
+    pub fn as_der_bytes(&self) -> &[u8] {
+        &self.der_bytes
+    }
+
+    /// Obtain the remaining converted DER from the current cursor position.
+    fn remaining(&self) -> Result<&[u8]> {
+        self.der_bytes
+            .get(usize::try_from(self.position)?..)
+            .ok_or_else(|| Error::incomplete(self.input_len))
+    }
+}
+
+impl<'r> Reader<'r> for BerReader {
+    fn input_len(&self) -> Length {
+        self.input_len
+    }
+
+    fn peek_byte(&self) -> Option<u8> {
+        self.remaining()
+            .ok()
+            .and_then(|bytes| bytes.first().copied())
+    }
+
+    fn peek_header(&self) -> Result<Header> {
+        Header::decode(&mut SliceReader::new(self.remaining()?)?)
+            .map_err(|e| e.nested(self.position))
+    }
+
+    fn position(&self) -> Length {
+        self.position
+    }
+
+    fn read_slice(&mut self, _len: Length) -> Result<&'r [u8]> {
+        // Can't borrow from the original input because it requires conversion
+        Err(ErrorKind::Reader.at(self.position))
+    }
+
+    fn read_into<'o>(&mut self, buf: &'o mut [u8]) -> Result<&'o [u8]> {
+        let new_position = (self.position + buf.len())?;
+
+        match self.remaining()?.get(..buf.len()) {
+            Some(bytes) => {
+                buf.copy_from_slice(bytes);
+                self.position = new_position;
+                Ok(buf)
+            }
+            None => Err(ErrorKind::Incomplete {
+                expected_len: new_position,
+                actual_len: self.input_len,
+            }
+            .at(self.position)),
+        }
+    }
+}
+
+/// Convert a single BER-encoded TLV production nested `depth` levels deep to
+/// DER, appending it to `out`.
+fn normalize_tlv(reader: &mut SliceReader<'_>, out: &mut Vec<u8>, depth: usize) -> Result<()> {
+    if depth > DEFAULT_MAX_DEPTH {
+        return Err(reader.error(ErrorKind::NestingLimitExceeded));
+    }
+
+    let octet = reader.read_byte()?;
+
+    if let Some(tag) = constructed_string_tag(octet) {
+        let length = decode_length(reader)?;
+        let mut contents = Vec::new();
+        let mut unused_bits = 0;
+
+        for_each_child(reader, length, |child| {
+            flatten_string(
+                child,
+                tag,
+                &mut contents,
+                &mut unused_bits,
+                depth.saturating_add(1),
+            )
+        })?;
+
+        if tag == Tag::BitString {
+            contents.insert(0, unused_bits);
+        }
+
+        return write_tlv(out, tag, &contents);
+    }
+
+    let tag = Tag::try_from(octet).map_err(|e| reader.error(e.kind()))?;
+    let length = decode_length(reader)?;
+
+    if tag.is_constructed() {
+        let mut contents = Vec::new();
+        for_each_child(reader, length, |child| {
+            normalize_tlv(child, &mut contents, depth.saturating_add(1))
+        })?;
+        write_tlv(out, tag, &contents)
+    } else {
+        let length = length.ok_or_else(|| reader.error(ErrorKind::IndefiniteLength))?;
+        let value = reader.read_slice(length)?;
+        write_tlv(out, tag, value)
+    }
+}
+
+/// Concatenate the segments of a constructed string of the given type nested
+/// `depth` levels deep, appending them to `contents`.
+///
+/// For `BIT STRING` the number of unused bits in the final segment is stored
+/// in `unused_bits` rather than in `contents`.
+fn flatten_string(
+    reader: &mut SliceReader<'_>,
+    tag: Tag,
+    contents: &mut Vec<u8>,
+    unused_bits: &mut u8,
+    depth: usize,
+) -> Result<()> {
+    if depth > DEFAULT_MAX_DEPTH {
+        return Err(reader.error(ErrorKind::NestingLimitExceeded));
+    }
+
+    let octet = reader.read_byte()?;
+    let length = decode_length(reader)?;
+
+    if octet == tag.octet() | CONSTRUCTED_FLAG {
+        return for_each_child(reader, length, |child| {
+            flatten_string(child, tag, contents, unused_bits, depth.saturating_add(1))
+        });
+    }
+
+    if octet != tag.octet() {
+        let kind = match Tag::try_from(octet) {
+            Ok(actual) => actual.unexpected_error(Some(tag)).kind(),
+            Err(err) => err.kind(),
+        };
+
+        return Err(reader.error(kind));
+    }
+
+    let length = length.ok_or_else(|| reader.error(ErrorKind::IndefiniteLength))?;
+    let segment = reader.read_slice(length)?;
+
+    if tag != Tag::BitString {
+        contents.extend_from_slice(segment);
+        return Ok(());
+    }
+
+    // Only the final segment of a `BIT STRING` may contain unused bits
+    match segment.split_first() {
+        Some((&segment_unused_bits, bits)) if *unused_bits == 0 && segment_unused_bits < 8 => {
+            *unused_bits = segment_unused_bits;
+            contents.extend_from_slice(bits);
+            Ok(())
+        }
+        Some(_) => Err(reader.error(tag.value_error().kind())),
+        None => Err(reader.error(tag.length_error().kind())),
+    }
+}
+
+/// Call `f` once for each TLV production contained within a constructed
+/// value with the given length, consuming the end-of-contents octets in the
+/// case of an indefinite length.
+fn for_each_child<'a>(
+    reader: &mut SliceReader<'a>,
+    length: Option<Length>,
+    mut f: impl FnMut(&mut SliceReader<'a>) -> Result<()>,
+) -> Result<()> {
+    match length {
+        Some(length) => {
+            let offset = reader.position();
+            let mut nested = SliceReader::new(reader.read_slice(length)?)?;
+
+            while !nested.is_finished() {
+                f(&mut nested).map_err(|e| e.nested(offset))?;
+            }
+
+            Ok(())
+        }
+        None => loop {
+            if reader.peek_byte() == Some(0) {
+                let mut end_of_contents = [0u8; 2];
+                reader.read_into(&mut end_of_contents)?;
+
+                return if end_of_contents == [0, 0] {
+                    Ok(())
+                } else {
+                    Err(reader.error(ErrorKind::TagUnknown { byte: 0 }))
+                };
+            }
+
+            f(reader)?;
+        },
+    }
+}
+
+/// Decode a BER length, returning `None` for an indefinite length.
+///
+/// Unlike [`Length::decode`], lengths which aren't encoded using the minimum
+/// number of octets are accepted.
+pub(crate) fn decode_length(reader: &mut SliceReader<'_>) -> Result<Option<Length>> {
+    match reader.read_byte()? {
+        0x80 => Ok(None),
+        len if len < 0x80 => Ok(Some(len.into())),
+        octet @ 0x81..=0x84 => {
+            let mut decoded_len = 0u32;
+
+            for _ in 0..(octet & 0x7F) {
+                decoded_len = decoded_len.checked_shl(8).ok_or(ErrorKind::Overflow)?
+                    | u32::from(reader.read_byte()?);
+            }
+
+            Length::try_from(decoded_len)
+                .map(Some)
+                .map_err(|_| reader.error(ErrorKind::Overlength))
+        }
+        _ => Err(reader.error(ErrorKind::Overlength)),
+    }
+}
+
+/// Write a DER-encoded TLV production with the given tag and value.
+fn write_tlv(out: &mut Vec<u8>, tag: Tag, value: &[u8]) -> Result<()> {
+    let mut header = [0u8; Header::MAX_SIZE];
+    out.extend_from_slice(Header::new(tag, value.len())?.encode_to_slice(&mut header)?);
+    out.extend_from_slice(value);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BerReader;
+    use crate::{
+        asn1::{BitString, ContextSpecific, OctetString, OctetStringRef},
+        Decode, ErrorKind, Length, Reader, Tag,
+    };
+    use alloc::vec::Vec;
+    use hex_literal::hex;
+
+    fn ber_to_der(ber: &[u8]) -> Vec<u8> {
+        BerReader::new(ber).unwrap().as_der_bytes().to_vec()
+    }
+
+    #[test]
+    fn der_is_unchanged() {
+        let der = hex!("3009 020101 0404DEADBEEF");
+        assert_eq!(ber_to_der(&der), der);
+    }
+
+    #[test]
+    fn indefinite_length_sequence() {
+        let ber = hex!("3080 020101 020102 0000");
+        assert_eq!(ber_to_der(&ber), hex!("3006 020101 020102"));
+
+        let mut reader = BerReader::new(&ber).unwrap();
+        let ints = Vec::<u8>::decode(&mut reader).unwrap();
+        assert_eq!(reader.finish(ints).unwrap(), [1, 2]);
+    }
+
+    #[test]
+    fn nested_indefinite_lengths() {
+        let ber = hex!("3080 A080 3080 0500 0000 0000 020101 0000");
+        assert_eq!(ber_to_der(&ber), hex!("3009 A004 3002 0500 020101"));
+
+        let mut reader = BerReader::new(&ber).unwrap();
+        reader
+            .sequence(|reader| {
+                let field = ContextSpecific::<Vec<()>>::decode(reader)?;
+                assert_eq!(field.tag_number.value(), 0);
+                assert_eq!(field.value, [()]);
+                assert_eq!(u8::decode(reader)?, 1);
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn constructed_octet_string() {
+        // Definite length containing a nested constructed segment
+        let ber = hex!("2409 0401AA 2404 0402BBCC");
+        let mut reader = BerReader::new(&ber).unwrap();
+        let octet_string = OctetString::decode(&mut reader).unwrap();
+        assert_eq!(octet_string.as_bytes(), hex!("AABBCC"));
+
+        // Indefinite length
+        let ber = hex!("2480 0402AABB 0401CC 0000");
+        assert_eq!(ber_to_der(&ber), hex!("0403AABBCC"));
+    }
+
+    #[test]
+    fn constructed_bit_string() {
+        let ber = hex!("2380 030200AA 030204B0 0000");
+        assert_eq!(ber_to_der(&ber), hex!("030304AAB0"));
+
+        let mut reader = BerReader::new(&ber).unwrap();
+        let bit_string = BitString::decode(&mut reader).unwrap();
+        assert_eq!(bit_string.bit_len(), 12);
+        assert_eq!(bit_string.raw_bytes(), hex!("AAB0"));
+    }
+
+    #[test]
+    fn constructed_bit_string_with_unused_bits_in_middle() {
+        let ber = hex!("2380 030204A0 030200BB 0000");
+        let err = BerReader::new(&ber).err().unwrap();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::Value {
+                tag: Tag::BitString
+            }
+        );
+    }
+
+    #[test]
+    fn constructed_string_with_mismatched_segment() {
+        let ber = hex!("2480 0C01AA 0000");
+        let err = BerReader::new(&ber).err().unwrap();
+
+        assert_eq!(
+            err.kind(),
+            ErrorKind::TagUnexpected {
+                expected: Some(Tag::OctetString),
+                actual: Tag::Utf8String
+            }
+        );
+    }
+
+    #[test]
+    fn non_minimal_length() {
+        assert_eq!(ber_to_der(&hex!("048101AA")), hex!("0401AA"));
+        assert_eq!(ber_to_der(&hex!("04820001AA")), hex!("0401AA"));
+    }
+
+    #[test]
+    fn missing_end_of_contents() {
+        let err = BerReader::new(&hex!("3080 020101")).err().unwrap();
+        assert!(matches!(err.kind(), ErrorKind::Incomplete { .. }));
+    }
+
+    #[test]
+    fn malformed_end_of_contents() {
+        let err = BerReader::new(&hex!("3080 020101 0001")).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::TagUnknown { byte: 0 });
+        assert_eq!(err.position(), Some(Length::from(7u8)));
+    }
+
+    #[test]
+    fn indefinite_length_primitive() {
+        let err = BerReader::new(&hex!("0480 AA 0000")).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::IndefiniteLength);
+    }
+
+    #[test]
+    fn error_position_within_nested_value() {
+        let err = BerReader::new(&hex!("3005 3003 0402AA")).err().unwrap();
+        assert!(matches!(err.kind(), ErrorKind::Incomplete { .. }));
+        assert_eq!(err.position(), Some(Length::from(6u8)));
+    }
+
+    #[test]
+    fn nesting_limit_exceeded() {
+        let mut ber = Vec::new();
+        for _ in 0..100 {
+            ber.extend_from_slice(&[0x30, 0x80]);
+        }
+        for _ in 0..100 {
+            ber.extend_from_slice(&[0x00, 0x00]);
+        }
+
+        let err = BerReader::new(&ber).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NestingLimitExceeded);
+    }
+
+    #[test]
+    fn read_slice_unsupported() {
+        let mut reader = BerReader::new(&hex!("0401AA")).unwrap();
+        let err = OctetStringRef::decode(&mut reader).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::Reader);
+    }
+}
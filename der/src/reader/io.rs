@@ -0,0 +1,408 @@
+//! Streaming reader for [`std::io::Read`] sources.
+
+use super::Reader;
+use crate::{Decode, Encode, Error, ErrorKind, Header, Length, Result, SliceReader};
+use core::cell::RefCell;
+use std::{io, vec::Vec};
+
+/// Default capacity of the internal read buffer.
+const DEFAULT_CAPACITY: usize = 8192;
+
+/// [`Reader`] which incrementally decodes a single TLV-encoded ASN.1 DER
+/// message from a [`std::io::Read`] source using an internal buffer.
+///
+/// The header of the outermost TLV production is read eagerly by
+/// [`IoReader::new`] and determines the input length. Reads from the
+/// underlying source never extend past the end of that production, so any
+/// data which follows it can be consumed after retrieving the source with
+/// [`IoReader::into_inner`].
+///
+/// Since the input is not held in memory, data can't be borrowed from it and
+/// [`Reader::read_slice`] always returns [`ErrorKind::Reader`]. This reader is
+/// intended to be used with types which impl [`DecodeOwned`][`crate::DecodeOwned`].
+///
+/// ```
+/// # fn main() -> der::Result<()> {
+/// use der::{Decode, IoReader, Reader};
+///
+/// // `SEQUENCE OF INTEGER` followed by unrelated trailing data
+/// let mut input: &[u8] = &[0x30, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x02, 0xFF];
+///
+/// let mut reader = IoReader::new(&mut input)?;
+/// let ints = Vec::<u8>::decode(&mut reader)?;
+/// let ints = reader.finish(ints)?;
+///
+/// assert_eq!(ints, [1, 2]);
+/// assert_eq!(input, &[0xFF]);
+/// # Ok(())
+/// # }
+/// ```
+pub struct IoReader<R> {
+    /// Inner I/O reader wrapped in a read buffer.
+    buffer: RefCell<Buffer<R>>,
+
+    /// Input length: total length of the outermost TLV production.
+    input_len: Length,
+
+    /// Position within the input.
+    position: Length,
+}
+
+impl<R: io::Read> IoReader<R> {
+    /// Create a new reader for the given I/O source, using a default-sized
+    /// internal buffer.
+    ///
+    /// Reads the header of the outermost TLV production from the source.
+    pub fn new(reader: R) -> Result<Self> {
+        Self::with_capacity(DEFAULT_CAPACITY, reader)
+    }
+
+    /// Create a new reader for the given I/O source with an internal buffer
+    /// of the given capacity (in bytes).
+    ///
+    /// Reads the header of the outermost TLV production from the source.
+    pub fn with_capacity(capacity: usize, reader: R) -> Result<Self> {
+        let mut buffer = Buffer::new(reader, capacity);
+
+        // Read the tag and the initial length octet, followed by any
+        // subsequent length octets in the long form
+        buffer.unread = 2;
+        buffer.fill(2).map_err(|e| io_error(e, Length::ZERO))?;
+
+        if let Some(&octet @ 0x81..=0x84) = buffer.available().get(1) {
+            buffer.unread = usize::from(octet & 0x7F);
+            buffer
+                .fill(Header::MAX_SIZE)
+                .map_err(|e| io_error(e, Length::ZERO))?;
+        }
+
+        let header = Header::decode(&mut SliceReader::new(buffer.available())?)?;
+        let header_len = header.encoded_len()?;
+        buffer.unread = usize::try_from(header.length)?;
+
+        Ok(Self {
+            buffer: RefCell::new(buffer),
+            input_len: (header_len + header.length)?,
+            position: Length::ZERO,
+        })
+    }
+
+    /// Consume this reader, returning the underlying I/O source.
+    ///
+    /// Once the outermost TLV production has been read in its entirety the
+    /// source is positioned immediately after it.
+    pub fn into_inner(self) -> R {
+        self.buffer.into_inner().inner
+    }
+}
+
+impl<'r, R: io::Read> Reader<'r> for IoReader<R> {
+    fn input_len(&self) -> Length {
+        self.input_len
+    }
+
+    fn peek_byte(&self) -> Option<u8> {
+        if self.is_finished() {
+            return None;
+        }
+
+        let mut buffer = self.buffer.borrow_mut();
+        buffer.fill(1).ok()?;
+        buffer.available().first().copied()
+    }
+
+    fn peek_header(&self) -> Result<Header> {
+        if self.is_finished() {
+            return Err(Error::incomplete(self.offset()));
+        }
+
+        let mut buffer = self.buffer.borrow_mut();
+        buffer
+            .fill(Header::MAX_SIZE)
+            .map_err(|e| io_error(e, self.position))?;
+        Header::decode(&mut SliceReader::new(buffer.available())?)
+            .map_err(|e| e.nested(self.position))
+    }
+
+    fn position(&self) -> Length {
+        self.position
+    }
+
+    fn read_slice(&mut self, _len: Length) -> Result<&'r [u8]> {
+        // Can't borrow from an I/O source
+        Err(ErrorKind::Reader.at(self.position))
+    }
+
+    fn read_into<'o>(&mut self, buf: &'o mut [u8]) -> Result<&'o [u8]> {
+        let new_position = (self.position + buf.len())?;
+
+        if new_position > self.input_len {
+            return Err(ErrorKind::Incomplete {
+                expected_len: new_position,
+                actual_len: self.input_len,
+            }
+            .at(self.position));
+        }
+
+        let buffer = self.buffer.get_mut();
+
+        match buffer.read_into(buf) {
+            Ok(()) => (),
+            Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => {
+                let actual_len = Length::try_from(buffer.total)?;
+
+                return Err(ErrorKind::Incomplete {
+                    expected_len: self.input_len,
+                    actual_len,
+                }
+                .at(actual_len));
+            }
+            Err(err) => return Err(io_error(err, self.position)),
+        }
+
+        self.position = new_position;
+        Ok(buf)
+    }
+}
+
+/// Convert an I/O error into an [`Error`] annotated with the given position.
+fn io_error(err: io::Error, position: Length) -> Error {
+    Error::from(err).kind().at(position)
+}
+
+/// Read buffer which never reads more than a given number of bytes from the
+/// underlying I/O source.
+struct Buffer<R> {
+    /// Underlying I/O source.
+    inner: R,
+
+    /// Read buffer.
+    buf: Vec<u8>,
+
+    /// Position of the head in the buffer.
+    pos: usize,
+
+    /// Position of the tail in the buffer.
+    filled: usize,
+
+    /// Number of bytes which may still be read from the I/O source.
+    unread: usize,
+
+    /// Total number of bytes read from the I/O source so far.
+    total: usize,
+}
+
+impl<R: io::Read> Buffer<R> {
+    fn new(inner: R, capacity: usize) -> Self {
+        Self {
+            inner,
+            buf: vec![0u8; capacity.max(Header::MAX_SIZE)],
+            pos: 0,
+            filled: 0,
+            unread: 0,
+            total: 0,
+        }
+    }
+
+    /// Buffered data which has not yet been consumed.
+    fn available(&self) -> &[u8] {
+        &self.buf[self.pos..self.filled]
+    }
+
+    /// Attempt to buffer at least `min` bytes, stopping early if there is no
+    /// more data which may be read from the I/O source.
+    fn fill(&mut self, min: usize) -> io::Result<()> {
+        if self.available().len() >= min || self.unread == 0 {
+            return Ok(());
+        }
+
+        if self.buf.len().saturating_sub(self.pos) < min {
+            self.buf.copy_within(self.pos..self.filled, 0);
+            self.filled = self.filled.saturating_sub(self.pos);
+            self.pos = 0;
+        }
+
+        while self.available().len() < min && self.unread != 0 {
+            let end = self.filled.saturating_add(self.unread).min(self.buf.len());
+
+            let nbytes = read(&mut self.inner, &mut self.buf[self.filled..end])?;
+
+            if nbytes == 0 {
+                break;
+            }
+
+            self.consume_unread(nbytes);
+            self.filled = self.filled.saturating_add(nbytes);
+        }
+
+        Ok(())
+    }
+
+    /// Fill the provided output buffer in its entirety, first from buffered
+    /// data and then from the I/O source.
+    ///
+    /// Returns [`io::ErrorKind::UnexpectedEof`] if there isn't enough data.
+    fn read_into(&mut self, out: &mut [u8]) -> io::Result<()> {
+        let mut out = self.copy_available(out);
+
+        // Bypass the buffer when reading large amounts of data
+        while out.len() >= self.buf.len() && self.unread != 0 {
+            let len = out.len().min(self.unread);
+
+            match read(&mut self.inner, &mut out[..len])? {
+                0 => break,
+                nbytes => {
+                    self.consume_unread(nbytes);
+                    out = &mut out[nbytes..];
+                }
+            }
+        }
+
+        while !out.is_empty() {
+            self.fill(out.len())?;
+
+            if self.available().is_empty() {
+                return Err(io::ErrorKind::UnexpectedEof.into());
+            }
+
+            out = self.copy_available(out);
+        }
+
+        Ok(())
+    }
+
+    /// Copy as much buffered data as possible into `out`, returning the
+    /// remaining unwritten portion of it.
+    fn copy_available<'o>(&mut self, out: &'o mut [u8]) -> &'o mut [u8] {
+        let len = self.available().len().min(out.len());
+        let (head, tail) = out.split_at_mut(len);
+        head.copy_from_slice(&self.available()[..len]);
+        self.pos = self.pos.saturating_add(len);
+        tail
+    }
+
+    /// Record that `nbytes` have been read from the I/O source.
+    fn consume_unread(&mut self, nbytes: usize) {
+        debug_assert!(nbytes <= self.unread);
+        self.unread = self.unread.saturating_sub(nbytes);
+        self.total = self.total.saturating_add(nbytes);
+    }
+}
+
+/// Read from the I/O source, retrying on interruption.
+fn read(reader: &mut impl io::Read, buf: &mut [u8]) -> io::Result<usize> {
+    loop {
+        match reader.read(buf) {
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            result => return result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IoReader;
+    use crate::{
+        asn1::{OctetString, OctetStringRef},
+        Decode, ErrorKind, Length, Reader, Tag,
+    };
+    use hex_literal::hex;
+    use std::{io, vec::Vec};
+
+    /// `SEQUENCE OF INTEGER` containing 1, 256, and 65536.
+    const EXAMPLE_MSG: &[u8] = &hex!("300C 020101 02020100 0203010000");
+
+    /// I/O source which returns at most one byte per read.
+    struct OneByteAtATime<'a>(&'a [u8]);
+
+    impl io::Read for OneByteAtATime<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let len = buf.len().min(1).min(self.0.len());
+            buf[..len].copy_from_slice(&self.0[..len]);
+            self.0 = &self.0[len..];
+            Ok(len)
+        }
+    }
+
+    #[test]
+    fn decode_sequence_of() {
+        let mut reader = IoReader::new(EXAMPLE_MSG).unwrap();
+        assert_eq!(reader.input_len(), Length::from(14u8));
+
+        let ints = Vec::<u32>::decode(&mut reader).unwrap();
+        assert_eq!(reader.finish(ints).unwrap(), [1, 256, 65536]);
+    }
+
+    #[test]
+    fn decode_one_byte_at_a_time() {
+        let mut reader = IoReader::with_capacity(0, OneByteAtATime(EXAMPLE_MSG)).unwrap();
+        let ints = Vec::<u32>::decode(&mut reader).unwrap();
+        assert_eq!(reader.finish(ints).unwrap(), [1, 256, 65536]);
+    }
+
+    #[test]
+    fn decode_long_form_length() {
+        let mut msg = hex!("04820100").to_vec();
+        msg.extend_from_slice(&[0x42; 256]);
+
+        let mut reader = IoReader::with_capacity(16, OneByteAtATime(&msg)).unwrap();
+        let octet_string = OctetString::decode(&mut reader).unwrap();
+        let octet_string = reader.finish(octet_string).unwrap();
+        assert_eq!(octet_string.as_bytes(), &[0x42; 256]);
+    }
+
+    #[test]
+    fn trailing_data_left_unread() {
+        let mut input = EXAMPLE_MSG.to_vec();
+        input.extend_from_slice(&hex!("0500"));
+
+        let mut source = input.as_slice();
+        let mut reader = IoReader::new(&mut source).unwrap();
+        let ints = Vec::<u32>::decode(&mut reader).unwrap();
+        reader.finish(ints).unwrap();
+
+        assert_eq!(source, &hex!("0500"));
+    }
+
+    #[test]
+    fn truncated_input() {
+        let mut reader = IoReader::new(&EXAMPLE_MSG[..8]).unwrap();
+        let err = Vec::<u32>::decode(&mut reader).err().unwrap();
+        assert_eq!(Some(Length::from(8u8)), err.position());
+
+        assert_eq!(
+            err.kind(),
+            ErrorKind::Incomplete {
+                expected_len: 14u8.into(),
+                actual_len: 8u8.into()
+            }
+        );
+    }
+
+    #[test]
+    fn indefinite_length() {
+        let err = IoReader::new(&hex!("3080 0000")[..]).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::IndefiniteLength);
+    }
+
+    #[test]
+    fn read_slice_unsupported() {
+        let mut reader = IoReader::new(&hex!("0401FF")[..]).unwrap();
+        let err = OctetStringRef::decode(&mut reader).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::Reader);
+    }
+
+    #[test]
+    fn peek_header() {
+        let mut reader = IoReader::new(OneByteAtATime(EXAMPLE_MSG)).unwrap();
+        let header = reader.peek_header().unwrap();
+        assert_eq!(header.tag, Tag::Sequence);
+        assert_eq!(header.length, Length::from(12u8));
+        assert_eq!(reader.position(), Length::ZERO); // Position unchanged
+
+        reader.read_into(&mut [0u8; 2]).unwrap();
+        assert_eq!(reader.peek_tag().unwrap(), Tag::Integer);
+        assert_eq!(reader.peek_header().unwrap().length, Length::ONE);
+        assert_eq!(reader.position(), Length::from(2u8)); // Position unchanged
+    }
+}
@@ -12,16 +12,26 @@ pub struct NestedReader<'i, R> {
 
     /// Position within the nested input.
     position: Length,
+
+    /// Nesting depth of this reader.
+    depth: usize,
 }
 
 impl<'i, 'r, R: Reader<'r>> NestedReader<'i, R> {
     /// Create a new nested reader which can read the given [`Length`].
     pub(crate) fn new(inner: &'i mut R, len: Length) -> Result<Self> {
+        let depth = inner.depth().saturating_add(1);
+
+        if depth > inner.max_depth() {
+            return Err(ErrorKind::NestingLimitExceeded.at(inner.offset()));
+        }
+
         if len <= inner.remaining_len() {
             Ok(Self {
                 inner,
                 input_len: len,
                 position: Length::ZERO,
+                depth,
             })
         } else {
             Err(ErrorKind::Incomplete {
@@ -76,6 +86,18 @@ impl<'i, 'r, R: Reader<'r>> Reader<'r> for NestedReader<'i, R> {
         self.position
     }
 
+    fn depth(&self) -> usize {
+        self.depth
+    }
+
+    fn max_depth(&self) -> usize {
+        self.inner.max_depth()
+    }
+
+    fn lenient_charsets(&self) -> bool {
+        self.inner.lenient_charsets()
+    }
+
     fn read_slice(&mut self, len: Length) -> Result<&'r [u8]> {
         self.advance_position(len)?;
         self.inner.read_slice(len)
@@ -1,6 +1,9 @@
 //! Slice reader.
 
-use crate::{BytesRef, Decode, Error, ErrorKind, Header, Length, Reader, Result, Tag};
+use crate::{
+    reader::DEFAULT_MAX_DEPTH, BytesRef, Decode, Error, ErrorKind, Header, Length, Reader, Result,
+    Tag,
+};
 
 /// [`Reader`] which consumes an input byte slice.
 #[derive(Clone, Debug)]
@@ -13,6 +16,12 @@ pub struct SliceReader<'a> {
 
     /// Position within the decoded slice.
     position: Length,
+
+    /// Maximum nesting depth of constructed values.
+    max_depth: usize,
+
+    /// Ignore the character sets of restricted string types.
+    lenient_charsets: bool,
 }
 
 impl<'a> SliceReader<'a> {
@@ -22,9 +31,31 @@ impl<'a> SliceReader<'a> {
             bytes: BytesRef::new(bytes)?,
             failed: false,
             position: Length::ZERO,
+            max_depth: DEFAULT_MAX_DEPTH,
+            lenient_charsets: false,
         })
     }
 
+    /// Set the maximum nesting depth of constructed values permitted when
+    /// decoding, returning [`ErrorKind::NestingLimitExceeded`] for input
+    /// which exceeds it.
+    ///
+    /// Defaults to 64.
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Accept `PrintableString` and `IA5String` values containing characters
+    /// outside of their respective character sets, as long as they are valid
+    /// UTF-8.
+    ///
+    /// Defaults to `false`, i.e. such values are rejected.
+    pub fn with_lenient_charsets(mut self, lenient: bool) -> Self {
+        self.lenient_charsets = lenient;
+        self
+    }
+
     /// Return an error with the given [`ErrorKind`], annotating it with
     /// context about where the error occurred.
     pub fn error(&mut self, kind: ErrorKind) -> Error {
@@ -75,6 +106,14 @@ impl<'a> Reader<'a> for SliceReader<'a> {
         self.position
     }
 
+    fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
+    fn lenient_charsets(&self) -> bool {
+        self.lenient_charsets
+    }
+
     fn read_slice(&mut self, len: Length) -> Result<&'a [u8]> {
         if self.is_failed() {
             return Err(self.error(ErrorKind::Failed));
@@ -209,6 +248,48 @@ mod tests {
         let header = reader.peek_header().unwrap();
         assert_eq!(header.tag, Tag::Integer);
         assert_eq!(header.length, Length::ONE);
+        assert_eq!(header.tlv_len().unwrap(), Length::new(3));
         assert_eq!(reader.position(), Length::ZERO); // Position unchanged
     }
+
+    // SEQUENCE { SEQUENCE { INTEGER: 42 } }
+    const NESTED_MSG: &[u8] = &hex!("3005 3003 02012A");
+
+    #[test]
+    fn nesting_depth() {
+        let mut reader = SliceReader::new(NESTED_MSG).unwrap();
+        assert_eq!(reader.depth(), 0);
+
+        let n = reader
+            .sequence(|reader| {
+                assert_eq!(reader.depth(), 1);
+                reader.sequence(|reader| {
+                    assert_eq!(reader.depth(), 2);
+                    reader.decode::<u8>()
+                })
+            })
+            .unwrap();
+
+        assert_eq!(n, 42);
+    }
+
+    #[test]
+    fn nesting_limit_exceeded() {
+        let mut reader = SliceReader::new(NESTED_MSG).unwrap().with_max_depth(1);
+        let err = reader
+            .sequence(|reader| reader.sequence(|reader| reader.decode::<u8>()))
+            .unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::NestingLimitExceeded);
+        assert_eq!(err.position(), Some(Length::from(4u8)));
+    }
+
+    #[test]
+    fn from_der_with_max_depth() {
+        let value = <[[u8; 1]; 1]>::from_der_with_max_depth(NESTED_MSG, 2).unwrap();
+        assert_eq!(value, [[42]]);
+
+        let err = <[[u8; 1]; 1]>::from_der_with_max_depth(NESTED_MSG, 1).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NestingLimitExceeded);
+    }
 }
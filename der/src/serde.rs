@@ -0,0 +1,170 @@
+//! Support for embedding DER-encoded values in [`serde`] data formats.
+//!
+//! Values are serialized as their complete DER encoding (i.e. including the
+//! tag and length), which is encoded as padded Base64 with human-readable
+//! formats or as raw bytes with binary formats.
+
+use crate::{asn1::AnyRef, Any, Decode, Document, Encode};
+use ::serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+use alloc::vec::Vec;
+use base64ct::{Base64, Encoding};
+use core::fmt;
+
+#[cfg(feature = "zeroize")]
+use {crate::SecretDocument, zeroize::Zeroizing};
+
+impl Serialize for AnyRef<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_der(&self.to_der().map_err(ser::Error::custom)?, serializer)
+    }
+}
+
+/// Only binary formats which support borrowing bytes from the input can be
+/// used to deserialize an [`AnyRef`]. Use [`Any`] otherwise.
+impl<'de> Deserialize<'de> for AnyRef<'de> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct BorrowedVisitor;
+
+        impl<'de> de::Visitor<'de> for BorrowedVisitor {
+            type Value = AnyRef<'de>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("borrowed DER-encoded bytes")
+            }
+
+            fn visit_borrowed_bytes<E>(self, v: &'de [u8]) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                AnyRef::from_der(v).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_bytes(BorrowedVisitor)
+    }
+}
+
+impl Serialize for Any {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_der(&self.to_der().map_err(ser::Error::custom)?, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Any {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Any::from_der(&deserialize_der(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for Document {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_der(self.as_bytes(), serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Document {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Document::try_from(deserialize_der(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Serialize for SecretDocument {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            let encoded = Zeroizing::new(Base64::encode_string(self.as_bytes()));
+            serializer.serialize_str(&encoded)
+        } else {
+            serializer.serialize_bytes(self.as_bytes())
+        }
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<'de> Deserialize<'de> for SecretDocument {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let der_bytes = Zeroizing::new(deserialize_der(deserializer)?);
+        SecretDocument::try_from(der_bytes.as_slice()).map_err(de::Error::custom)
+    }
+}
+
+/// Serialize DER bytes as Base64 when using human-readable formats, or as
+/// raw bytes when using binary formats.
+fn serialize_der<S>(der_bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    if serializer.is_human_readable() {
+        serializer.serialize_str(&Base64::encode_string(der_bytes))
+    } else {
+        serializer.serialize_bytes(der_bytes)
+    }
+}
+
+/// Deserialize DER bytes from Base64 when using human-readable formats, or
+/// from raw bytes when using binary formats.
+fn deserialize_der<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct DerVisitor;
+
+    impl<'de> de::Visitor<'de> for DerVisitor {
+        type Value = Vec<u8>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("DER-encoded bytes or a Base64 string")
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Base64::decode_vec(v).map_err(E::custom)
+        }
+
+        fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(v.to_vec())
+        }
+
+        fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(v)
+        }
+    }
+
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(DerVisitor)
+    } else {
+        deserializer.deserialize_byte_buf(DerVisitor)
+    }
+}
@@ -35,6 +35,11 @@ impl StrOwned {
         })
     }
 
+    /// Decode a [`String`] from ISO 8859-1 (Latin-1) encoded bytes.
+    pub fn from_latin1(bytes: &[u8]) -> Result<Self> {
+        Self::new(bytes.iter().copied().map(char::from).collect())
+    }
+
     /// Borrow the inner `str`
     pub fn as_str(&self) -> &str {
         &self.inner
@@ -11,7 +11,7 @@ use crate::{Decode, DerOrd, Encode, Error, ErrorKind, Length, Reader, Result, Wr
 use core::{cmp::Ordering, fmt};
 
 /// Indicator bit for constructed form encoding (i.e. vs primitive form)
-const CONSTRUCTED_FLAG: u8 = 0b100000;
+pub(crate) const CONSTRUCTED_FLAG: u8 = 0b100000;
 
 /// Types which have a constant ASN.1 [`Tag`].
 pub trait FixedTag {
@@ -78,6 +78,12 @@ pub enum Tag {
     /// `UTF8String` tag: `12`.
     Utf8String,
 
+    /// `RELATIVE-OID` tag: `13`.
+    RelativeOid,
+
+    /// `TIME` tag: `14`.
+    Time,
+
     /// `SEQUENCE` tag: `16`.
     Sequence,
 
@@ -105,9 +111,15 @@ pub enum Tag {
     /// `GeneralizedTime` tag: `24`.
     GeneralizedTime,
 
+    /// `GraphicString` tag: `25`.
+    GraphicString,
+
     /// `VisibleString` tag: `26`.
     VisibleString,
 
+    /// `GeneralString` tag: `27`.
+    GeneralString,
+
     /// `BMPString` tag: `30`.
     BmpString,
 
@@ -152,7 +164,7 @@ impl Tag {
     }
 
     /// Get the [`Class`] that corresponds to this [`Tag`].
-    pub fn class(self) -> Class {
+    pub const fn class(self) -> Class {
         match self {
             Tag::Application { .. } => Class::Application,
             Tag::ContextSpecific { .. } => Class::ContextSpecific,
@@ -192,7 +204,7 @@ impl Tag {
     }
 
     /// Get the octet encoding for this [`Tag`].
-    pub fn octet(self) -> u8 {
+    pub const fn octet(self) -> u8 {
         match self {
             Tag::Boolean => 0x01,
             Tag::Integer => 0x02,
@@ -203,6 +215,8 @@ impl Tag {
             Tag::Real => 0x09,
             Tag::Enumerated => 0x0A,
             Tag::Utf8String => 0x0C,
+            Tag::RelativeOid => 0x0D,
+            Tag::Time => 0x0E,
             Tag::Sequence => 0x10 | CONSTRUCTED_FLAG,
             Tag::Set => 0x11 | CONSTRUCTED_FLAG,
             Tag::NumericString => 0x12,
@@ -212,7 +226,9 @@ impl Tag {
             Tag::Ia5String => 0x16,
             Tag::UtcTime => 0x17,
             Tag::GeneralizedTime => 0x18,
+            Tag::GraphicString => 0x19,
             Tag::VisibleString => 0x1A,
+            Tag::GeneralString => 0x1B,
             Tag::BmpString => 0x1E,
             Tag::Application {
                 constructed,
@@ -257,12 +273,42 @@ impl Tag {
     }
 }
 
+/// Get the primitive [`Tag`] for an identifier octet which denotes the
+/// constructed form of a `UNIVERSAL` string type.
+pub(crate) fn constructed_string_tag(octet: u8) -> Option<Tag> {
+    match octet {
+        0x23 => Some(Tag::BitString),
+        0x24 => Some(Tag::OctetString),
+        0x2C => Some(Tag::Utf8String),
+        0x32 => Some(Tag::NumericString),
+        0x33 => Some(Tag::PrintableString),
+        0x34 => Some(Tag::TeletexString),
+        0x35 => Some(Tag::VideotexString),
+        0x36 => Some(Tag::Ia5String),
+        0x39 => Some(Tag::GraphicString),
+        0x3A => Some(Tag::VisibleString),
+        0x3B => Some(Tag::GeneralString),
+        0x3E => Some(Tag::BmpString),
+        _ => None,
+    }
+}
+
 impl TryFrom<u8> for Tag {
     type Error = Error;
 
     fn try_from(byte: u8) -> Result<Tag> {
+        Tag::from_octet(byte)
+    }
+}
+
+impl Tag {
+    /// Parse a [`Tag`] from its identifier octet (const-friendly).
+    pub(crate) const fn from_octet(byte: u8) -> Result<Tag> {
         let constructed = byte & CONSTRUCTED_FLAG != 0;
-        let number = TagNumber::try_from(byte & TagNumber::MASK)?;
+        let number = match TagNumber::from_octet(byte) {
+            Some(number) => number,
+            None => return Err(Error::from_kind(ErrorKind::TagNumberInvalid)),
+        };
 
         match byte {
             0x01 => Ok(Tag::Boolean),
@@ -274,6 +320,8 @@ impl TryFrom<u8> for Tag {
             0x09 => Ok(Tag::Real),
             0x0A => Ok(Tag::Enumerated),
             0x0C => Ok(Tag::Utf8String),
+            0x0D => Ok(Tag::RelativeOid),
+            0x0E => Ok(Tag::Time),
             0x12 => Ok(Tag::NumericString),
             0x13 => Ok(Tag::PrintableString),
             0x14 => Ok(Tag::TeletexString),
@@ -281,7 +329,9 @@ impl TryFrom<u8> for Tag {
             0x16 => Ok(Tag::Ia5String),
             0x17 => Ok(Tag::UtcTime),
             0x18 => Ok(Tag::GeneralizedTime),
+            0x19 => Ok(Tag::GraphicString),
             0x1A => Ok(Tag::VisibleString),
+            0x1B => Ok(Tag::GeneralString),
             0x1E => Ok(Tag::BmpString),
             0x30 => Ok(Tag::Sequence), // constructed
             0x31 => Ok(Tag::Set),      // constructed
@@ -297,7 +347,7 @@ impl TryFrom<u8> for Tag {
                 constructed,
                 number,
             }),
-            _ => Err(ErrorKind::TagUnknown { byte }.into()),
+            _ => Err(Error::from_kind(ErrorKind::TagUnknown { byte })),
         }
     }
 }
@@ -350,6 +400,8 @@ impl fmt::Display for Tag {
             Tag::Real => f.write_str("REAL"),
             Tag::Enumerated => f.write_str("ENUMERATED"),
             Tag::Utf8String => f.write_str("UTF8String"),
+            Tag::RelativeOid => f.write_str("RELATIVE-OID"),
+            Tag::Time => f.write_str("TIME"),
             Tag::Set => f.write_str("SET"),
             Tag::NumericString => f.write_str("NumericString"),
             Tag::PrintableString => f.write_str("PrintableString"),
@@ -358,7 +410,9 @@ impl fmt::Display for Tag {
             Tag::Ia5String => f.write_str("IA5String"),
             Tag::UtcTime => f.write_str("UTCTime"),
             Tag::GeneralizedTime => f.write_str("GeneralizedTime"),
+            Tag::GraphicString => f.write_str("GraphicString"),
             Tag::VisibleString => f.write_str("VisibleString"),
+            Tag::GeneralString => f.write_str("GeneralString"),
             Tag::BmpString => f.write_str("BMPString"),
             Tag::Sequence => f.write_str("SEQUENCE"),
             Tag::Application {
@@ -414,6 +468,8 @@ mod tests {
         assert_eq!(Tag::Real.class(), Class::Universal);
         assert_eq!(Tag::Enumerated.class(), Class::Universal);
         assert_eq!(Tag::Utf8String.class(), Class::Universal);
+        assert_eq!(Tag::RelativeOid.class(), Class::Universal);
+        assert_eq!(Tag::Time.class(), Class::Universal);
         assert_eq!(Tag::Set.class(), Class::Universal);
         assert_eq!(Tag::NumericString.class(), Class::Universal);
         assert_eq!(Tag::PrintableString.class(), Class::Universal);
@@ -422,6 +478,8 @@ mod tests {
         assert_eq!(Tag::Ia5String.class(), Class::Universal);
         assert_eq!(Tag::UtcTime.class(), Class::Universal);
         assert_eq!(Tag::GeneralizedTime.class(), Class::Universal);
+        assert_eq!(Tag::GraphicString.class(), Class::Universal);
+        assert_eq!(Tag::GeneralString.class(), Class::Universal);
         assert_eq!(Tag::Sequence.class(), Class::Universal);
 
         for num in 0..=30 {
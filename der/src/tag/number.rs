@@ -158,8 +158,17 @@ impl TagNumber {
         }
     }
 
+    /// Get the tag number from the lower 5 bits of an identifier octet,
+    /// if supported.
+    pub(super) const fn from_octet(byte: u8) -> Option<Self> {
+        match byte & Self::MASK {
+            number @ 0..=Self::MAX => Some(Self(number)),
+            _ => None,
+        }
+    }
+
     /// Get the inner value.
-    pub fn value(self) -> u8 {
+    pub const fn value(self) -> u8 {
         self.0
     }
 }
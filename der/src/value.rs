@@ -0,0 +1,396 @@
+//! Generic ASN.1 value trees.
+//!
+//! The [`Value`] type represents arbitrary DER-encoded data as a tree, which
+//! makes it possible to manipulate messages generically (e.g. to patch or
+//! strip a field) and re-encode them without defining Rust types for them.
+
+use crate::{
+    asn1::{AnyRef, BitString, Ia5String, Int, Null, OctetString, PrintableString},
+    Any, Choice, Decode, DecodeValue, Encode, EncodeValue, Error, ErrorKind, Header, Length,
+    Reader, Result, SliceReader, Tag, Tagged, Writer,
+};
+use alloc::{string::String, vec::Vec};
+
+#[cfg(feature = "oid")]
+use crate::asn1::ObjectIdentifier;
+
+/// Owned ASN.1 value tree.
+///
+/// Universal types commonly used by PKIX/PKCS formats are decoded into their
+/// corresponding variants, constructed values (e.g. `SEQUENCE`s and
+/// `EXPLICIT`ly tagged values) are decoded recursively, and all other values
+/// are retained verbatim as [`Value::Primitive`].
+///
+/// ```
+/// use der::{value::Value, Decode, Encode};
+/// use hex_literal::hex;
+///
+/// // `SEQUENCE { INTEGER 1, BOOLEAN TRUE }`
+/// let mut value = Value::from_der(&hex!("30 06 02 01 01 01 01 ff")).unwrap();
+///
+/// // Strip the `BOOLEAN` field
+/// value.children_mut().unwrap().pop();
+/// assert_eq!(value.to_der().unwrap(), hex!("30 03 02 01 01"));
+/// ```
+///
+/// The elements of a [`Value::Set`] are sorted by their encodings when it's
+/// encoded, as required by DER. No such check is performed when decoding.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Value {
+    /// ASN.1 `BOOLEAN`.
+    Boolean(bool),
+
+    /// ASN.1 `INTEGER`.
+    Integer(Int),
+
+    /// ASN.1 `BIT STRING`.
+    BitString(BitString),
+
+    /// ASN.1 `OCTET STRING`.
+    OctetString(OctetString),
+
+    /// ASN.1 `NULL`.
+    Null,
+
+    /// ASN.1 `OBJECT IDENTIFIER`.
+    #[cfg(feature = "oid")]
+    ObjectIdentifier(ObjectIdentifier),
+
+    /// ASN.1 `UTF8String`.
+    Utf8String(String),
+
+    /// ASN.1 `PrintableString`.
+    PrintableString(PrintableString),
+
+    /// ASN.1 `IA5String`.
+    Ia5String(Ia5String),
+
+    /// ASN.1 `SEQUENCE` or `SEQUENCE OF`.
+    Sequence(Vec<Value>),
+
+    /// ASN.1 `SET` or `SET OF`.
+    Set(Vec<Value>),
+
+    /// Any other constructed value, e.g. an `EXPLICIT`ly tagged one.
+    Constructed(Tag, Vec<Value>),
+
+    /// Any other primitive value, e.g. an `IMPLICIT`ly tagged one.
+    Primitive(Any),
+}
+
+impl Value {
+    /// Borrow the child values of a constructed value.
+    pub fn children(&self) -> Option<&[Value]> {
+        match self {
+            Self::Sequence(values) | Self::Set(values) | Self::Constructed(_, values) => {
+                Some(values)
+            }
+            _ => None,
+        }
+    }
+
+    /// Mutably borrow the child values of a constructed value.
+    pub fn children_mut(&mut self) -> Option<&mut Vec<Value>> {
+        match self {
+            Self::Sequence(values) | Self::Set(values) | Self::Constructed(_, values) => {
+                Some(values)
+            }
+            _ => None,
+        }
+    }
+
+    /// Create a constructed value with the given tag and child values.
+    fn constructed(tag: Tag, values: Vec<Value>) -> Self {
+        match tag {
+            Tag::Sequence => Self::Sequence(values),
+            Tag::Set => Self::Set(values),
+            tag => Self::Constructed(tag, values),
+        }
+    }
+}
+
+impl Choice<'_> for Value {
+    fn can_decode(_: Tag) -> bool {
+        true
+    }
+}
+
+impl<'a> Decode<'a> for Value {
+    fn decode<R: Reader<'a>>(reader: &mut R) -> Result<Self> {
+        let header = Header::decode(reader)?;
+        Self::decode_value(reader, header)
+    }
+}
+
+impl<'a> DecodeValue<'a> for Value {
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
+        if !header.tag.is_constructed() {
+            return decode_primitive(reader, header);
+        }
+
+        // Child values are decoded from a `SliceReader` to avoid recursively
+        // instantiating `NestedReader<NestedReader<...>>`
+        let contents = reader.read_vec(header.length)?;
+        let depth = reader.depth().saturating_add(1);
+
+        if depth > reader.max_depth() {
+            return Err(reader.error(ErrorKind::NestingLimitExceeded));
+        }
+
+        let values = decode_children(&contents, depth, reader.max_depth())?;
+        Ok(Self::constructed(header.tag, values))
+    }
+}
+
+impl EncodeValue for Value {
+    fn value_len(&self) -> Result<Length> {
+        match self {
+            Self::Boolean(value) => value.value_len(),
+            Self::Integer(value) => value.value_len(),
+            Self::BitString(value) => value.value_len(),
+            Self::OctetString(value) => value.value_len(),
+            Self::Null => Null.value_len(),
+            #[cfg(feature = "oid")]
+            Self::ObjectIdentifier(value) => value.value_len(),
+            Self::Utf8String(value) => value.value_len(),
+            Self::PrintableString(value) => value.value_len(),
+            Self::Ia5String(value) => value.value_len(),
+            Self::Sequence(values) | Self::Set(values) | Self::Constructed(_, values) => values
+                .iter()
+                .try_fold(Length::ZERO, |len, value| len + value.encoded_len()?),
+            Self::Primitive(value) => value.value_len(),
+        }
+    }
+
+    fn encode_value(&self, writer: &mut impl Writer) -> Result<()> {
+        match self {
+            Self::Boolean(value) => value.encode_value(writer),
+            Self::Integer(value) => value.encode_value(writer),
+            Self::BitString(value) => value.encode_value(writer),
+            Self::OctetString(value) => value.encode_value(writer),
+            Self::Null => Null.encode_value(writer),
+            #[cfg(feature = "oid")]
+            Self::ObjectIdentifier(value) => value.encode_value(writer),
+            Self::Utf8String(value) => value.encode_value(writer),
+            Self::PrintableString(value) => value.encode_value(writer),
+            Self::Ia5String(value) => value.encode_value(writer),
+            Self::Sequence(values) | Self::Constructed(_, values) => {
+                values.iter().try_for_each(|value| value.encode(writer))
+            }
+            Self::Set(values) => {
+                let mut encodings = values
+                    .iter()
+                    .map(Encode::to_der)
+                    .collect::<Result<Vec<_>>>()?;
+
+                encodings.sort();
+                encodings.iter().try_for_each(|der| writer.write(der))
+            }
+            Self::Primitive(value) => value.encode_value(writer),
+        }
+    }
+}
+
+impl Tagged for Value {
+    fn tag(&self) -> Tag {
+        match self {
+            Self::Boolean(_) => Tag::Boolean,
+            Self::Integer(_) => Tag::Integer,
+            Self::BitString(_) => Tag::BitString,
+            Self::OctetString(_) => Tag::OctetString,
+            Self::Null => Tag::Null,
+            #[cfg(feature = "oid")]
+            Self::ObjectIdentifier(_) => Tag::ObjectIdentifier,
+            Self::Utf8String(_) => Tag::Utf8String,
+            Self::PrintableString(_) => Tag::PrintableString,
+            Self::Ia5String(_) => Tag::Ia5String,
+            Self::Sequence(_) => Tag::Sequence,
+            Self::Set(_) => Tag::Set,
+            Self::Constructed(tag, _) => *tag,
+            Self::Primitive(value) => value.tag(),
+        }
+    }
+}
+
+impl TryFrom<AnyRef<'_>> for Value {
+    type Error = Error;
+
+    fn try_from(any: AnyRef<'_>) -> Result<Value> {
+        any.decode_as()
+    }
+}
+
+impl TryFrom<&Any> for Value {
+    type Error = Error;
+
+    fn try_from(any: &Any) -> Result<Value> {
+        any.decode_as()
+    }
+}
+
+impl TryFrom<&Value> for Any {
+    type Error = Error;
+
+    fn try_from(value: &Value) -> Result<Any> {
+        Any::encode_from(value)
+    }
+}
+
+/// Decode a primitive value.
+fn decode_primitive<'a, R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Value> {
+    match header.tag {
+        Tag::Boolean => bool::decode_value(reader, header).map(Value::Boolean),
+        Tag::Integer => Int::decode_value(reader, header).map(Value::Integer),
+        Tag::BitString => BitString::decode_value(reader, header).map(Value::BitString),
+        Tag::OctetString => OctetString::decode_value(reader, header).map(Value::OctetString),
+        Tag::Null => Null::decode_value(reader, header).map(|_| Value::Null),
+        #[cfg(feature = "oid")]
+        Tag::ObjectIdentifier => {
+            ObjectIdentifier::decode_value(reader, header).map(Value::ObjectIdentifier)
+        }
+        Tag::Utf8String => String::decode_value(reader, header).map(Value::Utf8String),
+        Tag::PrintableString => {
+            PrintableString::decode_value(reader, header).map(Value::PrintableString)
+        }
+        Tag::Ia5String => Ia5String::decode_value(reader, header).map(Value::Ia5String),
+        _ => Any::decode_value(reader, header).map(Value::Primitive),
+    }
+}
+
+/// Decode the child values contained in the given contents of a constructed
+/// value, which is nested `depth` levels deep.
+fn decode_children(contents: &[u8], depth: usize, max_depth: usize) -> Result<Vec<Value>> {
+    let mut reader = SliceReader::new(contents)?;
+    let mut values = Vec::new();
+
+    while !reader.is_finished() {
+        let offset = reader.position();
+        let header = Header::decode(&mut reader)?;
+
+        let value = if header.tag.is_constructed() {
+            if depth >= max_depth {
+                return Err(ErrorKind::NestingLimitExceeded.at(offset));
+            }
+
+            let contents_offset = reader.position();
+            let contents = reader.read_slice(header.length)?;
+            let children = decode_children(contents, depth.saturating_add(1), max_depth)
+                .map_err(|e| e.nested(contents_offset))?;
+
+            Value::constructed(header.tag, children)
+        } else {
+            decode_primitive(&mut reader, header)?
+        };
+
+        values.push(value);
+    }
+
+    reader.finish(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Value;
+    use crate::{
+        asn1::{Int, OctetString},
+        Any, Decode, Encode, ErrorKind, Tag, TagNumber,
+    };
+    use hex_literal::hex;
+
+    /// X.509 `Extension` for `basicConstraints` with `cA` set.
+    #[cfg(feature = "oid")]
+    const EXTENSION_DER: &[u8] = &hex!("30 0f 06 03 55 1d 13 01 01 ff 04 05 30 03 01 01 ff");
+
+    #[cfg(feature = "oid")]
+    #[test]
+    fn round_trip() {
+        let value = Value::from_der(EXTENSION_DER).unwrap();
+        let children = value.children().unwrap();
+        assert_eq!(children.len(), 3);
+        assert_eq!(
+            children[0],
+            Value::ObjectIdentifier("2.5.29.19".parse().unwrap())
+        );
+        assert_eq!(children[1], Value::Boolean(true));
+        assert_eq!(value.to_der().unwrap(), EXTENSION_DER);
+    }
+
+    #[cfg(feature = "oid")]
+    #[test]
+    fn patch_extension_value() {
+        let mut value = Value::from_der(EXTENSION_DER).unwrap();
+
+        // Replace the `BasicConstraints` with an empty `SEQUENCE`
+        let extn_value = Value::Sequence(vec![]).to_der().unwrap();
+        value.children_mut().unwrap()[2] =
+            Value::OctetString(OctetString::new(extn_value).unwrap());
+
+        assert_eq!(
+            value.to_der().unwrap(),
+            hex!("30 0c 06 03 55 1d 13 01 01 ff 04 02 30 00")
+        );
+    }
+
+    #[test]
+    fn tagged_values() {
+        // `[0] EXPLICIT INTEGER 1` followed by `[1] IMPLICIT OCTET STRING`
+        let der = hex!("30 08 a0 03 02 01 01 81 01 aa");
+        let value = Value::from_der(&der).unwrap();
+        let children = value.children().unwrap();
+
+        assert_eq!(
+            children[0],
+            Value::Constructed(
+                Tag::ContextSpecific {
+                    constructed: true,
+                    number: TagNumber::N0
+                },
+                vec![Value::Integer(Int::new(&[1]).unwrap())]
+            )
+        );
+        assert_eq!(
+            children[1],
+            Value::Primitive(
+                Any::new(
+                    Tag::ContextSpecific {
+                        constructed: false,
+                        number: TagNumber::N1
+                    },
+                    [0xaa]
+                )
+                .unwrap()
+            )
+        );
+        assert_eq!(value.to_der().unwrap(), der);
+    }
+
+    #[test]
+    fn set_elements_are_sorted() {
+        let set = Value::Set(vec![
+            Value::Integer(Int::new(&[2]).unwrap()),
+            Value::Integer(Int::new(&[1]).unwrap()),
+        ]);
+        assert_eq!(set.to_der().unwrap(), hex!("31 06 02 01 01 02 01 02"));
+    }
+
+    #[test]
+    fn rejects_deep_nesting() {
+        let mut value = Value::Null;
+
+        for _ in 0..100 {
+            value = Value::Sequence(vec![value]);
+        }
+
+        let err = Value::from_der(&value.to_der().unwrap()).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NestingLimitExceeded);
+    }
+
+    #[test]
+    fn any_conversions() {
+        let any = Any::from_der(&hex!("30 03 0c 01 41")).unwrap();
+        let value = Value::try_from(&any).unwrap();
+        assert_eq!(value, Value::Sequence(vec![Value::Utf8String("A".into())]));
+        assert_eq!(Any::try_from(&value).unwrap(), any);
+    }
+}
@@ -1,14 +1,13 @@
 //! Writer trait.
 
+#[cfg(feature = "std")]
+pub(crate) mod io;
 #[cfg(feature = "pem")]
 pub(crate) mod pem;
 pub(crate) mod slice;
 
 use crate::Result;
 
-#[cfg(feature = "std")]
-use std::io;
-
 /// Writer trait which outputs encoded DER.
 pub trait Writer {
     /// Write the given DER-encoded bytes as output.
@@ -21,9 +20,9 @@ pub trait Writer {
 }
 
 #[cfg(feature = "std")]
-impl<W: io::Write> Writer for W {
+impl<W: std::io::Write> Writer for W {
     fn write(&mut self, slice: &[u8]) -> Result<()> {
-        <Self as io::Write>::write(self, slice)?;
+        <Self as std::io::Write>::write_all(self, slice)?;
         Ok(())
     }
 }
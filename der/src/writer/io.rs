@@ -0,0 +1,185 @@
+//! Streaming writer for [`std::io::Write`] sinks.
+
+use super::Writer;
+use crate::{Encode, Error, ErrorKind, Length, Result};
+use std::io;
+
+/// [`Writer`] which streams encoded DER into a [`std::io::Write`] sink
+/// such as a file or socket, without buffering the complete message.
+///
+/// ASN.1 DER headers contain the length of the value which follows them,
+/// which this crate computes using [`Encode::encoded_len`] prior to writing
+/// each header. [`IoWriter::encode`] additionally checks that the number of
+/// bytes written matches the computed length of the message.
+///
+/// Each TLV production is written using several small writes, so unbuffered
+/// sinks should be wrapped in a [`std::io::BufWriter`].
+///
+/// ```
+/// # fn main() -> der::Result<()> {
+/// use der::{IoWriter, Length};
+///
+/// let mut writer = IoWriter::new(Vec::new());
+/// let len = writer.encode(&[1u8, 2u8])?;
+/// let output = writer.finish()?;
+///
+/// assert_eq!(len, Length::new(8));
+/// assert_eq!(output, [0x30, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x02]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct IoWriter<W> {
+    /// Inner I/O sink.
+    inner: W,
+
+    /// Total number of bytes written so far.
+    position: Length,
+}
+
+impl<W: io::Write> IoWriter<W> {
+    /// Create a new writer for the given I/O sink.
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner,
+            position: Length::ZERO,
+        }
+    }
+
+    /// Encode a value which impls the [`Encode`] trait, returning the number
+    /// of bytes written.
+    pub fn encode<T: Encode>(&mut self, encodable: &T) -> Result<Length> {
+        let start = self.position;
+        let expected_len = encodable.encoded_len()?;
+        encodable.encode(self).map_err(|e| e.nested(start))?;
+
+        let actual_len = (self.position - start)?;
+
+        if expected_len != actual_len {
+            return Err(ErrorKind::Incomplete {
+                expected_len,
+                actual_len,
+            }
+            .at(start));
+        }
+
+        Ok(actual_len)
+    }
+
+    /// Get the total number of bytes written so far.
+    pub fn position(&self) -> Length {
+        self.position
+    }
+
+    /// Borrow the inner I/O sink.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Flush the inner I/O sink, returning it on success.
+    pub fn finish(mut self) -> Result<W> {
+        self.inner.flush().map_err(|e| io_error(e, self.position))?;
+
+        Ok(self.inner)
+    }
+}
+
+impl<W: io::Write> Writer for IoWriter<W> {
+    fn write(&mut self, slice: &[u8]) -> Result<()> {
+        let end = (self.position + Length::try_from(slice.len())?)?;
+
+        self.inner
+            .write_all(slice)
+            .map_err(|e| io_error(e, self.position))?;
+
+        self.position = end;
+        Ok(())
+    }
+}
+
+/// Annotate an I/O error with the position at which it occurred.
+fn io_error(err: io::Error, position: Length) -> Error {
+    Error::from(err).kind().at(position)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IoWriter;
+    use crate::{asn1::OctetStringRef, Encode, ErrorKind, Length, Writer};
+    use hex_literal::hex;
+    use std::{io, vec::Vec};
+
+    /// I/O sink which accepts at most one byte per write and fails once a
+    /// given number of bytes have been written.
+    struct OneByteAtATime {
+        written: Vec<u8>,
+        limit: usize,
+    }
+
+    impl io::Write for OneByteAtATime {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            if self.written.len() == self.limit {
+                return Err(io::ErrorKind::WriteZero.into());
+            }
+
+            self.written.extend(buf.iter().take(1));
+            Ok(buf.len().min(1))
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn encode_sequence_of() {
+        let msg = [1u32, 256, 65536];
+        let mut writer = IoWriter::new(Vec::new());
+        assert_eq!(writer.encode(&msg).unwrap(), Length::new(14));
+        assert_eq!(writer.position(), Length::new(14));
+        assert_eq!(
+            writer.finish().unwrap(),
+            hex!("300C 020101 02020100 0203010000")
+        );
+    }
+
+    #[test]
+    fn encode_multiple_messages() {
+        let octets = OctetStringRef::new(&[0xAB; 300]).unwrap();
+
+        let mut writer = IoWriter::new(Vec::new());
+        assert_eq!(writer.encode(&true).unwrap(), Length::new(3));
+        assert_eq!(writer.encode(&octets).unwrap(), Length::new(304));
+        assert_eq!(writer.position(), Length::new(307));
+
+        let output = writer.finish().unwrap();
+        assert_eq!(output[..3], hex!("0101FF"));
+        assert_eq!(output[3..], octets.to_der().unwrap());
+    }
+
+    #[test]
+    fn partial_writes() {
+        let sink = OneByteAtATime {
+            written: Vec::new(),
+            limit: usize::MAX,
+        };
+
+        let mut writer = IoWriter::new(sink);
+        writer.encode(&[1u8, 2u8]).unwrap();
+        assert_eq!(writer.finish().unwrap().written, hex!("3006 020101 020102"));
+    }
+
+    #[test]
+    fn io_error_position() {
+        let sink = OneByteAtATime {
+            written: Vec::new(),
+            limit: 3,
+        };
+
+        let mut writer = IoWriter::new(sink);
+        let err = writer.write(&hex!("3006 020101 020102")).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::Io(io::ErrorKind::WriteZero));
+        assert_eq!(err.position(), Some(Length::ZERO));
+        assert_eq!(writer.position(), Length::ZERO);
+    }
+}
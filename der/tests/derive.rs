@@ -195,6 +195,39 @@ mod enumerated {
         CrlReason::KeyCompromise.encode(&mut encoder).unwrap();
         assert_eq!(KEY_COMPROMISE_DER, encoder.finish().unwrap());
     }
+
+    /// CMP `PKIStatus`, which tolerates status codes not listed below.
+    #[derive(Enumerated, Copy, Clone, Debug, Eq, PartialEq)]
+    #[asn1(type = "INTEGER")]
+    #[repr(u8)]
+    pub enum PkiStatus {
+        Accepted = 0,
+        GrantedWithMods = 1,
+        Rejection = 2,
+        #[asn1(other = "true")]
+        Unknown(u8),
+    }
+
+    #[test]
+    fn decode_other() {
+        let rejection = PkiStatus::from_der(&hex!("02 01 02")).unwrap();
+        assert_eq!(PkiStatus::Rejection, rejection);
+
+        let unknown = PkiStatus::from_der(&hex!("02 01 07")).unwrap();
+        assert_eq!(PkiStatus::Unknown(7), unknown);
+
+        // Unknown values are still subject to the `repr`
+        assert!(PkiStatus::from_der(&hex!("02 02 01 00")).is_err());
+    }
+
+    #[test]
+    fn encode_other() {
+        assert_eq!(
+            PkiStatus::GrantedWithMods.to_der().unwrap(),
+            hex!("02 01 01")
+        );
+        assert_eq!(PkiStatus::Unknown(7).to_der().unwrap(), hex!("02 01 07"));
+    }
 }
 
 /// Custom derive test cases for the `Sequence` macro.
@@ -336,7 +369,6 @@ mod sequence {
     }
 
     /// X.509 extension
-    // TODO(tarcieri): tests for code derived with the `default` attribute
     #[derive(Clone, Debug, Eq, PartialEq, Sequence, ValueOrd)]
     pub struct Extension<'a> {
         extn_id: ObjectIdentifier,
@@ -381,6 +413,26 @@ mod sequence {
         pub typed_context_specific_optional: Option<&'a [u8]>,
     }
 
+    /// Sequence with `DEFAULT` fields combined with ASN.1 types and
+    /// context-specific tags
+    #[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+    pub struct DefaultExample<'a> {
+        #[asn1(default = "default_false_example")]
+        pub flag: bool,
+        #[asn1(type = "OCTET STRING", default = "default_bytes_example")]
+        pub bytes: &'a [u8],
+        #[asn1(
+            context_specific = "0",
+            type = "OCTET STRING",
+            default = "default_bytes_example"
+        )]
+        pub tagged_bytes: &'a [u8],
+    }
+
+    fn default_bytes_example<'a>() -> &'a [u8] {
+        &[0xAA]
+    }
+
     #[test]
     fn idp_test() {
         let idp = IssuingDistributionPointExample::from_der(&hex!("30038101FF")).unwrap();
@@ -433,6 +485,37 @@ mod sequence {
         assert_eq!(ext2.critical, false);
     }
 
+    #[test]
+    fn default_values_omitted() {
+        let example = DefaultExample {
+            flag: false,
+            bytes: &[0xAA],
+            tagged_bytes: &[0xAA],
+        };
+
+        let der = example.to_der().unwrap();
+        assert_eq!(der, hex!("3000"));
+        assert_eq!(DefaultExample::from_der(&der).unwrap(), example);
+    }
+
+    #[test]
+    fn non_default_values_encoded() {
+        let example = DefaultExample {
+            flag: true,
+            bytes: &[0xBB],
+            tagged_bytes: &[0xCC],
+        };
+
+        let der = example.to_der().unwrap();
+        assert_eq!(der, hex!("300B 0101FF 0401BB A003 0401CC"));
+        assert_eq!(DefaultExample::from_der(&der).unwrap(), example);
+
+        let partial = DefaultExample::from_der(&hex!("3005 A003 0401CC")).unwrap();
+        assert_eq!(partial.flag, false);
+        assert_eq!(partial.bytes, &[0xAA]);
+        assert_eq!(partial.tagged_bytes, &[0xCC]);
+    }
+
     #[test]
     fn decode() {
         let algorithm_identifier = AlgorithmIdentifier::from_der(ALGORITHM_IDENTIFIER_DER).unwrap();
@@ -459,3 +542,328 @@ mod sequence {
         );
     }
 }
+
+/// Custom derive test cases for types which are generic over the capacity of
+/// heapless `SET OF` and `SEQUENCE OF` fields.
+mod heapless {
+    use der::{
+        asn1::{SequenceOf, SetOf},
+        Choice, Decode, Encode, ErrorKind, Sequence, ValueOrd,
+    };
+    use hex_literal::hex;
+
+    #[derive(Choice, Clone, Debug, Eq, PartialEq, ValueOrd)]
+    pub enum Value<const N: usize> {
+        Integer(u8),
+        Integers(SequenceOf<u8, N>),
+    }
+
+    #[derive(Clone, Debug, Eq, PartialEq, Sequence, ValueOrd)]
+    pub struct Signer<const N: usize> {
+        pub version: u8,
+        pub values: SetOf<Value<N>, N>,
+    }
+
+    #[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+    pub struct SignedData<const N: usize, const M: usize> {
+        pub signers: SetOf<Signer<N>, M>,
+    }
+
+    const SIGNED_DATA_DER: &[u8] = &hex!("3014 3112 3010 020100 310B 020101 3006 020102 020103");
+
+    fn signed_data_example() -> SignedData<2, 1> {
+        let values = [Value::Integers(SequenceOf::from([2, 3])), Value::Integer(1)];
+        let signer = Signer {
+            version: 0,
+            values: SetOf::try_from(values).unwrap(),
+        };
+
+        SignedData {
+            signers: SetOf::try_from([signer]).unwrap(),
+        }
+    }
+
+    #[test]
+    fn decode() {
+        let signed_data = SignedData::<2, 1>::from_der(SIGNED_DATA_DER).unwrap();
+        assert_eq!(signed_data, signed_data_example());
+    }
+
+    #[test]
+    fn encode() {
+        let mut buf = [0u8; 32];
+        let der = signed_data_example().encode_to_slice(&mut buf).unwrap();
+        assert_eq!(der, SIGNED_DATA_DER);
+    }
+
+    #[test]
+    fn decode_exceeding_capacity() {
+        let err = SignedData::<1, 1>::from_der(SIGNED_DATA_DER).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Overlength);
+    }
+}
+
+/// Custom derive test cases for `#[asn1(inner)]` fields, i.e. values which
+/// are DER-encoded inside of an `OCTET STRING`.
+mod inner {
+    use der::{Decode, Encode, Sequence};
+    use hex_literal::hex;
+
+    #[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+    pub struct BasicConstraints {
+        pub ca: bool,
+    }
+
+    /// X.509 `Extension`-like structure.
+    #[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+    pub struct Extension {
+        pub extn_id: u8,
+        #[asn1(inner)]
+        pub extn_value: BasicConstraints,
+    }
+
+    /// CMS `EncapsulatedContentInfo`-like structure.
+    #[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+    pub struct EncapsulatedContentInfo {
+        pub content_type: u8,
+        #[asn1(context_specific = "0", inner, optional)]
+        pub econtent: Option<BasicConstraints>,
+    }
+
+    const EXTENSION_DER: &[u8] = &hex!("300A 020105 0405 30030101FF");
+
+    const CONTENT_INFO_DER: &[u8] = &hex!("300C 020101 A007 0405 30030101FF");
+
+    #[test]
+    fn extension_round_trip() {
+        let extension = Extension::from_der(EXTENSION_DER).unwrap();
+        assert_eq!(
+            extension,
+            Extension {
+                extn_id: 5,
+                extn_value: BasicConstraints { ca: true }
+            }
+        );
+        assert_eq!(extension.to_der().unwrap(), EXTENSION_DER);
+    }
+
+    #[test]
+    fn extension_invalid_inner_value() {
+        assert!(Extension::from_der(&hex!("3006 020105 0401AA")).is_err());
+    }
+
+    #[test]
+    fn optional_context_specific_round_trip() {
+        let info = EncapsulatedContentInfo::from_der(CONTENT_INFO_DER).unwrap();
+        assert_eq!(info.econtent, Some(BasicConstraints { ca: true }));
+        assert_eq!(info.to_der().unwrap(), CONTENT_INFO_DER);
+
+        let info = EncapsulatedContentInfo {
+            content_type: 1,
+            econtent: None,
+        };
+        let der = info.to_der().unwrap();
+        assert_eq!(der, hex!("3003 020101"));
+        assert_eq!(EncapsulatedContentInfo::from_der(&der).unwrap(), info);
+    }
+}
+
+/// Custom derive test cases for `#[asn1(auto_tag)]`, i.e. context-specific
+/// tag numbers assigned by position.
+mod auto_tag {
+    use der::{
+        asn1::{OctetStringRef, Utf8StringRef},
+        Choice, Decode, Encode, Sequence,
+    };
+    use hex_literal::hex;
+
+    /// `[0] IMPLICIT OCTET STRING, [1] IMPLICIT UTF8String` alternatives.
+    #[derive(Choice, Clone, Copy, Debug, Eq, PartialEq)]
+    #[asn1(auto_tag, tag_mode = "IMPLICIT")]
+    pub enum Name<'a> {
+        #[asn1(type = "OCTET STRING")]
+        Id(OctetStringRef<'a>),
+        #[asn1(type = "UTF8String")]
+        Text(Utf8StringRef<'a>),
+    }
+
+    /// Sequence with optional fields tagged `[0]`, `[1]` and `[5]`.
+    #[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+    #[asn1(auto_tag)]
+    pub struct Record<'a> {
+        #[asn1(optional)]
+        pub version: Option<u8>,
+        #[asn1(optional)]
+        pub name: Option<Name<'a>>,
+        #[asn1(context_specific = "5", optional)]
+        pub flag: Option<bool>,
+    }
+
+    #[test]
+    fn choice_round_trip() {
+        let id = Name::from_der(&hex!("8001 2A")).unwrap();
+        assert_eq!(id, Name::Id(OctetStringRef::new(&[42]).unwrap()));
+        assert_eq!(id.to_der().unwrap(), hex!("8001 2A"));
+
+        let text = Name::from_der(&hex!("8102 6869")).unwrap();
+        assert_eq!(text, Name::Text(Utf8StringRef::new("hi").unwrap()));
+        assert_eq!(text.to_der().unwrap(), hex!("8102 6869"));
+    }
+
+    #[test]
+    fn sequence_round_trip() {
+        const RECORD_DER: &[u8] = &hex!("3010 A003 020102 A104 8102 6869 A503 0101FF");
+
+        let record = Record::from_der(RECORD_DER).unwrap();
+        assert_eq!(
+            record,
+            Record {
+                version: Some(2),
+                name: Some(Name::Text(Utf8StringRef::new("hi").unwrap())),
+                flag: Some(true),
+            }
+        );
+        assert_eq!(record.to_der().unwrap(), RECORD_DER);
+
+        let record = Record::from_der(&hex!("3005 A503 0101FF")).unwrap();
+        assert_eq!(record.version, None);
+        assert_eq!(record.name, None);
+    }
+}
+
+/// Custom derive test cases for `ErrorPath` annotations.
+#[cfg(feature = "error-path")]
+mod error_path {
+    use der::{asn1::OctetStringRef, Decode, PathSegment, Sequence};
+    use hex_literal::hex;
+
+    #[derive(Debug, Sequence)]
+    pub struct Extension<'a> {
+        pub extn_id: u8,
+        pub extn_value: OctetStringRef<'a>,
+    }
+
+    #[derive(Debug, Sequence)]
+    pub struct TbsCertificate<'a> {
+        pub version: u8,
+        pub extensions: Vec<Extension<'a>>,
+    }
+
+    #[derive(Debug, Sequence)]
+    pub struct Certificate<'a> {
+        pub tbs_certificate: TbsCertificate<'a>,
+    }
+
+    /// The `extn_value` of the second extension is an `INTEGER`.
+    const INVALID_EXTENSION_DER: &[u8] =
+        &hex!("3017 3015 020100 3010 3006020101 0401AA 3006020102 0201AA");
+
+    #[test]
+    fn field_path() {
+        let err = Certificate::from_der(INVALID_EXTENSION_DER).unwrap_err();
+
+        assert_eq!(
+            err.path().segments().collect::<Vec<_>>(),
+            [
+                PathSegment::Field("tbs_certificate"),
+                PathSegment::Field("extensions"),
+                PathSegment::Index(1),
+                PathSegment::Field("extn_value"),
+            ]
+        );
+        assert!(!err.path().is_truncated());
+        assert!(err
+            .to_string()
+            .starts_with("tbs_certificate.extensions[1].extn_value @ offset 0x"));
+    }
+}
+
+/// Custom derive types which also derive `Arbitrary`, as used for fuzzing.
+#[cfg(all(feature = "arbitrary", feature = "oid"))]
+mod arbitrary {
+    use arbitrary::{Arbitrary, Unstructured};
+    use der::{
+        asn1::{
+            Any, BitString, ContextSpecific, GeneralizedTime, Int, Null, ObjectIdentifier,
+            OctetString, SetOfVec, UtcTime,
+        },
+        Choice, Decode, Encode, Enumerated, Sequence, ValueOrd,
+    };
+
+    #[derive(Arbitrary, Enumerated, Copy, Clone, Debug, Eq, PartialEq)]
+    #[repr(u32)]
+    pub enum Reason {
+        Unspecified = 0,
+        KeyCompromise = 1,
+        RemoveFromCrl = 8,
+    }
+
+    #[derive(Arbitrary, Choice, Clone, Debug, Eq, PartialEq, ValueOrd)]
+    pub enum Timestamp {
+        #[asn1(type = "UTCTime")]
+        UtcTime(UtcTime),
+
+        #[asn1(type = "GeneralizedTime")]
+        GeneralTime(GeneralizedTime),
+
+        Null(Null),
+    }
+
+    #[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Sequence)]
+    pub struct Example {
+        pub algorithm: ObjectIdentifier,
+        pub parameters: Any,
+        pub serial: Int,
+        pub key: BitString,
+        pub data: OctetString,
+        pub time: Timestamp,
+        pub reason: Reason,
+        pub policies: SetOfVec<ObjectIdentifier>,
+        #[asn1(context_specific = "0", optional = "true")]
+        pub extra: Option<ContextSpecific<OctetString>>,
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut seed = 0x2545_f491_4f6c_dd1d_u64;
+        let mut successes = 0;
+
+        for _ in 0..256 {
+            let data: Vec<u8> = (0..512)
+                .map(|_| {
+                    seed ^= seed << 13;
+                    seed ^= seed >> 7;
+                    seed ^= seed << 17;
+                    seed.to_le_bytes()[0]
+                })
+                .collect();
+
+            let example = match Example::arbitrary(&mut Unstructured::new(&data)) {
+                Ok(example) => example,
+                Err(_) => continue,
+            };
+
+            // const-oid 0.9 can construct OIDs with large arcs which it then
+            // fails to parse, so skip those
+            if core::iter::once(&example.algorithm)
+                .chain(example.policies.iter())
+                .any(|oid| ObjectIdentifier::from_bytes(oid.as_bytes()).is_err())
+            {
+                continue;
+            }
+
+            // `extra` is `ContextSpecific`, so its tag must match the field
+            if let Some(extra) = &example.extra {
+                if extra.tag_number.value() != 0 || extra.tag_mode != Default::default() {
+                    continue;
+                }
+            }
+
+            let der = example.to_der().unwrap();
+            assert_eq!(Example::from_der(&der).unwrap(), example);
+            successes += 1;
+        }
+
+        assert!(successes > 0);
+    }
+}
@@ -0,0 +1,73 @@
+//! `serde` support tests.
+
+#![cfg(feature = "serde")]
+
+use der::{asn1::AnyRef, Any, Document, Tag};
+use hex_literal::hex;
+
+/// `INTEGER 42`
+const INTEGER_DER: &[u8] = &hex!("02 01 2A");
+
+/// `SEQUENCE { INTEGER 42 }`
+const SEQUENCE_DER: &[u8] = &hex!("30 03 02 01 2A");
+
+#[test]
+fn any_json() {
+    let any = Any::new(Tag::Integer, [42]).unwrap();
+    let json = serde_json::to_string(&any).unwrap();
+    assert_eq!(json, "\"AgEq\"");
+    assert_eq!(serde_json::from_str::<Any>(&json).unwrap(), any);
+
+    // Contents must be a single TLV production
+    assert!(serde_json::from_str::<Any>("\"AgEqAA==\"").is_err());
+}
+
+#[test]
+fn any_ref_bincode() {
+    let any = AnyRef::new(Tag::Integer, &[42]).unwrap();
+    let bytes = bincode::serialize(&any).unwrap();
+    assert!(bytes.ends_with(INTEGER_DER));
+    assert_eq!(bincode::deserialize::<AnyRef<'_>>(&bytes).unwrap(), any);
+}
+
+#[test]
+fn any_ref_json() {
+    let any = AnyRef::new(Tag::Integer, &[42]).unwrap();
+    assert_eq!(serde_json::to_string(&any).unwrap(), "\"AgEq\"");
+
+    // Base64 can't be decoded without allocating
+    assert!(serde_json::from_str::<AnyRef<'_>>("\"AgEq\"").is_err());
+}
+
+#[test]
+fn document_json() {
+    let doc = Document::try_from(SEQUENCE_DER).unwrap();
+    let json = serde_json::to_string(&doc).unwrap();
+    assert_eq!(json, "\"MAMCASo=\"");
+    assert_eq!(serde_json::from_str::<Document>(&json).unwrap(), doc);
+}
+
+#[test]
+fn document_bincode() {
+    let doc = Document::try_from(SEQUENCE_DER).unwrap();
+    let bytes = bincode::serialize(&doc).unwrap();
+    assert!(bytes.ends_with(SEQUENCE_DER));
+    assert_eq!(bincode::deserialize::<Document>(&bytes).unwrap(), doc);
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn secret_document_round_trip() {
+    use der::SecretDocument;
+
+    let doc = SecretDocument::try_from(SEQUENCE_DER).unwrap();
+
+    let json = serde_json::to_string(&doc).unwrap();
+    assert_eq!(json, "\"MAMCASo=\"");
+    let decoded = serde_json::from_str::<SecretDocument>(&json).unwrap();
+    assert_eq!(decoded.as_bytes(), SEQUENCE_DER);
+
+    let bytes = bincode::serialize(&doc).unwrap();
+    let decoded = bincode::deserialize::<SecretDocument>(&bytes).unwrap();
+    assert_eq!(decoded.as_bytes(), SEQUENCE_DER);
+}
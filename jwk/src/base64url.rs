@@ -0,0 +1,85 @@
+//! Base64url-encoded byte strings.
+
+use alloc::{string::String, vec::Vec};
+use base64ct::{Base64UrlUnpadded, Encoding};
+use core::fmt;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use zeroize::Zeroize;
+
+/// Byte string which is serialized as unpadded Base64url, as used for the
+/// key parameters of a JWK ([RFC 7518 § 2]).
+///
+/// The contents are zeroized on drop, as they may contain private key
+/// material.
+///
+/// [RFC 7518 § 2]: https://datatracker.ietf.org/doc/html/rfc7518#section-2
+#[derive(Clone, Default, Eq, PartialEq)]
+pub struct Base64Url(Vec<u8>);
+
+impl Base64Url {
+    /// Borrow the decoded bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Get the length of the decoded bytes.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Are the decoded bytes empty?
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl AsRef<[u8]> for Base64Url {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl From<&[u8]> for Base64Url {
+    fn from(bytes: &[u8]) -> Self {
+        Self(bytes.to_vec())
+    }
+}
+
+impl From<Vec<u8>> for Base64Url {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+}
+
+/// Only the length is printed, so that private key material doesn't end up
+/// in logs.
+impl fmt::Debug for Base64Url {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Base64Url")
+            .field("len", &self.0.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for Base64Url {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl Serialize for Base64Url {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&Base64UrlUnpadded::encode_string(&self.0))
+    }
+}
+
+impl<'de> Deserialize<'de> for Base64Url {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut encoded = String::deserialize(deserializer)?;
+        let decoded = Base64UrlUnpadded::decode_vec(&encoded).map_err(|_| {
+            de::Error::invalid_value(de::Unexpected::Str(&encoded), &"unpadded Base64url")
+        });
+        encoded.zeroize();
+        decoded.map(Self)
+    }
+}
@@ -0,0 +1,231 @@
+//! Elliptic curve keys ([RFC 7518 § 6.2]).
+//!
+//! [RFC 7518 § 6.2]: https://datatracker.ietf.org/doc/html/rfc7518#section-6.2
+
+use crate::Base64Url;
+use alloc::vec::Vec;
+use core::fmt;
+use der::{
+    asn1::{AnyRef, ObjectIdentifier},
+    Decode, Document, SecretDocument,
+};
+use pkcs8::{EncodePrivateKey, PrivateKeyInfo};
+use sec1::EcPrivateKey;
+use serde::{Deserialize, Serialize};
+use spki::{
+    AlgorithmIdentifierRef, EncodePublicKey, KeyAlgorithm, SubjectPublicKeyInfoBuilder,
+    SubjectPublicKeyInfoRef,
+};
+
+/// SEC1 tag for uncompressed elliptic curve points.
+const UNCOMPRESSED_POINT: u8 = 0x04;
+
+/// Elliptic curves for [`EcKey`] (`crv`).
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[non_exhaustive]
+pub enum EcCurve {
+    /// NIST P-256 (`secp256r1`).
+    #[serde(rename = "P-256")]
+    P256,
+
+    /// NIST P-384 (`secp384r1`).
+    #[serde(rename = "P-384")]
+    P384,
+
+    /// NIST P-521 (`secp521r1`).
+    #[serde(rename = "P-521")]
+    P521,
+}
+
+impl EcCurve {
+    /// Get the named curve OID.
+    pub fn oid(self) -> ObjectIdentifier {
+        match self {
+            EcCurve::P256 => ObjectIdentifier::new_unwrap("1.2.840.10045.3.1.7"),
+            EcCurve::P384 => ObjectIdentifier::new_unwrap("1.3.132.0.34"),
+            EcCurve::P521 => ObjectIdentifier::new_unwrap("1.3.132.0.35"),
+        }
+    }
+
+    /// Get the length of a field element, i.e. of a coordinate or private
+    /// key, in bytes.
+    pub fn field_len(self) -> usize {
+        match self {
+            EcCurve::P256 => 32,
+            EcCurve::P384 => 48,
+            EcCurve::P521 => 66,
+        }
+    }
+}
+
+impl TryFrom<ObjectIdentifier> for EcCurve {
+    type Error = spki::Error;
+
+    fn try_from(oid: ObjectIdentifier) -> spki::Result<Self> {
+        [EcCurve::P256, EcCurve::P384, EcCurve::P521]
+            .into_iter()
+            .find(|curve| curve.oid() == oid)
+            .ok_or(spki::Error::OidUnknown { oid })
+    }
+}
+
+/// Elliptic curve JSON Web Key (`"kty": "EC"`).
+///
+/// Conversions from SPKI and PKCS#8 require the public key to be an
+/// uncompressed point, and a PKCS#8 private key to include the public key.
+#[derive(Clone, Eq, PartialEq, Deserialize, Serialize)]
+pub struct EcKey {
+    /// Curve.
+    pub crv: EcCurve,
+
+    /// x-coordinate of the public key.
+    pub x: Base64Url,
+
+    /// y-coordinate of the public key.
+    pub y: Base64Url,
+
+    /// Private key.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub d: Option<Base64Url>,
+}
+
+impl EcKey {
+    /// Is this a private key?
+    pub fn is_private(&self) -> bool {
+        self.d.is_some()
+    }
+
+    /// Get the public key, i.e. this key without the private key.
+    pub fn public_key(&self) -> Self {
+        Self {
+            d: None,
+            ..self.clone()
+        }
+    }
+
+    /// Create a public key from the given SEC1-encoded uncompressed point.
+    fn from_point(crv: EcCurve, point: &[u8]) -> Option<Self> {
+        match point.split_first() {
+            Some((&UNCOMPRESSED_POINT, coordinates))
+                if coordinates.len() == crv.field_len() * 2 =>
+            {
+                let (x, y) = coordinates.split_at(crv.field_len());
+
+                Some(Self {
+                    crv,
+                    x: x.into(),
+                    y: y.into(),
+                    d: None,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Get the SEC1-encoded uncompressed point.
+    fn to_point(&self) -> Option<Vec<u8>> {
+        let field_len = self.crv.field_len();
+
+        if self.x.len() != field_len || self.y.len() != field_len {
+            return None;
+        }
+
+        let mut point = Vec::with_capacity(field_len * 2 + 1);
+        point.push(UNCOMPRESSED_POINT);
+        point.extend_from_slice(self.x.as_bytes());
+        point.extend_from_slice(self.y.as_bytes());
+        Some(point)
+    }
+}
+
+impl TryFrom<SubjectPublicKeyInfoRef<'_>> for EcKey {
+    type Error = spki::Error;
+
+    fn try_from(spki: SubjectPublicKeyInfoRef<'_>) -> spki::Result<Self> {
+        spki.algorithm.assert_algorithm_oid(sec1::ALGORITHM_OID)?;
+
+        let crv = EcCurve::try_from(spki.algorithm.parameters_oid()?)?;
+
+        spki.subject_public_key
+            .as_bytes()
+            .and_then(|point| Self::from_point(crv, point))
+            .ok_or(spki::Error::KeyMalformed)
+    }
+}
+
+impl TryFrom<PrivateKeyInfo<'_>> for EcKey {
+    type Error = pkcs8::Error;
+
+    fn try_from(pkcs8: PrivateKeyInfo<'_>) -> pkcs8::Result<Self> {
+        pkcs8.algorithm.assert_algorithm_oid(sec1::ALGORITHM_OID)?;
+
+        let oid = pkcs8.algorithm.parameters_oid()?;
+        let crv = EcCurve::try_from(oid)?;
+        let private_key =
+            EcPrivateKey::from_der(pkcs8.private_key).map_err(|_| pkcs8::Error::KeyMalformed)?;
+
+        if let Some(params) = private_key.parameters {
+            if params.curve_oid() != Some(oid) {
+                return Err(pkcs8::Error::ParametersMalformed);
+            }
+        }
+
+        if private_key.private_key.len() != crv.field_len() {
+            return Err(pkcs8::Error::KeyMalformed);
+        }
+
+        let public_key = private_key
+            .public_key
+            .and_then(|point| Self::from_point(crv, point))
+            .ok_or(pkcs8::Error::KeyMalformed)?;
+
+        Ok(Self {
+            d: Some(private_key.private_key.into()),
+            ..public_key
+        })
+    }
+}
+
+impl EncodePublicKey for EcKey {
+    fn to_public_key_der(&self) -> spki::Result<Document> {
+        let point = self.to_point().ok_or(spki::Error::KeyMalformed)?;
+        let builder = SubjectPublicKeyInfoBuilder::new(KeyAlgorithm::Ec(self.crv.oid()), &point);
+        Ok(Document::encode_msg(&builder.build()?)?)
+    }
+}
+
+impl EncodePrivateKey for EcKey {
+    fn to_pkcs8_der(&self) -> pkcs8::Result<SecretDocument> {
+        let oid = self.crv.oid();
+        let point = self.to_point().ok_or(pkcs8::Error::KeyMalformed)?;
+
+        let private_key = match &self.d {
+            Some(d) if d.len() == self.crv.field_len() => d.as_bytes(),
+            _ => return Err(pkcs8::Error::KeyMalformed),
+        };
+
+        let private_key = SecretDocument::encode_msg(&EcPrivateKey {
+            private_key,
+            parameters: None,
+            public_key: Some(&point),
+        })?;
+
+        let algorithm = AlgorithmIdentifierRef {
+            oid: sec1::ALGORITHM_OID,
+            parameters: Some(AnyRef::from(&oid)),
+        };
+
+        let pkcs8 = PrivateKeyInfo::new(algorithm, private_key.as_bytes());
+        Ok(SecretDocument::encode_msg(&pkcs8)?)
+    }
+}
+
+impl fmt::Debug for EcKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EcKey")
+            .field("crv", &self.crv)
+            .field("x", &self.x)
+            .field("y", &self.y)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,171 @@
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_auto_cfg))]
+#![doc = include_str!("../README.md")]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/media/6ee8e381/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/media/6ee8e381/logo.svg"
+)]
+#![forbid(unsafe_code)]
+#![warn(
+    clippy::mod_module_files,
+    clippy::unwrap_used,
+    missing_docs,
+    rust_2018_idioms,
+    unused_lifetimes,
+    unused_qualifications
+)]
+
+//! # Usage
+//!
+//! [`Jwk`] implements [`serde`]'s `Serialize` and `Deserialize`, as well as
+//! the [`DecodePublicKey`]/[`EncodePublicKey`] and
+//! [`DecodePrivateKey`]/[`EncodePrivateKey`] traits for converting to and
+//! from SPKI and PKCS#8 DER.
+//!
+//! ```
+//! use jwk::{pkcs8::DecodePrivateKey, spki::EncodePublicKey, Jwk};
+//!
+//! let jwk = Jwk::from_pkcs8_der(include_bytes!("../tests/examples/ed25519-priv.der"))?;
+//! assert!(jwk.is_private());
+//!
+//! let json = serde_json::to_string(&jwk.public_key()).unwrap();
+//! assert_eq!(
+//!     json,
+//!     r#"{"kty":"OKP","crv":"Ed25519","x":"TSkWfz8ZEqb3rfopOgUaFcBexnuPFyZ7HFVQ3OhTvQ0"}"#
+//! );
+//!
+//! let spki = jwk.to_public_key_der()?;
+//! assert_eq!(spki.as_bytes(), include_bytes!("../tests/examples/ed25519-pub.der"));
+//! # Ok::<(), jwk::pkcs8::Error>(())
+//! ```
+
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+mod base64url;
+mod ec;
+mod okp;
+mod rsa;
+
+pub use crate::{
+    base64url::Base64Url,
+    ec::{EcCurve, EcKey},
+    okp::{OkpCurve, OkpKey},
+    rsa::RsaKey,
+};
+pub use {der, pkcs8, pkcs8::spki};
+
+use der::{Document, SecretDocument};
+use pkcs8::{EncodePrivateKey, PrivateKeyInfo};
+use serde::{Deserialize, Serialize};
+use spki::{EncodePublicKey, SubjectPublicKeyInfoRef};
+
+#[cfg(doc)]
+use {pkcs8::DecodePrivateKey, spki::DecodePublicKey};
+
+/// JSON Web Key as described in [RFC 7517], i.e. a public or private RSA,
+/// elliptic curve or octet key pair key.
+///
+/// Only the key type (`kty`) and key parameters are (de)serialized; other
+/// parameters such as `kid` or `use` are ignored.
+///
+/// [RFC 7517]: https://datatracker.ietf.org/doc/html/rfc7517
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "kty")]
+#[non_exhaustive]
+pub enum Jwk {
+    /// RSA key (`"kty": "RSA"`).
+    #[serde(rename = "RSA")]
+    Rsa(RsaKey),
+
+    /// Elliptic curve key (`"kty": "EC"`).
+    #[serde(rename = "EC")]
+    Ec(EcKey),
+
+    /// Octet key pair (`"kty": "OKP"`).
+    #[serde(rename = "OKP")]
+    Okp(OkpKey),
+}
+
+impl Jwk {
+    /// Is this a private key?
+    pub fn is_private(&self) -> bool {
+        match self {
+            Jwk::Rsa(key) => key.is_private(),
+            Jwk::Ec(key) => key.is_private(),
+            Jwk::Okp(key) => key.is_private(),
+        }
+    }
+
+    /// Get the public key, i.e. this key without any private key parameters.
+    pub fn public_key(&self) -> Self {
+        match self {
+            Jwk::Rsa(key) => Jwk::Rsa(key.public_key()),
+            Jwk::Ec(key) => Jwk::Ec(key.public_key()),
+            Jwk::Okp(key) => Jwk::Okp(key.public_key()),
+        }
+    }
+}
+
+impl From<RsaKey> for Jwk {
+    fn from(key: RsaKey) -> Jwk {
+        Jwk::Rsa(key)
+    }
+}
+
+impl From<EcKey> for Jwk {
+    fn from(key: EcKey) -> Jwk {
+        Jwk::Ec(key)
+    }
+}
+
+impl From<OkpKey> for Jwk {
+    fn from(key: OkpKey) -> Jwk {
+        Jwk::Okp(key)
+    }
+}
+
+impl TryFrom<SubjectPublicKeyInfoRef<'_>> for Jwk {
+    type Error = spki::Error;
+
+    fn try_from(spki: SubjectPublicKeyInfoRef<'_>) -> spki::Result<Self> {
+        match spki.algorithm.oid {
+            oid if oid == pkcs1::ALGORITHM_OID => RsaKey::try_from(spki).map(Into::into),
+            oid if oid == sec1::ALGORITHM_OID => EcKey::try_from(spki).map(Into::into),
+            _ => OkpKey::try_from(spki).map(Into::into),
+        }
+    }
+}
+
+impl TryFrom<PrivateKeyInfo<'_>> for Jwk {
+    type Error = pkcs8::Error;
+
+    fn try_from(pkcs8: PrivateKeyInfo<'_>) -> pkcs8::Result<Self> {
+        match pkcs8.algorithm.oid {
+            oid if oid == pkcs1::ALGORITHM_OID => RsaKey::try_from(pkcs8).map(Into::into),
+            oid if oid == sec1::ALGORITHM_OID => EcKey::try_from(pkcs8).map(Into::into),
+            _ => OkpKey::try_from(pkcs8).map(Into::into),
+        }
+    }
+}
+
+impl EncodePublicKey for Jwk {
+    fn to_public_key_der(&self) -> spki::Result<Document> {
+        match self {
+            Jwk::Rsa(key) => key.to_public_key_der(),
+            Jwk::Ec(key) => key.to_public_key_der(),
+            Jwk::Okp(key) => key.to_public_key_der(),
+        }
+    }
+}
+
+impl EncodePrivateKey for Jwk {
+    fn to_pkcs8_der(&self) -> pkcs8::Result<SecretDocument> {
+        match self {
+            Jwk::Rsa(key) => key.to_pkcs8_der(),
+            Jwk::Ec(key) => key.to_pkcs8_der(),
+            Jwk::Okp(key) => key.to_pkcs8_der(),
+        }
+    }
+}
@@ -0,0 +1,193 @@
+//! Octet key pairs, i.e. Edwards and Montgomery curve keys ([RFC 8037 § 2]).
+//!
+//! [RFC 8037 § 2]: https://datatracker.ietf.org/doc/html/rfc8037#section-2
+
+use crate::Base64Url;
+use core::fmt;
+use der::{asn1::OctetStringRef, Decode, Document, Encode, SecretDocument};
+use pkcs8::{EncodePrivateKey, PrivateKeyInfo};
+use serde::{Deserialize, Serialize};
+use spki::{
+    algorithms, AlgorithmIdentifierRef, EncodePublicKey, KeyAlgorithm, SubjectPublicKeyInfoBuilder,
+    SubjectPublicKeyInfoRef,
+};
+use zeroize::Zeroizing;
+
+/// Curves for [`OkpKey`] (`crv`).
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[non_exhaustive]
+pub enum OkpCurve {
+    /// Ed25519 signature algorithm key pairs.
+    Ed25519,
+
+    /// Ed448 signature algorithm key pairs.
+    Ed448,
+
+    /// X25519 function key pairs.
+    X25519,
+
+    /// X448 function key pairs.
+    X448,
+}
+
+impl OkpCurve {
+    /// Get the [`AlgorithmIdentifierRef`] for keys on this curve.
+    pub fn algorithm(self) -> AlgorithmIdentifierRef<'static> {
+        match self {
+            OkpCurve::Ed25519 => algorithms::ED25519,
+            OkpCurve::Ed448 => algorithms::ED448,
+            OkpCurve::X25519 => algorithms::X25519,
+            OkpCurve::X448 => algorithms::X448,
+        }
+    }
+
+    /// Get the length of the public and private keys in bytes.
+    pub fn key_len(self) -> usize {
+        match self {
+            OkpCurve::Ed25519 | OkpCurve::X25519 => 32,
+            OkpCurve::Ed448 => 57,
+            OkpCurve::X448 => 56,
+        }
+    }
+
+    fn key_algorithm(self) -> KeyAlgorithm {
+        match self {
+            OkpCurve::Ed25519 => KeyAlgorithm::Ed25519,
+            OkpCurve::Ed448 => KeyAlgorithm::Ed448,
+            OkpCurve::X25519 => KeyAlgorithm::X25519,
+            OkpCurve::X448 => KeyAlgorithm::X448,
+        }
+    }
+}
+
+impl TryFrom<AlgorithmIdentifierRef<'_>> for OkpCurve {
+    type Error = spki::Error;
+
+    fn try_from(algorithm: AlgorithmIdentifierRef<'_>) -> spki::Result<Self> {
+        let curve = [
+            OkpCurve::Ed25519,
+            OkpCurve::Ed448,
+            OkpCurve::X25519,
+            OkpCurve::X448,
+        ]
+        .into_iter()
+        .find(|curve| curve.algorithm().oid == algorithm.oid)
+        .ok_or(spki::Error::OidUnknown { oid: algorithm.oid })?;
+
+        // RFC 8410 § 3: the parameters MUST be absent
+        match algorithm.parameters {
+            None => Ok(curve),
+            Some(_) => Err(der::Tag::Null.value_error().into()),
+        }
+    }
+}
+
+/// Octet key pair JSON Web Key (`"kty": "OKP"`).
+///
+/// Conversions from PKCS#8 require the private key to include the public key
+/// (i.e. a PKCS#8 v2 `OneAsymmetricKey`), which is always included when
+/// converting to PKCS#8.
+#[derive(Clone, Eq, PartialEq, Deserialize, Serialize)]
+pub struct OkpKey {
+    /// Curve.
+    pub crv: OkpCurve,
+
+    /// Public key.
+    pub x: Base64Url,
+
+    /// Private key.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub d: Option<Base64Url>,
+}
+
+impl OkpKey {
+    /// Is this a private key?
+    pub fn is_private(&self) -> bool {
+        self.d.is_some()
+    }
+
+    /// Get the public key, i.e. this key without the private key.
+    pub fn public_key(&self) -> Self {
+        Self {
+            d: None,
+            ..self.clone()
+        }
+    }
+}
+
+impl TryFrom<SubjectPublicKeyInfoRef<'_>> for OkpKey {
+    type Error = spki::Error;
+
+    fn try_from(spki: SubjectPublicKeyInfoRef<'_>) -> spki::Result<Self> {
+        let crv = OkpCurve::try_from(spki.algorithm)?;
+
+        match spki.subject_public_key.as_bytes() {
+            Some(x) if x.len() == crv.key_len() => Ok(Self {
+                crv,
+                x: x.into(),
+                d: None,
+            }),
+            _ => Err(spki::Error::KeyMalformed),
+        }
+    }
+}
+
+impl TryFrom<PrivateKeyInfo<'_>> for OkpKey {
+    type Error = pkcs8::Error;
+
+    fn try_from(pkcs8: PrivateKeyInfo<'_>) -> pkcs8::Result<Self> {
+        let crv = OkpCurve::try_from(pkcs8.algorithm)?;
+
+        // RFC 8410 § 7: `CurvePrivateKey ::= OCTET STRING`
+        let d = OctetStringRef::from_der(pkcs8.private_key)
+            .map_err(|_| pkcs8::Error::KeyMalformed)?
+            .as_bytes();
+
+        match pkcs8.public_key {
+            Some(x) if x.len() == crv.key_len() && d.len() == crv.key_len() => Ok(Self {
+                crv,
+                x: x.into(),
+                d: Some(d.into()),
+            }),
+            _ => Err(pkcs8::Error::KeyMalformed),
+        }
+    }
+}
+
+impl EncodePublicKey for OkpKey {
+    fn to_public_key_der(&self) -> spki::Result<Document> {
+        let builder = SubjectPublicKeyInfoBuilder::new(self.crv.key_algorithm(), self.x.as_bytes());
+        Ok(Document::encode_msg(&builder.build()?)?)
+    }
+}
+
+impl EncodePrivateKey for OkpKey {
+    fn to_pkcs8_der(&self) -> pkcs8::Result<SecretDocument> {
+        let (x, d) = match &self.d {
+            Some(d) if self.x.len() == self.crv.key_len() && d.len() == self.crv.key_len() => {
+                (self.x.as_bytes(), d.as_bytes())
+            }
+            _ => return Err(pkcs8::Error::KeyMalformed),
+        };
+
+        let private_key = Zeroizing::new(OctetStringRef::new(d)?.to_der()?);
+
+        let pkcs8 = PrivateKeyInfo {
+            algorithm: self.crv.algorithm(),
+            private_key: &private_key,
+            attributes: None,
+            public_key: Some(x),
+        };
+
+        Ok(SecretDocument::encode_msg(&pkcs8)?)
+    }
+}
+
+impl fmt::Debug for OkpKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OkpKey")
+            .field("crv", &self.crv)
+            .field("x", &self.x)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,177 @@
+//! RSA keys ([RFC 7518 § 6.3]).
+//!
+//! [RFC 7518 § 6.3]: https://datatracker.ietf.org/doc/html/rfc7518#section-6.3
+
+use crate::Base64Url;
+use core::fmt;
+use der::{asn1::UintRef, Decode, Document, Encode, SecretDocument};
+use pkcs1::{RsaPrivateKey, RsaPublicKey};
+use pkcs8::{EncodePrivateKey, PrivateKeyInfo};
+use serde::{Deserialize, Serialize};
+use spki::{EncodePublicKey, KeyAlgorithm, SubjectPublicKeyInfoBuilder, SubjectPublicKeyInfoRef};
+
+/// RSA JSON Web Key (`"kty": "RSA"`).
+///
+/// All integers are encoded as big endian with leading zeroes removed
+/// (`Base64urlUInt`). A private key must have all of the private key
+/// parameters, and multi-prime keys (i.e. the `oth` parameter) are not
+/// supported.
+#[derive(Clone, Eq, PartialEq, Deserialize, Serialize)]
+pub struct RsaKey {
+    /// Modulus.
+    pub n: Base64Url,
+
+    /// Public exponent.
+    pub e: Base64Url,
+
+    /// Private exponent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub d: Option<Base64Url>,
+
+    /// First prime factor.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub p: Option<Base64Url>,
+
+    /// Second prime factor.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub q: Option<Base64Url>,
+
+    /// First factor CRT exponent: `d mod (p-1)`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dp: Option<Base64Url>,
+
+    /// Second factor CRT exponent: `d mod (q-1)`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dq: Option<Base64Url>,
+
+    /// First CRT coefficient: `(inverse of q) mod p`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub qi: Option<Base64Url>,
+}
+
+impl RsaKey {
+    /// Is this a private key?
+    pub fn is_private(&self) -> bool {
+        self.d.is_some()
+    }
+
+    /// Get the public key, i.e. this key without the private key parameters.
+    pub fn public_key(&self) -> Self {
+        Self::new(self.n.as_bytes(), self.e.as_bytes())
+    }
+
+    /// Create a public key from the given modulus and public exponent.
+    fn new(n: &[u8], e: &[u8]) -> Self {
+        Self {
+            n: n.into(),
+            e: e.into(),
+            d: None,
+            p: None,
+            q: None,
+            dp: None,
+            dq: None,
+            qi: None,
+        }
+    }
+
+    fn rsa_public_key(&self) -> der::Result<RsaPublicKey<'_>> {
+        Ok(RsaPublicKey {
+            modulus: UintRef::new(self.n.as_bytes())?,
+            public_exponent: UintRef::new(self.e.as_bytes())?,
+        })
+    }
+
+    fn rsa_private_key(&self) -> pkcs8::Result<RsaPrivateKey<'_>> {
+        let (d, p, q, dp, dq, qi) = match (&self.d, &self.p, &self.q, &self.dp, &self.dq, &self.qi)
+        {
+            (Some(d), Some(p), Some(q), Some(dp), Some(dq), Some(qi)) => (d, p, q, dp, dq, qi),
+            _ => return Err(pkcs8::Error::KeyMalformed),
+        };
+
+        let public_key = self.rsa_public_key()?;
+
+        Ok(RsaPrivateKey {
+            modulus: public_key.modulus,
+            public_exponent: public_key.public_exponent,
+            private_exponent: UintRef::new(d.as_bytes())?,
+            prime1: UintRef::new(p.as_bytes())?,
+            prime2: UintRef::new(q.as_bytes())?,
+            exponent1: UintRef::new(dp.as_bytes())?,
+            exponent2: UintRef::new(dq.as_bytes())?,
+            coefficient: UintRef::new(qi.as_bytes())?,
+            other_prime_infos: None,
+        })
+    }
+}
+
+impl TryFrom<SubjectPublicKeyInfoRef<'_>> for RsaKey {
+    type Error = spki::Error;
+
+    fn try_from(spki: SubjectPublicKeyInfoRef<'_>) -> spki::Result<Self> {
+        spki.algorithm.assert_algorithm_oid(pkcs1::ALGORITHM_OID)?;
+
+        let public_key = spki
+            .subject_public_key
+            .as_bytes()
+            .and_then(|bytes| RsaPublicKey::from_der(bytes).ok())
+            .ok_or(spki::Error::KeyMalformed)?;
+
+        Ok(Self::new(
+            public_key.modulus.as_bytes(),
+            public_key.public_exponent.as_bytes(),
+        ))
+    }
+}
+
+impl TryFrom<PrivateKeyInfo<'_>> for RsaKey {
+    type Error = pkcs8::Error;
+
+    fn try_from(pkcs8: PrivateKeyInfo<'_>) -> pkcs8::Result<Self> {
+        pkcs8.algorithm.assert_algorithm_oid(pkcs1::ALGORITHM_OID)?;
+
+        let private_key =
+            RsaPrivateKey::from_der(pkcs8.private_key).map_err(|_| pkcs8::Error::KeyMalformed)?;
+
+        if private_key.other_prime_infos.is_some() {
+            return Err(pkcs8::Error::KeyMalformed);
+        }
+
+        Ok(Self {
+            d: Some(private_key.private_exponent.as_bytes().into()),
+            p: Some(private_key.prime1.as_bytes().into()),
+            q: Some(private_key.prime2.as_bytes().into()),
+            dp: Some(private_key.exponent1.as_bytes().into()),
+            dq: Some(private_key.exponent2.as_bytes().into()),
+            qi: Some(private_key.coefficient.as_bytes().into()),
+            ..Self::new(
+                private_key.modulus.as_bytes(),
+                private_key.public_exponent.as_bytes(),
+            )
+        })
+    }
+}
+
+impl EncodePublicKey for RsaKey {
+    fn to_public_key_der(&self) -> spki::Result<Document> {
+        let public_key = self.rsa_public_key()?.to_der()?;
+        let builder = SubjectPublicKeyInfoBuilder::new(KeyAlgorithm::Rsa, &public_key);
+        Ok(Document::encode_msg(&builder.build()?)?)
+    }
+}
+
+impl EncodePrivateKey for RsaKey {
+    fn to_pkcs8_der(&self) -> pkcs8::Result<SecretDocument> {
+        let private_key = SecretDocument::encode_msg(&self.rsa_private_key()?)?;
+        let pkcs8 = PrivateKeyInfo::new(pkcs1::ALGORITHM_ID, private_key.as_bytes());
+        Ok(SecretDocument::encode_msg(&pkcs8)?)
+    }
+}
+
+impl fmt::Debug for RsaKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RsaKey")
+            .field("n", &self.n)
+            .field("e", &self.e)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,150 @@
+//! JSON Web Key tests.
+
+use jwk::{
+    pkcs8::{DecodePrivateKey, EncodePrivateKey},
+    spki::{DecodePublicKey, EncodePublicKey},
+    EcCurve, Jwk, OkpCurve,
+};
+
+/// Check the private key converts to and from the given JWK and PKCS#8
+/// documents, and its public key to and from the given SPKI document.
+fn assert_round_trip(json: &str, pkcs8: &[u8], spki: &[u8]) -> Jwk {
+    let jwk = serde_json::from_str::<Jwk>(json).unwrap();
+    assert!(jwk.is_private());
+    assert_eq!(Jwk::from_pkcs8_der(pkcs8).unwrap(), jwk);
+    assert_eq!(jwk.to_pkcs8_der().unwrap().as_bytes(), pkcs8);
+    assert_eq!(
+        serde_json::to_value(&jwk).unwrap(),
+        serde_json::from_str::<serde_json::Value>(json).unwrap()
+    );
+
+    let public_key = jwk.public_key();
+    assert!(!public_key.is_private());
+    assert_eq!(Jwk::from_public_key_der(spki).unwrap(), public_key);
+    assert_eq!(jwk.to_public_key_der().unwrap().as_bytes(), spki);
+    assert_eq!(public_key.to_public_key_der().unwrap().as_bytes(), spki);
+    assert!(public_key.to_pkcs8_der().is_err());
+
+    jwk
+}
+
+#[test]
+fn rsa2048() {
+    let jwk = assert_round_trip(
+        include_str!("examples/rsa2048-priv.json"),
+        include_bytes!("examples/rsa2048-priv.der"),
+        include_bytes!("examples/rsa2048-pub.der"),
+    );
+
+    assert!(matches!(jwk, Jwk::Rsa(key) if key.e.as_bytes() == [1, 0, 1]));
+}
+
+#[test]
+fn ec_p256() {
+    let jwk = assert_round_trip(
+        include_str!("examples/p256-priv.json"),
+        include_bytes!("examples/p256-priv.der"),
+        include_bytes!("examples/p256-pub.der"),
+    );
+
+    assert!(matches!(jwk, Jwk::Ec(key) if key.crv == EcCurve::P256));
+}
+
+#[test]
+fn ec_p384() {
+    let jwk = assert_round_trip(
+        include_str!("examples/p384-priv.json"),
+        include_bytes!("examples/p384-priv.der"),
+        include_bytes!("examples/p384-pub.der"),
+    );
+
+    assert!(matches!(jwk, Jwk::Ec(key) if key.crv == EcCurve::P384));
+}
+
+#[test]
+fn ec_p521() {
+    let jwk = assert_round_trip(
+        include_str!("examples/p521-priv.json"),
+        include_bytes!("examples/p521-priv.der"),
+        include_bytes!("examples/p521-pub.der"),
+    );
+
+    assert!(matches!(jwk, Jwk::Ec(key) if key.crv == EcCurve::P521));
+}
+
+#[test]
+fn okp_ed25519() {
+    let jwk = assert_round_trip(
+        include_str!("examples/ed25519-priv.json"),
+        include_bytes!("examples/ed25519-priv.der"),
+        include_bytes!("examples/ed25519-pub.der"),
+    );
+
+    assert!(matches!(jwk, Jwk::Okp(key) if key.crv == OkpCurve::Ed25519));
+}
+
+/// Example from RFC 8037 Appendix A.1
+#[test]
+fn okp_rfc8037_example() {
+    let jwk = serde_json::from_str::<Jwk>(
+        r#"{"kty":"OKP","crv":"Ed25519",
+           "d":"nWGxne_9WmC6hEr0kuwsxERJxWl7MmkZcDusAxyuf2A",
+           "x":"11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"}"#,
+    )
+    .unwrap();
+
+    let public_key = jwk.to_public_key_der().unwrap();
+    assert_eq!(
+        Jwk::from_public_key_der(public_key.as_bytes()).unwrap(),
+        jwk.public_key()
+    );
+
+    let private_key = jwk.to_pkcs8_der().unwrap();
+    assert_eq!(Jwk::from_pkcs8_der(private_key.as_bytes()).unwrap(), jwk);
+}
+
+#[test]
+fn debug_omits_private_key() {
+    let jwk = serde_json::from_str::<Jwk>(include_str!("examples/p256-priv.json")).unwrap();
+    let Jwk::Ec(key) = &jwk else { unreachable!() };
+    let d = key.d.as_ref().unwrap();
+    assert_eq!(format!("{:?}", d), "Base64Url { len: 32, .. }");
+
+    let encoded = serde_json::to_string(d).unwrap();
+    assert!(!format!("{:?}", jwk).contains(encoded.trim_matches('"')));
+    assert!(!format!("{:?}", d).contains(encoded.trim_matches('"')));
+}
+
+#[test]
+fn pkcs8_without_public_key() {
+    // Ed25519 PKCS#8 v1 private key from RFC 8410 § 10.3
+    let der = include_bytes!("../../pkcs8/tests/examples/ed25519-priv-pkcs8v1.der");
+    assert_eq!(
+        Jwk::from_pkcs8_der(der),
+        Err(jwk::pkcs8::Error::KeyMalformed)
+    );
+}
+
+#[test]
+fn invalid_keys() {
+    for json in [
+        // Missing CRT parameters
+        r#"{"kty":"RSA","n":"AQAB","e":"AQAB","d":"AQAB"}"#,
+        // Coordinate lengths don't match the curve
+        r#"{"kty":"EC","crv":"P-256","x":"AQAB","y":"AQAB","d":"AQAB"}"#,
+        // Public key length doesn't match the curve
+        r#"{"kty":"OKP","crv":"Ed448","x":"AQAB","d":"AQAB"}"#,
+    ] {
+        let jwk = serde_json::from_str::<Jwk>(json).unwrap();
+        assert!(jwk.to_pkcs8_der().is_err(), "{}", json);
+    }
+
+    for json in [
+        r#"{"kty":"oct","k":"AQAB"}"#,
+        r#"{"kty":"EC","crv":"secp256k1","x":"AQAB","y":"AQAB"}"#,
+        r#"{"kty":"OKP","crv":"Ed25519","x":"AQAB="}"#,
+        r#"{"kty":"OKP","crv":"Ed25519","x":"AQ+B"}"#,
+    ] {
+        assert!(serde_json::from_str::<Jwk>(json).is_err(), "{}", json);
+    }
+}
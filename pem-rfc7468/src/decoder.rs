@@ -11,7 +11,7 @@
 //! [RFC 4648]: https://datatracker.ietf.org/doc/html/rfc4648
 
 use crate::{
-    grammar, Base64Decoder, Error, Result, BASE64_WRAP_WIDTH, POST_ENCAPSULATION_BOUNDARY,
+    grammar, Base64Decoder, Error, Headers, Result, BASE64_WRAP_WIDTH, POST_ENCAPSULATION_BOUNDARY,
     PRE_ENCAPSULATION_BOUNDARY,
 };
 use core::str;
@@ -42,6 +42,33 @@ pub fn decode<'i, 'o>(pem: &'i [u8], buf: &'o mut [u8]) -> Result<(&'i str, &'o
     }
 }
 
+/// Decode a PEM document which may contain RFC 1421 encapsulated headers,
+/// e.g. a legacy encrypted private key with `Proc-Type` and `DEK-Info`
+/// headers.
+///
+/// On success, writes the decoded document into the provided buffer, returning
+/// the decoded label, the encapsulated [`Headers`] (which are empty if the
+/// document has none), and the portion of the provided buffer containing the
+/// decoded message.
+pub fn decode_with_headers<'i, 'o>(
+    pem: &'i [u8],
+    buf: &'o mut [u8],
+) -> Result<(&'i str, Headers<'i>, &'o [u8])> {
+    let mut decoder = Decoder::new_with_headers(pem)?;
+    let type_label = decoder.type_label();
+    let headers = decoder.headers();
+    let buf = buf
+        .get_mut(..decoder.remaining_len())
+        .ok_or(Error::Length)?;
+    let decoded = decoder.decode(buf)?;
+
+    if decoder.base64.is_finished() {
+        Ok((type_label, headers, decoded))
+    } else {
+        Err(Error::Length)
+    }
+}
+
 /// Decode a PEM document according to RFC 7468's "Strict" grammar, returning
 /// the result as a [`Vec`] upon success.
 #[cfg(feature = "alloc")]
@@ -55,6 +82,30 @@ pub fn decode_vec(pem: &[u8]) -> Result<(&str, Vec<u8>)> {
     Ok((type_label, buf))
 }
 
+/// Decode a PEM document which may contain RFC 1421 encapsulated headers,
+/// returning the result as a [`Vec`] upon success.
+///
+/// See [`decode_with_headers`] for more information.
+#[cfg(feature = "alloc")]
+pub fn decode_vec_with_headers(pem: &[u8]) -> Result<(&str, Headers<'_>, Vec<u8>)> {
+    let mut decoder = Decoder::new_with_headers(pem)?;
+    let type_label = decoder.type_label();
+    let headers = decoder.headers();
+    let mut buf = Vec::new();
+    decoder.decode_to_end(&mut buf)?;
+    Ok((type_label, headers, buf))
+}
+
+/// Decode all of the PEM documents contained in the input, e.g. a
+/// certificate chain, according to RFC 7468's "Strict" grammar.
+///
+/// Returns an [`Iter`] which yields the type label and decoded data of each
+/// document. Text appearing between documents is skipped.
+#[cfg(feature = "alloc")]
+pub fn decode_all(pem: &[u8]) -> Iter<'_> {
+    Iter { remaining: pem }
+}
+
 /// Decode the encapsulation boundaries of a PEM document according to RFC 7468's "Strict" grammar.
 ///
 /// On success, returning the decoded label.
@@ -71,6 +122,10 @@ pub struct Decoder<'i> {
     /// PEM type label.
     type_label: &'i str,
 
+    /// Encapsulated headers, if the decoder was constructed with
+    /// [`Decoder::new_with_headers`].
+    headers: Headers<'i>,
+
     /// Buffered Base64 decoder.
     base64: Base64Decoder<'i>,
 }
@@ -88,7 +143,27 @@ impl<'i> Decoder<'i> {
         let encapsulation = Encapsulation::try_from(pem)?;
         let type_label = encapsulation.label();
         let base64 = Base64Decoder::new_wrapped(encapsulation.encapsulated_text, line_width)?;
-        Ok(Self { type_label, base64 })
+        Ok(Self {
+            type_label,
+            headers: Headers::default(),
+            base64,
+        })
+    }
+
+    /// Create a new PEM [`Decoder`] which accepts RFC 1421 encapsulated
+    /// headers preceding the Base64-encoded body.
+    ///
+    /// Uses the default 64-character line wrapping.
+    pub fn new_with_headers(pem: &'i [u8]) -> Result<Self> {
+        let encapsulation = Encapsulation::try_from(pem)?;
+        let type_label = encapsulation.label();
+        let (headers, encapsulated_text) = Headers::split(encapsulation.encapsulated_text)?;
+        let base64 = Base64Decoder::new_wrapped(encapsulated_text, BASE64_WRAP_WIDTH)?;
+        Ok(Self {
+            type_label,
+            headers,
+            base64,
+        })
     }
 
     /// Get the PEM type label for the input document.
@@ -96,6 +171,14 @@ impl<'i> Decoder<'i> {
         self.type_label
     }
 
+    /// Get the encapsulated headers of the input document.
+    ///
+    /// These are always empty unless the decoder was constructed with
+    /// [`Decoder::new_with_headers`].
+    pub fn headers(&self) -> Headers<'i> {
+        self.headers
+    }
+
     /// Decode data into the provided output buffer.
     ///
     /// There must be at least as much remaining Base64 input to be decoded
@@ -142,6 +225,88 @@ impl<'i> io::Read for Decoder<'i> {
     }
 }
 
+/// Iterator over the PEM documents contained in some input.
+///
+/// Created by [`decode_all`]. Each item is the result of decoding a single
+/// document, which makes it possible to recover from malformed documents by
+/// continuing on to the next one. Iteration stops after the last document, or
+/// once a pre-encapsulation boundary without a corresponding
+/// post-encapsulation boundary is encountered.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+pub struct Iter<'a> {
+    /// Input which hasn't been decoded yet.
+    remaining: &'a [u8],
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> Iterator for Iter<'a> {
+    type Item = Result<(&'a str, Vec<u8>)>;
+
+    fn next(&mut self) -> Option<Result<(&'a str, Vec<u8>)>> {
+        let start = find_boundary(self.remaining, PRE_ENCAPSULATION_BOUNDARY)?;
+        let document = &self.remaining[start..];
+
+        let end = find_boundary(document, POST_ENCAPSULATION_BOUNDARY);
+
+        // Resume from the next pre-encapsulation boundary if it occurs before
+        // the post-encapsulation boundary, i.e. this document is truncated
+        let next = document
+            .get(PRE_ENCAPSULATION_BOUNDARY.len()..)
+            .and_then(|rest| find_boundary(rest, PRE_ENCAPSULATION_BOUNDARY))
+            .map(|offset| offset.saturating_add(PRE_ENCAPSULATION_BOUNDARY.len()));
+
+        let end = match (end, next) {
+            (Some(end), Some(next)) if next < end => {
+                self.remaining = &document[next..];
+                return Some(Err(Error::PostEncapsulationBoundary));
+            }
+            (Some(end), _) => end,
+            (None, _) => {
+                self.remaining = &[];
+                return Some(Err(Error::PostEncapsulationBoundary));
+            }
+        };
+
+        // Include the remainder of the post-encapsulation boundary line
+        // along with its newline
+        let line = &document[end..];
+        let line_len = line
+            .iter()
+            .position(|&byte| matches!(byte, grammar::CHAR_CR | grammar::CHAR_LF))
+            .unwrap_or(line.len());
+        let rest = &line[line_len..];
+        let eol_len = rest
+            .len()
+            .saturating_sub(grammar::strip_leading_eol(rest).unwrap_or(rest).len());
+        let len = end.saturating_add(line_len).saturating_add(eol_len);
+
+        let (document, remaining) = document.split_at(len);
+        self.remaining = remaining;
+        Some(decode_vec(document))
+    }
+}
+
+/// Find the offset of an encapsulation boundary occurring at the start of a
+/// line in the given input.
+#[cfg(feature = "alloc")]
+fn find_boundary(input: &[u8], boundary: &[u8]) -> Option<usize> {
+    let mut offset = 0;
+
+    loop {
+        let line = input.get(offset..)?;
+
+        if line.starts_with(boundary) {
+            return Some(offset);
+        }
+
+        let line_len = line
+            .iter()
+            .position(|&byte| matches!(byte, grammar::CHAR_CR | grammar::CHAR_LF))?;
+        offset = offset.checked_add(line_len)?.checked_add(1)?;
+    }
+}
+
 /// PEM encapsulation parser.
 ///
 /// This parser performs an initial pass over the data, locating the
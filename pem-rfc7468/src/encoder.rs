@@ -1,7 +1,7 @@
 //! PEM encoder.
 
 use crate::{
-    grammar, Base64Encoder, Error, LineEnding, Result, BASE64_WRAP_WIDTH,
+    grammar, Base64Encoder, Error, Header, LineEnding, Result, BASE64_WRAP_WIDTH,
     ENCAPSULATION_BOUNDARY_DELIMITER, POST_ENCAPSULATION_BOUNDARY, PRE_ENCAPSULATION_BOUNDARY,
 };
 use base64ct::{Base64, Encoding};
@@ -78,9 +78,43 @@ pub fn encapsulated_len_wrapped(
 /// - `Ok(len)` on success
 /// - `Err(Error::Length)` on length overflow
 pub fn encoded_len(label: &str, line_ending: LineEnding, input: &[u8]) -> Result<usize> {
+    encoded_len_with_config(label, EncodeConfig::new(line_ending), input)
+}
+
+/// Get the length of a PEM encoded document with the given bytes and label,
+/// using the line width and line ending specified in the given
+/// [`EncodeConfig`].
+///
+/// ## Returns
+/// - `Ok(len)` on success
+/// - `Err(Error::Length)` on length overflow or if the line width is too small
+pub fn encoded_len_with_config(label: &str, config: EncodeConfig, input: &[u8]) -> Result<usize> {
+    if config.line_width < 4 {
+        return Err(Error::Length);
+    }
+
     let base64_len = Base64::encoded_len(input);
-    let base64_len_wrapped = base64_len_wrapped(base64_len, BASE64_WRAP_WIDTH, line_ending)?;
-    encapsulated_len_inner(label, line_ending, base64_len_wrapped)
+    let base64_len_wrapped = base64_len_wrapped(base64_len, config.line_width, config.line_ending)?;
+    encapsulated_len_inner(label, config.line_ending, base64_len_wrapped)
+}
+
+/// Get the length of a PEM encoded document with the given bytes, label,
+/// and RFC 1421 encapsulated headers.
+///
+/// This function computes a precise length of the PEM encoding.
+///
+/// ## Returns
+/// - `Ok(len)` on success
+/// - `Err(Error::Length)` on length overflow
+pub fn encoded_len_with_headers(
+    label: &str,
+    headers: &[Header<'_>],
+    line_ending: LineEnding,
+    input: &[u8],
+) -> Result<usize> {
+    encoded_len(label, line_ending, input)?
+        .checked_add(headers_len(headers, line_ending)?)
+        .ok_or(Error::Length)
 }
 
 /// Encode a PEM document according to RFC 7468's "Strict" grammar.
@@ -90,11 +124,95 @@ pub fn encode<'o>(
     input: &[u8],
     buf: &'o mut [u8],
 ) -> Result<&'o str> {
-    let mut encoder = Encoder::new(type_label, line_ending, buf)?;
+    encode_with_headers(type_label, &[], line_ending, input, buf)
+}
+
+/// Encode a PEM document with the given RFC 1421 encapsulated headers, e.g.
+/// the `Proc-Type` and `DEK-Info` headers of a legacy encrypted private key.
+///
+/// The headers are written after the pre-encapsulation boundary, followed by
+/// an empty line and then the Base64-encoded body. Note that RFC 7468
+/// disallows headers: if `headers` is empty, the output is identical to
+/// [`encode`].
+pub fn encode_with_headers<'o>(
+    type_label: &str,
+    headers: &[Header<'_>],
+    line_ending: LineEnding,
+    input: &[u8],
+    buf: &'o mut [u8],
+) -> Result<&'o str> {
+    let mut encoder = Encoder::new_with_headers(type_label, headers, line_ending, buf)?;
     encoder.encode(input)?;
     let encoded_len = encoder.finish()?;
-    let output = &buf[..encoded_len];
+    ascii_str(&buf[..encoded_len])
+}
+
+/// Encode a PEM document using the line width and line ending specified in
+/// the given [`EncodeConfig`].
+///
+/// Note that per [RFC7468 § 2] line widths other than 64 are technically
+/// non-compliant, however some consumers require them.
+///
+/// [RFC7468 § 2]: https://datatracker.ietf.org/doc/html/rfc7468#section-2
+pub fn encode_with_config<'o>(
+    type_label: &str,
+    config: EncodeConfig,
+    input: &[u8],
+    buf: &'o mut [u8],
+) -> Result<&'o str> {
+    let mut encoder = Encoder::new_wrapped(type_label, config.line_width, config.line_ending, buf)?;
+    encoder.encode(input)?;
+    let encoded_len = encoder.finish()?;
+    ascii_str(&buf[..encoded_len])
+}
+
+/// Encode a PEM document according to RFC 7468's "Strict" grammar, returning
+/// the result as a [`String`].
+#[cfg(feature = "alloc")]
+pub fn encode_string(label: &str, line_ending: LineEnding, input: &[u8]) -> Result<String> {
+    let expected_len = encoded_len(label, line_ending, input)?;
+    let mut buf = vec![0u8; expected_len];
+    let actual_len = encode(label, line_ending, input, &mut buf)?.len();
+    debug_assert_eq!(expected_len, actual_len);
+    String::from_utf8(buf).map_err(|_| Error::CharacterEncoding)
+}
+
+/// Encode a PEM document using the given [`EncodeConfig`], returning the
+/// result as a [`String`].
+#[cfg(feature = "alloc")]
+pub fn encode_string_with_config(
+    label: &str,
+    config: EncodeConfig,
+    input: &[u8],
+) -> Result<String> {
+    let expected_len = encoded_len_with_config(label, config, input)?;
+    let mut buf = vec![0u8; expected_len];
+    let actual_len = encode_with_config(label, config, input, &mut buf)?.len();
+    debug_assert_eq!(expected_len, actual_len);
+    String::from_utf8(buf).map_err(|_| Error::CharacterEncoding)
+}
+
+/// Encode a PEM document with the given RFC 1421 encapsulated headers,
+/// returning the result as a [`String`].
+///
+/// See [`encode_with_headers`] for more information.
+#[cfg(feature = "alloc")]
+pub fn encode_string_with_headers(
+    label: &str,
+    headers: &[Header<'_>],
+    line_ending: LineEnding,
+    input: &[u8],
+) -> Result<String> {
+    let expected_len = encoded_len_with_headers(label, headers, line_ending, input)?;
+    let mut buf = vec![0u8; expected_len];
+    let actual_len = encode_with_headers(label, headers, line_ending, input, &mut buf)?.len();
+    debug_assert_eq!(expected_len, actual_len);
+    String::from_utf8(buf).map_err(|_| Error::CharacterEncoding)
+}
 
+/// Convert the encoder's output to a `str`, ensuring it only contains
+/// characters from the lower 7-bit ASCII set.
+fn ascii_str(output: &[u8]) -> Result<&str> {
     // Sanity check
     debug_assert!(str::from_utf8(output).is_ok());
 
@@ -116,17 +234,6 @@ pub fn encode<'o>(
     }
 }
 
-/// Encode a PEM document according to RFC 7468's "Strict" grammar, returning
-/// the result as a [`String`].
-#[cfg(feature = "alloc")]
-pub fn encode_string(label: &str, line_ending: LineEnding, input: &[u8]) -> Result<String> {
-    let expected_len = encoded_len(label, line_ending, input)?;
-    let mut buf = vec![0u8; expected_len];
-    let actual_len = encode(label, line_ending, input, &mut buf)?.len();
-    debug_assert_eq!(expected_len, actual_len);
-    String::from_utf8(buf).map_err(|_| Error::CharacterEncoding)
-}
-
 /// Compute the encapsulated length of Base64 data of the given length.
 fn encapsulated_len_inner(
     label: &str,
@@ -150,6 +257,20 @@ fn encapsulated_len_inner(
     .ok_or(Error::Length)
 }
 
+/// Compute the length of the given encapsulated headers, including the
+/// empty line which separates them from the encapsulated text.
+fn headers_len(headers: &[Header<'_>], line_ending: LineEnding) -> Result<usize> {
+    if headers.is_empty() {
+        return Ok(0);
+    }
+
+    headers.iter().try_fold(line_ending.len(), |acc, header| {
+        acc.checked_add(header.encoded_len()?)
+            .and_then(|len| len.checked_add(line_ending.len()))
+            .ok_or(Error::Length)
+    })
+}
+
 /// Compute Base64 length line-wrapped at the specified width with the given
 /// line ending.
 fn base64_len_wrapped(
@@ -165,6 +286,52 @@ fn base64_len_wrapped(
         .ok_or(Error::Length)
 }
 
+/// Options for encoding PEM documents.
+///
+/// The default configuration wraps Base64 at the RFC 7468-compliant width of
+/// 64 characters, using the line ending conventional for the target OS.
+///
+/// Some consumers require other formats, e.g. 76-column Base64 or CRLF line
+/// endings regardless of platform:
+///
+/// ```
+/// use pem_rfc7468::{EncodeConfig, LineEnding};
+///
+/// let config = EncodeConfig::new(LineEnding::CRLF).with_line_width(76);
+/// assert_eq!(config.line_width, 76);
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct EncodeConfig {
+    /// Width at which the Base64 body is wrapped. Must be at least 4.
+    pub line_width: usize,
+
+    /// Line ending used both to wrap the Base64 body and to terminate the
+    /// encapsulation boundaries.
+    pub line_ending: LineEnding,
+}
+
+impl EncodeConfig {
+    /// Create a new configuration with the given line ending, wrapping at
+    /// the default width of 64 characters.
+    pub const fn new(line_ending: LineEnding) -> Self {
+        Self {
+            line_width: BASE64_WRAP_WIDTH,
+            line_ending,
+        }
+    }
+
+    /// Set the width at which the Base64 body is wrapped.
+    pub const fn with_line_width(self, line_width: usize) -> Self {
+        Self { line_width, ..self }
+    }
+}
+
+impl Default for EncodeConfig {
+    fn default() -> Self {
+        Self::new(LineEnding::default())
+    }
+}
+
 /// Buffered PEM encoder.
 ///
 /// Stateful buffered encoder type which encodes an input PEM document according
@@ -176,6 +343,9 @@ pub struct Encoder<'l, 'o> {
     /// Line ending used to wrap Base64.
     line_ending: LineEnding,
 
+    /// Length of the encapsulated headers written to the output.
+    headers_len: usize,
+
     /// Buffered Base64 encoder.
     base64: Base64Encoder<'o>,
 }
@@ -207,6 +377,31 @@ impl<'l, 'o> Encoder<'l, 'o> {
         type_label: &'l str,
         line_width: usize,
         line_ending: LineEnding,
+        out: &'o mut [u8],
+    ) -> Result<Self> {
+        Self::new_inner(type_label, &[], line_width, line_ending, out)
+    }
+
+    /// Create a new PEM [`Encoder`] which writes the given RFC 1421
+    /// encapsulated headers before the Base64-encoded body.
+    ///
+    /// Uses the default 64-character line wrapping.
+    pub fn new_with_headers(
+        type_label: &'l str,
+        headers: &[Header<'_>],
+        line_ending: LineEnding,
+        out: &'o mut [u8],
+    ) -> Result<Self> {
+        Self::new_inner(type_label, headers, BASE64_WRAP_WIDTH, line_ending, out)
+    }
+
+    /// Write the pre-encapsulation boundary and headers, and initialize the
+    /// Base64 encoder with the remaining output buffer.
+    fn new_inner(
+        type_label: &'l str,
+        headers: &[Header<'_>],
+        line_width: usize,
+        line_ending: LineEnding,
         mut out: &'o mut [u8],
     ) -> Result<Self> {
         grammar::validate_label(type_label.as_bytes())?;
@@ -217,14 +412,24 @@ impl<'l, 'o> Encoder<'l, 'o> {
             ENCAPSULATION_BOUNDARY_DELIMITER,
             line_ending.as_bytes(),
         ] {
-            if out.len() < boundary_part.len() {
-                return Err(Error::Length);
-            }
+            out = write_part(out, boundary_part)?;
+        }
 
-            let (part, rest) = out.split_at_mut(boundary_part.len());
-            out = rest;
+        for header in headers {
+            header.validate()?;
 
-            part.copy_from_slice(boundary_part);
+            for header_part in [
+                header.name.as_bytes(),
+                b": ",
+                header.value.as_bytes(),
+                line_ending.as_bytes(),
+            ] {
+                out = write_part(out, header_part)?;
+            }
+        }
+
+        if !headers.is_empty() {
+            out = write_part(out, line_ending.as_bytes())?;
         }
 
         let base64 = Base64Encoder::new_wrapped(out, line_width, line_ending)?;
@@ -232,6 +437,7 @@ impl<'l, 'o> Encoder<'l, 'o> {
         Ok(Self {
             type_label,
             line_ending,
+            headers_len: headers_len(headers, line_ending)?,
             base64,
         })
     }
@@ -281,8 +487,21 @@ impl<'l, 'o> Encoder<'l, 'o> {
             part.copy_from_slice(boundary_part);
         }
 
-        encapsulated_len_inner(self.type_label, self.line_ending, base64.len())
+        encapsulated_len_inner(self.type_label, self.line_ending, base64.len())?
+            .checked_add(self.headers_len)
+            .ok_or(Error::Length)
+    }
+}
+
+/// Copy `part` to the start of `out`, returning the remaining output buffer.
+fn write_part<'o>(out: &'o mut [u8], part: &[u8]) -> Result<&'o mut [u8]> {
+    if out.len() < part.len() {
+        return Err(Error::Length);
     }
+
+    let (dst, rest) = out.split_at_mut(part.len());
+    dst.copy_from_slice(part);
+    Ok(rest)
 }
 
 #[cfg(feature = "std")]
@@ -18,6 +18,9 @@ pub enum Error {
     /// Errors in the encapsulated text (which aren't specifically Base64-related).
     EncapsulatedText,
 
+    /// Invalid encapsulated header.
+    Header,
+
     /// Header detected in the encapsulated text.
     HeaderDisallowed,
 
@@ -49,6 +52,7 @@ impl fmt::Display for Error {
             Error::Base64(err) => write!(f, "PEM Base64 error: {}", err),
             Error::CharacterEncoding => f.write_str("PEM character encoding error"),
             Error::EncapsulatedText => f.write_str("PEM error in encapsulated text"),
+            Error::Header => f.write_str("PEM encapsulated header invalid"),
             Error::HeaderDisallowed => f.write_str("PEM headers disallowed by RFC7468"),
             Error::Label => f.write_str("PEM type label invalid"),
             Error::Length => f.write_str("PEM length invalid"),
@@ -98,6 +102,7 @@ impl From<Error> for std::io::Error {
             Error::Base64(err) => return err.into(), // Use existing conversion
             Error::CharacterEncoding
             | Error::EncapsulatedText
+            | Error::Header
             | Error::Label
             | Error::Preamble
             | Error::PreEncapsulationBoundary
@@ -45,6 +45,25 @@ pub(crate) fn is_wsp(char: u8) -> bool {
     matches!(char, CHAR_HT | CHAR_SP)
 }
 
+/// Remove leading and trailing "WSP" characters from the provided byte slice.
+pub(crate) fn trim_wsp(mut bytes: &[u8]) -> &[u8] {
+    while let [first, rest @ ..] = bytes {
+        if !is_wsp(*first) {
+            break;
+        }
+        bytes = rest;
+    }
+
+    while let [rest @ .., last] = bytes {
+        if !is_wsp(*last) {
+            break;
+        }
+        bytes = rest;
+    }
+
+    bytes
+}
+
 /// Strip the "preamble", i.e. data that appears before the PEM
 /// pre-encapsulation boundary.
 ///
@@ -131,6 +150,16 @@ pub(crate) fn strip_trailing_eol(bytes: &[u8]) -> Option<&[u8]> {
 /// - Whitespace MUST NOT contain more than one consecutive WSP character
 // TODO(tarcieri): evaluate whether this is too strict; support '-'
 pub(crate) fn split_label(bytes: &[u8]) -> Option<(&str, &[u8])> {
+    let (label, body) = split_delimited_label(bytes)?;
+    Some((label, strip_leading_eol(body)?))
+}
+
+/// Split a slice beginning with a type label followed by the `-----`
+/// encapsulation boundary delimiter, returning the label and the remaining
+/// data after the delimiter.
+///
+/// See [`split_label`] for the rules applied to the label.
+pub(crate) fn split_delimited_label(bytes: &[u8]) -> Option<(&str, &[u8])> {
     let mut n = 0usize;
 
     // TODO(tarcieri): handle hyphens in labels as well as spaces
@@ -161,7 +190,7 @@ pub(crate) fn split_label(bytes: &[u8]) -> Option<(&str, &[u8])> {
     let label = str::from_utf8(raw_label).ok()?;
 
     match rest {
-        [b'-', b'-', b'-', b'-', b'-', body @ ..] => Some((label, strip_leading_eol(body)?)),
+        [b'-', b'-', b'-', b'-', b'-', body @ ..] => Some((label, body)),
         _ => None,
     }
 }
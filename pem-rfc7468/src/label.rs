@@ -0,0 +1,186 @@
+//! Registry of well-known PEM type labels.
+
+use crate::{Error, Result};
+use core::{fmt, str::FromStr};
+
+/// Well-known PEM type labels.
+///
+/// Includes the labels registered in [RFC 7468 § 4-15], as well as legacy
+/// labels which are commonly encountered in practice.
+///
+/// [RFC 7468 § 4-15]: https://datatracker.ietf.org/doc/html/rfc7468#section-4
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum Label {
+    /// `CERTIFICATE`: X.509 public key certificate ([RFC 7468 § 5]).
+    ///
+    /// [RFC 7468 § 5]: https://datatracker.ietf.org/doc/html/rfc7468#section-5
+    Certificate,
+
+    /// `X509 CRL`: X.509 certificate revocation list ([RFC 7468 § 6]).
+    ///
+    /// [RFC 7468 § 6]: https://datatracker.ietf.org/doc/html/rfc7468#section-6
+    X509Crl,
+
+    /// `CERTIFICATE REQUEST`: PKCS#10 certification request ([RFC 7468 § 7]).
+    ///
+    /// [RFC 7468 § 7]: https://datatracker.ietf.org/doc/html/rfc7468#section-7
+    CertificateRequest,
+
+    /// `PKCS7`: PKCS#7 cryptographic message ([RFC 7468 § 8]).
+    ///
+    /// [RFC 7468 § 8]: https://datatracker.ietf.org/doc/html/rfc7468#section-8
+    Pkcs7,
+
+    /// `CMS`: Cryptographic Message Syntax ([RFC 7468 § 9]).
+    ///
+    /// [RFC 7468 § 9]: https://datatracker.ietf.org/doc/html/rfc7468#section-9
+    Cms,
+
+    /// `PRIVATE KEY`: PKCS#8 private key ([RFC 7468 § 10]).
+    ///
+    /// [RFC 7468 § 10]: https://datatracker.ietf.org/doc/html/rfc7468#section-10
+    PrivateKey,
+
+    /// `ENCRYPTED PRIVATE KEY`: PKCS#8 encrypted private key ([RFC 7468 § 11]).
+    ///
+    /// [RFC 7468 § 11]: https://datatracker.ietf.org/doc/html/rfc7468#section-11
+    EncryptedPrivateKey,
+
+    /// `ATTRIBUTE CERTIFICATE`: X.509 attribute certificate ([RFC 7468 § 12]).
+    ///
+    /// [RFC 7468 § 12]: https://datatracker.ietf.org/doc/html/rfc7468#section-12
+    AttributeCertificate,
+
+    /// `PUBLIC KEY`: X.509 `SubjectPublicKeyInfo` ([RFC 7468 § 13]).
+    ///
+    /// [RFC 7468 § 13]: https://datatracker.ietf.org/doc/html/rfc7468#section-13
+    PublicKey,
+
+    /// `RSA PRIVATE KEY`: PKCS#1 RSA private key (legacy).
+    RsaPrivateKey,
+
+    /// `RSA PUBLIC KEY`: PKCS#1 RSA public key (legacy).
+    RsaPublicKey,
+
+    /// `EC PRIVATE KEY`: SEC1 elliptic curve private key (legacy).
+    EcPrivateKey,
+
+    /// `OPENSSH PRIVATE KEY`: OpenSSH private key.
+    OpensshPrivateKey,
+}
+
+impl Label {
+    /// All known labels.
+    pub const ALL: &'static [Label] = &[
+        Label::Certificate,
+        Label::X509Crl,
+        Label::CertificateRequest,
+        Label::Pkcs7,
+        Label::Cms,
+        Label::PrivateKey,
+        Label::EncryptedPrivateKey,
+        Label::AttributeCertificate,
+        Label::PublicKey,
+        Label::RsaPrivateKey,
+        Label::RsaPublicKey,
+        Label::EcPrivateKey,
+        Label::OpensshPrivateKey,
+    ];
+
+    /// Get the string representation of this label, as used in the
+    /// encapsulation boundaries.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Label::Certificate => "CERTIFICATE",
+            Label::X509Crl => "X509 CRL",
+            Label::CertificateRequest => "CERTIFICATE REQUEST",
+            Label::Pkcs7 => "PKCS7",
+            Label::Cms => "CMS",
+            Label::PrivateKey => "PRIVATE KEY",
+            Label::EncryptedPrivateKey => "ENCRYPTED PRIVATE KEY",
+            Label::AttributeCertificate => "ATTRIBUTE CERTIFICATE",
+            Label::PublicKey => "PUBLIC KEY",
+            Label::RsaPrivateKey => "RSA PRIVATE KEY",
+            Label::RsaPublicKey => "RSA PUBLIC KEY",
+            Label::EcPrivateKey => "EC PRIVATE KEY",
+            Label::OpensshPrivateKey => "OPENSSH PRIVATE KEY",
+        }
+    }
+}
+
+impl AsRef<str> for Label {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl From<Label> for &'static str {
+    fn from(label: Label) -> &'static str {
+        label.as_str()
+    }
+}
+
+impl FromStr for Label {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Label::ALL
+            .iter()
+            .copied()
+            .find(|label| label.as_str() == s)
+            .ok_or(Error::Label)
+    }
+}
+
+impl TryFrom<&str> for Label {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self> {
+        s.parse()
+    }
+}
+
+impl PartialEq<str> for Label {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Label {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl fmt::Display for Label {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Label;
+    use crate::Error;
+
+    #[test]
+    fn round_trip() {
+        for &label in Label::ALL {
+            assert_eq!(label.as_str().parse::<Label>(), Ok(label));
+            crate::grammar::validate_label(label.as_str().as_bytes()).unwrap();
+        }
+    }
+
+    #[test]
+    fn unknown_label() {
+        assert_eq!("PRIVATE  KEY".parse::<Label>(), Err(Error::Label));
+        assert_eq!(Label::try_from("private key"), Err(Error::Label));
+    }
+
+    #[test]
+    fn compare_str() {
+        assert_eq!(Label::CertificateRequest, "CERTIFICATE REQUEST");
+        assert_ne!(Label::PrivateKey, "EC PRIVATE KEY");
+    }
+}
@@ -0,0 +1,363 @@
+//! Lax PEM decoder.
+//!
+//! Real-world PEM documents are frequently produced by tools which don't
+//! follow RFC 7468's "Strict" grammar, e.g. documents which have been pasted
+//! into configuration files or emails. The lax decoder tolerates the most
+//! common deviations and reports which of them were encountered via
+//! [`Relaxations`].
+
+use crate::{
+    grammar, Error, Result, BASE64_WRAP_WIDTH, POST_ENCAPSULATION_BOUNDARY,
+    PRE_ENCAPSULATION_BOUNDARY,
+};
+use base64ct::{Base64, Encoding};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Relaxations of RFC 7468's "Strict" grammar which were applied when
+/// decoding a document with [`decode_lax`].
+///
+/// Note that a missing newline after the post-encapsulation boundary, as well
+/// as text preceding the pre-encapsulation boundary, are permitted by the
+/// strict grammar and therefore aren't reported.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Relaxations {
+    /// Lines began with whitespace, e.g. an indented document.
+    pub leading_whitespace: bool,
+
+    /// Lines ended with whitespace.
+    pub trailing_whitespace: bool,
+
+    /// Blank lines occurred within the encapsulated text.
+    pub blank_lines: bool,
+
+    /// Base64 was not wrapped at exactly 64 characters per line.
+    pub line_width: bool,
+
+    /// The label in the post-encapsulation boundary didn't match the label
+    /// in the pre-encapsulation boundary.
+    pub label_mismatch: bool,
+
+    /// Text followed the post-encapsulation boundary.
+    pub postamble: bool,
+}
+
+impl Relaxations {
+    /// Did the document conform to the strict grammar, i.e. were no
+    /// relaxations applied?
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Record any leading or trailing whitespace on the given line.
+    fn record_whitespace(&mut self, line: &[u8]) {
+        if line.first().copied().map_or(false, grammar::is_wsp) {
+            self.leading_whitespace = true;
+        }
+
+        if line.last().copied().map_or(false, grammar::is_wsp) {
+            self.trailing_whitespace = true;
+        }
+    }
+}
+
+/// Decode a PEM document, tolerating common deviations from RFC 7468's
+/// "Strict" grammar:
+///
+/// - whitespace at the beginning or end of lines
+/// - blank lines within the encapsulated text
+/// - Base64 wrapped at widths other than 64 characters
+/// - mismatched labels in the encapsulation boundaries
+/// - text following the post-encapsulation boundary
+///
+/// On success, writes the decoded document into the provided buffer, returning
+/// the decoded label, the portion of the provided buffer containing the
+/// decoded message, and the [`Relaxations`] which were applied.
+///
+/// Encapsulated headers are still rejected with [`Error::HeaderDisallowed`].
+///
+/// Unlike [`decode`][`crate::decode`], the layout of the encapsulated text
+/// (i.e. the positions of whitespace) is not handled in constant time,
+/// although the Base64 decoding itself is.
+pub fn decode_lax<'i, 'o>(
+    pem: &'i [u8],
+    buf: &'o mut [u8],
+) -> Result<(&'i str, &'o [u8], Relaxations)> {
+    let (label, len, relaxations) = decode_lax_inner(pem, buf)?;
+    Ok((label, &buf[..len], relaxations))
+}
+
+/// Decode a PEM document according to the lax grammar described in
+/// [`decode_lax`], returning the result as a [`Vec`] upon success.
+#[cfg(feature = "alloc")]
+pub fn decode_lax_vec(pem: &[u8]) -> Result<(&str, Vec<u8>, Relaxations)> {
+    // Upper bound on the decoded length
+    let max_len = (pem.len() / 4)
+        .checked_mul(3)
+        .and_then(|len| len.checked_add(3))
+        .ok_or(Error::Length)?;
+
+    let mut buf = vec![0u8; max_len];
+    let (label, len, relaxations) = decode_lax_inner(pem, &mut buf)?;
+    buf.truncate(len);
+    Ok((label, buf, relaxations))
+}
+
+/// Decode a document, returning the label, decoded length, and relaxations.
+fn decode_lax_inner<'i>(pem: &'i [u8], buf: &mut [u8]) -> Result<(&'i str, usize, Relaxations)> {
+    let mut relaxations = Relaxations::default();
+    let mut lines = Lines { remaining: pem };
+
+    // Locate the pre-encapsulation boundary
+    let label = loop {
+        let line = lines.next().ok_or(Error::Preamble)?;
+        let trimmed = grammar::trim_wsp(line);
+
+        if let Some(rest) = trimmed.strip_prefix(PRE_ENCAPSULATION_BOUNDARY) {
+            relaxations.record_whitespace(line);
+            break parse_label(rest).ok_or(Error::PreEncapsulationBoundary)?;
+        }
+    };
+
+    let mut decoder = LaxBase64Decoder::new(buf);
+    let mut line_len = None;
+
+    // Decode the encapsulated text up to the post-encapsulation boundary
+    loop {
+        let line = lines.next().ok_or(Error::PostEncapsulationBoundary)?;
+        let trimmed = grammar::trim_wsp(line);
+        relaxations.record_whitespace(line);
+
+        if let Some(rest) = trimmed.strip_prefix(POST_ENCAPSULATION_BOUNDARY) {
+            let end_label = parse_label(rest).ok_or(Error::PostEncapsulationBoundary)?;
+            relaxations.label_mismatch = end_label != label;
+
+            if line_len.map_or(false, |len| len > BASE64_WRAP_WIDTH) {
+                relaxations.line_width = true;
+            }
+
+            break;
+        }
+
+        if trimmed.is_empty() {
+            relaxations.blank_lines = true;
+            continue;
+        }
+
+        // All lines except the last must be exactly 64 characters
+        if line_len.map_or(false, |len| len != BASE64_WRAP_WIDTH) {
+            relaxations.line_width = true;
+        }
+
+        line_len = Some(trimmed.len());
+
+        for &byte in trimmed {
+            decoder
+                .push(byte)
+                .map_err(|e| check_for_headers(trimmed, e))?;
+        }
+    }
+
+    relaxations.postamble = lines.any(|line| !grammar::trim_wsp(line).is_empty());
+    Ok((label, decoder.finish()?, relaxations))
+}
+
+/// Parse the label and trailing delimiter of an encapsulation boundary.
+fn parse_label(bytes: &[u8]) -> Option<&str> {
+    let (label, rest) = grammar::split_delimited_label(bytes)?;
+
+    if rest.is_empty() {
+        Some(label)
+    } else {
+        None
+    }
+}
+
+/// Map Base64 errors to [`Error::HeaderDisallowed`] if the line looks like
+/// an encapsulated header.
+fn check_for_headers(line: &[u8], err: Error) -> Error {
+    if err == Error::Base64(base64ct::Error::InvalidEncoding) && line.contains(&grammar::CHAR_COLON)
+    {
+        Error::HeaderDisallowed
+    } else {
+        err
+    }
+}
+
+/// Iterator over lines divided with CRLF, CR, or LF.
+struct Lines<'a> {
+    /// Input which hasn't been yielded yet.
+    remaining: &'a [u8],
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<&'a [u8]> {
+        if self.remaining.is_empty() {
+            return None;
+        }
+
+        let line_len = self
+            .remaining
+            .iter()
+            .position(|&byte| matches!(byte, grammar::CHAR_CR | grammar::CHAR_LF))
+            .unwrap_or(self.remaining.len());
+
+        let (line, rest) = self.remaining.split_at(line_len);
+        self.remaining = grammar::strip_leading_eol(rest).unwrap_or(rest);
+        Some(line)
+    }
+}
+
+/// Base64 decoder which accepts input a byte at a time, decoding each
+/// 4-character block as it's completed.
+struct LaxBase64Decoder<'o> {
+    /// Output buffer.
+    out: &'o mut [u8],
+
+    /// Number of bytes written to the output buffer.
+    pos: usize,
+
+    /// Partially filled block of Base64 characters.
+    block: [u8; 4],
+
+    /// Number of characters in `block`.
+    block_len: usize,
+
+    /// Has a padded (i.e. final) block been decoded?
+    padded: bool,
+}
+
+impl<'o> LaxBase64Decoder<'o> {
+    /// Create a new decoder which writes into the given buffer.
+    fn new(out: &'o mut [u8]) -> Self {
+        Self {
+            out,
+            pos: 0,
+            block: [0u8; 4],
+            block_len: 0,
+            padded: false,
+        }
+    }
+
+    /// Add a Base64 character to the current block, decoding it once full.
+    fn push(&mut self, byte: u8) -> Result<()> {
+        if self.padded {
+            return Err(Error::Base64(base64ct::Error::InvalidEncoding));
+        }
+
+        *self
+            .block
+            .get_mut(self.block_len)
+            .ok_or(Error::EncapsulatedText)? = byte;
+        self.block_len = self.block_len.checked_add(1).ok_or(Error::Length)?;
+
+        if self.block_len == self.block.len() {
+            let mut decoded = [0u8; 3];
+            let decoded = Base64::decode(self.block, &mut decoded)?;
+            let end = self.pos.checked_add(decoded.len()).ok_or(Error::Length)?;
+
+            self.out
+                .get_mut(self.pos..end)
+                .ok_or(Error::Length)?
+                .copy_from_slice(decoded);
+
+            self.pos = end;
+            self.block_len = 0;
+            self.padded = decoded.len() < 3;
+        }
+
+        Ok(())
+    }
+
+    /// Finish decoding, returning the number of bytes written.
+    fn finish(self) -> Result<usize> {
+        if self.block_len == 0 {
+            Ok(self.pos)
+        } else {
+            Err(Error::Base64(base64ct::Error::InvalidEncoding))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strict_document_has_no_relaxations() {
+        let pem = include_bytes!("../tests/examples/pkcs1.pem");
+        let mut buf = [0u8; 2048];
+        let (label, decoded, relaxations) = decode_lax(pem, &mut buf).unwrap();
+        assert_eq!(label, "RSA PRIVATE KEY");
+        assert_eq!(decoded, include_bytes!("../tests/examples/pkcs1.der"));
+        assert!(relaxations.is_empty());
+    }
+
+    #[test]
+    fn whitespace_and_blank_lines() {
+        let pem = b"  -----BEGIN TEST-----  \r\n\r\n  Zm9v\t\r\n\r\nYmFy\r\n-----END TEST-----";
+        let mut buf = [0u8; 16];
+        let (label, decoded, relaxations) = decode_lax(pem, &mut buf).unwrap();
+        assert_eq!(label, "TEST");
+        assert_eq!(decoded, b"foobar");
+        assert!(relaxations.leading_whitespace);
+        assert!(relaxations.trailing_whitespace);
+        assert!(relaxations.blank_lines);
+        assert!(relaxations.line_width);
+        assert!(!relaxations.label_mismatch);
+        assert!(!relaxations.postamble);
+    }
+
+    #[test]
+    fn label_mismatch_and_postamble() {
+        let pem = b"-----BEGIN FOO-----\nZm9vYg==\n-----END BAR-----\ncomment\n";
+        let mut buf = [0u8; 16];
+        let (label, decoded, relaxations) = decode_lax(pem, &mut buf).unwrap();
+        assert_eq!(label, "FOO");
+        assert_eq!(decoded, b"foob");
+        assert!(relaxations.label_mismatch);
+        assert!(relaxations.postamble);
+        assert!(!relaxations.line_width);
+    }
+
+    #[test]
+    fn block_split_across_lines() {
+        let pem = b"-----BEGIN TEST-----\nZm9vY\nmFy\n-----END TEST-----\n";
+        let mut buf = [0u8; 16];
+        let (_, decoded, _) = decode_lax(pem, &mut buf).unwrap();
+        assert_eq!(decoded, b"foobar");
+    }
+
+    #[test]
+    fn reject_data_after_padding() {
+        let pem = b"-----BEGIN TEST-----\nZm9vYg==Zm9v\n-----END TEST-----\n";
+        let mut buf = [0u8; 16];
+        assert_eq!(
+            decode_lax(pem, &mut buf),
+            Err(Error::Base64(base64ct::Error::InvalidEncoding))
+        );
+    }
+
+    #[test]
+    fn reject_truncated_block() {
+        let pem = b"-----BEGIN TEST-----\nZm9vY\n-----END TEST-----\n";
+        let mut buf = [0u8; 16];
+        assert_eq!(
+            decode_lax(pem, &mut buf),
+            Err(Error::Base64(base64ct::Error::InvalidEncoding))
+        );
+    }
+
+    #[test]
+    fn reject_missing_post_encapsulation_boundary() {
+        let pem = b"-----BEGIN TEST-----\nZm9v\n";
+        let mut buf = [0u8; 16];
+        assert_eq!(
+            decode_lax(pem, &mut buf),
+            Err(Error::PostEncapsulationBoundary)
+        );
+    }
+}
@@ -61,16 +61,38 @@ mod decoder;
 mod encoder;
 mod error;
 mod grammar;
+mod headers;
+mod label;
+mod lax;
+mod stream;
+
+#[cfg(feature = "std")]
+mod writer;
 
 pub use crate::{
-    decoder::{decode, decode_label, Decoder},
-    encoder::{encapsulated_len, encapsulated_len_wrapped, encode, encoded_len, Encoder},
+    decoder::{decode, decode_label, decode_with_headers, Decoder},
+    encoder::{
+        encapsulated_len, encapsulated_len_wrapped, encode, encode_with_config,
+        encode_with_headers, encoded_len, encoded_len_with_config, encoded_len_with_headers,
+        EncodeConfig, Encoder,
+    },
     error::{Error, Result},
+    headers::{DekInfo, Header, HeaderIter, Headers, ProcType, ProcTypeKind},
+    label::Label,
+    lax::{decode_lax, Relaxations},
+    stream::StreamDecoder,
 };
 pub use base64ct::LineEnding;
 
 #[cfg(feature = "alloc")]
-pub use crate::{decoder::decode_vec, encoder::encode_string};
+pub use crate::{
+    decoder::{decode_all, decode_vec, decode_vec_with_headers, Iter},
+    encoder::{encode_string, encode_string_with_config, encode_string_with_headers},
+    lax::decode_lax_vec,
+};
+
+#[cfg(feature = "std")]
+pub use crate::writer::ArmorWriter;
 
 /// The pre-encapsulation boundary appears before the encapsulated text.
 ///
@@ -105,6 +127,9 @@ pub type Base64Decoder<'i> = base64ct::Decoder<'i, base64ct::Base64>;
 pub type Base64Encoder<'o> = base64ct::Encoder<'o, base64ct::Base64>;
 
 /// Marker trait for types with an associated PEM type label.
+///
+/// Implementations for well-known document types can use [`Label::as_str`]
+/// to avoid spelling out the label by hand.
 pub trait PemLabel {
     /// Expected PEM type label for a given document, e.g. `"PRIVATE KEY"`
     const PEM_LABEL: &'static str;
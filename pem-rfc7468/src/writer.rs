@@ -0,0 +1,151 @@
+//! Streaming PEM writer.
+
+use crate::{
+    grammar, EncodeConfig, Error, LineEnding, ENCAPSULATION_BOUNDARY_DELIMITER,
+    POST_ENCAPSULATION_BOUNDARY, PRE_ENCAPSULATION_BOUNDARY,
+};
+use base64ct::{Base64, StreamEncoder};
+use core::{cmp, mem};
+use std::io;
+
+/// Maximum number of input bytes Base64-encoded at a time.
+const CHUNK_SIZE: usize = 768;
+
+/// Length of a Base64-encoded chunk.
+const ENCODED_CHUNK_SIZE: usize = 1024;
+
+/// PEM armor writer.
+///
+/// Wraps an [`io::Write`] and incrementally encodes everything written
+/// to it as the Base64 body of a PEM document, without buffering the whole
+/// payload. The pre-encapsulation boundary is written upon construction, and
+/// the post-encapsulation boundary is written by [`ArmorWriter::finish`].
+///
+/// The output is identical to that of [`encode`][`crate::encode`] for the
+/// same input.
+///
+/// Note that dropping an [`ArmorWriter`] without calling
+/// [`ArmorWriter::finish`] results in a truncated document.
+pub struct ArmorWriter<'l, W: io::Write> {
+    /// Inner writer.
+    inner: W,
+
+    /// PEM type label.
+    type_label: &'l str,
+
+    /// Line width and line ending.
+    config: EncodeConfig,
+
+    /// Streaming Base64 encoder.
+    base64: StreamEncoder<Base64>,
+
+    /// Number of Base64 characters written to the current line.
+    line_len: usize,
+}
+
+impl<'l, W: io::Write> ArmorWriter<'l, W> {
+    /// Create a new [`ArmorWriter`] with the default 64-character line
+    /// wrapping, writing the pre-encapsulation boundary to `inner`.
+    pub fn new(type_label: &'l str, line_ending: LineEnding, inner: W) -> io::Result<Self> {
+        Self::new_with_config(type_label, EncodeConfig::new(line_ending), inner)
+    }
+
+    /// Create a new [`ArmorWriter`] using the line width and line ending
+    /// specified in the given [`EncodeConfig`], writing the
+    /// pre-encapsulation boundary to `inner`.
+    pub fn new_with_config(
+        type_label: &'l str,
+        config: EncodeConfig,
+        mut inner: W,
+    ) -> io::Result<Self> {
+        grammar::validate_label(type_label.as_bytes())?;
+
+        if config.line_width < 4 {
+            return Err(Error::Length.into());
+        }
+
+        for boundary_part in [
+            PRE_ENCAPSULATION_BOUNDARY,
+            type_label.as_bytes(),
+            ENCAPSULATION_BOUNDARY_DELIMITER,
+            config.line_ending.as_bytes(),
+        ] {
+            inner.write_all(boundary_part)?;
+        }
+
+        Ok(Self {
+            inner,
+            type_label,
+            config,
+            base64: StreamEncoder::new(),
+            line_len: 0,
+        })
+    }
+
+    /// Get the PEM type label used for this document.
+    pub fn type_label(&self) -> &'l str {
+        self.type_label
+    }
+
+    /// Borrow the inner writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Finish writing the document, encoding any buffered input and writing
+    /// the post-encapsulation boundary.
+    ///
+    /// On success, returns the inner writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        let mut out = [0u8; 4];
+        let encoded = mem::take(&mut self.base64).finish(&mut out)?;
+        self.write_base64(encoded.as_bytes())?;
+
+        for boundary_part in [
+            self.config.line_ending.as_bytes(),
+            POST_ENCAPSULATION_BOUNDARY,
+            self.type_label.as_bytes(),
+            ENCAPSULATION_BOUNDARY_DELIMITER,
+            self.config.line_ending.as_bytes(),
+        ] {
+            self.inner.write_all(boundary_part)?;
+        }
+
+        self.inner.flush()?;
+        Ok(self.inner)
+    }
+
+    /// Write Base64 characters to the inner writer, wrapping lines at the
+    /// configured width.
+    fn write_base64(&mut self, mut chars: &[u8]) -> io::Result<()> {
+        while !chars.is_empty() {
+            if self.line_len == self.config.line_width {
+                self.inner.write_all(self.config.line_ending.as_bytes())?;
+                self.line_len = 0;
+            }
+
+            let remaining = self.config.line_width.saturating_sub(self.line_len);
+            let len = cmp::min(remaining, chars.len());
+            let (line, rest) = chars.split_at(len);
+            self.inner.write_all(line)?;
+            self.line_len = self.line_len.checked_add(len).ok_or(Error::Length)?;
+            chars = rest;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'l, W: io::Write> io::Write for ArmorWriter<'l, W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let len = cmp::min(buf.len(), CHUNK_SIZE);
+        let mut out = [0u8; ENCODED_CHUNK_SIZE];
+        let encoded = self.base64.encode(&buf[..len], &mut out)?;
+        self.write_base64(encoded.as_bytes())?;
+        Ok(len)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
@@ -110,3 +110,143 @@ fn ed25519_example() {
     let label = pem_rfc7468::decode_label(pem).unwrap();
     assert_eq!(label, "ED25519 CERT");
 }
+
+#[test]
+fn pkcs1_example_streaming() {
+    let pem = include_bytes!("examples/pkcs1_with_preceeding_junk.pem");
+    let mut scratch = [0u8; 128];
+    let mut decoder = pem_rfc7468::StreamDecoder::new(&mut scratch).unwrap();
+    let mut buf = [0u8; 2048];
+    let mut len = 0;
+
+    for mut chunk in pem.chunks(100) {
+        while !chunk.is_empty() {
+            let (consumed, decoded) = decoder.decode(chunk, &mut buf[len..]).unwrap();
+            len += decoded.len();
+            chunk = &chunk[consumed..];
+        }
+    }
+
+    decoder.finish().unwrap();
+    assert_eq!(decoder.type_label(), Some("RSA PRIVATE KEY"));
+    assert_eq!(&buf[..len], include_bytes!("examples/pkcs1.der"));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn decode_all_chain() {
+    let pkcs1 = include_bytes!("examples/pkcs1.pem");
+    let pkcs8 = include_bytes!("examples/pkcs8.pem");
+    let truncated = b"-----BEGIN CERTIFICATE-----\nAAAA\n";
+    let bad_label = b"-----BEGIN FOO-----\nAAAA\n-----END BAR-----\n";
+
+    let mut chain = b"Subject: example\n".to_vec();
+    chain.extend_from_slice(pkcs1);
+    chain.extend_from_slice(b"\nsome interspersed text\n");
+    chain.extend_from_slice(truncated);
+    chain.extend_from_slice(pkcs8);
+    chain.extend_from_slice(bad_label);
+    chain.extend_from_slice(pkcs8);
+    chain.extend_from_slice(b"trailing text");
+
+    let results = pem_rfc7468::decode_all(&chain).collect::<Vec<_>>();
+    assert_eq!(results.len(), 5);
+
+    let (label, der) = results[0].as_ref().unwrap();
+    assert_eq!(*label, "RSA PRIVATE KEY");
+    assert_eq!(der, include_bytes!("examples/pkcs1.der"));
+
+    assert_eq!(
+        results[1],
+        Err(pem_rfc7468::Error::PostEncapsulationBoundary)
+    );
+
+    let (label, der) = results[2].as_ref().unwrap();
+    assert_eq!(*label, "PRIVATE KEY");
+    assert_eq!(der, include_bytes!("examples/pkcs8.der"));
+
+    assert_eq!(
+        results[3],
+        Err(pem_rfc7468::Error::PostEncapsulationBoundary)
+    );
+    assert_eq!(results[2], results[4]);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn decode_all_unterminated() {
+    let mut iter = pem_rfc7468::decode_all(b"-----BEGIN CERTIFICATE-----\nAAAA\n");
+    assert_eq!(
+        iter.next(),
+        Some(Err(pem_rfc7468::Error::PostEncapsulationBoundary))
+    );
+    assert_eq!(iter.next(), None);
+    assert_eq!(pem_rfc7468::decode_all(b"no PEM here").next(), None);
+}
+
+#[test]
+fn pkcs1_enc_example_with_headers() {
+    let pem = include_bytes!("examples/ssh_rsa_pem_password.pem");
+    let mut buf = [0u8; 2048];
+    let (label, headers, decoded) = pem_rfc7468::decode_with_headers(pem, &mut buf).unwrap();
+    assert_eq!(label, "RSA PRIVATE KEY");
+    assert_eq!(decoded.len(), 1200);
+
+    let proc_type = headers.proc_type().unwrap().unwrap();
+    assert!(proc_type.is_encrypted());
+
+    let dek_info = headers.dek_info().unwrap().unwrap();
+    assert_eq!(dek_info.algorithm, "AES-128-CBC");
+    assert_eq!(dek_info.iv, "15670D76FD184D46C40C971733E0543F");
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn pkcs1_enc_example_with_headers_vec() {
+    let pem = include_bytes!("examples/ssh_rsa_pem_password.pem");
+    let (label, headers, decoded) = pem_rfc7468::decode_vec_with_headers(pem).unwrap();
+    assert_eq!(label, "RSA PRIVATE KEY");
+    assert_eq!(headers.iter().count(), 2);
+
+    let mut buf = [0u8; 2048];
+    let (_, _, expected) = pem_rfc7468::decode_with_headers(pem, &mut buf).unwrap();
+    assert_eq!(decoded, expected);
+}
+
+#[test]
+fn pkcs1_example_with_headers_empty() {
+    let pem = include_bytes!("examples/pkcs1.pem");
+    let mut buf = [0u8; 2048];
+    let (label, headers, decoded) = pem_rfc7468::decode_with_headers(pem, &mut buf).unwrap();
+    assert_eq!(label, "RSA PRIVATE KEY");
+    assert!(headers.is_empty());
+    assert_eq!(decoded, include_bytes!("examples/pkcs1.der"));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn pkcs1_example_lax() {
+    let pem = include_str!("examples/pkcs1.pem")
+        .lines()
+        .map(|line| format!("    {}  ", line))
+        .collect::<Vec<_>>()
+        .join("\r\n");
+
+    assert!(pem_rfc7468::decode_vec(pem.as_bytes()).is_err());
+
+    let (label, decoded, relaxations) = pem_rfc7468::decode_lax_vec(pem.as_bytes()).unwrap();
+    assert_eq!(label, "RSA PRIVATE KEY");
+    assert_eq!(decoded, include_bytes!("examples/pkcs1.der"));
+    assert!(relaxations.leading_whitespace);
+    assert!(relaxations.trailing_whitespace);
+    assert!(!relaxations.line_width);
+    assert!(!relaxations.blank_lines);
+}
+
+#[test]
+fn pkcs1_enc_example_lax() {
+    let pem = include_bytes!("examples/ssh_rsa_pem_password.pem");
+    let mut buf = [0u8; 2048];
+    let result = pem_rfc7468::decode_lax(pem, &mut buf);
+    assert_eq!(result, Err(pem_rfc7468::Error::HeaderDisallowed));
+}
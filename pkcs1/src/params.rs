@@ -2,14 +2,20 @@
 
 use crate::{Error, Result};
 use der::{
-    asn1::{AnyRef, ContextSpecificRef, ObjectIdentifier},
+    asn1::{AnyRef, ContextSpecificRef, ObjectIdentifier, OctetStringRef},
     oid::AssociatedOid,
     Decode, DecodeValue, Encode, EncodeValue, FixedTag, Length, Reader, Sequence, Tag, TagMode,
     TagNumber, Writer,
 };
 use spki::{AlgorithmIdentifier, AlgorithmIdentifierRef};
 
+#[cfg(feature = "alloc")]
+use {der::Any, spki::AlgorithmIdentifierOwned};
+
 const OID_SHA_1: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.14.3.2.26");
+const OID_SHA_256: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.2.1");
+const OID_SHA_384: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.2.2");
+const OID_SHA_512: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.2.3");
 const OID_MGF_1: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.8");
 const OID_PSPECIFIED: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.9");
 
@@ -98,15 +104,37 @@ impl<'a> RsaPssParams<'a> {
     where
         D: AssociatedOid,
     {
+        Self::with_hash(D::OID, salt_len)
+    }
+
+    /// Create new RsaPssParams for SHA-256, MGF1 with SHA-256, and a salt
+    /// length of 32 bytes (i.e. the size of the digest)
+    pub fn sha256() -> Self {
+        Self::with_hash(OID_SHA_256, 32)
+    }
+
+    /// Create new RsaPssParams for SHA-384, MGF1 with SHA-384, and a salt
+    /// length of 48 bytes (i.e. the size of the digest)
+    pub fn sha384() -> Self {
+        Self::with_hash(OID_SHA_384, 48)
+    }
+
+    /// Create new RsaPssParams for SHA-512, MGF1 with SHA-512, and a salt
+    /// length of 64 bytes (i.e. the size of the digest)
+    pub fn sha512() -> Self {
+        Self::with_hash(OID_SHA_512, 64)
+    }
+
+    fn with_hash(hash: ObjectIdentifier, salt_len: u8) -> Self {
         Self {
             hash: AlgorithmIdentifierRef {
-                oid: D::OID,
+                oid: hash,
                 parameters: Some(AnyRef::NULL),
             },
             mask_gen: AlgorithmIdentifier {
                 oid: OID_MGF_1,
                 parameters: Some(AlgorithmIdentifierRef {
-                    oid: D::OID,
+                    oid: hash,
                     parameters: Some(AnyRef::NULL),
                 }),
             },
@@ -217,6 +245,11 @@ impl EncodeValue for RsaPssParams<'_> {
 
 impl<'a> Sequence<'a> for RsaPssParams<'a> {}
 
+/// `id-RSASSA-PSS` Object Identifier (OID)
+impl AssociatedOid for RsaPssParams<'_> {
+    const OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.10");
+}
+
 impl<'a> TryFrom<&'a [u8]> for RsaPssParams<'a> {
     type Error = Error;
 
@@ -225,6 +258,36 @@ impl<'a> TryFrom<&'a [u8]> for RsaPssParams<'a> {
     }
 }
 
+impl<'a> TryFrom<AnyRef<'a>> for RsaPssParams<'a> {
+    type Error = Error;
+
+    fn try_from(any: AnyRef<'a>) -> Result<Self> {
+        Ok(any.decode_as()?)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl TryFrom<&RsaPssParams<'_>> for Any {
+    type Error = Error;
+
+    fn try_from(params: &RsaPssParams<'_>) -> Result<Any> {
+        Ok(Any::encode_from(params)?)
+    }
+}
+
+/// `id-RSASSA-PSS` algorithm identifier with the given parameters.
+#[cfg(feature = "alloc")]
+impl TryFrom<&RsaPssParams<'_>> for AlgorithmIdentifierOwned {
+    type Error = Error;
+
+    fn try_from(params: &RsaPssParams<'_>) -> Result<AlgorithmIdentifierOwned> {
+        Ok(AlgorithmIdentifierOwned {
+            oid: RsaPssParams::OID,
+            parameters: Some(Any::try_from(params)?),
+        })
+    }
+}
+
 /// Default Mask Generation Function (MGF): SHA-1.
 fn default_mgf1_sha1<'a>() -> AlgorithmIdentifier<AlgorithmIdentifierRef<'a>> {
     AlgorithmIdentifier::<AlgorithmIdentifierRef<'a>> {
@@ -274,15 +337,48 @@ impl<'a> RsaOaepParams<'a> {
     where
         D: AssociatedOid,
     {
+        Self::with_hash(D::OID, label.as_ref())
+    }
+
+    /// Create new RsaOaepParams for SHA-256, MGF1 with SHA-256, and the
+    /// specified label
+    pub fn sha256(label: &'a [u8]) -> Self {
+        Self::with_hash(OID_SHA_256, label)
+    }
+
+    /// Create new RsaOaepParams for SHA-384, MGF1 with SHA-384, and the
+    /// specified label
+    pub fn sha384(label: &'a [u8]) -> Self {
+        Self::with_hash(OID_SHA_384, label)
+    }
+
+    /// Create new RsaOaepParams for SHA-512, MGF1 with SHA-512, and the
+    /// specified label
+    pub fn sha512(label: &'a [u8]) -> Self {
+        Self::with_hash(OID_SHA_512, label)
+    }
+
+    /// Get the label L, if the label source is `id-pSpecified`
+    pub fn label(&self) -> Option<&'a [u8]> {
+        match self.p_source.parameters {
+            Some(params) if self.p_source.oid == OID_PSPECIFIED => params
+                .decode_as::<OctetStringRef<'a>>()
+                .ok()
+                .map(|label| label.as_bytes()),
+            _ => None,
+        }
+    }
+
+    fn with_hash(hash: ObjectIdentifier, label: &'a [u8]) -> Self {
         Self {
             hash: AlgorithmIdentifierRef {
-                oid: D::OID,
+                oid: hash,
                 parameters: Some(AnyRef::NULL),
             },
             mask_gen: AlgorithmIdentifier {
                 oid: OID_MGF_1,
                 parameters: Some(AlgorithmIdentifierRef {
-                    oid: D::OID,
+                    oid: hash,
                     parameters: Some(AnyRef::NULL),
                 }),
             },
@@ -376,6 +472,11 @@ impl EncodeValue for RsaOaepParams<'_> {
 
 impl<'a> Sequence<'a> for RsaOaepParams<'a> {}
 
+/// `id-RSAES-OAEP` Object Identifier (OID)
+impl AssociatedOid for RsaOaepParams<'_> {
+    const OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.7");
+}
+
 impl<'a> TryFrom<&'a [u8]> for RsaOaepParams<'a> {
     type Error = Error;
 
@@ -384,12 +485,40 @@ impl<'a> TryFrom<&'a [u8]> for RsaOaepParams<'a> {
     }
 }
 
-fn pspecicied_algorithm_identifier(label: &impl AsRef<[u8]>) -> AlgorithmIdentifierRef<'_> {
+impl<'a> TryFrom<AnyRef<'a>> for RsaOaepParams<'a> {
+    type Error = Error;
+
+    fn try_from(any: AnyRef<'a>) -> Result<Self> {
+        Ok(any.decode_as()?)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl TryFrom<&RsaOaepParams<'_>> for Any {
+    type Error = Error;
+
+    fn try_from(params: &RsaOaepParams<'_>) -> Result<Any> {
+        Ok(Any::encode_from(params)?)
+    }
+}
+
+/// `id-RSAES-OAEP` algorithm identifier with the given parameters.
+#[cfg(feature = "alloc")]
+impl TryFrom<&RsaOaepParams<'_>> for AlgorithmIdentifierOwned {
+    type Error = Error;
+
+    fn try_from(params: &RsaOaepParams<'_>) -> Result<AlgorithmIdentifierOwned> {
+        Ok(AlgorithmIdentifierOwned {
+            oid: RsaOaepParams::OID,
+            parameters: Some(Any::try_from(params)?),
+        })
+    }
+}
+
+fn pspecicied_algorithm_identifier(label: &[u8]) -> AlgorithmIdentifierRef<'_> {
     AlgorithmIdentifierRef {
         oid: OID_PSPECIFIED,
-        parameters: Some(
-            AnyRef::new(Tag::OctetString, label.as_ref()).expect("error creating OAEP params"),
-        ),
+        parameters: Some(AnyRef::new(Tag::OctetString, label).expect("error creating OAEP params")),
     }
 }
 
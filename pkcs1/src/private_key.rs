@@ -14,7 +14,13 @@ use der::{
 use {self::other_prime_info::OtherPrimeInfo, alloc::vec::Vec, der::SecretDocument};
 
 #[cfg(feature = "pem")]
-use der::pem::PemLabel;
+use der::pem::{Label, PemLabel};
+
+#[cfg(feature = "pkcs8")]
+use {
+    crate::{ALGORITHM_ID, ALGORITHM_OID},
+    pkcs8::PrivateKeyInfo,
+};
 
 /// PKCS#1 RSA Private Keys as defined in [RFC 8017 Appendix 1.2].
 ///
@@ -90,6 +96,68 @@ impl<'a> RsaPrivateKey<'a> {
             Version::TwoPrime
         }
     }
+
+    /// Get the additional primes of a multi-prime key, i.e. the contents of
+    /// [`RsaPrivateKey::other_prime_infos`].
+    ///
+    /// Returns an empty slice for two-prime keys.
+    #[cfg(feature = "alloc")]
+    pub fn other_primes(&self) -> &[OtherPrimeInfo<'a>] {
+        self.other_prime_infos.as_deref().unwrap_or_default()
+    }
+
+    /// Get all prime factors `r_1`, ..., `r_u` of `n`, in order.
+    pub fn primes(&self) -> impl Iterator<Item = UintRef<'a>> + '_ {
+        let primes = [self.prime1, self.prime2].into_iter();
+
+        #[cfg(feature = "alloc")]
+        let primes = primes.chain(self.other_primes().iter().map(|info| info.prime));
+
+        primes
+    }
+
+    /// Get the number of prime factors `u` of `n`.
+    pub fn prime_count(&self) -> usize {
+        self.primes().count()
+    }
+
+    /// Encode this key as a PKCS#8 [`PrivateKeyInfo`] into the provided
+    /// buffer, returning a slice containing the encoded message.
+    ///
+    /// This doesn't require `alloc`: the end of `buf` is used as scratch space
+    /// for the PKCS#1 encoding of this key, so it needs to be large enough to
+    /// hold both the PKCS#1 and the PKCS#8 encodings. The scratch space is
+    /// zeroed before returning.
+    #[cfg(feature = "pkcs8")]
+    pub fn encode_pkcs8_to_slice<'b>(&self, buf: &'b mut [u8]) -> Result<&'b [u8]> {
+        let pkcs1_len = usize::try_from(self.encoded_len()?)?;
+        let (pkcs8_buf, pkcs1_buf) = buf.split_at_mut(buf.len().saturating_sub(pkcs1_len));
+
+        let result = self.encode_to_slice(pkcs1_buf).and_then(|private_key| {
+            let private_key_info = PrivateKeyInfo {
+                algorithm: ALGORITHM_ID,
+                private_key,
+                attributes: None,
+                public_key: None,
+            };
+
+            private_key_info.encode_to_slice(pkcs8_buf)
+        });
+
+        pkcs1_buf.fill(0);
+        Ok(result?)
+    }
+
+    /// Ensure `otherPrimeInfos` contains at least one prime if present, as
+    /// required by its `SIZE(1..MAX)` constraint.
+    fn check_other_prime_infos(&self) -> der::Result<()> {
+        #[cfg(feature = "alloc")]
+        if self.other_primes().is_empty() && self.other_prime_infos.is_some() {
+            return Err(Tag::Sequence.value_error());
+        }
+
+        Ok(())
+    }
 }
 
 impl<'a> DecodeValue<'a> for RsaPrivateKey<'a> {
@@ -114,6 +182,8 @@ impl<'a> DecodeValue<'a> for RsaPrivateKey<'a> {
                 return Err(reader.error(der::ErrorKind::Value { tag: Tag::Integer }));
             }
 
+            result.check_other_prime_infos()?;
+
             Ok(result)
         })
     }
@@ -121,6 +191,8 @@ impl<'a> DecodeValue<'a> for RsaPrivateKey<'a> {
 
 impl EncodeValue for RsaPrivateKey<'_> {
     fn value_len(&self) -> der::Result<Length> {
+        self.check_other_prime_infos()?;
+
         self.version().encoded_len()?
             + self.modulus.encoded_len()?
             + self.public_exponent.encoded_len()?
@@ -170,6 +242,18 @@ impl<'a> TryFrom<&'a [u8]> for RsaPrivateKey<'a> {
     }
 }
 
+#[cfg(feature = "pkcs8")]
+impl<'a> TryFrom<PrivateKeyInfo<'a>> for RsaPrivateKey<'a> {
+    type Error = Error;
+
+    fn try_from(private_key_info: PrivateKeyInfo<'a>) -> Result<Self> {
+        private_key_info
+            .algorithm
+            .assert_algorithm_oid(ALGORITHM_OID)?;
+        Self::try_from(private_key_info.private_key)
+    }
+}
+
 impl fmt::Debug for RsaPrivateKey<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("RsaPrivateKey")
@@ -200,7 +284,7 @@ impl TryFrom<&RsaPrivateKey<'_>> for SecretDocument {
 
 #[cfg(feature = "pem")]
 impl PemLabel for RsaPrivateKey<'_> {
-    const PEM_LABEL: &'static str = "RSA PRIVATE KEY";
+    const PEM_LABEL: &'static str = Label::RsaPrivateKey.as_str();
 }
 
 /// Placeholder struct for `OtherPrimeInfos` in the no-`alloc` case.
@@ -10,7 +10,7 @@ use der::{
 use der::Document;
 
 #[cfg(feature = "pem")]
-use der::pem::PemLabel;
+use der::pem::{Label, PemLabel};
 
 /// PKCS#1 RSA Public Keys as defined in [RFC 8017 Appendix 1.1].
 ///
@@ -86,5 +86,5 @@ impl TryFrom<&RsaPublicKey<'_>> for Document {
 
 #[cfg(feature = "pem")]
 impl PemLabel for RsaPublicKey<'_> {
-    const PEM_LABEL: &'static str = "RSA PUBLIC KEY";
+    const PEM_LABEL: &'static str = Label::RsaPublicKey.as_str();
 }
@@ -163,6 +163,7 @@ where
         Ok(Self::try_from(pkcs8::PrivateKeyInfo {
             algorithm: ALGORITHM_ID,
             private_key,
+            attributes: None,
             public_key: None,
         })?)
     }
@@ -13,6 +13,10 @@ use pkcs1::{RsaOaepParams, RsaPssParams, TrailerField};
 const RSA_PSS_PARAMETERS_DEFAULTS: &[u8] = &hex!("3000");
 /// Example PSS parameters using SHA256 instead of SHA1
 const RSA_PSS_PARAMETERS_SHA2_256: &[u8] = &hex!("3034a00f300d06096086480165030402010500a11c301a06092a864886f70d010108300d06096086480165030402010500a203020120");
+/// Example PSS parameters using SHA384 instead of SHA1
+const RSA_PSS_PARAMETERS_SHA2_384: &[u8] = &hex!("3034a00f300d06096086480165030402020500a11c301a06092a864886f70d010108300d06096086480165030402020500a203020130");
+/// Example PSS parameters using SHA512 instead of SHA1
+const RSA_PSS_PARAMETERS_SHA2_512: &[u8] = &hex!("3034a00f300d06096086480165030402030500a11c301a06092a864886f70d010108300d06096086480165030402030500a203020140");
 
 /// Default OAEP parameters using all default values (SHA1, MGF1, Empty)
 const RSA_OAEP_PARAMETERS_DEFAULTS: &[u8] = &hex!("3000");
@@ -116,6 +120,38 @@ fn new_pss_param() {
     );
 }
 
+#[test]
+fn standard_pss_params() {
+    let mut buf = [0_u8; 256];
+
+    for (param, expected) in [
+        (RsaPssParams::sha256(), RSA_PSS_PARAMETERS_SHA2_256),
+        (RsaPssParams::sha384(), RSA_PSS_PARAMETERS_SHA2_384),
+        (RsaPssParams::sha512(), RSA_PSS_PARAMETERS_SHA2_512),
+    ] {
+        assert_eq!(param.encode_to_slice(&mut buf).unwrap(), expected);
+        assert_eq!(RsaPssParams::try_from(expected).unwrap(), param);
+    }
+
+    assert_eq!(RsaPssParams::new::<Sha256Mock>(32), RsaPssParams::sha256());
+}
+
+#[test]
+fn pss_param_from_algorithm_identifier() {
+    let algorithm = spki::algorithms::rsa_pss_sha384();
+    let param = RsaPssParams::try_from(algorithm.parameters.unwrap()).unwrap();
+    assert_eq!(param, RsaPssParams::sha384());
+
+    assert!(RsaPssParams::try_from(AnyRef::NULL).is_err());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn pss_param_to_any() {
+    let any = der::Any::try_from(&RsaPssParams::sha256()).unwrap();
+    assert_eq!(any.to_der().unwrap(), RSA_PSS_PARAMETERS_SHA2_256);
+}
+
 #[test]
 fn decode_oaep_param() {
     let param = RsaOaepParams::try_from(RSA_OAEP_PARAMETERS_SHA2_256).unwrap();
@@ -221,3 +257,57 @@ fn new_oaep_param() {
         RSA_OAEP_PARAMETERS_SHA2_256
     );
 }
+
+#[test]
+fn oaep_param_from_any() {
+    let any = AnyRef::try_from(RSA_OAEP_PARAMETERS_SHA2_256).unwrap();
+    let param = RsaOaepParams::try_from(any).unwrap();
+    assert_eq!(param, RsaOaepParams::new::<Sha256Mock>());
+
+    #[cfg(feature = "alloc")]
+    assert_eq!(
+        der::Any::try_from(&param).unwrap().to_der().unwrap(),
+        RSA_OAEP_PARAMETERS_SHA2_256
+    );
+}
+
+#[test]
+fn standard_oaep_params() {
+    let mut buf = [0_u8; 256];
+
+    let param = RsaOaepParams::sha256(&[]);
+    assert_eq!(param, RsaOaepParams::new::<Sha256Mock>());
+    assert_eq!(
+        param.encode_to_slice(&mut buf).unwrap(),
+        RSA_OAEP_PARAMETERS_SHA2_256
+    );
+    assert_eq!(param.label(), Some(&[][..]));
+
+    let param = RsaOaepParams::sha512(b"label");
+    assert_eq!(param.label(), Some(&b"label"[..]));
+    assert_eq!(
+        RsaOaepParams::try_from(param.encode_to_slice(&mut buf).unwrap()).unwrap(),
+        param
+    );
+    assert!(param
+        .hash
+        .assert_algorithm_oid(db::rfc5912::ID_SHA_512)
+        .is_ok());
+    assert_eq!(RsaOaepParams::default().label(), Some(&[][..]));
+}
+
+#[test]
+fn params_oids() {
+    assert_eq!(RsaPssParams::OID, db::rfc5912::ID_RSASSA_PSS);
+    assert_eq!(RsaOaepParams::OID, db::rfc5912::ID_RSAES_OAEP);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn oaep_algorithm_identifier() {
+    let algorithm = spki::AlgorithmIdentifierOwned::try_from(&RsaOaepParams::sha256(&[])).unwrap();
+    let der = algorithm.to_der().unwrap();
+
+    assert_eq!(&der[..13], &hex!("303c06092a864886f70d010107"));
+    assert_eq!(&der[13..], RSA_OAEP_PARAMETERS_SHA2_256);
+}
@@ -9,6 +9,10 @@ use pkcs1::{RsaPrivateKey, Version};
 /// example key in the `pkcs8` crate.
 const RSA_2048_DER_EXAMPLE: &[u8] = include_bytes!("examples/rsa2048-priv.der");
 
+/// RSA-2048 PKCS#8 private key encoded as ASN.1 DER (same key as above).
+#[cfg(feature = "pkcs8")]
+const RSA_2048_PKCS8_DER_EXAMPLE: &[u8] = include_bytes!("examples/rsa2048-priv-pkcs8.der");
+
 /// RSA-4096 PKCS#1 private key encoded as ASN.1 DER
 const RSA_4096_DER_EXAMPLE: &[u8] = include_bytes!("examples/rsa4096-priv.der");
 
@@ -82,6 +86,52 @@ fn decode_rsa2048_multi_prime_der() {
     assert_eq!(other_prime_infos[0].coefficient.as_bytes(), hex!("39EA226CABFB317E41A5593B9168D1A0124993B45D9CD14A22BD1557CDCB43D28024AC26ED2C8530B53E9B93A878F428807C5282EBB811399F913017CDF2149013D80CDF73F609D6C692475EB7A123D0E93E6A60FC"));
 }
 
+#[test]
+fn rsa2048_primes() {
+    let key = RsaPrivateKey::try_from(RSA_2048_DER_EXAMPLE).unwrap();
+    assert_eq!(key.prime_count(), 2);
+    assert!(key.primes().eq([key.prime1, key.prime2]));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn rsa2048_multi_prime_primes() {
+    let key = RsaPrivateKey::try_from(RSA_2048_MULTI_PRIME_DER_EXAMPLE).unwrap();
+    let other_primes = key.other_primes();
+    assert_eq!(other_primes.len(), 1);
+    assert_eq!(key.prime_count(), 3);
+    assert!(key
+        .primes()
+        .eq([key.prime1, key.prime2, other_primes[0].prime]));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn encode_rsa2048_multi_prime_der() {
+    use pkcs1::der::Encode;
+
+    let key = RsaPrivateKey::try_from(RSA_2048_MULTI_PRIME_DER_EXAMPLE).unwrap();
+    assert_eq!(key.to_der().unwrap(), RSA_2048_MULTI_PRIME_DER_EXAMPLE);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn empty_other_prime_infos() {
+    use pkcs1::der::Encode;
+
+    // Two-prime key re-encoded with `version` set to `multi` and an empty
+    // `otherPrimeInfos` SEQUENCE appended
+    let mut der = RSA_2048_DER_EXAMPLE.to_vec();
+    der[3] += 2;
+    der[6] = 1;
+    der.extend_from_slice(&[0x30, 0x00]);
+    assert!(RsaPrivateKey::try_from(der.as_slice()).is_err());
+
+    let mut key = RsaPrivateKey::try_from(RSA_2048_DER_EXAMPLE).unwrap();
+    key.other_prime_infos = Some(Vec::new());
+    assert!(key.to_der().is_err());
+}
+
 #[test]
 fn private_key_to_public_key() {
     let private_key = RsaPrivateKey::try_from(RSA_2048_DER_EXAMPLE).unwrap();
@@ -92,3 +142,31 @@ fn private_key_to_public_key() {
     assert_eq!(public_key.modulus.as_bytes(), hex!("B6C42C515F10A6AAF282C63EDBE24243A170F3FA2633BD4833637F47CA4F6F36E03A5D29EFC3191AC80F390D874B39E30F414FCEC1FCA0ED81E547EDC2CD382C76F61C9018973DB9FA537972A7C701F6B77E0982DFC15FC01927EE5E7CD94B4F599FF07013A7C8281BDF22DCBC9AD7CABB7C4311C982F58EDB7213AD4558B332266D743AED8192D1884CADB8B14739A8DADA66DC970806D9C7AC450CB13D0D7C575FB198534FC61BC41BC0F0574E0E0130C7BBBFBDFDC9F6A6E2E3E2AFF1CBEAC89BA57884528D55CFB08327A1E8C89F4E003CF2888E933241D9D695BCBBACDC90B44E3E095FA37058EA25B13F5E295CBEAC6DE838AB8C50AF61E298975B872F"));
     assert_eq!(public_key.public_exponent.as_bytes(), hex!("010001"));
 }
+
+#[cfg(feature = "pkcs8")]
+#[test]
+fn encode_rsa2048_pkcs8_to_slice() {
+    let key = RsaPrivateKey::try_from(RSA_2048_DER_EXAMPLE).unwrap();
+
+    let mut buf = [0xFF; 4096];
+    let pkcs8_len = key.encode_pkcs8_to_slice(&mut buf).unwrap().len();
+    assert_eq!(&buf[..pkcs8_len], RSA_2048_PKCS8_DER_EXAMPLE);
+
+    // Scratch space used for the PKCS#1 encoding is zeroed
+    let scratch = &buf[buf.len() - RSA_2048_DER_EXAMPLE.len()..];
+    assert!(scratch.iter().all(|&byte| byte == 0));
+
+    // Not enough space for both the PKCS#1 and PKCS#8 encodings
+    let mut buf = [0; 2048];
+    assert!(key.encode_pkcs8_to_slice(&mut buf).is_err());
+}
+
+#[cfg(feature = "pkcs8")]
+#[test]
+fn decode_rsa2048_pkcs8() {
+    let private_key_info = pkcs8::PrivateKeyInfo::try_from(RSA_2048_PKCS8_DER_EXAMPLE).unwrap();
+    let key = RsaPrivateKey::try_from(private_key_info).unwrap();
+    let expected = RsaPrivateKey::try_from(RSA_2048_DER_EXAMPLE).unwrap();
+    assert_eq!(key.modulus, expected.modulus);
+    assert_eq!(key.private_exponent, expected.private_exponent);
+}
@@ -22,7 +22,8 @@ pub enum Error {
     /// Encryption Failed
     EncryptFailed,
 
-    /// Pbes1 support is limited to parsing; encryption/decryption is not supported (won't fix)
+    /// Pbes1 support is limited to parsing; encryption/decryption requires the
+    /// `legacy` feature
     #[cfg(feature = "pbes2")]
     NoPbes1CryptSupport,
 
@@ -45,9 +46,9 @@ impl fmt::Display for Error {
             Error::DecryptFailed => f.write_str("PKCS#5 decryption failed"),
             Error::EncryptFailed => f.write_str("PKCS#5 encryption failed"),
             #[cfg(feature = "pbes2")]
-            Error::NoPbes1CryptSupport => {
-                f.write_str("PKCS#5 encryption/decryption unsupported for PBES1 (won't fix)")
-            }
+            Error::NoPbes1CryptSupport => f.write_str(
+                "PKCS#5 encryption/decryption unsupported for PBES1 (requires `legacy` feature)",
+            ),
             Error::UnsupportedAlgorithm { oid } => {
                 write!(f, "PKCS#5 algorithm {} is unsupported", oid)
             }
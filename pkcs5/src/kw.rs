@@ -0,0 +1,191 @@
+//! AES Key Wrap as defined in [RFC 3394] and AES Key Wrap with Padding as
+//! defined in [RFC 5649].
+//!
+//! This module maps the algorithm identifiers to the `aes-kw` crate, which
+//! implements the key wrapping itself.
+//!
+//! Unlike PBES1 and PBES2, key wrapping uses a caller-supplied
+//! key-encryption key (KEK) rather than deriving one from a password.
+//!
+//! [RFC 3394]: https://datatracker.ietf.org/doc/html/rfc3394
+//! [RFC 5649]: https://datatracker.ietf.org/doc/html/rfc5649
+
+#[cfg(feature = "aes-kw")]
+mod encryption;
+
+use der::{asn1::ObjectIdentifier, ErrorKind};
+
+#[cfg(feature = "aes-kw")]
+use crate::Result;
+
+#[cfg(all(feature = "alloc", feature = "aes-kw"))]
+use alloc::{vec, vec::Vec};
+
+/// `id-aes128-wrap` Object Identifier (OID).
+pub const AES_128_WRAP_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.1.5");
+
+/// `id-aes192-wrap` Object Identifier (OID).
+pub const AES_192_WRAP_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.1.25");
+
+/// `id-aes256-wrap` Object Identifier (OID).
+pub const AES_256_WRAP_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.1.45");
+
+/// `id-aes128-wrap-pad` Object Identifier (OID).
+pub const AES_128_WRAP_PAD_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.1.8");
+
+/// `id-aes192-wrap-pad` Object Identifier (OID).
+pub const AES_192_WRAP_PAD_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.1.28");
+
+/// `id-aes256-wrap-pad` Object Identifier (OID).
+pub const AES_256_WRAP_PAD_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.1.48");
+
+/// Length of the integrity check value prepended to wrapped data.
+pub const ICV_LENGTH: usize = 8;
+
+/// AES key wrap algorithms.
+///
+/// The `AlgorithmIdentifier` parameters are absent for all of these
+/// algorithms ([RFC 3565 Section 2.3.2], [RFC 5649 Section 6]).
+///
+/// Wrapping and unwrapping are available with the `aes-kw` feature.
+///
+/// The unpadded variants ([RFC 3394]) can only wrap data whose length is a
+/// multiple of 8 bytes and at least 16 bytes, which generally isn't the case
+/// for PKCS#8 documents. The padded variants ([RFC 5649]) accept data of any
+/// length.
+///
+/// [RFC 3394]: https://datatracker.ietf.org/doc/html/rfc3394
+/// [RFC 3565 Section 2.3.2]: https://datatracker.ietf.org/doc/html/rfc3565#section-2.3.2
+/// [RFC 5649]: https://datatracker.ietf.org/doc/html/rfc5649
+/// [RFC 5649 Section 6]: https://datatracker.ietf.org/doc/html/rfc5649#section-6
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Algorithm {
+    /// `id-aes128-wrap`
+    Aes128Wrap,
+
+    /// `id-aes192-wrap`
+    Aes192Wrap,
+
+    /// `id-aes256-wrap`
+    Aes256Wrap,
+
+    /// `id-aes128-wrap-pad`
+    Aes128WrapPad,
+
+    /// `id-aes192-wrap-pad`
+    Aes192WrapPad,
+
+    /// `id-aes256-wrap-pad`
+    Aes256WrapPad,
+}
+
+impl Algorithm {
+    /// Get the [`ObjectIdentifier`] (a.k.a OID) for this algorithm.
+    pub fn oid(self) -> ObjectIdentifier {
+        match self {
+            Self::Aes128Wrap => AES_128_WRAP_OID,
+            Self::Aes192Wrap => AES_192_WRAP_OID,
+            Self::Aes256Wrap => AES_256_WRAP_OID,
+            Self::Aes128WrapPad => AES_128_WRAP_PAD_OID,
+            Self::Aes192WrapPad => AES_192_WRAP_PAD_OID,
+            Self::Aes256WrapPad => AES_256_WRAP_PAD_OID,
+        }
+    }
+
+    /// Get the size of the key-encryption key in bytes.
+    pub fn key_size(self) -> usize {
+        match self {
+            Self::Aes128Wrap | Self::Aes128WrapPad => 16,
+            Self::Aes192Wrap | Self::Aes192WrapPad => 24,
+            Self::Aes256Wrap | Self::Aes256WrapPad => 32,
+        }
+    }
+
+    /// Is this AES Key Wrap with Padding ([RFC 5649])?
+    ///
+    /// [RFC 5649]: https://datatracker.ietf.org/doc/html/rfc5649
+    pub fn is_padded(self) -> bool {
+        matches!(
+            self,
+            Self::Aes128WrapPad | Self::Aes192WrapPad | Self::Aes256WrapPad
+        )
+    }
+
+    /// Attempt to unwrap the given ciphertext using the provided
+    /// key-encryption key, allocating and returning a byte vector containing
+    /// the plaintext.
+    #[cfg(all(feature = "alloc", feature = "aes-kw"))]
+    pub fn unwrap(self, kek: &[u8], ciphertext: &[u8]) -> Result<Vec<u8>> {
+        let mut buffer = vec![0u8; ciphertext.len().saturating_sub(ICV_LENGTH)];
+        let pt_len = self.unwrap_to_slice(kek, ciphertext, &mut buffer)?.len();
+        buffer.truncate(pt_len);
+        Ok(buffer)
+    }
+
+    /// Attempt to unwrap the given ciphertext using the provided
+    /// key-encryption key, writing the plaintext into `out`.
+    ///
+    /// `out` must have room for at least the ciphertext length minus
+    /// [`ICV_LENGTH`] bytes.
+    ///
+    /// Returns [`Error::DecryptFailed`][`crate::Error::DecryptFailed`] if
+    /// the ciphertext is malformed or its integrity check fails, in which
+    /// case `out` is zeroed.
+    #[cfg(feature = "aes-kw")]
+    pub fn unwrap_to_slice<'o>(
+        self,
+        kek: &[u8],
+        ciphertext: &[u8],
+        out: &'o mut [u8],
+    ) -> Result<&'o [u8]> {
+        encryption::unwrap(self, kek, ciphertext, out)
+    }
+
+    /// Wrap the given plaintext using the provided key-encryption key,
+    /// allocating and returning a vector containing the ciphertext.
+    #[cfg(all(feature = "alloc", feature = "aes-kw"))]
+    pub fn wrap(self, kek: &[u8], plaintext: &[u8]) -> Result<Vec<u8>> {
+        let mut buffer = vec![0u8; encryption::wrapped_len(self, plaintext.len())];
+        let ct_len = self.wrap_to_slice(kek, plaintext, &mut buffer)?.len();
+        buffer.truncate(ct_len);
+        Ok(buffer)
+    }
+
+    /// Wrap the given plaintext using the provided key-encryption key,
+    /// writing the ciphertext into `out`.
+    ///
+    /// `out` must have room for the ciphertext, which is the plaintext
+    /// length (rounded up to a multiple of 8 bytes for the padded variants)
+    /// plus [`ICV_LENGTH`].
+    #[cfg(feature = "aes-kw")]
+    pub fn wrap_to_slice<'o>(
+        self,
+        kek: &[u8],
+        plaintext: &[u8],
+        out: &'o mut [u8],
+    ) -> Result<&'o [u8]> {
+        encryption::wrap(self, kek, plaintext, out)
+    }
+}
+
+impl TryFrom<ObjectIdentifier> for Algorithm {
+    type Error = der::Error;
+
+    fn try_from(oid: ObjectIdentifier) -> der::Result<Self> {
+        match oid {
+            AES_128_WRAP_OID => Ok(Self::Aes128Wrap),
+            AES_192_WRAP_OID => Ok(Self::Aes192Wrap),
+            AES_256_WRAP_OID => Ok(Self::Aes256Wrap),
+            AES_128_WRAP_PAD_OID => Ok(Self::Aes128WrapPad),
+            AES_192_WRAP_PAD_OID => Ok(Self::Aes192WrapPad),
+            AES_256_WRAP_PAD_OID => Ok(Self::Aes256WrapPad),
+            _ => Err(ErrorKind::OidUnknown { oid }.into()),
+        }
+    }
+}
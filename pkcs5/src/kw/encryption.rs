@@ -0,0 +1,126 @@
+//! AES key wrapping and unwrapping.
+
+use super::{Algorithm, ICV_LENGTH};
+use crate::{Error, Result};
+use aes::cipher::{consts::U16, BlockCipher, BlockDecrypt, BlockEncrypt, BlockSizeUser, KeyInit};
+use aes_kw::{Kek, SEMIBLOCK_SIZE};
+
+/// Get the length of the ciphertext resulting from wrapping `len` bytes.
+pub(super) fn wrapped_len(alg: Algorithm, len: usize) -> usize {
+    let len = if alg.is_padded() {
+        len.saturating_add(SEMIBLOCK_SIZE - 1) / SEMIBLOCK_SIZE * SEMIBLOCK_SIZE
+    } else {
+        len
+    };
+
+    len.saturating_add(ICV_LENGTH)
+}
+
+pub(super) fn wrap<'o>(
+    alg: Algorithm,
+    kek: &[u8],
+    plaintext: &[u8],
+    out: &'o mut [u8],
+) -> Result<&'o [u8]> {
+    match alg.key_size() {
+        16 => wrap_with::<aes::Aes128>(alg, kek, plaintext, out),
+        24 => wrap_with::<aes::Aes192>(alg, kek, plaintext, out),
+        _ => wrap_with::<aes::Aes256>(alg, kek, plaintext, out),
+    }
+}
+
+pub(super) fn unwrap<'o>(
+    alg: Algorithm,
+    kek: &[u8],
+    ciphertext: &[u8],
+    out: &'o mut [u8],
+) -> Result<&'o [u8]> {
+    match alg.key_size() {
+        16 => unwrap_with::<aes::Aes128>(alg, kek, ciphertext, out),
+        24 => unwrap_with::<aes::Aes192>(alg, kek, ciphertext, out),
+        _ => unwrap_with::<aes::Aes256>(alg, kek, ciphertext, out),
+    }
+}
+
+fn wrap_with<'o, C>(
+    alg: Algorithm,
+    kek: &[u8],
+    plaintext: &[u8],
+    out: &'o mut [u8],
+) -> Result<&'o [u8]>
+where
+    C: KeyInit + BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt + BlockDecrypt,
+{
+    let kek = init_kek::<C>(alg, kek)?;
+
+    // RFC 3394 requires at least two semiblocks of plaintext, RFC 5649 at least one octet
+    let min_len = if alg.is_padded() {
+        1
+    } else {
+        2 * SEMIBLOCK_SIZE
+    };
+    if plaintext.len() < min_len {
+        return Err(Error::EncryptFailed);
+    }
+
+    let out = out
+        .get_mut(..wrapped_len(alg, plaintext.len()))
+        .ok_or(Error::EncryptFailed)?;
+
+    if alg.is_padded() {
+        kek.wrap_with_padding(plaintext, out)
+    } else {
+        kek.wrap(plaintext, out)
+    }
+    .map_err(|_| Error::EncryptFailed)?;
+
+    Ok(out)
+}
+
+fn unwrap_with<'o, C>(
+    alg: Algorithm,
+    kek: &[u8],
+    ciphertext: &[u8],
+    out: &'o mut [u8],
+) -> Result<&'o [u8]>
+where
+    C: KeyInit + BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt + BlockDecrypt,
+{
+    let kek = init_kek::<C>(alg, kek)?;
+
+    let min_len = if alg.is_padded() {
+        2 * SEMIBLOCK_SIZE
+    } else {
+        3 * SEMIBLOCK_SIZE
+    };
+    if ciphertext.len() < min_len {
+        return Err(Error::DecryptFailed);
+    }
+
+    let out = out
+        .get_mut(..ciphertext.len() - ICV_LENGTH)
+        .ok_or(Error::DecryptFailed)?;
+
+    let pt_len = if alg.is_padded() {
+        kek.unwrap_with_padding(ciphertext, out)
+            .map(|plaintext| plaintext.len())
+    } else {
+        kek.unwrap(ciphertext, out).map(|()| out.len())
+    };
+
+    match pt_len {
+        Ok(pt_len) => Ok(&out[..pt_len]),
+        Err(_) => {
+            // Don't leave unauthenticated plaintext behind
+            out.fill(0);
+            Err(Error::DecryptFailed)
+        }
+    }
+}
+
+fn init_kek<C>(alg: Algorithm, kek: &[u8]) -> Result<Kek<C>>
+where
+    C: KeyInit + BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt + BlockDecrypt,
+{
+    Kek::try_from(kek).map_err(|_| Error::AlgorithmParametersInvalid { oid: alg.oid() })
+}
@@ -23,26 +23,33 @@
 //!
 //! [RFC 8018]: https://tools.ietf.org/html/rfc8018
 
-#[cfg(all(feature = "alloc", feature = "pbes2"))]
+#[cfg(all(
+    feature = "alloc",
+    any(feature = "pbes2", feature = "legacy", feature = "aes-kw")
+))]
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 mod error;
 
+pub mod kw;
 pub mod pbes1;
 pub mod pbes2;
+pub mod pkcs12_pbe;
 
 pub use crate::error::{Error, Result};
 pub use der::{self, asn1::ObjectIdentifier};
 pub use spki::AlgorithmIdentifierRef;
 
 use der::{
-    Decode, DecodeValue, Encode, EncodeValue, Header, Length, Reader, Sequence, Tag, Writer,
+    Decode, DecodeValue, Encode, EncodeValue, Header, Length, Reader, Sequence, Tag, Tagged, Writer,
 };
 
 #[cfg(feature = "pbes2")]
 pub use scrypt;
 
-#[cfg(all(feature = "alloc", feature = "pbes2"))]
+#[cfg(all(feature = "alloc", any(feature = "pbes2", feature = "legacy")))]
 use alloc::vec::Vec;
 
 /// Supported PKCS#5 password-based encryption schemes.
@@ -59,16 +66,45 @@ pub enum EncryptionScheme<'a> {
     ///
     /// [RFC 8018 Section 6.2]: https://tools.ietf.org/html/rfc8018#section-6.2
     Pbes2(pbes2::Parameters<'a>),
+
+    /// AES Key Wrap as defined in [RFC 3394] and [RFC 5649].
+    ///
+    /// This scheme uses a caller-supplied key-encryption key rather than a
+    /// password: see [`kw::Algorithm::wrap`] and [`kw::Algorithm::unwrap`].
+    /// The password-based methods of [`EncryptionScheme`] return
+    /// [`Error::UnsupportedAlgorithm`] for it.
+    ///
+    /// [RFC 3394]: https://datatracker.ietf.org/doc/html/rfc3394
+    /// [RFC 5649]: https://datatracker.ietf.org/doc/html/rfc5649
+    KeyWrap(kw::Algorithm),
+
+    /// PKCS#12 password-based encryption as defined in [RFC 7292 Appendix C].
+    ///
+    /// [RFC 7292 Appendix C]: https://datatracker.ietf.org/doc/html/rfc7292#appendix-C
+    Pkcs12Pbe(pkcs12_pbe::Algorithm),
 }
 
 impl<'a> EncryptionScheme<'a> {
     /// Attempt to decrypt the given ciphertext, allocating and returning a
     /// byte vector containing the plaintext.
-    #[cfg(all(feature = "alloc", feature = "pbes2"))]
+    #[cfg(all(feature = "alloc", any(feature = "pbes2", feature = "legacy")))]
     pub fn decrypt(&self, password: impl AsRef<[u8]>, ciphertext: &[u8]) -> Result<Vec<u8>> {
         match self {
+            #[cfg(feature = "pbes2")]
             Self::Pbes2(params) => params.decrypt(password, ciphertext),
+            #[cfg(not(feature = "pbes2"))]
+            Self::Pbes2(_) => Err(Error::UnsupportedAlgorithm {
+                oid: pbes2::PBES2_OID,
+            }),
+            #[cfg(feature = "legacy")]
+            Self::Pbes1(alg) => alg.decrypt(password, ciphertext),
+            #[cfg(not(feature = "legacy"))]
             Self::Pbes1(_) => Err(Error::NoPbes1CryptSupport),
+            #[cfg(feature = "legacy")]
+            Self::Pkcs12Pbe(alg) => alg.decrypt(password, ciphertext),
+            #[cfg(not(feature = "legacy"))]
+            Self::Pkcs12Pbe(alg) => Err(Error::UnsupportedAlgorithm { oid: alg.oid() }),
+            Self::KeyWrap(alg) => Err(Error::UnsupportedAlgorithm { oid: alg.oid() }),
         }
     }
 
@@ -78,31 +114,57 @@ impl<'a> EncryptionScheme<'a> {
     /// Returns an error if the algorithm specified in this scheme's parameters
     /// is unsupported, or if the ciphertext is malformed (e.g. not a multiple
     /// of a block mode's padding)
-    #[cfg(feature = "pbes2")]
+    #[cfg(any(feature = "pbes2", feature = "legacy"))]
     pub fn decrypt_in_place<'b>(
         &self,
         password: impl AsRef<[u8]>,
         buffer: &'b mut [u8],
     ) -> Result<&'b [u8]> {
         match self {
+            #[cfg(feature = "pbes2")]
             Self::Pbes2(params) => params.decrypt_in_place(password, buffer),
+            #[cfg(not(feature = "pbes2"))]
+            Self::Pbes2(_) => Err(Error::UnsupportedAlgorithm {
+                oid: pbes2::PBES2_OID,
+            }),
+            #[cfg(feature = "legacy")]
+            Self::Pbes1(alg) => alg.decrypt_in_place(password, buffer),
+            #[cfg(not(feature = "legacy"))]
             Self::Pbes1(_) => Err(Error::NoPbes1CryptSupport),
+            #[cfg(feature = "legacy")]
+            Self::Pkcs12Pbe(alg) => alg.decrypt_in_place(password, buffer),
+            #[cfg(not(feature = "legacy"))]
+            Self::Pkcs12Pbe(alg) => Err(Error::UnsupportedAlgorithm { oid: alg.oid() }),
+            Self::KeyWrap(alg) => Err(Error::UnsupportedAlgorithm { oid: alg.oid() }),
         }
     }
 
     /// Encrypt the given plaintext, allocating and returning a vector
     /// containing the ciphertext.
-    #[cfg(all(feature = "alloc", feature = "pbes2"))]
+    #[cfg(all(feature = "alloc", any(feature = "pbes2", feature = "legacy")))]
     pub fn encrypt(&self, password: impl AsRef<[u8]>, plaintext: &[u8]) -> Result<Vec<u8>> {
         match self {
+            #[cfg(feature = "pbes2")]
             Self::Pbes2(params) => params.encrypt(password, plaintext),
+            #[cfg(not(feature = "pbes2"))]
+            Self::Pbes2(_) => Err(Error::UnsupportedAlgorithm {
+                oid: pbes2::PBES2_OID,
+            }),
+            #[cfg(feature = "legacy")]
+            Self::Pbes1(alg) => alg.encrypt(password, plaintext),
+            #[cfg(not(feature = "legacy"))]
             Self::Pbes1(_) => Err(Error::NoPbes1CryptSupport),
+            #[cfg(feature = "legacy")]
+            Self::Pkcs12Pbe(alg) => alg.encrypt(password, plaintext),
+            #[cfg(not(feature = "legacy"))]
+            Self::Pkcs12Pbe(alg) => Err(Error::UnsupportedAlgorithm { oid: alg.oid() }),
+            Self::KeyWrap(alg) => Err(Error::UnsupportedAlgorithm { oid: alg.oid() }),
         }
     }
 
     /// Encrypt the given ciphertext in-place using a key derived from the
     /// provided password and this scheme's parameters.
-    #[cfg(feature = "pbes2")]
+    #[cfg(any(feature = "pbes2", feature = "legacy"))]
     pub fn encrypt_in_place<'b>(
         &self,
         password: impl AsRef<[u8]>,
@@ -110,8 +172,21 @@ impl<'a> EncryptionScheme<'a> {
         pos: usize,
     ) -> Result<&'b [u8]> {
         match self {
+            #[cfg(feature = "pbes2")]
             Self::Pbes2(params) => params.encrypt_in_place(password, buffer, pos),
+            #[cfg(not(feature = "pbes2"))]
+            Self::Pbes2(_) => Err(Error::UnsupportedAlgorithm {
+                oid: pbes2::PBES2_OID,
+            }),
+            #[cfg(feature = "legacy")]
+            Self::Pbes1(alg) => alg.encrypt_in_place(password, buffer, pos),
+            #[cfg(not(feature = "legacy"))]
             Self::Pbes1(_) => Err(Error::NoPbes1CryptSupport),
+            #[cfg(feature = "legacy")]
+            Self::Pkcs12Pbe(alg) => alg.encrypt_in_place(password, buffer, pos),
+            #[cfg(not(feature = "legacy"))]
+            Self::Pkcs12Pbe(alg) => Err(Error::UnsupportedAlgorithm { oid: alg.oid() }),
+            Self::KeyWrap(alg) => Err(Error::UnsupportedAlgorithm { oid: alg.oid() }),
         }
     }
 
@@ -120,6 +195,8 @@ impl<'a> EncryptionScheme<'a> {
         match self {
             Self::Pbes1(params) => params.oid(),
             Self::Pbes2(_) => pbes2::PBES2_OID,
+            Self::KeyWrap(alg) => alg.oid(),
+            Self::Pkcs12Pbe(alg) => alg.oid(),
         }
     }
 
@@ -138,6 +215,22 @@ impl<'a> EncryptionScheme<'a> {
             _ => None,
         }
     }
+
+    /// Get the [`pkcs12_pbe::Algorithm`] if it is the selected algorithm.
+    pub fn pkcs12_pbe(&self) -> Option<&pkcs12_pbe::Algorithm> {
+        match self {
+            Self::Pkcs12Pbe(alg) => Some(alg),
+            _ => None,
+        }
+    }
+
+    /// Get the [`kw::Algorithm`] if it is the selected algorithm.
+    pub fn key_wrap(&self) -> Option<kw::Algorithm> {
+        match self {
+            Self::KeyWrap(alg) => Some(*alg),
+            _ => None,
+        }
+    }
 }
 
 impl<'a> DecodeValue<'a> for EncryptionScheme<'a> {
@@ -151,6 +244,8 @@ impl EncodeValue for EncryptionScheme<'_> {
         match self {
             Self::Pbes1(pbes1) => pbes1.oid().encoded_len()? + pbes1.parameters.encoded_len()?,
             Self::Pbes2(pbes2) => pbes2::PBES2_OID.encoded_len()? + pbes2.encoded_len()?,
+            Self::KeyWrap(alg) => alg.oid().encoded_len(),
+            Self::Pkcs12Pbe(alg) => alg.oid().encoded_len()? + alg.parameters.encoded_len()?,
         }
     }
 
@@ -164,6 +259,11 @@ impl EncodeValue for EncryptionScheme<'_> {
                 pbes2::PBES2_OID.encode(writer)?;
                 pbes2.encode(writer)?;
             }
+            Self::KeyWrap(alg) => alg.oid().encode(writer)?,
+            Self::Pkcs12Pbe(alg) => {
+                alg.oid().encode(writer)?;
+                alg.parameters.encode(writer)?;
+            }
         }
 
         Ok(())
@@ -184,6 +284,18 @@ impl<'a> From<pbes2::Parameters<'a>> for EncryptionScheme<'a> {
     }
 }
 
+impl<'a> From<kw::Algorithm> for EncryptionScheme<'a> {
+    fn from(alg: kw::Algorithm) -> EncryptionScheme<'a> {
+        Self::KeyWrap(alg)
+    }
+}
+
+impl<'a> From<pkcs12_pbe::Algorithm> for EncryptionScheme<'a> {
+    fn from(alg: pkcs12_pbe::Algorithm) -> EncryptionScheme<'a> {
+        Self::Pkcs12Pbe(alg)
+    }
+}
+
 impl<'a> TryFrom<AlgorithmIdentifierRef<'a>> for EncryptionScheme<'a> {
     type Error = der::Error;
 
@@ -193,6 +305,14 @@ impl<'a> TryFrom<AlgorithmIdentifierRef<'a>> for EncryptionScheme<'a> {
                 Some(params) => pbes2::Parameters::try_from(params).map(Into::into),
                 None => Err(Tag::OctetString.value_error()),
             }
+        } else if let Ok(key_wrap) = kw::Algorithm::try_from(alg.oid) {
+            // Parameters are absent for AES key wrap
+            match alg.parameters {
+                Some(params) => Err(params.tag().value_error()),
+                None => Ok(key_wrap.into()),
+            }
+        } else if pkcs12_pbe::EncryptionScheme::try_from(alg.oid).is_ok() {
+            pkcs12_pbe::Algorithm::try_from(alg).map(Into::into)
         } else {
             pbes1::Algorithm::try_from(alg).map(Into::into)
         }
@@ -2,12 +2,21 @@
 //!
 //! [RFC 8018 Section 6.1]: https://tools.ietf.org/html/rfc8018#section-6.1
 
+#[cfg(feature = "legacy")]
+mod encryption;
+
 use crate::AlgorithmIdentifierRef;
 use der::{
     asn1::{AnyRef, ObjectIdentifier, OctetStringRef},
     Decode, DecodeValue, Encode, EncodeValue, ErrorKind, Length, Reader, Sequence, Tag, Writer,
 };
 
+#[cfg(feature = "legacy")]
+use crate::Result;
+
+#[cfg(all(feature = "alloc", feature = "legacy"))]
+use alloc::vec::Vec;
+
 /// `pbeWithMD2AndDES-CBC` Object Identifier (OID).
 pub const PBE_WITH_MD2_AND_DES_CBC_OID: ObjectIdentifier =
     ObjectIdentifier::new_unwrap("1.2.840.113549.1.5.1");
@@ -35,6 +44,10 @@ pub const PBE_WITH_SHA1_AND_RC2_CBC_OID: ObjectIdentifier =
 /// Length of a PBES1 salt (as defined in the `PBEParameter` ASN.1 message).
 pub const SALT_LENGTH: usize = 8;
 
+/// DES / RC2 block size
+#[cfg(all(feature = "alloc", feature = "legacy"))]
+const DES_BLOCK_SIZE: usize = 8;
+
 /// Password-Based Encryption Scheme 1 algorithms as defined in [RFC 8018 Appendix A.C].
 ///
 /// ```text
@@ -49,6 +62,17 @@ pub const SALT_LENGTH: usize = 8;
 /// }
 /// ```
 ///
+/// Encryption and decryption are available with the `legacy` feature, for
+/// `pbeWithMD5AndDES-CBC` and `pbeWithSHA1AndDES-CBC`. The RC2 based schemes
+/// `pbeWithMD5AndRC2-CBC` and `pbeWithSHA1AndRC2-CBC` can additionally be
+/// decrypted, but not encrypted. The MD2 based schemes are limited to parsing.
+///
+/// See [`pkcs12_pbe`][`crate::pkcs12_pbe`] for the PKCS#12 scheme
+/// `pbeWithSHAAnd3-KeyTripleDES-CBC`, which uses the same parameters.
+///
+/// ⚠️ WARNING: DES, RC2 and PBKDF1 are all broken and PBES1 is only supported
+/// for reading existing data (e.g. in order to re-encrypt it with PBES2).
+///
 /// [RFC 8018 Appendix A.C]: https://datatracker.ietf.org/doc/html/rfc8018#appendix-C
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Algorithm {
@@ -64,6 +88,59 @@ impl Algorithm {
     pub fn oid(&self) -> ObjectIdentifier {
         self.encryption.oid()
     }
+
+    /// Attempt to decrypt the given ciphertext, allocating and returning a
+    /// byte vector containing the plaintext.
+    #[cfg(all(feature = "alloc", feature = "legacy"))]
+    pub fn decrypt(&self, password: impl AsRef<[u8]>, ciphertext: &[u8]) -> Result<Vec<u8>> {
+        let mut buffer = ciphertext.to_vec();
+        let pt_len = self.decrypt_in_place(password, &mut buffer)?.len();
+        buffer.truncate(pt_len);
+        Ok(buffer)
+    }
+
+    /// Attempt to decrypt the given ciphertext in-place using a key derived
+    /// from the provided password and this algorithm's parameters.
+    ///
+    /// Returns an error if the algorithm is unsupported, or if the ciphertext
+    /// is malformed (e.g. not a multiple of a block mode's padding)
+    #[cfg(feature = "legacy")]
+    pub fn decrypt_in_place<'b>(
+        &self,
+        password: impl AsRef<[u8]>,
+        buffer: &'b mut [u8],
+    ) -> Result<&'b [u8]> {
+        encryption::decrypt_in_place(self, password.as_ref(), buffer)
+    }
+
+    /// Encrypt the given plaintext, allocating and returning a vector
+    /// containing the ciphertext.
+    #[cfg(all(feature = "alloc", feature = "legacy"))]
+    pub fn encrypt(&self, password: impl AsRef<[u8]>, plaintext: &[u8]) -> Result<Vec<u8>> {
+        let mut buffer = Vec::with_capacity(plaintext.len() + DES_BLOCK_SIZE);
+        buffer.extend_from_slice(plaintext);
+        buffer.extend_from_slice(&[0u8; DES_BLOCK_SIZE]);
+
+        let ct_len = self
+            .encrypt_in_place(password, &mut buffer, plaintext.len())?
+            .len();
+
+        buffer.truncate(ct_len);
+        Ok(buffer)
+    }
+
+    /// Encrypt the given plaintext in-place using a key derived from the
+    /// provided password and this algorithm's parameters, writing the
+    /// ciphertext into the same buffer.
+    #[cfg(feature = "legacy")]
+    pub fn encrypt_in_place<'b>(
+        &self,
+        password: impl AsRef<[u8]>,
+        buffer: &'b mut [u8],
+        pos: usize,
+    ) -> Result<&'b [u8]> {
+        encryption::encrypt_in_place(self, password.as_ref(), buffer, pos)
+    }
 }
 
 impl<'a> DecodeValue<'a> for Algorithm {
@@ -114,7 +191,14 @@ impl<'a> TryFrom<AlgorithmIdentifierRef<'a>> for Algorithm {
 ///    iterationCount INTEGER }
 /// ```
 ///
+/// The PKCS#12 schemes in [`pkcs12_pbe`][`crate::pkcs12_pbe`] use these for
+/// `pkcs-12PbeParams` ([RFC 7292 Appendix C]), which has the same structure
+/// but permits other salt lengths and larger iteration counts. Only the
+/// 8-byte salts and iteration counts up to 65535 used in practice are
+/// supported.
+///
 /// [RFC 8018 Appendix A.3]: https://tools.ietf.org/html/rfc8018#appendix-A.3
+/// [RFC 7292 Appendix C]: https://datatracker.ietf.org/doc/html/rfc7292#appendix-C
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Parameters {
     /// Salt value
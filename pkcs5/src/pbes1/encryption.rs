@@ -0,0 +1,99 @@
+//! PBES1 encryption.
+
+use super::{Algorithm, DigestAlgorithm, Parameters, SymmetricCipher};
+use crate::{Error, Result};
+use cbc::cipher::{block_padding::Pkcs7, BlockDecryptMut, BlockEncryptMut, InnerIvInit, KeyIvInit};
+use md5::Md5;
+use rc2::Rc2;
+use sha1::{digest::Output, Digest, Sha1};
+
+/// DES / RC2 key and IV size
+const DES_BLOCK_SIZE: usize = 8;
+
+/// RC2 effective key length in bits ([RFC 8018 Section 6.1.1])
+///
+/// [RFC 8018 Section 6.1.1]: https://datatracker.ietf.org/doc/html/rfc8018#section-6.1.1
+const RC2_EFFECTIVE_KEY_BITS: usize = 64;
+
+pub fn encrypt_in_place<'b>(
+    alg: &Algorithm,
+    password: &[u8],
+    buf: &'b mut [u8],
+    pos: usize,
+) -> Result<&'b [u8]> {
+    match alg.encryption.cipher() {
+        SymmetricCipher::DesCbc => {
+            let (key, iv) = derive_key_and_iv(alg, password)?;
+            cbc::Encryptor::<des::Des>::new(&key.into(), &iv.into())
+                .encrypt_padded_mut::<Pkcs7>(buf, pos)
+        }
+        // RC2 is only supported for decryption
+        SymmetricCipher::Rc2Cbc => return Err(Error::UnsupportedAlgorithm { oid: alg.oid() }),
+    }
+    .map_err(|_| Error::EncryptFailed)
+}
+
+pub fn decrypt_in_place<'b>(
+    alg: &Algorithm,
+    password: &[u8],
+    buf: &'b mut [u8],
+) -> Result<&'b [u8]> {
+    match alg.encryption.cipher() {
+        SymmetricCipher::DesCbc => {
+            let (key, iv) = derive_key_and_iv(alg, password)?;
+            cbc::Decryptor::<des::Des>::new(&key.into(), &iv.into())
+                .decrypt_padded_mut::<Pkcs7>(buf)
+        }
+        SymmetricCipher::Rc2Cbc => {
+            let (key, iv) = derive_key_and_iv(alg, password)?;
+            let cipher = Rc2::new_with_eff_key_len(&key, RC2_EFFECTIVE_KEY_BITS);
+            cbc::Decryptor::inner_iv_init(cipher, &iv.into()).decrypt_padded_mut::<Pkcs7>(buf)
+        }
+    }
+    .map_err(|_| Error::DecryptFailed)
+}
+
+/// Derive the DES or RC2 key and IV from the password, i.e. the first and second
+/// half of the 16-byte PBKDF1 output ([RFC 8018 Section 6.1.1]).
+///
+/// [RFC 8018 Section 6.1.1]: https://datatracker.ietf.org/doc/html/rfc8018#section-6.1.1
+fn derive_key_and_iv(
+    alg: &Algorithm,
+    password: &[u8],
+) -> Result<([u8; DES_BLOCK_SIZE], [u8; DES_BLOCK_SIZE])> {
+    if alg.parameters.iteration_count == 0 {
+        return Err(Error::AlgorithmParametersInvalid { oid: alg.oid() });
+    }
+
+    let mut derived_key = [0u8; 2 * DES_BLOCK_SIZE];
+    match alg.encryption.digest() {
+        DigestAlgorithm::Md5 => derived_key
+            .copy_from_slice(&pbkdf1::<Md5>(password, &alg.parameters)[..2 * DES_BLOCK_SIZE]),
+        DigestAlgorithm::Sha1 => derived_key
+            .copy_from_slice(&pbkdf1::<Sha1>(password, &alg.parameters)[..2 * DES_BLOCK_SIZE]),
+        // MD2 is unsupported
+        DigestAlgorithm::Md2 => return Err(Error::UnsupportedAlgorithm { oid: alg.oid() }),
+    }
+
+    let mut key = [0u8; DES_BLOCK_SIZE];
+    let mut iv = [0u8; DES_BLOCK_SIZE];
+    key.copy_from_slice(&derived_key[..DES_BLOCK_SIZE]);
+    iv.copy_from_slice(&derived_key[DES_BLOCK_SIZE..]);
+    Ok((key, iv))
+}
+
+/// Password-Based Key Derivation Function 1 ([RFC 8018 Section 5.1]).
+///
+/// [RFC 8018 Section 5.1]: https://datatracker.ietf.org/doc/html/rfc8018#section-5.1
+fn pbkdf1<D: Digest>(password: &[u8], params: &Parameters) -> Output<D> {
+    let mut t = D::new()
+        .chain_update(password)
+        .chain_update(params.salt)
+        .finalize();
+
+    for _ in 1..params.iteration_count {
+        t = D::digest(&t);
+    }
+
+    t
+}
@@ -4,6 +4,8 @@
 
 mod kdf;
 
+#[cfg(feature = "pbes2")]
+mod decryptor;
 #[cfg(feature = "pbes2")]
 mod encryption;
 
@@ -12,15 +14,22 @@ pub use self::kdf::{
     PBKDF2_OID, SCRYPT_OID,
 };
 
+#[cfg(feature = "pbes2")]
+pub use self::decryptor::Decryptor;
+
 use crate::{AlgorithmIdentifierRef, Error, Result};
 use der::{
     asn1::{AnyRef, ObjectIdentifier, OctetStringRef},
     Decode, DecodeValue, Encode, EncodeValue, ErrorKind, Length, Reader, Sequence, Tag, Writer,
 };
+use spki::AlgorithmIdentifier;
 
 #[cfg(all(feature = "alloc", feature = "pbes2"))]
 use alloc::vec::Vec;
 
+#[cfg(all(feature = "std", feature = "pbes2"))]
+use std::time::{Duration, Instant};
+
 /// 128-bit Advanced Encryption Standard (AES) algorithm with Cipher-Block
 /// Chaining (CBC) mode of operation.
 pub const AES_128_CBC_OID: ObjectIdentifier =
@@ -36,6 +45,27 @@ pub const AES_192_CBC_OID: ObjectIdentifier =
 pub const AES_256_CBC_OID: ObjectIdentifier =
     ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.1.42");
 
+/// 128-bit Advanced Encryption Standard (AES) algorithm with Galois/Counter
+/// Mode (GCM) of operation ([RFC 5084]).
+///
+/// [RFC 5084]: https://datatracker.ietf.org/doc/html/rfc5084#section-3.2
+pub const AES_128_GCM_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.1.6");
+
+/// 192-bit Advanced Encryption Standard (AES) algorithm with Galois/Counter
+/// Mode (GCM) of operation ([RFC 5084]).
+///
+/// [RFC 5084]: https://datatracker.ietf.org/doc/html/rfc5084#section-3.2
+pub const AES_192_GCM_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.1.26");
+
+/// 256-bit Advanced Encryption Standard (AES) algorithm with Galois/Counter
+/// Mode (GCM) of operation ([RFC 5084]).
+///
+/// [RFC 5084]: https://datatracker.ietf.org/doc/html/rfc5084#section-3.2
+pub const AES_256_GCM_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.1.46");
+
 /// DES operating in CBC mode
 #[cfg(feature = "des-insecure")]
 pub const DES_CBC_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.14.3.2.7");
@@ -44,6 +74,10 @@ pub const DES_CBC_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.14.3
 #[cfg(feature = "3des")]
 pub const DES_EDE3_CBC_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.3.7");
 
+/// RC2 operating in CBC mode
+#[cfg(feature = "rc2-insecure")]
+pub const RC2_CBC_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.3.2");
+
 /// Password-Based Encryption Scheme 2 (PBES2) OID.
 ///
 /// <https://tools.ietf.org/html/rfc8018#section-6.2>
@@ -52,10 +86,38 @@ pub const PBES2_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.11
 /// AES cipher block size
 const AES_BLOCK_SIZE: usize = 16;
 
+/// AES-GCM nonce size
+const GCM_NONCE_SIZE: usize = 12;
+
+/// Default AES-GCM authentication tag (ICV) size ([RFC 5084 Section 3.2]).
+///
+/// [RFC 5084 Section 3.2]: https://datatracker.ietf.org/doc/html/rfc5084#section-3.2
+const GCM_DEFAULT_ICV_LEN: u8 = 12;
+
+/// Maximum AES-GCM authentication tag (ICV) size, used by the
+/// [`Parameters`] constructors.
+const GCM_MAX_ICV_LEN: u8 = 16;
+
+/// scrypt `log2(N)` for [`Parameters::interactive`] (16 MiB of memory with `r = 8`)
+const SCRYPT_INTERACTIVE_LOG_N: u8 = 14;
+
+/// scrypt `log2(N)` for [`Parameters::recommended`] (128 MiB of memory with `r = 8`)
+const SCRYPT_RECOMMENDED_LOG_N: u8 = 17;
+
+/// scrypt `log2(N)` for [`Parameters::sensitive`] (1 GiB of memory with `r = 8`)
+const SCRYPT_SENSITIVE_LOG_N: u8 = 20;
+
+/// scrypt block size `r` used by the [`Parameters`] presets
+const SCRYPT_BLOCK_SIZE: u16 = 8;
+
 /// DES / Triple DES block size
 #[cfg(any(feature = "3des", feature = "des-insecure"))]
 const DES_BLOCK_SIZE: usize = 8;
 
+/// RC2 block size
+#[cfg(feature = "rc2-insecure")]
+const RC2_BLOCK_SIZE: usize = 8;
+
 /// Password-Based Encryption Scheme 2 parameters as defined in [RFC 8018 Appendix A.4].
 ///
 /// ```text
@@ -75,6 +137,68 @@ pub struct Parameters<'a> {
 }
 
 impl<'a> Parameters<'a> {
+    /// Initialize PBES2 parameters using the recommended settings for
+    /// encrypting keys at rest: scrypt with `N = 2^17`, `r = 8` and `p = 1`
+    /// (128 MiB of memory) as the password-based key derivation function and
+    /// AES-256-CBC as the symmetric cipher.
+    ///
+    /// These match the defaults of the [`scrypt`] crate.
+    pub fn recommended(salt: &'a [u8], aes_iv: &'a [u8; AES_BLOCK_SIZE]) -> Self {
+        Self::scrypt_preset(SCRYPT_RECOMMENDED_LOG_N, salt, aes_iv)
+    }
+
+    /// Initialize PBES2 parameters suitable for interactive use, where the
+    /// derivation should be fast: scrypt with `N = 2^14`, `r = 8` and `p = 1`
+    /// (16 MiB of memory) and AES-256-CBC.
+    pub fn interactive(salt: &'a [u8], aes_iv: &'a [u8; AES_BLOCK_SIZE]) -> Self {
+        Self::scrypt_preset(SCRYPT_INTERACTIVE_LOG_N, salt, aes_iv)
+    }
+
+    /// Initialize PBES2 parameters for highly sensitive keys, where a slow
+    /// derivation is acceptable: scrypt with `N = 2^20`, `r = 8` and `p = 1`
+    /// (1 GiB of memory) and AES-256-CBC.
+    pub fn sensitive(salt: &'a [u8], aes_iv: &'a [u8; AES_BLOCK_SIZE]) -> Self {
+        Self::scrypt_preset(SCRYPT_SENSITIVE_LOG_N, salt, aes_iv)
+    }
+
+    /// Initialize PBES2 parameters using scrypt with a cost parameter `N`
+    /// chosen by measuring this host, such that the key derivation takes
+    /// about `target` (but no more), and AES-256-CBC.
+    ///
+    /// `N` is kept between the [`Parameters::interactive`] and
+    /// [`Parameters::sensitive`] values, i.e. `2^14` and `2^20`. Calibration
+    /// performs one key derivation with `N = 2^14`.
+    #[cfg(all(feature = "std", feature = "pbes2"))]
+    pub fn calibrate(
+        target: Duration,
+        salt: &'a [u8],
+        aes_iv: &'a [u8; AES_BLOCK_SIZE],
+    ) -> Result<Self> {
+        let params = scrypt::Params::new(
+            SCRYPT_INTERACTIVE_LOG_N,
+            SCRYPT_BLOCK_SIZE.into(),
+            1,
+            scrypt::Params::RECOMMENDED_LEN,
+        )
+        .map_err(|_| Error::AlgorithmParametersInvalid { oid: SCRYPT_OID })?;
+
+        let mut key = [0u8; scrypt::Params::RECOMMENDED_LEN];
+        let start = Instant::now();
+        scrypt::scrypt(b"calibration", salt, &params, &mut key)
+            .map_err(|_| Error::AlgorithmParametersInvalid { oid: SCRYPT_OID })?;
+        let elapsed = start.elapsed();
+
+        // The cost of scrypt is linear in `N`
+        let mut log_n = SCRYPT_INTERACTIVE_LOG_N;
+        while log_n < SCRYPT_SENSITIVE_LOG_N
+            && elapsed.saturating_mul(1 << (log_n + 1 - SCRYPT_INTERACTIVE_LOG_N)) <= target
+        {
+            log_n += 1;
+        }
+
+        Ok(Self::scrypt_preset(log_n, salt, aes_iv))
+    }
+
     /// Initialize PBES2 parameters using PBKDF2-SHA256 as the password-based
     /// key derivation function and AES-128-CBC as the symmetric cipher.
     pub fn pbkdf2_sha256_aes128cbc(
@@ -99,6 +223,38 @@ impl<'a> Parameters<'a> {
         Ok(Self { kdf, encryption })
     }
 
+    /// Initialize PBES2 parameters using PBKDF2-SHA256 as the password-based
+    /// key derivation function and AES-128-GCM with a 16-byte authentication
+    /// tag as the symmetric cipher.
+    pub fn pbkdf2_sha256_aes128gcm(
+        pbkdf2_iterations: u32,
+        pbkdf2_salt: &'a [u8],
+        aes_nonce: &'a [u8; GCM_NONCE_SIZE],
+    ) -> Result<Self> {
+        let kdf = Pbkdf2Params::hmac_with_sha256(pbkdf2_iterations, pbkdf2_salt)?.into();
+        let encryption = EncryptionScheme::Aes128Gcm {
+            nonce: aes_nonce,
+            icv_len: GCM_MAX_ICV_LEN,
+        };
+        Ok(Self { kdf, encryption })
+    }
+
+    /// Initialize PBES2 parameters using PBKDF2-SHA256 as the password-based
+    /// key derivation function and AES-256-GCM with a 16-byte authentication
+    /// tag as the symmetric cipher.
+    pub fn pbkdf2_sha256_aes256gcm(
+        pbkdf2_iterations: u32,
+        pbkdf2_salt: &'a [u8],
+        aes_nonce: &'a [u8; GCM_NONCE_SIZE],
+    ) -> Result<Self> {
+        let kdf = Pbkdf2Params::hmac_with_sha256(pbkdf2_iterations, pbkdf2_salt)?.into();
+        let encryption = EncryptionScheme::Aes256Gcm {
+            nonce: aes_nonce,
+            icv_len: GCM_MAX_ICV_LEN,
+        };
+        Ok(Self { kdf, encryption })
+    }
+
     /// Initialize PBES2 parameters using scrypt as the password-based
     /// key derivation function and AES-128-CBC as the symmetric cipher.
     ///
@@ -136,6 +292,22 @@ impl<'a> Parameters<'a> {
         Ok(Self { kdf, encryption })
     }
 
+    /// scrypt with `N = 2^log_n`, `r = 8` and `p = 1`, and AES-256-CBC.
+    fn scrypt_preset(log_n: u8, salt: &'a [u8], aes_iv: &'a [u8; AES_BLOCK_SIZE]) -> Self {
+        let kdf = ScryptParams {
+            salt,
+            cost_parameter: 1 << log_n,
+            block_size: SCRYPT_BLOCK_SIZE,
+            parallelization: 1,
+            key_length: None,
+        };
+
+        Self {
+            kdf: kdf.into(),
+            encryption: EncryptionScheme::Aes256Cbc { iv: aes_iv },
+        }
+    }
+
     /// Attempt to decrypt the given ciphertext, allocating and returning a
     /// byte vector containing the plaintext.
     #[cfg(all(feature = "alloc", feature = "pbes2"))]
@@ -152,6 +324,9 @@ impl<'a> Parameters<'a> {
     /// Returns an error if the algorithm specified in this scheme's parameters
     /// is unsupported, or if the ciphertext is malformed (e.g. not a multiple
     /// of a block mode's padding)
+    ///
+    /// For AES-GCM, the ciphertext is followed by the authentication tag, and
+    /// [`Error::DecryptFailed`] is returned if it doesn't verify.
     #[cfg(feature = "pbes2")]
     pub fn decrypt_in_place<'b>(
         &self,
@@ -161,6 +336,15 @@ impl<'a> Parameters<'a> {
         encryption::decrypt_in_place(self, password, buffer)
     }
 
+    /// Initialize a streaming [`Decryptor`] for ciphertext which is too large
+    /// to be decrypted in a single buffer.
+    ///
+    /// Only CBC encryption schemes are supported.
+    #[cfg(feature = "pbes2")]
+    pub fn decryptor(&self, password: impl AsRef<[u8]>) -> Result<Decryptor> {
+        Decryptor::new(self, password)
+    }
+
     /// Encrypt the given plaintext, allocating and returning a vector
     /// containing the ciphertext.
     #[cfg(all(feature = "alloc", feature = "pbes2"))]
@@ -181,6 +365,9 @@ impl<'a> Parameters<'a> {
     /// Encrypt the given plaintext in-place using a key derived from the
     /// provided password and this scheme's parameters, writing the ciphertext
     /// into the same buffer.
+    ///
+    /// For AES-GCM, the authentication tag is appended to the ciphertext, so
+    /// the buffer needs to have room for it after `pos`.
     #[cfg(feature = "pbes2")]
     pub fn encrypt_in_place<'b>(
         &self,
@@ -250,6 +437,33 @@ pub enum EncryptionScheme<'a> {
         iv: &'a [u8; AES_BLOCK_SIZE],
     },
 
+    /// AES-128 in GCM mode
+    Aes128Gcm {
+        /// Nonce
+        nonce: &'a [u8; GCM_NONCE_SIZE],
+
+        /// Authentication tag (ICV) length in bytes
+        icv_len: u8,
+    },
+
+    /// AES-192 in GCM mode
+    Aes192Gcm {
+        /// Nonce
+        nonce: &'a [u8; GCM_NONCE_SIZE],
+
+        /// Authentication tag (ICV) length in bytes
+        icv_len: u8,
+    },
+
+    /// AES-256 in GCM mode
+    Aes256Gcm {
+        /// Nonce
+        nonce: &'a [u8; GCM_NONCE_SIZE],
+
+        /// Authentication tag (ICV) length in bytes
+        icv_len: u8,
+    },
+
     /// 3-Key Triple DES in CBC mode
     #[cfg(feature = "3des")]
     DesEde3Cbc {
@@ -263,6 +477,20 @@ pub enum EncryptionScheme<'a> {
         /// Initialisation vector
         iv: &'a [u8; DES_BLOCK_SIZE],
     },
+
+    /// RC2 in CBC mode (decryption only)
+    ///
+    /// ⚠️ WARNING: RC2 is broken and only supported for reading legacy data.
+    #[cfg(feature = "rc2-insecure")]
+    Rc2Cbc {
+        /// Initialisation vector
+        iv: &'a [u8; RC2_BLOCK_SIZE],
+
+        /// Effective key length in bits: 32, 40, 64 or 128
+        ///
+        /// The key size is the effective key length as well.
+        effective_key_bits: u16,
+    },
 }
 
 impl<'a> EncryptionScheme<'a> {
@@ -272,10 +500,17 @@ impl<'a> EncryptionScheme<'a> {
             Self::Aes128Cbc { .. } => 16,
             Self::Aes192Cbc { .. } => 24,
             Self::Aes256Cbc { .. } => 32,
+            Self::Aes128Gcm { .. } => 16,
+            Self::Aes192Gcm { .. } => 24,
+            Self::Aes256Gcm { .. } => 32,
             #[cfg(feature = "des-insecure")]
             Self::DesCbc { .. } => 8,
             #[cfg(feature = "3des")]
             Self::DesEde3Cbc { .. } => 24,
+            #[cfg(feature = "rc2-insecure")]
+            Self::Rc2Cbc {
+                effective_key_bits, ..
+            } => usize::from(*effective_key_bits / 8),
         }
     }
 
@@ -285,10 +520,45 @@ impl<'a> EncryptionScheme<'a> {
             Self::Aes128Cbc { .. } => AES_128_CBC_OID,
             Self::Aes192Cbc { .. } => AES_192_CBC_OID,
             Self::Aes256Cbc { .. } => AES_256_CBC_OID,
+            Self::Aes128Gcm { .. } => AES_128_GCM_OID,
+            Self::Aes192Gcm { .. } => AES_192_GCM_OID,
+            Self::Aes256Gcm { .. } => AES_256_GCM_OID,
             #[cfg(feature = "des-insecure")]
             Self::DesCbc { .. } => DES_CBC_OID,
             #[cfg(feature = "3des")]
             Self::DesEde3Cbc { .. } => DES_EDE3_CBC_OID,
+            #[cfg(feature = "rc2-insecure")]
+            Self::Rc2Cbc { .. } => RC2_CBC_OID,
+        }
+    }
+
+    /// Is this an authenticated encryption scheme, i.e. AES-GCM?
+    pub fn is_aead(&self) -> bool {
+        self.gcm_parameters().is_some()
+    }
+
+    /// Get the AES-GCM parameters, if this is an AES-GCM scheme.
+    fn gcm_parameters(&self) -> Option<GcmParameters<'a>> {
+        match *self {
+            Self::Aes128Gcm { nonce, icv_len }
+            | Self::Aes192Gcm { nonce, icv_len }
+            | Self::Aes256Gcm { nonce, icv_len } => Some(GcmParameters { nonce, icv_len }),
+            _ => None,
+        }
+    }
+
+    /// Get the RC2-CBC parameters, if this is an RC2 scheme.
+    #[cfg(feature = "rc2-insecure")]
+    fn rc2_parameters(&self) -> Option<Rc2CbcParameters<'a>> {
+        match *self {
+            Self::Rc2Cbc {
+                iv,
+                effective_key_bits,
+            } => Some(Rc2CbcParameters {
+                iv,
+                effective_key_bits,
+            }),
+            _ => None,
         }
     }
 
@@ -310,6 +580,35 @@ impl<'a> TryFrom<AlgorithmIdentifierRef<'a>> for EncryptionScheme<'a> {
     type Error = der::Error;
 
     fn try_from(alg: AlgorithmIdentifierRef<'a>) -> der::Result<Self> {
+        if let AES_128_GCM_OID | AES_192_GCM_OID | AES_256_GCM_OID = alg.oid {
+            let GcmParameters { nonce, icv_len } = match alg.parameters {
+                Some(params) => params.decode_as()?,
+                None => return Err(Tag::Sequence.value_error()),
+            };
+
+            return Ok(match alg.oid {
+                AES_128_GCM_OID => Self::Aes128Gcm { nonce, icv_len },
+                AES_192_GCM_OID => Self::Aes192Gcm { nonce, icv_len },
+                _ => Self::Aes256Gcm { nonce, icv_len },
+            });
+        }
+
+        #[cfg(feature = "rc2-insecure")]
+        if alg.oid == RC2_CBC_OID {
+            let Rc2CbcParameters {
+                iv,
+                effective_key_bits,
+            } = match alg.parameters {
+                Some(params) => params.decode_as()?,
+                None => return Err(Tag::Sequence.value_error()),
+            };
+
+            return Ok(Self::Rc2Cbc {
+                iv,
+                effective_key_bits,
+            });
+        }
+
         // TODO(tarcieri): support for non-AES algorithms?
         let iv = match alg.parameters {
             Some(params) => params.decode_as::<OctetStringRef<'a>>()?.as_bytes(),
@@ -349,6 +648,11 @@ impl<'a> TryFrom<AlgorithmIdentifierRef<'a>> for EncryptionScheme<'a> {
     }
 }
 
+/// Converts the encryption scheme into an [`AlgorithmIdentifierRef`].
+///
+/// AES-GCM and RC2 schemes are unsupported, as their `GCMParameters` and
+/// `RC2-CBC-Parameter` can't be borrowed from the scheme. Use the [`Encode`] impl on [`EncryptionScheme`]
+/// to serialize them instead.
 impl<'a> TryFrom<EncryptionScheme<'a>> for AlgorithmIdentifierRef<'a> {
     type Error = der::Error;
 
@@ -357,10 +661,15 @@ impl<'a> TryFrom<EncryptionScheme<'a>> for AlgorithmIdentifierRef<'a> {
             EncryptionScheme::Aes128Cbc { iv } => iv,
             EncryptionScheme::Aes192Cbc { iv } => iv,
             EncryptionScheme::Aes256Cbc { iv } => iv,
+            EncryptionScheme::Aes128Gcm { .. }
+            | EncryptionScheme::Aes192Gcm { .. }
+            | EncryptionScheme::Aes256Gcm { .. } => return Err(Tag::Sequence.value_error()),
             #[cfg(feature = "des-insecure")]
             EncryptionScheme::DesCbc { iv } => iv,
             #[cfg(feature = "3des")]
             EncryptionScheme::DesEde3Cbc { iv } => iv,
+            #[cfg(feature = "rc2-insecure")]
+            EncryptionScheme::Rc2Cbc { .. } => return Err(Tag::Sequence.value_error()),
         })?;
 
         Ok(AlgorithmIdentifierRef {
@@ -372,10 +681,184 @@ impl<'a> TryFrom<EncryptionScheme<'a>> for AlgorithmIdentifierRef<'a> {
 
 impl<'a> Encode for EncryptionScheme<'a> {
     fn encoded_len(&self) -> der::Result<Length> {
+        if let Some(params) = self.gcm_parameters() {
+            return AlgorithmIdentifier {
+                oid: self.oid(),
+                parameters: Some(params),
+            }
+            .encoded_len();
+        }
+
+        #[cfg(feature = "rc2-insecure")]
+        if let Some(params) = self.rc2_parameters() {
+            return AlgorithmIdentifier {
+                oid: self.oid(),
+                parameters: Some(params),
+            }
+            .encoded_len();
+        }
+
         AlgorithmIdentifierRef::try_from(*self)?.encoded_len()
     }
 
     fn encode(&self, writer: &mut impl Writer) -> der::Result<()> {
+        if let Some(params) = self.gcm_parameters() {
+            return AlgorithmIdentifier {
+                oid: self.oid(),
+                parameters: Some(params),
+            }
+            .encode(writer);
+        }
+
+        #[cfg(feature = "rc2-insecure")]
+        if let Some(params) = self.rc2_parameters() {
+            return AlgorithmIdentifier {
+                oid: self.oid(),
+                parameters: Some(params),
+            }
+            .encode(writer);
+        }
+
         AlgorithmIdentifierRef::try_from(*self)?.encode(writer)
     }
 }
+
+/// AES-GCM parameters as defined in [RFC 5084 Section 3.2].
+///
+/// ```text
+/// GCMParameters ::= SEQUENCE {
+///     aes-nonce        OCTET STRING, -- recommended size is 12 octets
+///     aes-ICVlen       AES-GCM-ICVlen DEFAULT 12 }
+///
+/// AES-GCM-ICVlen ::= INTEGER (12 | 13 | 14 | 15 | 16)
+/// ```
+///
+/// Only the recommended 12-byte nonces are supported.
+///
+/// [RFC 5084 Section 3.2]: https://datatracker.ietf.org/doc/html/rfc5084#section-3.2
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+struct GcmParameters<'a> {
+    nonce: &'a [u8; GCM_NONCE_SIZE],
+    icv_len: u8,
+}
+
+impl GcmParameters<'_> {
+    /// Get the `aes-ICVlen` field, which is omitted when it has the default
+    /// value.
+    fn icv_len_field(&self) -> der::Result<Option<u8>> {
+        match self.icv_len {
+            GCM_DEFAULT_ICV_LEN => Ok(None),
+            13..=GCM_MAX_ICV_LEN => Ok(Some(self.icv_len)),
+            _ => Err(Tag::Integer.value_error()),
+        }
+    }
+}
+
+impl<'a> DecodeValue<'a> for GcmParameters<'a> {
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: der::Header) -> der::Result<Self> {
+        reader.read_nested(header.length, |reader| {
+            let nonce = OctetStringRef::decode(reader)?
+                .as_bytes()
+                .try_into()
+                .map_err(|_| Tag::OctetString.value_error())?;
+
+            let params = Self {
+                nonce,
+                icv_len: Option::<u8>::decode(reader)?.unwrap_or(GCM_DEFAULT_ICV_LEN),
+            };
+
+            params.icv_len_field()?;
+            Ok(params)
+        })
+    }
+}
+
+impl EncodeValue for GcmParameters<'_> {
+    fn value_len(&self) -> der::Result<Length> {
+        OctetStringRef::new(self.nonce)?.encoded_len()? + self.icv_len_field()?.encoded_len()?
+    }
+
+    fn encode_value(&self, writer: &mut impl Writer) -> der::Result<()> {
+        OctetStringRef::new(self.nonce)?.encode(writer)?;
+        self.icv_len_field()?.encode(writer)?;
+        Ok(())
+    }
+}
+
+impl<'a> Sequence<'a> for GcmParameters<'a> {}
+
+/// RC2-CBC parameters as defined in [RFC 8018 Appendix B.2.3].
+///
+/// ```text
+/// RC2-CBC-Parameter ::= SEQUENCE {
+///     rc2ParameterVersion INTEGER OPTIONAL,
+///     iv OCTET STRING (SIZE(8)) }
+/// ```
+///
+/// Only the `rc2ParameterVersion` values for effective key lengths of 40, 64
+/// and 128 bits are supported, as well as the default of 32 bits when absent.
+///
+/// [RFC 8018 Appendix B.2.3]: https://datatracker.ietf.org/doc/html/rfc8018#appendix-B.2.3
+#[cfg(feature = "rc2-insecure")]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+struct Rc2CbcParameters<'a> {
+    iv: &'a [u8; RC2_BLOCK_SIZE],
+    effective_key_bits: u16,
+}
+
+#[cfg(feature = "rc2-insecure")]
+impl Rc2CbcParameters<'_> {
+    /// Get the `rc2ParameterVersion` field ([RFC 2268 Section 6]).
+    ///
+    /// [RFC 2268 Section 6]: https://datatracker.ietf.org/doc/html/rfc2268#section-6
+    fn version(&self) -> der::Result<Option<u8>> {
+        match self.effective_key_bits {
+            32 => Ok(None),
+            40 => Ok(Some(160)),
+            64 => Ok(Some(120)),
+            128 => Ok(Some(58)),
+            _ => Err(Tag::Integer.value_error()),
+        }
+    }
+}
+
+#[cfg(feature = "rc2-insecure")]
+impl<'a> DecodeValue<'a> for Rc2CbcParameters<'a> {
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: der::Header) -> der::Result<Self> {
+        reader.read_nested(header.length, |reader| {
+            let effective_key_bits = match Option::<u8>::decode(reader)? {
+                None => 32,
+                Some(160) => 40,
+                Some(120) => 64,
+                Some(58) => 128,
+                Some(_) => return Err(Tag::Integer.value_error()),
+            };
+
+            let iv = OctetStringRef::decode(reader)?
+                .as_bytes()
+                .try_into()
+                .map_err(|_| Tag::OctetString.value_error())?;
+
+            Ok(Self {
+                iv,
+                effective_key_bits,
+            })
+        })
+    }
+}
+
+#[cfg(feature = "rc2-insecure")]
+impl EncodeValue for Rc2CbcParameters<'_> {
+    fn value_len(&self) -> der::Result<Length> {
+        self.version()?.encoded_len()? + OctetStringRef::new(self.iv)?.encoded_len()?
+    }
+
+    fn encode_value(&self, writer: &mut impl Writer) -> der::Result<()> {
+        self.version()?.encode(writer)?;
+        OctetStringRef::new(self.iv)?.encode(writer)?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "rc2-insecure")]
+impl<'a> Sequence<'a> for Rc2CbcParameters<'a> {}
@@ -0,0 +1,213 @@
+//! Streaming PBES2 decryption.
+
+use super::{encryption::EncryptionKey, EncryptionScheme, Parameters};
+use crate::{Error, Result};
+use cbc::cipher::{
+    block_padding::{Padding, Pkcs7},
+    generic_array::GenericArray,
+    BlockDecryptMut, BlockSizeUser, KeyIvInit,
+};
+
+#[cfg(feature = "rc2-insecure")]
+use {cbc::cipher::InnerIvInit, rc2::Rc2};
+
+/// Largest supported cipher block size
+const MAX_BLOCK_SIZE: usize = 16;
+
+/// Streaming PBES2 decryptor for CBC encryption schemes.
+///
+/// Ciphertext may be supplied in chunks of any size using
+/// [`Decryptor::update`], which writes all plaintext which can already be
+/// determined to a caller-provided output buffer. The final block, which
+/// contains the PKCS#7 padding, is held back until [`Decryptor::finalize`].
+///
+/// Each call to [`Decryptor::update`] outputs at most `ciphertext.len() + 15`
+/// bytes, and [`Decryptor::finalize`] at most one block (16 bytes).
+///
+/// AES-GCM schemes are not supported, as they would release plaintext before
+/// its authentication tag has been verified.
+pub struct Decryptor {
+    cipher: CbcDecryptor,
+
+    /// Ciphertext which has not been decrypted yet
+    buffer: [u8; MAX_BLOCK_SIZE],
+
+    /// Number of bytes in `buffer`
+    buffered: usize,
+}
+
+impl Decryptor {
+    /// Initialize a decryptor by deriving the key from the given password.
+    pub fn new(params: &Parameters<'_>, password: impl AsRef<[u8]>) -> Result<Self> {
+        let es = params.encryption;
+
+        if es.is_aead() {
+            return Err(Error::UnsupportedAlgorithm { oid: es.oid() });
+        }
+
+        let key =
+            EncryptionKey::derive_from_password(password.as_ref(), &params.kdf, es.key_size())?;
+
+        Ok(Self {
+            cipher: CbcDecryptor::new(es, key.as_slice())?,
+            buffer: [0u8; MAX_BLOCK_SIZE],
+            buffered: 0,
+        })
+    }
+
+    /// Decrypt the next chunk of ciphertext, writing the resulting plaintext
+    /// to the beginning of `out` and returning it.
+    ///
+    /// Returns [`Error::DecryptFailed`] if `out` is too small.
+    pub fn update<'b>(&mut self, ciphertext: &[u8], out: &'b mut [u8]) -> Result<&'b [u8]> {
+        let block_size = self.cipher.block_size();
+        let total = self.buffered + ciphertext.len();
+
+        // Always hold back the final (possibly partial) block
+        let len = total.saturating_sub(1) / block_size * block_size;
+
+        if len == 0 {
+            self.buffer[self.buffered..total].copy_from_slice(ciphertext);
+            self.buffered = total;
+            return Ok(&[]);
+        }
+
+        let out = out.get_mut(..len).ok_or(Error::DecryptFailed)?;
+        let (head, rest) = ciphertext.split_at(len - self.buffered);
+
+        out[..self.buffered].copy_from_slice(&self.buffer[..self.buffered]);
+        out[self.buffered..].copy_from_slice(head);
+        self.cipher.decrypt_blocks(out);
+
+        self.buffer[..rest.len()].copy_from_slice(rest);
+        self.buffered = rest.len();
+        Ok(out)
+    }
+
+    /// Decrypt the final block and remove its padding, writing the remaining
+    /// plaintext to the beginning of `out` and returning it.
+    ///
+    /// Returns [`Error::DecryptFailed`] if the ciphertext length was not a
+    /// multiple of the block size, the padding is invalid, or `out` is too
+    /// small.
+    pub fn finalize(mut self, out: &mut [u8]) -> Result<&[u8]> {
+        let block_size = self.cipher.block_size();
+
+        if self.buffered != block_size {
+            return Err(Error::DecryptFailed);
+        }
+
+        let block = &mut self.buffer[..block_size];
+        self.cipher.decrypt_blocks(block);
+        let plaintext = self.cipher.unpad(block)?;
+
+        let out = out.get_mut(..plaintext.len()).ok_or(Error::DecryptFailed)?;
+        out.copy_from_slice(plaintext);
+        Ok(out)
+    }
+}
+
+/// CBC mode decryptor for a supported block cipher.
+enum CbcDecryptor {
+    Aes128(cbc::Decryptor<aes::Aes128Dec>),
+    Aes192(cbc::Decryptor<aes::Aes192Dec>),
+    Aes256(cbc::Decryptor<aes::Aes256Dec>),
+    #[cfg(feature = "3des")]
+    DesEde3(cbc::Decryptor<des::TdesEde3>),
+    #[cfg(feature = "des-insecure")]
+    Des(cbc::Decryptor<des::Des>),
+    #[cfg(feature = "rc2-insecure")]
+    Rc2(cbc::Decryptor<Rc2>),
+}
+
+impl CbcDecryptor {
+    fn new(es: EncryptionScheme<'_>, key: &[u8]) -> Result<Self> {
+        let cipher = match es {
+            EncryptionScheme::Aes128Cbc { iv } => {
+                cbc::Decryptor::new_from_slices(key, iv).map(Self::Aes128)
+            }
+            EncryptionScheme::Aes192Cbc { iv } => {
+                cbc::Decryptor::new_from_slices(key, iv).map(Self::Aes192)
+            }
+            EncryptionScheme::Aes256Cbc { iv } => {
+                cbc::Decryptor::new_from_slices(key, iv).map(Self::Aes256)
+            }
+            #[cfg(feature = "3des")]
+            EncryptionScheme::DesEde3Cbc { iv } => {
+                cbc::Decryptor::new_from_slices(key, iv).map(Self::DesEde3)
+            }
+            #[cfg(feature = "des-insecure")]
+            EncryptionScheme::DesCbc { iv } => {
+                cbc::Decryptor::new_from_slices(key, iv).map(Self::Des)
+            }
+            #[cfg(feature = "rc2-insecure")]
+            EncryptionScheme::Rc2Cbc {
+                iv,
+                effective_key_bits,
+            } => {
+                let cipher = Rc2::new_with_eff_key_len(key, effective_key_bits.into());
+                cbc::Decryptor::inner_iv_slice_init(cipher, iv).map(Self::Rc2)
+            }
+            EncryptionScheme::Aes128Gcm { .. }
+            | EncryptionScheme::Aes192Gcm { .. }
+            | EncryptionScheme::Aes256Gcm { .. } => {
+                return Err(Error::UnsupportedAlgorithm { oid: es.oid() })
+            }
+        };
+
+        cipher.map_err(|_| es.to_alg_params_invalid())
+    }
+
+    /// Cipher block size in bytes.
+    fn block_size(&self) -> usize {
+        match self {
+            Self::Aes128(_) | Self::Aes192(_) | Self::Aes256(_) => 16,
+            #[cfg(feature = "3des")]
+            Self::DesEde3(_) => 8,
+            #[cfg(feature = "des-insecure")]
+            Self::Des(_) => 8,
+            #[cfg(feature = "rc2-insecure")]
+            Self::Rc2(_) => 8,
+        }
+    }
+
+    /// Decrypt `data` in place, whose length must be a multiple of the block
+    /// size.
+    fn decrypt_blocks(&mut self, data: &mut [u8]) {
+        match self {
+            Self::Aes128(cipher) => decrypt_blocks(cipher, data),
+            Self::Aes192(cipher) => decrypt_blocks(cipher, data),
+            Self::Aes256(cipher) => decrypt_blocks(cipher, data),
+            #[cfg(feature = "3des")]
+            Self::DesEde3(cipher) => decrypt_blocks(cipher, data),
+            #[cfg(feature = "des-insecure")]
+            Self::Des(cipher) => decrypt_blocks(cipher, data),
+            #[cfg(feature = "rc2-insecure")]
+            Self::Rc2(cipher) => decrypt_blocks(cipher, data),
+        }
+    }
+
+    /// Remove the PKCS#7 padding from the final decrypted block.
+    fn unpad<'b>(&self, block: &'b [u8]) -> Result<&'b [u8]> {
+        match self {
+            Self::Aes128(_) | Self::Aes192(_) | Self::Aes256(_) => unpad::<aes::Aes128Dec>(block),
+            #[cfg(feature = "3des")]
+            Self::DesEde3(_) => unpad::<des::TdesEde3>(block),
+            #[cfg(feature = "des-insecure")]
+            Self::Des(_) => unpad::<des::Des>(block),
+            #[cfg(feature = "rc2-insecure")]
+            Self::Rc2(_) => unpad::<Rc2>(block),
+        }
+    }
+}
+
+fn decrypt_blocks<C: BlockDecryptMut + BlockSizeUser>(cipher: &mut C, data: &mut [u8]) {
+    for block in data.chunks_exact_mut(C::block_size()) {
+        cipher.decrypt_block_mut(GenericArray::from_mut_slice(block));
+    }
+}
+
+fn unpad<C: BlockSizeUser>(block: &[u8]) -> Result<&[u8]> {
+    Pkcs7::unpad(GenericArray::<u8, C::BlockSize>::from_slice(block))
+        .map_err(|_| Error::DecryptFailed)
+}
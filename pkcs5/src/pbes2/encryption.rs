@@ -1,9 +1,19 @@
 //! PBES2 encryption.
 
-use super::{EncryptionScheme, Kdf, Parameters, Pbkdf2Params, Pbkdf2Prf, ScryptParams};
+use super::{
+    EncryptionScheme, Kdf, Parameters, Pbkdf2Params, Pbkdf2Prf, ScryptParams, GCM_NONCE_SIZE,
+};
 use crate::{Error, Result};
+use aes_gcm::{
+    aead::{
+        consts::{U12, U13, U14, U15, U16},
+        AeadInPlace,
+    },
+    AesGcm, Tag, TagSize,
+};
 use cbc::cipher::{
-    block_padding::Pkcs7, BlockCipher, BlockDecryptMut, BlockEncryptMut, KeyInit, KeyIvInit,
+    block_padding::Pkcs7, BlockCipher, BlockDecryptMut, BlockEncrypt, BlockEncryptMut, KeyInit,
+    KeyIvInit,
 };
 use pbkdf2::{
     hmac::digest::{
@@ -16,6 +26,9 @@ use pbkdf2::{
 };
 use scrypt::scrypt;
 
+#[cfg(feature = "rc2-insecure")]
+use {cbc::cipher::InnerIvInit, rc2::Rc2};
+
 /// Maximum size of a derived encryption key
 const MAX_KEY_LEN: usize = 32;
 
@@ -44,6 +57,109 @@ fn cbc_decrypt<'a, C: BlockDecryptMut + BlockCipher + KeyInit>(
         .map_err(|_| Error::EncryptFailed)
 }
 
+fn gcm_encrypt<'a, C>(
+    es: EncryptionScheme<'_>,
+    key: EncryptionKey,
+    nonce: &[u8; GCM_NONCE_SIZE],
+    icv_len: u8,
+    buffer: &'a mut [u8],
+    pos: usize,
+) -> Result<&'a [u8]>
+where
+    C: BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt + KeyInit,
+{
+    match icv_len {
+        12 => gcm_encrypt_with_tag::<C, U12>(es, key, nonce, buffer, pos),
+        13 => gcm_encrypt_with_tag::<C, U13>(es, key, nonce, buffer, pos),
+        14 => gcm_encrypt_with_tag::<C, U14>(es, key, nonce, buffer, pos),
+        15 => gcm_encrypt_with_tag::<C, U15>(es, key, nonce, buffer, pos),
+        16 => gcm_encrypt_with_tag::<C, U16>(es, key, nonce, buffer, pos),
+        _ => Err(es.to_alg_params_invalid()),
+    }
+}
+
+fn gcm_decrypt<'a, C>(
+    es: EncryptionScheme<'_>,
+    key: EncryptionKey,
+    nonce: &[u8; GCM_NONCE_SIZE],
+    icv_len: u8,
+    buffer: &'a mut [u8],
+) -> Result<&'a [u8]>
+where
+    C: BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt + KeyInit,
+{
+    match icv_len {
+        12 => gcm_decrypt_with_tag::<C, U12>(es, key, nonce, buffer),
+        13 => gcm_decrypt_with_tag::<C, U13>(es, key, nonce, buffer),
+        14 => gcm_decrypt_with_tag::<C, U14>(es, key, nonce, buffer),
+        15 => gcm_decrypt_with_tag::<C, U15>(es, key, nonce, buffer),
+        16 => gcm_decrypt_with_tag::<C, U16>(es, key, nonce, buffer),
+        _ => Err(es.to_alg_params_invalid()),
+    }
+}
+
+/// Encrypt `buffer[..pos]` in-place, appending an authentication tag of
+/// `T` bytes ([RFC 5084 Section 3]).
+///
+/// [RFC 5084 Section 3]: https://datatracker.ietf.org/doc/html/rfc5084#section-3
+fn gcm_encrypt_with_tag<'a, C, T>(
+    es: EncryptionScheme<'_>,
+    key: EncryptionKey,
+    nonce: &[u8; GCM_NONCE_SIZE],
+    buffer: &'a mut [u8],
+    pos: usize,
+) -> Result<&'a [u8]>
+where
+    C: BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt + KeyInit,
+    T: TagSize,
+{
+    let cipher = AesGcm::<C, U12, T>::new_from_slice(key.as_slice())
+        .map_err(|_| es.to_alg_params_invalid())?;
+
+    let end = pos
+        .checked_add(T::USIZE)
+        .filter(|&end| end <= buffer.len())
+        .ok_or(Error::EncryptFailed)?;
+
+    let (message, icv) = buffer[..end].split_at_mut(pos);
+    let tag = cipher
+        .encrypt_in_place_detached(nonce.into(), &[], message)
+        .map_err(|_| Error::EncryptFailed)?;
+    icv.copy_from_slice(&tag);
+
+    Ok(&buffer[..end])
+}
+
+/// Verify the authentication tag of `T` bytes at the end of `buffer` and
+/// decrypt the rest in-place ([RFC 5084 Section 3]).
+///
+/// [RFC 5084 Section 3]: https://datatracker.ietf.org/doc/html/rfc5084#section-3
+fn gcm_decrypt_with_tag<'a, C, T>(
+    es: EncryptionScheme<'_>,
+    key: EncryptionKey,
+    nonce: &[u8; GCM_NONCE_SIZE],
+    buffer: &'a mut [u8],
+) -> Result<&'a [u8]>
+where
+    C: BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt + KeyInit,
+    T: TagSize,
+{
+    let cipher = AesGcm::<C, U12, T>::new_from_slice(key.as_slice())
+        .map_err(|_| es.to_alg_params_invalid())?;
+
+    let pos = buffer
+        .len()
+        .checked_sub(T::USIZE)
+        .ok_or(Error::DecryptFailed)?;
+
+    let (message, icv) = buffer.split_at_mut(pos);
+    cipher
+        .decrypt_in_place_detached(nonce.into(), &[], message, Tag::<T>::from_slice(icv))
+        .map_err(|_| Error::DecryptFailed)?;
+
+    Ok(&buffer[..pos])
+}
+
 pub fn encrypt_in_place<'b>(
     params: &Parameters<'_>,
     password: impl AsRef<[u8]>,
@@ -61,12 +177,25 @@ pub fn encrypt_in_place<'b>(
         EncryptionScheme::Aes128Cbc { iv } => cbc_encrypt::<aes::Aes128Enc>(es, key, iv, buf, pos),
         EncryptionScheme::Aes192Cbc { iv } => cbc_encrypt::<aes::Aes192Enc>(es, key, iv, buf, pos),
         EncryptionScheme::Aes256Cbc { iv } => cbc_encrypt::<aes::Aes256Enc>(es, key, iv, buf, pos),
+        EncryptionScheme::Aes128Gcm { nonce, icv_len } => {
+            gcm_encrypt::<aes::Aes128Enc>(es, key, nonce, icv_len, buf, pos)
+        }
+        EncryptionScheme::Aes192Gcm { nonce, icv_len } => {
+            gcm_encrypt::<aes::Aes192Enc>(es, key, nonce, icv_len, buf, pos)
+        }
+        EncryptionScheme::Aes256Gcm { nonce, icv_len } => {
+            gcm_encrypt::<aes::Aes256Enc>(es, key, nonce, icv_len, buf, pos)
+        }
         #[cfg(feature = "3des")]
         EncryptionScheme::DesEde3Cbc { iv } => cbc_encrypt::<des::TdesEde3>(es, key, iv, buf, pos),
         #[cfg(feature = "des-insecure")]
         EncryptionScheme::DesCbc { .. } => Err(Error::UnsupportedAlgorithm {
             oid: super::DES_CBC_OID,
         }),
+        #[cfg(feature = "rc2-insecure")]
+        EncryptionScheme::Rc2Cbc { .. } => Err(Error::UnsupportedAlgorithm {
+            oid: super::RC2_CBC_OID,
+        }),
     }
 }
 
@@ -83,16 +212,36 @@ pub fn decrypt_in_place<'a>(
         EncryptionScheme::Aes128Cbc { iv } => cbc_decrypt::<aes::Aes128Dec>(es, key, iv, buf),
         EncryptionScheme::Aes192Cbc { iv } => cbc_decrypt::<aes::Aes192Dec>(es, key, iv, buf),
         EncryptionScheme::Aes256Cbc { iv } => cbc_decrypt::<aes::Aes256Dec>(es, key, iv, buf),
+        EncryptionScheme::Aes128Gcm { nonce, icv_len } => {
+            gcm_decrypt::<aes::Aes128Enc>(es, key, nonce, icv_len, buf)
+        }
+        EncryptionScheme::Aes192Gcm { nonce, icv_len } => {
+            gcm_decrypt::<aes::Aes192Enc>(es, key, nonce, icv_len, buf)
+        }
+        EncryptionScheme::Aes256Gcm { nonce, icv_len } => {
+            gcm_decrypt::<aes::Aes256Enc>(es, key, nonce, icv_len, buf)
+        }
         #[cfg(feature = "3des")]
         EncryptionScheme::DesEde3Cbc { iv } => cbc_decrypt::<des::TdesEde3>(es, key, iv, buf),
         #[cfg(feature = "des-insecure")]
         EncryptionScheme::DesCbc { iv } => cbc_decrypt::<des::Des>(es, key, iv, buf),
+        #[cfg(feature = "rc2-insecure")]
+        EncryptionScheme::Rc2Cbc {
+            iv,
+            effective_key_bits,
+        } => {
+            let cipher = Rc2::new_with_eff_key_len(key.as_slice(), effective_key_bits.into());
+            cbc::Decryptor::inner_iv_slice_init(cipher, iv)
+                .map_err(|_| es.to_alg_params_invalid())?
+                .decrypt_padded_mut::<Pkcs7>(buf)
+                .map_err(|_| Error::DecryptFailed)
+        }
     }
 }
 
 /// Encryption key as derived by PBKDF2
 // TODO(tarcieri): zeroize?
-struct EncryptionKey {
+pub(super) struct EncryptionKey {
     buffer: [u8; MAX_KEY_LEN],
     length: usize,
 }
@@ -199,7 +348,7 @@ impl EncryptionKey {
     }
 
     /// Get the key material as a slice
-    fn as_slice(&self) -> &[u8] {
+    pub fn as_slice(&self) -> &[u8] {
         &self.buffer[..self.length]
     }
 }
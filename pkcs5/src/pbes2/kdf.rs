@@ -38,6 +38,11 @@ pub const SCRYPT_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.6.1.4
 type ScryptCost = u64;
 
 /// Password-based key derivation function.
+///
+/// Argon2 ([RFC 9106]) isn't supported: no OID has been assigned for it as
+/// a PBES2 key derivation function.
+///
+/// [RFC 9106]: https://datatracker.ietf.org/doc/html/rfc9106
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum Kdf<'a> {
@@ -200,6 +205,12 @@ impl<'a> Pbkdf2Params<'a> {
     /// and [RFC 8018, §A.2](https://datatracker.ietf.org/doc/html/rfc8018#appendix-A.2)
     pub const MAX_ITERATION_COUNT: u32 = 100_000_000;
 
+    /// Recommended minimum iteration count of 600,000 for PBKDF2-HMAC-SHA256,
+    /// as given by the [OWASP Password Storage Cheat Sheet].
+    ///
+    /// [OWASP Password Storage Cheat Sheet]: https://cheatsheetseries.owasp.org/cheatsheets/Password_Storage_Cheat_Sheet.html#pbkdf2
+    pub const RECOMMENDED_ITERATIONS: u32 = 600_000;
+
     const INVALID_ERR: Error = Error::AlgorithmParametersInvalid { oid: PBKDF2_OID };
 
     /// Initialize PBKDF2-SHA256 with the given iteration count and salt
@@ -214,6 +225,17 @@ impl<'a> Pbkdf2Params<'a> {
             prf: Pbkdf2Prf::HmacWithSha256,
         })
     }
+
+    /// Initialize PBKDF2-SHA256 with the given salt and the
+    /// [`Pbkdf2Params::RECOMMENDED_ITERATIONS`] iteration count.
+    pub fn recommended(salt: &'a [u8]) -> Self {
+        Self {
+            salt,
+            iteration_count: Self::RECOMMENDED_ITERATIONS,
+            key_length: None,
+            prf: Pbkdf2Prf::HmacWithSha256,
+        }
+    }
 }
 
 impl<'a> DecodeValue<'a> for Pbkdf2Params<'a> {
@@ -0,0 +1,200 @@
+//! PKCS#12 password-based encryption schemes as defined in [RFC 7292 Appendix C].
+//!
+//! These are commonly found in keys produced by Java and older OpenSSL
+//! versions. They share the parameter structure of PBES1, but derive the key
+//! and IV with the PKCS#12 key derivation function ([RFC 7292 Appendix B]).
+//!
+//! [RFC 7292 Appendix B]: https://datatracker.ietf.org/doc/html/rfc7292#appendix-B
+//! [RFC 7292 Appendix C]: https://datatracker.ietf.org/doc/html/rfc7292#appendix-C
+
+#[cfg(feature = "legacy")]
+mod encryption;
+
+use crate::{pbes1::Parameters, AlgorithmIdentifierRef};
+use der::{
+    asn1::ObjectIdentifier, DecodeValue, Encode, EncodeValue, ErrorKind, Length, Reader, Sequence,
+    Tag, Writer,
+};
+
+#[cfg(feature = "legacy")]
+use crate::Result;
+
+#[cfg(all(feature = "alloc", feature = "legacy"))]
+use alloc::vec::Vec;
+
+/// `pbeWithSHAAnd3-KeyTripleDES-CBC` Object Identifier (OID).
+pub const PBE_WITH_SHA_AND_3_KEY_TRIPLE_DES_CBC_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.12.1.3");
+
+/// Triple DES block size
+#[cfg(all(feature = "alloc", feature = "legacy"))]
+const DES_BLOCK_SIZE: usize = 8;
+
+/// PKCS#12 password-based encryption algorithm as defined in [RFC 7292 Appendix C].
+///
+/// ```text
+/// pkcs-12PbeParams ::= SEQUENCE {
+///     salt        OCTET STRING,
+///     iterations  INTEGER
+/// }
+/// ```
+///
+/// The parameters have the same structure as the PBES1 `PBEParameter`, and
+/// only the 8-byte salts and iteration counts up to 65535 used in practice
+/// are supported.
+///
+/// Encryption and decryption are available with the `legacy` feature.
+/// Passwords must be valid UTF-8, as they are converted to a `BMPString` for
+/// the key derivation.
+///
+/// ⚠️ WARNING: These schemes are weak and only supported for interoperating
+/// with legacy software. Use PBES2 for new data.
+///
+/// [RFC 7292 Appendix C]: https://datatracker.ietf.org/doc/html/rfc7292#appendix-C
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Algorithm {
+    /// Encryption scheme.
+    pub encryption: EncryptionScheme,
+
+    /// Scheme parameters.
+    pub parameters: Parameters,
+}
+
+impl Algorithm {
+    /// Get the [`ObjectIdentifier`] (a.k.a OID) for this algorithm.
+    pub fn oid(&self) -> ObjectIdentifier {
+        self.encryption.oid()
+    }
+
+    /// Attempt to decrypt the given ciphertext, allocating and returning a
+    /// byte vector containing the plaintext.
+    #[cfg(all(feature = "alloc", feature = "legacy"))]
+    pub fn decrypt(&self, password: impl AsRef<[u8]>, ciphertext: &[u8]) -> Result<Vec<u8>> {
+        let mut buffer = ciphertext.to_vec();
+        let pt_len = self.decrypt_in_place(password, &mut buffer)?.len();
+        buffer.truncate(pt_len);
+        Ok(buffer)
+    }
+
+    /// Attempt to decrypt the given ciphertext in-place using a key derived
+    /// from the provided password and this algorithm's parameters.
+    ///
+    /// Returns an error if the password isn't valid UTF-8, or if the
+    /// ciphertext is malformed (e.g. not a multiple of a block mode's padding)
+    #[cfg(feature = "legacy")]
+    pub fn decrypt_in_place<'b>(
+        &self,
+        password: impl AsRef<[u8]>,
+        buffer: &'b mut [u8],
+    ) -> Result<&'b [u8]> {
+        encryption::decrypt_in_place(self, password.as_ref(), buffer)
+    }
+
+    /// Encrypt the given plaintext, allocating and returning a vector
+    /// containing the ciphertext.
+    #[cfg(all(feature = "alloc", feature = "legacy"))]
+    pub fn encrypt(&self, password: impl AsRef<[u8]>, plaintext: &[u8]) -> Result<Vec<u8>> {
+        let mut buffer = Vec::with_capacity(plaintext.len() + DES_BLOCK_SIZE);
+        buffer.extend_from_slice(plaintext);
+        buffer.extend_from_slice(&[0u8; DES_BLOCK_SIZE]);
+
+        let ct_len = self
+            .encrypt_in_place(password, &mut buffer, plaintext.len())?
+            .len();
+
+        buffer.truncate(ct_len);
+        Ok(buffer)
+    }
+
+    /// Encrypt the given plaintext in-place using a key derived from the
+    /// provided password and this algorithm's parameters, writing the
+    /// ciphertext into the same buffer.
+    #[cfg(feature = "legacy")]
+    pub fn encrypt_in_place<'b>(
+        &self,
+        password: impl AsRef<[u8]>,
+        buffer: &'b mut [u8],
+        pos: usize,
+    ) -> Result<&'b [u8]> {
+        encryption::encrypt_in_place(self, password.as_ref(), buffer, pos)
+    }
+}
+
+impl<'a> DecodeValue<'a> for Algorithm {
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: der::Header) -> der::Result<Self> {
+        AlgorithmIdentifierRef::decode_value(reader, header)?.try_into()
+    }
+}
+
+impl EncodeValue for Algorithm {
+    fn value_len(&self) -> der::Result<Length> {
+        self.encryption.encoded_len()? + self.parameters.encoded_len()?
+    }
+
+    fn encode_value(&self, writer: &mut impl Writer) -> der::Result<()> {
+        self.encryption.encode(writer)?;
+        self.parameters.encode(writer)?;
+        Ok(())
+    }
+}
+
+impl Sequence<'_> for Algorithm {}
+
+impl<'a> TryFrom<AlgorithmIdentifierRef<'a>> for Algorithm {
+    type Error = der::Error;
+
+    fn try_from(alg: AlgorithmIdentifierRef<'a>) -> der::Result<Self> {
+        let encryption = EncryptionScheme::try_from(alg.oid)?;
+
+        let parameters = alg
+            .parameters
+            .ok_or_else(|| Tag::OctetString.value_error())?
+            .try_into()?;
+
+        Ok(Self {
+            encryption,
+            parameters,
+        })
+    }
+}
+
+/// PKCS#12 password-based encryption schemes ([RFC 7292 Appendix C]).
+///
+/// [RFC 7292 Appendix C]: https://datatracker.ietf.org/doc/html/rfc7292#appendix-C
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum EncryptionScheme {
+    /// `pbeWithSHAAnd3-KeyTripleDES-CBC`: three-key Triple DES (DES-EDE3) in
+    /// CBC mode with a key and IV derived using SHA-1
+    PbeWithShaAnd3KeyTripleDesCbc,
+}
+
+impl EncryptionScheme {
+    /// Get the [`ObjectIdentifier`] (a.k.a OID) for this algorithm.
+    pub fn oid(self) -> ObjectIdentifier {
+        match self {
+            Self::PbeWithShaAnd3KeyTripleDesCbc => PBE_WITH_SHA_AND_3_KEY_TRIPLE_DES_CBC_OID,
+        }
+    }
+}
+
+impl TryFrom<ObjectIdentifier> for EncryptionScheme {
+    type Error = der::Error;
+
+    fn try_from(oid: ObjectIdentifier) -> der::Result<Self> {
+        match oid {
+            PBE_WITH_SHA_AND_3_KEY_TRIPLE_DES_CBC_OID => Ok(Self::PbeWithShaAnd3KeyTripleDesCbc),
+            _ => Err(ErrorKind::OidUnknown { oid }.into()),
+        }
+    }
+}
+
+impl Encode for EncryptionScheme {
+    fn encoded_len(&self) -> der::Result<Length> {
+        self.oid().encoded_len()
+    }
+
+    fn encode(&self, writer: &mut impl Writer) -> der::Result<()> {
+        self.oid().encode(writer)
+    }
+}
@@ -0,0 +1,132 @@
+//! PKCS#12 password-based encryption.
+
+use super::Algorithm;
+use crate::{pbes1::Parameters, Error, Result};
+use alloc::vec::Vec;
+use cbc::cipher::{block_padding::Pkcs7, BlockDecryptMut, BlockEncryptMut, KeyIvInit};
+use sha1::{Digest, Sha1};
+
+/// Triple DES block size, which is also the IV size
+const DES_BLOCK_SIZE: usize = 8;
+
+/// Three-key Triple DES key size
+const DES_EDE3_KEY_SIZE: usize = 24;
+
+/// SHA-1 block size, `v` in [RFC 7292 Appendix B.2]
+///
+/// [RFC 7292 Appendix B.2]: https://datatracker.ietf.org/doc/html/rfc7292#appendix-B.2
+const SHA1_BLOCK_SIZE: usize = 64;
+
+/// PKCS#12 key derivation purposes ([RFC 7292 Appendix B.3])
+///
+/// [RFC 7292 Appendix B.3]: https://datatracker.ietf.org/doc/html/rfc7292#appendix-B.3
+const PKCS12_KEY_ID: u8 = 1;
+const PKCS12_IV_ID: u8 = 2;
+
+pub fn encrypt_in_place<'b>(
+    alg: &Algorithm,
+    password: &[u8],
+    buf: &'b mut [u8],
+    pos: usize,
+) -> Result<&'b [u8]> {
+    let password = bmp_password(password).ok_or(Error::EncryptFailed)?;
+    let (key, iv) = derive_key_and_iv(alg, &password)?;
+    cbc::Encryptor::<des::TdesEde3>::new(&key.into(), &iv.into())
+        .encrypt_padded_mut::<Pkcs7>(buf, pos)
+        .map_err(|_| Error::EncryptFailed)
+}
+
+pub fn decrypt_in_place<'b>(
+    alg: &Algorithm,
+    password: &[u8],
+    buf: &'b mut [u8],
+) -> Result<&'b [u8]> {
+    let password = bmp_password(password).ok_or(Error::DecryptFailed)?;
+    let (key, iv) = derive_key_and_iv(alg, &password)?;
+    cbc::Decryptor::<des::TdesEde3>::new(&key.into(), &iv.into())
+        .decrypt_padded_mut::<Pkcs7>(buf)
+        .map_err(|_| Error::DecryptFailed)
+}
+
+/// Convert a UTF-8 password to a `BMPString` with a trailing NUL character,
+/// as the PKCS#12 key derivation function expects ([RFC 7292 Appendix B.1]).
+///
+/// Returns `None` if the password isn't valid UTF-8.
+///
+/// [RFC 7292 Appendix B.1]: https://datatracker.ietf.org/doc/html/rfc7292#appendix-B.1
+fn bmp_password(password: &[u8]) -> Option<Vec<u8>> {
+    let password = core::str::from_utf8(password).ok()?;
+    Some(
+        password
+            .encode_utf16()
+            .chain([0])
+            .flat_map(u16::to_be_bytes)
+            .collect(),
+    )
+}
+
+/// Derive the Triple DES key and IV from the `BMPString` password using the
+/// PKCS#12 key derivation function with SHA-1 ([RFC 7292 Appendix B]).
+///
+/// [RFC 7292 Appendix B]: https://datatracker.ietf.org/doc/html/rfc7292#appendix-B
+fn derive_key_and_iv(
+    alg: &Algorithm,
+    bmp_password: &[u8],
+) -> Result<([u8; DES_EDE3_KEY_SIZE], [u8; DES_BLOCK_SIZE])> {
+    if alg.parameters.iteration_count == 0 {
+        return Err(Error::AlgorithmParametersInvalid { oid: alg.oid() });
+    }
+
+    let mut key = [0u8; DES_EDE3_KEY_SIZE];
+    let mut iv = [0u8; DES_BLOCK_SIZE];
+    pkcs12_kdf_sha1(bmp_password, &alg.parameters, PKCS12_KEY_ID, &mut key);
+    pkcs12_kdf_sha1(bmp_password, &alg.parameters, PKCS12_IV_ID, &mut iv);
+    Ok((key, iv))
+}
+
+/// PKCS#12 key derivation function with SHA-1 ([RFC 7292 Appendix B.2]),
+/// filling `out` with key material for the purpose `id`.
+///
+/// [RFC 7292 Appendix B.2]: https://datatracker.ietf.org/doc/html/rfc7292#appendix-B.2
+fn pkcs12_kdf_sha1(bmp_password: &[u8], params: &Parameters, id: u8, out: &mut [u8]) {
+    let diversifier = [id; SHA1_BLOCK_SIZE];
+
+    // `I = S || P`, with salt and password each repeated to a multiple of
+    // the block size
+    let mut input = Vec::new();
+    for data in [params.salt.as_slice(), bmp_password] {
+        let len = (data.len() + SHA1_BLOCK_SIZE - 1) / SHA1_BLOCK_SIZE * SHA1_BLOCK_SIZE;
+        input.extend(data.iter().cycle().take(len));
+    }
+
+    for chunk in out.chunks_mut(<Sha1 as Digest>::output_size()) {
+        let mut a = Sha1::new()
+            .chain_update(diversifier)
+            .chain_update(&input)
+            .finalize();
+
+        for _ in 1..params.iteration_count {
+            a = Sha1::digest(a);
+        }
+
+        chunk.copy_from_slice(&a[..chunk.len()]);
+
+        // `I_j = (I_j + B + 1) mod 2^(8v)`, where `B` is `A` repeated to the
+        // block size
+        let b = a
+            .iter()
+            .cycle()
+            .take(SHA1_BLOCK_SIZE)
+            .copied()
+            .collect::<Vec<u8>>();
+
+        for block in input.chunks_exact_mut(SHA1_BLOCK_SIZE) {
+            let mut carry = 1u16;
+            for (x, y) in block.iter_mut().zip(&b).rev() {
+                carry += u16::from(*x) + u16::from(*y);
+                *x = carry as u8;
+                carry >>= 8;
+            }
+        }
+    }
+}
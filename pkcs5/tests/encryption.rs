@@ -53,6 +53,40 @@ const ED25519_PKCS8_KEY_CIPHERTEXT_SCRYPT: &[u8] = &hex!(
      065EEB366D6E6C98CC3B0E7E69BDC861C88AFEB8F03DBA1E2C6D99D06D17360C"
 );
 
+/// PBES2 + PBKDF2-SHA256 + AES-256-GCM `AlgorithmIdentifier` example
+/// with a 16-byte authentication tag.
+const PBES2_PBKDF2_SHA256_AES256GCM_ALG_ID: &[u8] = &hex!(
+    "305806092a864886f70d01050d304b302906092a864886f70d01050c301c0408
+     79d982e70df91a8802020800300c06082a864886f70d02090500301e06096086
+     4801650304012e3011040cb2d02d78b2efd9dff694cf8e020110"
+);
+
+/// PBES2 + PBKDF2-SHA256 + AES-128-GCM `AlgorithmIdentifier` example
+/// with the default 12-byte authentication tag.
+const PBES2_PBKDF2_SHA256_AES128GCM_ALG_ID: &[u8] = &hex!(
+    "305506092a864886f70d01050d3048302906092a864886f70d01050c301c0408
+     79d982e70df91a8802020800300c06082a864886f70d02090500301b06096086
+     48016503040106300e040cb2d02d78b2efd9dff694cf8e"
+);
+
+/// Ciphertext and authentication tag of Ed25519 PKCS#8 private key when
+/// encrypted using AES-256-GCM.
+///
+/// Generated with Python's `cryptography` package (`AESGCM`).
+const ED25519_PKCS8_KEY_CIPHERTEXT_AES256GCM: &[u8] = &hex!(
+    "DC95A61BC03E10E1865D750036DCF2D287EB0390E5BD234979FCBB01271830C0
+     585BC98D993C20957687AE7E8A267F94AFF98338AF30F4908B0F714A94E09A11"
+);
+
+/// Ciphertext and truncated authentication tag of Ed25519 PKCS#8 private
+/// key when encrypted using AES-128-GCM.
+///
+/// Generated with Python's `cryptography` package (`AESGCM`).
+const ED25519_PKCS8_KEY_CIPHERTEXT_AES128GCM: &[u8] = &hex!(
+    "3E0DC329AC5B06976DE13282429BD11BB4B7E525648D21042711084FAD57F779
+     27DDD619C2AA7174054107A2D8B0E2EBA0249A2B66E4DAF351D5DEA1"
+);
+
 /// PBES2 + DES-EDE3-CBC + PBKDF-SHA2 `AlgorithmIdentifier` example.
 ///
 /// Generated by OpenSSL and extracted from the `pkcs8` crate's
@@ -91,6 +125,44 @@ const ED25519_PKCS8_KEY_CIPHERTEXT_DESCBC: &[u8] = &hex!(
     93E4E3893840181FBC63D75297B416A0B96CB7F9AB45CEABA"
 );
 
+/// PBES2 + PBKDF2-SHA256 + RC2-40-CBC `AlgorithmIdentifier` example.
+///
+/// Generated by OpenSSL using:
+/// $ openssl pkcs8 -topk8 -v2 rc2-40-cbc -v2prf hmacWithSHA256 -provider legacy -provider default
+#[cfg(feature = "rc2-insecure")]
+const PBES2_PBKDF2_SHA256_RC2_40_CBC_ALG_ID: &[u8] = &hex!(
+    "305706092a864886f70d01050d304a302c06092a864886f70d01050c301f0408
+     e876fadc0147a35d02020800020105300c06082a864886f70d02090500301a06
+     082a864886f70d0302300e020200a00408c7f8b67355473297"
+);
+
+/// Ciphertext of Ed25519 PKCS#8 private key when encrypted using
+/// PBKDF2-SHA256 and RC2-40-CBC.
+#[cfg(feature = "rc2-insecure")]
+const ED25519_PKCS8_KEY_CIPHERTEXT_RC2_40_CBC: &[u8] = &hex!(
+    "5D23665DEA0230DEA5D60E379B535B2F0A0EC32641532B6188E0BDE26FF68154
+     6E25F40AF1E210821EB66570F44F8BAE16B52077A9049925"
+);
+
+/// PBES2 + PBKDF2-SHA256 + RC2-128-CBC `AlgorithmIdentifier` example.
+///
+/// Generated by OpenSSL using:
+/// $ openssl pkcs8 -topk8 -v2 rc2-cbc -v2prf hmacWithSHA256 -provider legacy -provider default
+#[cfg(feature = "rc2-insecure")]
+const PBES2_PBKDF2_SHA256_RC2_128_CBC_ALG_ID: &[u8] = &hex!(
+    "305606092a864886f70d01050d3049302c06092a864886f70d01050c301f0408
+     b2b9786e944be6bd02020800020110300c06082a864886f70d02090500301906
+     082a864886f70d0302300d02013a040855f84b5e1c69ad41"
+);
+
+/// Ciphertext of Ed25519 PKCS#8 private key when encrypted using
+/// PBKDF2-SHA256 and RC2-128-CBC.
+#[cfg(feature = "rc2-insecure")]
+const ED25519_PKCS8_KEY_CIPHERTEXT_RC2_128_CBC: &[u8] = &hex!(
+    "7048DFFFCC067CEC3F0EAFCB8390BB08C20EDE56DE054FB427022295E52B7919
+     F8F608705F2F8C4FCFC8E445CDE3F751502A77AAC8522528"
+);
+
 /// Password used to encrypt the keys.
 const PASSWORD: &[u8] = b"hunter42"; // Bad password; don't actually use outside tests!
 
@@ -127,3 +199,209 @@ fn decrypt_pbes2_pbkdf2_sha256_descbc() {
     let plaintext = scheme.decrypt_in_place(PASSWORD, &mut buffer).unwrap();
     assert_eq!(plaintext, ED25519_PKCS8_KEY_PLAINTEXT);
 }
+
+#[test]
+#[cfg(feature = "rc2-insecure")]
+fn decrypt_pbes2_pbkdf2_sha256_rc2cbc() {
+    for (alg_id, ciphertext) in [
+        (
+            PBES2_PBKDF2_SHA256_RC2_40_CBC_ALG_ID,
+            ED25519_PKCS8_KEY_CIPHERTEXT_RC2_40_CBC,
+        ),
+        (
+            PBES2_PBKDF2_SHA256_RC2_128_CBC_ALG_ID,
+            ED25519_PKCS8_KEY_CIPHERTEXT_RC2_128_CBC,
+        ),
+    ] {
+        let scheme = pkcs5::EncryptionScheme::try_from(alg_id).unwrap();
+        let mut buffer = Vec::from(ciphertext);
+        let plaintext = scheme.decrypt_in_place(PASSWORD, &mut buffer).unwrap();
+        assert_eq!(plaintext, ED25519_PKCS8_KEY_PLAINTEXT);
+
+        let plaintext = decrypt_chunked(scheme.pbes2().unwrap(), ciphertext, 3).unwrap();
+        assert_eq!(plaintext, ED25519_PKCS8_KEY_PLAINTEXT);
+
+        // RC2 is decrypt-only
+        let mut buffer = [0u8; 64];
+        buffer[..ED25519_PKCS8_KEY_PLAINTEXT.len()].copy_from_slice(ED25519_PKCS8_KEY_PLAINTEXT);
+        assert!(matches!(
+            scheme.encrypt_in_place(PASSWORD, &mut buffer, ED25519_PKCS8_KEY_PLAINTEXT.len()),
+            Err(pkcs5::Error::UnsupportedAlgorithm { .. })
+        ));
+    }
+}
+
+#[test]
+fn decrypt_pbes2_pbkdf2_sha256_aes256gcm() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBES2_PBKDF2_SHA256_AES256GCM_ALG_ID).unwrap();
+    let mut buffer = Vec::from(ED25519_PKCS8_KEY_CIPHERTEXT_AES256GCM);
+    let plaintext = scheme.decrypt_in_place(PASSWORD, &mut buffer).unwrap();
+    assert_eq!(plaintext, ED25519_PKCS8_KEY_PLAINTEXT);
+}
+
+#[test]
+fn decrypt_pbes2_pbkdf2_sha256_aes128gcm() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBES2_PBKDF2_SHA256_AES128GCM_ALG_ID).unwrap();
+    let mut buffer = Vec::from(ED25519_PKCS8_KEY_CIPHERTEXT_AES128GCM);
+    let plaintext = scheme.decrypt_in_place(PASSWORD, &mut buffer).unwrap();
+    assert_eq!(plaintext, ED25519_PKCS8_KEY_PLAINTEXT);
+}
+
+#[test]
+fn decrypt_pbes2_aesgcm_tampered() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBES2_PBKDF2_SHA256_AES256GCM_ALG_ID).unwrap();
+
+    let mut buffer = Vec::from(ED25519_PKCS8_KEY_CIPHERTEXT_AES256GCM);
+    buffer[0] ^= 1;
+    assert_eq!(
+        scheme.decrypt_in_place(PASSWORD, &mut buffer),
+        Err(pkcs5::Error::DecryptFailed)
+    );
+
+    let mut buffer = Vec::from(ED25519_PKCS8_KEY_CIPHERTEXT_AES256GCM);
+    assert_eq!(
+        scheme.decrypt_in_place(b"hunter2", &mut buffer),
+        Err(pkcs5::Error::DecryptFailed)
+    );
+
+    let mut buffer = [0u8; 15];
+    assert_eq!(
+        scheme.decrypt_in_place(PASSWORD, &mut buffer),
+        Err(pkcs5::Error::DecryptFailed)
+    );
+}
+
+#[test]
+fn encrypt_pbes2_pbkdf2_sha256_aes256gcm() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBES2_PBKDF2_SHA256_AES256GCM_ALG_ID).unwrap();
+
+    let mut buffer = [0u8; 64];
+    buffer[..ED25519_PKCS8_KEY_PLAINTEXT.len()].copy_from_slice(ED25519_PKCS8_KEY_PLAINTEXT);
+    let ciphertext = scheme
+        .encrypt_in_place(PASSWORD, &mut buffer, ED25519_PKCS8_KEY_PLAINTEXT.len())
+        .unwrap();
+    assert_eq!(ciphertext, ED25519_PKCS8_KEY_CIPHERTEXT_AES256GCM);
+
+    // Not enough room for the authentication tag
+    let mut buffer = [0u8; 60];
+    assert_eq!(
+        scheme.encrypt_in_place(PASSWORD, &mut buffer, ED25519_PKCS8_KEY_PLAINTEXT.len()),
+        Err(pkcs5::Error::EncryptFailed)
+    );
+}
+
+#[test]
+fn pbes2_aesgcm_invalid_icv_len() {
+    let nonce = [0u8; 12];
+    for icv_len in [0, 11, 17] {
+        let mut params =
+            pkcs5::pbes2::Parameters::pbkdf2_sha256_aes256gcm(1, b"salt", &nonce).unwrap();
+        params.encryption = pkcs5::pbes2::EncryptionScheme::Aes256Gcm {
+            nonce: &nonce,
+            icv_len,
+        };
+        let err = pkcs5::Error::AlgorithmParametersInvalid {
+            oid: pkcs5::pbes2::AES_256_GCM_OID,
+        };
+
+        let mut buffer = [0u8; 64];
+        assert_eq!(params.encrypt_in_place(PASSWORD, &mut buffer, 32), Err(err));
+        assert_eq!(params.decrypt_in_place(PASSWORD, &mut buffer), Err(err));
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn encrypt_pbes2_pbkdf2_sha256_aes128gcm() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBES2_PBKDF2_SHA256_AES128GCM_ALG_ID).unwrap();
+    let ciphertext = scheme
+        .encrypt(PASSWORD, ED25519_PKCS8_KEY_PLAINTEXT)
+        .unwrap();
+    assert_eq!(ciphertext, ED25519_PKCS8_KEY_CIPHERTEXT_AES128GCM);
+}
+
+/// Decrypt `ciphertext` with a streaming decryptor, in chunks of `chunk_size`.
+fn decrypt_chunked(
+    params: &pkcs5::pbes2::Parameters<'_>,
+    ciphertext: &[u8],
+    chunk_size: usize,
+) -> pkcs5::Result<Vec<u8>> {
+    let mut decryptor = params.decryptor(PASSWORD)?;
+    let mut plaintext = Vec::new();
+    let mut out = [0u8; 32];
+
+    for chunk in ciphertext.chunks(chunk_size) {
+        plaintext.extend_from_slice(decryptor.update(chunk, &mut out)?);
+    }
+
+    plaintext.extend_from_slice(decryptor.finalize(&mut out)?);
+    Ok(plaintext)
+}
+
+#[test]
+fn decrypt_pbes2_pbkdf2_sha256_aes256cbc_chunked() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBES2_PBKDF2_SHA256_AES256CBC_ALG_ID).unwrap();
+    let params = scheme.pbes2().unwrap();
+
+    for chunk_size in [1, 7, 15, 16, 17] {
+        let plaintext = decrypt_chunked(
+            params,
+            ED25519_PKCS8_KEY_CIPHERTEXT_PBKDF2_SHA256,
+            chunk_size,
+        )
+        .unwrap();
+        assert_eq!(plaintext, ED25519_PKCS8_KEY_PLAINTEXT);
+    }
+}
+
+#[test]
+#[cfg(feature = "3des")]
+fn decrypt_pbes2_pbkdf2_sha256_desede3cbc_chunked() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBES2_PBKDF2_SHA256_DESEDE3CBC_ALG_ID).unwrap();
+    let params = scheme.pbes2().unwrap();
+
+    for chunk_size in [1, 5, 8, 9, 24] {
+        let plaintext =
+            decrypt_chunked(params, ED25519_PKCS8_KEY_CIPHERTEXT_DESEDE3CBC, chunk_size).unwrap();
+        assert_eq!(plaintext, ED25519_PKCS8_KEY_PLAINTEXT);
+    }
+}
+
+#[test]
+fn decrypt_pbes2_chunked_errors() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBES2_PBKDF2_SHA256_AES256CBC_ALG_ID).unwrap();
+    let params = scheme.pbes2().unwrap();
+    let ciphertext = ED25519_PKCS8_KEY_CIPHERTEXT_PBKDF2_SHA256;
+
+    // Truncated ciphertext
+    assert_eq!(
+        decrypt_chunked(params, &ciphertext[..ciphertext.len() - 1], 16),
+        Err(pkcs5::Error::DecryptFailed)
+    );
+    assert_eq!(
+        decrypt_chunked(params, &[], 16),
+        Err(pkcs5::Error::DecryptFailed)
+    );
+
+    // Bad padding
+    let mut tampered = Vec::from(ciphertext);
+    tampered[ciphertext.len() - 17] ^= 1;
+    assert_eq!(
+        decrypt_chunked(params, &tampered, 16),
+        Err(pkcs5::Error::DecryptFailed)
+    );
+
+    // Output buffer too small
+    let mut decryptor = params.decryptor(PASSWORD).unwrap();
+    assert_eq!(
+        decryptor.update(ciphertext, &mut [0u8; 32]),
+        Err(pkcs5::Error::DecryptFailed)
+    );
+
+    // AES-GCM can't be decrypted incrementally
+    let scheme = pkcs5::EncryptionScheme::try_from(PBES2_PBKDF2_SHA256_AES256GCM_ALG_ID).unwrap();
+    assert!(matches!(
+        scheme.pbes2().unwrap().decryptor(PASSWORD),
+        Err(pkcs5::Error::UnsupportedAlgorithm { .. })
+    ));
+}
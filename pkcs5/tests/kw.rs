@@ -0,0 +1,212 @@
+//! AES key wrap tests
+
+use der::Encode;
+use hex_literal::hex;
+use pkcs5::{kw, EncryptionScheme};
+
+/// `id-aes256-wrap-pad` `AlgorithmIdentifier` example.
+const AES256_WRAP_PAD_ALG_ID: &[u8] = &hex!("300b0609608648016503040130");
+
+/// Plaintext of Ed25519 PKCS#8 private key.
+///
+/// This is the hex-encoded contents of `ed25519-priv.der` from
+/// `pkcs8/tests/examples`.
+#[cfg(feature = "aes-kw")]
+const ED25519_PKCS8_KEY_PLAINTEXT: &[u8] = &hex!(
+    "302e020100300506032b65700422042017ed9c73e9db649ec189a612831c5fc5
+     70238207c1aa9dfbd2c53e3ff5e5ea85"
+);
+
+/// Ciphertext of Ed25519 PKCS#8 private key when wrapped using
+/// `id-aes256-wrap-pad` with the KEK `000102..1f`.
+///
+/// Generated with Python's `cryptography.hazmat.primitives.keywrap`.
+#[cfg(feature = "aes-kw")]
+const ED25519_PKCS8_KEY_CIPHERTEXT_AES256_WRAP_PAD: &[u8] = &hex!(
+    "8a19e637cf948e7dc00d4edf914e732ca02dd57cd896fa865d91fbb038747553
+     01591381b76566f1a04d73bfbf0d940bac889e2aece4be49"
+);
+
+#[test]
+fn decode_aes256_wrap_pad() {
+    let scheme = EncryptionScheme::try_from(AES256_WRAP_PAD_ALG_ID).unwrap();
+    assert_eq!(
+        scheme,
+        EncryptionScheme::KeyWrap(kw::Algorithm::Aes256WrapPad)
+    );
+    assert_eq!(scheme.oid(), kw::AES_256_WRAP_PAD_OID);
+    assert_eq!(scheme.key_wrap(), Some(kw::Algorithm::Aes256WrapPad));
+    assert!(scheme.pbes2().is_none());
+}
+
+#[test]
+fn decode_aes_wrap_with_parameters() {
+    // `id-aes128-wrap` with NULL parameters
+    let alg_id = hex!("300d06096086480165030401050500");
+    assert!(EncryptionScheme::try_from(&alg_id[..]).is_err());
+}
+
+#[test]
+fn encode_aes256_wrap_pad() {
+    let scheme = EncryptionScheme::from(kw::Algorithm::Aes256WrapPad);
+    let mut buffer = [0u8; 13];
+    let encoded = scheme.encode_to_slice(&mut buffer).unwrap();
+    assert_eq!(encoded, AES256_WRAP_PAD_ALG_ID);
+}
+
+#[test]
+fn kw_algorithm_properties() {
+    assert_eq!(kw::Algorithm::Aes128Wrap.key_size(), 16);
+    assert_eq!(kw::Algorithm::Aes192WrapPad.key_size(), 24);
+    assert_eq!(kw::Algorithm::Aes256Wrap.key_size(), 32);
+    assert!(!kw::Algorithm::Aes128Wrap.is_padded());
+    assert!(kw::Algorithm::Aes128WrapPad.is_padded());
+    assert_eq!(
+        kw::Algorithm::try_from(kw::AES_192_WRAP_OID).unwrap(),
+        kw::Algorithm::Aes192Wrap
+    );
+}
+
+/// RFC 3394 Section 4.1: wrap 128 bits of key data with a 128-bit KEK
+#[cfg(all(feature = "aes-kw", feature = "alloc"))]
+#[test]
+fn aes128_wrap_rfc3394_vector() {
+    let kek = hex!("000102030405060708090A0B0C0D0E0F");
+    let key = hex!("00112233445566778899AABBCCDDEEFF");
+    let wrapped = hex!("1FA68B0A8112B447AEF34BD8FB5A7B829D3E862371D2CFE5");
+
+    let alg = kw::Algorithm::Aes128Wrap;
+    assert_eq!(alg.wrap(&kek, &key).unwrap(), wrapped);
+    assert_eq!(alg.unwrap(&kek, &wrapped).unwrap(), key);
+}
+
+/// RFC 3394 Section 4.6: wrap 256 bits of key data with a 256-bit KEK
+#[cfg(all(feature = "aes-kw", feature = "alloc"))]
+#[test]
+fn aes256_wrap_rfc3394_vector() {
+    let kek = hex!("000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F");
+    let key = hex!("00112233445566778899AABBCCDDEEFF000102030405060708090A0B0C0D0E0F");
+    let wrapped = hex!(
+        "28C9F404C4B810F4CBCCB35CFB87F8263F5786E2D80ED326
+         CBC7F0E71A99F43BFB988B9B7A02DD21"
+    );
+
+    let alg = kw::Algorithm::Aes256Wrap;
+    assert_eq!(alg.wrap(&kek, &key).unwrap(), wrapped);
+    assert_eq!(alg.unwrap(&kek, &wrapped).unwrap(), key);
+}
+
+/// RFC 5649 Section 6: wrap 20 octets and 7 octets with a 192-bit KEK
+#[cfg(all(feature = "aes-kw", feature = "alloc"))]
+#[test]
+fn aes192_wrap_pad_rfc5649_vectors() {
+    let kek = hex!("5840df6e29b02af1ab493b705bf16ea1ae8338f4dcc176a8");
+    let alg = kw::Algorithm::Aes192WrapPad;
+
+    let key = hex!("c37b7e6492584340bed12207808941155068f738");
+    let wrapped = hex!("138bdeaa9b8fa7fc61f97742e72248ee5ae6ae5360d1ae6a5f54f373fa543b6a");
+    assert_eq!(alg.wrap(&kek, &key).unwrap(), wrapped);
+    assert_eq!(alg.unwrap(&kek, &wrapped).unwrap(), key);
+
+    let key = hex!("466f7250617369");
+    let wrapped = hex!("afbeb0f07dfbf5419200f2ccb50bb24f");
+    assert_eq!(alg.wrap(&kek, &key).unwrap(), wrapped);
+    assert_eq!(alg.unwrap(&kek, &wrapped).unwrap(), key);
+}
+
+#[cfg(feature = "aes-kw")]
+#[test]
+fn aes256_wrap_pad_to_slice() {
+    let kek: [u8; 32] = core::array::from_fn(|i| i as u8);
+    let alg = kw::Algorithm::Aes256WrapPad;
+
+    let mut buffer = [0u8; 64];
+    let ciphertext = alg
+        .wrap_to_slice(&kek, ED25519_PKCS8_KEY_PLAINTEXT, &mut buffer)
+        .unwrap();
+    assert_eq!(ciphertext, ED25519_PKCS8_KEY_CIPHERTEXT_AES256_WRAP_PAD);
+
+    let mut buffer = [0u8; 64];
+    let plaintext = alg
+        .unwrap_to_slice(
+            &kek,
+            ED25519_PKCS8_KEY_CIPHERTEXT_AES256_WRAP_PAD,
+            &mut buffer,
+        )
+        .unwrap();
+    assert_eq!(plaintext, ED25519_PKCS8_KEY_PLAINTEXT);
+
+    // Buffer too small for the ciphertext
+    let mut buffer = [0u8; 55];
+    assert_eq!(
+        alg.wrap_to_slice(&kek, ED25519_PKCS8_KEY_PLAINTEXT, &mut buffer),
+        Err(pkcs5::Error::EncryptFailed)
+    );
+}
+
+#[cfg(feature = "aes-kw")]
+#[test]
+fn aes_wrap_errors() {
+    let kek: [u8; 32] = core::array::from_fn(|i| i as u8);
+    let ciphertext = ED25519_PKCS8_KEY_CIPHERTEXT_AES256_WRAP_PAD;
+    let mut buffer = [0u8; 64];
+
+    // Wrong KEK, which leaves no unauthenticated plaintext in the buffer
+    let mut wrong_kek = kek;
+    wrong_kek[0] ^= 1;
+    assert_eq!(
+        kw::Algorithm::Aes256WrapPad.unwrap_to_slice(&wrong_kek, ciphertext, &mut buffer),
+        Err(pkcs5::Error::DecryptFailed)
+    );
+    assert!(buffer.iter().all(|&b| b == 0));
+
+    // Invalid KEK length
+    assert_eq!(
+        kw::Algorithm::Aes128WrapPad.unwrap_to_slice(&kek, ciphertext, &mut buffer),
+        Err(pkcs5::Error::AlgorithmParametersInvalid {
+            oid: kw::AES_128_WRAP_PAD_OID
+        })
+    );
+
+    // Padded ciphertext with the unpadded algorithm
+    assert_eq!(
+        kw::Algorithm::Aes256Wrap.unwrap_to_slice(&kek, ciphertext, &mut buffer),
+        Err(pkcs5::Error::DecryptFailed)
+    );
+
+    // Truncated ciphertext
+    assert_eq!(
+        kw::Algorithm::Aes256WrapPad.unwrap_to_slice(&kek, &ciphertext[..52], &mut buffer),
+        Err(pkcs5::Error::DecryptFailed)
+    );
+
+    // Ciphertext shorter than two semiblocks
+    assert_eq!(
+        kw::Algorithm::Aes256WrapPad.unwrap_to_slice(&kek, &ciphertext[..8], &mut buffer),
+        Err(pkcs5::Error::DecryptFailed)
+    );
+
+    // Unpadded key wrap requires a multiple of 8 bytes
+    assert_eq!(
+        kw::Algorithm::Aes256Wrap.wrap_to_slice(&kek, &[0u8; 20], &mut buffer),
+        Err(pkcs5::Error::EncryptFailed)
+    );
+
+    // Padded key wrap requires at least one byte
+    assert_eq!(
+        kw::Algorithm::Aes256WrapPad.wrap_to_slice(&kek, &[], &mut buffer),
+        Err(pkcs5::Error::EncryptFailed)
+    );
+}
+
+#[cfg(all(feature = "aes-kw", feature = "pbes2", feature = "alloc"))]
+#[test]
+fn key_wrap_password_unsupported() {
+    let scheme = EncryptionScheme::try_from(AES256_WRAP_PAD_ALG_ID).unwrap();
+    assert_eq!(
+        scheme.decrypt(b"hunter42", ED25519_PKCS8_KEY_CIPHERTEXT_AES256_WRAP_PAD),
+        Err(pkcs5::Error::UnsupportedAlgorithm {
+            oid: kw::AES_256_WRAP_PAD_OID
+        })
+    );
+}
@@ -0,0 +1,158 @@
+//! Password-Based Encryption Scheme 1 tests
+
+#![cfg(feature = "legacy")]
+
+use hex_literal::hex;
+use pkcs5::pbes1;
+
+/// PBES1 + `pbeWithSHA1AndDES-CBC` `AlgorithmIdentifier` example.
+///
+/// Generated by OpenSSL using:
+/// $ openssl pkcs8 -topk8 -v1 PBE-SHA1-DES -provider legacy -provider default
+const PBES1_SHA1_DES_CBC_ALG_ID: &[u8] =
+    &hex!("301b06092a864886f70d01050a300e040823df43b0f18a920102020800");
+
+/// Plaintext of Ed25519 PKCS#8 private key.
+///
+/// This is the hex-encoded contents of `ed25519-priv.der` from
+/// `pkcs8/tests/examples`.
+const ED25519_PKCS8_KEY_PLAINTEXT: &[u8] = &hex!(
+    "302e020100300506032b65700422042017ed9c73e9db649ec189a612831c5fc5
+     70238207c1aa9dfbd2c53e3ff5e5ea85"
+);
+
+/// Ciphertext of Ed25519 PKCS#8 private key when encrypted using
+/// `pbeWithSHA1AndDES-CBC`.
+const ED25519_PKCS8_KEY_CIPHERTEXT_SHA1_DES_CBC: &[u8] = &hex!(
+    "56AABA0A107192585F13D2E98AB8B392E36D0C71DC104F66381361E9BEB52B0E
+     32CC3162979425A1E0A13A252476B665195AFF165A15622B"
+);
+
+/// PBES1 + `pbeWithSHA1AndRC2-CBC` `AlgorithmIdentifier` example.
+///
+/// Generated by OpenSSL using:
+/// $ openssl pkcs8 -topk8 -v1 PBE-SHA1-RC2-64 -provider legacy -provider default
+const PBES1_SHA1_RC2_CBC_ALG_ID: &[u8] =
+    &hex!("301b06092a864886f70d01050b300e0408b5ec9b347d7ad8c602020800");
+
+/// Ciphertext of Ed25519 PKCS#8 private key when encrypted using
+/// `pbeWithSHA1AndRC2-CBC`.
+const ED25519_PKCS8_KEY_CIPHERTEXT_SHA1_RC2_CBC: &[u8] = &hex!(
+    "92469AB6ED4E6E446D4C459F363F577B5BEC94EFF51188EE10C8366BB6834860
+     3AB312CE50660CBFCC5D071CFFCDAD959452D19A423A8562"
+);
+
+/// PBES1 + `pbeWithMD5AndDES-CBC` `AlgorithmIdentifier` example.
+///
+/// Generated by OpenSSL using:
+/// $ openssl pkcs8 -topk8 -v1 PBE-MD5-DES -provider legacy -provider default
+const PBES1_MD5_DES_CBC_ALG_ID: &[u8] =
+    &hex!("301b06092a864886f70d010503300e04087f738126904d764802020800");
+
+/// Ciphertext of Ed25519 PKCS#8 private key when encrypted using
+/// `pbeWithMD5AndDES-CBC`.
+const ED25519_PKCS8_KEY_CIPHERTEXT_MD5_DES_CBC: &[u8] = &hex!(
+    "6AB07CEDC1DEC942376673528A001B92FB5D0386CB05C308A4059294D5574A0A
+     A66C5D34410E27E1B50589F0E41AF798590AF603EF85D4CC"
+);
+
+const PASSWORD: &[u8] = b"hunter42"; // Bad password; don't actually use outside tests!
+
+#[test]
+fn decrypt_pbes1_sha1_des_cbc() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBES1_SHA1_DES_CBC_ALG_ID).unwrap();
+    let mut buffer = Vec::from(ED25519_PKCS8_KEY_CIPHERTEXT_SHA1_DES_CBC);
+    let plaintext = scheme.decrypt_in_place(PASSWORD, &mut buffer).unwrap();
+    assert_eq!(plaintext, ED25519_PKCS8_KEY_PLAINTEXT);
+}
+
+#[test]
+fn decrypt_pbes1_sha1_rc2_cbc() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBES1_SHA1_RC2_CBC_ALG_ID).unwrap();
+    let mut buffer = Vec::from(ED25519_PKCS8_KEY_CIPHERTEXT_SHA1_RC2_CBC);
+    let plaintext = scheme.decrypt_in_place(PASSWORD, &mut buffer).unwrap();
+    assert_eq!(plaintext, ED25519_PKCS8_KEY_PLAINTEXT);
+}
+
+#[test]
+fn encrypt_pbes1_sha1_des_cbc() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBES1_SHA1_DES_CBC_ALG_ID).unwrap();
+
+    let mut buffer = [0u8; 64];
+    buffer[..ED25519_PKCS8_KEY_PLAINTEXT.len()].copy_from_slice(ED25519_PKCS8_KEY_PLAINTEXT);
+    let ciphertext = scheme
+        .encrypt_in_place(PASSWORD, &mut buffer, ED25519_PKCS8_KEY_PLAINTEXT.len())
+        .unwrap();
+    assert_eq!(ciphertext, ED25519_PKCS8_KEY_CIPHERTEXT_SHA1_DES_CBC);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn encrypt_decrypt_pbes1_sha1_des_cbc() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBES1_SHA1_DES_CBC_ALG_ID).unwrap();
+    let ciphertext = scheme
+        .encrypt(PASSWORD, ED25519_PKCS8_KEY_PLAINTEXT)
+        .unwrap();
+    assert_eq!(ciphertext, ED25519_PKCS8_KEY_CIPHERTEXT_SHA1_DES_CBC);
+
+    let plaintext = scheme.decrypt(PASSWORD, &ciphertext).unwrap();
+    assert_eq!(plaintext, ED25519_PKCS8_KEY_PLAINTEXT);
+}
+
+#[test]
+fn decrypt_pbes1_md5_des_cbc() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBES1_MD5_DES_CBC_ALG_ID).unwrap();
+    assert_eq!(scheme.oid(), pbes1::PBE_WITH_MD5_AND_DES_CBC_OID);
+
+    let mut buffer = Vec::from(ED25519_PKCS8_KEY_CIPHERTEXT_MD5_DES_CBC);
+    let plaintext = scheme.decrypt_in_place(PASSWORD, &mut buffer).unwrap();
+    assert_eq!(plaintext, ED25519_PKCS8_KEY_PLAINTEXT);
+}
+
+#[test]
+fn encrypt_pbes1_md5_des_cbc() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBES1_MD5_DES_CBC_ALG_ID).unwrap();
+    let ciphertext = scheme
+        .encrypt(PASSWORD, ED25519_PKCS8_KEY_PLAINTEXT)
+        .unwrap();
+    assert_eq!(ciphertext, ED25519_PKCS8_KEY_CIPHERTEXT_MD5_DES_CBC);
+}
+
+#[test]
+fn decrypt_pbes1_wrong_password() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBES1_SHA1_DES_CBC_ALG_ID).unwrap();
+    let mut buffer = Vec::from(ED25519_PKCS8_KEY_CIPHERTEXT_SHA1_DES_CBC);
+    assert_eq!(
+        scheme.decrypt_in_place(b"hunter2", &mut buffer),
+        Err(pkcs5::Error::DecryptFailed)
+    );
+}
+
+#[test]
+fn pbes1_unsupported_algorithms() {
+    let mut alg = pbes1::Algorithm {
+        encryption: pbes1::EncryptionScheme::PbeWithMd2AndDesCbc,
+        parameters: pbes1::Parameters {
+            salt: hex!("23df43b0f18a9201"),
+            iteration_count: 2048,
+        },
+    };
+
+    let mut buffer = Vec::from(ED25519_PKCS8_KEY_CIPHERTEXT_SHA1_DES_CBC);
+    assert_eq!(
+        alg.decrypt_in_place(PASSWORD, &mut buffer),
+        Err(pkcs5::Error::UnsupportedAlgorithm {
+            oid: pbes1::PBE_WITH_MD2_AND_DES_CBC_OID
+        })
+    );
+
+    // RC2 is decrypt-only
+    let mut buffer = [0u8; 64];
+    alg.encryption = pbes1::EncryptionScheme::PbeWithSha1AndRc2Cbc;
+    assert_eq!(
+        alg.encrypt_in_place(PASSWORD, &mut buffer, ED25519_PKCS8_KEY_PLAINTEXT.len()),
+        Err(pkcs5::Error::UnsupportedAlgorithm {
+            oid: pbes1::PBE_WITH_SHA1_AND_RC2_CBC_OID
+        })
+    );
+}
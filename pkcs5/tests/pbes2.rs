@@ -4,6 +4,9 @@ use der::Encode;
 use hex_literal::hex;
 use pkcs5::pbes2;
 
+#[cfg(all(feature = "std", feature = "pbes2"))]
+use std::time::Duration;
+
 /// PBES2 + PBKDF2-SHA1 + AES-128-CBC `AlgorithmIdentifier` example.
 ///
 /// Generated by OpenSSL and extracted from the `pkcs8` crate's
@@ -34,6 +37,22 @@ const PBES2_SCRYPT_AES256CBC_ALG_ID: &[u8] = &hex!(
     09bd0a6251f2254f9fd5963887c27cf01"
 );
 
+/// PBES2 + PBKDF2-SHA256 + AES-256-GCM `AlgorithmIdentifier` example
+/// with a 16-byte authentication tag.
+const PBES2_PBKDF2_SHA256_AES256GCM_ALG_ID: &[u8] = &hex!(
+    "305806092a864886f70d01050d304b302906092a864886f70d01050c301c0408
+     79d982e70df91a8802020800300c06082a864886f70d02090500301e06096086
+     4801650304012e3011040cb2d02d78b2efd9dff694cf8e020110"
+);
+
+/// PBES2 + PBKDF2-SHA256 + AES-128-GCM `AlgorithmIdentifier` example
+/// with the default 12-byte authentication tag.
+const PBES2_PBKDF2_SHA256_AES128GCM_ALG_ID: &[u8] = &hex!(
+    "305506092a864886f70d01050d3048302906092a864886f70d01050c301c0408
+     79d982e70df91a8802020800300c06082a864886f70d02090500301b06096086
+     48016503040106300e040cb2d02d78b2efd9dff694cf8e"
+);
+
 /// PBES2 + DES-EDE3-CBC + PBKDF-SHA2 `AlgorithmIdentifier` example.
 ///
 /// Generated by OpenSSL and extracted from the `pkcs8` crate's
@@ -117,6 +136,27 @@ fn decode_pbes2_scrypt_aes256cbc() {
     }
 }
 
+/// Decoding test for PBES2 with an unsupported key derivation function,
+/// e.g. Argon2 for which no OID has been assigned
+#[test]
+fn decode_pbes2_unknown_kdf() {
+    // `PBES2_SCRYPT_AES256CBC_ALG_ID` with the scrypt OID replaced by
+    // 1.3.6.1.4.1.11591.4.99
+    let alg_id = hex!(
+        "304f06092a864886f70d01050d3042302106092b06010401da47046330140408
+         e6211e2348ad69e002024000020108020101301d060960864801650304012a041
+         09bd0a6251f2254f9fd5963887c27cf01"
+    );
+
+    let err = pkcs5::EncryptionScheme::try_from(&alg_id[..]).unwrap_err();
+    assert_eq!(
+        err.kind(),
+        der::ErrorKind::OidUnknown {
+            oid: "1.3.6.1.4.1.11591.4.99".parse().unwrap()
+        }
+    );
+}
+
 /// Decoding test for PBES2 + PBKDF2-SHA256 + DES-EDE3-CBC `AlgorithmIdentifier`
 #[cfg(feature = "3des")]
 #[test]
@@ -197,3 +237,183 @@ fn encode_pbes2_scrypt_aes256cbc() {
     let encoded_der = encoder.finish().unwrap();
     assert_eq!(encoded_der, PBES2_SCRYPT_AES256CBC_ALG_ID);
 }
+
+/// Decoding test for PBES2 + PBKDF2-SHA256 + AES-256-GCM `AlgorithmIdentifier`
+#[test]
+fn decode_pbes2_pbkdf2_sha256_aes256gcm() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBES2_PBKDF2_SHA256_AES256GCM_ALG_ID).unwrap();
+    let params = scheme.pbes2().unwrap();
+    assert_eq!(params.kdf.pbkdf2().unwrap().iteration_count, 2048);
+
+    match params.encryption {
+        pbes2::EncryptionScheme::Aes256Gcm { nonce, icv_len } => {
+            assert_eq!(nonce, &hex!("b2d02d78b2efd9dff694cf8e"));
+            assert_eq!(icv_len, 16);
+        }
+        other => panic!("unexpected encryption scheme: {:?}", other),
+    }
+    assert!(params.encryption.is_aead());
+}
+
+/// Decoding test for PBES2 + PBKDF2-SHA256 + AES-128-GCM `AlgorithmIdentifier`
+#[test]
+fn decode_pbes2_pbkdf2_sha256_aes128gcm() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBES2_PBKDF2_SHA256_AES128GCM_ALG_ID).unwrap();
+
+    match scheme.pbes2().unwrap().encryption {
+        pbes2::EncryptionScheme::Aes128Gcm { nonce, icv_len } => {
+            assert_eq!(nonce, &hex!("b2d02d78b2efd9dff694cf8e"));
+            assert_eq!(icv_len, 12);
+        }
+        other => panic!("unexpected encryption scheme: {:?}", other),
+    }
+}
+
+/// Encoding test for PBES2 + PBKDF2-SHA256 + AES-GCM `AlgorithmIdentifier`s
+#[test]
+fn encode_pbes2_pbkdf2_sha256_aesgcm() {
+    let mut buffer = [0u8; 1024];
+    let salt = hex!("79d982e70df91a88");
+    let nonce = hex!("b2d02d78b2efd9dff694cf8e");
+
+    let params = pbes2::Parameters::pbkdf2_sha256_aes256gcm(2048, &salt, &nonce).unwrap();
+    let scheme = pkcs5::EncryptionScheme::from(params);
+    assert_eq!(
+        scheme.encode_to_slice(&mut buffer).unwrap(),
+        PBES2_PBKDF2_SHA256_AES256GCM_ALG_ID
+    );
+
+    let mut params = pbes2::Parameters::pbkdf2_sha256_aes128gcm(2048, &salt, &nonce).unwrap();
+    params.encryption = pbes2::EncryptionScheme::Aes128Gcm {
+        nonce: &nonce,
+        icv_len: 12,
+    };
+    let scheme = pkcs5::EncryptionScheme::from(params);
+    assert_eq!(
+        scheme.encode_to_slice(&mut buffer).unwrap(),
+        PBES2_PBKDF2_SHA256_AES128GCM_ALG_ID
+    );
+
+    // Tag lengths outside of 12..=16 are invalid
+    let mut params = pbes2::Parameters::pbkdf2_sha256_aes128gcm(2048, &salt, &nonce).unwrap();
+    params.encryption = pbes2::EncryptionScheme::Aes128Gcm {
+        nonce: &nonce,
+        icv_len: 8,
+    };
+    assert!(params.encode_to_slice(&mut buffer).is_err());
+}
+
+/// Encoding test for the interactive scrypt + AES-256-CBC preset
+#[test]
+fn encode_pbes2_interactive() {
+    let mut buffer = [0u8; 1024];
+    let salt = hex!("E6211E2348AD69E0");
+    let iv = hex!("9BD0A6251F2254F9FD5963887C27CF01");
+
+    let scheme = pkcs5::EncryptionScheme::from(pbes2::Parameters::interactive(&salt, &iv));
+    assert_eq!(
+        scheme.encode_to_slice(&mut buffer).unwrap(),
+        PBES2_SCRYPT_AES256CBC_ALG_ID
+    );
+}
+
+/// Cost parameters of the scrypt presets
+#[test]
+fn pbes2_presets() {
+    let salt = hex!("E6211E2348AD69E0");
+    let iv = hex!("9BD0A6251F2254F9FD5963887C27CF01");
+
+    for (params, cost_parameter) in [
+        (pbes2::Parameters::interactive(&salt, &iv), 1 << 14),
+        (pbes2::Parameters::recommended(&salt, &iv), 1 << 17),
+        (pbes2::Parameters::sensitive(&salt, &iv), 1 << 20),
+    ] {
+        let scrypt_params = params.kdf.scrypt().unwrap();
+        assert_eq!(scrypt_params.salt, &salt);
+        assert_eq!(scrypt_params.cost_parameter, cost_parameter);
+        assert_eq!(scrypt_params.block_size, 8);
+        assert_eq!(scrypt_params.parallelization, 1);
+        assert_eq!(
+            params.encryption,
+            pbes2::EncryptionScheme::Aes256Cbc { iv: &iv }
+        );
+    }
+}
+
+/// Calibration never goes below the interactive preset
+#[cfg(all(feature = "std", feature = "pbes2"))]
+#[test]
+fn pbes2_calibrate() {
+    let salt = hex!("E6211E2348AD69E0");
+    let iv = hex!("9BD0A6251F2254F9FD5963887C27CF01");
+
+    let params = pbes2::Parameters::calibrate(Default::default(), &salt, &iv).unwrap();
+    assert_eq!(params, pbes2::Parameters::interactive(&salt, &iv));
+
+    let interactive = pbes2::Parameters::interactive(&salt, &iv);
+    let sensitive = pbes2::Parameters::sensitive(&salt, &iv);
+
+    for target in [Duration::from_millis(1), Duration::from_millis(250)] {
+        let params = pbes2::Parameters::calibrate(target, &salt, &iv).unwrap();
+        let cost_parameter = params.kdf.scrypt().unwrap().cost_parameter;
+        assert!(cost_parameter >= interactive.kdf.scrypt().unwrap().cost_parameter);
+        assert!(cost_parameter <= sensitive.kdf.scrypt().unwrap().cost_parameter);
+        assert_eq!(params.kdf.scrypt().unwrap().block_size, 8);
+        assert_eq!(params.kdf.scrypt().unwrap().parallelization, 1);
+        assert_eq!(params.encryption, interactive.encryption);
+    }
+}
+
+/// PBKDF2-SHA256 with the recommended iteration count
+#[test]
+fn pbkdf2_recommended() {
+    let salt = hex!("E6211E2348AD69E0");
+
+    let params = pbes2::Pbkdf2Params::recommended(&salt);
+    assert_eq!(params.salt, &salt);
+    assert_eq!(params.iteration_count, 600_000);
+    assert_eq!(params.key_length, None);
+    assert_eq!(params.prf, pbes2::Pbkdf2Prf::HmacWithSha256);
+    assert_eq!(
+        params,
+        pbes2::Pbkdf2Params::hmac_with_sha256(600_000, &salt).unwrap()
+    );
+}
+
+/// PBES2 + PBKDF2-SHA256 + RC2-40-CBC `AlgorithmIdentifier` example.
+///
+/// Generated by OpenSSL using:
+/// $ openssl pkcs8 -topk8 -v2 rc2-40-cbc -v2prf hmacWithSHA256 -provider legacy -provider default
+#[cfg(feature = "rc2-insecure")]
+const PBES2_PBKDF2_SHA256_RC2_40_CBC_ALG_ID: &[u8] = &hex!(
+    "305706092a864886f70d01050d304a302c06092a864886f70d01050c301f0408
+     e876fadc0147a35d02020800020105300c06082a864886f70d02090500301a06
+     082a864886f70d0302300e020200a00408c7f8b67355473297"
+);
+
+/// Decoding/encoding test for PBES2 + PBKDF2-SHA256 + RC2-40-CBC `AlgorithmIdentifier`
+#[cfg(feature = "rc2-insecure")]
+#[test]
+fn decode_encode_pbes2_pbkdf2_sha256_rc2cbc() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBES2_PBKDF2_SHA256_RC2_40_CBC_ALG_ID).unwrap();
+    let params = scheme.pbes2().unwrap();
+    assert_eq!(params.kdf.key_length(), Some(5));
+
+    match params.encryption {
+        pbes2::EncryptionScheme::Rc2Cbc {
+            iv,
+            effective_key_bits,
+        } => {
+            assert_eq!(iv, &hex!("c7f8b67355473297"));
+            assert_eq!(effective_key_bits, 40);
+        }
+        other => panic!("unexpected encryption scheme: {:?}", other),
+    }
+    assert_eq!(params.encryption.key_size(), 5);
+
+    let mut buffer = [0u8; 1024];
+    assert_eq!(
+        scheme.encode_to_slice(&mut buffer).unwrap(),
+        PBES2_PBKDF2_SHA256_RC2_40_CBC_ALG_ID
+    );
+}
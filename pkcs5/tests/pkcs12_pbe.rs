@@ -0,0 +1,83 @@
+//! PKCS#12 password-based encryption tests
+
+#![cfg(feature = "legacy")]
+
+use der::Encode;
+use hex_literal::hex;
+use pkcs5::pkcs12_pbe;
+
+/// Plaintext of Ed25519 PKCS#8 private key.
+///
+/// This is the hex-encoded contents of `ed25519-priv.der` from
+/// `pkcs8/tests/examples`.
+const ED25519_PKCS8_KEY_PLAINTEXT: &[u8] = &hex!(
+    "302e020100300506032b65700422042017ed9c73e9db649ec189a612831c5fc5
+     70238207c1aa9dfbd2c53e3ff5e5ea85"
+);
+
+/// `pbeWithSHAAnd3-KeyTripleDES-CBC` `AlgorithmIdentifier` example.
+///
+/// Generated by OpenSSL using:
+/// $ openssl pkcs8 -topk8 -v1 PBE-SHA1-3DES
+const SHA_3DES_CBC_ALG_ID: &[u8] =
+    &hex!("301c060a2a864886f70d010c0103300e04084f4332469ae136c802020800");
+
+/// Ciphertext of Ed25519 PKCS#8 private key when encrypted using
+/// `pbeWithSHAAnd3-KeyTripleDES-CBC`.
+const ED25519_PKCS8_KEY_CIPHERTEXT_SHA_3DES_CBC: &[u8] = &hex!(
+    "9F9B809584A59493752D087210C5DC2ED18F38C747B316A1C980769CA355CBA0
+     55186F7473FCBF30FAC982C0ABA7F0B2F0C088466CCAB1FB"
+);
+
+const PASSWORD: &[u8] = b"hunter42"; // Bad password; don't actually use outside tests!
+
+#[test]
+fn decode_encode_sha_3des_cbc() {
+    let scheme = pkcs5::EncryptionScheme::try_from(SHA_3DES_CBC_ALG_ID).unwrap();
+    let alg = scheme.pkcs12_pbe().unwrap();
+    assert_eq!(
+        alg.encryption,
+        pkcs12_pbe::EncryptionScheme::PbeWithShaAnd3KeyTripleDesCbc
+    );
+    assert_eq!(alg.parameters.salt, hex!("4f4332469ae136c8"));
+    assert_eq!(alg.parameters.iteration_count, 2048);
+    assert!(scheme.pbes1().is_none());
+
+    let mut buffer = [0u8; 64];
+    let encoded = scheme.encode_to_slice(&mut buffer).unwrap();
+    assert_eq!(encoded, SHA_3DES_CBC_ALG_ID);
+}
+
+#[test]
+fn decrypt_sha_3des_cbc() {
+    let scheme = pkcs5::EncryptionScheme::try_from(SHA_3DES_CBC_ALG_ID).unwrap();
+    assert_eq!(
+        scheme.oid(),
+        pkcs12_pbe::PBE_WITH_SHA_AND_3_KEY_TRIPLE_DES_CBC_OID
+    );
+
+    let mut buffer = Vec::from(ED25519_PKCS8_KEY_CIPHERTEXT_SHA_3DES_CBC);
+    let plaintext = scheme.decrypt_in_place(PASSWORD, &mut buffer).unwrap();
+    assert_eq!(plaintext, ED25519_PKCS8_KEY_PLAINTEXT);
+
+    let mut buffer = Vec::from(ED25519_PKCS8_KEY_CIPHERTEXT_SHA_3DES_CBC);
+    assert_eq!(
+        scheme.decrypt_in_place(b"hunter2", &mut buffer),
+        Err(pkcs5::Error::DecryptFailed)
+    );
+}
+
+#[test]
+fn encrypt_sha_3des_cbc() {
+    let scheme = pkcs5::EncryptionScheme::try_from(SHA_3DES_CBC_ALG_ID).unwrap();
+    let ciphertext = scheme
+        .encrypt(PASSWORD, ED25519_PKCS8_KEY_PLAINTEXT)
+        .unwrap();
+    assert_eq!(ciphertext, ED25519_PKCS8_KEY_CIPHERTEXT_SHA_3DES_CBC);
+
+    // The PKCS#12 key derivation requires UTF-8 passwords
+    assert_eq!(
+        scheme.encrypt(b"\xff", ED25519_PKCS8_KEY_PLAINTEXT),
+        Err(pkcs5::Error::EncryptFailed)
+    );
+}
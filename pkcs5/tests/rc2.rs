@@ -0,0 +1,83 @@
+//! RC2 tests
+//!
+//! PBES1 and PBES2 derive the RC2 key and pass the effective key length in
+//! bits to the `rc2` crate, so check that it expands keys accordingly.
+
+#![cfg(any(feature = "legacy", feature = "rc2-insecure"))]
+
+use hex_literal::hex;
+use rc2::{
+    cipher::{generic_array::GenericArray, BlockDecrypt, BlockEncrypt},
+    Rc2,
+};
+
+/// Key, effective key length in bits, plaintext and ciphertext
+type TestVector = (&'static [u8], usize, [u8; 8], [u8; 8]);
+
+/// Test vectors from [RFC 2268 Section 5].
+///
+/// [RFC 2268 Section 5]: https://datatracker.ietf.org/doc/html/rfc2268#section-5
+const RFC2268_VECTORS: &[TestVector] = &[
+    (
+        &hex!("0000000000000000"),
+        63,
+        hex!("0000000000000000"),
+        hex!("ebb773f993278eff"),
+    ),
+    (
+        &hex!("ffffffffffffffff"),
+        64,
+        hex!("ffffffffffffffff"),
+        hex!("278b27e42e2f0d49"),
+    ),
+    (
+        &hex!("3000000000000000"),
+        64,
+        hex!("1000000000000001"),
+        hex!("30649edf9be7d2c2"),
+    ),
+    (
+        &hex!("88"),
+        64,
+        hex!("0000000000000000"),
+        hex!("61a8a244adacccf0"),
+    ),
+    (
+        &hex!("88bca90e90875a"),
+        64,
+        hex!("0000000000000000"),
+        hex!("6ccf4308974c267f"),
+    ),
+    (
+        &hex!("88bca90e90875a7f0f79c384627bafb2"),
+        64,
+        hex!("0000000000000000"),
+        hex!("1a807d272bbe5db1"),
+    ),
+    (
+        &hex!("88bca90e90875a7f0f79c384627bafb2"),
+        128,
+        hex!("0000000000000000"),
+        hex!("2269552ab0f85ca6"),
+    ),
+    (
+        &hex!("88bca90e90875a7f0f79c384627bafb216f80a6f85920584c42fceb0be255daf1e"),
+        129,
+        hex!("0000000000000000"),
+        hex!("5b78d3a43dfff1f1"),
+    ),
+];
+
+#[test]
+fn rfc2268_vectors() {
+    for (key, effective_key_bits, plaintext, ciphertext) in RFC2268_VECTORS {
+        let cipher = Rc2::new_with_eff_key_len(key, *effective_key_bits);
+
+        let mut block = GenericArray::from(*plaintext);
+        cipher.encrypt_block(&mut block);
+        assert_eq!(block.as_slice(), ciphertext);
+
+        cipher.decrypt_block(&mut block);
+        assert_eq!(block.as_slice(), plaintext);
+    }
+}
@@ -0,0 +1,185 @@
+//! RFC 5958 `AsymmetricKeyPackage`.
+
+use crate::{Error, PrivateKeyInfo, Result};
+use alloc::vec::{self, Vec};
+use core::slice;
+use der::{
+    Decode, DecodeValue, EncodeValue, Header, Length, Reader, SecretDocument, Sequence, Tag, Writer,
+};
+
+#[cfg(feature = "encryption")]
+use {
+    crate::EncryptedPrivateKeyInfo,
+    rand_core::{CryptoRng, RngCore},
+};
+
+#[cfg(feature = "pem")]
+use der::pem::PemLabel;
+
+/// Asymmetric key package containing one or more [`PrivateKeyInfo`]
+/// (a.k.a. `OneAsymmetricKey`) entries.
+///
+/// Described in [RFC 5958 Section 2]:
+///
+/// ```text
+/// AsymmetricKeyPackage ::= SEQUENCE SIZE (1..MAX) OF OneAsymmetricKey
+/// ```
+///
+/// Packages are typically delivered inside a CMS content type such as
+/// `SignedData` or `EnvelopedData`, which is out of scope for this crate.
+///
+/// [RFC 5958 Section 2]: https://datatracker.ietf.org/doc/html/rfc5958#section-2
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "subtle", derive(Eq, PartialEq))]
+pub struct AsymmetricKeyPackage<'a> {
+    keys: Vec<PrivateKeyInfo<'a>>,
+}
+
+impl<'a> AsymmetricKeyPackage<'a> {
+    /// Create a new [`AsymmetricKeyPackage`] from the given keys.
+    ///
+    /// Returns an error if `keys` is empty.
+    pub fn new(keys: Vec<PrivateKeyInfo<'a>>) -> Result<Self> {
+        if keys.is_empty() {
+            return Err(Tag::Sequence.value_error().into());
+        }
+
+        Ok(Self { keys })
+    }
+
+    /// Get the keys in this package.
+    pub fn keys(&self) -> &[PrivateKeyInfo<'a>] {
+        &self.keys
+    }
+
+    /// Iterate over the keys in this package.
+    pub fn iter(&self) -> slice::Iter<'_, PrivateKeyInfo<'a>> {
+        self.keys.iter()
+    }
+
+    /// Add a key to this package.
+    pub fn push(&mut self, key: PrivateKeyInfo<'a>) {
+        self.keys.push(key);
+    }
+
+    /// Convert this package into its keys.
+    pub fn into_keys(self) -> Vec<PrivateKeyInfo<'a>> {
+        self.keys
+    }
+
+    /// Encrypt each key in this package individually using a symmetric
+    /// encryption key derived from the provided password, returning one
+    /// [`EncryptedPrivateKeyInfo`] document per key.
+    ///
+    /// See [`PrivateKeyInfo::encrypt`] for the algorithms used.
+    #[cfg(feature = "encryption")]
+    pub fn encrypt_keys(
+        &self,
+        mut rng: impl CryptoRng + RngCore,
+        password: impl AsRef<[u8]>,
+    ) -> Result<Vec<SecretDocument>> {
+        self.keys
+            .iter()
+            .map(|key| key.encrypt(&mut rng, password.as_ref()))
+            .collect()
+    }
+
+    /// Decrypt each of the given [`EncryptedPrivateKeyInfo`] entries using
+    /// the provided password, returning an [`AsymmetricKeyPackage`]
+    /// document containing the decrypted keys.
+    #[cfg(feature = "encryption")]
+    pub fn decrypt_keys<'b>(
+        encrypted_keys: impl IntoIterator<Item = &'b EncryptedPrivateKeyInfo<'b>>,
+        password: impl AsRef<[u8]>,
+    ) -> Result<SecretDocument> {
+        let documents = encrypted_keys
+            .into_iter()
+            .map(|key| key.decrypt(password.as_ref()))
+            .collect::<Result<Vec<_>>>()?;
+
+        let keys = documents
+            .iter()
+            .map(|doc| PrivateKeyInfo::try_from(doc.as_bytes()))
+            .collect::<Result<Vec<_>>>()?;
+
+        SecretDocument::try_from(AsymmetricKeyPackage::new(keys)?)
+    }
+}
+
+impl<'a> DecodeValue<'a> for AsymmetricKeyPackage<'a> {
+    fn decode_value<R: Reader<'a>>(
+        reader: &mut R,
+        header: Header,
+    ) -> der::Result<AsymmetricKeyPackage<'a>> {
+        let keys = Vec::<PrivateKeyInfo<'a>>::decode_value(reader, header)?;
+
+        if keys.is_empty() {
+            return Err(Tag::Sequence.value_error());
+        }
+
+        Ok(Self { keys })
+    }
+}
+
+impl EncodeValue for AsymmetricKeyPackage<'_> {
+    fn value_len(&self) -> der::Result<Length> {
+        self.keys.value_len()
+    }
+
+    fn encode_value(&self, writer: &mut impl Writer) -> der::Result<()> {
+        self.keys.encode_value(writer)
+    }
+}
+
+impl<'a> Sequence<'a> for AsymmetricKeyPackage<'a> {}
+
+impl<'a> TryFrom<&'a [u8]> for AsymmetricKeyPackage<'a> {
+    type Error = Error;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self> {
+        Ok(Self::from_der(bytes)?)
+    }
+}
+
+impl<'a> IntoIterator for AsymmetricKeyPackage<'a> {
+    type Item = PrivateKeyInfo<'a>;
+    type IntoIter = vec::IntoIter<PrivateKeyInfo<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.keys.into_iter()
+    }
+}
+
+impl<'a, 'b> IntoIterator for &'b AsymmetricKeyPackage<'a> {
+    type Item = &'b PrivateKeyInfo<'a>;
+    type IntoIter = slice::Iter<'b, PrivateKeyInfo<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.keys.iter()
+    }
+}
+
+impl TryFrom<AsymmetricKeyPackage<'_>> for SecretDocument {
+    type Error = Error;
+
+    fn try_from(package: AsymmetricKeyPackage<'_>) -> Result<SecretDocument> {
+        SecretDocument::try_from(&package)
+    }
+}
+
+impl TryFrom<&AsymmetricKeyPackage<'_>> for SecretDocument {
+    type Error = Error;
+
+    fn try_from(package: &AsymmetricKeyPackage<'_>) -> Result<SecretDocument> {
+        Ok(Self::encode_msg(package)?)
+    }
+}
+
+/// NOTE: [RFC 7468] doesn't define a label for this structure, so this
+/// follows the naming of the ASN.1 type.
+///
+/// [RFC 7468]: https://datatracker.ietf.org/doc/html/rfc7468
+#[cfg(feature = "pem")]
+impl PemLabel for AsymmetricKeyPackage<'_> {
+    const PEM_LABEL: &'static str = "ASYMMETRIC KEY PACKAGE";
+}
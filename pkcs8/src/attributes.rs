@@ -0,0 +1,116 @@
+//! PKCS#8 private key attributes.
+
+use crate::Result;
+use alloc::vec::Vec;
+use der::{
+    asn1::{Any, BmpString, ObjectIdentifier, OctetStringRef, SetOfVec},
+    oid::AssociatedOid,
+    Encode, EncodeValue, Tagged,
+};
+use x509_cert::{
+    attr::{Attribute, Attributes},
+    ext::pkix::PrivateKeyUsagePeriod,
+};
+
+/// PKCS#9 `friendlyName` attribute Object Identifier (OID) as defined in
+/// [RFC 2985 Section 5.5.1].
+///
+/// [RFC 2985 Section 5.5.1]: https://datatracker.ietf.org/doc/html/rfc2985#section-5.5.1
+pub const PKCS_9_AT_FRIENDLY_NAME: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.20");
+
+/// PKCS#9 `localKeyId` attribute Object Identifier (OID) as defined in
+/// [RFC 2985 Section 5.5.2].
+///
+/// [RFC 2985 Section 5.5.2]: https://datatracker.ietf.org/doc/html/rfc2985#section-5.5.2
+pub const PKCS_9_AT_LOCAL_KEY_ID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.21");
+
+/// Builder for the [`Attributes`] of a [`PrivateKeyInfo`][`crate::PrivateKeyInfo`].
+///
+/// Setting an attribute replaces any existing attribute with the same OID.
+///
+/// ```
+/// use pkcs8::{der::{asn1::AnyRef, Encode}, AttributesBuilder, PrivateKeyInfo};
+///
+/// let pkcs8_der = include_bytes!("../tests/examples/ed25519-priv-pkcs8v1.der");
+///
+/// let attributes = AttributesBuilder::new()
+///     .friendly_name("my key")?
+///     .local_key_id(&[1, 2, 3, 4])?
+///     .build()
+///     .to_der()?;
+///
+/// let private_key = PrivateKeyInfo::try_from(&pkcs8_der[..])?
+///     .with_attributes(AnyRef::try_from(attributes.as_slice())?)?;
+///
+/// assert_eq!(private_key.friendly_name()?.as_deref(), Some("my key"));
+/// # Ok::<(), pkcs8::Error>(())
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct AttributesBuilder {
+    attributes: Attributes,
+}
+
+impl AttributesBuilder {
+    /// Create a new builder without any attributes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new builder with the given initial attributes, e.g. those
+    /// returned by [`PrivateKeyInfo::decode_attributes`][`crate::PrivateKeyInfo::decode_attributes`].
+    pub fn from_attributes(attributes: Attributes) -> Self {
+        Self { attributes }
+    }
+
+    /// Set the given attribute.
+    pub fn attribute(mut self, attribute: Attribute) -> Result<Self> {
+        let oid = attribute.oid;
+        self.attributes
+            .insert_or_replace(attribute, |attr| attr.oid == oid)?;
+        Ok(self)
+    }
+
+    /// Set the PKCS#9 `friendlyName` attribute.
+    pub fn friendly_name(self, name: &str) -> Result<Self> {
+        let name = BmpString::from_utf8(name)?;
+        self.single_valued_attribute(PKCS_9_AT_FRIENDLY_NAME, &name)
+    }
+
+    /// Set the PKCS#9 `localKeyId` attribute.
+    pub fn local_key_id(self, id: &[u8]) -> Result<Self> {
+        self.single_valued_attribute(PKCS_9_AT_LOCAL_KEY_ID, &OctetStringRef::new(id)?)
+    }
+
+    /// Set the `privateKeyUsagePeriod` attribute.
+    pub fn key_usage_period(self, period: &PrivateKeyUsagePeriod) -> Result<Self> {
+        self.single_valued_attribute(PrivateKeyUsagePeriod::OID, period)
+    }
+
+    /// Remove the attribute with the given [`ObjectIdentifier`], if present.
+    pub fn remove(mut self, oid: ObjectIdentifier) -> Self {
+        self.attributes.remove_by(|attr| attr.oid == oid);
+        self
+    }
+
+    /// Get the resulting [`Attributes`].
+    pub fn build(self) -> Attributes {
+        self.attributes
+    }
+
+    /// Encode the resulting [`Attributes`] as ASN.1 DER, suitable for
+    /// [`PrivateKeyInfo::with_attributes`][`crate::PrivateKeyInfo::with_attributes`].
+    pub fn to_der(&self) -> Result<Vec<u8>> {
+        Ok(self.attributes.to_der()?)
+    }
+
+    fn single_valued_attribute(
+        self,
+        oid: ObjectIdentifier,
+        value: &(impl EncodeValue + Tagged),
+    ) -> Result<Self> {
+        let values = SetOfVec::try_from([Any::encode_from(value)?])?;
+        self.attribute(Attribute { oid, values })
+    }
+}
@@ -0,0 +1,217 @@
+//! Builder for encrypted PKCS#8 private keys.
+
+use crate::{EncryptedPrivateKeyInfo, Error, Result};
+use der::SecretDocument;
+use pkcs5::{
+    pbes2::{self, EncryptionScheme, Kdf, Pbkdf2Params, ScryptParams},
+    scrypt,
+};
+use rand_core::{CryptoRng, RngCore};
+
+#[cfg(feature = "pem")]
+use {
+    crate::LineEnding,
+    alloc::string::String,
+    der::{pem::PemLabel, zeroize::Zeroizing},
+};
+
+/// Minimum salt length in bytes, as recommended by [RFC 8018 Section 4.1].
+///
+/// [RFC 8018 Section 4.1]: https://datatracker.ietf.org/doc/html/rfc8018#section-4.1
+const MIN_SALT_LEN: usize = 8;
+
+/// Maximum supported salt length in bytes.
+const MAX_SALT_LEN: usize = 64;
+
+/// Default salt length in bytes.
+const DEFAULT_SALT_LEN: usize = 16;
+
+/// AES-CBC IV length in bytes.
+const AES_BLOCK_SIZE: usize = 16;
+
+/// AES-GCM nonce length in bytes.
+const GCM_NONCE_SIZE: usize = 12;
+
+/// AES-GCM authentication tag length in bytes.
+const GCM_ICV_LEN: u8 = 16;
+
+/// AES variants supported by [`EncryptedPrivateKeyInfoBuilder`].
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum EncryptionCipher {
+    /// AES-128 in CBC mode
+    Aes128Cbc,
+
+    /// AES-192 in CBC mode
+    Aes192Cbc,
+
+    /// AES-256 in CBC mode
+    #[default]
+    Aes256Cbc,
+
+    /// AES-128 in GCM mode
+    Aes128Gcm,
+
+    /// AES-192 in GCM mode
+    Aes192Gcm,
+
+    /// AES-256 in GCM mode
+    Aes256Gcm,
+}
+
+/// Password-based key derivation function.
+#[derive(Copy, Clone, Debug)]
+enum PasswordKdf {
+    Pbkdf2Sha256 { iterations: u32 },
+    Scrypt(scrypt::Params),
+}
+
+/// Builder for [`EncryptedPrivateKeyInfo`] documents using PBES2 with
+/// caller-selected parameters.
+///
+/// By default, keys are encrypted the same way as by
+/// [`PrivateKeyInfo::encrypt`][`crate::PrivateKeyInfo::encrypt`]: scrypt with
+/// the recommended parameters, a 16-byte salt and AES-256-CBC.
+///
+/// ```
+/// # #[cfg(feature = "getrandom")]
+/// # {
+/// use pkcs8::{rand_core::OsRng, EncryptedPrivateKeyInfoBuilder, EncryptionCipher};
+///
+/// let pkcs8_der = include_bytes!("../tests/examples/ed25519-priv-pkcs8v1.der");
+///
+/// let encrypted = EncryptedPrivateKeyInfoBuilder::new()
+///     .pbkdf2_sha256(600_000)
+///     .salt_len(16)
+///     .cipher(EncryptionCipher::Aes256Gcm)
+///     .encrypt(OsRng, "hunter42", pkcs8_der)?;
+/// # }
+/// # Ok::<(), pkcs8::Error>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct EncryptedPrivateKeyInfoBuilder {
+    kdf: PasswordKdf,
+    cipher: EncryptionCipher,
+    salt_len: usize,
+}
+
+impl EncryptedPrivateKeyInfoBuilder {
+    /// Create a new builder with the default parameters.
+    pub fn new() -> Self {
+        Self {
+            kdf: PasswordKdf::Scrypt(scrypt::Params::recommended()),
+            cipher: EncryptionCipher::default(),
+            salt_len: DEFAULT_SALT_LEN,
+        }
+    }
+
+    /// Use PBKDF2-HMAC-SHA256 with the given iteration count as the
+    /// password-based key derivation function.
+    ///
+    /// See [`Pbkdf2Params::RECOMMENDED_ITERATIONS`] for a recommended minimum.
+    pub fn pbkdf2_sha256(mut self, iterations: u32) -> Self {
+        self.kdf = PasswordKdf::Pbkdf2Sha256 { iterations };
+        self
+    }
+
+    /// Use scrypt with the given parameters as the password-based key
+    /// derivation function.
+    pub fn scrypt(mut self, params: scrypt::Params) -> Self {
+        self.kdf = PasswordKdf::Scrypt(params);
+        self
+    }
+
+    /// Set the length of the random salt in bytes (8 to 64).
+    pub fn salt_len(mut self, salt_len: usize) -> Self {
+        self.salt_len = salt_len;
+        self
+    }
+
+    /// Set the symmetric cipher.
+    pub fn cipher(mut self, cipher: EncryptionCipher) -> Self {
+        self.cipher = cipher;
+        self
+    }
+
+    /// Encrypt the given PKCS#8 private key document using a symmetric
+    /// encryption key derived from the provided password, generating the
+    /// salt and IV/nonce with `rng`.
+    pub fn encrypt(
+        &self,
+        mut rng: impl CryptoRng + RngCore,
+        password: impl AsRef<[u8]>,
+        pkcs8_der: &[u8],
+    ) -> Result<SecretDocument> {
+        let mut salt = [0u8; MAX_SALT_LEN];
+        let salt = match self.salt_len {
+            MIN_SALT_LEN..=MAX_SALT_LEN => &mut salt[..self.salt_len],
+            _ => return Err(self.kdf_params_invalid()),
+        };
+        rng.fill_bytes(salt);
+
+        let mut iv = [0u8; AES_BLOCK_SIZE];
+        rng.fill_bytes(&mut iv);
+
+        let kdf: Kdf<'_> = match self.kdf {
+            PasswordKdf::Pbkdf2Sha256 { iterations } => {
+                Pbkdf2Params::hmac_with_sha256(iterations, salt)?.into()
+            }
+            PasswordKdf::Scrypt(params) => ScryptParams::from_params_and_salt(params, salt)?.into(),
+        };
+
+        let mut nonce = [0u8; GCM_NONCE_SIZE];
+        nonce.copy_from_slice(&iv[..GCM_NONCE_SIZE]);
+
+        let encryption = match self.cipher {
+            EncryptionCipher::Aes128Cbc => EncryptionScheme::Aes128Cbc { iv: &iv },
+            EncryptionCipher::Aes192Cbc => EncryptionScheme::Aes192Cbc { iv: &iv },
+            EncryptionCipher::Aes256Cbc => EncryptionScheme::Aes256Cbc { iv: &iv },
+            EncryptionCipher::Aes128Gcm => EncryptionScheme::Aes128Gcm {
+                nonce: &nonce,
+                icv_len: GCM_ICV_LEN,
+            },
+            EncryptionCipher::Aes192Gcm => EncryptionScheme::Aes192Gcm {
+                nonce: &nonce,
+                icv_len: GCM_ICV_LEN,
+            },
+            EncryptionCipher::Aes256Gcm => EncryptionScheme::Aes256Gcm {
+                nonce: &nonce,
+                icv_len: GCM_ICV_LEN,
+            },
+        };
+
+        let params = pbes2::Parameters { kdf, encryption };
+        EncryptedPrivateKeyInfo::encrypt_with(params, password, pkcs8_der)
+    }
+
+    /// Encrypt the given PKCS#8 private key document like
+    /// [`EncryptedPrivateKeyInfoBuilder::encrypt`], and encode the result as
+    /// PEM with the given [`LineEnding`].
+    #[cfg(feature = "pem")]
+    pub fn encrypt_pem(
+        &self,
+        rng: impl CryptoRng + RngCore,
+        password: impl AsRef<[u8]>,
+        pkcs8_der: &[u8],
+        line_ending: LineEnding,
+    ) -> Result<Zeroizing<String>> {
+        let doc = self.encrypt(rng, password, pkcs8_der)?;
+        Ok(doc.to_pem(EncryptedPrivateKeyInfo::PEM_LABEL, line_ending)?)
+    }
+
+    /// Error for invalid KDF parameters, i.e. the salt length.
+    fn kdf_params_invalid(&self) -> Error {
+        let oid = match self.kdf {
+            PasswordKdf::Pbkdf2Sha256 { .. } => pbes2::PBKDF2_OID,
+            PasswordKdf::Scrypt(_) => pbes2::SCRYPT_OID,
+        };
+
+        pkcs5::Error::AlgorithmParametersInvalid { oid }.into()
+    }
+}
+
+impl Default for EncryptedPrivateKeyInfoBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
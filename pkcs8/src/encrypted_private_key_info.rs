@@ -13,12 +13,16 @@ use der::SecretDocument;
 
 #[cfg(feature = "encryption")]
 use {
+    crate::EncryptedPrivateKeyInfoBuilder,
     pkcs5::pbes2,
     rand_core::{CryptoRng, RngCore},
 };
 
+#[cfg(feature = "aes-kw")]
+use pkcs5::kw;
+
 #[cfg(feature = "pem")]
-use der::pem::PemLabel;
+use der::pem::{Label, PemLabel};
 
 /// PKCS#8 `EncryptedPrivateKeyInfo`.
 ///
@@ -64,18 +68,11 @@ impl<'a> EncryptedPrivateKeyInfo<'a> {
     /// derived from the provided password.
     #[cfg(feature = "encryption")]
     pub(crate) fn encrypt(
-        mut rng: impl CryptoRng + RngCore,
+        rng: impl CryptoRng + RngCore,
         password: impl AsRef<[u8]>,
         doc: &[u8],
     ) -> Result<SecretDocument> {
-        let mut salt = [0u8; 16];
-        rng.fill_bytes(&mut salt);
-
-        let mut iv = [0u8; 16];
-        rng.fill_bytes(&mut iv);
-
-        let pbes2_params = pbes2::Parameters::scrypt_aes256cbc(Default::default(), &salt, &iv)?;
-        EncryptedPrivateKeyInfo::encrypt_with(pbes2_params, password, doc)
+        EncryptedPrivateKeyInfoBuilder::new().encrypt(rng, password, doc)
     }
 
     /// Encrypt this private key using a symmetric encryption key derived
@@ -94,6 +91,37 @@ impl<'a> EncryptedPrivateKeyInfo<'a> {
         }
         .try_into()
     }
+
+    /// Attempt to decrypt this encrypted private key using the provided
+    /// key-encryption key, if it was encrypted using AES key wrap.
+    #[cfg(feature = "aes-kw")]
+    pub fn decrypt_with_kek(&self, kek: &[u8]) -> Result<SecretDocument> {
+        let algorithm =
+            self.encryption_algorithm
+                .key_wrap()
+                .ok_or(pkcs5::Error::UnsupportedAlgorithm {
+                    oid: self.encryption_algorithm.oid(),
+                })?;
+
+        Ok(algorithm.unwrap(kek, self.encrypted_data)?.try_into()?)
+    }
+
+    /// Encrypt the given ASN.1 DER document with the provided key-encryption
+    /// key using the given AES key wrap algorithm.
+    #[cfg(feature = "aes-kw")]
+    pub(crate) fn encrypt_with_kek(
+        algorithm: kw::Algorithm,
+        kek: &[u8],
+        doc: &[u8],
+    ) -> Result<SecretDocument> {
+        let encrypted_data = algorithm.wrap(kek, doc)?;
+
+        EncryptedPrivateKeyInfo {
+            encryption_algorithm: algorithm.into(),
+            encrypted_data: &encrypted_data,
+        }
+        .try_into()
+    }
 }
 
 impl<'a> DecodeValue<'a> for EncryptedPrivateKeyInfo<'a> {
@@ -161,5 +189,5 @@ impl TryFrom<&EncryptedPrivateKeyInfo<'_>> for SecretDocument {
 
 #[cfg(feature = "pem")]
 impl PemLabel for EncryptedPrivateKeyInfo<'_> {
-    const PEM_LABEL: &'static str = "ENCRYPTED PRIVATE KEY";
+    const PEM_LABEL: &'static str = Label::EncryptedPrivateKey.as_str();
 }
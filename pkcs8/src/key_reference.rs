@@ -0,0 +1,112 @@
+//! References to externally held private keys.
+
+use crate::{AlgorithmIdentifierRef, PrivateKeyInfo, Result};
+use der::asn1::{AnyRef, ObjectIdentifier};
+
+#[cfg(feature = "alloc")]
+use {crate::EncodePrivateKey, der::SecretDocument};
+
+/// Reference to a private key held externally, e.g. by an HSM or hardware
+/// token, stored in place of the key material of a [`PrivateKeyInfo`].
+///
+/// Such "stub" documents are encoded as follows:
+///
+/// - `privateKeyAlgorithm` contains the [`KeyReference::format`] OID, which
+///   identifies how the reference is to be interpreted, and is chosen by the
+///   application (e.g. under its own private enterprise arc). The
+///   `parameters` contain the [`KeyReference::key_algorithm`] OID, if any.
+/// - `privateKey` contains the [`KeyReference::handle`], e.g. a token URI
+///   or an opaque key handle.
+///
+/// As the format OID isn't a private key algorithm, stub documents are
+/// rejected by [`DecodePrivateKey`][`crate::DecodePrivateKey`]
+/// implementations for actual key types, while still being stored and
+/// PEM-encoded like any other PKCS#8 document.
+///
+/// ```
+/// # #[cfg(feature = "alloc")]
+/// # {
+/// use pkcs8::{der::Encode, KeyReference, ObjectIdentifier, PrivateKeyInfo};
+///
+/// const FORMAT: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.6.1.4.1.32473.1");
+/// const ED25519: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.101.112");
+///
+/// let stub = KeyReference::new(FORMAT, b"pkcs11:token=hsm;object=key1")
+///     .with_key_algorithm(ED25519);
+/// let stub_der = stub.to_private_key_info().to_der()?;
+///
+/// let private_key = PrivateKeyInfo::try_from(stub_der.as_slice())?;
+/// assert_eq!(private_key.key_reference(FORMAT)?, Some(stub));
+/// # }
+/// # Ok::<(), pkcs8::Error>(())
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct KeyReference<'a> {
+    /// Object identifier for the format of the reference.
+    pub format: ObjectIdentifier,
+
+    /// Object identifier for the algorithm of the referenced key.
+    pub key_algorithm: Option<ObjectIdentifier>,
+
+    /// Token, key handle, or URI identifying the referenced key.
+    pub handle: &'a [u8],
+}
+
+impl<'a> KeyReference<'a> {
+    /// Create a new [`KeyReference`] with the given format OID and handle.
+    pub fn new(format: ObjectIdentifier, handle: &'a [u8]) -> Self {
+        Self {
+            format,
+            key_algorithm: None,
+            handle,
+        }
+    }
+
+    /// Set the algorithm of the referenced key.
+    pub fn with_key_algorithm(mut self, key_algorithm: ObjectIdentifier) -> Self {
+        self.key_algorithm = Some(key_algorithm);
+        self
+    }
+
+    /// Parse a [`KeyReference`] with the given format OID from the given
+    /// [`PrivateKeyInfo`].
+    ///
+    /// Returns an error if the algorithm OID isn't `format`, or if the
+    /// algorithm parameters are present but not an OID.
+    pub fn from_private_key_info(
+        format: ObjectIdentifier,
+        private_key: &PrivateKeyInfo<'a>,
+    ) -> Result<Self> {
+        private_key.algorithm.assert_algorithm_oid(format)?;
+        let (_, key_algorithm) = private_key.algorithm.oids()?;
+
+        Ok(Self {
+            format,
+            key_algorithm,
+            handle: private_key.private_key,
+        })
+    }
+
+    /// Parse a [`KeyReference`] with the given format OID from an ASN.1
+    /// DER-encoded PKCS#8 document.
+    pub fn from_pkcs8_der(format: ObjectIdentifier, bytes: &'a [u8]) -> Result<Self> {
+        Self::from_private_key_info(format, &PrivateKeyInfo::try_from(bytes)?)
+    }
+
+    /// Get the [`PrivateKeyInfo`] stub document for this reference.
+    pub fn to_private_key_info(&self) -> PrivateKeyInfo<'_> {
+        let algorithm = AlgorithmIdentifierRef {
+            oid: self.format,
+            parameters: self.key_algorithm.as_ref().map(AnyRef::from),
+        };
+
+        PrivateKeyInfo::new(algorithm, self.handle)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl EncodePrivateKey for KeyReference<'_> {
+    fn to_pkcs8_der(&self) -> Result<SecretDocument> {
+        self.to_private_key_info().try_into()
+    }
+}
@@ -48,7 +48,14 @@
 //!       - SHA-1 based PRF with HMAC-SHA1, when the `sha1` feature of this crate is enabled.
 //!   - Symmetric encryption: AES-128-CBC, AES-192-CBC, or AES-256-CBC
 //!     (best available options for PKCS#5v2)
-//!  
+//!
+//! ## AES Key Wrap support (optional)
+//! When the `aes-kw` feature is enabled, [`EncryptedPrivateKeyInfo::decrypt_with_kek`]
+//! and [`PrivateKeyInfo::encrypt_with_kek`] protect keys with AES Key Wrap
+//! ([RFC 3394]) or AES Key Wrap with Padding ([RFC 5649]) under a
+//! caller-supplied key-encryption key (KEK) instead of a password, e.g. for
+//! escrowing keys with an HSM.
+//!
 //! ## Legacy DES-CBC and DES-EDE3-CBC (3DES) support (optional)
 //! When the `des-insecure` and/or `3des` features are enabled this crate provides support for
 //! private keys encrypted with with DES-CBC and DES-EDE3-CBC (3DES or Triple DES) symmetric
@@ -64,18 +71,27 @@
 //!
 //! New keys should use AES instead.
 //!
+//! [RFC 3394]: https://datatracker.ietf.org/doc/html/rfc3394
 //! [RFC 5208]: https://tools.ietf.org/html/rfc5208
+//! [RFC 5649]: https://datatracker.ietf.org/doc/html/rfc5649
 //! [RFC 5958]: https://tools.ietf.org/html/rfc5958
 //! [RFC 7914]: https://datatracker.ietf.org/doc/html/rfc7914
 //! [PKCS#5v2 Password Based Encryption Scheme 2 (RFC 8018)]: https://tools.ietf.org/html/rfc8018#section-6.2
 //! [scrypt]: https://en.wikipedia.org/wiki/Scrypt
 
-#[cfg(feature = "pem")]
+#[cfg(feature = "alloc")]
 extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
+#[cfg(feature = "alloc")]
+mod asymmetric_key_package;
+#[cfg(feature = "x509-cert")]
+mod attributes;
+#[cfg(feature = "encryption")]
+mod builder;
 mod error;
+mod key_reference;
 mod private_key_info;
 mod traits;
 mod version;
@@ -85,6 +101,7 @@ pub(crate) mod encrypted_private_key_info;
 
 pub use crate::{
     error::{Error, Result},
+    key_reference::KeyReference,
     private_key_info::PrivateKeyInfo,
     traits::DecodePrivateKey,
     version::Version,
@@ -96,7 +113,7 @@ pub use spki::{
 
 #[cfg(feature = "alloc")]
 pub use {
-    crate::traits::EncodePrivateKey,
+    crate::{asymmetric_key_package::AsymmetricKeyPackage, traits::EncodePrivateKey},
     der::{Document, SecretDocument},
     spki::EncodePublicKey,
 };
@@ -107,5 +124,14 @@ pub use der::pem::LineEnding;
 #[cfg(feature = "pkcs5")]
 pub use {encrypted_private_key_info::EncryptedPrivateKeyInfo, pkcs5};
 
+#[cfg(feature = "encryption")]
+pub use crate::builder::{EncryptedPrivateKeyInfoBuilder, EncryptionCipher};
+
+#[cfg(feature = "x509-cert")]
+pub use {
+    crate::attributes::{AttributesBuilder, PKCS_9_AT_FRIENDLY_NAME, PKCS_9_AT_LOCAL_KEY_ID},
+    x509_cert,
+};
+
 #[cfg(feature = "rand_core")]
 pub use rand_core;
@@ -1,38 +1,60 @@
 //! PKCS#8 `PrivateKeyInfo`.
 
-use crate::{AlgorithmIdentifierRef, Error, Result, Version};
+use crate::{
+    AlgorithmIdentifierRef, Error, KeyReference, Result, SubjectPublicKeyInfoRef, Version,
+};
 use core::fmt;
 use der::{
-    asn1::{AnyRef, BitStringRef, ContextSpecific, OctetStringRef},
-    Decode, DecodeValue, Encode, EncodeValue, Header, Length, Reader, Sequence, TagMode, TagNumber,
-    Writer,
+    asn1::{AnyRef, BitStringRef, ContextSpecific, ObjectIdentifier, OctetStringRef},
+    Decode, DecodeValue, Encode, EncodeValue, Header, Length, Reader, Sequence, Tag, TagMode,
+    TagNumber, Tagged, Writer,
 };
 
 #[cfg(feature = "alloc")]
 use der::SecretDocument;
 
+#[cfg(any(feature = "encryption", feature = "aes-kw"))]
+use {crate::EncryptedPrivateKeyInfo, der::zeroize::Zeroizing};
+
 #[cfg(feature = "encryption")]
 use {
-    crate::EncryptedPrivateKeyInfo,
-    der::zeroize::Zeroizing,
     pkcs5::pbes2,
     rand_core::{CryptoRng, RngCore},
 };
 
+#[cfg(feature = "aes-kw")]
+use pkcs5::kw;
+
 #[cfg(feature = "pem")]
-use der::pem::PemLabel;
+use der::pem::{Label, PemLabel};
 
 #[cfg(feature = "subtle")]
 use subtle::{Choice, ConstantTimeEq};
 
+#[cfg(feature = "x509-cert")]
+use {
+    crate::attributes::{PKCS_9_AT_FRIENDLY_NAME, PKCS_9_AT_LOCAL_KEY_ID},
+    alloc::{string::String, vec::Vec},
+    der::{
+        asn1::{BmpString, OctetString},
+        oid::AssociatedOid,
+    },
+    x509_cert::{
+        attr::{Attribute, Attributes},
+        ext::pkix::PrivateKeyUsagePeriod,
+    },
+};
+
+/// Context-specific tag number for the attributes.
+const ATTRIBUTES_TAG: TagNumber = TagNumber::N0;
+
 /// Context-specific tag number for the public key.
 const PUBLIC_KEY_TAG: TagNumber = TagNumber::N1;
 
 /// PKCS#8 `PrivateKeyInfo`.
 ///
 /// ASN.1 structure containing an `AlgorithmIdentifier`, private key
-/// data in an algorithm specific format, and optional attributes
-/// (ignored by this implementation).
+/// data in an algorithm specific format, and optional attributes.
 ///
 /// Supports PKCS#8 v1 as described in [RFC 5208] and PKCS#8 v2 as described
 /// in [RFC 5958]. PKCS#8 v2 keys include an additional public key field.
@@ -97,6 +119,13 @@ pub struct PrivateKeyInfo<'a> {
     /// Private key data.
     pub private_key: &'a [u8],
 
+    /// Attributes, encoded as a DER `SET OF Attribute`.
+    ///
+    /// With the `x509-cert` feature, these can be decoded using
+    /// [`PrivateKeyInfo::decode_attributes`] and built using
+    /// [`AttributesBuilder`][`crate::AttributesBuilder`].
+    pub attributes: Option<AnyRef<'a>>,
+
     /// Public key data, optionally available if version is V2.
     pub public_key: Option<&'a [u8]>,
 }
@@ -110,10 +139,78 @@ impl<'a> PrivateKeyInfo<'a> {
         Self {
             algorithm,
             private_key,
+            attributes: None,
             public_key: None,
         }
     }
 
+    /// Attach the given attributes to this [`PrivateKeyInfo`].
+    ///
+    /// The attributes must be a DER-encoded `SET OF Attribute`, e.g. as
+    /// produced by encoding the output of
+    /// [`AttributesBuilder::build`][`crate::AttributesBuilder::build`].
+    pub fn with_attributes(mut self, attributes: AnyRef<'a>) -> Result<Self> {
+        attributes.tag().assert_eq(Tag::Set)?;
+        self.attributes = Some(attributes);
+        Ok(self)
+    }
+
+    /// Attach the given public key to this [`PrivateKeyInfo`], making it a
+    /// PKCS#8 v2 `OneAsymmetricKey`.
+    ///
+    /// The public key is the raw key data, which will be encoded as the
+    /// contents of the `publicKey` BIT STRING.
+    pub fn with_public_key(mut self, public_key: &'a [u8]) -> Self {
+        self.public_key = Some(public_key);
+        self
+    }
+
+    /// Attach the public key contained in the given [`SubjectPublicKeyInfo`]
+    /// to this [`PrivateKeyInfo`], making it a PKCS#8 v2 `OneAsymmetricKey`.
+    ///
+    /// Returns an error if the algorithm OIDs of the private and public keys
+    /// differ, or if the public key `BIT STRING` isn't octet-aligned.
+    ///
+    /// [`SubjectPublicKeyInfo`]: crate::SubjectPublicKeyInfo
+    pub fn with_public_key_info(self, public_key: &SubjectPublicKeyInfoRef<'a>) -> Result<Self> {
+        public_key
+            .algorithm
+            .assert_algorithm_oid(self.algorithm.oid)?;
+
+        let public_key = public_key
+            .subject_public_key
+            .as_bytes()
+            .ok_or(Error::KeyMalformed)?;
+
+        Ok(self.with_public_key(public_key))
+    }
+
+    /// Get the public key contained in this [`PrivateKeyInfo`], if present,
+    /// as a [`SubjectPublicKeyInfo`] using the private key's algorithm
+    /// identifier.
+    ///
+    /// [`SubjectPublicKeyInfo`]: crate::SubjectPublicKeyInfo
+    pub fn public_key_info(&self) -> Result<Option<SubjectPublicKeyInfoRef<'a>>> {
+        self.public_key
+            .map(|pk| {
+                Ok(SubjectPublicKeyInfoRef {
+                    algorithm: self.algorithm,
+                    subject_public_key: BitStringRef::from_bytes(pk)?,
+                })
+            })
+            .transpose()
+    }
+
+    /// Get the [`KeyReference`] stored in this [`PrivateKeyInfo`] if its
+    /// algorithm OID is the given reference `format`.
+    pub fn key_reference(&self, format: ObjectIdentifier) -> Result<Option<KeyReference<'a>>> {
+        if self.algorithm.oid == format {
+            KeyReference::from_private_key_info(format, self).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
     /// Get the PKCS#8 [`Version`] for this structure.
     ///
     /// [`Version::V1`] if `public_key` is `None`, [`Version::V2`] if `Some`.
@@ -156,6 +253,83 @@ impl<'a> PrivateKeyInfo<'a> {
         EncryptedPrivateKeyInfo::encrypt_with(pbes2_params, password, der.as_ref())
     }
 
+    /// Encrypt this private key with the provided key-encryption key using
+    /// the given AES key wrap algorithm.
+    ///
+    /// Use one of the `*WrapPad` algorithms unless the encoded key is known
+    /// to be a multiple of 8 bytes long.
+    #[cfg(feature = "aes-kw")]
+    pub fn encrypt_with_kek(&self, algorithm: kw::Algorithm, kek: &[u8]) -> Result<SecretDocument> {
+        let der = Zeroizing::new(self.to_der()?);
+        EncryptedPrivateKeyInfo::encrypt_with_kek(algorithm, kek, der.as_ref())
+    }
+
+    /// Decode the attributes of this [`PrivateKeyInfo`].
+    ///
+    /// Returns an empty set if no attributes are present.
+    #[cfg(feature = "x509-cert")]
+    pub fn decode_attributes(&self) -> Result<Attributes> {
+        match self.attributes {
+            Some(attributes) => Ok(attributes.decode_as()?),
+            None => Ok(Attributes::new()),
+        }
+    }
+
+    /// Get the attribute with the given [`ObjectIdentifier`], if present.
+    #[cfg(feature = "x509-cert")]
+    pub fn attribute(&self, oid: ObjectIdentifier) -> Result<Option<Attribute>> {
+        let mut attributes = self.decode_attributes()?;
+        Ok(attributes.remove_by(|attr| attr.oid == oid))
+    }
+
+    /// Get the PKCS#9 `friendlyName` attribute, if present.
+    #[cfg(feature = "x509-cert")]
+    pub fn friendly_name(&self) -> Result<Option<String>> {
+        Ok(self
+            .single_valued_attribute::<BmpString>(PKCS_9_AT_FRIENDLY_NAME)?
+            .map(|name| name.chars().collect()))
+    }
+
+    /// Get the PKCS#9 `localKeyId` attribute, if present.
+    #[cfg(feature = "x509-cert")]
+    pub fn local_key_id(&self) -> Result<Option<Vec<u8>>> {
+        Ok(self
+            .single_valued_attribute::<OctetString>(PKCS_9_AT_LOCAL_KEY_ID)?
+            .map(OctetString::into_bytes))
+    }
+
+    /// Get the `privateKeyUsagePeriod` attribute, if present.
+    #[cfg(feature = "x509-cert")]
+    pub fn key_usage_period(&self) -> Result<Option<PrivateKeyUsagePeriod>> {
+        self.single_valued_attribute(PrivateKeyUsagePeriod::OID)
+    }
+
+    /// Decode the value of a single-valued attribute, if present.
+    #[cfg(feature = "x509-cert")]
+    fn single_valued_attribute<T>(&self, oid: ObjectIdentifier) -> Result<Option<T>>
+    where
+        T: for<'b> der::Choice<'b> + for<'b> DecodeValue<'b>,
+    {
+        let attribute = match self.attribute(oid)? {
+            Some(attribute) => attribute,
+            None => return Ok(None),
+        };
+
+        match attribute.values.as_slice() {
+            [value] => Ok(Some(value.decode_as()?)),
+            _ => Err(Tag::Set.value_error().into()),
+        }
+    }
+
+    /// Get a context-specific representation of the attributes, if present.
+    fn attributes_field(&self) -> Option<ContextSpecific<AnyRef<'a>>> {
+        self.attributes.map(|value| ContextSpecific {
+            tag_number: ATTRIBUTES_TAG,
+            tag_mode: TagMode::Implicit,
+            value,
+        })
+    }
+
     /// Get a `BIT STRING` representation of the public key, if present.
     fn public_key_bit_string(&self) -> der::Result<Option<ContextSpecific<BitStringRef<'a>>>> {
         self.public_key
@@ -180,17 +354,24 @@ impl<'a> DecodeValue<'a> for PrivateKeyInfo<'a> {
             let version = Version::decode(reader)?;
             let algorithm = reader.decode()?;
             let private_key = OctetStringRef::decode(reader)?.into();
+            let attributes =
+                ContextSpecific::<AnyRef<'_>>::decode_implicit(reader, ATTRIBUTES_TAG)?
+                    .map(|field| {
+                        if !field.value.tag().is_constructed() {
+                            return Err(field.value.tag().non_canonical_error());
+                        }
+
+                        AnyRef::new(Tag::Set, field.value.value())
+                    })
+                    .transpose()?;
             let public_key = reader
                 .context_specific::<BitStringRef<'_>>(PUBLIC_KEY_TAG, TagMode::Implicit)?
-                .map(|bs| {
-                    bs.as_bytes()
-                        .ok_or_else(|| der::Tag::BitString.value_error())
-                })
+                .map(|bs| bs.as_bytes().ok_or_else(|| Tag::BitString.value_error()))
                 .transpose()?;
 
             if version.has_public_key() != public_key.is_some() {
                 return Err(reader.error(
-                    der::Tag::ContextSpecific {
+                    Tag::ContextSpecific {
                         constructed: true,
                         number: PUBLIC_KEY_TAG,
                     }
@@ -207,6 +388,7 @@ impl<'a> DecodeValue<'a> for PrivateKeyInfo<'a> {
             Ok(Self {
                 algorithm,
                 private_key,
+                attributes,
                 public_key,
             })
         })
@@ -218,6 +400,7 @@ impl EncodeValue for PrivateKeyInfo<'_> {
         self.version().encoded_len()?
             + self.algorithm.encoded_len()?
             + OctetStringRef::new(self.private_key)?.encoded_len()?
+            + self.attributes_field().encoded_len()?
             + self.public_key_bit_string()?.encoded_len()?
     }
 
@@ -225,6 +408,7 @@ impl EncodeValue for PrivateKeyInfo<'_> {
         self.version().encode(writer)?;
         self.algorithm.encode(writer)?;
         OctetStringRef::new(self.private_key)?.encode(writer)?;
+        self.attributes_field().encode(writer)?;
         self.public_key_bit_string()?.encode(writer)?;
         Ok(())
     }
@@ -245,6 +429,7 @@ impl<'a> fmt::Debug for PrivateKeyInfo<'a> {
         f.debug_struct("PrivateKeyInfo")
             .field("version", &self.version())
             .field("algorithm", &self.algorithm)
+            .field("attributes", &self.attributes)
             .field("public_key", &self.public_key)
             .finish_non_exhaustive()
     }
@@ -270,15 +455,16 @@ impl TryFrom<&PrivateKeyInfo<'_>> for SecretDocument {
 
 #[cfg(feature = "pem")]
 impl PemLabel for PrivateKeyInfo<'_> {
-    const PEM_LABEL: &'static str = "PRIVATE KEY";
+    const PEM_LABEL: &'static str = Label::PrivateKey.as_str();
 }
 
 #[cfg(feature = "subtle")]
 impl<'a> ConstantTimeEq for PrivateKeyInfo<'a> {
     fn ct_eq(&self, other: &Self) -> Choice {
         // NOTE: public fields are not compared in constant time
-        let public_fields_eq =
-            self.algorithm == other.algorithm && self.public_key == other.public_key;
+        let public_fields_eq = self.algorithm == other.algorithm
+            && self.attributes == other.attributes
+            && self.public_key == other.public_key;
 
         self.private_key.ct_eq(other.private_key) & Choice::from(public_fields_eq as u8)
     }
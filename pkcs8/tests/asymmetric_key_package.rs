@@ -0,0 +1,128 @@
+//! `AsymmetricKeyPackage` tests.
+
+#![cfg(feature = "alloc")]
+
+use der::Encode;
+use hex_literal::hex;
+use pkcs8::{AsymmetricKeyPackage, PrivateKeyInfo};
+
+#[cfg(feature = "pem")]
+use der::{pem::LineEnding, EncodePem, SecretDocument};
+
+#[cfg(feature = "encryption")]
+use pkcs8::EncryptedPrivateKeyInfo;
+
+/// Ed25519 PKCS#8 v1 private key encoded as ASN.1 DER.
+const ED25519_DER_V1_EXAMPLE: &[u8] = include_bytes!("examples/ed25519-priv-pkcs8v1.der");
+
+/// Ed25519 PKCS#8 v2 private key + public key encoded as ASN.1 DER.
+const ED25519_DER_V2_EXAMPLE: &[u8] = include_bytes!("examples/ed25519-priv-pkcs8v2.der");
+
+/// X25519 PKCS#8 private key encoded as ASN.1 DER.
+const X25519_DER_EXAMPLE: &[u8] = include_bytes!("examples/x25519-priv.der");
+
+/// Ed25519 PKCS#8 encrypted private key (PBES2 + AES-256-CBC + PBKDF2-SHA256)
+/// encoded as ASN.1 DER.
+#[cfg(feature = "encryption")]
+const ED25519_DER_AES256_PBKDF2_SHA256_EXAMPLE: &[u8] =
+    include_bytes!("examples/ed25519-encpriv-aes256-pbkdf2-sha256.der");
+
+/// Password used to encrypt the keys.
+#[cfg(feature = "encryption")]
+const PASSWORD: &[u8] = b"hunter42";
+
+fn example_package() -> AsymmetricKeyPackage<'static> {
+    AsymmetricKeyPackage::new(vec![
+        PrivateKeyInfo::try_from(ED25519_DER_V1_EXAMPLE).unwrap(),
+        PrivateKeyInfo::try_from(X25519_DER_EXAMPLE).unwrap(),
+    ])
+    .unwrap()
+}
+
+#[test]
+fn encode_decode_package() {
+    let package_der = example_package().to_der().unwrap();
+
+    // SEQUENCE header followed by the concatenated keys
+    assert_eq!(package_der[..2], hex!("3060"));
+    assert_eq!(
+        package_der[2..],
+        [ED25519_DER_V1_EXAMPLE, X25519_DER_EXAMPLE].concat()
+    );
+
+    let package = AsymmetricKeyPackage::try_from(package_der.as_slice()).unwrap();
+    assert_eq!(package.keys().len(), 2);
+
+    let oids = package
+        .iter()
+        .map(|key| key.algorithm.oid.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(oids, ["1.3.101.112", "1.3.101.110"]);
+}
+
+#[test]
+fn push_and_into_iter() {
+    let mut package = example_package();
+    package.push(PrivateKeyInfo::try_from(ED25519_DER_V2_EXAMPLE).unwrap());
+
+    let package_der = package.to_der().unwrap();
+    let package = AsymmetricKeyPackage::try_from(package_der.as_slice()).unwrap();
+    assert_eq!((&package).into_iter().count(), 3);
+
+    let keys = package.into_iter().collect::<Vec<_>>();
+    assert_eq!(keys[2].to_der().unwrap(), ED25519_DER_V2_EXAMPLE);
+}
+
+#[test]
+fn empty_package() {
+    assert!(AsymmetricKeyPackage::new(Vec::new()).is_err());
+    assert!(AsymmetricKeyPackage::try_from(&hex!("3000")[..]).is_err());
+}
+
+#[cfg(feature = "pem")]
+#[test]
+fn encode_package_pem() {
+    let pem = example_package().to_pem(LineEnding::LF).unwrap();
+    assert!(pem.starts_with("-----BEGIN ASYMMETRIC KEY PACKAGE-----\n"));
+
+    let (label, doc) = SecretDocument::from_pem(&pem).unwrap();
+    assert_eq!(label, "ASYMMETRIC KEY PACKAGE");
+
+    let package = AsymmetricKeyPackage::try_from(doc.as_bytes()).unwrap();
+    assert_eq!(package.keys().len(), 2);
+}
+
+#[cfg(feature = "encryption")]
+#[test]
+fn decrypt_keys() {
+    let encrypted =
+        EncryptedPrivateKeyInfo::try_from(ED25519_DER_AES256_PBKDF2_SHA256_EXAMPLE).unwrap();
+
+    let doc = AsymmetricKeyPackage::decrypt_keys([&encrypted, &encrypted], PASSWORD).unwrap();
+    let package = AsymmetricKeyPackage::try_from(doc.as_bytes()).unwrap();
+    assert_eq!(package.keys().len(), 2);
+
+    for key in &package {
+        assert_eq!(key.to_der().unwrap(), ED25519_DER_V1_EXAMPLE);
+    }
+
+    assert!(AsymmetricKeyPackage::decrypt_keys([&encrypted], b"wrong password").is_err());
+}
+
+#[cfg(all(feature = "encryption", feature = "getrandom"))]
+#[test]
+fn encrypt_keys_round_trip() {
+    use pkcs8::rand_core::OsRng;
+
+    let package = example_package();
+    let encrypted_docs = package.encrypt_keys(OsRng, PASSWORD).unwrap();
+    assert_eq!(encrypted_docs.len(), 2);
+
+    let encrypted_keys = encrypted_docs
+        .iter()
+        .map(|doc| EncryptedPrivateKeyInfo::try_from(doc.as_bytes()).unwrap())
+        .collect::<Vec<_>>();
+
+    let doc = AsymmetricKeyPackage::decrypt_keys(&encrypted_keys, PASSWORD).unwrap();
+    assert_eq!(doc.as_bytes(), package.to_der().unwrap());
+}
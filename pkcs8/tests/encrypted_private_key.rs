@@ -5,6 +5,9 @@
 use hex_literal::hex;
 use pkcs8::{pkcs5::pbes2, EncryptedPrivateKeyInfo, PrivateKeyInfo};
 
+#[cfg(any(feature = "encryption", feature = "aes-kw"))]
+use pkcs8::pkcs5;
+
 #[cfg(feature = "alloc")]
 use der::Encode;
 
@@ -12,7 +15,7 @@ use der::Encode;
 use der::EncodePem;
 
 /// Ed25519 PKCS#8 private key plaintext encoded as ASN.1 DER
-#[cfg(feature = "encryption")]
+#[cfg(any(feature = "encryption", feature = "aes-kw"))]
 const ED25519_DER_PLAINTEXT_EXAMPLE: &[u8] = include_bytes!("examples/ed25519-priv-pkcs8v1.der");
 
 /// Ed25519 PKCS#8 encrypted private key (PBES2 + AES-128-CBC + PBKDF2-SHA1) encoded as ASN.1 DER.
@@ -73,6 +76,17 @@ const ED25519_DER_DES3_PBKDF2_SHA256_EXAMPLE: &[u8] =
 const ED25519_DER_DES_PBKDF2_SHA256_EXAMPLE: &[u8] =
     include_bytes!("examples/ed25519-encpriv-des-pbkdf2-sha256.der");
 
+/// Ed25519 PKCS#8 encrypted private key (AES-256 key wrap with padding) encoded as ASN.1 DER
+///
+/// Wrapped with the KEK [`KEK`] using Python's `cryptography.hazmat.primitives.keywrap`.
+#[cfg(feature = "aes-kw")]
+const ED25519_DER_AES256_WRAP_PAD_EXAMPLE: &[u8] =
+    include_bytes!("examples/ed25519-encpriv-aes256-wrap-pad.der");
+
+/// Key-encryption key used to wrap the keys.
+#[cfg(feature = "aes-kw")]
+const KEK: [u8; 32] = hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f");
+
 /// Password used to encrypt the keys.
 #[cfg(feature = "encryption")]
 const PASSWORD: &[u8] = b"hunter42"; // Bad password; don't actually use outside tests!
@@ -232,3 +246,185 @@ fn decrypt_ed25519_der_encpriv_des_pbkdf2_sha256() {
     let pk = enc_pk.decrypt(PASSWORD).unwrap();
     assert_eq!(pk.as_bytes(), ED25519_DER_PLAINTEXT_EXAMPLE);
 }
+
+/// RNG returning predetermined bytes, so that the salt and IV can be chosen.
+#[cfg(feature = "encryption")]
+struct FixedRng<'a>(&'a [u8]);
+
+#[cfg(feature = "encryption")]
+impl pkcs8::rand_core::RngCore for FixedRng<'_> {
+    fn next_u32(&mut self) -> u32 {
+        unimplemented!()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        unimplemented!()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        let (bytes, rest) = self.0.split_at(dest.len());
+        dest.copy_from_slice(bytes);
+        self.0 = rest;
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), pkcs8::rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "encryption")]
+impl pkcs8::rand_core::CryptoRng for FixedRng<'_> {}
+
+#[cfg(feature = "encryption")]
+#[test]
+fn build_ed25519_der_encpriv_aes256_pbkdf2_sha256() {
+    let rng = FixedRng(&hex!("79d982e70df91a88 b2d02d78b2efd9dff694cf8e0af40925"));
+
+    let pk_encrypted = pkcs8::EncryptedPrivateKeyInfoBuilder::new()
+        .pbkdf2_sha256(2048)
+        .salt_len(8)
+        .cipher(pkcs8::EncryptionCipher::Aes256Cbc)
+        .encrypt(rng, PASSWORD, ED25519_DER_PLAINTEXT_EXAMPLE)
+        .unwrap();
+
+    assert_eq!(
+        pk_encrypted.as_bytes(),
+        ED25519_DER_AES256_PBKDF2_SHA256_EXAMPLE
+    );
+}
+
+#[cfg(feature = "encryption")]
+#[test]
+fn build_ed25519_der_encpriv_aes128_gcm_scrypt() {
+    let rng = FixedRng(&[0x42; 32]);
+
+    let pk_encrypted = pkcs8::EncryptedPrivateKeyInfoBuilder::new()
+        .scrypt(pkcs5::scrypt::Params::new(10, 8, 1, 16).unwrap())
+        .cipher(pkcs8::EncryptionCipher::Aes128Gcm)
+        .encrypt(rng, PASSWORD, ED25519_DER_PLAINTEXT_EXAMPLE)
+        .unwrap();
+
+    let enc_pk = EncryptedPrivateKeyInfo::try_from(pk_encrypted.as_bytes()).unwrap();
+    let params = enc_pk.encryption_algorithm.pbes2().unwrap();
+    let scrypt_params = params.kdf.scrypt().unwrap();
+    assert_eq!(scrypt_params.salt, &[0x42; 16]);
+    assert_eq!(scrypt_params.cost_parameter, 1024);
+    assert_eq!(
+        params.encryption,
+        pbes2::EncryptionScheme::Aes128Gcm {
+            nonce: &[0x42; 12],
+            icv_len: 16
+        }
+    );
+
+    let pk = enc_pk.decrypt(PASSWORD).unwrap();
+    assert_eq!(pk.as_bytes(), ED25519_DER_PLAINTEXT_EXAMPLE);
+}
+
+#[cfg(feature = "encryption")]
+#[test]
+fn build_encpriv_invalid_salt_len() {
+    for salt_len in [0, 7, 65] {
+        let result = pkcs8::EncryptedPrivateKeyInfoBuilder::new()
+            .pbkdf2_sha256(2048)
+            .salt_len(salt_len)
+            .encrypt(FixedRng(&[0; 96]), PASSWORD, ED25519_DER_PLAINTEXT_EXAMPLE);
+
+        assert_eq!(
+            result.err(),
+            Some(pkcs8::Error::EncryptedPrivateKey(
+                pkcs5::Error::AlgorithmParametersInvalid {
+                    oid: pbes2::PBKDF2_OID
+                }
+            ))
+        );
+    }
+}
+
+#[cfg(all(feature = "encryption", feature = "pem"))]
+#[test]
+fn build_ed25519_pem_encpriv_aes256_pbkdf2_sha256() {
+    let rng = FixedRng(&hex!("79d982e70df91a88 b2d02d78b2efd9dff694cf8e0af40925"));
+
+    let pem = pkcs8::EncryptedPrivateKeyInfoBuilder::new()
+        .pbkdf2_sha256(2048)
+        .salt_len(8)
+        .encrypt_pem(
+            rng,
+            PASSWORD,
+            ED25519_DER_PLAINTEXT_EXAMPLE,
+            Default::default(),
+        )
+        .unwrap();
+
+    assert_eq!(pem.as_str(), ED25519_PEM_AES256_PBKDF2_SHA256_EXAMPLE);
+}
+
+#[cfg(feature = "aes-kw")]
+#[test]
+fn decode_ed25519_encpriv_aes256_wrap_pad() {
+    let pk = EncryptedPrivateKeyInfo::try_from(ED25519_DER_AES256_WRAP_PAD_EXAMPLE).unwrap();
+    assert_eq!(
+        pk.encryption_algorithm.key_wrap(),
+        Some(pkcs5::kw::Algorithm::Aes256WrapPad)
+    );
+    assert_eq!(pk.encrypted_data.len(), 56);
+}
+
+#[cfg(feature = "aes-kw")]
+#[test]
+fn decrypt_ed25519_der_encpriv_aes256_wrap_pad() {
+    let enc_pk = EncryptedPrivateKeyInfo::try_from(ED25519_DER_AES256_WRAP_PAD_EXAMPLE).unwrap();
+    let pk = enc_pk.decrypt_with_kek(&KEK).unwrap();
+    assert_eq!(pk.as_bytes(), ED25519_DER_PLAINTEXT_EXAMPLE);
+
+    let mut wrong_kek = KEK;
+    wrong_kek[31] ^= 1;
+    assert_eq!(
+        enc_pk.decrypt_with_kek(&wrong_kek).err(),
+        Some(pkcs8::Error::EncryptedPrivateKey(
+            pkcs5::Error::DecryptFailed
+        ))
+    );
+}
+
+#[cfg(feature = "aes-kw")]
+#[test]
+fn decrypt_with_kek_requires_key_wrap() {
+    let enc_pk =
+        EncryptedPrivateKeyInfo::try_from(ED25519_DER_AES256_PBKDF2_SHA256_EXAMPLE).unwrap();
+    assert_eq!(
+        enc_pk.decrypt_with_kek(&KEK).err(),
+        Some(pkcs8::Error::EncryptedPrivateKey(
+            pkcs5::Error::UnsupportedAlgorithm {
+                oid: pbes2::PBES2_OID
+            }
+        ))
+    );
+}
+
+#[cfg(feature = "aes-kw")]
+#[test]
+fn encrypt_ed25519_der_encpriv_aes256_wrap_pad() {
+    let pk = PrivateKeyInfo::try_from(ED25519_DER_PLAINTEXT_EXAMPLE).unwrap();
+    let pk_encrypted = pk
+        .encrypt_with_kek(pkcs5::kw::Algorithm::Aes256WrapPad, &KEK)
+        .unwrap();
+
+    assert_eq!(pk_encrypted.as_bytes(), ED25519_DER_AES256_WRAP_PAD_EXAMPLE);
+
+    // The encoded key is a multiple of 8 bytes, so unpadded key wrap works too
+    let pk_encrypted = pk
+        .encrypt_with_kek(pkcs5::kw::Algorithm::Aes256Wrap, &KEK)
+        .unwrap();
+    let enc_pk = EncryptedPrivateKeyInfo::try_from(pk_encrypted.as_bytes()).unwrap();
+    assert_eq!(
+        enc_pk.encryption_algorithm.key_wrap(),
+        Some(pkcs5::kw::Algorithm::Aes256Wrap)
+    );
+    assert_eq!(
+        enc_pk.decrypt_with_kek(&KEK).unwrap().as_bytes(),
+        ED25519_DER_PLAINTEXT_EXAMPLE
+    );
+}
@@ -1,8 +1,11 @@
 //! PKCS#8 private key tests
 
-use der::asn1::ObjectIdentifier;
+use der::{
+    asn1::{AnyRef, ObjectIdentifier},
+    Tag, Tagged,
+};
 use hex_literal::hex;
-use pkcs8::{PrivateKeyInfo, Version};
+use pkcs8::{PrivateKeyInfo, SubjectPublicKeyInfoRef, Version};
 
 #[cfg(feature = "alloc")]
 use der::Encode;
@@ -19,6 +22,12 @@ const ED25519_DER_V1_EXAMPLE: &[u8] = include_bytes!("examples/ed25519-priv-pkcs
 /// Ed25519 PKCS#8 v2 private key + public key encoded as ASN.1 DER
 const ED25519_DER_V2_EXAMPLE: &[u8] = include_bytes!("examples/ed25519-priv-pkcs8v2.der");
 
+/// Ed25519 public key encoded as ASN.1 DER, matching [`ED25519_DER_V1_EXAMPLE`]
+const ED25519_PUB_DER_EXAMPLE: &[u8] = include_bytes!("examples/ed25519-pub.der");
+
+/// Elliptic Curve (P-256) public key encoded as ASN.1 DER
+const EC_P256_PUB_DER_EXAMPLE: &[u8] = include_bytes!("examples/p256-pub.der");
+
 /// RSA-2048 PKCS#8 private key encoded as ASN.1 DER
 const RSA_2048_DER_EXAMPLE: &[u8] = include_bytes!("examples/rsa2048-priv.der");
 
@@ -96,6 +105,74 @@ fn decode_ed25519_der_v2() {
     assert_eq!(pk.algorithm.parameters, None);
     assert_eq!(pk.private_key, PRIV_KEY);
     assert_eq!(pk.public_key, Some(&PUB_KEY[..]));
+    assert_eq!(pk.attributes.unwrap().tag(), Tag::Set);
+}
+
+#[test]
+#[cfg(feature = "x509-cert")]
+fn decode_ed25519_der_v2_attributes() {
+    let pk = PrivateKeyInfo::try_from(ED25519_DER_V2_EXAMPLE).unwrap();
+    let attributes = pk.decode_attributes().unwrap();
+    assert_eq!(attributes.len(), 1);
+
+    // Attribute from RFC 5958 Section 2
+    let oid = "1.2.840.113549.1.9.9.20".parse().unwrap();
+    let attribute = pk.attribute(oid).unwrap().unwrap();
+    assert_eq!(attribute.values.len(), 1);
+
+    let value = attribute.values.get(0).unwrap();
+    assert_eq!(value.tag(), Tag::Utf8String);
+    assert_eq!(value.value(), b"Curdle Chairs");
+
+    assert_eq!(pk.friendly_name().unwrap(), None);
+    assert_eq!(pk.local_key_id().unwrap(), None);
+    assert_eq!(pk.key_usage_period().unwrap(), None);
+}
+
+#[test]
+fn ed25519_public_key_info() {
+    let pk = PrivateKeyInfo::try_from(ED25519_DER_V1_EXAMPLE).unwrap();
+    assert_eq!(pk.public_key_info().unwrap(), None);
+
+    let pk = PrivateKeyInfo::try_from(ED25519_DER_V2_EXAMPLE).unwrap();
+    let spki = pk.public_key_info().unwrap().unwrap();
+    assert_eq!(spki.algorithm, pk.algorithm);
+    assert_eq!(spki.subject_public_key.as_bytes(), pk.public_key);
+}
+
+#[test]
+fn ed25519_with_public_key_info() {
+    let spki = SubjectPublicKeyInfoRef::try_from(ED25519_PUB_DER_EXAMPLE).unwrap();
+    let pk = PrivateKeyInfo::try_from(ED25519_DER_V1_EXAMPLE)
+        .unwrap()
+        .with_public_key_info(&spki)
+        .unwrap();
+
+    assert_eq!(pk.version(), Version::V2);
+    assert_eq!(pk.public_key, spki.subject_public_key.as_bytes());
+    assert_eq!(pk.public_key_info().unwrap(), Some(spki));
+}
+
+#[test]
+fn ed25519_with_public_key_info_algorithm_mismatch() {
+    let spki = SubjectPublicKeyInfoRef::try_from(EC_P256_PUB_DER_EXAMPLE).unwrap();
+    let result = PrivateKeyInfo::try_from(ED25519_DER_V1_EXAMPLE)
+        .unwrap()
+        .with_public_key_info(&spki);
+
+    assert_eq!(
+        result.err(),
+        Some(pkcs8::Error::PublicKey(pkcs8::spki::Error::OidUnknown {
+            oid: "1.3.101.112".parse().unwrap()
+        }))
+    );
+}
+
+#[test]
+fn ed25519_with_attributes_not_set() {
+    let pk = PrivateKeyInfo::try_from(ED25519_DER_V1_EXAMPLE).unwrap();
+    let attributes = AnyRef::new(Tag::Sequence, &[]).unwrap();
+    assert!(pk.with_attributes(attributes).is_err());
 }
 
 #[test]
@@ -149,6 +226,89 @@ fn encode_ed25519_der_v2() {
         private_key,
         PrivateKeyInfo::try_from(private_der.as_ref()).unwrap()
     );
+    assert_eq!(ED25519_DER_V2_EXAMPLE, private_der);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn encode_ed25519_der_with_public_key() {
+    let pk_v2 = PrivateKeyInfo::try_from(ED25519_DER_V2_EXAMPLE).unwrap();
+    let pk = PrivateKeyInfo::new(pk_v2.algorithm, pk_v2.private_key)
+        .with_public_key(pk_v2.public_key.unwrap());
+
+    assert_eq!(pk.version(), Version::V2);
+
+    // NOTE: the example contains attributes, which aren't copied here
+    let pk_der = pk.to_der().unwrap();
+    let pk = PrivateKeyInfo::try_from(pk_der.as_slice()).unwrap();
+    assert_eq!(pk.version(), Version::V2);
+    assert_eq!(pk.private_key, pk_v2.private_key);
+    assert_eq!(pk.public_key, pk_v2.public_key);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn encode_ed25519_der_with_public_key_info() {
+    let spki = SubjectPublicKeyInfoRef::try_from(ED25519_PUB_DER_EXAMPLE).unwrap();
+    let pk_der = PrivateKeyInfo::try_from(ED25519_DER_V1_EXAMPLE)
+        .unwrap()
+        .with_public_key_info(&spki)
+        .unwrap()
+        .to_der()
+        .unwrap();
+
+    let pk = PrivateKeyInfo::try_from(pk_der.as_slice()).unwrap();
+    assert_eq!(pk.version(), Version::V2);
+
+    let spki_der = pk.public_key_info().unwrap().unwrap().to_der().unwrap();
+    assert_eq!(ED25519_PUB_DER_EXAMPLE, spki_der);
+}
+
+#[test]
+#[cfg(feature = "x509-cert")]
+fn encode_ed25519_der_with_attributes() {
+    use core::time::Duration;
+    use der::asn1::GeneralizedTime;
+    use pkcs8::{x509_cert::ext::pkix::PrivateKeyUsagePeriod, AttributesBuilder};
+
+    let period = PrivateKeyUsagePeriod {
+        not_before: Some(GeneralizedTime::from_unix_duration(Duration::from_secs(0)).unwrap()),
+        not_after: None,
+    };
+
+    let attributes = AttributesBuilder::new()
+        .friendly_name("old name")
+        .unwrap()
+        .local_key_id(&[1, 2, 3, 4])
+        .unwrap()
+        .key_usage_period(&period)
+        .unwrap()
+        .friendly_name("Ed25519 key")
+        .unwrap()
+        .to_der()
+        .unwrap();
+
+    let pk_der = PrivateKeyInfo::try_from(ED25519_DER_V1_EXAMPLE)
+        .unwrap()
+        .with_attributes(AnyRef::try_from(attributes.as_slice()).unwrap())
+        .unwrap()
+        .to_der()
+        .unwrap();
+
+    let pk = PrivateKeyInfo::try_from(pk_der.as_slice()).unwrap();
+    assert_eq!(pk.version(), Version::V1);
+    assert_eq!(pk.decode_attributes().unwrap().len(), 3);
+    assert_eq!(pk.friendly_name().unwrap().as_deref(), Some("Ed25519 key"));
+    assert_eq!(
+        pk.local_key_id().unwrap().as_deref(),
+        Some(&[1, 2, 3, 4][..])
+    );
+    assert_eq!(pk.key_usage_period().unwrap(), Some(period));
+
+    let attributes = AttributesBuilder::from_attributes(pk.decode_attributes().unwrap())
+        .remove(pkcs8::PKCS_9_AT_FRIENDLY_NAME)
+        .build();
+    assert_eq!(attributes.len(), 2);
 }
 
 #[test]
@@ -34,6 +34,8 @@ pub mod point;
 
 mod error;
 #[cfg(feature = "der")]
+mod named_curve;
+#[cfg(feature = "der")]
 mod parameters;
 #[cfg(feature = "der")]
 mod private_key;
@@ -52,7 +54,15 @@ pub use crate::point::EncodedPoint;
 pub use generic_array::typenum::consts;
 
 #[cfg(feature = "der")]
-pub use crate::{parameters::EcParameters, private_key::EcPrivateKey, traits::DecodeEcPrivateKey};
+pub use crate::{
+    named_curve::NamedCurve,
+    parameters::{
+        Curve, EcParameters, FieldId, SpecifiedEcDomain, CHARACTERISTIC_TWO_FIELD_OID,
+        PRIME_FIELD_OID,
+    },
+    private_key::EcPrivateKey,
+    traits::DecodeEcPrivateKey,
+};
 
 #[cfg(all(feature = "alloc", feature = "der"))]
 pub use crate::traits::EncodeEcPrivateKey;
@@ -0,0 +1,207 @@
+//! Registry of common named elliptic curves.
+
+use crate::{EcPrivateKey, Result, SpecifiedEcDomain};
+use core::fmt;
+use der::{asn1::ObjectIdentifier, Decode};
+
+#[cfg(feature = "point")]
+use crate::{point::Tag, Error};
+
+/// Named elliptic curve, as identified by the `namedCurve` choice of
+/// [`EcParameters`][`crate::EcParameters`].
+///
+/// This registry covers the NIST, SEC and Brainpool curves in common use.
+/// It describes the sizes of each curve's field elements and scalars, which
+/// allows validating the lengths of keys and points without depending on a
+/// crate implementing the curve arithmetic, as well as each curve's domain
+/// parameters, which allows recognizing explicitly specified curves.
+///
+/// ```
+/// use sec1::{der::asn1::ObjectIdentifier, NamedCurve};
+///
+/// let oid = ObjectIdentifier::new_unwrap("1.2.840.10045.3.1.7");
+/// let curve = NamedCurve::from_oid(oid).unwrap();
+/// assert_eq!(curve.name, "secp256r1");
+/// assert_eq!(curve.nist_name, Some("P-256"));
+/// assert_eq!(curve.uncompressed_point_len(), 65);
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct NamedCurve {
+    /// Object identifier for this curve.
+    pub oid: ObjectIdentifier,
+
+    /// Name of this curve as used in [RFC 5480] and [RFC 5639], e.g.
+    /// `secp256r1` or `brainpoolP256r1`.
+    ///
+    /// [RFC 5480]: https://datatracker.ietf.org/doc/html/rfc5480
+    /// [RFC 5639]: https://datatracker.ietf.org/doc/html/rfc5639
+    pub name: &'static str,
+
+    /// Name of this curve in [FIPS 186-4], e.g. `P-256`, if any.
+    ///
+    /// [FIPS 186-4]: https://csrc.nist.gov/publications/detail/fips/186/4/final
+    pub nist_name: Option<&'static str>,
+
+    /// Size of a serialized base field element in bytes.
+    pub field_size: usize,
+
+    /// Size of a serialized scalar (i.e. private key) in bytes.
+    pub scalar_size: usize,
+
+    /// ASN.1 DER-encoded `SpecifiedECDomain` for this curve.
+    domain: &'static [u8],
+}
+
+macro_rules! named_curves {
+    ($($(#[$attr:meta])* $const:ident: $oid:expr, $name:literal, $nist_name:expr, $field_size:expr, $scalar_size:expr;)+) => {
+        impl NamedCurve {
+            $(
+                $(#[$attr])*
+                pub const $const: Self = Self {
+                    oid: ObjectIdentifier::new_unwrap($oid),
+                    name: $name,
+                    nist_name: $nist_name,
+                    field_size: $field_size,
+                    scalar_size: $scalar_size,
+                    domain: include_bytes!(concat!("named_curve/", $name, ".der")),
+                };
+            )+
+
+            /// All curves in this registry.
+            pub const ALL: &'static [Self] = &[$(Self::$const),+];
+        }
+    };
+}
+
+named_curves! {
+    /// NIST P-192 (`secp192r1`)
+    SECP192R1: "1.2.840.10045.3.1.1", "secp192r1", Some("P-192"), 24, 24;
+
+    /// NIST P-224 (`secp224r1`)
+    SECP224R1: "1.3.132.0.33", "secp224r1", Some("P-224"), 28, 28;
+
+    /// NIST P-256 (`secp256r1`)
+    SECP256R1: "1.2.840.10045.3.1.7", "secp256r1", Some("P-256"), 32, 32;
+
+    /// NIST P-384 (`secp384r1`)
+    SECP384R1: "1.3.132.0.34", "secp384r1", Some("P-384"), 48, 48;
+
+    /// NIST P-521 (`secp521r1`)
+    SECP521R1: "1.3.132.0.35", "secp521r1", Some("P-521"), 66, 66;
+
+    /// SEC `secp192k1`
+    SECP192K1: "1.3.132.0.31", "secp192k1", None, 24, 24;
+
+    /// SEC `secp256k1`
+    SECP256K1: "1.3.132.0.10", "secp256k1", None, 32, 32;
+
+    /// Brainpool `brainpoolP224r1`
+    BRAINPOOL_P224R1: "1.3.36.3.3.2.8.1.1.5", "brainpoolP224r1", None, 28, 28;
+
+    /// Brainpool `brainpoolP224t1`
+    BRAINPOOL_P224T1: "1.3.36.3.3.2.8.1.1.6", "brainpoolP224t1", None, 28, 28;
+
+    /// Brainpool `brainpoolP256r1`
+    BRAINPOOL_P256R1: "1.3.36.3.3.2.8.1.1.7", "brainpoolP256r1", None, 32, 32;
+
+    /// Brainpool `brainpoolP256t1`
+    BRAINPOOL_P256T1: "1.3.36.3.3.2.8.1.1.8", "brainpoolP256t1", None, 32, 32;
+
+    /// Brainpool `brainpoolP320r1`
+    BRAINPOOL_P320R1: "1.3.36.3.3.2.8.1.1.9", "brainpoolP320r1", None, 40, 40;
+
+    /// Brainpool `brainpoolP320t1`
+    BRAINPOOL_P320T1: "1.3.36.3.3.2.8.1.1.10", "brainpoolP320t1", None, 40, 40;
+
+    /// Brainpool `brainpoolP384r1`
+    BRAINPOOL_P384R1: "1.3.36.3.3.2.8.1.1.11", "brainpoolP384r1", None, 48, 48;
+
+    /// Brainpool `brainpoolP384t1`
+    BRAINPOOL_P384T1: "1.3.36.3.3.2.8.1.1.12", "brainpoolP384t1", None, 48, 48;
+
+    /// Brainpool `brainpoolP512r1`
+    BRAINPOOL_P512R1: "1.3.36.3.3.2.8.1.1.13", "brainpoolP512r1", None, 64, 64;
+
+    /// Brainpool `brainpoolP512t1`
+    BRAINPOOL_P512T1: "1.3.36.3.3.2.8.1.1.14", "brainpoolP512t1", None, 64, 64;
+}
+
+impl NamedCurve {
+    /// Look up a curve by its [`ObjectIdentifier`].
+    pub fn from_oid(oid: ObjectIdentifier) -> Option<&'static Self> {
+        Self::ALL.iter().find(|curve| curve.oid == oid)
+    }
+
+    /// Look up a curve by its name or NIST name, e.g. `secp256r1` or
+    /// `P-256`.
+    pub fn from_name(name: &str) -> Option<&'static Self> {
+        Self::ALL
+            .iter()
+            .find(|curve| curve.name == name || curve.nist_name == Some(name))
+    }
+
+    /// Get the explicitly specified domain parameters of this curve, i.e.
+    /// its field, curve coefficients, base point, order and cofactor.
+    pub fn domain_parameters(&self) -> SpecifiedEcDomain<'static> {
+        SpecifiedEcDomain::from_der(self.domain).expect("invalid built-in domain parameters")
+    }
+
+    /// Get the length of a compressed SEC1-encoded point on this curve,
+    /// including the tag byte.
+    pub fn compressed_point_len(&self) -> usize {
+        1 + self.field_size
+    }
+
+    /// Get the length of an uncompressed SEC1-encoded point on this curve,
+    /// including the tag byte.
+    pub fn uncompressed_point_len(&self) -> usize {
+        1 + 2 * self.field_size
+    }
+
+    /// Check that the given SEC1-encoded point has a valid tag and the
+    /// length corresponding to it for this curve.
+    ///
+    /// This doesn't check that the point is actually on the curve.
+    #[cfg(feature = "point")]
+    pub fn validate_point(&self, point: &[u8]) -> Result<()> {
+        let tag = point
+            .first()
+            .cloned()
+            .ok_or(Error::PointEncoding)
+            .and_then(Tag::from_u8)?;
+
+        if point.len() == tag.message_len(self.field_size) {
+            Ok(())
+        } else {
+            Err(Error::PointEncoding)
+        }
+    }
+
+    /// Check that the given [`EcPrivateKey`] has a private key (and public
+    /// key, if present) of the correct length for this curve, and that its
+    /// parameters, if present, describe this curve.
+    pub fn validate_private_key(&self, private_key: &EcPrivateKey<'_>) -> Result<()> {
+        if let Some(params) = private_key.parameters {
+            if params.curve_oid() != Some(self.oid) {
+                return Err(der::Tag::ObjectIdentifier.value_error().into());
+            }
+        }
+
+        if private_key.private_key.len() != self.scalar_size {
+            return Err(der::Tag::OctetString.length_error().into());
+        }
+
+        #[cfg(feature = "point")]
+        if let Some(public_key) = private_key.public_key {
+            self.validate_point(public_key)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for NamedCurve {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name)
+    }
+}
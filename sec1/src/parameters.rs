@@ -1,6 +1,13 @@
+mod specified;
+
+pub use self::specified::{
+    Curve, FieldId, SpecifiedEcDomain, CHARACTERISTIC_TWO_FIELD_OID, PRIME_FIELD_OID,
+};
+
+use crate::NamedCurve;
 use der::{
     asn1::{AnyRef, ObjectIdentifier},
-    DecodeValue, EncodeValue, FixedTag, Header, Length, Reader, Tag, Writer,
+    Choice, Decode, DecodeValue, EncodeValue, Header, Length, Reader, Tag, Tagged, Writer,
 };
 
 /// Elliptic curve parameters as described in
@@ -17,59 +24,140 @@ use der::{
 ///   -- Any future additions to this CHOICE should be coordinated
 ///   -- with ANSI X9.
 /// ```
+///
+/// Though not permitted in PKIX, the `specifiedCurve` choice is supported
+/// for compatibility with legacy software and hardware which emits explicit
+/// curve parameters. [`EcParameters::curve_oid`] can be used to map such
+/// parameters back to a named curve.
+#[allow(clippy::large_enum_variant)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
-pub enum EcParameters {
+pub enum EcParameters<'a> {
     /// Elliptic curve named by a particular OID.
     ///
     /// > namedCurve identifies all the required values for a particular
     /// > set of elliptic curve domain parameters to be represented by an
     /// > object identifier.
     NamedCurve(ObjectIdentifier),
+
+    /// Elliptic curve with explicitly specified domain parameters.
+    SpecifiedCurve(SpecifiedEcDomain<'a>),
+}
+
+impl<'a> EcParameters<'a> {
+    /// Obtain the `namedCurve` OID.
+    ///
+    /// Returns `None` for `specifiedCurve` parameters, even if they describe
+    /// a named curve. Use [`EcParameters::curve_oid`] to also map those.
+    pub fn named_curve(self) -> Option<ObjectIdentifier> {
+        match self {
+            Self::NamedCurve(oid) => Some(oid),
+            Self::SpecifiedCurve(_) => None,
+        }
+    }
+
+    /// Obtain the `specifiedCurve` domain parameters.
+    pub fn specified_curve(self) -> Option<SpecifiedEcDomain<'a>> {
+        match self {
+            Self::NamedCurve(_) => None,
+            Self::SpecifiedCurve(domain) => Some(domain),
+        }
+    }
+
+    /// Obtain the OID of the curve described by these parameters.
+    ///
+    /// For `specifiedCurve` parameters, this is a best-effort mapping to a
+    /// [`NamedCurve`] as described in [`SpecifiedEcDomain::to_named_curve`].
+    pub fn curve_oid(self) -> Option<ObjectIdentifier> {
+        match self {
+            Self::NamedCurve(oid) => Some(oid),
+            Self::SpecifiedCurve(domain) => domain.to_named_curve().map(|curve| curve.oid),
+        }
+    }
+
+    /// Obtain the [`NamedCurve`] described by these parameters, if it is in
+    /// the registry.
+    pub fn to_named_curve(self) -> Option<&'static NamedCurve> {
+        match self {
+            Self::NamedCurve(oid) => NamedCurve::from_oid(oid),
+            Self::SpecifiedCurve(domain) => domain.to_named_curve(),
+        }
+    }
+}
+
+impl<'a> Choice<'a> for EcParameters<'a> {
+    fn can_decode(tag: Tag) -> bool {
+        matches!(tag, Tag::ObjectIdentifier | Tag::Sequence)
+    }
 }
 
-impl<'a> DecodeValue<'a> for EcParameters {
+impl<'a> Decode<'a> for EcParameters<'a> {
+    fn decode<R: Reader<'a>>(reader: &mut R) -> der::Result<Self> {
+        let header = Header::decode(reader)?;
+        Self::decode_value(reader, header)
+    }
+}
+
+impl<'a> DecodeValue<'a> for EcParameters<'a> {
     fn decode_value<R: Reader<'a>>(decoder: &mut R, header: Header) -> der::Result<Self> {
-        ObjectIdentifier::decode_value(decoder, header).map(Self::NamedCurve)
+        match header.tag {
+            Tag::ObjectIdentifier => {
+                ObjectIdentifier::decode_value(decoder, header).map(Self::NamedCurve)
+            }
+            Tag::Sequence => {
+                SpecifiedEcDomain::decode_value(decoder, header).map(Self::SpecifiedCurve)
+            }
+            actual => Err(actual.unexpected_error(None)),
+        }
     }
 }
 
-impl EncodeValue for EcParameters {
+impl EncodeValue for EcParameters<'_> {
     fn value_len(&self) -> der::Result<Length> {
         match self {
             Self::NamedCurve(oid) => oid.value_len(),
+            Self::SpecifiedCurve(domain) => domain.value_len(),
         }
     }
 
     fn encode_value(&self, writer: &mut impl Writer) -> der::Result<()> {
         match self {
             Self::NamedCurve(oid) => oid.encode_value(writer),
+            Self::SpecifiedCurve(domain) => domain.encode_value(writer),
         }
     }
 }
 
-impl EcParameters {
-    /// Obtain the `namedCurve` OID.
-    pub fn named_curve(self) -> Option<ObjectIdentifier> {
+impl Tagged for EcParameters<'_> {
+    fn tag(&self) -> Tag {
         match self {
-            Self::NamedCurve(oid) => Some(oid),
+            Self::NamedCurve(_) => Tag::ObjectIdentifier,
+            Self::SpecifiedCurve(_) => Tag::Sequence,
         }
     }
 }
 
-impl<'a> From<&'a EcParameters> for AnyRef<'a> {
-    fn from(params: &'a EcParameters) -> AnyRef<'a> {
+/// NOTE: `specifiedCurve` parameters can't be borrowed as an [`AnyRef`], as
+/// they aren't stored in their encoded form, so this conversion fails for
+/// them.
+impl<'a> TryFrom<&'a EcParameters<'_>> for AnyRef<'a> {
+    type Error = der::Error;
+
+    fn try_from(params: &'a EcParameters<'_>) -> der::Result<AnyRef<'a>> {
         match params {
-            EcParameters::NamedCurve(oid) => oid.into(),
+            EcParameters::NamedCurve(oid) => Ok(oid.into()),
+            EcParameters::SpecifiedCurve(_) => Err(Tag::Sequence.value_error()),
         }
     }
 }
 
-impl From<ObjectIdentifier> for EcParameters {
-    fn from(oid: ObjectIdentifier) -> EcParameters {
+impl From<ObjectIdentifier> for EcParameters<'_> {
+    fn from(oid: ObjectIdentifier) -> Self {
         EcParameters::NamedCurve(oid)
     }
 }
 
-impl FixedTag for EcParameters {
-    const TAG: Tag = Tag::ObjectIdentifier;
+impl<'a> From<SpecifiedEcDomain<'a>> for EcParameters<'a> {
+    fn from(domain: SpecifiedEcDomain<'a>) -> Self {
+        EcParameters::SpecifiedCurve(domain)
+    }
 }
@@ -0,0 +1,295 @@
+//! Explicitly specified elliptic curve domain parameters.
+
+use crate::NamedCurve;
+use der::{
+    asn1::{AnyRef, BitStringRef, ObjectIdentifier, OctetStringRef, UintRef},
+    Decode, DecodeValue, Encode, EncodeValue, Header, Length, Reader, Sequence, Tag, Writer,
+};
+
+/// Object identifier for prime fields (`prime-field`).
+///
+/// <http://oid-info.com/get/1.2.840.10045.1.1>
+pub const PRIME_FIELD_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.1.1");
+
+/// Object identifier for characteristic-two fields
+/// (`characteristic-two-field`).
+///
+/// <http://oid-info.com/get/1.2.840.10045.1.2>
+pub const CHARACTERISTIC_TWO_FIELD_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.10045.1.2");
+
+/// Lowest supported `SpecifiedECDomainVersion` (`ecdpVer1`).
+const MIN_VERSION: u8 = 1;
+
+/// Highest supported `SpecifiedECDomainVersion` (`ecdpVer3`).
+const MAX_VERSION: u8 = 3;
+
+/// Explicitly specified elliptic curve domain parameters.
+///
+/// Described in [SEC1: Elliptic Curve Cryptography (Version 2.0)]
+/// Appendix C.2 (p.104):
+///
+/// ```text
+/// SpecifiedECDomain ::= SEQUENCE {
+///   version   SpecifiedECDomainVersion(ecdpVer1 | ecdpVer2 | ecdpVer3, ...),
+///   fieldID   FieldID {{FieldTypes}},
+///   curve     Curve,
+///   base      ECPoint,
+///   order     INTEGER,
+///   cofactor  INTEGER OPTIONAL,
+///   hash      HashAlgorithm OPTIONAL,
+///   ...
+/// }
+/// ```
+///
+/// [SEC1: Elliptic Curve Cryptography (Version 2.0)]: https://www.secg.org/sec1-v2.pdf
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct SpecifiedEcDomain<'a> {
+    /// Version of these domain parameters (`ecdpVer1` through `ecdpVer3`).
+    pub version: u8,
+
+    /// Finite field over which the curve is defined.
+    pub field_id: FieldId<'a>,
+
+    /// Curve coefficients.
+    pub curve: Curve<'a>,
+
+    /// SEC1-encoded base point of the curve.
+    pub base: &'a [u8],
+
+    /// Order of the base point.
+    pub order: UintRef<'a>,
+
+    /// Cofactor of the curve, i.e. its number of points divided by `order`.
+    pub cofactor: Option<UintRef<'a>>,
+
+    /// Hash algorithm used to generate the curve from its seed.
+    pub hash: Option<AnyRef<'a>>,
+}
+
+impl<'a> SpecifiedEcDomain<'a> {
+    /// Find the [`NamedCurve`] these domain parameters describe, if any.
+    ///
+    /// This is a best-effort mapping: the parameters match a curve if the
+    /// field, curve coefficients, base point and order (as well as the
+    /// cofactor, if present) are all equal to those of the named curve.
+    /// The seed, hash algorithm and version are ignored, as is the encoding
+    /// of the base point, i.e. a compressed base point matches too.
+    ///
+    /// Note that this doesn't validate the parameters, so a `None` result
+    /// doesn't imply that they don't describe a valid curve.
+    pub fn to_named_curve(&self) -> Option<&'static NamedCurve> {
+        NamedCurve::ALL
+            .iter()
+            .find(|curve| curve.domain_parameters().is_equivalent(self))
+    }
+
+    /// Compare the values of the parameters which define the curve and base
+    /// point, where `self` has an uncompressed base point.
+    fn is_equivalent(&self, other: &SpecifiedEcDomain<'_>) -> bool {
+        if self.field_id != other.field_id
+            || !field_elements_eq(self.curve.a, other.curve.a)
+            || !field_elements_eq(self.curve.b, other.curve.b)
+            || self.order != other.order
+        {
+            return false;
+        }
+
+        if let Some(cofactor) = other.cofactor {
+            if self.cofactor != Some(cofactor) {
+                return false;
+            }
+        }
+
+        let field_size = match self.base.len().checked_sub(1) {
+            Some(len) if len % 2 == 0 => len / 2,
+            _ => return false,
+        };
+
+        let (x, y) = self.base[1..].split_at(field_size);
+        let y_is_odd = y.last().map_or(false, |byte| byte & 1 == 1);
+
+        match other.base.split_first() {
+            // Uncompressed
+            Some((0x04, point)) => point == &self.base[1..],
+            // Compressed
+            Some((&tag @ (0x02 | 0x03), other_x)) => other_x == x && (tag == 0x03) == y_is_odd,
+            // Hybrid
+            Some((&tag @ (0x06 | 0x07), point)) => {
+                point == &self.base[1..] && (tag == 0x07) == y_is_odd
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<'a> DecodeValue<'a> for SpecifiedEcDomain<'a> {
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> der::Result<Self> {
+        reader.read_nested(header.length, |reader| {
+            let version = u8::decode(reader)?;
+
+            if !(MIN_VERSION..=MAX_VERSION).contains(&version) {
+                return Err(Tag::Integer.value_error());
+            }
+
+            Ok(Self {
+                version,
+                field_id: reader.decode()?,
+                curve: reader.decode()?,
+                base: OctetStringRef::decode(reader)?.as_bytes(),
+                order: reader.decode()?,
+                cofactor: reader.decode()?,
+                hash: reader.decode()?,
+            })
+        })
+    }
+}
+
+impl EncodeValue for SpecifiedEcDomain<'_> {
+    fn value_len(&self) -> der::Result<Length> {
+        self.version.encoded_len()?
+            + self.field_id.encoded_len()?
+            + self.curve.encoded_len()?
+            + OctetStringRef::new(self.base)?.encoded_len()?
+            + self.order.encoded_len()?
+            + self.cofactor.encoded_len()?
+            + self.hash.encoded_len()?
+    }
+
+    fn encode_value(&self, writer: &mut impl Writer) -> der::Result<()> {
+        self.version.encode(writer)?;
+        self.field_id.encode(writer)?;
+        self.curve.encode(writer)?;
+        OctetStringRef::new(self.base)?.encode(writer)?;
+        self.order.encode(writer)?;
+        self.cofactor.encode(writer)?;
+        self.hash.encode(writer)?;
+        Ok(())
+    }
+}
+
+impl<'a> Sequence<'a> for SpecifiedEcDomain<'a> {}
+
+/// Finite field over which a curve is defined.
+///
+/// ```text
+/// FieldID { FIELD-ID:IOSet } ::= SEQUENCE {
+///   fieldType   FIELD-ID.&id({IOSet}),
+///   parameters  FIELD-ID.&Type({IOSet}{@fieldType})
+/// }
+/// ```
+///
+/// For [`PRIME_FIELD_OID`] the parameters are the prime `p` as an
+/// `INTEGER`, which can be obtained using [`FieldId::prime`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct FieldId<'a> {
+    /// Object identifier for the type of field.
+    pub field_type: ObjectIdentifier,
+
+    /// Field parameters, whose type depends on `field_type`.
+    pub parameters: AnyRef<'a>,
+}
+
+impl<'a> FieldId<'a> {
+    /// Get the prime `p` of a prime field.
+    ///
+    /// Returns `Ok(None)` if this isn't a prime field.
+    pub fn prime(&self) -> der::Result<Option<UintRef<'a>>> {
+        if self.field_type == PRIME_FIELD_OID {
+            self.parameters.decode_as().map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+impl<'a> DecodeValue<'a> for FieldId<'a> {
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> der::Result<Self> {
+        reader.read_nested(header.length, |reader| {
+            Ok(Self {
+                field_type: reader.decode()?,
+                parameters: reader.decode()?,
+            })
+        })
+    }
+}
+
+impl EncodeValue for FieldId<'_> {
+    fn value_len(&self) -> der::Result<Length> {
+        self.field_type.encoded_len()? + self.parameters.encoded_len()?
+    }
+
+    fn encode_value(&self, writer: &mut impl Writer) -> der::Result<()> {
+        self.field_type.encode(writer)?;
+        self.parameters.encode(writer)
+    }
+}
+
+impl<'a> Sequence<'a> for FieldId<'a> {}
+
+/// Coefficients of the curve equation, e.g. `y² = x³ + ax + b` for curves
+/// over prime fields.
+///
+/// ```text
+/// Curve ::= SEQUENCE {
+///   a     FieldElement,
+///   b     FieldElement,
+///   seed  BIT STRING OPTIONAL
+///   -- Shall be present if used in SpecifiedECDomain
+///   -- with version equal to ecdpVer2 or ecdpVer3
+/// }
+///
+/// FieldElement ::= OCTET STRING
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Curve<'a> {
+    /// Serialized field element `a`.
+    pub a: &'a [u8],
+
+    /// Serialized field element `b`.
+    pub b: &'a [u8],
+
+    /// Seed from which the curve was generated, if any.
+    pub seed: Option<BitStringRef<'a>>,
+}
+
+impl<'a> DecodeValue<'a> for Curve<'a> {
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> der::Result<Self> {
+        reader.read_nested(header.length, |reader| {
+            Ok(Self {
+                a: OctetStringRef::decode(reader)?.as_bytes(),
+                b: OctetStringRef::decode(reader)?.as_bytes(),
+                seed: reader.decode()?,
+            })
+        })
+    }
+}
+
+impl EncodeValue for Curve<'_> {
+    fn value_len(&self) -> der::Result<Length> {
+        OctetStringRef::new(self.a)?.encoded_len()?
+            + OctetStringRef::new(self.b)?.encoded_len()?
+            + self.seed.encoded_len()?
+    }
+
+    fn encode_value(&self, writer: &mut impl Writer) -> der::Result<()> {
+        OctetStringRef::new(self.a)?.encode(writer)?;
+        OctetStringRef::new(self.b)?.encode(writer)?;
+        self.seed.encode(writer)
+    }
+}
+
+impl<'a> Sequence<'a> for Curve<'a> {}
+
+/// Compare two serialized field elements, ignoring leading zeros.
+fn field_elements_eq(a: &[u8], b: &[u8]) -> bool {
+    fn strip_leading_zeros(mut bytes: &[u8]) -> &[u8] {
+        while let Some((0, rest)) = bytes.split_first() {
+            bytes = rest;
+        }
+
+        bytes
+    }
+
+    strip_leading_zeros(a) == strip_leading_zeros(b)
+}
@@ -187,6 +187,53 @@ where
         }
     }
 
+    /// Decompress this [`EncodedPoint`] using the given y-coordinate,
+    /// returning a new uncompressed [`EncodedPoint`].
+    ///
+    /// This performs no curve arithmetic: the y-coordinate must be computed
+    /// externally (e.g. by solving the curve equation), and is only checked
+    /// for consistency with the point's encoding:
+    ///
+    /// - compressed points require `y` to have the parity given by the tag
+    /// - uncompressed points require `y` to be the point's y-coordinate
+    /// - compact points accept any `y`
+    ///
+    /// Returns [`Error::PointEncoding`] if these checks fail or if this point
+    /// is the identity.
+    pub fn decompress_with_y(&self, y: &GenericArray<u8, Size>) -> Result<Self> {
+        let x = match self.coordinates() {
+            Coordinates::Identity => return Err(Error::PointEncoding),
+            Coordinates::Compressed { x, y_is_odd } => {
+                if Tag::compress_y(y).y_is_odd() != Some(y_is_odd) {
+                    return Err(Error::PointEncoding);
+                }
+
+                x
+            }
+            Coordinates::Uncompressed { x, y: point_y } => {
+                if point_y != y {
+                    return Err(Error::PointEncoding);
+                }
+
+                x
+            }
+            Coordinates::Compact { x } => x,
+        };
+
+        Ok(Self::from_affine_coordinates(x, y, false))
+    }
+
+    /// Get the raw uncompressed coordinates of this [`EncodedPoint`], i.e.
+    /// the concatenated `x || y` coordinates with no leading SEC1 tag byte.
+    ///
+    /// Returns `None` unless this point is uncompressed.
+    pub fn to_untagged_bytes(&self) -> Option<GenericArray<u8, Size::UntaggedPointSize>> {
+        match self.tag() {
+            Tag::Uncompressed => Some(GenericArray::clone_from_slice(&self.bytes[1..])),
+            _ => None,
+        }
+    }
+
     /// Get the SEC1 tag for this [`EncodedPoint`]
     pub fn tag(&self) -> Tag {
         // Tag is ensured valid by the constructor
@@ -514,6 +561,17 @@ impl Tag {
         self == Tag::Identity
     }
 
+    /// Get the parity of the y-coordinate given by this tag.
+    ///
+    /// Returns `None` unless this tag is for a compressed point.
+    pub fn y_is_odd(self) -> Option<bool> {
+        match self {
+            Tag::CompressedEvenY => Some(false),
+            Tag::CompressedOddY => Some(true),
+            _ => None,
+        }
+    }
+
     /// Compute the expected total message length for a message prefixed
     /// with this tag (including the tag byte), given the field element size
     /// (in bytes) for a particular elliptic curve.
@@ -526,8 +584,13 @@ impl Tag {
         }
     }
 
-    /// Compress the given y-coordinate, returning a `Tag::Compressed*` value
-    fn compress_y(y: &[u8]) -> Self {
+    /// Compress the given big endian serialized y-coordinate, returning a
+    /// `Tag::Compressed*` value.
+    ///
+    /// # Panics
+    ///
+    /// If `y` is empty.
+    pub fn compress_y(y: &[u8]) -> Self {
         // Is the y-coordinate odd in the SEC1 sense: `self mod 2 == 1`?
         if y.as_ref().last().expect("empty y-coordinate") & 1 == 1 {
             Tag::CompressedOddY
@@ -724,6 +787,48 @@ mod tests {
         assert_eq!(compressed_point.as_bytes(), &COMPRESSED_BYTES[..]);
     }
 
+    #[test]
+    fn decompress_with_y() {
+        let y = hex!("2222222222222222222222222222222222222222222222222222222222222222");
+        let compressed_point = EncodedPoint::from_bytes(&COMPRESSED_BYTES[..]).unwrap();
+        let uncompressed_point = compressed_point.decompress_with_y(&y.into()).unwrap();
+        assert_eq!(uncompressed_point.as_bytes(), &UNCOMPRESSED_BYTES[..]);
+        assert_eq!(
+            uncompressed_point.decompress_with_y(&y.into()),
+            Ok(uncompressed_point)
+        );
+
+        // y-coordinate with the wrong parity
+        let odd_y = hex!("2222222222222222222222222222222222222222222222222222222222222223");
+        assert!(compressed_point.decompress_with_y(&odd_y.into()).is_err());
+        assert!(uncompressed_point.decompress_with_y(&odd_y.into()).is_err());
+
+        assert!(EncodedPoint::identity()
+            .decompress_with_y(&y.into())
+            .is_err());
+    }
+
+    #[test]
+    fn to_untagged_bytes() {
+        let uncompressed_point = EncodedPoint::from_bytes(&UNCOMPRESSED_BYTES[..]).unwrap();
+        assert_eq!(
+            uncompressed_point.to_untagged_bytes().unwrap().as_slice(),
+            &UNCOMPRESSED_BYTES[1..]
+        );
+
+        let compressed_point = EncodedPoint::from_bytes(&COMPRESSED_BYTES[..]).unwrap();
+        assert_eq!(compressed_point.to_untagged_bytes(), None);
+    }
+
+    #[test]
+    fn tag_y_parity() {
+        assert_eq!(Tag::compress_y(&[0x22]), Tag::CompressedEvenY);
+        assert_eq!(Tag::compress_y(&[0x23]), Tag::CompressedOddY);
+        assert_eq!(Tag::CompressedEvenY.y_is_odd(), Some(false));
+        assert_eq!(Tag::CompressedOddY.y_is_odd(), Some(true));
+        assert_eq!(Tag::Uncompressed.y_is_odd(), None);
+    }
+
     #[cfg(feature = "subtle")]
     #[test]
     fn conditional_select() {
@@ -8,7 +8,7 @@
 use crate::{EcParameters, Error, Result};
 use core::fmt;
 use der::{
-    asn1::{BitStringRef, ContextSpecific, ContextSpecificRef, OctetStringRef},
+    asn1::{BitStringRef, ContextSpecific, ContextSpecificRef, ObjectIdentifier, OctetStringRef},
     Decode, DecodeValue, Encode, EncodeValue, Header, Length, Reader, Sequence, Tag, TagMode,
     TagNumber, Writer,
 };
@@ -16,8 +16,14 @@ use der::{
 #[cfg(all(feature = "alloc", feature = "zeroize"))]
 use der::SecretDocument;
 
+#[cfg(feature = "pkcs8")]
+use crate::ALGORITHM_OID;
+
+#[cfg(all(feature = "alloc", feature = "pkcs8"))]
+use zeroize::Zeroizing;
+
 #[cfg(feature = "pem")]
-use der::pem::PemLabel;
+use der::pem::{Label, PemLabel};
 
 /// `ECPrivateKey` version.
 ///
@@ -64,14 +70,98 @@ pub struct EcPrivateKey<'a> {
     pub private_key: &'a [u8],
 
     /// Elliptic curve parameters.
-    pub parameters: Option<EcParameters>,
+    pub parameters: Option<EcParameters<'a>>,
 
     /// Public key data, optionally available if version is V2.
     pub public_key: Option<&'a [u8]>,
 }
 
 impl<'a> EcPrivateKey<'a> {
-    fn context_specific_parameters(&self) -> Option<ContextSpecificRef<'_, EcParameters>> {
+    /// Create a new [`EcPrivateKey`] from the given serialized private
+    /// scalar, without parameters or a public key.
+    pub fn new(private_key: &'a [u8]) -> Self {
+        Self {
+            private_key,
+            parameters: None,
+            public_key: None,
+        }
+    }
+
+    /// Set the named curve [`EcParameters`] of this private key.
+    pub fn with_named_curve(mut self, curve_oid: ObjectIdentifier) -> Self {
+        self.parameters = Some(curve_oid.into());
+        self
+    }
+
+    /// Set the SEC1-encoded public key of this private key.
+    pub fn with_public_key(mut self, public_key: &'a [u8]) -> Self {
+        self.public_key = Some(public_key);
+        self
+    }
+
+    /// Parse an [`EcPrivateKey`] from the given [`PrivateKeyInfo`].
+    ///
+    /// The named curve is taken from the `AlgorithmIdentifier` parameters
+    /// and placed into the returned key's [`EcParameters`]. If the inner
+    /// `ECPrivateKey` also contains parameters, they must describe the same
+    /// curve. A public key stored in the PKCS#8 v2 `publicKey` field is used
+    /// if the inner `ECPrivateKey` doesn't contain one.
+    ///
+    /// [`PrivateKeyInfo`]: pkcs8::PrivateKeyInfo
+    #[cfg(feature = "pkcs8")]
+    pub fn from_pkcs8(private_key_info: &pkcs8::PrivateKeyInfo<'a>) -> Result<Self> {
+        private_key_info
+            .algorithm
+            .assert_algorithm_oid(ALGORITHM_OID)?;
+
+        let curve_oid = private_key_info.algorithm.parameters_oid()?;
+        let mut private_key = Self::from_der(private_key_info.private_key)?;
+
+        if let Some(params) = private_key.parameters {
+            if params.curve_oid() != Some(curve_oid) {
+                return Err(pkcs8::Error::ParametersMalformed.into());
+            }
+        }
+
+        private_key.parameters = Some(curve_oid.into());
+        private_key.public_key = private_key.public_key.or(private_key_info.public_key);
+        Ok(private_key)
+    }
+
+    /// Serialize this private key as a PKCS#8 [`PrivateKeyInfo`] document
+    /// for the given named curve.
+    ///
+    /// The curve OID is placed in the `AlgorithmIdentifier` parameters and
+    /// the [`EcParameters`] are omitted from the inner `ECPrivateKey`, as
+    /// they would be redundant. If this key has parameters, they must describe
+    /// the curve identified by `curve_oid`.
+    ///
+    /// [`PrivateKeyInfo`]: pkcs8::PrivateKeyInfo
+    #[cfg(all(feature = "alloc", feature = "pkcs8"))]
+    pub fn to_pkcs8(&self, curve_oid: ObjectIdentifier) -> Result<SecretDocument> {
+        if let Some(params) = self.parameters {
+            if params.curve_oid() != Some(curve_oid) {
+                return Err(pkcs8::Error::ParametersMalformed.into());
+            }
+        }
+
+        let private_key = Zeroizing::new(
+            Self {
+                parameters: None,
+                ..self.clone()
+            }
+            .to_der()?,
+        );
+
+        let algorithm = pkcs8::AlgorithmIdentifierRef {
+            oid: ALGORITHM_OID,
+            parameters: Some((&curve_oid).into()),
+        };
+
+        Ok(pkcs8::PrivateKeyInfo::new(algorithm, &private_key).try_into()?)
+    }
+
+    fn context_specific_parameters(&self) -> Option<ContextSpecificRef<'_, EcParameters<'a>>> {
         self.parameters.as_ref().map(|params| ContextSpecificRef {
             tag_number: EC_PARAMETERS_TAG,
             tag_mode: TagMode::Explicit,
@@ -102,7 +192,8 @@ impl<'a> DecodeValue<'a> for EcPrivateKey<'a> {
             }
 
             let private_key = OctetStringRef::decode(reader)?.as_bytes();
-            let parameters = reader.context_specific(EC_PARAMETERS_TAG, TagMode::Explicit)?;
+            let parameters = ContextSpecific::decode_explicit(reader, EC_PARAMETERS_TAG)?
+                .map(|field| field.value);
             let public_key = reader
                 .context_specific::<BitStringRef<'_>>(PUBLIC_KEY_TAG, TagMode::Explicit)?
                 .map(|bs| bs.as_bytes().ok_or_else(|| Tag::BitString.value_error()))
@@ -173,5 +264,5 @@ impl TryFrom<&EcPrivateKey<'_>> for SecretDocument {
 
 #[cfg(feature = "pem")]
 impl PemLabel for EcPrivateKey<'_> {
-    const PEM_LABEL: &'static str = "EC PRIVATE KEY";
+    const PEM_LABEL: &'static str = Label::EcPrivateKey.as_str();
 }
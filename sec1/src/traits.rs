@@ -93,7 +93,7 @@ where
     fn from_sec1_der(private_key: &[u8]) -> Result<Self> {
         let params_oid = EcPrivateKey::from_der(private_key)?
             .parameters
-            .and_then(|params| params.named_curve());
+            .and_then(|params| params.curve_oid());
 
         let algorithm = pkcs8::AlgorithmIdentifierRef {
             oid: ALGORITHM_OID,
@@ -103,6 +103,7 @@ where
         Ok(Self::try_from(pkcs8::PrivateKeyInfo {
             algorithm,
             private_key,
+            attributes: None,
             public_key: None,
         })?)
     }
@@ -113,10 +114,6 @@ impl<T: pkcs8::EncodePrivateKey> EncodeEcPrivateKey for T {
     fn to_sec1_der(&self) -> Result<SecretDocument> {
         let doc = self.to_pkcs8_der()?;
         let pkcs8_key = pkcs8::PrivateKeyInfo::from_der(doc.as_bytes())?;
-        pkcs8_key.algorithm.assert_algorithm_oid(ALGORITHM_OID)?;
-
-        let mut pkcs1_key = EcPrivateKey::from_der(pkcs8_key.private_key)?;
-        pkcs1_key.parameters = Some(pkcs8_key.algorithm.parameters_oid()?.into());
-        pkcs1_key.try_into()
+        EcPrivateKey::from_pkcs8(&pkcs8_key)?.try_into()
     }
 }
@@ -0,0 +1,79 @@
+//! Named curve registry tests
+
+#![cfg(feature = "der")]
+
+use der::asn1::ObjectIdentifier;
+use sec1::{EcPrivateKey, NamedCurve};
+
+/// NIST P-256 SEC1 private key encoded as ASN.1 DER.
+const P256_DER_EXAMPLE: &[u8] = include_bytes!("examples/p256-priv.der");
+
+#[test]
+fn lookup_by_oid() {
+    let curve = NamedCurve::from_oid(ObjectIdentifier::new_unwrap("1.3.132.0.34")).unwrap();
+    assert_eq!(curve, &NamedCurve::SECP384R1);
+    assert_eq!(curve.name, "secp384r1");
+    assert_eq!(curve.nist_name, Some("P-384"));
+    assert_eq!(curve.field_size, 48);
+
+    let curve =
+        NamedCurve::from_oid(ObjectIdentifier::new_unwrap("1.3.36.3.3.2.8.1.1.13")).unwrap();
+    assert_eq!(curve.to_string(), "brainpoolP512r1");
+    assert_eq!(curve.compressed_point_len(), 65);
+    assert_eq!(curve.uncompressed_point_len(), 129);
+
+    // Ed25519 is not a named curve
+    assert_eq!(
+        NamedCurve::from_oid(ObjectIdentifier::new_unwrap("1.3.101.112")),
+        None
+    );
+}
+
+#[test]
+fn lookup_by_name() {
+    assert_eq!(NamedCurve::from_name("P-521"), Some(&NamedCurve::SECP521R1));
+    assert_eq!(
+        NamedCurve::from_name("secp256k1"),
+        Some(&NamedCurve::SECP256K1)
+    );
+    assert_eq!(NamedCurve::from_name("p-256"), None);
+}
+
+#[test]
+fn registry_is_consistent() {
+    for (i, curve) in NamedCurve::ALL.iter().enumerate() {
+        assert_eq!(NamedCurve::from_oid(curve.oid), Some(curve));
+        assert_eq!(NamedCurve::from_name(curve.name), Some(curve));
+        assert!(NamedCurve::ALL[..i].iter().all(|c| c.oid != curve.oid));
+    }
+}
+
+#[test]
+fn validate_private_key() {
+    let key = EcPrivateKey::try_from(P256_DER_EXAMPLE).unwrap();
+    assert!(NamedCurve::SECP256R1.validate_private_key(&key).is_ok());
+    assert!(NamedCurve::SECP256K1.validate_private_key(&key).is_err());
+
+    // Without parameters, only the lengths are checked
+    let key = EcPrivateKey::new(key.private_key);
+    assert!(NamedCurve::SECP256K1.validate_private_key(&key).is_ok());
+    assert!(NamedCurve::SECP384R1.validate_private_key(&key).is_err());
+}
+
+#[cfg(feature = "point")]
+#[test]
+fn validate_point() {
+    let key = EcPrivateKey::try_from(P256_DER_EXAMPLE).unwrap();
+    let public_key = key.public_key.unwrap();
+    assert!(NamedCurve::SECP256R1.validate_point(public_key).is_ok());
+    assert!(NamedCurve::SECP256R1
+        .validate_point(&public_key[..33])
+        .is_err());
+    assert!(NamedCurve::SECP384R1.validate_point(public_key).is_err());
+    assert!(NamedCurve::SECP384R1.validate_point(&[0]).is_ok());
+    assert!(NamedCurve::SECP384R1.validate_point(&[]).is_err());
+
+    // Public key of the wrong length
+    let key = EcPrivateKey::new(key.private_key).with_public_key(&public_key[..33]);
+    assert!(NamedCurve::SECP256R1.validate_private_key(&key).is_err());
+}
@@ -0,0 +1,156 @@
+//! Elliptic curve parameters tests
+
+#![cfg(feature = "der")]
+
+use der::{
+    asn1::{AnyRef, ObjectIdentifier},
+    Decode,
+};
+use sec1::{EcParameters, EcPrivateKey, NamedCurve, SpecifiedEcDomain, PRIME_FIELD_OID};
+
+#[cfg(feature = "alloc")]
+use der::Encode;
+
+/// NIST P-256 SEC1 private key with explicit curve parameters encoded as
+/// ASN.1 DER.
+///
+/// Generated using:
+/// $ openssl ecparam -genkey -name prime256v1 -param_enc explicit -noout -outform der
+const P256_EXPLICIT_DER_EXAMPLE: &[u8] = include_bytes!("examples/p256-priv-explicit.der");
+
+/// NIST P-256 named curve OID.
+const P256_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.3.1.7");
+
+fn p256_explicit_params() -> SpecifiedEcDomain<'static> {
+    EcPrivateKey::try_from(P256_EXPLICIT_DER_EXAMPLE)
+        .unwrap()
+        .parameters
+        .and_then(EcParameters::specified_curve)
+        .unwrap()
+}
+
+#[test]
+fn decode_named_curve() {
+    let params =
+        EcParameters::from_der(&[0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x03, 0x01, 0x07])
+            .unwrap();
+    assert_eq!(params, EcParameters::NamedCurve(P256_OID));
+    assert_eq!(params.curve_oid(), Some(P256_OID));
+    assert_eq!(AnyRef::try_from(&params).unwrap().decode_as(), Ok(P256_OID));
+}
+
+#[test]
+fn decode_specified_curve() {
+    let key = EcPrivateKey::try_from(P256_EXPLICIT_DER_EXAMPLE).unwrap();
+    let params = key.parameters.unwrap();
+    assert_eq!(params.named_curve(), None);
+    assert_eq!(params.curve_oid(), Some(P256_OID));
+    assert_eq!(params.to_named_curve(), Some(&NamedCurve::SECP256R1));
+    assert!(AnyRef::try_from(&params).is_err());
+
+    let domain = params.specified_curve().unwrap();
+    assert_eq!(domain.version, 1);
+    assert_eq!(domain.field_id.field_type, PRIME_FIELD_OID);
+    assert_eq!(
+        domain.field_id.prime().unwrap().unwrap().as_bytes().len(),
+        32
+    );
+    assert_eq!(domain.curve.a.len(), 32);
+    assert!(domain.curve.seed.is_some());
+    assert_eq!(domain.base.len(), 65);
+    assert_eq!(domain.cofactor.unwrap().as_bytes(), [1]);
+    assert_eq!(domain.hash, None);
+
+    NamedCurve::SECP256R1.validate_private_key(&key).unwrap();
+    assert!(NamedCurve::SECP384R1.validate_private_key(&key).is_err());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn encode_specified_curve() {
+    let key = EcPrivateKey::try_from(P256_EXPLICIT_DER_EXAMPLE).unwrap();
+    assert_eq!(key.to_der().unwrap(), P256_EXPLICIT_DER_EXAMPLE);
+}
+
+#[test]
+fn builtin_domain_parameters() {
+    for curve in NamedCurve::ALL {
+        let domain = curve.domain_parameters();
+        assert_eq!(domain.to_named_curve(), Some(curve), "{}", curve);
+
+        let p = domain.field_id.prime().unwrap().unwrap();
+        assert_eq!(p.as_bytes().len(), curve.field_size, "{}", curve);
+        assert_eq!(
+            domain.base.len(),
+            curve.uncompressed_point_len(),
+            "{}",
+            curve
+        );
+    }
+}
+
+#[test]
+fn compressed_base_point() {
+    let mut compressed_base = [0u8; 33];
+    let explicit = p256_explicit_params();
+    let y_is_odd = explicit.base[64] & 1 == 1;
+
+    compressed_base[0] = if y_is_odd { 0x03 } else { 0x02 };
+    compressed_base[1..].copy_from_slice(&explicit.base[1..33]);
+
+    let domain = SpecifiedEcDomain {
+        base: &compressed_base,
+        ..explicit
+    };
+    assert_eq!(domain.to_named_curve(), Some(&NamedCurve::SECP256R1));
+
+    // The other point with the same x-coordinate
+    compressed_base[0] ^= 1;
+    let domain = SpecifiedEcDomain {
+        base: &compressed_base,
+        ..explicit
+    };
+    assert_eq!(domain.to_named_curve(), None);
+}
+
+#[test]
+fn modified_parameters() {
+    let explicit = p256_explicit_params();
+
+    let mut base = [0u8; 65];
+    base.copy_from_slice(explicit.base);
+    base[64] ^= 0x02;
+
+    let domain = SpecifiedEcDomain {
+        base: &base,
+        ..explicit
+    };
+    assert_eq!(domain.to_named_curve(), None);
+
+    let mut b = [0u8; 32];
+    b.copy_from_slice(explicit.curve.b);
+    b[31] ^= 0x01;
+
+    let mut domain = explicit;
+    domain.curve.b = &b;
+    assert_eq!(domain.to_named_curve(), None);
+
+    let domain = SpecifiedEcDomain {
+        cofactor: None,
+        ..explicit
+    };
+    assert_eq!(domain.to_named_curve(), Some(&NamedCurve::SECP256R1));
+}
+
+#[cfg(all(feature = "alloc", feature = "pkcs8"))]
+#[test]
+fn specified_curve_to_pkcs8() {
+    let key = EcPrivateKey::try_from(P256_EXPLICIT_DER_EXAMPLE).unwrap();
+    let pkcs8_doc = key.to_pkcs8(P256_OID).unwrap();
+
+    let pkcs8_key = sec1::pkcs8::PrivateKeyInfo::try_from(pkcs8_doc.as_bytes()).unwrap();
+    let decoded = EcPrivateKey::from_pkcs8(&pkcs8_key).unwrap();
+    assert_eq!(decoded.parameters, Some(EcParameters::NamedCurve(P256_OID)));
+    assert_eq!(decoded.private_key, key.private_key);
+    assert_eq!(decoded.public_key, key.public_key);
+}
@@ -41,3 +41,65 @@ fn encode_p256_der() {
     let key_encoded = key.to_der().unwrap();
     assert_eq!(P256_DER_EXAMPLE, key_encoded);
 }
+
+/// NIST P-256 PKCS#8 private key encoded as ASN.1 DER.
+///
+/// Generated from `p256-priv.der` using:
+/// $ openssl pkcs8 -topk8 -nocrypt -inform der -in p256-priv.der -outform der
+#[cfg(feature = "pkcs8")]
+const P256_PKCS8_DER_EXAMPLE: &[u8] = include_bytes!("examples/p256-priv-pkcs8.der");
+
+/// NIST P-256 named curve OID.
+#[cfg(feature = "pkcs8")]
+const P256_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.3.1.7");
+
+/// NIST P-384 named curve OID.
+#[cfg(all(feature = "alloc", feature = "pkcs8"))]
+const P384_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.132.0.34");
+
+#[cfg(feature = "pkcs8")]
+#[test]
+fn from_pkcs8_p256() {
+    let pkcs8_key = sec1::pkcs8::PrivateKeyInfo::try_from(P256_PKCS8_DER_EXAMPLE).unwrap();
+    let key = EcPrivateKey::from_pkcs8(&pkcs8_key).unwrap();
+    assert_eq!(key.parameters, Some(EcParameters::NamedCurve(P256_OID)));
+
+    #[cfg(feature = "alloc")]
+    assert_eq!(key.to_der().unwrap(), P256_DER_EXAMPLE);
+}
+
+#[cfg(all(feature = "alloc", feature = "pkcs8"))]
+#[test]
+fn to_pkcs8_p256() {
+    let key = EcPrivateKey::try_from(P256_DER_EXAMPLE).unwrap();
+    let doc = key.to_pkcs8(P256_OID).unwrap();
+    assert_eq!(doc.as_bytes(), P256_PKCS8_DER_EXAMPLE);
+
+    // Curve OID which doesn't match the key's parameters
+    assert!(key.to_pkcs8(P384_OID).is_err());
+
+    // Keys without parameters use the given curve
+    let key = EcPrivateKey::new(key.private_key).with_public_key(key.public_key.unwrap());
+    let doc = key.to_pkcs8(P256_OID).unwrap();
+    assert_eq!(doc.as_bytes(), P256_PKCS8_DER_EXAMPLE);
+}
+
+#[cfg(all(feature = "alloc", feature = "pkcs8"))]
+#[test]
+fn from_pkcs8_curve_mismatch() {
+    let key = EcPrivateKey::try_from(P256_DER_EXAMPLE).unwrap();
+    let algorithm = sec1::pkcs8::AlgorithmIdentifierRef {
+        oid: sec1::ALGORITHM_OID,
+        parameters: Some((&P384_OID).into()),
+    };
+
+    let pkcs8_key = sec1::pkcs8::PrivateKeyInfo::new(algorithm, P256_DER_EXAMPLE);
+    assert!(EcPrivateKey::from_pkcs8(&pkcs8_key).is_err());
+
+    // Without inner parameters, the outer curve OID is used
+    let inner = EcPrivateKey::new(key.private_key).to_der().unwrap();
+    let pkcs8_key = sec1::pkcs8::PrivateKeyInfo::new(algorithm, &inner);
+    let key = EcPrivateKey::from_pkcs8(&pkcs8_key).unwrap();
+    assert_eq!(key.parameters, Some(EcParameters::NamedCurve(P384_OID)));
+    assert_eq!(key.public_key, None);
+}
@@ -4,8 +4,8 @@ use crate::{Error, Result};
 use core::cmp::Ordering;
 use der::{
     asn1::{AnyRef, Choice, ObjectIdentifier},
-    Decode, DecodeValue, DerOrd, Encode, EncodeValue, Header, Length, Reader, Sequence, ValueOrd,
-    Writer,
+    const_decode, Decode, DecodeValue, DerOrd, Encode, EncodeValue, Header, Length, Reader,
+    Sequence, Tag, ValueOrd, Writer,
 };
 
 #[cfg(feature = "alloc")]
@@ -96,6 +96,37 @@ pub type AlgorithmIdentifierWithOid = AlgorithmIdentifier<ObjectIdentifier>;
 pub type AlgorithmIdentifierOwned = AlgorithmIdentifier<Any>;
 
 impl<Params> AlgorithmIdentifier<Params> {
+    /// Decode a DER-encoded `AlgorithmIdentifier` which omits `parameters`
+    /// (const-friendly).
+    ///
+    /// This allows algorithm identifiers embedded as byte literals to be
+    /// validated at compile time:
+    ///
+    /// ```
+    /// use spki::AlgorithmIdentifierRef;
+    ///
+    /// // `AlgorithmIdentifier` for Ed25519
+    /// const ED25519: AlgorithmIdentifierRef<'static> =
+    ///     match AlgorithmIdentifierRef::from_der_without_params(&[0x30, 0x05, 0x06, 0x03, 0x2B, 0x65, 0x70]) {
+    ///         Ok(algorithm) => algorithm,
+    ///         Err(_) => panic!("invalid AlgorithmIdentifier"),
+    ///     };
+    ///
+    /// assert_eq!(ED25519.oid.to_string(), "1.3.101.112");
+    /// ```
+    pub const fn from_der_without_params(bytes: &[u8]) -> der::Result<Self> {
+        match const_decode::decode_tlv(bytes, Tag::Sequence) {
+            Ok(value) => match const_decode::decode_oid(value) {
+                Ok(oid) => Ok(Self {
+                    oid,
+                    parameters: None,
+                }),
+                Err(err) => Err(err),
+            },
+            Err(err) => Err(err),
+        }
+    }
+
     /// Assert the `algorithm` OID is an expected value.
     pub fn assert_algorithm_oid(&self, expected_oid: ObjectIdentifier) -> Result<ObjectIdentifier> {
         if self.oid == expected_oid {
@@ -0,0 +1,192 @@
+//! Well-known [`AlgorithmIdentifier`] values.
+//!
+//! The `parameters` of these algorithm identifiers are encoded as required by
+//! the respective specifications, which notably differ as to whether absent
+//! parameters are encoded as `NULL` or omitted entirely:
+//!
+//! - RSA (PKCS#1 v1.5): `NULL` ([RFC 4055 § 5])
+//! - RSASSA-PSS: `RSASSA-PSS-params` ([RFC 4055 § 3.1])
+//! - ECDSA: the named curve for public keys, omitted for signatures
+//!   ([RFC 5480 § 2.1.1], [RFC 5758 § 3.2])
+//! - Ed25519/Ed448/X25519/X448: omitted ([RFC 8410 § 3])
+//! - SHA-2: omitted ([RFC 5754 § 2])
+//! - DSA: `Dss-Parms` or omitted if inherited from the issuer ([RFC 3279 § 2.3.2])
+//!
+//! [RFC 3279 § 2.3.2]: https://datatracker.ietf.org/doc/html/rfc3279#section-2.3.2
+//! [RFC 4055 § 3.1]: https://datatracker.ietf.org/doc/html/rfc4055#section-3.1
+//! [RFC 4055 § 5]: https://datatracker.ietf.org/doc/html/rfc4055#section-5
+//! [RFC 5480 § 2.1.1]: https://datatracker.ietf.org/doc/html/rfc5480#section-2.1.1
+//! [RFC 5754 § 2]: https://datatracker.ietf.org/doc/html/rfc5754#section-2
+//! [RFC 5758 § 3.2]: https://datatracker.ietf.org/doc/html/rfc5758#section-3.2
+//! [RFC 8410 § 3]: https://datatracker.ietf.org/doc/html/rfc8410#section-3
+
+use crate::{AlgorithmIdentifier, AlgorithmIdentifierRef, AlgorithmIdentifierWithOid};
+use der::{
+    asn1::{AnyRef, ObjectIdentifier},
+    Decode,
+};
+
+/// Algorithm identifier with absent parameters.
+const fn without_params(oid: &str) -> AlgorithmIdentifierRef<'static> {
+    AlgorithmIdentifier {
+        oid: ObjectIdentifier::new_unwrap(oid),
+        parameters: None,
+    }
+}
+
+/// Algorithm identifier with `NULL` parameters.
+const fn with_null_params(oid: &str) -> AlgorithmIdentifierRef<'static> {
+    AlgorithmIdentifier {
+        oid: ObjectIdentifier::new_unwrap(oid),
+        parameters: Some(AnyRef::NULL),
+    }
+}
+
+/// Algorithm identifier with named curve parameters.
+const fn with_curve(curve: &str) -> AlgorithmIdentifierWithOid {
+    AlgorithmIdentifier {
+        oid: ObjectIdentifier::new_unwrap("1.2.840.10045.2.1"),
+        parameters: Some(ObjectIdentifier::new_unwrap(curve)),
+    }
+}
+
+/// `rsaEncryption` public keys.
+pub const RSA_ENCRYPTION: AlgorithmIdentifierRef<'static> =
+    with_null_params("1.2.840.113549.1.1.1");
+
+/// `sha224WithRSAEncryption` (RSASSA-PKCS1-v1_5 with SHA-224) signatures.
+pub const SHA224_WITH_RSA_ENCRYPTION: AlgorithmIdentifierRef<'static> =
+    with_null_params("1.2.840.113549.1.1.14");
+
+/// `sha256WithRSAEncryption` (RSASSA-PKCS1-v1_5 with SHA-256) signatures.
+pub const SHA256_WITH_RSA_ENCRYPTION: AlgorithmIdentifierRef<'static> =
+    with_null_params("1.2.840.113549.1.1.11");
+
+/// `sha384WithRSAEncryption` (RSASSA-PKCS1-v1_5 with SHA-384) signatures.
+pub const SHA384_WITH_RSA_ENCRYPTION: AlgorithmIdentifierRef<'static> =
+    with_null_params("1.2.840.113549.1.1.12");
+
+/// `sha512WithRSAEncryption` (RSASSA-PKCS1-v1_5 with SHA-512) signatures.
+pub const SHA512_WITH_RSA_ENCRYPTION: AlgorithmIdentifierRef<'static> =
+    with_null_params("1.2.840.113549.1.1.13");
+
+/// DER encoding of `id-RSASSA-PSS` with SHA-256, MGF1 with SHA-256 and a
+/// 32-byte salt.
+const RSASSA_PSS_SHA256: &[u8] = &[
+    0x30, 0x41, 0x06, 0x09, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0a, 0x30, 0x34, 0xa0,
+    0x0f, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01, 0x05, 0x00,
+    0xa1, 0x1c, 0x30, 0x1a, 0x06, 0x09, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x08, 0x30,
+    0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01, 0x05, 0x00, 0xa2, 0x03,
+    0x02, 0x01, 0x20,
+];
+
+/// DER encoding of `id-RSASSA-PSS` with SHA-384, MGF1 with SHA-384 and a
+/// 48-byte salt.
+const RSASSA_PSS_SHA384: &[u8] = &[
+    0x30, 0x41, 0x06, 0x09, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0a, 0x30, 0x34, 0xa0,
+    0x0f, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x02, 0x05, 0x00,
+    0xa1, 0x1c, 0x30, 0x1a, 0x06, 0x09, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x08, 0x30,
+    0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x02, 0x05, 0x00, 0xa2, 0x03,
+    0x02, 0x01, 0x30,
+];
+
+/// DER encoding of `id-RSASSA-PSS` with SHA-512, MGF1 with SHA-512 and a
+/// 64-byte salt.
+const RSASSA_PSS_SHA512: &[u8] = &[
+    0x30, 0x41, 0x06, 0x09, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0a, 0x30, 0x34, 0xa0,
+    0x0f, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x03, 0x05, 0x00,
+    0xa1, 0x1c, 0x30, 0x1a, 0x06, 0x09, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x08, 0x30,
+    0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x03, 0x05, 0x00, 0xa2, 0x03,
+    0x02, 0x01, 0x40,
+];
+
+/// `id-RSASSA-PSS` signatures using SHA-256, MGF1 with SHA-256, and a salt
+/// length of 32 bytes.
+///
+/// The hash algorithms in the parameters have `NULL` parameters, as
+/// specified in [RFC 4055 § 2.1].
+///
+/// [RFC 4055 § 2.1]: https://datatracker.ietf.org/doc/html/rfc4055#section-2.1
+pub fn rsa_pss_sha256() -> AlgorithmIdentifierRef<'static> {
+    AlgorithmIdentifierRef::from_der(RSASSA_PSS_SHA256).expect("invalid RSASSA-PSS parameters")
+}
+
+/// `id-RSASSA-PSS` signatures using SHA-384, MGF1 with SHA-384, and a salt
+/// length of 48 bytes.
+///
+/// See [`rsa_pss_sha256`] for more information.
+pub fn rsa_pss_sha384() -> AlgorithmIdentifierRef<'static> {
+    AlgorithmIdentifierRef::from_der(RSASSA_PSS_SHA384).expect("invalid RSASSA-PSS parameters")
+}
+
+/// `id-RSASSA-PSS` signatures using SHA-512, MGF1 with SHA-512, and a salt
+/// length of 64 bytes.
+///
+/// See [`rsa_pss_sha256`] for more information.
+pub fn rsa_pss_sha512() -> AlgorithmIdentifierRef<'static> {
+    AlgorithmIdentifierRef::from_der(RSASSA_PSS_SHA512).expect("invalid RSASSA-PSS parameters")
+}
+
+/// `id-ecPublicKey` public keys on the NIST P-192 (`secp192r1`) curve.
+pub const EC_PUBLIC_KEY_P192: AlgorithmIdentifierWithOid = with_curve("1.2.840.10045.3.1.1");
+
+/// `id-ecPublicKey` public keys on the NIST P-224 (`secp224r1`) curve.
+pub const EC_PUBLIC_KEY_P224: AlgorithmIdentifierWithOid = with_curve("1.3.132.0.33");
+
+/// `id-ecPublicKey` public keys on the NIST P-256 (`secp256r1`) curve.
+pub const EC_PUBLIC_KEY_P256: AlgorithmIdentifierWithOid = with_curve("1.2.840.10045.3.1.7");
+
+/// `id-ecPublicKey` public keys on the NIST P-384 (`secp384r1`) curve.
+pub const EC_PUBLIC_KEY_P384: AlgorithmIdentifierWithOid = with_curve("1.3.132.0.34");
+
+/// `id-ecPublicKey` public keys on the NIST P-521 (`secp521r1`) curve.
+pub const EC_PUBLIC_KEY_P521: AlgorithmIdentifierWithOid = with_curve("1.3.132.0.35");
+
+/// `id-ecPublicKey` public keys on the `secp256k1` curve.
+pub const EC_PUBLIC_KEY_K256: AlgorithmIdentifierWithOid = with_curve("1.3.132.0.10");
+
+/// `ecdsa-with-SHA224` signatures.
+pub const ECDSA_WITH_SHA224: AlgorithmIdentifierRef<'static> =
+    without_params("1.2.840.10045.4.3.1");
+
+/// `ecdsa-with-SHA256` signatures.
+pub const ECDSA_WITH_SHA256: AlgorithmIdentifierRef<'static> =
+    without_params("1.2.840.10045.4.3.2");
+
+/// `ecdsa-with-SHA384` signatures.
+pub const ECDSA_WITH_SHA384: AlgorithmIdentifierRef<'static> =
+    without_params("1.2.840.10045.4.3.3");
+
+/// `ecdsa-with-SHA512` signatures.
+pub const ECDSA_WITH_SHA512: AlgorithmIdentifierRef<'static> =
+    without_params("1.2.840.10045.4.3.4");
+
+/// `id-dsa` public keys whose [`DsaParameters`] are inherited from the
+/// issuer.
+///
+/// [`DsaParameters`]: crate::DsaParameters
+pub const DSA: AlgorithmIdentifierRef<'static> = without_params("1.2.840.10040.4.1");
+
+/// `id-X25519` public keys.
+pub const X25519: AlgorithmIdentifierRef<'static> = without_params("1.3.101.110");
+
+/// `id-X448` public keys.
+pub const X448: AlgorithmIdentifierRef<'static> = without_params("1.3.101.111");
+
+/// `id-Ed25519` public keys and signatures.
+pub const ED25519: AlgorithmIdentifierRef<'static> = without_params("1.3.101.112");
+
+/// `id-Ed448` public keys and signatures.
+pub const ED448: AlgorithmIdentifierRef<'static> = without_params("1.3.101.113");
+
+/// `id-sha224` digests.
+pub const SHA224: AlgorithmIdentifierRef<'static> = without_params("2.16.840.1.101.3.4.2.4");
+
+/// `id-sha256` digests.
+pub const SHA256: AlgorithmIdentifierRef<'static> = without_params("2.16.840.1.101.3.4.2.1");
+
+/// `id-sha384` digests.
+pub const SHA384: AlgorithmIdentifierRef<'static> = without_params("2.16.840.1.101.3.4.2.2");
+
+/// `id-sha512` digests.
+pub const SHA512: AlgorithmIdentifierRef<'static> = without_params("2.16.840.1.101.3.4.2.3");
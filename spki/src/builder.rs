@@ -0,0 +1,162 @@
+//! Builder for [`SubjectPublicKeyInfo`] from raw public key bytes.
+
+use crate::{algorithms, AlgorithmIdentifier, Error, Result, SubjectPublicKeyInfoRef};
+use der::{
+    asn1::{AnyRef, BitStringRef, ObjectIdentifier, UintRef},
+    Reader, SliceReader,
+};
+
+#[cfg(doc)]
+use crate::SubjectPublicKeyInfo;
+
+/// Public key algorithm selector for [`SubjectPublicKeyInfoBuilder`].
+///
+/// Each variant determines the expected format of the raw public key bytes,
+/// and how the `parameters` of the resulting [`AlgorithmIdentifier`] are
+/// encoded (see the [`algorithms`] module).
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum KeyAlgorithm {
+    /// `rsaEncryption`: DER-encoded PKCS#1 `RSAPublicKey`.
+    Rsa,
+
+    /// `id-ecPublicKey` on the given named curve: SEC1-encoded elliptic curve
+    /// point, either compressed or uncompressed.
+    Ec(ObjectIdentifier),
+
+    /// `id-X25519`: 32-byte public key.
+    X25519,
+
+    /// `id-X448`: 56-byte public key.
+    X448,
+
+    /// `id-Ed25519`: 32-byte public key.
+    Ed25519,
+
+    /// `id-Ed448`: 57-byte public key.
+    Ed448,
+}
+
+/// Builder for [`SubjectPublicKeyInfo`] from raw public key bytes.
+///
+/// Checks the public key bytes are well-formed for the selected
+/// [`KeyAlgorithm`], and produces an SPKI with the appropriate algorithm
+/// parameters and the key wrapped in a `BIT STRING`.
+///
+/// ```
+/// use spki::{der::Encode, KeyAlgorithm, SubjectPublicKeyInfoBuilder};
+///
+/// let key = [0x42; 32];
+/// let builder = SubjectPublicKeyInfoBuilder::new(KeyAlgorithm::Ed25519, &key);
+/// let spki = builder.build()?;
+///
+/// assert_eq!(spki.subject_public_key.raw_bytes(), key);
+/// assert_eq!(spki.encoded_len()?, 44u8.into());
+/// # Ok::<(), spki::Error>(())
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct SubjectPublicKeyInfoBuilder<'a> {
+    algorithm: KeyAlgorithm,
+    public_key: &'a [u8],
+}
+
+impl<'a> SubjectPublicKeyInfoBuilder<'a> {
+    /// Create a new builder for the given algorithm and raw public key bytes.
+    pub fn new(algorithm: KeyAlgorithm, public_key: &'a [u8]) -> Self {
+        Self {
+            algorithm,
+            public_key,
+        }
+    }
+
+    /// Build the [`SubjectPublicKeyInfo`].
+    ///
+    /// Returns [`Error::KeyMalformed`] if the public key bytes are not valid
+    /// for the selected [`KeyAlgorithm`].
+    pub fn build(&self) -> Result<SubjectPublicKeyInfoRef<'_>> {
+        let algorithm = match &self.algorithm {
+            KeyAlgorithm::Rsa => {
+                check_rsa_public_key(self.public_key)?;
+                algorithms::RSA_ENCRYPTION
+            }
+            KeyAlgorithm::Ec(curve) => {
+                check_ec_point(curve, self.public_key)?;
+                AlgorithmIdentifier {
+                    oid: algorithms::EC_PUBLIC_KEY_P256.oid,
+                    parameters: Some(AnyRef::from(curve)),
+                }
+            }
+            KeyAlgorithm::X25519 => check_len(algorithms::X25519, self.public_key, 32)?,
+            KeyAlgorithm::X448 => check_len(algorithms::X448, self.public_key, 56)?,
+            KeyAlgorithm::Ed25519 => check_len(algorithms::ED25519, self.public_key, 32)?,
+            KeyAlgorithm::Ed448 => check_len(algorithms::ED448, self.public_key, 57)?,
+        };
+
+        Ok(SubjectPublicKeyInfoRef {
+            algorithm,
+            subject_public_key: BitStringRef::from_bytes(self.public_key)?,
+        })
+    }
+}
+
+/// Check the key has the expected length, returning the algorithm.
+fn check_len<'a>(
+    algorithm: AlgorithmIdentifier<AnyRef<'a>>,
+    public_key: &[u8],
+    len: usize,
+) -> Result<AlgorithmIdentifier<AnyRef<'a>>> {
+    if public_key.len() == len {
+        Ok(algorithm)
+    } else {
+        Err(Error::KeyMalformed)
+    }
+}
+
+/// Check the key is a DER-encoded PKCS#1 `RSAPublicKey`:
+///
+/// ```text
+/// RSAPublicKey ::= SEQUENCE {
+///     modulus           INTEGER,  -- n
+///     publicExponent    INTEGER   -- e
+/// }
+/// ```
+fn check_rsa_public_key(public_key: &[u8]) -> Result<()> {
+    let mut reader = SliceReader::new(public_key)?;
+
+    reader
+        .sequence(|reader| {
+            reader.decode::<UintRef<'_>>()?;
+            reader.decode::<UintRef<'_>>()?;
+            Ok(())
+        })
+        .and_then(|()| reader.finish(()))
+        .map_err(|_| Error::KeyMalformed)
+}
+
+/// Check the key is a SEC1-encoded point, with the correct length if the
+/// curve is one of the curves in [`algorithms`].
+fn check_ec_point(curve: &ObjectIdentifier, public_key: &[u8]) -> Result<()> {
+    let field_len = [
+        (algorithms::EC_PUBLIC_KEY_P192, 24),
+        (algorithms::EC_PUBLIC_KEY_P224, 28),
+        (algorithms::EC_PUBLIC_KEY_P256, 32),
+        (algorithms::EC_PUBLIC_KEY_P384, 48),
+        (algorithms::EC_PUBLIC_KEY_P521, 66),
+        (algorithms::EC_PUBLIC_KEY_K256, 32),
+    ]
+    .iter()
+    .find(|(alg, _)| alg.parameters.as_ref() == Some(curve))
+    .map(|&(_, len)| len);
+
+    let coordinates_len = match public_key.split_first() {
+        Some((0x02 | 0x03, coordinates)) => coordinates.len(),
+        Some((0x04, coordinates)) if coordinates.len() % 2 == 0 => coordinates.len() / 2,
+        _ => return Err(Error::KeyMalformed),
+    };
+
+    match field_len {
+        _ if coordinates_len == 0 => Err(Error::KeyMalformed),
+        Some(len) if len != coordinates_len => Err(Error::KeyMalformed),
+        _ => Ok(()),
+    }
+}
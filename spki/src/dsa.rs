@@ -0,0 +1,118 @@
+//! DSA domain parameters.
+
+use crate::{algorithms, Error, Result, SubjectPublicKeyInfoRef};
+use der::{
+    asn1::{AnyRef, UintRef},
+    Decode, DecodeValue, Encode, EncodeValue, Header, Length, Reader, Sequence, Writer,
+};
+
+#[cfg(feature = "alloc")]
+use der::asn1::Any;
+
+/// DSA domain parameters (`Dss-Parms`) as defined in [RFC 3279 § 2.3.2].
+///
+/// These are the `parameters` of an `id-dsa` [`AlgorithmIdentifier`], which
+/// may also use [`DsaParameters`] as its `Params` type, i.e.
+/// `AlgorithmIdentifier<DsaParameters<'a>>`.
+///
+/// ```text
+/// Dss-Parms  ::=  SEQUENCE  {
+///     p             INTEGER,
+///     q             INTEGER,
+///     g             INTEGER  }
+/// ```
+///
+/// [RFC 3279 § 2.3.2]: https://datatracker.ietf.org/doc/html/rfc3279#section-2.3.2
+/// [`AlgorithmIdentifier`]: crate::AlgorithmIdentifier
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct DsaParameters<'a> {
+    /// Prime modulus `p`.
+    pub p: UintRef<'a>,
+
+    /// Prime divisor `q` of `p - 1`.
+    pub q: UintRef<'a>,
+
+    /// Generator `g` of the subgroup of order `q`.
+    pub g: UintRef<'a>,
+}
+
+impl<'a> DecodeValue<'a> for DsaParameters<'a> {
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> der::Result<Self> {
+        reader.read_nested(header.length, |reader| {
+            Ok(Self {
+                p: reader.decode()?,
+                q: reader.decode()?,
+                g: reader.decode()?,
+            })
+        })
+    }
+}
+
+impl EncodeValue for DsaParameters<'_> {
+    fn value_len(&self) -> der::Result<Length> {
+        self.p.encoded_len()? + self.q.encoded_len()? + self.g.encoded_len()?
+    }
+
+    fn encode_value(&self, writer: &mut impl Writer) -> der::Result<()> {
+        self.p.encode(writer)?;
+        self.q.encode(writer)?;
+        self.g.encode(writer)?;
+        Ok(())
+    }
+}
+
+impl<'a> Sequence<'a> for DsaParameters<'a> {}
+
+impl<'a> TryFrom<&'a [u8]> for DsaParameters<'a> {
+    type Error = Error;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self> {
+        Ok(Self::from_der(bytes)?)
+    }
+}
+
+impl<'a> TryFrom<AnyRef<'a>> for DsaParameters<'a> {
+    type Error = Error;
+
+    fn try_from(any: AnyRef<'a>) -> Result<Self> {
+        Ok(any.decode_as()?)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl TryFrom<&DsaParameters<'_>> for Any {
+    type Error = Error;
+
+    fn try_from(params: &DsaParameters<'_>) -> Result<Any> {
+        Ok(Any::encode_from(params)?)
+    }
+}
+
+impl<'a> SubjectPublicKeyInfoRef<'a> {
+    /// Get the [`DsaParameters`] of an `id-dsa` public key.
+    ///
+    /// Returns `Ok(None)` if the parameters are absent, in which case they
+    /// are inherited from the issuing CA ([RFC 3279 § 2.3.2]).
+    ///
+    /// [RFC 3279 § 2.3.2]: https://datatracker.ietf.org/doc/html/rfc3279#section-2.3.2
+    pub fn dsa_parameters(&self) -> Result<Option<DsaParameters<'a>>> {
+        self.algorithm.assert_algorithm_oid(algorithms::DSA.oid)?;
+        self.algorithm
+            .parameters
+            .map(DsaParameters::try_from)
+            .transpose()
+    }
+
+    /// Get the public key `y` of an `id-dsa` public key.
+    ///
+    /// ```text
+    /// DSAPublicKey ::= INTEGER -- public key, Y
+    /// ```
+    pub fn dsa_public_key(&self) -> Result<UintRef<'a>> {
+        self.algorithm.assert_algorithm_oid(algorithms::DSA.oid)?;
+        self.subject_public_key
+            .as_bytes()
+            .and_then(|bytes| UintRef::from_der(bytes).ok())
+            .ok_or(Error::KeyMalformed)
+    }
+}
@@ -1,7 +1,8 @@
 //! SPKI fingerprint support.
 
 use der::Writer;
-use sha2::{Digest, Sha256};
+use digest::{Digest, Output};
+use sha2::Sha256;
 
 /// Size of a SHA-256 SPKI fingerprint in bytes.
 pub(crate) const SIZE: usize = 32;
@@ -16,25 +17,25 @@ pub type FingerprintBytes = [u8; SIZE];
 
 /// Writer newtype which accepts DER being serialized on-the-fly and computes a
 /// hash of the contents.
-#[derive(Clone, Default)]
-pub(crate) struct Builder {
+#[derive(Clone)]
+pub(crate) struct Builder<D: Digest = Sha256> {
     /// In-progress digest being computed from streaming DER.
-    digest: Sha256,
+    digest: D,
 }
 
-impl Builder {
+impl<D: Digest> Builder<D> {
     /// Create a new fingerprint builder.
     pub fn new() -> Self {
-        Self::default()
+        Self { digest: D::new() }
     }
 
     /// Finish computing a fingerprint, returning the computed digest.
-    pub fn finish(self) -> FingerprintBytes {
-        self.digest.finalize().into()
+    pub fn finish(self) -> Output<D> {
+        self.digest.finalize()
     }
 }
 
-impl Writer for Builder {
+impl<D: Digest> Writer for Builder<D> {
     fn write(&mut self, der_bytes: &[u8]) -> der::Result<()> {
         self.digest.update(der_bytes);
         Ok(())
@@ -38,7 +38,11 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
+pub mod algorithms;
+
 mod algorithm;
+mod builder;
+mod dsa;
 mod error;
 mod spki;
 mod traits;
@@ -48,6 +52,8 @@ mod fingerprint;
 
 pub use crate::{
     algorithm::{AlgorithmIdentifier, AlgorithmIdentifierRef, AlgorithmIdentifierWithOid},
+    builder::{KeyAlgorithm, SubjectPublicKeyInfoBuilder},
+    dsa::DsaParameters,
     error::{Error, Result},
     spki::{SubjectPublicKeyInfo, SubjectPublicKeyInfoRef},
     traits::{AssociatedAlgorithmIdentifier, DecodePublicKey, SignatureAlgorithmIdentifier},
@@ -68,4 +74,4 @@ pub use {
 };
 
 #[cfg(feature = "fingerprint")]
-pub use crate::fingerprint::FingerprintBytes;
+pub use {crate::fingerprint::FingerprintBytes, digest};
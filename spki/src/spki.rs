@@ -15,10 +15,14 @@ use der::{
 };
 
 #[cfg(feature = "fingerprint")]
-use crate::{fingerprint, FingerprintBytes};
+use {
+    crate::{fingerprint, FingerprintBytes},
+    digest::{Digest, Output},
+    sha2::Sha256,
+};
 
 #[cfg(feature = "pem")]
-use der::pem::PemLabel;
+use der::pem::{Label, PemLabel};
 
 /// [`SubjectPublicKeyInfo`] with [`AnyRef`] algorithm parameters, and [`BitStringRef`] params.
 pub type SubjectPublicKeyInfoRef<'a> = SubjectPublicKeyInfo<AnyRef<'a>, BitStringRef<'a>>;
@@ -76,7 +80,19 @@ where
     /// [RFC7469 § 2.1.1]: https://datatracker.ietf.org/doc/html/rfc7469#section-2.1.1
     #[cfg(feature = "fingerprint")]
     pub fn fingerprint_bytes(&self) -> Result<FingerprintBytes> {
-        let mut builder = fingerprint::Builder::new();
+        Ok(self.fingerprint::<Sha256>()?.into())
+    }
+
+    /// Calculate the fingerprint of this [`SubjectPublicKeyInfo`] using the
+    /// given [`Digest`] algorithm, e.g. SHA-1 or SHA-512.
+    ///
+    /// Use [`SubjectPublicKeyInfo::fingerprint_bytes`] for the SHA-256
+    /// fingerprint described in [RFC7469 § 2.1.1].
+    ///
+    /// [RFC7469 § 2.1.1]: https://datatracker.ietf.org/doc/html/rfc7469#section-2.1.1
+    #[cfg(feature = "fingerprint")]
+    pub fn fingerprint<D: Digest>(&self) -> Result<Output<D>> {
+        let mut builder = fingerprint::Builder::<D>::new();
         self.encode(&mut builder)?;
         Ok(builder.finish())
     }
@@ -175,7 +191,7 @@ where
 
 #[cfg(feature = "pem")]
 impl<Params, Key> PemLabel for SubjectPublicKeyInfo<Params, Key> {
-    const PEM_LABEL: &'static str = "PUBLIC KEY";
+    const PEM_LABEL: &'static str = Label::PublicKey.as_str();
 }
 
 #[cfg(feature = "alloc")]
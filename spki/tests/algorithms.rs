@@ -0,0 +1,98 @@
+//! Tests for well-known `AlgorithmIdentifier` values.
+
+use der::Encode;
+use hex_literal::hex;
+use spki::{algorithms, AlgorithmIdentifierRef};
+
+/// Assert the given value has the expected DER encoding.
+fn assert_der(value: impl Encode, expected: &[u8]) {
+    let mut buf = [0u8; 128];
+    assert_eq!(value.encode_to_slice(&mut buf).unwrap(), expected);
+}
+
+#[test]
+fn rsa() {
+    assert_der(
+        algorithms::RSA_ENCRYPTION,
+        &hex!("300d06092a864886f70d0101010500"),
+    );
+    assert_der(
+        algorithms::SHA256_WITH_RSA_ENCRYPTION,
+        &hex!("300d06092a864886f70d01010b0500"),
+    );
+    assert_der(
+        algorithms::SHA512_WITH_RSA_ENCRYPTION,
+        &hex!("300d06092a864886f70d01010d0500"),
+    );
+}
+
+/// Generated using `openssl req -x509 -sigopt rsa_padding_mode:pss -sigopt rsa_pss_saltlen:digest`
+#[test]
+fn rsa_pss() {
+    assert_der(
+        algorithms::rsa_pss_sha256(),
+        &hex!(
+            "304106092a864886f70d01010a3034a00f300d06096086480165030402010500a11c301a06092a"
+            "864886f70d010108300d06096086480165030402010500a203020120"
+        ),
+    );
+    assert_der(
+        algorithms::rsa_pss_sha384(),
+        &hex!(
+            "304106092a864886f70d01010a3034a00f300d06096086480165030402020500a11c301a06092a"
+            "864886f70d010108300d06096086480165030402020500a203020130"
+        ),
+    );
+    assert_der(
+        algorithms::rsa_pss_sha512(),
+        &hex!(
+            "304106092a864886f70d01010a3034a00f300d06096086480165030402030500a11c301a06092a"
+            "864886f70d010108300d06096086480165030402030500a203020140"
+        ),
+    );
+}
+
+#[test]
+fn ecdsa() {
+    assert_der(
+        algorithms::EC_PUBLIC_KEY_P256,
+        &hex!("301306072a8648ce3d020106082a8648ce3d030107"),
+    );
+    assert_der(
+        algorithms::EC_PUBLIC_KEY_P384,
+        &hex!("301006072a8648ce3d020106052b81040022"),
+    );
+    assert_der(
+        algorithms::ECDSA_WITH_SHA256,
+        &hex!("300a06082a8648ce3d040302"),
+    );
+}
+
+#[test]
+fn ecdsa_public_key_matches_spki() {
+    // `id-ecPublicKey` with `secp256r1` parameters
+    let alg =
+        AlgorithmIdentifierRef::try_from(&hex!("301306072a8648ce3d020106082a8648ce3d030107")[..])
+            .unwrap();
+
+    assert_eq!(
+        alg.oids().unwrap(),
+        (
+            algorithms::EC_PUBLIC_KEY_P256.oid,
+            algorithms::EC_PUBLIC_KEY_P256.parameters
+        )
+    );
+}
+
+#[test]
+fn edwards() {
+    assert_der(algorithms::ED25519, &hex!("300506032b6570"));
+    assert_der(algorithms::ED448, &hex!("300506032b6571"));
+    assert_der(algorithms::X25519, &hex!("300506032b656e"));
+}
+
+#[test]
+fn sha2() {
+    assert_der(algorithms::SHA256, &hex!("300b0609608648016503040201"));
+    assert_der(algorithms::SHA512, &hex!("300b0609608648016503040203"));
+}
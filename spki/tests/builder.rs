@@ -0,0 +1,95 @@
+//! `SubjectPublicKeyInfoBuilder` tests.
+
+use der::{asn1::ObjectIdentifier, Decode, Encode};
+use hex_literal::hex;
+use spki::{Error, KeyAlgorithm, SubjectPublicKeyInfoBuilder, SubjectPublicKeyInfoRef};
+
+/// Elliptic Curve (P-256) `SubjectPublicKeyInfo` encoded as ASN.1 DER
+const EC_P256_DER_EXAMPLE: &[u8] = include_bytes!("examples/p256-pub.der");
+
+/// Ed25519 `SubjectPublicKeyInfo` encoded as ASN.1 DER
+const ED25519_DER_EXAMPLE: &[u8] = include_bytes!("examples/ed25519-pub.der");
+
+/// RSA-2048 `SubjectPublicKeyInfo` encoded as ASN.1 DER
+const RSA_2048_DER_EXAMPLE: &[u8] = include_bytes!("examples/rsa2048-pub.der");
+
+/// NIST P-256 (`secp256r1`) curve OID
+const SECP256R1: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.3.1.7");
+
+/// NIST P-384 (`secp384r1`) curve OID
+const SECP384R1: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.132.0.34");
+
+/// Rebuild the given SPKI from its raw key bytes and check the encoding matches.
+fn assert_rebuilds(algorithm: KeyAlgorithm, der: &[u8]) {
+    let expected = SubjectPublicKeyInfoRef::from_der(der).unwrap();
+    let key = expected.subject_public_key.raw_bytes();
+    let builder = SubjectPublicKeyInfoBuilder::new(algorithm, key);
+    let spki = builder.build().unwrap();
+
+    let mut buf = [0u8; 512];
+    assert_eq!(spki, expected);
+    assert_eq!(spki.encode_to_slice(&mut buf).unwrap(), der);
+}
+
+#[test]
+fn build_ec_p256() {
+    assert_rebuilds(KeyAlgorithm::Ec(SECP256R1), EC_P256_DER_EXAMPLE);
+}
+
+#[test]
+fn build_ed25519() {
+    assert_rebuilds(KeyAlgorithm::Ed25519, ED25519_DER_EXAMPLE);
+}
+
+#[test]
+fn build_rsa() {
+    assert_rebuilds(KeyAlgorithm::Rsa, RSA_2048_DER_EXAMPLE);
+}
+
+#[test]
+fn build_ec_compressed() {
+    let mut point = [0x11; 33];
+    point[0] = 0x03;
+
+    let builder = SubjectPublicKeyInfoBuilder::new(KeyAlgorithm::Ec(SECP256R1), &point);
+    assert!(builder.build().is_ok());
+
+    let builder = SubjectPublicKeyInfoBuilder::new(KeyAlgorithm::Ec(SECP384R1), &point);
+    assert_eq!(builder.build(), Err(Error::KeyMalformed));
+}
+
+#[test]
+fn build_ec_unknown_curve() {
+    // brainpoolP256r1
+    let curve = ObjectIdentifier::new_unwrap("1.3.36.3.3.2.8.1.1.7");
+    let point = hex!("04 0102 0304");
+
+    let builder = SubjectPublicKeyInfoBuilder::new(KeyAlgorithm::Ec(curve), &point);
+    let spki = builder.build().unwrap();
+    assert_eq!(
+        spki.algorithm.oids().unwrap(),
+        (
+            ObjectIdentifier::new_unwrap("1.2.840.10045.2.1"),
+            Some(curve)
+        )
+    );
+}
+
+#[test]
+fn build_malformed() {
+    for (algorithm, key) in [
+        (KeyAlgorithm::Ed25519, &[0u8; 31][..]),
+        (KeyAlgorithm::Ed448, &[0u8; 32]),
+        (KeyAlgorithm::X25519, &[0u8; 33]),
+        (KeyAlgorithm::X448, &[0u8; 57]),
+        (KeyAlgorithm::Ec(SECP256R1), &[0x04; 64]),
+        (KeyAlgorithm::Ec(SECP256R1), &[0x04]),
+        (KeyAlgorithm::Ec(SECP256R1), &[0x00]),
+        (KeyAlgorithm::Ec(SECP256R1), &[]),
+        (KeyAlgorithm::Rsa, &hex!("3003020101")),
+        (KeyAlgorithm::Rsa, &hex!("30060201010201010000")),
+    ] {
+        let builder = SubjectPublicKeyInfoBuilder::new(algorithm, key);
+        assert_eq!(builder.build(), Err(Error::KeyMalformed), "{:?}", algorithm);
+    }
+}
@@ -0,0 +1,89 @@
+//! DSA parameter tests.
+
+use der::{asn1::BitStringRef, Decode, Encode};
+use hex_literal::hex;
+use spki::{
+    algorithms, AlgorithmIdentifier, DsaParameters, Error, SubjectPublicKeyInfo,
+    SubjectPublicKeyInfoRef,
+};
+
+/// DSA-2048 `SubjectPublicKeyInfo` encoded as ASN.1 DER
+const DSA_2048_DER_EXAMPLE: &[u8] = include_bytes!("examples/dsa2048-pub.der");
+
+/// Ed25519 `SubjectPublicKeyInfo` encoded as ASN.1 DER
+const ED25519_DER_EXAMPLE: &[u8] = include_bytes!("examples/ed25519-pub.der");
+
+#[test]
+fn decode_dsa_parameters() {
+    let spki = SubjectPublicKeyInfoRef::try_from(DSA_2048_DER_EXAMPLE).unwrap();
+    let params = spki.dsa_parameters().unwrap().unwrap();
+
+    assert_eq!(params.p.as_bytes().len(), 256);
+    assert_eq!(&params.p.as_bytes()[..4], &hex!("AA2562CE"));
+    assert_eq!(
+        params.q.as_bytes(),
+        &hex!("C709FACAFAA5E5189F35CB636CC98BDB143AE88ADC5A8B96DEE1E205")
+    );
+    assert_eq!(params.g.as_bytes().len(), 256);
+    assert_eq!(&params.g.as_bytes()[..4], &hex!("13464B94"));
+
+    let public_key = spki.dsa_public_key().unwrap();
+    assert_eq!(public_key.as_bytes().len(), 256);
+    assert_eq!(&public_key.as_bytes()[..4], &hex!("5980C525"));
+}
+
+#[test]
+fn encode_dsa_parameters() {
+    let spki = SubjectPublicKeyInfoRef::try_from(DSA_2048_DER_EXAMPLE).unwrap();
+
+    let typed = SubjectPublicKeyInfo::<DsaParameters<'_>, BitStringRef<'_>> {
+        algorithm: AlgorithmIdentifier {
+            oid: spki.algorithm.oid,
+            parameters: spki.dsa_parameters().unwrap(),
+        },
+        subject_public_key: spki.subject_public_key,
+    };
+
+    let mut buf = [0u8; 1024];
+    assert_eq!(
+        typed.encode_to_slice(&mut buf).unwrap(),
+        DSA_2048_DER_EXAMPLE
+    );
+    assert_eq!(
+        SubjectPublicKeyInfo::<DsaParameters<'_>, BitStringRef<'_>>::from_der(DSA_2048_DER_EXAMPLE)
+            .unwrap(),
+        typed
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn dsa_parameters_to_any() {
+    let spki = SubjectPublicKeyInfoRef::try_from(DSA_2048_DER_EXAMPLE).unwrap();
+    let params = spki.dsa_parameters().unwrap().unwrap();
+    let any = der::Any::try_from(&params).unwrap();
+
+    assert_eq!(der::AnyRef::from(&any), spki.algorithm.parameters.unwrap());
+}
+
+#[test]
+fn inherited_dsa_parameters() {
+    let spki = SubjectPublicKeyInfoRef {
+        algorithm: algorithms::DSA,
+        subject_public_key: BitStringRef::from_bytes(&hex!("020101")).unwrap(),
+    };
+
+    assert_eq!(spki.dsa_parameters(), Ok(None));
+    assert_eq!(spki.dsa_public_key().unwrap().as_bytes(), [1]);
+}
+
+#[test]
+fn not_dsa() {
+    let spki = SubjectPublicKeyInfoRef::try_from(ED25519_DER_EXAMPLE).unwrap();
+
+    assert!(matches!(
+        spki.dsa_parameters(),
+        Err(Error::OidUnknown { .. })
+    ));
+    assert!(spki.dsa_public_key().is_err());
+}
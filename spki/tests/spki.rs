@@ -2,7 +2,7 @@
 
 use der::asn1::ObjectIdentifier;
 use hex_literal::hex;
-use spki::SubjectPublicKeyInfoRef;
+use spki::{AlgorithmIdentifierRef, SubjectPublicKeyInfoRef};
 
 #[cfg(feature = "alloc")]
 use der::Encode;
@@ -45,6 +45,24 @@ const ED25519_SPKI_FINGERPRINT_BASE64: &str = "Vd1MdLDkhTTi9OFzzs61DfjyenrCqomRz
 const ED25519_SPKI_FINGERPRINT: &[u8] =
     &hex!("55dd4c74b0e48534e2f4e173ceceb50df8f27a7ac2aa8991cc7ae914e030bced");
 
+#[test]
+fn decode_algorithm_identifier_const() {
+    const ED25519: AlgorithmIdentifierRef<'static> =
+        match AlgorithmIdentifierRef::from_der_without_params(&hex!("300506032B6570")) {
+            Ok(algorithm) => algorithm,
+            Err(_) => panic!("invalid AlgorithmIdentifier"),
+        };
+
+    assert_eq!(ED25519.oid, ObjectIdentifier::new_unwrap("1.3.101.112"));
+    assert_eq!(ED25519.parameters, None);
+
+    // `id-ecPublicKey` with `secp256r1` parameters
+    assert!(AlgorithmIdentifierRef::from_der_without_params(&hex!(
+        "301306072A8648CE3D020106082A8648CE3D030107"
+    ))
+    .is_err());
+}
+
 #[test]
 fn decode_ec_p256_der() {
     let spki = SubjectPublicKeyInfoRef::try_from(EC_P256_DER_EXAMPLE).unwrap();
@@ -83,6 +101,32 @@ fn decode_ed25519_and_fingerprint_spki() {
     );
 }
 
+#[test]
+#[cfg(feature = "fingerprint")]
+fn fingerprint_with_digest() {
+    let spki = SubjectPublicKeyInfoRef::try_from(ED25519_DER_EXAMPLE).unwrap();
+
+    assert_eq!(
+        spki.fingerprint::<sha2::Sha256>().unwrap().as_slice(),
+        ED25519_SPKI_FINGERPRINT
+    );
+
+    // Generated using `cat ed25519-pub.der | openssl dgst -sha1`
+    assert_eq!(
+        spki.fingerprint::<sha1::Sha1>().unwrap().as_slice(),
+        &hex!("63c1882056add5c33b7bb8086a24ee0562b7ed60")
+    );
+
+    // Generated using `cat ed25519-pub.der | openssl dgst -sha512`
+    assert_eq!(
+        spki.fingerprint::<sha2::Sha512>().unwrap().as_slice(),
+        &hex!(
+            "c2c631fa12073fd44f61b7fb8644d03a426d3af57b28387e59ede1bfa5d033f7"
+            "1170cae4509264c0ad2f1f798921bf09c4b6ae002dc2e5dfd5a3b96c793695e9"
+        )
+    );
+}
+
 #[test]
 #[cfg(all(feature = "alloc", feature = "base64", feature = "fingerprint"))]
 fn decode_ed25519_and_fingerprint_base64() {
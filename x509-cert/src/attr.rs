@@ -1,6 +1,6 @@
 //! Attribute-related definitions as defined in X.501 (and updated by RFC 5280).
 
-use alloc::vec::Vec;
+use alloc::{borrow::Cow, string::String, vec::Vec};
 use const_oid::db::{
     rfc4519::{COUNTRY_NAME, DOMAIN_COMPONENT, SERIAL_NUMBER},
     Database, DB,
@@ -11,8 +11,8 @@ use core::{
 };
 use der::{
     asn1::{
-        Any, Ia5StringRef, ObjectIdentifier, PrintableStringRef, SetOfVec, TeletexStringRef,
-        Utf8StringRef,
+        Any, GeneralString, GraphicString, Ia5StringRef, ObjectIdentifier, PrintableStringRef,
+        SetOfVec, TeletexString, Utf8StringRef, VideotexString,
     },
     Decode, Encode, Error, ErrorKind, Sequence, Tag, Tagged, ValueOrd,
 };
@@ -249,14 +249,26 @@ impl fmt::Display for AttributeTypeAndValue {
         let val = match self.value.tag() {
             Tag::PrintableString => PrintableStringRef::try_from(&self.value)
                 .ok()
-                .map(|s| s.as_str()),
+                .map(|s| Cow::Borrowed(s.as_str())),
             Tag::Utf8String => Utf8StringRef::try_from(&self.value)
                 .ok()
-                .map(|s| s.as_str()),
-            Tag::Ia5String => Ia5StringRef::try_from(&self.value).ok().map(|s| s.as_str()),
-            Tag::TeletexString => TeletexStringRef::try_from(&self.value)
+                .map(|s| Cow::Borrowed(s.as_str())),
+            Tag::Ia5String => Ia5StringRef::try_from(&self.value)
                 .ok()
-                .map(|s| s.as_str()),
+                .map(|s| Cow::Borrowed(s.as_str())),
+            // Legacy 8-bit strings which may need to be decoded as ISO 8859-1
+            Tag::TeletexString => TeletexString::try_from(&self.value)
+                .ok()
+                .map(|s| Cow::Owned(String::from(s.as_str()))),
+            Tag::VideotexString => VideotexString::try_from(&self.value)
+                .ok()
+                .map(|s| Cow::Owned(String::from(s.as_str()))),
+            Tag::GraphicString => GraphicString::try_from(&self.value)
+                .ok()
+                .map(|s| Cow::Owned(String::from(s.as_str()))),
+            Tag::GeneralString => GeneralString::try_from(&self.value)
+                .ok()
+                .map(|s| Cow::Owned(String::from(s.as_str()))),
             _ => None,
         };
 
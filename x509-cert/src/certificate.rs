@@ -10,7 +10,7 @@ use spki::{AlgorithmIdentifierOwned, SubjectPublicKeyInfoOwned};
 
 #[cfg(feature = "pem")]
 use der::{
-    pem::{self, PemLabel},
+    pem::{self, Label, PemLabel},
     DecodePem,
 };
 
@@ -202,7 +202,7 @@ pub struct CertificateInner<P: Profile = Rfc5280> {
 
 #[cfg(feature = "pem")]
 impl<P: Profile> PemLabel for CertificateInner<P> {
-    const PEM_LABEL: &'static str = "CERTIFICATE";
+    const PEM_LABEL: &'static str = Label::Certificate.as_str();
 }
 
 /// `PkiPath` as defined by X.509 and referenced by [RFC 6066].
@@ -45,7 +45,6 @@ pub enum DirectoryString {
     #[asn1(type = "PrintableString")]
     PrintableString(PrintableString),
 
-    #[asn1(type = "TeletexString")]
     TeletexString(TeletexString),
 
     #[asn1(type = "UTF8String")]
@@ -18,7 +18,7 @@ use der::{
 use spki::{AlgorithmIdentifierOwned, SubjectPublicKeyInfoOwned};
 
 #[cfg(feature = "pem")]
-use der::pem::PemLabel;
+use der::pem::{Label, PemLabel};
 
 /// Version identifier for certification request information.
 ///
@@ -85,7 +85,7 @@ pub struct CertReq {
 
 #[cfg(feature = "pem")]
 impl PemLabel for CertReq {
-    const PEM_LABEL: &'static str = "CERTIFICATE REQUEST";
+    const PEM_LABEL: &'static str = Label::CertificateRequest.as_str();
 }
 
 impl<'a> TryFrom<&'a [u8]> for CertReq {
@@ -8,6 +8,9 @@ use der::{Choice, DateTime, Sequence, ValueOrd};
 #[cfg(feature = "std")]
 use std::time::SystemTime;
 
+#[cfg(feature = "time")]
+use der::time::OffsetDateTime;
+
 /// X.501 `Time` as defined in [RFC 5280 Section 4.1.2.5].
 ///
 /// Schema definition from [RFC 5280 Appendix A]:
@@ -41,6 +44,24 @@ impl Time {
     pub const INFINITY: Time =
         Time::GeneralTime(GeneralizedTime::from_date_time(DateTime::INFINITY));
 
+    /// Create a [`Time`] from a [`DateTime`], choosing the encoding according
+    /// to [RFC 5280 Section 4.1.2.5]:
+    ///
+    /// > CAs conforming to this profile MUST always encode certificate
+    /// > validity dates through the year 2049 as UTCTime; certificate validity
+    /// > dates in 2050 or later MUST be encoded as GeneralizedTime.
+    ///
+    /// Fractional seconds are truncated.
+    ///
+    /// [RFC 5280 Section 4.1.2.5]: https://tools.ietf.org/html/rfc5280#section-4.1.2.5
+    pub fn from_date_time(datetime: DateTime) -> der::Result<Self> {
+        if datetime.year() <= UtcTime::MAX_YEAR {
+            UtcTime::from_date_time(datetime).map(Time::UtcTime)
+        } else {
+            Ok(Time::GeneralTime(GeneralizedTime::from_date_time(datetime)))
+        }
+    }
+
     /// Get duration since `UNIX_EPOCH`.
     pub fn to_unix_duration(self) -> Duration {
         match self {
@@ -71,10 +92,7 @@ impl Time {
     #[cfg(feature = "builder")]
     pub(crate) fn rfc5280_adjust_utc_time(&mut self) -> der::Result<()> {
         if let Time::GeneralTime(t) = self {
-            let date = t.to_date_time();
-            if date.year() <= UtcTime::MAX_YEAR {
-                *self = Time::UtcTime(UtcTime::from_date_time(date)?);
-            }
+            *self = Time::from_date_time(t.to_date_time())?;
         }
 
         Ok(())
@@ -122,6 +140,48 @@ impl TryFrom<SystemTime> for Time {
     }
 }
 
+/// Applies the RFC 5280 UTCTime-vs-GeneralizedTime cutover rules.
+///
+/// See [`Time::from_date_time`] for more information.
+#[cfg(feature = "time")]
+impl TryFrom<OffsetDateTime> for Time {
+    type Error = der::Error;
+
+    fn try_from(time: OffsetDateTime) -> der::Result<Time> {
+        Time::from_date_time(DateTime::try_from(time)?)
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<Time> for OffsetDateTime {
+    type Error = der::Error;
+
+    fn try_from(time: Time) -> der::Result<OffsetDateTime> {
+        time.to_date_time().try_into()
+    }
+}
+
+/// Applies the RFC 5280 UTCTime-vs-GeneralizedTime cutover rules.
+///
+/// See [`Time::from_date_time`] for more information.
+#[cfg(feature = "chrono")]
+impl<Tz: der::chrono::TimeZone> TryFrom<der::chrono::DateTime<Tz>> for Time {
+    type Error = der::Error;
+
+    fn try_from(time: der::chrono::DateTime<Tz>) -> der::Result<Time> {
+        Time::from_date_time(DateTime::try_from(time)?)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<Time> for der::chrono::DateTime<der::chrono::Utc> {
+    type Error = der::Error;
+
+    fn try_from(time: Time) -> der::Result<der::chrono::DateTime<der::chrono::Utc>> {
+        time.to_date_time().try_into()
+    }
+}
+
 /// X.501 `Validity` as defined in [RFC 5280 Section 4.1.2.5]
 ///
 /// ```text
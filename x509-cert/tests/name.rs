@@ -368,3 +368,13 @@ fn rdns_serde() {
         }
     }
 }
+
+/// Legacy strings which aren't valid UTF-8 are decoded as ISO 8859-1
+#[test]
+fn decode_latin1_teletex_string() {
+    // CN=Jürgen, with the TeletexString value encoded as ISO 8859-1
+    let name_der = hex!("3011310F300D06035504031406 4AFC7267656E");
+    let name = Name::from_der(&name_der).unwrap();
+    assert_eq!(name.to_string(), "CN=Jürgen");
+    assert_eq!(name.to_der().unwrap(), name_der);
+}
@@ -1,8 +1,8 @@
 //! Validity tests
 
-use der::{Decode, Encode};
+use der::{DateTime, Decode, Encode};
 use hex_literal::hex;
-use x509_cert::time::Validity;
+use x509_cert::time::{Time, Validity};
 
 #[test]
 fn decode_validity() {
@@ -134,3 +134,63 @@ fn encode_validity() {
         &hex!("3020180F32303032303130313132303130305A170D3330313233313038333030305A")[..]
     );
 }
+
+#[test]
+fn time_from_date_time_cutover() {
+    let last_utc = DateTime::new(2049, 12, 31, 23, 59, 59).unwrap();
+    assert!(matches!(
+        Time::from_date_time(last_utc).unwrap(),
+        Time::UtcTime(_)
+    ));
+
+    let first_generalized = DateTime::new(2050, 1, 1, 0, 0, 0).unwrap();
+    assert!(matches!(
+        Time::from_date_time(first_generalized).unwrap(),
+        Time::GeneralTime(_)
+    ));
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn time_crate_conversions() {
+    use der::time::{Date, Month, OffsetDateTime, PrimitiveDateTime, UtcOffset};
+
+    let date_time = |year, month, day, hour, minute| {
+        let date = Date::from_calendar_date(year, month, day).unwrap();
+        let time = der::time::Time::from_hms(hour, minute, 0).unwrap();
+        PrimitiveDateTime::new(date, time)
+    };
+
+    let offset = UtcOffset::from_hms(2, 0, 0).unwrap();
+    let time =
+        Time::try_from(date_time(2030, Month::December, 31, 8, 30).assume_offset(offset)).unwrap();
+    assert!(matches!(time, Time::UtcTime(_)));
+    assert_eq!(
+        time.to_date_time(),
+        DateTime::new(2030, 12, 31, 6, 30, 0).unwrap()
+    );
+    assert_eq!(
+        OffsetDateTime::try_from(time).unwrap(),
+        date_time(2030, Month::December, 31, 6, 30).assume_utc()
+    );
+
+    let time = Time::try_from(date_time(2050, Month::January, 1, 0, 0).assume_utc()).unwrap();
+    assert!(matches!(time, Time::GeneralTime(_)));
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn chrono_conversions() {
+    use der::chrono::{FixedOffset, TimeZone, Utc};
+
+    let offset = FixedOffset::east_opt(2 * 3600).unwrap();
+    let time = Time::try_from(offset.with_ymd_and_hms(2030, 12, 31, 8, 30, 0).unwrap()).unwrap();
+    assert!(matches!(time, Time::UtcTime(_)));
+    assert_eq!(
+        der::chrono::DateTime::<Utc>::try_from(time).unwrap(),
+        Utc.with_ymd_and_hms(2030, 12, 31, 6, 30, 0).unwrap()
+    );
+
+    let time = Time::try_from(Utc.with_ymd_and_hms(2050, 1, 1, 0, 0, 0).unwrap()).unwrap();
+    assert!(matches!(time, Time::GeneralTime(_)));
+}
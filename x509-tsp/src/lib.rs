@@ -8,18 +8,35 @@
 
 extern crate alloc;
 
+use alloc::vec::Vec;
 use cmpv2::status::PkiStatusInfo;
-use cms::content_info::ContentInfo;
+use cms::{
+    content_info::ContentInfo,
+    signed_data::{SignedData, SignerInfo, UnsignedAttributes},
+};
 use der::{
-    asn1::{GeneralizedTime, Int, OctetString},
-    oid::ObjectIdentifier,
-    Any, Enumerated, Sequence,
+    asn1::{FractionalGeneralizedTime, Int, OctetString, SetOfVec},
+    oid::{AssociatedOid, ObjectIdentifier},
+    Any, Decode, Enumerated, Sequence,
 };
+use digest::Digest;
 use x509_cert::{
+    attr::Attribute,
     ext::{pkix::name::GeneralName, Extensions},
     spki::AlgorithmIdentifier,
 };
 
+/// `id-ct-TSTInfo` content type of the encapsulated content of a [`TimeStampToken`]
+/// ([RFC 3161 § 2.4.2](https://datatracker.ietf.org/doc/html/rfc3161#section-2.4.2))
+pub const ID_CT_TST_INFO: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.16.1.4");
+
+/// `id-aa-timeStampToken` unsigned attribute holding a [`TimeStampToken`] over the signature of
+/// a `SignerInfo`
+/// ([RFC 3161 Appendix A](https://datatracker.ietf.org/doc/html/rfc3161#appendix-A))
+pub const ID_AA_TIME_STAMP_TOKEN: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.16.2.14");
+
 #[derive(Clone, Copy, Debug, Enumerated, Eq, PartialEq, PartialOrd, Ord)]
 #[asn1(type = "INTEGER")]
 #[repr(u8)]
@@ -67,6 +84,43 @@ pub struct MessageImprint {
     pub hashed_message: OctetString,
 }
 
+impl MessageImprint {
+    /// Create the imprint of `message` using the hash algorithm `D`.
+    pub fn new<D: Digest + AssociatedOid>(message: &[u8]) -> der::Result<Self> {
+        Ok(Self {
+            hash_algorithm: AlgorithmIdentifier {
+                oid: D::OID,
+                parameters: None,
+            },
+            hashed_message: OctetString::new(D::digest(message).to_vec())?,
+        })
+    }
+
+    /// Create the imprint of the signature of `signer_info`, as timestamped by the
+    /// `id-aa-timeStampToken` attribute
+    /// ([RFC 3161 Appendix A](https://datatracker.ietf.org/doc/html/rfc3161#appendix-A)).
+    pub fn from_signature<D: Digest + AssociatedOid>(
+        signer_info: &SignerInfo,
+    ) -> der::Result<Self> {
+        Self::new::<D>(signer_info.signature.as_bytes())
+    }
+
+    /// Check whether this is the imprint of `message` using the hash algorithm `D`.
+    ///
+    /// Returns `false` if the imprint was created using another hash algorithm, the caller is
+    /// expected to select `D` according to `hash_algorithm`.
+    pub fn verify<D: Digest + AssociatedOid>(&self, message: &[u8]) -> bool {
+        self.hash_algorithm.oid == D::OID
+            && self.hashed_message.as_bytes() == D::digest(message).as_slice()
+    }
+
+    /// Check whether this is the imprint of the signature of `signer_info` using the hash
+    /// algorithm `D`, see [`MessageImprint::from_signature`].
+    pub fn verify_signature<D: Digest + AssociatedOid>(&self, signer_info: &SignerInfo) -> bool {
+        self.verify::<D>(signer_info.signature.as_bytes())
+    }
+}
+
 /// ```text
 /// TimeStampResp ::= SEQUENCE  {
 ///     status                  PKIStatusInfo,
@@ -109,7 +163,7 @@ pub struct TstInfo {
     pub policy: TsaPolicyId,
     pub message_imprint: MessageImprint,
     pub serial_number: Int,
-    pub gen_time: GeneralizedTime,
+    pub gen_time: FractionalGeneralizedTime,
     #[asn1(optional = "true")]
     pub accuracy: Option<Accuracy>,
     #[asn1(default = "Default::default")]
@@ -122,6 +176,85 @@ pub struct TstInfo {
     pub extensions: Option<Extensions>,
 }
 
+impl TstInfo {
+    /// Extract the `TSTInfo` encapsulated in `token`.
+    ///
+    /// This only decodes the token, its signature has to be verified separately.
+    pub fn from_time_stamp_token(token: &TimeStampToken) -> der::Result<Self> {
+        if token.content_type != der::oid::db::rfc5911::ID_SIGNED_DATA {
+            return Err(der::ErrorKind::OidUnknown {
+                oid: token.content_type,
+            }
+            .into());
+        }
+        let signed_data = token.content.decode_as::<SignedData>()?;
+        let encap_content_info = signed_data.encap_content_info;
+        if encap_content_info.econtent_type != ID_CT_TST_INFO {
+            return Err(der::ErrorKind::OidUnknown {
+                oid: encap_content_info.econtent_type,
+            }
+            .into());
+        }
+        let econtent = encap_content_info
+            .econtent
+            .ok_or_else(|| der::Tag::OctetString.value_error())?;
+        Self::from_der(econtent.value())
+    }
+}
+
+/// Attach `token` to `signer_info` as an `id-aa-timeStampToken` unsigned attribute
+/// ([RFC 3161 Appendix A](https://datatracker.ietf.org/doc/html/rfc3161#appendix-A)).
+///
+/// The token must timestamp the signature of `signer_info`, see
+/// [`MessageImprint::from_signature`]. If the attribute is already present, `token` is added to
+/// its values.
+pub fn add_time_stamp_token(
+    signer_info: &mut SignerInfo,
+    token: &TimeStampToken,
+) -> der::Result<()> {
+    let value = Any::encode_from(token)?;
+    let mut unsigned_attributes = signer_info
+        .unsigned_attrs
+        .take()
+        .map(|unsigned_attributes| unsigned_attributes.into_vec())
+        .unwrap_or_default();
+
+    match unsigned_attributes
+        .iter_mut()
+        .find(|attribute| attribute.oid == ID_AA_TIME_STAMP_TOKEN)
+    {
+        Some(attribute) => attribute.values.insert(value)?,
+        None => {
+            let mut values = SetOfVec::new();
+            values.insert(value)?;
+            unsigned_attributes.push(Attribute {
+                oid: ID_AA_TIME_STAMP_TOKEN,
+                values,
+            });
+        }
+    }
+
+    signer_info.unsigned_attrs = Some(UnsignedAttributes::try_from(unsigned_attributes)?);
+    Ok(())
+}
+
+/// Get the time-stamp tokens attached to `signer_info` as `id-aa-timeStampToken` unsigned
+/// attributes ([RFC 3161 Appendix A](https://datatracker.ietf.org/doc/html/rfc3161#appendix-A)).
+pub fn time_stamp_tokens(signer_info: &SignerInfo) -> der::Result<Vec<TimeStampToken>> {
+    let mut tokens = Vec::new();
+    for attribute in signer_info
+        .unsigned_attrs
+        .iter()
+        .flat_map(|unsigned_attributes| unsigned_attributes.iter())
+        .filter(|attribute| attribute.oid == ID_AA_TIME_STAMP_TOKEN)
+    {
+        for value in attribute.values.iter() {
+            tokens.push(value.decode_as::<TimeStampToken>()?);
+        }
+    }
+    Ok(tokens)
+}
+
 /// ```text
 /// Accuracy ::= SEQUENCE {
 ///     seconds        INTEGER              OPTIONAL,
@@ -143,10 +276,10 @@ mod tests {
     use super::*;
     use alloc::string::ToString;
     use cmpv2::status::*;
-    use cms::signed_data::SignedData;
-    use der::oid::db::rfc5912::ID_SHA_256;
-    use der::{Decode, Encode};
+    use der::oid::db::{rfc5911::ID_DATA, rfc5912::ID_SHA_256};
+    use der::Encode;
     use hex_literal::hex;
+    use sha2::{Sha256, Sha384};
 
     // Tests use keys generated via openssl as follows:
     //  openssl ecparam -genkey -name secp384r1 -noout -out ec384-tsa-key.pem
@@ -184,6 +317,9 @@ mod tests {
     // ess_cert_id_alg		= sha1	# algorithm to compute certificate
     // 				# identifier (optional, default: sha1)
 
+    // openssl ts -reply -queryfile query.tsq -signer ec384-tsa-key.crt -inkey ec384-tsa-key.pem -out response.tsr -config tsa.cnf
+    const RESPONSE: [u8; 648] = hex!("3082028430030201003082027B06092A864886F70D010702A082026C30820268020103310F300D060960864801650304020105003081C9060B2A864886F70D0109100104A081B90481B63081B302010106042A0304013031300D060960864801650304020105000420BA7816BF8F01CFEA414140DE5DAE2223B00361A396177A9CB410FF61F20015AD020104180F32303233303630373131323632365A300A020101800201F48101640101FF0208314CFCE4E0651827A048A4463044310B30090603550406130255533113301106035504080C0A536F6D652D5374617465310D300B060355040A0C04546573743111300F06035504030C0854657374205453413182018430820180020101305C3044310B30090603550406130255533113301106035504080C0A536F6D652D5374617465310D300B060355040A0C04546573743111300F06035504030C08546573742054534102146A0DCC59137C11D1C2B092042B4BC51C0D634D24300D06096086480165030402010500A08198301A06092A864886F70D010903310D060B2A864886F70D0109100104301C06092A864886F70D010905310F170D3233303630373131323632365A302B060B2A864886F70D010910020C311C301A3018301604142F36B1B52456F5AC3A1CA09794AE3D0D64AD38C2302F06092A864886F70D01090431220420BAF4CCF82E9B5B3956EADCC87346B407684F26D82B68D0E7DE0D31EA79AF648C300A06082A8648CE3D0403020467306502305A6E1C175B20A93FAB25D14CC5F5A2836D726D6D4A964B66FFBFFCE46276A96475F1408728B3385DCA37C2BA46BE17E1023100C46B7F08D03409A8ECCFD7637765412C3C5EC050E0D39CF48F0F5015950342CB18D8434FF331BA4463C086297C37D07B");
+
     #[test]
     fn request_test() {
         // openssl ts --query --data abc.txt -out query.tsq
@@ -199,8 +335,7 @@ mod tests {
     }
     #[test]
     fn response_test() {
-        // openssl ts -reply -queryfile query.tsq -signer ec384-tsa-key.crt -inkey ec384-tsa-key.pem -out response.tsr -config tsa.cnf
-        let enc_resp = hex!("3082028430030201003082027B06092A864886F70D010702A082026C30820268020103310F300D060960864801650304020105003081C9060B2A864886F70D0109100104A081B90481B63081B302010106042A0304013031300D060960864801650304020105000420BA7816BF8F01CFEA414140DE5DAE2223B00361A396177A9CB410FF61F20015AD020104180F32303233303630373131323632365A300A020101800201F48101640101FF0208314CFCE4E0651827A048A4463044310B30090603550406130255533113301106035504080C0A536F6D652D5374617465310D300B060355040A0C04546573743111300F06035504030C0854657374205453413182018430820180020101305C3044310B30090603550406130255533113301106035504080C0A536F6D652D5374617465310D300B060355040A0C04546573743111300F06035504030C08546573742054534102146A0DCC59137C11D1C2B092042B4BC51C0D634D24300D06096086480165030402010500A08198301A06092A864886F70D010903310D060B2A864886F70D0109100104301C06092A864886F70D010905310F170D3233303630373131323632365A302B060B2A864886F70D010910020C311C301A3018301604142F36B1B52456F5AC3A1CA09794AE3D0D64AD38C2302F06092A864886F70D01090431220420BAF4CCF82E9B5B3956EADCC87346B407684F26D82B68D0E7DE0D31EA79AF648C300A06082A8648CE3D0403020467306502305A6E1C175B20A93FAB25D14CC5F5A2836D726D6D4A964B66FFBFFCE46276A96475F1408728B3385DCA37C2BA46BE17E1023100C46B7F08D03409A8ECCFD7637765412C3C5EC050E0D39CF48F0F5015950342CB18D8434FF331BA4463C086297C37D07B");
+        let enc_resp = RESPONSE;
         let resp = TimeStampResp::from_der(&enc_resp).unwrap();
         let content = resp.time_stamp_token.unwrap().content;
         let sd = SignedData::from_der(&content.to_der().unwrap()).unwrap();
@@ -230,4 +365,58 @@ mod tests {
         );
         assert!(tst.extensions.is_none());
     }
+
+    #[test]
+    fn tst_info_from_token_test() {
+        let token = TimeStampResp::from_der(&RESPONSE)
+            .unwrap()
+            .time_stamp_token
+            .unwrap();
+        let tst = TstInfo::from_time_stamp_token(&token).unwrap();
+        assert_eq!(tst.serial_number.as_bytes(), hex!("04"));
+        assert!(tst.message_imprint.verify::<Sha256>(b"abc"));
+        assert!(!tst.message_imprint.verify::<Sha256>(b"abd"));
+        assert!(!tst.message_imprint.verify::<Sha384>(b"abc"));
+        assert_eq!(
+            MessageImprint::new::<Sha256>(b"abc")
+                .unwrap()
+                .hashed_message,
+            tst.message_imprint.hashed_message
+        );
+
+        let data = ContentInfo {
+            content_type: ID_DATA,
+            content: Any::encode_from(&OctetString::new(tst.to_der().unwrap()).unwrap()).unwrap(),
+        };
+        assert!(TstInfo::from_time_stamp_token(&data).is_err());
+    }
+
+    #[test]
+    fn time_stamp_token_attribute_test() {
+        let token = TimeStampResp::from_der(&RESPONSE)
+            .unwrap()
+            .time_stamp_token
+            .unwrap();
+        let sd = SignedData::from_der(&token.content.to_der().unwrap()).unwrap();
+        let mut signer_info = sd.signer_infos.0.get(0).unwrap().clone();
+        assert!(time_stamp_tokens(&signer_info).unwrap().is_empty());
+
+        add_time_stamp_token(&mut signer_info, &token).unwrap();
+        let tokens = time_stamp_tokens(&signer_info).unwrap();
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0], token);
+        let unsigned_attrs = signer_info.unsigned_attrs.as_ref().unwrap();
+        assert_eq!(unsigned_attrs.len(), 1);
+        assert_eq!(unsigned_attrs.get(0).unwrap().oid, ID_AA_TIME_STAMP_TOKEN);
+
+        // Adding the same token again is rejected as a duplicate value.
+        assert!(add_time_stamp_token(&mut signer_info, &token).is_err());
+
+        // The example token timestamps "abc" rather than the signature of `signer_info`.
+        let tst = TstInfo::from_time_stamp_token(&tokens[0]).unwrap();
+        assert!(!tst.message_imprint.verify_signature::<Sha256>(&signer_info));
+        let imprint = MessageImprint::from_signature::<Sha256>(&signer_info).unwrap();
+        assert!(imprint.verify_signature::<Sha256>(&signer_info));
+        assert_eq!(imprint.hash_algorithm.oid, ID_SHA_256);
+    }
 }